@@ -39,6 +39,9 @@ extension RagErrorMessage on RagError {
     invalidInput: (msg) => msg,
     internalError: (msg) => msg,
     unknown: (msg) => msg,
+    databaseBusy: (msg) => msg,
+    notInitialized: (msg) => msg,
+    parseError: (msg) => msg,
   );
 }
 
@@ -438,6 +441,11 @@ class SourceRagService {
         internalError: (msg) =>
             debugPrint('[SmartError] Internal error rebuilding indexes: $msg'),
         unknown: (_) {},
+        databaseBusy: (msg) =>
+            debugPrint('[SmartError] DB busy rebuilding index: $msg'),
+        notInitialized: (msg) =>
+            debugPrint('[SmartError] Not initialized rebuilding index: $msg'),
+        parseError: (_) {},
       );
       rethrow;
     }
@@ -605,6 +613,11 @@ class SourceRagService {
         internalError: (msg) =>
             debugPrint('[SmartError] Search engine failure: $msg'),
         unknown: (msg) => debugPrint('[SmartError] Unknown search error: $msg'),
+        databaseBusy: (msg) =>
+            debugPrint('[SmartError] Search failed (database busy): $msg'),
+        notInitialized: (msg) =>
+            debugPrint('[SmartError] Search engine not initialized: $msg'),
+        parseError: (_) {},
       );
       rethrow;
     }
@@ -874,7 +887,7 @@ class SourceRagService {
           ? (topK * 10).clamp(50, 200)
           : topK;
 
-      results = await hybrid.searchHybrid(
+      results = (await hybrid.searchHybrid(
         queryText: query,
         queryEmbedding: queryEmbedding,
         topK: effectiveTopK,
@@ -882,11 +895,14 @@ class SourceRagService {
           k: 60,
           vectorWeight: vectorWeight,
           bm25Weight: bm25Weight,
+          typeBoosts: const {},
+          recencyWeight: 0.0,
+          fusion: const hybrid.FusionStrategy.rrf(),
         ),
         filter: sourceIds != null
             ? hybrid.SearchFilter(sourceIds: _toInt64List(sourceIds))
             : null,
-      );
+      )).results;
     } on RagError catch (e) {
       e.when(
         databaseError: (msg) =>
@@ -897,6 +913,11 @@ class SourceRagService {
         internalError: (msg) =>
             log('[SmartError] Hybrid search engine error: $msg'),
         unknown: (msg) => log('[SmartError] Hybrid search unknown error: $msg'),
+        databaseBusy: (msg) =>
+            debugPrint('[SmartError] Hybrid search DB busy: $msg'),
+        notInitialized: (msg) =>
+            log('[SmartError] Hybrid search not initialized: $msg'),
+        parseError: (_) {},
       );
       rethrow;
     }
@@ -1046,6 +1067,7 @@ Future<void> _processContentInIsolate(_IsolateRequest req) async {
       int chunkIdx;
       int startPos;
       int endPos;
+      String? batchId;
 
       if (req.strategy == ChunkingStrategy.markdown) {
         final c = rawChunk as StructuredChunk;
@@ -1056,6 +1078,7 @@ Future<void> _processContentInIsolate(_IsolateRequest req) async {
         chunkIdx = c.index;
         startPos = c.startPos;
         endPos = c.endPos;
+        batchId = c.batchId;
       } else {
         final c = rawChunk as SemanticChunk;
         contentStr = c.content;
@@ -1075,6 +1098,7 @@ Future<void> _processContentInIsolate(_IsolateRequest req) async {
           endPos: endPos,
           chunkType: chunkType,
           embedding: Float32List.fromList(embedding),
+          batchId: batchId,
         ),
       );
     }
@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `defaults`, `for_word`, `get`, `insert`, `is_stopword_in`, `is_stopword`, `is_stopword`, `plain`, `registry`, `strip_korean_particle`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Registry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Add or replace the analyzer for `analyzer.language`. Registering
+/// "unknown" replaces the fallback used for undetected scripts.
+void  registerLanguageAnalyzer({required LanguageAnalyzer analyzer }) => RustLib.instance.api.crateApiAnalyzersRegisterLanguageAnalyzer(analyzer: analyzer);
+
+/// Analyzer applied to `language` (the fallback if none is registered).
+LanguageAnalyzer  getLanguageAnalyzer({required String language }) => RustLib.instance.api.crateApiAnalyzersGetLanguageAnalyzer(language: language);
+
+List<LanguageAnalyzer>  listLanguageAnalyzers() => RustLib.instance.api.crateApiAnalyzersListLanguageAnalyzers();
+
+/// Restore the built-in analyzers.
+void  resetLanguageAnalyzers() => RustLib.instance.api.crateApiAnalyzersResetLanguageAnalyzers();
+
+            class LanguageAnalyzer  {
+                /// Code as returned by `detect_language` ("en", "ko", "ja", "zh", "unknown").
+final String language;
+/// Used by query normalization, keyword extraction and compression.
+final List<String> stopwords;
+
+                const LanguageAnalyzer({required this.language ,required this.stopwords ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => language.hashCode^stopwords.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LanguageAnalyzer &&
+                runtimeType == other.runtimeType
+                && language == other.language&& stopwords == other.stopwords;
+        
+            }
+            
\ No newline at end of file
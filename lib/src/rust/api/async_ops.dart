@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'hybrid_search.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search_trace.dart';
+import 'snippets.dart';
+import 'source_grouping.dart';
+import 'source_rag.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `run_on`, `run_thread`, `start_threads`, `submit`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Lane`, `Pending`, `Pool`, `Slot`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `poll`
+
+
+            /// `search_hybrid` on the query pool.
+Future<HybridSearchResponse>  searchHybridAsync({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options }) => RustLib.instance.api.crateApiAsyncOpsSearchHybridAsync(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, config: config, filter: filter, options: options);
+
+/// `add_chunks` on the heavy pool.
+Future<int>  addChunksAsync({required PlatformInt64 sourceId , required List<ChunkData> chunks }) => RustLib.instance.api.crateApiAsyncOpsAddChunksAsync(sourceId: sourceId, chunks: chunks);
+
+/// `add_chunks_packed` on the heavy pool.
+Future<int>  addChunksPackedAsync({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets }) => RustLib.instance.api.crateApiAsyncOpsAddChunksPackedAsync(sourceId: sourceId, chunks: chunks, embeddings: embeddings, offsets: offsets);
+
+/// `rebuild_chunk_hnsw_index` on the heavy pool.
+Future<void>  rebuildChunkHnswIndexAsync() => RustLib.instance.api.crateApiAsyncOpsRebuildChunkHnswIndexAsync();
+
+/// `rebuild_chunk_bm25_index` on the heavy pool.
+Future<void>  rebuildChunkBm25IndexAsync() => RustLib.instance.api.crateApiAsyncOpsRebuildChunkBm25IndexAsync();
+
+            
+            
\ No newline at end of file
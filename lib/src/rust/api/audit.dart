@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add`, `audit_database`, `check_chunks`, `check_dangling_references`, `check_index_parity`, `check_sources`, `check_sqlite`, `severity_rank`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Findings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Audit the active tenant's data and indexes (plus database-wide checks).
+Future<AuditReport>  audit() => RustLib.instance.api.crateApiAuditAudit();
+
+/// Report of the most recent `audit()` call in this process.
+AuditReport?  getLastAuditReport() => RustLib.instance.api.crateApiAuditGetLastAuditReport();
+
+            class AuditIssue  {
+                /// One of the `SEVERITY_*` values.
+final String severity;
+/// Stable identifier, e.g. "content_hash_mismatch".
+final String code;
+final String message;
+final PlatformInt64? sourceId;
+final PlatformInt64? chunkId;
+
+                const AuditIssue({required this.severity ,required this.code ,required this.message ,this.sourceId ,this.chunkId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => severity.hashCode^code.hashCode^message.hashCode^sourceId.hashCode^chunkId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuditIssue &&
+                runtimeType == other.runtimeType
+                && severity == other.severity&& code == other.code&& message == other.message&& sourceId == other.sourceId&& chunkId == other.chunkId;
+        
+            }
+
+class AuditReport  {
+                final PlatformInt64 startedAt;
+final BigInt durationMs;
+final BigInt sourcesChecked;
+final BigInt chunksChecked;
+/// First `MAX_REPORTED_ISSUES` findings.
+final List<AuditIssue> issues;
+final int issueCount;
+/// Highest severity found, or `None` when the audit is clean.
+final String? maxSeverity;
+
+                const AuditReport({required this.startedAt ,required this.durationMs ,required this.sourcesChecked ,required this.chunksChecked ,required this.issues ,required this.issueCount ,this.maxSeverity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startedAt.hashCode^durationMs.hashCode^sourcesChecked.hashCode^chunksChecked.hashCode^issues.hashCode^issueCount.hashCode^maxSeverity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuditReport &&
+                runtimeType == other.runtimeType
+                && startedAt == other.startedAt&& durationMs == other.durationMs&& sourcesChecked == other.sourcesChecked&& chunksChecked == other.chunksChecked&& issues == other.issues&& issueCount == other.issueCount&& maxSeverity == other.maxSeverity;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'incremental_index.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'reembedding.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `is_finished`, `now_secs`, `prune_finished`, `run_worker`, `submit`, `update_task`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TaskQueue`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Queue a rebuild of the active tenant's chunk HNSW and BM25 indexes.
+Future<PlatformInt64>  submitIndexRebuild() => RustLib.instance.api.crateApiBackgroundTasksSubmitIndexRebuild();
+
+/// Queue a merge of the active tenant's incremental buffer of `table`
+/// into HNSW (see `merge_buffer_into_hnsw`).
+Future<PlatformInt64>  submitBufferMerge({required BufferTable table }) => RustLib.instance.api.crateApiBackgroundTasksSubmitBufferMerge(table: table);
+
+/// Queue a snapshot export to `path`.
+Future<PlatformInt64>  submitSnapshotExport({required String path }) => RustLib.instance.api.crateApiBackgroundTasksSubmitSnapshotExport(path: path);
+
+/// Queue a re-embedding job; arguments are as for `start_reembedding_job`.
+/// The Dart callbacks are driven from the worker thread.
+Future<PlatformInt64>  submitReembedding({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress }) => RustLib.instance.api.crateApiBackgroundTasksSubmitReembedding(modelName: modelName, batchSize: batchSize, embedBatch: embedBatch, onProgress: onProgress);
+
+BackgroundTask?  getBackgroundTask({required PlatformInt64 taskId }) => RustLib.instance.api.crateApiBackgroundTasksGetBackgroundTask(taskId: taskId);
+
+/// Known tasks, oldest first.
+List<BackgroundTask>  listBackgroundTasks() => RustLib.instance.api.crateApiBackgroundTasksListBackgroundTasks();
+
+/// Cancel a queued task. Returns false if it already started or finished.
+bool  cancelBackgroundTask({required PlatformInt64 taskId }) => RustLib.instance.api.crateApiBackgroundTasksCancelBackgroundTask(taskId: taskId);
+
+bool  isBackgroundWorkerRunning() => RustLib.instance.api.crateApiBackgroundTasksIsBackgroundWorkerRunning();
+
+            class BackgroundTask  {
+                final PlatformInt64 id;
+final BackgroundTaskKind kind;
+/// Tenant active at submission; the task fails if it changed.
+final String tenantId;
+/// One of the `TASK_*` values.
+final String state;
+/// Short outcome summary for completed tasks.
+final String? detail;
+final String? error;
+/// Seconds since the Unix epoch.
+final PlatformInt64 submittedAt;
+final PlatformInt64? startedAt;
+final PlatformInt64? finishedAt;
+
+                const BackgroundTask({required this.id ,required this.kind ,required this.tenantId ,required this.state ,this.detail ,this.error ,required this.submittedAt ,this.startedAt ,this.finishedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^tenantId.hashCode^state.hashCode^detail.hashCode^error.hashCode^submittedAt.hashCode^startedAt.hashCode^finishedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackgroundTask &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& tenantId == other.tenantId&& state == other.state&& detail == other.detail&& error == other.error&& submittedAt == other.submittedAt&& startedAt == other.startedAt&& finishedAt == other.finishedAt;
+        
+            }
+
+enum BackgroundTaskKind {
+                    /// Rebuild the chunk HNSW and BM25 indexes from the database.
+indexRebuild,
+/// Fold the incremental buffer into the HNSW index.
+bufferMerge,
+/// Write a snapshot archive (`create_snapshot`).
+snapshotExport,
+/// Re-embed every chunk (`start_reembedding_job`).
+reembedding,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `elapsed_ms`, `exact_top_k`, `norm`, `percentile`, `read_proc_status_kb`, `recall`, `record`, `synthetic_embedding`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `StageRecorder`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Measure the active tenant's chunk index against brute force.
+///
+/// Runs `sample_size` randomly chosen stored embeddings as queries and
+/// compares the index's top `top_k` with an exact scan of every stored
+/// embedding under the configured metric. Read-only; needs a loaded
+/// index. The scan holds all embeddings in memory while it runs.
+Future<IndexEvaluation>  evaluateIndex({required int sampleSize , required int topK }) => RustLib.instance.api.crateApiBenchmarkEvaluateIndex(sampleSize: sampleSize, topK: topK);
+
+/// Run the pipeline benchmark and return a per-stage report.
+Future<BenchmarkReport>  runBenchmark({required BenchmarkConfig config }) => RustLib.instance.api.crateApiBenchmarkRunBenchmark(config: config);
+
+            class BenchmarkConfig  {
+                /// Document to parse (PDF or DOCX bytes). Synthetic text when `None`.
+final Uint8List? documentBytes;
+/// Paragraph repetitions for the synthetic document.
+final int syntheticParagraphs;
+final int chunkMaxChars;
+final int embeddingDim;
+final int searchIterations;
+final int topK;
+
+                const BenchmarkConfig({this.documentBytes ,required this.syntheticParagraphs ,required this.chunkMaxChars ,required this.embeddingDim ,required this.searchIterations ,required this.topK ,});
+
+                static Future<BenchmarkConfig>  default_()=>RustLib.instance.api.crateApiBenchmarkBenchmarkConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => documentBytes.hashCode^syntheticParagraphs.hashCode^chunkMaxChars.hashCode^embeddingDim.hashCode^searchIterations.hashCode^topK.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkConfig &&
+                runtimeType == other.runtimeType
+                && documentBytes == other.documentBytes&& syntheticParagraphs == other.syntheticParagraphs&& chunkMaxChars == other.chunkMaxChars&& embeddingDim == other.embeddingDim&& searchIterations == other.searchIterations&& topK == other.topK;
+        
+            }
+
+class BenchmarkReport  {
+                final List<BenchmarkStage> stages;
+final int documentChars;
+final int chunkCount;
+final int tokenCount;
+/// Mean and p95 latency of a single hybrid (vector + BM25) search.
+final double searchMeanMs;
+final double searchP95Ms;
+/// Peak resident memory of the process (KB), if available.
+final BigInt? peakRssKb;
+final double totalMs;
+
+                const BenchmarkReport({required this.stages ,required this.documentChars ,required this.chunkCount ,required this.tokenCount ,required this.searchMeanMs ,required this.searchP95Ms ,this.peakRssKb ,required this.totalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stages.hashCode^documentChars.hashCode^chunkCount.hashCode^tokenCount.hashCode^searchMeanMs.hashCode^searchP95Ms.hashCode^peakRssKb.hashCode^totalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkReport &&
+                runtimeType == other.runtimeType
+                && stages == other.stages&& documentChars == other.documentChars&& chunkCount == other.chunkCount&& tokenCount == other.tokenCount&& searchMeanMs == other.searchMeanMs&& searchP95Ms == other.searchP95Ms&& peakRssKb == other.peakRssKb&& totalMs == other.totalMs;
+        
+            }
+
+class BenchmarkStage  {
+                /// "parse", "chunk", "tokenize", "index" or "search".
+final String name;
+final double durationMs;
+/// Stage was not run (e.g. tokenizer not initialized).
+final bool skipped;
+/// Resident memory after the stage (KB); `None` where the OS doesn't expose it.
+final BigInt? rssKb;
+
+                const BenchmarkStage({required this.name ,required this.durationMs ,required this.skipped ,this.rssKb ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^durationMs.hashCode^skipped.hashCode^rssKb.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkStage &&
+                runtimeType == other.runtimeType
+                && name == other.name&& durationMs == other.durationMs&& skipped == other.skipped&& rssKb == other.rssKb;
+        
+            }
+
+class IndexEvaluation  {
+                /// Queries actually run (at most the number of stored chunks).
+final int sampleSize;
+final int topK;
+final BigInt corpusSize;
+/// Mean share of the exact top-k the index returned.
+final double recallAtK;
+/// Worst single-query recall.
+final double minRecall;
+final double latencyP50Ms;
+final double latencyP95Ms;
+final double latencyP99Ms;
+/// Mean latency of the brute-force scan, for comparison.
+final double exactMeanMs;
+
+                const IndexEvaluation({required this.sampleSize ,required this.topK ,required this.corpusSize ,required this.recallAtK ,required this.minRecall ,required this.latencyP50Ms ,required this.latencyP95Ms ,required this.latencyP99Ms ,required this.exactMeanMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sampleSize.hashCode^topK.hashCode^corpusSize.hashCode^recallAtK.hashCode^minRecall.hashCode^latencyP50Ms.hashCode^latencyP95Ms.hashCode^latencyP99Ms.hashCode^exactMeanMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexEvaluation &&
+                runtimeType == other.runtimeType
+                && sampleSize == other.sampleSize&& topK == other.topK&& corpusSize == other.corpusSize&& recallAtK == other.recallAtK&& minRecall == other.minRecall&& latencyP50Ms == other.latencyP50Ms&& latencyP95Ms == other.latencyP95Ms&& latencyP99Ms == other.latencyP99Ms&& exactMeanMs == other.exactMeanMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'source_rag.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build`, `candidates`, `clear_all_binary_indexes`, `drop_binary_index`, `hamming`, `load_points`, `pack_signs`, `rebuild`, `size_bytes`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BinaryIndex`
+
+
+            /// Rebuild the active tenant's binary index from the chunks table and
+/// return the number of points indexed.
+Future<int>  buildBinaryIndex() => RustLib.instance.api.crateApiBinaryIndexBuildBinaryIndex();
+
+/// Search chunks with a Hamming-distance prefilter over the binary index,
+/// then rank the shortlist by the exact configured distance against the
+/// stored embeddings. The index is (re)built on first use and after any change
+/// to the tenant's chunks.
+Future<List<ChunkSearchResult>>  searchChunksBinary({required List<double> queryEmbedding , required int topK }) => RustLib.instance.api.crateApiBinaryIndexSearchChunksBinary(queryEmbedding: queryEmbedding, topK: topK);
+
+            
+            
\ No newline at end of file
@@ -4,63 +4,177 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `tokenize_for_bm25`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DocMeta`, `InvertedIndex`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `fmt`
-// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `add_document`, `clear`, `is_empty`, `len`, `new`, `remove_document`, `search`
 
-/// Add document to BM25 index.
-Future<void> bm25AddDocument({
-  required PlatformInt64 docId,
-  required String content,
-}) => RustLib.instance.api.crateApiBm25SearchBm25AddDocument(
-  docId: docId,
-  content: content,
-);
+            // These functions are ignored because they are not marked as `pub`: `active_index_options`, `bm25_add_documents_to_tenant`, `bm25_add_documents_with_progress`, `bm25_boolean_constraint`, `bm25_clear_tenant_index`, `bm25_document_ids`, `bm25_dump_tenant`, `bm25_load_tenant`, `bm25_remove_documents_from_tenant`, `bm25_search_in_tenant`, `bm25_tenants`, `bm25_term_stats`, `bm25_vocabulary_size`, `cjk_ngram_tokens`, `configured_options`, `drop_all_bm25_indexes`, `drop_tenant_bm25_index`, `expand_terms`, `index_tokens`, `is_cjk_or_hangul`, `keep_bm25_token`, `rebuild_vocabulary`, `search_tokens`, `stemmer_algorithm`, `tenant_bm25_index_loaded`, `term_score`, `tokenize_for_bm25`, `tokenize_with`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BooleanTokens`, `DocConstraint`, `DocMeta`, `InvertedIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `add_document`, `allows`, `clear`, `constraint`, `contains`, `default`, `default`, `document_frequency`, `from_query`, `idf`, `is_empty`, `len`, `new`, `remove_document`, `search_boolean`, `search`, `tokenize`, `update_document`, `vocabulary_size`, `with_options`
+
+
+            /// Set the active tenant's BM25 options. They take effect when the index
+/// is next built (e.g. `rebuild_chunk_bm25_index`); until then searches
+/// keep using the options the current index was built with.
+void  setBm25IndexOptions({required Bm25IndexOptions options }) => RustLib.instance.api.crateApiBm25SearchSetBm25IndexOptions(options: options);
+
+/// The active tenant's configured BM25 options.
+Bm25IndexOptions  getBm25IndexOptions() => RustLib.instance.api.crateApiBm25SearchGetBm25IndexOptions();
+
+void  setBm25Config({required Bm25Config config }) => RustLib.instance.api.crateApiBm25SearchSetBm25Config(config: config);
+
+Bm25Config  getBm25Config() => RustLib.instance.api.crateApiBm25SearchGetBm25Config();
+
+/// Add document to BM25 index. A no-op with the FTS5 backend, whose
+/// triggers index every row.
+Future<void>  bm25AddDocument({required PlatformInt64 docId , required String content }) => RustLib.instance.api.crateApiBm25SearchBm25AddDocument(docId: docId, content: content);
 
 /// Add multiple documents to BM25 index (batch).
-Future<void> bm25AddDocuments({required List<(PlatformInt64, String)> docs}) =>
-    RustLib.instance.api.crateApiBm25SearchBm25AddDocuments(docs: docs);
+Future<void>  bm25AddDocuments({required List<(PlatformInt64,String)> docs }) => RustLib.instance.api.crateApiBm25SearchBm25AddDocuments(docs: docs);
+
+/// Re-index `doc_id` with new `content` (added if not indexed yet).
+/// A no-op with the FTS5 backend, whose triggers follow the update.
+Future<void>  bm25UpdateDocument({required PlatformInt64 docId , required String content }) => RustLib.instance.api.crateApiBm25SearchBm25UpdateDocument(docId: docId, content: content);
 
 /// Remove document from BM25 index.
-Future<void> bm25RemoveDocument({required PlatformInt64 docId}) =>
-    RustLib.instance.api.crateApiBm25SearchBm25RemoveDocument(docId: docId);
+Future<void>  bm25RemoveDocument({required PlatformInt64 docId }) => RustLib.instance.api.crateApiBm25SearchBm25RemoveDocument(docId: docId);
 
 /// Search using BM25.
-Future<List<Bm25SearchResult>> bm25Search({
-  required String query,
-  required int topK,
-}) =>
-    RustLib.instance.api.crateApiBm25SearchBm25Search(query: query, topK: topK);
+Future<List<Bm25SearchResult>>  bm25Search({required String query , required int topK }) => RustLib.instance.api.crateApiBm25SearchBm25Search(query: query, topK: topK);
 
-/// Clear BM25 index.
-Future<void> bm25ClearIndex() =>
-    RustLib.instance.api.crateApiBm25SearchBm25ClearIndex();
+/// Clear BM25 index (for the active tenant).
+Future<void>  bm25ClearIndex() => RustLib.instance.api.crateApiBm25SearchBm25ClearIndex();
 
-/// Check if BM25 index is loaded.
-Future<bool> isBm25IndexLoaded() =>
-    RustLib.instance.api.crateApiBm25SearchIsBm25IndexLoaded();
+/// Check if BM25 index is loaded (for the active tenant).
+Future<bool>  isBm25IndexLoaded() => RustLib.instance.api.crateApiBm25SearchIsBm25IndexLoaded();
 
 /// Get BM25 index document count.
-Future<BigInt> bm25GetDocumentCount() =>
-    RustLib.instance.api.crateApiBm25SearchBm25GetDocumentCount();
-
-class Bm25SearchResult {
-  final PlatformInt64 docId;
-  final double score;
-
-  const Bm25SearchResult({required this.docId, required this.score});
-
-  @override
-  int get hashCode => docId.hashCode ^ score.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Bm25SearchResult &&
-          runtimeType == other.runtimeType &&
-          docId == other.docId &&
-          score == other.score;
-}
+Future<BigInt>  bm25GetDocumentCount() => RustLib.instance.api.crateApiBm25SearchBm25GetDocumentCount();
+
+            /// BM25 scoring parameters, used by the in-memory index and the scoped
+/// scan of filtered hybrid search alike. Applies from the next query;
+/// no rebuild needed.
+class Bm25Config  {
+                /// Term-frequency saturation (typically 1.2-2.0).
+final double k1;
+/// Length normalization, 0 (none) to 1 (full). Lower it for corpora
+/// of long chunks, raise it for short notes.
+final double b;
+final Bm25Variant variant;
+
+                const Bm25Config({required this.k1 ,required this.b ,required this.variant ,});
+
+                static Future<Bm25Config>  default_()=>RustLib.instance.api.crateApiBm25SearchBm25ConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => k1.hashCode^b.hashCode^variant.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bm25Config &&
+                runtimeType == other.runtimeType
+                && k1 == other.k1&& b == other.b&& variant == other.variant;
+        
+            }
+
+/// Per-index token processing, applied to documents and queries alike.
+/// An index keeps the options it was built with; see
+/// `set_bm25_index_options`.
+class Bm25IndexOptions  {
+                final Bm25Tokenization tokenization;
+/// Snowball stemmer for non-CJK words ("english", "german", "french",
+/// ...), so "running" matches "run"; `None` disables stemming.
+final String? stemmer;
+/// Strip trailing postpositions (이/가/을/를/에서...) from Hangul words.
+final bool stripKoreanParticles;
+/// Drop each word's language stopwords (see `register_language_analyzer`).
+final bool removeStopwords;
+
+                const Bm25IndexOptions({required this.tokenization ,this.stemmer ,required this.stripKoreanParticles ,required this.removeStopwords ,});
+
+                static Future<Bm25IndexOptions>  default_()=>RustLib.instance.api.crateApiBm25SearchBm25IndexOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => tokenization.hashCode^stemmer.hashCode^stripKoreanParticles.hashCode^removeStopwords.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bm25IndexOptions &&
+                runtimeType == other.runtimeType
+                && tokenization == other.tokenization&& stemmer == other.stemmer&& stripKoreanParticles == other.stripKoreanParticles&& removeStopwords == other.removeStopwords;
+        
+            }
+
+class Bm25SearchResult  {
+                final PlatformInt64 docId;
+final double score;
+
+                const Bm25SearchResult({required this.docId ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => docId.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bm25SearchResult &&
+                runtimeType == other.runtimeType
+                && docId == other.docId&& score == other.score;
+        
+            }
+
+/// How BM25 splits text into tokens (`Bm25IndexOptions::tokenization`).
+enum Bm25Tokenization {
+                    /// Unicode word segmentation (the default). Han and Kana split into
+/// single glyphs; Hangul and other scripts split at spaces.
+words,
+/// Split at whitespace and punctuation only.
+whitespace,
+/// Character bigrams over CJK/Hangul runs, words elsewhere.
+cjkBigrams,
+/// Character trigrams over CJK/Hangul runs, words elsewhere.
+cjkTrigrams,
+/// Words plus CJK/Hangul bigrams: whole words still match exactly and
+/// the bigrams recover unsegmented or inflected text.
+mixed,
+                    ;
+                    static Future<Bm25Tokenization>  default_()=>RustLib.instance.api.crateApiBm25SearchBm25TokenizationDefault();
+
+
+                }
+
+/// Term-frequency saturation formula.
+enum Bm25Variant {
+                    /// Okapi BM25 (the default).
+bm25,
+/// BM25+: adds a floor so a match in a very long document still
+/// outscores no match.
+bm25Plus,
+/// BM25L: shifts normalized term frequency, which penalizes long
+/// documents less.
+bm25L,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_chat_memory_table`, `insert_message`, `scan_memory`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Store a chat turn with its embedding. Returns the message id.
+Future<PlatformInt64>  addMemoryMessage({required String conversationId , required String role , required String content , required List<double> embedding }) => RustLib.instance.api.crateApiChatMemoryAddMemoryMessage(conversationId: conversationId, role: role, content: content, embedding: embedding);
+
+/// Recall the messages of a conversation most similar to the query.
+Future<List<MemorySearchResult>>  searchMemory({required List<double> queryEmbedding , required int topK , required String conversationId }) => RustLib.instance.api.crateApiChatMemorySearchMemory(queryEmbedding: queryEmbedding, topK: topK, conversationId: conversationId);
+
+/// All messages of a conversation in chronological order.
+Future<List<MemoryMessage>>  getConversationMessages({required String conversationId }) => RustLib.instance.api.crateApiChatMemoryGetConversationMessages(conversationId: conversationId);
+
+/// Delete a conversation's memory.
+Future<void>  deleteConversationMemory({required String conversationId }) => RustLib.instance.api.crateApiChatMemoryDeleteConversationMemory(conversationId: conversationId);
+
+            class MemoryMessage  {
+                final PlatformInt64 id;
+final String conversationId;
+/// "user", "assistant" or "system".
+final String role;
+final String content;
+/// Unix timestamp (seconds).
+final PlatformInt64 createdAt;
+
+                const MemoryMessage({required this.id ,required this.conversationId ,required this.role ,required this.content ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^conversationId.hashCode^role.hashCode^content.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryMessage &&
+                runtimeType == other.runtimeType
+                && id == other.id&& conversationId == other.conversationId&& role == other.role&& content == other.content&& createdAt == other.createdAt;
+        
+            }
+
+class MemorySearchResult  {
+                final MemoryMessage message;
+final double similarity;
+
+                const MemorySearchResult({required this.message ,required this.similarity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => message.hashCode^similarity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemorySearchResult &&
+                runtimeType == other.runtimeType
+                && message == other.message&& similarity == other.similarity;
+        
+            }
+            
\ No newline at end of file
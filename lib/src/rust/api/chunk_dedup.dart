@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `band_keys`, `find_duplicates`, `fnv1a`, `minhash`, `similarity`, `splitmix64`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Finds exact and near-duplicate chunks so they can be skipped before
+/// embedding. Returns the indexes to keep and, for each dropped chunk, the
+/// kept chunk it duplicates.
+DedupeReport  dedupeChunks({required List<String> chunks , required DedupeConfig config }) => RustLib.instance.api.crateApiChunkDedupDedupeChunks(chunks: chunks, config: config);
+
+            class DedupeConfig  {
+                /// Estimated Jaccard similarity at or above which a chunk is dropped.
+final double nearDuplicateThreshold;
+/// Tokens per shingle.
+final int shingleSize;
+/// Only drop exact (normalized) duplicates.
+final bool exactOnly;
+
+                const DedupeConfig({required this.nearDuplicateThreshold ,required this.shingleSize ,required this.exactOnly ,});
+
+                static Future<DedupeConfig>  default_()=>RustLib.instance.api.crateApiChunkDedupDedupeConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => nearDuplicateThreshold.hashCode^shingleSize.hashCode^exactOnly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DedupeConfig &&
+                runtimeType == other.runtimeType
+                && nearDuplicateThreshold == other.nearDuplicateThreshold&& shingleSize == other.shingleSize&& exactOnly == other.exactOnly;
+        
+            }
+
+class DedupeReport  {
+                /// Indexes of the chunks to keep, in input order.
+final Uint32List kept;
+final List<DuplicateChunk> duplicates;
+
+                const DedupeReport({required this.kept ,required this.duplicates ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kept.hashCode^duplicates.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DedupeReport &&
+                runtimeType == other.runtimeType
+                && kept == other.kept&& duplicates == other.duplicates;
+        
+            }
+
+class DuplicateChunk  {
+                final int index;
+/// Index of the kept chunk it duplicates.
+final int duplicateOf;
+/// 1.0 for exact duplicates, the MinHash estimate otherwise.
+final double similarity;
+
+                const DuplicateChunk({required this.index ,required this.duplicateOf ,required this.similarity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^duplicateOf.hashCode^similarity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DuplicateChunk &&
+                runtimeType == other.runtimeType
+                && index == other.index&& duplicateOf == other.duplicateOf&& similarity == other.similarity;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `chunk_table_state`, `database_file`, `index_location`, `is_marker_current`, `marker_path`, `persist_chunk_index`, `read_marker`, `remove_marker`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChunkTableState`, `IndexMarker`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Make the active tenant's chunk HNSW index available for search.
+///
+/// Keeps an up-to-date index already in memory, otherwise loads the dump
+/// saved by the last `rebuild_chunk_hnsw_index` when its marker matches
+/// the `chunks` table (same schema version, row count, highest id and
+/// vector change counter) and the index config (quantization and metric),
+/// and rebuilds from the table only when neither applies. Call this on startup instead of `rebuild_chunk_hnsw_index`.
+Future<ChunkIndexSource>  ensureChunkIndex() => RustLib.instance.api.crateApiChunkIndexStoreEnsureChunkIndex();
+
+            /// How `ensure_chunk_index` obtained the chunk index.
+enum ChunkIndexSource {
+                    /// Already in memory and up to date.
+memory,
+/// Loaded from the dump next to the database.
+disk,
+/// Rebuilt from the `chunks` table.
+rebuilt,
+                    ;
+                    
+                }
+            
\ No newline at end of file
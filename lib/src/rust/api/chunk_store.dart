@@ -0,0 +1,121 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cosine_similarity`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HeapHit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>
+                abstract class MemoryStore implements RustOpaqueInterface, ChunkStore {
+                     Future<BigInt>  chunkCount();
+
+
+static Future<MemoryStore>  default_()=>RustLib.instance.api.crateApiChunkStoreMemoryStoreDefault();
+
+
+ Future<BigInt>  deleteSource({required PlatformInt64 sourceId });
+
+
+ Future<StoredChunk?>  getChunk({required PlatformInt64 id });
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+static Future<MemoryStore>  newInstance()=>RustLib.instance.api.crateApiChunkStoreMemoryStoreNew();
+
+
+ Future<void>  putChunk({required StoredChunk chunk });
+
+
+ Future<List<StoreSearchHit>>  searchVectors({required List<double> query , required BigInt topK });
+
+
+
+                    
+                }
+                
+
+
+                abstract class ChunkStore {
+                     Future<BigInt>  chunkCount();
+
+
+/// Remove every chunk of `source_id`; returns how many were removed.
+ Future<BigInt>  deleteSource({required PlatformInt64 sourceId });
+
+
+ Future<StoredChunk?>  getChunk({required PlatformInt64 id });
+
+
+/// Insert or replace the chunk with `chunk.id`.
+ Future<void>  putChunk({required StoredChunk chunk });
+
+
+/// Cosine similarity search, best first. Chunks whose dimension does
+/// not match the query are skipped.
+ Future<List<StoreSearchHit>>  searchVectors({required List<double> query , required BigInt topK });
+
+
+                }
+                
+
+/// One vector search hit; higher `similarity` is better.
+class StoreSearchHit  {
+                final PlatformInt64 id;
+final double similarity;
+
+                const StoreSearchHit({required this.id ,required this.similarity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^similarity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoreSearchHit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& similarity == other.similarity;
+        
+            }
+
+class StoredChunk  {
+                final PlatformInt64 id;
+final PlatformInt64 sourceId;
+final String content;
+final Float32List embedding;
+
+                const StoredChunk({required this.id ,required this.sourceId ,required this.content ,required this.embedding ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^sourceId.hashCode^content.hashCode^embedding.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoredChunk &&
+                runtimeType == other.runtimeType
+                && id == other.id&& sourceId == other.sourceId&& content == other.content&& embedding == other.embedding;
+        
+            }
+            
\ No newline at end of file
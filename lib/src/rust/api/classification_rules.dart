@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_with_rules`, `compile_rule`, `create_classification_rules_table`, `load_classification_rules`, `match_rules`, `parse_label`, `read_rules`, `save_rules`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CompiledRule`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Replace the custom classification rules and persist them.
+///
+/// Rules are tried in order before the built-in English patterns; the
+/// first rule whose language matches and whose patterns match at least
+/// `min_matches` times decides the label. An empty list removes all rules.
+Future<void>  setClassificationRules({required List<ClassificationRule> rules }) => RustLib.instance.api.crateApiClassificationRulesSetClassificationRules(rules: rules);
+
+/// Currently registered classification rules, in priority order.
+List<ClassificationRule>  getClassificationRules() => RustLib.instance.api.crateApiClassificationRulesGetClassificationRules();
+
+            class ClassificationRule  {
+                /// A `ChunkType` label: "definition", "example", "list", "procedure",
+/// "comparison" or "general".
+final String label;
+/// Language code from `detect_language` ("ko", "ja", ...) or "*".
+final String language;
+/// Regexes tried against the chunk text; use `(?i)` for case folding.
+final List<String> patterns;
+/// Patterns that must match for the rule to apply (at least 1).
+final int minMatches;
+
+                const ClassificationRule({required this.label ,required this.language ,required this.patterns ,required this.minMatches ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^language.hashCode^patterns.hashCode^minMatches.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClassificationRule &&
+                runtimeType == other.runtimeType
+                && label == other.label&& language == other.language&& patterns == other.patterns&& minMatches == other.minMatches;
+        
+            }
+            
\ No newline at end of file
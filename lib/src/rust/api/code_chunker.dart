@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'semantic_chunker.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `char_literal_len`, `chunk_units`, `detect_symbol`, `head_line`, `is_prefix_line`, `join_path`, `leading_ident`, `level`, `line_text`, `push_chunk`, `push_range`, `scan_lines`, `skip_generics`, `split_large`, `split_lines`, `split_units`, `starts_with_ident_char`, `symbol`, `syntax_for`, `unit_len`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CodeChunker`, `LexState`, `Line`, `Syntax`, `Unit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Splits source code into chunks along function/class boundaries.
+///
+/// `language` is a name or file extension (`rust`, `py`, `ts`, ...); unknown
+/// languages fall back to brace or indentation heuristics depending on the
+/// text. Small neighbouring declarations are packed together up to
+/// `max_chars` (minimum 100), and each chunk's `header_path` lists the
+/// symbols it covers.
+List<StructuredChunk>  codeChunk({required String text , required String language , required int maxChars }) => RustLib.instance.api.crateApiCodeChunkerCodeChunk(text: text, language: language, maxChars: maxChars);
+
+            
+            
\ No newline at end of file
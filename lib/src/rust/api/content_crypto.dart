@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cipher`, `decrypt_content`, `decrypt`, `encrypt_content`, `encrypt`, `ensure_no_fts`, `install`, `is_current`, `new`, `purge_plaintext_side_tables`, `read_content`, `reencrypt_rows`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `KeyRing`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+
+
+            /// Install a 32-byte key and use it for all new content writes.
+///
+/// Earlier keys stay installed for reading. The key itself is never
+/// persisted; the app must provide it again after every launch. Stored
+/// entities and triples are deleted, as they hold plaintext, and chunks
+/// whose language couldn't be detected without the key are backfilled.
+/// Fails while the FTS5 keyword backend's tables exist.
+void  setContentEncryptionKey({required String keyId , required List<int> key }) => RustLib.instance.api.crateApiContentCryptoSetContentEncryptionKey(keyId: keyId, key: key);
+
+/// Forget all keys. New content is written in plaintext and existing
+/// encrypted rows become unreadable until their key is installed again.
+void  clearContentEncryptionKeys() => RustLib.instance.api.crateApiContentCryptoClearContentEncryptionKeys();
+
+bool  isContentEncryptionEnabled() => RustLib.instance.api.crateApiContentCryptoIsContentEncryptionEnabled();
+
+/// Install a new key and re-encrypt all stored content with it.
+///
+/// Also encrypts plaintext rows, so calling this once after enabling
+/// encryption protects data ingested earlier. Applies to every tenant.
+/// Returns the number of rows rewritten. Fails while the FTS5 keyword
+/// backend's tables exist.
+Future<int>  rotateContentKey({required String newKeyId , required List<int> newKey }) => RustLib.instance.api.crateApiContentCryptoRotateContentKey(newKeyId: newKeyId, newKey: newKey);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,160 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `collect_corpus_stats`, `sorted_counts`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CorpusStats`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Corpus statistics and index freshness for the active tenant.
+Future<CorpusReport>  getCorpusReport() => RustLib.instance.api.crateApiCorpusReportGetCorpusReport();
+
+            class CorpusCount  {
+                final String key;
+final BigInt count;
+
+                const CorpusCount({required this.key ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CorpusCount &&
+                runtimeType == other.runtimeType
+                && key == other.key&& count == other.count;
+        
+            }
+
+class CorpusReport  {
+                final BigInt sourceCount;
+final BigInt chunkCount;
+final BigInt totalChars;
+/// Distinct terms in the BM25 index.
+final BigInt vocabularySize;
+final List<SourceCorpusStats> sources;
+final List<LengthBucket> chunkLengthHistogram;
+/// Chunk counts by type, most common first.
+final List<CorpusCount> chunkTypes;
+/// Chunk counts by detected language, most common first.
+final List<CorpusCount> languages;
+final IndexFreshness index;
+
+                const CorpusReport({required this.sourceCount ,required this.chunkCount ,required this.totalChars ,required this.vocabularySize ,required this.sources ,required this.chunkLengthHistogram ,required this.chunkTypes ,required this.languages ,required this.index ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceCount.hashCode^chunkCount.hashCode^totalChars.hashCode^vocabularySize.hashCode^sources.hashCode^chunkLengthHistogram.hashCode^chunkTypes.hashCode^languages.hashCode^index.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CorpusReport &&
+                runtimeType == other.runtimeType
+                && sourceCount == other.sourceCount&& chunkCount == other.chunkCount&& totalChars == other.totalChars&& vocabularySize == other.vocabularySize&& sources == other.sources&& chunkLengthHistogram == other.chunkLengthHistogram&& chunkTypes == other.chunkTypes&& languages == other.languages&& index == other.index;
+        
+            }
+
+class IndexFreshness  {
+                final BigInt dbChunkCount;
+/// `None` while no HNSW index is loaded.
+final BigInt? hnswPointCount;
+final BigInt bm25DocumentCount;
+final bool hnswUpToDate;
+final bool bm25UpToDate;
+/// Unix seconds of the newest source.
+final PlatformInt64? lastSourceAddedAt;
+
+                const IndexFreshness({required this.dbChunkCount ,this.hnswPointCount ,required this.bm25DocumentCount ,required this.hnswUpToDate ,required this.bm25UpToDate ,this.lastSourceAddedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dbChunkCount.hashCode^hnswPointCount.hashCode^bm25DocumentCount.hashCode^hnswUpToDate.hashCode^bm25UpToDate.hashCode^lastSourceAddedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexFreshness &&
+                runtimeType == other.runtimeType
+                && dbChunkCount == other.dbChunkCount&& hnswPointCount == other.hnswPointCount&& bm25DocumentCount == other.bm25DocumentCount&& hnswUpToDate == other.hnswUpToDate&& bm25UpToDate == other.bm25UpToDate&& lastSourceAddedAt == other.lastSourceAddedAt;
+        
+            }
+
+class LengthBucket  {
+                /// Inclusive upper bound in chars; `None` for the overflow bucket.
+final int? leChars;
+final BigInt count;
+
+                const LengthBucket({this.leChars ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => leChars.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LengthBucket &&
+                runtimeType == other.runtimeType
+                && leChars == other.leChars&& count == other.count;
+        
+            }
+
+class SourceCorpusStats  {
+                final PlatformInt64 sourceId;
+final String? name;
+final String? status;
+final BigInt chunkCount;
+final int minChunkChars;
+final int maxChunkChars;
+final double avgChunkChars;
+
+                const SourceCorpusStats({required this.sourceId ,this.name ,this.status ,required this.chunkCount ,required this.minChunkChars ,required this.maxChunkChars ,required this.avgChunkChars ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceId.hashCode^name.hashCode^status.hashCode^chunkCount.hashCode^minChunkChars.hashCode^maxChunkChars.hashCode^avgChunkChars.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceCorpusStats &&
+                runtimeType == other.runtimeType
+                && sourceId == other.sourceId&& name == other.name&& status == other.status&& chunkCount == other.chunkCount&& minChunkChars == other.minChunkChars&& maxChunkChars == other.maxChunkChars&& avgChunkChars == other.avgChunkChars;
+        
+            }
+            
\ No newline at end of file
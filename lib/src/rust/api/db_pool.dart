@@ -4,11 +4,14 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_connection`
 
-/// Initialize the global connection pool with optimized SQLite settings.
+            // These functions are ignored because they are not marked as `pub`: `close_db_pool_when_idle`, `db_pool_config`, `get_connection`, `pool_not_initialized`
+
+
+            /// Initialize the global connection pool with optimized SQLite settings.
 ///
 /// This should be called once during application startup, before any database operations.
 ///
@@ -18,32 +21,31 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 ///
 /// # SQLite Optimizations
 /// - WAL mode: Better concurrency for read-heavy workloads
-/// - 64MB page cache: Reduces disk I/O
+/// - 64MB page cache: Reduces disk I/O (8MB in low-memory mode)
 /// - Memory temp storage: Faster temporary operations
-/// - 256MB mmap: Memory-mapped I/O for large databases
+/// - 256MB mmap: Memory-mapped I/O for large databases (off in low-memory mode)
 ///
 /// # Example
-/// ```rust
-/// init_db_pool("/path/to/rag.sqlite", 4)?;
+/// ```no_run
+/// # use rag_engine_flutter::api::db_pool::init_db_pool;
+/// init_db_pool("/path/to/rag.sqlite".to_string(), 4)?;
+/// # Ok::<(), rag_engine_flutter::api::error::RagError>(())
 /// ```
-Future<void> initDbPool({required String dbPath, required int maxSize}) =>
-    RustLib.instance.api.crateApiDbPoolInitDbPool(
-      dbPath: dbPath,
-      maxSize: maxSize,
-    );
+Future<void>  initDbPool({required String dbPath , required int maxSize }) => RustLib.instance.api.crateApiDbPoolInitDbPool(dbPath: dbPath, maxSize: maxSize);
 
 /// Check if the connection pool is initialized.
-Future<bool> isPoolInitialized() =>
-    RustLib.instance.api.crateApiDbPoolIsPoolInitialized();
+Future<bool>  isPoolInitialized() => RustLib.instance.api.crateApiDbPoolIsPoolInitialized();
 
 /// Get pool statistics for monitoring.
 ///
 /// Returns (active_connections, idle_connections, max_size)
-Future<(int, int, int)?> getPoolStats() =>
-    RustLib.instance.api.crateApiDbPoolGetPoolStats();
+Future<(int,int,int)?>  getPoolStats() => RustLib.instance.api.crateApiDbPoolGetPoolStats();
 
 /// Close the connection pool and release all resources.
 ///
 /// This should be called during application shutdown. After calling this,
 /// you must call `init_db_pool` again before using database operations.
-Future<void> closeDbPool() => RustLib.instance.api.crateApiDbPoolCloseDbPool();
+Future<void>  closeDbPool() => RustLib.instance.api.crateApiDbPoolCloseDbPool();
+
+            
+            
\ No newline at end of file
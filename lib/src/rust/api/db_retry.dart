@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backoff_delay`, `is_transient`, `retry_transient`, `with_write_retry`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `WriteError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`, `from`
+
+
+            /// Configure the retry policy for database writes.
+void  setDbRetryConfig({required DbRetryConfig config }) => RustLib.instance.api.crateApiDbRetrySetDbRetryConfig(config: config);
+
+/// Current retry policy for database writes.
+DbRetryConfig  getDbRetryConfig() => RustLib.instance.api.crateApiDbRetryGetDbRetryConfig();
+
+            class DbRetryConfig  {
+                /// Total attempts, including the first one.
+final int maxAttempts;
+/// Delay before the first retry; doubled on every further retry.
+final BigInt baseDelayMs;
+/// Upper bound for a single delay.
+final BigInt maxDelayMs;
+
+                const DbRetryConfig({required this.maxAttempts ,required this.baseDelayMs ,required this.maxDelayMs ,});
+
+                static Future<DbRetryConfig>  default_()=>RustLib.instance.api.crateApiDbRetryDbRetryConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^baseDelayMs.hashCode^maxDelayMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DbRetryConfig &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& baseDelayMs == other.baseDelayMs&& maxDelayMs == other.maxDelayMs;
+        
+            }
+            
\ No newline at end of file
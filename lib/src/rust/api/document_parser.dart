@@ -4,26 +4,23 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `is_cjk`, `join_pages_cjk_experimental`, `join_pages`, `remove_trailing_page_number`
 
-/// Extract text content from a PDF file (bytes)
+            // These functions are ignored because they are not marked as `pub`: `is_cjk`, `join_pages_cjk_experimental`, `join_pages`, `remove_trailing_page_number`
+
+
+            /// Extract text content from a PDF file (bytes)
 /// Uses page-by-page extraction for safe page number removal and hyphenation handling
-Future<String> extractTextFromPdf({required List<int> fileBytes}) => RustLib
-    .instance
-    .api
-    .crateApiDocumentParserExtractTextFromPdf(fileBytes: fileBytes);
+Future<String>  extractTextFromPdf({required List<int> fileBytes }) => RustLib.instance.api.crateApiDocumentParserExtractTextFromPdf(fileBytes: fileBytes);
 
 /// Extract text content from a DOCX file (bytes)
-Future<String> extractTextFromDocx({required List<int> fileBytes}) => RustLib
-    .instance
-    .api
-    .crateApiDocumentParserExtractTextFromDocx(fileBytes: fileBytes);
+Future<String>  extractTextFromDocx({required List<int> fileBytes }) => RustLib.instance.api.crateApiDocumentParserExtractTextFromDocx(fileBytes: fileBytes);
 
 /// Auto-detect document type and extract text
 /// Uses magic bytes to determine file format
-Future<String> extractTextFromDocument({required List<int> fileBytes}) =>
-    RustLib.instance.api.crateApiDocumentParserExtractTextFromDocument(
-      fileBytes: fileBytes,
-    );
+Future<String>  extractTextFromDocument({required List<int> fileBytes }) => RustLib.instance.api.crateApiDocumentParserExtractTextFromDocument(fileBytes: fileBytes);
+
+            
+            
\ No newline at end of file
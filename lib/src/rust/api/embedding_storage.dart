@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `blob_distance`, `decode_embedding_into`, `decode_embedding`, `encode_embedding`, `encode_f32`, `encode_quantized`, `is_quantized`, `quantize_values`, `quantized_parts`, `stored_dimension`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Store newly written embeddings as int8 (true) or `f32` (false).
+/// Existing rows keep their format; see `quantize_stored_embeddings`.
+void  setQuantizedEmbeddingStorage({required bool enabled }) => RustLib.instance.api.crateApiEmbeddingStorageSetQuantizedEmbeddingStorage(enabled: enabled);
+
+bool  isQuantizedEmbeddingStorage() => RustLib.instance.api.crateApiEmbeddingStorageIsQuantizedEmbeddingStorage();
+
+/// Convert the active tenant's `f32` embeddings to the quantized format,
+/// `CONVERT_BATCH` rows per transaction. Returns the number converted.
+/// The HNSW index is unaffected until its next rebuild.
+Future<int>  quantizeStoredEmbeddings() => RustLib.instance.api.crateApiEmbeddingStorageQuantizeStoredEmbeddings();
+
+Future<EmbeddingStorageStats>  getEmbeddingStorageStats() => RustLib.instance.api.crateApiEmbeddingStorageGetEmbeddingStorageStats();
+
+            class EmbeddingStorageStats  {
+                final PlatformInt64 f32Chunks;
+final PlatformInt64 quantizedChunks;
+/// Total size of the active tenant's embedding column.
+final PlatformInt64 embeddingBytes;
+
+                const EmbeddingStorageStats({required this.f32Chunks ,required this.quantizedChunks ,required this.embeddingBytes ,});
+
+                static Future<EmbeddingStorageStats>  default_()=>RustLib.instance.api.crateApiEmbeddingStorageEmbeddingStorageStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => f32Chunks.hashCode^quantizedChunks.hashCode^embeddingBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingStorageStats &&
+                runtimeType == other.runtimeType
+                && f32Chunks == other.f32Chunks&& quantizedChunks == other.quantizedChunks&& embeddingBytes == other.embeddingBytes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active_features`, `build_features`, `database_info`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Register the name of the embedding model the app runs, so it shows up
+/// in `get_engine_info()` (`None` clears it).
+void  setEmbeddingModelName({String? name }) => RustLib.instance.api.crateApiEngineInfoSetEmbeddingModelName(name: name);
+
+/// Versions, loaded tokenizer/model and enabled features of this engine.
+Future<EngineInfo>  getEngineInfo() => RustLib.instance.api.crateApiEngineInfoGetEngineInfo();
+
+            class EngineInfo  {
+                /// Version of this crate (`CARGO_PKG_VERSION`).
+final String crateVersion;
+/// Table layout this engine creates.
+final int schemaVersion;
+/// `user_version` of the open database; `None` before `init_db_pool`.
+final int? databaseSchemaVersion;
+final int hnswFormatVersion;
+final int bm25FormatVersion;
+final int snapshotFormatVersion;
+/// `<dir>/<file>` of the tokenizer passed to `init_tokenizer`.
+final String? tokenizerName;
+/// Set with `set_embedding_model_name`.
+final String? embeddingModelName;
+/// Dimension of the active tenant's stored embeddings; `None` when it
+/// has no chunks or the database is not open.
+final int? embeddingDimension;
+final String activeTenant;
+/// Runtime features currently on: `encryption`, `quantized_index`,
+/// `quantized_embeddings`, `low_memory`, `source_indexes`,
+/// `seeded_hnsw`, `search_analytics`, `search_tracing`.
+final List<String> activeFeatures;
+/// Cargo features this library was compiled with.
+final List<String> buildFeatures;
+
+                const EngineInfo({required this.crateVersion ,required this.schemaVersion ,this.databaseSchemaVersion ,required this.hnswFormatVersion ,required this.bm25FormatVersion ,required this.snapshotFormatVersion ,this.tokenizerName ,this.embeddingModelName ,this.embeddingDimension ,required this.activeTenant ,required this.activeFeatures ,required this.buildFeatures ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => crateVersion.hashCode^schemaVersion.hashCode^databaseSchemaVersion.hashCode^hnswFormatVersion.hashCode^bm25FormatVersion.hashCode^snapshotFormatVersion.hashCode^tokenizerName.hashCode^embeddingModelName.hashCode^embeddingDimension.hashCode^activeTenant.hashCode^activeFeatures.hashCode^buildFeatures.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EngineInfo &&
+                runtimeType == other.runtimeType
+                && crateVersion == other.crateVersion&& schemaVersion == other.schemaVersion&& databaseSchemaVersion == other.databaseSchemaVersion&& hnswFormatVersion == other.hnswFormatVersion&& bm25FormatVersion == other.bm25FormatVersion&& snapshotFormatVersion == other.snapshotFormatVersion&& tokenizerName == other.tokenizerName&& embeddingModelName == other.embeddingModelName&& embeddingDimension == other.embeddingDimension&& activeTenant == other.activeTenant&& activeFeatures == other.activeFeatures&& buildFeatures == other.buildFeatures;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_entity_table`, `entity_filter_condition`, `month_number`, `normalize_date`, `push`, `store_chunk_entities`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Collector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Replace the gazetteer used for new chunks.
+void  setEntityGazetteer({required List<GazetteerEntry> entries }) => RustLib.instance.api.crateApiEntityExtractionSetEntityGazetteer(entries: entries);
+
+/// Extract entities from text (the same rules used at ingestion).
+List<ExtractedEntity>  extractEntities({required String text }) => RustLib.instance.api.crateApiEntityExtractionExtractEntities(text: text);
+
+/// Entities stored for a chunk.
+Future<List<ExtractedEntity>>  getChunkEntities({required PlatformInt64 chunkId }) => RustLib.instance.api.crateApiEntityExtractionGetChunkEntities(chunkId: chunkId);
+
+/// Most frequent entity values (optionally of one type) for facet UIs.
+Future<List<EntityFacet>>  listEntityFacets({String? entityType , required int limit }) => RustLib.instance.api.crateApiEntityExtractionListEntityFacets(entityType: entityType, limit: limit);
+
+            class EntityFacet  {
+                final String entityType;
+final String normalized;
+final int chunkCount;
+
+                const EntityFacet({required this.entityType ,required this.normalized ,required this.chunkCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entityType.hashCode^normalized.hashCode^chunkCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EntityFacet &&
+                runtimeType == other.runtimeType
+                && entityType == other.entityType&& normalized == other.normalized&& chunkCount == other.chunkCount;
+        
+            }
+
+class ExtractedEntity  {
+                /// "date", "amount", "email", "url", "phrase" or a gazetteer type.
+final String entityType;
+/// Text as it appears in the chunk.
+final String text;
+/// Canonical value used for filtering ("2023-09-15", "$1,000", lowercase emails/terms).
+final String normalized;
+
+                const ExtractedEntity({required this.entityType ,required this.text ,required this.normalized ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entityType.hashCode^text.hashCode^normalized.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExtractedEntity &&
+                runtimeType == other.runtimeType
+                && entityType == other.entityType&& text == other.text&& normalized == other.normalized;
+        
+            }
+
+/// A user-provided term to tag, e.g. `{ term: "Ethereum", entity_type: "chain" }`.
+class GazetteerEntry  {
+                final String term;
+final String entityType;
+
+                const GazetteerEntry({required this.term ,required this.entityType ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => term.hashCode^entityType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GazetteerEntry &&
+                runtimeType == other.runtimeType
+                && term == other.term&& entityType == other.entityType;
+        
+            }
+            
\ No newline at end of file
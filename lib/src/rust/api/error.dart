@@ -8,28 +8,54 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'error.freezed.dart';
 
+            // These functions are ignored because they are not marked as `pub`: `db_error`, `io_error`, `mentions_any`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
 
-@freezed
-sealed class RagError with _$RagError implements FrbException {
-  const RagError._();
 
-  /// Database related error (potential for retry).
-  const factory RagError.databaseError(String field0) = RagError_DatabaseError;
+            
 
-  /// I/O error (file missing, permission issues, etc.).
-  const factory RagError.ioError(String field0) = RagError_IoError;
+            @freezed
+                sealed class RagError with _$RagError implements FrbException {
+                    const RagError._();
 
-  /// Failed to load embedding model.
-  const factory RagError.modelLoadError(String field0) =
-      RagError_ModelLoadError;
+                     /// Database related error (potential for retry).
+const factory RagError.databaseError(  String field0,) = RagError_DatabaseError;
+ /// I/O error (file missing, permission issues, etc.).
+const factory RagError.ioError(  String field0,) = RagError_IoError;
+ /// Failed to load embedding model.
+const factory RagError.modelLoadError(  String field0,) = RagError_ModelLoadError;
+ /// User input error (invalid query, etc.).
+const factory RagError.invalidInput(  String field0,) = RagError_InvalidInput;
+ /// Internal system error (HNSW, Logic, etc.).
+const factory RagError.internalError(  String field0,) = RagError_InternalError;
+ /// Unknown error.
+const factory RagError.unknown(  String field0,) = RagError_Unknown;
+ /// Database stayed busy/locked after all automatic retries.
+const factory RagError.databaseBusy(  String field0,) = RagError_DatabaseBusy;
+ /// A required component (DB pool, tokenizer, index) was not set up yet.
+const factory RagError.notInitialized(  String field0,) = RagError_NotInitialized;
+ /// Input could not be parsed (corrupt PDF/DOCX, unreadable archive).
+const factory RagError.parseError(  String field0,) = RagError_ParseError;
 
-  /// User input error (invalid query, etc.).
-  const factory RagError.invalidInput(String field0) = RagError_InvalidInput;
+                    
 
-  /// Internal system error (HNSW, Logic, etc.).
-  const factory RagError.internalError(String field0) = RagError_InternalError;
+                    /// Stable machine-readable code for this error kind.
+///
+/// Codes never change between releases, so the Flutter layer can branch
+/// on them instead of parsing messages.
+ String  code()=>RustLib.instance.api.crateApiErrorRagErrorCode(that: this, );
 
-  /// Unknown error.
-  const factory RagError.unknown(String field0) = RagError_Unknown;
-}
+
+/// Message without the kind prefix.
+ String  detail()=>RustLib.instance.api.crateApiErrorRagErrorDetail(that: this, );
+
+
+/// Whether retrying the same call may succeed: a busy or locked
+/// database, or a transient file system error (interrupted, timed out,
+/// temporarily unavailable). Other errors need a user-facing message or
+/// a code fix instead.
+ bool  isRetryable()=>RustLib.instance.api.crateApiErrorRagErrorIsRetryable(that: this, );
+
+
+                }
+            
\ No newline at end of file
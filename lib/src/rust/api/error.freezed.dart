@@ -86,7 +86,7 @@ extension RagErrorPatterns on RagError {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RagError_DatabaseError value)?  databaseError,TResult Function( RagError_IoError value)?  ioError,TResult Function( RagError_ModelLoadError value)?  modelLoadError,TResult Function( RagError_InvalidInput value)?  invalidInput,TResult Function( RagError_InternalError value)?  internalError,TResult Function( RagError_Unknown value)?  unknown,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RagError_DatabaseError value)?  databaseError,TResult Function( RagError_IoError value)?  ioError,TResult Function( RagError_ModelLoadError value)?  modelLoadError,TResult Function( RagError_InvalidInput value)?  invalidInput,TResult Function( RagError_InternalError value)?  internalError,TResult Function( RagError_Unknown value)?  unknown,TResult Function( RagError_DatabaseBusy value)?  databaseBusy,TResult Function( RagError_NotInitialized value)?  notInitialized,TResult Function( RagError_ParseError value)?  parseError,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case RagError_DatabaseError() when databaseError != null:
@@ -95,7 +95,10 @@ return ioError(_that);case RagError_ModelLoadError() when modelLoadError != null
 return modelLoadError(_that);case RagError_InvalidInput() when invalidInput != null:
 return invalidInput(_that);case RagError_InternalError() when internalError != null:
 return internalError(_that);case RagError_Unknown() when unknown != null:
-return unknown(_that);case _:
+return unknown(_that);case RagError_DatabaseBusy() when databaseBusy != null:
+return databaseBusy(_that);case RagError_NotInitialized() when notInitialized != null:
+return notInitialized(_that);case RagError_ParseError() when parseError != null:
+return parseError(_that);case _:
   return orElse();
 
 }
@@ -113,7 +116,7 @@ return unknown(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RagError_DatabaseError value)  databaseError,required TResult Function( RagError_IoError value)  ioError,required TResult Function( RagError_ModelLoadError value)  modelLoadError,required TResult Function( RagError_InvalidInput value)  invalidInput,required TResult Function( RagError_InternalError value)  internalError,required TResult Function( RagError_Unknown value)  unknown,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RagError_DatabaseError value)  databaseError,required TResult Function( RagError_IoError value)  ioError,required TResult Function( RagError_ModelLoadError value)  modelLoadError,required TResult Function( RagError_InvalidInput value)  invalidInput,required TResult Function( RagError_InternalError value)  internalError,required TResult Function( RagError_Unknown value)  unknown,required TResult Function( RagError_DatabaseBusy value)  databaseBusy,required TResult Function( RagError_NotInitialized value)  notInitialized,required TResult Function( RagError_ParseError value)  parseError,}){
 final _that = this;
 switch (_that) {
 case RagError_DatabaseError():
@@ -122,7 +125,10 @@ return ioError(_that);case RagError_ModelLoadError():
 return modelLoadError(_that);case RagError_InvalidInput():
 return invalidInput(_that);case RagError_InternalError():
 return internalError(_that);case RagError_Unknown():
-return unknown(_that);}
+return unknown(_that);case RagError_DatabaseBusy():
+return databaseBusy(_that);case RagError_NotInitialized():
+return notInitialized(_that);case RagError_ParseError():
+return parseError(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -136,7 +142,7 @@ return unknown(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RagError_DatabaseError value)?  databaseError,TResult? Function( RagError_IoError value)?  ioError,TResult? Function( RagError_ModelLoadError value)?  modelLoadError,TResult? Function( RagError_InvalidInput value)?  invalidInput,TResult? Function( RagError_InternalError value)?  internalError,TResult? Function( RagError_Unknown value)?  unknown,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RagError_DatabaseError value)?  databaseError,TResult? Function( RagError_IoError value)?  ioError,TResult? Function( RagError_ModelLoadError value)?  modelLoadError,TResult? Function( RagError_InvalidInput value)?  invalidInput,TResult? Function( RagError_InternalError value)?  internalError,TResult? Function( RagError_Unknown value)?  unknown,TResult? Function( RagError_DatabaseBusy value)?  databaseBusy,TResult? Function( RagError_NotInitialized value)?  notInitialized,TResult? Function( RagError_ParseError value)?  parseError,}){
 final _that = this;
 switch (_that) {
 case RagError_DatabaseError() when databaseError != null:
@@ -145,7 +151,10 @@ return ioError(_that);case RagError_ModelLoadError() when modelLoadError != null
 return modelLoadError(_that);case RagError_InvalidInput() when invalidInput != null:
 return invalidInput(_that);case RagError_InternalError() when internalError != null:
 return internalError(_that);case RagError_Unknown() when unknown != null:
-return unknown(_that);case _:
+return unknown(_that);case RagError_DatabaseBusy() when databaseBusy != null:
+return databaseBusy(_that);case RagError_NotInitialized() when notInitialized != null:
+return notInitialized(_that);case RagError_ParseError() when parseError != null:
+return parseError(_that);case _:
   return null;
 
 }
@@ -162,7 +171,7 @@ return unknown(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  databaseError,TResult Function( String field0)?  ioError,TResult Function( String field0)?  modelLoadError,TResult Function( String field0)?  invalidInput,TResult Function( String field0)?  internalError,TResult Function( String field0)?  unknown,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  databaseError,TResult Function( String field0)?  ioError,TResult Function( String field0)?  modelLoadError,TResult Function( String field0)?  invalidInput,TResult Function( String field0)?  internalError,TResult Function( String field0)?  unknown,TResult Function( String field0)?  databaseBusy,TResult Function( String field0)?  notInitialized,TResult Function( String field0)?  parseError,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case RagError_DatabaseError() when databaseError != null:
 return databaseError(_that.field0);case RagError_IoError() when ioError != null:
@@ -170,7 +179,10 @@ return ioError(_that.field0);case RagError_ModelLoadError() when modelLoadError
 return modelLoadError(_that.field0);case RagError_InvalidInput() when invalidInput != null:
 return invalidInput(_that.field0);case RagError_InternalError() when internalError != null:
 return internalError(_that.field0);case RagError_Unknown() when unknown != null:
-return unknown(_that.field0);case _:
+return unknown(_that.field0);case RagError_DatabaseBusy() when databaseBusy != null:
+return databaseBusy(_that.field0);case RagError_NotInitialized() when notInitialized != null:
+return notInitialized(_that.field0);case RagError_ParseError() when parseError != null:
+return parseError(_that.field0);case _:
   return orElse();
 
 }
@@ -188,7 +200,7 @@ return unknown(_that.field0);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  databaseError,required TResult Function( String field0)  ioError,required TResult Function( String field0)  modelLoadError,required TResult Function( String field0)  invalidInput,required TResult Function( String field0)  internalError,required TResult Function( String field0)  unknown,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  databaseError,required TResult Function( String field0)  ioError,required TResult Function( String field0)  modelLoadError,required TResult Function( String field0)  invalidInput,required TResult Function( String field0)  internalError,required TResult Function( String field0)  unknown,required TResult Function( String field0)  databaseBusy,required TResult Function( String field0)  notInitialized,required TResult Function( String field0)  parseError,}) {final _that = this;
 switch (_that) {
 case RagError_DatabaseError():
 return databaseError(_that.field0);case RagError_IoError():
@@ -196,7 +208,10 @@ return ioError(_that.field0);case RagError_ModelLoadError():
 return modelLoadError(_that.field0);case RagError_InvalidInput():
 return invalidInput(_that.field0);case RagError_InternalError():
 return internalError(_that.field0);case RagError_Unknown():
-return unknown(_that.field0);}
+return unknown(_that.field0);case RagError_DatabaseBusy():
+return databaseBusy(_that.field0);case RagError_NotInitialized():
+return notInitialized(_that.field0);case RagError_ParseError():
+return parseError(_that.field0);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -210,7 +225,7 @@ return unknown(_that.field0);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  databaseError,TResult? Function( String field0)?  ioError,TResult? Function( String field0)?  modelLoadError,TResult? Function( String field0)?  invalidInput,TResult? Function( String field0)?  internalError,TResult? Function( String field0)?  unknown,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  databaseError,TResult? Function( String field0)?  ioError,TResult? Function( String field0)?  modelLoadError,TResult? Function( String field0)?  invalidInput,TResult? Function( String field0)?  internalError,TResult? Function( String field0)?  unknown,TResult? Function( String field0)?  databaseBusy,TResult? Function( String field0)?  notInitialized,TResult? Function( String field0)?  parseError,}) {final _that = this;
 switch (_that) {
 case RagError_DatabaseError() when databaseError != null:
 return databaseError(_that.field0);case RagError_IoError() when ioError != null:
@@ -218,7 +233,10 @@ return ioError(_that.field0);case RagError_ModelLoadError() when modelLoadError
 return modelLoadError(_that.field0);case RagError_InvalidInput() when invalidInput != null:
 return invalidInput(_that.field0);case RagError_InternalError() when internalError != null:
 return internalError(_that.field0);case RagError_Unknown() when unknown != null:
-return unknown(_that.field0);case _:
+return unknown(_that.field0);case RagError_DatabaseBusy() when databaseBusy != null:
+return databaseBusy(_that.field0);case RagError_NotInitialized() when notInitialized != null:
+return notInitialized(_that.field0);case RagError_ParseError() when parseError != null:
+return parseError(_that.field0);case _:
   return null;
 
 }
@@ -620,6 +638,204 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class RagError_DatabaseBusy extends RagError {
+  const RagError_DatabaseBusy(this.field0): super._();
+  
+
+@override final  String field0;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RagError_DatabaseBusyCopyWith<RagError_DatabaseBusy> get copyWith => _$RagError_DatabaseBusyCopyWithImpl<RagError_DatabaseBusy>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RagError_DatabaseBusy&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'RagError.databaseBusy(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RagError_DatabaseBusyCopyWith<$Res> implements $RagErrorCopyWith<$Res> {
+  factory $RagError_DatabaseBusyCopyWith(RagError_DatabaseBusy value, $Res Function(RagError_DatabaseBusy) _then) = _$RagError_DatabaseBusyCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$RagError_DatabaseBusyCopyWithImpl<$Res>
+    implements $RagError_DatabaseBusyCopyWith<$Res> {
+  _$RagError_DatabaseBusyCopyWithImpl(this._self, this._then);
+
+  final RagError_DatabaseBusy _self;
+  final $Res Function(RagError_DatabaseBusy) _then;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(RagError_DatabaseBusy(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RagError_NotInitialized extends RagError {
+  const RagError_NotInitialized(this.field0): super._();
+  
+
+@override final  String field0;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RagError_NotInitializedCopyWith<RagError_NotInitialized> get copyWith => _$RagError_NotInitializedCopyWithImpl<RagError_NotInitialized>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RagError_NotInitialized&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'RagError.notInitialized(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RagError_NotInitializedCopyWith<$Res> implements $RagErrorCopyWith<$Res> {
+  factory $RagError_NotInitializedCopyWith(RagError_NotInitialized value, $Res Function(RagError_NotInitialized) _then) = _$RagError_NotInitializedCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$RagError_NotInitializedCopyWithImpl<$Res>
+    implements $RagError_NotInitializedCopyWith<$Res> {
+  _$RagError_NotInitializedCopyWithImpl(this._self, this._then);
+
+  final RagError_NotInitialized _self;
+  final $Res Function(RagError_NotInitialized) _then;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(RagError_NotInitialized(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RagError_ParseError extends RagError {
+  const RagError_ParseError(this.field0): super._();
+  
+
+@override final  String field0;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RagError_ParseErrorCopyWith<RagError_ParseError> get copyWith => _$RagError_ParseErrorCopyWithImpl<RagError_ParseError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RagError_ParseError&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'RagError.parseError(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RagError_ParseErrorCopyWith<$Res> implements $RagErrorCopyWith<$Res> {
+  factory $RagError_ParseErrorCopyWith(RagError_ParseError value, $Res Function(RagError_ParseError) _then) = _$RagError_ParseErrorCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$RagError_ParseErrorCopyWithImpl<$Res>
+    implements $RagError_ParseErrorCopyWith<$Res> {
+  _$RagError_ParseErrorCopyWithImpl(this._self, this._then);
+
+  final RagError_ParseError _self;
+  final $Res Function(RagError_ParseError) _then;
+
+/// Create a copy of RagError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(RagError_ParseError(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
 }
 
 // dart format on
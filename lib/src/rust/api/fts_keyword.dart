@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `drop_fts_for_encryption`, `fts_constraint_in`, `fts_constraint`, `fts_keyword_enabled`, `fts_matches`, `fts_phrase`, `fts_search`, `fts_tables_installed`, `install_fts`, `join_phrases`, `match_expression`, `search_fts`, `table_exists`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            KeywordBackend  getKeywordBackend() => RustLib.instance.api.crateApiFtsKeywordGetKeywordBackend();
+
+/// Select the keyword backend.
+///
+/// Switching to `Fts5` creates the FTS tables and triggers (filling them
+/// from existing rows the first time) and frees the in-memory indexes.
+/// The tables persist, so call this again with `Fts5` after each start.
+/// Not available with content encryption, since the index would hold
+/// ciphertext. Switching back to `InMemory` drops the tables; rebuild
+/// the in-memory index afterwards (`rebuild_chunk_bm25_index`).
+Future<void>  setKeywordBackend({required KeywordBackend backend }) => RustLib.instance.api.crateApiFtsKeywordSetKeywordBackend(backend: backend);
+
+            /// Where keyword (BM25) search runs.
+enum KeywordBackend {
+                    /// The per-tenant in-memory inverted index (best for small corpora).
+inMemory,
+/// SQLite FTS5 tables maintained by triggers.
+fts5,
+                    ;
+                    static Future<KeywordBackend>  default_()=>RustLib.instance.api.crateApiFtsKeywordKeywordBackendDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allowed_edits`, `fuzzy_weight`, `levenshtein`, `push`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BkNode`, `BkTree`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `clear`, `default`, `find`, `insert`
+
+
+            /// Enable fuzzy matching for BM25 query terms without postings, or turn
+/// it off with `None` (the default).
+void  setBm25FuzzyMatching({Bm25FuzzyConfig? config }) => RustLib.instance.api.crateApiFuzzyTermsSetBm25FuzzyMatching(config: config);
+
+Bm25FuzzyConfig?  getBm25FuzzyMatching() => RustLib.instance.api.crateApiFuzzyTermsGetBm25FuzzyMatching();
+
+            class Bm25FuzzyConfig  {
+                /// Edit distance allowed for long terms, 1 or 2.
+final int maxEdits;
+/// Shorter query terms are never corrected.
+final int minTermChars;
+/// Indexed terms a misspelled term expands to, closest first.
+final int maxExpansions;
+
+                const Bm25FuzzyConfig({required this.maxEdits ,required this.minTermChars ,required this.maxExpansions ,});
+
+                static Future<Bm25FuzzyConfig>  default_()=>RustLib.instance.api.crateApiFuzzyTermsBm25FuzzyConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxEdits.hashCode^minTermChars.hashCode^maxExpansions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bm25FuzzyConfig &&
+                runtimeType == other.runtimeType
+                && maxEdits == other.maxEdits&& minTermChars == other.minTermChars&& maxExpansions == other.maxExpansions;
+        
+            }
+            
\ No newline at end of file
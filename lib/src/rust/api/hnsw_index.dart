@@ -4,104 +4,331 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`
 
-/// Build HNSW index from embedding points.
+            // These functions are ignored because they are not marked as `pub`: `adaptive`, `add_tombstones`, `build_graph`, `build_hnsw_index_for_tenant`, `build_hnsw_index_with_progress`, `build_tenant_graph`, `build_typed_graph`, `clear_all_hnsw_indexes`, `clear_tombstones`, `dimension`, `distance`, `drop_tenant_hnsw_index`, `dump_tenant_hnsw_index`, `dumped_graph_kind`, `file_dump`, `get_nb_point`, `hnsw_graph_kind`, `hnsw_point_count`, `hnsw_point_ids`, `hnsw_tenants`, `inner_product_from_graph`, `insert_into_tenant_index`, `insert`, `load_tenant_hnsw_index`, `manifest_path`, `metric`, `point_ids`, `quantization`, `quantize_for_graph`, `read_manifest`, `search_hnsw_in_tenant_filtered`, `search_hnsw_in_tenant`, `search`, `seeded_order`, `set_tenant_build_config`, `tenant_hnsw_index_loaded`, `tenant_hnsw_point_count`, `tombstone_count`, `validate`, `write_manifest`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DistCosineI8`, `DistInnerProduct`, `IndexManifest`, `TenantGraph`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eval`, `eval`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `default`, `default`
+
+
+            /// Set how embeddings are stored and indexed.
 ///
+/// With `Quantization::Int8`, new embeddings are stored as int8 with a
+/// per-vector scale (see `set_quantized_embedding_storage`) and HNSW
+/// graphs are built over int8 vectors. Existing rows keep their format
+/// until `quantize_stored_embeddings`, and loaded graphs until their next
+/// rebuild. Low-memory mode's flat quantized index still takes precedence.
+///
+/// The metric applies to exact scans immediately and to HNSW graphs from
+/// their next build; int8 graphs support cosine only.
+void  setIndexConfig({required IndexConfig config }) => RustLib.instance.api.crateApiHnswIndexSetIndexConfig(config: config);
+
+IndexConfig  getIndexConfig() => RustLib.instance.api.crateApiHnswIndexGetIndexConfig();
+
+/// Build HNSW index from embedding points.
+/// 
 /// Parameters are tuned for optimal recall vs speed tradeoff:
 /// - M (max connections per node): 16-24 based on dataset size
 /// - M0 (layer 0 connections): 2*M for better recall
 /// - efConstruction: 100-200 based on dataset size
-Future<void> buildHnswIndex({
-  required List<(PlatformInt64, Float32List)> points,
-}) => RustLib.instance.api.crateApiHnswIndexBuildHnswIndex(points: points);
+///
+/// A config set with `build_hnsw_index_with_config` or
+/// `set_hnsw_build_config` is used instead when present.
+Future<void>  buildHnswIndex({required List<(PlatformInt64,Float32List)> points }) => RustLib.instance.api.crateApiHnswIndexBuildHnswIndex(points: points);
+
+/// Build with explicit parameters (`None` returns to the adaptive ones).
+/// The choice sticks for later rebuilds of the active tenant.
+Future<void>  buildHnswIndexWithConfig({required List<(PlatformInt64,Float32List)> points , HnswBuildConfig? config }) => RustLib.instance.api.crateApiHnswIndexBuildHnswIndexWithConfig(points: points, config: config);
+
+/// Set (or with `None`, clear) the active tenant's build parameters for
+/// subsequent builds and rebuilds without building now.
+void  setHnswBuildConfig({HnswBuildConfig? config }) => RustLib.instance.api.crateApiHnswIndexSetHnswBuildConfig(config: config);
+
+/// Set the active tenant's build and search parameters. Build parameters
+/// apply from the next build; `ef_search` applies immediately.
+void  setHnswConfig({required HnswConfig config }) => RustLib.instance.api.crateApiHnswIndexSetHnswConfig(config: config);
+
+/// The active tenant's configured parameters (`None` fields are adaptive).
+HnswConfig  getHnswConfig() => RustLib.instance.api.crateApiHnswIndexGetHnswConfig();
+
+/// Effective parameters of the active tenant's graph, or `None` when no
+/// graph is loaded (including low-memory mode's flat index).
+HnswIndexStats?  getHnswIndexStats() => RustLib.instance.api.crateApiHnswIndexGetHnswIndexStats();
+
+/// Parameters the active tenant's in-memory index was built with, or
+/// `None` when no graph index is loaded.
+HnswBuildConfig?  getHnswBuildConfig() => RustLib.instance.api.crateApiHnswIndexGetHnswBuildConfig();
+
+/// Enable (or with `None`, disable) reproducible builds for tests and
+/// evaluation runs.
+///
+/// hnsw_rs draws node levels from an unseeded RNG, so seeded builds use a
+/// single layer (no draw matters) and insert one point at a time in an
+/// order shuffled by `seed`. The same points and seed always give the same
+/// graph and search results. Recall is similar for small corpora, but
+/// searches of large ones are slower than with the layered graph.
+void  setHnswSeededBuild({BigInt? seed }) => RustLib.instance.api.crateApiHnswIndexSetHnswSeededBuild(seed: seed);
+
+/// Seed of the reproducible build mode, if enabled.
+BigInt?  getHnswSeededBuild() => RustLib.instance.api.crateApiHnswIndexGetHnswSeededBuild();
 
 /// Save HNSW index to disk using hnsw_rs persistence.
 ///
 /// This saves the full graph and data to a directory specified by [base_path].
-Future<void> saveHnswIndex({required String basePath}) =>
-    RustLib.instance.api.crateApiHnswIndexSaveHnswIndex(basePath: basePath);
+/// Non-default tenants save next to it with a `_<tenant>` file suffix.
+Future<void>  saveHnswIndex({required String basePath }) => RustLib.instance.api.crateApiHnswIndexSaveHnswIndex(basePath: basePath);
 
-/// Load HNSW index from disk.
-///
+/// Load HNSW index from disk. 
+/// 
 /// Returns true if the index was successfully loaded into memory.
-Future<bool> loadHnswIndex({required String basePath}) =>
-    RustLib.instance.api.crateApiHnswIndexLoadHnswIndex(basePath: basePath);
+Future<bool>  loadHnswIndex({required String basePath }) => RustLib.instance.api.crateApiHnswIndexLoadHnswIndex(basePath: basePath);
 
 /// Search in HNSW index.
-///
+/// 
 /// ef_search parameter controls accuracy vs speed:
 /// - Higher ef_search = better recall but slower
 /// - Lower ef_search = faster but may miss relevant results
+/// 
+/// Current tuning targets ~95% recall for most use cases; set a fixed
+/// value with `set_hnsw_config` or per call with `search_hnsw_with_ef`.
+Future<List<HnswSearchResult>>  searchHnsw({required List<double> queryEmbedding , required BigInt topK }) => RustLib.instance.api.crateApiHnswIndexSearchHnsw(queryEmbedding: queryEmbedding, topK: topK);
+
+/// `search_hnsw` with an `ef_search` override for this call only (`None`
+/// uses the configured or adaptive value).
+Future<List<HnswSearchResult>>  searchHnswWithEf({required List<double> queryEmbedding , required BigInt topK , int? efSearch }) => RustLib.instance.api.crateApiHnswIndexSearchHnswWithEf(queryEmbedding: queryEmbedding, topK: topK, efSearch: efSearch);
+
+/// `search_hnsw` among `allowed_ids` only, e.g. the chunks of a few
+/// sources. The graph is still traversed through other points, so this
+/// stays sublinear where an exact scan of the allowed chunks would not;
+/// recall drops when the allowed set is a small share of the index.
+Future<List<HnswSearchResult>>  searchHnswFiltered({required List<double> queryEmbedding , required BigInt topK , required Int64List allowedIds }) => RustLib.instance.api.crateApiHnswIndexSearchHnswFiltered(queryEmbedding: queryEmbedding, topK: topK, allowedIds: allowedIds);
+
+/// Insert one point into the active tenant's live index, where it is
+/// searchable immediately without a rebuild.
+///
+/// Returns false when no index is loaded yet (build one first). `id` must
+/// not already be in the index; hnsw_rs would keep both points.
+Future<bool>  hnswInsert({required PlatformInt64 id , required List<double> embedding }) => RustLib.instance.api.crateApiHnswIndexHnswInsert(id: id, embedding: embedding);
+
+/// Check if HNSW index is loaded (for the active tenant).
+Future<bool>  isHnswIndexLoaded() => RustLib.instance.api.crateApiHnswIndexIsHnswIndexLoaded();
+
+/// Clear HNSW index from memory (for the active tenant).
+Future<void>  clearHnswIndex() => RustLib.instance.api.crateApiHnswIndexClearHnswIndex();
+
+            /// How vector search compares embeddings.
 ///
-/// Current tuning targets ~95% recall for most use cases.
-Future<List<HnswSearchResult>> searchHnsw({
-  required List<double> queryEmbedding,
-  required BigInt topK,
-}) => RustLib.instance.api.crateApiHnswIndexSearchHnsw(
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-);
-
-/// Check if HNSW index is loaded.
-Future<bool> isHnswIndexLoaded() =>
-    RustLib.instance.api.crateApiHnswIndexIsHnswIndexLoaded();
-
-/// Clear HNSW index from memory.
-Future<void> clearHnswIndex() =>
-    RustLib.instance.api.crateApiHnswIndexClearHnswIndex();
+/// Search results report a distance where lower is better; `1 - distance`
+/// is the cosine similarity or the dot product, and L2 results report the
+/// Euclidean distance itself.
+enum DistanceMetric {
+                    /// Angle between the vectors; magnitudes are ignored.
+cosine,
+/// Inner product, for models trained with unnormalized dot-product scores.
+dotProduct,
+/// Euclidean distance.
+l2,
+                    ;
+                    static Future<DistanceMetric>  default_()=>RustLib.instance.api.crateApiHnswIndexDistanceMetricDefault();
+
+
+                }
 
 /// Embedding point wrapper for FRB compatibility (legacy support).
-class EmbeddingPoint {
-  final PlatformInt64 id;
-  final Float32List embedding;
-  final double norm;
-
-  const EmbeddingPoint({
-    required this.id,
-    required this.embedding,
-    required this.norm,
-  });
-
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<EmbeddingPoint> newInstance({
-    required PlatformInt64 id,
-    required List<double> embedding,
-  }) => RustLib.instance.api.crateApiHnswIndexEmbeddingPointNew(
-    id: id,
-    embedding: embedding,
-  );
-
-  @override
-  int get hashCode => id.hashCode ^ embedding.hashCode ^ norm.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EmbeddingPoint &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          embedding == other.embedding &&
-          norm == other.norm;
-}
+class EmbeddingPoint  {
+                final PlatformInt64 id;
+final Float32List embedding;
+final double norm;
+
+                const EmbeddingPoint({required this.id ,required this.embedding ,required this.norm ,});
+
+                  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+static Future<EmbeddingPoint>  newInstance({required PlatformInt64 id , required List<double> embedding })=>RustLib.instance.api.crateApiHnswIndexEmbeddingPointNew(id: id, embedding: embedding);
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^embedding.hashCode^norm.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingPoint &&
+                runtimeType == other.runtimeType
+                && id == other.id&& embedding == other.embedding&& norm == other.norm;
+        
+            }
+
+/// HNSW graph construction parameters.
+///
+/// hnsw_rs gives layer 0 `2 * m` connections itself, so M0 is derived
+/// from `m` rather than set separately.
+class HnswBuildConfig  {
+                /// Max connections per node on the upper layers (2..=256).
+final int m;
+/// Number of graph layers (1..=16).
+final int maxLayer;
+/// Candidate list size during insertion; higher gives better recall
+/// and a slower build.
+final int efConstruction;
+
+                const HnswBuildConfig({required this.m ,required this.maxLayer ,required this.efConstruction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => m.hashCode^maxLayer.hashCode^efConstruction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HnswBuildConfig &&
+                runtimeType == other.runtimeType
+                && m == other.m&& maxLayer == other.maxLayer&& efConstruction == other.efConstruction;
+        
+            }
+
+/// Build and search parameters of the active tenant's index.
+class HnswConfig  {
+                /// Graph parameters for subsequent builds; `None` uses the adaptive ones.
+final HnswBuildConfig? build;
+/// Candidate list size during search (raised to the number of results
+/// fetched when lower); `None` uses `max(100, 5 * top_k)`.
+final int? efSearch;
+
+                const HnswConfig({this.build ,this.efSearch ,});
+
+                static Future<HnswConfig>  default_()=>RustLib.instance.api.crateApiHnswIndexHnswConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => build.hashCode^efSearch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HnswConfig &&
+                runtimeType == other.runtimeType
+                && build == other.build&& efSearch == other.efSearch;
+        
+            }
+
+/// Effective parameters of the active tenant's loaded graph.
+class HnswIndexStats  {
+                final BigInt pointCount;
+final int dimension;
+final Quantization quantization;
+final DistanceMetric metric;
+/// Parameters the graph was built with.
+final HnswBuildConfig build;
+/// Layer 0 connections (`2 * build.m`).
+final int m0;
+/// `build` was chosen by the caller rather than adaptive.
+final bool customBuild;
+/// Configured `ef_search`; `None` means adaptive per search.
+final int? efSearch;
+/// Deleted points still in the graph until the next rebuild.
+final BigInt deletedPoints;
+
+                const HnswIndexStats({required this.pointCount ,required this.dimension ,required this.quantization ,required this.metric ,required this.build ,required this.m0 ,required this.customBuild ,this.efSearch ,required this.deletedPoints ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pointCount.hashCode^dimension.hashCode^quantization.hashCode^metric.hashCode^build.hashCode^m0.hashCode^customBuild.hashCode^efSearch.hashCode^deletedPoints.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HnswIndexStats &&
+                runtimeType == other.runtimeType
+                && pointCount == other.pointCount&& dimension == other.dimension&& quantization == other.quantization&& metric == other.metric&& build == other.build&& m0 == other.m0&& customBuild == other.customBuild&& efSearch == other.efSearch&& deletedPoints == other.deletedPoints;
+        
+            }
 
 /// HNSW search result containing doc ID and distance.
-class HnswSearchResult {
-  final PlatformInt64 id;
-  final double distance;
-
-  const HnswSearchResult({required this.id, required this.distance});
-
-  @override
-  int get hashCode => id.hashCode ^ distance.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is HnswSearchResult &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          distance == other.distance;
-}
+class HnswSearchResult  {
+                final PlatformInt64 id;
+final double distance;
+
+                const HnswSearchResult({required this.id ,required this.distance ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^distance.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HnswSearchResult &&
+                runtimeType == other.runtimeType
+                && id == other.id&& distance == other.distance;
+        
+            }
+
+/// Storage and index options; see `set_index_config`.
+class IndexConfig  {
+                final Quantization quantization;
+/// Metric of indexes built from now on (and of exact scans).
+final DistanceMetric metric;
+
+                const IndexConfig({required this.quantization ,required this.metric ,});
+
+                static Future<IndexConfig>  default_()=>RustLib.instance.api.crateApiHnswIndexIndexConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => quantization.hashCode^metric.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexConfig &&
+                runtimeType == other.runtimeType
+                && quantization == other.quantization&& metric == other.metric;
+        
+            }
+
+/// Vector representation of the tenant HNSW graphs.
+enum Quantization {
+                    /// Full `f32` vectors.
+none,
+/// int8 vectors with a per-vector scale, about a quarter of the memory
+/// for the vectors at a small recall cost.
+int8,
+                    ;
+                    static Future<Quantization>  default_()=>RustLib.instance.api.crateApiHnswIndexQuantizationDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -6,139 +6,381 @@
 import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'related_chunks.dart';
+import 'search_trace.dart';
+import 'snippets.dart';
+import 'source_grouping.dart';
+part 'hybrid_search.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `rrf_score`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
-
-/// Perform hybrid search combining vector and keyword search.
-Future<List<HybridSearchResult>> searchHybrid({
-  required String queryText,
-  required List<double> queryEmbedding,
-  required int topK,
-  RrfConfig? config,
-  SearchFilter? filter,
-}) => RustLib.instance.api.crateApiHybridSearchSearchHybrid(
-  queryText: queryText,
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-  config: config,
-  filter: filter,
-);
+            // These functions are ignored because they are not marked as `pub`: `apply_recency`, `apply_type_boosts`, `attach_snippets`, `chunk_sql_conditions`, `deepen_hybrid`, `diversify`, `exact_scan`, `filter_sql_conditions`, `filtered_chunk_ids`, `fuse_candidates`, `hybrid_page`, `hydrate`, `id_list`, `interleave_compare_results`, `language_condition`, `load_chunk_type_and_embedding`, `load_chunk_types`, `load_source_dates`, `metadata_equals_condition`, `narrows_within_sources`, `normalize_scores`, `paged`, `push_bounded`, `rank_candidates`, `rank_collection`, `rerank_with_preset`, `rrf_score`, `run_hybrid_stages`, `search_hybrid_impl`, `search_hybrid_page`, `source_sql_conditions`, `sources_passing_filter`, `sql_quote`, `stream_hybrid_stages`, `validate_page_size`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExactScan`, `PagedHybridRanking`, `ScanRow`, `ScoredChunk`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+
+            /// Perform hybrid search combining vector and keyword search.
+///
+/// Without `options` this returns the best `top_k` chunks in `results`;
+/// see `HybridSearchOptions` for the rest.
+Future<HybridSearchResponse>  searchHybrid({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options }) => RustLib.instance.api.crateApiHybridSearchSearchHybrid(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, config: config, filter: filter, options: options);
+
+/// Hybrid search that also returns per-stage timings when search tracing
+/// is enabled (`set_search_tracing(true)`); `trace` is `None` otherwise.
+Future<TracedHybridSearch>  searchHybridTraced({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter }) => RustLib.instance.api.crateApiHybridSearchSearchHybridTraced(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, config: config, filter: filter);
+
+/// Hybrid search that streams over `sink`: the fused ranking first, then
+/// contents in rank order as they are fetched, so the UI can render the
+/// ranking immediately. Stops early if the listener goes away.
+Stream<HybridSearchEvent>  searchHybridStream({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter }) => RustLib.instance.api.crateApiHybridSearchSearchHybridStream(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, config: config, filter: filter);
 
 /// Simplified hybrid search returning content strings only.
-Future<List<String>> searchHybridSimple({
-  required String queryText,
-  required List<double> queryEmbedding,
-  required int topK,
-}) => RustLib.instance.api.crateApiHybridSearchSearchHybridSimple(
-  queryText: queryText,
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-);
+Future<List<String>>  searchHybridSimple({required String queryText , required List<double> queryEmbedding , required int topK }) => RustLib.instance.api.crateApiHybridSearchSearchHybridSimple(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK);
 
 /// Search with custom weights (vector_weight + bm25_weight = 1.0 recommended).
-Future<List<HybridSearchResult>> searchHybridWeighted({
-  required String queryText,
-  required List<double> queryEmbedding,
-  required int topK,
-  required double vectorWeight,
-  required double bm25Weight,
-}) => RustLib.instance.api.crateApiHybridSearchSearchHybridWeighted(
-  queryText: queryText,
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-  vectorWeight: vectorWeight,
-  bm25Weight: bm25Weight,
-);
-
-class HybridSearchResult {
-  final PlatformInt64 docId;
-  final String content;
-  final double score;
-  final int vectorRank;
-  final int bm25Rank;
-  final PlatformInt64 sourceId;
-  final String? metadata;
-  final int chunkIndex;
-
-  const HybridSearchResult({
-    required this.docId,
-    required this.content,
-    required this.score,
-    required this.vectorRank,
-    required this.bm25Rank,
-    required this.sourceId,
-    this.metadata,
-    required this.chunkIndex,
-  });
-
-  @override
-  int get hashCode =>
-      docId.hashCode ^
-      content.hashCode ^
-      score.hashCode ^
-      vectorRank.hashCode ^
-      bm25Rank.hashCode ^
-      sourceId.hashCode ^
-      metadata.hashCode ^
-      chunkIndex.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is HybridSearchResult &&
-          runtimeType == other.runtimeType &&
-          docId == other.docId &&
-          content == other.content &&
-          score == other.score &&
-          vectorRank == other.vectorRank &&
-          bm25Rank == other.bm25Rank &&
-          sourceId == other.sourceId &&
-          metadata == other.metadata &&
-          chunkIndex == other.chunkIndex;
-}
-
-class RrfConfig {
-  final int k;
-  final double vectorWeight;
-  final double bm25Weight;
-
-  const RrfConfig({
-    required this.k,
-    required this.vectorWeight,
-    required this.bm25Weight,
-  });
-
-  static Future<RrfConfig> default_() =>
-      RustLib.instance.api.crateApiHybridSearchRrfConfigDefault();
-
-  @override
-  int get hashCode => k.hashCode ^ vectorWeight.hashCode ^ bm25Weight.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is RrfConfig &&
-          runtimeType == other.runtimeType &&
-          k == other.k &&
-          vectorWeight == other.vectorWeight &&
-          bm25Weight == other.bm25Weight;
-}
-
-class SearchFilter {
-  final Int64List? sourceIds;
-  final String? metadataLike;
-
-  const SearchFilter({this.sourceIds, this.metadataLike});
-
-  @override
-  int get hashCode => sourceIds.hashCode ^ metadataLike.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SearchFilter &&
-          runtimeType == other.runtimeType &&
-          sourceIds == other.sourceIds &&
-          metadataLike == other.metadataLike;
-}
+Future<List<HybridSearchResult>>  searchHybridWeighted({required String queryText , required List<double> queryEmbedding , required int topK , required double vectorWeight , required double bm25Weight }) => RustLib.instance.api.crateApiHybridSearchSearchHybridWeighted(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, vectorWeight: vectorWeight, bm25Weight: bm25Weight);
+
+/// Hybrid search using the retrieval preset of an intent type
+/// ("define", "summary", ...; see `intent_presets`).
+///
+/// Applies the preset's top_k and weights, boosts its favored chunk type
+/// and optionally diversifies the results with MMR.
+Future<List<HybridSearchResult>>  searchHybridForIntent({required String intentType , required String queryText , required List<double> queryEmbedding , SearchFilter? filter }) => RustLib.instance.api.crateApiHybridSearchSearchHybridForIntent(intentType: intentType, queryText: queryText, queryEmbedding: queryEmbedding, filter: filter);
+
+/// Paired retrieval for `/compare A vs B`.
+///
+/// Runs one hybrid search per subject and interleaves the rankings
+/// (left #1, right #1, left #2, ...). A chunk found by both subjects is
+/// kept only at its first position.
+Future<List<CompareSearchResult>>  searchHybridCompare({required String leftText , required List<double> leftEmbedding , required String rightText , required List<double> rightEmbedding , required int topK , RrfConfig? config , SearchFilter? filter }) => RustLib.instance.api.crateApiHybridSearchSearchHybridCompare(leftText: leftText, leftEmbedding: leftEmbedding, rightText: rightText, rightEmbedding: rightEmbedding, topK: topK, config: config, filter: filter);
+
+/// Hybrid search followed by related-chunk expansion.
+///
+/// Each hit pulls in up to `max_related_per_hit` chunks linked to it (same
+/// source neighbours, shared entities, same split code block), scored at
+/// a decayed fraction of the hit. Expanded chunks respect `filter`.
+Future<List<ExpandedSearchResult>>  searchHybridExpanded({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , ExpansionConfig? expansion }) => RustLib.instance.api.crateApiHybridSearchSearchHybridExpanded(queryText: queryText, queryEmbedding: queryEmbedding, topK: topK, config: config, filter: filter, expansion: expansion);
+
+            /// One result of a paired `/compare` retrieval.
+class CompareSearchResult  {
+                /// 0 for the left subject, 1 for the right subject.
+final int subjectIndex;
+final HybridSearchResult result;
+
+                const CompareSearchResult({required this.subjectIndex ,required this.result ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subjectIndex.hashCode^result.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompareSearchResult &&
+                runtimeType == other.runtimeType
+                && subjectIndex == other.subjectIndex&& result == other.result;
+        
+            }
+
+@freezed
+                sealed class FusionStrategy with _$FusionStrategy  {
+                    const FusionStrategy._();
+
+                     /// Reciprocal rank fusion: only ranks count (the default).
+const factory FusionStrategy.rrf() = FusionStrategy_Rrf;
+ /// Blend normalized vector similarities and BM25 scores with the
+/// configured weights, so a very confident match in one list stands
+/// out. A candidate missing from a list gets that list's lowest value.
+const factory FusionStrategy.weightedScore({   required ScoreNormalization normalization , }) = FusionStrategy_WeightedScore;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class HybridSearchEvent with _$HybridSearchEvent  {
+                    const HybridSearchEvent._();
+
+                     /// The fused ranking, best first.
+const factory HybridSearchEvent.ranking(  List<RankedHit> field0,) = HybridSearchEvent_Ranking;
+ /// The next hydrated results, in ranking order.
+const factory HybridSearchEvent.results(  List<HybridSearchResult> field0,) = HybridSearchEvent_Results;
+ /// All results have been delivered.
+const factory HybridSearchEvent.done() = HybridSearchEvent_Done;
+
+                    
+
+                    
+                }
+
+/// Per-call options of `search_hybrid`. Paging (`page_offset` or
+/// `cursor`) does not combine with `diversity_lambda`, `group_by_source`
+/// or `collection`.
+class HybridSearchOptions  {
+                /// HNSW `ef_search` for this call only (`None` uses the configured or
+/// adaptive value; see `set_hnsw_config`).
+final int? efSearch;
+/// MMR diversification: 1.0 keeps the fused ranking, lower values
+/// trade relevance for covering more distinct passages (0.5-0.7
+/// usually works well).
+final double? diversityLambda;
+/// Search the collection with this name instead: its named HNSW index
+/// (`build_hnsw_index_named`) fused with its named BM25 index
+/// (`build_bm25_index_named`), so keyword statistics come from that
+/// collection only. Both indexes must exist; `filter` must be `None`.
+final String? collection;
+/// Collapse results to the best `top_k` sources, each with at most
+/// this many chunks (at least 1), in `HybridSearchResponse::groups`.
+final int? groupBySource;
+/// Return per-stage timings and candidate counts, for tuning candidate
+/// multipliers and HNSW parameters on the device.
+final bool withDiagnostics;
+/// Page the results: `top_k` results starting at this offset, plus a
+/// cursor for the next page. Ranks a few pages ahead so following
+/// pages are only a content fetch.
+final int? pageOffset;
+/// Continue a paged search from the `next_cursor` of its previous
+/// page, with that search's query, config and filter; results already
+/// returned are never repeated.
+final String? cursor;
+
+                const HybridSearchOptions({this.efSearch ,this.diversityLambda ,this.collection ,this.groupBySource ,required this.withDiagnostics ,this.pageOffset ,this.cursor ,});
+
+                static Future<HybridSearchOptions>  default_()=>RustLib.instance.api.crateApiHybridSearchHybridSearchOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => efSearch.hashCode^diversityLambda.hashCode^collection.hashCode^groupBySource.hashCode^withDiagnostics.hashCode^pageOffset.hashCode^cursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HybridSearchOptions &&
+                runtimeType == other.runtimeType
+                && efSearch == other.efSearch&& diversityLambda == other.diversityLambda&& collection == other.collection&& groupBySource == other.groupBySource&& withDiagnostics == other.withDiagnostics&& pageOffset == other.pageOffset&& cursor == other.cursor;
+        
+            }
+
+/// What `search_hybrid` found, shaped by its `HybridSearchOptions`.
+class HybridSearchResponse  {
+                /// The best results, best first; empty when grouped.
+final List<HybridSearchResult> results;
+/// With `group_by_source`: the best sources, best first.
+final List<SourceGroup>? groups;
+/// With `with_diagnostics`: stage timings and candidate counts.
+final SearchDiagnostics? diagnostics;
+/// When paging: pass as `cursor` for the following page; `None` once
+/// there are no more results.
+final String? nextCursor;
+
+                const HybridSearchResponse({required this.results ,this.groups ,this.diagnostics ,this.nextCursor ,});
+
+                static Future<HybridSearchResponse>  default_()=>RustLib.instance.api.crateApiHybridSearchHybridSearchResponseDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => results.hashCode^groups.hashCode^diagnostics.hashCode^nextCursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HybridSearchResponse &&
+                runtimeType == other.runtimeType
+                && results == other.results&& groups == other.groups&& diagnostics == other.diagnostics&& nextCursor == other.nextCursor;
+        
+            }
+
+class HybridSearchResult  {
+                final PlatformInt64 docId;
+final String content;
+final double score;
+final int vectorRank;
+final int bm25Rank;
+final PlatformInt64 sourceId;
+final String? metadata;
+final int chunkIndex;
+/// "general", "definition", "code", ... ("general" for Simple RAG docs).
+final String chunkType;
+/// Section breadcrumb such as "Installation > Windows", when the
+/// chunker recorded one.
+final String? headerPath;
+/// Query terms (as BM25 tokens) found in `content`.
+final List<String> matchedTerms;
+/// Best-matching sentence window with highlighted query terms.
+final SearchSnippet? snippet;
+
+                const HybridSearchResult({required this.docId ,required this.content ,required this.score ,required this.vectorRank ,required this.bm25Rank ,required this.sourceId ,this.metadata ,required this.chunkIndex ,required this.chunkType ,this.headerPath ,required this.matchedTerms ,this.snippet ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => docId.hashCode^content.hashCode^score.hashCode^vectorRank.hashCode^bm25Rank.hashCode^sourceId.hashCode^metadata.hashCode^chunkIndex.hashCode^chunkType.hashCode^headerPath.hashCode^matchedTerms.hashCode^snippet.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HybridSearchResult &&
+                runtimeType == other.runtimeType
+                && docId == other.docId&& content == other.content&& score == other.score&& vectorRank == other.vectorRank&& bm25Rank == other.bm25Rank&& sourceId == other.sourceId&& metadata == other.metadata&& chunkIndex == other.chunkIndex&& chunkType == other.chunkType&& headerPath == other.headerPath&& matchedTerms == other.matchedTerms&& snippet == other.snippet;
+        
+            }
+
+/// A fused ranking entry before its content has been fetched.
+class RankedHit  {
+                final PlatformInt64 docId;
+final double score;
+final int vectorRank;
+final int bm25Rank;
+
+                const RankedHit({required this.docId ,required this.score ,required this.vectorRank ,required this.bm25Rank ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => docId.hashCode^score.hashCode^vectorRank.hashCode^bm25Rank.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RankedHit &&
+                runtimeType == other.runtimeType
+                && docId == other.docId&& score == other.score&& vectorRank == other.vectorRank&& bm25Rank == other.bm25Rank;
+        
+            }
+
+class RrfConfig  {
+                final int k;
+final double vectorWeight;
+final double bm25Weight;
+/// Score multipliers by chunk type, applied after fusion (e.g.
+/// `{"definition": 1.5, "code": 0.5}`); unlisted types keep 1.0.
+final Map<String, double> typeBoosts;
+/// Half-life of the recency boost in days, by when the source was
+/// last updated (its `created_at` if never); `None` disables it.
+/// Chunks without a date get the full decay.
+final double? recencyHalfLifeDays;
+/// Share of the score that decays with age (0.0-1.0): a chunk one
+/// half-life old keeps `1 - recency_weight / 2` of its score.
+final double recencyWeight;
+/// `k` only applies to `FusionStrategy::Rrf`.
+final FusionStrategy fusion;
+
+                const RrfConfig({required this.k ,required this.vectorWeight ,required this.bm25Weight ,required this.typeBoosts ,this.recencyHalfLifeDays ,required this.recencyWeight ,required this.fusion ,});
+
+                static Future<RrfConfig>  default_()=>RustLib.instance.api.crateApiHybridSearchRrfConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => k.hashCode^vectorWeight.hashCode^bm25Weight.hashCode^typeBoosts.hashCode^recencyHalfLifeDays.hashCode^recencyWeight.hashCode^fusion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RrfConfig &&
+                runtimeType == other.runtimeType
+                && k == other.k&& vectorWeight == other.vectorWeight&& bm25Weight == other.bm25Weight&& typeBoosts == other.typeBoosts&& recencyHalfLifeDays == other.recencyHalfLifeDays&& recencyWeight == other.recencyWeight&& fusion == other.fusion;
+        
+            }
+
+/// How per-modality scores are put on one scale before blending.
+enum ScoreNormalization {
+                    /// Rescale each list to 0-1 by its own best and worst score.
+minMax,
+/// Standardize each list, then map through a logistic to 0-1.
+zScore,
+                    ;
+                    
+                }
+
+class SearchFilter  {
+                final Int64List? sourceIds;
+final String? metadataLike;
+/// Only chunks mentioning this entity value (e.g. "2023-09"; see `entity_extraction`).
+final String? entity;
+/// Only chunks in this language (e.g. "ko"; see `detect_language`).
+final String? language;
+/// Only chunks of these types ("definition", "code", ...).
+final List<String>? chunkTypes;
+/// Only chunks whose source metadata JSON has every `(key, value)`
+/// pair as a top-level field; values compare as text.
+final List<(String,String)>? metadataEquals;
+/// Only chunks of sources created at or after this time (unix seconds).
+final PlatformInt64? createdAfter;
+/// Only chunks of sources created before this time (unix seconds).
+final PlatformInt64? createdBefore;
+
+                const SearchFilter({this.sourceIds ,this.metadataLike ,this.entity ,this.language ,this.chunkTypes ,this.metadataEquals ,this.createdAfter ,this.createdBefore ,});
+
+                static Future<SearchFilter>  default_()=>RustLib.instance.api.crateApiHybridSearchSearchFilterDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => sourceIds.hashCode^metadataLike.hashCode^entity.hashCode^language.hashCode^chunkTypes.hashCode^metadataEquals.hashCode^createdAfter.hashCode^createdBefore.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchFilter &&
+                runtimeType == other.runtimeType
+                && sourceIds == other.sourceIds&& metadataLike == other.metadataLike&& entity == other.entity&& language == other.language&& chunkTypes == other.chunkTypes&& metadataEquals == other.metadataEquals&& createdAfter == other.createdAfter&& createdBefore == other.createdBefore;
+        
+            }
+
+/// Hybrid search results with an optional per-stage trace.
+class TracedHybridSearch  {
+                final List<HybridSearchResult> results;
+final SearchTrace? trace;
+
+                const TracedHybridSearch({required this.results ,this.trace ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => results.hashCode^trace.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TracedHybridSearch &&
+                runtimeType == other.runtimeType
+                && results == other.results&& trace == other.trace;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,608 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'hybrid_search.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$FusionStrategy {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FusionStrategy);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FusionStrategy()';
+}
+
+
+}
+
+/// @nodoc
+class $FusionStrategyCopyWith<$Res>  {
+$FusionStrategyCopyWith(FusionStrategy _, $Res Function(FusionStrategy) __);
+}
+
+
+/// Adds pattern-matching-related methods to [FusionStrategy].
+extension FusionStrategyPatterns on FusionStrategy {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( FusionStrategy_Rrf value)?  rrf,TResult Function( FusionStrategy_WeightedScore value)?  weightedScore,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf() when rrf != null:
+return rrf(_that);case FusionStrategy_WeightedScore() when weightedScore != null:
+return weightedScore(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( FusionStrategy_Rrf value)  rrf,required TResult Function( FusionStrategy_WeightedScore value)  weightedScore,}){
+final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf():
+return rrf(_that);case FusionStrategy_WeightedScore():
+return weightedScore(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( FusionStrategy_Rrf value)?  rrf,TResult? Function( FusionStrategy_WeightedScore value)?  weightedScore,}){
+final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf() when rrf != null:
+return rrf(_that);case FusionStrategy_WeightedScore() when weightedScore != null:
+return weightedScore(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( )?  rrf,TResult Function( ScoreNormalization normalization)?  weightedScore,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf() when rrf != null:
+return rrf();case FusionStrategy_WeightedScore() when weightedScore != null:
+return weightedScore(_that.normalization);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( )  rrf,required TResult Function( ScoreNormalization normalization)  weightedScore,}) {final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf():
+return rrf();case FusionStrategy_WeightedScore():
+return weightedScore(_that.normalization);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( )?  rrf,TResult? Function( ScoreNormalization normalization)?  weightedScore,}) {final _that = this;
+switch (_that) {
+case FusionStrategy_Rrf() when rrf != null:
+return rrf();case FusionStrategy_WeightedScore() when weightedScore != null:
+return weightedScore(_that.normalization);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class FusionStrategy_Rrf extends FusionStrategy {
+  const FusionStrategy_Rrf(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FusionStrategy_Rrf);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FusionStrategy.rrf()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class FusionStrategy_WeightedScore extends FusionStrategy {
+  const FusionStrategy_WeightedScore({required this.normalization}): super._();
+  
+
+ final  ScoreNormalization normalization;
+
+/// Create a copy of FusionStrategy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FusionStrategy_WeightedScoreCopyWith<FusionStrategy_WeightedScore> get copyWith => _$FusionStrategy_WeightedScoreCopyWithImpl<FusionStrategy_WeightedScore>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FusionStrategy_WeightedScore&&(identical(other.normalization, normalization) || other.normalization == normalization));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,normalization);
+
+@override
+String toString() {
+  return 'FusionStrategy.weightedScore(normalization: $normalization)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FusionStrategy_WeightedScoreCopyWith<$Res> implements $FusionStrategyCopyWith<$Res> {
+  factory $FusionStrategy_WeightedScoreCopyWith(FusionStrategy_WeightedScore value, $Res Function(FusionStrategy_WeightedScore) _then) = _$FusionStrategy_WeightedScoreCopyWithImpl;
+@useResult
+$Res call({
+ ScoreNormalization normalization
+});
+
+
+
+
+}
+/// @nodoc
+class _$FusionStrategy_WeightedScoreCopyWithImpl<$Res>
+    implements $FusionStrategy_WeightedScoreCopyWith<$Res> {
+  _$FusionStrategy_WeightedScoreCopyWithImpl(this._self, this._then);
+
+  final FusionStrategy_WeightedScore _self;
+  final $Res Function(FusionStrategy_WeightedScore) _then;
+
+/// Create a copy of FusionStrategy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? normalization = null,}) {
+  return _then(FusionStrategy_WeightedScore(
+normalization: null == normalization ? _self.normalization : normalization // ignore: cast_nullable_to_non_nullable
+as ScoreNormalization,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$HybridSearchEvent {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HybridSearchEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'HybridSearchEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $HybridSearchEventCopyWith<$Res>  {
+$HybridSearchEventCopyWith(HybridSearchEvent _, $Res Function(HybridSearchEvent) __);
+}
+
+
+/// Adds pattern-matching-related methods to [HybridSearchEvent].
+extension HybridSearchEventPatterns on HybridSearchEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( HybridSearchEvent_Ranking value)?  ranking,TResult Function( HybridSearchEvent_Results value)?  results,TResult Function( HybridSearchEvent_Done value)?  done,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking() when ranking != null:
+return ranking(_that);case HybridSearchEvent_Results() when results != null:
+return results(_that);case HybridSearchEvent_Done() when done != null:
+return done(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( HybridSearchEvent_Ranking value)  ranking,required TResult Function( HybridSearchEvent_Results value)  results,required TResult Function( HybridSearchEvent_Done value)  done,}){
+final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking():
+return ranking(_that);case HybridSearchEvent_Results():
+return results(_that);case HybridSearchEvent_Done():
+return done(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( HybridSearchEvent_Ranking value)?  ranking,TResult? Function( HybridSearchEvent_Results value)?  results,TResult? Function( HybridSearchEvent_Done value)?  done,}){
+final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking() when ranking != null:
+return ranking(_that);case HybridSearchEvent_Results() when results != null:
+return results(_that);case HybridSearchEvent_Done() when done != null:
+return done(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( List<RankedHit> field0)?  ranking,TResult Function( List<HybridSearchResult> field0)?  results,TResult Function( )?  done,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking() when ranking != null:
+return ranking(_that.field0);case HybridSearchEvent_Results() when results != null:
+return results(_that.field0);case HybridSearchEvent_Done() when done != null:
+return done();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( List<RankedHit> field0)  ranking,required TResult Function( List<HybridSearchResult> field0)  results,required TResult Function( )  done,}) {final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking():
+return ranking(_that.field0);case HybridSearchEvent_Results():
+return results(_that.field0);case HybridSearchEvent_Done():
+return done();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( List<RankedHit> field0)?  ranking,TResult? Function( List<HybridSearchResult> field0)?  results,TResult? Function( )?  done,}) {final _that = this;
+switch (_that) {
+case HybridSearchEvent_Ranking() when ranking != null:
+return ranking(_that.field0);case HybridSearchEvent_Results() when results != null:
+return results(_that.field0);case HybridSearchEvent_Done() when done != null:
+return done();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class HybridSearchEvent_Ranking extends HybridSearchEvent {
+  const HybridSearchEvent_Ranking(final  List<RankedHit> field0): _field0 = field0,super._();
+  
+
+ final  List<RankedHit> _field0;
+ List<RankedHit> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of HybridSearchEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HybridSearchEvent_RankingCopyWith<HybridSearchEvent_Ranking> get copyWith => _$HybridSearchEvent_RankingCopyWithImpl<HybridSearchEvent_Ranking>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HybridSearchEvent_Ranking&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'HybridSearchEvent.ranking(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HybridSearchEvent_RankingCopyWith<$Res> implements $HybridSearchEventCopyWith<$Res> {
+  factory $HybridSearchEvent_RankingCopyWith(HybridSearchEvent_Ranking value, $Res Function(HybridSearchEvent_Ranking) _then) = _$HybridSearchEvent_RankingCopyWithImpl;
+@useResult
+$Res call({
+ List<RankedHit> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HybridSearchEvent_RankingCopyWithImpl<$Res>
+    implements $HybridSearchEvent_RankingCopyWith<$Res> {
+  _$HybridSearchEvent_RankingCopyWithImpl(this._self, this._then);
+
+  final HybridSearchEvent_Ranking _self;
+  final $Res Function(HybridSearchEvent_Ranking) _then;
+
+/// Create a copy of HybridSearchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HybridSearchEvent_Ranking(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<RankedHit>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HybridSearchEvent_Results extends HybridSearchEvent {
+  const HybridSearchEvent_Results(final  List<HybridSearchResult> field0): _field0 = field0,super._();
+  
+
+ final  List<HybridSearchResult> _field0;
+ List<HybridSearchResult> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of HybridSearchEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HybridSearchEvent_ResultsCopyWith<HybridSearchEvent_Results> get copyWith => _$HybridSearchEvent_ResultsCopyWithImpl<HybridSearchEvent_Results>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HybridSearchEvent_Results&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'HybridSearchEvent.results(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HybridSearchEvent_ResultsCopyWith<$Res> implements $HybridSearchEventCopyWith<$Res> {
+  factory $HybridSearchEvent_ResultsCopyWith(HybridSearchEvent_Results value, $Res Function(HybridSearchEvent_Results) _then) = _$HybridSearchEvent_ResultsCopyWithImpl;
+@useResult
+$Res call({
+ List<HybridSearchResult> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HybridSearchEvent_ResultsCopyWithImpl<$Res>
+    implements $HybridSearchEvent_ResultsCopyWith<$Res> {
+  _$HybridSearchEvent_ResultsCopyWithImpl(this._self, this._then);
+
+  final HybridSearchEvent_Results _self;
+  final $Res Function(HybridSearchEvent_Results) _then;
+
+/// Create a copy of HybridSearchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HybridSearchEvent_Results(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<HybridSearchResult>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HybridSearchEvent_Done extends HybridSearchEvent {
+  const HybridSearchEvent_Done(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HybridSearchEvent_Done);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'HybridSearchEvent.done()';
+}
+
+
+}
+
+// dart format on
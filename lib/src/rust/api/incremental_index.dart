@@ -4,103 +4,136 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `cosine_distance`, `new`
+
+            // These functions are ignored because they are not marked as `pub`: `buffer_len`, `clear_all_buffers`, `cosine_distance`, `drop_tenant_buffer`, `drop_tenant_buffers`, `merge_tenant_buffer`, `new`, `on_threshold_reached`, `stored_points`, `tenant_buffer_len`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BufferEntry`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
-
-/// Add a single vector to buffer (immediately searchable).
-Future<void> incrementalAdd({
-  required PlatformInt64 docId,
-  required List<double> embedding,
-}) => RustLib.instance.api.crateApiIncrementalIndexIncrementalAdd(
-  docId: docId,
-  embedding: embedding,
-);
-
-/// Add multiple vectors to buffer.
-Future<void> incrementalAddBatch({
-  required List<(PlatformInt64, Float32List)> docs,
-}) => RustLib.instance.api.crateApiIncrementalIndexIncrementalAddBatch(
-  docs: docs,
-);
-
-/// Remove a document from buffer.
-Future<void> incrementalRemove({required PlatformInt64 docId}) => RustLib
-    .instance
-    .api
-    .crateApiIncrementalIndexIncrementalRemove(docId: docId);
-
-/// Search both buffer and HNSW.
-Future<List<IncrementalSearchResult>> incrementalSearch({
-  required List<double> queryEmbedding,
-  required BigInt topK,
-}) => RustLib.instance.api.crateApiIncrementalIndexIncrementalSearch(
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-);
-
-Future<BufferStats> getBufferStats() =>
-    RustLib.instance.api.crateApiIncrementalIndexGetBufferStats();
-
-/// Clear buffer.
-Future<void> clearBuffer() =>
-    RustLib.instance.api.crateApiIncrementalIndexClearBuffer();
-
-/// Check if buffer needs merging.
-Future<bool> needsMerge() =>
-    RustLib.instance.api.crateApiIncrementalIndexNeedsMerge();
-
-/// Get buffer entries for HNSW rebuild.
-Future<List<(PlatformInt64, Float32List)>> getBufferForMerge() =>
-    RustLib.instance.api.crateApiIncrementalIndexGetBufferForMerge();
-
-class BufferStats {
-  final BigInt bufferSize;
-  final BigInt threshold;
-  final bool hnswLoaded;
-
-  const BufferStats({
-    required this.bufferSize,
-    required this.threshold,
-    required this.hnswLoaded,
-  });
-
-  @override
-  int get hashCode =>
-      bufferSize.hashCode ^ threshold.hashCode ^ hnswLoaded.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BufferStats &&
-          runtimeType == other.runtimeType &&
-          bufferSize == other.bufferSize &&
-          threshold == other.threshold &&
-          hnswLoaded == other.hnswLoaded;
-}
-
-class IncrementalSearchResult {
-  final PlatformInt64 docId;
-  final double distance;
-  final String source;
-
-  const IncrementalSearchResult({
-    required this.docId,
-    required this.distance,
-    required this.source,
-  });
-
-  @override
-  int get hashCode => docId.hashCode ^ distance.hashCode ^ source.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is IncrementalSearchResult &&
-          runtimeType == other.runtimeType &&
-          docId == other.docId &&
-          distance == other.distance &&
-          source == other.source;
-}
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`
+
+
+            void  setIncrementalConfig({required IncrementalConfig config }) => RustLib.instance.api.crateApiIncrementalIndexSetIncrementalConfig(config: config);
+
+IncrementalConfig  getIncrementalConfig() => RustLib.instance.api.crateApiIncrementalIndexGetIncrementalConfig();
+
+/// Add a single vector to `table`'s buffer (immediately searchable).
+Future<void>  incrementalAdd({required BufferTable table , required PlatformInt64 docId , required List<double> embedding }) => RustLib.instance.api.crateApiIncrementalIndexIncrementalAdd(table: table, docId: docId, embedding: embedding);
+
+/// Add multiple vectors to `table`'s buffer.
+Future<void>  incrementalAddBatch({required BufferTable table , required List<(PlatformInt64,Float32List)> docs }) => RustLib.instance.api.crateApiIncrementalIndexIncrementalAddBatch(table: table, docs: docs);
+
+/// Fold the active tenant's buffer of `table` into its HNSW index on the
+/// background worker. Returns the task id for `get_background_task`.
+///
+/// Buffered vectors are inserted into the live graph when one is loaded;
+/// otherwise (or when an id was deleted from it before) the graph is
+/// rebuilt from `table` plus the buffer and swapped in once built, so
+/// searches keep using the old one meanwhile. Vectors added during the
+/// merge stay buffered.
+Future<PlatformInt64>  mergeBufferIntoHnsw({required BufferTable table }) => RustLib.instance.api.crateApiIncrementalIndexMergeBufferIntoHnsw(table: table);
+
+/// Remove a row from `table`'s buffer.
+Future<void>  incrementalRemove({required BufferTable table , required PlatformInt64 docId }) => RustLib.instance.api.crateApiIncrementalIndexIncrementalRemove(table: table, docId: docId);
+
+/// Search both `table`'s buffer and HNSW.
+Future<List<IncrementalSearchResult>>  incrementalSearch({required BufferTable table , required List<double> queryEmbedding , required BigInt topK }) => RustLib.instance.api.crateApiIncrementalIndexIncrementalSearch(table: table, queryEmbedding: queryEmbedding, topK: topK);
+
+Future<BufferStats>  getBufferStats({required BufferTable table }) => RustLib.instance.api.crateApiIncrementalIndexGetBufferStats(table: table);
+
+/// Clear `table`'s buffer.
+Future<void>  clearBuffer({required BufferTable table }) => RustLib.instance.api.crateApiIncrementalIndexClearBuffer(table: table);
+
+/// Check if `table`'s buffer needs merging.
+Future<bool>  needsMerge({required BufferTable table }) => RustLib.instance.api.crateApiIncrementalIndexNeedsMerge(table: table);
+
+/// Get `table`'s buffer entries for HNSW rebuild.
+Future<List<(PlatformInt64,Float32List)>>  getBufferForMerge({required BufferTable table }) => RustLib.instance.api.crateApiIncrementalIndexGetBufferForMerge(table: table);
+
+            class BufferStats  {
+                final BigInt bufferSize;
+final BigInt threshold;
+final bool hnswLoaded;
+
+                const BufferStats({required this.bufferSize ,required this.threshold ,required this.hnswLoaded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bufferSize.hashCode^threshold.hashCode^hnswLoaded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BufferStats &&
+                runtimeType == other.runtimeType
+                && bufferSize == other.bufferSize&& threshold == other.threshold&& hnswLoaded == other.hnswLoaded;
+        
+            }
+
+/// The table whose row ids a buffer holds. `docs` and `chunks` ids
+/// overlap, so each gets its own buffer.
+enum BufferTable {
+                    /// Source chunks (`source_rag`).
+chunks,
+/// Simple RAG documents (`simple_rag`).
+docs,
+                    ;
+                    
+                }
+
+class IncrementalConfig  {
+                /// Queue `merge_buffer_into_hnsw` when the buffer reaches the threshold.
+final bool autoMerge;
+
+                const IncrementalConfig({required this.autoMerge ,});
+
+                static Future<IncrementalConfig>  default_()=>RustLib.instance.api.crateApiIncrementalIndexIncrementalConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => autoMerge.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IncrementalConfig &&
+                runtimeType == other.runtimeType
+                && autoMerge == other.autoMerge;
+        
+            }
+
+class IncrementalSearchResult  {
+                final PlatformInt64 docId;
+final double distance;
+final String source;
+
+                const IncrementalSearchResult({required this.docId ,required this.distance ,required this.source ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => docId.hashCode^distance.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IncrementalSearchResult &&
+                runtimeType == other.runtimeType
+                && docId == other.docId&& distance == other.distance&& source == other.source;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `advance_indexed`, `between`, `change_versions`, `check_tenant_index_freshness`, `create_change_tracking`, `ensure_tenant_indexes_fresh`, `forget_indexed_versions`, `id_list`, `is_consistent`, `is_stale`, `record_indexed`, `repair_bm25`, `repair_hnsw`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `IndexKind`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Compare the active tenant's indexes against the database without
+/// rebuilding anything.
+Future<IndexStaleness>  checkIndexFreshness() => RustLib.instance.api.crateApiIndexFreshnessCheckIndexFreshness();
+
+/// Rebuild whichever of the active tenant's HNSW and BM25 indexes is
+/// stale. Returns what was found stale (and therefore rebuilt).
+Future<IndexStaleness>  ensureIndexesFresh() => RustLib.instance.api.crateApiIndexFreshnessEnsureIndexesFresh();
+
+/// Compare the ids in the active tenant's HNSW and BM25 indexes with the
+/// chunks table. With `repair`, the differences are applied in place (a
+/// delta, not a rebuild) and the indexes are marked fresh.
+///
+/// Unlike `check_index_freshness`, this also catches indexes that were
+/// loaded from disk already out of date or missed a write in a crash.
+Future<IndexConsistency>  verifyIndexConsistency({required bool repair }) => RustLib.instance.api.crateApiIndexFreshnessVerifyIndexConsistency(repair: repair);
+
+            class IndexConsistency  {
+                final BigInt chunkCount;
+final IndexDivergence hnsw;
+final IndexDivergence bm25;
+/// Whether both loaded indexes match the chunks table.
+final bool consistent;
+/// Whether `repair` applied the differences.
+final bool repaired;
+
+                const IndexConsistency({required this.chunkCount ,required this.hnsw ,required this.bm25 ,required this.consistent ,required this.repaired ,});
+
+                static Future<IndexConsistency>  default_()=>RustLib.instance.api.crateApiIndexFreshnessIndexConsistencyDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => chunkCount.hashCode^hnsw.hashCode^bm25.hashCode^consistent.hashCode^repaired.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexConsistency &&
+                runtimeType == other.runtimeType
+                && chunkCount == other.chunkCount&& hnsw == other.hnsw&& bm25 == other.bm25&& consistent == other.consistent&& repaired == other.repaired;
+        
+            }
+
+/// Ids an index disagrees with the database on.
+class IndexDivergence  {
+                /// Whether the index is loaded; nothing is compared when it is not.
+final bool loaded;
+final BigInt indexedCount;
+/// Chunks the index does not have.
+final Int64List missingIds;
+/// Indexed ids with no chunk row behind them.
+final Int64List extraIds;
+
+                const IndexDivergence({required this.loaded ,required this.indexedCount ,required this.missingIds ,required this.extraIds ,});
+
+                static Future<IndexDivergence>  default_()=>RustLib.instance.api.crateApiIndexFreshnessIndexDivergenceDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => loaded.hashCode^indexedCount.hashCode^missingIds.hashCode^extraIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexDivergence &&
+                runtimeType == other.runtimeType
+                && loaded == other.loaded&& indexedCount == other.indexedCount&& missingIds == other.missingIds&& extraIds == other.extraIds;
+        
+            }
+
+class IndexStaleness  {
+                final bool hnswStale;
+final bool bm25Stale;
+
+                const IndexStaleness({required this.hnswStale ,required this.bm25Stale ,});
+
+                static Future<IndexStaleness>  default_()=>RustLib.instance.api.crateApiIndexFreshnessIndexStalenessDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => hnswStale.hashCode^bm25Stale.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexStaleness &&
+                runtimeType == other.runtimeType
+                && hnswStale == other.hnswStale&& bm25Stale == other.bm25Stale;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,138 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'semantic_chunker.dart';
+part 'ingest.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `chunks_to_embed`, `embed_and_store`, `ingest_with`, `parse_input`, `prepare_chunks`, `resolve_strategy`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PreparedChunk`, `Stored`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Ingest a document in one call.
+///
+/// `embed` is called once per chunk with its text; `on_progress` receives
+/// one event per stage (and per embedded chunk). Duplicate content is
+/// detected before embedding, so re-ingesting a document is cheap. The
+/// source is stored with all of its chunks in one transaction once every
+/// chunk is embedded, so a failed ingest stores nothing.
+Future<IngestReport>  ingestDocument({required IngestInput input , required IngestOptions options , required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestProgress) onProgress }) => RustLib.instance.api.crateApiIngestIngestDocument(input: input, options: options, embed: embed, onProgress: onProgress);
+
+            @freezed
+                sealed class IngestInput with _$IngestInput  {
+                    const IngestInput._();
+
+                     const factory IngestInput.text(  String field0,) = IngestInput_Text;
+ /// PDF or DOCX bytes (detected by magic bytes); anything else must be UTF-8.
+const factory IngestInput.bytes(  Uint8List field0,) = IngestInput_Bytes;
+
+                    
+
+                    
+                }
+
+class IngestOptions  {
+                final String? name;
+final String? metadata;
+/// `None` picks markdown chunking for `.md`/`.markdown` names.
+final ChunkingStrategy? strategy;
+final int maxChars;
+final int overlapChars;
+/// Bring the HNSW and BM25 indexes up to date after storing the chunks.
+/// The chunks are already in a loaded HNSW index, so usually only BM25
+/// is rebuilt.
+final bool rebuildIndexes;
+/// Skip exact and near-duplicate chunks (repeated headers, footers).
+final bool dedupeChunks;
+
+                const IngestOptions({this.name ,this.metadata ,this.strategy ,required this.maxChars ,required this.overlapChars ,required this.rebuildIndexes ,required this.dedupeChunks ,});
+
+                static Future<IngestOptions>  default_()=>RustLib.instance.api.crateApiIngestIngestOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^metadata.hashCode^strategy.hashCode^maxChars.hashCode^overlapChars.hashCode^rebuildIndexes.hashCode^dedupeChunks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IngestOptions &&
+                runtimeType == other.runtimeType
+                && name == other.name&& metadata == other.metadata&& strategy == other.strategy&& maxChars == other.maxChars&& overlapChars == other.overlapChars&& rebuildIndexes == other.rebuildIndexes&& dedupeChunks == other.dedupeChunks;
+        
+            }
+
+class IngestProgress  {
+                /// One of the `STAGE_*` values.
+final String stage;
+final int done;
+final int total;
+final PlatformInt64? sourceId;
+
+                const IngestProgress({required this.stage ,required this.done ,required this.total ,this.sourceId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stage.hashCode^done.hashCode^total.hashCode^sourceId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IngestProgress &&
+                runtimeType == other.runtimeType
+                && stage == other.stage&& done == other.done&& total == other.total&& sourceId == other.sourceId;
+        
+            }
+
+/// Outcome of `ingest_document`.
+class IngestReport  {
+                final PlatformInt64 sourceId;
+/// The content was already stored; nothing was embedded.
+final bool isDuplicate;
+/// Status of the stored source: `completed`, or for a duplicate
+/// whatever the earlier ingest left it at.
+final String status;
+/// Chunks stored (0 for a duplicate).
+final int chunkCount;
+/// Exact and near-duplicate chunks dropped before embedding.
+final int skippedChunks;
+/// Characters of text after parsing.
+final BigInt textChars;
+final BigInt elapsedMs;
+
+                const IngestReport({required this.sourceId ,required this.isDuplicate ,required this.status ,required this.chunkCount ,required this.skippedChunks ,required this.textChars ,required this.elapsedMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceId.hashCode^isDuplicate.hashCode^status.hashCode^chunkCount.hashCode^skippedChunks.hashCode^textChars.hashCode^elapsedMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IngestReport &&
+                runtimeType == other.runtimeType
+                && sourceId == other.sourceId&& isDuplicate == other.isDuplicate&& status == other.status&& chunkCount == other.chunkCount&& skippedChunks == other.skippedChunks&& textChars == other.textChars&& elapsedMs == other.elapsedMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,306 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'ingest.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$IngestInput {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is IngestInput);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'IngestInput()';
+}
+
+
+}
+
+/// @nodoc
+class $IngestInputCopyWith<$Res>  {
+$IngestInputCopyWith(IngestInput _, $Res Function(IngestInput) __);
+}
+
+
+/// Adds pattern-matching-related methods to [IngestInput].
+extension IngestInputPatterns on IngestInput {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( IngestInput_Text value)?  text,TResult Function( IngestInput_Bytes value)?  bytes,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case IngestInput_Text() when text != null:
+return text(_that);case IngestInput_Bytes() when bytes != null:
+return bytes(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( IngestInput_Text value)  text,required TResult Function( IngestInput_Bytes value)  bytes,}){
+final _that = this;
+switch (_that) {
+case IngestInput_Text():
+return text(_that);case IngestInput_Bytes():
+return bytes(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( IngestInput_Text value)?  text,TResult? Function( IngestInput_Bytes value)?  bytes,}){
+final _that = this;
+switch (_that) {
+case IngestInput_Text() when text != null:
+return text(_that);case IngestInput_Bytes() when bytes != null:
+return bytes(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  text,TResult Function( Uint8List field0)?  bytes,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case IngestInput_Text() when text != null:
+return text(_that.field0);case IngestInput_Bytes() when bytes != null:
+return bytes(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  text,required TResult Function( Uint8List field0)  bytes,}) {final _that = this;
+switch (_that) {
+case IngestInput_Text():
+return text(_that.field0);case IngestInput_Bytes():
+return bytes(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  text,TResult? Function( Uint8List field0)?  bytes,}) {final _that = this;
+switch (_that) {
+case IngestInput_Text() when text != null:
+return text(_that.field0);case IngestInput_Bytes() when bytes != null:
+return bytes(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class IngestInput_Text extends IngestInput {
+  const IngestInput_Text(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of IngestInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$IngestInput_TextCopyWith<IngestInput_Text> get copyWith => _$IngestInput_TextCopyWithImpl<IngestInput_Text>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is IngestInput_Text&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'IngestInput.text(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $IngestInput_TextCopyWith<$Res> implements $IngestInputCopyWith<$Res> {
+  factory $IngestInput_TextCopyWith(IngestInput_Text value, $Res Function(IngestInput_Text) _then) = _$IngestInput_TextCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$IngestInput_TextCopyWithImpl<$Res>
+    implements $IngestInput_TextCopyWith<$Res> {
+  _$IngestInput_TextCopyWithImpl(this._self, this._then);
+
+  final IngestInput_Text _self;
+  final $Res Function(IngestInput_Text) _then;
+
+/// Create a copy of IngestInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(IngestInput_Text(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class IngestInput_Bytes extends IngestInput {
+  const IngestInput_Bytes(this.field0): super._();
+  
+
+ final  Uint8List field0;
+
+/// Create a copy of IngestInput
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$IngestInput_BytesCopyWith<IngestInput_Bytes> get copyWith => _$IngestInput_BytesCopyWithImpl<IngestInput_Bytes>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is IngestInput_Bytes&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'IngestInput.bytes(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $IngestInput_BytesCopyWith<$Res> implements $IngestInputCopyWith<$Res> {
+  factory $IngestInput_BytesCopyWith(IngestInput_Bytes value, $Res Function(IngestInput_Bytes) _then) = _$IngestInput_BytesCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$IngestInput_BytesCopyWithImpl<$Res>
+    implements $IngestInput_BytesCopyWith<$Res> {
+  _$IngestInput_BytesCopyWithImpl(this._self, this._then);
+
+  final IngestInput_Bytes _self;
+  final $Res Function(IngestInput_Bytes) _then;
+
+/// Create a copy of IngestInput
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(IngestInput_Bytes(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'ingest.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'semantic_chunker.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allowed_from`, `block_on`, `claim_next_job`, `create_ingest_jobs_table`, `discard_partial_source`, `insert_job`, `load_job`, `process_job`, `read_job`, `record_progress`, `requeue_interrupted_jobs`, `run_worker`, `set_job_state`, `transition`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ClaimedJob`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Queue a file for ingestion into the active tenant. Returns the job id.
+Future<PlatformInt64>  enqueueIngestJob({required String filePath , required IngestOptions options }) => RustLib.instance.api.crateApiIngestJobsEnqueueIngestJob(filePath: filePath, options: options);
+
+/// Pause a queued or running job. A running job stops before its next
+/// chunk is embedded and restarts from the beginning when resumed.
+Future<void>  pauseIngestJob({required PlatformInt64 jobId }) => RustLib.instance.api.crateApiIngestJobsPauseIngestJob(jobId: jobId);
+
+/// Re-queue a paused or failed job.
+Future<void>  resumeIngestJob({required PlatformInt64 jobId }) => RustLib.instance.api.crateApiIngestJobsResumeIngestJob(jobId: jobId);
+
+/// Cancel a job that has not completed.
+Future<void>  cancelIngestJob({required PlatformInt64 jobId }) => RustLib.instance.api.crateApiIngestJobsCancelIngestJob(jobId: jobId);
+
+/// Jobs of the active tenant, oldest first.
+Future<List<IngestJob>>  listIngestJobs() => RustLib.instance.api.crateApiIngestJobsListIngestJobs();
+
+/// Delete completed and cancelled jobs of the active tenant.
+Future<int>  clearFinishedIngestJobs() => RustLib.instance.api.crateApiIngestJobsClearFinishedIngestJobs();
+
+bool  isIngestWorkerRunning() => RustLib.instance.api.crateApiIngestJobsIsIngestWorkerRunning();
+
+/// Start processing queued jobs of the active tenant on a background
+/// thread. `embed` is called per chunk as in `ingest_document`;
+/// `on_job_update` receives a job snapshot after each progress event and
+/// state change. The worker stops when the queue is empty, then rebuilds
+/// the indexes of each tenant it ingested into once. Returns false if a worker is already running (it
+/// will pick up newly queued jobs).
+Future<bool>  startIngestWorker({required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestJob) onJobUpdate }) => RustLib.instance.api.crateApiIngestJobsStartIngestWorker(embed: embed, onJobUpdate: onJobUpdate);
+
+            class IngestJob  {
+                final PlatformInt64 id;
+final String filePath;
+final String? name;
+/// One of the `JOB_*` values.
+final String state;
+/// Last `ingest` stage reported for this job.
+final String? stage;
+final int progressDone;
+final int progressTotal;
+final PlatformInt64? sourceId;
+final String? error;
+final PlatformInt64 createdAt;
+final PlatformInt64 updatedAt;
+
+                const IngestJob({required this.id ,required this.filePath ,this.name ,required this.state ,this.stage ,required this.progressDone ,required this.progressTotal ,this.sourceId ,this.error ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^filePath.hashCode^name.hashCode^state.hashCode^stage.hashCode^progressDone.hashCode^progressTotal.hashCode^sourceId.hashCode^error.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IngestJob &&
+                runtimeType == other.runtimeType
+                && id == other.id&& filePath == other.filePath&& name == other.name&& state == other.state&& stage == other.stage&& progressDone == other.progressDone&& progressTotal == other.progressTotal&& sourceId == other.sourceId&& error == other.error&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `default_presets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Get the preset for an intent type, falling back to "general".
+RetrievalPreset  getRetrievalPreset({required String intentType }) => RustLib.instance.api.crateApiIntentPresetsGetRetrievalPreset(intentType: intentType);
+
+/// Override (or add) the preset for an intent type.
+void  setRetrievalPreset({required String intentType , required RetrievalPreset preset }) => RustLib.instance.api.crateApiIntentPresetsSetRetrievalPreset(intentType: intentType, preset: preset);
+
+/// Restore the built-in preset table.
+void  resetRetrievalPresets() => RustLib.instance.api.crateApiIntentPresetsResetRetrievalPresets();
+
+            class RetrievalPreset  {
+                final int topK;
+final double vectorWeight;
+final double bm25Weight;
+/// Chunk type to favor (see `ChunkType::as_str`), e.g. "definition".
+final String? boostChunkType;
+/// Score multiplier applied to chunks of `boost_chunk_type`.
+final double chunkTypeBoost;
+/// Re-rank with Maximal Marginal Relevance to diversify results.
+final bool useMmr;
+/// MMR trade-off: 1.0 = pure relevance, 0.0 = pure diversity.
+final double mmrLambda;
+
+                const RetrievalPreset({required this.topK ,required this.vectorWeight ,required this.bm25Weight ,this.boostChunkType ,required this.chunkTypeBoost ,required this.useMmr ,required this.mmrLambda ,});
+
+                static Future<RetrievalPreset>  default_()=>RustLib.instance.api.crateApiIntentPresetsRetrievalPresetDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => topK.hashCode^vectorWeight.hashCode^bm25Weight.hashCode^boostChunkType.hashCode^chunkTypeBoost.hashCode^useMmr.hashCode^mmrLambda.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetrievalPreset &&
+                runtimeType == other.runtimeType
+                && topK == other.topK&& vectorWeight == other.vectorWeight&& bm25Weight == other.bm25Weight&& boostChunkType == other.boostChunkType&& chunkTypeBoost == other.chunkTypeBoost&& useMmr == other.useMmr&& mmrLambda == other.mmrLambda;
+        
+            }
+            
\ No newline at end of file
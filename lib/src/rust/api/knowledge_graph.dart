@@ -0,0 +1,109 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'source_rag.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_knowledge_graph_table`, `entity_key`, `store_chunk_triples`, `walk_neighborhood`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Enable or disable triple extraction at ingestion (off by default).
+void  setKnowledgeGraphEnabled({required bool enabled }) => RustLib.instance.api.crateApiKnowledgeGraphSetKnowledgeGraphEnabled(enabled: enabled);
+
+bool  isKnowledgeGraphEnabled() => RustLib.instance.api.crateApiKnowledgeGraphIsKnowledgeGraphEnabled();
+
+/// Extract triples from text using the sentence pattern rules.
+List<KnowledgeTriple>  extractTriples({required String text }) => RustLib.instance.api.crateApiKnowledgeGraphExtractTriples(text: text);
+
+/// Edges within `max_hops` (1..=3) of an entity, nearest first.
+Future<GraphNeighborhood>  getEntityNeighborhood({required String entity , required int maxHops , required int limit }) => RustLib.instance.api.crateApiKnowledgeGraphGetEntityNeighborhood(entity: entity, maxHops: maxHops, limit: limit);
+
+/// Chunks backing an entity's neighborhood. `similarity` is 1/hop, so
+/// direct evidence ranks above multi-hop evidence.
+Future<List<ChunkSearchResult>>  getEntitySupportingChunks({required String entity , required int maxHops , required int limit }) => RustLib.instance.api.crateApiKnowledgeGraphGetEntitySupportingChunks(entity: entity, maxHops: maxHops, limit: limit);
+
+            class GraphEdge  {
+                final String subject;
+final String relation;
+final String object;
+final PlatformInt64 chunkId;
+/// Hops from the queried entity (1 = direct edge).
+final int hop;
+
+                const GraphEdge({required this.subject ,required this.relation ,required this.object ,required this.chunkId ,required this.hop ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^relation.hashCode^object.hashCode^chunkId.hashCode^hop.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GraphEdge &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& relation == other.relation&& object == other.object&& chunkId == other.chunkId&& hop == other.hop;
+        
+            }
+
+class GraphNeighborhood  {
+                final String entity;
+final List<GraphEdge> edges;
+/// Distinct supporting chunks, closest hop first.
+final Int64List chunkIds;
+
+                const GraphNeighborhood({required this.entity ,required this.edges ,required this.chunkIds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entity.hashCode^edges.hashCode^chunkIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GraphNeighborhood &&
+                runtimeType == other.runtimeType
+                && entity == other.entity&& edges == other.edges&& chunkIds == other.chunkIds;
+        
+            }
+
+class KnowledgeTriple  {
+                final String subject;
+final String relation;
+final String object;
+
+                const KnowledgeTriple({required this.subject ,required this.relation ,required this.object ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subject.hashCode^relation.hashCode^object.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KnowledgeTriple &&
+                runtimeType == other.runtimeType
+                && subject == other.subject&& relation == other.relation&& object == other.object;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `count_scripts`, `detect_language_code`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ScriptCounts`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Detect the dominant language of a text.
+///
+/// Returns an ISO 639-1 code ("ko", "ja", "zh", "en") or "unknown" when the
+/// text has no letters. Latin-script text is reported as "en".
+String  detectLanguage({required String text }) => RustLib.instance.api.crateApiLanguageDetectDetectLanguage(text: text);
+
+            
+            
\ No newline at end of file
@@ -6,25 +6,43 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `try_send_log_to_dart`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CombinedLogger`, `DART_LOG_SINK`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `enabled`, `flush`, `initialize`, `log`
 
-/// Initialize the global logger.
-///
+            // These functions are ignored because they are not marked as `pub`: `apply_log_levels`, `default_level`, `level_for`, `max_level`, `parse_level`, `try_send_log_to_dart`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CombinedLogger`, `DART_LOG_SINK`, `LOG_LEVELS`, `LogLevels`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `enabled`, `flush`, `initialize`, `initialize`, `log`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Initialize the global logger.
+/// 
 /// This function is idempotent - calling it multiple times is safe and will
 /// simply return Ok(()) if the logger is already initialized.
-///
-/// Log levels:
-/// - Debug builds: DEBUG and above
-/// - Release builds: INFO and above
-Future<void> initLogger() => RustLib.instance.api.crateApiLoggerInitLogger();
+/// 
+/// Log levels default to the build profile (DEBUG in debug builds, INFO in
+/// release); use `set_log_level` / `set_module_log_level` to change them.
+Future<void>  initLogger() => RustLib.instance.api.crateApiLoggerInitLogger();
+
+/// Set the global log level at runtime (`off`, `error`, `warn`, `info`,
+/// `debug` or `trace`). Module overrides still take precedence.
+void  setLogLevel({required String level }) => RustLib.instance.api.crateApiLoggerSetLogLevel(level: level);
+
+/// Current global log level.
+String  getLogLevel() => RustLib.instance.api.crateApiLoggerGetLogLevel();
+
+/// Override the level for one module (e.g. `hybrid_search`, or a
+/// dependency crate such as `hnsw_rs`). `None` removes the override.
+void  setModuleLogLevel({required String module , String? level }) => RustLib.instance.api.crateApiLoggerSetModuleLogLevel(module: module, level: level);
+
+/// Drop all overrides and go back to the build profile's level.
+void  resetLogLevels() => RustLib.instance.api.crateApiLoggerResetLogLevels();
 
 /// Initialize the Dart log stream.
 /// Call this from Dart to start receiving Rust logs.
-Stream<String> initLogStream() =>
-    RustLib.instance.api.crateApiLoggerInitLogStream();
+Stream<String>  initLogStream() => RustLib.instance.api.crateApiLoggerInitLogStream();
 
 /// Close the Dart log stream.
 /// Call this when disposing the log subscription to prevent memory leaks.
-void closeLogStream() => RustLib.instance.api.crateApiLoggerCloseLogStream();
+void  closeLogStream() => RustLib.instance.api.crateApiLoggerCloseLogStream();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `checkpoint_wal`, `chunk_ids`, `compact_hnsw`, `host_allows`, `lower_thread_priority`, `now_secs`, `pass_due`, `prune_bm25`, `query_guard`, `run_pass`, `stale_ids`, `wait_for_idle_queries`, `wait_for_turn`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `QueryGuard`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `fmt`, `fmt`
+
+
+            void  setMaintenanceConfig({required MaintenanceConfig config }) => RustLib.instance.api.crateApiMaintenanceSetMaintenanceConfig(config: config);
+
+MaintenanceConfig  getMaintenanceConfig() => RustLib.instance.api.crateApiMaintenanceGetMaintenanceConfig();
+
+bool  isMaintenanceRunning() => RustLib.instance.api.crateApiMaintenanceIsMaintenanceRunning();
+
+MaintenanceReport?  getLastMaintenanceReport() => RustLib.instance.api.crateApiMaintenanceGetLastMaintenanceReport();
+
+/// Report the host's idle/charging state. Starts a maintenance pass for
+/// the active tenant when the state allows it and the last pass is older
+/// than `min_interval_secs`; returns true if a pass was started. Leaving
+/// the idle state stops a running pass after its current step.
+bool  notifyHostState({required bool idle , required bool charging }) => RustLib.instance.api.crateApiMaintenanceNotifyHostState(idle: idle, charging: charging);
+
+            class MaintenanceConfig  {
+                /// Only run while the device is charging.
+final bool requireCharging;
+/// Minimum time between the start of two passes.
+final int minIntervalSecs;
+
+                const MaintenanceConfig({required this.requireCharging ,required this.minIntervalSecs ,});
+
+                static Future<MaintenanceConfig>  default_()=>RustLib.instance.api.crateApiMaintenanceMaintenanceConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => requireCharging.hashCode^minIntervalSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MaintenanceConfig &&
+                runtimeType == other.runtimeType
+                && requireCharging == other.requireCharging&& minIntervalSecs == other.minIntervalSecs;
+        
+            }
+
+class MaintenanceReport  {
+                final PlatformInt64 startedAt;
+final BigInt durationMs;
+/// Buffered vectors were folded into the HNSW index.
+final bool bufferMerged;
+/// HNSW was rebuilt to drop points of deleted chunks.
+final bool hnswCompacted;
+final int bm25Pruned;
+final PlatformInt64 walPagesCheckpointed;
+/// The pass stopped early because the host left the idle state.
+final bool interrupted;
+
+                const MaintenanceReport({required this.startedAt ,required this.durationMs ,required this.bufferMerged ,required this.hnswCompacted ,required this.bm25Pruned ,required this.walPagesCheckpointed ,required this.interrupted ,});
+
+                static Future<MaintenanceReport>  default_()=>RustLib.instance.api.crateApiMaintenanceMaintenanceReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => startedAt.hashCode^durationMs.hashCode^bufferMerged.hashCode^hnswCompacted.hashCode^bm25Pruned.hashCode^walPagesCheckpointed.hashCode^interrupted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MaintenanceReport &&
+                runtimeType == other.runtimeType
+                && startedAt == other.startedAt&& durationMs == other.durationMs&& bufferMerged == other.bufferMerged&& hnswCompacted == other.hnswCompacted&& bm25Pruned == other.bm25Pruned&& walPagesCheckpointed == other.walPagesCheckpointed&& interrupted == other.interrupted;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `for_budget`, `memory_profile`, `process_rss_bytes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Set the memory budget in MB (`None` for standard mode) and return the
+/// resulting profile.
+Future<MemoryProfile>  setMemoryBudget({int? budgetMb }) => RustLib.instance.api.crateApiMemoryBudgetSetMemoryBudget(budgetMb: budgetMb);
+
+MemoryProfile  getMemoryProfile() => RustLib.instance.api.crateApiMemoryBudgetGetMemoryProfile();
+
+/// Current memory usage of the engine's main consumers.
+MemoryUsage  getMemoryUsage() => RustLib.instance.api.crateApiMemoryBudgetGetMemoryUsage();
+
+            class MemoryProfile  {
+                /// `None` = no budget set (standard mode).
+final int? budgetMb;
+final bool lowMemory;
+final int sqliteCacheKb;
+final int sqliteMmapMb;
+/// Use the int8 quantized flat index instead of HNSW.
+final bool quantizeIndex;
+/// Vector/BM25 candidates fetched per requested result.
+final int candidateMultiplier;
+/// Same, when a search filter is active.
+final int filteredCandidateMultiplier;
+/// Rows fetched per page by exact scans; 0 loads them all at once.
+final int scanPageRows;
+
+                const MemoryProfile({this.budgetMb ,required this.lowMemory ,required this.sqliteCacheKb ,required this.sqliteMmapMb ,required this.quantizeIndex ,required this.candidateMultiplier ,required this.filteredCandidateMultiplier ,required this.scanPageRows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => budgetMb.hashCode^lowMemory.hashCode^sqliteCacheKb.hashCode^sqliteMmapMb.hashCode^quantizeIndex.hashCode^candidateMultiplier.hashCode^filteredCandidateMultiplier.hashCode^scanPageRows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryProfile &&
+                runtimeType == other.runtimeType
+                && budgetMb == other.budgetMb&& lowMemory == other.lowMemory&& sqliteCacheKb == other.sqliteCacheKb&& sqliteMmapMb == other.sqliteMmapMb&& quantizeIndex == other.quantizeIndex&& candidateMultiplier == other.candidateMultiplier&& filteredCandidateMultiplier == other.filteredCandidateMultiplier&& scanPageRows == other.scanPageRows;
+        
+            }
+
+class MemoryUsage  {
+                final bool lowMemory;
+/// Points in the active tenant's HNSW graph (0 when not loaded).
+final BigInt hnswPoints;
+/// Points in the active tenant's quantized index (0 when not loaded).
+final BigInt quantizedPoints;
+/// Approximate bytes held by all quantized indexes.
+final BigInt quantizedIndexBytes;
+/// Embeddings waiting in the active tenant's incremental buffer.
+final BigInt bufferedEmbeddings;
+/// Heap currently allocated by SQLite (page caches included).
+final BigInt sqliteMemoryBytes;
+/// Resident set size of the process, where the platform exposes it.
+final BigInt? processRssBytes;
+
+                const MemoryUsage({required this.lowMemory ,required this.hnswPoints ,required this.quantizedPoints ,required this.quantizedIndexBytes ,required this.bufferedEmbeddings ,required this.sqliteMemoryBytes ,this.processRssBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => lowMemory.hashCode^hnswPoints.hashCode^quantizedPoints.hashCode^quantizedIndexBytes.hashCode^bufferedEmbeddings.hashCode^sqliteMemoryBytes.hashCode^processRssBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryUsage &&
+                runtimeType == other.runtimeType
+                && lowMemory == other.lowMemory&& hnswPoints == other.hnswPoints&& quantizedPoints == other.quantizedPoints&& quantizedIndexBytes == other.quantizedIndexBytes&& bufferedEmbeddings == other.bufferedEmbeddings&& sqliteMemoryBytes == other.sqliteMemoryBytes&& processRssBytes == other.processRssBytes;
+        
+            }
+            
\ No newline at end of file
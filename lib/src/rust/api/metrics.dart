@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `record_cache_hit`, `record_cache_miss`, `record_db_error`, `record_index_rebuild`, `record_panic`, `record_search`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Snapshot of all engine metrics.
+EngineMetrics  getMetrics() => RustLib.instance.api.crateApiMetricsGetMetrics();
+
+/// Reset all counters and the histogram to zero.
+void  resetMetrics() => RustLib.instance.api.crateApiMetricsResetMetrics();
+
+            class EngineMetrics  {
+                final BigInt searchesExecuted;
+/// In-memory index reused by a search (no rebuild from DB needed).
+final BigInt cacheHits;
+final BigInt cacheMisses;
+final BigInt indexRebuilds;
+final BigInt dbErrors;
+/// Panics seen by the panic hook (see `install_panic_hook`).
+final BigInt panics;
+final double avgSearchLatencyMs;
+final List<LatencyBucket> searchLatencyHistogram;
+
+                const EngineMetrics({required this.searchesExecuted ,required this.cacheHits ,required this.cacheMisses ,required this.indexRebuilds ,required this.dbErrors ,required this.panics ,required this.avgSearchLatencyMs ,required this.searchLatencyHistogram ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => searchesExecuted.hashCode^cacheHits.hashCode^cacheMisses.hashCode^indexRebuilds.hashCode^dbErrors.hashCode^panics.hashCode^avgSearchLatencyMs.hashCode^searchLatencyHistogram.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EngineMetrics &&
+                runtimeType == other.runtimeType
+                && searchesExecuted == other.searchesExecuted&& cacheHits == other.cacheHits&& cacheMisses == other.cacheMisses&& indexRebuilds == other.indexRebuilds&& dbErrors == other.dbErrors&& panics == other.panics&& avgSearchLatencyMs == other.avgSearchLatencyMs&& searchLatencyHistogram == other.searchLatencyHistogram;
+        
+            }
+
+class LatencyBucket  {
+                /// Inclusive upper bound in ms; `None` for the overflow bucket.
+final double? leMs;
+final BigInt count;
+
+                const LatencyBucket({this.leMs ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => leMs.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LatencyBucket &&
+                runtimeType == other.runtimeType
+                && leMs == other.leMs&& count == other.count;
+        
+            }
+            
\ No newline at end of file
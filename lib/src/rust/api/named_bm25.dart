@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'bm25_search.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `clear_all_named_bm25_indexes`, `delete_named_bm25_chunks`, `drop_tenant_named_bm25_indexes`, `missing`, `search_bm25_named_constrained`, `search_index`, `update_named_bm25_document`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Build (or replace) the active tenant's BM25 index `name` from `docs`.
+Future<void>  buildBm25IndexNamed({required String name , required List<(PlatformInt64,String)> docs }) => RustLib.instance.api.crateApiNamedBm25BuildBm25IndexNamed(name: name, docs: docs);
+
+/// Add documents to the existing index `name`; ids already in it are
+/// re-indexed with the new content.
+Future<void>  bm25AddDocumentsNamed({required String name , required List<(PlatformInt64,String)> docs }) => RustLib.instance.api.crateApiNamedBm25Bm25AddDocumentsNamed(name: name, docs: docs);
+
+/// Remove documents from the index `name`; unknown ids are ignored.
+Future<void>  bm25RemoveDocumentsNamed({required String name , required Int64List docIds }) => RustLib.instance.api.crateApiNamedBm25Bm25RemoveDocumentsNamed(name: name, docIds: docIds);
+
+/// Search the active tenant's BM25 index `name`, best first.
+Future<List<Bm25SearchResult>>  searchBm25Named({required String name , required String query , required int topK }) => RustLib.instance.api.crateApiNamedBm25SearchBm25Named(name: name, query: query, topK: topK);
+
+/// Drop the active tenant's BM25 index `name`; false if it did not exist.
+bool  dropBm25IndexNamed({required String name }) => RustLib.instance.api.crateApiNamedBm25DropBm25IndexNamed(name: name);
+
+/// The active tenant's named BM25 indexes, sorted by name.
+List<NamedBm25IndexInfo>  listBm25IndexesNamed() => RustLib.instance.api.crateApiNamedBm25ListBm25IndexesNamed();
+
+            class NamedBm25IndexInfo  {
+                final String name;
+final BigInt documentCount;
+final BigInt vocabularySize;
+
+                const NamedBm25IndexInfo({required this.name ,required this.documentCount ,required this.vocabularySize ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^documentCount.hashCode^vocabularySize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NamedBm25IndexInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& documentCount == other.documentCount&& vocabularySize == other.vocabularySize;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'hnsw_index.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_named`, `check_points`, `clear_all_named_indexes`, `delete_from`, `delete_named_chunks`, `drop_tenant_named_indexes`, `index_key`, `insert_named`, `missing`, `search_hnsw_named_with_ef`, `search_named`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NamedIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Build (or replace) the active tenant's index `name` from `points`,
+/// which may be empty.
+Future<void>  buildHnswIndexNamed({required String name , required List<(PlatformInt64,Float32List)> points , required NamedIndexConfig config }) => RustLib.instance.api.crateApiNamedIndexBuildHnswIndexNamed(name: name, points: points, config: config);
+
+/// Search the active tenant's index `name`, nearest first.
+Future<List<HnswSearchResult>>  searchHnswNamed({required String name , required List<double> queryEmbedding , required BigInt topK }) => RustLib.instance.api.crateApiNamedIndexSearchHnswNamed(name: name, queryEmbedding: queryEmbedding, topK: topK);
+
+/// Add points to the existing index `name` without rebuilding it. Ids
+/// must be new to the index, deleted ones included.
+Future<void>  insertHnswNamed({required String name , required List<(PlatformInt64,Float32List)> points }) => RustLib.instance.api.crateApiNamedIndexInsertHnswNamed(name: name, points: points);
+
+/// Hide `ids` from searches of the index `name` until it is rebuilt.
+/// Returns how many were newly deleted; unknown ids are ignored.
+Future<int>  deleteHnswNamed({required String name , required Int64List ids }) => RustLib.instance.api.crateApiNamedIndexDeleteHnswNamed(name: name, ids: ids);
+
+/// Drop the active tenant's index `name`; false if it did not exist.
+bool  dropHnswIndexNamed({required String name }) => RustLib.instance.api.crateApiNamedIndexDropHnswIndexNamed(name: name);
+
+/// The active tenant's named indexes, sorted by name.
+List<NamedIndexInfo>  listHnswIndexesNamed() => RustLib.instance.api.crateApiNamedIndexListHnswIndexesNamed();
+
+            /// Parameters of one named index; `None` fields fall back to the index
+/// config's metric, the adaptive build parameters and `max(100, 5 * top_k)`.
+class NamedIndexConfig  {
+                /// Distance metric; int8 indexes support cosine only.
+final DistanceMetric? metric;
+final HnswBuildConfig? build;
+/// Candidate list size during search (raised to the number of results
+/// fetched when lower).
+final int? efSearch;
+
+                const NamedIndexConfig({this.metric ,this.build ,this.efSearch ,});
+
+                static Future<NamedIndexConfig>  default_()=>RustLib.instance.api.crateApiNamedIndexNamedIndexConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => metric.hashCode^build.hashCode^efSearch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NamedIndexConfig &&
+                runtimeType == other.runtimeType
+                && metric == other.metric&& build == other.build&& efSearch == other.efSearch;
+        
+            }
+
+class NamedIndexInfo  {
+                final String name;
+/// Points in the graph, deleted ones included.
+final BigInt pointCount;
+final BigInt deletedPoints;
+final int dimension;
+final DistanceMetric metric;
+
+                const NamedIndexInfo({required this.name ,required this.pointCount ,required this.deletedPoints ,required this.dimension ,required this.metric ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^pointCount.hashCode^deletedPoints.hashCode^dimension.hashCode^metric.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NamedIndexInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& pointCount == other.pointCount&& deletedPoints == other.deletedPoints&& dimension == other.dimension&& metric == other.metric;
+        
+            }
+            
\ No newline at end of file
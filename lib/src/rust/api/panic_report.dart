@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_backtrace`, `catch_panic`, `payload_message`, `report`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Install the panic hook. Safe to call more than once; the previously
+/// installed hook still runs after the event is recorded.
+void  installPanicHook() => RustLib.instance.api.crateApiPanicReportInstallPanicHook();
+
+/// Most recent panics, oldest first.
+List<PanicEvent>  getRecentPanics() => RustLib.instance.api.crateApiPanicReportGetRecentPanics();
+
+void  clearRecentPanics() => RustLib.instance.api.crateApiPanicReportClearRecentPanics();
+
+            class PanicEvent  {
+                /// Name of the panicking thread (`<unnamed>` for anonymous threads).
+final String thread;
+final String message;
+/// `file:line:column`, when known.
+final String? location;
+/// Captured in debug builds only.
+final String? backtrace;
+/// Unix timestamp (seconds).
+final PlatformInt64 timestamp;
+
+                const PanicEvent({required this.thread ,required this.message ,this.location ,this.backtrace ,required this.timestamp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => thread.hashCode^message.hashCode^location.hashCode^backtrace.hashCode^timestamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PanicEvent &&
+                runtimeType == other.runtimeType
+                && thread == other.thread&& message == other.message&& location == other.location&& backtrace == other.backtrace&& timestamp == other.timestamp;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `is_milestone`, `new`, `report`, `with_pause`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Progress`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Start receiving progress events.
+Stream<ProgressEvent>  initProgressStream() => RustLib.instance.api.crateApiProgressInitProgressStream();
+
+/// Stop sending progress events.
+void  closeProgressStream() => RustLib.instance.api.crateApiProgressCloseProgressStream();
+
+            class ProgressEvent  {
+                /// `hnsw_rebuild`, `bm25_rebuild` or `add_chunks` (the `OP_*` values).
+final String operation;
+/// `insert`, `build` or `index` (the `PHASE_*` values).
+final String phase;
+/// Items of this phase processed so far.
+final int done;
+final int total;
+
+                const ProgressEvent({required this.operation ,required this.phase ,required this.done ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => operation.hashCode^phase.hashCode^done.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProgressEvent &&
+                runtimeType == other.runtimeType
+                && operation == other.operation&& phase == other.phase&& done == other.done&& total == other.total;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capitalize_first`, `split_parts`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Split a multi-part question into ordered sub-queries.
+QueryDecomposition  decomposeQuery({required String query }) => RustLib.instance.api.crateApiQueryDecomposeDecomposeQuery(query: query);
+
+            class QueryDecomposition  {
+                final String original;
+/// Sub-queries in question order; just `[original]` when nothing was split.
+final List<String> subQueries;
+
+                const QueryDecomposition({required this.original ,required this.subQueries ,});
+
+                 Future<bool>  isDecomposed()=>RustLib.instance.api.crateApiQueryDecomposeQueryDecompositionIsDecomposed(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => original.hashCode^subQueries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryDecomposition &&
+                runtimeType == other.runtimeType
+                && original == other.original&& subQueries == other.subQueries;
+        
+            }
+            
\ No newline at end of file
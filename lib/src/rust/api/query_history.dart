@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_query_history_table`, `insert_query`, `select_suggestions`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Record an executed query and how many results it returned.
+Future<void>  recordQuery({required String queryText , required int resultCount }) => RustLib.instance.api.crateApiQueryHistoryRecordQuery(queryText: queryText, resultCount: resultCount);
+
+/// Suggest previous queries starting with `prefix`, most frequent first
+/// (ties broken by recency).
+Future<List<QuerySuggestion>>  getQuerySuggestions({required String prefix }) => RustLib.instance.api.crateApiQueryHistoryGetQuerySuggestions(prefix: prefix);
+
+/// Delete the active tenant's saved queries.
+Future<void>  clearQueryHistory() => RustLib.instance.api.crateApiQueryHistoryClearQueryHistory();
+
+            class QuerySuggestion  {
+                /// Most recent spelling of the query as the user typed it.
+final String query;
+final int useCount;
+/// Unix timestamp (seconds) of the last use.
+final PlatformInt64 lastUsedAt;
+
+                const QuerySuggestion({required this.query ,required this.useCount ,required this.lastUsedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => query.hashCode^useCount.hashCode^lastUsedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QuerySuggestion &&
+                runtimeType == other.runtimeType
+                && query == other.query&& useCount == other.useCount&& lastUsedAt == other.lastUsedAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `candidate_phrases`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Extract ranked keywords (single terms and short phrases) from a query.
+List<QueryKeyword>  extractQueryKeywords({required String query }) => RustLib.instance.api.crateApiQueryKeywordsExtractQueryKeywords(query: query);
+
+            class QueryKeyword  {
+                /// Lowercased keyword or phrase (words joined by a single space).
+final String text;
+final double score;
+/// Document frequency of the keyword's rarest indexed word; 0 when none
+/// of its words are in the BM25 dictionary.
+final int docFreq;
+/// Whether any word of the keyword exists in the BM25 dictionary.
+final bool inIndex;
+
+                const QueryKeyword({required this.text ,required this.score ,required this.docFreq ,required this.inIndex ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^score.hashCode^docFreq.hashCode^inIndex.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryKeyword &&
+                runtimeType == other.runtimeType
+                && text == other.text&& score == other.score&& docFreq == other.docFreq&& inIndex == other.inIndex;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `normalize_query_text`, `normalize_query_with`, `strip_punctuation`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Normalize a query exactly as the engine does before searching.
+String  normalizeQuery({required String query }) => RustLib.instance.api.crateApiQueryNormalizeNormalizeQuery(query: query);
+
+void  setQueryNormalizationConfig({required QueryNormalizationConfig config }) => RustLib.instance.api.crateApiQueryNormalizeSetQueryNormalizationConfig(config: config);
+
+QueryNormalizationConfig  getQueryNormalizationConfig() => RustLib.instance.api.crateApiQueryNormalizeGetQueryNormalizationConfig();
+
+            class QueryNormalizationConfig  {
+                /// NFKC: folds full-width forms, ligatures and compatibility characters.
+final bool unicodeNfkc;
+/// Replace punctuation and symbols with spaces, except `+`/`#` ending a
+/// word so names like "C++" and "C#" survive.
+final bool stripPunctuation;
+final bool lowercase;
+/// Drop stopwords of each word's language (off by default; hurts embeddings).
+final bool removeStopwords;
+
+                const QueryNormalizationConfig({required this.unicodeNfkc ,required this.stripPunctuation ,required this.lowercase ,required this.removeStopwords ,});
+
+                static Future<QueryNormalizationConfig>  default_()=>RustLib.instance.api.crateApiQueryNormalizeQueryNormalizationConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => unicodeNfkc.hashCode^stripPunctuation.hashCode^lowercase.hashCode^removeStopwords.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryNormalizationConfig &&
+                runtimeType == other.runtimeType
+                && unicodeNfkc == other.unicodeNfkc&& stripPunctuation == other.stripPunctuation&& lowercase == other.lowercase&& removeStopwords == other.removeStopwords;
+        
+            }
+            
\ No newline at end of file
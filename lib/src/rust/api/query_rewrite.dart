@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `find_antecedent`, `replace_case_insensitive`, `unchanged`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Rewrite `query` into a standalone query using earlier user turns
+/// (oldest first).
+RewrittenQuery  rewriteQuery({required List<String> history , required String query }) => RustLib.instance.api.crateApiQueryRewriteRewriteQuery(history: history, query: query);
+
+/// Remember a user turn for `rewrite_query_with_context`.
+void  addConversationTurn({required String text }) => RustLib.instance.api.crateApiQueryRewriteAddConversationTurn(text: text);
+
+/// User turns currently kept by the engine (oldest first).
+List<String>  getConversationTurns() => RustLib.instance.api.crateApiQueryRewriteGetConversationTurns();
+
+/// Forget the conversation (e.g. when the user starts a new chat).
+void  clearConversationTurns() => RustLib.instance.api.crateApiQueryRewriteClearConversationTurns();
+
+/// Rewrite against the engine's stored turns, then store the rewritten query
+/// as the newest turn so follow-ups chain.
+RewrittenQuery  rewriteQueryWithContext({required String query }) => RustLib.instance.api.crateApiQueryRewriteRewriteQueryWithContext(query: query);
+
+            class RewrittenQuery  {
+                final String original;
+/// Standalone query for retrieval (equal to `original` when unchanged).
+final String rewritten;
+final bool wasRewritten;
+/// Topic from history that pronouns/ellipsis were resolved to.
+final String? antecedent;
+
+                const RewrittenQuery({required this.original ,required this.rewritten ,required this.wasRewritten ,this.antecedent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => original.hashCode^rewritten.hashCode^wasRewritten.hashCode^antecedent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RewrittenQuery &&
+                runtimeType == other.runtimeType
+                && original == other.original&& rewritten == other.rewritten&& wasRewritten == other.wasRewritten&& antecedent == other.antecedent;
+        
+            }
+            
\ No newline at end of file
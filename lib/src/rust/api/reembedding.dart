@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `acquire`, `count_chunks_after`, `create_reembedding_jobs_table`, `drive_job`, `load_job`, `load_unfinished_job`, `next_batch`, `open_job`, `read_job`, `set_state`, `stop_job`, `store_batch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DriverGuard`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+
+
+            /// The active tenant's latest re-embedding job, if any.
+Future<ReembeddingJob?>  getReembeddingJob() => RustLib.instance.api.crateApiReembeddingGetReembeddingJob();
+
+/// Stop the running job after its current batch; `start_reembedding_job`
+/// with the same model resumes it. Returns false if nothing was running.
+Future<bool>  pauseReembeddingJob() => RustLib.instance.api.crateApiReembeddingPauseReembeddingJob();
+
+/// Abandon the running or paused job. Chunks already re-embedded keep
+/// their new vectors; the next start begins from the first chunk.
+Future<bool>  cancelReembeddingJob() => RustLib.instance.api.crateApiReembeddingCancelReembeddingJob();
+
+/// Re-embed every chunk of the active tenant with `model_name`.
+///
+/// `embed_batch` receives up to `batch_size` chunk texts and must return
+/// one vector per text, all of the same dimension. `on_progress` gets a
+/// job snapshot after each stored batch. Resumes an unfinished job for the
+/// same model; returns the job in its final (or paused/cancelled) state.
+Future<ReembeddingJob>  startReembeddingJob({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress }) => RustLib.instance.api.crateApiReembeddingStartReembeddingJob(modelName: modelName, batchSize: batchSize, embedBatch: embedBatch, onProgress: onProgress);
+
+            class ReembeddingJob  {
+                final PlatformInt64 id;
+final String modelName;
+final int batchSize;
+/// One of the `REEMBED_*` values.
+final String state;
+final int done;
+final int total;
+/// Checkpoint: every chunk with a smaller or equal id is re-embedded.
+final PlatformInt64 lastChunkId;
+final int? dimension;
+final String? error;
+final PlatformInt64 createdAt;
+final PlatformInt64 updatedAt;
+
+                const ReembeddingJob({required this.id ,required this.modelName ,required this.batchSize ,required this.state ,required this.done ,required this.total ,required this.lastChunkId ,this.dimension ,this.error ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^modelName.hashCode^batchSize.hashCode^state.hashCode^done.hashCode^total.hashCode^lastChunkId.hashCode^dimension.hashCode^error.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReembeddingJob &&
+                runtimeType == other.runtimeType
+                && id == other.id&& modelName == other.modelName&& batchSize == other.batchSize&& state == other.state&& done == other.done&& total == other.total&& lastChunkId == other.lastChunkId&& dimension == other.dimension&& error == other.error&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'hybrid_search.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'snippets.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `expand_with_related`, `find_related`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            class ExpandedSearchResult  {
+                /// Related chunks have `vector_rank` and `bm25_rank` set to 0.
+final HybridSearchResult result;
+/// One of the `LINK_*` values ("hit" for the original results).
+final String link;
+/// Chunk id of the hit this chunk was reached from.
+final PlatformInt64? expandedFrom;
+
+                const ExpandedSearchResult({required this.result ,required this.link ,this.expandedFrom ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => result.hashCode^link.hashCode^expandedFrom.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExpandedSearchResult &&
+                runtimeType == other.runtimeType
+                && result == other.result&& link == other.link&& expandedFrom == other.expandedFrom;
+        
+            }
+
+class ExpansionConfig  {
+                /// Related chunks added per hit at most.
+final int maxRelatedPerHit;
+/// Related score = hit score * decay.
+final double scoreDecay;
+final bool followAdjacent;
+final bool followSharedEntities;
+final bool followSameBatch;
+
+                const ExpansionConfig({required this.maxRelatedPerHit ,required this.scoreDecay ,required this.followAdjacent ,required this.followSharedEntities ,required this.followSameBatch ,});
+
+                static Future<ExpansionConfig>  default_()=>RustLib.instance.api.crateApiRelatedChunksExpansionConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxRelatedPerHit.hashCode^scoreDecay.hashCode^followAdjacent.hashCode^followSharedEntities.hashCode^followSameBatch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExpansionConfig &&
+                runtimeType == other.runtimeType
+                && maxRelatedPerHit == other.maxRelatedPerHit&& scoreDecay == other.scoreDecay&& followAdjacent == other.followAdjacent&& followSharedEntities == other.followSharedEntities&& followSameBatch == other.followSameBatch;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'hybrid_search.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'snippets.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `drop_tenant_candidates`, `fuse_rerank_scores`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PendingCandidates`
+
+
+            /// Phase one: the top `candidate_count` hybrid results, with contents, to
+/// score externally. Replaces any candidates still pending.
+Future<List<HybridSearchResult>>  searchHybridCandidates({required String queryText , required List<double> queryEmbedding , required int candidateCount , RrfConfig? config , SearchFilter? filter }) => RustLib.instance.api.crateApiRerankSearchHybridCandidates(queryText: queryText, queryEmbedding: queryEmbedding, candidateCount: candidateCount, config: config, filter: filter);
+
+/// Phase two: fuse cross-encoder `scores` (higher is better, one per id
+/// in `doc_ids`) into the pending candidates and return the best `top_k`.
+///
+/// Each candidate gets `rerank_weight / (k + rerank rank)` added to its
+/// RRF score or, with `FusionStrategy::WeightedScore`, `rerank_weight`
+/// times its rerank score normalized like the other lists
+/// (`rerank_weight` defaults to 1.0, letting the reranker dominate the
+/// 0.5/0.5 vector/BM25 weights). Candidates left unscored keep their
+/// score; ids that were not candidates are ignored.
+Future<List<HybridSearchResult>>  finalizeWithRerankScores({required Int64List docIds , required List<double> scores , required int topK , double? rerankWeight }) => RustLib.instance.api.crateApiRerankFinalizeWithRerankScores(docIds: docIds, scores: scores, topK: topK, rerankWeight: rerankWeight);
+
+            
+            
\ No newline at end of file
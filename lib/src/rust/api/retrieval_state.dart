@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `citations_from_chunks`, `citations_from_hybrid`, `drop_tenant_retrieval`, `record_chunk_retrieval`, `record_hybrid_retrieval`, `store`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Get the chunks and sources used by the active tenant's most recent
+/// retrieval.
+LastRetrieval?  getLastRetrieval() => RustLib.instance.api.crateApiRetrievalStateGetLastRetrieval();
+
+/// Forget the active tenant's last retrieval (e.g. when starting a new
+/// conversation).
+void  clearLastRetrieval() => RustLib.instance.api.crateApiRetrievalStateClearLastRetrieval();
+
+            /// Snapshot of the most recent retrieval.
+class LastRetrieval  {
+                /// Query text; `None` for vector-only (chunk) searches.
+final String? query;
+final List<RetrievalCitation> citations;
+
+                const LastRetrieval({this.query ,required this.citations ,});
+
+                /// Distinct source ids in citation order.
+ Future<Int64List>  sourceIds()=>RustLib.instance.api.crateApiRetrievalStateLastRetrievalSourceIds(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => query.hashCode^citations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LastRetrieval &&
+                runtimeType == other.runtimeType
+                && query == other.query&& citations == other.citations;
+        
+            }
+
+/// One chunk that contributed to the last retrieval.
+class RetrievalCitation  {
+                final PlatformInt64 chunkId;
+final PlatformInt64 sourceId;
+final int chunkIndex;
+final double score;
+/// Source metadata (JSON), including page information when the app stored it.
+final String? metadata;
+
+                const RetrievalCitation({required this.chunkId ,required this.sourceId ,required this.chunkIndex ,required this.score ,this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chunkId.hashCode^sourceId.hashCode^chunkIndex.hashCode^score.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetrievalCitation &&
+                runtimeType == other.runtimeType
+                && chunkId == other.chunkId&& sourceId == other.sourceId&& chunkIndex == other.chunkIndex&& score == other.score&& metadata == other.metadata;
+        
+            }
+            
\ No newline at end of file
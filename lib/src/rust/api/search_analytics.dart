@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_str`, `create_search_analytics_tables`, `delete_search_analytics`, `insert_event`, `insert_feedback`, `load_events`, `query_hash`, `record_search_event`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExportedEvent`, `ExportedFeedback`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Turn analytics collection on or off (off by default).
+void  setSearchAnalyticsEnabled({required bool enabled }) => RustLib.instance.api.crateApiSearchAnalyticsSetSearchAnalyticsEnabled(enabled: enabled);
+
+bool  isSearchAnalyticsEnabled() => RustLib.instance.api.crateApiSearchAnalyticsIsSearchAnalyticsEnabled();
+
+/// Report that the user clicked or accepted `chunk_id` from the results of
+/// `query_text`. Attaches to the latest recorded search for that query in
+/// the active tenant; returns `false` when there is none (e.g. collection
+/// was disabled at search time).
+Future<bool>  reportSearchFeedback({required String queryText , required PlatformInt64 chunkId , required SearchFeedbackAction action }) => RustLib.instance.api.crateApiSearchAnalyticsReportSearchFeedback(queryText: queryText, chunkId: chunkId, action: action);
+
+/// Write the active tenant's recorded searches with their feedback to
+/// `path` as JSON Lines, one search per line. Returns the number written.
+Future<int>  exportSearchAnalytics({required String path }) => RustLib.instance.api.crateApiSearchAnalyticsExportSearchAnalytics(path: path);
+
+/// Delete the active tenant's recorded searches and feedback.
+Future<void>  clearSearchAnalytics() => RustLib.instance.api.crateApiSearchAnalyticsClearSearchAnalytics();
+
+            /// What the user did with a search result.
+enum SearchFeedbackAction {
+                    /// Opened or expanded the result.
+clicked,
+/// Used the result, e.g. kept it in the answer context.
+accepted,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `diagnose`, `diagnostics`, `finish`, `new`, `record`, `with_diagnostics`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SearchTracer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Enable or disable search tracing (debug builds / field diagnostics).
+void  setSearchTracing({required bool enabled }) => RustLib.instance.api.crateApiSearchTraceSetSearchTracing(enabled: enabled);
+
+bool  isSearchTracingEnabled() => RustLib.instance.api.crateApiSearchTraceIsSearchTracingEnabled();
+
+            /// Stage timings and candidate counts of one hybrid search.
+class SearchDiagnostics  {
+                final double vectorMs;
+final double bm25Ms;
+/// Filter SQL, or the exact scan over filtered sources.
+final double filterMs;
+final double fusionMs;
+final double fetchMs;
+final double totalMs;
+/// Candidates requested from each retriever (`top_k` times the multiplier).
+final int candidateK;
+/// Raw vector and BM25 candidates, before filtering.
+final int vectorCandidates;
+final int bm25Candidates;
+/// Candidates left after filtering (or found by the exact scan).
+final int filteredVectorCandidates;
+final int filteredBm25Candidates;
+/// Whether vector candidates came from an exact scan of the filtered
+/// sources instead of the HNSW index.
+final bool exactScan;
+/// Whether vector candidates came from the HNSW graph restricted to
+/// the filtered chunk ids.
+final bool graphFilter;
+final bool hnswLoaded;
+
+                const SearchDiagnostics({required this.vectorMs ,required this.bm25Ms ,required this.filterMs ,required this.fusionMs ,required this.fetchMs ,required this.totalMs ,required this.candidateK ,required this.vectorCandidates ,required this.bm25Candidates ,required this.filteredVectorCandidates ,required this.filteredBm25Candidates ,required this.exactScan ,required this.graphFilter ,required this.hnswLoaded ,});
+
+                static Future<SearchDiagnostics>  default_()=>RustLib.instance.api.crateApiSearchTraceSearchDiagnosticsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => vectorMs.hashCode^bm25Ms.hashCode^filterMs.hashCode^fusionMs.hashCode^fetchMs.hashCode^totalMs.hashCode^candidateK.hashCode^vectorCandidates.hashCode^bm25Candidates.hashCode^filteredVectorCandidates.hashCode^filteredBm25Candidates.hashCode^exactScan.hashCode^graphFilter.hashCode^hnswLoaded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchDiagnostics &&
+                runtimeType == other.runtimeType
+                && vectorMs == other.vectorMs&& bm25Ms == other.bm25Ms&& filterMs == other.filterMs&& fusionMs == other.fusionMs&& fetchMs == other.fetchMs&& totalMs == other.totalMs&& candidateK == other.candidateK&& vectorCandidates == other.vectorCandidates&& bm25Candidates == other.bm25Candidates&& filteredVectorCandidates == other.filteredVectorCandidates&& filteredBm25Candidates == other.filteredBm25Candidates&& exactScan == other.exactScan&& graphFilter == other.graphFilter&& hnswLoaded == other.hnswLoaded;
+        
+            }
+
+class SearchSpan  {
+                /// Stage name, e.g. "vector_search" or "content_fetch".
+final String name;
+final double durationMs;
+
+                const SearchSpan({required this.name ,required this.durationMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchSpan &&
+                runtimeType == other.runtimeType
+                && name == other.name&& durationMs == other.durationMs;
+        
+            }
+
+class SearchTrace  {
+                final List<SearchSpan> spans;
+final double totalMs;
+
+                const SearchTrace({required this.spans ,required this.totalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spans.hashCode^totalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchTrace &&
+                runtimeType == other.runtimeType
+                && spans == other.spans&& totalMs == other.totalMs;
+        
+            }
+            
\ No newline at end of file
@@ -4,150 +4,236 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'corpus_report.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `is_article_title`, `protect_structural_blocks`, `recursive_split`, `split_by_headers`, `split_by_lines`, `split_by_sentences`, `split_table_preserving_headers`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChunkingStrategy`, `ProtectedText`, `Section`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// Classify chunk by rule-based pattern matching.
-ChunkType classifyChunk({required String text}) =>
-    RustLib.instance.api.crateApiSemanticChunkerClassifyChunk(text: text);
+            // These functions are ignored because they are not marked as `pub`: `anchor_chunk`, `anchor_semantic_chunks`, `anchor_structured_chunks`, `byte_at`, `char_at`, `compile_section_patterns`, `effective_max_chars`, `estimate_tokens`, `extend_with_overlap`, `find`, `group_list_intros`, `is_article_title`, `is_list_item`, `linearize_table`, `match_section_title`, `new`, `new`, `paragraph_chunk`, `place`, `protect_structural_blocks`, `recursive_split`, `section_title`, `split_by_headers`, `split_by_lines`, `split_by_sentences`, `split_inclusive_terminators`, `split_list`, `split_table_preserving_headers`, `structured_chunk`, `table_cells`, `with_overlap`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CharIndex`, `ProtectedText`, `SectionSummary`, `Section`, `SpanLocator`, `TokenSizer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `size`
+
+
+            /// Classify chunk by rule-based pattern matching.
+ChunkType  classifyChunk({required String text }) => RustLib.instance.api.crateApiSemanticChunkerClassifyChunk(text: text);
 
 /// Split text into semantic chunks using paragraph-first strategy.
-List<SemanticChunk> semanticChunk({
-  required String text,
-  required int maxChars,
-}) => RustLib.instance.api.crateApiSemanticChunkerSemanticChunk(
-  text: text,
-  maxChars: maxChars,
-);
-
-/// Split text with overlap (API compatibility wrapper).
-List<SemanticChunk> semanticChunkWithOverlap({
-  required String text,
-  required int maxChars,
-  required int overlapChars,
-}) => RustLib.instance.api.crateApiSemanticChunkerSemanticChunkWithOverlap(
-  text: text,
-  maxChars: maxChars,
-  overlapChars: overlapChars,
-);
+List<SemanticChunk>  semanticChunk({required String text , required int maxChars }) => RustLib.instance.api.crateApiSemanticChunkerSemanticChunk(text: text, maxChars: maxChars);
 
-/// Markdown chunk with structure preservation and metadata inheritance.
+/// Split text into chunks of at most `max_tokens` tokens (special tokens
+/// excluded), preferring paragraph, then sentence, then word boundaries.
 ///
+/// Counts come from the tokenizer passed to `init_tokenizer`, so CJK and
+/// Latin text fill the embedding context equally well; without one a
+/// conservative estimate is used.
+List<SemanticChunk>  semanticChunkByTokens({required String text , required int maxTokens }) => RustLib.instance.api.crateApiSemanticChunkerSemanticChunkByTokens(text: text, maxTokens: maxTokens);
+
+/// Register regex patterns for section titles (e.g. `제\d+조`, `Article \d+`,
+/// `Section \d+`). A line starting with a match begins a new chunk in both
+/// `semantic_chunk` and `markdown_chunk`, and becomes part of the header
+/// path (the matched text) in the latter. Replaces the current patterns; an empty list turns
+/// detection off. Defaults to Korean statute articles.
+void  setSectionPatterns({required List<String> patterns }) => RustLib.instance.api.crateApiSemanticChunkerSetSectionPatterns(patterns: patterns);
+
+/// Currently registered section-title patterns.
+List<String>  getSectionPatterns() => RustLib.instance.api.crateApiSemanticChunkerGetSectionPatterns();
+
+/// Split text with overlap (API compatibility wrapper). `overlap_chars`
+/// counts characters, `max_chars` bytes.
+List<SemanticChunk>  semanticChunkWithOverlap({required String text , required int maxChars , required int overlapChars }) => RustLib.instance.api.crateApiSemanticChunkerSemanticChunkWithOverlap(text: text, maxChars: maxChars, overlapChars: overlapChars);
+
+/// Chunk `text` with `config` and report the size distribution without
+/// storing anything, for previewing chunker settings before embedding.
+ChunkStats  analyzeChunks({required String text , required ChunkingConfig config }) => RustLib.instance.api.crateApiSemanticChunkerAnalyzeChunks(text: text, config: config);
+
+/// Chunk text with the strategy and limits in `config`.
+///
+/// `Recursive` splits by paragraph, then line, then sentence; chunk types
+/// are the `classify_chunk` categories. `Markdown` follows `markdown_chunk`.
+List<StructuredChunk>  chunkText({required String text , required ChunkingConfig config }) => RustLib.instance.api.crateApiSemanticChunkerChunkText(text: text, config: config);
+
+/// Markdown chunk with structure preservation and metadata inheritance.
+/// 
 /// - Splits by Markdown headers (#, ##, ###)
 /// - Preserves code blocks (```) as single units
 /// - Preserves tables (|---|) as single units
 /// - Inherits header path as metadata
-List<StructuredChunk> markdownChunk({
-  required String text,
-  required int maxChars,
-}) => RustLib.instance.api.crateApiSemanticChunkerMarkdownChunk(
-  text: text,
-  maxChars: maxChars,
-);
+List<StructuredChunk>  markdownChunk({required String text , required int maxChars }) => RustLib.instance.api.crateApiSemanticChunkerMarkdownChunk(text: text, maxChars: maxChars);
+
+            /// Size distribution of the chunks a config would produce.
+class ChunkStats  {
+                final int chunkCount;
+/// Sizes are in bytes, the unit `max_chars` limits.
+final int minSize;
+final int maxSize;
+final double avgSize;
+final double medianSize;
+/// Chunk counts by `chunk_type`, most common first.
+final List<CorpusCount> typeHistogram;
+/// Chunks longer than `max_chars` (unsplittable lines, overlap, tables).
+final int oversizeCount;
+
+                const ChunkStats({required this.chunkCount ,required this.minSize ,required this.maxSize ,required this.avgSize ,required this.medianSize ,required this.typeHistogram ,required this.oversizeCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chunkCount.hashCode^minSize.hashCode^maxSize.hashCode^avgSize.hashCode^medianSize.hashCode^typeHistogram.hashCode^oversizeCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkStats &&
+                runtimeType == other.runtimeType
+                && chunkCount == other.chunkCount&& minSize == other.minSize&& maxSize == other.maxSize&& avgSize == other.avgSize&& medianSize == other.medianSize&& typeHistogram == other.typeHistogram&& oversizeCount == other.oversizeCount;
+        
+            }
 
 /// Chunk type classification.
 enum ChunkType {
-  definition,
-  example,
-  list,
-  procedure,
-  comparison,
-  general;
+                    definition,
+example,
+list,
+procedure,
+comparison,
+general,
+                    ;
+                     Future<void>  asStr()=>RustLib.instance.api.crateApiSemanticChunkerChunkTypeAsStr(that: this, );
+
+
+static Future<ChunkType>  fromStr({required String s })=>RustLib.instance.api.crateApiSemanticChunkerChunkTypeFromStr(s: s);
 
-  Future<void> asStr() =>
-      RustLib.instance.api.crateApiSemanticChunkerChunkTypeAsStr(that: this);
 
-  static Future<ChunkType> fromStr({required String s}) =>
-      RustLib.instance.api.crateApiSemanticChunkerChunkTypeFromStr(s: s);
-}
+                }
+
+/// Tunable chunking behavior for `chunk_text`.
+///
+/// The defaults reproduce `semantic_chunk` / `markdown_chunk`.
+class ChunkingConfig  {
+                /// Upper bound on chunk length in bytes.
+final int maxChars;
+/// Floor applied to `max_chars`, so tiny limits can't shred the text.
+final int minChars;
+/// Characters (not bytes, unlike `max_chars`) of the previous chunk's
+/// tail prepended to each text chunk.
+final int overlap;
+final ChunkingStrategy strategy;
+/// Keep Markdown tables as units, repeating the header row when split.
+final bool preserveTables;
+/// Keep fenced code blocks as units, linked by `batch_id` when split.
+final bool preserveCode;
+/// Strings that end a sentence when a paragraph must be split further.
+/// Empty uses the Unicode-aware segmenter (see `sentence_segmenter`).
+final List<String> sentenceTerminators;
+/// Markdown only: when > 0, emit a synthetic `"summary"` chunk per
+/// top-level header holding the first N sentences of its section.
+final int sectionSummarySentences;
+/// Markdown only: also emit each table as `"Column: value"` sentences per
+/// row (`"table_text"` chunks), linked to the raw table by `batch_id`.
+final bool linearizeTables;
+/// Split lists between items and repeat their introduction ("Features:")
+/// in every piece, instead of splitting by line or sentence.
+final bool keepListIntro;
+
+                const ChunkingConfig({required this.maxChars ,required this.minChars ,required this.overlap ,required this.strategy ,required this.preserveTables ,required this.preserveCode ,required this.sentenceTerminators ,required this.sectionSummarySentences ,required this.linearizeTables ,required this.keepListIntro ,});
+
+                static Future<ChunkingConfig>  default_()=>RustLib.instance.api.crateApiSemanticChunkerChunkingConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxChars.hashCode^minChars.hashCode^overlap.hashCode^strategy.hashCode^preserveTables.hashCode^preserveCode.hashCode^sentenceTerminators.hashCode^sectionSummarySentences.hashCode^linearizeTables.hashCode^keepListIntro.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkingConfig &&
+                runtimeType == other.runtimeType
+                && maxChars == other.maxChars&& minChars == other.minChars&& overlap == other.overlap&& strategy == other.strategy&& preserveTables == other.preserveTables&& preserveCode == other.preserveCode&& sentenceTerminators == other.sentenceTerminators&& sectionSummarySentences == other.sectionSummarySentences&& linearizeTables == other.linearizeTables&& keepListIntro == other.keepListIntro;
+        
+            }
+
+/// Chunking strategy for structure-aware chunking.
+enum ChunkingStrategy {
+                    recursive,
+markdown,
+                    ;
+                    
+                }
 
 /// Semantic chunk result.
-class SemanticChunk {
-  final int index;
-  final String content;
-  final int startPos;
-  final int endPos;
-  final String chunkType;
-
-  const SemanticChunk({
-    required this.index,
-    required this.content,
-    required this.startPos,
-    required this.endPos,
-    required this.chunkType,
-  });
-
-  @override
-  int get hashCode =>
-      index.hashCode ^
-      content.hashCode ^
-      startPos.hashCode ^
-      endPos.hashCode ^
-      chunkType.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SemanticChunk &&
-          runtimeType == other.runtimeType &&
-          index == other.index &&
-          content == other.content &&
-          startPos == other.startPos &&
-          endPos == other.endPos &&
-          chunkType == other.chunkType;
-}
+class SemanticChunk  {
+                final int index;
+final String content;
+/// Char offset of `content` in the source text.
+final int startPos;
+/// Char offset one past the end of `content`.
+final int endPos;
+final String chunkType;
+/// Dominant language code ("ko", "en", ...; see `detect_language`).
+final String language;
+
+                const SemanticChunk({required this.index ,required this.content ,required this.startPos ,required this.endPos ,required this.chunkType ,required this.language ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^content.hashCode^startPos.hashCode^endPos.hashCode^chunkType.hashCode^language.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SemanticChunk &&
+                runtimeType == other.runtimeType
+                && index == other.index&& content == other.content&& startPos == other.startPos&& endPos == other.endPos&& chunkType == other.chunkType&& language == other.language;
+        
+            }
 
 /// Structured chunk with header path for context inheritance.
-class StructuredChunk {
-  final int index;
-  final String content;
-  final String headerPath;
-  final String chunkType;
-  final int startPos;
-  final int endPos;
-  final String? batchId;
-  final int? batchIndex;
-  final int? batchTotal;
-
-  const StructuredChunk({
-    required this.index,
-    required this.content,
-    required this.headerPath,
-    required this.chunkType,
-    required this.startPos,
-    required this.endPos,
-    this.batchId,
-    this.batchIndex,
-    this.batchTotal,
-  });
-
-  @override
-  int get hashCode =>
-      index.hashCode ^
-      content.hashCode ^
-      headerPath.hashCode ^
-      chunkType.hashCode ^
-      startPos.hashCode ^
-      endPos.hashCode ^
-      batchId.hashCode ^
-      batchIndex.hashCode ^
-      batchTotal.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is StructuredChunk &&
-          runtimeType == other.runtimeType &&
-          index == other.index &&
-          content == other.content &&
-          headerPath == other.headerPath &&
-          chunkType == other.chunkType &&
-          startPos == other.startPos &&
-          endPos == other.endPos &&
-          batchId == other.batchId &&
-          batchIndex == other.batchIndex &&
-          batchTotal == other.batchTotal;
-}
+class StructuredChunk  {
+                final int index;
+final String content;
+final String headerPath;
+final String chunkType;
+/// Char offset of `content` in the source text. Summary and
+/// `table_text` chunks span the section or table they were built from.
+final int startPos;
+/// Char offset one past the end of the chunk's span.
+final int endPos;
+final String? batchId;
+final int? batchIndex;
+final int? batchTotal;
+final String language;
+
+                const StructuredChunk({required this.index ,required this.content ,required this.headerPath ,required this.chunkType ,required this.startPos ,required this.endPos ,this.batchId ,this.batchIndex ,this.batchTotal ,required this.language ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^content.hashCode^headerPath.hashCode^chunkType.hashCode^startPos.hashCode^endPos.hashCode^batchId.hashCode^batchIndex.hashCode^batchTotal.hashCode^language.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StructuredChunk &&
+                runtimeType == other.runtimeType
+                && index == other.index&& content == other.content&& headerPath == other.headerPath&& chunkType == other.chunkType&& startPos == other.startPos&& endPos == other.endPos&& batchId == other.batchId&& batchIndex == other.batchIndex&& batchTotal == other.batchTotal&& language == other.language;
+        
+            }
+            
\ No newline at end of file
@@ -6,6 +6,11 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Simple greeting function for FRB demo.
-String greet({required String name}) =>
-    RustLib.instance.api.crateApiSimpleGreet(name: name);
+
+            
+
+            /// Simple greeting function for FRB demo.
+String  greet({required String name }) => RustLib.instance.api.crateApiSimpleGreet(name: name);
+
+            
+            
\ No newline at end of file
@@ -4,87 +4,68 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `calculate_content_hash`, `rebuild_bm25_index_internal`, `rebuild_hnsw_index_internal`, `search_with_hnsw`, `search_with_linear_scan`, `truncate_str`
+
+            // These functions are ignored because they are not marked as `pub`: `calculate_content_hash`, `rebuild_bm25_index_internal`, `rebuild_hnsw_index_internal`, `search_with_hnsw`, `search_with_linear_scan`, `truncate_str`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
-/// Calculate cosine similarity between two vectors.
-double calculateCosineSimilarity({
-  required List<double> vecA,
-  required List<double> vecB,
-}) => RustLib.instance.api.crateApiSimpleRagCalculateCosineSimilarity(
-  vecA: vecA,
-  vecB: vecB,
-);
+
+            /// Calculate cosine similarity between two vectors.
+double  calculateCosineSimilarity({required List<double> vecA , required List<double> vecB }) => RustLib.instance.api.crateApiSimpleRagCalculateCosineSimilarity(vecA: vecA, vecB: vecB);
 
 /// Initialize database with docs table.
-Future<void> initDb() => RustLib.instance.api.crateApiSimpleRagInitDb();
+Future<void>  initDb() => RustLib.instance.api.crateApiSimpleRagInitDb();
 
 /// Rebuild HNSW index.
-Future<void> rebuildHnswIndex() =>
-    RustLib.instance.api.crateApiSimpleRagRebuildHnswIndex();
+Future<void>  rebuildHnswIndex() => RustLib.instance.api.crateApiSimpleRagRebuildHnswIndex();
 
 /// Rebuild BM25 index.
-Future<void> rebuildBm25Index() =>
-    RustLib.instance.api.crateApiSimpleRagRebuildBm25Index();
+Future<void>  rebuildBm25Index() => RustLib.instance.api.crateApiSimpleRagRebuildBm25Index();
 
 /// Add document with embedding vector (with deduplication).
-Future<AddDocumentResult> addDocument({
-  required String content,
-  required List<double> embedding,
-}) => RustLib.instance.api.crateApiSimpleRagAddDocument(
-  content: content,
-  embedding: embedding,
-);
+Future<AddDocumentResult>  addDocument({required String content , required List<double> embedding }) => RustLib.instance.api.crateApiSimpleRagAddDocument(content: content, embedding: embedding);
+
+/// Replace a document's content and re-index it for BM25, including the
+/// named BM25 indexes that hold it. The embedding is kept. Returns false
+/// if no document has this id.
+Future<bool>  updateDocument({required PlatformInt64 docId , required String content }) => RustLib.instance.api.crateApiSimpleRagUpdateDocument(docId: docId, content: content);
 
 /// Legacy add_document for backward compatibility.
-Future<void> addDocumentSimple({
-  required String content,
-  required List<double> embedding,
-}) => RustLib.instance.api.crateApiSimpleRagAddDocumentSimple(
-  content: content,
-  embedding: embedding,
-);
+Future<void>  addDocumentSimple({required String content , required List<double> embedding }) => RustLib.instance.api.crateApiSimpleRagAddDocumentSimple(content: content, embedding: embedding);
 
 /// Similarity-based search (uses HNSW).
-Future<List<String>> searchSimilar({
-  required List<double> queryEmbedding,
-  required int topK,
-}) => RustLib.instance.api.crateApiSimpleRagSearchSimilar(
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-);
+Future<List<String>>  searchSimilar({required List<double> queryEmbedding , required int topK }) => RustLib.instance.api.crateApiSimpleRagSearchSimilar(queryEmbedding: queryEmbedding, topK: topK);
 
 /// Get document count.
-Future<PlatformInt64> getDocumentCount() =>
-    RustLib.instance.api.crateApiSimpleRagGetDocumentCount();
+Future<PlatformInt64>  getDocumentCount() => RustLib.instance.api.crateApiSimpleRagGetDocumentCount();
 
 /// Clear all documents.
-Future<void> clearAllDocuments() =>
-    RustLib.instance.api.crateApiSimpleRagClearAllDocuments();
-
-class AddDocumentResult {
-  final bool success;
-  final bool isDuplicate;
-  final String message;
-
-  const AddDocumentResult({
-    required this.success,
-    required this.isDuplicate,
-    required this.message,
-  });
-
-  @override
-  int get hashCode =>
-      success.hashCode ^ isDuplicate.hashCode ^ message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is AddDocumentResult &&
-          runtimeType == other.runtimeType &&
-          success == other.success &&
-          isDuplicate == other.isDuplicate &&
-          message == other.message;
-}
+Future<void>  clearAllDocuments() => RustLib.instance.api.crateApiSimpleRagClearAllDocuments();
+
+            class AddDocumentResult  {
+                final bool success;
+final bool isDuplicate;
+final String message;
+
+                const AddDocumentResult({required this.success ,required this.isDuplicate ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => success.hashCode^isDuplicate.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AddDocumentResult &&
+                runtimeType == other.runtimeType
+                && success == other.success&& isDuplicate == other.isDuplicate&& message == other.message;
+        
+            }
+            
\ No newline at end of file
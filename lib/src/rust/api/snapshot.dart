@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `copy_hashed`, `describe_file`, `entry_path`, `index_error`, `io_error`, `read_archive`, `restore_indexes`, `restore_staged`, `scratch_dir`, `stage_entries`, `write_archive`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Write a snapshot of the whole engine (all tenants) to `path`.
+///
+/// The archive is written to a temporary file and renamed into place, so
+/// an interrupted call never leaves a truncated snapshot behind.
+Future<SnapshotManifest>  createSnapshot({required String path }) => RustLib.instance.api.crateApiSnapshotCreateSnapshot(path: path);
+
+/// Replace the engine state with the snapshot at `path`.
+///
+/// The archive is unpacked next to the database and fully verified before
+/// anything is touched. The pool is then closed once every connection is
+/// back (failing with `DatabaseBusy` if one stays in use), the database
+/// file is swapped and the pool re-opened with the same settings; HNSW
+/// indexes, BM25 namespaces and incremental buffers are replaced. Callers
+/// that persist HNSW files should save them again.
+Future<SnapshotManifest>  restoreSnapshot({required String path }) => RustLib.instance.api.crateApiSnapshotRestoreSnapshot(path: path);
+
+            class SnapshotFile  {
+                final String name;
+final BigInt size;
+final String sha256;
+
+                const SnapshotFile({required this.name ,required this.size ,required this.sha256 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^size.hashCode^sha256.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnapshotFile &&
+                runtimeType == other.runtimeType
+                && name == other.name&& size == other.size&& sha256 == other.sha256;
+        
+            }
+
+class SnapshotManifest  {
+                final int formatVersion;
+final String engineVersion;
+/// Seconds since the Unix epoch.
+final PlatformInt64 createdAt;
+final List<String> tenants;
+final PlatformInt64 sourceCount;
+final PlatformInt64 chunkCount;
+final List<SnapshotFile> files;
+
+                const SnapshotManifest({required this.formatVersion ,required this.engineVersion ,required this.createdAt ,required this.tenants ,required this.sourceCount ,required this.chunkCount ,required this.files ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => formatVersion.hashCode^engineVersion.hashCode^createdAt.hashCode^tenants.hashCode^sourceCount.hashCode^chunkCount.hashCode^files.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnapshotManifest &&
+                runtimeType == other.runtimeType
+                && formatVersion == other.formatVersion&& engineVersion == other.engineVersion&& createdAt == other.createdAt&& tenants == other.tenants&& sourceCount == other.sourceCount&& chunkCount == other.chunkCount&& files == other.files;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_snippet_with`, `build_snippet`, `find_hits`, `floor_char_boundary`, `is_cjk_word`, `utf16_len`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// UTF-16 range of a query-term hit.
+class HighlightSpan  {
+                final int start;
+final int end;
+
+                const HighlightSpan({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HighlightSpan &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+class SearchSnippet  {
+                final String text;
+/// UTF-16 range of `text` within the result's content.
+final int start;
+final int end;
+/// Query-term hits within `text` (UTF-16 offsets relative to `text`),
+/// in order and not overlapping.
+final List<HighlightSpan> highlights;
+
+                const SearchSnippet({required this.text ,required this.start ,required this.end ,required this.highlights ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^start.hashCode^end.hashCode^highlights.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchSnippet &&
+                runtimeType == other.runtimeType
+                && text == other.text&& start == other.start&& end == other.end&& highlights == other.highlights;
+        
+            }
+            
\ No newline at end of file
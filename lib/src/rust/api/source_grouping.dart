@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'hybrid_search.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'snippets.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `group_by_source`, `group_results`, `id_list`, `load_document_ids`, `load_source_names`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            class SourceGroup  {
+                /// The source's id, or the document's when `is_document`.
+final PlatformInt64 sourceId;
+/// Whether the group is a single Simple RAG document, not a source.
+final bool isDocument;
+final String? sourceName;
+/// Sum of the scores of `chunks`.
+final double score;
+/// The source's best chunks, best first.
+final List<HybridSearchResult> chunks;
+
+                const SourceGroup({required this.sourceId ,required this.isDocument ,this.sourceName ,required this.score ,required this.chunks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceId.hashCode^isDocument.hashCode^sourceName.hashCode^score.hashCode^chunks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceGroup &&
+                runtimeType == other.runtimeType
+                && sourceId == other.sourceId&& isDocument == other.isDocument&& sourceName == other.sourceName&& score == other.score&& chunks == other.chunks;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_source_index`, `clear_all_source_indexes`, `drop_source_index`, `drop_tenant_source_indexes`, `get`, `insert`, `search_source_indexes`, `source_fingerprint`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedIndex`, `SourceIndexCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            void  setSourceIndexConfig({required SourceIndexConfig config }) => RustLib.instance.api.crateApiSourceIndexSetSourceIndexConfig(config: config);
+
+SourceIndexConfig  getSourceIndexConfig() => RustLib.instance.api.crateApiSourceIndexGetSourceIndexConfig();
+
+/// Number of per-source sub-indexes currently cached.
+int  cachedSourceIndexCount() => RustLib.instance.api.crateApiSourceIndexCachedSourceIndexCount();
+
+            class SourceIndexConfig  {
+                final bool enabled;
+/// Sub-indexes kept in memory; the least recently used is evicted.
+final int maxCached;
+
+                const SourceIndexConfig({required this.enabled ,required this.maxCached ,});
+
+                static Future<SourceIndexConfig>  default_()=>RustLib.instance.api.crateApiSourceIndexSourceIndexConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^maxCached.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceIndexConfig &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& maxCached == other.maxCached;
+        
+            }
+            
\ No newline at end of file
@@ -5,293 +5,355 @@
 
 import '../frb_generated.dart';
 import 'error.dart';
+import 'hnsw_index.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `hash_content`, `search_chunks_linear`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// Initialize database with sources and chunks tables.
-Future<void> initSourceDb() =>
-    RustLib.instance.api.crateApiSourceRagInitSourceDb();
+            // These functions are ignored because they are not marked as `pub`: `add_source_with_chunks`, `backfill_chunk_languages`, `backfill_embedding_norms`, `chunk_page`, `deepen_chunks`, `find_source_by_content`, `hash_content`, `index_new_chunks`, `insert_chunk_rows`, `load_chunk_result`, `rebuild_tenant_chunk_bm25`, `rebuild_tenant_chunk_hnsw`, `record_chunk_search_event`, `search_chunks_linear`, `split_chunk_type`, `store_chunks`, `update_source_with_chunks`, `validate_offsets`, `write_embedding_updates`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChunkRow`, `KeptChunk`, `PagedChunkRanking`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Initialize database with sources and chunks tables.
+Future<void>  initSourceDb() => RustLib.instance.api.crateApiSourceRagInitSourceDb();
 
 /// Add a source document (chunks added separately via add_chunks).
-Future<AddSourceResult> addSource({
-  required String content,
-  String? metadata,
-  String? name,
-}) => RustLib.instance.api.crateApiSourceRagAddSource(
-  content: content,
-  metadata: metadata,
-  name: name,
-);
+Future<AddSourceResult>  addSource({required String content , String? metadata , String? name }) => RustLib.instance.api.crateApiSourceRagAddSource(content: content, metadata: metadata, name: name);
 
 /// Update processing status of a source (e.g., 'pending', 'processing', 'completed', 'failed').
-Future<void> updateSourceStatus({
-  required PlatformInt64 sourceId,
-  required String status,
-}) => RustLib.instance.api.crateApiSourceRagUpdateSourceStatus(
-  sourceId: sourceId,
-  status: status,
-);
+Future<void>  updateSourceStatus({required PlatformInt64 sourceId , required String status }) => RustLib.instance.api.crateApiSourceRagUpdateSourceStatus(sourceId: sourceId, status: status);
 
-Future<List<SourceEntry>> listSources() =>
-    RustLib.instance.api.crateApiSourceRagListSources();
+Future<List<SourceEntry>>  listSources() => RustLib.instance.api.crateApiSourceRagListSources();
 
 /// Add chunks for a source (uses transaction for atomicity).
-Future<int> addChunks({
-  required PlatformInt64 sourceId,
-  required List<ChunkData> chunks,
-}) => RustLib.instance.api.crateApiSourceRagAddChunks(
-  sourceId: sourceId,
-  chunks: chunks,
-);
+Future<int>  addChunks({required PlatformInt64 sourceId , required List<ChunkData> chunks }) => RustLib.instance.api.crateApiSourceRagAddChunks(sourceId: sourceId, chunks: chunks);
+
+/// Add chunks whose embeddings arrive as one packed buffer.
+///
+/// Chunk `i`'s embedding is `embeddings[offsets[i]..offsets[i + 1]]`, so
+/// `offsets` has `chunks.len() + 1` entries and ends at `embeddings.len()`.
+/// One `Float32List` crosses the FFI instead of a list per chunk, which
+/// cuts copies and allocations for bulk ingestion.
+Future<int>  addChunksPacked({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets }) => RustLib.instance.api.crateApiSourceRagAddChunksPacked(sourceId: sourceId, chunks: chunks, embeddings: embeddings, offsets: offsets);
 
 /// Rebuild HNSW index from chunks table.
-Future<void> rebuildChunkHnswIndex() =>
-    RustLib.instance.api.crateApiSourceRagRebuildChunkHnswIndex();
+///
+/// The result is also saved next to the database for `ensure_chunk_index`.
+Future<void>  rebuildChunkHnswIndex() => RustLib.instance.api.crateApiSourceRagRebuildChunkHnswIndex();
+
+/// Rebuild the chunk HNSW index if more than 20% of its points belong to
+/// deleted chunks, dropping them (see `delete_source`). Returns whether it
+/// rebuilt.
+Future<bool>  compactIndex() => RustLib.instance.api.crateApiSourceRagCompactIndex();
+
+/// Rebuild the chunk HNSW index with explicit build parameters (`None`
+/// returns to the adaptive ones); later rebuilds keep using them.
+Future<void>  rebuildChunkHnswIndexWithConfig({HnswBuildConfig? config }) => RustLib.instance.api.crateApiSourceRagRebuildChunkHnswIndexWithConfig(config: config);
 
 /// Rebuild BM25 index from chunks table.
-Future<void> rebuildChunkBm25Index() =>
-    RustLib.instance.api.crateApiSourceRagRebuildChunkBm25Index();
+Future<void>  rebuildChunkBm25Index() => RustLib.instance.api.crateApiSourceRagRebuildChunkBm25Index();
 
 /// Check if BM25 index is loaded for chunks.
-Future<bool> isChunkBm25IndexLoaded() =>
-    RustLib.instance.api.crateApiSourceRagIsChunkBm25IndexLoaded();
+Future<bool>  isChunkBm25IndexLoaded() => RustLib.instance.api.crateApiSourceRagIsChunkBm25IndexLoaded();
 
 /// Search chunks by embedding similarity.
-Future<List<ChunkSearchResult>> searchChunks({
-  required List<double> queryEmbedding,
-  required int topK,
-}) => RustLib.instance.api.crateApiSourceRagSearchChunks(
-  queryEmbedding: queryEmbedding,
-  topK: topK,
-);
+///
+/// `similarity` is `1 - distance` under the configured `DistanceMetric`.
+Future<List<ChunkSearchResult>>  searchChunks({required List<double> queryEmbedding , required int topK }) => RustLib.instance.api.crateApiSourceRagSearchChunks(queryEmbedding: queryEmbedding, topK: topK);
+
+/// `search_chunks` with MMR diversification (as with
+/// `HybridSearchOptions::diversity_lambda`): `diversity_lambda` 1.0 keeps
+/// the similarity order, lower values skip chunks that repeat ones
+/// already picked.
+Future<List<ChunkSearchResult>>  searchChunksDiverse({required List<double> queryEmbedding , required int topK , required double diversityLambda }) => RustLib.instance.api.crateApiSourceRagSearchChunksDiverse(queryEmbedding: queryEmbedding, topK: topK, diversityLambda: diversityLambda);
+
+/// `search_chunks` returning `page_size` results starting at `offset`,
+/// plus a cursor for the next page (see `HybridSearchOptions::page_offset`).
+Future<ChunkSearchPage>  searchChunksPaged({required List<double> queryEmbedding , required int pageSize , required int offset }) => RustLib.instance.api.crateApiSourceRagSearchChunksPaged(queryEmbedding: queryEmbedding, pageSize: pageSize, offset: offset);
+
+/// The page after the one `cursor` came with, never repeating results.
+Future<ChunkSearchPage>  searchChunksNextPage({required String cursor , required int pageSize }) => RustLib.instance.api.crateApiSourceRagSearchChunksNextPage(cursor: cursor, pageSize: pageSize);
 
 /// Get source document by ID.
-Future<String?> getSource({required PlatformInt64 sourceId}) =>
-    RustLib.instance.api.crateApiSourceRagGetSource(sourceId: sourceId);
+Future<String?>  getSource({required PlatformInt64 sourceId }) => RustLib.instance.api.crateApiSourceRagGetSource(sourceId: sourceId);
 
 /// Get all chunks for a source.
-Future<List<String>> getSourceChunks({required PlatformInt64 sourceId}) =>
-    RustLib.instance.api.crateApiSourceRagGetSourceChunks(sourceId: sourceId);
+Future<List<String>>  getSourceChunks({required PlatformInt64 sourceId }) => RustLib.instance.api.crateApiSourceRagGetSourceChunks(sourceId: sourceId);
 
 /// Get adjacent chunks by source_id and chunk_index range.
-Future<List<ChunkSearchResult>> getAdjacentChunks({
-  required PlatformInt64 sourceId,
-  required int minIndex,
-  required int maxIndex,
-}) => RustLib.instance.api.crateApiSourceRagGetAdjacentChunks(
-  sourceId: sourceId,
-  minIndex: minIndex,
-  maxIndex: maxIndex,
-);
+Future<List<ChunkSearchResult>>  getAdjacentChunks({required PlatformInt64 sourceId , required int minIndex , required int maxIndex }) => RustLib.instance.api.crateApiSourceRagGetAdjacentChunks(sourceId: sourceId, minIndex: minIndex, maxIndex: maxIndex);
 
 /// Delete a source and all its chunks.
-Future<void> deleteSource({required PlatformInt64 sourceId}) =>
-    RustLib.instance.api.crateApiSourceRagDeleteSource(sourceId: sourceId);
+Future<void>  deleteSource({required PlatformInt64 sourceId }) => RustLib.instance.api.crateApiSourceRagDeleteSource(sourceId: sourceId);
 
 /// Get the number of chunks for a specific source.
-Future<int> getSourceChunkCount({required PlatformInt64 sourceId}) => RustLib
-    .instance
-    .api
-    .crateApiSourceRagGetSourceChunkCount(sourceId: sourceId);
+Future<int>  getSourceChunkCount({required PlatformInt64 sourceId }) => RustLib.instance.api.crateApiSourceRagGetSourceChunkCount(sourceId: sourceId);
 
-Future<SourceStats> getSourceStats() =>
-    RustLib.instance.api.crateApiSourceRagGetSourceStats();
+Future<SourceStats>  getSourceStats() => RustLib.instance.api.crateApiSourceRagGetSourceStats();
 
 /// Get all chunk IDs and contents for re-embedding.
-Future<List<ChunkForReembedding>> getAllChunkIdsAndContents() =>
-    RustLib.instance.api.crateApiSourceRagGetAllChunkIdsAndContents();
+Future<List<ChunkForReembedding>>  getAllChunkIdsAndContents() => RustLib.instance.api.crateApiSourceRagGetAllChunkIdsAndContents();
+
+/// Replace the content of a chunk and re-index it for keyword search,
+/// entities and triples. The embedding is left as is; pass the new one
+/// to `update_chunk_embedding`. Returns false if the chunk does not exist
+/// in the active tenant.
+Future<bool>  updateChunkContent({required PlatformInt64 chunkId , required String content }) => RustLib.instance.api.crateApiSourceRagUpdateChunkContent(chunkId: chunkId, content: content);
 
 /// Update embedding for a single chunk.
-Future<void> updateChunkEmbedding({
-  required PlatformInt64 chunkId,
-  required List<double> embedding,
-}) => RustLib.instance.api.crateApiSourceRagUpdateChunkEmbedding(
-  chunkId: chunkId,
-  embedding: embedding,
-);
-
-class AddSourceResult {
-  final PlatformInt64 sourceId;
-  final bool isDuplicate;
-  final int chunkCount;
-  final String message;
-
-  const AddSourceResult({
-    required this.sourceId,
-    required this.isDuplicate,
-    required this.chunkCount,
-    required this.message,
-  });
-
-  @override
-  int get hashCode =>
-      sourceId.hashCode ^
-      isDuplicate.hashCode ^
-      chunkCount.hashCode ^
-      message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is AddSourceResult &&
-          runtimeType == other.runtimeType &&
-          sourceId == other.sourceId &&
-          isDuplicate == other.isDuplicate &&
-          chunkCount == other.chunkCount &&
-          message == other.message;
-}
-
-class ChunkData {
-  final String content;
-  final int chunkIndex;
-  final int startPos;
-  final int endPos;
-  final String chunkType;
-  final Float32List embedding;
-
-  const ChunkData({
-    required this.content,
-    required this.chunkIndex,
-    required this.startPos,
-    required this.endPos,
-    required this.chunkType,
-    required this.embedding,
-  });
-
-  @override
-  int get hashCode =>
-      content.hashCode ^
-      chunkIndex.hashCode ^
-      startPos.hashCode ^
-      endPos.hashCode ^
-      chunkType.hashCode ^
-      embedding.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ChunkData &&
-          runtimeType == other.runtimeType &&
-          content == other.content &&
-          chunkIndex == other.chunkIndex &&
-          startPos == other.startPos &&
-          endPos == other.endPos &&
-          chunkType == other.chunkType &&
-          embedding == other.embedding;
-}
-
-class ChunkForReembedding {
-  final PlatformInt64 chunkId;
-  final String content;
-
-  const ChunkForReembedding({required this.chunkId, required this.content});
-
-  @override
-  int get hashCode => chunkId.hashCode ^ content.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ChunkForReembedding &&
-          runtimeType == other.runtimeType &&
-          chunkId == other.chunkId &&
-          content == other.content;
-}
-
-class ChunkSearchResult {
-  final PlatformInt64 chunkId;
-  final PlatformInt64 sourceId;
-  final int chunkIndex;
-  final String content;
-  final String chunkType;
-  final double similarity;
-  final String? metadata;
-
-  const ChunkSearchResult({
-    required this.chunkId,
-    required this.sourceId,
-    required this.chunkIndex,
-    required this.content,
-    required this.chunkType,
-    required this.similarity,
-    this.metadata,
-  });
-
-  @override
-  int get hashCode =>
-      chunkId.hashCode ^
-      sourceId.hashCode ^
-      chunkIndex.hashCode ^
-      content.hashCode ^
-      chunkType.hashCode ^
-      similarity.hashCode ^
-      metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ChunkSearchResult &&
-          runtimeType == other.runtimeType &&
-          chunkId == other.chunkId &&
-          sourceId == other.sourceId &&
-          chunkIndex == other.chunkIndex &&
-          content == other.content &&
-          chunkType == other.chunkType &&
-          similarity == other.similarity &&
-          metadata == other.metadata;
-}
-
-class SourceEntry {
-  final PlatformInt64 id;
-  final String? name;
-  final PlatformInt64 createdAt;
-  final String? metadata;
-  final String? status;
-
-  const SourceEntry({
-    required this.id,
-    this.name,
-    required this.createdAt,
-    this.metadata,
-    this.status,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      name.hashCode ^
-      createdAt.hashCode ^
-      metadata.hashCode ^
-      status.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SourceEntry &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          createdAt == other.createdAt &&
-          metadata == other.metadata &&
-          status == other.status;
-}
-
-class SourceStats {
-  final PlatformInt64 sourceCount;
-  final PlatformInt64 chunkCount;
-
-  const SourceStats({required this.sourceCount, required this.chunkCount});
-
-  @override
-  int get hashCode => sourceCount.hashCode ^ chunkCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SourceStats &&
-          runtimeType == other.runtimeType &&
-          sourceCount == other.sourceCount &&
-          chunkCount == other.chunkCount;
-}
+Future<void>  updateChunkEmbedding({required PlatformInt64 chunkId , required List<double> embedding }) => RustLib.instance.api.crateApiSourceRagUpdateChunkEmbedding(chunkId: chunkId, embedding: embedding);
+
+/// Update many chunk embeddings in one transaction: either all rows are
+/// written or none are. After commit the active tenant's in-memory HNSW
+/// index and incremental buffer are dropped so the next rebuild picks up
+/// the new vectors, then `on_progress` reports the finished batch. It is
+/// never called inside the transaction, so a slow callback can't hold the
+/// write lock and a retried write can't report twice.
+/// Returns the number of chunks updated (ids of other tenants or deleted
+/// chunks are skipped).
+Future<int>  updateChunkEmbeddingsBatch({required List<(PlatformInt64,Float32List)> updates , required FutureOr<void> Function(EmbeddingBatchProgress) onProgress }) => RustLib.instance.api.crateApiSourceRagUpdateChunkEmbeddingsBatch(updates: updates, onProgress: onProgress);
+
+            class AddSourceResult  {
+                final PlatformInt64 sourceId;
+final bool isDuplicate;
+final int chunkCount;
+final String message;
+
+                const AddSourceResult({required this.sourceId ,required this.isDuplicate ,required this.chunkCount ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceId.hashCode^isDuplicate.hashCode^chunkCount.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AddSourceResult &&
+                runtimeType == other.runtimeType
+                && sourceId == other.sourceId&& isDuplicate == other.isDuplicate&& chunkCount == other.chunkCount&& message == other.message;
+        
+            }
+
+class ChunkData  {
+                final String content;
+final int chunkIndex;
+final int startPos;
+final int endPos;
+/// May carry a markdown header path as "type|header path"; it is
+/// stored in its own column.
+final String chunkType;
+final Float32List embedding;
+/// Shared by the sibling chunks of a split code block.
+final String? batchId;
+
+                const ChunkData({required this.content ,required this.chunkIndex ,required this.startPos ,required this.endPos ,required this.chunkType ,required this.embedding ,this.batchId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => content.hashCode^chunkIndex.hashCode^startPos.hashCode^endPos.hashCode^chunkType.hashCode^embedding.hashCode^batchId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkData &&
+                runtimeType == other.runtimeType
+                && content == other.content&& chunkIndex == other.chunkIndex&& startPos == other.startPos&& endPos == other.endPos&& chunkType == other.chunkType&& embedding == other.embedding&& batchId == other.batchId;
+        
+            }
+
+class ChunkForReembedding  {
+                final PlatformInt64 chunkId;
+final String content;
+
+                const ChunkForReembedding({required this.chunkId ,required this.content ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chunkId.hashCode^content.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkForReembedding &&
+                runtimeType == other.runtimeType
+                && chunkId == other.chunkId&& content == other.content;
+        
+            }
+
+/// Chunk fields without the embedding, for `add_chunks_packed`.
+class ChunkMeta  {
+                final String content;
+final int chunkIndex;
+final int startPos;
+final int endPos;
+final String chunkType;
+final String? batchId;
+
+                const ChunkMeta({required this.content ,required this.chunkIndex ,required this.startPos ,required this.endPos ,required this.chunkType ,this.batchId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => content.hashCode^chunkIndex.hashCode^startPos.hashCode^endPos.hashCode^chunkType.hashCode^batchId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkMeta &&
+                runtimeType == other.runtimeType
+                && content == other.content&& chunkIndex == other.chunkIndex&& startPos == other.startPos&& endPos == other.endPos&& chunkType == other.chunkType&& batchId == other.batchId;
+        
+            }
+
+/// One page of `search_chunks_paged` results.
+class ChunkSearchPage  {
+                final List<ChunkSearchResult> results;
+/// Pass to `search_chunks_next_page` for the following page; `None`
+/// once there are no more results.
+final String? nextCursor;
+
+                const ChunkSearchPage({required this.results ,this.nextCursor ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => results.hashCode^nextCursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkSearchPage &&
+                runtimeType == other.runtimeType
+                && results == other.results&& nextCursor == other.nextCursor;
+        
+            }
+
+class ChunkSearchResult  {
+                final PlatformInt64 chunkId;
+final PlatformInt64 sourceId;
+final int chunkIndex;
+final String content;
+final String chunkType;
+/// Markdown header path ("Setup > Install") of the chunk, if any.
+final String? headerPath;
+final double similarity;
+final String? metadata;
+
+                const ChunkSearchResult({required this.chunkId ,required this.sourceId ,required this.chunkIndex ,required this.content ,required this.chunkType ,this.headerPath ,required this.similarity ,this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chunkId.hashCode^sourceId.hashCode^chunkIndex.hashCode^content.hashCode^chunkType.hashCode^headerPath.hashCode^similarity.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkSearchResult &&
+                runtimeType == other.runtimeType
+                && chunkId == other.chunkId&& sourceId == other.sourceId&& chunkIndex == other.chunkIndex&& content == other.content&& chunkType == other.chunkType&& headerPath == other.headerPath&& similarity == other.similarity&& metadata == other.metadata;
+        
+            }
+
+class EmbeddingBatchProgress  {
+                final int done;
+final int total;
+
+                const EmbeddingBatchProgress({required this.done ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => done.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingBatchProgress &&
+                runtimeType == other.runtimeType
+                && done == other.done&& total == other.total;
+        
+            }
+
+class SourceEntry  {
+                final PlatformInt64 id;
+final String? name;
+final PlatformInt64 createdAt;
+final String? metadata;
+final String? status;
+
+                const SourceEntry({required this.id ,this.name ,required this.createdAt ,this.metadata ,this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^createdAt.hashCode^metadata.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceEntry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& createdAt == other.createdAt&& metadata == other.metadata&& status == other.status;
+        
+            }
+
+class SourceStats  {
+                final PlatformInt64 sourceCount;
+final PlatformInt64 chunkCount;
+
+                const SourceStats({required this.sourceCount ,required this.chunkCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceCount.hashCode^chunkCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceStats &&
+                runtimeType == other.runtimeType
+                && sourceCount == other.sourceCount&& chunkCount == other.chunkCount;
+        
+            }
+            
\ No newline at end of file
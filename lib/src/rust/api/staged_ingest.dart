@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'ingest.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'semantic_chunker.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_staging_tables`, `delete_staging`, `delete_tenant_staging`, `match_chunks`, `now_secs`, `purge_expired`, `stage`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `StagedChunk`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Chunk `text` and stage the chunks; embed the returned texts and pass
+/// the vectors to `commit_ingest`. Nothing is searchable until then.
+Future<StagedIngest>  prepareIngest({required String text , required IngestOptions options }) => RustLib.instance.api.crateApiStagedIngestPrepareIngest(text: text, options: options);
+
+/// Re-chunk source `source_id` with `new_content` and stage the update.
+/// Unchanged chunks keep their embeddings, so only the returned `chunks`
+/// need embedding before `commit_ingest`. The source stays as it is until
+/// the commit. `options.metadata` and `options.name` replace the stored
+/// ones when set; use the chunking options the source was ingested with,
+/// or few chunks will match.
+Future<SourceUpdate>  updateSource({required PlatformInt64 sourceId , required String newContent , required IngestOptions options }) => RustLib.instance.api.crateApiStagedIngestUpdateSource(sourceId: sourceId, newContent: newContent, options: options);
+
+/// Store the staged document with `embeddings` (one per returned chunk
+/// text, in order, all of one dimension) in a single transaction. The
+/// staging is kept if the commit fails, so it can be retried until it
+/// expires.
+Future<IngestReport>  commitIngest({required String stagingId , required List<Float32List> embeddings }) => RustLib.instance.api.crateApiStagedIngestCommitIngest(stagingId: stagingId, embeddings: embeddings);
+
+/// Drop a staged document without storing it; false if unknown.
+Future<bool>  cancelIngest({required String stagingId }) => RustLib.instance.api.crateApiStagedIngestCancelIngest(stagingId: stagingId);
+
+            class SourceUpdate  {
+                /// Pass to `commit_ingest` or `cancel_ingest`.
+final String stagingId;
+/// Texts of the new or changed chunks, in order; `commit_ingest`
+/// expects one embedding per entry (none when this is empty).
+final List<String> chunks;
+/// Unchanged chunks that keep their embeddings.
+final int keptChunks;
+/// Existing chunks the commit deletes.
+final int removedChunks;
+/// Exact and near-duplicate chunks dropped before staging.
+final int skippedChunks;
+/// Seconds since the Unix epoch after which the staging is dropped.
+final PlatformInt64 expiresAt;
+
+                const SourceUpdate({required this.stagingId ,required this.chunks ,required this.keptChunks ,required this.removedChunks ,required this.skippedChunks ,required this.expiresAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stagingId.hashCode^chunks.hashCode^keptChunks.hashCode^removedChunks.hashCode^skippedChunks.hashCode^expiresAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SourceUpdate &&
+                runtimeType == other.runtimeType
+                && stagingId == other.stagingId&& chunks == other.chunks&& keptChunks == other.keptChunks&& removedChunks == other.removedChunks&& skippedChunks == other.skippedChunks&& expiresAt == other.expiresAt;
+        
+            }
+
+class StagedIngest  {
+                /// Pass to `commit_ingest` or `cancel_ingest`.
+final String stagingId;
+/// Chunk texts to embed, in order; `commit_ingest` expects one
+/// embedding per entry.
+final List<String> chunks;
+/// Exact and near-duplicate chunks dropped before staging.
+final int skippedChunks;
+/// Seconds since the Unix epoch after which the staging is dropped.
+final PlatformInt64 expiresAt;
+
+                const StagedIngest({required this.stagingId ,required this.chunks ,required this.skippedChunks ,required this.expiresAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stagingId.hashCode^chunks.hashCode^skippedChunks.hashCode^expiresAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StagedIngest &&
+                runtimeType == other.runtimeType
+                && stagingId == other.stagingId&& chunks == other.chunks&& skippedChunks == other.skippedChunks&& expiresAt == other.expiresAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `canonical`, `create_synonyms_table`, `drop_tenant_synonyms`, `expand_query`, `expansions_for`, `load_synonyms`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Add `expansions` for `term` in the active tenant; existing pairs are
+/// kept. Returns how many pairs were new.
+Future<int>  addSynonyms({required String term , required List<String> expansions }) => RustLib.instance.api.crateApiSynonymsAddSynonyms(term: term, expansions: expansions);
+
+/// Remove `expansions` of `term` in the active tenant, or all of them
+/// when `None`. Returns how many pairs were removed.
+Future<int>  removeSynonyms({required String term , List<String>? expansions }) => RustLib.instance.api.crateApiSynonymsRemoveSynonyms(term: term, expansions: expansions);
+
+/// The active tenant's entries, sorted by term.
+List<SynonymEntry>  listSynonyms() => RustLib.instance.api.crateApiSynonymsListSynonyms();
+
+            class SynonymEntry  {
+                final String term;
+final List<String> expansions;
+
+                const SynonymEntry({required this.term ,required this.expansions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => term.hashCode^expansions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SynonymEntry &&
+                runtimeType == other.runtimeType
+                && term == other.term&& expansions == other.expansions;
+        
+            }
+            
\ No newline at end of file
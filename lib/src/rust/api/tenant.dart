@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active_tenant`, `tenant_condition`, `tenant_index_path`, `validate_tenant_id`
+
+
+            /// Switch the active tenant. Indexes of other tenants stay in memory.
+void  setActiveTenant({required String tenantId }) => RustLib.instance.api.crateApiTenantSetActiveTenant(tenantId: tenantId);
+
+String  getActiveTenant() => RustLib.instance.api.crateApiTenantGetActiveTenant();
+
+/// Tenants that own at least one source, plus the active tenant.
+Future<List<String>>  listTenants() => RustLib.instance.api.crateApiTenantListTenants();
+
+/// Delete every source, chunk, chat message, saved query and search
+/// analytics record of a tenant and drop its indexes.
+Future<void>  deleteTenant({required String tenantId }) => RustLib.instance.api.crateApiTenantDeleteTenant(tenantId: tenantId);
+
+            
+            
\ No newline at end of file
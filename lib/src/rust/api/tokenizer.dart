@@ -4,21 +4,24 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Initialize tokenizer with tokenizer.json file path.
-Future<void> initTokenizer({required String tokenizerPath}) => RustLib
-    .instance
-    .api
-    .crateApiTokenizerInitTokenizer(tokenizerPath: tokenizerPath);
+
+            // These functions are ignored because they are not marked as `pub`: `count_tokens`, `loaded_tokenizer_name`, `not_initialized`, `resolve_truncation_max_length`, `tokenizer_name`
+
+
+            /// Initialize tokenizer with tokenizer.json file path.
+Future<void>  initTokenizer({required String tokenizerPath }) => RustLib.instance.api.crateApiTokenizerInitTokenizer(tokenizerPath: tokenizerPath);
 
 /// Tokenize text (returns token IDs with CLS/SEP tokens).
-Uint32List tokenize({required String text}) =>
-    RustLib.instance.api.crateApiTokenizerTokenize(text: text);
+Uint32List  tokenize({required String text }) => RustLib.instance.api.crateApiTokenizerTokenize(text: text);
 
 /// Decode token IDs to text.
-String decodeTokens({required List<int> tokenIds}) =>
-    RustLib.instance.api.crateApiTokenizerDecodeTokens(tokenIds: tokenIds);
+String  decodeTokens({required List<int> tokenIds }) => RustLib.instance.api.crateApiTokenizerDecodeTokens(tokenIds: tokenIds);
 
 /// Get vocab size.
-int getVocabSize() => RustLib.instance.api.crateApiTokenizerGetVocabSize();
+int  getVocabSize() => RustLib.instance.api.crateApiTokenizerGetVocabSize();
+
+            
+            
\ No newline at end of file
@@ -55,7 +55,7 @@ extension UserIntentPatterns on UserIntent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( UserIntent_Summary value)?  summary,TResult Function( UserIntent_Define value)?  define,TResult Function( UserIntent_ExpandKnowledge value)?  expandKnowledge,TResult Function( UserIntent_General value)?  general,TResult Function( UserIntent_InvalidCommand value)?  invalidCommand,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( UserIntent_Summary value)?  summary,TResult Function( UserIntent_Define value)?  define,TResult Function( UserIntent_ExpandKnowledge value)?  expandKnowledge,TResult Function( UserIntent_General value)?  general,TResult Function( UserIntent_InvalidCommand value)?  invalidCommand,TResult Function( UserIntent_Compare value)?  compare,TResult Function( UserIntent_Cite value)?  cite,TResult Function( UserIntent_Sources value)?  sources,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case UserIntent_Summary() when summary != null:
@@ -63,7 +63,10 @@ return summary(_that);case UserIntent_Define() when define != null:
 return define(_that);case UserIntent_ExpandKnowledge() when expandKnowledge != null:
 return expandKnowledge(_that);case UserIntent_General() when general != null:
 return general(_that);case UserIntent_InvalidCommand() when invalidCommand != null:
-return invalidCommand(_that);case _:
+return invalidCommand(_that);case UserIntent_Compare() when compare != null:
+return compare(_that);case UserIntent_Cite() when cite != null:
+return cite(_that);case UserIntent_Sources() when sources != null:
+return sources(_that);case _:
   return orElse();
 
 }
@@ -81,7 +84,7 @@ return invalidCommand(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( UserIntent_Summary value)  summary,required TResult Function( UserIntent_Define value)  define,required TResult Function( UserIntent_ExpandKnowledge value)  expandKnowledge,required TResult Function( UserIntent_General value)  general,required TResult Function( UserIntent_InvalidCommand value)  invalidCommand,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( UserIntent_Summary value)  summary,required TResult Function( UserIntent_Define value)  define,required TResult Function( UserIntent_ExpandKnowledge value)  expandKnowledge,required TResult Function( UserIntent_General value)  general,required TResult Function( UserIntent_InvalidCommand value)  invalidCommand,required TResult Function( UserIntent_Compare value)  compare,required TResult Function( UserIntent_Cite value)  cite,required TResult Function( UserIntent_Sources value)  sources,}){
 final _that = this;
 switch (_that) {
 case UserIntent_Summary():
@@ -89,7 +92,10 @@ return summary(_that);case UserIntent_Define():
 return define(_that);case UserIntent_ExpandKnowledge():
 return expandKnowledge(_that);case UserIntent_General():
 return general(_that);case UserIntent_InvalidCommand():
-return invalidCommand(_that);}
+return invalidCommand(_that);case UserIntent_Compare():
+return compare(_that);case UserIntent_Cite():
+return cite(_that);case UserIntent_Sources():
+return sources(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -103,7 +109,7 @@ return invalidCommand(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( UserIntent_Summary value)?  summary,TResult? Function( UserIntent_Define value)?  define,TResult? Function( UserIntent_ExpandKnowledge value)?  expandKnowledge,TResult? Function( UserIntent_General value)?  general,TResult? Function( UserIntent_InvalidCommand value)?  invalidCommand,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( UserIntent_Summary value)?  summary,TResult? Function( UserIntent_Define value)?  define,TResult? Function( UserIntent_ExpandKnowledge value)?  expandKnowledge,TResult? Function( UserIntent_General value)?  general,TResult? Function( UserIntent_InvalidCommand value)?  invalidCommand,TResult? Function( UserIntent_Compare value)?  compare,TResult? Function( UserIntent_Cite value)?  cite,TResult? Function( UserIntent_Sources value)?  sources,}){
 final _that = this;
 switch (_that) {
 case UserIntent_Summary() when summary != null:
@@ -111,7 +117,10 @@ return summary(_that);case UserIntent_Define() when define != null:
 return define(_that);case UserIntent_ExpandKnowledge() when expandKnowledge != null:
 return expandKnowledge(_that);case UserIntent_General() when general != null:
 return general(_that);case UserIntent_InvalidCommand() when invalidCommand != null:
-return invalidCommand(_that);case _:
+return invalidCommand(_that);case UserIntent_Compare() when compare != null:
+return compare(_that);case UserIntent_Cite() when cite != null:
+return cite(_that);case UserIntent_Sources() when sources != null:
+return sources(_that);case _:
   return null;
 
 }
@@ -128,14 +137,17 @@ return invalidCommand(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String query)?  summary,TResult Function( String term)?  define,TResult Function( String query)?  expandKnowledge,TResult Function( String query)?  general,TResult Function( String command,  String reason)?  invalidCommand,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String query)?  summary,TResult Function( String term)?  define,TResult Function( String query)?  expandKnowledge,TResult Function( String query)?  general,TResult Function( String command,  String reason)?  invalidCommand,TResult Function( String left,  String right)?  compare,TResult Function( String query)?  cite,TResult Function( String query)?  sources,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case UserIntent_Summary() when summary != null:
 return summary(_that.query);case UserIntent_Define() when define != null:
 return define(_that.term);case UserIntent_ExpandKnowledge() when expandKnowledge != null:
 return expandKnowledge(_that.query);case UserIntent_General() when general != null:
 return general(_that.query);case UserIntent_InvalidCommand() when invalidCommand != null:
-return invalidCommand(_that.command,_that.reason);case _:
+return invalidCommand(_that.command,_that.reason);case UserIntent_Compare() when compare != null:
+return compare(_that.left,_that.right);case UserIntent_Cite() when cite != null:
+return cite(_that.query);case UserIntent_Sources() when sources != null:
+return sources(_that.query);case _:
   return orElse();
 
 }
@@ -153,14 +165,17 @@ return invalidCommand(_that.command,_that.reason);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String query)  summary,required TResult Function( String term)  define,required TResult Function( String query)  expandKnowledge,required TResult Function( String query)  general,required TResult Function( String command,  String reason)  invalidCommand,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String query)  summary,required TResult Function( String term)  define,required TResult Function( String query)  expandKnowledge,required TResult Function( String query)  general,required TResult Function( String command,  String reason)  invalidCommand,required TResult Function( String left,  String right)  compare,required TResult Function( String query)  cite,required TResult Function( String query)  sources,}) {final _that = this;
 switch (_that) {
 case UserIntent_Summary():
 return summary(_that.query);case UserIntent_Define():
 return define(_that.term);case UserIntent_ExpandKnowledge():
 return expandKnowledge(_that.query);case UserIntent_General():
 return general(_that.query);case UserIntent_InvalidCommand():
-return invalidCommand(_that.command,_that.reason);}
+return invalidCommand(_that.command,_that.reason);case UserIntent_Compare():
+return compare(_that.left,_that.right);case UserIntent_Cite():
+return cite(_that.query);case UserIntent_Sources():
+return sources(_that.query);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -174,14 +189,17 @@ return invalidCommand(_that.command,_that.reason);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String query)?  summary,TResult? Function( String term)?  define,TResult? Function( String query)?  expandKnowledge,TResult? Function( String query)?  general,TResult? Function( String command,  String reason)?  invalidCommand,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String query)?  summary,TResult? Function( String term)?  define,TResult? Function( String query)?  expandKnowledge,TResult? Function( String query)?  general,TResult? Function( String command,  String reason)?  invalidCommand,TResult? Function( String left,  String right)?  compare,TResult? Function( String query)?  cite,TResult? Function( String query)?  sources,}) {final _that = this;
 switch (_that) {
 case UserIntent_Summary() when summary != null:
 return summary(_that.query);case UserIntent_Define() when define != null:
 return define(_that.term);case UserIntent_ExpandKnowledge() when expandKnowledge != null:
 return expandKnowledge(_that.query);case UserIntent_General() when general != null:
 return general(_that.query);case UserIntent_InvalidCommand() when invalidCommand != null:
-return invalidCommand(_that.command,_that.reason);case _:
+return invalidCommand(_that.command,_that.reason);case UserIntent_Compare() when compare != null:
+return compare(_that.left,_that.right);case UserIntent_Cite() when cite != null:
+return cite(_that.query);case UserIntent_Sources() when sources != null:
+return sources(_that.query);case _:
   return null;
 
 }
@@ -519,6 +537,206 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class UserIntent_Compare extends UserIntent {
+  const UserIntent_Compare({required this.left, required this.right}): super._();
+  
+
+ final  String left;
+ final  String right;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UserIntent_CompareCopyWith<UserIntent_Compare> get copyWith => _$UserIntent_CompareCopyWithImpl<UserIntent_Compare>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UserIntent_Compare&&(identical(other.left, left) || other.left == left)&&(identical(other.right, right) || other.right == right));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,left,right);
+
+@override
+String toString() {
+  return 'UserIntent.compare(left: $left, right: $right)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UserIntent_CompareCopyWith<$Res> implements $UserIntentCopyWith<$Res> {
+  factory $UserIntent_CompareCopyWith(UserIntent_Compare value, $Res Function(UserIntent_Compare) _then) = _$UserIntent_CompareCopyWithImpl;
+@useResult
+$Res call({
+ String left, String right
+});
+
+
+
+
+}
+/// @nodoc
+class _$UserIntent_CompareCopyWithImpl<$Res>
+    implements $UserIntent_CompareCopyWith<$Res> {
+  _$UserIntent_CompareCopyWithImpl(this._self, this._then);
+
+  final UserIntent_Compare _self;
+  final $Res Function(UserIntent_Compare) _then;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? left = null,Object? right = null,}) {
+  return _then(UserIntent_Compare(
+left: null == left ? _self.left : left // ignore: cast_nullable_to_non_nullable
+as String,right: null == right ? _self.right : right // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UserIntent_Cite extends UserIntent {
+  const UserIntent_Cite({required this.query}): super._();
+  
+
+ final  String query;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UserIntent_CiteCopyWith<UserIntent_Cite> get copyWith => _$UserIntent_CiteCopyWithImpl<UserIntent_Cite>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UserIntent_Cite&&(identical(other.query, query) || other.query == query));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,query);
+
+@override
+String toString() {
+  return 'UserIntent.cite(query: $query)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UserIntent_CiteCopyWith<$Res> implements $UserIntentCopyWith<$Res> {
+  factory $UserIntent_CiteCopyWith(UserIntent_Cite value, $Res Function(UserIntent_Cite) _then) = _$UserIntent_CiteCopyWithImpl;
+@useResult
+$Res call({
+ String query
+});
+
+
+
+
+}
+/// @nodoc
+class _$UserIntent_CiteCopyWithImpl<$Res>
+    implements $UserIntent_CiteCopyWith<$Res> {
+  _$UserIntent_CiteCopyWithImpl(this._self, this._then);
+
+  final UserIntent_Cite _self;
+  final $Res Function(UserIntent_Cite) _then;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? query = null,}) {
+  return _then(UserIntent_Cite(
+query: null == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class UserIntent_Sources extends UserIntent {
+  const UserIntent_Sources({required this.query}): super._();
+  
+
+ final  String query;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$UserIntent_SourcesCopyWith<UserIntent_Sources> get copyWith => _$UserIntent_SourcesCopyWithImpl<UserIntent_Sources>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is UserIntent_Sources&&(identical(other.query, query) || other.query == query));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,query);
+
+@override
+String toString() {
+  return 'UserIntent.sources(query: $query)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $UserIntent_SourcesCopyWith<$Res> implements $UserIntentCopyWith<$Res> {
+  factory $UserIntent_SourcesCopyWith(UserIntent_Sources value, $Res Function(UserIntent_Sources) _then) = _$UserIntent_SourcesCopyWithImpl;
+@useResult
+$Res call({
+ String query
+});
+
+
+
+
+}
+/// @nodoc
+class _$UserIntent_SourcesCopyWithImpl<$Res>
+    implements $UserIntent_SourcesCopyWith<$Res> {
+  _$UserIntent_SourcesCopyWithImpl(this._self, this._then);
+
+  final UserIntent_Sources _self;
+  final $Res Function(UserIntent_Sources) _then;
+
+/// Create a copy of UserIntent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? query = null,}) {
+  return _then(UserIntent_Sources(
+query: null == query ? _self.query : query // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
 }
 
 // dart format on
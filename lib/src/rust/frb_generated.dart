@@ -3,5169 +3,14133 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/analyzers.dart';
+import 'api/async_ops.dart';
+import 'api/audit.dart';
+import 'api/background_tasks.dart';
+import 'api/benchmark.dart';
+import 'api/binary_index.dart';
 import 'api/bm25_search.dart';
+import 'api/chat_memory.dart';
+import 'api/chunk_dedup.dart';
+import 'api/chunk_index_store.dart';
+import 'api/chunk_store.dart';
+import 'api/classification_rules.dart';
+import 'api/code_chunker.dart';
 import 'api/compression_utils.dart';
+import 'api/content_crypto.dart';
+import 'api/corpus_report.dart';
 import 'api/db_pool.dart';
+import 'api/db_retry.dart';
 import 'api/document_parser.dart';
+import 'api/embedding_storage.dart';
+import 'api/engine_info.dart';
+import 'api/entity_extraction.dart';
 import 'api/error.dart';
+import 'api/fts_keyword.dart';
+import 'api/fuzzy_terms.dart';
 import 'api/hnsw_index.dart';
 import 'api/hybrid_search.dart';
 import 'api/incremental_index.dart';
+import 'api/index_freshness.dart';
+import 'api/ingest.dart';
+import 'api/ingest_jobs.dart';
+import 'api/intent_presets.dart';
+import 'api/knowledge_graph.dart';
+import 'api/language_detect.dart';
 import 'api/logger.dart';
+import 'api/maintenance.dart';
+import 'api/memory_budget.dart';
+import 'api/metrics.dart';
+import 'api/named_bm25.dart';
+import 'api/named_index.dart';
+import 'api/panic_report.dart';
+import 'api/progress.dart';
+import 'api/query_decompose.dart';
+import 'api/query_history.dart';
+import 'api/query_keywords.dart';
+import 'api/query_normalize.dart';
+import 'api/query_rewrite.dart';
+import 'api/reembedding.dart';
+import 'api/related_chunks.dart';
+import 'api/rerank.dart';
+import 'api/retrieval_state.dart';
+import 'api/search_analytics.dart';
+import 'api/search_trace.dart';
 import 'api/semantic_chunker.dart';
 import 'api/simple.dart';
 import 'api/simple_rag.dart';
+import 'api/snapshot.dart';
+import 'api/snippets.dart';
+import 'api/source_grouping.dart';
+import 'api/source_index.dart';
 import 'api/source_rag.dart';
+import 'api/staged_ingest.dart';
+import 'api/synonyms.dart';
+import 'api/tenant.dart';
 import 'api/tokenizer.dart';
 import 'api/user_intent.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
 
-  @override
-  int get rustContentHash => 1964511040;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rag_engine_flutter',
-        ioDirectory: 'rust_builder/rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<int> crateApiSourceRagAddChunks({
-    required PlatformInt64 sourceId,
-    required List<ChunkData> chunks,
-  });
-
-  Future<AddDocumentResult> crateApiSimpleRagAddDocument({
-    required String content,
-    required List<double> embedding,
-  });
-
-  Future<void> crateApiSimpleRagAddDocumentSimple({
-    required String content,
-    required List<double> embedding,
-  });
-
-  Future<AddSourceResult> crateApiSourceRagAddSource({
-    required String content,
-    String? metadata,
-    String? name,
-  });
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  Future<void> crateApiBm25SearchBm25AddDocument({
-    required PlatformInt64 docId,
-    required String content,
-  });
+                  RustLib._();
 
-  Future<void> crateApiBm25SearchBm25AddDocuments({
-    required List<(PlatformInt64, String)> docs,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  Future<void> crateApiBm25SearchBm25ClearIndex();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  Future<BigInt> crateApiBm25SearchBm25GetDocumentCount();
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  Future<void> crateApiBm25SearchBm25RemoveDocument({
-    required PlatformInt64 docId,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<List<Bm25SearchResult>> crateApiBm25SearchBm25Search({
-    required String query,
-    required int topK,
-  });
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<void> crateApiHnswIndexBuildHnswIndex({
-    required List<(PlatformInt64, Float32List)> points,
-  });
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
 
-  double crateApiSimpleRagCalculateCosineSimilarity({
-    required List<double> vecA,
-    required List<double> vecB,
-  });
+                  }
 
-  Future<void> crateApiSemanticChunkerChunkTypeAsStr({required ChunkType that});
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<ChunkType> crateApiSemanticChunkerChunkTypeFromStr({
-    required String s,
-  });
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  ChunkType crateApiSemanticChunkerClassifyChunk({required String text});
+                  @override
+                  int get rustContentHash => 2072812933;
 
-  Future<void> crateApiSimpleRagClearAllDocuments();
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rag_engine_flutter',
+                    ioDirectory: 'rust_builder/rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<void> crateApiIncrementalIndexClearBuffer();
+                abstract class RustLibApi extends BaseApi {
+                  Future<BigInt> crateApiChunkStoreMemoryStoreChunkCount({required MemoryStore that });
 
-  Future<void> crateApiHnswIndexClearHnswIndex();
+Future<MemoryStore> crateApiChunkStoreMemoryStoreDefault();
 
-  Future<void> crateApiDbPoolCloseDbPool();
+Future<BigInt> crateApiChunkStoreMemoryStoreDeleteSource({required MemoryStore that , required PlatformInt64 sourceId });
 
-  void crateApiLoggerCloseLogStream();
+Future<StoredChunk?> crateApiChunkStoreMemoryStoreGetChunk({required MemoryStore that , required PlatformInt64 id });
 
-  Future<CompressedText> crateApiCompressionUtilsCompressText({
-    required String text,
-    required int maxChars,
-    required CompressionOptions options,
-  });
+Future<MemoryStore> crateApiChunkStoreMemoryStoreNew();
 
-  Future<String> crateApiCompressionUtilsCompressTextSimple({
-    required String text,
-    required int level,
-  });
+Future<void> crateApiChunkStoreMemoryStorePutChunk({required MemoryStore that , required StoredChunk chunk });
 
-  Future<CompressionOptions>
-  crateApiCompressionUtilsCompressionOptionsDefault();
+Future<List<StoreSearchHit>> crateApiChunkStoreMemoryStoreSearchVectors({required MemoryStore that , required List<double> query , required BigInt topK });
 
-  String crateApiTokenizerDecodeTokens({required List<int> tokenIds});
+Future<int> crateApiSourceRagAddChunks({required PlatformInt64 sourceId , required List<ChunkData> chunks });
 
-  Future<void> crateApiSourceRagDeleteSource({required PlatformInt64 sourceId});
+Future<int> crateApiAsyncOpsAddChunksAsync({required PlatformInt64 sourceId , required List<ChunkData> chunks });
 
-  Future<EmbeddingPoint> crateApiHnswIndexEmbeddingPointNew({
-    required PlatformInt64 id,
-    required List<double> embedding,
-  });
+Future<int> crateApiSourceRagAddChunksPacked({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets });
 
-  Future<String> crateApiDocumentParserExtractTextFromDocument({
-    required List<int> fileBytes,
-  });
+Future<int> crateApiAsyncOpsAddChunksPackedAsync({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets });
 
-  Future<String> crateApiDocumentParserExtractTextFromDocx({
-    required List<int> fileBytes,
-  });
+void crateApiQueryRewriteAddConversationTurn({required String text });
 
-  Future<String> crateApiDocumentParserExtractTextFromPdf({
-    required List<int> fileBytes,
-  });
+Future<AddDocumentResult> crateApiSimpleRagAddDocument({required String content , required List<double> embedding });
 
-  Future<List<ChunkSearchResult>> crateApiSourceRagGetAdjacentChunks({
-    required PlatformInt64 sourceId,
-    required int minIndex,
-    required int maxIndex,
-  });
+Future<void> crateApiSimpleRagAddDocumentSimple({required String content , required List<double> embedding });
 
-  Future<List<ChunkForReembedding>>
-  crateApiSourceRagGetAllChunkIdsAndContents();
+Future<PlatformInt64> crateApiChatMemoryAddMemoryMessage({required String conversationId , required String role , required String content , required List<double> embedding });
 
-  Future<List<(PlatformInt64, Float32List)>>
-  crateApiIncrementalIndexGetBufferForMerge();
+Future<AddSourceResult> crateApiSourceRagAddSource({required String content , String? metadata , String? name });
 
-  Future<BufferStats> crateApiIncrementalIndexGetBufferStats();
+Future<int> crateApiSynonymsAddSynonyms({required String term , required List<String> expansions });
 
-  Future<PlatformInt64> crateApiSimpleRagGetDocumentCount();
+ChunkStats crateApiSemanticChunkerAnalyzeChunks({required String text , required ChunkingConfig config });
 
-  Future<(int, int, int)?> crateApiDbPoolGetPoolStats();
+Future<AuditReport> crateApiAuditAudit();
 
-  Future<String?> crateApiSourceRagGetSource({required PlatformInt64 sourceId});
+Future<BenchmarkConfig> crateApiBenchmarkBenchmarkConfigDefault();
 
-  Future<int> crateApiSourceRagGetSourceChunkCount({
-    required PlatformInt64 sourceId,
-  });
+Future<void> crateApiBm25SearchBm25AddDocument({required PlatformInt64 docId , required String content });
 
-  Future<List<String>> crateApiSourceRagGetSourceChunks({
-    required PlatformInt64 sourceId,
-  });
+Future<void> crateApiBm25SearchBm25AddDocuments({required List<(PlatformInt64,String)> docs });
 
-  Future<SourceStats> crateApiSourceRagGetSourceStats();
+Future<void> crateApiNamedBm25Bm25AddDocumentsNamed({required String name , required List<(PlatformInt64,String)> docs });
 
-  int crateApiTokenizerGetVocabSize();
+Future<void> crateApiBm25SearchBm25ClearIndex();
 
-  String crateApiSimpleGreet({required String name});
+Future<BigInt> crateApiBm25SearchBm25GetDocumentCount();
 
-  Future<void> crateApiIncrementalIndexIncrementalAdd({
-    required PlatformInt64 docId,
-    required List<double> embedding,
-  });
+Future<void> crateApiBm25SearchBm25RemoveDocument({required PlatformInt64 docId });
 
-  Future<void> crateApiIncrementalIndexIncrementalAddBatch({
-    required List<(PlatformInt64, Float32List)> docs,
-  });
+Future<void> crateApiNamedBm25Bm25RemoveDocumentsNamed({required String name , required Int64List docIds });
 
-  Future<void> crateApiIncrementalIndexIncrementalRemove({
-    required PlatformInt64 docId,
-  });
+Future<List<Bm25SearchResult>> crateApiBm25SearchBm25Search({required String query , required int topK });
 
-  Future<List<IncrementalSearchResult>>
-  crateApiIncrementalIndexIncrementalSearch({
-    required List<double> queryEmbedding,
-    required BigInt topK,
-  });
+Future<void> crateApiBm25SearchBm25UpdateDocument({required PlatformInt64 docId , required String content });
 
-  Future<void> crateApiSimpleInitApp();
+Future<Bm25Config> crateApiBm25SearchBm25ConfigDefault();
 
-  Future<void> crateApiSimpleRagInitDb();
+Future<Bm25FuzzyConfig> crateApiFuzzyTermsBm25FuzzyConfigDefault();
 
-  Future<void> crateApiDbPoolInitDbPool({
-    required String dbPath,
-    required int maxSize,
-  });
+Future<Bm25IndexOptions> crateApiBm25SearchBm25IndexOptionsDefault();
 
-  Stream<String> crateApiLoggerInitLogStream();
+Future<Bm25Tokenization> crateApiBm25SearchBm25TokenizationDefault();
 
-  Future<void> crateApiLoggerInitLogger();
+Future<int> crateApiBinaryIndexBuildBinaryIndex();
 
-  Future<void> crateApiSourceRagInitSourceDb();
+Future<void> crateApiNamedBm25BuildBm25IndexNamed({required String name , required List<(PlatformInt64,String)> docs });
 
-  Future<void> crateApiTokenizerInitTokenizer({required String tokenizerPath});
+Future<void> crateApiHnswIndexBuildHnswIndex({required List<(PlatformInt64,Float32List)> points });
 
-  Future<bool> crateApiBm25SearchIsBm25IndexLoaded();
+Future<void> crateApiNamedIndexBuildHnswIndexNamed({required String name , required List<(PlatformInt64,Float32List)> points , required NamedIndexConfig config });
 
-  Future<bool> crateApiSourceRagIsChunkBm25IndexLoaded();
+Future<void> crateApiHnswIndexBuildHnswIndexWithConfig({required List<(PlatformInt64,Float32List)> points , HnswBuildConfig? config });
 
-  Future<bool> crateApiHnswIndexIsHnswIndexLoaded();
+int crateApiSourceIndexCachedSourceIndexCount();
 
-  Future<bool> crateApiDbPoolIsPoolInitialized();
+double crateApiSimpleRagCalculateCosineSimilarity({required List<double> vecA , required List<double> vecB });
 
-  Future<List<SourceEntry>> crateApiSourceRagListSources();
+bool crateApiBackgroundTasksCancelBackgroundTask({required PlatformInt64 taskId });
 
-  Future<bool> crateApiHnswIndexLoadHnswIndex({required String basePath});
+Future<bool> crateApiStagedIngestCancelIngest({required String stagingId });
 
-  List<StructuredChunk> crateApiSemanticChunkerMarkdownChunk({
-    required String text,
-    required int maxChars,
-  });
+Future<void> crateApiIngestJobsCancelIngestJob({required PlatformInt64 jobId });
 
-  Future<bool> crateApiIncrementalIndexNeedsMerge();
+Future<bool> crateApiReembeddingCancelReembeddingJob();
 
-  ParsedIntent crateApiUserIntentParseIntent({required String input});
+Future<IndexStaleness> crateApiIndexFreshnessCheckIndexFreshness();
 
-  UserIntent crateApiUserIntentParseUserIntent({required String input});
+List<StructuredChunk> crateApiSemanticChunkerChunkText({required String text , required ChunkingConfig config });
 
-  Future<void> crateApiSimpleRagRebuildBm25Index();
+Future<void> crateApiSemanticChunkerChunkTypeAsStr({required ChunkType that });
 
-  Future<void> crateApiSourceRagRebuildChunkBm25Index();
+Future<ChunkType> crateApiSemanticChunkerChunkTypeFromStr({required String s });
 
-  Future<void> crateApiSourceRagRebuildChunkHnswIndex();
+Future<ChunkingConfig> crateApiSemanticChunkerChunkingConfigDefault();
 
-  Future<void> crateApiSimpleRagRebuildHnswIndex();
+ChunkType crateApiSemanticChunkerClassifyChunk({required String text });
 
-  Future<RrfConfig> crateApiHybridSearchRrfConfigDefault();
+Future<void> crateApiSimpleRagClearAllDocuments();
 
-  Future<void> crateApiHnswIndexSaveHnswIndex({required String basePath});
+Future<void> crateApiIncrementalIndexClearBuffer({required BufferTable table });
 
-  Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunks({
-    required List<double> queryEmbedding,
-    required int topK,
-  });
+void crateApiContentCryptoClearContentEncryptionKeys();
 
-  Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnsw({
-    required List<double> queryEmbedding,
-    required BigInt topK,
-  });
+void crateApiQueryRewriteClearConversationTurns();
 
-  Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybrid({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-    RrfConfig? config,
-    SearchFilter? filter,
-  });
+Future<int> crateApiIngestJobsClearFinishedIngestJobs();
 
-  Future<List<String>> crateApiHybridSearchSearchHybridSimple({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-  });
+Future<void> crateApiHnswIndexClearHnswIndex();
 
-  Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridWeighted({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-    required double vectorWeight,
-    required double bm25Weight,
-  });
+void crateApiRetrievalStateClearLastRetrieval();
 
-  Future<List<String>> crateApiSimpleRagSearchSimilar({
-    required List<double> queryEmbedding,
-    required int topK,
-  });
+Future<void> crateApiQueryHistoryClearQueryHistory();
 
-  List<SemanticChunk> crateApiSemanticChunkerSemanticChunk({
-    required String text,
-    required int maxChars,
-  });
+void crateApiPanicReportClearRecentPanics();
 
-  List<SemanticChunk> crateApiSemanticChunkerSemanticChunkWithOverlap({
-    required String text,
-    required int maxChars,
-    required int overlapChars,
-  });
+Future<void> crateApiSearchAnalyticsClearSearchAnalytics();
 
-  Future<BigInt> crateApiCompressionUtilsSentenceHash({
-    required String sentence,
-  });
+Future<void> crateApiDbPoolCloseDbPool();
 
-  Future<bool> crateApiCompressionUtilsShouldCompress({
-    required String text,
-    required int tokenThreshold,
-  });
+void crateApiLoggerCloseLogStream();
 
-  Future<List<String>> crateApiCompressionUtilsSplitSentences({
-    required String text,
-  });
+void crateApiProgressCloseProgressStream();
 
-  Uint32List crateApiTokenizerTokenize({required String text});
-
-  Future<void> crateApiSourceRagUpdateChunkEmbedding({
-    required PlatformInt64 chunkId,
-    required List<double> embedding,
-  });
-
-  Future<void> crateApiSourceRagUpdateSourceStatus({
-    required PlatformInt64 sourceId,
-    required String status,
-  });
-
-  Future<void> crateApiUserIntentUserIntentGetQuery({required UserIntent that});
-
-  Future<void> crateApiUserIntentUserIntentIntentType({
-    required UserIntent that,
-  });
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<int> crateApiSourceRagAddChunks({
-    required PlatformInt64 sourceId,
-    required List<ChunkData> chunks,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          sse_encode_list_chunk_data(chunks, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+List<StructuredChunk> crateApiCodeChunkerCodeChunk({required String text , required String language , required int maxChars });
+
+Future<IngestReport> crateApiStagedIngestCommitIngest({required String stagingId , required List<Float32List> embeddings });
+
+Future<bool> crateApiSourceRagCompactIndex();
+
+Future<CompressedText> crateApiCompressionUtilsCompressText({required String text , required int maxChars , required CompressionOptions options });
+
+Future<String> crateApiCompressionUtilsCompressTextSimple({required String text , required int level });
+
+Future<CompressionOptions> crateApiCompressionUtilsCompressionOptionsDefault();
+
+Future<SnapshotManifest> crateApiSnapshotCreateSnapshot({required String path });
+
+Future<DbRetryConfig> crateApiDbRetryDbRetryConfigDefault();
+
+String crateApiTokenizerDecodeTokens({required List<int> tokenIds });
+
+QueryDecomposition crateApiQueryDecomposeDecomposeQuery({required String query });
+
+DedupeReport crateApiChunkDedupDedupeChunks({required List<String> chunks , required DedupeConfig config });
+
+Future<DedupeConfig> crateApiChunkDedupDedupeConfigDefault();
+
+Future<void> crateApiChatMemoryDeleteConversationMemory({required String conversationId });
+
+Future<int> crateApiNamedIndexDeleteHnswNamed({required String name , required Int64List ids });
+
+Future<void> crateApiSourceRagDeleteSource({required PlatformInt64 sourceId });
+
+Future<void> crateApiTenantDeleteTenant({required String tenantId });
+
+String crateApiLanguageDetectDetectLanguage({required String text });
+
+Future<DistanceMetric> crateApiHnswIndexDistanceMetricDefault();
+
+bool crateApiNamedBm25DropBm25IndexNamed({required String name });
+
+bool crateApiNamedIndexDropHnswIndexNamed({required String name });
+
+Future<EmbeddingPoint> crateApiHnswIndexEmbeddingPointNew({required PlatformInt64 id , required List<double> embedding });
+
+Future<EmbeddingStorageStats> crateApiEmbeddingStorageEmbeddingStorageStatsDefault();
+
+Future<PlatformInt64> crateApiIngestJobsEnqueueIngestJob({required String filePath , required IngestOptions options });
+
+Future<ChunkIndexSource> crateApiChunkIndexStoreEnsureChunkIndex();
+
+Future<IndexStaleness> crateApiIndexFreshnessEnsureIndexesFresh();
+
+Future<IndexEvaluation> crateApiBenchmarkEvaluateIndex({required int sampleSize , required int topK });
+
+Future<ExpansionConfig> crateApiRelatedChunksExpansionConfigDefault();
+
+Future<int> crateApiSearchAnalyticsExportSearchAnalytics({required String path });
+
+List<ExtractedEntity> crateApiEntityExtractionExtractEntities({required String text });
+
+List<QueryKeyword> crateApiQueryKeywordsExtractQueryKeywords({required String query });
+
+Future<String> crateApiDocumentParserExtractTextFromDocument({required List<int> fileBytes });
+
+Future<String> crateApiDocumentParserExtractTextFromDocx({required List<int> fileBytes });
+
+Future<String> crateApiDocumentParserExtractTextFromPdf({required List<int> fileBytes });
+
+List<KnowledgeTriple> crateApiKnowledgeGraphExtractTriples({required String text });
+
+Future<List<HybridSearchResult>> crateApiRerankFinalizeWithRerankScores({required Int64List docIds , required List<double> scores , required int topK , double? rerankWeight });
+
+String crateApiTenantGetActiveTenant();
+
+Future<List<ChunkSearchResult>> crateApiSourceRagGetAdjacentChunks({required PlatformInt64 sourceId , required int minIndex , required int maxIndex });
+
+Future<List<ChunkForReembedding>> crateApiSourceRagGetAllChunkIdsAndContents();
+
+BackgroundTask? crateApiBackgroundTasksGetBackgroundTask({required PlatformInt64 taskId });
+
+Bm25Config crateApiBm25SearchGetBm25Config();
+
+Bm25FuzzyConfig? crateApiFuzzyTermsGetBm25FuzzyMatching();
+
+Bm25IndexOptions crateApiBm25SearchGetBm25IndexOptions();
+
+Future<List<(PlatformInt64,Float32List)>> crateApiIncrementalIndexGetBufferForMerge({required BufferTable table });
+
+Future<BufferStats> crateApiIncrementalIndexGetBufferStats({required BufferTable table });
+
+Future<List<ExtractedEntity>> crateApiEntityExtractionGetChunkEntities({required PlatformInt64 chunkId });
+
+List<ClassificationRule> crateApiClassificationRulesGetClassificationRules();
+
+Future<List<MemoryMessage>> crateApiChatMemoryGetConversationMessages({required String conversationId });
+
+List<String> crateApiQueryRewriteGetConversationTurns();
+
+Future<CorpusReport> crateApiCorpusReportGetCorpusReport();
+
+DbRetryConfig crateApiDbRetryGetDbRetryConfig();
+
+Future<PlatformInt64> crateApiSimpleRagGetDocumentCount();
+
+Future<EmbeddingStorageStats> crateApiEmbeddingStorageGetEmbeddingStorageStats();
+
+Future<EngineInfo> crateApiEngineInfoGetEngineInfo();
+
+Future<GraphNeighborhood> crateApiKnowledgeGraphGetEntityNeighborhood({required String entity , required int maxHops , required int limit });
+
+Future<List<ChunkSearchResult>> crateApiKnowledgeGraphGetEntitySupportingChunks({required String entity , required int maxHops , required int limit });
+
+HnswBuildConfig? crateApiHnswIndexGetHnswBuildConfig();
+
+HnswConfig crateApiHnswIndexGetHnswConfig();
+
+HnswIndexStats? crateApiHnswIndexGetHnswIndexStats();
+
+BigInt? crateApiHnswIndexGetHnswSeededBuild();
+
+IncrementalConfig crateApiIncrementalIndexGetIncrementalConfig();
+
+IndexConfig crateApiHnswIndexGetIndexConfig();
+
+KeywordBackend crateApiFtsKeywordGetKeywordBackend();
+
+LanguageAnalyzer crateApiAnalyzersGetLanguageAnalyzer({required String language });
+
+AuditReport? crateApiAuditGetLastAuditReport();
+
+MaintenanceReport? crateApiMaintenanceGetLastMaintenanceReport();
+
+LastRetrieval? crateApiRetrievalStateGetLastRetrieval();
+
+String crateApiLoggerGetLogLevel();
+
+MaintenanceConfig crateApiMaintenanceGetMaintenanceConfig();
+
+MemoryProfile crateApiMemoryBudgetGetMemoryProfile();
+
+MemoryUsage crateApiMemoryBudgetGetMemoryUsage();
+
+EngineMetrics crateApiMetricsGetMetrics();
+
+Future<(int,int,int)?> crateApiDbPoolGetPoolStats();
+
+QueryNormalizationConfig crateApiQueryNormalizeGetQueryNormalizationConfig();
+
+Future<List<QuerySuggestion>> crateApiQueryHistoryGetQuerySuggestions({required String prefix });
+
+List<PanicEvent> crateApiPanicReportGetRecentPanics();
+
+Future<ReembeddingJob?> crateApiReembeddingGetReembeddingJob();
+
+RetrievalPreset crateApiIntentPresetsGetRetrievalPreset({required String intentType });
+
+List<String> crateApiSemanticChunkerGetSectionPatterns();
+
+Future<String?> crateApiSourceRagGetSource({required PlatformInt64 sourceId });
+
+Future<int> crateApiSourceRagGetSourceChunkCount({required PlatformInt64 sourceId });
+
+Future<List<String>> crateApiSourceRagGetSourceChunks({required PlatformInt64 sourceId });
+
+SourceIndexConfig crateApiSourceIndexGetSourceIndexConfig();
+
+Future<SourceStats> crateApiSourceRagGetSourceStats();
+
+int crateApiTokenizerGetVocabSize();
+
+String crateApiSimpleGreet({required String name });
+
+Future<HnswConfig> crateApiHnswIndexHnswConfigDefault();
+
+Future<bool> crateApiHnswIndexHnswInsert({required PlatformInt64 id , required List<double> embedding });
+
+Future<HybridSearchOptions> crateApiHybridSearchHybridSearchOptionsDefault();
+
+Future<HybridSearchResponse> crateApiHybridSearchHybridSearchResponseDefault();
+
+Future<void> crateApiIncrementalIndexIncrementalAdd({required BufferTable table , required PlatformInt64 docId , required List<double> embedding });
+
+Future<void> crateApiIncrementalIndexIncrementalAddBatch({required BufferTable table , required List<(PlatformInt64,Float32List)> docs });
+
+Future<IncrementalConfig> crateApiIncrementalIndexIncrementalConfigDefault();
+
+Future<void> crateApiIncrementalIndexIncrementalRemove({required BufferTable table , required PlatformInt64 docId });
+
+Future<List<IncrementalSearchResult>> crateApiIncrementalIndexIncrementalSearch({required BufferTable table , required List<double> queryEmbedding , required BigInt topK });
+
+Future<IndexConfig> crateApiHnswIndexIndexConfigDefault();
+
+Future<IndexConsistency> crateApiIndexFreshnessIndexConsistencyDefault();
+
+Future<IndexDivergence> crateApiIndexFreshnessIndexDivergenceDefault();
+
+Future<IndexStaleness> crateApiIndexFreshnessIndexStalenessDefault();
+
+Future<IngestReport> crateApiIngestIngestDocument({required IngestInput input , required IngestOptions options , required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestProgress) onProgress });
+
+Future<IngestOptions> crateApiIngestIngestOptionsDefault();
+
+Future<void> crateApiSimpleInitApp();
+
+Future<void> crateApiSimpleRagInitDb();
+
+Future<void> crateApiDbPoolInitDbPool({required String dbPath , required int maxSize });
+
+Stream<String> crateApiLoggerInitLogStream();
+
+Future<void> crateApiLoggerInitLogger();
+
+Stream<ProgressEvent> crateApiProgressInitProgressStream();
+
+Future<void> crateApiSourceRagInitSourceDb();
+
+Future<void> crateApiTokenizerInitTokenizer({required String tokenizerPath });
+
+Future<void> crateApiNamedIndexInsertHnswNamed({required String name , required List<(PlatformInt64,Float32List)> points });
+
+void crateApiPanicReportInstallPanicHook();
+
+bool crateApiBackgroundTasksIsBackgroundWorkerRunning();
+
+Future<bool> crateApiBm25SearchIsBm25IndexLoaded();
+
+Future<bool> crateApiSourceRagIsChunkBm25IndexLoaded();
+
+bool crateApiContentCryptoIsContentEncryptionEnabled();
+
+Future<bool> crateApiHnswIndexIsHnswIndexLoaded();
+
+bool crateApiIngestJobsIsIngestWorkerRunning();
+
+bool crateApiKnowledgeGraphIsKnowledgeGraphEnabled();
+
+bool crateApiMaintenanceIsMaintenanceRunning();
+
+Future<bool> crateApiDbPoolIsPoolInitialized();
+
+bool crateApiEmbeddingStorageIsQuantizedEmbeddingStorage();
+
+bool crateApiSearchAnalyticsIsSearchAnalyticsEnabled();
+
+bool crateApiSearchTraceIsSearchTracingEnabled();
+
+Future<KeywordBackend> crateApiFtsKeywordKeywordBackendDefault();
+
+Future<Int64List> crateApiRetrievalStateLastRetrievalSourceIds({required LastRetrieval that });
+
+List<BackgroundTask> crateApiBackgroundTasksListBackgroundTasks();
+
+List<NamedBm25IndexInfo> crateApiNamedBm25ListBm25IndexesNamed();
+
+Future<List<EntityFacet>> crateApiEntityExtractionListEntityFacets({String? entityType , required int limit });
+
+List<NamedIndexInfo> crateApiNamedIndexListHnswIndexesNamed();
+
+Future<List<IngestJob>> crateApiIngestJobsListIngestJobs();
+
+List<LanguageAnalyzer> crateApiAnalyzersListLanguageAnalyzers();
+
+Future<List<SourceEntry>> crateApiSourceRagListSources();
+
+List<SynonymEntry> crateApiSynonymsListSynonyms();
+
+Future<List<String>> crateApiTenantListTenants();
+
+Future<bool> crateApiHnswIndexLoadHnswIndex({required String basePath });
+
+Future<MaintenanceConfig> crateApiMaintenanceMaintenanceConfigDefault();
+
+Future<MaintenanceReport> crateApiMaintenanceMaintenanceReportDefault();
+
+List<StructuredChunk> crateApiSemanticChunkerMarkdownChunk({required String text , required int maxChars });
+
+Future<PlatformInt64> crateApiIncrementalIndexMergeBufferIntoHnsw({required BufferTable table });
+
+Future<NamedIndexConfig> crateApiNamedIndexNamedIndexConfigDefault();
+
+Future<bool> crateApiIncrementalIndexNeedsMerge({required BufferTable table });
+
+String crateApiQueryNormalizeNormalizeQuery({required String query });
+
+bool crateApiMaintenanceNotifyHostState({required bool idle , required bool charging });
+
+ParsedIntent crateApiUserIntentParseIntent({required String input });
+
+UserIntent crateApiUserIntentParseUserIntent({required String input });
+
+Future<void> crateApiIngestJobsPauseIngestJob({required PlatformInt64 jobId });
+
+Future<bool> crateApiReembeddingPauseReembeddingJob();
+
+Future<StagedIngest> crateApiStagedIngestPrepareIngest({required String text , required IngestOptions options });
+
+Future<Quantization> crateApiHnswIndexQuantizationDefault();
+
+Future<int> crateApiEmbeddingStorageQuantizeStoredEmbeddings();
+
+Future<bool> crateApiQueryDecomposeQueryDecompositionIsDecomposed({required QueryDecomposition that });
+
+Future<QueryNormalizationConfig> crateApiQueryNormalizeQueryNormalizationConfigDefault();
+
+String crateApiErrorRagErrorCode({required RagError that });
+
+String crateApiErrorRagErrorDetail({required RagError that });
+
+bool crateApiErrorRagErrorIsRetryable({required RagError that });
+
+Future<void> crateApiSimpleRagRebuildBm25Index();
+
+Future<void> crateApiSourceRagRebuildChunkBm25Index();
+
+Future<void> crateApiAsyncOpsRebuildChunkBm25IndexAsync();
+
+Future<void> crateApiSourceRagRebuildChunkHnswIndex();
+
+Future<void> crateApiAsyncOpsRebuildChunkHnswIndexAsync();
+
+Future<void> crateApiSourceRagRebuildChunkHnswIndexWithConfig({HnswBuildConfig? config });
+
+Future<void> crateApiSimpleRagRebuildHnswIndex();
+
+Future<void> crateApiQueryHistoryRecordQuery({required String queryText , required int resultCount });
+
+void crateApiAnalyzersRegisterLanguageAnalyzer({required LanguageAnalyzer analyzer });
+
+Future<int> crateApiSynonymsRemoveSynonyms({required String term , List<String>? expansions });
+
+Future<bool> crateApiSearchAnalyticsReportSearchFeedback({required String queryText , required PlatformInt64 chunkId , required SearchFeedbackAction action });
+
+void crateApiAnalyzersResetLanguageAnalyzers();
+
+void crateApiLoggerResetLogLevels();
+
+void crateApiMetricsResetMetrics();
+
+void crateApiIntentPresetsResetRetrievalPresets();
+
+Future<SnapshotManifest> crateApiSnapshotRestoreSnapshot({required String path });
+
+Future<void> crateApiIngestJobsResumeIngestJob({required PlatformInt64 jobId });
+
+Future<RetrievalPreset> crateApiIntentPresetsRetrievalPresetDefault();
+
+RewrittenQuery crateApiQueryRewriteRewriteQuery({required List<String> history , required String query });
+
+RewrittenQuery crateApiQueryRewriteRewriteQueryWithContext({required String query });
+
+Future<int> crateApiContentCryptoRotateContentKey({required String newKeyId , required List<int> newKey });
+
+Future<RrfConfig> crateApiHybridSearchRrfConfigDefault();
+
+Future<BenchmarkReport> crateApiBenchmarkRunBenchmark({required BenchmarkConfig config });
+
+Future<void> crateApiHnswIndexSaveHnswIndex({required String basePath });
+
+Future<List<Bm25SearchResult>> crateApiNamedBm25SearchBm25Named({required String name , required String query , required int topK });
+
+Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunks({required List<double> queryEmbedding , required int topK });
+
+Future<List<ChunkSearchResult>> crateApiBinaryIndexSearchChunksBinary({required List<double> queryEmbedding , required int topK });
+
+Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunksDiverse({required List<double> queryEmbedding , required int topK , required double diversityLambda });
+
+Future<ChunkSearchPage> crateApiSourceRagSearchChunksNextPage({required String cursor , required int pageSize });
+
+Future<ChunkSearchPage> crateApiSourceRagSearchChunksPaged({required List<double> queryEmbedding , required int pageSize , required int offset });
+
+Future<SearchDiagnostics> crateApiSearchTraceSearchDiagnosticsDefault();
+
+Future<SearchFilter> crateApiHybridSearchSearchFilterDefault();
+
+Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnsw({required List<double> queryEmbedding , required BigInt topK });
+
+Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnswFiltered({required List<double> queryEmbedding , required BigInt topK , required Int64List allowedIds });
+
+Future<List<HnswSearchResult>> crateApiNamedIndexSearchHnswNamed({required String name , required List<double> queryEmbedding , required BigInt topK });
+
+Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnswWithEf({required List<double> queryEmbedding , required BigInt topK , int? efSearch });
+
+Future<HybridSearchResponse> crateApiHybridSearchSearchHybrid({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options });
+
+Future<HybridSearchResponse> crateApiAsyncOpsSearchHybridAsync({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options });
+
+Future<List<HybridSearchResult>> crateApiRerankSearchHybridCandidates({required String queryText , required List<double> queryEmbedding , required int candidateCount , RrfConfig? config , SearchFilter? filter });
+
+Future<List<CompareSearchResult>> crateApiHybridSearchSearchHybridCompare({required String leftText , required List<double> leftEmbedding , required String rightText , required List<double> rightEmbedding , required int topK , RrfConfig? config , SearchFilter? filter });
+
+Future<List<ExpandedSearchResult>> crateApiHybridSearchSearchHybridExpanded({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , ExpansionConfig? expansion });
+
+Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridForIntent({required String intentType , required String queryText , required List<double> queryEmbedding , SearchFilter? filter });
+
+Future<List<String>> crateApiHybridSearchSearchHybridSimple({required String queryText , required List<double> queryEmbedding , required int topK });
+
+Stream<HybridSearchEvent> crateApiHybridSearchSearchHybridStream({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter });
+
+Future<TracedHybridSearch> crateApiHybridSearchSearchHybridTraced({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter });
+
+Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridWeighted({required String queryText , required List<double> queryEmbedding , required int topK , required double vectorWeight , required double bm25Weight });
+
+Future<List<MemorySearchResult>> crateApiChatMemorySearchMemory({required List<double> queryEmbedding , required int topK , required String conversationId });
+
+Future<List<String>> crateApiSimpleRagSearchSimilar({required List<double> queryEmbedding , required int topK });
+
+List<SemanticChunk> crateApiSemanticChunkerSemanticChunk({required String text , required int maxChars });
+
+List<SemanticChunk> crateApiSemanticChunkerSemanticChunkByTokens({required String text , required int maxTokens });
+
+List<SemanticChunk> crateApiSemanticChunkerSemanticChunkWithOverlap({required String text , required int maxChars , required int overlapChars });
+
+Future<BigInt> crateApiCompressionUtilsSentenceHash({required String sentence });
+
+void crateApiTenantSetActiveTenant({required String tenantId });
+
+void crateApiBm25SearchSetBm25Config({required Bm25Config config });
+
+void crateApiFuzzyTermsSetBm25FuzzyMatching({Bm25FuzzyConfig? config });
+
+void crateApiBm25SearchSetBm25IndexOptions({required Bm25IndexOptions options });
+
+Future<void> crateApiClassificationRulesSetClassificationRules({required List<ClassificationRule> rules });
+
+void crateApiContentCryptoSetContentEncryptionKey({required String keyId , required List<int> key });
+
+void crateApiDbRetrySetDbRetryConfig({required DbRetryConfig config });
+
+void crateApiEngineInfoSetEmbeddingModelName({String? name });
+
+void crateApiEntityExtractionSetEntityGazetteer({required List<GazetteerEntry> entries });
+
+void crateApiHnswIndexSetHnswBuildConfig({HnswBuildConfig? config });
+
+void crateApiHnswIndexSetHnswConfig({required HnswConfig config });
+
+void crateApiHnswIndexSetHnswSeededBuild({BigInt? seed });
+
+void crateApiIncrementalIndexSetIncrementalConfig({required IncrementalConfig config });
+
+void crateApiHnswIndexSetIndexConfig({required IndexConfig config });
+
+Future<void> crateApiFtsKeywordSetKeywordBackend({required KeywordBackend backend });
+
+void crateApiKnowledgeGraphSetKnowledgeGraphEnabled({required bool enabled });
+
+void crateApiLoggerSetLogLevel({required String level });
+
+void crateApiMaintenanceSetMaintenanceConfig({required MaintenanceConfig config });
+
+Future<MemoryProfile> crateApiMemoryBudgetSetMemoryBudget({int? budgetMb });
+
+void crateApiLoggerSetModuleLogLevel({required String module , String? level });
+
+void crateApiEmbeddingStorageSetQuantizedEmbeddingStorage({required bool enabled });
+
+void crateApiQueryNormalizeSetQueryNormalizationConfig({required QueryNormalizationConfig config });
+
+void crateApiIntentPresetsSetRetrievalPreset({required String intentType , required RetrievalPreset preset });
+
+void crateApiSearchAnalyticsSetSearchAnalyticsEnabled({required bool enabled });
+
+void crateApiSearchTraceSetSearchTracing({required bool enabled });
+
+void crateApiSemanticChunkerSetSectionPatterns({required List<String> patterns });
+
+void crateApiSourceIndexSetSourceIndexConfig({required SourceIndexConfig config });
+
+Future<bool> crateApiCompressionUtilsShouldCompress({required String text , required int tokenThreshold });
+
+Future<SourceIndexConfig> crateApiSourceIndexSourceIndexConfigDefault();
+
+Future<List<String>> crateApiCompressionUtilsSplitSentences({required String text });
+
+Future<bool> crateApiIngestJobsStartIngestWorker({required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestJob) onJobUpdate });
+
+Future<ReembeddingJob> crateApiReembeddingStartReembeddingJob({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress });
+
+Future<PlatformInt64> crateApiBackgroundTasksSubmitBufferMerge({required BufferTable table });
+
+Future<PlatformInt64> crateApiBackgroundTasksSubmitIndexRebuild();
+
+Future<PlatformInt64> crateApiBackgroundTasksSubmitReembedding({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress });
+
+Future<PlatformInt64> crateApiBackgroundTasksSubmitSnapshotExport({required String path });
+
+Uint32List crateApiTokenizerTokenize({required String text });
+
+Future<bool> crateApiSourceRagUpdateChunkContent({required PlatformInt64 chunkId , required String content });
+
+Future<void> crateApiSourceRagUpdateChunkEmbedding({required PlatformInt64 chunkId , required List<double> embedding });
+
+Future<int> crateApiSourceRagUpdateChunkEmbeddingsBatch({required List<(PlatformInt64,Float32List)> updates , required FutureOr<void> Function(EmbeddingBatchProgress) onProgress });
+
+Future<bool> crateApiSimpleRagUpdateDocument({required PlatformInt64 docId , required String content });
+
+Future<SourceUpdate> crateApiStagedIngestUpdateSource({required PlatformInt64 sourceId , required String newContent , required IngestOptions options });
+
+Future<void> crateApiSourceRagUpdateSourceStatus({required PlatformInt64 sourceId , required String status });
+
+Future<void> crateApiUserIntentUserIntentGetQuery({required UserIntent that });
+
+Future<void> crateApiUserIntentUserIntentIntentType({required UserIntent that });
+
+Future<IndexConsistency> crateApiIndexFreshnessVerifyIndexConsistency({required bool repair });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MemoryStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MemoryStore;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MemoryStorePtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<BigInt> crateApiChunkStoreMemoryStoreChunkCount({required MemoryStore that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreChunkCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreChunkCountConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_chunk_count",
+            argNames: ["that"],
+        );
+        
+
+@override Future<MemoryStore> crateApiChunkStoreMemoryStoreDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreDefaultConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_default",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiChunkStoreMemoryStoreDeleteSource({required MemoryStore that , required PlatformInt64 sourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(that, serializer);
+sse_encode_i_64(sourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreDeleteSourceConstMeta,
+            argValues: [that, sourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreDeleteSourceConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_delete_source",
+            argNames: ["that", "sourceId"],
+        );
+        
+
+@override Future<StoredChunk?> crateApiChunkStoreMemoryStoreGetChunk({required MemoryStore that , required PlatformInt64 id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(that, serializer);
+sse_encode_i_64(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stored_chunk,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreGetChunkConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreGetChunkConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_get_chunk",
+            argNames: ["that", "id"],
+        );
+        
+
+@override Future<MemoryStore> crateApiChunkStoreMemoryStoreNew()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreNewConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_new",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiChunkStoreMemoryStorePutChunk({required MemoryStore that , required StoredChunk chunk })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(that, serializer);
+sse_encode_box_autoadd_stored_chunk(chunk, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStorePutChunkConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStorePutChunkConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_put_chunk",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Future<List<StoreSearchHit>> crateApiChunkStoreMemoryStoreSearchVectors({required MemoryStore that , required List<double> query , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(that, serializer);
+sse_encode_list_prim_f_32_loose(query, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_store_search_hit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkStoreMemoryStoreSearchVectorsConstMeta,
+            argValues: [that, query, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkStoreMemoryStoreSearchVectorsConstMeta => const TaskConstMeta(
+            debugName: "MemoryStore_search_vectors",
+            argNames: ["that", "query", "topK"],
+        );
+        
+
+@override Future<int> crateApiSourceRagAddChunks({required PlatformInt64 sourceId , required List<ChunkData> chunks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_list_chunk_data(chunks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagAddChunksConstMeta,
+            argValues: [sourceId, chunks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagAddChunksConstMeta => const TaskConstMeta(
+            debugName: "add_chunks",
+            argNames: ["sourceId", "chunks"],
+        );
+        
+
+@override Future<int> crateApiAsyncOpsAddChunksAsync({required PlatformInt64 sourceId , required List<ChunkData> chunks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_list_chunk_data(chunks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAsyncOpsAddChunksAsyncConstMeta,
+            argValues: [sourceId, chunks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAsyncOpsAddChunksAsyncConstMeta => const TaskConstMeta(
+            debugName: "add_chunks_async",
+            argNames: ["sourceId", "chunks"],
+        );
+        
+
+@override Future<int> crateApiSourceRagAddChunksPacked({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_list_chunk_meta(chunks, serializer);
+sse_encode_list_prim_f_32_loose(embeddings, serializer);
+sse_encode_list_prim_u_32_loose(offsets, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagAddChunksPackedConstMeta,
+            argValues: [sourceId, chunks, embeddings, offsets],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagAddChunksPackedConstMeta => const TaskConstMeta(
+            debugName: "add_chunks_packed",
+            argNames: ["sourceId", "chunks", "embeddings", "offsets"],
+        );
+        
+
+@override Future<int> crateApiAsyncOpsAddChunksPackedAsync({required PlatformInt64 sourceId , required List<ChunkMeta> chunks , required List<double> embeddings , required List<int> offsets })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_list_chunk_meta(chunks, serializer);
+sse_encode_list_prim_f_32_loose(embeddings, serializer);
+sse_encode_list_prim_u_32_loose(offsets, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAsyncOpsAddChunksPackedAsyncConstMeta,
+            argValues: [sourceId, chunks, embeddings, offsets],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAsyncOpsAddChunksPackedAsyncConstMeta => const TaskConstMeta(
+            debugName: "add_chunks_packed_async",
+            argNames: ["sourceId", "chunks", "embeddings", "offsets"],
+        );
+        
+
+@override void crateApiQueryRewriteAddConversationTurn({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryRewriteAddConversationTurnConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryRewriteAddConversationTurnConstMeta => const TaskConstMeta(
+            debugName: "add_conversation_turn",
+            argNames: ["text"],
+        );
+        
+
+@override Future<AddDocumentResult> crateApiSimpleRagAddDocument({required String content , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_add_document_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagAddDocumentConstMeta,
+            argValues: [content, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagAddDocumentConstMeta => const TaskConstMeta(
+            debugName: "add_document",
+            argNames: ["content", "embedding"],
+        );
+        
+
+@override Future<void> crateApiSimpleRagAddDocumentSimple({required String content , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagAddDocumentSimpleConstMeta,
+            argValues: [content, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagAddDocumentSimpleConstMeta => const TaskConstMeta(
+            debugName: "add_document_simple",
+            argNames: ["content", "embedding"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiChatMemoryAddMemoryMessage({required String conversationId , required String role , required String content , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(conversationId, serializer);
+sse_encode_String(role, serializer);
+sse_encode_String(content, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiChatMemoryAddMemoryMessageConstMeta,
+            argValues: [conversationId, role, content, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatMemoryAddMemoryMessageConstMeta => const TaskConstMeta(
+            debugName: "add_memory_message",
+            argNames: ["conversationId", "role", "content", "embedding"],
+        );
+        
+
+@override Future<AddSourceResult> crateApiSourceRagAddSource({required String content , String? metadata , String? name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_opt_String(metadata, serializer);
+sse_encode_opt_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_add_source_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagAddSourceConstMeta,
+            argValues: [content, metadata, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagAddSourceConstMeta => const TaskConstMeta(
+            debugName: "add_source",
+            argNames: ["content", "metadata", "name"],
+        );
+        
+
+@override Future<int> crateApiSynonymsAddSynonyms({required String term , required List<String> expansions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(term, serializer);
+sse_encode_list_String(expansions, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSynonymsAddSynonymsConstMeta,
+            argValues: [term, expansions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSynonymsAddSynonymsConstMeta => const TaskConstMeta(
+            debugName: "add_synonyms",
+            argNames: ["term", "expansions"],
+        );
+        
+
+@override ChunkStats crateApiSemanticChunkerAnalyzeChunks({required String text , required ChunkingConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_box_autoadd_chunking_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerAnalyzeChunksConstMeta,
+            argValues: [text, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerAnalyzeChunksConstMeta => const TaskConstMeta(
+            debugName: "analyze_chunks",
+            argNames: ["text", "config"],
+        );
+        
+
+@override Future<AuditReport> crateApiAuditAudit()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audit_report,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAuditAuditConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditAuditConstMeta => const TaskConstMeta(
+            debugName: "audit",
+            argNames: [],
+        );
+        
+
+@override Future<BenchmarkConfig> crateApiBenchmarkBenchmarkConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_benchmark_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBenchmarkBenchmarkConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBenchmarkBenchmarkConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "benchmark_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiBm25SearchBm25AddDocument({required PlatformInt64 docId , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(docId, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25AddDocumentConstMeta,
+            argValues: [docId, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25AddDocumentConstMeta => const TaskConstMeta(
+            debugName: "bm25_add_document",
+            argNames: ["docId", "content"],
+        );
+        
+
+@override Future<void> crateApiBm25SearchBm25AddDocuments({required List<(PlatformInt64,String)> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_i_64_string(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25AddDocumentsConstMeta,
+            argValues: [docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25AddDocumentsConstMeta => const TaskConstMeta(
+            debugName: "bm25_add_documents",
+            argNames: ["docs"],
+        );
+        
+
+@override Future<void> crateApiNamedBm25Bm25AddDocumentsNamed({required String name , required List<(PlatformInt64,String)> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_record_i_64_string(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25Bm25AddDocumentsNamedConstMeta,
+            argValues: [name, docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25Bm25AddDocumentsNamedConstMeta => const TaskConstMeta(
+            debugName: "bm25_add_documents_named",
+            argNames: ["name", "docs"],
+        );
+        
+
+@override Future<void> crateApiBm25SearchBm25ClearIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25ClearIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25ClearIndexConstMeta => const TaskConstMeta(
+            debugName: "bm25_clear_index",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiBm25SearchBm25GetDocumentCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25GetDocumentCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25GetDocumentCountConstMeta => const TaskConstMeta(
+            debugName: "bm25_get_document_count",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiBm25SearchBm25RemoveDocument({required PlatformInt64 docId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(docId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25RemoveDocumentConstMeta,
+            argValues: [docId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25RemoveDocumentConstMeta => const TaskConstMeta(
+            debugName: "bm25_remove_document",
+            argNames: ["docId"],
+        );
+        
+
+@override Future<void> crateApiNamedBm25Bm25RemoveDocumentsNamed({required String name , required Int64List docIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_prim_i_64_strict(docIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25Bm25RemoveDocumentsNamedConstMeta,
+            argValues: [name, docIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25Bm25RemoveDocumentsNamedConstMeta => const TaskConstMeta(
+            debugName: "bm25_remove_documents_named",
+            argNames: ["name", "docIds"],
+        );
+        
+
+@override Future<List<Bm25SearchResult>> crateApiBm25SearchBm25Search({required String query , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bm_25_search_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25SearchConstMeta,
+            argValues: [query, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25SearchConstMeta => const TaskConstMeta(
+            debugName: "bm25_search",
+            argNames: ["query", "topK"],
+        );
+        
+
+@override Future<void> crateApiBm25SearchBm25UpdateDocument({required PlatformInt64 docId , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(docId, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25UpdateDocumentConstMeta,
+            argValues: [docId, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25UpdateDocumentConstMeta => const TaskConstMeta(
+            debugName: "bm25_update_document",
+            argNames: ["docId", "content"],
+        );
+        
+
+@override Future<Bm25Config> crateApiBm25SearchBm25ConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25ConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25ConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "bm_25_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<Bm25FuzzyConfig> crateApiFuzzyTermsBm25FuzzyConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_fuzzy_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFuzzyTermsBm25FuzzyConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFuzzyTermsBm25FuzzyConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "bm_25_fuzzy_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<Bm25IndexOptions> crateApiBm25SearchBm25IndexOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_index_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25IndexOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25IndexOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "bm_25_index_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<Bm25Tokenization> crateApiBm25SearchBm25TokenizationDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_tokenization,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchBm25TokenizationDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchBm25TokenizationDefaultConstMeta => const TaskConstMeta(
+            debugName: "bm_25_tokenization_default",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiBinaryIndexBuildBinaryIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiBinaryIndexBuildBinaryIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBinaryIndexBuildBinaryIndexConstMeta => const TaskConstMeta(
+            debugName: "build_binary_index",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiNamedBm25BuildBm25IndexNamed({required String name , required List<(PlatformInt64,String)> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_record_i_64_string(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25BuildBm25IndexNamedConstMeta,
+            argValues: [name, docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25BuildBm25IndexNamedConstMeta => const TaskConstMeta(
+            debugName: "build_bm25_index_named",
+            argNames: ["name", "docs"],
+        );
+        
+
+@override Future<void> crateApiHnswIndexBuildHnswIndex({required List<(PlatformInt64,Float32List)> points })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_i_64_list_prim_f_32_strict(points, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexBuildHnswIndexConstMeta,
+            argValues: [points],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexBuildHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "build_hnsw_index",
+            argNames: ["points"],
+        );
+        
+
+@override Future<void> crateApiNamedIndexBuildHnswIndexNamed({required String name , required List<(PlatformInt64,Float32List)> points , required NamedIndexConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_record_i_64_list_prim_f_32_strict(points, serializer);
+sse_encode_box_autoadd_named_index_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexBuildHnswIndexNamedConstMeta,
+            argValues: [name, points, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexBuildHnswIndexNamedConstMeta => const TaskConstMeta(
+            debugName: "build_hnsw_index_named",
+            argNames: ["name", "points", "config"],
+        );
+        
+
+@override Future<void> crateApiHnswIndexBuildHnswIndexWithConfig({required List<(PlatformInt64,Float32List)> points , HnswBuildConfig? config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_i_64_list_prim_f_32_strict(points, serializer);
+sse_encode_opt_box_autoadd_hnsw_build_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexBuildHnswIndexWithConfigConstMeta,
+            argValues: [points, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexBuildHnswIndexWithConfigConstMeta => const TaskConstMeta(
+            debugName: "build_hnsw_index_with_config",
+            argNames: ["points", "config"],
+        );
+        
+
+@override int crateApiSourceIndexCachedSourceIndexCount()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSourceIndexCachedSourceIndexCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceIndexCachedSourceIndexCountConstMeta => const TaskConstMeta(
+            debugName: "cached_source_index_count",
+            argNames: [],
+        );
+        
+
+@override double crateApiSimpleRagCalculateCosineSimilarity({required List<double> vecA , required List<double> vecB })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(vecA, serializer);
+sse_encode_list_prim_f_32_loose(vecB, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagCalculateCosineSimilarityConstMeta,
+            argValues: [vecA, vecB],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagCalculateCosineSimilarityConstMeta => const TaskConstMeta(
+            debugName: "calculate_cosine_similarity",
+            argNames: ["vecA", "vecB"],
+        );
+        
+
+@override bool crateApiBackgroundTasksCancelBackgroundTask({required PlatformInt64 taskId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(taskId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksCancelBackgroundTaskConstMeta,
+            argValues: [taskId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksCancelBackgroundTaskConstMeta => const TaskConstMeta(
+            debugName: "cancel_background_task",
+            argNames: ["taskId"],
+        );
+        
+
+@override Future<bool> crateApiStagedIngestCancelIngest({required String stagingId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(stagingId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiStagedIngestCancelIngestConstMeta,
+            argValues: [stagingId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStagedIngestCancelIngestConstMeta => const TaskConstMeta(
+            debugName: "cancel_ingest",
+            argNames: ["stagingId"],
+        );
+        
+
+@override Future<void> crateApiIngestJobsCancelIngestJob({required PlatformInt64 jobId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(jobId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsCancelIngestJobConstMeta,
+            argValues: [jobId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsCancelIngestJobConstMeta => const TaskConstMeta(
+            debugName: "cancel_ingest_job",
+            argNames: ["jobId"],
+        );
+        
+
+@override Future<bool> crateApiReembeddingCancelReembeddingJob()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiReembeddingCancelReembeddingJobConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReembeddingCancelReembeddingJobConstMeta => const TaskConstMeta(
+            debugName: "cancel_reembedding_job",
+            argNames: [],
+        );
+        
+
+@override Future<IndexStaleness> crateApiIndexFreshnessCheckIndexFreshness()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_staleness,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessCheckIndexFreshnessConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessCheckIndexFreshnessConstMeta => const TaskConstMeta(
+            debugName: "check_index_freshness",
+            argNames: [],
+        );
+        
+
+@override List<StructuredChunk> crateApiSemanticChunkerChunkText({required String text , required ChunkingConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_box_autoadd_chunking_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_structured_chunk,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerChunkTextConstMeta,
+            argValues: [text, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerChunkTextConstMeta => const TaskConstMeta(
+            debugName: "chunk_text",
+            argNames: ["text", "config"],
+        );
+        
+
+@override Future<void> crateApiSemanticChunkerChunkTypeAsStr({required ChunkType that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_chunk_type(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerChunkTypeAsStrConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerChunkTypeAsStrConstMeta => const TaskConstMeta(
+            debugName: "chunk_type_as_str",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ChunkType> crateApiSemanticChunkerChunkTypeFromStr({required String s })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(s, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerChunkTypeFromStrConstMeta,
+            argValues: [s],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerChunkTypeFromStrConstMeta => const TaskConstMeta(
+            debugName: "chunk_type_from_str",
+            argNames: ["s"],
+        );
+        
+
+@override Future<ChunkingConfig> crateApiSemanticChunkerChunkingConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunking_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerChunkingConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerChunkingConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "chunking_config_default",
+            argNames: [],
+        );
+        
+
+@override ChunkType crateApiSemanticChunkerClassifyChunk({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerClassifyChunkConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerClassifyChunkConstMeta => const TaskConstMeta(
+            debugName: "classify_chunk",
+            argNames: ["text"],
+        );
+        
+
+@override Future<void> crateApiSimpleRagClearAllDocuments()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagClearAllDocumentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagClearAllDocumentsConstMeta => const TaskConstMeta(
+            debugName: "clear_all_documents",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiIncrementalIndexClearBuffer({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexClearBufferConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexClearBufferConstMeta => const TaskConstMeta(
+            debugName: "clear_buffer",
+            argNames: ["table"],
+        );
+        
+
+@override void crateApiContentCryptoClearContentEncryptionKeys()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiContentCryptoClearContentEncryptionKeysConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentCryptoClearContentEncryptionKeysConstMeta => const TaskConstMeta(
+            debugName: "clear_content_encryption_keys",
+            argNames: [],
+        );
+        
+
+@override void crateApiQueryRewriteClearConversationTurns()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryRewriteClearConversationTurnsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryRewriteClearConversationTurnsConstMeta => const TaskConstMeta(
+            debugName: "clear_conversation_turns",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiIngestJobsClearFinishedIngestJobs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsClearFinishedIngestJobsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsClearFinishedIngestJobsConstMeta => const TaskConstMeta(
+            debugName: "clear_finished_ingest_jobs",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiHnswIndexClearHnswIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexClearHnswIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexClearHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "clear_hnsw_index",
+            argNames: [],
+        );
+        
+
+@override void crateApiRetrievalStateClearLastRetrieval()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRetrievalStateClearLastRetrievalConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetrievalStateClearLastRetrievalConstMeta => const TaskConstMeta(
+            debugName: "clear_last_retrieval",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiQueryHistoryClearQueryHistory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiQueryHistoryClearQueryHistoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryHistoryClearQueryHistoryConstMeta => const TaskConstMeta(
+            debugName: "clear_query_history",
+            argNames: [],
+        );
+        
+
+@override void crateApiPanicReportClearRecentPanics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPanicReportClearRecentPanicsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPanicReportClearRecentPanicsConstMeta => const TaskConstMeta(
+            debugName: "clear_recent_panics",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSearchAnalyticsClearSearchAnalytics()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSearchAnalyticsClearSearchAnalyticsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAnalyticsClearSearchAnalyticsConstMeta => const TaskConstMeta(
+            debugName: "clear_search_analytics",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDbPoolCloseDbPool()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbPoolCloseDbPoolConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbPoolCloseDbPoolConstMeta => const TaskConstMeta(
+            debugName: "close_db_pool",
+            argNames: [],
+        );
+        
+
+@override void crateApiLoggerCloseLogStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggerCloseLogStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerCloseLogStreamConstMeta => const TaskConstMeta(
+            debugName: "close_log_stream",
+            argNames: [],
+        );
+        
+
+@override void crateApiProgressCloseProgressStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProgressCloseProgressStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProgressCloseProgressStreamConstMeta => const TaskConstMeta(
+            debugName: "close_progress_stream",
+            argNames: [],
+        );
+        
+
+@override List<StructuredChunk> crateApiCodeChunkerCodeChunk({required String text , required String language , required int maxChars })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_String(language, serializer);
+sse_encode_i_32(maxChars, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_structured_chunk,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCodeChunkerCodeChunkConstMeta,
+            argValues: [text, language, maxChars],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCodeChunkerCodeChunkConstMeta => const TaskConstMeta(
+            debugName: "code_chunk",
+            argNames: ["text", "language", "maxChars"],
+        );
+        
+
+@override Future<IngestReport> crateApiStagedIngestCommitIngest({required String stagingId , required List<Float32List> embeddings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(stagingId, serializer);
+sse_encode_list_list_prim_f_32_strict(embeddings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ingest_report,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiStagedIngestCommitIngestConstMeta,
+            argValues: [stagingId, embeddings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStagedIngestCommitIngestConstMeta => const TaskConstMeta(
+            debugName: "commit_ingest",
+            argNames: ["stagingId", "embeddings"],
+        );
+        
+
+@override Future<bool> crateApiSourceRagCompactIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagCompactIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagCompactIndexConstMeta => const TaskConstMeta(
+            debugName: "compact_index",
+            argNames: [],
+        );
+        
+
+@override Future<CompressedText> crateApiCompressionUtilsCompressText({required String text , required int maxChars , required CompressionOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(maxChars, serializer);
+sse_encode_box_autoadd_compression_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compressed_text,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsCompressTextConstMeta,
+            argValues: [text, maxChars, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsCompressTextConstMeta => const TaskConstMeta(
+            debugName: "compress_text",
+            argNames: ["text", "maxChars", "options"],
+        );
+        
+
+@override Future<String> crateApiCompressionUtilsCompressTextSimple({required String text , required int level })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(level, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsCompressTextSimpleConstMeta,
+            argValues: [text, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsCompressTextSimpleConstMeta => const TaskConstMeta(
+            debugName: "compress_text_simple",
+            argNames: ["text", "level"],
+        );
+        
+
+@override Future<CompressionOptions> crateApiCompressionUtilsCompressionOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compression_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsCompressionOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsCompressionOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "compression_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<SnapshotManifest> crateApiSnapshotCreateSnapshot({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snapshot_manifest,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSnapshotCreateSnapshotConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSnapshotCreateSnapshotConstMeta => const TaskConstMeta(
+            debugName: "create_snapshot",
+            argNames: ["path"],
+        );
+        
+
+@override Future<DbRetryConfig> crateApiDbRetryDbRetryConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_db_retry_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbRetryDbRetryConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbRetryDbRetryConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "db_retry_config_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiTokenizerDecodeTokens({required List<int> tokenIds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_32_loose(tokenIds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTokenizerDecodeTokensConstMeta,
+            argValues: [tokenIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTokenizerDecodeTokensConstMeta => const TaskConstMeta(
+            debugName: "decode_tokens",
+            argNames: ["tokenIds"],
+        );
+        
+
+@override QueryDecomposition crateApiQueryDecomposeDecomposeQuery({required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_decomposition,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryDecomposeDecomposeQueryConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryDecomposeDecomposeQueryConstMeta => const TaskConstMeta(
+            debugName: "decompose_query",
+            argNames: ["query"],
+        );
+        
+
+@override DedupeReport crateApiChunkDedupDedupeChunks({required List<String> chunks , required DedupeConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(chunks, serializer);
+sse_encode_box_autoadd_dedupe_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dedupe_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkDedupDedupeChunksConstMeta,
+            argValues: [chunks, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkDedupDedupeChunksConstMeta => const TaskConstMeta(
+            debugName: "dedupe_chunks",
+            argNames: ["chunks", "config"],
+        );
+        
+
+@override Future<DedupeConfig> crateApiChunkDedupDedupeConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dedupe_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChunkDedupDedupeConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkDedupDedupeConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "dedupe_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiChatMemoryDeleteConversationMemory({required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiChatMemoryDeleteConversationMemoryConstMeta,
+            argValues: [conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatMemoryDeleteConversationMemoryConstMeta => const TaskConstMeta(
+            debugName: "delete_conversation_memory",
+            argNames: ["conversationId"],
+        );
+        
+
+@override Future<int> crateApiNamedIndexDeleteHnswNamed({required String name , required Int64List ids })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_prim_i_64_strict(ids, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexDeleteHnswNamedConstMeta,
+            argValues: [name, ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexDeleteHnswNamedConstMeta => const TaskConstMeta(
+            debugName: "delete_hnsw_named",
+            argNames: ["name", "ids"],
+        );
+        
+
+@override Future<void> crateApiSourceRagDeleteSource({required PlatformInt64 sourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagDeleteSourceConstMeta,
+            argValues: [sourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagDeleteSourceConstMeta => const TaskConstMeta(
+            debugName: "delete_source",
+            argNames: ["sourceId"],
+        );
+        
+
+@override Future<void> crateApiTenantDeleteTenant({required String tenantId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(tenantId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTenantDeleteTenantConstMeta,
+            argValues: [tenantId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantDeleteTenantConstMeta => const TaskConstMeta(
+            debugName: "delete_tenant",
+            argNames: ["tenantId"],
+        );
+        
+
+@override String crateApiLanguageDetectDetectLanguage({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLanguageDetectDetectLanguageConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLanguageDetectDetectLanguageConstMeta => const TaskConstMeta(
+            debugName: "detect_language",
+            argNames: ["text"],
+        );
+        
+
+@override Future<DistanceMetric> crateApiHnswIndexDistanceMetricDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_distance_metric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexDistanceMetricDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexDistanceMetricDefaultConstMeta => const TaskConstMeta(
+            debugName: "distance_metric_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiNamedBm25DropBm25IndexNamed({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25DropBm25IndexNamedConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25DropBm25IndexNamedConstMeta => const TaskConstMeta(
+            debugName: "drop_bm25_index_named",
+            argNames: ["name"],
+        );
+        
+
+@override bool crateApiNamedIndexDropHnswIndexNamed({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexDropHnswIndexNamedConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexDropHnswIndexNamedConstMeta => const TaskConstMeta(
+            debugName: "drop_hnsw_index_named",
+            argNames: ["name"],
+        );
+        
+
+@override Future<EmbeddingPoint> crateApiHnswIndexEmbeddingPointNew({required PlatformInt64 id , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_embedding_point,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexEmbeddingPointNewConstMeta,
+            argValues: [id, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexEmbeddingPointNewConstMeta => const TaskConstMeta(
+            debugName: "embedding_point_new",
+            argNames: ["id", "embedding"],
+        );
+        
+
+@override Future<EmbeddingStorageStats> crateApiEmbeddingStorageEmbeddingStorageStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_embedding_storage_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEmbeddingStorageEmbeddingStorageStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddingStorageEmbeddingStorageStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "embedding_storage_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<PlatformInt64> crateApiIngestJobsEnqueueIngestJob({required String filePath , required IngestOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+sse_encode_box_autoadd_ingest_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsEnqueueIngestJobConstMeta,
+            argValues: [filePath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsEnqueueIngestJobConstMeta => const TaskConstMeta(
+            debugName: "enqueue_ingest_job",
+            argNames: ["filePath", "options"],
+        );
+        
+
+@override Future<ChunkIndexSource> crateApiChunkIndexStoreEnsureChunkIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_index_source,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiChunkIndexStoreEnsureChunkIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChunkIndexStoreEnsureChunkIndexConstMeta => const TaskConstMeta(
+            debugName: "ensure_chunk_index",
+            argNames: [],
+        );
+        
+
+@override Future<IndexStaleness> crateApiIndexFreshnessEnsureIndexesFresh()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_staleness,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessEnsureIndexesFreshConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessEnsureIndexesFreshConstMeta => const TaskConstMeta(
+            debugName: "ensure_indexes_fresh",
+            argNames: [],
+        );
+        
+
+@override Future<IndexEvaluation> crateApiBenchmarkEvaluateIndex({required int sampleSize , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(sampleSize, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_evaluation,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiBenchmarkEvaluateIndexConstMeta,
+            argValues: [sampleSize, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBenchmarkEvaluateIndexConstMeta => const TaskConstMeta(
+            debugName: "evaluate_index",
+            argNames: ["sampleSize", "topK"],
+        );
+        
+
+@override Future<ExpansionConfig> crateApiRelatedChunksExpansionConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_expansion_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRelatedChunksExpansionConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRelatedChunksExpansionConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "expansion_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiSearchAnalyticsExportSearchAnalytics({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSearchAnalyticsExportSearchAnalyticsConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAnalyticsExportSearchAnalyticsConstMeta => const TaskConstMeta(
+            debugName: "export_search_analytics",
+            argNames: ["path"],
+        );
+        
+
+@override List<ExtractedEntity> crateApiEntityExtractionExtractEntities({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_extracted_entity,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEntityExtractionExtractEntitiesConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEntityExtractionExtractEntitiesConstMeta => const TaskConstMeta(
+            debugName: "extract_entities",
+            argNames: ["text"],
+        );
+        
+
+@override List<QueryKeyword> crateApiQueryKeywordsExtractQueryKeywords({required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_query_keyword,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryKeywordsExtractQueryKeywordsConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryKeywordsExtractQueryKeywordsConstMeta => const TaskConstMeta(
+            debugName: "extract_query_keywords",
+            argNames: ["query"],
+        );
+        
+
+@override Future<String> crateApiDocumentParserExtractTextFromDocument({required List<int> fileBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(fileBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiDocumentParserExtractTextFromDocumentConstMeta,
+            argValues: [fileBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDocumentParserExtractTextFromDocumentConstMeta => const TaskConstMeta(
+            debugName: "extract_text_from_document",
+            argNames: ["fileBytes"],
+        );
+        
+
+@override Future<String> crateApiDocumentParserExtractTextFromDocx({required List<int> fileBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(fileBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiDocumentParserExtractTextFromDocxConstMeta,
+            argValues: [fileBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDocumentParserExtractTextFromDocxConstMeta => const TaskConstMeta(
+            debugName: "extract_text_from_docx",
+            argNames: ["fileBytes"],
+        );
+        
+
+@override Future<String> crateApiDocumentParserExtractTextFromPdf({required List<int> fileBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(fileBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiDocumentParserExtractTextFromPdfConstMeta,
+            argValues: [fileBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDocumentParserExtractTextFromPdfConstMeta => const TaskConstMeta(
+            debugName: "extract_text_from_pdf",
+            argNames: ["fileBytes"],
+        );
+        
+
+@override List<KnowledgeTriple> crateApiKnowledgeGraphExtractTriples({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_knowledge_triple,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKnowledgeGraphExtractTriplesConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKnowledgeGraphExtractTriplesConstMeta => const TaskConstMeta(
+            debugName: "extract_triples",
+            argNames: ["text"],
+        );
+        
+
+@override Future<List<HybridSearchResult>> crateApiRerankFinalizeWithRerankScores({required Int64List docIds , required List<double> scores , required int topK , double? rerankWeight })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_i_64_strict(docIds, serializer);
+sse_encode_list_prim_f_64_loose(scores, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_f_64(rerankWeight, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hybrid_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiRerankFinalizeWithRerankScoresConstMeta,
+            argValues: [docIds, scores, topK, rerankWeight],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRerankFinalizeWithRerankScoresConstMeta => const TaskConstMeta(
+            debugName: "finalize_with_rerank_scores",
+            argNames: ["docIds", "scores", "topK", "rerankWeight"],
+        );
+        
+
+@override String crateApiTenantGetActiveTenant()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTenantGetActiveTenantConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantGetActiveTenantConstMeta => const TaskConstMeta(
+            debugName: "get_active_tenant",
+            argNames: [],
+        );
+        
+
+@override Future<List<ChunkSearchResult>> crateApiSourceRagGetAdjacentChunks({required PlatformInt64 sourceId , required int minIndex , required int maxIndex })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_i_32(minIndex, serializer);
+sse_encode_i_32(maxIndex, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetAdjacentChunksConstMeta,
+            argValues: [sourceId, minIndex, maxIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetAdjacentChunksConstMeta => const TaskConstMeta(
+            debugName: "get_adjacent_chunks",
+            argNames: ["sourceId", "minIndex", "maxIndex"],
+        );
+        
+
+@override Future<List<ChunkForReembedding>> crateApiSourceRagGetAllChunkIdsAndContents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_for_reembedding,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetAllChunkIdsAndContentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetAllChunkIdsAndContentsConstMeta => const TaskConstMeta(
+            debugName: "get_all_chunk_ids_and_contents",
+            argNames: [],
+        );
+        
+
+@override BackgroundTask? crateApiBackgroundTasksGetBackgroundTask({required PlatformInt64 taskId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(taskId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_background_task,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksGetBackgroundTaskConstMeta,
+            argValues: [taskId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksGetBackgroundTaskConstMeta => const TaskConstMeta(
+            debugName: "get_background_task",
+            argNames: ["taskId"],
+        );
+        
+
+@override Bm25Config crateApiBm25SearchGetBm25Config()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchGetBm25ConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchGetBm25ConfigConstMeta => const TaskConstMeta(
+            debugName: "get_bm25_config",
+            argNames: [],
+        );
+        
+
+@override Bm25FuzzyConfig? crateApiFuzzyTermsGetBm25FuzzyMatching()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bm_25_fuzzy_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFuzzyTermsGetBm25FuzzyMatchingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFuzzyTermsGetBm25FuzzyMatchingConstMeta => const TaskConstMeta(
+            debugName: "get_bm25_fuzzy_matching",
+            argNames: [],
+        );
+        
+
+@override Bm25IndexOptions crateApiBm25SearchGetBm25IndexOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bm_25_index_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchGetBm25IndexOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchGetBm25IndexOptionsConstMeta => const TaskConstMeta(
+            debugName: "get_bm25_index_options",
+            argNames: [],
+        );
+        
+
+@override Future<List<(PlatformInt64,Float32List)>> crateApiIncrementalIndexGetBufferForMerge({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_i_64_list_prim_f_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexGetBufferForMergeConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexGetBufferForMergeConstMeta => const TaskConstMeta(
+            debugName: "get_buffer_for_merge",
+            argNames: ["table"],
+        );
+        
+
+@override Future<BufferStats> crateApiIncrementalIndexGetBufferStats({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_buffer_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexGetBufferStatsConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexGetBufferStatsConstMeta => const TaskConstMeta(
+            debugName: "get_buffer_stats",
+            argNames: ["table"],
+        );
+        
+
+@override Future<List<ExtractedEntity>> crateApiEntityExtractionGetChunkEntities({required PlatformInt64 chunkId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(chunkId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_extracted_entity,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiEntityExtractionGetChunkEntitiesConstMeta,
+            argValues: [chunkId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEntityExtractionGetChunkEntitiesConstMeta => const TaskConstMeta(
+            debugName: "get_chunk_entities",
+            argNames: ["chunkId"],
+        );
+        
+
+@override List<ClassificationRule> crateApiClassificationRulesGetClassificationRules()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_classification_rule,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClassificationRulesGetClassificationRulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClassificationRulesGetClassificationRulesConstMeta => const TaskConstMeta(
+            debugName: "get_classification_rules",
+            argNames: [],
+        );
+        
+
+@override Future<List<MemoryMessage>> crateApiChatMemoryGetConversationMessages({required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_memory_message,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiChatMemoryGetConversationMessagesConstMeta,
+            argValues: [conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatMemoryGetConversationMessagesConstMeta => const TaskConstMeta(
+            debugName: "get_conversation_messages",
+            argNames: ["conversationId"],
+        );
+        
+
+@override List<String> crateApiQueryRewriteGetConversationTurns()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryRewriteGetConversationTurnsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryRewriteGetConversationTurnsConstMeta => const TaskConstMeta(
+            debugName: "get_conversation_turns",
+            argNames: [],
+        );
+        
+
+@override Future<CorpusReport> crateApiCorpusReportGetCorpusReport()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_corpus_report,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiCorpusReportGetCorpusReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCorpusReportGetCorpusReportConstMeta => const TaskConstMeta(
+            debugName: "get_corpus_report",
+            argNames: [],
+        );
+        
+
+@override DbRetryConfig crateApiDbRetryGetDbRetryConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_db_retry_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbRetryGetDbRetryConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbRetryGetDbRetryConfigConstMeta => const TaskConstMeta(
+            debugName: "get_db_retry_config",
+            argNames: [],
+        );
+        
+
+@override Future<PlatformInt64> crateApiSimpleRagGetDocumentCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagGetDocumentCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagGetDocumentCountConstMeta => const TaskConstMeta(
+            debugName: "get_document_count",
+            argNames: [],
+        );
+        
+
+@override Future<EmbeddingStorageStats> crateApiEmbeddingStorageGetEmbeddingStorageStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_embedding_storage_stats,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiEmbeddingStorageGetEmbeddingStorageStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddingStorageGetEmbeddingStorageStatsConstMeta => const TaskConstMeta(
+            debugName: "get_embedding_storage_stats",
+            argNames: [],
+        );
+        
+
+@override Future<EngineInfo> crateApiEngineInfoGetEngineInfo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_engine_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInfoGetEngineInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInfoGetEngineInfoConstMeta => const TaskConstMeta(
+            debugName: "get_engine_info",
+            argNames: [],
+        );
+        
+
+@override Future<GraphNeighborhood> crateApiKnowledgeGraphGetEntityNeighborhood({required String entity , required int maxHops , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(entity, serializer);
+sse_encode_u_32(maxHops, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_graph_neighborhood,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiKnowledgeGraphGetEntityNeighborhoodConstMeta,
+            argValues: [entity, maxHops, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKnowledgeGraphGetEntityNeighborhoodConstMeta => const TaskConstMeta(
+            debugName: "get_entity_neighborhood",
+            argNames: ["entity", "maxHops", "limit"],
+        );
+        
+
+@override Future<List<ChunkSearchResult>> crateApiKnowledgeGraphGetEntitySupportingChunks({required String entity , required int maxHops , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(entity, serializer);
+sse_encode_u_32(maxHops, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiKnowledgeGraphGetEntitySupportingChunksConstMeta,
+            argValues: [entity, maxHops, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKnowledgeGraphGetEntitySupportingChunksConstMeta => const TaskConstMeta(
+            debugName: "get_entity_supporting_chunks",
+            argNames: ["entity", "maxHops", "limit"],
+        );
+        
+
+@override HnswBuildConfig? crateApiHnswIndexGetHnswBuildConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_hnsw_build_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexGetHnswBuildConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexGetHnswBuildConfigConstMeta => const TaskConstMeta(
+            debugName: "get_hnsw_build_config",
+            argNames: [],
+        );
+        
+
+@override HnswConfig crateApiHnswIndexGetHnswConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hnsw_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexGetHnswConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexGetHnswConfigConstMeta => const TaskConstMeta(
+            debugName: "get_hnsw_config",
+            argNames: [],
+        );
+        
+
+@override HnswIndexStats? crateApiHnswIndexGetHnswIndexStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_hnsw_index_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexGetHnswIndexStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexGetHnswIndexStatsConstMeta => const TaskConstMeta(
+            debugName: "get_hnsw_index_stats",
+            argNames: [],
+        );
+        
+
+@override BigInt? crateApiHnswIndexGetHnswSeededBuild()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexGetHnswSeededBuildConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexGetHnswSeededBuildConstMeta => const TaskConstMeta(
+            debugName: "get_hnsw_seeded_build",
+            argNames: [],
+        );
+        
+
+@override IncrementalConfig crateApiIncrementalIndexGetIncrementalConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_incremental_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexGetIncrementalConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexGetIncrementalConfigConstMeta => const TaskConstMeta(
+            debugName: "get_incremental_config",
+            argNames: [],
+        );
+        
+
+@override IndexConfig crateApiHnswIndexGetIndexConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexGetIndexConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexGetIndexConfigConstMeta => const TaskConstMeta(
+            debugName: "get_index_config",
+            argNames: [],
+        );
+        
+
+@override KeywordBackend crateApiFtsKeywordGetKeywordBackend()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_keyword_backend,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFtsKeywordGetKeywordBackendConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFtsKeywordGetKeywordBackendConstMeta => const TaskConstMeta(
+            debugName: "get_keyword_backend",
+            argNames: [],
+        );
+        
+
+@override LanguageAnalyzer crateApiAnalyzersGetLanguageAnalyzer({required String language })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(language, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_language_analyzer,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalyzersGetLanguageAnalyzerConstMeta,
+            argValues: [language],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzersGetLanguageAnalyzerConstMeta => const TaskConstMeta(
+            debugName: "get_language_analyzer",
+            argNames: ["language"],
+        );
+        
+
+@override AuditReport? crateApiAuditGetLastAuditReport()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_audit_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAuditGetLastAuditReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditGetLastAuditReportConstMeta => const TaskConstMeta(
+            debugName: "get_last_audit_report",
+            argNames: [],
+        );
+        
+
+@override MaintenanceReport? crateApiMaintenanceGetLastMaintenanceReport()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_maintenance_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceGetLastMaintenanceReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceGetLastMaintenanceReportConstMeta => const TaskConstMeta(
+            debugName: "get_last_maintenance_report",
+            argNames: [],
+        );
+        
+
+@override LastRetrieval? crateApiRetrievalStateGetLastRetrieval()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_last_retrieval,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRetrievalStateGetLastRetrievalConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetrievalStateGetLastRetrievalConstMeta => const TaskConstMeta(
+            debugName: "get_last_retrieval",
+            argNames: [],
+        );
+        
+
+@override String crateApiLoggerGetLogLevel()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggerGetLogLevelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerGetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "get_log_level",
+            argNames: [],
+        );
+        
+
+@override MaintenanceConfig crateApiMaintenanceGetMaintenanceConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_maintenance_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceGetMaintenanceConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceGetMaintenanceConfigConstMeta => const TaskConstMeta(
+            debugName: "get_maintenance_config",
+            argNames: [],
+        );
+        
+
+@override MemoryProfile crateApiMemoryBudgetGetMemoryProfile()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_profile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMemoryBudgetGetMemoryProfileConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryBudgetGetMemoryProfileConstMeta => const TaskConstMeta(
+            debugName: "get_memory_profile",
+            argNames: [],
+        );
+        
+
+@override MemoryUsage crateApiMemoryBudgetGetMemoryUsage()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_usage,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMemoryBudgetGetMemoryUsageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryBudgetGetMemoryUsageConstMeta => const TaskConstMeta(
+            debugName: "get_memory_usage",
+            argNames: [],
+        );
+        
+
+@override EngineMetrics crateApiMetricsGetMetrics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_engine_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetricsGetMetricsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsGetMetricsConstMeta => const TaskConstMeta(
+            debugName: "get_metrics",
+            argNames: [],
+        );
+        
+
+@override Future<(int,int,int)?> crateApiDbPoolGetPoolStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_u_32_u_32_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbPoolGetPoolStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbPoolGetPoolStatsConstMeta => const TaskConstMeta(
+            debugName: "get_pool_stats",
+            argNames: [],
+        );
+        
+
+@override QueryNormalizationConfig crateApiQueryNormalizeGetQueryNormalizationConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_normalization_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryNormalizeGetQueryNormalizationConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryNormalizeGetQueryNormalizationConfigConstMeta => const TaskConstMeta(
+            debugName: "get_query_normalization_config",
+            argNames: [],
+        );
+        
+
+@override Future<List<QuerySuggestion>> crateApiQueryHistoryGetQuerySuggestions({required String prefix })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_query_suggestion,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiQueryHistoryGetQuerySuggestionsConstMeta,
+            argValues: [prefix],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryHistoryGetQuerySuggestionsConstMeta => const TaskConstMeta(
+            debugName: "get_query_suggestions",
+            argNames: ["prefix"],
+        );
+        
+
+@override List<PanicEvent> crateApiPanicReportGetRecentPanics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_panic_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPanicReportGetRecentPanicsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPanicReportGetRecentPanicsConstMeta => const TaskConstMeta(
+            debugName: "get_recent_panics",
+            argNames: [],
+        );
+        
+
+@override Future<ReembeddingJob?> crateApiReembeddingGetReembeddingJob()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_reembedding_job,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiReembeddingGetReembeddingJobConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReembeddingGetReembeddingJobConstMeta => const TaskConstMeta(
+            debugName: "get_reembedding_job",
+            argNames: [],
+        );
+        
+
+@override RetrievalPreset crateApiIntentPresetsGetRetrievalPreset({required String intentType })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(intentType, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_retrieval_preset,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIntentPresetsGetRetrievalPresetConstMeta,
+            argValues: [intentType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIntentPresetsGetRetrievalPresetConstMeta => const TaskConstMeta(
+            debugName: "get_retrieval_preset",
+            argNames: ["intentType"],
+        );
+        
+
+@override List<String> crateApiSemanticChunkerGetSectionPatterns()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerGetSectionPatternsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerGetSectionPatternsConstMeta => const TaskConstMeta(
+            debugName: "get_section_patterns",
+            argNames: [],
+        );
+        
+
+@override Future<String?> crateApiSourceRagGetSource({required PlatformInt64 sourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetSourceConstMeta,
+            argValues: [sourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetSourceConstMeta => const TaskConstMeta(
+            debugName: "get_source",
+            argNames: ["sourceId"],
+        );
+        
+
+@override Future<int> crateApiSourceRagGetSourceChunkCount({required PlatformInt64 sourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_i_32,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagAddChunksConstMeta,
-        argValues: [sourceId, chunks],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagAddChunksConstMeta => const TaskConstMeta(
-    debugName: "add_chunks",
-    argNames: ["sourceId", "chunks"],
-  );
-
-  @override
-  Future<AddDocumentResult> crateApiSimpleRagAddDocument({
-    required String content,
-    required List<double> embedding,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          sse_encode_list_prim_f_32_loose(embedding, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_add_document_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagAddDocumentConstMeta,
-        argValues: [content, embedding],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagAddDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_document",
-        argNames: ["content", "embedding"],
-      );
-
-  @override
-  Future<void> crateApiSimpleRagAddDocumentSimple({
-    required String content,
-    required List<double> embedding,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          sse_encode_list_prim_f_32_loose(embedding, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetSourceChunkCountConstMeta,
+            argValues: [sourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetSourceChunkCountConstMeta => const TaskConstMeta(
+            debugName: "get_source_chunk_count",
+            argNames: ["sourceId"],
+        );
+        
+
+@override Future<List<String>> crateApiSourceRagGetSourceChunks({required PlatformInt64 sourceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetSourceChunksConstMeta,
+            argValues: [sourceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetSourceChunksConstMeta => const TaskConstMeta(
+            debugName: "get_source_chunks",
+            argNames: ["sourceId"],
+        );
+        
+
+@override SourceIndexConfig crateApiSourceIndexGetSourceIndexConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_source_index_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSourceIndexGetSourceIndexConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceIndexGetSourceIndexConfigConstMeta => const TaskConstMeta(
+            debugName: "get_source_index_config",
+            argNames: [],
+        );
+        
+
+@override Future<SourceStats> crateApiSourceRagGetSourceStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_source_stats,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagGetSourceStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagGetSourceStatsConstMeta => const TaskConstMeta(
+            debugName: "get_source_stats",
+            argNames: [],
+        );
+        
+
+@override int crateApiTokenizerGetVocabSize()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTokenizerGetVocabSizeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTokenizerGetVocabSizeConstMeta => const TaskConstMeta(
+            debugName: "get_vocab_size",
+            argNames: [],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<HnswConfig> crateApiHnswIndexHnswConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hnsw_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexHnswConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexHnswConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "hnsw_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiHnswIndexHnswInsert({required PlatformInt64 id , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexHnswInsertConstMeta,
+            argValues: [id, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexHnswInsertConstMeta => const TaskConstMeta(
+            debugName: "hnsw_insert",
+            argNames: ["id", "embedding"],
+        );
+        
+
+@override Future<HybridSearchOptions> crateApiHybridSearchHybridSearchOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hybrid_search_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchHybridSearchOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchHybridSearchOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "hybrid_search_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<HybridSearchResponse> crateApiHybridSearchHybridSearchResponseDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hybrid_search_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchHybridSearchResponseDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchHybridSearchResponseDefaultConstMeta => const TaskConstMeta(
+            debugName: "hybrid_search_response_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiIncrementalIndexIncrementalAdd({required BufferTable table , required PlatformInt64 docId , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+sse_encode_i_64(docId, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexIncrementalAddConstMeta,
+            argValues: [table, docId, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexIncrementalAddConstMeta => const TaskConstMeta(
+            debugName: "incremental_add",
+            argNames: ["table", "docId", "embedding"],
+        );
+        
+
+@override Future<void> crateApiIncrementalIndexIncrementalAddBatch({required BufferTable table , required List<(PlatformInt64,Float32List)> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+sse_encode_list_record_i_64_list_prim_f_32_strict(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexIncrementalAddBatchConstMeta,
+            argValues: [table, docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexIncrementalAddBatchConstMeta => const TaskConstMeta(
+            debugName: "incremental_add_batch",
+            argNames: ["table", "docs"],
+        );
+        
+
+@override Future<IncrementalConfig> crateApiIncrementalIndexIncrementalConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_incremental_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexIncrementalConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexIncrementalConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "incremental_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiIncrementalIndexIncrementalRemove({required BufferTable table , required PlatformInt64 docId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+sse_encode_i_64(docId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexIncrementalRemoveConstMeta,
+            argValues: [table, docId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexIncrementalRemoveConstMeta => const TaskConstMeta(
+            debugName: "incremental_remove",
+            argNames: ["table", "docId"],
+        );
+        
+
+@override Future<List<IncrementalSearchResult>> crateApiIncrementalIndexIncrementalSearch({required BufferTable table , required List<double> queryEmbedding , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_incremental_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexIncrementalSearchConstMeta,
+            argValues: [table, queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexIncrementalSearchConstMeta => const TaskConstMeta(
+            debugName: "incremental_search",
+            argNames: ["table", "queryEmbedding", "topK"],
+        );
+        
+
+@override Future<IndexConfig> crateApiHnswIndexIndexConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexIndexConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexIndexConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "index_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<IndexConsistency> crateApiIndexFreshnessIndexConsistencyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_consistency,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessIndexConsistencyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessIndexConsistencyDefaultConstMeta => const TaskConstMeta(
+            debugName: "index_consistency_default",
+            argNames: [],
+        );
+        
+
+@override Future<IndexDivergence> crateApiIndexFreshnessIndexDivergenceDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_divergence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessIndexDivergenceDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessIndexDivergenceDefaultConstMeta => const TaskConstMeta(
+            debugName: "index_divergence_default",
+            argNames: [],
+        );
+        
+
+@override Future<IndexStaleness> crateApiIndexFreshnessIndexStalenessDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_staleness,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessIndexStalenessDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessIndexStalenessDefaultConstMeta => const TaskConstMeta(
+            debugName: "index_staleness_default",
+            argNames: [],
+        );
+        
+
+@override Future<IngestReport> crateApiIngestIngestDocument({required IngestInput input , required IngestOptions options , required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestProgress) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_ingest_input(input, serializer);
+sse_encode_box_autoadd_ingest_options(options, serializer);
+sse_encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(embed, serializer);
+sse_encode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ingest_report,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestIngestDocumentConstMeta,
+            argValues: [input, options, embed, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestIngestDocumentConstMeta => const TaskConstMeta(
+            debugName: "ingest_document",
+            argNames: ["input", "options", "embed", "onProgress"],
+        );
+        
+
+@override Future<IngestOptions> crateApiIngestIngestOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ingest_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIngestIngestOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestIngestOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "ingest_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleRagInitDb()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagInitDbConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagInitDbConstMeta => const TaskConstMeta(
+            debugName: "init_db",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDbPoolInitDbPool({required String dbPath , required int maxSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+sse_encode_u_32(maxSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiDbPoolInitDbPoolConstMeta,
+            argValues: [dbPath, maxSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbPoolInitDbPoolConstMeta => const TaskConstMeta(
+            debugName: "init_db_pool",
+            argNames: ["dbPath", "maxSize"],
+        );
+        
+
+@override Stream<String> crateApiLoggerInitLogStream()  { 
+            final sink = RustStreamSink<String>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_String_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggerInitLogStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggerInitLogStreamConstMeta => const TaskConstMeta(
+            debugName: "init_log_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiLoggerInitLogger()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggerInitLoggerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta => const TaskConstMeta(
+            debugName: "init_logger",
+            argNames: [],
+        );
+        
+
+@override Stream<ProgressEvent> crateApiProgressInitProgressStream()  { 
+            final sink = RustStreamSink<ProgressEvent>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_progress_event_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProgressInitProgressStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiProgressInitProgressStreamConstMeta => const TaskConstMeta(
+            debugName: "init_progress_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiSourceRagInitSourceDb()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagInitSourceDbConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagInitSourceDbConstMeta => const TaskConstMeta(
+            debugName: "init_source_db",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTokenizerInitTokenizer({required String tokenizerPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(tokenizerPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTokenizerInitTokenizerConstMeta,
+            argValues: [tokenizerPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTokenizerInitTokenizerConstMeta => const TaskConstMeta(
+            debugName: "init_tokenizer",
+            argNames: ["tokenizerPath"],
+        );
+        
+
+@override Future<void> crateApiNamedIndexInsertHnswNamed({required String name , required List<(PlatformInt64,Float32List)> points })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_record_i_64_list_prim_f_32_strict(points, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexInsertHnswNamedConstMeta,
+            argValues: [name, points],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexInsertHnswNamedConstMeta => const TaskConstMeta(
+            debugName: "insert_hnsw_named",
+            argNames: ["name", "points"],
+        );
+        
+
+@override void crateApiPanicReportInstallPanicHook()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPanicReportInstallPanicHookConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPanicReportInstallPanicHookConstMeta => const TaskConstMeta(
+            debugName: "install_panic_hook",
+            argNames: [],
+        );
+        
+
+@override bool crateApiBackgroundTasksIsBackgroundWorkerRunning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksIsBackgroundWorkerRunningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksIsBackgroundWorkerRunningConstMeta => const TaskConstMeta(
+            debugName: "is_background_worker_running",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiBm25SearchIsBm25IndexLoaded()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchIsBm25IndexLoadedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchIsBm25IndexLoadedConstMeta => const TaskConstMeta(
+            debugName: "is_bm25_index_loaded",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiSourceRagIsChunkBm25IndexLoaded()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSourceRagIsChunkBm25IndexLoadedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagIsChunkBm25IndexLoadedConstMeta => const TaskConstMeta(
+            debugName: "is_chunk_bm25_index_loaded",
+            argNames: [],
+        );
+        
+
+@override bool crateApiContentCryptoIsContentEncryptionEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiContentCryptoIsContentEncryptionEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentCryptoIsContentEncryptionEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_content_encryption_enabled",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiHnswIndexIsHnswIndexLoaded()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexIsHnswIndexLoadedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexIsHnswIndexLoadedConstMeta => const TaskConstMeta(
+            debugName: "is_hnsw_index_loaded",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIngestJobsIsIngestWorkerRunning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsIsIngestWorkerRunningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsIsIngestWorkerRunningConstMeta => const TaskConstMeta(
+            debugName: "is_ingest_worker_running",
+            argNames: [],
+        );
+        
+
+@override bool crateApiKnowledgeGraphIsKnowledgeGraphEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKnowledgeGraphIsKnowledgeGraphEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKnowledgeGraphIsKnowledgeGraphEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_knowledge_graph_enabled",
+            argNames: [],
+        );
+        
+
+@override bool crateApiMaintenanceIsMaintenanceRunning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceIsMaintenanceRunningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceIsMaintenanceRunningConstMeta => const TaskConstMeta(
+            debugName: "is_maintenance_running",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiDbPoolIsPoolInitialized()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbPoolIsPoolInitializedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbPoolIsPoolInitializedConstMeta => const TaskConstMeta(
+            debugName: "is_pool_initialized",
+            argNames: [],
+        );
+        
+
+@override bool crateApiEmbeddingStorageIsQuantizedEmbeddingStorage()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEmbeddingStorageIsQuantizedEmbeddingStorageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddingStorageIsQuantizedEmbeddingStorageConstMeta => const TaskConstMeta(
+            debugName: "is_quantized_embedding_storage",
+            argNames: [],
+        );
+        
+
+@override bool crateApiSearchAnalyticsIsSearchAnalyticsEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchAnalyticsIsSearchAnalyticsEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAnalyticsIsSearchAnalyticsEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_search_analytics_enabled",
+            argNames: [],
+        );
+        
+
+@override bool crateApiSearchTraceIsSearchTracingEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchTraceIsSearchTracingEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchTraceIsSearchTracingEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_search_tracing_enabled",
+            argNames: [],
+        );
+        
+
+@override Future<KeywordBackend> crateApiFtsKeywordKeywordBackendDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_keyword_backend,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFtsKeywordKeywordBackendDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFtsKeywordKeywordBackendDefaultConstMeta => const TaskConstMeta(
+            debugName: "keyword_backend_default",
+            argNames: [],
+        );
+        
+
+@override Future<Int64List> crateApiRetrievalStateLastRetrievalSourceIds({required LastRetrieval that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_last_retrieval(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRetrievalStateLastRetrievalSourceIdsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetrievalStateLastRetrievalSourceIdsConstMeta => const TaskConstMeta(
+            debugName: "last_retrieval_source_ids",
+            argNames: ["that"],
+        );
+        
+
+@override List<BackgroundTask> crateApiBackgroundTasksListBackgroundTasks()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_background_task,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksListBackgroundTasksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksListBackgroundTasksConstMeta => const TaskConstMeta(
+            debugName: "list_background_tasks",
+            argNames: [],
+        );
+        
+
+@override List<NamedBm25IndexInfo> crateApiNamedBm25ListBm25IndexesNamed()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_named_bm_25_index_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25ListBm25IndexesNamedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25ListBm25IndexesNamedConstMeta => const TaskConstMeta(
+            debugName: "list_bm25_indexes_named",
+            argNames: [],
+        );
+        
+
+@override Future<List<EntityFacet>> crateApiEntityExtractionListEntityFacets({String? entityType , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(entityType, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_entity_facet,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiEntityExtractionListEntityFacetsConstMeta,
+            argValues: [entityType, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEntityExtractionListEntityFacetsConstMeta => const TaskConstMeta(
+            debugName: "list_entity_facets",
+            argNames: ["entityType", "limit"],
+        );
+        
+
+@override List<NamedIndexInfo> crateApiNamedIndexListHnswIndexesNamed()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_named_index_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexListHnswIndexesNamedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexListHnswIndexesNamedConstMeta => const TaskConstMeta(
+            debugName: "list_hnsw_indexes_named",
+            argNames: [],
+        );
+        
+
+@override Future<List<IngestJob>> crateApiIngestJobsListIngestJobs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ingest_job,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsListIngestJobsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsListIngestJobsConstMeta => const TaskConstMeta(
+            debugName: "list_ingest_jobs",
+            argNames: [],
+        );
+        
+
+@override List<LanguageAnalyzer> crateApiAnalyzersListLanguageAnalyzers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_language_analyzer,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalyzersListLanguageAnalyzersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzersListLanguageAnalyzersConstMeta => const TaskConstMeta(
+            debugName: "list_language_analyzers",
+            argNames: [],
+        );
+        
+
+@override Future<List<SourceEntry>> crateApiSourceRagListSources()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_source_entry,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagListSourcesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagListSourcesConstMeta => const TaskConstMeta(
+            debugName: "list_sources",
+            argNames: [],
+        );
+        
+
+@override List<SynonymEntry> crateApiSynonymsListSynonyms()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_synonym_entry,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSynonymsListSynonymsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSynonymsListSynonymsConstMeta => const TaskConstMeta(
+            debugName: "list_synonyms",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiTenantListTenants()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTenantListTenantsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantListTenantsConstMeta => const TaskConstMeta(
+            debugName: "list_tenants",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiHnswIndexLoadHnswIndex({required String basePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexLoadHnswIndexConstMeta,
+            argValues: [basePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexLoadHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "load_hnsw_index",
+            argNames: ["basePath"],
+        );
+        
+
+@override Future<MaintenanceConfig> crateApiMaintenanceMaintenanceConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_maintenance_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceMaintenanceConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceMaintenanceConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "maintenance_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<MaintenanceReport> crateApiMaintenanceMaintenanceReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_maintenance_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceMaintenanceReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceMaintenanceReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "maintenance_report_default",
+            argNames: [],
+        );
+        
+
+@override List<StructuredChunk> crateApiSemanticChunkerMarkdownChunk({required String text , required int maxChars })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(maxChars, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_structured_chunk,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerMarkdownChunkConstMeta,
+            argValues: [text, maxChars],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerMarkdownChunkConstMeta => const TaskConstMeta(
+            debugName: "markdown_chunk",
+            argNames: ["text", "maxChars"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiIncrementalIndexMergeBufferIntoHnsw({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexMergeBufferIntoHnswConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexMergeBufferIntoHnswConstMeta => const TaskConstMeta(
+            debugName: "merge_buffer_into_hnsw",
+            argNames: ["table"],
+        );
+        
+
+@override Future<NamedIndexConfig> crateApiNamedIndexNamedIndexConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_named_index_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexNamedIndexConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexNamedIndexConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "named_index_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiIncrementalIndexNeedsMerge({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexNeedsMergeConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexNeedsMergeConstMeta => const TaskConstMeta(
+            debugName: "needs_merge",
+            argNames: ["table"],
+        );
+        
+
+@override String crateApiQueryNormalizeNormalizeQuery({required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryNormalizeNormalizeQueryConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryNormalizeNormalizeQueryConstMeta => const TaskConstMeta(
+            debugName: "normalize_query",
+            argNames: ["query"],
+        );
+        
+
+@override bool crateApiMaintenanceNotifyHostState({required bool idle , required bool charging })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(idle, serializer);
+sse_encode_bool(charging, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceNotifyHostStateConstMeta,
+            argValues: [idle, charging],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceNotifyHostStateConstMeta => const TaskConstMeta(
+            debugName: "notify_host_state",
+            argNames: ["idle", "charging"],
+        );
+        
+
+@override ParsedIntent crateApiUserIntentParseIntent({required String input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(input, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_parsed_intent,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUserIntentParseIntentConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUserIntentParseIntentConstMeta => const TaskConstMeta(
+            debugName: "parse_intent",
+            argNames: ["input"],
+        );
+        
+
+@override UserIntent crateApiUserIntentParseUserIntent({required String input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(input, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_user_intent,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUserIntentParseUserIntentConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUserIntentParseUserIntentConstMeta => const TaskConstMeta(
+            debugName: "parse_user_intent",
+            argNames: ["input"],
+        );
+        
+
+@override Future<void> crateApiIngestJobsPauseIngestJob({required PlatformInt64 jobId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(jobId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsPauseIngestJobConstMeta,
+            argValues: [jobId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsPauseIngestJobConstMeta => const TaskConstMeta(
+            debugName: "pause_ingest_job",
+            argNames: ["jobId"],
+        );
+        
+
+@override Future<bool> crateApiReembeddingPauseReembeddingJob()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiReembeddingPauseReembeddingJobConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReembeddingPauseReembeddingJobConstMeta => const TaskConstMeta(
+            debugName: "pause_reembedding_job",
+            argNames: [],
+        );
+        
+
+@override Future<StagedIngest> crateApiStagedIngestPrepareIngest({required String text , required IngestOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_box_autoadd_ingest_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_staged_ingest,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiStagedIngestPrepareIngestConstMeta,
+            argValues: [text, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStagedIngestPrepareIngestConstMeta => const TaskConstMeta(
+            debugName: "prepare_ingest",
+            argNames: ["text", "options"],
+        );
+        
+
+@override Future<Quantization> crateApiHnswIndexQuantizationDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_quantization,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexQuantizationDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexQuantizationDefaultConstMeta => const TaskConstMeta(
+            debugName: "quantization_default",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiEmbeddingStorageQuantizeStoredEmbeddings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiEmbeddingStorageQuantizeStoredEmbeddingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddingStorageQuantizeStoredEmbeddingsConstMeta => const TaskConstMeta(
+            debugName: "quantize_stored_embeddings",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiQueryDecomposeQueryDecompositionIsDecomposed({required QueryDecomposition that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query_decomposition(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryDecomposeQueryDecompositionIsDecomposedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryDecomposeQueryDecompositionIsDecomposedConstMeta => const TaskConstMeta(
+            debugName: "query_decomposition_is_decomposed",
+            argNames: ["that"],
+        );
+        
+
+@override Future<QueryNormalizationConfig> crateApiQueryNormalizeQueryNormalizationConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_query_normalization_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryNormalizeQueryNormalizationConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryNormalizeQueryNormalizationConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "query_normalization_config_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiErrorRagErrorCode({required RagError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_rag_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiErrorRagErrorCodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiErrorRagErrorCodeConstMeta => const TaskConstMeta(
+            debugName: "rag_error_code",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiErrorRagErrorDetail({required RagError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_rag_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiErrorRagErrorDetailConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiErrorRagErrorDetailConstMeta => const TaskConstMeta(
+            debugName: "rag_error_detail",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiErrorRagErrorIsRetryable({required RagError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_rag_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiErrorRagErrorIsRetryableConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiErrorRagErrorIsRetryableConstMeta => const TaskConstMeta(
+            debugName: "rag_error_is_retryable",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiSimpleRagRebuildBm25Index()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagRebuildBm25IndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagRebuildBm25IndexConstMeta => const TaskConstMeta(
+            debugName: "rebuild_bm25_index",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSourceRagRebuildChunkBm25Index()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagRebuildChunkBm25IndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagRebuildChunkBm25IndexConstMeta => const TaskConstMeta(
+            debugName: "rebuild_chunk_bm25_index",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiAsyncOpsRebuildChunkBm25IndexAsync()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAsyncOpsRebuildChunkBm25IndexAsyncConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAsyncOpsRebuildChunkBm25IndexAsyncConstMeta => const TaskConstMeta(
+            debugName: "rebuild_chunk_bm25_index_async",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSourceRagRebuildChunkHnswIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagRebuildChunkHnswIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagRebuildChunkHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "rebuild_chunk_hnsw_index",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiAsyncOpsRebuildChunkHnswIndexAsync()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagAddDocumentSimpleConstMeta,
-        argValues: [content, embedding],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagAddDocumentSimpleConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_document_simple",
-        argNames: ["content", "embedding"],
-      );
-
-  @override
-  Future<AddSourceResult> crateApiSourceRagAddSource({
-    required String content,
-    String? metadata,
-    String? name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          sse_encode_opt_String(metadata, serializer);
-          sse_encode_opt_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_add_source_result,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagAddSourceConstMeta,
-        argValues: [content, metadata, name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagAddSourceConstMeta => const TaskConstMeta(
-    debugName: "add_source",
-    argNames: ["content", "metadata", "name"],
-  );
-
-  @override
-  Future<void> crateApiBm25SearchBm25AddDocument({
-    required PlatformInt64 docId,
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(docId, serializer);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAsyncOpsRebuildChunkHnswIndexAsyncConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAsyncOpsRebuildChunkHnswIndexAsyncConstMeta => const TaskConstMeta(
+            debugName: "rebuild_chunk_hnsw_index_async",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSourceRagRebuildChunkHnswIndexWithConfig({HnswBuildConfig? config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_hnsw_build_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25AddDocumentConstMeta,
-        argValues: [docId, content],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25AddDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "bm25_add_document",
-        argNames: ["docId", "content"],
-      );
-
-  @override
-  Future<void> crateApiBm25SearchBm25AddDocuments({
-    required List<(PlatformInt64, String)> docs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_record_i_64_string(docs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagRebuildChunkHnswIndexWithConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagRebuildChunkHnswIndexWithConfigConstMeta => const TaskConstMeta(
+            debugName: "rebuild_chunk_hnsw_index_with_config",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiSimpleRagRebuildHnswIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagRebuildHnswIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagRebuildHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "rebuild_hnsw_index",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiQueryHistoryRecordQuery({required String queryText , required int resultCount })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_u_32(resultCount, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiQueryHistoryRecordQueryConstMeta,
+            argValues: [queryText, resultCount],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryHistoryRecordQueryConstMeta => const TaskConstMeta(
+            debugName: "record_query",
+            argNames: ["queryText", "resultCount"],
+        );
+        
+
+@override void crateApiAnalyzersRegisterLanguageAnalyzer({required LanguageAnalyzer analyzer })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_language_analyzer(analyzer, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAnalyzersRegisterLanguageAnalyzerConstMeta,
+            argValues: [analyzer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzersRegisterLanguageAnalyzerConstMeta => const TaskConstMeta(
+            debugName: "register_language_analyzer",
+            argNames: ["analyzer"],
+        );
+        
+
+@override Future<int> crateApiSynonymsRemoveSynonyms({required String term , List<String>? expansions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(term, serializer);
+sse_encode_opt_list_String(expansions, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSynonymsRemoveSynonymsConstMeta,
+            argValues: [term, expansions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSynonymsRemoveSynonymsConstMeta => const TaskConstMeta(
+            debugName: "remove_synonyms",
+            argNames: ["term", "expansions"],
+        );
+        
+
+@override Future<bool> crateApiSearchAnalyticsReportSearchFeedback({required String queryText , required PlatformInt64 chunkId , required SearchFeedbackAction action })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_i_64(chunkId, serializer);
+sse_encode_search_feedback_action(action, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSearchAnalyticsReportSearchFeedbackConstMeta,
+            argValues: [queryText, chunkId, action],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAnalyticsReportSearchFeedbackConstMeta => const TaskConstMeta(
+            debugName: "report_search_feedback",
+            argNames: ["queryText", "chunkId", "action"],
+        );
+        
+
+@override void crateApiAnalyzersResetLanguageAnalyzers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25AddDocumentsConstMeta,
-        argValues: [docs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25AddDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "bm25_add_documents", argNames: ["docs"]);
-
-  @override
-  Future<void> crateApiBm25SearchBm25ClearIndex() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzersResetLanguageAnalyzersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzersResetLanguageAnalyzersConstMeta => const TaskConstMeta(
+            debugName: "reset_language_analyzers",
+            argNames: [],
+        );
+        
+
+@override void crateApiLoggerResetLogLevels()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggerResetLogLevelsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerResetLogLevelsConstMeta => const TaskConstMeta(
+            debugName: "reset_log_levels",
+            argNames: [],
+        );
+        
+
+@override void crateApiMetricsResetMetrics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25ClearIndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25ClearIndexConstMeta =>
-      const TaskConstMeta(debugName: "bm25_clear_index", argNames: []);
-
-  @override
-  Future<BigInt> crateApiBm25SearchBm25GetDocumentCount() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+        )
+        ,
+            constMeta: kCrateApiMetricsResetMetricsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsResetMetricsConstMeta => const TaskConstMeta(
+            debugName: "reset_metrics",
+            argNames: [],
+        );
+        
+
+@override void crateApiIntentPresetsResetRetrievalPresets()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25GetDocumentCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25GetDocumentCountConstMeta =>
-      const TaskConstMeta(debugName: "bm25_get_document_count", argNames: []);
-
-  @override
-  Future<void> crateApiBm25SearchBm25RemoveDocument({
-    required PlatformInt64 docId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(docId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIntentPresetsResetRetrievalPresetsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIntentPresetsResetRetrievalPresetsConstMeta => const TaskConstMeta(
+            debugName: "reset_retrieval_presets",
+            argNames: [],
+        );
+        
+
+@override Future<SnapshotManifest> crateApiSnapshotRestoreSnapshot({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snapshot_manifest,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSnapshotRestoreSnapshotConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSnapshotRestoreSnapshotConstMeta => const TaskConstMeta(
+            debugName: "restore_snapshot",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiIngestJobsResumeIngestJob({required PlatformInt64 jobId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(jobId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsResumeIngestJobConstMeta,
+            argValues: [jobId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsResumeIngestJobConstMeta => const TaskConstMeta(
+            debugName: "resume_ingest_job",
+            argNames: ["jobId"],
+        );
+        
+
+@override Future<RetrievalPreset> crateApiIntentPresetsRetrievalPresetDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_retrieval_preset,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25RemoveDocumentConstMeta,
-        argValues: [docId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25RemoveDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "bm25_remove_document",
-        argNames: ["docId"],
-      );
-
-  @override
-  Future<List<Bm25SearchResult>> crateApiBm25SearchBm25Search({
-    required String query,
-    required int topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
-          sse_encode_u_32(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_bm_25_search_result,
+        )
+        ,
+            constMeta: kCrateApiIntentPresetsRetrievalPresetDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIntentPresetsRetrievalPresetDefaultConstMeta => const TaskConstMeta(
+            debugName: "retrieval_preset_default",
+            argNames: [],
+        );
+        
+
+@override RewrittenQuery crateApiQueryRewriteRewriteQuery({required List<String> history , required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(history, serializer);
+sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rewritten_query,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchBm25SearchConstMeta,
-        argValues: [query, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchBm25SearchConstMeta =>
-      const TaskConstMeta(
-        debugName: "bm25_search",
-        argNames: ["query", "topK"],
-      );
-
-  @override
-  Future<void> crateApiHnswIndexBuildHnswIndex({
-    required List<(PlatformInt64, Float32List)> points,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_record_i_64_list_prim_f_32_strict(points, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiQueryRewriteRewriteQueryConstMeta,
+            argValues: [history, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryRewriteRewriteQueryConstMeta => const TaskConstMeta(
+            debugName: "rewrite_query",
+            argNames: ["history", "query"],
+        );
+        
+
+@override RewrittenQuery crateApiQueryRewriteRewriteQueryWithContext({required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rewritten_query,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQueryRewriteRewriteQueryWithContextConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryRewriteRewriteQueryWithContextConstMeta => const TaskConstMeta(
+            debugName: "rewrite_query_with_context",
+            argNames: ["query"],
+        );
+        
+
+@override Future<int> crateApiContentCryptoRotateContentKey({required String newKeyId , required List<int> newKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(newKeyId, serializer);
+sse_encode_list_prim_u_8_loose(newKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiContentCryptoRotateContentKeyConstMeta,
+            argValues: [newKeyId, newKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentCryptoRotateContentKeyConstMeta => const TaskConstMeta(
+            debugName: "rotate_content_key",
+            argNames: ["newKeyId", "newKey"],
+        );
+        
+
+@override Future<RrfConfig> crateApiHybridSearchRrfConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rrf_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchRrfConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchRrfConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "rrf_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<BenchmarkReport> crateApiBenchmarkRunBenchmark({required BenchmarkConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_benchmark_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_benchmark_report,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiHnswIndexBuildHnswIndexConstMeta,
-        argValues: [points],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexBuildHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "build_hnsw_index", argNames: ["points"]);
-
-  @override
-  double crateApiSimpleRagCalculateCosineSimilarity({
-    required List<double> vecA,
-    required List<double> vecB,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(vecA, serializer);
-          sse_encode_list_prim_f_32_loose(vecB, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_f_64,
+        )
+        ,
+            constMeta: kCrateApiBenchmarkRunBenchmarkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBenchmarkRunBenchmarkConstMeta => const TaskConstMeta(
+            debugName: "run_benchmark",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiHnswIndexSaveHnswIndex({required String basePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(basePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSaveHnswIndexConstMeta,
+            argValues: [basePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSaveHnswIndexConstMeta => const TaskConstMeta(
+            debugName: "save_hnsw_index",
+            argNames: ["basePath"],
+        );
+        
+
+@override Future<List<Bm25SearchResult>> crateApiNamedBm25SearchBm25Named({required String name , required String query , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_String(query, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bm_25_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedBm25SearchBm25NamedConstMeta,
+            argValues: [name, query, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedBm25SearchBm25NamedConstMeta => const TaskConstMeta(
+            debugName: "search_bm25_named",
+            argNames: ["name", "query", "topK"],
+        );
+        
+
+@override Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunks({required List<double> queryEmbedding , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagSearchChunksConstMeta,
+            argValues: [queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagSearchChunksConstMeta => const TaskConstMeta(
+            debugName: "search_chunks",
+            argNames: ["queryEmbedding", "topK"],
+        );
+        
+
+@override Future<List<ChunkSearchResult>> crateApiBinaryIndexSearchChunksBinary({required List<double> queryEmbedding , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiBinaryIndexSearchChunksBinaryConstMeta,
+            argValues: [queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBinaryIndexSearchChunksBinaryConstMeta => const TaskConstMeta(
+            debugName: "search_chunks_binary",
+            argNames: ["queryEmbedding", "topK"],
+        );
+        
+
+@override Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunksDiverse({required List<double> queryEmbedding , required int topK , required double diversityLambda })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_f_64(diversityLambda, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chunk_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagSearchChunksDiverseConstMeta,
+            argValues: [queryEmbedding, topK, diversityLambda],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagSearchChunksDiverseConstMeta => const TaskConstMeta(
+            debugName: "search_chunks_diverse",
+            argNames: ["queryEmbedding", "topK", "diversityLambda"],
+        );
+        
+
+@override Future<ChunkSearchPage> crateApiSourceRagSearchChunksNextPage({required String cursor , required int pageSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(cursor, serializer);
+sse_encode_u_32(pageSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_search_page,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagSearchChunksNextPageConstMeta,
+            argValues: [cursor, pageSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagSearchChunksNextPageConstMeta => const TaskConstMeta(
+            debugName: "search_chunks_next_page",
+            argNames: ["cursor", "pageSize"],
+        );
+        
+
+@override Future<ChunkSearchPage> crateApiSourceRagSearchChunksPaged({required List<double> queryEmbedding , required int pageSize , required int offset })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(pageSize, serializer);
+sse_encode_u_32(offset, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunk_search_page,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagSearchChunksPagedConstMeta,
+            argValues: [queryEmbedding, pageSize, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagSearchChunksPagedConstMeta => const TaskConstMeta(
+            debugName: "search_chunks_paged",
+            argNames: ["queryEmbedding", "pageSize", "offset"],
+        );
+        
+
+@override Future<SearchDiagnostics> crateApiSearchTraceSearchDiagnosticsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_diagnostics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchTraceSearchDiagnosticsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchTraceSearchDiagnosticsDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_diagnostics_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchFilter> crateApiHybridSearchSearchFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_filter,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleRagCalculateCosineSimilarityConstMeta,
-        argValues: [vecA, vecB],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagCalculateCosineSimilarityConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_cosine_similarity",
-        argNames: ["vecA", "vecB"],
-      );
-
-  @override
-  Future<void> crateApiSemanticChunkerChunkTypeAsStr({
-    required ChunkType that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_chunk_type(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnsw({required List<double> queryEmbedding , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hnsw_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSearchHnswConstMeta,
+            argValues: [queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSearchHnswConstMeta => const TaskConstMeta(
+            debugName: "search_hnsw",
+            argNames: ["queryEmbedding", "topK"],
+        );
+        
+
+@override Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnswFiltered({required List<double> queryEmbedding , required BigInt topK , required Int64List allowedIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_usize(topK, serializer);
+sse_encode_list_prim_i_64_strict(allowedIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hnsw_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSearchHnswFilteredConstMeta,
+            argValues: [queryEmbedding, topK, allowedIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSearchHnswFilteredConstMeta => const TaskConstMeta(
+            debugName: "search_hnsw_filtered",
+            argNames: ["queryEmbedding", "topK", "allowedIds"],
+        );
+        
+
+@override Future<List<HnswSearchResult>> crateApiNamedIndexSearchHnswNamed({required String name , required List<double> queryEmbedding , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hnsw_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiNamedIndexSearchHnswNamedConstMeta,
+            argValues: [name, queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNamedIndexSearchHnswNamedConstMeta => const TaskConstMeta(
+            debugName: "search_hnsw_named",
+            argNames: ["name", "queryEmbedding", "topK"],
+        );
+        
+
+@override Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnswWithEf({required List<double> queryEmbedding , required BigInt topK , int? efSearch })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_usize(topK, serializer);
+sse_encode_opt_box_autoadd_u_32(efSearch, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hnsw_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSearchHnswWithEfConstMeta,
+            argValues: [queryEmbedding, topK, efSearch],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSearchHnswWithEfConstMeta => const TaskConstMeta(
+            debugName: "search_hnsw_with_ef",
+            argNames: ["queryEmbedding", "topK", "efSearch"],
+        );
+        
+
+@override Future<HybridSearchResponse> crateApiHybridSearchSearchHybrid({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+sse_encode_opt_box_autoadd_hybrid_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hybrid_search_response,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridConstMeta,
+            argValues: [queryText, queryEmbedding, topK, config, filter, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid",
+            argNames: ["queryText", "queryEmbedding", "topK", "config", "filter", "options"],
+        );
+        
+
+@override Future<HybridSearchResponse> crateApiAsyncOpsSearchHybridAsync({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , HybridSearchOptions? options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+sse_encode_opt_box_autoadd_hybrid_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hybrid_search_response,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiAsyncOpsSearchHybridAsyncConstMeta,
+            argValues: [queryText, queryEmbedding, topK, config, filter, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAsyncOpsSearchHybridAsyncConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_async",
+            argNames: ["queryText", "queryEmbedding", "topK", "config", "filter", "options"],
+        );
+        
+
+@override Future<List<HybridSearchResult>> crateApiRerankSearchHybridCandidates({required String queryText , required List<double> queryEmbedding , required int candidateCount , RrfConfig? config , SearchFilter? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(candidateCount, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hybrid_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiRerankSearchHybridCandidatesConstMeta,
+            argValues: [queryText, queryEmbedding, candidateCount, config, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRerankSearchHybridCandidatesConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_candidates",
+            argNames: ["queryText", "queryEmbedding", "candidateCount", "config", "filter"],
+        );
+        
+
+@override Future<List<CompareSearchResult>> crateApiHybridSearchSearchHybridCompare({required String leftText , required List<double> leftEmbedding , required String rightText , required List<double> rightEmbedding , required int topK , RrfConfig? config , SearchFilter? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(leftText, serializer);
+sse_encode_list_prim_f_32_loose(leftEmbedding, serializer);
+sse_encode_String(rightText, serializer);
+sse_encode_list_prim_f_32_loose(rightEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_compare_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridCompareConstMeta,
+            argValues: [leftText, leftEmbedding, rightText, rightEmbedding, topK, config, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridCompareConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_compare",
+            argNames: ["leftText", "leftEmbedding", "rightText", "rightEmbedding", "topK", "config", "filter"],
+        );
+        
+
+@override Future<List<ExpandedSearchResult>> crateApiHybridSearchSearchHybridExpanded({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter , ExpansionConfig? expansion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+sse_encode_opt_box_autoadd_expansion_config(expansion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_expanded_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridExpandedConstMeta,
+            argValues: [queryText, queryEmbedding, topK, config, filter, expansion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridExpandedConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_expanded",
+            argNames: ["queryText", "queryEmbedding", "topK", "config", "filter", "expansion"],
+        );
+        
+
+@override Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridForIntent({required String intentType , required String queryText , required List<double> queryEmbedding , SearchFilter? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(intentType, serializer);
+sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hybrid_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridForIntentConstMeta,
+            argValues: [intentType, queryText, queryEmbedding, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridForIntentConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_for_intent",
+            argNames: ["intentType", "queryText", "queryEmbedding", "filter"],
+        );
+        
+
+@override Future<List<String>> crateApiHybridSearchSearchHybridSimple({required String queryText , required List<double> queryEmbedding , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridSimpleConstMeta,
+            argValues: [queryText, queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridSimpleConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_simple",
+            argNames: ["queryText", "queryEmbedding", "topK"],
+        );
+        
+
+@override Stream<HybridSearchEvent> crateApiHybridSearchSearchHybridStream({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter })  { 
+            final sink = RustStreamSink<HybridSearchEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+sse_encode_StreamSink_hybrid_search_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridStreamConstMeta,
+            argValues: [queryText, queryEmbedding, topK, config, filter, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridStreamConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_stream",
+            argNames: ["queryText", "queryEmbedding", "topK", "config", "filter", "sink"],
+        );
+        
+
+@override Future<TracedHybridSearch> crateApiHybridSearchSearchHybridTraced({required String queryText , required List<double> queryEmbedding , required int topK , RrfConfig? config , SearchFilter? filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_opt_box_autoadd_rrf_config(config, serializer);
+sse_encode_opt_box_autoadd_search_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_traced_hybrid_search,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridTracedConstMeta,
+            argValues: [queryText, queryEmbedding, topK, config, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridTracedConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_traced",
+            argNames: ["queryText", "queryEmbedding", "topK", "config", "filter"],
+        );
+        
+
+@override Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridWeighted({required String queryText , required List<double> queryEmbedding , required int topK , required double vectorWeight , required double bm25Weight })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryText, serializer);
+sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_f_64(vectorWeight, serializer);
+sse_encode_f_64(bm25Weight, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hybrid_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHybridSearchSearchHybridWeightedConstMeta,
+            argValues: [queryText, queryEmbedding, topK, vectorWeight, bm25Weight],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHybridSearchSearchHybridWeightedConstMeta => const TaskConstMeta(
+            debugName: "search_hybrid_weighted",
+            argNames: ["queryText", "queryEmbedding", "topK", "vectorWeight", "bm25Weight"],
+        );
+        
+
+@override Future<List<MemorySearchResult>> crateApiChatMemorySearchMemory({required List<double> queryEmbedding , required int topK , required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_memory_search_result,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiChatMemorySearchMemoryConstMeta,
+            argValues: [queryEmbedding, topK, conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatMemorySearchMemoryConstMeta => const TaskConstMeta(
+            debugName: "search_memory",
+            argNames: ["queryEmbedding", "topK", "conversationId"],
+        );
+        
+
+@override Future<List<String>> crateApiSimpleRagSearchSimilar({required List<double> queryEmbedding , required int topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
+sse_encode_u_32(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagSearchSimilarConstMeta,
+            argValues: [queryEmbedding, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagSearchSimilarConstMeta => const TaskConstMeta(
+            debugName: "search_similar",
+            argNames: ["queryEmbedding", "topK"],
+        );
+        
+
+@override List<SemanticChunk> crateApiSemanticChunkerSemanticChunk({required String text , required int maxChars })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(maxChars, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_semantic_chunk,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerChunkTypeAsStrConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerChunkTypeAsStrConstMeta =>
-      const TaskConstMeta(debugName: "chunk_type_as_str", argNames: ["that"]);
-
-  @override
-  Future<ChunkType> crateApiSemanticChunkerChunkTypeFromStr({
-    required String s,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(s, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_chunk_type,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerSemanticChunkConstMeta,
+            argValues: [text, maxChars],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerSemanticChunkConstMeta => const TaskConstMeta(
+            debugName: "semantic_chunk",
+            argNames: ["text", "maxChars"],
+        );
+        
+
+@override List<SemanticChunk> crateApiSemanticChunkerSemanticChunkByTokens({required String text , required int maxTokens })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(maxTokens, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_semantic_chunk,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerChunkTypeFromStrConstMeta,
-        argValues: [s],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerChunkTypeFromStrConstMeta =>
-      const TaskConstMeta(debugName: "chunk_type_from_str", argNames: ["s"]);
-
-  @override
-  ChunkType crateApiSemanticChunkerClassifyChunk({required String text}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_chunk_type,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerSemanticChunkByTokensConstMeta,
+            argValues: [text, maxTokens],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerSemanticChunkByTokensConstMeta => const TaskConstMeta(
+            debugName: "semantic_chunk_by_tokens",
+            argNames: ["text", "maxTokens"],
+        );
+        
+
+@override List<SemanticChunk> crateApiSemanticChunkerSemanticChunkWithOverlap({required String text , required int maxChars , required int overlapChars })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(maxChars, serializer);
+sse_encode_i_32(overlapChars, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_semantic_chunk,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerSemanticChunkWithOverlapConstMeta,
+            argValues: [text, maxChars, overlapChars],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerSemanticChunkWithOverlapConstMeta => const TaskConstMeta(
+            debugName: "semantic_chunk_with_overlap",
+            argNames: ["text", "maxChars", "overlapChars"],
+        );
+        
+
+@override Future<BigInt> crateApiCompressionUtilsSentenceHash({required String sentence })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sentence, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerClassifyChunkConstMeta,
-        argValues: [text],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerClassifyChunkConstMeta =>
-      const TaskConstMeta(debugName: "classify_chunk", argNames: ["text"]);
-
-  @override
-  Future<void> crateApiSimpleRagClearAllDocuments() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsSentenceHashConstMeta,
+            argValues: [sentence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsSentenceHashConstMeta => const TaskConstMeta(
+            debugName: "sentence_hash",
+            argNames: ["sentence"],
+        );
+        
+
+@override void crateApiTenantSetActiveTenant({required String tenantId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(tenantId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagClearAllDocumentsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagClearAllDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "clear_all_documents", argNames: []);
-
-  @override
-  Future<void> crateApiIncrementalIndexClearBuffer() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTenantSetActiveTenantConstMeta,
+            argValues: [tenantId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTenantSetActiveTenantConstMeta => const TaskConstMeta(
+            debugName: "set_active_tenant",
+            argNames: ["tenantId"],
+        );
+        
+
+@override void crateApiBm25SearchSetBm25Config({required Bm25Config config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_bm_25_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchSetBm25ConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchSetBm25ConfigConstMeta => const TaskConstMeta(
+            debugName: "set_bm25_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiFuzzyTermsSetBm25FuzzyMatching({Bm25FuzzyConfig? config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_bm_25_fuzzy_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 277)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiFuzzyTermsSetBm25FuzzyMatchingConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFuzzyTermsSetBm25FuzzyMatchingConstMeta => const TaskConstMeta(
+            debugName: "set_bm25_fuzzy_matching",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiBm25SearchSetBm25IndexOptions({required Bm25IndexOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_bm_25_index_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 278)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiBm25SearchSetBm25IndexOptionsConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBm25SearchSetBm25IndexOptionsConstMeta => const TaskConstMeta(
+            debugName: "set_bm25_index_options",
+            argNames: ["options"],
+        );
+        
+
+@override Future<void> crateApiClassificationRulesSetClassificationRules({required List<ClassificationRule> rules })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_classification_rule(rules, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 279, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiClassificationRulesSetClassificationRulesConstMeta,
+            argValues: [rules],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClassificationRulesSetClassificationRulesConstMeta => const TaskConstMeta(
+            debugName: "set_classification_rules",
+            argNames: ["rules"],
+        );
+        
+
+@override void crateApiContentCryptoSetContentEncryptionKey({required String keyId , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(keyId, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 280)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiContentCryptoSetContentEncryptionKeyConstMeta,
+            argValues: [keyId, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentCryptoSetContentEncryptionKeyConstMeta => const TaskConstMeta(
+            debugName: "set_content_encryption_key",
+            argNames: ["keyId", "key"],
+        );
+        
+
+@override void crateApiDbRetrySetDbRetryConfig({required DbRetryConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_db_retry_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 281)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDbRetrySetDbRetryConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDbRetrySetDbRetryConfigConstMeta => const TaskConstMeta(
+            debugName: "set_db_retry_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiEngineInfoSetEmbeddingModelName({String? name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 282)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexClearBufferConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexClearBufferConstMeta =>
-      const TaskConstMeta(debugName: "clear_buffer", argNames: []);
-
-  @override
-  Future<void> crateApiHnswIndexClearHnswIndex() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEngineInfoSetEmbeddingModelNameConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInfoSetEmbeddingModelNameConstMeta => const TaskConstMeta(
+            debugName: "set_embedding_model_name",
+            argNames: ["name"],
+        );
+        
+
+@override void crateApiEntityExtractionSetEntityGazetteer({required List<GazetteerEntry> entries })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_gazetteer_entry(entries, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 283)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiHnswIndexClearHnswIndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexClearHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "clear_hnsw_index", argNames: []);
-
-  @override
-  Future<void> crateApiDbPoolCloseDbPool() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEntityExtractionSetEntityGazetteerConstMeta,
+            argValues: [entries],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEntityExtractionSetEntityGazetteerConstMeta => const TaskConstMeta(
+            debugName: "set_entity_gazetteer",
+            argNames: ["entries"],
+        );
+        
+
+@override void crateApiHnswIndexSetHnswBuildConfig({HnswBuildConfig? config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_hnsw_build_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 284)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDbPoolCloseDbPoolConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDbPoolCloseDbPoolConstMeta =>
-      const TaskConstMeta(debugName: "close_db_pool", argNames: []);
-
-  @override
-  void crateApiLoggerCloseLogStream() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSetHnswBuildConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSetHnswBuildConfigConstMeta => const TaskConstMeta(
+            debugName: "set_hnsw_build_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiHnswIndexSetHnswConfig({required HnswConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_hnsw_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 285)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSetHnswConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSetHnswConfigConstMeta => const TaskConstMeta(
+            debugName: "set_hnsw_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiHnswIndexSetHnswSeededBuild({BigInt? seed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_64(seed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 286)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiLoggerCloseLogStreamConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLoggerCloseLogStreamConstMeta =>
-      const TaskConstMeta(debugName: "close_log_stream", argNames: []);
-
-  @override
-  Future<CompressedText> crateApiCompressionUtilsCompressText({
-    required String text,
-    required int maxChars,
-    required CompressionOptions options,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(maxChars, serializer);
-          sse_encode_box_autoadd_compression_options(options, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_compressed_text,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsCompressTextConstMeta,
-        argValues: [text, maxChars, options],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompressionUtilsCompressTextConstMeta =>
-      const TaskConstMeta(
-        debugName: "compress_text",
-        argNames: ["text", "maxChars", "options"],
-      );
-
-  @override
-  Future<String> crateApiCompressionUtilsCompressTextSimple({
-    required String text,
-    required int level,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(level, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsCompressTextSimpleConstMeta,
-        argValues: [text, level],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompressionUtilsCompressTextSimpleConstMeta =>
-      const TaskConstMeta(
-        debugName: "compress_text_simple",
-        argNames: ["text", "level"],
-      );
-
-  @override
-  Future<CompressionOptions>
-  crateApiCompressionUtilsCompressionOptionsDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_compression_options,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsCompressionOptionsDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCompressionUtilsCompressionOptionsDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "compression_options_default",
-        argNames: [],
-      );
-
-  @override
-  String crateApiTokenizerDecodeTokens({required List<int> tokenIds}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_32_loose(tokenIds, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTokenizerDecodeTokensConstMeta,
-        argValues: [tokenIds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTokenizerDecodeTokensConstMeta =>
-      const TaskConstMeta(debugName: "decode_tokens", argNames: ["tokenIds"]);
-
-  @override
-  Future<void> crateApiSourceRagDeleteSource({
-    required PlatformInt64 sourceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSetHnswSeededBuildConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSetHnswSeededBuildConstMeta => const TaskConstMeta(
+            debugName: "set_hnsw_seeded_build",
+            argNames: ["seed"],
+        );
+        
+
+@override void crateApiIncrementalIndexSetIncrementalConfig({required IncrementalConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_incremental_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 287)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagDeleteSourceConstMeta,
-        argValues: [sourceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagDeleteSourceConstMeta =>
-      const TaskConstMeta(debugName: "delete_source", argNames: ["sourceId"]);
-
-  @override
-  Future<EmbeddingPoint> crateApiHnswIndexEmbeddingPointNew({
-    required PlatformInt64 id,
-    required List<double> embedding,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(id, serializer);
-          sse_encode_list_prim_f_32_loose(embedding, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_embedding_point,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiHnswIndexEmbeddingPointNewConstMeta,
-        argValues: [id, embedding],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexEmbeddingPointNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "embedding_point_new",
-        argNames: ["id", "embedding"],
-      );
-
-  @override
-  Future<String> crateApiDocumentParserExtractTextFromDocument({
-    required List<int> fileBytes,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(fileBytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDocumentParserExtractTextFromDocumentConstMeta,
-        argValues: [fileBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDocumentParserExtractTextFromDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_text_from_document",
-        argNames: ["fileBytes"],
-      );
-
-  @override
-  Future<String> crateApiDocumentParserExtractTextFromDocx({
-    required List<int> fileBytes,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(fileBytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDocumentParserExtractTextFromDocxConstMeta,
-        argValues: [fileBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDocumentParserExtractTextFromDocxConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_text_from_docx",
-        argNames: ["fileBytes"],
-      );
-
-  @override
-  Future<String> crateApiDocumentParserExtractTextFromPdf({
-    required List<int> fileBytes,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(fileBytes, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDocumentParserExtractTextFromPdfConstMeta,
-        argValues: [fileBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDocumentParserExtractTextFromPdfConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_text_from_pdf",
-        argNames: ["fileBytes"],
-      );
-
-  @override
-  Future<List<ChunkSearchResult>> crateApiSourceRagGetAdjacentChunks({
-    required PlatformInt64 sourceId,
-    required int minIndex,
-    required int maxIndex,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          sse_encode_i_32(minIndex, serializer);
-          sse_encode_i_32(maxIndex, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_chunk_search_result,
+        )
+        ,
+            constMeta: kCrateApiIncrementalIndexSetIncrementalConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrementalIndexSetIncrementalConfigConstMeta => const TaskConstMeta(
+            debugName: "set_incremental_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiHnswIndexSetIndexConfig({required IndexConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_index_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 288)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetAdjacentChunksConstMeta,
-        argValues: [sourceId, minIndex, maxIndex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetAdjacentChunksConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_adjacent_chunks",
-        argNames: ["sourceId", "minIndex", "maxIndex"],
-      );
-
-  @override
-  Future<List<ChunkForReembedding>>
-  crateApiSourceRagGetAllChunkIdsAndContents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_chunk_for_reembedding,
+        )
+        ,
+            constMeta: kCrateApiHnswIndexSetIndexConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHnswIndexSetIndexConfigConstMeta => const TaskConstMeta(
+            debugName: "set_index_config",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiFtsKeywordSetKeywordBackend({required KeywordBackend backend })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_keyword_backend(backend, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 289, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetAllChunkIdsAndContentsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetAllChunkIdsAndContentsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_chunk_ids_and_contents",
-        argNames: [],
-      );
-
-  @override
-  Future<List<(PlatformInt64, Float32List)>>
-  crateApiIncrementalIndexGetBufferForMerge() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_record_i_64_list_prim_f_32_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexGetBufferForMergeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexGetBufferForMergeConstMeta =>
-      const TaskConstMeta(debugName: "get_buffer_for_merge", argNames: []);
-
-  @override
-  Future<BufferStats> crateApiIncrementalIndexGetBufferStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_buffer_stats,
+        )
+        ,
+            constMeta: kCrateApiFtsKeywordSetKeywordBackendConstMeta,
+            argValues: [backend],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFtsKeywordSetKeywordBackendConstMeta => const TaskConstMeta(
+            debugName: "set_keyword_backend",
+            argNames: ["backend"],
+        );
+        
+
+@override void crateApiKnowledgeGraphSetKnowledgeGraphEnabled({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 290)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexGetBufferStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexGetBufferStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_buffer_stats", argNames: []);
-
-  @override
-  Future<PlatformInt64> crateApiSimpleRagGetDocumentCount() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
+        )
+        ,
+            constMeta: kCrateApiKnowledgeGraphSetKnowledgeGraphEnabledConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKnowledgeGraphSetKnowledgeGraphEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_knowledge_graph_enabled",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiLoggerSetLogLevel({required String level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 291)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagGetDocumentCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagGetDocumentCountConstMeta =>
-      const TaskConstMeta(debugName: "get_document_count", argNames: []);
-
-  @override
-  Future<(int, int, int)?> crateApiDbPoolGetPoolStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_record_u_32_u_32_u_32,
+        )
+        ,
+            constMeta: kCrateApiLoggerSetLogLevelConstMeta,
+            argValues: [level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerSetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "set_log_level",
+            argNames: ["level"],
+        );
+        
+
+@override void crateApiMaintenanceSetMaintenanceConfig({required MaintenanceConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_maintenance_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 292)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDbPoolGetPoolStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDbPoolGetPoolStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_pool_stats", argNames: []);
-
-  @override
-  Future<String?> crateApiSourceRagGetSource({
-    required PlatformInt64 sourceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
-          decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetSourceConstMeta,
-        argValues: [sourceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetSourceConstMeta =>
-      const TaskConstMeta(debugName: "get_source", argNames: ["sourceId"]);
-
-  @override
-  Future<int> crateApiSourceRagGetSourceChunkCount({
-    required PlatformInt64 sourceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_i_32,
-          decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetSourceChunkCountConstMeta,
-        argValues: [sourceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetSourceChunkCountConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_source_chunk_count",
-        argNames: ["sourceId"],
-      );
-
-  @override
-  Future<List<String>> crateApiSourceRagGetSourceChunks({
-    required PlatformInt64 sourceId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetSourceChunksConstMeta,
-        argValues: [sourceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetSourceChunksConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_source_chunks",
-        argNames: ["sourceId"],
-      );
-
-  @override
-  Future<SourceStats> crateApiSourceRagGetSourceStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_source_stats,
+        )
+        ,
+            constMeta: kCrateApiMaintenanceSetMaintenanceConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMaintenanceSetMaintenanceConfigConstMeta => const TaskConstMeta(
+            debugName: "set_maintenance_config",
+            argNames: ["config"],
+        );
+        
+
+@override Future<MemoryProfile> crateApiMemoryBudgetSetMemoryBudget({int? budgetMb })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_32(budgetMb, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 293, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_profile,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagGetSourceStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagGetSourceStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_source_stats", argNames: []);
-
-  @override
-  int crateApiTokenizerGetVocabSize() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+        )
+        ,
+            constMeta: kCrateApiMemoryBudgetSetMemoryBudgetConstMeta,
+            argValues: [budgetMb],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryBudgetSetMemoryBudgetConstMeta => const TaskConstMeta(
+            debugName: "set_memory_budget",
+            argNames: ["budgetMb"],
+        );
+        
+
+@override void crateApiLoggerSetModuleLogLevel({required String module , String? level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(module, serializer);
+sse_encode_opt_String(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 294)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTokenizerGetVocabSizeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTokenizerGetVocabSizeConstMeta =>
-      const TaskConstMeta(debugName: "get_vocab_size", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiIncrementalIndexIncrementalAdd({
-    required PlatformInt64 docId,
-    required List<double> embedding,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(docId, serializer);
-          sse_encode_list_prim_f_32_loose(embedding, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLoggerSetModuleLogLevelConstMeta,
+            argValues: [module, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggerSetModuleLogLevelConstMeta => const TaskConstMeta(
+            debugName: "set_module_log_level",
+            argNames: ["module", "level"],
+        );
+        
+
+@override void crateApiEmbeddingStorageSetQuantizedEmbeddingStorage({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 295)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexIncrementalAddConstMeta,
-        argValues: [docId, embedding],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexIncrementalAddConstMeta =>
-      const TaskConstMeta(
-        debugName: "incremental_add",
-        argNames: ["docId", "embedding"],
-      );
-
-  @override
-  Future<void> crateApiIncrementalIndexIncrementalAddBatch({
-    required List<(PlatformInt64, Float32List)> docs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_record_i_64_list_prim_f_32_strict(docs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEmbeddingStorageSetQuantizedEmbeddingStorageConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddingStorageSetQuantizedEmbeddingStorageConstMeta => const TaskConstMeta(
+            debugName: "set_quantized_embedding_storage",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiQueryNormalizeSetQueryNormalizationConfig({required QueryNormalizationConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query_normalization_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 296)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexIncrementalAddBatchConstMeta,
-        argValues: [docs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexIncrementalAddBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "incremental_add_batch",
-        argNames: ["docs"],
-      );
-
-  @override
-  Future<void> crateApiIncrementalIndexIncrementalRemove({
-    required PlatformInt64 docId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(docId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiQueryNormalizeSetQueryNormalizationConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryNormalizeSetQueryNormalizationConfigConstMeta => const TaskConstMeta(
+            debugName: "set_query_normalization_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiIntentPresetsSetRetrievalPreset({required String intentType , required RetrievalPreset preset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(intentType, serializer);
+sse_encode_box_autoadd_retrieval_preset(preset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 297)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexIncrementalRemoveConstMeta,
-        argValues: [docId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexIncrementalRemoveConstMeta =>
-      const TaskConstMeta(debugName: "incremental_remove", argNames: ["docId"]);
-
-  @override
-  Future<List<IncrementalSearchResult>>
-  crateApiIncrementalIndexIncrementalSearch({
-    required List<double> queryEmbedding,
-    required BigInt topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_usize(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_incremental_search_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiIncrementalIndexIncrementalSearchConstMeta,
-        argValues: [queryEmbedding, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexIncrementalSearchConstMeta =>
-      const TaskConstMeta(
-        debugName: "incremental_search",
-        argNames: ["queryEmbedding", "topK"],
-      );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIntentPresetsSetRetrievalPresetConstMeta,
+            argValues: [intentType, preset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIntentPresetsSetRetrievalPresetConstMeta => const TaskConstMeta(
+            debugName: "set_retrieval_preset",
+            argNames: ["intentType", "preset"],
+        );
+        
+
+@override void crateApiSearchAnalyticsSetSearchAnalyticsEnabled({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 298)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<void> crateApiSimpleRagInitDb() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagInitDbConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagInitDbConstMeta =>
-      const TaskConstMeta(debugName: "init_db", argNames: []);
-
-  @override
-  Future<void> crateApiDbPoolInitDbPool({
-    required String dbPath,
-    required int maxSize,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dbPath, serializer);
-          sse_encode_u_32(maxSize, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDbPoolInitDbPoolConstMeta,
-        argValues: [dbPath, maxSize],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDbPoolInitDbPoolConstMeta => const TaskConstMeta(
-    debugName: "init_db_pool",
-    argNames: ["dbPath", "maxSize"],
-  );
-
-  @override
-  Stream<String> crateApiLoggerInitLogStream() {
-    final sink = RustStreamSink<String>();
-    handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_StreamSink_String_Sse(sink, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiLoggerInitLogStreamConstMeta,
-        argValues: [sink],
-        apiImpl: this,
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiLoggerInitLogStreamConstMeta =>
-      const TaskConstMeta(debugName: "init_log_stream", argNames: ["sink"]);
-
-  @override
-  Future<void> crateApiLoggerInitLogger() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchAnalyticsSetSearchAnalyticsEnabledConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAnalyticsSetSearchAnalyticsEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_search_analytics_enabled",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiSearchTraceSetSearchTracing({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 299)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiLoggerInitLoggerConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta =>
-      const TaskConstMeta(debugName: "init_logger", argNames: []);
-
-  @override
-  Future<void> crateApiSourceRagInitSourceDb() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchTraceSetSearchTracingConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchTraceSetSearchTracingConstMeta => const TaskConstMeta(
+            debugName: "set_search_tracing",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiSemanticChunkerSetSectionPatterns({required List<String> patterns })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(patterns, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 300)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagInitSourceDbConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagInitSourceDbConstMeta =>
-      const TaskConstMeta(debugName: "init_source_db", argNames: []);
-
-  @override
-  Future<void> crateApiTokenizerInitTokenizer({required String tokenizerPath}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(tokenizerPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSemanticChunkerSetSectionPatternsConstMeta,
+            argValues: [patterns],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSemanticChunkerSetSectionPatternsConstMeta => const TaskConstMeta(
+            debugName: "set_section_patterns",
+            argNames: ["patterns"],
+        );
+        
+
+@override void crateApiSourceIndexSetSourceIndexConfig({required SourceIndexConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_source_index_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 301)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTokenizerInitTokenizerConstMeta,
-        argValues: [tokenizerPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTokenizerInitTokenizerConstMeta =>
-      const TaskConstMeta(
-        debugName: "init_tokenizer",
-        argNames: ["tokenizerPath"],
-      );
-
-  @override
-  Future<bool> crateApiBm25SearchIsBm25IndexLoaded() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBm25SearchIsBm25IndexLoadedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBm25SearchIsBm25IndexLoadedConstMeta =>
-      const TaskConstMeta(debugName: "is_bm25_index_loaded", argNames: []);
-
-  @override
-  Future<bool> crateApiSourceRagIsChunkBm25IndexLoaded() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSourceRagIsChunkBm25IndexLoadedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagIsChunkBm25IndexLoadedConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_chunk_bm25_index_loaded",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiHnswIndexIsHnswIndexLoaded() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 55,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSourceIndexSetSourceIndexConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceIndexSetSourceIndexConfigConstMeta => const TaskConstMeta(
+            debugName: "set_source_index_config",
+            argNames: ["config"],
+        );
+        
+
+@override Future<bool> crateApiCompressionUtilsShouldCompress({required String text , required int tokenThreshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_i_32(tokenThreshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 302, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiHnswIndexIsHnswIndexLoadedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexIsHnswIndexLoadedConstMeta =>
-      const TaskConstMeta(debugName: "is_hnsw_index_loaded", argNames: []);
-
-  @override
-  Future<bool> crateApiDbPoolIsPoolInitialized() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsShouldCompressConstMeta,
+            argValues: [text, tokenThreshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsShouldCompressConstMeta => const TaskConstMeta(
+            debugName: "should_compress",
+            argNames: ["text", "tokenThreshold"],
+        );
+        
+
+@override Future<SourceIndexConfig> crateApiSourceIndexSourceIndexConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 303, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_source_index_config,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDbPoolIsPoolInitializedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDbPoolIsPoolInitializedConstMeta =>
-      const TaskConstMeta(debugName: "is_pool_initialized", argNames: []);
-
-  @override
-  Future<List<SourceEntry>> crateApiSourceRagListSources() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_source_entry,
-          decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagListSourcesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagListSourcesConstMeta =>
-      const TaskConstMeta(debugName: "list_sources", argNames: []);
-
-  @override
-  Future<bool> crateApiHnswIndexLoadHnswIndex({required String basePath}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(basePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 58,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiHnswIndexLoadHnswIndexConstMeta,
-        argValues: [basePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexLoadHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "load_hnsw_index", argNames: ["basePath"]);
-
-  @override
-  List<StructuredChunk> crateApiSemanticChunkerMarkdownChunk({
-    required String text,
-    required int maxChars,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(maxChars, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_structured_chunk,
+        )
+        ,
+            constMeta: kCrateApiSourceIndexSourceIndexConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceIndexSourceIndexConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "source_index_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiCompressionUtilsSplitSentences({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 304, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerMarkdownChunkConstMeta,
-        argValues: [text, maxChars],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerMarkdownChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "markdown_chunk",
-        argNames: ["text", "maxChars"],
-      );
-
-  @override
-  Future<bool> crateApiIncrementalIndexNeedsMerge() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 60,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCompressionUtilsSplitSentencesConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionUtilsSplitSentencesConstMeta => const TaskConstMeta(
+            debugName: "split_sentences",
+            argNames: ["text"],
+        );
+        
+
+@override Future<bool> crateApiIngestJobsStartIngestWorker({required FutureOr<Float32List> Function(String) embed , required FutureOr<void> Function(IngestJob) onJobUpdate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(embed, serializer);
+sse_encode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(onJobUpdate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 305, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIncrementalIndexNeedsMergeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIncrementalIndexNeedsMergeConstMeta =>
-      const TaskConstMeta(debugName: "needs_merge", argNames: []);
-
-  @override
-  ParsedIntent crateApiUserIntentParseIntent({required String input}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(input, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_parsed_intent,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUserIntentParseIntentConstMeta,
-        argValues: [input],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUserIntentParseIntentConstMeta =>
-      const TaskConstMeta(debugName: "parse_intent", argNames: ["input"]);
-
-  @override
-  UserIntent crateApiUserIntentParseUserIntent({required String input}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(input, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_user_intent,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUserIntentParseUserIntentConstMeta,
-        argValues: [input],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUserIntentParseUserIntentConstMeta =>
-      const TaskConstMeta(debugName: "parse_user_intent", argNames: ["input"]);
-
-  @override
-  Future<void> crateApiSimpleRagRebuildBm25Index() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 63,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagRebuildBm25IndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagRebuildBm25IndexConstMeta =>
-      const TaskConstMeta(debugName: "rebuild_bm25_index", argNames: []);
-
-  @override
-  Future<void> crateApiSourceRagRebuildChunkBm25Index() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 64,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagRebuildChunkBm25IndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagRebuildChunkBm25IndexConstMeta =>
-      const TaskConstMeta(debugName: "rebuild_chunk_bm25_index", argNames: []);
-
-  @override
-  Future<void> crateApiSourceRagRebuildChunkHnswIndex() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 65,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiIngestJobsStartIngestWorkerConstMeta,
+            argValues: [embed, onJobUpdate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIngestJobsStartIngestWorkerConstMeta => const TaskConstMeta(
+            debugName: "start_ingest_worker",
+            argNames: ["embed", "onJobUpdate"],
+        );
+        
+
+@override Future<ReembeddingJob> crateApiReembeddingStartReembeddingJob({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelName, serializer);
+sse_encode_u_32(batchSize, serializer);
+sse_encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(embedBatch, serializer);
+sse_encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 306, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reembedding_job,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagRebuildChunkHnswIndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagRebuildChunkHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "rebuild_chunk_hnsw_index", argNames: []);
-
-  @override
-  Future<void> crateApiSimpleRagRebuildHnswIndex() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 66,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagRebuildHnswIndexConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagRebuildHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "rebuild_hnsw_index", argNames: []);
-
-  @override
-  Future<RrfConfig> crateApiHybridSearchRrfConfigDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 67,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_rrf_config,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiHybridSearchRrfConfigDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHybridSearchRrfConfigDefaultConstMeta =>
-      const TaskConstMeta(debugName: "rrf_config_default", argNames: []);
-
-  @override
-  Future<void> crateApiHnswIndexSaveHnswIndex({required String basePath}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(basePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 68,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiHnswIndexSaveHnswIndexConstMeta,
-        argValues: [basePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexSaveHnswIndexConstMeta =>
-      const TaskConstMeta(debugName: "save_hnsw_index", argNames: ["basePath"]);
-
-  @override
-  Future<List<ChunkSearchResult>> crateApiSourceRagSearchChunks({
-    required List<double> queryEmbedding,
-    required int topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_u_32(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 69,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_chunk_search_result,
+        )
+        ,
+            constMeta: kCrateApiReembeddingStartReembeddingJobConstMeta,
+            argValues: [modelName, batchSize, embedBatch, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReembeddingStartReembeddingJobConstMeta => const TaskConstMeta(
+            debugName: "start_reembedding_job",
+            argNames: ["modelName", "batchSize", "embedBatch", "onProgress"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiBackgroundTasksSubmitBufferMerge({required BufferTable table })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_buffer_table(table, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 307, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagSearchChunksConstMeta,
-        argValues: [queryEmbedding, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagSearchChunksConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_chunks",
-        argNames: ["queryEmbedding", "topK"],
-      );
-
-  @override
-  Future<List<HnswSearchResult>> crateApiHnswIndexSearchHnsw({
-    required List<double> queryEmbedding,
-    required BigInt topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_usize(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 70,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_hnsw_search_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiHnswIndexSearchHnswConstMeta,
-        argValues: [queryEmbedding, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHnswIndexSearchHnswConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_hnsw",
-        argNames: ["queryEmbedding", "topK"],
-      );
-
-  @override
-  Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybrid({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-    RrfConfig? config,
-    SearchFilter? filter,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(queryText, serializer);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_u_32(topK, serializer);
-          sse_encode_opt_box_autoadd_rrf_config(config, serializer);
-          sse_encode_opt_box_autoadd_search_filter(filter, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 71,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_hybrid_search_result,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksSubmitBufferMergeConstMeta,
+            argValues: [table],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksSubmitBufferMergeConstMeta => const TaskConstMeta(
+            debugName: "submit_buffer_merge",
+            argNames: ["table"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiBackgroundTasksSubmitIndexRebuild()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 308, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiHybridSearchSearchHybridConstMeta,
-        argValues: [queryText, queryEmbedding, topK, config, filter],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHybridSearchSearchHybridConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_hybrid",
-        argNames: ["queryText", "queryEmbedding", "topK", "config", "filter"],
-      );
-
-  @override
-  Future<List<String>> crateApiHybridSearchSearchHybridSimple({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(queryText, serializer);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_u_32(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 72,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksSubmitIndexRebuildConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksSubmitIndexRebuildConstMeta => const TaskConstMeta(
+            debugName: "submit_index_rebuild",
+            argNames: [],
+        );
+        
+
+@override Future<PlatformInt64> crateApiBackgroundTasksSubmitReembedding({required String modelName , required int batchSize , required FutureOr<List<Float32List>> Function(List<String>) embedBatch , required FutureOr<void> Function(ReembeddingJob) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelName, serializer);
+sse_encode_u_32(batchSize, serializer);
+sse_encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(embedBatch, serializer);
+sse_encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 309, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiHybridSearchSearchHybridSimpleConstMeta,
-        argValues: [queryText, queryEmbedding, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHybridSearchSearchHybridSimpleConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_hybrid_simple",
-        argNames: ["queryText", "queryEmbedding", "topK"],
-      );
-
-  @override
-  Future<List<HybridSearchResult>> crateApiHybridSearchSearchHybridWeighted({
-    required String queryText,
-    required List<double> queryEmbedding,
-    required int topK,
-    required double vectorWeight,
-    required double bm25Weight,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(queryText, serializer);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_u_32(topK, serializer);
-          sse_encode_f_64(vectorWeight, serializer);
-          sse_encode_f_64(bm25Weight, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 73,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_hybrid_search_result,
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksSubmitReembeddingConstMeta,
+            argValues: [modelName, batchSize, embedBatch, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksSubmitReembeddingConstMeta => const TaskConstMeta(
+            debugName: "submit_reembedding",
+            argNames: ["modelName", "batchSize", "embedBatch", "onProgress"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiBackgroundTasksSubmitSnapshotExport({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 310, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiHybridSearchSearchHybridWeightedConstMeta,
-        argValues: [queryText, queryEmbedding, topK, vectorWeight, bm25Weight],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiHybridSearchSearchHybridWeightedConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_hybrid_weighted",
-        argNames: [
-          "queryText",
-          "queryEmbedding",
-          "topK",
-          "vectorWeight",
-          "bm25Weight",
-        ],
-      );
-
-  @override
-  Future<List<String>> crateApiSimpleRagSearchSimilar({
-    required List<double> queryEmbedding,
-    required int topK,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(queryEmbedding, serializer);
-          sse_encode_u_32(topK, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 74,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSimpleRagSearchSimilarConstMeta,
-        argValues: [queryEmbedding, topK],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleRagSearchSimilarConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_similar",
-        argNames: ["queryEmbedding", "topK"],
-      );
-
-  @override
-  List<SemanticChunk> crateApiSemanticChunkerSemanticChunk({
-    required String text,
-    required int maxChars,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(maxChars, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_semantic_chunk,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerSemanticChunkConstMeta,
-        argValues: [text, maxChars],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerSemanticChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "semantic_chunk",
-        argNames: ["text", "maxChars"],
-      );
-
-  @override
-  List<SemanticChunk> crateApiSemanticChunkerSemanticChunkWithOverlap({
-    required String text,
-    required int maxChars,
-    required int overlapChars,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(maxChars, serializer);
-          sse_encode_i_32(overlapChars, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_semantic_chunk,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSemanticChunkerSemanticChunkWithOverlapConstMeta,
-        argValues: [text, maxChars, overlapChars],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSemanticChunkerSemanticChunkWithOverlapConstMeta =>
-      const TaskConstMeta(
-        debugName: "semantic_chunk_with_overlap",
-        argNames: ["text", "maxChars", "overlapChars"],
-      );
-
-  @override
-  Future<BigInt> crateApiCompressionUtilsSentenceHash({
-    required String sentence,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sentence, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 77,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsSentenceHashConstMeta,
-        argValues: [sentence],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompressionUtilsSentenceHashConstMeta =>
-      const TaskConstMeta(debugName: "sentence_hash", argNames: ["sentence"]);
-
-  @override
-  Future<bool> crateApiCompressionUtilsShouldCompress({
-    required String text,
-    required int tokenThreshold,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_i_32(tokenThreshold, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 78,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsShouldCompressConstMeta,
-        argValues: [text, tokenThreshold],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompressionUtilsShouldCompressConstMeta =>
-      const TaskConstMeta(
-        debugName: "should_compress",
-        argNames: ["text", "tokenThreshold"],
-      );
-
-  @override
-  Future<List<String>> crateApiCompressionUtilsSplitSentences({
-    required String text,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 79,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCompressionUtilsSplitSentencesConstMeta,
-        argValues: [text],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCompressionUtilsSplitSentencesConstMeta =>
-      const TaskConstMeta(debugName: "split_sentences", argNames: ["text"]);
-
-  @override
-  Uint32List crateApiTokenizerTokenize({required String text}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBackgroundTasksSubmitSnapshotExportConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackgroundTasksSubmitSnapshotExportConstMeta => const TaskConstMeta(
+            debugName: "submit_snapshot_export",
+            argNames: ["path"],
+        );
+        
+
+@override Uint32List crateApiTokenizerTokenize({required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 311)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_32_strict,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTokenizerTokenizeConstMeta,
-        argValues: [text],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTokenizerTokenizeConstMeta =>
-      const TaskConstMeta(debugName: "tokenize", argNames: ["text"]);
-
-  @override
-  Future<void> crateApiSourceRagUpdateChunkEmbedding({
-    required PlatformInt64 chunkId,
-    required List<double> embedding,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(chunkId, serializer);
-          sse_encode_list_prim_f_32_loose(embedding, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 81,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiTokenizerTokenizeConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTokenizerTokenizeConstMeta => const TaskConstMeta(
+            debugName: "tokenize",
+            argNames: ["text"],
+        );
+        
+
+@override Future<bool> crateApiSourceRagUpdateChunkContent({required PlatformInt64 chunkId , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(chunkId, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 312, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagUpdateChunkContentConstMeta,
+            argValues: [chunkId, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagUpdateChunkContentConstMeta => const TaskConstMeta(
+            debugName: "update_chunk_content",
+            argNames: ["chunkId", "content"],
+        );
+        
+
+@override Future<void> crateApiSourceRagUpdateChunkEmbedding({required PlatformInt64 chunkId , required List<double> embedding })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(chunkId, serializer);
+sse_encode_list_prim_f_32_loose(embedding, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 313, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagUpdateChunkEmbeddingConstMeta,
-        argValues: [chunkId, embedding],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagUpdateChunkEmbeddingConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_chunk_embedding",
-        argNames: ["chunkId", "embedding"],
-      );
-
-  @override
-  Future<void> crateApiSourceRagUpdateSourceStatus({
-    required PlatformInt64 sourceId,
-    required String status,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(sourceId, serializer);
-          sse_encode_String(status, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 82,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSourceRagUpdateChunkEmbeddingConstMeta,
+            argValues: [chunkId, embedding],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagUpdateChunkEmbeddingConstMeta => const TaskConstMeta(
+            debugName: "update_chunk_embedding",
+            argNames: ["chunkId", "embedding"],
+        );
+        
+
+@override Future<int> crateApiSourceRagUpdateChunkEmbeddingsBatch({required List<(PlatformInt64,Float32List)> updates , required FutureOr<void> Function(EmbeddingBatchProgress) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_i_64_list_prim_f_32_strict(updates, serializer);
+sse_encode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 314, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSourceRagUpdateChunkEmbeddingsBatchConstMeta,
+            argValues: [updates, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagUpdateChunkEmbeddingsBatchConstMeta => const TaskConstMeta(
+            debugName: "update_chunk_embeddings_batch",
+            argNames: ["updates", "onProgress"],
+        );
+        
+
+@override Future<bool> crateApiSimpleRagUpdateDocument({required PlatformInt64 docId , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(docId, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 315, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiSimpleRagUpdateDocumentConstMeta,
+            argValues: [docId, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRagUpdateDocumentConstMeta => const TaskConstMeta(
+            debugName: "update_document",
+            argNames: ["docId", "content"],
+        );
+        
+
+@override Future<SourceUpdate> crateApiStagedIngestUpdateSource({required PlatformInt64 sourceId , required String newContent , required IngestOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_String(newContent, serializer);
+sse_encode_box_autoadd_ingest_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 316, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_source_update,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiStagedIngestUpdateSourceConstMeta,
+            argValues: [sourceId, newContent, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStagedIngestUpdateSourceConstMeta => const TaskConstMeta(
+            debugName: "update_source",
+            argNames: ["sourceId", "newContent", "options"],
+        );
+        
+
+@override Future<void> crateApiSourceRagUpdateSourceStatus({required PlatformInt64 sourceId , required String status })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(sourceId, serializer);
+sse_encode_String(status, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 317, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_rag_error,
-        ),
-        constMeta: kCrateApiSourceRagUpdateSourceStatusConstMeta,
-        argValues: [sourceId, status],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSourceRagUpdateSourceStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_source_status",
-        argNames: ["sourceId", "status"],
-      );
-
-  @override
-  Future<void> crateApiUserIntentUserIntentGetQuery({
-    required UserIntent that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_user_intent(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 83,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSourceRagUpdateSourceStatusConstMeta,
+            argValues: [sourceId, status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSourceRagUpdateSourceStatusConstMeta => const TaskConstMeta(
+            debugName: "update_source_status",
+            argNames: ["sourceId", "status"],
+        );
+        
+
+@override Future<void> crateApiUserIntentUserIntentGetQuery({required UserIntent that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_user_intent(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 318, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUserIntentUserIntentGetQueryConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUserIntentUserIntentGetQueryConstMeta =>
-      const TaskConstMeta(
-        debugName: "user_intent_get_query",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void> crateApiUserIntentUserIntentIntentType({
-    required UserIntent that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_user_intent(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 84,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiUserIntentUserIntentGetQueryConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUserIntentUserIntentGetQueryConstMeta => const TaskConstMeta(
+            debugName: "user_intent_get_query",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiUserIntentUserIntentIntentType({required UserIntent that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_user_intent(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 319, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUserIntentUserIntentIntentTypeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUserIntentUserIntentIntentTypeConstMeta =>
-      const TaskConstMeta(
-        debugName: "user_intent_intent_type",
-        argNames: ["that"],
-      );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AddDocumentResult dco_decode_add_document_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return AddDocumentResult(
-      success: dco_decode_bool(arr[0]),
-      isDuplicate: dco_decode_bool(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  AddSourceResult dco_decode_add_source_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return AddSourceResult(
-      sourceId: dco_decode_i_64(arr[0]),
-      isDuplicate: dco_decode_bool(arr[1]),
-      chunkCount: dco_decode_i_32(arr[2]),
-      message: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  Bm25SearchResult dco_decode_bm_25_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return Bm25SearchResult(
-      docId: dco_decode_i_64(arr[0]),
-      score: dco_decode_f_64(arr[1]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  CompressionOptions dco_decode_box_autoadd_compression_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_compression_options(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  (int, int, int) dco_decode_box_autoadd_record_u_32_u_32_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as (int, int, int);
-  }
-
-  @protected
-  RrfConfig dco_decode_box_autoadd_rrf_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_rrf_config(raw);
-  }
-
-  @protected
-  SearchFilter dco_decode_box_autoadd_search_filter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_search_filter(raw);
-  }
-
-  @protected
-  UserIntent dco_decode_box_autoadd_user_intent(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_user_intent(raw);
-  }
-
-  @protected
-  BufferStats dco_decode_buffer_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return BufferStats(
-      bufferSize: dco_decode_usize(arr[0]),
-      threshold: dco_decode_usize(arr[1]),
-      hnswLoaded: dco_decode_bool(arr[2]),
-    );
-  }
-
-  @protected
-  ChunkData dco_decode_chunk_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return ChunkData(
-      content: dco_decode_String(arr[0]),
-      chunkIndex: dco_decode_i_32(arr[1]),
-      startPos: dco_decode_i_32(arr[2]),
-      endPos: dco_decode_i_32(arr[3]),
-      chunkType: dco_decode_String(arr[4]),
-      embedding: dco_decode_list_prim_f_32_strict(arr[5]),
-    );
-  }
-
-  @protected
-  ChunkForReembedding dco_decode_chunk_for_reembedding(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ChunkForReembedding(
-      chunkId: dco_decode_i_64(arr[0]),
-      content: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  ChunkSearchResult dco_decode_chunk_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return ChunkSearchResult(
-      chunkId: dco_decode_i_64(arr[0]),
-      sourceId: dco_decode_i_64(arr[1]),
-      chunkIndex: dco_decode_i_32(arr[2]),
-      content: dco_decode_String(arr[3]),
-      chunkType: dco_decode_String(arr[4]),
-      similarity: dco_decode_f_64(arr[5]),
-      metadata: dco_decode_opt_String(arr[6]),
-    );
-  }
-
-  @protected
-  ChunkType dco_decode_chunk_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChunkType.values[raw as int];
-  }
-
-  @protected
-  CompressedText dco_decode_compressed_text(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return CompressedText(
-      text: dco_decode_String(arr[0]),
-      originalChars: dco_decode_i_32(arr[1]),
-      compressedChars: dco_decode_i_32(arr[2]),
-      ratio: dco_decode_f_64(arr[3]),
-      sentencesRemoved: dco_decode_i_32(arr[4]),
-      charsSavedStopwords: dco_decode_i_32(arr[5]),
-      charsSavedTruncation: dco_decode_i_32(arr[6]),
-    );
-  }
-
-  @protected
-  CompressionOptions dco_decode_compression_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return CompressionOptions(
-      removeStopwords: dco_decode_bool(arr[0]),
-      removeDuplicates: dco_decode_bool(arr[1]),
-      language: dco_decode_String(arr[2]),
-      level: dco_decode_i_32(arr[3]),
-    );
-  }
-
-  @protected
-  EmbeddingPoint dco_decode_embedding_point(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EmbeddingPoint(
-      id: dco_decode_i_64(arr[0]),
-      embedding: dco_decode_list_prim_f_32_strict(arr[1]),
-      norm: dco_decode_f_32(arr[2]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  HnswSearchResult dco_decode_hnsw_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return HnswSearchResult(
-      id: dco_decode_i_64(arr[0]),
-      distance: dco_decode_f_32(arr[1]),
-    );
-  }
-
-  @protected
-  HybridSearchResult dco_decode_hybrid_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return HybridSearchResult(
-      docId: dco_decode_i_64(arr[0]),
-      content: dco_decode_String(arr[1]),
-      score: dco_decode_f_64(arr[2]),
-      vectorRank: dco_decode_u_32(arr[3]),
-      bm25Rank: dco_decode_u_32(arr[4]),
-      sourceId: dco_decode_i_64(arr[5]),
-      metadata: dco_decode_opt_String(arr[6]),
-      chunkIndex: dco_decode_u_32(arr[7]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  IncrementalSearchResult dco_decode_incremental_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return IncrementalSearchResult(
-      docId: dco_decode_i_64(arr[0]),
-      distance: dco_decode_f_32(arr[1]),
-      source: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<Bm25SearchResult> dco_decode_list_bm_25_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_bm_25_search_result).toList();
-  }
-
-  @protected
-  List<ChunkData> dco_decode_list_chunk_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_chunk_data).toList();
-  }
-
-  @protected
-  List<ChunkForReembedding> dco_decode_list_chunk_for_reembedding(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_chunk_for_reembedding)
-        .toList();
-  }
-
-  @protected
-  List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_chunk_search_result).toList();
-  }
-
-  @protected
-  List<HnswSearchResult> dco_decode_list_hnsw_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_hnsw_search_result).toList();
-  }
-
-  @protected
-  List<HybridSearchResult> dco_decode_list_hybrid_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_hybrid_search_result).toList();
-  }
-
-  @protected
-  List<IncrementalSearchResult> dco_decode_list_incremental_search_result(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_incremental_search_result)
-        .toList();
-  }
-
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<double>;
-  }
-
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
-  }
-
-  @protected
-  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeInt64List(raw);
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(PlatformInt64, Float32List)>
-  dco_decode_list_record_i_64_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_i_64_list_prim_f_32_strict)
-        .toList();
-  }
-
-  @protected
-  List<(PlatformInt64, String)> dco_decode_list_record_i_64_string(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_i_64_string).toList();
-  }
-
-  @protected
-  List<SemanticChunk> dco_decode_list_semantic_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_semantic_chunk).toList();
-  }
-
-  @protected
-  List<SourceEntry> dco_decode_list_source_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_source_entry).toList();
-  }
-
-  @protected
-  List<StructuredChunk> dco_decode_list_structured_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_structured_chunk).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  (int, int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32_u_32(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_record_u_32_u_32_u_32(raw);
-  }
-
-  @protected
-  RrfConfig? dco_decode_opt_box_autoadd_rrf_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_rrf_config(raw);
-  }
-
-  @protected
-  SearchFilter? dco_decode_opt_box_autoadd_search_filter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_search_filter(raw);
-  }
-
-  @protected
-  Int64List? dco_decode_opt_list_prim_i_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_i_64_strict(raw);
-  }
-
-  @protected
-  ParsedIntent dco_decode_parsed_intent(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ParsedIntent(
-      intentType: dco_decode_String(arr[0]),
-      query: dco_decode_String(arr[1]),
-      isValid: dco_decode_bool(arr[2]),
-      errorMessage: dco_decode_opt_String(arr[3]),
-    );
-  }
-
-  @protected
-  RagError dco_decode_rag_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return RagError_DatabaseError(dco_decode_String(raw[1]));
-      case 1:
-        return RagError_IoError(dco_decode_String(raw[1]));
-      case 2:
-        return RagError_ModelLoadError(dco_decode_String(raw[1]));
-      case 3:
-        return RagError_InvalidInput(dco_decode_String(raw[1]));
-      case 4:
-        return RagError_InternalError(dco_decode_String(raw[1]));
-      case 5:
-        return RagError_Unknown(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  (PlatformInt64, Float32List) dco_decode_record_i_64_list_prim_f_32_strict(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_i_64(arr[0]), dco_decode_list_prim_f_32_strict(arr[1]));
-  }
-
-  @protected
-  (PlatformInt64, String) dco_decode_record_i_64_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_i_64(arr[0]), dco_decode_String(arr[1]));
-  }
-
-  @protected
-  (int, int, int) dco_decode_record_u_32_u_32_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_32(arr[0]),
-      dco_decode_u_32(arr[1]),
-      dco_decode_u_32(arr[2]),
-    );
-  }
-
-  @protected
-  RrfConfig dco_decode_rrf_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return RrfConfig(
-      k: dco_decode_u_32(arr[0]),
-      vectorWeight: dco_decode_f_64(arr[1]),
-      bm25Weight: dco_decode_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  SearchFilter dco_decode_search_filter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SearchFilter(
-      sourceIds: dco_decode_opt_list_prim_i_64_strict(arr[0]),
-      metadataLike: dco_decode_opt_String(arr[1]),
-    );
-  }
-
-  @protected
-  SemanticChunk dco_decode_semantic_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SemanticChunk(
-      index: dco_decode_i_32(arr[0]),
-      content: dco_decode_String(arr[1]),
-      startPos: dco_decode_i_32(arr[2]),
-      endPos: dco_decode_i_32(arr[3]),
-      chunkType: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  SourceEntry dco_decode_source_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SourceEntry(
-      id: dco_decode_i_64(arr[0]),
-      name: dco_decode_opt_String(arr[1]),
-      createdAt: dco_decode_i_64(arr[2]),
-      metadata: dco_decode_opt_String(arr[3]),
-      status: dco_decode_opt_String(arr[4]),
-    );
-  }
-
-  @protected
-  SourceStats dco_decode_source_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SourceStats(
-      sourceCount: dco_decode_i_64(arr[0]),
-      chunkCount: dco_decode_i_64(arr[1]),
-    );
-  }
-
-  @protected
-  StructuredChunk dco_decode_structured_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return StructuredChunk(
-      index: dco_decode_i_32(arr[0]),
-      content: dco_decode_String(arr[1]),
-      headerPath: dco_decode_String(arr[2]),
-      chunkType: dco_decode_String(arr[3]),
-      startPos: dco_decode_i_32(arr[4]),
-      endPos: dco_decode_i_32(arr[5]),
-      batchId: dco_decode_opt_String(arr[6]),
-      batchIndex: dco_decode_opt_box_autoadd_i_32(arr[7]),
-      batchTotal: dco_decode_opt_box_autoadd_i_32(arr[8]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  UserIntent dco_decode_user_intent(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return UserIntent_Summary(query: dco_decode_String(raw[1]));
-      case 1:
-        return UserIntent_Define(term: dco_decode_String(raw[1]));
-      case 2:
-        return UserIntent_ExpandKnowledge(query: dco_decode_String(raw[1]));
-      case 3:
-        return UserIntent_General(query: dco_decode_String(raw[1]));
-      case 4:
-        return UserIntent_InvalidCommand(
-          command: dco_decode_String(raw[1]),
-          reason: dco_decode_String(raw[2]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  AddDocumentResult sse_decode_add_document_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_success = sse_decode_bool(deserializer);
-    var var_isDuplicate = sse_decode_bool(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return AddDocumentResult(
-      success: var_success,
-      isDuplicate: var_isDuplicate,
-      message: var_message,
-    );
-  }
-
-  @protected
-  AddSourceResult sse_decode_add_source_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sourceId = sse_decode_i_64(deserializer);
-    var var_isDuplicate = sse_decode_bool(deserializer);
-    var var_chunkCount = sse_decode_i_32(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return AddSourceResult(
-      sourceId: var_sourceId,
-      isDuplicate: var_isDuplicate,
-      chunkCount: var_chunkCount,
-      message: var_message,
-    );
-  }
-
-  @protected
-  Bm25SearchResult sse_decode_bm_25_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_docId = sse_decode_i_64(deserializer);
-    var var_score = sse_decode_f_64(deserializer);
-    return Bm25SearchResult(docId: var_docId, score: var_score);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  CompressionOptions sse_decode_box_autoadd_compression_options(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_compression_options(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  (int, int, int) sse_decode_box_autoadd_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_record_u_32_u_32_u_32(deserializer));
-  }
-
-  @protected
-  RrfConfig sse_decode_box_autoadd_rrf_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_rrf_config(deserializer));
-  }
-
-  @protected
-  SearchFilter sse_decode_box_autoadd_search_filter(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_search_filter(deserializer));
-  }
-
-  @protected
-  UserIntent sse_decode_box_autoadd_user_intent(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_user_intent(deserializer));
-  }
-
-  @protected
-  BufferStats sse_decode_buffer_stats(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bufferSize = sse_decode_usize(deserializer);
-    var var_threshold = sse_decode_usize(deserializer);
-    var var_hnswLoaded = sse_decode_bool(deserializer);
-    return BufferStats(
-      bufferSize: var_bufferSize,
-      threshold: var_threshold,
-      hnswLoaded: var_hnswLoaded,
-    );
-  }
-
-  @protected
-  ChunkData sse_decode_chunk_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_content = sse_decode_String(deserializer);
-    var var_chunkIndex = sse_decode_i_32(deserializer);
-    var var_startPos = sse_decode_i_32(deserializer);
-    var var_endPos = sse_decode_i_32(deserializer);
-    var var_chunkType = sse_decode_String(deserializer);
-    var var_embedding = sse_decode_list_prim_f_32_strict(deserializer);
-    return ChunkData(
-      content: var_content,
-      chunkIndex: var_chunkIndex,
-      startPos: var_startPos,
-      endPos: var_endPos,
-      chunkType: var_chunkType,
-      embedding: var_embedding,
-    );
-  }
-
-  @protected
-  ChunkForReembedding sse_decode_chunk_for_reembedding(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_chunkId = sse_decode_i_64(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    return ChunkForReembedding(chunkId: var_chunkId, content: var_content);
-  }
-
-  @protected
-  ChunkSearchResult sse_decode_chunk_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_chunkId = sse_decode_i_64(deserializer);
-    var var_sourceId = sse_decode_i_64(deserializer);
-    var var_chunkIndex = sse_decode_i_32(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_chunkType = sse_decode_String(deserializer);
-    var var_similarity = sse_decode_f_64(deserializer);
-    var var_metadata = sse_decode_opt_String(deserializer);
-    return ChunkSearchResult(
-      chunkId: var_chunkId,
-      sourceId: var_sourceId,
-      chunkIndex: var_chunkIndex,
-      content: var_content,
-      chunkType: var_chunkType,
-      similarity: var_similarity,
-      metadata: var_metadata,
-    );
-  }
-
-  @protected
-  ChunkType sse_decode_chunk_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return ChunkType.values[inner];
-  }
-
-  @protected
-  CompressedText sse_decode_compressed_text(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_text = sse_decode_String(deserializer);
-    var var_originalChars = sse_decode_i_32(deserializer);
-    var var_compressedChars = sse_decode_i_32(deserializer);
-    var var_ratio = sse_decode_f_64(deserializer);
-    var var_sentencesRemoved = sse_decode_i_32(deserializer);
-    var var_charsSavedStopwords = sse_decode_i_32(deserializer);
-    var var_charsSavedTruncation = sse_decode_i_32(deserializer);
-    return CompressedText(
-      text: var_text,
-      originalChars: var_originalChars,
-      compressedChars: var_compressedChars,
-      ratio: var_ratio,
-      sentencesRemoved: var_sentencesRemoved,
-      charsSavedStopwords: var_charsSavedStopwords,
-      charsSavedTruncation: var_charsSavedTruncation,
-    );
-  }
-
-  @protected
-  CompressionOptions sse_decode_compression_options(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_removeStopwords = sse_decode_bool(deserializer);
-    var var_removeDuplicates = sse_decode_bool(deserializer);
-    var var_language = sse_decode_String(deserializer);
-    var var_level = sse_decode_i_32(deserializer);
-    return CompressionOptions(
-      removeStopwords: var_removeStopwords,
-      removeDuplicates: var_removeDuplicates,
-      language: var_language,
-      level: var_level,
-    );
-  }
-
-  @protected
-  EmbeddingPoint sse_decode_embedding_point(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_embedding = sse_decode_list_prim_f_32_strict(deserializer);
-    var var_norm = sse_decode_f_32(deserializer);
-    return EmbeddingPoint(id: var_id, embedding: var_embedding, norm: var_norm);
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  HnswSearchResult sse_decode_hnsw_search_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_distance = sse_decode_f_32(deserializer);
-    return HnswSearchResult(id: var_id, distance: var_distance);
-  }
-
-  @protected
-  HybridSearchResult sse_decode_hybrid_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_docId = sse_decode_i_64(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_score = sse_decode_f_64(deserializer);
-    var var_vectorRank = sse_decode_u_32(deserializer);
-    var var_bm25Rank = sse_decode_u_32(deserializer);
-    var var_sourceId = sse_decode_i_64(deserializer);
-    var var_metadata = sse_decode_opt_String(deserializer);
-    var var_chunkIndex = sse_decode_u_32(deserializer);
-    return HybridSearchResult(
-      docId: var_docId,
-      content: var_content,
-      score: var_score,
-      vectorRank: var_vectorRank,
-      bm25Rank: var_bm25Rank,
-      sourceId: var_sourceId,
-      metadata: var_metadata,
-      chunkIndex: var_chunkIndex,
-    );
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  IncrementalSearchResult sse_decode_incremental_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_docId = sse_decode_i_64(deserializer);
-    var var_distance = sse_decode_f_32(deserializer);
-    var var_source = sse_decode_String(deserializer);
-    return IncrementalSearchResult(
-      docId: var_docId,
-      distance: var_distance,
-      source: var_source,
-    );
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Bm25SearchResult> sse_decode_list_bm_25_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Bm25SearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_bm_25_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ChunkData> sse_decode_list_chunk_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ChunkData>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_chunk_data(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ChunkForReembedding> sse_decode_list_chunk_for_reembedding(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ChunkForReembedding>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_chunk_for_reembedding(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ChunkSearchResult> sse_decode_list_chunk_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ChunkSearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_chunk_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<HnswSearchResult> sse_decode_list_hnsw_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HnswSearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_hnsw_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<HybridSearchResult> sse_decode_list_hybrid_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HybridSearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_hybrid_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<IncrementalSearchResult> sse_decode_list_incremental_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <IncrementalSearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_incremental_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt64List(len_);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
-  }
-
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(PlatformInt64, Float32List)>
-  sse_decode_list_record_i_64_list_prim_f_32_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(PlatformInt64, Float32List)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_64_list_prim_f_32_strict(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(PlatformInt64, String)> sse_decode_list_record_i_64_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(PlatformInt64, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_64_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SemanticChunk> sse_decode_list_semantic_chunk(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SemanticChunk>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_semantic_chunk(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SourceEntry> sse_decode_list_source_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SourceEntry>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_source_entry(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<StructuredChunk> sse_decode_list_structured_chunk(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <StructuredChunk>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_structured_chunk(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (int, int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_record_u_32_u_32_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  RrfConfig? sse_decode_opt_box_autoadd_rrf_config(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_rrf_config(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  SearchFilter? sse_decode_opt_box_autoadd_search_filter(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_search_filter(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Int64List? sse_decode_opt_list_prim_i_64_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_i_64_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ParsedIntent sse_decode_parsed_intent(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_intentType = sse_decode_String(deserializer);
-    var var_query = sse_decode_String(deserializer);
-    var var_isValid = sse_decode_bool(deserializer);
-    var var_errorMessage = sse_decode_opt_String(deserializer);
-    return ParsedIntent(
-      intentType: var_intentType,
-      query: var_query,
-      isValid: var_isValid,
-      errorMessage: var_errorMessage,
-    );
-  }
-
-  @protected
-  RagError sse_decode_rag_error(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_DatabaseError(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_IoError(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_ModelLoadError(var_field0);
-      case 3:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_InvalidInput(var_field0);
-      case 4:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_InternalError(var_field0);
-      case 5:
-        var var_field0 = sse_decode_String(deserializer);
-        return RagError_Unknown(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  (PlatformInt64, Float32List) sse_decode_record_i_64_list_prim_f_32_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_i_64(deserializer);
-    var var_field1 = sse_decode_list_prim_f_32_strict(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (PlatformInt64, String) sse_decode_record_i_64_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_i_64(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, int, int) sse_decode_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_u_32(deserializer);
-    var var_field2 = sse_decode_u_32(deserializer);
-    return (var_field0, var_field1, var_field2);
-  }
-
-  @protected
-  RrfConfig sse_decode_rrf_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_k = sse_decode_u_32(deserializer);
-    var var_vectorWeight = sse_decode_f_64(deserializer);
-    var var_bm25Weight = sse_decode_f_64(deserializer);
-    return RrfConfig(
-      k: var_k,
-      vectorWeight: var_vectorWeight,
-      bm25Weight: var_bm25Weight,
-    );
-  }
-
-  @protected
-  SearchFilter sse_decode_search_filter(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sourceIds = sse_decode_opt_list_prim_i_64_strict(deserializer);
-    var var_metadataLike = sse_decode_opt_String(deserializer);
-    return SearchFilter(
-      sourceIds: var_sourceIds,
-      metadataLike: var_metadataLike,
-    );
-  }
-
-  @protected
-  SemanticChunk sse_decode_semantic_chunk(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_index = sse_decode_i_32(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_startPos = sse_decode_i_32(deserializer);
-    var var_endPos = sse_decode_i_32(deserializer);
-    var var_chunkType = sse_decode_String(deserializer);
-    return SemanticChunk(
-      index: var_index,
-      content: var_content,
-      startPos: var_startPos,
-      endPos: var_endPos,
-      chunkType: var_chunkType,
-    );
-  }
-
-  @protected
-  SourceEntry sse_decode_source_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_createdAt = sse_decode_i_64(deserializer);
-    var var_metadata = sse_decode_opt_String(deserializer);
-    var var_status = sse_decode_opt_String(deserializer);
-    return SourceEntry(
-      id: var_id,
-      name: var_name,
-      createdAt: var_createdAt,
-      metadata: var_metadata,
-      status: var_status,
-    );
-  }
-
-  @protected
-  SourceStats sse_decode_source_stats(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sourceCount = sse_decode_i_64(deserializer);
-    var var_chunkCount = sse_decode_i_64(deserializer);
-    return SourceStats(
-      sourceCount: var_sourceCount,
-      chunkCount: var_chunkCount,
-    );
-  }
-
-  @protected
-  StructuredChunk sse_decode_structured_chunk(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_index = sse_decode_i_32(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_headerPath = sse_decode_String(deserializer);
-    var var_chunkType = sse_decode_String(deserializer);
-    var var_startPos = sse_decode_i_32(deserializer);
-    var var_endPos = sse_decode_i_32(deserializer);
-    var var_batchId = sse_decode_opt_String(deserializer);
-    var var_batchIndex = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_batchTotal = sse_decode_opt_box_autoadd_i_32(deserializer);
-    return StructuredChunk(
-      index: var_index,
-      content: var_content,
-      headerPath: var_headerPath,
-      chunkType: var_chunkType,
-      startPos: var_startPos,
-      endPos: var_endPos,
-      batchId: var_batchId,
-      batchIndex: var_batchIndex,
-      batchTotal: var_batchTotal,
-    );
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  UserIntent sse_decode_user_intent(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_query = sse_decode_String(deserializer);
-        return UserIntent_Summary(query: var_query);
-      case 1:
-        var var_term = sse_decode_String(deserializer);
-        return UserIntent_Define(term: var_term);
-      case 2:
-        var var_query = sse_decode_String(deserializer);
-        return UserIntent_ExpandKnowledge(query: var_query);
-      case 3:
-        var var_query = sse_decode_String(deserializer);
-        return UserIntent_General(query: var_query);
-      case 4:
-        var var_command = sse_decode_String(deserializer);
-        var var_reason = sse_decode_String(deserializer);
-        return UserIntent_InvalidCommand(
-          command: var_command,
-          reason: var_reason,
-        );
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-    RustStreamSink<String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_add_document_result(
-    AddDocumentResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.success, serializer);
-    sse_encode_bool(self.isDuplicate, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_add_source_result(
-    AddSourceResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.sourceId, serializer);
-    sse_encode_bool(self.isDuplicate, serializer);
-    sse_encode_i_32(self.chunkCount, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_bm_25_search_result(
-    Bm25SearchResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.docId, serializer);
-    sse_encode_f_64(self.score, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_compression_options(
-    CompressionOptions self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_compression_options(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_record_u_32_u_32_u_32(
-    (int, int, int) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_u_32_u_32_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_rrf_config(
-    RrfConfig self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_rrf_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_search_filter(
-    SearchFilter self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_search_filter(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_user_intent(
-    UserIntent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_user_intent(self, serializer);
-  }
-
-  @protected
-  void sse_encode_buffer_stats(BufferStats self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.bufferSize, serializer);
-    sse_encode_usize(self.threshold, serializer);
-    sse_encode_bool(self.hnswLoaded, serializer);
-  }
-
-  @protected
-  void sse_encode_chunk_data(ChunkData self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.content, serializer);
-    sse_encode_i_32(self.chunkIndex, serializer);
-    sse_encode_i_32(self.startPos, serializer);
-    sse_encode_i_32(self.endPos, serializer);
-    sse_encode_String(self.chunkType, serializer);
-    sse_encode_list_prim_f_32_strict(self.embedding, serializer);
-  }
-
-  @protected
-  void sse_encode_chunk_for_reembedding(
-    ChunkForReembedding self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.chunkId, serializer);
-    sse_encode_String(self.content, serializer);
-  }
-
-  @protected
-  void sse_encode_chunk_search_result(
-    ChunkSearchResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.chunkId, serializer);
-    sse_encode_i_64(self.sourceId, serializer);
-    sse_encode_i_32(self.chunkIndex, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_String(self.chunkType, serializer);
-    sse_encode_f_64(self.similarity, serializer);
-    sse_encode_opt_String(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_chunk_type(ChunkType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_compressed_text(
-    CompressedText self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.text, serializer);
-    sse_encode_i_32(self.originalChars, serializer);
-    sse_encode_i_32(self.compressedChars, serializer);
-    sse_encode_f_64(self.ratio, serializer);
-    sse_encode_i_32(self.sentencesRemoved, serializer);
-    sse_encode_i_32(self.charsSavedStopwords, serializer);
-    sse_encode_i_32(self.charsSavedTruncation, serializer);
-  }
-
-  @protected
-  void sse_encode_compression_options(
-    CompressionOptions self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.removeStopwords, serializer);
-    sse_encode_bool(self.removeDuplicates, serializer);
-    sse_encode_String(self.language, serializer);
-    sse_encode_i_32(self.level, serializer);
-  }
-
-  @protected
-  void sse_encode_embedding_point(
-    EmbeddingPoint self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_list_prim_f_32_strict(self.embedding, serializer);
-    sse_encode_f_32(self.norm, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_hnsw_search_result(
-    HnswSearchResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_f_32(self.distance, serializer);
-  }
-
-  @protected
-  void sse_encode_hybrid_search_result(
-    HybridSearchResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.docId, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_f_64(self.score, serializer);
-    sse_encode_u_32(self.vectorRank, serializer);
-    sse_encode_u_32(self.bm25Rank, serializer);
-    sse_encode_i_64(self.sourceId, serializer);
-    sse_encode_opt_String(self.metadata, serializer);
-    sse_encode_u_32(self.chunkIndex, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_incremental_search_result(
-    IncrementalSearchResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.docId, serializer);
-    sse_encode_f_32(self.distance, serializer);
-    sse_encode_String(self.source, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_bm_25_search_result(
-    List<Bm25SearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_bm_25_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_chunk_data(
-    List<ChunkData> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_chunk_data(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_chunk_for_reembedding(
-    List<ChunkForReembedding> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_chunk_for_reembedding(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_chunk_search_result(
-    List<ChunkSearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_chunk_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_hnsw_search_result(
-    List<HnswSearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_hnsw_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_hybrid_search_result(
-    List<HybridSearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_hybrid_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_incremental_search_result(
-    List<IncrementalSearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_incremental_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-    List<double> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(
-      self is Float32List ? self : Float32List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_i_64_strict(
-    Int64List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putInt64List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_32_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(
-      self is Uint32List ? self : Uint32List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_i_64_list_prim_f_32_strict(
-    List<(PlatformInt64, Float32List)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_64_list_prim_f_32_strict(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_i_64_string(
-    List<(PlatformInt64, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_64_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_semantic_chunk(
-    List<SemanticChunk> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_semantic_chunk(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_source_entry(
-    List<SourceEntry> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_source_entry(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_structured_chunk(
-    List<StructuredChunk> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_structured_chunk(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_record_u_32_u_32_u_32(
-    (int, int, int)? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_record_u_32_u_32_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_rrf_config(
-    RrfConfig? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_rrf_config(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_search_filter(
-    SearchFilter? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_search_filter(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_i_64_strict(
-    Int64List? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_i_64_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_parsed_intent(ParsedIntent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.intentType, serializer);
-    sse_encode_String(self.query, serializer);
-    sse_encode_bool(self.isValid, serializer);
-    sse_encode_opt_String(self.errorMessage, serializer);
-  }
-
-  @protected
-  void sse_encode_rag_error(RagError self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case RagError_DatabaseError(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case RagError_IoError(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(field0, serializer);
-      case RagError_ModelLoadError(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case RagError_InvalidInput(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
-      case RagError_InternalError(field0: final field0):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(field0, serializer);
-      case RagError_Unknown(field0: final field0):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(field0, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_i_64_list_prim_f_32_strict(
-    (PlatformInt64, Float32List) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.$1, serializer);
-    sse_encode_list_prim_f_32_strict(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_i_64_string(
-    (PlatformInt64, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_u_32_u_32(
-    (int, int, int) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_u_32(self.$2, serializer);
-    sse_encode_u_32(self.$3, serializer);
-  }
-
-  @protected
-  void sse_encode_rrf_config(RrfConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.k, serializer);
-    sse_encode_f_64(self.vectorWeight, serializer);
-    sse_encode_f_64(self.bm25Weight, serializer);
-  }
-
-  @protected
-  void sse_encode_search_filter(SearchFilter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_list_prim_i_64_strict(self.sourceIds, serializer);
-    sse_encode_opt_String(self.metadataLike, serializer);
-  }
-
-  @protected
-  void sse_encode_semantic_chunk(SemanticChunk self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_i_32(self.startPos, serializer);
-    sse_encode_i_32(self.endPos, serializer);
-    sse_encode_String(self.chunkType, serializer);
-  }
-
-  @protected
-  void sse_encode_source_entry(SourceEntry self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_i_64(self.createdAt, serializer);
-    sse_encode_opt_String(self.metadata, serializer);
-    sse_encode_opt_String(self.status, serializer);
-  }
-
-  @protected
-  void sse_encode_source_stats(SourceStats self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.sourceCount, serializer);
-    sse_encode_i_64(self.chunkCount, serializer);
-  }
-
-  @protected
-  void sse_encode_structured_chunk(
-    StructuredChunk self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_String(self.headerPath, serializer);
-    sse_encode_String(self.chunkType, serializer);
-    sse_encode_i_32(self.startPos, serializer);
-    sse_encode_i_32(self.endPos, serializer);
-    sse_encode_opt_String(self.batchId, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.batchIndex, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.batchTotal, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_user_intent(UserIntent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case UserIntent_Summary(query: final query):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(query, serializer);
-      case UserIntent_Define(term: final term):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(term, serializer);
-      case UserIntent_ExpandKnowledge(query: final query):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(query, serializer);
-      case UserIntent_General(query: final query):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(query, serializer);
-      case UserIntent_InvalidCommand(
-        command: final command,
-        reason: final reason,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(command, serializer);
-        sse_encode_String(reason, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiUserIntentUserIntentIntentTypeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUserIntentUserIntentIntentTypeConstMeta => const TaskConstMeta(
+            debugName: "user_intent_intent_type",
+            argNames: ["that"],
+        );
+        
+
+@override Future<IndexConsistency> crateApiIndexFreshnessVerifyIndexConsistency({required bool repair })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(repair, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 320, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_consistency,
+          decodeErrorData: sse_decode_rag_error,
+        )
+        ,
+            constMeta: kCrateApiIndexFreshnessVerifyIndexConsistencyConstMeta,
+            argValues: [repair],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexFreshnessVerifyIndexConsistencyConstMeta => const TaskConstMeta(
+            debugName: "verify_index_consistency",
+            argNames: ["repair"],
+        );
+        
+
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(FutureOr<Float32List> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<Float32List>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_list_prim_f_32_strict(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(FutureOr<void> Function(EmbeddingBatchProgress) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_embedding_batch_progress(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(FutureOr<void> Function(IngestJob) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_ingest_job(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(FutureOr<void> Function(IngestProgress) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_ingest_progress(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(FutureOr<List<Float32List>> Function(List<String>) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_list_String(rawArg0);
+
+
+                Box<List<Float32List>>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_list_list_prim_f_32_strict(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(FutureOr<void> Function(ReembeddingJob) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_reembedding_job(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MemoryStore => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MemoryStore => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected MemoryStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MemoryStore dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MemoryStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected FutureOr<Float32List> Function(String) dco_decode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(EmbeddingBatchProgress) dco_decode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(IngestJob) dco_decode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(IngestProgress) dco_decode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<List<Float32List>> Function(List<String>) dco_decode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ReembeddingJob) dco_decode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_f_64(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected MemoryStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<HybridSearchEvent> dco_decode_StreamSink_hybrid_search_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ProgressEvent> dco_decode_StreamSink_progress_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected ChunkStore dco_decode_TraitDef_ChunkStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected AddDocumentResult dco_decode_add_document_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AddDocumentResult(success: dco_decode_bool(arr[0]),
+isDuplicate: dco_decode_bool(arr[1]),
+message: dco_decode_String(arr[2]),); }
+
+@protected AddSourceResult dco_decode_add_source_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AddSourceResult(sourceId: dco_decode_i_64(arr[0]),
+isDuplicate: dco_decode_bool(arr[1]),
+chunkCount: dco_decode_i_32(arr[2]),
+message: dco_decode_String(arr[3]),); }
+
+@protected AuditIssue dco_decode_audit_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AuditIssue(severity: dco_decode_String(arr[0]),
+code: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),
+sourceId: dco_decode_opt_box_autoadd_i_64(arr[3]),
+chunkId: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected AuditReport dco_decode_audit_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AuditReport(startedAt: dco_decode_i_64(arr[0]),
+durationMs: dco_decode_u_64(arr[1]),
+sourcesChecked: dco_decode_u_64(arr[2]),
+chunksChecked: dco_decode_u_64(arr[3]),
+issues: dco_decode_list_audit_issue(arr[4]),
+issueCount: dco_decode_u_32(arr[5]),
+maxSeverity: dco_decode_opt_String(arr[6]),); }
+
+@protected BackgroundTask dco_decode_background_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return BackgroundTask(id: dco_decode_i_64(arr[0]),
+kind: dco_decode_background_task_kind(arr[1]),
+tenantId: dco_decode_String(arr[2]),
+state: dco_decode_String(arr[3]),
+detail: dco_decode_opt_String(arr[4]),
+error: dco_decode_opt_String(arr[5]),
+submittedAt: dco_decode_i_64(arr[6]),
+startedAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+finishedAt: dco_decode_opt_box_autoadd_i_64(arr[8]),); }
+
+@protected BackgroundTaskKind dco_decode_background_task_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BackgroundTaskKind.values[raw as int]; }
+
+@protected BenchmarkConfig dco_decode_benchmark_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return BenchmarkConfig(documentBytes: dco_decode_opt_list_prim_u_8_strict(arr[0]),
+syntheticParagraphs: dco_decode_u_32(arr[1]),
+chunkMaxChars: dco_decode_i_32(arr[2]),
+embeddingDim: dco_decode_u_32(arr[3]),
+searchIterations: dco_decode_u_32(arr[4]),
+topK: dco_decode_u_32(arr[5]),); }
+
+@protected BenchmarkReport dco_decode_benchmark_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return BenchmarkReport(stages: dco_decode_list_benchmark_stage(arr[0]),
+documentChars: dco_decode_u_32(arr[1]),
+chunkCount: dco_decode_u_32(arr[2]),
+tokenCount: dco_decode_u_32(arr[3]),
+searchMeanMs: dco_decode_f_64(arr[4]),
+searchP95Ms: dco_decode_f_64(arr[5]),
+peakRssKb: dco_decode_opt_box_autoadd_u_64(arr[6]),
+totalMs: dco_decode_f_64(arr[7]),); }
+
+@protected BenchmarkStage dco_decode_benchmark_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BenchmarkStage(name: dco_decode_String(arr[0]),
+durationMs: dco_decode_f_64(arr[1]),
+skipped: dco_decode_bool(arr[2]),
+rssKb: dco_decode_opt_box_autoadd_u_64(arr[3]),); }
+
+@protected Bm25Config dco_decode_bm_25_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Bm25Config(k1: dco_decode_f_64(arr[0]),
+b: dco_decode_f_64(arr[1]),
+variant: dco_decode_bm_25_variant(arr[2]),); }
+
+@protected Bm25FuzzyConfig dco_decode_bm_25_fuzzy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Bm25FuzzyConfig(maxEdits: dco_decode_u_32(arr[0]),
+minTermChars: dco_decode_u_32(arr[1]),
+maxExpansions: dco_decode_u_32(arr[2]),); }
+
+@protected Bm25IndexOptions dco_decode_bm_25_index_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Bm25IndexOptions(tokenization: dco_decode_bm_25_tokenization(arr[0]),
+stemmer: dco_decode_opt_String(arr[1]),
+stripKoreanParticles: dco_decode_bool(arr[2]),
+removeStopwords: dco_decode_bool(arr[3]),); }
+
+@protected Bm25SearchResult dco_decode_bm_25_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Bm25SearchResult(docId: dco_decode_i_64(arr[0]),
+score: dco_decode_f_64(arr[1]),); }
+
+@protected Bm25Tokenization dco_decode_bm_25_tokenization(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Bm25Tokenization.values[raw as int]; }
+
+@protected Bm25Variant dco_decode_bm_25_variant(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Bm25Variant.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AuditReport dco_decode_box_autoadd_audit_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audit_report(raw); }
+
+@protected BackgroundTask dco_decode_box_autoadd_background_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_background_task(raw); }
+
+@protected BenchmarkConfig dco_decode_box_autoadd_benchmark_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_benchmark_config(raw); }
+
+@protected Bm25Config dco_decode_box_autoadd_bm_25_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bm_25_config(raw); }
+
+@protected Bm25FuzzyConfig dco_decode_box_autoadd_bm_25_fuzzy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bm_25_fuzzy_config(raw); }
+
+@protected Bm25IndexOptions dco_decode_box_autoadd_bm_25_index_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bm_25_index_options(raw); }
+
+@protected ChunkingConfig dco_decode_box_autoadd_chunking_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chunking_config(raw); }
+
+@protected ChunkingStrategy dco_decode_box_autoadd_chunking_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chunking_strategy(raw); }
+
+@protected CompressionOptions dco_decode_box_autoadd_compression_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_compression_options(raw); }
+
+@protected DbRetryConfig dco_decode_box_autoadd_db_retry_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_db_retry_config(raw); }
+
+@protected DedupeConfig dco_decode_box_autoadd_dedupe_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_dedupe_config(raw); }
+
+@protected DistanceMetric dco_decode_box_autoadd_distance_metric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_distance_metric(raw); }
+
+@protected ExpansionConfig dco_decode_box_autoadd_expansion_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_expansion_config(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HnswBuildConfig dco_decode_box_autoadd_hnsw_build_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hnsw_build_config(raw); }
+
+@protected HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hnsw_config(raw); }
+
+@protected HnswIndexStats dco_decode_box_autoadd_hnsw_index_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hnsw_index_stats(raw); }
+
+@protected HybridSearchOptions dco_decode_box_autoadd_hybrid_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_hybrid_search_options(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected IncrementalConfig dco_decode_box_autoadd_incremental_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_incremental_config(raw); }
+
+@protected IndexConfig dco_decode_box_autoadd_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_index_config(raw); }
+
+@protected IngestInput dco_decode_box_autoadd_ingest_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ingest_input(raw); }
+
+@protected IngestOptions dco_decode_box_autoadd_ingest_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ingest_options(raw); }
+
+@protected LanguageAnalyzer dco_decode_box_autoadd_language_analyzer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_language_analyzer(raw); }
+
+@protected LastRetrieval dco_decode_box_autoadd_last_retrieval(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_last_retrieval(raw); }
+
+@protected MaintenanceConfig dco_decode_box_autoadd_maintenance_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_maintenance_config(raw); }
+
+@protected MaintenanceReport dco_decode_box_autoadd_maintenance_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_maintenance_report(raw); }
+
+@protected NamedIndexConfig dco_decode_box_autoadd_named_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_named_index_config(raw); }
+
+@protected QueryDecomposition dco_decode_box_autoadd_query_decomposition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_decomposition(raw); }
+
+@protected QueryNormalizationConfig dco_decode_box_autoadd_query_normalization_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_normalization_config(raw); }
+
+@protected RagError dco_decode_box_autoadd_rag_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rag_error(raw); }
+
+@protected (int,int,int) dco_decode_box_autoadd_record_u_32_u_32_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as (int,int,int); }
+
+@protected ReembeddingJob dco_decode_box_autoadd_reembedding_job(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_reembedding_job(raw); }
+
+@protected RetrievalPreset dco_decode_box_autoadd_retrieval_preset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_retrieval_preset(raw); }
+
+@protected RrfConfig dco_decode_box_autoadd_rrf_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rrf_config(raw); }
+
+@protected SearchDiagnostics dco_decode_box_autoadd_search_diagnostics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_diagnostics(raw); }
+
+@protected SearchFilter dco_decode_box_autoadd_search_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_filter(raw); }
+
+@protected SearchSnippet dco_decode_box_autoadd_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_snippet(raw); }
+
+@protected SearchTrace dco_decode_box_autoadd_search_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_trace(raw); }
+
+@protected SourceIndexConfig dco_decode_box_autoadd_source_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_source_index_config(raw); }
+
+@protected StoredChunk dco_decode_box_autoadd_stored_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stored_chunk(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected UserIntent dco_decode_box_autoadd_user_intent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_user_intent(raw); }
+
+@protected BufferStats dco_decode_buffer_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BufferStats(bufferSize: dco_decode_usize(arr[0]),
+threshold: dco_decode_usize(arr[1]),
+hnswLoaded: dco_decode_bool(arr[2]),); }
+
+@protected BufferTable dco_decode_buffer_table(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BufferTable.values[raw as int]; }
+
+@protected ChunkData dco_decode_chunk_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ChunkData(content: dco_decode_String(arr[0]),
+chunkIndex: dco_decode_i_32(arr[1]),
+startPos: dco_decode_i_32(arr[2]),
+endPos: dco_decode_i_32(arr[3]),
+chunkType: dco_decode_String(arr[4]),
+embedding: dco_decode_list_prim_f_32_strict(arr[5]),
+batchId: dco_decode_opt_String(arr[6]),); }
+
+@protected ChunkForReembedding dco_decode_chunk_for_reembedding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChunkForReembedding(chunkId: dco_decode_i_64(arr[0]),
+content: dco_decode_String(arr[1]),); }
+
+@protected ChunkIndexSource dco_decode_chunk_index_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChunkIndexSource.values[raw as int]; }
+
+@protected ChunkMeta dco_decode_chunk_meta(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ChunkMeta(content: dco_decode_String(arr[0]),
+chunkIndex: dco_decode_i_32(arr[1]),
+startPos: dco_decode_i_32(arr[2]),
+endPos: dco_decode_i_32(arr[3]),
+chunkType: dco_decode_String(arr[4]),
+batchId: dco_decode_opt_String(arr[5]),); }
+
+@protected ChunkSearchPage dco_decode_chunk_search_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChunkSearchPage(results: dco_decode_list_chunk_search_result(arr[0]),
+nextCursor: dco_decode_opt_String(arr[1]),); }
+
+@protected ChunkSearchResult dco_decode_chunk_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ChunkSearchResult(chunkId: dco_decode_i_64(arr[0]),
+sourceId: dco_decode_i_64(arr[1]),
+chunkIndex: dco_decode_i_32(arr[2]),
+content: dco_decode_String(arr[3]),
+chunkType: dco_decode_String(arr[4]),
+headerPath: dco_decode_opt_String(arr[5]),
+similarity: dco_decode_f_64(arr[6]),
+metadata: dco_decode_opt_String(arr[7]),); }
+
+@protected ChunkStats dco_decode_chunk_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ChunkStats(chunkCount: dco_decode_u_32(arr[0]),
+minSize: dco_decode_u_32(arr[1]),
+maxSize: dco_decode_u_32(arr[2]),
+avgSize: dco_decode_f_64(arr[3]),
+medianSize: dco_decode_f_64(arr[4]),
+typeHistogram: dco_decode_list_corpus_count(arr[5]),
+oversizeCount: dco_decode_u_32(arr[6]),); }
+
+@protected ChunkType dco_decode_chunk_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChunkType.values[raw as int]; }
+
+@protected ChunkingConfig dco_decode_chunking_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ChunkingConfig(maxChars: dco_decode_i_32(arr[0]),
+minChars: dco_decode_i_32(arr[1]),
+overlap: dco_decode_i_32(arr[2]),
+strategy: dco_decode_chunking_strategy(arr[3]),
+preserveTables: dco_decode_bool(arr[4]),
+preserveCode: dco_decode_bool(arr[5]),
+sentenceTerminators: dco_decode_list_String(arr[6]),
+sectionSummarySentences: dco_decode_u_32(arr[7]),
+linearizeTables: dco_decode_bool(arr[8]),
+keepListIntro: dco_decode_bool(arr[9]),); }
+
+@protected ChunkingStrategy dco_decode_chunking_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChunkingStrategy.values[raw as int]; }
+
+@protected ClassificationRule dco_decode_classification_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ClassificationRule(label: dco_decode_String(arr[0]),
+language: dco_decode_String(arr[1]),
+patterns: dco_decode_list_String(arr[2]),
+minMatches: dco_decode_u_32(arr[3]),); }
+
+@protected CompareSearchResult dco_decode_compare_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CompareSearchResult(subjectIndex: dco_decode_u_32(arr[0]),
+result: dco_decode_hybrid_search_result(arr[1]),); }
+
+@protected CompressedText dco_decode_compressed_text(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CompressedText(text: dco_decode_String(arr[0]),
+originalChars: dco_decode_i_32(arr[1]),
+compressedChars: dco_decode_i_32(arr[2]),
+ratio: dco_decode_f_64(arr[3]),
+sentencesRemoved: dco_decode_i_32(arr[4]),
+charsSavedStopwords: dco_decode_i_32(arr[5]),
+charsSavedTruncation: dco_decode_i_32(arr[6]),); }
+
+@protected CompressionOptions dco_decode_compression_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CompressionOptions(removeStopwords: dco_decode_bool(arr[0]),
+removeDuplicates: dco_decode_bool(arr[1]),
+language: dco_decode_String(arr[2]),
+level: dco_decode_i_32(arr[3]),); }
+
+@protected CorpusCount dco_decode_corpus_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CorpusCount(key: dco_decode_String(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected CorpusReport dco_decode_corpus_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return CorpusReport(sourceCount: dco_decode_u_64(arr[0]),
+chunkCount: dco_decode_u_64(arr[1]),
+totalChars: dco_decode_u_64(arr[2]),
+vocabularySize: dco_decode_u_64(arr[3]),
+sources: dco_decode_list_source_corpus_stats(arr[4]),
+chunkLengthHistogram: dco_decode_list_length_bucket(arr[5]),
+chunkTypes: dco_decode_list_corpus_count(arr[6]),
+languages: dco_decode_list_corpus_count(arr[7]),
+index: dco_decode_index_freshness(arr[8]),); }
+
+@protected DbRetryConfig dco_decode_db_retry_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DbRetryConfig(maxAttempts: dco_decode_u_32(arr[0]),
+baseDelayMs: dco_decode_u_64(arr[1]),
+maxDelayMs: dco_decode_u_64(arr[2]),); }
+
+@protected DedupeConfig dco_decode_dedupe_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DedupeConfig(nearDuplicateThreshold: dco_decode_f_64(arr[0]),
+shingleSize: dco_decode_u_32(arr[1]),
+exactOnly: dco_decode_bool(arr[2]),); }
+
+@protected DedupeReport dco_decode_dedupe_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DedupeReport(kept: dco_decode_list_prim_u_32_strict(arr[0]),
+duplicates: dco_decode_list_duplicate_chunk(arr[1]),); }
+
+@protected DistanceMetric dco_decode_distance_metric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DistanceMetric.values[raw as int]; }
+
+@protected DuplicateChunk dco_decode_duplicate_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DuplicateChunk(index: dco_decode_u_32(arr[0]),
+duplicateOf: dco_decode_u_32(arr[1]),
+similarity: dco_decode_f_64(arr[2]),); }
+
+@protected EmbeddingBatchProgress dco_decode_embedding_batch_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EmbeddingBatchProgress(done: dco_decode_u_32(arr[0]),
+total: dco_decode_u_32(arr[1]),); }
+
+@protected EmbeddingPoint dco_decode_embedding_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EmbeddingPoint(id: dco_decode_i_64(arr[0]),
+embedding: dco_decode_list_prim_f_32_strict(arr[1]),
+norm: dco_decode_f_32(arr[2]),); }
+
+@protected EmbeddingStorageStats dco_decode_embedding_storage_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EmbeddingStorageStats(f32Chunks: dco_decode_i_64(arr[0]),
+quantizedChunks: dco_decode_i_64(arr[1]),
+embeddingBytes: dco_decode_i_64(arr[2]),); }
+
+@protected EngineInfo dco_decode_engine_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return EngineInfo(crateVersion: dco_decode_String(arr[0]),
+schemaVersion: dco_decode_u_32(arr[1]),
+databaseSchemaVersion: dco_decode_opt_box_autoadd_u_32(arr[2]),
+hnswFormatVersion: dco_decode_u_32(arr[3]),
+bm25FormatVersion: dco_decode_u_32(arr[4]),
+snapshotFormatVersion: dco_decode_u_32(arr[5]),
+tokenizerName: dco_decode_opt_String(arr[6]),
+embeddingModelName: dco_decode_opt_String(arr[7]),
+embeddingDimension: dco_decode_opt_box_autoadd_u_32(arr[8]),
+activeTenant: dco_decode_String(arr[9]),
+activeFeatures: dco_decode_list_String(arr[10]),
+buildFeatures: dco_decode_list_String(arr[11]),); }
+
+@protected EngineMetrics dco_decode_engine_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return EngineMetrics(searchesExecuted: dco_decode_u_64(arr[0]),
+cacheHits: dco_decode_u_64(arr[1]),
+cacheMisses: dco_decode_u_64(arr[2]),
+indexRebuilds: dco_decode_u_64(arr[3]),
+dbErrors: dco_decode_u_64(arr[4]),
+panics: dco_decode_u_64(arr[5]),
+avgSearchLatencyMs: dco_decode_f_64(arr[6]),
+searchLatencyHistogram: dco_decode_list_latency_bucket(arr[7]),); }
+
+@protected EntityFacet dco_decode_entity_facet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EntityFacet(entityType: dco_decode_String(arr[0]),
+normalized: dco_decode_String(arr[1]),
+chunkCount: dco_decode_u_32(arr[2]),); }
+
+@protected ExpandedSearchResult dco_decode_expanded_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ExpandedSearchResult(result: dco_decode_hybrid_search_result(arr[0]),
+link: dco_decode_String(arr[1]),
+expandedFrom: dco_decode_opt_box_autoadd_i_64(arr[2]),); }
+
+@protected ExpansionConfig dco_decode_expansion_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ExpansionConfig(maxRelatedPerHit: dco_decode_u_32(arr[0]),
+scoreDecay: dco_decode_f_64(arr[1]),
+followAdjacent: dco_decode_bool(arr[2]),
+followSharedEntities: dco_decode_bool(arr[3]),
+followSameBatch: dco_decode_bool(arr[4]),); }
+
+@protected ExtractedEntity dco_decode_extracted_entity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ExtractedEntity(entityType: dco_decode_String(arr[0]),
+text: dco_decode_String(arr[1]),
+normalized: dco_decode_String(arr[2]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FusionStrategy dco_decode_fusion_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FusionStrategy_Rrf();
+case 1: return FusionStrategy_WeightedScore(normalization: dco_decode_score_normalization(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected GazetteerEntry dco_decode_gazetteer_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return GazetteerEntry(term: dco_decode_String(arr[0]),
+entityType: dco_decode_String(arr[1]),); }
+
+@protected GraphEdge dco_decode_graph_edge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return GraphEdge(subject: dco_decode_String(arr[0]),
+relation: dco_decode_String(arr[1]),
+object: dco_decode_String(arr[2]),
+chunkId: dco_decode_i_64(arr[3]),
+hop: dco_decode_u_32(arr[4]),); }
+
+@protected GraphNeighborhood dco_decode_graph_neighborhood(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return GraphNeighborhood(entity: dco_decode_String(arr[0]),
+edges: dco_decode_list_graph_edge(arr[1]),
+chunkIds: dco_decode_list_prim_i_64_strict(arr[2]),); }
+
+@protected HighlightSpan dco_decode_highlight_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HighlightSpan(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected HnswBuildConfig dco_decode_hnsw_build_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HnswBuildConfig(m: dco_decode_u_32(arr[0]),
+maxLayer: dco_decode_u_32(arr[1]),
+efConstruction: dco_decode_u_32(arr[2]),); }
+
+@protected HnswConfig dco_decode_hnsw_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HnswConfig(build: dco_decode_opt_box_autoadd_hnsw_build_config(arr[0]),
+efSearch: dco_decode_opt_box_autoadd_u_32(arr[1]),); }
+
+@protected HnswIndexStats dco_decode_hnsw_index_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return HnswIndexStats(pointCount: dco_decode_u_64(arr[0]),
+dimension: dco_decode_u_32(arr[1]),
+quantization: dco_decode_quantization(arr[2]),
+metric: dco_decode_distance_metric(arr[3]),
+build: dco_decode_hnsw_build_config(arr[4]),
+m0: dco_decode_u_32(arr[5]),
+customBuild: dco_decode_bool(arr[6]),
+efSearch: dco_decode_opt_box_autoadd_u_32(arr[7]),
+deletedPoints: dco_decode_u_64(arr[8]),); }
+
+@protected HnswSearchResult dco_decode_hnsw_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HnswSearchResult(id: dco_decode_i_64(arr[0]),
+distance: dco_decode_f_32(arr[1]),); }
+
+@protected HybridSearchEvent dco_decode_hybrid_search_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return HybridSearchEvent_Ranking(dco_decode_list_ranked_hit(raw[1]),);
+case 1: return HybridSearchEvent_Results(dco_decode_list_hybrid_search_result(raw[1]),);
+case 2: return HybridSearchEvent_Done();
+                default: throw Exception("unreachable");
+            } }
+
+@protected HybridSearchOptions dco_decode_hybrid_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return HybridSearchOptions(efSearch: dco_decode_opt_box_autoadd_u_32(arr[0]),
+diversityLambda: dco_decode_opt_box_autoadd_f_64(arr[1]),
+collection: dco_decode_opt_String(arr[2]),
+groupBySource: dco_decode_opt_box_autoadd_u_32(arr[3]),
+withDiagnostics: dco_decode_bool(arr[4]),
+pageOffset: dco_decode_opt_box_autoadd_u_32(arr[5]),
+cursor: dco_decode_opt_String(arr[6]),); }
+
+@protected HybridSearchResponse dco_decode_hybrid_search_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HybridSearchResponse(results: dco_decode_list_hybrid_search_result(arr[0]),
+groups: dco_decode_opt_list_source_group(arr[1]),
+diagnostics: dco_decode_opt_box_autoadd_search_diagnostics(arr[2]),
+nextCursor: dco_decode_opt_String(arr[3]),); }
+
+@protected HybridSearchResult dco_decode_hybrid_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return HybridSearchResult(docId: dco_decode_i_64(arr[0]),
+content: dco_decode_String(arr[1]),
+score: dco_decode_f_64(arr[2]),
+vectorRank: dco_decode_u_32(arr[3]),
+bm25Rank: dco_decode_u_32(arr[4]),
+sourceId: dco_decode_i_64(arr[5]),
+metadata: dco_decode_opt_String(arr[6]),
+chunkIndex: dco_decode_u_32(arr[7]),
+chunkType: dco_decode_String(arr[8]),
+headerPath: dco_decode_opt_String(arr[9]),
+matchedTerms: dco_decode_list_String(arr[10]),
+snippet: dco_decode_opt_box_autoadd_search_snippet(arr[11]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IncrementalConfig dco_decode_incremental_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return IncrementalConfig(autoMerge: dco_decode_bool(arr[0]),); }
+
+@protected IncrementalSearchResult dco_decode_incremental_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return IncrementalSearchResult(docId: dco_decode_i_64(arr[0]),
+distance: dco_decode_f_32(arr[1]),
+source: dco_decode_String(arr[2]),); }
+
+@protected IndexConfig dco_decode_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexConfig(quantization: dco_decode_quantization(arr[0]),
+metric: dco_decode_distance_metric(arr[1]),); }
+
+@protected IndexConsistency dco_decode_index_consistency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return IndexConsistency(chunkCount: dco_decode_u_64(arr[0]),
+hnsw: dco_decode_index_divergence(arr[1]),
+bm25: dco_decode_index_divergence(arr[2]),
+consistent: dco_decode_bool(arr[3]),
+repaired: dco_decode_bool(arr[4]),); }
+
+@protected IndexDivergence dco_decode_index_divergence(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IndexDivergence(loaded: dco_decode_bool(arr[0]),
+indexedCount: dco_decode_u_64(arr[1]),
+missingIds: dco_decode_list_prim_i_64_strict(arr[2]),
+extraIds: dco_decode_list_prim_i_64_strict(arr[3]),); }
+
+@protected IndexEvaluation dco_decode_index_evaluation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return IndexEvaluation(sampleSize: dco_decode_u_32(arr[0]),
+topK: dco_decode_u_32(arr[1]),
+corpusSize: dco_decode_u_64(arr[2]),
+recallAtK: dco_decode_f_64(arr[3]),
+minRecall: dco_decode_f_64(arr[4]),
+latencyP50Ms: dco_decode_f_64(arr[5]),
+latencyP95Ms: dco_decode_f_64(arr[6]),
+latencyP99Ms: dco_decode_f_64(arr[7]),
+exactMeanMs: dco_decode_f_64(arr[8]),); }
+
+@protected IndexFreshness dco_decode_index_freshness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return IndexFreshness(dbChunkCount: dco_decode_u_64(arr[0]),
+hnswPointCount: dco_decode_opt_box_autoadd_u_64(arr[1]),
+bm25DocumentCount: dco_decode_u_64(arr[2]),
+hnswUpToDate: dco_decode_bool(arr[3]),
+bm25UpToDate: dco_decode_bool(arr[4]),
+lastSourceAddedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),); }
+
+@protected IndexStaleness dco_decode_index_staleness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexStaleness(hnswStale: dco_decode_bool(arr[0]),
+bm25Stale: dco_decode_bool(arr[1]),); }
+
+@protected IngestInput dco_decode_ingest_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return IngestInput_Text(dco_decode_String(raw[1]),);
+case 1: return IngestInput_Bytes(dco_decode_list_prim_u_8_strict(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected IngestJob dco_decode_ingest_job(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return IngestJob(id: dco_decode_i_64(arr[0]),
+filePath: dco_decode_String(arr[1]),
+name: dco_decode_opt_String(arr[2]),
+state: dco_decode_String(arr[3]),
+stage: dco_decode_opt_String(arr[4]),
+progressDone: dco_decode_u_32(arr[5]),
+progressTotal: dco_decode_u_32(arr[6]),
+sourceId: dco_decode_opt_box_autoadd_i_64(arr[7]),
+error: dco_decode_opt_String(arr[8]),
+createdAt: dco_decode_i_64(arr[9]),
+updatedAt: dco_decode_i_64(arr[10]),); }
+
+@protected IngestOptions dco_decode_ingest_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return IngestOptions(name: dco_decode_opt_String(arr[0]),
+metadata: dco_decode_opt_String(arr[1]),
+strategy: dco_decode_opt_box_autoadd_chunking_strategy(arr[2]),
+maxChars: dco_decode_i_32(arr[3]),
+overlapChars: dco_decode_i_32(arr[4]),
+rebuildIndexes: dco_decode_bool(arr[5]),
+dedupeChunks: dco_decode_bool(arr[6]),); }
+
+@protected IngestProgress dco_decode_ingest_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IngestProgress(stage: dco_decode_String(arr[0]),
+done: dco_decode_u_32(arr[1]),
+total: dco_decode_u_32(arr[2]),
+sourceId: dco_decode_opt_box_autoadd_i_64(arr[3]),); }
+
+@protected IngestReport dco_decode_ingest_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return IngestReport(sourceId: dco_decode_i_64(arr[0]),
+isDuplicate: dco_decode_bool(arr[1]),
+status: dco_decode_String(arr[2]),
+chunkCount: dco_decode_u_32(arr[3]),
+skippedChunks: dco_decode_u_32(arr[4]),
+textChars: dco_decode_u_64(arr[5]),
+elapsedMs: dco_decode_u_64(arr[6]),); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected KeywordBackend dco_decode_keyword_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KeywordBackend.values[raw as int]; }
+
+@protected KnowledgeTriple dco_decode_knowledge_triple(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KnowledgeTriple(subject: dco_decode_String(arr[0]),
+relation: dco_decode_String(arr[1]),
+object: dco_decode_String(arr[2]),); }
+
+@protected LanguageAnalyzer dco_decode_language_analyzer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LanguageAnalyzer(language: dco_decode_String(arr[0]),
+stopwords: dco_decode_list_String(arr[1]),); }
+
+@protected LastRetrieval dco_decode_last_retrieval(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LastRetrieval(query: dco_decode_opt_String(arr[0]),
+citations: dco_decode_list_retrieval_citation(arr[1]),); }
+
+@protected LatencyBucket dco_decode_latency_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LatencyBucket(leMs: dco_decode_opt_box_autoadd_f_64(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected LengthBucket dco_decode_length_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LengthBucket(leChars: dco_decode_opt_box_autoadd_u_32(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AuditIssue> dco_decode_list_audit_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_audit_issue).toList(); }
+
+@protected List<BackgroundTask> dco_decode_list_background_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_background_task).toList(); }
+
+@protected List<BenchmarkStage> dco_decode_list_benchmark_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_benchmark_stage).toList(); }
+
+@protected List<Bm25SearchResult> dco_decode_list_bm_25_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_bm_25_search_result).toList(); }
+
+@protected List<ChunkData> dco_decode_list_chunk_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chunk_data).toList(); }
+
+@protected List<ChunkForReembedding> dco_decode_list_chunk_for_reembedding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chunk_for_reembedding).toList(); }
+
+@protected List<ChunkMeta> dco_decode_list_chunk_meta(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chunk_meta).toList(); }
+
+@protected List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chunk_search_result).toList(); }
+
+@protected List<ClassificationRule> dco_decode_list_classification_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_classification_rule).toList(); }
+
+@protected List<CompareSearchResult> dco_decode_list_compare_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_compare_search_result).toList(); }
+
+@protected List<CorpusCount> dco_decode_list_corpus_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_corpus_count).toList(); }
+
+@protected List<DuplicateChunk> dco_decode_list_duplicate_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_duplicate_chunk).toList(); }
+
+@protected List<EntityFacet> dco_decode_list_entity_facet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_entity_facet).toList(); }
+
+@protected List<ExpandedSearchResult> dco_decode_list_expanded_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_expanded_search_result).toList(); }
+
+@protected List<ExtractedEntity> dco_decode_list_extracted_entity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_extracted_entity).toList(); }
+
+@protected List<GazetteerEntry> dco_decode_list_gazetteer_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_gazetteer_entry).toList(); }
+
+@protected List<GraphEdge> dco_decode_list_graph_edge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_graph_edge).toList(); }
+
+@protected List<HighlightSpan> dco_decode_list_highlight_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_highlight_span).toList(); }
+
+@protected List<HnswSearchResult> dco_decode_list_hnsw_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_hnsw_search_result).toList(); }
+
+@protected List<HybridSearchResult> dco_decode_list_hybrid_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_hybrid_search_result).toList(); }
+
+@protected List<IncrementalSearchResult> dco_decode_list_incremental_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_incremental_search_result).toList(); }
+
+@protected List<IngestJob> dco_decode_list_ingest_job(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ingest_job).toList(); }
+
+@protected List<KnowledgeTriple> dco_decode_list_knowledge_triple(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_knowledge_triple).toList(); }
+
+@protected List<LanguageAnalyzer> dco_decode_list_language_analyzer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_language_analyzer).toList(); }
+
+@protected List<LatencyBucket> dco_decode_list_latency_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_latency_bucket).toList(); }
+
+@protected List<LengthBucket> dco_decode_list_length_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_length_bucket).toList(); }
+
+@protected List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_prim_f_32_strict).toList(); }
+
+@protected List<MemoryMessage> dco_decode_list_memory_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_memory_message).toList(); }
+
+@protected List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_memory_search_result).toList(); }
+
+@protected List<NamedBm25IndexInfo> dco_decode_list_named_bm_25_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_named_bm_25_index_info).toList(); }
+
+@protected List<NamedIndexInfo> dco_decode_list_named_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_named_index_info).toList(); }
+
+@protected List<PanicEvent> dco_decode_list_panic_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_panic_event).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected List<double> dco_decode_list_prim_f_64_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float64List; }
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeInt64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryKeyword> dco_decode_list_query_keyword(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_keyword).toList(); }
+
+@protected List<QuerySuggestion> dco_decode_list_query_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_suggestion).toList(); }
+
+@protected List<RankedHit> dco_decode_list_ranked_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ranked_hit).toList(); }
+
+@protected List<(PlatformInt64,Float32List)> dco_decode_list_record_i_64_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_i_64_list_prim_f_32_strict).toList(); }
+
+@protected List<(PlatformInt64,String)> dco_decode_list_record_i_64_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_i_64_string).toList(); }
+
+@protected List<(String,double)> dco_decode_list_record_string_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_f_64).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<RetrievalCitation> dco_decode_list_retrieval_citation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_retrieval_citation).toList(); }
+
+@protected List<SearchSpan> dco_decode_list_search_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_span).toList(); }
+
+@protected List<SemanticChunk> dco_decode_list_semantic_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_semantic_chunk).toList(); }
+
+@protected List<SnapshotFile> dco_decode_list_snapshot_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snapshot_file).toList(); }
+
+@protected List<SourceCorpusStats> dco_decode_list_source_corpus_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_source_corpus_stats).toList(); }
+
+@protected List<SourceEntry> dco_decode_list_source_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_source_entry).toList(); }
+
+@protected List<SourceGroup> dco_decode_list_source_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_source_group).toList(); }
+
+@protected List<StoreSearchHit> dco_decode_list_store_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_store_search_hit).toList(); }
+
+@protected List<StructuredChunk> dco_decode_list_structured_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_structured_chunk).toList(); }
+
+@protected List<SynonymEntry> dco_decode_list_synonym_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_synonym_entry).toList(); }
+
+@protected MaintenanceConfig dco_decode_maintenance_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MaintenanceConfig(requireCharging: dco_decode_bool(arr[0]),
+minIntervalSecs: dco_decode_u_32(arr[1]),); }
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return MaintenanceReport(startedAt: dco_decode_i_64(arr[0]),
+durationMs: dco_decode_u_64(arr[1]),
+bufferMerged: dco_decode_bool(arr[2]),
+hnswCompacted: dco_decode_bool(arr[3]),
+bm25Pruned: dco_decode_u_32(arr[4]),
+walPagesCheckpointed: dco_decode_i_64(arr[5]),
+interrupted: dco_decode_bool(arr[6]),); }
+
+@protected MemoryMessage dco_decode_memory_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MemoryMessage(id: dco_decode_i_64(arr[0]),
+conversationId: dco_decode_String(arr[1]),
+role: dco_decode_String(arr[2]),
+content: dco_decode_String(arr[3]),
+createdAt: dco_decode_i_64(arr[4]),); }
+
+@protected MemoryProfile dco_decode_memory_profile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MemoryProfile(budgetMb: dco_decode_opt_box_autoadd_u_32(arr[0]),
+lowMemory: dco_decode_bool(arr[1]),
+sqliteCacheKb: dco_decode_u_32(arr[2]),
+sqliteMmapMb: dco_decode_u_32(arr[3]),
+quantizeIndex: dco_decode_bool(arr[4]),
+candidateMultiplier: dco_decode_u_32(arr[5]),
+filteredCandidateMultiplier: dco_decode_u_32(arr[6]),
+scanPageRows: dco_decode_u_32(arr[7]),); }
+
+@protected MemorySearchResult dco_decode_memory_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MemorySearchResult(message: dco_decode_memory_message(arr[0]),
+similarity: dco_decode_f_64(arr[1]),); }
+
+@protected MemoryUsage dco_decode_memory_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return MemoryUsage(lowMemory: dco_decode_bool(arr[0]),
+hnswPoints: dco_decode_u_64(arr[1]),
+quantizedPoints: dco_decode_u_64(arr[2]),
+quantizedIndexBytes: dco_decode_u_64(arr[3]),
+bufferedEmbeddings: dco_decode_u_64(arr[4]),
+sqliteMemoryBytes: dco_decode_u_64(arr[5]),
+processRssBytes: dco_decode_opt_box_autoadd_u_64(arr[6]),); }
+
+@protected NamedBm25IndexInfo dco_decode_named_bm_25_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NamedBm25IndexInfo(name: dco_decode_String(arr[0]),
+documentCount: dco_decode_u_64(arr[1]),
+vocabularySize: dco_decode_u_64(arr[2]),); }
+
+@protected NamedIndexConfig dco_decode_named_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NamedIndexConfig(metric: dco_decode_opt_box_autoadd_distance_metric(arr[0]),
+build: dco_decode_opt_box_autoadd_hnsw_build_config(arr[1]),
+efSearch: dco_decode_opt_box_autoadd_u_32(arr[2]),); }
+
+@protected NamedIndexInfo dco_decode_named_index_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NamedIndexInfo(name: dco_decode_String(arr[0]),
+pointCount: dco_decode_u_64(arr[1]),
+deletedPoints: dco_decode_u_64(arr[2]),
+dimension: dco_decode_u_32(arr[3]),
+metric: dco_decode_distance_metric(arr[4]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AuditReport? dco_decode_opt_box_autoadd_audit_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_audit_report(raw); }
+
+@protected BackgroundTask? dco_decode_opt_box_autoadd_background_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_background_task(raw); }
+
+@protected Bm25FuzzyConfig? dco_decode_opt_box_autoadd_bm_25_fuzzy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bm_25_fuzzy_config(raw); }
+
+@protected ChunkingStrategy? dco_decode_opt_box_autoadd_chunking_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_chunking_strategy(raw); }
+
+@protected DistanceMetric? dco_decode_opt_box_autoadd_distance_metric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_distance_metric(raw); }
+
+@protected ExpansionConfig? dco_decode_opt_box_autoadd_expansion_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_expansion_config(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected HnswBuildConfig? dco_decode_opt_box_autoadd_hnsw_build_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_hnsw_build_config(raw); }
+
+@protected HnswIndexStats? dco_decode_opt_box_autoadd_hnsw_index_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_hnsw_index_stats(raw); }
+
+@protected HybridSearchOptions? dco_decode_opt_box_autoadd_hybrid_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_hybrid_search_options(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected LastRetrieval? dco_decode_opt_box_autoadd_last_retrieval(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_last_retrieval(raw); }
+
+@protected MaintenanceReport? dco_decode_opt_box_autoadd_maintenance_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_maintenance_report(raw); }
+
+@protected (int,int,int)? dco_decode_opt_box_autoadd_record_u_32_u_32_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_record_u_32_u_32_u_32(raw); }
+
+@protected ReembeddingJob? dco_decode_opt_box_autoadd_reembedding_job(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_reembedding_job(raw); }
+
+@protected RrfConfig? dco_decode_opt_box_autoadd_rrf_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rrf_config(raw); }
+
+@protected SearchDiagnostics? dco_decode_opt_box_autoadd_search_diagnostics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_search_diagnostics(raw); }
+
+@protected SearchFilter? dco_decode_opt_box_autoadd_search_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_search_filter(raw); }
+
+@protected SearchSnippet? dco_decode_opt_box_autoadd_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_search_snippet(raw); }
+
+@protected SearchTrace? dco_decode_opt_box_autoadd_search_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_search_trace(raw); }
+
+@protected StoredChunk? dco_decode_opt_box_autoadd_stored_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stored_chunk(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected Int64List? dco_decode_opt_list_prim_i_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_i_64_strict(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected List<(String,String)>? dco_decode_opt_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_record_string_string(raw); }
+
+@protected List<SourceGroup>? dco_decode_opt_list_source_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_source_group(raw); }
+
+@protected PanicEvent dco_decode_panic_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PanicEvent(thread: dco_decode_String(arr[0]),
+message: dco_decode_String(arr[1]),
+location: dco_decode_opt_String(arr[2]),
+backtrace: dco_decode_opt_String(arr[3]),
+timestamp: dco_decode_i_64(arr[4]),); }
+
+@protected ParsedIntent dco_decode_parsed_intent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ParsedIntent(intentType: dco_decode_String(arr[0]),
+query: dco_decode_String(arr[1]),
+isValid: dco_decode_bool(arr[2]),
+errorMessage: dco_decode_opt_String(arr[3]),
+language: dco_decode_String(arr[4]),); }
+
+@protected ProgressEvent dco_decode_progress_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ProgressEvent(operation: dco_decode_String(arr[0]),
+phase: dco_decode_String(arr[1]),
+done: dco_decode_u_32(arr[2]),
+total: dco_decode_u_32(arr[3]),); }
+
+@protected Quantization dco_decode_quantization(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Quantization.values[raw as int]; }
+
+@protected QueryDecomposition dco_decode_query_decomposition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return QueryDecomposition(original: dco_decode_String(arr[0]),
+subQueries: dco_decode_list_String(arr[1]),); }
+
+@protected QueryKeyword dco_decode_query_keyword(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return QueryKeyword(text: dco_decode_String(arr[0]),
+score: dco_decode_f_64(arr[1]),
+docFreq: dco_decode_u_32(arr[2]),
+inIndex: dco_decode_bool(arr[3]),); }
+
+@protected QueryNormalizationConfig dco_decode_query_normalization_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return QueryNormalizationConfig(unicodeNfkc: dco_decode_bool(arr[0]),
+stripPunctuation: dco_decode_bool(arr[1]),
+lowercase: dco_decode_bool(arr[2]),
+removeStopwords: dco_decode_bool(arr[3]),); }
+
+@protected QuerySuggestion dco_decode_query_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QuerySuggestion(query: dco_decode_String(arr[0]),
+useCount: dco_decode_u_32(arr[1]),
+lastUsedAt: dco_decode_i_64(arr[2]),); }
+
+@protected RagError dco_decode_rag_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RagError_DatabaseError(dco_decode_String(raw[1]),);
+case 1: return RagError_IoError(dco_decode_String(raw[1]),);
+case 2: return RagError_ModelLoadError(dco_decode_String(raw[1]),);
+case 3: return RagError_InvalidInput(dco_decode_String(raw[1]),);
+case 4: return RagError_InternalError(dco_decode_String(raw[1]),);
+case 5: return RagError_Unknown(dco_decode_String(raw[1]),);
+case 6: return RagError_DatabaseBusy(dco_decode_String(raw[1]),);
+case 7: return RagError_NotInitialized(dco_decode_String(raw[1]),);
+case 8: return RagError_ParseError(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RankedHit dco_decode_ranked_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RankedHit(docId: dco_decode_i_64(arr[0]),
+score: dco_decode_f_64(arr[1]),
+vectorRank: dco_decode_u_32(arr[2]),
+bm25Rank: dco_decode_u_32(arr[3]),); }
+
+@protected (PlatformInt64,Float32List) dco_decode_record_i_64_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_64(arr[0]),dco_decode_list_prim_f_32_strict(arr[1]),); }
+
+@protected (PlatformInt64,String) dco_decode_record_i_64_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_64(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected (String,double) dco_decode_record_string_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_f_64(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected (int,int,int) dco_decode_record_u_32_u_32_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 3) {
+                throw Exception('Expected 3 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_u_32(arr[1]),dco_decode_u_32(arr[2]),); }
+
+@protected ReembeddingJob dco_decode_reembedding_job(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ReembeddingJob(id: dco_decode_i_64(arr[0]),
+modelName: dco_decode_String(arr[1]),
+batchSize: dco_decode_u_32(arr[2]),
+state: dco_decode_String(arr[3]),
+done: dco_decode_u_32(arr[4]),
+total: dco_decode_u_32(arr[5]),
+lastChunkId: dco_decode_i_64(arr[6]),
+dimension: dco_decode_opt_box_autoadd_u_32(arr[7]),
+error: dco_decode_opt_String(arr[8]),
+createdAt: dco_decode_i_64(arr[9]),
+updatedAt: dco_decode_i_64(arr[10]),); }
+
+@protected RetrievalCitation dco_decode_retrieval_citation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RetrievalCitation(chunkId: dco_decode_i_64(arr[0]),
+sourceId: dco_decode_i_64(arr[1]),
+chunkIndex: dco_decode_i_32(arr[2]),
+score: dco_decode_f_64(arr[3]),
+metadata: dco_decode_opt_String(arr[4]),); }
+
+@protected RetrievalPreset dco_decode_retrieval_preset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RetrievalPreset(topK: dco_decode_u_32(arr[0]),
+vectorWeight: dco_decode_f_64(arr[1]),
+bm25Weight: dco_decode_f_64(arr[2]),
+boostChunkType: dco_decode_opt_String(arr[3]),
+chunkTypeBoost: dco_decode_f_64(arr[4]),
+useMmr: dco_decode_bool(arr[5]),
+mmrLambda: dco_decode_f_64(arr[6]),); }
+
+@protected RewrittenQuery dco_decode_rewritten_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RewrittenQuery(original: dco_decode_String(arr[0]),
+rewritten: dco_decode_String(arr[1]),
+wasRewritten: dco_decode_bool(arr[2]),
+antecedent: dco_decode_opt_String(arr[3]),); }
+
+@protected RrfConfig dco_decode_rrf_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RrfConfig(k: dco_decode_u_32(arr[0]),
+vectorWeight: dco_decode_f_64(arr[1]),
+bm25Weight: dco_decode_f_64(arr[2]),
+typeBoosts: dco_decode_Map_String_f_64_None(arr[3]),
+recencyHalfLifeDays: dco_decode_opt_box_autoadd_f_64(arr[4]),
+recencyWeight: dco_decode_f_64(arr[5]),
+fusion: dco_decode_fusion_strategy(arr[6]),); }
+
+@protected ScoreNormalization dco_decode_score_normalization(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScoreNormalization.values[raw as int]; }
+
+@protected SearchDiagnostics dco_decode_search_diagnostics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return SearchDiagnostics(vectorMs: dco_decode_f_64(arr[0]),
+bm25Ms: dco_decode_f_64(arr[1]),
+filterMs: dco_decode_f_64(arr[2]),
+fusionMs: dco_decode_f_64(arr[3]),
+fetchMs: dco_decode_f_64(arr[4]),
+totalMs: dco_decode_f_64(arr[5]),
+candidateK: dco_decode_u_32(arr[6]),
+vectorCandidates: dco_decode_u_32(arr[7]),
+bm25Candidates: dco_decode_u_32(arr[8]),
+filteredVectorCandidates: dco_decode_u_32(arr[9]),
+filteredBm25Candidates: dco_decode_u_32(arr[10]),
+exactScan: dco_decode_bool(arr[11]),
+graphFilter: dco_decode_bool(arr[12]),
+hnswLoaded: dco_decode_bool(arr[13]),); }
+
+@protected SearchFeedbackAction dco_decode_search_feedback_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SearchFeedbackAction.values[raw as int]; }
+
+@protected SearchFilter dco_decode_search_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SearchFilter(sourceIds: dco_decode_opt_list_prim_i_64_strict(arr[0]),
+metadataLike: dco_decode_opt_String(arr[1]),
+entity: dco_decode_opt_String(arr[2]),
+language: dco_decode_opt_String(arr[3]),
+chunkTypes: dco_decode_opt_list_String(arr[4]),
+metadataEquals: dco_decode_opt_list_record_string_string(arr[5]),
+createdAfter: dco_decode_opt_box_autoadd_i_64(arr[6]),
+createdBefore: dco_decode_opt_box_autoadd_i_64(arr[7]),); }
+
+@protected SearchSnippet dco_decode_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SearchSnippet(text: dco_decode_String(arr[0]),
+start: dco_decode_u_32(arr[1]),
+end: dco_decode_u_32(arr[2]),
+highlights: dco_decode_list_highlight_span(arr[3]),); }
+
+@protected SearchSpan dco_decode_search_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SearchSpan(name: dco_decode_String(arr[0]),
+durationMs: dco_decode_f_64(arr[1]),); }
+
+@protected SearchTrace dco_decode_search_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SearchTrace(spans: dco_decode_list_search_span(arr[0]),
+totalMs: dco_decode_f_64(arr[1]),); }
+
+@protected SemanticChunk dco_decode_semantic_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SemanticChunk(index: dco_decode_i_32(arr[0]),
+content: dco_decode_String(arr[1]),
+startPos: dco_decode_i_32(arr[2]),
+endPos: dco_decode_i_32(arr[3]),
+chunkType: dco_decode_String(arr[4]),
+language: dco_decode_String(arr[5]),); }
+
+@protected SnapshotFile dco_decode_snapshot_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SnapshotFile(name: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),
+sha256: dco_decode_String(arr[2]),); }
+
+@protected SnapshotManifest dco_decode_snapshot_manifest(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SnapshotManifest(formatVersion: dco_decode_u_32(arr[0]),
+engineVersion: dco_decode_String(arr[1]),
+createdAt: dco_decode_i_64(arr[2]),
+tenants: dco_decode_list_String(arr[3]),
+sourceCount: dco_decode_i_64(arr[4]),
+chunkCount: dco_decode_i_64(arr[5]),
+files: dco_decode_list_snapshot_file(arr[6]),); }
+
+@protected SourceCorpusStats dco_decode_source_corpus_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SourceCorpusStats(sourceId: dco_decode_i_64(arr[0]),
+name: dco_decode_opt_String(arr[1]),
+status: dco_decode_opt_String(arr[2]),
+chunkCount: dco_decode_u_64(arr[3]),
+minChunkChars: dco_decode_u_32(arr[4]),
+maxChunkChars: dco_decode_u_32(arr[5]),
+avgChunkChars: dco_decode_f_64(arr[6]),); }
+
+@protected SourceEntry dco_decode_source_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SourceEntry(id: dco_decode_i_64(arr[0]),
+name: dco_decode_opt_String(arr[1]),
+createdAt: dco_decode_i_64(arr[2]),
+metadata: dco_decode_opt_String(arr[3]),
+status: dco_decode_opt_String(arr[4]),); }
+
+@protected SourceGroup dco_decode_source_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SourceGroup(sourceId: dco_decode_i_64(arr[0]),
+isDocument: dco_decode_bool(arr[1]),
+sourceName: dco_decode_opt_String(arr[2]),
+score: dco_decode_f_64(arr[3]),
+chunks: dco_decode_list_hybrid_search_result(arr[4]),); }
+
+@protected SourceIndexConfig dco_decode_source_index_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SourceIndexConfig(enabled: dco_decode_bool(arr[0]),
+maxCached: dco_decode_u_32(arr[1]),); }
+
+@protected SourceStats dco_decode_source_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SourceStats(sourceCount: dco_decode_i_64(arr[0]),
+chunkCount: dco_decode_i_64(arr[1]),); }
+
+@protected SourceUpdate dco_decode_source_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SourceUpdate(stagingId: dco_decode_String(arr[0]),
+chunks: dco_decode_list_String(arr[1]),
+keptChunks: dco_decode_u_32(arr[2]),
+removedChunks: dco_decode_u_32(arr[3]),
+skippedChunks: dco_decode_u_32(arr[4]),
+expiresAt: dco_decode_i_64(arr[5]),); }
+
+@protected StagedIngest dco_decode_staged_ingest(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return StagedIngest(stagingId: dco_decode_String(arr[0]),
+chunks: dco_decode_list_String(arr[1]),
+skippedChunks: dco_decode_u_32(arr[2]),
+expiresAt: dco_decode_i_64(arr[3]),); }
+
+@protected StoreSearchHit dco_decode_store_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return StoreSearchHit(id: dco_decode_i_64(arr[0]),
+similarity: dco_decode_f_64(arr[1]),); }
+
+@protected StoredChunk dco_decode_stored_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return StoredChunk(id: dco_decode_i_64(arr[0]),
+sourceId: dco_decode_i_64(arr[1]),
+content: dco_decode_String(arr[2]),
+embedding: dco_decode_list_prim_f_32_strict(arr[3]),); }
+
+@protected StructuredChunk dco_decode_structured_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return StructuredChunk(index: dco_decode_i_32(arr[0]),
+content: dco_decode_String(arr[1]),
+headerPath: dco_decode_String(arr[2]),
+chunkType: dco_decode_String(arr[3]),
+startPos: dco_decode_i_32(arr[4]),
+endPos: dco_decode_i_32(arr[5]),
+batchId: dco_decode_opt_String(arr[6]),
+batchIndex: dco_decode_opt_box_autoadd_i_32(arr[7]),
+batchTotal: dco_decode_opt_box_autoadd_i_32(arr[8]),
+language: dco_decode_String(arr[9]),); }
+
+@protected SynonymEntry dco_decode_synonym_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SynonymEntry(term: dco_decode_String(arr[0]),
+expansions: dco_decode_list_String(arr[1]),); }
+
+@protected TracedHybridSearch dco_decode_traced_hybrid_search(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TracedHybridSearch(results: dco_decode_list_hybrid_search_result(arr[0]),
+trace: dco_decode_opt_box_autoadd_search_trace(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UserIntent dco_decode_user_intent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UserIntent_Summary(query: dco_decode_String(raw[1]),);
+case 1: return UserIntent_Define(term: dco_decode_String(raw[1]),);
+case 2: return UserIntent_ExpandKnowledge(query: dco_decode_String(raw[1]),);
+case 3: return UserIntent_General(query: dco_decode_String(raw[1]),);
+case 4: return UserIntent_InvalidCommand(command: dco_decode_String(raw[1]),reason: dco_decode_String(raw[2]),);
+case 5: return UserIntent_Compare(left: dco_decode_String(raw[1]),right: dco_decode_String(raw[2]),);
+case 6: return UserIntent_Cite(query: dco_decode_String(raw[1]),);
+case 7: return UserIntent_Sources(query: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected MemoryStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MemoryStore sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MemoryStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, double> sse_decode_Map_String_f_64_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_f_64(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected MemoryStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MemoryStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<HybridSearchEvent> sse_decode_StreamSink_hybrid_search_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ProgressEvent> sse_decode_StreamSink_progress_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AddDocumentResult sse_decode_add_document_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_success = sse_decode_bool(deserializer);
+var var_isDuplicate = sse_decode_bool(deserializer);
+var var_message = sse_decode_String(deserializer);
+return AddDocumentResult(success: var_success, isDuplicate: var_isDuplicate, message: var_message); }
+
+@protected AddSourceResult sse_decode_add_source_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_isDuplicate = sse_decode_bool(deserializer);
+var var_chunkCount = sse_decode_i_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+return AddSourceResult(sourceId: var_sourceId, isDuplicate: var_isDuplicate, chunkCount: var_chunkCount, message: var_message); }
+
+@protected AuditIssue sse_decode_audit_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_severity = sse_decode_String(deserializer);
+var var_code = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_sourceId = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_chunkId = sse_decode_opt_box_autoadd_i_64(deserializer);
+return AuditIssue(severity: var_severity, code: var_code, message: var_message, sourceId: var_sourceId, chunkId: var_chunkId); }
+
+@protected AuditReport sse_decode_audit_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startedAt = sse_decode_i_64(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_sourcesChecked = sse_decode_u_64(deserializer);
+var var_chunksChecked = sse_decode_u_64(deserializer);
+var var_issues = sse_decode_list_audit_issue(deserializer);
+var var_issueCount = sse_decode_u_32(deserializer);
+var var_maxSeverity = sse_decode_opt_String(deserializer);
+return AuditReport(startedAt: var_startedAt, durationMs: var_durationMs, sourcesChecked: var_sourcesChecked, chunksChecked: var_chunksChecked, issues: var_issues, issueCount: var_issueCount, maxSeverity: var_maxSeverity); }
+
+@protected BackgroundTask sse_decode_background_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_kind = sse_decode_background_task_kind(deserializer);
+var var_tenantId = sse_decode_String(deserializer);
+var var_state = sse_decode_String(deserializer);
+var var_detail = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_submittedAt = sse_decode_i_64(deserializer);
+var var_startedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_finishedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return BackgroundTask(id: var_id, kind: var_kind, tenantId: var_tenantId, state: var_state, detail: var_detail, error: var_error, submittedAt: var_submittedAt, startedAt: var_startedAt, finishedAt: var_finishedAt); }
+
+@protected BackgroundTaskKind sse_decode_background_task_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BackgroundTaskKind.values[inner]; }
+
+@protected BenchmarkConfig sse_decode_benchmark_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_documentBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_syntheticParagraphs = sse_decode_u_32(deserializer);
+var var_chunkMaxChars = sse_decode_i_32(deserializer);
+var var_embeddingDim = sse_decode_u_32(deserializer);
+var var_searchIterations = sse_decode_u_32(deserializer);
+var var_topK = sse_decode_u_32(deserializer);
+return BenchmarkConfig(documentBytes: var_documentBytes, syntheticParagraphs: var_syntheticParagraphs, chunkMaxChars: var_chunkMaxChars, embeddingDim: var_embeddingDim, searchIterations: var_searchIterations, topK: var_topK); }
+
+@protected BenchmarkReport sse_decode_benchmark_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stages = sse_decode_list_benchmark_stage(deserializer);
+var var_documentChars = sse_decode_u_32(deserializer);
+var var_chunkCount = sse_decode_u_32(deserializer);
+var var_tokenCount = sse_decode_u_32(deserializer);
+var var_searchMeanMs = sse_decode_f_64(deserializer);
+var var_searchP95Ms = sse_decode_f_64(deserializer);
+var var_peakRssKb = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_totalMs = sse_decode_f_64(deserializer);
+return BenchmarkReport(stages: var_stages, documentChars: var_documentChars, chunkCount: var_chunkCount, tokenCount: var_tokenCount, searchMeanMs: var_searchMeanMs, searchP95Ms: var_searchP95Ms, peakRssKb: var_peakRssKb, totalMs: var_totalMs); }
+
+@protected BenchmarkStage sse_decode_benchmark_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_durationMs = sse_decode_f_64(deserializer);
+var var_skipped = sse_decode_bool(deserializer);
+var var_rssKb = sse_decode_opt_box_autoadd_u_64(deserializer);
+return BenchmarkStage(name: var_name, durationMs: var_durationMs, skipped: var_skipped, rssKb: var_rssKb); }
+
+@protected Bm25Config sse_decode_bm_25_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_k1 = sse_decode_f_64(deserializer);
+var var_b = sse_decode_f_64(deserializer);
+var var_variant = sse_decode_bm_25_variant(deserializer);
+return Bm25Config(k1: var_k1, b: var_b, variant: var_variant); }
+
+@protected Bm25FuzzyConfig sse_decode_bm_25_fuzzy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxEdits = sse_decode_u_32(deserializer);
+var var_minTermChars = sse_decode_u_32(deserializer);
+var var_maxExpansions = sse_decode_u_32(deserializer);
+return Bm25FuzzyConfig(maxEdits: var_maxEdits, minTermChars: var_minTermChars, maxExpansions: var_maxExpansions); }
+
+@protected Bm25IndexOptions sse_decode_bm_25_index_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tokenization = sse_decode_bm_25_tokenization(deserializer);
+var var_stemmer = sse_decode_opt_String(deserializer);
+var var_stripKoreanParticles = sse_decode_bool(deserializer);
+var var_removeStopwords = sse_decode_bool(deserializer);
+return Bm25IndexOptions(tokenization: var_tokenization, stemmer: var_stemmer, stripKoreanParticles: var_stripKoreanParticles, removeStopwords: var_removeStopwords); }
+
+@protected Bm25SearchResult sse_decode_bm_25_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_docId = sse_decode_i_64(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+return Bm25SearchResult(docId: var_docId, score: var_score); }
+
+@protected Bm25Tokenization sse_decode_bm_25_tokenization(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Bm25Tokenization.values[inner]; }
+
+@protected Bm25Variant sse_decode_bm_25_variant(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Bm25Variant.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AuditReport sse_decode_box_autoadd_audit_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audit_report(deserializer)); }
+
+@protected BackgroundTask sse_decode_box_autoadd_background_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_background_task(deserializer)); }
+
+@protected BenchmarkConfig sse_decode_box_autoadd_benchmark_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_benchmark_config(deserializer)); }
+
+@protected Bm25Config sse_decode_box_autoadd_bm_25_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bm_25_config(deserializer)); }
+
+@protected Bm25FuzzyConfig sse_decode_box_autoadd_bm_25_fuzzy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bm_25_fuzzy_config(deserializer)); }
+
+@protected Bm25IndexOptions sse_decode_box_autoadd_bm_25_index_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bm_25_index_options(deserializer)); }
+
+@protected ChunkingConfig sse_decode_box_autoadd_chunking_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chunking_config(deserializer)); }
+
+@protected ChunkingStrategy sse_decode_box_autoadd_chunking_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chunking_strategy(deserializer)); }
+
+@protected CompressionOptions sse_decode_box_autoadd_compression_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_compression_options(deserializer)); }
+
+@protected DbRetryConfig sse_decode_box_autoadd_db_retry_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_db_retry_config(deserializer)); }
+
+@protected DedupeConfig sse_decode_box_autoadd_dedupe_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_dedupe_config(deserializer)); }
+
+@protected DistanceMetric sse_decode_box_autoadd_distance_metric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_distance_metric(deserializer)); }
+
+@protected ExpansionConfig sse_decode_box_autoadd_expansion_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_expansion_config(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected HnswBuildConfig sse_decode_box_autoadd_hnsw_build_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hnsw_build_config(deserializer)); }
+
+@protected HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hnsw_config(deserializer)); }
+
+@protected HnswIndexStats sse_decode_box_autoadd_hnsw_index_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hnsw_index_stats(deserializer)); }
+
+@protected HybridSearchOptions sse_decode_box_autoadd_hybrid_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_hybrid_search_options(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected IncrementalConfig sse_decode_box_autoadd_incremental_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_incremental_config(deserializer)); }
+
+@protected IndexConfig sse_decode_box_autoadd_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_index_config(deserializer)); }
+
+@protected IngestInput sse_decode_box_autoadd_ingest_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ingest_input(deserializer)); }
+
+@protected IngestOptions sse_decode_box_autoadd_ingest_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ingest_options(deserializer)); }
+
+@protected LanguageAnalyzer sse_decode_box_autoadd_language_analyzer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_language_analyzer(deserializer)); }
+
+@protected LastRetrieval sse_decode_box_autoadd_last_retrieval(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_last_retrieval(deserializer)); }
+
+@protected MaintenanceConfig sse_decode_box_autoadd_maintenance_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_maintenance_config(deserializer)); }
+
+@protected MaintenanceReport sse_decode_box_autoadd_maintenance_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_maintenance_report(deserializer)); }
+
+@protected NamedIndexConfig sse_decode_box_autoadd_named_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_named_index_config(deserializer)); }
+
+@protected QueryDecomposition sse_decode_box_autoadd_query_decomposition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_decomposition(deserializer)); }
+
+@protected QueryNormalizationConfig sse_decode_box_autoadd_query_normalization_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_normalization_config(deserializer)); }
+
+@protected RagError sse_decode_box_autoadd_rag_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rag_error(deserializer)); }
+
+@protected (int,int,int) sse_decode_box_autoadd_record_u_32_u_32_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_u_32_u_32_u_32(deserializer)); }
+
+@protected ReembeddingJob sse_decode_box_autoadd_reembedding_job(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_reembedding_job(deserializer)); }
+
+@protected RetrievalPreset sse_decode_box_autoadd_retrieval_preset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_retrieval_preset(deserializer)); }
+
+@protected RrfConfig sse_decode_box_autoadd_rrf_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rrf_config(deserializer)); }
+
+@protected SearchDiagnostics sse_decode_box_autoadd_search_diagnostics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_diagnostics(deserializer)); }
+
+@protected SearchFilter sse_decode_box_autoadd_search_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_filter(deserializer)); }
+
+@protected SearchSnippet sse_decode_box_autoadd_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_snippet(deserializer)); }
+
+@protected SearchTrace sse_decode_box_autoadd_search_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_trace(deserializer)); }
+
+@protected SourceIndexConfig sse_decode_box_autoadd_source_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_source_index_config(deserializer)); }
+
+@protected StoredChunk sse_decode_box_autoadd_stored_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stored_chunk(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected UserIntent sse_decode_box_autoadd_user_intent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_user_intent(deserializer)); }
+
+@protected BufferStats sse_decode_buffer_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bufferSize = sse_decode_usize(deserializer);
+var var_threshold = sse_decode_usize(deserializer);
+var var_hnswLoaded = sse_decode_bool(deserializer);
+return BufferStats(bufferSize: var_bufferSize, threshold: var_threshold, hnswLoaded: var_hnswLoaded); }
+
+@protected BufferTable sse_decode_buffer_table(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BufferTable.values[inner]; }
+
+@protected ChunkData sse_decode_chunk_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_content = sse_decode_String(deserializer);
+var var_chunkIndex = sse_decode_i_32(deserializer);
+var var_startPos = sse_decode_i_32(deserializer);
+var var_endPos = sse_decode_i_32(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_embedding = sse_decode_list_prim_f_32_strict(deserializer);
+var var_batchId = sse_decode_opt_String(deserializer);
+return ChunkData(content: var_content, chunkIndex: var_chunkIndex, startPos: var_startPos, endPos: var_endPos, chunkType: var_chunkType, embedding: var_embedding, batchId: var_batchId); }
+
+@protected ChunkForReembedding sse_decode_chunk_for_reembedding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkId = sse_decode_i_64(deserializer);
+var var_content = sse_decode_String(deserializer);
+return ChunkForReembedding(chunkId: var_chunkId, content: var_content); }
+
+@protected ChunkIndexSource sse_decode_chunk_index_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChunkIndexSource.values[inner]; }
+
+@protected ChunkMeta sse_decode_chunk_meta(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_content = sse_decode_String(deserializer);
+var var_chunkIndex = sse_decode_i_32(deserializer);
+var var_startPos = sse_decode_i_32(deserializer);
+var var_endPos = sse_decode_i_32(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_batchId = sse_decode_opt_String(deserializer);
+return ChunkMeta(content: var_content, chunkIndex: var_chunkIndex, startPos: var_startPos, endPos: var_endPos, chunkType: var_chunkType, batchId: var_batchId); }
+
+@protected ChunkSearchPage sse_decode_chunk_search_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_results = sse_decode_list_chunk_search_result(deserializer);
+var var_nextCursor = sse_decode_opt_String(deserializer);
+return ChunkSearchPage(results: var_results, nextCursor: var_nextCursor); }
+
+@protected ChunkSearchResult sse_decode_chunk_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkId = sse_decode_i_64(deserializer);
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_chunkIndex = sse_decode_i_32(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_headerPath = sse_decode_opt_String(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+return ChunkSearchResult(chunkId: var_chunkId, sourceId: var_sourceId, chunkIndex: var_chunkIndex, content: var_content, chunkType: var_chunkType, headerPath: var_headerPath, similarity: var_similarity, metadata: var_metadata); }
+
+@protected ChunkStats sse_decode_chunk_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkCount = sse_decode_u_32(deserializer);
+var var_minSize = sse_decode_u_32(deserializer);
+var var_maxSize = sse_decode_u_32(deserializer);
+var var_avgSize = sse_decode_f_64(deserializer);
+var var_medianSize = sse_decode_f_64(deserializer);
+var var_typeHistogram = sse_decode_list_corpus_count(deserializer);
+var var_oversizeCount = sse_decode_u_32(deserializer);
+return ChunkStats(chunkCount: var_chunkCount, minSize: var_minSize, maxSize: var_maxSize, avgSize: var_avgSize, medianSize: var_medianSize, typeHistogram: var_typeHistogram, oversizeCount: var_oversizeCount); }
+
+@protected ChunkType sse_decode_chunk_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChunkType.values[inner]; }
+
+@protected ChunkingConfig sse_decode_chunking_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxChars = sse_decode_i_32(deserializer);
+var var_minChars = sse_decode_i_32(deserializer);
+var var_overlap = sse_decode_i_32(deserializer);
+var var_strategy = sse_decode_chunking_strategy(deserializer);
+var var_preserveTables = sse_decode_bool(deserializer);
+var var_preserveCode = sse_decode_bool(deserializer);
+var var_sentenceTerminators = sse_decode_list_String(deserializer);
+var var_sectionSummarySentences = sse_decode_u_32(deserializer);
+var var_linearizeTables = sse_decode_bool(deserializer);
+var var_keepListIntro = sse_decode_bool(deserializer);
+return ChunkingConfig(maxChars: var_maxChars, minChars: var_minChars, overlap: var_overlap, strategy: var_strategy, preserveTables: var_preserveTables, preserveCode: var_preserveCode, sentenceTerminators: var_sentenceTerminators, sectionSummarySentences: var_sectionSummarySentences, linearizeTables: var_linearizeTables, keepListIntro: var_keepListIntro); }
+
+@protected ChunkingStrategy sse_decode_chunking_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChunkingStrategy.values[inner]; }
+
+@protected ClassificationRule sse_decode_classification_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_language = sse_decode_String(deserializer);
+var var_patterns = sse_decode_list_String(deserializer);
+var var_minMatches = sse_decode_u_32(deserializer);
+return ClassificationRule(label: var_label, language: var_language, patterns: var_patterns, minMatches: var_minMatches); }
+
+@protected CompareSearchResult sse_decode_compare_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subjectIndex = sse_decode_u_32(deserializer);
+var var_result = sse_decode_hybrid_search_result(deserializer);
+return CompareSearchResult(subjectIndex: var_subjectIndex, result: var_result); }
+
+@protected CompressedText sse_decode_compressed_text(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_originalChars = sse_decode_i_32(deserializer);
+var var_compressedChars = sse_decode_i_32(deserializer);
+var var_ratio = sse_decode_f_64(deserializer);
+var var_sentencesRemoved = sse_decode_i_32(deserializer);
+var var_charsSavedStopwords = sse_decode_i_32(deserializer);
+var var_charsSavedTruncation = sse_decode_i_32(deserializer);
+return CompressedText(text: var_text, originalChars: var_originalChars, compressedChars: var_compressedChars, ratio: var_ratio, sentencesRemoved: var_sentencesRemoved, charsSavedStopwords: var_charsSavedStopwords, charsSavedTruncation: var_charsSavedTruncation); }
+
+@protected CompressionOptions sse_decode_compression_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_removeStopwords = sse_decode_bool(deserializer);
+var var_removeDuplicates = sse_decode_bool(deserializer);
+var var_language = sse_decode_String(deserializer);
+var var_level = sse_decode_i_32(deserializer);
+return CompressionOptions(removeStopwords: var_removeStopwords, removeDuplicates: var_removeDuplicates, language: var_language, level: var_level); }
+
+@protected CorpusCount sse_decode_corpus_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return CorpusCount(key: var_key, count: var_count); }
+
+@protected CorpusReport sse_decode_corpus_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceCount = sse_decode_u_64(deserializer);
+var var_chunkCount = sse_decode_u_64(deserializer);
+var var_totalChars = sse_decode_u_64(deserializer);
+var var_vocabularySize = sse_decode_u_64(deserializer);
+var var_sources = sse_decode_list_source_corpus_stats(deserializer);
+var var_chunkLengthHistogram = sse_decode_list_length_bucket(deserializer);
+var var_chunkTypes = sse_decode_list_corpus_count(deserializer);
+var var_languages = sse_decode_list_corpus_count(deserializer);
+var var_index = sse_decode_index_freshness(deserializer);
+return CorpusReport(sourceCount: var_sourceCount, chunkCount: var_chunkCount, totalChars: var_totalChars, vocabularySize: var_vocabularySize, sources: var_sources, chunkLengthHistogram: var_chunkLengthHistogram, chunkTypes: var_chunkTypes, languages: var_languages, index: var_index); }
+
+@protected DbRetryConfig sse_decode_db_retry_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_baseDelayMs = sse_decode_u_64(deserializer);
+var var_maxDelayMs = sse_decode_u_64(deserializer);
+return DbRetryConfig(maxAttempts: var_maxAttempts, baseDelayMs: var_baseDelayMs, maxDelayMs: var_maxDelayMs); }
+
+@protected DedupeConfig sse_decode_dedupe_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nearDuplicateThreshold = sse_decode_f_64(deserializer);
+var var_shingleSize = sse_decode_u_32(deserializer);
+var var_exactOnly = sse_decode_bool(deserializer);
+return DedupeConfig(nearDuplicateThreshold: var_nearDuplicateThreshold, shingleSize: var_shingleSize, exactOnly: var_exactOnly); }
+
+@protected DedupeReport sse_decode_dedupe_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kept = sse_decode_list_prim_u_32_strict(deserializer);
+var var_duplicates = sse_decode_list_duplicate_chunk(deserializer);
+return DedupeReport(kept: var_kept, duplicates: var_duplicates); }
+
+@protected DistanceMetric sse_decode_distance_metric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DistanceMetric.values[inner]; }
+
+@protected DuplicateChunk sse_decode_duplicate_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_duplicateOf = sse_decode_u_32(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+return DuplicateChunk(index: var_index, duplicateOf: var_duplicateOf, similarity: var_similarity); }
+
+@protected EmbeddingBatchProgress sse_decode_embedding_batch_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_done = sse_decode_u_32(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+return EmbeddingBatchProgress(done: var_done, total: var_total); }
+
+@protected EmbeddingPoint sse_decode_embedding_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_embedding = sse_decode_list_prim_f_32_strict(deserializer);
+var var_norm = sse_decode_f_32(deserializer);
+return EmbeddingPoint(id: var_id, embedding: var_embedding, norm: var_norm); }
+
+@protected EmbeddingStorageStats sse_decode_embedding_storage_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_f32Chunks = sse_decode_i_64(deserializer);
+var var_quantizedChunks = sse_decode_i_64(deserializer);
+var var_embeddingBytes = sse_decode_i_64(deserializer);
+return EmbeddingStorageStats(f32Chunks: var_f32Chunks, quantizedChunks: var_quantizedChunks, embeddingBytes: var_embeddingBytes); }
+
+@protected EngineInfo sse_decode_engine_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_crateVersion = sse_decode_String(deserializer);
+var var_schemaVersion = sse_decode_u_32(deserializer);
+var var_databaseSchemaVersion = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_hnswFormatVersion = sse_decode_u_32(deserializer);
+var var_bm25FormatVersion = sse_decode_u_32(deserializer);
+var var_snapshotFormatVersion = sse_decode_u_32(deserializer);
+var var_tokenizerName = sse_decode_opt_String(deserializer);
+var var_embeddingModelName = sse_decode_opt_String(deserializer);
+var var_embeddingDimension = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_activeTenant = sse_decode_String(deserializer);
+var var_activeFeatures = sse_decode_list_String(deserializer);
+var var_buildFeatures = sse_decode_list_String(deserializer);
+return EngineInfo(crateVersion: var_crateVersion, schemaVersion: var_schemaVersion, databaseSchemaVersion: var_databaseSchemaVersion, hnswFormatVersion: var_hnswFormatVersion, bm25FormatVersion: var_bm25FormatVersion, snapshotFormatVersion: var_snapshotFormatVersion, tokenizerName: var_tokenizerName, embeddingModelName: var_embeddingModelName, embeddingDimension: var_embeddingDimension, activeTenant: var_activeTenant, activeFeatures: var_activeFeatures, buildFeatures: var_buildFeatures); }
+
+@protected EngineMetrics sse_decode_engine_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_searchesExecuted = sse_decode_u_64(deserializer);
+var var_cacheHits = sse_decode_u_64(deserializer);
+var var_cacheMisses = sse_decode_u_64(deserializer);
+var var_indexRebuilds = sse_decode_u_64(deserializer);
+var var_dbErrors = sse_decode_u_64(deserializer);
+var var_panics = sse_decode_u_64(deserializer);
+var var_avgSearchLatencyMs = sse_decode_f_64(deserializer);
+var var_searchLatencyHistogram = sse_decode_list_latency_bucket(deserializer);
+return EngineMetrics(searchesExecuted: var_searchesExecuted, cacheHits: var_cacheHits, cacheMisses: var_cacheMisses, indexRebuilds: var_indexRebuilds, dbErrors: var_dbErrors, panics: var_panics, avgSearchLatencyMs: var_avgSearchLatencyMs, searchLatencyHistogram: var_searchLatencyHistogram); }
+
+@protected EntityFacet sse_decode_entity_facet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entityType = sse_decode_String(deserializer);
+var var_normalized = sse_decode_String(deserializer);
+var var_chunkCount = sse_decode_u_32(deserializer);
+return EntityFacet(entityType: var_entityType, normalized: var_normalized, chunkCount: var_chunkCount); }
+
+@protected ExpandedSearchResult sse_decode_expanded_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_result = sse_decode_hybrid_search_result(deserializer);
+var var_link = sse_decode_String(deserializer);
+var var_expandedFrom = sse_decode_opt_box_autoadd_i_64(deserializer);
+return ExpandedSearchResult(result: var_result, link: var_link, expandedFrom: var_expandedFrom); }
+
+@protected ExpansionConfig sse_decode_expansion_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxRelatedPerHit = sse_decode_u_32(deserializer);
+var var_scoreDecay = sse_decode_f_64(deserializer);
+var var_followAdjacent = sse_decode_bool(deserializer);
+var var_followSharedEntities = sse_decode_bool(deserializer);
+var var_followSameBatch = sse_decode_bool(deserializer);
+return ExpansionConfig(maxRelatedPerHit: var_maxRelatedPerHit, scoreDecay: var_scoreDecay, followAdjacent: var_followAdjacent, followSharedEntities: var_followSharedEntities, followSameBatch: var_followSameBatch); }
+
+@protected ExtractedEntity sse_decode_extracted_entity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entityType = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_normalized = sse_decode_String(deserializer);
+return ExtractedEntity(entityType: var_entityType, text: var_text, normalized: var_normalized); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FusionStrategy sse_decode_fusion_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FusionStrategy_Rrf();case 1: var var_normalization = sse_decode_score_normalization(deserializer);
+return FusionStrategy_WeightedScore(normalization: var_normalization); default: throw UnimplementedError(''); }
+             }
+
+@protected GazetteerEntry sse_decode_gazetteer_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_term = sse_decode_String(deserializer);
+var var_entityType = sse_decode_String(deserializer);
+return GazetteerEntry(term: var_term, entityType: var_entityType); }
+
+@protected GraphEdge sse_decode_graph_edge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_relation = sse_decode_String(deserializer);
+var var_object = sse_decode_String(deserializer);
+var var_chunkId = sse_decode_i_64(deserializer);
+var var_hop = sse_decode_u_32(deserializer);
+return GraphEdge(subject: var_subject, relation: var_relation, object: var_object, chunkId: var_chunkId, hop: var_hop); }
+
+@protected GraphNeighborhood sse_decode_graph_neighborhood(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entity = sse_decode_String(deserializer);
+var var_edges = sse_decode_list_graph_edge(deserializer);
+var var_chunkIds = sse_decode_list_prim_i_64_strict(deserializer);
+return GraphNeighborhood(entity: var_entity, edges: var_edges, chunkIds: var_chunkIds); }
+
+@protected HighlightSpan sse_decode_highlight_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return HighlightSpan(start: var_start, end: var_end); }
+
+@protected HnswBuildConfig sse_decode_hnsw_build_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_m = sse_decode_u_32(deserializer);
+var var_maxLayer = sse_decode_u_32(deserializer);
+var var_efConstruction = sse_decode_u_32(deserializer);
+return HnswBuildConfig(m: var_m, maxLayer: var_maxLayer, efConstruction: var_efConstruction); }
+
+@protected HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_build = sse_decode_opt_box_autoadd_hnsw_build_config(deserializer);
+var var_efSearch = sse_decode_opt_box_autoadd_u_32(deserializer);
+return HnswConfig(build: var_build, efSearch: var_efSearch); }
+
+@protected HnswIndexStats sse_decode_hnsw_index_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pointCount = sse_decode_u_64(deserializer);
+var var_dimension = sse_decode_u_32(deserializer);
+var var_quantization = sse_decode_quantization(deserializer);
+var var_metric = sse_decode_distance_metric(deserializer);
+var var_build = sse_decode_hnsw_build_config(deserializer);
+var var_m0 = sse_decode_u_32(deserializer);
+var var_customBuild = sse_decode_bool(deserializer);
+var var_efSearch = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_deletedPoints = sse_decode_u_64(deserializer);
+return HnswIndexStats(pointCount: var_pointCount, dimension: var_dimension, quantization: var_quantization, metric: var_metric, build: var_build, m0: var_m0, customBuild: var_customBuild, efSearch: var_efSearch, deletedPoints: var_deletedPoints); }
+
+@protected HnswSearchResult sse_decode_hnsw_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_distance = sse_decode_f_32(deserializer);
+return HnswSearchResult(id: var_id, distance: var_distance); }
+
+@protected HybridSearchEvent sse_decode_hybrid_search_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_list_ranked_hit(deserializer);
+return HybridSearchEvent_Ranking(var_field0);case 1: var var_field0 = sse_decode_list_hybrid_search_result(deserializer);
+return HybridSearchEvent_Results(var_field0);case 2: return HybridSearchEvent_Done(); default: throw UnimplementedError(''); }
+             }
+
+@protected HybridSearchOptions sse_decode_hybrid_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_efSearch = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_diversityLambda = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_collection = sse_decode_opt_String(deserializer);
+var var_groupBySource = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_withDiagnostics = sse_decode_bool(deserializer);
+var var_pageOffset = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_cursor = sse_decode_opt_String(deserializer);
+return HybridSearchOptions(efSearch: var_efSearch, diversityLambda: var_diversityLambda, collection: var_collection, groupBySource: var_groupBySource, withDiagnostics: var_withDiagnostics, pageOffset: var_pageOffset, cursor: var_cursor); }
+
+@protected HybridSearchResponse sse_decode_hybrid_search_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_results = sse_decode_list_hybrid_search_result(deserializer);
+var var_groups = sse_decode_opt_list_source_group(deserializer);
+var var_diagnostics = sse_decode_opt_box_autoadd_search_diagnostics(deserializer);
+var var_nextCursor = sse_decode_opt_String(deserializer);
+return HybridSearchResponse(results: var_results, groups: var_groups, diagnostics: var_diagnostics, nextCursor: var_nextCursor); }
+
+@protected HybridSearchResult sse_decode_hybrid_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_docId = sse_decode_i_64(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_vectorRank = sse_decode_u_32(deserializer);
+var var_bm25Rank = sse_decode_u_32(deserializer);
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+var var_chunkIndex = sse_decode_u_32(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_headerPath = sse_decode_opt_String(deserializer);
+var var_matchedTerms = sse_decode_list_String(deserializer);
+var var_snippet = sse_decode_opt_box_autoadd_search_snippet(deserializer);
+return HybridSearchResult(docId: var_docId, content: var_content, score: var_score, vectorRank: var_vectorRank, bm25Rank: var_bm25Rank, sourceId: var_sourceId, metadata: var_metadata, chunkIndex: var_chunkIndex, chunkType: var_chunkType, headerPath: var_headerPath, matchedTerms: var_matchedTerms, snippet: var_snippet); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IncrementalConfig sse_decode_incremental_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_autoMerge = sse_decode_bool(deserializer);
+return IncrementalConfig(autoMerge: var_autoMerge); }
+
+@protected IncrementalSearchResult sse_decode_incremental_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_docId = sse_decode_i_64(deserializer);
+var var_distance = sse_decode_f_32(deserializer);
+var var_source = sse_decode_String(deserializer);
+return IncrementalSearchResult(docId: var_docId, distance: var_distance, source: var_source); }
+
+@protected IndexConfig sse_decode_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_quantization = sse_decode_quantization(deserializer);
+var var_metric = sse_decode_distance_metric(deserializer);
+return IndexConfig(quantization: var_quantization, metric: var_metric); }
+
+@protected IndexConsistency sse_decode_index_consistency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkCount = sse_decode_u_64(deserializer);
+var var_hnsw = sse_decode_index_divergence(deserializer);
+var var_bm25 = sse_decode_index_divergence(deserializer);
+var var_consistent = sse_decode_bool(deserializer);
+var var_repaired = sse_decode_bool(deserializer);
+return IndexConsistency(chunkCount: var_chunkCount, hnsw: var_hnsw, bm25: var_bm25, consistent: var_consistent, repaired: var_repaired); }
+
+@protected IndexDivergence sse_decode_index_divergence(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_loaded = sse_decode_bool(deserializer);
+var var_indexedCount = sse_decode_u_64(deserializer);
+var var_missingIds = sse_decode_list_prim_i_64_strict(deserializer);
+var var_extraIds = sse_decode_list_prim_i_64_strict(deserializer);
+return IndexDivergence(loaded: var_loaded, indexedCount: var_indexedCount, missingIds: var_missingIds, extraIds: var_extraIds); }
+
+@protected IndexEvaluation sse_decode_index_evaluation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sampleSize = sse_decode_u_32(deserializer);
+var var_topK = sse_decode_u_32(deserializer);
+var var_corpusSize = sse_decode_u_64(deserializer);
+var var_recallAtK = sse_decode_f_64(deserializer);
+var var_minRecall = sse_decode_f_64(deserializer);
+var var_latencyP50Ms = sse_decode_f_64(deserializer);
+var var_latencyP95Ms = sse_decode_f_64(deserializer);
+var var_latencyP99Ms = sse_decode_f_64(deserializer);
+var var_exactMeanMs = sse_decode_f_64(deserializer);
+return IndexEvaluation(sampleSize: var_sampleSize, topK: var_topK, corpusSize: var_corpusSize, recallAtK: var_recallAtK, minRecall: var_minRecall, latencyP50Ms: var_latencyP50Ms, latencyP95Ms: var_latencyP95Ms, latencyP99Ms: var_latencyP99Ms, exactMeanMs: var_exactMeanMs); }
+
+@protected IndexFreshness sse_decode_index_freshness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbChunkCount = sse_decode_u_64(deserializer);
+var var_hnswPointCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_bm25DocumentCount = sse_decode_u_64(deserializer);
+var var_hnswUpToDate = sse_decode_bool(deserializer);
+var var_bm25UpToDate = sse_decode_bool(deserializer);
+var var_lastSourceAddedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return IndexFreshness(dbChunkCount: var_dbChunkCount, hnswPointCount: var_hnswPointCount, bm25DocumentCount: var_bm25DocumentCount, hnswUpToDate: var_hnswUpToDate, bm25UpToDate: var_bm25UpToDate, lastSourceAddedAt: var_lastSourceAddedAt); }
+
+@protected IndexStaleness sse_decode_index_staleness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hnswStale = sse_decode_bool(deserializer);
+var var_bm25Stale = sse_decode_bool(deserializer);
+return IndexStaleness(hnswStale: var_hnswStale, bm25Stale: var_bm25Stale); }
+
+@protected IngestInput sse_decode_ingest_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return IngestInput_Text(var_field0);case 1: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return IngestInput_Bytes(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected IngestJob sse_decode_ingest_job(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_state = sse_decode_String(deserializer);
+var var_stage = sse_decode_opt_String(deserializer);
+var var_progressDone = sse_decode_u_32(deserializer);
+var var_progressTotal = sse_decode_u_32(deserializer);
+var var_sourceId = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+return IngestJob(id: var_id, filePath: var_filePath, name: var_name, state: var_state, stage: var_stage, progressDone: var_progressDone, progressTotal: var_progressTotal, sourceId: var_sourceId, error: var_error, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected IngestOptions sse_decode_ingest_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+var var_strategy = sse_decode_opt_box_autoadd_chunking_strategy(deserializer);
+var var_maxChars = sse_decode_i_32(deserializer);
+var var_overlapChars = sse_decode_i_32(deserializer);
+var var_rebuildIndexes = sse_decode_bool(deserializer);
+var var_dedupeChunks = sse_decode_bool(deserializer);
+return IngestOptions(name: var_name, metadata: var_metadata, strategy: var_strategy, maxChars: var_maxChars, overlapChars: var_overlapChars, rebuildIndexes: var_rebuildIndexes, dedupeChunks: var_dedupeChunks); }
+
+@protected IngestProgress sse_decode_ingest_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stage = sse_decode_String(deserializer);
+var var_done = sse_decode_u_32(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+var var_sourceId = sse_decode_opt_box_autoadd_i_64(deserializer);
+return IngestProgress(stage: var_stage, done: var_done, total: var_total, sourceId: var_sourceId); }
+
+@protected IngestReport sse_decode_ingest_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_isDuplicate = sse_decode_bool(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_chunkCount = sse_decode_u_32(deserializer);
+var var_skippedChunks = sse_decode_u_32(deserializer);
+var var_textChars = sse_decode_u_64(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+return IngestReport(sourceId: var_sourceId, isDuplicate: var_isDuplicate, status: var_status, chunkCount: var_chunkCount, skippedChunks: var_skippedChunks, textChars: var_textChars, elapsedMs: var_elapsedMs); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected KeywordBackend sse_decode_keyword_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KeywordBackend.values[inner]; }
+
+@protected KnowledgeTriple sse_decode_knowledge_triple(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subject = sse_decode_String(deserializer);
+var var_relation = sse_decode_String(deserializer);
+var var_object = sse_decode_String(deserializer);
+return KnowledgeTriple(subject: var_subject, relation: var_relation, object: var_object); }
+
+@protected LanguageAnalyzer sse_decode_language_analyzer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_language = sse_decode_String(deserializer);
+var var_stopwords = sse_decode_list_String(deserializer);
+return LanguageAnalyzer(language: var_language, stopwords: var_stopwords); }
+
+@protected LastRetrieval sse_decode_last_retrieval(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_query = sse_decode_opt_String(deserializer);
+var var_citations = sse_decode_list_retrieval_citation(deserializer);
+return LastRetrieval(query: var_query, citations: var_citations); }
+
+@protected LatencyBucket sse_decode_latency_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_leMs = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return LatencyBucket(leMs: var_leMs, count: var_count); }
+
+@protected LengthBucket sse_decode_length_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_leChars = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return LengthBucket(leChars: var_leChars, count: var_count); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AuditIssue> sse_decode_list_audit_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AuditIssue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_audit_issue(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BackgroundTask> sse_decode_list_background_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BackgroundTask>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_background_task(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BenchmarkStage> sse_decode_list_benchmark_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BenchmarkStage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_benchmark_stage(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Bm25SearchResult> sse_decode_list_bm_25_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Bm25SearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_bm_25_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChunkData> sse_decode_list_chunk_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChunkData>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chunk_data(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChunkForReembedding> sse_decode_list_chunk_for_reembedding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChunkForReembedding>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chunk_for_reembedding(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChunkMeta> sse_decode_list_chunk_meta(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChunkMeta>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chunk_meta(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChunkSearchResult> sse_decode_list_chunk_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChunkSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chunk_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ClassificationRule> sse_decode_list_classification_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ClassificationRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_classification_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CompareSearchResult> sse_decode_list_compare_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CompareSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_compare_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CorpusCount> sse_decode_list_corpus_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CorpusCount>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_corpus_count(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DuplicateChunk> sse_decode_list_duplicate_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DuplicateChunk>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_duplicate_chunk(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EntityFacet> sse_decode_list_entity_facet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EntityFacet>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_entity_facet(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExpandedSearchResult> sse_decode_list_expanded_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExpandedSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_expanded_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExtractedEntity> sse_decode_list_extracted_entity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExtractedEntity>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_extracted_entity(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GazetteerEntry> sse_decode_list_gazetteer_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GazetteerEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_gazetteer_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GraphEdge>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_graph_edge(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HighlightSpan> sse_decode_list_highlight_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HighlightSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_highlight_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HnswSearchResult> sse_decode_list_hnsw_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HnswSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_hnsw_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HybridSearchResult> sse_decode_list_hybrid_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HybridSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_hybrid_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IncrementalSearchResult> sse_decode_list_incremental_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IncrementalSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_incremental_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IngestJob> sse_decode_list_ingest_job(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IngestJob>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ingest_job(deserializer)); }
+        return ans_;
+         }
+
+@protected List<KnowledgeTriple> sse_decode_list_knowledge_triple(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <KnowledgeTriple>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_knowledge_triple(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LanguageAnalyzer> sse_decode_list_language_analyzer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LanguageAnalyzer>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_language_analyzer(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LatencyBucket> sse_decode_list_latency_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LatencyBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_latency_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LengthBucket> sse_decode_list_length_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LengthBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_length_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Float32List> sse_decode_list_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Float32List>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_prim_f_32_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MemoryMessage> sse_decode_list_memory_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MemoryMessage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_memory_message(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MemorySearchResult> sse_decode_list_memory_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MemorySearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_memory_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NamedBm25IndexInfo> sse_decode_list_named_bm_25_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NamedBm25IndexInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_named_bm_25_index_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NamedIndexInfo> sse_decode_list_named_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NamedIndexInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_named_index_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PanicEvent> sse_decode_list_panic_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PanicEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_panic_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryKeyword> sse_decode_list_query_keyword(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryKeyword>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_keyword(deserializer)); }
+        return ans_;
+         }
+
+@protected List<QuerySuggestion> sse_decode_list_query_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QuerySuggestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_suggestion(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RankedHit> sse_decode_list_ranked_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RankedHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ranked_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(PlatformInt64,Float32List)> sse_decode_list_record_i_64_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(PlatformInt64,Float32List)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_i_64_list_prim_f_32_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(PlatformInt64,String)> sse_decode_list_record_i_64_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(PlatformInt64,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_i_64_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,double)> sse_decode_list_record_string_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,double)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_f_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RetrievalCitation> sse_decode_list_retrieval_citation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RetrievalCitation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_retrieval_citation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchSpan> sse_decode_list_search_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SemanticChunk> sse_decode_list_semantic_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SemanticChunk>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_semantic_chunk(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SnapshotFile> sse_decode_list_snapshot_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SnapshotFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snapshot_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SourceCorpusStats> sse_decode_list_source_corpus_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SourceCorpusStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_source_corpus_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SourceEntry> sse_decode_list_source_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SourceEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_source_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SourceGroup> sse_decode_list_source_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SourceGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_source_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StoreSearchHit> sse_decode_list_store_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StoreSearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_store_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StructuredChunk> sse_decode_list_structured_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StructuredChunk>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_structured_chunk(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SynonymEntry> sse_decode_list_synonym_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SynonymEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_synonym_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected MaintenanceConfig sse_decode_maintenance_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requireCharging = sse_decode_bool(deserializer);
+var var_minIntervalSecs = sse_decode_u_32(deserializer);
+return MaintenanceConfig(requireCharging: var_requireCharging, minIntervalSecs: var_minIntervalSecs); }
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startedAt = sse_decode_i_64(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_bufferMerged = sse_decode_bool(deserializer);
+var var_hnswCompacted = sse_decode_bool(deserializer);
+var var_bm25Pruned = sse_decode_u_32(deserializer);
+var var_walPagesCheckpointed = sse_decode_i_64(deserializer);
+var var_interrupted = sse_decode_bool(deserializer);
+return MaintenanceReport(startedAt: var_startedAt, durationMs: var_durationMs, bufferMerged: var_bufferMerged, hnswCompacted: var_hnswCompacted, bm25Pruned: var_bm25Pruned, walPagesCheckpointed: var_walPagesCheckpointed, interrupted: var_interrupted); }
+
+@protected MemoryMessage sse_decode_memory_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_conversationId = sse_decode_String(deserializer);
+var var_role = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return MemoryMessage(id: var_id, conversationId: var_conversationId, role: var_role, content: var_content, createdAt: var_createdAt); }
+
+@protected MemoryProfile sse_decode_memory_profile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_budgetMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_lowMemory = sse_decode_bool(deserializer);
+var var_sqliteCacheKb = sse_decode_u_32(deserializer);
+var var_sqliteMmapMb = sse_decode_u_32(deserializer);
+var var_quantizeIndex = sse_decode_bool(deserializer);
+var var_candidateMultiplier = sse_decode_u_32(deserializer);
+var var_filteredCandidateMultiplier = sse_decode_u_32(deserializer);
+var var_scanPageRows = sse_decode_u_32(deserializer);
+return MemoryProfile(budgetMb: var_budgetMb, lowMemory: var_lowMemory, sqliteCacheKb: var_sqliteCacheKb, sqliteMmapMb: var_sqliteMmapMb, quantizeIndex: var_quantizeIndex, candidateMultiplier: var_candidateMultiplier, filteredCandidateMultiplier: var_filteredCandidateMultiplier, scanPageRows: var_scanPageRows); }
+
+@protected MemorySearchResult sse_decode_memory_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_message = sse_decode_memory_message(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+return MemorySearchResult(message: var_message, similarity: var_similarity); }
+
+@protected MemoryUsage sse_decode_memory_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lowMemory = sse_decode_bool(deserializer);
+var var_hnswPoints = sse_decode_u_64(deserializer);
+var var_quantizedPoints = sse_decode_u_64(deserializer);
+var var_quantizedIndexBytes = sse_decode_u_64(deserializer);
+var var_bufferedEmbeddings = sse_decode_u_64(deserializer);
+var var_sqliteMemoryBytes = sse_decode_u_64(deserializer);
+var var_processRssBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+return MemoryUsage(lowMemory: var_lowMemory, hnswPoints: var_hnswPoints, quantizedPoints: var_quantizedPoints, quantizedIndexBytes: var_quantizedIndexBytes, bufferedEmbeddings: var_bufferedEmbeddings, sqliteMemoryBytes: var_sqliteMemoryBytes, processRssBytes: var_processRssBytes); }
+
+@protected NamedBm25IndexInfo sse_decode_named_bm_25_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_documentCount = sse_decode_u_64(deserializer);
+var var_vocabularySize = sse_decode_u_64(deserializer);
+return NamedBm25IndexInfo(name: var_name, documentCount: var_documentCount, vocabularySize: var_vocabularySize); }
+
+@protected NamedIndexConfig sse_decode_named_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_metric = sse_decode_opt_box_autoadd_distance_metric(deserializer);
+var var_build = sse_decode_opt_box_autoadd_hnsw_build_config(deserializer);
+var var_efSearch = sse_decode_opt_box_autoadd_u_32(deserializer);
+return NamedIndexConfig(metric: var_metric, build: var_build, efSearch: var_efSearch); }
+
+@protected NamedIndexInfo sse_decode_named_index_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_pointCount = sse_decode_u_64(deserializer);
+var var_deletedPoints = sse_decode_u_64(deserializer);
+var var_dimension = sse_decode_u_32(deserializer);
+var var_metric = sse_decode_distance_metric(deserializer);
+return NamedIndexInfo(name: var_name, pointCount: var_pointCount, deletedPoints: var_deletedPoints, dimension: var_dimension, metric: var_metric); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AuditReport? sse_decode_opt_box_autoadd_audit_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_audit_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BackgroundTask? sse_decode_opt_box_autoadd_background_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_background_task(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Bm25FuzzyConfig? sse_decode_opt_box_autoadd_bm_25_fuzzy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bm_25_fuzzy_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ChunkingStrategy? sse_decode_opt_box_autoadd_chunking_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_chunking_strategy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DistanceMetric? sse_decode_opt_box_autoadd_distance_metric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_distance_metric(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ExpansionConfig? sse_decode_opt_box_autoadd_expansion_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_expansion_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HnswBuildConfig? sse_decode_opt_box_autoadd_hnsw_build_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_hnsw_build_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HnswIndexStats? sse_decode_opt_box_autoadd_hnsw_index_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_hnsw_index_stats(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HybridSearchOptions? sse_decode_opt_box_autoadd_hybrid_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_hybrid_search_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LastRetrieval? sse_decode_opt_box_autoadd_last_retrieval(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_last_retrieval(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MaintenanceReport? sse_decode_opt_box_autoadd_maintenance_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_maintenance_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (int,int,int)? sse_decode_opt_box_autoadd_record_u_32_u_32_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_record_u_32_u_32_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ReembeddingJob? sse_decode_opt_box_autoadd_reembedding_job(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_reembedding_job(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RrfConfig? sse_decode_opt_box_autoadd_rrf_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rrf_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SearchDiagnostics? sse_decode_opt_box_autoadd_search_diagnostics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_search_diagnostics(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SearchFilter? sse_decode_opt_box_autoadd_search_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_search_filter(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SearchSnippet? sse_decode_opt_box_autoadd_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_search_snippet(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SearchTrace? sse_decode_opt_box_autoadd_search_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_search_trace(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StoredChunk? sse_decode_opt_box_autoadd_stored_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stored_chunk(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Int64List? sse_decode_opt_list_prim_i_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_i_64_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<(String,String)>? sse_decode_opt_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_record_string_string(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<SourceGroup>? sse_decode_opt_list_source_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_source_group(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PanicEvent sse_decode_panic_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_thread = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_location = sse_decode_opt_String(deserializer);
+var var_backtrace = sse_decode_opt_String(deserializer);
+var var_timestamp = sse_decode_i_64(deserializer);
+return PanicEvent(thread: var_thread, message: var_message, location: var_location, backtrace: var_backtrace, timestamp: var_timestamp); }
+
+@protected ParsedIntent sse_decode_parsed_intent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intentType = sse_decode_String(deserializer);
+var var_query = sse_decode_String(deserializer);
+var var_isValid = sse_decode_bool(deserializer);
+var var_errorMessage = sse_decode_opt_String(deserializer);
+var var_language = sse_decode_String(deserializer);
+return ParsedIntent(intentType: var_intentType, query: var_query, isValid: var_isValid, errorMessage: var_errorMessage, language: var_language); }
+
+@protected ProgressEvent sse_decode_progress_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_operation = sse_decode_String(deserializer);
+var var_phase = sse_decode_String(deserializer);
+var var_done = sse_decode_u_32(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+return ProgressEvent(operation: var_operation, phase: var_phase, done: var_done, total: var_total); }
+
+@protected Quantization sse_decode_quantization(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Quantization.values[inner]; }
+
+@protected QueryDecomposition sse_decode_query_decomposition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_original = sse_decode_String(deserializer);
+var var_subQueries = sse_decode_list_String(deserializer);
+return QueryDecomposition(original: var_original, subQueries: var_subQueries); }
+
+@protected QueryKeyword sse_decode_query_keyword(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_docFreq = sse_decode_u_32(deserializer);
+var var_inIndex = sse_decode_bool(deserializer);
+return QueryKeyword(text: var_text, score: var_score, docFreq: var_docFreq, inIndex: var_inIndex); }
+
+@protected QueryNormalizationConfig sse_decode_query_normalization_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_unicodeNfkc = sse_decode_bool(deserializer);
+var var_stripPunctuation = sse_decode_bool(deserializer);
+var var_lowercase = sse_decode_bool(deserializer);
+var var_removeStopwords = sse_decode_bool(deserializer);
+return QueryNormalizationConfig(unicodeNfkc: var_unicodeNfkc, stripPunctuation: var_stripPunctuation, lowercase: var_lowercase, removeStopwords: var_removeStopwords); }
+
+@protected QuerySuggestion sse_decode_query_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_query = sse_decode_String(deserializer);
+var var_useCount = sse_decode_u_32(deserializer);
+var var_lastUsedAt = sse_decode_i_64(deserializer);
+return QuerySuggestion(query: var_query, useCount: var_useCount, lastUsedAt: var_lastUsedAt); }
+
+@protected RagError sse_decode_rag_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return RagError_DatabaseError(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return RagError_IoError(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return RagError_ModelLoadError(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return RagError_InvalidInput(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return RagError_InternalError(var_field0);case 5: var var_field0 = sse_decode_String(deserializer);
+return RagError_Unknown(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return RagError_DatabaseBusy(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return RagError_NotInitialized(var_field0);case 8: var var_field0 = sse_decode_String(deserializer);
+return RagError_ParseError(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected RankedHit sse_decode_ranked_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_docId = sse_decode_i_64(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_vectorRank = sse_decode_u_32(deserializer);
+var var_bm25Rank = sse_decode_u_32(deserializer);
+return RankedHit(docId: var_docId, score: var_score, vectorRank: var_vectorRank, bm25Rank: var_bm25Rank); }
+
+@protected (PlatformInt64,Float32List) sse_decode_record_i_64_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_64(deserializer);
+var var_field1 = sse_decode_list_prim_f_32_strict(deserializer);
+return (var_field0, var_field1); }
+
+@protected (PlatformInt64,String) sse_decode_record_i_64_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_64(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,double) sse_decode_record_string_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_f_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int,int) sse_decode_record_u_32_u_32_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_u_32(deserializer);
+var var_field2 = sse_decode_u_32(deserializer);
+return (var_field0, var_field1, var_field2); }
+
+@protected ReembeddingJob sse_decode_reembedding_job(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_modelName = sse_decode_String(deserializer);
+var var_batchSize = sse_decode_u_32(deserializer);
+var var_state = sse_decode_String(deserializer);
+var var_done = sse_decode_u_32(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+var var_lastChunkId = sse_decode_i_64(deserializer);
+var var_dimension = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+return ReembeddingJob(id: var_id, modelName: var_modelName, batchSize: var_batchSize, state: var_state, done: var_done, total: var_total, lastChunkId: var_lastChunkId, dimension: var_dimension, error: var_error, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected RetrievalCitation sse_decode_retrieval_citation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkId = sse_decode_i_64(deserializer);
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_chunkIndex = sse_decode_i_32(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+return RetrievalCitation(chunkId: var_chunkId, sourceId: var_sourceId, chunkIndex: var_chunkIndex, score: var_score, metadata: var_metadata); }
+
+@protected RetrievalPreset sse_decode_retrieval_preset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topK = sse_decode_u_32(deserializer);
+var var_vectorWeight = sse_decode_f_64(deserializer);
+var var_bm25Weight = sse_decode_f_64(deserializer);
+var var_boostChunkType = sse_decode_opt_String(deserializer);
+var var_chunkTypeBoost = sse_decode_f_64(deserializer);
+var var_useMmr = sse_decode_bool(deserializer);
+var var_mmrLambda = sse_decode_f_64(deserializer);
+return RetrievalPreset(topK: var_topK, vectorWeight: var_vectorWeight, bm25Weight: var_bm25Weight, boostChunkType: var_boostChunkType, chunkTypeBoost: var_chunkTypeBoost, useMmr: var_useMmr, mmrLambda: var_mmrLambda); }
+
+@protected RewrittenQuery sse_decode_rewritten_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_original = sse_decode_String(deserializer);
+var var_rewritten = sse_decode_String(deserializer);
+var var_wasRewritten = sse_decode_bool(deserializer);
+var var_antecedent = sse_decode_opt_String(deserializer);
+return RewrittenQuery(original: var_original, rewritten: var_rewritten, wasRewritten: var_wasRewritten, antecedent: var_antecedent); }
+
+@protected RrfConfig sse_decode_rrf_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_k = sse_decode_u_32(deserializer);
+var var_vectorWeight = sse_decode_f_64(deserializer);
+var var_bm25Weight = sse_decode_f_64(deserializer);
+var var_typeBoosts = sse_decode_Map_String_f_64_None(deserializer);
+var var_recencyHalfLifeDays = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_recencyWeight = sse_decode_f_64(deserializer);
+var var_fusion = sse_decode_fusion_strategy(deserializer);
+return RrfConfig(k: var_k, vectorWeight: var_vectorWeight, bm25Weight: var_bm25Weight, typeBoosts: var_typeBoosts, recencyHalfLifeDays: var_recencyHalfLifeDays, recencyWeight: var_recencyWeight, fusion: var_fusion); }
+
+@protected ScoreNormalization sse_decode_score_normalization(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ScoreNormalization.values[inner]; }
+
+@protected SearchDiagnostics sse_decode_search_diagnostics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_vectorMs = sse_decode_f_64(deserializer);
+var var_bm25Ms = sse_decode_f_64(deserializer);
+var var_filterMs = sse_decode_f_64(deserializer);
+var var_fusionMs = sse_decode_f_64(deserializer);
+var var_fetchMs = sse_decode_f_64(deserializer);
+var var_totalMs = sse_decode_f_64(deserializer);
+var var_candidateK = sse_decode_u_32(deserializer);
+var var_vectorCandidates = sse_decode_u_32(deserializer);
+var var_bm25Candidates = sse_decode_u_32(deserializer);
+var var_filteredVectorCandidates = sse_decode_u_32(deserializer);
+var var_filteredBm25Candidates = sse_decode_u_32(deserializer);
+var var_exactScan = sse_decode_bool(deserializer);
+var var_graphFilter = sse_decode_bool(deserializer);
+var var_hnswLoaded = sse_decode_bool(deserializer);
+return SearchDiagnostics(vectorMs: var_vectorMs, bm25Ms: var_bm25Ms, filterMs: var_filterMs, fusionMs: var_fusionMs, fetchMs: var_fetchMs, totalMs: var_totalMs, candidateK: var_candidateK, vectorCandidates: var_vectorCandidates, bm25Candidates: var_bm25Candidates, filteredVectorCandidates: var_filteredVectorCandidates, filteredBm25Candidates: var_filteredBm25Candidates, exactScan: var_exactScan, graphFilter: var_graphFilter, hnswLoaded: var_hnswLoaded); }
+
+@protected SearchFeedbackAction sse_decode_search_feedback_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SearchFeedbackAction.values[inner]; }
+
+@protected SearchFilter sse_decode_search_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceIds = sse_decode_opt_list_prim_i_64_strict(deserializer);
+var var_metadataLike = sse_decode_opt_String(deserializer);
+var var_entity = sse_decode_opt_String(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_chunkTypes = sse_decode_opt_list_String(deserializer);
+var var_metadataEquals = sse_decode_opt_list_record_string_string(deserializer);
+var var_createdAfter = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_createdBefore = sse_decode_opt_box_autoadd_i_64(deserializer);
+return SearchFilter(sourceIds: var_sourceIds, metadataLike: var_metadataLike, entity: var_entity, language: var_language, chunkTypes: var_chunkTypes, metadataEquals: var_metadataEquals, createdAfter: var_createdAfter, createdBefore: var_createdBefore); }
+
+@protected SearchSnippet sse_decode_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_highlights = sse_decode_list_highlight_span(deserializer);
+return SearchSnippet(text: var_text, start: var_start, end: var_end, highlights: var_highlights); }
+
+@protected SearchSpan sse_decode_search_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_durationMs = sse_decode_f_64(deserializer);
+return SearchSpan(name: var_name, durationMs: var_durationMs); }
+
+@protected SearchTrace sse_decode_search_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spans = sse_decode_list_search_span(deserializer);
+var var_totalMs = sse_decode_f_64(deserializer);
+return SearchTrace(spans: var_spans, totalMs: var_totalMs); }
+
+@protected SemanticChunk sse_decode_semantic_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_i_32(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_startPos = sse_decode_i_32(deserializer);
+var var_endPos = sse_decode_i_32(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_language = sse_decode_String(deserializer);
+return SemanticChunk(index: var_index, content: var_content, startPos: var_startPos, endPos: var_endPos, chunkType: var_chunkType, language: var_language); }
+
+@protected SnapshotFile sse_decode_snapshot_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_sha256 = sse_decode_String(deserializer);
+return SnapshotFile(name: var_name, size: var_size, sha256: var_sha256); }
+
+@protected SnapshotManifest sse_decode_snapshot_manifest(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_formatVersion = sse_decode_u_32(deserializer);
+var var_engineVersion = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_tenants = sse_decode_list_String(deserializer);
+var var_sourceCount = sse_decode_i_64(deserializer);
+var var_chunkCount = sse_decode_i_64(deserializer);
+var var_files = sse_decode_list_snapshot_file(deserializer);
+return SnapshotManifest(formatVersion: var_formatVersion, engineVersion: var_engineVersion, createdAt: var_createdAt, tenants: var_tenants, sourceCount: var_sourceCount, chunkCount: var_chunkCount, files: var_files); }
+
+@protected SourceCorpusStats sse_decode_source_corpus_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_status = sse_decode_opt_String(deserializer);
+var var_chunkCount = sse_decode_u_64(deserializer);
+var var_minChunkChars = sse_decode_u_32(deserializer);
+var var_maxChunkChars = sse_decode_u_32(deserializer);
+var var_avgChunkChars = sse_decode_f_64(deserializer);
+return SourceCorpusStats(sourceId: var_sourceId, name: var_name, status: var_status, chunkCount: var_chunkCount, minChunkChars: var_minChunkChars, maxChunkChars: var_maxChunkChars, avgChunkChars: var_avgChunkChars); }
+
+@protected SourceEntry sse_decode_source_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+var var_status = sse_decode_opt_String(deserializer);
+return SourceEntry(id: var_id, name: var_name, createdAt: var_createdAt, metadata: var_metadata, status: var_status); }
+
+@protected SourceGroup sse_decode_source_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_isDocument = sse_decode_bool(deserializer);
+var var_sourceName = sse_decode_opt_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_chunks = sse_decode_list_hybrid_search_result(deserializer);
+return SourceGroup(sourceId: var_sourceId, isDocument: var_isDocument, sourceName: var_sourceName, score: var_score, chunks: var_chunks); }
+
+@protected SourceIndexConfig sse_decode_source_index_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_maxCached = sse_decode_u_32(deserializer);
+return SourceIndexConfig(enabled: var_enabled, maxCached: var_maxCached); }
+
+@protected SourceStats sse_decode_source_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceCount = sse_decode_i_64(deserializer);
+var var_chunkCount = sse_decode_i_64(deserializer);
+return SourceStats(sourceCount: var_sourceCount, chunkCount: var_chunkCount); }
+
+@protected SourceUpdate sse_decode_source_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stagingId = sse_decode_String(deserializer);
+var var_chunks = sse_decode_list_String(deserializer);
+var var_keptChunks = sse_decode_u_32(deserializer);
+var var_removedChunks = sse_decode_u_32(deserializer);
+var var_skippedChunks = sse_decode_u_32(deserializer);
+var var_expiresAt = sse_decode_i_64(deserializer);
+return SourceUpdate(stagingId: var_stagingId, chunks: var_chunks, keptChunks: var_keptChunks, removedChunks: var_removedChunks, skippedChunks: var_skippedChunks, expiresAt: var_expiresAt); }
+
+@protected StagedIngest sse_decode_staged_ingest(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stagingId = sse_decode_String(deserializer);
+var var_chunks = sse_decode_list_String(deserializer);
+var var_skippedChunks = sse_decode_u_32(deserializer);
+var var_expiresAt = sse_decode_i_64(deserializer);
+return StagedIngest(stagingId: var_stagingId, chunks: var_chunks, skippedChunks: var_skippedChunks, expiresAt: var_expiresAt); }
+
+@protected StoreSearchHit sse_decode_store_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+return StoreSearchHit(id: var_id, similarity: var_similarity); }
+
+@protected StoredChunk sse_decode_stored_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_sourceId = sse_decode_i_64(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_embedding = sse_decode_list_prim_f_32_strict(deserializer);
+return StoredChunk(id: var_id, sourceId: var_sourceId, content: var_content, embedding: var_embedding); }
+
+@protected StructuredChunk sse_decode_structured_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_i_32(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_headerPath = sse_decode_String(deserializer);
+var var_chunkType = sse_decode_String(deserializer);
+var var_startPos = sse_decode_i_32(deserializer);
+var var_endPos = sse_decode_i_32(deserializer);
+var var_batchId = sse_decode_opt_String(deserializer);
+var var_batchIndex = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_batchTotal = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_language = sse_decode_String(deserializer);
+return StructuredChunk(index: var_index, content: var_content, headerPath: var_headerPath, chunkType: var_chunkType, startPos: var_startPos, endPos: var_endPos, batchId: var_batchId, batchIndex: var_batchIndex, batchTotal: var_batchTotal, language: var_language); }
+
+@protected SynonymEntry sse_decode_synonym_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_term = sse_decode_String(deserializer);
+var var_expansions = sse_decode_list_String(deserializer);
+return SynonymEntry(term: var_term, expansions: var_expansions); }
+
+@protected TracedHybridSearch sse_decode_traced_hybrid_search(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_results = sse_decode_list_hybrid_search_result(deserializer);
+var var_trace = sse_decode_opt_box_autoadd_search_trace(deserializer);
+return TracedHybridSearch(results: var_results, trace: var_trace); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UserIntent sse_decode_user_intent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_query = sse_decode_String(deserializer);
+return UserIntent_Summary(query: var_query);case 1: var var_term = sse_decode_String(deserializer);
+return UserIntent_Define(term: var_term);case 2: var var_query = sse_decode_String(deserializer);
+return UserIntent_ExpandKnowledge(query: var_query);case 3: var var_query = sse_decode_String(deserializer);
+return UserIntent_General(query: var_query);case 4: var var_command = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return UserIntent_InvalidCommand(command: var_command, reason: var_reason);case 5: var var_left = sse_decode_String(deserializer);
+var var_right = sse_decode_String(deserializer);
+return UserIntent_Compare(left: var_left, right: var_right);case 6: var var_query = sse_decode_String(deserializer);
+return UserIntent_Cite(query: var_query);case 7: var var_query = sse_decode_String(deserializer);
+return UserIntent_Sources(query: var_query); default: throw UnimplementedError(''); }
+             }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MemoryStoreImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MemoryStoreImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MemoryStoreImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(FutureOr<Float32List> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(FutureOr<void> Function(EmbeddingBatchProgress) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(FutureOr<void> Function(IngestJob) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(FutureOr<void> Function(IngestProgress) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(FutureOr<List<Float32List>> Function(List<String>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(FutureOr<void> Function(ReembeddingJob) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_f_64_None(Map<String, double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_f_64(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MemoryStoreImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_hybrid_search_event_Sse(RustStreamSink<HybridSearchEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_hybrid_search_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_progress_event_Sse(RustStreamSink<ProgressEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_progress_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_add_document_result(AddDocumentResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.success, serializer);
+sse_encode_bool(self.isDuplicate, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_add_source_result(AddSourceResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_bool(self.isDuplicate, serializer);
+sse_encode_i_32(self.chunkCount, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_audit_issue(AuditIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.severity, serializer);
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_box_autoadd_i_64(self.sourceId, serializer);
+sse_encode_opt_box_autoadd_i_64(self.chunkId, serializer);
+ }
+
+@protected void sse_encode_audit_report(AuditReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.startedAt, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_u_64(self.sourcesChecked, serializer);
+sse_encode_u_64(self.chunksChecked, serializer);
+sse_encode_list_audit_issue(self.issues, serializer);
+sse_encode_u_32(self.issueCount, serializer);
+sse_encode_opt_String(self.maxSeverity, serializer);
+ }
+
+@protected void sse_encode_background_task(BackgroundTask self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_background_task_kind(self.kind, serializer);
+sse_encode_String(self.tenantId, serializer);
+sse_encode_String(self.state, serializer);
+sse_encode_opt_String(self.detail, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_i_64(self.submittedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.startedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.finishedAt, serializer);
+ }
+
+@protected void sse_encode_background_task_kind(BackgroundTaskKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_benchmark_config(BenchmarkConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_list_prim_u_8_strict(self.documentBytes, serializer);
+sse_encode_u_32(self.syntheticParagraphs, serializer);
+sse_encode_i_32(self.chunkMaxChars, serializer);
+sse_encode_u_32(self.embeddingDim, serializer);
+sse_encode_u_32(self.searchIterations, serializer);
+sse_encode_u_32(self.topK, serializer);
+ }
+
+@protected void sse_encode_benchmark_report(BenchmarkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_benchmark_stage(self.stages, serializer);
+sse_encode_u_32(self.documentChars, serializer);
+sse_encode_u_32(self.chunkCount, serializer);
+sse_encode_u_32(self.tokenCount, serializer);
+sse_encode_f_64(self.searchMeanMs, serializer);
+sse_encode_f_64(self.searchP95Ms, serializer);
+sse_encode_opt_box_autoadd_u_64(self.peakRssKb, serializer);
+sse_encode_f_64(self.totalMs, serializer);
+ }
+
+@protected void sse_encode_benchmark_stage(BenchmarkStage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_f_64(self.durationMs, serializer);
+sse_encode_bool(self.skipped, serializer);
+sse_encode_opt_box_autoadd_u_64(self.rssKb, serializer);
+ }
+
+@protected void sse_encode_bm_25_config(Bm25Config self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.k1, serializer);
+sse_encode_f_64(self.b, serializer);
+sse_encode_bm_25_variant(self.variant, serializer);
+ }
+
+@protected void sse_encode_bm_25_fuzzy_config(Bm25FuzzyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxEdits, serializer);
+sse_encode_u_32(self.minTermChars, serializer);
+sse_encode_u_32(self.maxExpansions, serializer);
+ }
+
+@protected void sse_encode_bm_25_index_options(Bm25IndexOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bm_25_tokenization(self.tokenization, serializer);
+sse_encode_opt_String(self.stemmer, serializer);
+sse_encode_bool(self.stripKoreanParticles, serializer);
+sse_encode_bool(self.removeStopwords, serializer);
+ }
+
+@protected void sse_encode_bm_25_search_result(Bm25SearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.docId, serializer);
+sse_encode_f_64(self.score, serializer);
+ }
+
+@protected void sse_encode_bm_25_tokenization(Bm25Tokenization self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bm_25_variant(Bm25Variant self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_audit_report(AuditReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audit_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_background_task(BackgroundTask self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_background_task(self, serializer); }
+
+@protected void sse_encode_box_autoadd_benchmark_config(BenchmarkConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_benchmark_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bm_25_config(Bm25Config self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bm_25_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bm_25_fuzzy_config(Bm25FuzzyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bm_25_fuzzy_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bm_25_index_options(Bm25IndexOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bm_25_index_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chunking_config(ChunkingConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chunking_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chunking_strategy(ChunkingStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chunking_strategy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_compression_options(CompressionOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_compression_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_db_retry_config(DbRetryConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_db_retry_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_dedupe_config(DedupeConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_dedupe_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_distance_metric(DistanceMetric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_distance_metric(self, serializer); }
+
+@protected void sse_encode_box_autoadd_expansion_config(ExpansionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_expansion_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hnsw_build_config(HnswBuildConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hnsw_build_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hnsw_config(HnswConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hnsw_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hnsw_index_stats(HnswIndexStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hnsw_index_stats(self, serializer); }
+
+@protected void sse_encode_box_autoadd_hybrid_search_options(HybridSearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hybrid_search_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_incremental_config(IncrementalConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_incremental_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_index_config(IndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_index_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ingest_input(IngestInput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ingest_input(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ingest_options(IngestOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ingest_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_language_analyzer(LanguageAnalyzer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_language_analyzer(self, serializer); }
+
+@protected void sse_encode_box_autoadd_last_retrieval(LastRetrieval self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_last_retrieval(self, serializer); }
+
+@protected void sse_encode_box_autoadd_maintenance_config(MaintenanceConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_maintenance_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_maintenance_report(MaintenanceReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_maintenance_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_named_index_config(NamedIndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_named_index_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query_decomposition(QueryDecomposition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_decomposition(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query_normalization_config(QueryNormalizationConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_normalization_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rag_error(RagError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rag_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_u_32_u_32_u_32((int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_32_u_32_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_reembedding_job(ReembeddingJob self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_reembedding_job(self, serializer); }
+
+@protected void sse_encode_box_autoadd_retrieval_preset(RetrievalPreset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retrieval_preset(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rrf_config(RrfConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rrf_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_diagnostics(SearchDiagnostics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_diagnostics(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_filter(SearchFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_snippet(SearchSnippet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_snippet(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_trace(SearchTrace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_trace(self, serializer); }
+
+@protected void sse_encode_box_autoadd_source_index_config(SourceIndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_source_index_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stored_chunk(StoredChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stored_chunk(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_user_intent(UserIntent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_user_intent(self, serializer); }
+
+@protected void sse_encode_buffer_stats(BufferStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.bufferSize, serializer);
+sse_encode_usize(self.threshold, serializer);
+sse_encode_bool(self.hnswLoaded, serializer);
+ }
+
+@protected void sse_encode_buffer_table(BufferTable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chunk_data(ChunkData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.content, serializer);
+sse_encode_i_32(self.chunkIndex, serializer);
+sse_encode_i_32(self.startPos, serializer);
+sse_encode_i_32(self.endPos, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_list_prim_f_32_strict(self.embedding, serializer);
+sse_encode_opt_String(self.batchId, serializer);
+ }
+
+@protected void sse_encode_chunk_for_reembedding(ChunkForReembedding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.chunkId, serializer);
+sse_encode_String(self.content, serializer);
+ }
+
+@protected void sse_encode_chunk_index_source(ChunkIndexSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chunk_meta(ChunkMeta self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.content, serializer);
+sse_encode_i_32(self.chunkIndex, serializer);
+sse_encode_i_32(self.startPos, serializer);
+sse_encode_i_32(self.endPos, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_opt_String(self.batchId, serializer);
+ }
+
+@protected void sse_encode_chunk_search_page(ChunkSearchPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_chunk_search_result(self.results, serializer);
+sse_encode_opt_String(self.nextCursor, serializer);
+ }
+
+@protected void sse_encode_chunk_search_result(ChunkSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.chunkId, serializer);
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_i_32(self.chunkIndex, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_opt_String(self.headerPath, serializer);
+sse_encode_f_64(self.similarity, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_chunk_stats(ChunkStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.chunkCount, serializer);
+sse_encode_u_32(self.minSize, serializer);
+sse_encode_u_32(self.maxSize, serializer);
+sse_encode_f_64(self.avgSize, serializer);
+sse_encode_f_64(self.medianSize, serializer);
+sse_encode_list_corpus_count(self.typeHistogram, serializer);
+sse_encode_u_32(self.oversizeCount, serializer);
+ }
+
+@protected void sse_encode_chunk_type(ChunkType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chunking_config(ChunkingConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.maxChars, serializer);
+sse_encode_i_32(self.minChars, serializer);
+sse_encode_i_32(self.overlap, serializer);
+sse_encode_chunking_strategy(self.strategy, serializer);
+sse_encode_bool(self.preserveTables, serializer);
+sse_encode_bool(self.preserveCode, serializer);
+sse_encode_list_String(self.sentenceTerminators, serializer);
+sse_encode_u_32(self.sectionSummarySentences, serializer);
+sse_encode_bool(self.linearizeTables, serializer);
+sse_encode_bool(self.keepListIntro, serializer);
+ }
+
+@protected void sse_encode_chunking_strategy(ChunkingStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_classification_rule(ClassificationRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_String(self.language, serializer);
+sse_encode_list_String(self.patterns, serializer);
+sse_encode_u_32(self.minMatches, serializer);
+ }
+
+@protected void sse_encode_compare_search_result(CompareSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.subjectIndex, serializer);
+sse_encode_hybrid_search_result(self.result, serializer);
+ }
+
+@protected void sse_encode_compressed_text(CompressedText self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_i_32(self.originalChars, serializer);
+sse_encode_i_32(self.compressedChars, serializer);
+sse_encode_f_64(self.ratio, serializer);
+sse_encode_i_32(self.sentencesRemoved, serializer);
+sse_encode_i_32(self.charsSavedStopwords, serializer);
+sse_encode_i_32(self.charsSavedTruncation, serializer);
+ }
+
+@protected void sse_encode_compression_options(CompressionOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.removeStopwords, serializer);
+sse_encode_bool(self.removeDuplicates, serializer);
+sse_encode_String(self.language, serializer);
+sse_encode_i_32(self.level, serializer);
+ }
+
+@protected void sse_encode_corpus_count(CorpusCount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_corpus_report(CorpusReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.sourceCount, serializer);
+sse_encode_u_64(self.chunkCount, serializer);
+sse_encode_u_64(self.totalChars, serializer);
+sse_encode_u_64(self.vocabularySize, serializer);
+sse_encode_list_source_corpus_stats(self.sources, serializer);
+sse_encode_list_length_bucket(self.chunkLengthHistogram, serializer);
+sse_encode_list_corpus_count(self.chunkTypes, serializer);
+sse_encode_list_corpus_count(self.languages, serializer);
+sse_encode_index_freshness(self.index, serializer);
+ }
+
+@protected void sse_encode_db_retry_config(DbRetryConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_64(self.baseDelayMs, serializer);
+sse_encode_u_64(self.maxDelayMs, serializer);
+ }
+
+@protected void sse_encode_dedupe_config(DedupeConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.nearDuplicateThreshold, serializer);
+sse_encode_u_32(self.shingleSize, serializer);
+sse_encode_bool(self.exactOnly, serializer);
+ }
+
+@protected void sse_encode_dedupe_report(DedupeReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_32_strict(self.kept, serializer);
+sse_encode_list_duplicate_chunk(self.duplicates, serializer);
+ }
+
+@protected void sse_encode_distance_metric(DistanceMetric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_duplicate_chunk(DuplicateChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_u_32(self.duplicateOf, serializer);
+sse_encode_f_64(self.similarity, serializer);
+ }
+
+@protected void sse_encode_embedding_batch_progress(EmbeddingBatchProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.done, serializer);
+sse_encode_u_32(self.total, serializer);
+ }
+
+@protected void sse_encode_embedding_point(EmbeddingPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_list_prim_f_32_strict(self.embedding, serializer);
+sse_encode_f_32(self.norm, serializer);
+ }
+
+@protected void sse_encode_embedding_storage_stats(EmbeddingStorageStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.f32Chunks, serializer);
+sse_encode_i_64(self.quantizedChunks, serializer);
+sse_encode_i_64(self.embeddingBytes, serializer);
+ }
+
+@protected void sse_encode_engine_info(EngineInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.crateVersion, serializer);
+sse_encode_u_32(self.schemaVersion, serializer);
+sse_encode_opt_box_autoadd_u_32(self.databaseSchemaVersion, serializer);
+sse_encode_u_32(self.hnswFormatVersion, serializer);
+sse_encode_u_32(self.bm25FormatVersion, serializer);
+sse_encode_u_32(self.snapshotFormatVersion, serializer);
+sse_encode_opt_String(self.tokenizerName, serializer);
+sse_encode_opt_String(self.embeddingModelName, serializer);
+sse_encode_opt_box_autoadd_u_32(self.embeddingDimension, serializer);
+sse_encode_String(self.activeTenant, serializer);
+sse_encode_list_String(self.activeFeatures, serializer);
+sse_encode_list_String(self.buildFeatures, serializer);
+ }
+
+@protected void sse_encode_engine_metrics(EngineMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.searchesExecuted, serializer);
+sse_encode_u_64(self.cacheHits, serializer);
+sse_encode_u_64(self.cacheMisses, serializer);
+sse_encode_u_64(self.indexRebuilds, serializer);
+sse_encode_u_64(self.dbErrors, serializer);
+sse_encode_u_64(self.panics, serializer);
+sse_encode_f_64(self.avgSearchLatencyMs, serializer);
+sse_encode_list_latency_bucket(self.searchLatencyHistogram, serializer);
+ }
+
+@protected void sse_encode_entity_facet(EntityFacet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.entityType, serializer);
+sse_encode_String(self.normalized, serializer);
+sse_encode_u_32(self.chunkCount, serializer);
+ }
+
+@protected void sse_encode_expanded_search_result(ExpandedSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hybrid_search_result(self.result, serializer);
+sse_encode_String(self.link, serializer);
+sse_encode_opt_box_autoadd_i_64(self.expandedFrom, serializer);
+ }
+
+@protected void sse_encode_expansion_config(ExpansionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxRelatedPerHit, serializer);
+sse_encode_f_64(self.scoreDecay, serializer);
+sse_encode_bool(self.followAdjacent, serializer);
+sse_encode_bool(self.followSharedEntities, serializer);
+sse_encode_bool(self.followSameBatch, serializer);
+ }
+
+@protected void sse_encode_extracted_entity(ExtractedEntity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.entityType, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_String(self.normalized, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_fusion_strategy(FusionStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FusionStrategy_Rrf(): sse_encode_i_32(0, serializer); case FusionStrategy_WeightedScore(normalization: final normalization): sse_encode_i_32(1, serializer); sse_encode_score_normalization(normalization, serializer);
+  } }
+
+@protected void sse_encode_gazetteer_entry(GazetteerEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.term, serializer);
+sse_encode_String(self.entityType, serializer);
+ }
+
+@protected void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.object, serializer);
+sse_encode_i_64(self.chunkId, serializer);
+sse_encode_u_32(self.hop, serializer);
+ }
+
+@protected void sse_encode_graph_neighborhood(GraphNeighborhood self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.entity, serializer);
+sse_encode_list_graph_edge(self.edges, serializer);
+sse_encode_list_prim_i_64_strict(self.chunkIds, serializer);
+ }
+
+@protected void sse_encode_highlight_span(HighlightSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_hnsw_build_config(HnswBuildConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.m, serializer);
+sse_encode_u_32(self.maxLayer, serializer);
+sse_encode_u_32(self.efConstruction, serializer);
+ }
+
+@protected void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_hnsw_build_config(self.build, serializer);
+sse_encode_opt_box_autoadd_u_32(self.efSearch, serializer);
+ }
+
+@protected void sse_encode_hnsw_index_stats(HnswIndexStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.pointCount, serializer);
+sse_encode_u_32(self.dimension, serializer);
+sse_encode_quantization(self.quantization, serializer);
+sse_encode_distance_metric(self.metric, serializer);
+sse_encode_hnsw_build_config(self.build, serializer);
+sse_encode_u_32(self.m0, serializer);
+sse_encode_bool(self.customBuild, serializer);
+sse_encode_opt_box_autoadd_u_32(self.efSearch, serializer);
+sse_encode_u_64(self.deletedPoints, serializer);
+ }
+
+@protected void sse_encode_hnsw_search_result(HnswSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_f_32(self.distance, serializer);
+ }
+
+@protected void sse_encode_hybrid_search_event(HybridSearchEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case HybridSearchEvent_Ranking(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_list_ranked_hit(field0, serializer);
+case HybridSearchEvent_Results(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_list_hybrid_search_result(field0, serializer);
+case HybridSearchEvent_Done(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_hybrid_search_options(HybridSearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.efSearch, serializer);
+sse_encode_opt_box_autoadd_f_64(self.diversityLambda, serializer);
+sse_encode_opt_String(self.collection, serializer);
+sse_encode_opt_box_autoadd_u_32(self.groupBySource, serializer);
+sse_encode_bool(self.withDiagnostics, serializer);
+sse_encode_opt_box_autoadd_u_32(self.pageOffset, serializer);
+sse_encode_opt_String(self.cursor, serializer);
+ }
+
+@protected void sse_encode_hybrid_search_response(HybridSearchResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_hybrid_search_result(self.results, serializer);
+sse_encode_opt_list_source_group(self.groups, serializer);
+sse_encode_opt_box_autoadd_search_diagnostics(self.diagnostics, serializer);
+sse_encode_opt_String(self.nextCursor, serializer);
+ }
+
+@protected void sse_encode_hybrid_search_result(HybridSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.docId, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_u_32(self.vectorRank, serializer);
+sse_encode_u_32(self.bm25Rank, serializer);
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+sse_encode_u_32(self.chunkIndex, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_opt_String(self.headerPath, serializer);
+sse_encode_list_String(self.matchedTerms, serializer);
+sse_encode_opt_box_autoadd_search_snippet(self.snippet, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_incremental_config(IncrementalConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.autoMerge, serializer);
+ }
+
+@protected void sse_encode_incremental_search_result(IncrementalSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.docId, serializer);
+sse_encode_f_32(self.distance, serializer);
+sse_encode_String(self.source, serializer);
+ }
+
+@protected void sse_encode_index_config(IndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_quantization(self.quantization, serializer);
+sse_encode_distance_metric(self.metric, serializer);
+ }
+
+@protected void sse_encode_index_consistency(IndexConsistency self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.chunkCount, serializer);
+sse_encode_index_divergence(self.hnsw, serializer);
+sse_encode_index_divergence(self.bm25, serializer);
+sse_encode_bool(self.consistent, serializer);
+sse_encode_bool(self.repaired, serializer);
+ }
+
+@protected void sse_encode_index_divergence(IndexDivergence self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.loaded, serializer);
+sse_encode_u_64(self.indexedCount, serializer);
+sse_encode_list_prim_i_64_strict(self.missingIds, serializer);
+sse_encode_list_prim_i_64_strict(self.extraIds, serializer);
+ }
+
+@protected void sse_encode_index_evaluation(IndexEvaluation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.sampleSize, serializer);
+sse_encode_u_32(self.topK, serializer);
+sse_encode_u_64(self.corpusSize, serializer);
+sse_encode_f_64(self.recallAtK, serializer);
+sse_encode_f_64(self.minRecall, serializer);
+sse_encode_f_64(self.latencyP50Ms, serializer);
+sse_encode_f_64(self.latencyP95Ms, serializer);
+sse_encode_f_64(self.latencyP99Ms, serializer);
+sse_encode_f_64(self.exactMeanMs, serializer);
+ }
+
+@protected void sse_encode_index_freshness(IndexFreshness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.dbChunkCount, serializer);
+sse_encode_opt_box_autoadd_u_64(self.hnswPointCount, serializer);
+sse_encode_u_64(self.bm25DocumentCount, serializer);
+sse_encode_bool(self.hnswUpToDate, serializer);
+sse_encode_bool(self.bm25UpToDate, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastSourceAddedAt, serializer);
+ }
+
+@protected void sse_encode_index_staleness(IndexStaleness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.hnswStale, serializer);
+sse_encode_bool(self.bm25Stale, serializer);
+ }
+
+@protected void sse_encode_ingest_input(IngestInput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case IngestInput_Text(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case IngestInput_Bytes(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+  } }
+
+@protected void sse_encode_ingest_job(IngestJob self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.filePath, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_String(self.state, serializer);
+sse_encode_opt_String(self.stage, serializer);
+sse_encode_u_32(self.progressDone, serializer);
+sse_encode_u_32(self.progressTotal, serializer);
+sse_encode_opt_box_autoadd_i_64(self.sourceId, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_ingest_options(IngestOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+sse_encode_opt_box_autoadd_chunking_strategy(self.strategy, serializer);
+sse_encode_i_32(self.maxChars, serializer);
+sse_encode_i_32(self.overlapChars, serializer);
+sse_encode_bool(self.rebuildIndexes, serializer);
+sse_encode_bool(self.dedupeChunks, serializer);
+ }
+
+@protected void sse_encode_ingest_progress(IngestProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stage, serializer);
+sse_encode_u_32(self.done, serializer);
+sse_encode_u_32(self.total, serializer);
+sse_encode_opt_box_autoadd_i_64(self.sourceId, serializer);
+ }
+
+@protected void sse_encode_ingest_report(IngestReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_bool(self.isDuplicate, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_u_32(self.chunkCount, serializer);
+sse_encode_u_32(self.skippedChunks, serializer);
+sse_encode_u_64(self.textChars, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+ }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_keyword_backend(KeywordBackend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_knowledge_triple(KnowledgeTriple self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.relation, serializer);
+sse_encode_String(self.object, serializer);
+ }
+
+@protected void sse_encode_language_analyzer(LanguageAnalyzer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.language, serializer);
+sse_encode_list_String(self.stopwords, serializer);
+ }
+
+@protected void sse_encode_last_retrieval(LastRetrieval self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.query, serializer);
+sse_encode_list_retrieval_citation(self.citations, serializer);
+ }
+
+@protected void sse_encode_latency_bucket(LatencyBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.leMs, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_length_bucket(LengthBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.leChars, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_audit_issue(List<AuditIssue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_audit_issue(item, serializer); } }
+
+@protected void sse_encode_list_background_task(List<BackgroundTask> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_background_task(item, serializer); } }
+
+@protected void sse_encode_list_benchmark_stage(List<BenchmarkStage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_benchmark_stage(item, serializer); } }
+
+@protected void sse_encode_list_bm_25_search_result(List<Bm25SearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_bm_25_search_result(item, serializer); } }
+
+@protected void sse_encode_list_chunk_data(List<ChunkData> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chunk_data(item, serializer); } }
+
+@protected void sse_encode_list_chunk_for_reembedding(List<ChunkForReembedding> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chunk_for_reembedding(item, serializer); } }
+
+@protected void sse_encode_list_chunk_meta(List<ChunkMeta> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chunk_meta(item, serializer); } }
+
+@protected void sse_encode_list_chunk_search_result(List<ChunkSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chunk_search_result(item, serializer); } }
+
+@protected void sse_encode_list_classification_rule(List<ClassificationRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_classification_rule(item, serializer); } }
+
+@protected void sse_encode_list_compare_search_result(List<CompareSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_compare_search_result(item, serializer); } }
+
+@protected void sse_encode_list_corpus_count(List<CorpusCount> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_corpus_count(item, serializer); } }
+
+@protected void sse_encode_list_duplicate_chunk(List<DuplicateChunk> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_duplicate_chunk(item, serializer); } }
+
+@protected void sse_encode_list_entity_facet(List<EntityFacet> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_entity_facet(item, serializer); } }
+
+@protected void sse_encode_list_expanded_search_result(List<ExpandedSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_expanded_search_result(item, serializer); } }
+
+@protected void sse_encode_list_extracted_entity(List<ExtractedEntity> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_extracted_entity(item, serializer); } }
+
+@protected void sse_encode_list_gazetteer_entry(List<GazetteerEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_gazetteer_entry(item, serializer); } }
+
+@protected void sse_encode_list_graph_edge(List<GraphEdge> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_graph_edge(item, serializer); } }
+
+@protected void sse_encode_list_highlight_span(List<HighlightSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_highlight_span(item, serializer); } }
+
+@protected void sse_encode_list_hnsw_search_result(List<HnswSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_hnsw_search_result(item, serializer); } }
+
+@protected void sse_encode_list_hybrid_search_result(List<HybridSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_hybrid_search_result(item, serializer); } }
+
+@protected void sse_encode_list_incremental_search_result(List<IncrementalSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_incremental_search_result(item, serializer); } }
+
+@protected void sse_encode_list_ingest_job(List<IngestJob> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ingest_job(item, serializer); } }
+
+@protected void sse_encode_list_knowledge_triple(List<KnowledgeTriple> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_knowledge_triple(item, serializer); } }
+
+@protected void sse_encode_list_language_analyzer(List<LanguageAnalyzer> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_language_analyzer(item, serializer); } }
+
+@protected void sse_encode_list_latency_bucket(List<LatencyBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_latency_bucket(item, serializer); } }
+
+@protected void sse_encode_list_length_bucket(List<LengthBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_length_bucket(item, serializer); } }
+
+@protected void sse_encode_list_list_prim_f_32_strict(List<Float32List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_prim_f_32_strict(item, serializer); } }
+
+@protected void sse_encode_list_memory_message(List<MemoryMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_memory_message(item, serializer); } }
+
+@protected void sse_encode_list_memory_search_result(List<MemorySearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_memory_search_result(item, serializer); } }
+
+@protected void sse_encode_list_named_bm_25_index_info(List<NamedBm25IndexInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_named_bm_25_index_info(item, serializer); } }
+
+@protected void sse_encode_list_named_index_info(List<NamedIndexInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_named_index_info(item, serializer); } }
+
+@protected void sse_encode_list_panic_event(List<PanicEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_panic_event(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self is Float32List ? self : Float32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_f_64_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self is Float64List ? self : Float64List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self); }
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt64List(self); }
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self is Uint32List ? self : Uint32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_keyword(List<QueryKeyword> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_keyword(item, serializer); } }
+
+@protected void sse_encode_list_query_suggestion(List<QuerySuggestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_suggestion(item, serializer); } }
+
+@protected void sse_encode_list_ranked_hit(List<RankedHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ranked_hit(item, serializer); } }
+
+@protected void sse_encode_list_record_i_64_list_prim_f_32_strict(List<(PlatformInt64,Float32List)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_i_64_list_prim_f_32_strict(item, serializer); } }
+
+@protected void sse_encode_list_record_i_64_string(List<(PlatformInt64,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_i_64_string(item, serializer); } }
+
+@protected void sse_encode_list_record_string_f_64(List<(String,double)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_f_64(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_retrieval_citation(List<RetrievalCitation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_retrieval_citation(item, serializer); } }
+
+@protected void sse_encode_list_search_span(List<SearchSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_span(item, serializer); } }
+
+@protected void sse_encode_list_semantic_chunk(List<SemanticChunk> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_semantic_chunk(item, serializer); } }
+
+@protected void sse_encode_list_snapshot_file(List<SnapshotFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snapshot_file(item, serializer); } }
+
+@protected void sse_encode_list_source_corpus_stats(List<SourceCorpusStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_source_corpus_stats(item, serializer); } }
+
+@protected void sse_encode_list_source_entry(List<SourceEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_source_entry(item, serializer); } }
+
+@protected void sse_encode_list_source_group(List<SourceGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_source_group(item, serializer); } }
+
+@protected void sse_encode_list_store_search_hit(List<StoreSearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_store_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_structured_chunk(List<StructuredChunk> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_structured_chunk(item, serializer); } }
+
+@protected void sse_encode_list_synonym_entry(List<SynonymEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_synonym_entry(item, serializer); } }
+
+@protected void sse_encode_maintenance_config(MaintenanceConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.requireCharging, serializer);
+sse_encode_u_32(self.minIntervalSecs, serializer);
+ }
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.startedAt, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_bool(self.bufferMerged, serializer);
+sse_encode_bool(self.hnswCompacted, serializer);
+sse_encode_u_32(self.bm25Pruned, serializer);
+sse_encode_i_64(self.walPagesCheckpointed, serializer);
+sse_encode_bool(self.interrupted, serializer);
+ }
+
+@protected void sse_encode_memory_message(MemoryMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.conversationId, serializer);
+sse_encode_String(self.role, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_memory_profile(MemoryProfile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.budgetMb, serializer);
+sse_encode_bool(self.lowMemory, serializer);
+sse_encode_u_32(self.sqliteCacheKb, serializer);
+sse_encode_u_32(self.sqliteMmapMb, serializer);
+sse_encode_bool(self.quantizeIndex, serializer);
+sse_encode_u_32(self.candidateMultiplier, serializer);
+sse_encode_u_32(self.filteredCandidateMultiplier, serializer);
+sse_encode_u_32(self.scanPageRows, serializer);
+ }
+
+@protected void sse_encode_memory_search_result(MemorySearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_memory_message(self.message, serializer);
+sse_encode_f_64(self.similarity, serializer);
+ }
+
+@protected void sse_encode_memory_usage(MemoryUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.lowMemory, serializer);
+sse_encode_u_64(self.hnswPoints, serializer);
+sse_encode_u_64(self.quantizedPoints, serializer);
+sse_encode_u_64(self.quantizedIndexBytes, serializer);
+sse_encode_u_64(self.bufferedEmbeddings, serializer);
+sse_encode_u_64(self.sqliteMemoryBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.processRssBytes, serializer);
+ }
+
+@protected void sse_encode_named_bm_25_index_info(NamedBm25IndexInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.documentCount, serializer);
+sse_encode_u_64(self.vocabularySize, serializer);
+ }
+
+@protected void sse_encode_named_index_config(NamedIndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_distance_metric(self.metric, serializer);
+sse_encode_opt_box_autoadd_hnsw_build_config(self.build, serializer);
+sse_encode_opt_box_autoadd_u_32(self.efSearch, serializer);
+ }
+
+@protected void sse_encode_named_index_info(NamedIndexInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.pointCount, serializer);
+sse_encode_u_64(self.deletedPoints, serializer);
+sse_encode_u_32(self.dimension, serializer);
+sse_encode_distance_metric(self.metric, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_audit_report(AuditReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_audit_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_background_task(BackgroundTask? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_background_task(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bm_25_fuzzy_config(Bm25FuzzyConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bm_25_fuzzy_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_chunking_strategy(ChunkingStrategy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_chunking_strategy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_distance_metric(DistanceMetric? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_distance_metric(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_expansion_config(ExpansionConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_expansion_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_hnsw_build_config(HnswBuildConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_hnsw_build_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_hnsw_index_stats(HnswIndexStats? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_hnsw_index_stats(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_hybrid_search_options(HybridSearchOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_hybrid_search_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_last_retrieval(LastRetrieval? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_last_retrieval(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_maintenance_report(MaintenanceReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_maintenance_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_record_u_32_u_32_u_32((int,int,int)? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_record_u_32_u_32_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_reembedding_job(ReembeddingJob? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_reembedding_job(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rrf_config(RrfConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rrf_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_search_diagnostics(SearchDiagnostics? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_search_diagnostics(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_search_filter(SearchFilter? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_search_filter(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_search_snippet(SearchSnippet? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_search_snippet(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_search_trace(SearchTrace? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_search_trace(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stored_chunk(StoredChunk? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stored_chunk(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_i_64_strict(Int64List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_i_64_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_record_string_string(List<(String,String)>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_record_string_string(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_source_group(List<SourceGroup>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_source_group(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_panic_event(PanicEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.thread, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_String(self.location, serializer);
+sse_encode_opt_String(self.backtrace, serializer);
+sse_encode_i_64(self.timestamp, serializer);
+ }
+
+@protected void sse_encode_parsed_intent(ParsedIntent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.intentType, serializer);
+sse_encode_String(self.query, serializer);
+sse_encode_bool(self.isValid, serializer);
+sse_encode_opt_String(self.errorMessage, serializer);
+sse_encode_String(self.language, serializer);
+ }
+
+@protected void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.operation, serializer);
+sse_encode_String(self.phase, serializer);
+sse_encode_u_32(self.done, serializer);
+sse_encode_u_32(self.total, serializer);
+ }
+
+@protected void sse_encode_quantization(Quantization self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_query_decomposition(QueryDecomposition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.original, serializer);
+sse_encode_list_String(self.subQueries, serializer);
+ }
+
+@protected void sse_encode_query_keyword(QueryKeyword self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_u_32(self.docFreq, serializer);
+sse_encode_bool(self.inIndex, serializer);
+ }
+
+@protected void sse_encode_query_normalization_config(QueryNormalizationConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.unicodeNfkc, serializer);
+sse_encode_bool(self.stripPunctuation, serializer);
+sse_encode_bool(self.lowercase, serializer);
+sse_encode_bool(self.removeStopwords, serializer);
+ }
+
+@protected void sse_encode_query_suggestion(QuerySuggestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.query, serializer);
+sse_encode_u_32(self.useCount, serializer);
+sse_encode_i_64(self.lastUsedAt, serializer);
+ }
+
+@protected void sse_encode_rag_error(RagError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RagError_DatabaseError(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case RagError_IoError(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case RagError_ModelLoadError(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case RagError_InvalidInput(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case RagError_InternalError(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case RagError_Unknown(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+case RagError_DatabaseBusy(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case RagError_NotInitialized(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+case RagError_ParseError(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_ranked_hit(RankedHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.docId, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_u_32(self.vectorRank, serializer);
+sse_encode_u_32(self.bm25Rank, serializer);
+ }
+
+@protected void sse_encode_record_i_64_list_prim_f_32_strict((PlatformInt64,Float32List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.$1, serializer);
+sse_encode_list_prim_f_32_strict(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_i_64_string((PlatformInt64,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_f_64((String,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_f_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_u_32_u_32((int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_u_32(self.$2, serializer);
+sse_encode_u_32(self.$3, serializer);
+ }
+
+@protected void sse_encode_reembedding_job(ReembeddingJob self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.modelName, serializer);
+sse_encode_u_32(self.batchSize, serializer);
+sse_encode_String(self.state, serializer);
+sse_encode_u_32(self.done, serializer);
+sse_encode_u_32(self.total, serializer);
+sse_encode_i_64(self.lastChunkId, serializer);
+sse_encode_opt_box_autoadd_u_32(self.dimension, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_retrieval_citation(RetrievalCitation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.chunkId, serializer);
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_i_32(self.chunkIndex, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_retrieval_preset(RetrievalPreset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.topK, serializer);
+sse_encode_f_64(self.vectorWeight, serializer);
+sse_encode_f_64(self.bm25Weight, serializer);
+sse_encode_opt_String(self.boostChunkType, serializer);
+sse_encode_f_64(self.chunkTypeBoost, serializer);
+sse_encode_bool(self.useMmr, serializer);
+sse_encode_f_64(self.mmrLambda, serializer);
+ }
+
+@protected void sse_encode_rewritten_query(RewrittenQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.original, serializer);
+sse_encode_String(self.rewritten, serializer);
+sse_encode_bool(self.wasRewritten, serializer);
+sse_encode_opt_String(self.antecedent, serializer);
+ }
+
+@protected void sse_encode_rrf_config(RrfConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.k, serializer);
+sse_encode_f_64(self.vectorWeight, serializer);
+sse_encode_f_64(self.bm25Weight, serializer);
+sse_encode_Map_String_f_64_None(self.typeBoosts, serializer);
+sse_encode_opt_box_autoadd_f_64(self.recencyHalfLifeDays, serializer);
+sse_encode_f_64(self.recencyWeight, serializer);
+sse_encode_fusion_strategy(self.fusion, serializer);
+ }
+
+@protected void sse_encode_score_normalization(ScoreNormalization self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_search_diagnostics(SearchDiagnostics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.vectorMs, serializer);
+sse_encode_f_64(self.bm25Ms, serializer);
+sse_encode_f_64(self.filterMs, serializer);
+sse_encode_f_64(self.fusionMs, serializer);
+sse_encode_f_64(self.fetchMs, serializer);
+sse_encode_f_64(self.totalMs, serializer);
+sse_encode_u_32(self.candidateK, serializer);
+sse_encode_u_32(self.vectorCandidates, serializer);
+sse_encode_u_32(self.bm25Candidates, serializer);
+sse_encode_u_32(self.filteredVectorCandidates, serializer);
+sse_encode_u_32(self.filteredBm25Candidates, serializer);
+sse_encode_bool(self.exactScan, serializer);
+sse_encode_bool(self.graphFilter, serializer);
+sse_encode_bool(self.hnswLoaded, serializer);
+ }
+
+@protected void sse_encode_search_feedback_action(SearchFeedbackAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_search_filter(SearchFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_list_prim_i_64_strict(self.sourceIds, serializer);
+sse_encode_opt_String(self.metadataLike, serializer);
+sse_encode_opt_String(self.entity, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_opt_list_String(self.chunkTypes, serializer);
+sse_encode_opt_list_record_string_string(self.metadataEquals, serializer);
+sse_encode_opt_box_autoadd_i_64(self.createdAfter, serializer);
+sse_encode_opt_box_autoadd_i_64(self.createdBefore, serializer);
+ }
+
+@protected void sse_encode_search_snippet(SearchSnippet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_list_highlight_span(self.highlights, serializer);
+ }
+
+@protected void sse_encode_search_span(SearchSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_f_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_search_trace(SearchTrace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_span(self.spans, serializer);
+sse_encode_f_64(self.totalMs, serializer);
+ }
+
+@protected void sse_encode_semantic_chunk(SemanticChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_i_32(self.startPos, serializer);
+sse_encode_i_32(self.endPos, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_String(self.language, serializer);
+ }
+
+@protected void sse_encode_snapshot_file(SnapshotFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.sha256, serializer);
+ }
+
+@protected void sse_encode_snapshot_manifest(SnapshotManifest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.formatVersion, serializer);
+sse_encode_String(self.engineVersion, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_list_String(self.tenants, serializer);
+sse_encode_i_64(self.sourceCount, serializer);
+sse_encode_i_64(self.chunkCount, serializer);
+sse_encode_list_snapshot_file(self.files, serializer);
+ }
+
+@protected void sse_encode_source_corpus_stats(SourceCorpusStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.status, serializer);
+sse_encode_u_64(self.chunkCount, serializer);
+sse_encode_u_32(self.minChunkChars, serializer);
+sse_encode_u_32(self.maxChunkChars, serializer);
+sse_encode_f_64(self.avgChunkChars, serializer);
+ }
+
+@protected void sse_encode_source_entry(SourceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+sse_encode_opt_String(self.status, serializer);
+ }
+
+@protected void sse_encode_source_group(SourceGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_bool(self.isDocument, serializer);
+sse_encode_opt_String(self.sourceName, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_list_hybrid_search_result(self.chunks, serializer);
+ }
+
+@protected void sse_encode_source_index_config(SourceIndexConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_u_32(self.maxCached, serializer);
+ }
+
+@protected void sse_encode_source_stats(SourceStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sourceCount, serializer);
+sse_encode_i_64(self.chunkCount, serializer);
+ }
+
+@protected void sse_encode_source_update(SourceUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stagingId, serializer);
+sse_encode_list_String(self.chunks, serializer);
+sse_encode_u_32(self.keptChunks, serializer);
+sse_encode_u_32(self.removedChunks, serializer);
+sse_encode_u_32(self.skippedChunks, serializer);
+sse_encode_i_64(self.expiresAt, serializer);
+ }
+
+@protected void sse_encode_staged_ingest(StagedIngest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stagingId, serializer);
+sse_encode_list_String(self.chunks, serializer);
+sse_encode_u_32(self.skippedChunks, serializer);
+sse_encode_i_64(self.expiresAt, serializer);
+ }
+
+@protected void sse_encode_store_search_hit(StoreSearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_f_64(self.similarity, serializer);
+ }
+
+@protected void sse_encode_stored_chunk(StoredChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_i_64(self.sourceId, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_list_prim_f_32_strict(self.embedding, serializer);
+ }
+
+@protected void sse_encode_structured_chunk(StructuredChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_String(self.headerPath, serializer);
+sse_encode_String(self.chunkType, serializer);
+sse_encode_i_32(self.startPos, serializer);
+sse_encode_i_32(self.endPos, serializer);
+sse_encode_opt_String(self.batchId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.batchIndex, serializer);
+sse_encode_opt_box_autoadd_i_32(self.batchTotal, serializer);
+sse_encode_String(self.language, serializer);
+ }
+
+@protected void sse_encode_synonym_entry(SynonymEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.term, serializer);
+sse_encode_list_String(self.expansions, serializer);
+ }
+
+@protected void sse_encode_traced_hybrid_search(TracedHybridSearch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_hybrid_search_result(self.results, serializer);
+sse_encode_opt_box_autoadd_search_trace(self.trace, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_user_intent(UserIntent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UserIntent_Summary(query: final query): sse_encode_i_32(0, serializer); sse_encode_String(query, serializer);
+case UserIntent_Define(term: final term): sse_encode_i_32(1, serializer); sse_encode_String(term, serializer);
+case UserIntent_ExpandKnowledge(query: final query): sse_encode_i_32(2, serializer); sse_encode_String(query, serializer);
+case UserIntent_General(query: final query): sse_encode_i_32(3, serializer); sse_encode_String(query, serializer);
+case UserIntent_InvalidCommand(command: final command,reason: final reason): sse_encode_i_32(4, serializer); sse_encode_String(command, serializer);
+sse_encode_String(reason, serializer);
+case UserIntent_Compare(left: final left,right: final right): sse_encode_i_32(5, serializer); sse_encode_String(left, serializer);
+sse_encode_String(right, serializer);
+case UserIntent_Cite(query: final query): sse_encode_i_32(6, serializer); sse_encode_String(query, serializer);
+case UserIntent_Sources(query: final query): sse_encode_i_32(7, serializer); sse_encode_String(query, serializer);
+  } }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class MemoryStoreImpl extends RustOpaque implements MemoryStore {
+                // Not to be used by end users
+                MemoryStoreImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MemoryStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MemoryStore,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MemoryStore,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MemoryStorePtr,
+                );
+
+                 Future<BigInt>  chunkCount()=>RustLib.instance.api.crateApiChunkStoreMemoryStoreChunkCount(that: this, );
+
+
+ Future<BigInt>  deleteSource({required PlatformInt64 sourceId })=>RustLib.instance.api.crateApiChunkStoreMemoryStoreDeleteSource(that: this, sourceId: sourceId);
+
+
+ Future<StoredChunk?>  getChunk({required PlatformInt64 id })=>RustLib.instance.api.crateApiChunkStoreMemoryStoreGetChunk(that: this, id: id);
+
+
+ Future<void>  putChunk({required StoredChunk chunk })=>RustLib.instance.api.crateApiChunkStoreMemoryStorePutChunk(that: this, chunk: chunk);
+
+
+ Future<List<StoreSearchHit>>  searchVectors({required List<double> query , required BigInt topK })=>RustLib.instance.api.crateApiChunkStoreMemoryStoreSearchVectors(that: this, query: query, topK: topK);
+
+
+            }
\ No newline at end of file
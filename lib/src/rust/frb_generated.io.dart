@@ -3,19 +3,70 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/analyzers.dart';
+import 'api/async_ops.dart';
+import 'api/audit.dart';
+import 'api/background_tasks.dart';
+import 'api/benchmark.dart';
+import 'api/binary_index.dart';
 import 'api/bm25_search.dart';
+import 'api/chat_memory.dart';
+import 'api/chunk_dedup.dart';
+import 'api/chunk_index_store.dart';
+import 'api/chunk_store.dart';
+import 'api/classification_rules.dart';
+import 'api/code_chunker.dart';
 import 'api/compression_utils.dart';
+import 'api/content_crypto.dart';
+import 'api/corpus_report.dart';
 import 'api/db_pool.dart';
+import 'api/db_retry.dart';
 import 'api/document_parser.dart';
+import 'api/embedding_storage.dart';
+import 'api/engine_info.dart';
+import 'api/entity_extraction.dart';
 import 'api/error.dart';
+import 'api/fts_keyword.dart';
+import 'api/fuzzy_terms.dart';
 import 'api/hnsw_index.dart';
 import 'api/hybrid_search.dart';
 import 'api/incremental_index.dart';
+import 'api/index_freshness.dart';
+import 'api/ingest.dart';
+import 'api/ingest_jobs.dart';
+import 'api/intent_presets.dart';
+import 'api/knowledge_graph.dart';
+import 'api/language_detect.dart';
 import 'api/logger.dart';
+import 'api/maintenance.dart';
+import 'api/memory_budget.dart';
+import 'api/metrics.dart';
+import 'api/named_bm25.dart';
+import 'api/named_index.dart';
+import 'api/panic_report.dart';
+import 'api/progress.dart';
+import 'api/query_decompose.dart';
+import 'api/query_history.dart';
+import 'api/query_keywords.dart';
+import 'api/query_normalize.dart';
+import 'api/query_rewrite.dart';
+import 'api/reembedding.dart';
+import 'api/related_chunks.dart';
+import 'api/rerank.dart';
+import 'api/retrieval_state.dart';
+import 'api/search_analytics.dart';
+import 'api/search_trace.dart';
 import 'api/semantic_chunker.dart';
 import 'api/simple.dart';
 import 'api/simple_rag.dart';
+import 'api/snapshot.dart';
+import 'api/snippets.dart';
+import 'api/source_grouping.dart';
+import 'api/source_index.dart';
 import 'api/source_rag.dart';
+import 'api/staged_ingest.dart';
+import 'api/synonyms.dart';
+import 'api/tenant.dart';
 import 'api/tokenizer.dart';
 import 'api/user_intent.dart';
 import 'dart:async';
@@ -24,857 +75,1810 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  AddDocumentResult dco_decode_add_document_result(dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MemoryStorePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStorePtr;
 
-  @protected
-  AddSourceResult dco_decode_add_source_result(dynamic raw);
 
-  @protected
-  Bm25SearchResult dco_decode_bm_25_search_result(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  CompressionOptions dco_decode_box_autoadd_compression_options(dynamic raw);
+@protected MemoryStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw);
 
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
+@protected MemoryStore dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw);
 
-  @protected
-  (int, int, int) dco_decode_box_autoadd_record_u_32_u_32_u_32(dynamic raw);
+@protected MemoryStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw);
 
-  @protected
-  RrfConfig dco_decode_box_autoadd_rrf_config(dynamic raw);
+@protected FutureOr<Float32List> Function(String) dco_decode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(dynamic raw);
 
-  @protected
-  SearchFilter dco_decode_box_autoadd_search_filter(dynamic raw);
+@protected FutureOr<void> Function(EmbeddingBatchProgress) dco_decode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(dynamic raw);
 
-  @protected
-  UserIntent dco_decode_box_autoadd_user_intent(dynamic raw);
+@protected FutureOr<void> Function(IngestJob) dco_decode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(dynamic raw);
 
-  @protected
-  BufferStats dco_decode_buffer_stats(dynamic raw);
+@protected FutureOr<void> Function(IngestProgress) dco_decode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(dynamic raw);
 
-  @protected
-  ChunkData dco_decode_chunk_data(dynamic raw);
+@protected FutureOr<List<Float32List>> Function(List<String>) dco_decode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(dynamic raw);
 
-  @protected
-  ChunkForReembedding dco_decode_chunk_for_reembedding(dynamic raw);
+@protected FutureOr<void> Function(ReembeddingJob) dco_decode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(dynamic raw);
 
-  @protected
-  ChunkSearchResult dco_decode_chunk_search_result(dynamic raw);
+@protected Object dco_decode_DartOpaque(dynamic raw);
 
-  @protected
-  ChunkType dco_decode_chunk_type(dynamic raw);
+@protected Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw);
 
-  @protected
-  CompressedText dco_decode_compressed_text(dynamic raw);
+@protected MemoryStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(dynamic raw);
 
-  @protected
-  CompressionOptions dco_decode_compression_options(dynamic raw);
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  EmbeddingPoint dco_decode_embedding_point(dynamic raw);
+@protected RustStreamSink<HybridSearchEvent> dco_decode_StreamSink_hybrid_search_event_Sse(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+@protected RustStreamSink<ProgressEvent> dco_decode_StreamSink_progress_event_Sse(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  HnswSearchResult dco_decode_hnsw_search_result(dynamic raw);
+@protected ChunkStore dco_decode_TraitDef_ChunkStore(dynamic raw);
 
-  @protected
-  HybridSearchResult dco_decode_hybrid_search_result(dynamic raw);
+@protected AddDocumentResult dco_decode_add_document_result(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected AddSourceResult dco_decode_add_source_result(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected AuditIssue dco_decode_audit_issue(dynamic raw);
 
-  @protected
-  IncrementalSearchResult dco_decode_incremental_search_result(dynamic raw);
+@protected AuditReport dco_decode_audit_report(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected BackgroundTask dco_decode_background_task(dynamic raw);
 
-  @protected
-  List<Bm25SearchResult> dco_decode_list_bm_25_search_result(dynamic raw);
+@protected BackgroundTaskKind dco_decode_background_task_kind(dynamic raw);
 
-  @protected
-  List<ChunkData> dco_decode_list_chunk_data(dynamic raw);
+@protected BenchmarkConfig dco_decode_benchmark_config(dynamic raw);
 
-  @protected
-  List<ChunkForReembedding> dco_decode_list_chunk_for_reembedding(dynamic raw);
+@protected BenchmarkReport dco_decode_benchmark_report(dynamic raw);
 
-  @protected
-  List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw);
+@protected BenchmarkStage dco_decode_benchmark_stage(dynamic raw);
 
-  @protected
-  List<HnswSearchResult> dco_decode_list_hnsw_search_result(dynamic raw);
+@protected Bm25Config dco_decode_bm_25_config(dynamic raw);
 
-  @protected
-  List<HybridSearchResult> dco_decode_list_hybrid_search_result(dynamic raw);
+@protected Bm25FuzzyConfig dco_decode_bm_25_fuzzy_config(dynamic raw);
 
-  @protected
-  List<IncrementalSearchResult> dco_decode_list_incremental_search_result(
-    dynamic raw,
-  );
+@protected Bm25IndexOptions dco_decode_bm_25_index_options(dynamic raw);
 
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+@protected Bm25SearchResult dco_decode_bm_25_search_result(dynamic raw);
 
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+@protected Bm25Tokenization dco_decode_bm_25_tokenization(dynamic raw);
 
-  @protected
-  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+@protected Bm25Variant dco_decode_bm_25_variant(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+@protected AuditReport dco_decode_box_autoadd_audit_report(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected BackgroundTask dco_decode_box_autoadd_background_task(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected BenchmarkConfig dco_decode_box_autoadd_benchmark_config(dynamic raw);
 
-  @protected
-  List<(PlatformInt64, Float32List)>
-  dco_decode_list_record_i_64_list_prim_f_32_strict(dynamic raw);
+@protected Bm25Config dco_decode_box_autoadd_bm_25_config(dynamic raw);
 
-  @protected
-  List<(PlatformInt64, String)> dco_decode_list_record_i_64_string(dynamic raw);
+@protected Bm25FuzzyConfig dco_decode_box_autoadd_bm_25_fuzzy_config(dynamic raw);
 
-  @protected
-  List<SemanticChunk> dco_decode_list_semantic_chunk(dynamic raw);
+@protected Bm25IndexOptions dco_decode_box_autoadd_bm_25_index_options(dynamic raw);
 
-  @protected
-  List<SourceEntry> dco_decode_list_source_entry(dynamic raw);
+@protected ChunkingConfig dco_decode_box_autoadd_chunking_config(dynamic raw);
 
-  @protected
-  List<StructuredChunk> dco_decode_list_structured_chunk(dynamic raw);
+@protected ChunkingStrategy dco_decode_box_autoadd_chunking_strategy(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected CompressionOptions dco_decode_box_autoadd_compression_options(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+@protected DbRetryConfig dco_decode_box_autoadd_db_retry_config(dynamic raw);
 
-  @protected
-  (int, int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32_u_32(
-    dynamic raw,
-  );
+@protected DedupeConfig dco_decode_box_autoadd_dedupe_config(dynamic raw);
 
-  @protected
-  RrfConfig? dco_decode_opt_box_autoadd_rrf_config(dynamic raw);
+@protected DistanceMetric dco_decode_box_autoadd_distance_metric(dynamic raw);
 
-  @protected
-  SearchFilter? dco_decode_opt_box_autoadd_search_filter(dynamic raw);
+@protected ExpansionConfig dco_decode_box_autoadd_expansion_config(dynamic raw);
 
-  @protected
-  Int64List? dco_decode_opt_list_prim_i_64_strict(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  ParsedIntent dco_decode_parsed_intent(dynamic raw);
+@protected HnswBuildConfig dco_decode_box_autoadd_hnsw_build_config(dynamic raw);
 
-  @protected
-  RagError dco_decode_rag_error(dynamic raw);
+@protected HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw);
 
-  @protected
-  (PlatformInt64, Float32List) dco_decode_record_i_64_list_prim_f_32_strict(
-    dynamic raw,
-  );
+@protected HnswIndexStats dco_decode_box_autoadd_hnsw_index_stats(dynamic raw);
 
-  @protected
-  (PlatformInt64, String) dco_decode_record_i_64_string(dynamic raw);
+@protected HybridSearchOptions dco_decode_box_autoadd_hybrid_search_options(dynamic raw);
 
-  @protected
-  (int, int, int) dco_decode_record_u_32_u_32_u_32(dynamic raw);
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  RrfConfig dco_decode_rrf_config(dynamic raw);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  SearchFilter dco_decode_search_filter(dynamic raw);
+@protected IncrementalConfig dco_decode_box_autoadd_incremental_config(dynamic raw);
 
-  @protected
-  SemanticChunk dco_decode_semantic_chunk(dynamic raw);
+@protected IndexConfig dco_decode_box_autoadd_index_config(dynamic raw);
 
-  @protected
-  SourceEntry dco_decode_source_entry(dynamic raw);
+@protected IngestInput dco_decode_box_autoadd_ingest_input(dynamic raw);
 
-  @protected
-  SourceStats dco_decode_source_stats(dynamic raw);
+@protected IngestOptions dco_decode_box_autoadd_ingest_options(dynamic raw);
 
-  @protected
-  StructuredChunk dco_decode_structured_chunk(dynamic raw);
+@protected LanguageAnalyzer dco_decode_box_autoadd_language_analyzer(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected LastRetrieval dco_decode_box_autoadd_last_retrieval(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected MaintenanceConfig dco_decode_box_autoadd_maintenance_config(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected MaintenanceReport dco_decode_box_autoadd_maintenance_report(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected NamedIndexConfig dco_decode_box_autoadd_named_index_config(dynamic raw);
 
-  @protected
-  UserIntent dco_decode_user_intent(dynamic raw);
+@protected QueryDecomposition dco_decode_box_autoadd_query_decomposition(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected QueryNormalizationConfig dco_decode_box_autoadd_query_normalization_config(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected RagError dco_decode_box_autoadd_rag_error(dynamic raw);
 
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-    SseDeserializer deserializer,
-  );
+@protected (int,int,int) dco_decode_box_autoadd_record_u_32_u_32_u_32(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected ReembeddingJob dco_decode_box_autoadd_reembedding_job(dynamic raw);
 
-  @protected
-  AddDocumentResult sse_decode_add_document_result(
-    SseDeserializer deserializer,
-  );
+@protected RetrievalPreset dco_decode_box_autoadd_retrieval_preset(dynamic raw);
 
-  @protected
-  AddSourceResult sse_decode_add_source_result(SseDeserializer deserializer);
+@protected RrfConfig dco_decode_box_autoadd_rrf_config(dynamic raw);
 
-  @protected
-  Bm25SearchResult sse_decode_bm_25_search_result(SseDeserializer deserializer);
+@protected SearchDiagnostics dco_decode_box_autoadd_search_diagnostics(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected SearchFilter dco_decode_box_autoadd_search_filter(dynamic raw);
 
-  @protected
-  CompressionOptions sse_decode_box_autoadd_compression_options(
-    SseDeserializer deserializer,
-  );
+@protected SearchSnippet dco_decode_box_autoadd_search_snippet(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+@protected SearchTrace dco_decode_box_autoadd_search_trace(dynamic raw);
 
-  @protected
-  (int, int, int) sse_decode_box_autoadd_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  );
+@protected SourceIndexConfig dco_decode_box_autoadd_source_index_config(dynamic raw);
 
-  @protected
-  RrfConfig sse_decode_box_autoadd_rrf_config(SseDeserializer deserializer);
+@protected StoredChunk dco_decode_box_autoadd_stored_chunk(dynamic raw);
 
-  @protected
-  SearchFilter sse_decode_box_autoadd_search_filter(
-    SseDeserializer deserializer,
-  );
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  UserIntent sse_decode_box_autoadd_user_intent(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  BufferStats sse_decode_buffer_stats(SseDeserializer deserializer);
+@protected UserIntent dco_decode_box_autoadd_user_intent(dynamic raw);
 
-  @protected
-  ChunkData sse_decode_chunk_data(SseDeserializer deserializer);
+@protected BufferStats dco_decode_buffer_stats(dynamic raw);
 
-  @protected
-  ChunkForReembedding sse_decode_chunk_for_reembedding(
-    SseDeserializer deserializer,
-  );
+@protected BufferTable dco_decode_buffer_table(dynamic raw);
 
-  @protected
-  ChunkSearchResult sse_decode_chunk_search_result(
-    SseDeserializer deserializer,
-  );
+@protected ChunkData dco_decode_chunk_data(dynamic raw);
 
-  @protected
-  ChunkType sse_decode_chunk_type(SseDeserializer deserializer);
+@protected ChunkForReembedding dco_decode_chunk_for_reembedding(dynamic raw);
 
-  @protected
-  CompressedText sse_decode_compressed_text(SseDeserializer deserializer);
+@protected ChunkIndexSource dco_decode_chunk_index_source(dynamic raw);
 
-  @protected
-  CompressionOptions sse_decode_compression_options(
-    SseDeserializer deserializer,
-  );
+@protected ChunkMeta dco_decode_chunk_meta(dynamic raw);
 
-  @protected
-  EmbeddingPoint sse_decode_embedding_point(SseDeserializer deserializer);
+@protected ChunkSearchPage dco_decode_chunk_search_page(dynamic raw);
 
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected ChunkSearchResult dco_decode_chunk_search_result(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected ChunkStats dco_decode_chunk_stats(dynamic raw);
 
-  @protected
-  HnswSearchResult sse_decode_hnsw_search_result(SseDeserializer deserializer);
+@protected ChunkType dco_decode_chunk_type(dynamic raw);
 
-  @protected
-  HybridSearchResult sse_decode_hybrid_search_result(
-    SseDeserializer deserializer,
-  );
+@protected ChunkingConfig dco_decode_chunking_config(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected ChunkingStrategy dco_decode_chunking_strategy(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected ClassificationRule dco_decode_classification_rule(dynamic raw);
 
-  @protected
-  IncrementalSearchResult sse_decode_incremental_search_result(
-    SseDeserializer deserializer,
-  );
+@protected CompareSearchResult dco_decode_compare_search_result(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected CompressedText dco_decode_compressed_text(dynamic raw);
 
-  @protected
-  List<Bm25SearchResult> sse_decode_list_bm_25_search_result(
-    SseDeserializer deserializer,
-  );
+@protected CompressionOptions dco_decode_compression_options(dynamic raw);
 
-  @protected
-  List<ChunkData> sse_decode_list_chunk_data(SseDeserializer deserializer);
+@protected CorpusCount dco_decode_corpus_count(dynamic raw);
 
-  @protected
-  List<ChunkForReembedding> sse_decode_list_chunk_for_reembedding(
-    SseDeserializer deserializer,
-  );
+@protected CorpusReport dco_decode_corpus_report(dynamic raw);
 
-  @protected
-  List<ChunkSearchResult> sse_decode_list_chunk_search_result(
-    SseDeserializer deserializer,
-  );
+@protected DbRetryConfig dco_decode_db_retry_config(dynamic raw);
 
-  @protected
-  List<HnswSearchResult> sse_decode_list_hnsw_search_result(
-    SseDeserializer deserializer,
-  );
+@protected DedupeConfig dco_decode_dedupe_config(dynamic raw);
 
-  @protected
-  List<HybridSearchResult> sse_decode_list_hybrid_search_result(
-    SseDeserializer deserializer,
-  );
+@protected DedupeReport dco_decode_dedupe_report(dynamic raw);
 
-  @protected
-  List<IncrementalSearchResult> sse_decode_list_incremental_search_result(
-    SseDeserializer deserializer,
-  );
+@protected DistanceMetric dco_decode_distance_metric(dynamic raw);
 
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+@protected DuplicateChunk dco_decode_duplicate_chunk(dynamic raw);
 
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+@protected EmbeddingBatchProgress dco_decode_embedding_batch_progress(dynamic raw);
 
-  @protected
-  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+@protected EmbeddingPoint dco_decode_embedding_point(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+@protected EmbeddingStorageStats dco_decode_embedding_storage_stats(dynamic raw);
 
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+@protected EngineInfo dco_decode_engine_info(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected EngineMetrics dco_decode_engine_metrics(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected EntityFacet dco_decode_entity_facet(dynamic raw);
 
-  @protected
-  List<(PlatformInt64, Float32List)>
-  sse_decode_list_record_i_64_list_prim_f_32_strict(
-    SseDeserializer deserializer,
-  );
+@protected ExpandedSearchResult dco_decode_expanded_search_result(dynamic raw);
 
-  @protected
-  List<(PlatformInt64, String)> sse_decode_list_record_i_64_string(
-    SseDeserializer deserializer,
-  );
+@protected ExpansionConfig dco_decode_expansion_config(dynamic raw);
 
-  @protected
-  List<SemanticChunk> sse_decode_list_semantic_chunk(
-    SseDeserializer deserializer,
-  );
+@protected ExtractedEntity dco_decode_extracted_entity(dynamic raw);
 
-  @protected
-  List<SourceEntry> sse_decode_list_source_entry(SseDeserializer deserializer);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  List<StructuredChunk> sse_decode_list_structured_chunk(
-    SseDeserializer deserializer,
-  );
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected FusionStrategy dco_decode_fusion_strategy(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+@protected GazetteerEntry dco_decode_gazetteer_entry(dynamic raw);
 
-  @protected
-  (int, int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  );
+@protected GraphEdge dco_decode_graph_edge(dynamic raw);
 
-  @protected
-  RrfConfig? sse_decode_opt_box_autoadd_rrf_config(
-    SseDeserializer deserializer,
-  );
+@protected GraphNeighborhood dco_decode_graph_neighborhood(dynamic raw);
 
-  @protected
-  SearchFilter? sse_decode_opt_box_autoadd_search_filter(
-    SseDeserializer deserializer,
-  );
+@protected HighlightSpan dco_decode_highlight_span(dynamic raw);
 
-  @protected
-  Int64List? sse_decode_opt_list_prim_i_64_strict(SseDeserializer deserializer);
+@protected HnswBuildConfig dco_decode_hnsw_build_config(dynamic raw);
 
-  @protected
-  ParsedIntent sse_decode_parsed_intent(SseDeserializer deserializer);
+@protected HnswConfig dco_decode_hnsw_config(dynamic raw);
 
-  @protected
-  RagError sse_decode_rag_error(SseDeserializer deserializer);
+@protected HnswIndexStats dco_decode_hnsw_index_stats(dynamic raw);
 
-  @protected
-  (PlatformInt64, Float32List) sse_decode_record_i_64_list_prim_f_32_strict(
-    SseDeserializer deserializer,
-  );
+@protected HnswSearchResult dco_decode_hnsw_search_result(dynamic raw);
 
-  @protected
-  (PlatformInt64, String) sse_decode_record_i_64_string(
-    SseDeserializer deserializer,
-  );
+@protected HybridSearchEvent dco_decode_hybrid_search_event(dynamic raw);
 
-  @protected
-  (int, int, int) sse_decode_record_u_32_u_32_u_32(
-    SseDeserializer deserializer,
-  );
+@protected HybridSearchOptions dco_decode_hybrid_search_options(dynamic raw);
 
-  @protected
-  RrfConfig sse_decode_rrf_config(SseDeserializer deserializer);
-
-  @protected
-  SearchFilter sse_decode_search_filter(SseDeserializer deserializer);
-
-  @protected
-  SemanticChunk sse_decode_semantic_chunk(SseDeserializer deserializer);
+@protected HybridSearchResponse dco_decode_hybrid_search_response(dynamic raw);
 
-  @protected
-  SourceEntry sse_decode_source_entry(SseDeserializer deserializer);
+@protected HybridSearchResult dco_decode_hybrid_search_result(dynamic raw);
 
-  @protected
-  SourceStats sse_decode_source_stats(SseDeserializer deserializer);
-
-  @protected
-  StructuredChunk sse_decode_structured_chunk(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  UserIntent sse_decode_user_intent(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-    RustStreamSink<String> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_add_document_result(
-    AddDocumentResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_add_source_result(
-    AddSourceResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_bm_25_search_result(
-    Bm25SearchResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_compression_options(
-    CompressionOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_record_u_32_u_32_u_32(
-    (int, int, int) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_rrf_config(
-    RrfConfig self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_search_filter(
-    SearchFilter self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_user_intent(
-    UserIntent self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_buffer_stats(BufferStats self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_chunk_data(ChunkData self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_chunk_for_reembedding(
-    ChunkForReembedding self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_chunk_search_result(
-    ChunkSearchResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_chunk_type(ChunkType self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_compressed_text(
-    CompressedText self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_compression_options(
-    CompressionOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_embedding_point(
-    EmbeddingPoint self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_hnsw_search_result(
-    HnswSearchResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_hybrid_search_result(
-    HybridSearchResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_incremental_search_result(
-    IncrementalSearchResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_bm_25_search_result(
-    List<Bm25SearchResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_chunk_data(
-    List<ChunkData> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_chunk_for_reembedding(
-    List<ChunkForReembedding> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_chunk_search_result(
-    List<ChunkSearchResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_hnsw_search_result(
-    List<HnswSearchResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_hybrid_search_result(
-    List<HybridSearchResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_incremental_search_result(
-    List<IncrementalSearchResult> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-    List<double> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_i_64_strict(
-    Int64List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_32_loose(
-    List<int> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_record_i_64_list_prim_f_32_strict(
-    List<(PlatformInt64, Float32List)> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_record_i_64_string(
-    List<(PlatformInt64, String)> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_semantic_chunk(
-    List<SemanticChunk> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_source_entry(
-    List<SourceEntry> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_structured_chunk(
-    List<StructuredChunk> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_record_u_32_u_32_u_32(
-    (int, int, int)? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_rrf_config(
-    RrfConfig? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_search_filter(
-    SearchFilter? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_list_prim_i_64_strict(
-    Int64List? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_parsed_intent(ParsedIntent self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_rag_error(RagError self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_record_i_64_list_prim_f_32_strict(
-    (PlatformInt64, Float32List) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_record_i_64_string(
-    (PlatformInt64, String) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_record_u_32_u_32_u_32(
-    (int, int, int) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_rrf_config(RrfConfig self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_search_filter(SearchFilter self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_semantic_chunk(SemanticChunk self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_source_entry(SourceEntry self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_source_stats(SourceStats self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_structured_chunk(
-    StructuredChunk self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_user_intent(UserIntent self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
+@protected int dco_decode_i_32(dynamic raw);
 
-// Section: wire_class
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected IncrementalConfig dco_decode_incremental_config(dynamic raw);
+
+@protected IncrementalSearchResult dco_decode_incremental_search_result(dynamic raw);
+
+@protected IndexConfig dco_decode_index_config(dynamic raw);
+
+@protected IndexConsistency dco_decode_index_consistency(dynamic raw);
+
+@protected IndexDivergence dco_decode_index_divergence(dynamic raw);
+
+@protected IndexEvaluation dco_decode_index_evaluation(dynamic raw);
+
+@protected IndexFreshness dco_decode_index_freshness(dynamic raw);
+
+@protected IndexStaleness dco_decode_index_staleness(dynamic raw);
+
+@protected IngestInput dco_decode_ingest_input(dynamic raw);
+
+@protected IngestJob dco_decode_ingest_job(dynamic raw);
+
+@protected IngestOptions dco_decode_ingest_options(dynamic raw);
+
+@protected IngestProgress dco_decode_ingest_progress(dynamic raw);
+
+@protected IngestReport dco_decode_ingest_report(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected KeywordBackend dco_decode_keyword_backend(dynamic raw);
+
+@protected KnowledgeTriple dco_decode_knowledge_triple(dynamic raw);
+
+@protected LanguageAnalyzer dco_decode_language_analyzer(dynamic raw);
+
+@protected LastRetrieval dco_decode_last_retrieval(dynamic raw);
+
+@protected LatencyBucket dco_decode_latency_bucket(dynamic raw);
+
+@protected LengthBucket dco_decode_length_bucket(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AuditIssue> dco_decode_list_audit_issue(dynamic raw);
+
+@protected List<BackgroundTask> dco_decode_list_background_task(dynamic raw);
+
+@protected List<BenchmarkStage> dco_decode_list_benchmark_stage(dynamic raw);
+
+@protected List<Bm25SearchResult> dco_decode_list_bm_25_search_result(dynamic raw);
+
+@protected List<ChunkData> dco_decode_list_chunk_data(dynamic raw);
+
+@protected List<ChunkForReembedding> dco_decode_list_chunk_for_reembedding(dynamic raw);
+
+@protected List<ChunkMeta> dco_decode_list_chunk_meta(dynamic raw);
+
+@protected List<ChunkSearchResult> dco_decode_list_chunk_search_result(dynamic raw);
+
+@protected List<ClassificationRule> dco_decode_list_classification_rule(dynamic raw);
+
+@protected List<CompareSearchResult> dco_decode_list_compare_search_result(dynamic raw);
+
+@protected List<CorpusCount> dco_decode_list_corpus_count(dynamic raw);
+
+@protected List<DuplicateChunk> dco_decode_list_duplicate_chunk(dynamic raw);
+
+@protected List<EntityFacet> dco_decode_list_entity_facet(dynamic raw);
+
+@protected List<ExpandedSearchResult> dco_decode_list_expanded_search_result(dynamic raw);
+
+@protected List<ExtractedEntity> dco_decode_list_extracted_entity(dynamic raw);
+
+@protected List<GazetteerEntry> dco_decode_list_gazetteer_entry(dynamic raw);
+
+@protected List<GraphEdge> dco_decode_list_graph_edge(dynamic raw);
+
+@protected List<HighlightSpan> dco_decode_list_highlight_span(dynamic raw);
+
+@protected List<HnswSearchResult> dco_decode_list_hnsw_search_result(dynamic raw);
+
+@protected List<HybridSearchResult> dco_decode_list_hybrid_search_result(dynamic raw);
+
+@protected List<IncrementalSearchResult> dco_decode_list_incremental_search_result(dynamic raw);
+
+@protected List<IngestJob> dco_decode_list_ingest_job(dynamic raw);
+
+@protected List<KnowledgeTriple> dco_decode_list_knowledge_triple(dynamic raw);
+
+@protected List<LanguageAnalyzer> dco_decode_list_language_analyzer(dynamic raw);
+
+@protected List<LatencyBucket> dco_decode_list_latency_bucket(dynamic raw);
+
+@protected List<LengthBucket> dco_decode_list_length_bucket(dynamic raw);
+
+@protected List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+
+@protected List<MemoryMessage> dco_decode_list_memory_message(dynamic raw);
+
+@protected List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw);
+
+@protected List<NamedBm25IndexInfo> dco_decode_list_named_bm_25_index_info(dynamic raw);
+
+@protected List<NamedIndexInfo> dco_decode_list_named_index_info(dynamic raw);
+
+@protected List<PanicEvent> dco_decode_list_panic_event(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_64_loose(dynamic raw);
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QueryKeyword> dco_decode_list_query_keyword(dynamic raw);
+
+@protected List<QuerySuggestion> dco_decode_list_query_suggestion(dynamic raw);
+
+@protected List<RankedHit> dco_decode_list_ranked_hit(dynamic raw);
+
+@protected List<(PlatformInt64,Float32List)> dco_decode_list_record_i_64_list_prim_f_32_strict(dynamic raw);
+
+@protected List<(PlatformInt64,String)> dco_decode_list_record_i_64_string(dynamic raw);
+
+@protected List<(String,double)> dco_decode_list_record_string_f_64(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<RetrievalCitation> dco_decode_list_retrieval_citation(dynamic raw);
+
+@protected List<SearchSpan> dco_decode_list_search_span(dynamic raw);
+
+@protected List<SemanticChunk> dco_decode_list_semantic_chunk(dynamic raw);
+
+@protected List<SnapshotFile> dco_decode_list_snapshot_file(dynamic raw);
+
+@protected List<SourceCorpusStats> dco_decode_list_source_corpus_stats(dynamic raw);
+
+@protected List<SourceEntry> dco_decode_list_source_entry(dynamic raw);
+
+@protected List<SourceGroup> dco_decode_list_source_group(dynamic raw);
+
+@protected List<StoreSearchHit> dco_decode_list_store_search_hit(dynamic raw);
+
+@protected List<StructuredChunk> dco_decode_list_structured_chunk(dynamic raw);
+
+@protected List<SynonymEntry> dco_decode_list_synonym_entry(dynamic raw);
+
+@protected MaintenanceConfig dco_decode_maintenance_config(dynamic raw);
+
+@protected MaintenanceReport dco_decode_maintenance_report(dynamic raw);
+
+@protected MemoryMessage dco_decode_memory_message(dynamic raw);
+
+@protected MemoryProfile dco_decode_memory_profile(dynamic raw);
+
+@protected MemorySearchResult dco_decode_memory_search_result(dynamic raw);
+
+@protected MemoryUsage dco_decode_memory_usage(dynamic raw);
+
+@protected NamedBm25IndexInfo dco_decode_named_bm_25_index_info(dynamic raw);
+
+@protected NamedIndexConfig dco_decode_named_index_config(dynamic raw);
+
+@protected NamedIndexInfo dco_decode_named_index_info(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected AuditReport? dco_decode_opt_box_autoadd_audit_report(dynamic raw);
+
+@protected BackgroundTask? dco_decode_opt_box_autoadd_background_task(dynamic raw);
+
+@protected Bm25FuzzyConfig? dco_decode_opt_box_autoadd_bm_25_fuzzy_config(dynamic raw);
+
+@protected ChunkingStrategy? dco_decode_opt_box_autoadd_chunking_strategy(dynamic raw);
+
+@protected DistanceMetric? dco_decode_opt_box_autoadd_distance_metric(dynamic raw);
+
+@protected ExpansionConfig? dco_decode_opt_box_autoadd_expansion_config(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected HnswBuildConfig? dco_decode_opt_box_autoadd_hnsw_build_config(dynamic raw);
+
+@protected HnswIndexStats? dco_decode_opt_box_autoadd_hnsw_index_stats(dynamic raw);
+
+@protected HybridSearchOptions? dco_decode_opt_box_autoadd_hybrid_search_options(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected LastRetrieval? dco_decode_opt_box_autoadd_last_retrieval(dynamic raw);
+
+@protected MaintenanceReport? dco_decode_opt_box_autoadd_maintenance_report(dynamic raw);
+
+@protected (int,int,int)? dco_decode_opt_box_autoadd_record_u_32_u_32_u_32(dynamic raw);
+
+@protected ReembeddingJob? dco_decode_opt_box_autoadd_reembedding_job(dynamic raw);
+
+@protected RrfConfig? dco_decode_opt_box_autoadd_rrf_config(dynamic raw);
+
+@protected SearchDiagnostics? dco_decode_opt_box_autoadd_search_diagnostics(dynamic raw);
+
+@protected SearchFilter? dco_decode_opt_box_autoadd_search_filter(dynamic raw);
+
+@protected SearchSnippet? dco_decode_opt_box_autoadd_search_snippet(dynamic raw);
+
+@protected SearchTrace? dco_decode_opt_box_autoadd_search_trace(dynamic raw);
+
+@protected StoredChunk? dco_decode_opt_box_autoadd_stored_chunk(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected Int64List? dco_decode_opt_list_prim_i_64_strict(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String,String)>? dco_decode_opt_list_record_string_string(dynamic raw);
+
+@protected List<SourceGroup>? dco_decode_opt_list_source_group(dynamic raw);
+
+@protected PanicEvent dco_decode_panic_event(dynamic raw);
+
+@protected ParsedIntent dco_decode_parsed_intent(dynamic raw);
+
+@protected ProgressEvent dco_decode_progress_event(dynamic raw);
+
+@protected Quantization dco_decode_quantization(dynamic raw);
+
+@protected QueryDecomposition dco_decode_query_decomposition(dynamic raw);
+
+@protected QueryKeyword dco_decode_query_keyword(dynamic raw);
+
+@protected QueryNormalizationConfig dco_decode_query_normalization_config(dynamic raw);
+
+@protected QuerySuggestion dco_decode_query_suggestion(dynamic raw);
+
+@protected RagError dco_decode_rag_error(dynamic raw);
+
+@protected RankedHit dco_decode_ranked_hit(dynamic raw);
+
+@protected (PlatformInt64,Float32List) dco_decode_record_i_64_list_prim_f_32_strict(dynamic raw);
+
+@protected (PlatformInt64,String) dco_decode_record_i_64_string(dynamic raw);
+
+@protected (String,double) dco_decode_record_string_f_64(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected (int,int,int) dco_decode_record_u_32_u_32_u_32(dynamic raw);
+
+@protected ReembeddingJob dco_decode_reembedding_job(dynamic raw);
+
+@protected RetrievalCitation dco_decode_retrieval_citation(dynamic raw);
+
+@protected RetrievalPreset dco_decode_retrieval_preset(dynamic raw);
+
+@protected RewrittenQuery dco_decode_rewritten_query(dynamic raw);
+
+@protected RrfConfig dco_decode_rrf_config(dynamic raw);
+
+@protected ScoreNormalization dco_decode_score_normalization(dynamic raw);
+
+@protected SearchDiagnostics dco_decode_search_diagnostics(dynamic raw);
+
+@protected SearchFeedbackAction dco_decode_search_feedback_action(dynamic raw);
+
+@protected SearchFilter dco_decode_search_filter(dynamic raw);
+
+@protected SearchSnippet dco_decode_search_snippet(dynamic raw);
+
+@protected SearchSpan dco_decode_search_span(dynamic raw);
+
+@protected SearchTrace dco_decode_search_trace(dynamic raw);
+
+@protected SemanticChunk dco_decode_semantic_chunk(dynamic raw);
+
+@protected SnapshotFile dco_decode_snapshot_file(dynamic raw);
+
+@protected SnapshotManifest dco_decode_snapshot_manifest(dynamic raw);
+
+@protected SourceCorpusStats dco_decode_source_corpus_stats(dynamic raw);
+
+@protected SourceEntry dco_decode_source_entry(dynamic raw);
+
+@protected SourceGroup dco_decode_source_group(dynamic raw);
+
+@protected SourceIndexConfig dco_decode_source_index_config(dynamic raw);
+
+@protected SourceStats dco_decode_source_stats(dynamic raw);
+
+@protected SourceUpdate dco_decode_source_update(dynamic raw);
+
+@protected StagedIngest dco_decode_staged_ingest(dynamic raw);
+
+@protected StoreSearchHit dco_decode_store_search_hit(dynamic raw);
+
+@protected StoredChunk dco_decode_stored_chunk(dynamic raw);
+
+@protected StructuredChunk dco_decode_structured_chunk(dynamic raw);
+
+@protected SynonymEntry dco_decode_synonym_entry(dynamic raw);
+
+@protected TracedHybridSearch dco_decode_traced_hybrid_search(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UserIntent dco_decode_user_intent(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected MemoryStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer);
+
+@protected MemoryStore sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer);
+
+@protected MemoryStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected Map<String, double> sse_decode_Map_String_f_64_None(SseDeserializer deserializer);
+
+@protected MemoryStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<HybridSearchEvent> sse_decode_StreamSink_hybrid_search_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ProgressEvent> sse_decode_StreamSink_progress_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AddDocumentResult sse_decode_add_document_result(SseDeserializer deserializer);
+
+@protected AddSourceResult sse_decode_add_source_result(SseDeserializer deserializer);
+
+@protected AuditIssue sse_decode_audit_issue(SseDeserializer deserializer);
+
+@protected AuditReport sse_decode_audit_report(SseDeserializer deserializer);
+
+@protected BackgroundTask sse_decode_background_task(SseDeserializer deserializer);
+
+@protected BackgroundTaskKind sse_decode_background_task_kind(SseDeserializer deserializer);
+
+@protected BenchmarkConfig sse_decode_benchmark_config(SseDeserializer deserializer);
+
+@protected BenchmarkReport sse_decode_benchmark_report(SseDeserializer deserializer);
+
+@protected BenchmarkStage sse_decode_benchmark_stage(SseDeserializer deserializer);
+
+@protected Bm25Config sse_decode_bm_25_config(SseDeserializer deserializer);
+
+@protected Bm25FuzzyConfig sse_decode_bm_25_fuzzy_config(SseDeserializer deserializer);
+
+@protected Bm25IndexOptions sse_decode_bm_25_index_options(SseDeserializer deserializer);
+
+@protected Bm25SearchResult sse_decode_bm_25_search_result(SseDeserializer deserializer);
+
+@protected Bm25Tokenization sse_decode_bm_25_tokenization(SseDeserializer deserializer);
+
+@protected Bm25Variant sse_decode_bm_25_variant(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AuditReport sse_decode_box_autoadd_audit_report(SseDeserializer deserializer);
+
+@protected BackgroundTask sse_decode_box_autoadd_background_task(SseDeserializer deserializer);
+
+@protected BenchmarkConfig sse_decode_box_autoadd_benchmark_config(SseDeserializer deserializer);
+
+@protected Bm25Config sse_decode_box_autoadd_bm_25_config(SseDeserializer deserializer);
+
+@protected Bm25FuzzyConfig sse_decode_box_autoadd_bm_25_fuzzy_config(SseDeserializer deserializer);
+
+@protected Bm25IndexOptions sse_decode_box_autoadd_bm_25_index_options(SseDeserializer deserializer);
+
+@protected ChunkingConfig sse_decode_box_autoadd_chunking_config(SseDeserializer deserializer);
+
+@protected ChunkingStrategy sse_decode_box_autoadd_chunking_strategy(SseDeserializer deserializer);
+
+@protected CompressionOptions sse_decode_box_autoadd_compression_options(SseDeserializer deserializer);
+
+@protected DbRetryConfig sse_decode_box_autoadd_db_retry_config(SseDeserializer deserializer);
+
+@protected DedupeConfig sse_decode_box_autoadd_dedupe_config(SseDeserializer deserializer);
+
+@protected DistanceMetric sse_decode_box_autoadd_distance_metric(SseDeserializer deserializer);
+
+@protected ExpansionConfig sse_decode_box_autoadd_expansion_config(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HnswBuildConfig sse_decode_box_autoadd_hnsw_build_config(SseDeserializer deserializer);
+
+@protected HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer);
+
+@protected HnswIndexStats sse_decode_box_autoadd_hnsw_index_stats(SseDeserializer deserializer);
+
+@protected HybridSearchOptions sse_decode_box_autoadd_hybrid_search_options(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IncrementalConfig sse_decode_box_autoadd_incremental_config(SseDeserializer deserializer);
+
+@protected IndexConfig sse_decode_box_autoadd_index_config(SseDeserializer deserializer);
+
+@protected IngestInput sse_decode_box_autoadd_ingest_input(SseDeserializer deserializer);
+
+@protected IngestOptions sse_decode_box_autoadd_ingest_options(SseDeserializer deserializer);
+
+@protected LanguageAnalyzer sse_decode_box_autoadd_language_analyzer(SseDeserializer deserializer);
+
+@protected LastRetrieval sse_decode_box_autoadd_last_retrieval(SseDeserializer deserializer);
+
+@protected MaintenanceConfig sse_decode_box_autoadd_maintenance_config(SseDeserializer deserializer);
+
+@protected MaintenanceReport sse_decode_box_autoadd_maintenance_report(SseDeserializer deserializer);
+
+@protected NamedIndexConfig sse_decode_box_autoadd_named_index_config(SseDeserializer deserializer);
+
+@protected QueryDecomposition sse_decode_box_autoadd_query_decomposition(SseDeserializer deserializer);
+
+@protected QueryNormalizationConfig sse_decode_box_autoadd_query_normalization_config(SseDeserializer deserializer);
+
+@protected RagError sse_decode_box_autoadd_rag_error(SseDeserializer deserializer);
+
+@protected (int,int,int) sse_decode_box_autoadd_record_u_32_u_32_u_32(SseDeserializer deserializer);
+
+@protected ReembeddingJob sse_decode_box_autoadd_reembedding_job(SseDeserializer deserializer);
+
+@protected RetrievalPreset sse_decode_box_autoadd_retrieval_preset(SseDeserializer deserializer);
+
+@protected RrfConfig sse_decode_box_autoadd_rrf_config(SseDeserializer deserializer);
+
+@protected SearchDiagnostics sse_decode_box_autoadd_search_diagnostics(SseDeserializer deserializer);
+
+@protected SearchFilter sse_decode_box_autoadd_search_filter(SseDeserializer deserializer);
+
+@protected SearchSnippet sse_decode_box_autoadd_search_snippet(SseDeserializer deserializer);
+
+@protected SearchTrace sse_decode_box_autoadd_search_trace(SseDeserializer deserializer);
+
+@protected SourceIndexConfig sse_decode_box_autoadd_source_index_config(SseDeserializer deserializer);
+
+@protected StoredChunk sse_decode_box_autoadd_stored_chunk(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UserIntent sse_decode_box_autoadd_user_intent(SseDeserializer deserializer);
+
+@protected BufferStats sse_decode_buffer_stats(SseDeserializer deserializer);
+
+@protected BufferTable sse_decode_buffer_table(SseDeserializer deserializer);
+
+@protected ChunkData sse_decode_chunk_data(SseDeserializer deserializer);
+
+@protected ChunkForReembedding sse_decode_chunk_for_reembedding(SseDeserializer deserializer);
+
+@protected ChunkIndexSource sse_decode_chunk_index_source(SseDeserializer deserializer);
+
+@protected ChunkMeta sse_decode_chunk_meta(SseDeserializer deserializer);
+
+@protected ChunkSearchPage sse_decode_chunk_search_page(SseDeserializer deserializer);
+
+@protected ChunkSearchResult sse_decode_chunk_search_result(SseDeserializer deserializer);
+
+@protected ChunkStats sse_decode_chunk_stats(SseDeserializer deserializer);
+
+@protected ChunkType sse_decode_chunk_type(SseDeserializer deserializer);
+
+@protected ChunkingConfig sse_decode_chunking_config(SseDeserializer deserializer);
+
+@protected ChunkingStrategy sse_decode_chunking_strategy(SseDeserializer deserializer);
+
+@protected ClassificationRule sse_decode_classification_rule(SseDeserializer deserializer);
+
+@protected CompareSearchResult sse_decode_compare_search_result(SseDeserializer deserializer);
+
+@protected CompressedText sse_decode_compressed_text(SseDeserializer deserializer);
+
+@protected CompressionOptions sse_decode_compression_options(SseDeserializer deserializer);
+
+@protected CorpusCount sse_decode_corpus_count(SseDeserializer deserializer);
+
+@protected CorpusReport sse_decode_corpus_report(SseDeserializer deserializer);
+
+@protected DbRetryConfig sse_decode_db_retry_config(SseDeserializer deserializer);
+
+@protected DedupeConfig sse_decode_dedupe_config(SseDeserializer deserializer);
+
+@protected DedupeReport sse_decode_dedupe_report(SseDeserializer deserializer);
+
+@protected DistanceMetric sse_decode_distance_metric(SseDeserializer deserializer);
+
+@protected DuplicateChunk sse_decode_duplicate_chunk(SseDeserializer deserializer);
+
+@protected EmbeddingBatchProgress sse_decode_embedding_batch_progress(SseDeserializer deserializer);
+
+@protected EmbeddingPoint sse_decode_embedding_point(SseDeserializer deserializer);
+
+@protected EmbeddingStorageStats sse_decode_embedding_storage_stats(SseDeserializer deserializer);
+
+@protected EngineInfo sse_decode_engine_info(SseDeserializer deserializer);
+
+@protected EngineMetrics sse_decode_engine_metrics(SseDeserializer deserializer);
+
+@protected EntityFacet sse_decode_entity_facet(SseDeserializer deserializer);
+
+@protected ExpandedSearchResult sse_decode_expanded_search_result(SseDeserializer deserializer);
+
+@protected ExpansionConfig sse_decode_expansion_config(SseDeserializer deserializer);
+
+@protected ExtractedEntity sse_decode_extracted_entity(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FusionStrategy sse_decode_fusion_strategy(SseDeserializer deserializer);
+
+@protected GazetteerEntry sse_decode_gazetteer_entry(SseDeserializer deserializer);
+
+@protected GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
+
+@protected GraphNeighborhood sse_decode_graph_neighborhood(SseDeserializer deserializer);
+
+@protected HighlightSpan sse_decode_highlight_span(SseDeserializer deserializer);
+
+@protected HnswBuildConfig sse_decode_hnsw_build_config(SseDeserializer deserializer);
+
+@protected HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer);
+
+@protected HnswIndexStats sse_decode_hnsw_index_stats(SseDeserializer deserializer);
+
+@protected HnswSearchResult sse_decode_hnsw_search_result(SseDeserializer deserializer);
+
+@protected HybridSearchEvent sse_decode_hybrid_search_event(SseDeserializer deserializer);
+
+@protected HybridSearchOptions sse_decode_hybrid_search_options(SseDeserializer deserializer);
+
+@protected HybridSearchResponse sse_decode_hybrid_search_response(SseDeserializer deserializer);
+
+@protected HybridSearchResult sse_decode_hybrid_search_result(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IncrementalConfig sse_decode_incremental_config(SseDeserializer deserializer);
+
+@protected IncrementalSearchResult sse_decode_incremental_search_result(SseDeserializer deserializer);
+
+@protected IndexConfig sse_decode_index_config(SseDeserializer deserializer);
+
+@protected IndexConsistency sse_decode_index_consistency(SseDeserializer deserializer);
+
+@protected IndexDivergence sse_decode_index_divergence(SseDeserializer deserializer);
+
+@protected IndexEvaluation sse_decode_index_evaluation(SseDeserializer deserializer);
+
+@protected IndexFreshness sse_decode_index_freshness(SseDeserializer deserializer);
+
+@protected IndexStaleness sse_decode_index_staleness(SseDeserializer deserializer);
+
+@protected IngestInput sse_decode_ingest_input(SseDeserializer deserializer);
+
+@protected IngestJob sse_decode_ingest_job(SseDeserializer deserializer);
+
+@protected IngestOptions sse_decode_ingest_options(SseDeserializer deserializer);
+
+@protected IngestProgress sse_decode_ingest_progress(SseDeserializer deserializer);
+
+@protected IngestReport sse_decode_ingest_report(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected KeywordBackend sse_decode_keyword_backend(SseDeserializer deserializer);
+
+@protected KnowledgeTriple sse_decode_knowledge_triple(SseDeserializer deserializer);
+
+@protected LanguageAnalyzer sse_decode_language_analyzer(SseDeserializer deserializer);
+
+@protected LastRetrieval sse_decode_last_retrieval(SseDeserializer deserializer);
+
+@protected LatencyBucket sse_decode_latency_bucket(SseDeserializer deserializer);
+
+@protected LengthBucket sse_decode_length_bucket(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AuditIssue> sse_decode_list_audit_issue(SseDeserializer deserializer);
+
+@protected List<BackgroundTask> sse_decode_list_background_task(SseDeserializer deserializer);
+
+@protected List<BenchmarkStage> sse_decode_list_benchmark_stage(SseDeserializer deserializer);
+
+@protected List<Bm25SearchResult> sse_decode_list_bm_25_search_result(SseDeserializer deserializer);
+
+@protected List<ChunkData> sse_decode_list_chunk_data(SseDeserializer deserializer);
+
+@protected List<ChunkForReembedding> sse_decode_list_chunk_for_reembedding(SseDeserializer deserializer);
+
+@protected List<ChunkMeta> sse_decode_list_chunk_meta(SseDeserializer deserializer);
+
+@protected List<ChunkSearchResult> sse_decode_list_chunk_search_result(SseDeserializer deserializer);
+
+@protected List<ClassificationRule> sse_decode_list_classification_rule(SseDeserializer deserializer);
+
+@protected List<CompareSearchResult> sse_decode_list_compare_search_result(SseDeserializer deserializer);
+
+@protected List<CorpusCount> sse_decode_list_corpus_count(SseDeserializer deserializer);
+
+@protected List<DuplicateChunk> sse_decode_list_duplicate_chunk(SseDeserializer deserializer);
+
+@protected List<EntityFacet> sse_decode_list_entity_facet(SseDeserializer deserializer);
+
+@protected List<ExpandedSearchResult> sse_decode_list_expanded_search_result(SseDeserializer deserializer);
+
+@protected List<ExtractedEntity> sse_decode_list_extracted_entity(SseDeserializer deserializer);
+
+@protected List<GazetteerEntry> sse_decode_list_gazetteer_entry(SseDeserializer deserializer);
+
+@protected List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
+
+@protected List<HighlightSpan> sse_decode_list_highlight_span(SseDeserializer deserializer);
+
+@protected List<HnswSearchResult> sse_decode_list_hnsw_search_result(SseDeserializer deserializer);
+
+@protected List<HybridSearchResult> sse_decode_list_hybrid_search_result(SseDeserializer deserializer);
+
+@protected List<IncrementalSearchResult> sse_decode_list_incremental_search_result(SseDeserializer deserializer);
+
+@protected List<IngestJob> sse_decode_list_ingest_job(SseDeserializer deserializer);
+
+@protected List<KnowledgeTriple> sse_decode_list_knowledge_triple(SseDeserializer deserializer);
+
+@protected List<LanguageAnalyzer> sse_decode_list_language_analyzer(SseDeserializer deserializer);
+
+@protected List<LatencyBucket> sse_decode_list_latency_bucket(SseDeserializer deserializer);
+
+@protected List<LengthBucket> sse_decode_list_length_bucket(SseDeserializer deserializer);
+
+@protected List<Float32List> sse_decode_list_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<MemoryMessage> sse_decode_list_memory_message(SseDeserializer deserializer);
+
+@protected List<MemorySearchResult> sse_decode_list_memory_search_result(SseDeserializer deserializer);
+
+@protected List<NamedBm25IndexInfo> sse_decode_list_named_bm_25_index_info(SseDeserializer deserializer);
+
+@protected List<NamedIndexInfo> sse_decode_list_named_index_info(SseDeserializer deserializer);
+
+@protected List<PanicEvent> sse_decode_list_panic_event(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer);
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryKeyword> sse_decode_list_query_keyword(SseDeserializer deserializer);
+
+@protected List<QuerySuggestion> sse_decode_list_query_suggestion(SseDeserializer deserializer);
+
+@protected List<RankedHit> sse_decode_list_ranked_hit(SseDeserializer deserializer);
+
+@protected List<(PlatformInt64,Float32List)> sse_decode_list_record_i_64_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<(PlatformInt64,String)> sse_decode_list_record_i_64_string(SseDeserializer deserializer);
+
+@protected List<(String,double)> sse_decode_list_record_string_f_64(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<RetrievalCitation> sse_decode_list_retrieval_citation(SseDeserializer deserializer);
+
+@protected List<SearchSpan> sse_decode_list_search_span(SseDeserializer deserializer);
+
+@protected List<SemanticChunk> sse_decode_list_semantic_chunk(SseDeserializer deserializer);
+
+@protected List<SnapshotFile> sse_decode_list_snapshot_file(SseDeserializer deserializer);
+
+@protected List<SourceCorpusStats> sse_decode_list_source_corpus_stats(SseDeserializer deserializer);
+
+@protected List<SourceEntry> sse_decode_list_source_entry(SseDeserializer deserializer);
+
+@protected List<SourceGroup> sse_decode_list_source_group(SseDeserializer deserializer);
+
+@protected List<StoreSearchHit> sse_decode_list_store_search_hit(SseDeserializer deserializer);
+
+@protected List<StructuredChunk> sse_decode_list_structured_chunk(SseDeserializer deserializer);
+
+@protected List<SynonymEntry> sse_decode_list_synonym_entry(SseDeserializer deserializer);
+
+@protected MaintenanceConfig sse_decode_maintenance_config(SseDeserializer deserializer);
+
+@protected MaintenanceReport sse_decode_maintenance_report(SseDeserializer deserializer);
+
+@protected MemoryMessage sse_decode_memory_message(SseDeserializer deserializer);
+
+@protected MemoryProfile sse_decode_memory_profile(SseDeserializer deserializer);
+
+@protected MemorySearchResult sse_decode_memory_search_result(SseDeserializer deserializer);
+
+@protected MemoryUsage sse_decode_memory_usage(SseDeserializer deserializer);
+
+@protected NamedBm25IndexInfo sse_decode_named_bm_25_index_info(SseDeserializer deserializer);
+
+@protected NamedIndexConfig sse_decode_named_index_config(SseDeserializer deserializer);
+
+@protected NamedIndexInfo sse_decode_named_index_info(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AuditReport? sse_decode_opt_box_autoadd_audit_report(SseDeserializer deserializer);
+
+@protected BackgroundTask? sse_decode_opt_box_autoadd_background_task(SseDeserializer deserializer);
+
+@protected Bm25FuzzyConfig? sse_decode_opt_box_autoadd_bm_25_fuzzy_config(SseDeserializer deserializer);
+
+@protected ChunkingStrategy? sse_decode_opt_box_autoadd_chunking_strategy(SseDeserializer deserializer);
+
+@protected DistanceMetric? sse_decode_opt_box_autoadd_distance_metric(SseDeserializer deserializer);
+
+@protected ExpansionConfig? sse_decode_opt_box_autoadd_expansion_config(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HnswBuildConfig? sse_decode_opt_box_autoadd_hnsw_build_config(SseDeserializer deserializer);
+
+@protected HnswIndexStats? sse_decode_opt_box_autoadd_hnsw_index_stats(SseDeserializer deserializer);
+
+@protected HybridSearchOptions? sse_decode_opt_box_autoadd_hybrid_search_options(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LastRetrieval? sse_decode_opt_box_autoadd_last_retrieval(SseDeserializer deserializer);
+
+@protected MaintenanceReport? sse_decode_opt_box_autoadd_maintenance_report(SseDeserializer deserializer);
+
+@protected (int,int,int)? sse_decode_opt_box_autoadd_record_u_32_u_32_u_32(SseDeserializer deserializer);
+
+@protected ReembeddingJob? sse_decode_opt_box_autoadd_reembedding_job(SseDeserializer deserializer);
+
+@protected RrfConfig? sse_decode_opt_box_autoadd_rrf_config(SseDeserializer deserializer);
+
+@protected SearchDiagnostics? sse_decode_opt_box_autoadd_search_diagnostics(SseDeserializer deserializer);
+
+@protected SearchFilter? sse_decode_opt_box_autoadd_search_filter(SseDeserializer deserializer);
+
+@protected SearchSnippet? sse_decode_opt_box_autoadd_search_snippet(SseDeserializer deserializer);
+
+@protected SearchTrace? sse_decode_opt_box_autoadd_search_trace(SseDeserializer deserializer);
+
+@protected StoredChunk? sse_decode_opt_box_autoadd_stored_chunk(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected Int64List? sse_decode_opt_list_prim_i_64_strict(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String,String)>? sse_decode_opt_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<SourceGroup>? sse_decode_opt_list_source_group(SseDeserializer deserializer);
+
+@protected PanicEvent sse_decode_panic_event(SseDeserializer deserializer);
+
+@protected ParsedIntent sse_decode_parsed_intent(SseDeserializer deserializer);
+
+@protected ProgressEvent sse_decode_progress_event(SseDeserializer deserializer);
+
+@protected Quantization sse_decode_quantization(SseDeserializer deserializer);
+
+@protected QueryDecomposition sse_decode_query_decomposition(SseDeserializer deserializer);
+
+@protected QueryKeyword sse_decode_query_keyword(SseDeserializer deserializer);
+
+@protected QueryNormalizationConfig sse_decode_query_normalization_config(SseDeserializer deserializer);
+
+@protected QuerySuggestion sse_decode_query_suggestion(SseDeserializer deserializer);
+
+@protected RagError sse_decode_rag_error(SseDeserializer deserializer);
+
+@protected RankedHit sse_decode_ranked_hit(SseDeserializer deserializer);
+
+@protected (PlatformInt64,Float32List) sse_decode_record_i_64_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected (PlatformInt64,String) sse_decode_record_i_64_string(SseDeserializer deserializer);
+
+@protected (String,double) sse_decode_record_string_f_64(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected (int,int,int) sse_decode_record_u_32_u_32_u_32(SseDeserializer deserializer);
+
+@protected ReembeddingJob sse_decode_reembedding_job(SseDeserializer deserializer);
+
+@protected RetrievalCitation sse_decode_retrieval_citation(SseDeserializer deserializer);
+
+@protected RetrievalPreset sse_decode_retrieval_preset(SseDeserializer deserializer);
+
+@protected RewrittenQuery sse_decode_rewritten_query(SseDeserializer deserializer);
+
+@protected RrfConfig sse_decode_rrf_config(SseDeserializer deserializer);
+
+@protected ScoreNormalization sse_decode_score_normalization(SseDeserializer deserializer);
+
+@protected SearchDiagnostics sse_decode_search_diagnostics(SseDeserializer deserializer);
+
+@protected SearchFeedbackAction sse_decode_search_feedback_action(SseDeserializer deserializer);
+
+@protected SearchFilter sse_decode_search_filter(SseDeserializer deserializer);
+
+@protected SearchSnippet sse_decode_search_snippet(SseDeserializer deserializer);
+
+@protected SearchSpan sse_decode_search_span(SseDeserializer deserializer);
+
+@protected SearchTrace sse_decode_search_trace(SseDeserializer deserializer);
+
+@protected SemanticChunk sse_decode_semantic_chunk(SseDeserializer deserializer);
+
+@protected SnapshotFile sse_decode_snapshot_file(SseDeserializer deserializer);
+
+@protected SnapshotManifest sse_decode_snapshot_manifest(SseDeserializer deserializer);
+
+@protected SourceCorpusStats sse_decode_source_corpus_stats(SseDeserializer deserializer);
+
+@protected SourceEntry sse_decode_source_entry(SseDeserializer deserializer);
+
+@protected SourceGroup sse_decode_source_group(SseDeserializer deserializer);
+
+@protected SourceIndexConfig sse_decode_source_index_config(SseDeserializer deserializer);
+
+@protected SourceStats sse_decode_source_stats(SseDeserializer deserializer);
+
+@protected SourceUpdate sse_decode_source_update(SseDeserializer deserializer);
+
+@protected StagedIngest sse_decode_staged_ingest(SseDeserializer deserializer);
+
+@protected StoreSearchHit sse_decode_store_search_hit(SseDeserializer deserializer);
+
+@protected StoredChunk sse_decode_stored_chunk(SseDeserializer deserializer);
+
+@protected StructuredChunk sse_decode_structured_chunk(SseDeserializer deserializer);
+
+@protected SynonymEntry sse_decode_synonym_entry(SseDeserializer deserializer);
+
+@protected TracedHybridSearch sse_decode_traced_hybrid_search(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UserIntent sse_decode_user_intent(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(FutureOr<Float32List> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(FutureOr<void> Function(EmbeddingBatchProgress) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(FutureOr<void> Function(IngestJob) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(FutureOr<void> Function(IngestProgress) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(FutureOr<List<Float32List>> Function(List<String>) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(FutureOr<void> Function(ReembeddingJob) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_f_64_None(Map<String, double> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(MemoryStore self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_hybrid_search_event_Sse(RustStreamSink<HybridSearchEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_progress_event_Sse(RustStreamSink<ProgressEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_add_document_result(AddDocumentResult self, SseSerializer serializer);
+
+@protected void sse_encode_add_source_result(AddSourceResult self, SseSerializer serializer);
+
+@protected void sse_encode_audit_issue(AuditIssue self, SseSerializer serializer);
+
+@protected void sse_encode_audit_report(AuditReport self, SseSerializer serializer);
+
+@protected void sse_encode_background_task(BackgroundTask self, SseSerializer serializer);
+
+@protected void sse_encode_background_task_kind(BackgroundTaskKind self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_config(BenchmarkConfig self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_report(BenchmarkReport self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_stage(BenchmarkStage self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_config(Bm25Config self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_fuzzy_config(Bm25FuzzyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_index_options(Bm25IndexOptions self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_search_result(Bm25SearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_tokenization(Bm25Tokenization self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_variant(Bm25Variant self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audit_report(AuditReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_background_task(BackgroundTask self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_benchmark_config(BenchmarkConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bm_25_config(Bm25Config self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bm_25_fuzzy_config(Bm25FuzzyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bm_25_index_options(Bm25IndexOptions self, SseSerializer serializer);
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
+@protected void sse_encode_box_autoadd_chunking_config(ChunkingConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chunking_strategy(ChunkingStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_compression_options(CompressionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_db_retry_config(DbRetryConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_dedupe_config(DedupeConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_distance_metric(DistanceMetric self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_expansion_config(ExpansionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hnsw_build_config(HnswBuildConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hnsw_index_stats(HnswIndexStats self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hybrid_search_options(HybridSearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_incremental_config(IncrementalConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_index_config(IndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ingest_input(IngestInput self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ingest_options(IngestOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_language_analyzer(LanguageAnalyzer self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_last_retrieval(LastRetrieval self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_maintenance_config(MaintenanceConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_maintenance_report(MaintenanceReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_named_index_config(NamedIndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_decomposition(QueryDecomposition self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_normalization_config(QueryNormalizationConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rag_error(RagError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_record_u_32_u_32_u_32((int,int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_reembedding_job(ReembeddingJob self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retrieval_preset(RetrievalPreset self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rrf_config(RrfConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_diagnostics(SearchDiagnostics self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_filter(SearchFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_snippet(SearchSnippet self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_trace(SearchTrace self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_source_index_config(SourceIndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stored_chunk(StoredChunk self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_user_intent(UserIntent self, SseSerializer serializer);
+
+@protected void sse_encode_buffer_stats(BufferStats self, SseSerializer serializer);
+
+@protected void sse_encode_buffer_table(BufferTable self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_data(ChunkData self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_for_reembedding(ChunkForReembedding self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_index_source(ChunkIndexSource self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_meta(ChunkMeta self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_search_page(ChunkSearchPage self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_search_result(ChunkSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_stats(ChunkStats self, SseSerializer serializer);
+
+@protected void sse_encode_chunk_type(ChunkType self, SseSerializer serializer);
+
+@protected void sse_encode_chunking_config(ChunkingConfig self, SseSerializer serializer);
+
+@protected void sse_encode_chunking_strategy(ChunkingStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_classification_rule(ClassificationRule self, SseSerializer serializer);
+
+@protected void sse_encode_compare_search_result(CompareSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_compressed_text(CompressedText self, SseSerializer serializer);
+
+@protected void sse_encode_compression_options(CompressionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_corpus_count(CorpusCount self, SseSerializer serializer);
+
+@protected void sse_encode_corpus_report(CorpusReport self, SseSerializer serializer);
+
+@protected void sse_encode_db_retry_config(DbRetryConfig self, SseSerializer serializer);
+
+@protected void sse_encode_dedupe_config(DedupeConfig self, SseSerializer serializer);
+
+@protected void sse_encode_dedupe_report(DedupeReport self, SseSerializer serializer);
+
+@protected void sse_encode_distance_metric(DistanceMetric self, SseSerializer serializer);
+
+@protected void sse_encode_duplicate_chunk(DuplicateChunk self, SseSerializer serializer);
+
+@protected void sse_encode_embedding_batch_progress(EmbeddingBatchProgress self, SseSerializer serializer);
+
+@protected void sse_encode_embedding_point(EmbeddingPoint self, SseSerializer serializer);
+
+@protected void sse_encode_embedding_storage_stats(EmbeddingStorageStats self, SseSerializer serializer);
+
+@protected void sse_encode_engine_info(EngineInfo self, SseSerializer serializer);
+
+@protected void sse_encode_engine_metrics(EngineMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_entity_facet(EntityFacet self, SseSerializer serializer);
+
+@protected void sse_encode_expanded_search_result(ExpandedSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_expansion_config(ExpansionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_extracted_entity(ExtractedEntity self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_fusion_strategy(FusionStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_gazetteer_entry(GazetteerEntry self, SseSerializer serializer);
+
+@protected void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
+
+@protected void sse_encode_graph_neighborhood(GraphNeighborhood self, SseSerializer serializer);
+
+@protected void sse_encode_highlight_span(HighlightSpan self, SseSerializer serializer);
+
+@protected void sse_encode_hnsw_build_config(HnswBuildConfig self, SseSerializer serializer);
+
+@protected void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+@protected void sse_encode_hnsw_index_stats(HnswIndexStats self, SseSerializer serializer);
+
+@protected void sse_encode_hnsw_search_result(HnswSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_hybrid_search_event(HybridSearchEvent self, SseSerializer serializer);
+
+@protected void sse_encode_hybrid_search_options(HybridSearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_hybrid_search_response(HybridSearchResponse self, SseSerializer serializer);
+
+@protected void sse_encode_hybrid_search_result(HybridSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_incremental_config(IncrementalConfig self, SseSerializer serializer);
+
+@protected void sse_encode_incremental_search_result(IncrementalSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_index_config(IndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_index_consistency(IndexConsistency self, SseSerializer serializer);
+
+@protected void sse_encode_index_divergence(IndexDivergence self, SseSerializer serializer);
+
+@protected void sse_encode_index_evaluation(IndexEvaluation self, SseSerializer serializer);
+
+@protected void sse_encode_index_freshness(IndexFreshness self, SseSerializer serializer);
+
+@protected void sse_encode_index_staleness(IndexStaleness self, SseSerializer serializer);
+
+@protected void sse_encode_ingest_input(IngestInput self, SseSerializer serializer);
+
+@protected void sse_encode_ingest_job(IngestJob self, SseSerializer serializer);
+
+@protected void sse_encode_ingest_options(IngestOptions self, SseSerializer serializer);
+
+@protected void sse_encode_ingest_progress(IngestProgress self, SseSerializer serializer);
+
+@protected void sse_encode_ingest_report(IngestReport self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_keyword_backend(KeywordBackend self, SseSerializer serializer);
+
+@protected void sse_encode_knowledge_triple(KnowledgeTriple self, SseSerializer serializer);
+
+@protected void sse_encode_language_analyzer(LanguageAnalyzer self, SseSerializer serializer);
+
+@protected void sse_encode_last_retrieval(LastRetrieval self, SseSerializer serializer);
+
+@protected void sse_encode_latency_bucket(LatencyBucket self, SseSerializer serializer);
+
+@protected void sse_encode_length_bucket(LengthBucket self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_audit_issue(List<AuditIssue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_background_task(List<BackgroundTask> self, SseSerializer serializer);
+
+@protected void sse_encode_list_benchmark_stage(List<BenchmarkStage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_bm_25_search_result(List<Bm25SearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chunk_data(List<ChunkData> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chunk_for_reembedding(List<ChunkForReembedding> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chunk_meta(List<ChunkMeta> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chunk_search_result(List<ChunkSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_classification_rule(List<ClassificationRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_compare_search_result(List<CompareSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_corpus_count(List<CorpusCount> self, SseSerializer serializer);
+
+@protected void sse_encode_list_duplicate_chunk(List<DuplicateChunk> self, SseSerializer serializer);
+
+@protected void sse_encode_list_entity_facet(List<EntityFacet> self, SseSerializer serializer);
+
+@protected void sse_encode_list_expanded_search_result(List<ExpandedSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_extracted_entity(List<ExtractedEntity> self, SseSerializer serializer);
+
+@protected void sse_encode_list_gazetteer_entry(List<GazetteerEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_graph_edge(List<GraphEdge> self, SseSerializer serializer);
+
+@protected void sse_encode_list_highlight_span(List<HighlightSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_hnsw_search_result(List<HnswSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_hybrid_search_result(List<HybridSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_incremental_search_result(List<IncrementalSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_ingest_job(List<IngestJob> self, SseSerializer serializer);
+
+@protected void sse_encode_list_knowledge_triple(List<KnowledgeTriple> self, SseSerializer serializer);
+
+@protected void sse_encode_list_language_analyzer(List<LanguageAnalyzer> self, SseSerializer serializer);
+
+@protected void sse_encode_list_latency_bucket(List<LatencyBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_length_bucket(List<LengthBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_f_32_strict(List<Float32List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_memory_message(List<MemoryMessage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_memory_search_result(List<MemorySearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_named_bm_25_index_info(List<NamedBm25IndexInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_named_index_info(List<NamedIndexInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_panic_event(List<PanicEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_keyword(List<QueryKeyword> self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_suggestion(List<QuerySuggestion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_ranked_hit(List<RankedHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_i_64_list_prim_f_32_strict(List<(PlatformInt64,Float32List)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_i_64_string(List<(PlatformInt64,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_f_64(List<(String,double)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_retrieval_citation(List<RetrievalCitation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_span(List<SearchSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_semantic_chunk(List<SemanticChunk> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snapshot_file(List<SnapshotFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_source_corpus_stats(List<SourceCorpusStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_source_entry(List<SourceEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_source_group(List<SourceGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_store_search_hit(List<StoreSearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_structured_chunk(List<StructuredChunk> self, SseSerializer serializer);
+
+@protected void sse_encode_list_synonym_entry(List<SynonymEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_maintenance_config(MaintenanceConfig self, SseSerializer serializer);
+
+@protected void sse_encode_maintenance_report(MaintenanceReport self, SseSerializer serializer);
+
+@protected void sse_encode_memory_message(MemoryMessage self, SseSerializer serializer);
+
+@protected void sse_encode_memory_profile(MemoryProfile self, SseSerializer serializer);
+
+@protected void sse_encode_memory_search_result(MemorySearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_memory_usage(MemoryUsage self, SseSerializer serializer);
+
+@protected void sse_encode_named_bm_25_index_info(NamedBm25IndexInfo self, SseSerializer serializer);
+
+@protected void sse_encode_named_index_config(NamedIndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_named_index_info(NamedIndexInfo self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_audit_report(AuditReport? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_background_task(BackgroundTask? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bm_25_fuzzy_config(Bm25FuzzyConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_chunking_strategy(ChunkingStrategy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_distance_metric(DistanceMetric? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_expansion_config(ExpansionConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_hnsw_build_config(HnswBuildConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_hnsw_index_stats(HnswIndexStats? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_hybrid_search_options(HybridSearchOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_last_retrieval(LastRetrieval? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_maintenance_report(MaintenanceReport? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_record_u_32_u_32_u_32((int,int,int)? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_reembedding_job(ReembeddingJob? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_rrf_config(RrfConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_search_diagnostics(SearchDiagnostics? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_search_filter(SearchFilter? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_search_snippet(SearchSnippet? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_search_trace(SearchTrace? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_stored_chunk(StoredChunk? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_i_64_strict(Int64List? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_record_string_string(List<(String,String)>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_source_group(List<SourceGroup>? self, SseSerializer serializer);
+
+@protected void sse_encode_panic_event(PanicEvent self, SseSerializer serializer);
+
+@protected void sse_encode_parsed_intent(ParsedIntent self, SseSerializer serializer);
+
+@protected void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer);
+
+@protected void sse_encode_quantization(Quantization self, SseSerializer serializer);
+
+@protected void sse_encode_query_decomposition(QueryDecomposition self, SseSerializer serializer);
+
+@protected void sse_encode_query_keyword(QueryKeyword self, SseSerializer serializer);
+
+@protected void sse_encode_query_normalization_config(QueryNormalizationConfig self, SseSerializer serializer);
+
+@protected void sse_encode_query_suggestion(QuerySuggestion self, SseSerializer serializer);
+
+@protected void sse_encode_rag_error(RagError self, SseSerializer serializer);
+
+@protected void sse_encode_ranked_hit(RankedHit self, SseSerializer serializer);
+
+@protected void sse_encode_record_i_64_list_prim_f_32_strict((PlatformInt64,Float32List) self, SseSerializer serializer);
+
+@protected void sse_encode_record_i_64_string((PlatformInt64,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_f_64((String,double) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_32_u_32_u_32((int,int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_reembedding_job(ReembeddingJob self, SseSerializer serializer);
+
+@protected void sse_encode_retrieval_citation(RetrievalCitation self, SseSerializer serializer);
+
+@protected void sse_encode_retrieval_preset(RetrievalPreset self, SseSerializer serializer);
+
+@protected void sse_encode_rewritten_query(RewrittenQuery self, SseSerializer serializer);
+
+@protected void sse_encode_rrf_config(RrfConfig self, SseSerializer serializer);
+
+@protected void sse_encode_score_normalization(ScoreNormalization self, SseSerializer serializer);
+
+@protected void sse_encode_search_diagnostics(SearchDiagnostics self, SseSerializer serializer);
+
+@protected void sse_encode_search_feedback_action(SearchFeedbackAction self, SseSerializer serializer);
+
+@protected void sse_encode_search_filter(SearchFilter self, SseSerializer serializer);
+
+@protected void sse_encode_search_snippet(SearchSnippet self, SseSerializer serializer);
+
+@protected void sse_encode_search_span(SearchSpan self, SseSerializer serializer);
+
+@protected void sse_encode_search_trace(SearchTrace self, SseSerializer serializer);
+
+@protected void sse_encode_semantic_chunk(SemanticChunk self, SseSerializer serializer);
+
+@protected void sse_encode_snapshot_file(SnapshotFile self, SseSerializer serializer);
+
+@protected void sse_encode_snapshot_manifest(SnapshotManifest self, SseSerializer serializer);
+
+@protected void sse_encode_source_corpus_stats(SourceCorpusStats self, SseSerializer serializer);
+
+@protected void sse_encode_source_entry(SourceEntry self, SseSerializer serializer);
+
+@protected void sse_encode_source_group(SourceGroup self, SseSerializer serializer);
+
+@protected void sse_encode_source_index_config(SourceIndexConfig self, SseSerializer serializer);
+
+@protected void sse_encode_source_stats(SourceStats self, SseSerializer serializer);
+
+@protected void sse_encode_source_update(SourceUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_staged_ingest(StagedIngest self, SseSerializer serializer);
+
+@protected void sse_encode_store_search_hit(StoreSearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_stored_chunk(StoredChunk self, SseSerializer serializer);
+
+@protected void sse_encode_structured_chunk(StructuredChunk self, SseSerializer serializer);
+
+@protected void sse_encode_synonym_entry(SynonymEntry self, SseSerializer serializer);
+
+@protected void sse_encode_traced_hybrid_search(TracedHybridSearch self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_user_intent(UserIntent self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-}
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_rag_engine_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_rag_engine_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
@@ -11,6 +11,9 @@ extension RagErrorUi on RagError {
       invalidInput: (msg) => '입력값이 올바르지 않습니다: $msg',
       internalError: (_) => '일시적인 내부 오류가 발생했습니다.',
       unknown: (_) => '알 수 없는 오류가 발생했습니다.',
+      databaseBusy: (_) => '데이터베이스가 사용 중입니다. 잠시 후 다시 시도해주세요.',
+      notInitialized: (_) => '엔진이 아직 초기화되지 않았습니다. 앱을 재시작해주세요.',
+      parseError: (_) => '문서를 읽을 수 없습니다. 파일이 손상되었는지 확인해주세요.',
     );
   }
 
@@ -23,6 +26,9 @@ extension RagErrorUi on RagError {
       invalidInput: (msg) => msg,
       internalError: (msg) => msg,
       unknown: (msg) => msg,
+      databaseBusy: (msg) => msg,
+      notInitialized: (msg) => msg,
+      parseError: (msg) => msg,
     );
   }
 }
@@ -0,0 +1,61 @@
+/*
+ * Copyright 2025 mobile_rag_engine contributors
+ * SPDX-License-Identifier: MIT
+ *
+ * C ABI for embedding the engine without Flutter (see src/c_api.rs).
+ * Functions return RAG_OK or a negative RAG_ERR_* status; call
+ * rag_last_error() on the same thread for details. Strings returned via
+ * out-pointers must be released with rag_string_free().
+ */
+#ifndef RAG_ENGINE_H
+#define RAG_ENGINE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define RAG_OK 0
+#define RAG_ERR_DATABASE (-1)
+#define RAG_ERR_IO (-2)
+#define RAG_ERR_MODEL_LOAD (-3)
+#define RAG_ERR_INVALID_INPUT (-4)
+#define RAG_ERR_INTERNAL (-5)
+#define RAG_ERR_UNKNOWN (-6)
+#define RAG_ERR_DATABASE_BUSY (-7)
+#define RAG_ERR_NOT_INITIALIZED (-8)
+#define RAG_ERR_PARSE (-9)
+
+int32_t rag_init(const char *db_path, uint32_t max_pool_size);
+
+/* metadata and name may be NULL. */
+int32_t rag_add_source(const char *content, const char *metadata, const char *name,
+                       int64_t *out_source_id, bool *out_is_duplicate);
+
+/* embeddings holds count * dimension floats, one row per chunk. */
+int32_t rag_add_chunks(int64_t source_id, const char *const *contents, size_t count,
+                       const float *embeddings, size_t dimension, int32_t *out_added);
+
+int32_t rag_rebuild_indexes(void);
+
+/* *out_json receives a JSON array of results; free with rag_string_free. */
+int32_t rag_search(const char *query, const float *embedding, size_t dimension,
+                   uint32_t top_k, char **out_json);
+
+int32_t rag_delete_source(int64_t source_id);
+
+int32_t rag_source_stats(int64_t *out_sources, int64_t *out_chunks);
+
+/* Valid until the next rag_* call on this thread; NULL after success. */
+const char *rag_last_error(void);
+
+void rag_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RAG_ENGINE_H */
@@ -0,0 +1,203 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Per-language text analyzers.
+//!
+//! One registry holds the stopwords for each language code produced by
+//! `detect_language`. Query normalization, keyword extraction, prompt
+//! compression and BM25 indexes built with `remove_stopwords` all consult
+//! it, so supporting a new language is one `register_language_analyzer`
+//! call at init instead of edits in each module. Words are looked up in
+//! their own script's list, so documents and queries mixing languages are
+//! analyzed consistently.
+//!
+//! How BM25 tokens are stemmed or split is set per index with
+//! `set_bm25_index_options`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use log::info;
+use once_cell::sync::Lazy;
+
+use crate::api::error::RagError;
+use crate::api::language_detect::{detect_language_code, LANG_ENGLISH, LANG_UNKNOWN};
+
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "a", "about", "an", "and", "are", "as", "at", "be", "between", "by", "can", "could",
+    "did", "do", "does", "explain", "for", "from", "give", "how", "i", "in", "is", "it",
+    "its", "me", "my", "of", "on", "or", "please", "should", "tell", "than", "that", "the",
+    "their", "there", "these", "this", "to", "vs", "was", "what", "when", "where", "which",
+    "who", "why", "will", "with", "would", "you", "your",
+];
+
+/// Korean particles, longest first so "에서" wins over "에".
+const KOREAN_PARTICLES: &[&str] = &[
+    "에서", "으로", "에게", "까지", "부터", "은", "는", "이", "가", "을", "를", "의", "에", "로", "와", "과", "도",
+];
+
+#[derive(Debug, Clone)]
+pub struct LanguageAnalyzer {
+    /// Code as returned by `detect_language` ("en", "ko", "ja", "zh", "unknown").
+    pub language: String,
+    /// Used by query normalization, keyword extraction and compression.
+    pub stopwords: Vec<String>,
+}
+
+impl LanguageAnalyzer {
+    fn plain(language: &str) -> Self {
+        Self {
+            language: language.to_string(),
+            stopwords: Vec::new(),
+        }
+    }
+
+    fn is_stopword(&self, word: &str) -> bool {
+        self.stopwords.iter().any(|s| s == word)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Registry {
+    analyzers: HashMap<String, LanguageAnalyzer>,
+    fallback: LanguageAnalyzer,
+}
+
+impl Registry {
+    fn defaults() -> Self {
+        let english = LanguageAnalyzer {
+            stopwords: ENGLISH_STOPWORDS.iter().map(|s| s.to_string()).collect(),
+            ..LanguageAnalyzer::plain(LANG_ENGLISH)
+        };
+        let mut analyzers = HashMap::new();
+        analyzers.insert(LANG_ENGLISH.to_string(), english);
+        Self { analyzers, fallback: LanguageAnalyzer::plain(LANG_UNKNOWN) }
+    }
+
+    fn insert(&mut self, analyzer: LanguageAnalyzer) {
+        if analyzer.language == LANG_UNKNOWN {
+            self.fallback = analyzer;
+        } else {
+            self.analyzers.insert(analyzer.language.clone(), analyzer);
+        }
+    }
+
+    fn get(&self, language: &str) -> &LanguageAnalyzer {
+        self.analyzers.get(language).unwrap_or(&self.fallback)
+    }
+
+    /// Analyzer for a single word, chosen by the word's script.
+    fn for_word(&self, word: &str) -> &LanguageAnalyzer {
+        self.get(detect_language_code(word))
+    }
+}
+
+static REGISTRY: Lazy<RwLock<Arc<Registry>>> = Lazy::new(|| RwLock::new(Arc::new(Registry::defaults())));
+
+fn registry() -> Arc<Registry> {
+    REGISTRY.read().unwrap().clone()
+}
+
+/// Add or replace the analyzer for `analyzer.language`. Registering
+/// "unknown" replaces the fallback used for undetected scripts.
+#[flutter_rust_bridge::frb(sync)]
+pub fn register_language_analyzer(analyzer: LanguageAnalyzer) -> Result<(), RagError> {
+    if analyzer.language.trim().is_empty() {
+        return Err(RagError::InvalidInput("Analyzer language is required".to_string()));
+    }
+    let mut analyzer = analyzer;
+    analyzer.stopwords = analyzer.stopwords.iter().map(|s| s.to_lowercase()).collect();
+    info!("[analyzers] Registered analyzer for {}", analyzer.language);
+    let mut guard = REGISTRY.write().unwrap();
+    let mut next = (**guard).clone();
+    next.insert(analyzer);
+    *guard = Arc::new(next);
+    Ok(())
+}
+
+/// Analyzer applied to `language` (the fallback if none is registered).
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_language_analyzer(language: String) -> LanguageAnalyzer {
+    registry().get(&language).clone()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_language_analyzers() -> Vec<LanguageAnalyzer> {
+    let registry = registry();
+    let mut analyzers: Vec<LanguageAnalyzer> = registry.analyzers.values().cloned().collect();
+    analyzers.sort_by(|a, b| a.language.cmp(&b.language));
+    analyzers.push(registry.fallback.clone());
+    analyzers
+}
+
+/// Restore the built-in analyzers.
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_language_analyzers() {
+    *REGISTRY.write().unwrap() = Arc::new(Registry::defaults());
+    info!("[analyzers] Reset to defaults");
+}
+
+/// Whether a lowercase word is a stopword in its own language.
+pub(crate) fn is_stopword(word: &str) -> bool {
+    registry().for_word(word).is_stopword(word)
+}
+
+/// Whether a lowercase word is a stopword of `language` ("auto" detects
+/// per word).
+pub(crate) fn is_stopword_in(language: &str, word: &str) -> bool {
+    let registry = registry();
+    let analyzer = if language == "auto" { registry.for_word(word) } else { registry.get(language) };
+    analyzer.is_stopword(word)
+}
+
+/// Strip a trailing postposition (이/가/을/를/에서...) from a Hangul word.
+pub(crate) fn strip_korean_particle(token: String) -> String {
+    for particle in KOREAN_PARTICLES {
+        if let Some(stem) = token.strip_suffix(particle) {
+            // Keep at least two syllables so nouns like "국가" survive.
+            if stem.chars().count() >= 2 {
+                return stem.to_string();
+            }
+        }
+    }
+    token
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_stopwords_by_script() {
+        let mut registry = Registry::defaults();
+        assert!(registry.get("en").is_stopword("the"));
+        assert!(!registry.get("ko").is_stopword("the"));
+        registry.insert(LanguageAnalyzer { stopwords: words(&["그리고"]), ..LanguageAnalyzer::plain("ko") });
+        assert!(registry.for_word("그리고").is_stopword("그리고"));
+        assert!(!registry.for_word("the").is_stopword("그리고"));
+    }
+
+    #[test]
+    fn test_strip_korean_particle() {
+        assert_eq!(strip_korean_particle("주가가".to_string()), "주가");
+        assert_eq!(strip_korean_particle("서울에서".to_string()), "서울");
+        assert_eq!(strip_korean_particle("국가".to_string()), "국가");
+    }
+}
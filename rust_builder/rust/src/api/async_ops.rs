@@ -0,0 +1,246 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Non-blocking variants of the heavy search and ingest calls.
+//!
+//! The plain functions run on the FRB worker that called them, so a long
+//! index rebuild holds that worker until it finishes and other Rust calls
+//! queue behind it. The `*_async` variants hand the work to a dedicated
+//! pool and return a future that resolves when it is done. Searches run
+//! on the `rag-query-N` threads, while writes and rebuilds run one at a
+//! time on a low-priority `rag-heavy` thread, so a rebuild never holds
+//! up a search. A call fails if the active tenant changes before its work
+//! starts, and a panic in the work becomes an `InternalError`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use log::{info, warn};
+
+use crate::api::error::RagError;
+use crate::api::hybrid_search::{
+    search_hybrid, HybridSearchOptions, HybridSearchResponse, RrfConfig, SearchFilter,
+};
+use crate::api::maintenance::lower_thread_priority;
+use crate::api::panic_report::catch_panic;
+use crate::api::source_rag::{
+    add_chunks, add_chunks_packed, rebuild_chunk_bm25_index, rebuild_chunk_hnsw_index, ChunkData, ChunkMeta,
+};
+use crate::api::tenant::active_tenant;
+
+/// Threads serving async searches.
+const QUERY_THREADS: usize = 2;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Which pool a call runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lane {
+    /// Read-only searches, run concurrently.
+    Query,
+    /// Writes and index rebuilds, run one at a time in submission order.
+    Heavy,
+}
+
+struct Pool {
+    sender: Mutex<Option<Sender<Job>>>,
+}
+
+impl Pool {
+    const fn new() -> Self {
+        Self { sender: Mutex::new(None) }
+    }
+
+    /// Queue `job`, starting the pool's threads on first use.
+    fn submit(&self, lane: Lane, job: Job) -> Result<(), RagError> {
+        let mut sender = self.sender.lock().unwrap();
+        if sender.is_none() {
+            *sender = Some(start_threads(lane)?);
+        }
+        sender
+            .as_ref()
+            .unwrap()
+            .send(job)
+            .map_err(|_| RagError::InternalError(format!("{:?} pool has shut down", lane)))
+    }
+}
+
+static QUERY_POOL: Pool = Pool::new();
+static HEAVY_POOL: Pool = Pool::new();
+
+fn start_threads(lane: Lane) -> Result<Sender<Job>, RagError> {
+    let (sender, receiver) = channel::<Job>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    let names: Vec<String> = match lane {
+        Lane::Query => (0..QUERY_THREADS).map(|i| format!("rag-query-{}", i)).collect(),
+        Lane::Heavy => vec!["rag-heavy".to_string()],
+    };
+    for name in names {
+        let receiver = Arc::clone(&receiver);
+        std::thread::Builder::new()
+            .name(name)
+            .spawn(move || run_thread(lane, receiver))
+            .map_err(|e| RagError::InternalError(format!("Failed to start {:?} pool: {}", lane, e)))?;
+    }
+    info!("[async] Started {:?} pool", lane);
+    Ok(sender)
+}
+
+fn run_thread(lane: Lane, receiver: Arc<Mutex<Receiver<Job>>>) {
+    if lane == Lane::Heavy {
+        lower_thread_priority();
+    }
+    loop {
+        // The lock is released before the job runs.
+        let next = receiver.lock().unwrap().recv();
+        match next {
+            Ok(job) => job(),
+            Err(_) => break,
+        }
+    }
+}
+
+/// State shared by a job and the future awaiting its result.
+struct Slot<T> {
+    result: Option<Result<T, RagError>>,
+    waker: Option<Waker>,
+}
+
+/// Resolves with the result of a job run by `run_on`.
+pub(crate) struct Pending<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> Future for Pending<T> {
+    type Output = Result<T, RagError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Run `f` on `lane`'s pool as the tenant active now.
+fn run_on<T, F>(lane: Lane, context: &'static str, f: F) -> Pending<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, RagError> + Send + 'static,
+{
+    let slot = Arc::new(Mutex::new(Slot { result: None, waker: None }));
+    let tenant_id = active_tenant();
+    let job_slot = Arc::clone(&slot);
+    let job: Job = Box::new(move || {
+        let result = if active_tenant() != tenant_id {
+            Err(RagError::InvalidInput(format!("Active tenant changed from {}", tenant_id)))
+        } else {
+            catch_panic(context, f).and_then(|result| result)
+        };
+        if let Err(e) = &result {
+            warn!("[async] {} failed: {}", context, e);
+        }
+        let mut slot = job_slot.lock().unwrap();
+        slot.result = Some(result);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    });
+    let pool = match lane {
+        Lane::Query => &QUERY_POOL,
+        Lane::Heavy => &HEAVY_POOL,
+    };
+    if let Err(e) = pool.submit(lane, job) {
+        slot.lock().unwrap().result = Some(Err(e));
+    }
+    Pending { slot }
+}
+
+/// `search_hybrid` on the query pool.
+pub async fn search_hybrid_async(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    options: Option<HybridSearchOptions>,
+) -> Result<HybridSearchResponse, RagError> {
+    run_on(Lane::Query, "search_hybrid", move || {
+        search_hybrid(query_text, query_embedding, top_k, config, filter, options)
+    })
+    .await
+}
+
+/// `add_chunks` on the heavy pool.
+pub async fn add_chunks_async(source_id: i64, chunks: Vec<ChunkData>) -> Result<i32, RagError> {
+    run_on(Lane::Heavy, "add_chunks", move || add_chunks(source_id, chunks)).await
+}
+
+/// `add_chunks_packed` on the heavy pool.
+pub async fn add_chunks_packed_async(
+    source_id: i64,
+    chunks: Vec<ChunkMeta>,
+    embeddings: Vec<f32>,
+    offsets: Vec<u32>,
+) -> Result<i32, RagError> {
+    run_on(Lane::Heavy, "add_chunks_packed", move || {
+        add_chunks_packed(source_id, chunks, embeddings, offsets)
+    })
+    .await
+}
+
+/// `rebuild_chunk_hnsw_index` on the heavy pool.
+pub async fn rebuild_chunk_hnsw_index_async() -> Result<(), RagError> {
+    run_on(Lane::Heavy, "rebuild_chunk_hnsw_index", rebuild_chunk_hnsw_index).await
+}
+
+/// `rebuild_chunk_bm25_index` on the heavy pool.
+pub async fn rebuild_chunk_bm25_index_async() -> Result<(), RagError> {
+    run_on(Lane::Heavy, "rebuild_chunk_bm25_index", rebuild_chunk_bm25_index).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ingest_jobs::block_on;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_run_on_resolves_results_and_panics() {
+        assert_eq!(block_on(run_on(Lane::Query, "answer", || Ok(42))).unwrap(), 42);
+
+        let err = block_on(run_on(Lane::Heavy, "rebuild", || -> Result<(), RagError> { panic!("boom") }))
+            .unwrap_err();
+        assert!(matches!(err, RagError::InternalError(ref m) if m.contains("boom")));
+
+        // The heavy thread survives the panic and keeps serving jobs.
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&ran);
+        block_on(run_on(Lane::Heavy, "flag", move || {
+            flag.store(true, Ordering::SeqCst);
+            Ok(())
+        }))
+        .unwrap();
+        assert!(ran.load(Ordering::SeqCst));
+    }
+}
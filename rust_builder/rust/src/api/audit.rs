@@ -0,0 +1,389 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Integrity audit of stored data and indexes.
+//!
+//! `audit()` runs SQLite's `quick_check`, recomputes source content
+//! hashes, validates embedding blobs, looks for orphaned rows and compares
+//! the in-memory indexes with the database. Every finding carries a stable
+//! `code` and a severity so the app can log, alert or trigger a rebuild.
+//! Meant to run periodically (e.g. from the maintenance window).
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection};
+
+use crate::api::bm25_search::bm25_get_document_count;
+use crate::api::content_crypto::decrypt_content;
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::stored_dimension;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::hnsw_point_count;
+use crate::api::source_rag::hash_content;
+use crate::api::tenant::active_tenant;
+
+/// Severities, in increasing order.
+pub const SEVERITY_INFO: &str = "info";
+pub const SEVERITY_WARNING: &str = "warning";
+pub const SEVERITY_ERROR: &str = "error";
+pub const SEVERITY_CRITICAL: &str = "critical";
+
+/// Issues kept in a report; `issue_count` still counts all of them.
+const MAX_REPORTED_ISSUES: usize = 200;
+
+static LAST_REPORT: Lazy<RwLock<Option<AuditReport>>> = Lazy::new(|| RwLock::new(None));
+
+#[derive(Debug, Clone)]
+pub struct AuditIssue {
+    /// One of the `SEVERITY_*` values.
+    pub severity: String,
+    /// Stable identifier, e.g. "content_hash_mismatch".
+    pub code: String,
+    pub message: String,
+    pub source_id: Option<i64>,
+    pub chunk_id: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditReport {
+    pub started_at: i64,
+    pub duration_ms: u64,
+    pub sources_checked: u64,
+    pub chunks_checked: u64,
+    /// First `MAX_REPORTED_ISSUES` findings.
+    pub issues: Vec<AuditIssue>,
+    pub issue_count: u32,
+    /// Highest severity found, or `None` when the audit is clean.
+    pub max_severity: Option<String>,
+}
+
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        SEVERITY_CRITICAL => 3,
+        SEVERITY_ERROR => 2,
+        SEVERITY_WARNING => 1,
+        _ => 0,
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Findings {
+    issues: Vec<AuditIssue>,
+    count: u32,
+    max_severity: Option<&'static str>,
+}
+
+impl Findings {
+    fn add(&mut self, severity: &'static str, code: &str, message: String, source_id: Option<i64>, chunk_id: Option<i64>) {
+        self.count += 1;
+        if self.max_severity.is_none_or(|max| severity_rank(severity) > severity_rank(max)) {
+            self.max_severity = Some(severity);
+        }
+        if self.issues.len() < MAX_REPORTED_ISSUES {
+            self.issues.push(AuditIssue {
+                severity: severity.to_string(),
+                code: code.to_string(),
+                message,
+                source_id,
+                chunk_id,
+            });
+        }
+    }
+}
+
+fn check_sqlite(conn: &Connection, findings: &mut Findings) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("PRAGMA quick_check")?;
+    let results: Vec<String> = stmt.query_map([], |row| row.get(0))?.collect::<rusqlite::Result<_>>()?;
+    for result in results.into_iter().filter(|r| r != "ok") {
+        findings.add(SEVERITY_CRITICAL, "sqlite_corruption", result, None, None);
+    }
+    Ok(())
+}
+
+fn check_sources(conn: &Connection, tenant_id: &str, findings: &mut Findings) -> rusqlite::Result<u64> {
+    let mut stmt = conn.prepare("SELECT id, content, content_hash FROM sources WHERE tenant_id = ?1")?;
+    let rows = stmt.query_map(params![tenant_id], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?))
+    })?;
+    let mut checked = 0;
+    for row in rows {
+        let (source_id, stored, stored_hash) = row?;
+        checked += 1;
+        let content = match decrypt_content(stored) {
+            Ok(content) => content,
+            Err(e) => {
+                findings.add(SEVERITY_ERROR, "content_unreadable", e.to_string(), Some(source_id), None);
+                continue;
+            }
+        };
+        match stored_hash {
+            Some(hash) if hash != hash_content(&content, tenant_id) => findings.add(
+                SEVERITY_ERROR,
+                "content_hash_mismatch",
+                "Source content does not match its stored hash".to_string(),
+                Some(source_id),
+                None,
+            ),
+            Some(_) => {}
+            None => findings.add(
+                SEVERITY_INFO,
+                "content_hash_missing",
+                "Source has no content hash (duplicate detection disabled for it)".to_string(),
+                Some(source_id),
+                None,
+            ),
+        }
+    }
+    Ok(checked)
+}
+
+struct ChunkRow {
+    chunk_id: i64,
+    source_id: i64,
+    embedding_bytes: Option<i64>,
+    /// Dimension decoded from the blob's length and header.
+    embedding_dim: Option<usize>,
+    /// `None` when the chunk's source row is missing.
+    joined_source: Option<i64>,
+    source_tenant: Option<String>,
+}
+
+fn check_chunks(conn: &Connection, tenant_id: &str, findings: &mut Findings) -> rusqlite::Result<u64> {
+    let mut stmt = conn.prepare(
+        "SELECT c.id, c.source_id, length(c.embedding), substr(c.embedding, 1, 9), s.id, s.tenant_id
+         FROM chunks c LEFT JOIN sources s ON s.id = c.source_id
+         WHERE c.tenant_id = ?1",
+    )?;
+    let rows: Vec<ChunkRow> = stmt
+        .query_map(params![tenant_id], |row| {
+            let embedding_bytes: Option<i64> = row.get(2)?;
+            let prefix: Option<Vec<u8>> = row.get(3)?;
+            Ok(ChunkRow {
+                chunk_id: row.get(0)?,
+                source_id: row.get(1)?,
+                embedding_bytes,
+                embedding_dim: stored_dimension(embedding_bytes.unwrap_or(0) as usize, &prefix.unwrap_or_default()),
+                joined_source: row.get(4)?,
+                source_tenant: row.get(5)?,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    // The most common dimension is taken as the corpus dimension.
+    let mut dims: HashMap<usize, u64> = HashMap::new();
+    for row in &rows {
+        if let Some(dim) = row.embedding_dim {
+            *dims.entry(dim).or_default() += 1;
+        }
+    }
+    let expected_dim = dims.into_iter().max_by_key(|(dim, count)| (*count, *dim)).map(|(dim, _)| dim);
+
+    for row in &rows {
+        let (chunk, source) = (Some(row.chunk_id), Some(row.source_id));
+        match row.embedding_dim {
+            Some(d) => {
+                if expected_dim.is_some_and(|dim| dim != d) {
+                    findings.add(
+                        SEVERITY_ERROR,
+                        "embedding_dimension_mismatch",
+                        format!("Embedding has {} dims, corpus uses {}", d, expected_dim.unwrap_or(0)),
+                        source,
+                        chunk,
+                    );
+                }
+            }
+            _ => findings.add(
+                SEVERITY_ERROR,
+                "embedding_invalid",
+                format!("Embedding blob is {} bytes", row.embedding_bytes.unwrap_or(0)),
+                source,
+                chunk,
+            ),
+        }
+        match (row.joined_source, &row.source_tenant) {
+            (None, _) => findings.add(
+                SEVERITY_ERROR,
+                "orphaned_chunk",
+                format!("Chunk references missing source {}", row.source_id),
+                source,
+                chunk,
+            ),
+            (Some(_), Some(t)) if t != tenant_id => findings.add(
+                SEVERITY_ERROR,
+                "tenant_mismatch",
+                format!("Chunk belongs to {} but its source to {}", tenant_id, t),
+                source,
+                chunk,
+            ),
+            _ => {}
+        }
+    }
+    Ok(rows.len() as u64)
+}
+
+/// Rows in side tables pointing at chunks that no longer exist. These
+/// tables are shared by all tenants, so the check is global.
+fn check_dangling_references(conn: &Connection, findings: &mut Findings) -> rusqlite::Result<()> {
+    for table in ["chunk_entities", "kg_triples"] {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+            params![table],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            continue;
+        }
+        let dangling: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {} WHERE chunk_id NOT IN (SELECT id FROM chunks)", table),
+            [],
+            |row| row.get(0),
+        )?;
+        if dangling > 0 {
+            findings.add(
+                SEVERITY_WARNING,
+                "dangling_reference",
+                format!("{} rows in {} reference deleted chunks", dangling, table),
+                None,
+                None,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn check_index_parity(chunk_count: u64, hnsw_points: Option<u64>, bm25_docs: u64, findings: &mut Findings) {
+    if let Some(points) = hnsw_points.filter(|p| *p != chunk_count) {
+        findings.add(
+            SEVERITY_WARNING,
+            "hnsw_count_mismatch",
+            format!("HNSW has {} points for {} chunks; rebuild the index", points, chunk_count),
+            None,
+            None,
+        );
+    }
+    if bm25_docs > 0 && bm25_docs != chunk_count {
+        findings.add(
+            SEVERITY_WARNING,
+            "bm25_count_mismatch",
+            format!("BM25 has {} documents for {} chunks; rebuild the index", bm25_docs, chunk_count),
+            None,
+            None,
+        );
+    }
+}
+
+fn audit_database(conn: &Connection, tenant_id: &str, findings: &mut Findings) -> rusqlite::Result<(u64, u64)> {
+    check_sqlite(conn, findings)?;
+    let sources = check_sources(conn, tenant_id, findings)?;
+    let chunks = check_chunks(conn, tenant_id, findings)?;
+    check_dangling_references(conn, findings)?;
+    Ok((sources, chunks))
+}
+
+/// Audit the active tenant's data and indexes (plus database-wide checks).
+pub fn audit() -> Result<AuditReport, RagError> {
+    let started = Instant::now();
+    let started_at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+    let tenant_id = active_tenant();
+    let mut findings = Findings::default();
+
+    let conn = get_connection()?;
+    let (sources_checked, chunks_checked) = audit_database(&conn, &tenant_id, &mut findings).map_err(db_error)?;
+    drop(conn);
+    check_index_parity(
+        chunks_checked,
+        hnsw_point_count().map(|n| n as u64),
+        bm25_get_document_count() as u64,
+        &mut findings,
+    );
+
+    let report = AuditReport {
+        started_at,
+        duration_ms: started.elapsed().as_millis() as u64,
+        sources_checked,
+        chunks_checked,
+        issues: findings.issues,
+        issue_count: findings.count,
+        max_severity: findings.max_severity.map(str::to_string),
+    };
+    match &report.max_severity {
+        Some(severity) => warn!("[audit] {} issues, max severity {}", report.issue_count, severity),
+        None => info!("[audit] Clean ({} sources, {} chunks)", sources_checked, chunks_checked),
+    }
+    *LAST_REPORT.write().unwrap() = Some(report.clone());
+    Ok(report)
+}
+
+/// Report of the most recent `audit()` call in this process.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_last_audit_report() -> Option<AuditReport> {
+    LAST_REPORT.read().unwrap().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_database_finds_corruption() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, content TEXT NOT NULL, content_hash TEXT,
+                 tenant_id TEXT NOT NULL DEFAULT 'default');
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER NOT NULL, embedding BLOB NOT NULL,
+                 tenant_id TEXT NOT NULL DEFAULT 'default');
+             CREATE TABLE kg_triples (id INTEGER PRIMARY KEY, chunk_id INTEGER NOT NULL);
+             INSERT INTO kg_triples (chunk_id) VALUES (99);",
+        )
+        .unwrap();
+        let good_hash = hash_content("intact", "default");
+        conn.execute(
+            "INSERT INTO sources (id, content, content_hash) VALUES (1, 'intact', ?1), (2, 'edited', ?1)",
+            params![good_hash],
+        )
+        .unwrap();
+        let dim4 = vec![0u8; 16];
+        conn.execute(
+            "INSERT INTO chunks (id, source_id, embedding) VALUES (1, 1, ?1), (2, 1, ?1), (3, 1, ?2), (4, 5, ?1)",
+            params![dim4, vec![0u8; 7]],
+        )
+        .unwrap();
+
+        let mut findings = Findings::default();
+        let (sources, chunks) = audit_database(&conn, "default", &mut findings).unwrap();
+        assert_eq!((sources, chunks), (2, 4));
+        let codes: Vec<(&str, Option<i64>)> =
+            findings.issues.iter().map(|i| (i.code.as_str(), i.chunk_id.or(i.source_id))).collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("content_hash_mismatch", Some(2)),
+                ("embedding_invalid", Some(3)),
+                ("orphaned_chunk", Some(4)),
+                ("dangling_reference", None),
+            ]
+        );
+        assert_eq!(findings.max_severity, Some(SEVERITY_ERROR));
+
+        check_index_parity(4, Some(3), 0, &mut findings);
+        assert_eq!(findings.issues.last().unwrap().code, "hnsw_count_mismatch");
+    }
+}
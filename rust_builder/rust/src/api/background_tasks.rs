@@ -0,0 +1,342 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Background worker for heavy operations.
+//!
+//! Index rebuilds, buffer merges, snapshot exports and re-embedding are
+//! submitted here instead of running on whichever thread FRB calls them
+//! on. A single low-priority `rag-worker` thread runs tasks one at a time
+//! in submission order and waits for in-flight searches (see
+//! `query_guard`) before starting each one, so interactive queries keep
+//! priority. Submission returns a task id for `get_background_task`;
+//! queued tasks can be cancelled. Tasks are not persisted across restarts.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flutter_rust_bridge::DartFnFuture;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+
+use crate::api::error::RagError;
+use crate::api::incremental_index::{merge_tenant_buffer, BufferTable};
+use crate::api::ingest_jobs::block_on;
+use crate::api::maintenance::{lower_thread_priority, wait_for_idle_queries};
+use crate::api::panic_report::catch_panic;
+use crate::api::reembedding::{start_reembedding_job, ReembeddingJob};
+use crate::api::snapshot::create_snapshot;
+use crate::api::source_rag::{rebuild_chunk_bm25_index, rebuild_chunk_hnsw_index};
+use crate::api::tenant::active_tenant;
+
+/// Task states.
+pub const TASK_QUEUED: &str = "queued";
+pub const TASK_RUNNING: &str = "running";
+pub const TASK_COMPLETED: &str = "completed";
+pub const TASK_FAILED: &str = "failed";
+pub const TASK_CANCELLED: &str = "cancelled";
+
+/// Finished tasks kept for status queries; older ones are dropped.
+const MAX_FINISHED_TASKS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundTaskKind {
+    /// Rebuild the chunk HNSW and BM25 indexes from the database.
+    IndexRebuild,
+    /// Fold the incremental buffer into the HNSW index.
+    BufferMerge,
+    /// Write a snapshot archive (`create_snapshot`).
+    SnapshotExport,
+    /// Re-embed every chunk (`start_reembedding_job`).
+    Reembedding,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackgroundTask {
+    pub id: i64,
+    pub kind: BackgroundTaskKind,
+    /// Tenant active at submission; the task fails if it changed.
+    pub tenant_id: String,
+    /// One of the `TASK_*` values.
+    pub state: String,
+    /// Short outcome summary for completed tasks.
+    pub detail: Option<String>,
+    pub error: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: i64,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+}
+
+type Job = Box<dyn FnOnce() -> Result<Option<String>, RagError> + Send>;
+
+struct Pending {
+    id: i64,
+    tenant_id: String,
+    job: Job,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct TaskQueue {
+    next_id: i64,
+    pending: VecDeque<Pending>,
+    tasks: Vec<BackgroundTask>,
+    worker_running: bool,
+}
+
+static QUEUE: Lazy<Mutex<TaskQueue>> = Lazy::new(|| Mutex::new(TaskQueue::default()));
+
+fn now_secs() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64)
+}
+
+fn is_finished(task: &BackgroundTask) -> bool {
+    task.state != TASK_QUEUED && task.state != TASK_RUNNING
+}
+
+/// Drop the oldest finished tasks beyond `MAX_FINISHED_TASKS`.
+fn prune_finished(tasks: &mut Vec<BackgroundTask>) {
+    let mut excess = tasks.iter().filter(|t| is_finished(t)).count().saturating_sub(MAX_FINISHED_TASKS);
+    tasks.retain(|t| {
+        if excess > 0 && is_finished(t) {
+            excess -= 1;
+            false
+        } else {
+            true
+        }
+    });
+}
+
+fn submit(kind: BackgroundTaskKind, job: Job) -> Result<i64, RagError> {
+    let tenant_id = active_tenant();
+    let mut queue = QUEUE.lock().unwrap();
+    if !queue.worker_running {
+        // The worker takes the lock before touching the queue, so it cannot
+        // observe the queue until this submission is complete.
+        std::thread::Builder::new()
+            .name("rag-worker".to_string())
+            .spawn(run_worker)
+            .map_err(|e| RagError::InternalError(format!("Failed to start background worker: {}", e)))?;
+        queue.worker_running = true;
+    }
+    queue.next_id += 1;
+    let id = queue.next_id;
+    queue.tasks.push(BackgroundTask {
+        id,
+        kind,
+        tenant_id: tenant_id.clone(),
+        state: TASK_QUEUED.to_string(),
+        detail: None,
+        error: None,
+        submitted_at: now_secs(),
+        started_at: None,
+        finished_at: None,
+    });
+    queue.pending.push_back(Pending { id, tenant_id, job });
+    info!("[background] Queued task {} ({:?})", id, kind);
+    Ok(id)
+}
+
+fn update_task(id: i64, update: impl FnOnce(&mut BackgroundTask)) {
+    let mut queue = QUEUE.lock().unwrap();
+    if let Some(task) = queue.tasks.iter_mut().find(|t| t.id == id) {
+        update(task);
+    }
+    prune_finished(&mut queue.tasks);
+}
+
+fn run_worker() {
+    lower_thread_priority();
+    loop {
+        // Interactive searches go first; only start a task while none run.
+        wait_for_idle_queries();
+        let next = {
+            let mut queue = QUEUE.lock().unwrap();
+            let next = queue.pending.pop_front();
+            if next.is_none() {
+                queue.worker_running = false;
+            }
+            next
+        };
+        let Some(Pending { id, tenant_id, job }) = next else {
+            break;
+        };
+        update_task(id, |t| {
+            t.state = TASK_RUNNING.to_string();
+            t.started_at = Some(now_secs());
+        });
+        let outcome = if active_tenant() != tenant_id {
+            Err(RagError::InvalidInput(format!("Active tenant changed from {}", tenant_id)))
+        } else {
+            catch_panic("background task", job).and_then(|result| result)
+        };
+        match &outcome {
+            Ok(_) => info!("[background] Task {} completed", id),
+            Err(e) => warn!("[background] Task {} failed: {}", id, e),
+        }
+        update_task(id, |t| {
+            t.finished_at = Some(now_secs());
+            match outcome {
+                Ok(detail) => {
+                    t.state = TASK_COMPLETED.to_string();
+                    t.detail = detail;
+                }
+                Err(e) => {
+                    t.state = TASK_FAILED.to_string();
+                    t.error = Some(e.to_string());
+                }
+            }
+        });
+    }
+}
+
+/// Queue a rebuild of the active tenant's chunk HNSW and BM25 indexes.
+pub fn submit_index_rebuild() -> Result<i64, RagError> {
+    submit(
+        BackgroundTaskKind::IndexRebuild,
+        Box::new(|| {
+            rebuild_chunk_hnsw_index()?;
+            rebuild_chunk_bm25_index()?;
+            Ok(None)
+        }),
+    )
+}
+
+/// Queue a merge of the active tenant's incremental buffer of `table`
+/// into HNSW (see `merge_buffer_into_hnsw`).
+pub fn submit_buffer_merge(table: BufferTable) -> Result<i64, RagError> {
+    submit(
+        BackgroundTaskKind::BufferMerge,
+        Box::new(move || {
+            let merged = merge_tenant_buffer(&active_tenant(), table)?;
+            Ok(Some(format!("{} vectors merged", merged)))
+        }),
+    )
+}
+
+/// Queue a snapshot export to `path`.
+pub fn submit_snapshot_export(path: String) -> Result<i64, RagError> {
+    submit(
+        BackgroundTaskKind::SnapshotExport,
+        Box::new(move || {
+            let manifest = create_snapshot(path)?;
+            Ok(Some(format!("{} sources, {} chunks", manifest.source_count, manifest.chunk_count)))
+        }),
+    )
+}
+
+/// Queue a re-embedding job; arguments are as for `start_reembedding_job`.
+/// The Dart callbacks are driven from the worker thread.
+pub fn submit_reembedding(
+    model_name: String,
+    batch_size: u32,
+    embed_batch: impl Fn(Vec<String>) -> DartFnFuture<Vec<Vec<f32>>> + Send + Sync + 'static,
+    on_progress: impl Fn(ReembeddingJob) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<i64, RagError> {
+    submit(
+        BackgroundTaskKind::Reembedding,
+        Box::new(move || {
+            let job = block_on(start_reembedding_job(model_name, batch_size, embed_batch, on_progress))?;
+            Ok(Some(format!("{}: {}/{} chunks", job.state, job.done, job.total)))
+        }),
+    )
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_background_task(task_id: i64) -> Option<BackgroundTask> {
+    QUEUE.lock().unwrap().tasks.iter().find(|t| t.id == task_id).cloned()
+}
+
+/// Known tasks, oldest first.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_background_tasks() -> Vec<BackgroundTask> {
+    QUEUE.lock().unwrap().tasks.clone()
+}
+
+/// Cancel a queued task. Returns false if it already started or finished.
+#[flutter_rust_bridge::frb(sync)]
+pub fn cancel_background_task(task_id: i64) -> bool {
+    let mut queue = QUEUE.lock().unwrap();
+    let Some(pos) = queue.pending.iter().position(|p| p.id == task_id) else {
+        return false;
+    };
+    queue.pending.remove(pos);
+    if let Some(task) = queue.tasks.iter_mut().find(|t| t.id == task_id) {
+        task.state = TASK_CANCELLED.to_string();
+        task.finished_at = Some(now_secs());
+    }
+    prune_finished(&mut queue.tasks);
+    true
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_background_worker_running() -> bool {
+    QUEUE.lock().unwrap().worker_running
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn wait_finished(id: i64) -> BackgroundTask {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let task = get_background_task(id).expect("task is tracked");
+            if is_finished(&task) || Instant::now() > deadline {
+                return task;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_tasks_run_in_order_and_contain_failures() {
+        let ok = submit(BackgroundTaskKind::IndexRebuild, Box::new(|| Ok(Some("done".to_string())))).unwrap();
+        let panics = submit(BackgroundTaskKind::BufferMerge, Box::new(|| panic!("boom"))).unwrap();
+        assert!(ok < panics);
+
+        let ok = wait_finished(ok);
+        assert_eq!(ok.state, TASK_COMPLETED);
+        assert_eq!(ok.detail.as_deref(), Some("done"));
+        let panics = wait_finished(panics);
+        assert_eq!(panics.state, TASK_FAILED);
+        assert!(panics.error.unwrap().contains("boom"));
+        assert!(!cancel_background_task(panics.id));
+    }
+
+    #[test]
+    fn test_prune_finished_keeps_active_tasks() {
+        let task = |id: i64, state: &str| BackgroundTask {
+            id,
+            kind: BackgroundTaskKind::SnapshotExport,
+            tenant_id: "default".to_string(),
+            state: state.to_string(),
+            detail: None,
+            error: None,
+            submitted_at: 0,
+            started_at: None,
+            finished_at: None,
+        };
+        let mut tasks: Vec<BackgroundTask> = (0..MAX_FINISHED_TASKS as i64 + 2).map(|id| task(id, TASK_COMPLETED)).collect();
+        tasks.insert(0, task(-1, TASK_QUEUED));
+        prune_finished(&mut tasks);
+        assert_eq!(tasks.len(), MAX_FINISHED_TASKS + 1);
+        assert_eq!(tasks[0].id, -1);
+        assert_eq!(tasks[1].id, 2);
+    }
+}
@@ -0,0 +1,386 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! End-to-end pipeline benchmark.
+//!
+//! Measures per-stage latency (parse, chunk, tokenize, index, search) on the
+//! current device. Runs against private, throw-away indexes so the app's
+//! live HNSW/BM25 indexes are never touched. Embeddings are computed on the
+//! Dart side, so the index/search stages use deterministic synthetic vectors
+//! of the configured dimension.
+//!
+//! `evaluate_index` instead measures the live chunk index on the user's
+//! own data: recall@k against brute-force ground truth, plus latency.
+
+use std::collections::HashSet;
+use std::time::Instant;
+use anyhow::Result;
+use hnsw_rs::prelude::*;
+use log::info;
+use rusqlite::params;
+
+use crate::api::bm25_search::InvertedIndex;
+use crate::api::db_pool::get_connection;
+use crate::api::document_parser::extract_text_from_document;
+use crate::api::embedding_storage::decode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::{get_index_config, is_hnsw_index_loaded, search_hnsw, DistanceMetric};
+use crate::api::semantic_chunker::semantic_chunk;
+use crate::api::tenant::active_tenant;
+use crate::api::tokenizer::tokenize;
+
+/// Filler used when no document is supplied (~1.5KB, mixed en/ko).
+const SAMPLE_PARAGRAPH: &str = "Real-world asset tokenization represents ownership of physical assets \
+as tokens on a blockchain. Settlement happens on-chain, while custody remains with a regulated entity. \
+스마트 계약은 조건이 충족되면 자동으로 실행되는 프로그램입니다. Fees depend on the issuance platform \
+and the secondary market venue.\n\n";
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkConfig {
+    /// Document to parse (PDF or DOCX bytes). Synthetic text when `None`.
+    pub document_bytes: Option<Vec<u8>>,
+    /// Paragraph repetitions for the synthetic document.
+    pub synthetic_paragraphs: u32,
+    pub chunk_max_chars: i32,
+    pub embedding_dim: u32,
+    pub search_iterations: u32,
+    pub top_k: u32,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            document_bytes: None,
+            synthetic_paragraphs: 200,
+            chunk_max_chars: 500,
+            embedding_dim: 384,
+            search_iterations: 50,
+            top_k: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkStage {
+    /// "parse", "chunk", "tokenize", "index" or "search".
+    pub name: String,
+    pub duration_ms: f64,
+    /// Stage was not run (e.g. tokenizer not initialized).
+    pub skipped: bool,
+    /// Resident memory after the stage (KB); `None` where the OS doesn't expose it.
+    pub rss_kb: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    pub stages: Vec<BenchmarkStage>,
+    pub document_chars: u32,
+    pub chunk_count: u32,
+    pub token_count: u32,
+    /// Mean and p95 latency of a single hybrid (vector + BM25) search.
+    pub search_mean_ms: f64,
+    pub search_p95_ms: f64,
+    /// Peak resident memory of the process (KB), if available.
+    pub peak_rss_kb: Option<u64>,
+    pub total_ms: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexEvaluation {
+    /// Queries actually run (at most the number of stored chunks).
+    pub sample_size: u32,
+    pub top_k: u32,
+    pub corpus_size: u64,
+    /// Mean share of the exact top-k the index returned.
+    pub recall_at_k: f64,
+    /// Worst single-query recall.
+    pub min_recall: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+    pub latency_p99_ms: f64,
+    /// Mean latency of the brute-force scan, for comparison.
+    pub exact_mean_ms: f64,
+}
+
+/// Read a `/proc/self/status` field in KB (Linux/Android only).
+fn read_proc_status_kb(field: &str) -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find(|l| l.starts_with(field))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+/// Deterministic pseudo-embedding (xorshift seeded by FNV-1a of the text).
+fn synthetic_embedding(text: &str, dim: usize) -> Vec<f32> {
+    let mut state: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        state ^= byte as u64;
+        state = state.wrapping_mul(0x100000001b3);
+    }
+    (0..dim)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 2000) as f32 / 1000.0 - 1.0
+        })
+        .collect()
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Nearest-rank percentile of ascending `sorted` values (0 when empty).
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    sorted
+        .get(((sorted.len() as f64 * p).ceil() as usize).saturating_sub(1))
+        .copied()
+        .unwrap_or(0.0)
+}
+
+fn norm(embedding: &[f32]) -> f32 {
+    embedding.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+/// Ids of the `k` points closest to `query` under `metric`, ties by id.
+fn exact_top_k(points: &[(i64, Vec<f32>, f32)], query: &[f32], k: usize, metric: DistanceMetric) -> Vec<i64> {
+    let query_norm = norm(query);
+    let mut scored: Vec<(f32, i64)> = points
+        .iter()
+        .filter(|(_, e, _)| e.len() == query.len())
+        .map(|(id, e, n)| {
+            let dot: f32 = e.iter().zip(query).map(|(a, b)| a * b).sum();
+            (metric.distance(dot, query_norm, *n), *id)
+        })
+        .collect();
+    scored.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().take(k).map(|(_, id)| id).collect()
+}
+
+/// Share of `truth` found in `found` (1 when `truth` is empty).
+fn recall(found: &[i64], truth: &[i64]) -> f64 {
+    if truth.is_empty() {
+        return 1.0;
+    }
+    let found: HashSet<i64> = found.iter().copied().collect();
+    truth.iter().filter(|id| found.contains(id)).count() as f64 / truth.len() as f64
+}
+
+/// Measure the active tenant's chunk index against brute force.
+///
+/// Runs `sample_size` randomly chosen stored embeddings as queries and
+/// compares the index's top `top_k` with an exact scan of every stored
+/// embedding under the configured metric. Read-only; needs a loaded
+/// index. The scan holds all embeddings in memory while it runs.
+pub fn evaluate_index(sample_size: u32, top_k: u32) -> Result<IndexEvaluation, RagError> {
+    if !is_hnsw_index_loaded() {
+        return Err(RagError::InvalidInput("HNSW index is not loaded".to_string()));
+    }
+    let top_k = top_k.max(1) as usize;
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    let points: Vec<(i64, Vec<f32>, f32)> = conn
+        .prepare("SELECT id, embedding FROM chunks WHERE tenant_id = ?1")
+        .map_err(db_error)?
+        .query_map(params![tenant_id], |row| {
+            let embedding = decode_embedding(&row.get::<_, Vec<u8>>(1)?);
+            let n = norm(&embedding);
+            Ok((row.get(0)?, embedding, n))
+        })
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    let queries: Vec<Vec<f32>> = conn
+        .prepare("SELECT embedding FROM chunks WHERE tenant_id = ?1 ORDER BY RANDOM() LIMIT ?2")
+        .map_err(db_error)?
+        .query_map(params![tenant_id, sample_size], |row| Ok(decode_embedding(&row.get::<_, Vec<u8>>(0)?)))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    drop(conn);
+
+    let metric = get_index_config().metric;
+    let mut latencies = Vec::with_capacity(queries.len());
+    let mut recalls = Vec::with_capacity(queries.len());
+    let mut exact_ms = 0.0;
+    for query in &queries {
+        let start = Instant::now();
+        let found: Vec<i64> = search_hnsw(query.clone(), top_k)?.into_iter().map(|r| r.id).collect();
+        latencies.push(elapsed_ms(start));
+        let start = Instant::now();
+        let truth = exact_top_k(&points, query, top_k, metric);
+        exact_ms += elapsed_ms(start);
+        recalls.push(recall(&found, &truth));
+    }
+
+    latencies.sort_by(f64::total_cmp);
+    let runs = queries.len().max(1) as f64;
+    let evaluation = IndexEvaluation {
+        sample_size: queries.len() as u32,
+        top_k: top_k as u32,
+        corpus_size: points.len() as u64,
+        recall_at_k: if recalls.is_empty() { 0.0 } else { recalls.iter().sum::<f64>() / runs },
+        min_recall: recalls.iter().copied().reduce(f64::min).unwrap_or(0.0),
+        latency_p50_ms: percentile(&latencies, 0.5),
+        latency_p95_ms: percentile(&latencies, 0.95),
+        latency_p99_ms: percentile(&latencies, 0.99),
+        exact_mean_ms: exact_ms / runs,
+    };
+    info!(
+        "[benchmark] Index recall@{} = {:.3} over {} queries (p95 {:.2}ms)",
+        top_k, evaluation.recall_at_k, evaluation.sample_size, evaluation.latency_p95_ms
+    );
+    Ok(evaluation)
+}
+
+struct StageRecorder {
+    stages: Vec<BenchmarkStage>,
+}
+
+impl StageRecorder {
+    fn record(&mut self, name: &str, start: Instant, skipped: bool) {
+        self.stages.push(BenchmarkStage {
+            name: name.to_string(),
+            duration_ms: elapsed_ms(start),
+            skipped,
+            rss_kb: read_proc_status_kb("VmRSS:"),
+        });
+    }
+}
+
+/// Run the pipeline benchmark and return a per-stage report.
+pub fn run_benchmark(config: BenchmarkConfig) -> Result<BenchmarkReport> {
+    info!("[benchmark] Starting with {:?}", BenchmarkConfig { document_bytes: None, ..config.clone() });
+    let total_start = Instant::now();
+    let mut recorder = StageRecorder { stages: Vec::new() };
+
+    // 1. Parse
+    let start = Instant::now();
+    let text = match config.document_bytes {
+        Some(bytes) => extract_text_from_document(bytes)?,
+        None => SAMPLE_PARAGRAPH.repeat(config.synthetic_paragraphs.max(1) as usize),
+    };
+    recorder.record("parse", start, false);
+
+    // 2. Chunk
+    let start = Instant::now();
+    let chunks = semantic_chunk(text.clone(), config.chunk_max_chars);
+    recorder.record("chunk", start, false);
+
+    // 3. Tokenize (only when the app has initialized a tokenizer)
+    let start = Instant::now();
+    let mut token_count = 0usize;
+    let mut tokenizer_ready = true;
+    for chunk in &chunks {
+        match tokenize(chunk.content.clone()) {
+            Ok(ids) => token_count += ids.len(),
+            Err(_) => {
+                tokenizer_ready = false;
+                break;
+            }
+        }
+    }
+    recorder.record("tokenize", start, !tokenizer_ready);
+
+    // 4. Index (private HNSW + BM25)
+    let dim = config.embedding_dim.max(1) as usize;
+    let start = Instant::now();
+    let hnsw: Hnsw<f32, DistCosine> = Hnsw::new(16, chunks.len().max(1), 32, 100, DistCosine);
+    let mut bm25 = InvertedIndex::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let embedding = synthetic_embedding(&chunk.content, dim);
+        hnsw.insert((&embedding, i));
+        bm25.add_document(i as i64, &chunk.content);
+    }
+    recorder.record("index", start, chunks.is_empty());
+
+    // 5. Search
+    let start = Instant::now();
+    let top_k = config.top_k.max(1) as usize;
+    let mut latencies: Vec<f64> = Vec::new();
+    if !chunks.is_empty() {
+        for i in 0..config.search_iterations as usize {
+            let query = &chunks[i % chunks.len()].content;
+            let query_embedding = synthetic_embedding(&format!("q{}{}", i, query), dim);
+            let query_start = Instant::now();
+            let _ = hnsw.search(&query_embedding, top_k, std::cmp::max(100, top_k * 5));
+            let _ = bm25.search(query, top_k);
+            latencies.push(elapsed_ms(query_start));
+        }
+    }
+    recorder.record("search", start, latencies.is_empty());
+
+    latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let search_mean_ms = if latencies.is_empty() { 0.0 } else { latencies.iter().sum::<f64>() / latencies.len() as f64 };
+    let search_p95_ms = percentile(&latencies, 0.95);
+
+    let report = BenchmarkReport {
+        stages: recorder.stages,
+        document_chars: text.chars().count() as u32,
+        chunk_count: chunks.len() as u32,
+        token_count: token_count as u32,
+        search_mean_ms,
+        search_p95_ms,
+        peak_rss_kb: read_proc_status_kb("VmHWM:"),
+        total_ms: elapsed_ms(total_start),
+    };
+    info!(
+        "[benchmark] Done in {:.1}ms ({} chunks, search mean {:.2}ms)",
+        report.total_ms, report.chunk_count, report.search_mean_ms
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_benchmark_synthetic() {
+        let config = BenchmarkConfig {
+            synthetic_paragraphs: 10,
+            embedding_dim: 8,
+            search_iterations: 5,
+            ..Default::default()
+        };
+        let report = run_benchmark(config).unwrap();
+        let names: Vec<&str> = report.stages.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["parse", "chunk", "tokenize", "index", "search"]);
+        assert!(report.chunk_count > 0);
+        assert!(!report.stages[4].skipped);
+    }
+
+    #[test]
+    fn test_exact_top_k_and_recall() {
+        let points: Vec<(i64, Vec<f32>, f32)> = [(1, vec![1.0, 0.0]), (2, vec![0.0, 1.0]), (3, vec![0.9, 0.1])]
+            .into_iter()
+            .map(|(id, e)| {
+                let n = norm(&e);
+                (id, e, n)
+            })
+            .collect();
+        assert_eq!(exact_top_k(&points, &[1.0, 0.0], 2, DistanceMetric::Cosine), vec![1, 3]);
+        assert_eq!(recall(&[1, 2], &[1, 3]), 0.5);
+        assert_eq!(recall(&[], &[]), 1.0);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 0.5), 2.0);
+        assert_eq!(percentile(&[], 0.95), 0.0);
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Binary-quantized flat index with full-precision rescoring.
+//!
+//! Each embedding is reduced to its sign bits (1 bit per dimension, 32x
+//! smaller than f32) and the query is compared by Hamming distance, which
+//! is a XOR and popcount per 64 dimensions. The closest
+//! `top_k * RESCORE_MULTIPLIER` candidates are then rescored with their
+//! stored embeddings from SQLite, so the final ranking is the exact
+//! configured distance metric over the shortlist.
+//!
+//! The index is in memory only and rebuilt from the chunks table whenever
+//! the tenant's vector change version has moved since it was built.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use log::{debug, info};
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use rusqlite::{params, Connection};
+
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::{blob_distance, decode_embedding};
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::get_index_config;
+use crate::api::index_freshness::change_versions;
+use crate::api::maintenance::query_guard;
+use crate::api::metrics::record_search;
+use crate::api::retrieval_state::record_chunk_retrieval;
+use crate::api::source_rag::{load_chunk_result, record_chunk_search_event, ChunkSearchResult};
+use crate::api::tenant::active_tenant;
+
+/// Candidates rescored per requested result.
+const RESCORE_MULTIPLIER: usize = 8;
+
+struct BinaryIndex {
+    dimension: usize,
+    /// `u64` words per vector.
+    words: usize,
+    ids: Vec<i64>,
+    /// `ids.len() * words` packed sign bits.
+    bits: Vec<u64>,
+    /// Vector change version of the chunks table when built.
+    version: i64,
+}
+
+static BINARY_INDEX: Lazy<RwLock<HashMap<String, BinaryIndex>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Pack the sign of each component into `dimension.div_ceil(64)` words;
+/// bit `i` is set when `embedding[i] > 0`.
+fn pack_signs(embedding: &[f32]) -> Vec<u64> {
+    let mut words = vec![0u64; embedding.len().div_ceil(64)];
+    for (i, value) in embedding.iter().enumerate() {
+        if *value > 0.0 {
+            words[i / 64] |= 1 << (i % 64);
+        }
+    }
+    words
+}
+
+fn hamming(a: &[u64], b: &[u64]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+impl BinaryIndex {
+    fn build(points: &[(i64, Vec<f32>)], version: i64) -> Self {
+        let dimension = points.first().map_or(0, |(_, e)| e.len());
+        let words = dimension.div_ceil(64);
+        let kept: Vec<&(i64, Vec<f32>)> = points
+            .iter()
+            .filter(|(_, e)| e.len() == dimension)
+            .collect();
+        let bits = kept
+            .par_iter()
+            .flat_map_iter(|(_, e)| pack_signs(e))
+            .collect();
+        BinaryIndex {
+            dimension,
+            words,
+            ids: kept.iter().map(|(id, _)| *id).collect(),
+            bits,
+            version,
+        }
+    }
+
+    /// Ids of the `limit` vectors closest to `query` in Hamming distance,
+    /// nearest first.
+    fn candidates(&self, query: &[f32], limit: usize) -> Vec<i64> {
+        if query.len() != self.dimension || limit == 0 || self.ids.is_empty() {
+            return Vec::new();
+        }
+        let query = pack_signs(query);
+        let mut scored: Vec<(u32, i64)> = self
+            .bits
+            .par_chunks(self.words.max(1))
+            .zip(self.ids.par_iter())
+            .map(|(bits, id)| (hamming(&query, bits), *id))
+            .collect();
+        if scored.len() > limit {
+            scored.select_nth_unstable(limit - 1);
+            scored.truncate(limit);
+        }
+        scored.sort_unstable();
+        scored.into_iter().map(|(_, id)| id).collect()
+    }
+
+    fn size_bytes(&self) -> usize {
+        self.bits.len() * 8 + self.ids.len() * 8
+    }
+}
+
+fn load_points(conn: &Connection, tenant_id: &str) -> Result<Vec<(i64, Vec<f32>)>, RagError> {
+    let mut stmt = conn
+        .prepare("SELECT id, embedding FROM chunks WHERE tenant_id = ?1")
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map(params![tenant_id], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })
+        .map_err(db_error)?;
+    let mut points = Vec::new();
+    for row in rows {
+        let (id, blob) = row.map_err(db_error)?;
+        points.push((id, decode_embedding(&blob)));
+    }
+    Ok(points)
+}
+
+fn rebuild(conn: &Connection, tenant_id: &str, version: i64) -> Result<u32, RagError> {
+    let points = load_points(conn, tenant_id)?;
+    let index = BinaryIndex::build(&points, version);
+    let count = index.ids.len() as u32;
+    info!(
+        "[binary] Built index for tenant {} with {} points ({} bytes)",
+        tenant_id,
+        count,
+        index.size_bytes()
+    );
+    BINARY_INDEX
+        .write()
+        .unwrap()
+        .insert(tenant_id.to_string(), index);
+    Ok(count)
+}
+
+/// Rebuild the active tenant's binary index from the chunks table and
+/// return the number of points indexed.
+pub fn build_binary_index() -> Result<u32, RagError> {
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    let (version, _) = change_versions(&conn, &tenant_id).map_err(db_error)?;
+    rebuild(&conn, &tenant_id, version)
+}
+
+/// Search chunks with a Hamming-distance prefilter over the binary index,
+/// then rank the shortlist by the exact configured distance against the
+/// stored embeddings. The index is (re)built on first use and after any change
+/// to the tenant's chunks.
+pub fn search_chunks_binary(
+    query_embedding: Vec<f32>,
+    top_k: u32,
+) -> Result<Vec<ChunkSearchResult>, RagError> {
+    let _query = query_guard();
+    let started = Instant::now();
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+
+    let (version, _) = change_versions(&conn, &tenant_id).map_err(db_error)?;
+    let fresh = BINARY_INDEX
+        .read()
+        .unwrap()
+        .get(&tenant_id)
+        .is_some_and(|index| index.version == version);
+    if !fresh {
+        debug!("[binary] Index missing or stale, rebuilding");
+        rebuild(&conn, &tenant_id, version)?;
+    }
+
+    let top_k = top_k as usize;
+    let candidates = BINARY_INDEX
+        .read()
+        .unwrap()
+        .get(&tenant_id)
+        .map(|index| index.candidates(&query_embedding, top_k * RESCORE_MULTIPLIER))
+        .unwrap_or_default();
+
+    let query_norm = query_embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let metric = get_index_config().metric;
+    let mut stmt = conn
+        .prepare_cached("SELECT embedding FROM chunks WHERE id = ?1 AND tenant_id = ?2")
+        .map_err(db_error)?;
+    let mut rescored: Vec<(f32, i64)> = Vec::with_capacity(candidates.len());
+    for id in candidates {
+        let blob: Option<Vec<u8>> = stmt
+            .query_row(params![id, tenant_id], |row| row.get(0))
+            .ok();
+        if let Some(distance) = blob.and_then(|b| blob_distance(&query_embedding, query_norm, &b, metric)) {
+            rescored.push((distance, id));
+        }
+    }
+    rescored.sort_by(|a, b| a.0.total_cmp(&b.0));
+    rescored.truncate(top_k);
+
+    let results: Vec<ChunkSearchResult> = rescored
+        .into_iter()
+        .filter_map(|(distance, id)| load_chunk_result(&conn, &tenant_id, id, 1.0 - distance as f64).transpose())
+        .collect::<Result<_, _>>()?;
+
+    debug!(
+        "[binary] Found {} results in {:?}",
+        results.len(),
+        started.elapsed()
+    );
+    record_chunk_retrieval(&results);
+    record_search(started.elapsed());
+    record_chunk_search_event(started.elapsed(), &results);
+    Ok(results)
+}
+
+pub(crate) fn drop_binary_index(tenant_id: &str) {
+    BINARY_INDEX.write().unwrap().remove(tenant_id);
+}
+
+pub(crate) fn clear_all_binary_indexes() {
+    BINARY_INDEX.write().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_signs_and_hamming() {
+        let mut embedding = vec![-1.0f32; 70];
+        embedding[0] = 0.5;
+        embedding[65] = 2.0;
+        let packed = pack_signs(&embedding);
+        assert_eq!(packed, vec![1, 1 << 1]);
+        assert_eq!(hamming(&packed, &pack_signs(&[-1.0; 70])), 2);
+    }
+
+    #[test]
+    fn test_candidates_ranked_by_hamming_distance() {
+        let points = vec![
+            (1, vec![1.0, 1.0, 1.0, 1.0]),
+            (2, vec![-1.0, -1.0, -1.0, -1.0]),
+            (3, vec![1.0, 1.0, -1.0, 1.0]),
+            (4, vec![1.0, 1.0, 1.0]),
+        ];
+        let index = BinaryIndex::build(&points, 7);
+        assert_eq!(index.ids, vec![1, 2, 3]);
+        assert_eq!(index.candidates(&[0.9, 0.2, 0.4, 0.1], 2), vec![1, 3]);
+        assert!(index.candidates(&[1.0, 1.0], 2).is_empty());
+    }
+}
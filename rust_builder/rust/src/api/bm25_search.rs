@@ -18,13 +18,128 @@
 
 use log::{debug, info};
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 
-static INVERTED_INDEX: Lazy<RwLock<InvertedIndex>> =
-    Lazy::new(|| RwLock::new(InvertedIndex::new()));
+use rust_stemmers::{Algorithm, Stemmer};
 
-#[derive(Clone, Debug)]
+use crate::api::analyzers::{is_stopword, strip_korean_particle};
+use crate::api::error::RagError;
+use crate::api::fts_keyword::{fts_constraint, fts_keyword_enabled, fts_search};
+use crate::api::fuzzy_terms::{
+    allowed_edits, fuzzy_weight, get_bm25_fuzzy_matching, BkTree, Bm25FuzzyConfig,
+};
+use crate::api::progress::{Progress, PHASE_INDEX};
+use crate::api::query_normalize::normalize_query_text;
+use crate::api::query_parser::{parse_boolean_query, BooleanQuery};
+use crate::api::synonyms::expand_query;
+use crate::api::tenant::active_tenant;
+
+/// Serialized layout of an `InvertedIndex` (bincode, e.g. in snapshots),
+/// written ahead of every dump; bump on change.
+pub const BM25_FORMAT_VERSION: u32 = 3;
+
+/// One BM25 namespace per tenant.
+static INVERTED_INDEX: Lazy<RwLock<HashMap<String, InvertedIndex>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Options each tenant's index is (re)built with.
+static INDEX_OPTIONS: Lazy<RwLock<HashMap<String, Bm25IndexOptions>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Per-index token processing, applied to documents and queries alike.
+/// An index keeps the options it was built with; see
+/// `set_bm25_index_options`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bm25IndexOptions {
+    pub tokenization: Bm25Tokenization,
+    /// Snowball stemmer for non-CJK words ("english", "german", "french",
+    /// ...), so "running" matches "run"; `None` disables stemming.
+    pub stemmer: Option<String>,
+    /// Strip trailing postpositions (이/가/을/를/에서...) from Hangul words.
+    pub strip_korean_particles: bool,
+    /// Drop each word's language stopwords (see `register_language_analyzer`).
+    pub remove_stopwords: bool,
+}
+
+fn stemmer_algorithm(name: &str) -> Option<Algorithm> {
+    Some(match name.to_lowercase().as_str() {
+        "arabic" => Algorithm::Arabic,
+        "danish" => Algorithm::Danish,
+        "dutch" => Algorithm::Dutch,
+        "english" => Algorithm::English,
+        "finnish" => Algorithm::Finnish,
+        "french" => Algorithm::French,
+        "german" => Algorithm::German,
+        "greek" => Algorithm::Greek,
+        "hungarian" => Algorithm::Hungarian,
+        "italian" => Algorithm::Italian,
+        "norwegian" => Algorithm::Norwegian,
+        "portuguese" => Algorithm::Portuguese,
+        "romanian" => Algorithm::Romanian,
+        "russian" => Algorithm::Russian,
+        "spanish" => Algorithm::Spanish,
+        "swedish" => Algorithm::Swedish,
+        "tamil" => Algorithm::Tamil,
+        "turkish" => Algorithm::Turkish,
+        _ => return None,
+    })
+}
+
+/// `text` as tokens of an index built with `options`.
+pub(crate) fn index_tokens(text: &str, options: &Bm25IndexOptions) -> Vec<String> {
+    let tokens = tokenize_with(text, options.tokenization);
+    if options.stemmer.is_none() && !options.strip_korean_particles && !options.remove_stopwords {
+        return tokens;
+    }
+    let stemmer = options
+        .stemmer
+        .as_deref()
+        .and_then(stemmer_algorithm)
+        .map(Stemmer::create);
+    tokens
+        .into_iter()
+        .filter(|token| !options.remove_stopwords || !is_stopword(token))
+        .map(|token| match &stemmer {
+            Some(stemmer) if !token.chars().any(is_cjk_or_hangul) => {
+                stemmer.stem(&token).into_owned()
+            }
+            _ if options.strip_korean_particles => strip_korean_particle(token),
+            _ => token,
+        })
+        .collect()
+}
+
+pub(crate) fn configured_options(tenant_id: &str) -> Bm25IndexOptions {
+    INDEX_OPTIONS
+        .read()
+        .unwrap()
+        .get(tenant_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Set the active tenant's BM25 options. They take effect when the index
+/// is next built (e.g. `rebuild_chunk_bm25_index`); until then searches
+/// keep using the options the current index was built with.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_bm25_index_options(options: Bm25IndexOptions) -> Result<(), RagError> {
+    if let Some(name) = options.stemmer.as_deref().filter(|n| stemmer_algorithm(n).is_none()) {
+        return Err(RagError::InvalidInput(format!("Unknown stemmer '{}'", name)));
+    }
+    info!("[bm25] Index options set to {:?}; rebuild the index to apply them", options);
+    INDEX_OPTIONS.write().unwrap().insert(active_tenant(), options);
+    Ok(())
+}
+
+/// The active tenant's configured BM25 options.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_bm25_index_options() -> Bm25IndexOptions {
+    configured_options(&active_tenant())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct DocMeta {
     length: usize,
     #[allow(dead_code)]
@@ -32,32 +147,49 @@ struct DocMeta {
 }
 
 #[flutter_rust_bridge::frb(ignore)]
-#[derive(Debug)]
-struct InvertedIndex {
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct InvertedIndex {
     postings: HashMap<String, Vec<(i64, u32)>>,
     doc_meta: HashMap<i64, DocMeta>,
     doc_count: usize,
     avg_doc_length: f64,
     total_tokens: usize,
+    options: Bm25IndexOptions,
+    /// Rebuilt from `postings` after deserializing.
+    #[serde(skip)]
+    vocabulary: BkTree,
 }
 
 impl InvertedIndex {
     pub fn new() -> Self {
+        Self::with_options(Bm25IndexOptions::default())
+    }
+
+    pub fn with_options(options: Bm25IndexOptions) -> Self {
         Self {
             postings: HashMap::new(),
             doc_meta: HashMap::new(),
             doc_count: 0,
             avg_doc_length: 0.0,
             total_tokens: 0,
+            options,
+            vocabulary: BkTree::default(),
         }
     }
 
+    /// `text` as this index's tokens.
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        index_tokens(text, &self.options)
+    }
+
+    /// Index `content` under `doc_id`; ids already indexed are skipped
+    /// (see `update_document`).
     pub fn add_document(&mut self, doc_id: i64, content: &str) {
         if self.doc_meta.contains_key(&doc_id) {
             return;
         }
 
-        let tokens = tokenize_for_bm25(content);
+        let tokens = self.tokenize(content);
         let doc_length = tokens.len();
         if doc_length == 0 {
             return;
@@ -69,6 +201,9 @@ impl InvertedIndex {
         }
 
         for (term, freq) in term_freqs {
+            if !self.postings.contains_key(&term) {
+                self.vocabulary.insert(&term);
+            }
             self.postings
                 .entry(term)
                 .or_insert_with(Vec::new)
@@ -104,32 +239,68 @@ impl InvertedIndex {
         }
     }
 
+    /// Replace the indexed content of `doc_id`, adding it if absent.
+    pub fn update_document(&mut self, doc_id: i64, content: &str) {
+        self.remove_document(doc_id);
+        self.add_document(doc_id, content);
+    }
+
+    pub fn contains(&self, doc_id: i64) -> bool {
+        self.doc_meta.contains_key(&doc_id)
+    }
+
     pub fn search(&self, query: &str, top_k: usize) -> Vec<(i64, f64)> {
-        if self.doc_count == 0 {
-            return vec![];
-        }
+        self.search_tokens(&self.tokenize(query), top_k, |_| true)
+    }
+
+    /// `search` honoring required and excluded terms.
+    pub fn search_boolean(&self, query: &BooleanTokens, top_k: usize) -> Vec<(i64, f64)> {
+        let constraint = self.constraint(query);
+        let tokens: Vec<String> = query.should.iter().chain(&query.must).cloned().collect();
+        self.search_tokens(&tokens, top_k, |doc_id| constraint.allows(doc_id))
+    }
+
+    /// Documents a boolean query admits, from the postings of its
+    /// required (intersection) and excluded (union) terms.
+    pub fn constraint(&self, query: &BooleanTokens) -> DocConstraint {
+        let docs_with = |term: &String| -> HashSet<i64> {
+            self.postings
+                .get(term)
+                .map(|p| p.iter().map(|(id, _)| *id).collect())
+                .unwrap_or_default()
+        };
+        let required = query.must.iter().map(docs_with).reduce(|acc, docs| {
+            acc.intersection(&docs).copied().collect()
+        });
+        let excluded = query.must_not.iter().flat_map(docs_with).collect();
+        DocConstraint { required, excluded }
+    }
 
-        let query_tokens = tokenize_for_bm25(query);
-        if query_tokens.is_empty() {
+    fn search_tokens(
+        &self,
+        query_tokens: &[String],
+        top_k: usize,
+        allowed: impl Fn(i64) -> bool,
+    ) -> Vec<(i64, f64)> {
+        if self.doc_count == 0 || query_tokens.is_empty() {
             return vec![];
         }
 
-        let k1 = 1.2;
-        let b = 0.75;
+        let config = get_bm25_config();
         let mut scores: HashMap<i64, f64> = HashMap::new();
 
-        for token in &query_tokens {
+        for (token, weight) in self.expand_terms(query_tokens, get_bm25_fuzzy_matching().as_ref()) {
             if let Some(postings) = self.postings.get(token) {
-                let n = postings.len() as f64;
-                let idf = ((self.doc_count as f64 - n + 0.5) / (n + 0.5) + 1.0).ln();
+                let idf = self.idf(postings.len()) * weight;
 
                 for &(doc_id, tf) in postings {
+                    if !allowed(doc_id) {
+                        continue;
+                    }
                     if let Some(meta) = self.doc_meta.get(&doc_id) {
-                        let tf_f = tf as f64;
-                        let doc_len = meta.length as f64;
-                        let tf_component = (tf_f * (k1 + 1.0))
-                            / (tf_f + k1 * (1.0 - b + b * (doc_len / self.avg_doc_length)));
-                        *scores.entry(doc_id).or_insert(0.0) += idf * tf_component;
+                        let score =
+                            config.term_score(idf, tf as f64, meta.length as f64, self.avg_doc_length);
+                        *scores.entry(doc_id).or_insert(0.0) += score;
                     }
                 }
             }
@@ -141,12 +312,66 @@ impl InvertedIndex {
         results
     }
 
+    /// Query tokens with their score weight. With `fuzzy`, a token that
+    /// has no postings becomes its closest indexed terms instead.
+    fn expand_terms<'a>(
+        &'a self,
+        query_tokens: &'a [String],
+        fuzzy: Option<&Bm25FuzzyConfig>,
+    ) -> Vec<(&'a str, f64)> {
+        let mut terms = Vec::with_capacity(query_tokens.len());
+        for token in query_tokens {
+            let edits = fuzzy.map_or(0, |f| allowed_edits(token, f));
+            if self.postings.contains_key(token) || edits == 0 {
+                terms.push((token.as_str(), 1.0));
+                continue;
+            }
+            let mut close: Vec<(&str, u32)> = self
+                .vocabulary
+                .find(token, edits)
+                .into_iter()
+                .filter(|(term, _)| self.postings.contains_key(*term))
+                .collect();
+            close.sort_by(|a, b| {
+                a.1.cmp(&b.1)
+                    .then(self.document_frequency(b.0).cmp(&self.document_frequency(a.0)))
+                    .then(a.0.cmp(b.0))
+            });
+            let limit = fuzzy.map_or(0, |f| f.max_expansions as usize);
+            terms.extend(close.into_iter().take(limit).map(|(t, d)| (t, fuzzy_weight(d))));
+        }
+        terms
+    }
+
+    /// Rebuild the fuzzy-matching vocabulary from the postings.
+    fn rebuild_vocabulary(&mut self) {
+        self.vocabulary.clear();
+        for term in self.postings.keys() {
+            self.vocabulary.insert(term);
+        }
+    }
+
+    /// BM25 inverse document frequency for a term found in `doc_freq` documents.
+    pub fn idf(&self, doc_freq: usize) -> f64 {
+        let n = doc_freq as f64;
+        ((self.doc_count as f64 - n + 0.5) / (n + 0.5) + 1.0).ln()
+    }
+
+    pub fn vocabulary_size(&self) -> usize {
+        self.postings.len()
+    }
+
+    pub fn document_frequency(&self, term: &str) -> usize {
+        self.postings.get(term).map_or(0, |p| p.len())
+    }
+
     pub fn clear(&mut self) {
         self.postings.clear();
         self.doc_meta.clear();
         self.doc_count = 0;
         self.avg_doc_length = 0.0;
         self.total_tokens = 0;
+        self.vocabulary.clear();
     }
 
     pub fn len(&self) -> usize {
@@ -157,7 +382,48 @@ impl InvertedIndex {
     }
 }
 
-fn is_cjk_or_hangul(ch: char) -> bool {
+/// A parsed boolean query as BM25 tokens (see `query_parser`).
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default)]
+pub(crate) struct BooleanTokens {
+    pub should: Vec<String>,
+    pub must: Vec<String>,
+    pub must_not: Vec<String>,
+}
+
+impl BooleanTokens {
+    pub fn from_query(query: &BooleanQuery, tokenize: impl Fn(&str) -> Vec<String>) -> Self {
+        let tokens = |clauses: &[String]| -> Vec<String> {
+            clauses
+                .iter()
+                .flat_map(|c| tokenize(&normalize_query_text(c)))
+                .collect()
+        };
+        Self {
+            should: tokens(&query.should),
+            must: tokens(&query.must),
+            must_not: tokens(&query.must_not),
+        }
+    }
+}
+
+/// Which documents a boolean query admits.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default)]
+pub(crate) struct DocConstraint {
+    /// Documents containing every required term; `None` if none required.
+    pub required: Option<HashSet<i64>>,
+    pub excluded: HashSet<i64>,
+}
+
+impl DocConstraint {
+    pub fn allows(&self, doc_id: i64) -> bool {
+        !self.excluded.contains(&doc_id)
+            && self.required.as_ref().is_none_or(|docs| docs.contains(&doc_id))
+    }
+}
+
+pub(crate) fn is_cjk_or_hangul(ch: char) -> bool {
     matches!(
         ch as u32,
         0x3040..=0x30FF | // Hiragana + Katakana
@@ -188,38 +454,253 @@ fn keep_bm25_token(token: &str) -> bool {
     false
 }
 
+/// Term-frequency saturation formula.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bm25Variant {
+    /// Okapi BM25 (the default).
+    Bm25,
+    /// BM25+: adds a floor so a match in a very long document still
+    /// outscores no match.
+    Bm25Plus,
+    /// BM25L: shifts normalized term frequency, which penalizes long
+    /// documents less.
+    Bm25L,
+}
+
+/// BM25 scoring parameters, used by the in-memory index and the scoped
+/// scan of filtered hybrid search alike. Applies from the next query;
+/// no rebuild needed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Config {
+    /// Term-frequency saturation (typically 1.2-2.0).
+    pub k1: f64,
+    /// Length normalization, 0 (none) to 1 (full). Lower it for corpora
+    /// of long chunks, raise it for short notes.
+    pub b: f64,
+    pub variant: Bm25Variant,
+}
+
+/// Lower bound added per matched term by BM25+.
+const BM25_PLUS_DELTA: f64 = 1.0;
+/// Term-frequency shift of BM25L.
+const BM25L_DELTA: f64 = 0.5;
+
+const DEFAULT_BM25_CONFIG: Bm25Config = Bm25Config {
+    k1: 1.2,
+    b: 0.75,
+    variant: Bm25Variant::Bm25,
+};
+
+impl Default for Bm25Config {
+    fn default() -> Self {
+        DEFAULT_BM25_CONFIG
+    }
+}
+
+impl Bm25Config {
+    /// Score contribution of one query term occurring `tf` times in a
+    /// document of `doc_len` tokens.
+    pub(crate) fn term_score(&self, idf: f64, tf: f64, doc_len: f64, avg_doc_len: f64) -> f64 {
+        let norm = 1.0 - self.b + self.b * (doc_len / avg_doc_len);
+        match self.variant {
+            Bm25Variant::Bm25 => idf * (tf * (self.k1 + 1.0)) / (tf + self.k1 * norm),
+            Bm25Variant::Bm25Plus => {
+                idf * ((tf * (self.k1 + 1.0)) / (tf + self.k1 * norm) + BM25_PLUS_DELTA)
+            }
+            Bm25Variant::Bm25L => {
+                let shifted = tf / norm + BM25L_DELTA;
+                idf * ((self.k1 + 1.0) * shifted) / (self.k1 + shifted)
+            }
+        }
+    }
+}
+
+static BM25_CONFIG: RwLock<Bm25Config> = RwLock::new(DEFAULT_BM25_CONFIG);
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_bm25_config(config: Bm25Config) -> Result<(), RagError> {
+    if !config.k1.is_finite() || config.k1 < 0.0 {
+        return Err(RagError::InvalidInput(format!(
+            "BM25 k1 must be non-negative, got {}",
+            config.k1
+        )));
+    }
+    if !(0.0..=1.0).contains(&config.b) {
+        return Err(RagError::InvalidInput(format!(
+            "BM25 b must be between 0 and 1, got {}",
+            config.b
+        )));
+    }
+    *BM25_CONFIG.write().unwrap() = config;
+    info!("[bm25] Scoring set to {:?}", config);
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_bm25_config() -> Bm25Config {
+    *BM25_CONFIG.read().unwrap()
+}
+
+/// How BM25 splits text into tokens (`Bm25IndexOptions::tokenization`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Bm25Tokenization {
+    /// Unicode word segmentation (the default). Han and Kana split into
+    /// single glyphs; Hangul and other scripts split at spaces.
+    #[default]
+    Words,
+    /// Split at whitespace and punctuation only.
+    Whitespace,
+    /// Character bigrams over CJK/Hangul runs, words elsewhere.
+    CjkBigrams,
+    /// Character trigrams over CJK/Hangul runs, words elsewhere.
+    CjkTrigrams,
+    /// Words plus CJK/Hangul bigrams: whole words still match exactly and
+    /// the bigrams recover unsegmented or inflected text.
+    Mixed,
+}
+
+/// `text` as BM25 tokens of the active tenant's index.
 pub(crate) fn tokenize_for_bm25(text: &str) -> Vec<String> {
+    index_tokens(text, &active_index_options())
+}
+
+/// The options the active tenant's index was built with (its configured
+/// ones before the first build), for tokenizing many texts alike.
+pub(crate) fn active_index_options() -> Bm25IndexOptions {
+    let tenant_id = active_tenant();
+    INVERTED_INDEX
+        .read()
+        .unwrap()
+        .get(&tenant_id)
+        .map(|index| index.options.clone())
+        .unwrap_or_else(|| configured_options(&tenant_id))
+}
+
+fn tokenize_with(text: &str, mode: Bm25Tokenization) -> Vec<String> {
     use unicode_segmentation::UnicodeSegmentation;
 
-    // Use Unicode word segmentation for better CJK support
-    // Handles Korean, Chinese, Japanese, and multi-language text
-    text.unicode_words()
-        .filter(|s| keep_bm25_token(s))
-        .map(|s| s.to_lowercase())
-        .collect()
+    let words = || {
+        // Use Unicode word segmentation for better CJK support
+        // Handles Korean, Chinese, Japanese, and multi-language text
+        text.unicode_words()
+            .filter(|s| keep_bm25_token(s))
+            .map(|s| s.to_lowercase())
+    };
+    match mode {
+        Bm25Tokenization::Words => words().collect(),
+        Bm25Tokenization::Whitespace => text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|s| keep_bm25_token(s))
+            .map(|s| s.to_lowercase())
+            .collect(),
+        Bm25Tokenization::CjkBigrams => cjk_ngram_tokens(text, 2, true),
+        Bm25Tokenization::CjkTrigrams => cjk_ngram_tokens(text, 3, true),
+        Bm25Tokenization::Mixed => {
+            let mut tokens: Vec<String> = words().collect();
+            tokens.extend(cjk_ngram_tokens(text, 2, false));
+            tokens
+        }
+    }
+}
+
+/// Character `n`-grams of every CJK/Hangul run (a run shorter than `n`
+/// is one token), plus the other words when `with_words` is set.
+fn cjk_ngram_tokens(text: &str, n: usize, with_words: bool) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for segment in text.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let cjk = is_cjk_or_hangul(chars[start]);
+            let end = chars[start..]
+                .iter()
+                .position(|&c| is_cjk_or_hangul(c) != cjk)
+                .map_or(chars.len(), |p| start + p);
+            let run = &chars[start..end];
+            if cjk {
+                if run.len() <= n {
+                    tokens.push(run.iter().collect());
+                } else {
+                    tokens.extend(run.windows(n).map(|w| w.iter().collect::<String>()));
+                }
+            } else if with_words {
+                let word: String = run.iter().collect();
+                if keep_bm25_token(&word) {
+                    tokens.push(word.to_lowercase());
+                }
+            }
+            start = end;
+        }
+    }
+    tokens
 }
 
-/// Add document to BM25 index.
+/// Add document to BM25 index. A no-op with the FTS5 backend, whose
+/// triggers index every row.
 pub fn bm25_add_document(doc_id: i64, content: String) {
-    let mut index = INVERTED_INDEX.write().unwrap();
+    if fts_keyword_enabled() {
+        return;
+    }
+    let tenant_id = active_tenant();
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    let index = indexes
+        .entry(tenant_id.clone())
+        .or_insert_with(|| InvertedIndex::with_options(configured_options(&tenant_id)));
     index.add_document(doc_id, &content);
     debug!("[bm25] Added document {} to index", doc_id);
 }
 
 /// Add multiple documents to BM25 index (batch).
 pub fn bm25_add_documents(docs: Vec<(i64, String)>) {
+    bm25_add_documents_to_tenant(&active_tenant(), docs);
+}
+
+pub(crate) fn bm25_add_documents_to_tenant(tenant_id: &str, docs: Vec<(i64, String)>) {
+    bm25_add_documents_with_progress(tenant_id, docs, Progress::NONE);
+}
+
+/// `bm25_add_documents_to_tenant`, reporting each indexed document.
+pub(crate) fn bm25_add_documents_with_progress(tenant_id: &str, docs: Vec<(i64, String)>, progress: Progress) {
+    if fts_keyword_enabled() {
+        return;
+    }
     let doc_count = docs.len();
-    let mut index = INVERTED_INDEX.write().unwrap();
-    for (doc_id, content) in docs {
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    let index = indexes
+        .entry(tenant_id.to_string())
+        .or_insert_with(|| InvertedIndex::with_options(configured_options(tenant_id)));
+    for (i, (doc_id, content)) in docs.into_iter().enumerate() {
+        progress.report(PHASE_INDEX, i, doc_count);
         index.add_document(doc_id, &content);
     }
-    info!("[bm25] Added {} documents to index", doc_count);
+    progress.report(PHASE_INDEX, doc_count, doc_count);
+    info!(
+        "[bm25] Added {} documents to index of tenant {}",
+        doc_count, tenant_id
+    );
+}
+
+/// Re-index `doc_id` with new `content` (added if not indexed yet).
+/// A no-op with the FTS5 backend, whose triggers follow the update.
+pub fn bm25_update_document(doc_id: i64, content: String) {
+    if fts_keyword_enabled() {
+        return;
+    }
+    let tenant_id = active_tenant();
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    let index = indexes
+        .entry(tenant_id.clone())
+        .or_insert_with(|| InvertedIndex::with_options(configured_options(&tenant_id)));
+    index.update_document(doc_id, &content);
+    debug!("[bm25] Updated document {} in index", doc_id);
 }
 
 /// Remove document from BM25 index.
 pub fn bm25_remove_document(doc_id: i64) {
-    let mut index = INVERTED_INDEX.write().unwrap();
-    index.remove_document(doc_id);
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    if let Some(index) = indexes.get_mut(&active_tenant()) {
+        index.remove_document(doc_id);
+    }
     debug!("[bm25] Removed document {} from index", doc_id);
 }
 
@@ -231,8 +712,34 @@ pub struct Bm25SearchResult {
 
 /// Search using BM25.
 pub fn bm25_search(query: String, top_k: u32) -> Vec<Bm25SearchResult> {
-    let index = INVERTED_INDEX.read().unwrap();
-    let results = index.search(&query, top_k as usize);
+    bm25_search_in_tenant(&active_tenant(), &query, top_k)
+}
+
+pub(crate) fn bm25_search_in_tenant(
+    tenant_id: &str,
+    query: &str,
+    top_k: u32,
+) -> Vec<Bm25SearchResult> {
+    let query = &expand_query(tenant_id, query);
+    let parsed = parse_boolean_query(query);
+    let indexes = INVERTED_INDEX.read().unwrap();
+    let results = if fts_keyword_enabled() {
+        fts_search(tenant_id, query, top_k)
+    } else if parsed.has_operators() {
+        indexes
+            .get(tenant_id)
+            .map(|index| {
+                let tokens = BooleanTokens::from_query(&parsed, |t| index.tokenize(t));
+                index.search_boolean(&tokens, top_k as usize)
+            })
+            .unwrap_or_default()
+    } else {
+        let query = normalize_query_text(query);
+        indexes
+            .get(tenant_id)
+            .map(|index| index.search(&query, top_k as usize))
+            .unwrap_or_default()
+    };
     debug!(
         "[bm25] Search for '{}' returned {} results",
         query,
@@ -244,23 +751,163 @@ pub fn bm25_search(query: String, top_k: u32) -> Vec<Bm25SearchResult> {
         .collect()
 }
 
-/// Clear BM25 index.
+/// The documents `query`'s `+`/`-`/AND/NOT clauses admit in the active
+/// tenant's index; `None` for a query without operators.
+pub(crate) fn bm25_boolean_constraint(query: &str) -> Option<DocConstraint> {
+    if fts_keyword_enabled() {
+        return fts_constraint(&active_tenant(), query);
+    }
+    let parsed = parse_boolean_query(query);
+    if !parsed.has_operators() {
+        return None;
+    }
+    let indexes = INVERTED_INDEX.read().unwrap();
+    Some(
+        indexes
+            .get(&active_tenant())
+            .map(|index| index.constraint(&BooleanTokens::from_query(&parsed, |t| index.tokenize(t))))
+            .unwrap_or_else(|| DocConstraint {
+                required: (!parsed.must.is_empty()).then(HashSet::new),
+                excluded: HashSet::new(),
+            }),
+    )
+}
+
+/// Clear BM25 index (for the active tenant).
 pub fn bm25_clear_index() {
-    let mut index = INVERTED_INDEX.write().unwrap();
-    index.clear();
+    bm25_clear_tenant_index(&active_tenant());
+}
+
+/// Empty `tenant_id`'s index, keeping it loaded.
+pub(crate) fn bm25_clear_tenant_index(tenant_id: &str) {
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    if let Some(index) = indexes.get_mut(tenant_id) {
+        index.clear();
+        // The rebuild that follows a clear picks up new options.
+        index.options = configured_options(tenant_id);
+    }
     info!("[bm25] Index cleared");
 }
 
-/// Check if BM25 index is loaded.
+pub(crate) fn drop_tenant_bm25_index(tenant_id: &str) {
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    indexes.remove(tenant_id);
+    INDEX_OPTIONS.write().unwrap().remove(tenant_id);
+    info!("[bm25] Index cleared for tenant {}", tenant_id);
+}
+
+/// Tenants with a BM25 namespace in memory.
+pub(crate) fn bm25_tenants() -> Vec<String> {
+    INVERTED_INDEX.read().unwrap().keys().cloned().collect()
+}
+
+/// Serialize `tenant_id`'s index (format version, then bincode), if it
+/// has one.
+pub(crate) fn bm25_dump_tenant(tenant_id: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    let indexes = INVERTED_INDEX.read().unwrap();
+    match indexes.get(tenant_id) {
+        Some(index) => {
+            let mut dump = BM25_FORMAT_VERSION.to_le_bytes().to_vec();
+            dump.extend(bincode::serialize(index)?);
+            Ok(Some(dump))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Replace `tenant_id`'s index with one produced by `bm25_dump_tenant`.
+/// Dumps of another format version are rejected.
+pub(crate) fn bm25_load_tenant(tenant_id: &str, dump: &[u8]) -> anyhow::Result<()> {
+    let (version, body) = dump
+        .split_first_chunk::<4>()
+        .ok_or_else(|| anyhow::anyhow!("BM25 dump is truncated"))?;
+    let version = u32::from_le_bytes(*version);
+    if version != BM25_FORMAT_VERSION {
+        anyhow::bail!(
+            "BM25 dump format {} does not match supported {}",
+            version,
+            BM25_FORMAT_VERSION
+        );
+    }
+    let mut index: InvertedIndex = bincode::deserialize(body)?;
+    index.rebuild_vocabulary();
+    info!(
+        "[bm25] Loaded {} documents for tenant {}",
+        index.len(),
+        tenant_id
+    );
+    INVERTED_INDEX
+        .write()
+        .unwrap()
+        .insert(tenant_id.to_string(), index);
+    Ok(())
+}
+
+/// Document ids in `tenant_id`'s index.
+pub(crate) fn bm25_document_ids(tenant_id: &str) -> Vec<i64> {
+    let indexes = INVERTED_INDEX.read().unwrap();
+    indexes
+        .get(tenant_id)
+        .map_or_else(Vec::new, |index| index.doc_meta.keys().copied().collect())
+}
+
+pub(crate) fn bm25_remove_documents_from_tenant(tenant_id: &str, doc_ids: &[i64]) {
+    let mut indexes = INVERTED_INDEX.write().unwrap();
+    if let Some(index) = indexes.get_mut(tenant_id) {
+        for doc_id in doc_ids {
+            index.remove_document(*doc_id);
+        }
+    }
+    info!(
+        "[bm25] Removed {} documents from tenant {}",
+        doc_ids.len(),
+        tenant_id
+    );
+}
+
+/// Drop every tenant's BM25 namespace.
+pub(crate) fn drop_all_bm25_indexes() {
+    INVERTED_INDEX.write().unwrap().clear();
+    info!("[bm25] All indexes cleared");
+}
+
+/// Check if BM25 index is loaded (for the active tenant).
 pub fn is_bm25_index_loaded() -> bool {
-    let index = INVERTED_INDEX.read().unwrap();
-    !index.is_empty()
+    tenant_bm25_index_loaded(&active_tenant())
+}
+
+pub(crate) fn tenant_bm25_index_loaded(tenant_id: &str) -> bool {
+    let indexes = INVERTED_INDEX.read().unwrap();
+    indexes
+        .get(tenant_id)
+        .is_some_and(|index| !index.is_empty())
+}
+
+/// Look up a (tokenized, lowercase) term in the BM25 dictionary.
+///
+/// Returns `(document_frequency, idf)`, or `None` while the index is empty.
+pub(crate) fn bm25_term_stats(term: &str) -> Option<(usize, f64)> {
+    let indexes = INVERTED_INDEX.read().unwrap();
+    let index = indexes.get(&active_tenant())?;
+    if index.is_empty() {
+        return None;
+    }
+    let doc_freq = index.document_frequency(term);
+    Some((doc_freq, index.idf(doc_freq)))
+}
+
+/// Distinct terms in the active tenant's BM25 index.
+pub(crate) fn bm25_vocabulary_size() -> usize {
+    let indexes = INVERTED_INDEX.read().unwrap();
+    indexes
+        .get(&active_tenant())
+        .map_or(0, |index| index.vocabulary_size())
 }
 
 /// Get BM25 index document count.
 pub fn bm25_get_document_count() -> usize {
-    let index = INVERTED_INDEX.read().unwrap();
-    index.len()
+    let indexes = INVERTED_INDEX.read().unwrap();
+    indexes.get(&active_tenant()).map_or(0, |index| index.len())
 }
 
 #[cfg(test)]
@@ -278,6 +925,85 @@ mod tests {
         assert_eq!(results[0].0, 2);
     }
 
+    #[test]
+    fn test_bm25_boolean_search() {
+        let mut index = InvertedIndex::new();
+        index.add_document(1, "error in the deprecated parser");
+        index.add_document(2, "error while loading the index");
+        index.add_document(3, "parser error with stack trace");
+        let search = |query: &str| -> Vec<i64> {
+            let tokens = BooleanTokens::from_query(&parse_boolean_query(query), |t| index.tokenize(t));
+            let mut ids: Vec<i64> = index.search_boolean(&tokens, 10).iter().map(|r| r.0).collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(search("error -deprecated"), vec![2, 3]);
+        assert_eq!(search("error AND parser"), vec![1, 3]);
+        assert_eq!(search("+\"stack trace\" error"), vec![3]);
+        assert_eq!(search("error NOT index NOT deprecated"), vec![3]);
+        assert!(search("-error").is_empty());
+    }
+
+    #[test]
+    fn test_index_options_stem_and_drop_stopwords() {
+        let mut index = InvertedIndex::with_options(Bm25IndexOptions {
+            stemmer: Some("english".to_string()),
+            remove_stopwords: true,
+            ..Default::default()
+        });
+        index.add_document(1, "The runner was running the tests");
+        index.add_document(2, "Walking in the park");
+
+        assert_eq!(index.tokenize("the running tests"), vec!["run", "test"]);
+        assert_eq!(index.search("run", 10)[0].0, 1);
+        assert_eq!(index.search("walked", 10)[0].0, 2);
+        assert!(index.search("the", 10).is_empty());
+        assert_eq!(stemmer_algorithm("German"), Some(Algorithm::German));
+        assert!(stemmer_algorithm("klingon").is_none());
+    }
+
+    #[test]
+    fn test_index_options_tokenization_and_particles() {
+        let index = InvertedIndex::with_options(Bm25IndexOptions {
+            tokenization: Bm25Tokenization::Whitespace,
+            strip_korean_particles: true,
+            ..Default::default()
+        });
+        assert_eq!(index.tokenize("주가가 서울에서 C++"), vec!["주가", "서울", "c"]);
+    }
+
+    #[test]
+    fn test_dump_rejects_other_format_versions() {
+        let tenant = "bm25_version_test_tenant";
+        bm25_add_documents_to_tenant(tenant, vec![(1, "alpha".to_string())]);
+        let mut dump = bm25_dump_tenant(tenant).unwrap().unwrap();
+        dump[..4].copy_from_slice(&(BM25_FORMAT_VERSION - 1).to_le_bytes());
+        assert!(bm25_load_tenant(tenant, &dump).is_err());
+        assert!(bm25_load_tenant(tenant, &[1, 2]).is_err());
+        drop_tenant_bm25_index(tenant);
+    }
+
+    #[test]
+    fn test_cjk_ngram_tokenization() {
+        let text = "삼성전자주가 iPhone15 출시";
+        assert_eq!(
+            tokenize_with(text, Bm25Tokenization::Whitespace),
+            vec!["삼성전자주가", "iphone15", "출시"]
+        );
+        assert_eq!(
+            tokenize_with(text, Bm25Tokenization::CjkBigrams),
+            vec!["삼성", "성전", "전자", "자주", "주가", "iphone15", "출시"]
+        );
+        assert_eq!(
+            tokenize_with("東京タワー", Bm25Tokenization::CjkTrigrams),
+            vec!["東京タ", "京タワ", "タワー"]
+        );
+        let mixed = tokenize_with("주가가 올랐다", Bm25Tokenization::Mixed);
+        assert!(mixed.contains(&"주가가".to_string()));
+        assert!(mixed.contains(&"주가".to_string()));
+    }
+
     #[test]
     fn test_tokenize_for_bm25() {
         let tokens = tokenize_for_bm25("Hello, World! This is a test.");
@@ -334,4 +1060,54 @@ mod tests {
         assert!(!tokens.contains(&"a".to_string()));
         assert!(!tokens.contains(&"i".to_string()));
     }
+
+    #[test]
+    fn test_bm25_config_variants() {
+        let okapi = Bm25Config::default();
+        assert!((okapi.term_score(1.0, 1.0, 10.0, 10.0) - 1.0).abs() < 1e-9);
+
+        // Long documents: BM25+ keeps at least delta, BM25L decays slower.
+        let plus = Bm25Config { variant: Bm25Variant::Bm25Plus, ..okapi };
+        let l = Bm25Config { variant: Bm25Variant::Bm25L, ..okapi };
+        let long = |config: Bm25Config| config.term_score(1.0, 1.0, 200.0, 10.0);
+        assert!(long(okapi) < 0.2);
+        assert!(long(plus) > BM25_PLUS_DELTA);
+        assert!(long(l) > long(okapi));
+
+        let flat = Bm25Config { b: 0.0, ..okapi };
+        assert_eq!(flat.term_score(1.0, 2.0, 5.0, 10.0), flat.term_score(1.0, 2.0, 50.0, 10.0));
+        assert!(set_bm25_config(Bm25Config { b: 1.5, ..okapi }).is_err());
+        assert!(set_bm25_config(Bm25Config { k1: -1.0, ..okapi }).is_err());
+    }
+
+    #[test]
+    fn test_bm25_update_document_reindexes() {
+        let mut index = InvertedIndex::new();
+        index.add_document(1, "old wording about caching");
+        index.add_document(1, "ignored duplicate add");
+        assert!(index.search("duplicate", 10).is_empty());
+
+        index.update_document(1, "new wording about indexing");
+        assert!(index.search("caching", 10).is_empty());
+        assert_eq!(index.search("indexing", 10)[0].0, 1);
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn test_bm25_fuzzy_terms() {
+        let mut index = InvertedIndex::new();
+        index.add_document(1, "deploying kubernetes clusters");
+        index.add_document(2, "docker compose files");
+        let tokens = index.tokenize("kuberntes docker");
+
+        let exact = index.expand_terms(&tokens, None);
+        assert_eq!(exact, vec![("kuberntes", 1.0), ("docker", 1.0)]);
+        let fuzzy = index.expand_terms(&tokens, Some(&Bm25FuzzyConfig::default()));
+        assert_eq!(fuzzy, vec![("kubernetes", 0.5), ("docker", 1.0)]);
+
+        // Removed terms are skipped even though the tree keeps them.
+        index.remove_document(1);
+        let fuzzy = index.expand_terms(&tokens, Some(&Bm25FuzzyConfig::default()));
+        assert_eq!(fuzzy, vec![("docker", 1.0)]);
+    }
 }
@@ -0,0 +1,221 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Conversation memory with vector recall.
+//!
+//! Stores chat turns with their embeddings in a `messages` table, separate
+//! from document chunks, so the assistant can recall earlier statements in
+//! long conversations. Conversations are small, so recall is an exact
+//! cosine scan scoped to one conversation of the active tenant.
+
+use rusqlite::{params, Connection};
+use log::{debug, info};
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::{decode_embedding, encode_embedding};
+use crate::api::error::{db_error, RagError};
+use crate::api::tenant::active_tenant;
+use crate::api::vector_ops::{dot, norm};
+
+#[derive(Debug, Clone)]
+pub struct MemoryMessage {
+    pub id: i64,
+    pub conversation_id: String,
+    /// "user", "assistant" or "system".
+    pub role: String,
+    pub content: String,
+    /// Unix timestamp (seconds).
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemorySearchResult {
+    pub message: MemoryMessage,
+    pub similarity: f64,
+}
+
+/// Create the `messages` table (called from `init_source_db`).
+pub(crate) fn create_chat_memory_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS messages (
+            id INTEGER PRIMARY KEY,
+            conversation_id TEXT NOT NULL,
+            role TEXT NOT NULL,
+            content TEXT NOT NULL,
+            embedding BLOB NOT NULL,
+            created_at INTEGER DEFAULT (strftime('%s', 'now')),
+            tenant_id TEXT NOT NULL DEFAULT 'default'
+        )",
+        [],
+    )?;
+    // Migration: messages stored before tenants belong to the default tenant
+    if conn.prepare("SELECT tenant_id FROM messages LIMIT 1").is_err() {
+        info!("[chat_memory] Migrating: adding tenant_id column to messages");
+        conn.execute("ALTER TABLE messages ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default'", [])?;
+    }
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_messages_conversation ON messages(conversation_id)",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_messages_tenant_conversation ON messages(tenant_id, conversation_id)",
+        [],
+    )?;
+    Ok(())
+}
+
+fn insert_message(
+    conn: &Connection,
+    tenant_id: &str,
+    conversation_id: &str,
+    role: &str,
+    content: &str,
+    embedding: &[f32],
+) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO messages (conversation_id, role, content, embedding, tenant_id) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![conversation_id, role, content, encode_embedding(embedding), tenant_id],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn scan_memory(
+    conn: &Connection,
+    tenant_id: &str,
+    query_embedding: &[f32],
+    top_k: usize,
+    conversation_id: &str,
+) -> rusqlite::Result<Vec<MemorySearchResult>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, role, content, embedding, created_at FROM messages WHERE conversation_id = ?1 AND tenant_id = ?2",
+    )?;
+    let rows = stmt.query_map(params![conversation_id, tenant_id], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Vec<u8>>(3)?,
+            row.get::<_, i64>(4)?,
+        ))
+    })?;
+
+    let query_norm = norm(query_embedding);
+    let mut results = Vec::new();
+    for row in rows {
+        let (id, role, content, blob, created_at) = row?;
+        let embedding = decode_embedding(&blob);
+        if embedding.len() != query_embedding.len() {
+            continue;
+        }
+        let embedding_norm = norm(&embedding);
+        let similarity = if query_norm == 0.0 || embedding_norm == 0.0 {
+            0.0
+        } else {
+            (dot(query_embedding, &embedding) / (query_norm * embedding_norm)) as f64
+        };
+        results.push(MemorySearchResult {
+            similarity,
+            message: MemoryMessage {
+                id,
+                conversation_id: conversation_id.to_string(),
+                role,
+                content,
+                created_at,
+            },
+        });
+    }
+    results.sort_by(|a, b| b.similarity.partial_cmp(&a.similarity).unwrap_or(std::cmp::Ordering::Equal));
+    results.truncate(top_k);
+    Ok(results)
+}
+
+/// Store a chat turn with its embedding. Returns the message id.
+pub fn add_memory_message(
+    conversation_id: String,
+    role: String,
+    content: String,
+    embedding: Vec<f32>,
+) -> Result<i64, RagError> {
+    let conn = get_connection()?;
+    let id = insert_message(&conn, &active_tenant(), &conversation_id, &role, &content, &embedding).map_err(db_error)?;
+    debug!("[chat_memory] Stored {} message {} in '{}'", role, id, conversation_id);
+    Ok(id)
+}
+
+/// Recall the messages of a conversation most similar to the query.
+pub fn search_memory(
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    conversation_id: String,
+) -> Result<Vec<MemorySearchResult>, RagError> {
+    let conn = get_connection()?;
+    scan_memory(&conn, &active_tenant(), &query_embedding, top_k as usize, &conversation_id).map_err(db_error)
+}
+
+/// All messages of a conversation in chronological order.
+pub fn get_conversation_messages(conversation_id: String) -> Result<Vec<MemoryMessage>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, role, content, created_at FROM messages
+             WHERE conversation_id = ?1 AND tenant_id = ?2 ORDER BY id",
+        )
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map(params![conversation_id, active_tenant()], |row| {
+            Ok(MemoryMessage {
+                id: row.get(0)?,
+                conversation_id: conversation_id.clone(),
+                role: row.get(1)?,
+                content: row.get(2)?,
+                created_at: row.get(3)?,
+            })
+        })
+        .map_err(db_error)?;
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(db_error)
+}
+
+/// Delete a conversation's memory.
+pub fn delete_conversation_memory(conversation_id: String) -> Result<(), RagError> {
+    let conn = get_connection()?;
+    let deleted = conn
+        .execute(
+            "DELETE FROM messages WHERE conversation_id = ?1 AND tenant_id = ?2",
+            params![conversation_id, active_tenant()],
+        )
+        .map_err(db_error)?;
+    info!("[chat_memory] Deleted {} messages from '{}'", deleted, conversation_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_recall_is_scoped_to_conversation() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_chat_memory_table(&conn).unwrap();
+        insert_message(&conn, "default", "c1", "user", "My budget is 10k", &[1.0, 0.0]).unwrap();
+        insert_message(&conn, "default", "c1", "user", "I prefer bonds", &[0.0, 1.0]).unwrap();
+        insert_message(&conn, "default", "c2", "user", "Other chat", &[1.0, 0.0]).unwrap();
+        insert_message(&conn, "work", "c1", "user", "Work budget", &[1.0, 0.0]).unwrap();
+
+        let results = scan_memory(&conn, "default", &[0.9, 0.1], 5, "c1").unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].message.content, "My budget is 10k");
+        assert!(results.iter().all(|r| r.message.conversation_id == "c1"));
+    }
+}
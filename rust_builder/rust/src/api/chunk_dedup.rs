@@ -0,0 +1,210 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Exact and near-duplicate chunk removal before embedding.
+//!
+//! PDFs repeat headers, footers and boilerplate on every page, which turns
+//! into dozens of identical chunks that cost embedding calls and crowd out
+//! real hits. Exact duplicates are caught by hashing whitespace- and
+//! case-normalized text; near-duplicates by MinHash signatures over token
+//! shingles, bucketed with LSH so only likely pairs are compared. The first
+//! occurrence of a chunk is always the one kept.
+
+use std::collections::HashMap;
+
+use crate::api::bm25_search::tokenize_for_bm25;
+
+/// MinHash signature length; the estimate's error is about 1/sqrt(64).
+const NUM_HASHES: usize = 64;
+/// Rows per LSH band: 16 bands of 4 make pairs above ~0.7 collide.
+const BAND_ROWS: usize = 4;
+
+type Signature = [u64; NUM_HASHES];
+
+#[derive(Debug, Clone)]
+pub struct DedupeConfig {
+    /// Estimated Jaccard similarity at or above which a chunk is dropped.
+    pub near_duplicate_threshold: f64,
+    /// Tokens per shingle.
+    pub shingle_size: u32,
+    /// Only drop exact (normalized) duplicates.
+    pub exact_only: bool,
+}
+
+impl Default for DedupeConfig {
+    fn default() -> Self {
+        Self {
+            near_duplicate_threshold: 0.9,
+            shingle_size: 3,
+            exact_only: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateChunk {
+    pub index: u32,
+    /// Index of the kept chunk it duplicates.
+    pub duplicate_of: u32,
+    /// 1.0 for exact duplicates, the MinHash estimate otherwise.
+    pub similarity: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DedupeReport {
+    /// Indexes of the chunks to keep, in input order.
+    pub kept: Vec<u32>,
+    pub duplicates: Vec<DuplicateChunk>,
+}
+
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn minhash(text: &str, shingle_size: usize) -> Option<Signature> {
+    let tokens = tokenize_for_bm25(text);
+    if tokens.is_empty() {
+        return None;
+    }
+    let mut signature = [u64::MAX; NUM_HASHES];
+    for shingle in tokens.windows(shingle_size.clamp(1, tokens.len())) {
+        let base = fnv1a(shingle.join("\u{1f}").into_bytes());
+        for (i, slot) in signature.iter_mut().enumerate() {
+            *slot = (*slot).min(splitmix64(base ^ (i as u64 + 1)));
+        }
+    }
+    Some(signature)
+}
+
+fn similarity(a: &Signature, b: &Signature) -> f64 {
+    a.iter().zip(b).filter(|(x, y)| x == y).count() as f64 / NUM_HASHES as f64
+}
+
+fn band_keys(signature: &Signature) -> impl Iterator<Item = (usize, u64)> + '_ {
+    signature
+        .chunks(BAND_ROWS)
+        .enumerate()
+        .map(|(band, rows)| (band, fnv1a(rows.iter().flat_map(|r| r.to_le_bytes()))))
+}
+
+/// Finds duplicates among `chunks`; see `dedupe_chunks`.
+pub(crate) fn find_duplicates<S: AsRef<str>>(chunks: &[S], config: &DedupeConfig) -> DedupeReport {
+    let mut kept = Vec::with_capacity(chunks.len());
+    let mut duplicates = Vec::new();
+    let mut exact: HashMap<String, u32> = HashMap::new();
+    let mut signatures: HashMap<u32, Signature> = HashMap::new();
+    let mut buckets: HashMap<(usize, u64), Vec<u32>> = HashMap::new();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let index = i as u32;
+        let normalized = chunk
+            .as_ref()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if let Some(&first) = exact.get(&normalized) {
+            duplicates.push(DuplicateChunk {
+                index,
+                duplicate_of: first,
+                similarity: 1.0,
+            });
+            continue;
+        }
+        exact.insert(normalized, index);
+
+        let signature = if config.exact_only {
+            None
+        } else {
+            minhash(chunk.as_ref(), config.shingle_size as usize)
+        };
+        if let Some(signature) = signature {
+            let best = band_keys(&signature)
+                .filter_map(|key| buckets.get(&key))
+                .flatten()
+                .map(|&other| (other, similarity(&signature, &signatures[&other])))
+                .filter(|&(_, sim)| sim >= config.near_duplicate_threshold)
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+            if let Some((duplicate_of, similarity)) = best {
+                duplicates.push(DuplicateChunk {
+                    index,
+                    duplicate_of,
+                    similarity,
+                });
+                continue;
+            }
+            for key in band_keys(&signature) {
+                buckets.entry(key).or_default().push(index);
+            }
+            signatures.insert(index, signature);
+        }
+        kept.push(index);
+    }
+    DedupeReport { kept, duplicates }
+}
+
+/// Finds exact and near-duplicate chunks so they can be skipped before
+/// embedding. Returns the indexes to keep and, for each dropped chunk, the
+/// kept chunk it duplicates.
+#[flutter_rust_bridge::frb(sync)]
+pub fn dedupe_chunks(chunks: Vec<String>, config: DedupeConfig) -> DedupeReport {
+    find_duplicates(&chunks, &config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedupe_chunks_drops_exact_and_near_duplicates() {
+        let body = "The validator set rotates every epoch and slashing applies to double signing \
+                    as well as extended downtime of the node operator";
+        let chunks = vec![
+            "ACME Corp Confidential".to_string(),
+            body.to_string(),
+            "acme corp   CONFIDENTIAL".to_string(),
+            format!("{} today", body),
+            "Rewards are distributed pro rata to delegators.".to_string(),
+        ];
+        let report = dedupe_chunks(chunks, DedupeConfig::default());
+        assert_eq!(report.kept, vec![0, 1, 4]);
+        assert_eq!(report.duplicates[0].duplicate_of, 0);
+        assert_eq!(report.duplicates[0].similarity, 1.0);
+        assert_eq!(report.duplicates[1].index, 3);
+        assert_eq!(report.duplicates[1].duplicate_of, 1);
+        assert!(report.duplicates[1].similarity >= 0.9);
+    }
+
+    #[test]
+    fn test_dedupe_chunks_exact_only() {
+        let chunks = vec!["a b c d e f".to_string(), "a b c d e f g".to_string()];
+        let config = DedupeConfig {
+            exact_only: true,
+            near_duplicate_threshold: 0.0,
+            ..DedupeConfig::default()
+        };
+        assert_eq!(dedupe_chunks(chunks, config).kept, vec![0, 1]);
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! On-disk persistence of the chunk HNSW index.
+//!
+//! `rebuild_chunk_hnsw_index` dumps the graph next to the SQLite database
+//! (`<db stem>_chunks.hnsw.{graph,data}`, tenant-suffixed like
+//! `save_hnsw_index`) along with a marker recording the schema version,
+//! the graph's metric and the state of the tenant's `chunks` rows it was
+//! built from. `ensure_chunk_index` loads that dump on a cold start when
+//! the marker still matches the table and the index config, and rebuilds
+//! only otherwise.
+
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::api::db_pool::{db_pool_config, get_connection};
+use crate::api::error::{db_error, io_error, RagError};
+use crate::api::hnsw_index::{
+    dump_tenant_hnsw_index, get_index_config, hnsw_graph_kind, is_hnsw_index_loaded,
+    load_tenant_hnsw_index, DistanceMetric, Quantization,
+};
+use crate::api::index_freshness::{
+    change_versions, check_index_freshness, record_indexed, IndexKind,
+};
+use crate::api::memory_budget::memory_profile;
+use crate::api::source_index::drop_tenant_source_indexes;
+use crate::api::source_rag::{rebuild_chunk_hnsw_index, SCHEMA_VERSION};
+use crate::api::tenant::{active_tenant, tenant_index_path};
+
+/// What a tenant's `chunks` rows looked like when an index was built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ChunkTableState {
+    pub row_count: i64,
+    pub max_chunk_id: i64,
+    /// Vector change counter (see `index_freshness`).
+    pub vector_version: i64,
+}
+
+/// Stored as `<base>.hnsw.marker.json` once the dump is complete.
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexMarker {
+    schema_version: u32,
+    state: ChunkTableState,
+    /// Markers written before metrics existed describe cosine graphs.
+    #[serde(default)]
+    quantization: Quantization,
+    #[serde(default)]
+    metric: DistanceMetric,
+    /// Basename hnsw_rs wrote the graph and data under.
+    basename: String,
+}
+
+/// How `ensure_chunk_index` obtained the chunk index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkIndexSource {
+    /// Already in memory and up to date.
+    Memory,
+    /// Loaded from the dump next to the database.
+    Disk,
+    /// Rebuilt from the `chunks` table.
+    Rebuilt,
+}
+
+pub(crate) fn chunk_table_state(
+    conn: &Connection,
+    tenant_id: &str,
+) -> rusqlite::Result<ChunkTableState> {
+    let (vector_version, _) = change_versions(conn, tenant_id)?;
+    let (row_count, max_chunk_id) = conn.query_row(
+        "SELECT COUNT(*), COALESCE(MAX(id), 0) FROM chunks WHERE tenant_id = ?1",
+        params![tenant_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    Ok(ChunkTableState {
+        row_count,
+        max_chunk_id,
+        vector_version,
+    })
+}
+
+/// Directory and file stem of `tenant_id`'s dump, or `None` without a
+/// file-backed database.
+fn index_location(tenant_id: &str) -> Option<(PathBuf, String)> {
+    let (db_path, _) = db_pool_config()?;
+    let db_path = database_file(&db_path)?;
+    let db_path = Path::new(db_path);
+    let stem = db_path.file_stem()?.to_str()?;
+    let base = db_path.with_file_name(format!("{}_chunks", stem));
+    let base = tenant_index_path(base.to_str()?, tenant_id);
+    let base = Path::new(&base);
+    Some((
+        base.parent()?.to_path_buf(),
+        base.file_name()?.to_str()?.to_string(),
+    ))
+}
+
+/// File behind a pool path, which may be a `file:` URI; `None` for
+/// in-memory databases.
+fn database_file(db_path: &str) -> Option<&str> {
+    let (path, query) = match db_path.strip_prefix("file:") {
+        Some(uri) => uri.split_once('?').unwrap_or((uri, "")),
+        None => (db_path, ""),
+    };
+    let in_memory =
+        path.is_empty() || path == ":memory:" || query.split('&').any(|p| p == "mode=memory");
+    (!in_memory).then_some(path)
+}
+
+fn marker_path(dir: &Path, stem: &str) -> PathBuf {
+    dir.join(format!("{}.hnsw.marker.json", stem))
+}
+
+fn read_marker(dir: &Path, stem: &str) -> Option<IndexMarker> {
+    let path = marker_path(dir, stem);
+    let bytes = std::fs::read(&path).ok()?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| {
+            warn!(
+                "[chunk_index] Ignoring unreadable marker {}: {}",
+                path.display(),
+                e
+            )
+        })
+        .ok()
+}
+
+fn remove_marker(dir: &Path, stem: &str) -> Result<(), RagError> {
+    let path = marker_path(dir, stem);
+    match std::fs::remove_file(&path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(path.display(), e)),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a dump described by `marker` reflects `state` in this schema
+/// and was built as the current index config would build it.
+fn is_marker_current(marker: &IndexMarker, state: &ChunkTableState) -> bool {
+    let config = get_index_config();
+    marker.schema_version == SCHEMA_VERSION
+        && marker.state == *state
+        && marker.quantization == config.quantization
+        && marker.metric == config.metric
+}
+
+/// Dump `tenant_id`'s in-memory index, built from `state`, next to the
+/// database. The marker is removed first and written last, so an
+/// interrupted dump is never loaded.
+pub(crate) fn persist_chunk_index(tenant_id: &str, state: ChunkTableState) -> Result<(), RagError> {
+    let Some((dir, stem)) = index_location(tenant_id) else {
+        return Ok(());
+    };
+    remove_marker(&dir, &stem)?;
+    // Quantized indexes are rebuilt from SQLite; only full graphs are dumped.
+    if memory_profile().quantize_index {
+        return Ok(());
+    }
+    let Some((quantization, metric)) = hnsw_graph_kind(tenant_id) else {
+        return Ok(());
+    };
+    let Some(basename) = dump_tenant_hnsw_index(tenant_id, &dir, &stem)? else {
+        return Ok(());
+    };
+    let marker = IndexMarker {
+        schema_version: SCHEMA_VERSION,
+        state,
+        quantization,
+        metric,
+        basename,
+    };
+    let path = marker_path(&dir, &stem);
+    let json = serde_json::to_vec_pretty(&marker).map_err(|e| io_error(path.display(), e))?;
+    std::fs::write(&path, json).map_err(|e| io_error(path.display(), e))?;
+    info!(
+        "[chunk_index] Saved {} points to {}",
+        state.row_count,
+        dir.join(&marker.basename).display()
+    );
+    Ok(())
+}
+
+/// Make the active tenant's chunk HNSW index available for search.
+///
+/// Keeps an up-to-date index already in memory, otherwise loads the dump
+/// saved by the last `rebuild_chunk_hnsw_index` when its marker matches
+/// the `chunks` table (same schema version, row count, highest id and
+/// vector change counter) and the index config (quantization and metric),
+/// and rebuilds from the table only when neither applies. Call this on startup instead of `rebuild_chunk_hnsw_index`.
+pub fn ensure_chunk_index() -> Result<ChunkIndexSource, RagError> {
+    let tenant_id = active_tenant();
+    if is_hnsw_index_loaded() && !check_index_freshness()?.hnsw_stale {
+        return Ok(ChunkIndexSource::Memory);
+    }
+    let conn = get_connection()?;
+    let state = chunk_table_state(&conn, &tenant_id).map_err(db_error)?;
+    drop(conn);
+
+    if let Some((dir, stem)) = index_location(&tenant_id).filter(|_| state.row_count > 0) {
+        if let Some(marker) = read_marker(&dir, &stem).filter(|m| is_marker_current(m, &state)) {
+            match load_tenant_hnsw_index(&tenant_id, &dir, &marker.basename) {
+                Ok(()) => {
+                    drop_tenant_source_indexes(&tenant_id);
+                    record_indexed(&tenant_id, IndexKind::Vector, state.vector_version);
+                    info!("[chunk_index] Loaded {} points from disk", state.row_count);
+                    return Ok(ChunkIndexSource::Disk);
+                }
+                Err(e) => warn!(
+                    "[chunk_index] Failed to load saved index: {}. Rebuilding.",
+                    e
+                ),
+            }
+        }
+    }
+
+    rebuild_chunk_hnsw_index()?;
+    Ok(ChunkIndexSource::Rebuilt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::index_freshness::create_change_tracking;
+
+    #[test]
+    fn test_chunk_table_state_tracks_rows() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT, embedding BLOB, tenant_id TEXT);",
+        )
+        .unwrap();
+        create_change_tracking(&conn).unwrap();
+        let empty = chunk_table_state(&conn, "a").unwrap();
+        assert_eq!(
+            empty,
+            ChunkTableState {
+                row_count: 0,
+                max_chunk_id: 0,
+                vector_version: 0
+            }
+        );
+
+        conn.execute_batch("INSERT INTO chunks VALUES (1, 'x', x'00', 'a'), (7, 'y', x'00', 'a'), (9, 'z', x'00', 'b');")
+            .unwrap();
+        let state = chunk_table_state(&conn, "a").unwrap();
+        assert_eq!((state.row_count, state.max_chunk_id), (2, 7));
+
+        conn.execute("UPDATE chunks SET embedding = x'01' WHERE id = 1", [])
+            .unwrap();
+        let updated = chunk_table_state(&conn, "a").unwrap();
+        assert_eq!(updated.row_count, state.row_count);
+        assert_ne!(updated, state);
+    }
+
+    #[test]
+    fn test_database_file_skips_in_memory_databases() {
+        assert_eq!(database_file("/data/rag.sqlite"), Some("/data/rag.sqlite"));
+        assert_eq!(
+            database_file("file:/data/rag.sqlite?cache=shared"),
+            Some("/data/rag.sqlite")
+        );
+        assert_eq!(database_file(":memory:"), None);
+        assert_eq!(
+            database_file("file:rag_test?mode=memory&cache=shared"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_marker_requires_matching_schema_and_state() {
+        let state = ChunkTableState {
+            row_count: 3,
+            max_chunk_id: 3,
+            vector_version: 3,
+        };
+        let marker = IndexMarker {
+            schema_version: SCHEMA_VERSION,
+            state,
+            quantization: Quantization::None,
+            metric: DistanceMetric::Cosine,
+            basename: "rag_chunks".to_string(),
+        };
+        assert!(is_marker_current(&marker, &state));
+        assert!(!is_marker_current(
+            &marker,
+            &ChunkTableState {
+                row_count: 4,
+                ..state
+            }
+        ));
+
+        let json = serde_json::to_vec(&marker).unwrap();
+        let old: IndexMarker = serde_json::from_slice(&json).unwrap();
+        assert!(is_marker_current(&old, &state));
+        let other_schema = IndexMarker {
+            schema_version: SCHEMA_VERSION + 1,
+            ..old
+        };
+        assert!(!is_marker_current(&other_schema, &state));
+        let other_metric = IndexMarker {
+            schema_version: SCHEMA_VERSION,
+            metric: DistanceMetric::L2,
+            ..other_schema
+        };
+        assert!(!is_marker_current(&other_metric, &state));
+
+        let legacy = br#"{"schema_version":1,"state":{"row_count":3,"max_chunk_id":3,"vector_version":3},"basename":"rag_chunks"}"#;
+        let legacy: IndexMarker = serde_json::from_slice(legacy).unwrap();
+        assert_eq!(legacy.metric, DistanceMetric::Cosine);
+        assert_eq!(legacy.quantization, Quantization::None);
+    }
+}
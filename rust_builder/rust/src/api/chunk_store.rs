@@ -0,0 +1,184 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Storage abstraction for targets without SQLite.
+//!
+//! `ChunkStore` is the minimal chunk storage surface the core pipeline
+//! (chunking, BM25, compression, intent parsing) needs: put, get, delete
+//! by source and linear vector search. `MemoryStore` implements it with
+//! plain std collections and no native dependencies, so it also builds
+//! for wasm32; an IndexedDB-backed store for Flutter Web would implement
+//! the same trait. The SQLite-backed modules (`source_rag`, `hybrid_search`
+//! and friends) still use the connection pool directly.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredChunk {
+    pub id: i64,
+    pub source_id: i64,
+    pub content: String,
+    pub embedding: Vec<f32>,
+}
+
+/// One vector search hit; higher `similarity` is better.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoreSearchHit {
+    pub id: i64,
+    pub similarity: f64,
+}
+
+pub trait ChunkStore: Send + Sync {
+    /// Insert or replace the chunk with `chunk.id`.
+    fn put_chunk(&mut self, chunk: StoredChunk);
+    fn get_chunk(&self, id: i64) -> Option<StoredChunk>;
+    /// Remove every chunk of `source_id`; returns how many were removed.
+    fn delete_source(&mut self, source_id: i64) -> usize;
+    fn chunk_count(&self) -> usize;
+    /// Cosine similarity search, best first. Chunks whose dimension does
+    /// not match the query are skipped.
+    fn search_vectors(&self, query: &[f32], top_k: usize) -> Vec<StoreSearchHit>;
+}
+
+/// In-memory `ChunkStore` with exact (linear) vector search.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    chunks: BTreeMap<i64, StoredChunk>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Min-heap entry on similarity so the worst hit is popped first.
+struct HeapHit(StoreSearchHit);
+
+impl PartialEq for HeapHit {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapHit {}
+
+impl PartialOrd for HeapHit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapHit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .similarity
+            .total_cmp(&self.0.similarity)
+            .then_with(|| self.0.id.cmp(&other.0.id))
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b) {
+        dot += (*x as f64) * (*y as f64);
+        norm_a += (*x as f64) * (*x as f64);
+        norm_b += (*y as f64) * (*y as f64);
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+impl ChunkStore for MemoryStore {
+    fn put_chunk(&mut self, chunk: StoredChunk) {
+        self.chunks.insert(chunk.id, chunk);
+    }
+
+    fn get_chunk(&self, id: i64) -> Option<StoredChunk> {
+        self.chunks.get(&id).cloned()
+    }
+
+    fn delete_source(&mut self, source_id: i64) -> usize {
+        let before = self.chunks.len();
+        self.chunks.retain(|_, chunk| chunk.source_id != source_id);
+        before - self.chunks.len()
+    }
+
+    fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    fn search_vectors(&self, query: &[f32], top_k: usize) -> Vec<StoreSearchHit> {
+        if top_k == 0 {
+            return vec![];
+        }
+        let mut heap: BinaryHeap<HeapHit> = BinaryHeap::with_capacity(top_k + 1);
+        for chunk in self.chunks.values() {
+            if chunk.embedding.len() != query.len() {
+                continue;
+            }
+            heap.push(HeapHit(StoreSearchHit {
+                id: chunk.id,
+                similarity: cosine_similarity(query, &chunk.embedding),
+            }));
+            if heap.len() > top_k {
+                heap.pop();
+            }
+        }
+        // Ascending by the heap order is best first.
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|hit| hit.0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(id: i64, source_id: i64, embedding: Vec<f32>) -> StoredChunk {
+        StoredChunk {
+            id,
+            source_id,
+            content: format!("chunk {}", id),
+            embedding,
+        }
+    }
+
+    #[test]
+    fn test_memory_store_search_and_delete() {
+        let mut store = MemoryStore::new();
+        store.put_chunk(chunk(1, 10, vec![1.0, 0.0]));
+        store.put_chunk(chunk(2, 10, vec![0.7, 0.7]));
+        store.put_chunk(chunk(3, 20, vec![0.0, 1.0]));
+        store.put_chunk(chunk(4, 20, vec![1.0, 0.0, 0.0]));
+
+        let hits = store.search_vectors(&[1.0, 0.1], 2);
+        let ids: Vec<i64> = hits.iter().map(|h| h.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert!(hits[0].similarity > hits[1].similarity);
+
+        assert_eq!(store.delete_source(10), 2);
+        assert_eq!(store.chunk_count(), 2);
+        assert!(store.get_chunk(1).is_none());
+        assert_eq!(store.search_vectors(&[1.0, 0.1], 5)[0].id, 3);
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! User-registered chunk classification rules.
+//!
+//! `classify_chunk` only knows English phrasings. Apps register rules
+//! (label + regex patterns, optionally scoped to a language) that are
+//! checked first, so Korean or Japanese corpora get Definition/Procedure
+//! labels too. Rules are stored in the `classification_rules` table and
+//! loaded by `init_source_db`, so they survive restarts.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rusqlite::{params, Connection};
+use std::sync::RwLock;
+
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::language_detect::detect_language_code;
+use crate::api::semantic_chunker::ChunkType;
+
+/// Rule language matching every chunk.
+pub const ANY_LANGUAGE: &str = "*";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassificationRule {
+    /// A `ChunkType` label: "definition", "example", "list", "procedure",
+    /// "comparison" or "general".
+    pub label: String,
+    /// Language code from `detect_language` ("ko", "ja", ...) or "*".
+    pub language: String,
+    /// Regexes tried against the chunk text; use `(?i)` for case folding.
+    pub patterns: Vec<String>,
+    /// Patterns that must match for the rule to apply (at least 1).
+    pub min_matches: u32,
+}
+
+struct CompiledRule {
+    rule: ClassificationRule,
+    label: ChunkType,
+    patterns: Vec<Regex>,
+}
+
+/// Registered rules in priority order.
+static RULES: Lazy<RwLock<Vec<CompiledRule>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+fn parse_label(label: &str) -> Option<ChunkType> {
+    let chunk_type = ChunkType::from_str(label.trim());
+    (chunk_type.as_str() == label.trim()).then_some(chunk_type)
+}
+
+fn compile_rule(rule: ClassificationRule) -> Result<CompiledRule, RagError> {
+    let label = parse_label(&rule.label)
+        .ok_or_else(|| RagError::InvalidInput(format!("Unknown chunk type '{}'", rule.label)))?;
+    if rule.patterns.is_empty() {
+        return Err(RagError::InvalidInput(format!(
+            "Rule for '{}' has no patterns",
+            rule.label
+        )));
+    }
+    let patterns = rule
+        .patterns
+        .iter()
+        .map(|p| {
+            Regex::new(p).map_err(|e| {
+                RagError::InvalidInput(format!("Invalid classification pattern '{}': {}", p, e))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(CompiledRule {
+        rule,
+        label,
+        patterns,
+    })
+}
+
+fn match_rules(rules: &[CompiledRule], text: &str) -> Option<ChunkType> {
+    let mut language: Option<&str> = None;
+    rules.iter().find_map(|compiled| {
+        let rule_language = compiled.rule.language.as_str();
+        if rule_language != ANY_LANGUAGE
+            && *language.get_or_insert_with(|| detect_language_code(text)) != rule_language
+        {
+            return None;
+        }
+        let matches = compiled
+            .patterns
+            .iter()
+            .filter(|p| p.is_match(text))
+            .count();
+        (matches >= compiled.rule.min_matches.max(1) as usize).then_some(compiled.label)
+    })
+}
+
+/// Label from the first registered rule matching `text`, if any.
+pub(crate) fn classify_with_rules(text: &str) -> Option<ChunkType> {
+    let rules = RULES.read().unwrap();
+    if rules.is_empty() {
+        return None;
+    }
+    match_rules(&rules, text)
+}
+
+/// Create the `classification_rules` table (called from `init_source_db`).
+pub(crate) fn create_classification_rules_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS classification_rules (
+            id INTEGER PRIMARY KEY,
+            label TEXT NOT NULL,
+            language TEXT NOT NULL,
+            patterns TEXT NOT NULL,
+            min_matches INTEGER NOT NULL DEFAULT 1
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn save_rules(conn: &mut Connection, rules: &[ClassificationRule]) -> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM classification_rules", [])?;
+    for rule in rules {
+        let patterns = serde_json::to_string(&rule.patterns).unwrap_or_else(|_| "[]".to_string());
+        tx.execute(
+            "INSERT INTO classification_rules (label, language, patterns, min_matches)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![rule.label, rule.language, patterns, rule.min_matches],
+        )?;
+    }
+    tx.commit()
+}
+
+fn read_rules(conn: &Connection) -> rusqlite::Result<Vec<ClassificationRule>> {
+    let mut stmt = conn.prepare(
+        "SELECT label, language, patterns, min_matches FROM classification_rules ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        let patterns: String = row.get(2)?;
+        Ok(ClassificationRule {
+            label: row.get(0)?,
+            language: row.get(1)?,
+            patterns: serde_json::from_str(&patterns).unwrap_or_default(),
+            min_matches: row.get(3)?,
+        })
+    })?;
+    rows.collect()
+}
+
+/// Load stored rules into memory (called from `init_source_db`). Rules that
+/// no longer compile are skipped.
+pub(crate) fn load_classification_rules(conn: &Connection) -> rusqlite::Result<()> {
+    let compiled: Vec<CompiledRule> = read_rules(conn)?
+        .into_iter()
+        .filter_map(|rule| match compile_rule(rule) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                warn!("[classify] Skipping stored rule: {}", e);
+                None
+            }
+        })
+        .collect();
+    if !compiled.is_empty() {
+        info!("[classify] Loaded {} classification rules", compiled.len());
+    }
+    *RULES.write().unwrap() = compiled;
+    Ok(())
+}
+
+/// Replace the custom classification rules and persist them.
+///
+/// Rules are tried in order before the built-in English patterns; the
+/// first rule whose language matches and whose patterns match at least
+/// `min_matches` times decides the label. An empty list removes all rules.
+pub fn set_classification_rules(rules: Vec<ClassificationRule>) -> Result<(), RagError> {
+    let compiled = rules
+        .into_iter()
+        .map(|mut rule| {
+            rule.label = rule.label.trim().to_string();
+            rule.language = rule.language.trim().to_lowercase();
+            if rule.language.is_empty() {
+                rule.language = ANY_LANGUAGE.to_string();
+            }
+            compile_rule(rule)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut conn = get_connection()?;
+    let rules: Vec<ClassificationRule> = compiled.iter().map(|c| c.rule.clone()).collect();
+    save_rules(&mut conn, &rules).map_err(db_error)?;
+    info!("[classify] Saved {} classification rules", rules.len());
+    *RULES.write().unwrap() = compiled;
+    Ok(())
+}
+
+/// Currently registered classification rules, in priority order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_classification_rules() -> Vec<ClassificationRule> {
+    RULES
+        .read()
+        .unwrap()
+        .iter()
+        .map(|c| c.rule.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(
+        label: &str,
+        language: &str,
+        patterns: &[&str],
+        min_matches: u32,
+    ) -> ClassificationRule {
+        ClassificationRule {
+            label: label.to_string(),
+            language: language.to_string(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            min_matches,
+        }
+    }
+
+    #[test]
+    fn test_rules_match_by_language_and_count() {
+        let rules: Vec<CompiledRule> = vec![
+            rule("definition", "ko", &[r"(이)?란\s", r"을 말한다"], 1),
+            rule("procedure", "*", &[r"먼저", r"다음으로", r"마지막으로"], 2),
+        ]
+        .into_iter()
+        .map(|r| compile_rule(r).unwrap())
+        .collect();
+
+        assert_eq!(
+            match_rules(&rules, "스테이킹이란 토큰을 예치하는 것을 말한다."),
+            Some(ChunkType::Definition)
+        );
+        assert_eq!(
+            match_rules(&rules, "먼저 지갑을 연다. 다음으로 서명한다."),
+            Some(ChunkType::Procedure)
+        );
+        assert_eq!(match_rules(&rules, "먼저 지갑을 연다."), None);
+        assert_eq!(match_rules(&rules, "Staking란 means locking."), None);
+        assert!(compile_rule(rule("glossary", "ko", &["x"], 1)).is_err());
+        assert!(compile_rule(rule("list", "ko", &["("], 1)).is_err());
+    }
+
+    #[test]
+    fn test_rules_round_trip_through_table() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        create_classification_rules_table(&conn).unwrap();
+        let rules = vec![
+            rule("definition", "ja", &["とは"], 1),
+            rule("example", "*", &["예를 들어", "例えば"], 1),
+        ];
+        save_rules(&mut conn, &rules).unwrap();
+        assert_eq!(read_rules(&conn).unwrap(), rules);
+        save_rules(&mut conn, &rules[1..]).unwrap();
+        assert_eq!(read_rules(&conn).unwrap(), rules[1..]);
+    }
+}
@@ -0,0 +1,725 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Code-aware chunking for source files.
+//!
+//! Splits source code along declaration boundaries instead of blind line
+//! windows, so a function or class stays in one chunk whenever it fits.
+//! Block structure comes from bracket depth for C-like languages and from
+//! indentation for Python-like ones; a small lexer keeps brackets inside
+//! strings and comments from counting. Each chunk's `header_path` names the
+//! symbols it contains (e.g. `impl Parser > fn parse`).
+//!
+//! Declarations larger than `max_chars` are split into their members when
+//! they have any (methods of a class, functions of an impl), otherwise into
+//! line windows that share a `batch_id`.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::api::language_detect::detect_language_code;
+use crate::api::semantic_chunker::{CharIndex, StructuredChunk};
+
+/// How a language marks blocks, comments and strings.
+#[derive(Debug, Clone, Copy)]
+struct Syntax {
+    /// Blocks are delimited by indentation rather than braces.
+    indent_blocks: bool,
+    line_comment: &'static str,
+    block_comment: Option<(&'static str, &'static str)>,
+    /// `'...'` is a string; otherwise it is a char literal or a lifetime.
+    single_quote_strings: bool,
+    backtick_strings: bool,
+    triple_quotes: bool,
+}
+
+const C_LIKE: Syntax = Syntax {
+    indent_blocks: false,
+    line_comment: "//",
+    block_comment: Some(("/*", "*/")),
+    single_quote_strings: false,
+    backtick_strings: false,
+    triple_quotes: false,
+};
+
+fn syntax_for(language: &str, text: &str) -> Syntax {
+    match language.trim().to_lowercase().as_str() {
+        "rust" | "rs" | "c" | "h" | "cpp" | "c++" | "cc" | "hpp" | "java" | "csharp" | "cs"
+        | "c#" | "swift" | "scala" => C_LIKE,
+        "kotlin" | "kt" | "go" | "golang" => Syntax {
+            backtick_strings: true,
+            ..C_LIKE
+        },
+        "javascript" | "js" | "jsx" | "typescript" | "ts" | "tsx" | "dart" | "php" => Syntax {
+            single_quote_strings: true,
+            backtick_strings: true,
+            triple_quotes: language.eq_ignore_ascii_case("dart"),
+            ..C_LIKE
+        },
+        "python" | "py" | "ruby" | "rb" => Syntax {
+            indent_blocks: true,
+            line_comment: "#",
+            block_comment: None,
+            single_quote_strings: true,
+            backtick_strings: false,
+            triple_quotes: true,
+        },
+        // Unknown language: braces are the better guess if there are any.
+        _ if text.contains('{') => Syntax {
+            single_quote_strings: true,
+            ..C_LIKE
+        },
+        _ => Syntax {
+            indent_blocks: true,
+            line_comment: "#",
+            block_comment: None,
+            single_quote_strings: true,
+            backtick_strings: false,
+            triple_quotes: true,
+        },
+    }
+}
+
+/// One source line with the block structure at its start.
+#[derive(Debug, Clone)]
+struct Line {
+    start: usize,
+    /// End offset, excluding the line terminator.
+    end: usize,
+    /// Bracket depth at the start of the line.
+    depth: usize,
+    indent: usize,
+    /// Starts inside a multi-line string or comment.
+    in_literal: bool,
+    /// Has anything besides whitespace and comments.
+    has_code: bool,
+    blank: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LexState {
+    Code,
+    BlockComment,
+    Str(char),
+    Triple(char),
+}
+
+/// Length of a char literal at the start of `rest` (which begins with `'`),
+/// or `None` when the quote is a lifetime or label.
+fn char_literal_len(rest: &str) -> Option<usize> {
+    let mut chars = rest.char_indices().skip(1);
+    let (_, first) = chars.next()?;
+    if first == '\\' {
+        // Escapes such as '\n' or '\u{1F600}'.
+        return rest[2..]
+            .char_indices()
+            .take(10)
+            .find(|&(_, c)| c == '\'')
+            .map(|(i, _)| i + 3);
+    }
+    let (i, closing) = chars.next()?;
+    (closing == '\'').then_some(i + 1)
+}
+
+fn scan_lines(text: &str, syntax: &Syntax) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut depth = 0usize;
+    let mut state = LexState::Code;
+    let mut line_start = 0;
+
+    for raw in text.split_inclusive('\n') {
+        let in_literal = state != LexState::Code;
+        let depth_at_start = depth;
+        let mut has_code = false;
+        let mut i = 0;
+
+        while i < raw.len() {
+            let rest = &raw[i..];
+            let c = rest.chars().next().unwrap_or('\n');
+            match state {
+                LexState::BlockComment => {
+                    if let Some((_, close)) = syntax.block_comment {
+                        if rest.starts_with(close) {
+                            state = LexState::Code;
+                            i += close.len();
+                            continue;
+                        }
+                    }
+                }
+                LexState::Str(quote) | LexState::Triple(quote) => {
+                    if !c.is_whitespace() {
+                        has_code = true;
+                    }
+                    if c == '\\' {
+                        i += 1;
+                        if let Some(escaped) = raw[i..].chars().next() {
+                            i += escaped.len_utf8();
+                        }
+                        continue;
+                    }
+                    if let LexState::Triple(_) = state {
+                        if rest.starts_with(&quote.to_string().repeat(3)) {
+                            state = LexState::Code;
+                            i += 3;
+                            continue;
+                        }
+                    } else if c == quote || (c == '\n' && quote != '`') {
+                        // Unterminated plain strings end with the line.
+                        state = LexState::Code;
+                    }
+                }
+                LexState::Code => {
+                    if rest.starts_with(syntax.line_comment) {
+                        break;
+                    }
+                    if let Some((open, _)) = syntax.block_comment {
+                        if rest.starts_with(open) {
+                            state = LexState::BlockComment;
+                            i += open.len();
+                            continue;
+                        }
+                    }
+                    if !c.is_whitespace() {
+                        has_code = true;
+                    }
+                    if syntax.triple_quotes
+                        && (rest.starts_with("\"\"\"") || rest.starts_with("'''"))
+                    {
+                        state = LexState::Triple(c);
+                        i += 3;
+                        continue;
+                    }
+                    match c {
+                        '"' => state = LexState::Str('"'),
+                        '`' if syntax.backtick_strings => state = LexState::Str('`'),
+                        '\'' if syntax.single_quote_strings => state = LexState::Str('\''),
+                        '\'' => {
+                            if let Some(len) = char_literal_len(rest) {
+                                i += len;
+                                continue;
+                            }
+                        }
+                        '(' | '[' | '{' => depth += 1,
+                        ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+            }
+            i += c.len_utf8();
+        }
+
+        let content = raw.trim_end_matches(['\n', '\r']);
+        let indent = content
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        lines.push(Line {
+            start: line_start,
+            end: line_start + content.len(),
+            depth: depth_at_start,
+            indent,
+            in_literal,
+            has_code,
+            blank: content.trim().is_empty(),
+        });
+        line_start += raw.len();
+    }
+    lines
+}
+
+/// A run of lines `first..=last` forming one declaration or statement.
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    first: usize,
+    last: usize,
+}
+
+struct CodeChunker<'a> {
+    text: &'a str,
+    syntax: Syntax,
+    lines: Vec<Line>,
+    max_chars: usize,
+    chunks: Vec<StructuredChunk>,
+}
+
+impl<'a> CodeChunker<'a> {
+    fn line_text(&self, idx: usize) -> &'a str {
+        &self.text[self.lines[idx].start..self.lines[idx].end]
+    }
+
+    fn unit_len(&self, unit: Unit) -> usize {
+        self.lines[unit.last].end - self.lines[unit.first].start
+    }
+
+    /// Block level of a line; `None` for lines that can never start a unit.
+    fn level(&self, idx: usize) -> Option<usize> {
+        let line = &self.lines[idx];
+        if line.blank || line.in_literal {
+            return None;
+        }
+        let trimmed = self.line_text(idx).trim_start();
+        if self.syntax.indent_blocks {
+            let continues = [
+                "else", "elif", "except", "finally", "ensure", "rescue", "end",
+            ]
+            .iter()
+            .any(|kw| trimmed.starts_with(kw) && !starts_with_ident_char(&trimmed[kw.len()..]));
+            if line.depth > 0 || continues || trimmed.starts_with([')', ']', '}']) {
+                return None;
+            }
+            Some(line.indent)
+        } else {
+            // Closing brackets and Allman-style opening braces continue
+            // the previous declaration.
+            if trimmed.starts_with([')', ']', '}', '{']) {
+                return None;
+            }
+            Some(line.depth)
+        }
+    }
+
+    /// Comments, attributes and decorators belong to the next declaration.
+    fn is_prefix_line(&self, idx: usize) -> bool {
+        let trimmed = self.line_text(idx).trim_start();
+        self.lines[idx].blank
+            || !self.lines[idx].has_code
+            || trimmed.starts_with("#[")
+            || trimmed.starts_with('@')
+    }
+
+    fn split_units(&self, first: usize, last: usize, base: usize) -> Vec<Unit> {
+        let mut units: Vec<Unit> = Vec::new();
+        for idx in first..=last {
+            match units.last_mut() {
+                Some(unit) if self.level(idx) != Some(base) => unit.last = idx,
+                _ => units.push(Unit {
+                    first: idx,
+                    last: idx,
+                }),
+            }
+        }
+
+        // Fold comment/attribute-only units into the declaration they precede.
+        let mut merged: Vec<Unit> = Vec::new();
+        let mut pending_first: Option<usize> = None;
+        for unit in units {
+            if (unit.first..=unit.last).all(|i| self.is_prefix_line(i)) {
+                pending_first.get_or_insert(unit.first);
+                continue;
+            }
+            merged.push(Unit {
+                first: pending_first.take().unwrap_or(unit.first),
+                last: unit.last,
+            });
+        }
+        if let Some(first) = pending_first {
+            match merged.last_mut() {
+                Some(unit) => unit.last = last,
+                None => merged.push(Unit { first, last }),
+            }
+        }
+        merged
+    }
+
+    /// Index of the first line of `unit` that is actual code.
+    fn head_line(&self, unit: Unit) -> Option<usize> {
+        (unit.first..=unit.last).find(|&i| !self.is_prefix_line(i))
+    }
+
+    fn symbol(&self, unit: Unit) -> Option<String> {
+        self.head_line(unit)
+            .and_then(|idx| detect_symbol(self.line_text(idx)))
+    }
+
+    fn chunk_units(&mut self, units: &[Unit], parent_path: &str, skip_symbol_at: Option<usize>) {
+        let mut group: Option<(Unit, Vec<String>)> = None;
+        for &unit in units {
+            let symbol = if self.head_line(unit) == skip_symbol_at {
+                None
+            } else {
+                self.symbol(unit)
+            };
+            if self.unit_len(unit) > self.max_chars {
+                if let Some((pending, symbols)) = group.take() {
+                    self.push_chunk(pending, &join_path(parent_path, &symbols), None);
+                }
+                let path = join_path(parent_path, symbol.as_slice());
+                self.split_large(unit, &path);
+                continue;
+            }
+            match group.as_mut() {
+                Some((pending, symbols))
+                    if self.lines[unit.last].end - self.lines[pending.first].start
+                        <= self.max_chars =>
+                {
+                    pending.last = unit.last;
+                    symbols.extend(symbol);
+                }
+                _ => {
+                    if let Some((pending, symbols)) = group.take() {
+                        self.push_chunk(pending, &join_path(parent_path, &symbols), None);
+                    }
+                    group = Some((unit, symbol.into_iter().collect()));
+                }
+            }
+        }
+        if let Some((pending, symbols)) = group {
+            self.push_chunk(pending, &join_path(parent_path, &symbols), None);
+        }
+    }
+
+    /// Splits an oversized declaration into its members, or into line
+    /// windows when it has none.
+    fn split_large(&mut self, unit: Unit, path: &str) {
+        if let Some(head) = self.head_line(unit) {
+            let body_base = if self.syntax.indent_blocks {
+                (head + 1..=unit.last)
+                    .filter_map(|i| self.level(i))
+                    .filter(|&level| level > self.lines[head].indent)
+                    .min()
+            } else {
+                Some(self.lines[head].depth + 1)
+            };
+            if let Some(base) = body_base {
+                let members = self.split_units(unit.first, unit.last, base);
+                let named = members
+                    .iter()
+                    .filter(|m| self.head_line(**m) != Some(head) && self.symbol(**m).is_some())
+                    .count();
+                if named > 0 && members.len() > 1 {
+                    self.chunk_units(&members, path, Some(head));
+                    return;
+                }
+            }
+        }
+        self.split_lines(unit, path);
+    }
+
+    fn split_lines(&mut self, unit: Unit, path: &str) {
+        let mut windows: Vec<(usize, usize)> = Vec::new();
+        let mut start = self.lines[unit.first].start;
+        let mut end = start;
+        for idx in unit.first..=unit.last {
+            let line = &self.lines[idx];
+            if line.end - start > self.max_chars && end > start {
+                windows.push((start, end));
+                start = line.start;
+            }
+            end = line.end;
+            // A single line longer than the limit is cut at char boundaries.
+            while end - start > self.max_chars {
+                let mut cut = start + self.max_chars;
+                while !self.text.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                windows.push((start, cut));
+                start = cut;
+            }
+        }
+        if end > start {
+            windows.push((start, end));
+        }
+
+        let batch_id = (windows.len() > 1).then(|| uuid::Uuid::new_v4().to_string());
+        let total = windows.len() as i32;
+        for (i, (start, end)) in windows.into_iter().enumerate() {
+            let batch = batch_id.as_ref().map(|id| (id.clone(), i as i32, total));
+            self.push_range(start, end, path, batch);
+        }
+    }
+
+    fn push_chunk(&mut self, unit: Unit, path: &str, batch: Option<(String, i32, i32)>) {
+        let (start, end) = (self.lines[unit.first].start, self.lines[unit.last].end);
+        self.push_range(start, end, path, batch);
+    }
+
+    fn push_range(
+        &mut self,
+        start: usize,
+        end: usize,
+        path: &str,
+        batch: Option<(String, i32, i32)>,
+    ) {
+        let slice = &self.text[start..end];
+        let content = slice.trim_end();
+        let leading = content.len() - content.trim_start_matches(['\n', '\r']).len();
+        let content = &content[leading..];
+        if content.trim().is_empty() {
+            return;
+        }
+        let start_pos = start + leading;
+        let (batch_id, batch_index, batch_total) = match batch {
+            Some((id, index, total)) => (Some(id), Some(index), Some(total)),
+            None => (None, None, None),
+        };
+        self.chunks.push(StructuredChunk {
+            index: self.chunks.len() as i32,
+            content: content.to_string(),
+            header_path: path.to_string(),
+            chunk_type: "code".to_string(),
+            start_pos: start_pos as i32,
+            end_pos: (start_pos + content.len()) as i32,
+            batch_id,
+            batch_index,
+            batch_total,
+            language: detect_language_code(content).to_string(),
+        });
+    }
+}
+
+fn starts_with_ident_char(s: &str) -> bool {
+    s.chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn join_path(parent: &str, symbols: &[String]) -> String {
+    match (parent.is_empty(), symbols.is_empty()) {
+        (_, true) => parent.to_string(),
+        (true, false) => symbols.join(", "),
+        (false, false) => format!("{} > {}", parent, symbols.join(", ")),
+    }
+}
+
+static DECL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(fn|fun|func|function|def|class|struct|enum|trait|interface|impl|mod|module|object|protocol|extension|namespace|union|record)\b",
+    )
+    .unwrap()
+});
+
+static BINDING_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(const|let|var|val)\s+([A-Za-z_$][\w$]*)\s*(?::[^=]*)?=\s*(async\s+)?(function\b|\([^)]*\)\s*=>|[A-Za-z_$][\w$]*\s*=>)").unwrap()
+});
+
+static CALLABLE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"([A-Za-z_$][\w$]*)\s*(?:<[^()]*>)?\s*\(").unwrap());
+
+const CONTROL_KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "catch", "return", "else", "do", "match", "when", "new",
+    "sizeof", "typeof", "await", "throw", "using", "lock", "foreach",
+];
+
+fn leading_ident(s: &str) -> &str {
+    let end = s
+        .char_indices()
+        .find(|&(_, c)| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Names the declaration on `line`, e.g. `fn parse`, `class Parser` or
+/// `impl Display for Token`. Returns `None` for plain statements.
+fn detect_symbol(line: &str) -> Option<String> {
+    let code = line.trim();
+    // Declaration keywords only count before the signature's first bracket
+    // or assignment, so `x = new Foo(function() {})` is not a function.
+    let head_end = code.find(['(', '=', '{', ';']).unwrap_or(code.len());
+    if let Some(m) = DECL_RE.find(&code[..head_end]) {
+        let keyword = m.as_str();
+        let rest = code[m.end()..].trim_start();
+        let name = match keyword {
+            "impl" => {
+                let rest = skip_generics(rest);
+                let end = rest
+                    .find(" where")
+                    .or_else(|| rest.find('{'))
+                    .unwrap_or(rest.len());
+                rest[..end].trim().to_string()
+            }
+            // Go methods: `func (r *Reader) Read(`
+            "func" if rest.starts_with('(') => rest
+                .find(')')
+                .map(|i| leading_ident(rest[i + 1..].trim_start()).to_string())
+                .unwrap_or_default(),
+            _ => leading_ident(rest).to_string(),
+        };
+        if !name.is_empty() {
+            return Some(format!("{} {}", keyword, name));
+        }
+    }
+    if let Some(caps) = BINDING_RE.captures(code) {
+        return Some(format!("{} {}", &caps[1], &caps[2]));
+    }
+    // C-style `int parse(const char *s) {` or Java-style methods.
+    if code.ends_with(';') || code.starts_with(['#', '.', ')']) {
+        return None;
+    }
+    let caps = CALLABLE_RE.captures(code)?;
+    let name = caps.get(1)?.as_str();
+    let before = code[..caps.get(0)?.start()].trim_end();
+    let is_call = before.is_empty() || before.ends_with(['=', '.', ',', '(', ':']);
+    if CONTROL_KEYWORDS.contains(&name) || is_call {
+        return None;
+    }
+    Some(format!("{}()", name))
+}
+
+fn skip_generics(s: &str) -> &str {
+    if !s.starts_with('<') {
+        return s;
+    }
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return s[i + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+    s
+}
+
+/// Splits source code into chunks along function/class boundaries.
+///
+/// `language` is a name or file extension (`rust`, `py`, `ts`, ...); unknown
+/// languages fall back to brace or indentation heuristics depending on the
+/// text. Small neighbouring declarations are packed together up to
+/// `max_chars` (minimum 100), and each chunk's `header_path` lists the
+/// symbols it covers.
+#[flutter_rust_bridge::frb(sync)]
+pub fn code_chunk(text: String, language: String, max_chars: i32) -> Vec<StructuredChunk> {
+    if text.trim().is_empty() {
+        return vec![];
+    }
+    let syntax = syntax_for(&language, &text);
+    let lines = scan_lines(&text, &syntax);
+    let mut chunker = CodeChunker {
+        text: &text,
+        syntax,
+        lines,
+        max_chars: max_chars.max(100) as usize,
+        chunks: Vec::new(),
+    };
+    let base = if syntax.indent_blocks {
+        (0..chunker.lines.len())
+            .filter_map(|i| chunker.level(i))
+            .min()
+            .unwrap_or(0)
+    } else {
+        0
+    };
+    let last = chunker.lines.len() - 1;
+    let units = chunker.split_units(0, last, base);
+    chunker.chunk_units(&units, "", None);
+    // Spans were tracked in bytes; report them in chars
+    let index = CharIndex::new(&text);
+    let mut chunks = chunker.chunks;
+    for chunk in &mut chunks {
+        chunk.start_pos = index.char_at(chunk.start_pos as usize);
+        chunk.end_pos = index.char_at(chunk.end_pos as usize);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST_SRC: &str = r#"use std::fmt;
+
+/// A parsed token.
+#[derive(Debug)]
+pub struct Token {
+    text: String, // "}" must not close the struct
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+pub fn tokenize(input: &str) -> Vec<Token> {
+    input
+        .split('{')
+        .map(|s| Token { text: s.to_string() })
+        .collect()
+}
+"#;
+
+    #[test]
+    fn test_code_chunk_keeps_functions_whole() {
+        let chunks = code_chunk(RUST_SRC.to_string(), "rust".to_string(), 200);
+        let tokenize = chunks
+            .iter()
+            .find(|c| c.header_path == "fn tokenize")
+            .expect("tokenize chunk");
+        assert!(tokenize.content.starts_with("pub fn tokenize"));
+        assert!(tokenize.content.ends_with('}'));
+
+        let token = chunks
+            .iter()
+            .find(|c| c.header_path.contains("struct Token"))
+            .expect("struct chunk");
+        assert!(token.content.contains("/// A parsed token."));
+        assert!(token.content.contains("#[derive(Debug)]"));
+        assert!(chunks
+            .iter()
+            .any(|c| c.header_path == "impl fmt::Display for Token"));
+
+        for chunk in &chunks {
+            assert_eq!(chunk.chunk_type, "code");
+            assert_eq!(
+                &RUST_SRC[chunk.start_pos as usize..chunk.end_pos as usize],
+                chunk.content
+            );
+        }
+    }
+
+    #[test]
+    fn test_code_chunk_splits_large_class_into_methods() {
+        let body = "        total = 0\n".repeat(8);
+        let src = format!(
+            "import os\n\n\nclass Loader:\n    \"\"\"Loads things.\"\"\"\n\n    def __init__(self):\n{body}\n    @staticmethod\n    def load(path):\n{body}        return total\n\n\ndef main():\n    Loader()\n"
+        );
+        let chunks = code_chunk(src, "python".to_string(), 200);
+        let paths: Vec<&str> = chunks.iter().map(|c| c.header_path.as_str()).collect();
+        assert!(
+            paths.contains(&"class Loader > def __init__"),
+            "{:?}",
+            paths
+        );
+        let load = chunks
+            .iter()
+            .find(|c| c.header_path == "class Loader > def load")
+            .expect("load chunk");
+        assert!(load.content.starts_with("    @staticmethod"));
+        assert!(paths.iter().any(|p| p.ends_with("def main")));
+    }
+
+    #[test]
+    fn test_code_chunk_batches_oversized_function() {
+        let body = "    let value = compute_something_expensive(index);\n".repeat(20);
+        let src = format!("fn huge() {{\n{body}}}\n");
+        let chunks = code_chunk(src, "rs".to_string(), 300);
+        assert!(chunks.len() > 1);
+        let batch_id = chunks[0].batch_id.clone().expect("batch id");
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.header_path, "fn huge");
+            assert_eq!(chunk.batch_id.as_ref(), Some(&batch_id));
+            assert_eq!(chunk.batch_index, Some(i as i32));
+            assert!(chunk.content.len() <= 300);
+        }
+    }
+}
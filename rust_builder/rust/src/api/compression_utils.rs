@@ -18,17 +18,22 @@
 
 use std::collections::HashSet;
 
+use crate::api::analyzers::is_stopword_in;
+use crate::api::sentence_segmenter;
+
 #[derive(Debug, Clone)]
 pub struct CompressionOptions {
+    /// Drop stopwords (off by default; it damages context).
     pub remove_stopwords: bool,
     pub remove_duplicates: bool,
+    /// Analyzer language for stopword removal ("auto" detects per word).
     pub language: String,
     pub level: i32,
 }
 
 impl Default for CompressionOptions {
     fn default() -> Self {
-        Self { remove_stopwords: true, remove_duplicates: true, language: "en".to_string(), level: 1 }
+        Self { remove_stopwords: false, remove_duplicates: true, language: "en".to_string(), level: 1 }
     }
 }
 
@@ -43,26 +48,9 @@ pub struct CompressedText {
     pub chars_saved_truncation: i32,
 }
 
-/// Split text into sentences.
+/// Split text into sentences (Unicode-aware; see `sentence_segmenter`).
 pub fn split_sentences(text: String) -> Vec<String> {
-    if text.is_empty() { return vec![]; }
-
-    let mut sentences = Vec::new();
-    let mut current = String::new();
-
-    for ch in text.chars() {
-        current.push(ch);
-        if ch == '.' || ch == '?' || ch == '!' || ch == '。' {
-            let trimmed = current.trim().to_string();
-            if !trimmed.is_empty() && trimmed.len() > 1 { sentences.push(trimmed); }
-            current = String::new();
-        }
-    }
-
-    let trimmed = current.trim().to_string();
-    if !trimmed.is_empty() { sentences.push(trimmed); }
-
-    sentences
+    sentence_segmenter::split_sentences(&text)
 }
 
 /// Calculate hash for sentence deduplication (FNV-1a).
@@ -103,6 +91,12 @@ pub fn compress_text(text: String, max_chars: i32, options: CompressionOptions)
     }
     
     let mut result = unique_sentences.join(" ");
+    let mut chars_saved_stopwords = 0;
+    if options.remove_stopwords {
+        let before = result.chars().count() as i32;
+        result = remove_stopwords(&result, &options.language);
+        chars_saved_stopwords = before - result.chars().count() as i32;
+    }
     let chars_before_truncation = result.chars().count() as i32;
     
     if max_chars > 0 && result.chars().count() > max_chars as usize {
@@ -119,10 +113,29 @@ pub fn compress_text(text: String, max_chars: i32, options: CompressionOptions)
     CompressedText {
         text: result, original_chars, compressed_chars,
         ratio: if original_chars > 0 { compressed_chars as f64 / original_chars as f64 } else { 1.0 },
-        sentences_removed, chars_saved_stopwords: 0, chars_saved_truncation,
+        sentences_removed, chars_saved_stopwords, chars_saved_truncation,
     }
 }
 
+/// Drop words that are stopwords for `language`, keeping punctuation
+/// attached to them (a sentence-final "it." leaves its ".").
+fn remove_stopwords(text: &str, language: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if core.is_empty() || !is_stopword_in(language, &core.to_lowercase()) {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(word);
+            continue;
+        }
+        let tail_len = word.chars().rev().take_while(|c| !c.is_alphanumeric()).map(char::len_utf8).sum::<usize>();
+        out.push_str(&word[word.len() - tail_len..]);
+    }
+    out
+}
+
 /// Quick compress with default options.
 pub fn compress_text_simple(text: String, level: i32) -> String {
     compress_text(text, 0, CompressionOptions { level, ..Default::default() }).text
@@ -141,6 +154,8 @@ mod tests {
     fn test_split_sentences() {
         let sentences = split_sentences("Hello. World!".to_string());
         assert_eq!(sentences.len(), 2);
+        let sentences = split_sentences("회의는 끝났어요. “다음에 봐요.”라고 했다！".to_string());
+        assert_eq!(sentences, vec!["회의는 끝났어요.", "“다음에 봐요.”라고 했다！"]);
     }
 
     #[test]
@@ -157,4 +172,12 @@ mod tests {
         let result = compress_text(text, 0, options);
         assert_eq!(result.sentences_removed, 1);
     }
+
+    #[test]
+    fn test_compress_text_removes_stopwords() {
+        let options = CompressionOptions { remove_stopwords: true, remove_duplicates: false, ..Default::default() };
+        let result = compress_text("The fee is paid by the issuer. Who pays it?".to_string(), 0, options);
+        assert_eq!(result.text, "fee paid issuer. pays?");
+        assert!(result.chars_saved_stopwords > 0);
+    }
 }
@@ -0,0 +1,328 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Optional application-level encryption of source and chunk content
+//! (staged documents included).
+//!
+//! Independent of SQLCipher: with a key installed, `content` columns are
+//! stored as `enc1:<key_id>:<base64(nonce || AES-256-GCM ciphertext)>`
+//! while embeddings stay plaintext, so vector search keeps working.
+//! Rows are decrypted on read; the BM25 index is rebuilt from plaintext
+//! in memory only. Retired keys stay available for reading until
+//! `rotate_content_key` has re-encrypted every row.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use log::info;
+use once_cell::sync::Lazy;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use rusqlite::{params, Connection, Row};
+
+use crate::api::db_pool::{get_connection, is_pool_initialized};
+use crate::api::error::{db_error, RagError};
+use crate::api::fts_keyword::{fts_tables_installed, table_exists};
+use crate::api::source_rag::backfill_chunk_languages;
+
+const ENC_PREFIX: &str = "enc1:";
+const KEY_LEN: usize = 32;
+const MAX_KEY_ID_LEN: usize = 32;
+
+static KEY_RING: Lazy<RwLock<KeyRing>> = Lazy::new(|| RwLock::new(KeyRing::new()));
+
+/// Active key (used for new writes) plus retired keys (read-only).
+#[derive(Clone)]
+pub(crate) struct KeyRing {
+    active: Option<String>,
+    keys: HashMap<String, [u8; KEY_LEN]>,
+}
+
+impl KeyRing {
+    fn new() -> Self {
+        KeyRing { active: None, keys: HashMap::new() }
+    }
+
+    fn install(&mut self, key_id: &str, key: &[u8]) -> Result<(), RagError> {
+        let valid_id = !key_id.is_empty()
+            && key_id.len() <= MAX_KEY_ID_LEN
+            && key_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid_id {
+            return Err(RagError::InvalidInput(format!("Invalid key id '{}'", key_id)));
+        }
+        let key: [u8; KEY_LEN] = key.try_into().map_err(|_| {
+            RagError::InvalidInput(format!("Content key must be {} bytes, got {}", KEY_LEN, key.len()))
+        })?;
+        if self.keys.get(key_id).is_some_and(|existing| existing != &key) {
+            return Err(RagError::InvalidInput(format!("Key id '{}' is already used by another key", key_id)));
+        }
+        self.keys.insert(key_id.to_string(), key);
+        self.active = Some(key_id.to_string());
+        Ok(())
+    }
+
+    fn cipher(&self, key_id: &str) -> Result<LessSafeKey, RagError> {
+        let key = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| RagError::InvalidInput(format!("Content key '{}' is not installed", key_id)))?;
+        let unbound = UnboundKey::new(&AES_256_GCM, key)
+            .map_err(|_| RagError::InternalError("Invalid AES-256-GCM key".to_string()))?;
+        Ok(LessSafeKey::new(unbound))
+    }
+
+    /// Encrypt with the active key, or return the text unchanged when
+    /// encryption is off. The key id is bound as associated data.
+    pub(crate) fn encrypt(&self, plain: &str) -> Result<String, RagError> {
+        let Some(key_id) = &self.active else {
+            return Ok(plain.to_string());
+        };
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| RagError::InternalError("Failed to generate nonce".to_string()))?;
+        let mut in_out = plain.as_bytes().to_vec();
+        self.cipher(key_id)?
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(key_id.as_bytes()), &mut in_out)
+            .map_err(|_| RagError::InternalError("Content encryption failed".to_string()))?;
+        let mut blob = nonce.to_vec();
+        blob.extend_from_slice(&in_out);
+        Ok(format!("{}{}:{}", ENC_PREFIX, key_id, BASE64.encode(blob)))
+    }
+
+    /// Decrypt stored content; plaintext (legacy) rows pass through.
+    pub(crate) fn decrypt(&self, stored: String) -> Result<String, RagError> {
+        let Some(rest) = stored.strip_prefix(ENC_PREFIX) else {
+            return Ok(stored);
+        };
+        let corrupt = || RagError::InternalError("Corrupt encrypted content".to_string());
+        let (key_id, encoded) = rest.split_once(':').ok_or_else(corrupt)?;
+        let blob = BASE64.decode(encoded).map_err(|_| corrupt())?;
+        if blob.len() < NONCE_LEN {
+            return Err(corrupt());
+        }
+        let (nonce, sealed) = blob.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| corrupt())?;
+        let mut in_out = sealed.to_vec();
+        let plain = self
+            .cipher(key_id)?
+            .open_in_place(nonce, Aad::from(key_id.as_bytes()), &mut in_out)
+            .map_err(|_| RagError::InvalidInput(format!("Content key '{}' failed to decrypt", key_id)))?;
+        String::from_utf8(plain.to_vec()).map_err(|_| corrupt())
+    }
+
+    fn is_current(&self, stored: &str) -> bool {
+        match &self.active {
+            Some(key_id) => stored.starts_with(&format!("{}{}:", ENC_PREFIX, key_id)),
+            None => !stored.starts_with(ENC_PREFIX),
+        }
+    }
+}
+
+/// Refuse to encrypt while FTS tables exist: their triggers would index
+/// the ciphertext.
+fn ensure_no_fts() -> Result<(), RagError> {
+    if fts_tables_installed()? {
+        return Err(RagError::InvalidInput(
+            "Switch the keyword backend to InMemory before enabling content encryption".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Delete the entities and triples extracted from chunk text. They are
+/// stored in plaintext, so they are not kept (or extracted) while content
+/// encryption is on.
+pub(crate) fn purge_plaintext_side_tables(conn: &Connection) -> rusqlite::Result<()> {
+    for table in ["chunk_entities", "kg_triples"] {
+        if !table_exists(conn, table)? {
+            continue;
+        }
+        let purged = conn.execute(&format!("DELETE FROM {}", table), [])?;
+        if purged > 0 {
+            info!("[content_crypto] Purged {} plaintext rows from {}", purged, table);
+        }
+    }
+    Ok(())
+}
+
+/// Install a 32-byte key and use it for all new content writes.
+///
+/// Earlier keys stay installed for reading. The key itself is never
+/// persisted; the app must provide it again after every launch. Stored
+/// entities and triples are deleted, as they hold plaintext, and chunks
+/// whose language couldn't be detected without the key are backfilled.
+/// Fails while the FTS5 keyword backend's tables exist.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_content_encryption_key(key_id: String, key: Vec<u8>) -> Result<(), RagError> {
+    ensure_no_fts()?;
+    KEY_RING.write().unwrap().install(&key_id, &key)?;
+    if is_pool_initialized() {
+        let conn = get_connection()?;
+        purge_plaintext_side_tables(&conn).map_err(db_error)?;
+        let has_language: bool = conn.prepare("SELECT language FROM chunks LIMIT 1").is_ok();
+        if has_language {
+            backfill_chunk_languages(&conn).map_err(db_error)?;
+        }
+    }
+    info!("[content_crypto] Content encryption enabled with key '{}'", key_id);
+    Ok(())
+}
+
+/// Forget all keys. New content is written in plaintext and existing
+/// encrypted rows become unreadable until their key is installed again.
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_content_encryption_keys() {
+    *KEY_RING.write().unwrap() = KeyRing::new();
+    info!("[content_crypto] Content keys cleared");
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_content_encryption_enabled() -> bool {
+    KEY_RING.read().unwrap().active.is_some()
+}
+
+pub(crate) fn encrypt_content(plain: &str) -> Result<String, RagError> {
+    KEY_RING.read().unwrap().encrypt(plain)
+}
+
+pub(crate) fn decrypt_content(stored: String) -> Result<String, RagError> {
+    KEY_RING.read().unwrap().decrypt(stored)
+}
+
+/// Read and decrypt a content column inside a rusqlite row mapper.
+pub(crate) fn read_content(row: &Row, idx: usize) -> rusqlite::Result<String> {
+    decrypt_content(row.get(idx)?).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+/// Re-encrypt every source and chunk not yet under the active key
+/// (including plaintext rows) and purge the plaintext side tables. Runs
+/// in one transaction.
+fn reencrypt_rows(conn: &mut Connection, ring: &KeyRing) -> Result<u32, RagError> {
+    let tx = conn.transaction().map_err(db_error)?;
+    purge_plaintext_side_tables(&tx).map_err(db_error)?;
+    let mut updated = 0u32;
+    for table in ["sources", "chunks", "ingest_staging", "ingest_staging_chunks"] {
+        let rows: Vec<(i64, String)> = {
+            let mut stmt = tx.prepare(&format!("SELECT rowid, content FROM {}", table)).map_err(db_error)?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(db_error)?
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(db_error)?;
+            rows
+        };
+        let mut update = tx.prepare(&format!("UPDATE {} SET content = ?1 WHERE rowid = ?2", table)).map_err(db_error)?;
+        for (id, stored) in rows {
+            if ring.is_current(&stored) {
+                continue;
+            }
+            let content = ring.encrypt(&ring.decrypt(stored)?)?;
+            update.execute(params![content, id]).map_err(db_error)?;
+            updated += 1;
+        }
+    }
+    tx.commit().map_err(db_error)?;
+    Ok(updated)
+}
+
+/// Install a new key and re-encrypt all stored content with it.
+///
+/// Also encrypts plaintext rows, so calling this once after enabling
+/// encryption protects data ingested earlier. Applies to every tenant.
+/// Returns the number of rows rewritten. Fails while the FTS5 keyword
+/// backend's tables exist.
+pub fn rotate_content_key(new_key_id: String, new_key: Vec<u8>) -> Result<u32, RagError> {
+    ensure_no_fts()?;
+    // Re-encrypt with a copy, so reads and writes aren't blocked meanwhile.
+    let ring = {
+        let mut ring = KEY_RING.write().unwrap();
+        ring.install(&new_key_id, &new_key)?;
+        ring.clone()
+    };
+    let mut conn = get_connection()?;
+    let updated = reencrypt_rows(&mut conn, &ring)?;
+    info!("[content_crypto] Rotated to key '{}', re-encrypted {} rows", new_key_id, updated);
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::staged_ingest::create_staging_tables;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let mut ring = KeyRing::new();
+        assert_eq!(ring.encrypt("plain").unwrap(), "plain");
+        assert!(ring.install("k1", &[7u8; 16]).is_err());
+
+        ring.install("k1", &[7u8; KEY_LEN]).unwrap();
+        let stored = ring.encrypt("스마트 계약 contract").unwrap();
+        assert!(stored.starts_with("enc1:k1:"));
+        assert!(!stored.contains("contract"));
+        assert_eq!(ring.decrypt(stored.clone()).unwrap(), "스마트 계약 contract");
+        assert_eq!(ring.decrypt("legacy row".to_string()).unwrap(), "legacy row");
+
+        let mut tampered = stored.into_bytes();
+        let last = tampered.len() - 3;
+        tampered[last] = if tampered[last] == b'A' { b'B' } else { b'A' };
+        assert!(ring.decrypt(String::from_utf8(tampered).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_reencrypt_rows_rotates_keys() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, content TEXT);
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT);
+             CREATE TABLE kg_triples (chunk_id INTEGER, subject TEXT);
+             INSERT INTO kg_triples VALUES (1, 'plaintext subject');
+             INSERT INTO sources VALUES (1, 'legacy source');",
+        )
+        .unwrap();
+        create_staging_tables(&conn).unwrap();
+        conn.execute_batch(
+            "INSERT INTO ingest_staging (id, tenant_id, content, rebuild_indexes, skipped_chunks, created_at)
+                 VALUES ('s1', 'default', 'staged text', 0, 0, 0);
+             INSERT INTO ingest_staging_chunks
+                 (staging_id, position, content, chunk_index, start_pos, end_pos, chunk_type)
+                 VALUES ('s1', 0, 'staged chunk', 0, 0, 12, 'general');",
+        )
+        .unwrap();
+        let mut ring = KeyRing::new();
+        ring.install("k1", &[1u8; KEY_LEN]).unwrap();
+        let chunk = ring.encrypt("old chunk").unwrap();
+        conn.execute("INSERT INTO chunks VALUES (1, ?1)", params![chunk]).unwrap();
+
+        ring.install("k2", &[2u8; KEY_LEN]).unwrap();
+        assert_eq!(reencrypt_rows(&mut conn, &ring).unwrap(), 4);
+        assert_eq!(reencrypt_rows(&mut conn, &ring).unwrap(), 0);
+
+        let stored: String = conn.query_row("SELECT content FROM chunks WHERE id = 1", [], |r| r.get(0)).unwrap();
+        assert!(stored.starts_with("enc1:k2:"));
+        assert_eq!(ring.decrypt(stored).unwrap(), "old chunk");
+        let staged: String = conn
+            .query_row("SELECT content FROM ingest_staging_chunks", [], |r| r.get(0))
+            .unwrap();
+        assert!(staged.starts_with("enc1:k2:"));
+        let triples: i64 = conn.query_row("SELECT COUNT(*) FROM kg_triples", [], |r| r.get(0)).unwrap();
+        assert_eq!(triples, 0);
+    }
+}
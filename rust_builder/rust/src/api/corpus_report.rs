@@ -0,0 +1,255 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Corpus analytics for a "knowledge base health" screen.
+//!
+//! `get_corpus_report()` summarizes the active tenant's sources and
+//! chunks (sizes, chunk types, languages) and compares the in-memory
+//! indexes against the database to show whether they are up to date.
+
+use std::collections::{BTreeMap, HashMap};
+use rusqlite::{params, Connection};
+
+use crate::api::bm25_search::{bm25_get_document_count, bm25_vocabulary_size, tokenize_for_bm25};
+use crate::api::content_crypto::read_content;
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::hnsw_point_count;
+use crate::api::language_detect::detect_language_code;
+use crate::api::tenant::active_tenant;
+
+/// Upper bounds (chars) of the chunk length histogram buckets; the last
+/// bucket collects everything longer.
+const LENGTH_BUCKETS_CHARS: [u32; 6] = [128, 256, 512, 1024, 2048, 4096];
+
+#[derive(Debug, Clone)]
+pub struct CorpusCount {
+    pub key: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LengthBucket {
+    /// Inclusive upper bound in chars; `None` for the overflow bucket.
+    pub le_chars: Option<u32>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceCorpusStats {
+    pub source_id: i64,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub chunk_count: u64,
+    pub min_chunk_chars: u32,
+    pub max_chunk_chars: u32,
+    pub avg_chunk_chars: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexFreshness {
+    pub db_chunk_count: u64,
+    /// `None` while no HNSW index is loaded.
+    pub hnsw_point_count: Option<u64>,
+    pub bm25_document_count: u64,
+    pub hnsw_up_to_date: bool,
+    pub bm25_up_to_date: bool,
+    /// Unix seconds of the newest source.
+    pub last_source_added_at: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CorpusReport {
+    pub source_count: u64,
+    pub chunk_count: u64,
+    pub total_chars: u64,
+    /// Distinct terms in the BM25 index.
+    pub vocabulary_size: u64,
+    pub sources: Vec<SourceCorpusStats>,
+    pub chunk_length_histogram: Vec<LengthBucket>,
+    /// Chunk counts by type, most common first.
+    pub chunk_types: Vec<CorpusCount>,
+    /// Chunk counts by detected language, most common first.
+    pub languages: Vec<CorpusCount>,
+    pub index: IndexFreshness,
+}
+
+/// Database-side part of the report.
+struct CorpusStats {
+    sources: Vec<SourceCorpusStats>,
+    total_chars: u64,
+    chunk_count: u64,
+    /// Chunks with at least one BM25 token (what the BM25 index should hold).
+    indexable_chunks: u64,
+    histogram: Vec<LengthBucket>,
+    chunk_types: Vec<CorpusCount>,
+    languages: Vec<CorpusCount>,
+    last_source_added_at: Option<i64>,
+}
+
+fn sorted_counts(counts: HashMap<String, u64>) -> Vec<CorpusCount> {
+    let mut out: Vec<CorpusCount> = counts.into_iter().map(|(key, count)| CorpusCount { key, count }).collect();
+    out.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    out
+}
+
+fn collect_corpus_stats(conn: &Connection, tenant_id: &str) -> rusqlite::Result<CorpusStats> {
+    let mut sources: BTreeMap<i64, SourceCorpusStats> = BTreeMap::new();
+    let mut last_source_added_at = None;
+    let mut stmt = conn.prepare(
+        "SELECT id, name, status, created_at FROM sources WHERE tenant_id = ?1 ORDER BY id",
+    )?;
+    let rows = stmt.query_map(params![tenant_id], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get(1)?, row.get(2)?, row.get::<_, Option<i64>>(3)?))
+    })?;
+    for row in rows {
+        let (source_id, name, status, created_at) = row?;
+        last_source_added_at = last_source_added_at.max(created_at);
+        sources.insert(
+            source_id,
+            SourceCorpusStats {
+                source_id,
+                name,
+                status,
+                chunk_count: 0,
+                min_chunk_chars: 0,
+                max_chunk_chars: 0,
+                avg_chunk_chars: 0.0,
+            },
+        );
+    }
+
+    let mut histogram: Vec<LengthBucket> = LENGTH_BUCKETS_CHARS
+        .iter()
+        .map(|&le| Some(le))
+        .chain(std::iter::once(None))
+        .map(|le_chars| LengthBucket { le_chars, count: 0 })
+        .collect();
+    let mut chunk_types: HashMap<String, u64> = HashMap::new();
+    let mut languages: HashMap<String, u64> = HashMap::new();
+    let mut total_chars = 0u64;
+    let mut chunk_count = 0u64;
+    let mut indexable_chunks = 0u64;
+
+    let mut stmt = conn.prepare(
+        "SELECT source_id, content, COALESCE(chunk_type, 'general') FROM chunks WHERE tenant_id = ?1",
+    )?;
+    let rows = stmt.query_map(params![tenant_id], |row| {
+        Ok((row.get::<_, i64>(0)?, read_content(row, 1)?, row.get::<_, String>(2)?))
+    })?;
+    for row in rows {
+        let (source_id, content, chunk_type) = row?;
+        let chars = content.chars().count() as u32;
+        chunk_count += 1;
+        total_chars += chars as u64;
+        if !tokenize_for_bm25(&content).is_empty() {
+            indexable_chunks += 1;
+        }
+        let bucket = LENGTH_BUCKETS_CHARS
+            .iter()
+            .position(|&le| chars <= le)
+            .unwrap_or(LENGTH_BUCKETS_CHARS.len());
+        histogram[bucket].count += 1;
+        *chunk_types.entry(chunk_type).or_insert(0) += 1;
+        *languages.entry(detect_language_code(&content).to_string()).or_insert(0) += 1;
+
+        if let Some(stats) = sources.get_mut(&source_id) {
+            stats.min_chunk_chars = if stats.chunk_count == 0 { chars } else { stats.min_chunk_chars.min(chars) };
+            stats.max_chunk_chars = stats.max_chunk_chars.max(chars);
+            // Sum for now; divided by chunk_count below.
+            stats.avg_chunk_chars += chars as f64;
+            stats.chunk_count += 1;
+        }
+    }
+    for stats in sources.values_mut() {
+        if stats.chunk_count > 0 {
+            stats.avg_chunk_chars /= stats.chunk_count as f64;
+        }
+    }
+
+    Ok(CorpusStats {
+        sources: sources.into_values().collect(),
+        total_chars,
+        chunk_count,
+        indexable_chunks,
+        histogram,
+        chunk_types: sorted_counts(chunk_types),
+        languages: sorted_counts(languages),
+        last_source_added_at,
+    })
+}
+
+/// Corpus statistics and index freshness for the active tenant.
+pub fn get_corpus_report() -> Result<CorpusReport, RagError> {
+    let conn = get_connection()?;
+    let stats = collect_corpus_stats(&conn, &active_tenant()).map_err(db_error)?;
+
+    let hnsw_point_count = hnsw_point_count().map(|n| n as u64);
+    let bm25_document_count = bm25_get_document_count() as u64;
+    let index = IndexFreshness {
+        db_chunk_count: stats.chunk_count,
+        hnsw_point_count,
+        bm25_document_count,
+        hnsw_up_to_date: hnsw_point_count == Some(stats.chunk_count),
+        bm25_up_to_date: bm25_document_count == stats.indexable_chunks,
+        last_source_added_at: stats.last_source_added_at,
+    };
+
+    Ok(CorpusReport {
+        source_count: stats.sources.len() as u64,
+        chunk_count: stats.chunk_count,
+        total_chars: stats.total_chars,
+        vocabulary_size: bm25_vocabulary_size() as u64,
+        sources: stats.sources,
+        chunk_length_histogram: stats.histogram,
+        chunk_types: stats.chunk_types,
+        languages: stats.languages,
+        index,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_corpus_stats() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, name TEXT, status TEXT, created_at INTEGER, tenant_id TEXT);
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER, content TEXT, chunk_type TEXT, tenant_id TEXT);
+             INSERT INTO sources VALUES (1, 'guide', 'completed', 100, 'default'), (2, 'empty', 'pending', 200, 'default'),
+                (3, 'other', 'completed', 300, 'work');
+             INSERT INTO chunks VALUES
+                (1, 1, 'Smart contracts run on chain.', 'text', 'default'),
+                (2, 1, '스마트 계약은 블록체인에서 실행됩니다', 'text', 'default'),
+                (3, 1, 'fn main() {}', 'code', 'default'),
+                (4, 3, 'Hidden tenant chunk', 'text', 'work');",
+        )
+        .unwrap();
+
+        let stats = collect_corpus_stats(&conn, "default").unwrap();
+        assert_eq!(stats.chunk_count, 3);
+        assert_eq!(stats.sources.len(), 2);
+        assert_eq!(stats.sources[0].chunk_count, 3);
+        assert_eq!(stats.sources[0].min_chunk_chars, 12);
+        assert_eq!(stats.sources[1].chunk_count, 0);
+        assert_eq!(stats.last_source_added_at, Some(200));
+        assert_eq!(stats.histogram[0].count, 3);
+        assert_eq!((stats.chunk_types[0].key.as_str(), stats.chunk_types[0].count), ("text", 2));
+        assert_eq!((stats.languages[0].key.as_str(), stats.languages[0].count), ("en", 2));
+    }
+}
@@ -26,12 +26,18 @@ use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
 use once_cell::sync::OnceCell;
 use std::sync::RwLock;
-use anyhow::Result;
+use std::time::{Duration, Instant};
 use log::info;
 
+use crate::api::error::{db_error, RagError};
+use crate::api::memory_budget::memory_profile;
+
 /// Global database connection pool (thread-safe)
 static DB_POOL: OnceCell<RwLock<Option<Pool<SqliteConnectionManager>>>> = OnceCell::new();
 
+/// Path and size the pool was last initialized with (for re-opening).
+static DB_CONFIG: RwLock<Option<(String, u32)>> = RwLock::new(None);
+
 /// Initialize the global connection pool with optimized SQLite settings.
 ///
 /// This should be called once during application startup, before any database operations.
@@ -42,28 +48,35 @@ static DB_POOL: OnceCell<RwLock<Option<Pool<SqliteConnectionManager>>>> = OnceCe
 ///
 /// # SQLite Optimizations
 /// - WAL mode: Better concurrency for read-heavy workloads
-/// - 64MB page cache: Reduces disk I/O
+/// - 64MB page cache: Reduces disk I/O (8MB in low-memory mode)
 /// - Memory temp storage: Faster temporary operations
-/// - 256MB mmap: Memory-mapped I/O for large databases
+/// - 256MB mmap: Memory-mapped I/O for large databases (off in low-memory mode)
 ///
 /// # Example
-/// ```rust
-/// init_db_pool("/path/to/rag.sqlite", 4)?;
+/// ```no_run
+/// # use rag_engine_flutter::api::db_pool::init_db_pool;
+/// init_db_pool("/path/to/rag.sqlite".to_string(), 4)?;
+/// # Ok::<(), rag_engine_flutter::api::error::RagError>(())
 /// ```
-pub fn init_db_pool(db_path: String, max_size: u32) -> Result<()> {
+pub fn init_db_pool(db_path: String, max_size: u32) -> Result<(), RagError> {
     info!("[db_pool] Initializing connection pool: path={}, max_size={}", db_path, max_size);
     
+    // Cache and mmap sizes follow the memory profile (see `set_memory_budget`).
+    let profile = memory_profile();
+    let pragmas = format!(
+        "PRAGMA journal_mode = WAL;
+         PRAGMA synchronous = NORMAL;
+         PRAGMA cache_size = -{};
+         PRAGMA temp_store = MEMORY;
+         PRAGMA mmap_size = {};
+         PRAGMA page_size = 4096;",
+        profile.sqlite_cache_kb,
+        profile.sqlite_mmap_mb as u64 * 1024 * 1024
+    );
     let manager = SqliteConnectionManager::file(&db_path)
-        .with_init(|conn| {
+        .with_init(move |conn| {
             // SQLite performance optimizations
-            conn.execute_batch(
-                "PRAGMA journal_mode = WAL;
-                 PRAGMA synchronous = NORMAL;
-                 PRAGMA cache_size = -64000;
-                 PRAGMA temp_store = MEMORY;
-                 PRAGMA mmap_size = 268435456;
-                 PRAGMA page_size = 4096;"
-            )?;
+            conn.execute_batch(&pragmas)?;
             Ok(())
         });
     
@@ -71,8 +84,11 @@ pub fn init_db_pool(db_path: String, max_size: u32) -> Result<()> {
         .max_size(max_size)
         .min_idle(Some(1))  // Keep at least 1 connection alive
         .connection_timeout(std::time::Duration::from_secs(5))
-        .build(manager)?;
+        .build(manager)
+        .map_err(db_error)?;
     
+    *DB_CONFIG.write().unwrap() = Some((db_path, max_size));
+
     // Support re-initialization
     if let Some(lock) = DB_POOL.get() {
         let mut guard = lock.write().unwrap();
@@ -108,27 +124,38 @@ pub fn init_db_pool(db_path: String, max_size: u32) -> Result<()> {
 ///
 /// # Errors
 /// Returns an error if:
-/// - The pool has not been initialized (call `init_db_pool` first)
-/// - No connections are available within the timeout period
+/// - The pool has not been initialized (call `init_db_pool` first):
+///   `RagError::NotInitialized`
+/// - No connections are available within the timeout period:
+///   `RagError::DatabaseError`
 ///
 /// # Example
-/// ```rust
+/// ```ignore
 /// let conn = get_connection()?;
 /// conn.execute("INSERT INTO ...", params![])?;
 /// // Connection automatically returned to pool when `conn` goes out of scope
 /// ```
-pub(crate) fn get_connection() -> Result<r2d2::PooledConnection<SqliteConnectionManager>> {
+pub(crate) fn get_connection() -> Result<r2d2::PooledConnection<SqliteConnectionManager>, RagError> {
     let pool_guard = DB_POOL
         .get()
-        .ok_or_else(|| anyhow::anyhow!("DB pool not initialized. Call init_db_pool() first."))?
+        .ok_or_else(pool_not_initialized)?
         .read()
         .unwrap();
     
     let pool = pool_guard
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("DB pool is None"))?;
+        .ok_or_else(pool_not_initialized)?;
     
-    Ok(pool.get()?)
+    pool.get().map_err(db_error)
+}
+
+pub(crate) fn pool_not_initialized() -> RagError {
+    RagError::NotInitialized("DB pool not initialized. Call init_db_pool() first.".to_string())
+}
+
+/// Database path and pool size from the last `init_db_pool` call.
+pub(crate) fn db_pool_config() -> Option<(String, u32)> {
+    DB_CONFIG.read().unwrap().clone()
 }
 
 /// Check if the connection pool is initialized.
@@ -165,6 +192,37 @@ pub fn close_db_pool() {
     }
 }
 
+/// Close the pool once every checked-out connection has been returned, so
+/// no connection keeps the database file open (e.g. before replacing it).
+/// New checkouts fail while waiting; on timeout the pool is put back and
+/// `DatabaseBusy` is returned.
+pub(crate) fn close_db_pool_when_idle(timeout: Duration) -> Result<(), RagError> {
+    let Some(pool_lock) = DB_POOL.get() else {
+        return Ok(());
+    };
+    let Some(pool) = pool_lock.write().unwrap().take() else {
+        return Ok(());
+    };
+    let deadline = Instant::now() + timeout;
+    loop {
+        let state = pool.state();
+        if state.idle_connections == state.connections {
+            break;
+        }
+        if Instant::now() >= deadline {
+            *pool_lock.write().unwrap() = Some(pool);
+            return Err(RagError::DatabaseBusy(format!(
+                "{} connections still in use",
+                state.connections - state.idle_connections
+            )));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    drop(pool);
+    info!("[db_pool] Connection pool closed");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,198 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Automatic retry for transient SQLite write failures.
+//!
+//! Concurrent search + ingest occasionally hits `SQLITE_BUSY`/`SQLITE_LOCKED`
+//! (or a transient `SQLITE_IOERR`) on mobile. Write paths run through
+//! `with_write_retry`, which re-runs the whole operation with bounded
+//! exponential backoff and only surfaces `RagError::DatabaseBusy` once every
+//! attempt has failed. Other errors are returned immediately.
+
+use std::thread;
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+use log::warn;
+use once_cell::sync::Lazy;
+use rusqlite::{Connection, ErrorCode};
+use std::sync::RwLock;
+
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::metrics::record_db_error;
+
+#[derive(Debug, Clone)]
+pub struct DbRetryConfig {
+    /// Total attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled on every further retry.
+    pub base_delay_ms: u64,
+    /// Upper bound for a single delay.
+    pub max_delay_ms: u64,
+}
+
+impl Default for DbRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay_ms: 20,
+            max_delay_ms: 500,
+        }
+    }
+}
+
+static RETRY_CONFIG: Lazy<RwLock<DbRetryConfig>> = Lazy::new(|| RwLock::new(DbRetryConfig::default()));
+
+/// Configure the retry policy for database writes.
+#[frb(sync)]
+pub fn set_db_retry_config(config: DbRetryConfig) {
+    *RETRY_CONFIG.write().unwrap() = DbRetryConfig {
+        max_attempts: config.max_attempts.max(1),
+        ..config
+    };
+}
+
+/// Current retry policy for database writes.
+#[frb(sync)]
+pub fn get_db_retry_config() -> DbRetryConfig {
+    RETRY_CONFIG.read().unwrap().clone()
+}
+
+/// Error from one write attempt: SQLite errors are classified for retry,
+/// everything else is returned as-is.
+pub(crate) enum WriteError {
+    Sqlite(rusqlite::Error),
+    Rag(RagError),
+}
+
+impl From<rusqlite::Error> for WriteError {
+    fn from(e: rusqlite::Error) -> Self {
+        WriteError::Sqlite(e)
+    }
+}
+
+impl From<RagError> for WriteError {
+    fn from(e: RagError) -> Self {
+        WriteError::Rag(e)
+    }
+}
+
+/// `SQLITE_BUSY`, `SQLITE_LOCKED` and `SQLITE_IOERR` are worth retrying.
+pub(crate) fn is_transient(e: &rusqlite::Error) -> bool {
+    matches!(
+        e.sqlite_error_code(),
+        Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked | ErrorCode::SystemIoFailure)
+    )
+}
+
+fn backoff_delay(config: &DbRetryConfig, retry: u32) -> Duration {
+    let factor = 1u64 << retry.min(16);
+    Duration::from_millis(config.base_delay_ms.saturating_mul(factor).min(config.max_delay_ms))
+}
+
+fn retry_transient<T>(
+    config: &DbRetryConfig,
+    op: &str,
+    mut attempt: impl FnMut() -> Result<T, WriteError>,
+) -> Result<T, RagError> {
+    let mut tries = 0;
+    loop {
+        tries += 1;
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(WriteError::Rag(e)) => return Err(e),
+            Err(WriteError::Sqlite(e)) if !is_transient(&e) => return Err(db_error(e)),
+            Err(WriteError::Sqlite(e)) if tries >= config.max_attempts => {
+                record_db_error();
+                return Err(RagError::DatabaseBusy(format!(
+                    "{} failed after {} attempts: {}",
+                    op, tries, e
+                )));
+            }
+            Err(WriteError::Sqlite(e)) => {
+                let delay = backoff_delay(config, tries - 1);
+                warn!("[db_retry] {} attempt {} failed ({}), retrying in {:?}", op, tries, e, delay);
+                thread::sleep(delay);
+            }
+        }
+    }
+}
+
+/// Run a write against a pooled connection, retrying transient failures.
+///
+/// `write` may run several times, so it must be safe to repeat (wrap
+/// multi-statement writes in a transaction).
+pub(crate) fn with_write_retry<T>(
+    op: &str,
+    mut write: impl FnMut(&mut Connection) -> Result<T, WriteError>,
+) -> Result<T, RagError> {
+    let config = get_db_retry_config();
+    retry_transient(&config, op, || {
+        let mut conn = get_connection()?;
+        write(&mut conn)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn busy() -> rusqlite::Error {
+        rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY), None)
+    }
+
+    fn no_delay(max_attempts: u32) -> DbRetryConfig {
+        DbRetryConfig { max_attempts, base_delay_ms: 0, max_delay_ms: 0 }
+    }
+
+    #[test]
+    fn test_retries_transient_until_success() {
+        let mut calls = 0;
+        let result = retry_transient(&no_delay(5), "insert", || {
+            calls += 1;
+            if calls < 3 { Err(busy().into()) } else { Ok(calls) }
+        });
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_exhaustion_and_non_transient() {
+        let mut calls = 0;
+        let result: Result<(), _> = retry_transient(&no_delay(3), "insert", || {
+            calls += 1;
+            Err(busy().into())
+        });
+        assert!(matches!(result, Err(RagError::DatabaseBusy(_))));
+        assert_eq!(calls, 3);
+
+        calls = 0;
+        let result: Result<(), _> = retry_transient(&no_delay(3), "insert", || {
+            calls += 1;
+            Err(rusqlite::Error::QueryReturnedNoRows.into())
+        });
+        assert!(matches!(result, Err(RagError::DatabaseError(_))));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_backoff_is_bounded() {
+        let config = DbRetryConfig { max_attempts: 10, base_delay_ms: 20, max_delay_ms: 100 };
+        assert_eq!(backoff_delay(&config, 0), Duration::from_millis(20));
+        assert_eq!(backoff_delay(&config, 2), Duration::from_millis(80));
+        assert_eq!(backoff_delay(&config, 9), Duration::from_millis(100));
+    }
+}
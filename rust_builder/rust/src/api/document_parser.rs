@@ -3,9 +3,10 @@
 //
 // Document-to-Text (DTT) module for PDF and DOCX text extraction
 
-use anyhow::{anyhow, Result};
 use regex::Regex;
 
+use crate::api::error::RagError;
+
 /// Remove page number from the end of a page text (if present)
 /// Only removes if the last non-empty line is purely numeric
 fn remove_trailing_page_number(page_text: &str) -> String {
@@ -103,29 +104,29 @@ fn join_pages(pages: Vec<String>) -> String {
 
 /// Extract text content from a PDF file (bytes)
 /// Uses page-by-page extraction for safe page number removal and hyphenation handling
-pub fn extract_text_from_pdf(file_bytes: Vec<u8>) -> Result<String> {
+pub fn extract_text_from_pdf(file_bytes: Vec<u8>) -> Result<String, RagError> {
     let pages = pdf_extract::extract_text_from_mem_by_pages(&file_bytes)
-        .map_err(|e| anyhow!("PDF extraction failed: {:?}", e))?;
+        .map_err(|e| RagError::ParseError(format!("PDF extraction failed: {:?}", e)))?;
     Ok(join_pages(pages))
 }
 
 /// Extract text content from a DOCX file (bytes)
-pub fn extract_text_from_docx(file_bytes: Vec<u8>) -> Result<String> {
+pub fn extract_text_from_docx(file_bytes: Vec<u8>) -> Result<String, RagError> {
     docx_lite::extract_text_from_bytes(&file_bytes)
-        .map_err(|e| anyhow!("DOCX extraction failed: {}", e))
+        .map_err(|e| RagError::ParseError(format!("DOCX extraction failed: {}", e)))
 }
 
 /// Auto-detect document type and extract text
 /// Uses magic bytes to determine file format
-pub fn extract_text_from_document(file_bytes: Vec<u8>) -> Result<String> {
+pub fn extract_text_from_document(file_bytes: Vec<u8>) -> Result<String, RagError> {
     const MAX_FILE_SIZE: usize = 50 * 1024 * 1024; // 50MB
     
     if file_bytes.len() > MAX_FILE_SIZE {
-        return Err(anyhow!("File too large ({} bytes). Maximum supported size is 50MB.", file_bytes.len()));
+        return Err(RagError::InvalidInput(format!("File too large ({} bytes). Maximum supported size is 50MB.", file_bytes.len())));
     }
 
     if file_bytes.len() < 4 {
-        return Err(anyhow!("File too small to determine format"));
+        return Err(RagError::InvalidInput("File too small to determine format".to_string()));
     }
     
     // PDF magic bytes: %PDF
@@ -138,7 +139,7 @@ pub fn extract_text_from_document(file_bytes: Vec<u8>) -> Result<String> {
         return extract_text_from_docx(file_bytes);
     }
     
-    Err(anyhow!("Unsupported document format. Expected PDF or DOCX."))
+    Err(RagError::InvalidInput("Unsupported document format. Expected PDF or DOCX.".to_string()))
 }
 
 /// EXPERIMENTAL: Smart CJK dehyphenation
@@ -0,0 +1,287 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! On-disk encoding of chunk embeddings.
+//!
+//! Embeddings are stored either as raw native-endian `f32` (the original
+//! format) or, with `set_quantized_embedding_storage(true)`, as int8 with
+//! a per-vector scale, which cuts the `chunks.embedding` column to about a
+//! quarter. Both formats can coexist: a quantized blob is
+//! `[tag][dimension u32][scale f32][i8 × dimension][zero padding]`, padded
+//! so its length is 1 mod 4, while an `f32` blob is always a multiple of 4.
+//! Readers go through `decode_embedding` or `blob_distance`; the latter
+//! scores a blob without materializing an `f32` vector, so exact scans
+//! over quantized rows dequantize on the fly.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::info;
+use rusqlite::params;
+
+use crate::api::db_pool::get_connection;
+use crate::api::db_retry::with_write_retry;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::DistanceMetric;
+use crate::api::tenant::active_tenant;
+
+const QUANTIZED_TAG: u8 = b'Q';
+/// Tag, dimension and scale.
+const HEADER_LEN: usize = 9;
+/// Rows converted per transaction by `quantize_stored_embeddings`.
+const CONVERT_BATCH: u32 = 500;
+
+static QUANTIZED_STORAGE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddingStorageStats {
+    pub f32_chunks: i64,
+    pub quantized_chunks: i64,
+    /// Total size of the active tenant's embedding column.
+    pub embedding_bytes: i64,
+}
+
+/// Store newly written embeddings as int8 (true) or `f32` (false).
+/// Existing rows keep their format; see `quantize_stored_embeddings`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_quantized_embedding_storage(enabled: bool) {
+    info!("[embedding_storage] Quantized storage: {}", enabled);
+    QUANTIZED_STORAGE.store(enabled, Ordering::Release);
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_quantized_embedding_storage() -> bool {
+    QUANTIZED_STORAGE.load(Ordering::Acquire)
+}
+
+fn is_quantized(blob: &[u8]) -> bool {
+    blob.len() >= HEADER_LEN && blob.len() % 4 == 1 && blob[0] == QUANTIZED_TAG
+}
+
+/// Encode for storage in the current format.
+pub(crate) fn encode_embedding(values: &[f32]) -> Vec<u8> {
+    if is_quantized_embedding_storage() {
+        encode_quantized(values)
+    } else {
+        encode_f32(values)
+    }
+}
+
+fn encode_f32(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes()).collect()
+}
+
+/// `(scale, values)` with `values[i] * scale ≈ embedding[i]`, the largest
+/// component mapping to ±127. Shared by the stored format, the int8 HNSW
+/// graphs and the low-memory quantized index.
+pub(crate) fn quantize_values(embedding: &[f32]) -> (f32, Vec<i8>) {
+    let max_abs = embedding.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    let scale = if max_abs > 0.0 { max_abs / 127.0 } else { 0.0 };
+    let values = embedding
+        .iter()
+        .map(|v| if scale > 0.0 { (v / scale).round().clamp(-127.0, 127.0) as i8 } else { 0 })
+        .collect();
+    (scale, values)
+}
+
+fn encode_quantized(values: &[f32]) -> Vec<u8> {
+    let (scale, quantized) = quantize_values(values);
+    let padded = values.len().div_ceil(4) * 4;
+    let mut blob = Vec::with_capacity(HEADER_LEN + padded);
+    blob.push(QUANTIZED_TAG);
+    blob.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    blob.extend_from_slice(&scale.to_le_bytes());
+    blob.extend(quantized.iter().map(|q| *q as u8));
+    blob.resize(HEADER_LEN + padded, 0);
+    blob
+}
+
+/// `(scale, int8 values)` of a quantized blob.
+fn quantized_parts(blob: &[u8]) -> (f32, &[u8]) {
+    let dim = u32::from_le_bytes(blob[1..5].try_into().unwrap()) as usize;
+    let scale = f32::from_le_bytes(blob[5..9].try_into().unwrap());
+    let end = (HEADER_LEN + dim).min(blob.len());
+    (scale, &blob[HEADER_LEN..end])
+}
+
+/// Decode a stored embedding of either format.
+pub(crate) fn decode_embedding(blob: &[u8]) -> Vec<f32> {
+    let mut values = Vec::new();
+    decode_embedding_into(blob, &mut values);
+    values
+}
+
+/// `decode_embedding` into a reused buffer, for scans.
+pub(crate) fn decode_embedding_into(blob: &[u8], out: &mut Vec<f32>) {
+    out.clear();
+    if is_quantized(blob) {
+        let (scale, values) = quantized_parts(blob);
+        out.extend(values.iter().map(|q| *q as i8 as f32 * scale));
+    } else {
+        out.extend(blob.chunks_exact(4).map(|c| f32::from_ne_bytes(c.try_into().unwrap())));
+    }
+}
+
+/// Dimension of a stored embedding from its length and first
+/// `HEADER_LEN` bytes; `None` for an empty or malformed blob.
+pub(crate) fn stored_dimension(len: usize, prefix: &[u8]) -> Option<usize> {
+    if len >= HEADER_LEN && len % 4 == 1 && prefix.first() == Some(&QUANTIZED_TAG) && prefix.len() >= 5 {
+        let dim = u32::from_le_bytes(prefix[1..5].try_into().unwrap()) as usize;
+        return (dim > 0 && HEADER_LEN + dim <= len).then_some(dim);
+    }
+    (len > 0 && len.is_multiple_of(4)).then_some(len / 4)
+}
+
+/// Distance under `metric` between `query` (with precomputed `query_norm`)
+/// and a stored blob, without allocating. `None` when the dimensions
+/// differ.
+pub(crate) fn blob_distance(
+    query: &[f32],
+    query_norm: f32,
+    blob: &[u8],
+    metric: DistanceMetric,
+) -> Option<f32> {
+    let (dot, norm_sq) = if is_quantized(blob) {
+        let (scale, values) = quantized_parts(blob);
+        if values.len() != query.len() {
+            return None;
+        }
+        let (mut dot, mut norm_sq) = (0.0f32, 0.0f32);
+        for (q, v) in query.iter().zip(values) {
+            let v = *v as i8 as f32;
+            dot += q * v;
+            norm_sq += v * v;
+        }
+        (dot * scale, norm_sq * scale * scale)
+    } else {
+        if blob.len() != query.len() * 4 {
+            return None;
+        }
+        let (mut dot, mut norm_sq) = (0.0f32, 0.0f32);
+        for (q, c) in query.iter().zip(blob.chunks_exact(4)) {
+            let v = f32::from_ne_bytes(c.try_into().unwrap());
+            dot += q * v;
+            norm_sq += v * v;
+        }
+        (dot, norm_sq)
+    };
+    Some(metric.distance(dot, query_norm, norm_sq.sqrt()))
+}
+
+/// Convert the active tenant's `f32` embeddings to the quantized format,
+/// `CONVERT_BATCH` rows per transaction. Returns the number converted.
+/// The HNSW index is unaffected until its next rebuild.
+pub fn quantize_stored_embeddings() -> Result<u32, RagError> {
+    let tenant_id = active_tenant();
+    let mut converted = 0u32;
+    let mut after_id = 0i64;
+    loop {
+        let batch: Vec<(i64, Vec<u8>)> = {
+            let conn = get_connection()?;
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, embedding FROM chunks
+                     WHERE tenant_id = ?1 AND id > ?2 AND length(embedding) % 4 = 0
+                     ORDER BY id LIMIT ?3",
+                )
+                .map_err(db_error)?;
+            let rows = stmt
+                .query_map(params![tenant_id, after_id, CONVERT_BATCH], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(db_error)?
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(db_error)?;
+            rows
+        };
+        let Some(last_id) = batch.last().map(|(id, _)| *id) else {
+            break;
+        };
+        let encoded: Vec<(i64, Vec<u8>)> =
+            batch.iter().map(|(id, blob)| (*id, encode_quantized(&decode_embedding(blob)))).collect();
+        with_write_retry("quantize_stored_embeddings", |conn| {
+            let tx = conn.transaction()?;
+            {
+                let mut update = tx.prepare("UPDATE chunks SET embedding = ?1 WHERE id = ?2 AND tenant_id = ?3")?;
+                for (id, blob) in &encoded {
+                    update.execute(params![blob, id, tenant_id])?;
+                }
+            }
+            tx.commit()?;
+            Ok(())
+        })?;
+        converted += encoded.len() as u32;
+        after_id = last_id;
+    }
+    info!("[embedding_storage] Quantized {} stored embeddings", converted);
+    Ok(converted)
+}
+
+pub fn get_embedding_storage_stats() -> Result<EmbeddingStorageStats, RagError> {
+    let conn = get_connection()?;
+    conn.query_row(
+        "SELECT COALESCE(SUM(length(embedding) % 4 = 0), 0), COALESCE(SUM(length(embedding) % 4 = 1), 0),
+                COALESCE(SUM(length(embedding)), 0)
+         FROM chunks WHERE tenant_id = ?1",
+        params![active_tenant()],
+        |row| {
+            Ok(EmbeddingStorageStats {
+                f32_chunks: row.get(0)?,
+                quantized_chunks: row.get(1)?,
+                embedding_bytes: row.get(2)?,
+            })
+        },
+    )
+    .map_err(db_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantized_roundtrip_and_format_detection() {
+        let values = vec![0.5, -1.0, 0.25, 0.0, 0.75];
+        let blob = encode_quantized(&values);
+        assert_eq!(blob.len() % 4, 1);
+        assert_eq!(blob.len(), HEADER_LEN + 8);
+        assert_eq!(stored_dimension(blob.len(), &blob), Some(5));
+        let decoded = decode_embedding(&blob);
+        assert_eq!(decoded.len(), 5);
+        for (a, b) in values.iter().zip(&decoded) {
+            assert!((a - b).abs() < 0.01, "{} vs {}", a, b);
+        }
+
+        let raw = encode_f32(&values);
+        assert_eq!(stored_dimension(raw.len(), &raw), Some(5));
+        assert_eq!(decode_embedding(&raw), values);
+        assert_eq!(stored_dimension(0, &[]), None);
+    }
+
+    #[test]
+    fn test_blob_distance_matches_across_formats() {
+        let query = [1.0f32, 0.5, -0.25];
+        let norm = query.iter().map(|v| v * v).sum::<f32>().sqrt();
+        let target = [0.8f32, 0.4, -0.1];
+        for metric in [DistanceMetric::Cosine, DistanceMetric::DotProduct, DistanceMetric::L2] {
+            let exact = blob_distance(&query, norm, &encode_f32(&target), metric).unwrap();
+            let quantized = blob_distance(&query, norm, &encode_quantized(&target), metric).unwrap();
+            assert!((exact - quantized).abs() < 0.01, "{:?}", metric);
+        }
+        let l2 = blob_distance(&query, norm, &encode_f32(&target), DistanceMetric::L2).unwrap();
+        assert!((l2 - (0.04f32 + 0.01 + 0.0225).sqrt()).abs() < 1e-5);
+        let cosine = DistanceMetric::Cosine;
+        assert!(blob_distance(&query, norm, &encode_quantized(&[1.0, 0.0]), cosine).is_none());
+        assert_eq!(blob_distance(&query, norm, &encode_quantized(&[0.0, 0.0, 0.0]), cosine), Some(1.0));
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Engine version and capability reporting.
+//!
+//! `get_engine_info()` gathers versions, loaded models and enabled features
+//! in one call, for feature gating in the app and for bug reports.
+
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use rusqlite::params;
+
+use crate::api::bm25_search::BM25_FORMAT_VERSION;
+use crate::api::content_crypto::is_content_encryption_enabled;
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::{is_quantized_embedding_storage, stored_dimension};
+use crate::api::hnsw_index::{get_hnsw_seeded_build, get_index_config, Quantization, HNSW_FORMAT_VERSION};
+use crate::api::memory_budget::memory_profile;
+use crate::api::search_analytics::is_search_analytics_enabled;
+use crate::api::search_trace::is_search_tracing_enabled;
+use crate::api::snapshot::SNAPSHOT_FORMAT_VERSION;
+use crate::api::source_index::get_source_index_config;
+use crate::api::source_rag::SCHEMA_VERSION;
+use crate::api::tenant::active_tenant;
+use crate::api::tokenizer::loaded_tokenizer_name;
+
+/// Embedding model name registered by the app (inference runs in Dart).
+static EMBEDDING_MODEL_NAME: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+#[derive(Debug, Clone)]
+pub struct EngineInfo {
+    /// Version of this crate (`CARGO_PKG_VERSION`).
+    pub crate_version: String,
+    /// Table layout this engine creates.
+    pub schema_version: u32,
+    /// `user_version` of the open database; `None` before `init_db_pool`.
+    pub database_schema_version: Option<u32>,
+    pub hnsw_format_version: u32,
+    pub bm25_format_version: u32,
+    pub snapshot_format_version: u32,
+    /// `<dir>/<file>` of the tokenizer passed to `init_tokenizer`.
+    pub tokenizer_name: Option<String>,
+    /// Set with `set_embedding_model_name`.
+    pub embedding_model_name: Option<String>,
+    /// Dimension of the active tenant's stored embeddings; `None` when it
+    /// has no chunks or the database is not open.
+    pub embedding_dimension: Option<u32>,
+    pub active_tenant: String,
+    /// Runtime features currently on: `encryption`, `quantized_index`,
+    /// `quantized_embeddings`, `low_memory`, `source_indexes`,
+    /// `seeded_hnsw`, `search_analytics`, `search_tracing`.
+    pub active_features: Vec<String>,
+    /// Cargo features this library was compiled with.
+    pub build_features: Vec<String>,
+}
+
+/// Register the name of the embedding model the app runs, so it shows up
+/// in `get_engine_info()` (`None` clears it).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_embedding_model_name(name: Option<String>) {
+    *EMBEDDING_MODEL_NAME.write().unwrap() = name;
+}
+
+fn active_features() -> Vec<String> {
+    let profile = memory_profile();
+    [
+        ("encryption", is_content_encryption_enabled()),
+        ("quantized_index", profile.quantize_index),
+        ("quantized_embeddings", is_quantized_embedding_storage()),
+        ("int8_hnsw", get_index_config().quantization == Quantization::Int8),
+        ("low_memory", profile.low_memory),
+        ("source_indexes", get_source_index_config().enabled),
+        ("seeded_hnsw", get_hnsw_seeded_build().is_some()),
+        ("search_analytics", is_search_analytics_enabled()),
+        ("search_tracing", is_search_tracing_enabled()),
+    ]
+    .into_iter()
+    .filter(|(_, on)| *on)
+    .map(|(name, _)| name.to_string())
+    .collect()
+}
+
+fn build_features() -> Vec<String> {
+    let mut features = Vec::new();
+    if cfg!(feature = "dev-server") {
+        features.push("dev-server".to_string());
+    }
+    if cfg!(feature = "test_support") {
+        features.push("test_support".to_string());
+    }
+    features
+}
+
+/// `(user_version, embedding dimension)` from the open database.
+fn database_info(tenant_id: &str) -> (Option<u32>, Option<u32>) {
+    let Ok(conn) = get_connection() else {
+        return (None, None);
+    };
+    let user_version = conn.pragma_query_value(None, "user_version", |row| row.get(0)).ok();
+    let dimension = conn
+        .query_row(
+            "SELECT length(embedding), substr(embedding, 1, 9) FROM chunks WHERE tenant_id = ?1 LIMIT 1",
+            params![tenant_id],
+            |row| Ok((row.get::<_, Option<i64>>(0)?, row.get::<_, Option<Vec<u8>>>(1)?)),
+        )
+        .ok()
+        .and_then(|(len, prefix)| stored_dimension(len.unwrap_or(0) as usize, &prefix.unwrap_or_default()))
+        .map(|dim| dim as u32);
+    (user_version, dimension)
+}
+
+/// Versions, loaded tokenizer/model and enabled features of this engine.
+pub fn get_engine_info() -> EngineInfo {
+    let tenant_id = active_tenant();
+    let (database_schema_version, embedding_dimension) = database_info(&tenant_id);
+    EngineInfo {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: SCHEMA_VERSION,
+        database_schema_version,
+        hnsw_format_version: HNSW_FORMAT_VERSION,
+        bm25_format_version: BM25_FORMAT_VERSION,
+        snapshot_format_version: SNAPSHOT_FORMAT_VERSION,
+        tokenizer_name: loaded_tokenizer_name(),
+        embedding_model_name: EMBEDDING_MODEL_NAME.read().unwrap().clone(),
+        embedding_dimension,
+        active_tenant: tenant_id,
+        active_features: active_features(),
+        build_features: build_features(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_info_reports_versions() {
+        let info = get_engine_info();
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.schema_version, SCHEMA_VERSION);
+        assert!(info.active_features.iter().all(|f| !f.is_empty()));
+    }
+}
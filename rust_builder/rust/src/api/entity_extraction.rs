@@ -0,0 +1,328 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Rule-based entity extraction for faceted filtering.
+//!
+//! Chunks are annotated at ingestion (`add_chunks`) with dates, amounts,
+//! emails, URLs, capitalized phrases and user-provided gazetteer terms.
+//! Entities live in `chunk_entities`, keyed by a normalized value, so
+//! `SearchFilter { entity: Some("2023-09"), .. }` keeps only chunks that
+//! mention September 2023 (any day).
+
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rusqlite::{params, Connection};
+use log::info;
+
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::tenant::active_tenant;
+
+pub const ENTITY_DATE: &str = "date";
+pub const ENTITY_AMOUNT: &str = "amount";
+pub const ENTITY_EMAIL: &str = "email";
+pub const ENTITY_URL: &str = "url";
+pub const ENTITY_PHRASE: &str = "phrase";
+
+static URL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"https?://[^\s<>()\[\]"']+"#).unwrap());
+static EMAIL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").unwrap());
+static ISO_DATE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(\d{4})[-/.](\d{1,2})(?:[-/.](\d{1,2}))?\b").unwrap());
+static KO_DATE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{4})년\s*(\d{1,2})월(?:\s*(\d{1,2})일)?").unwrap());
+static EN_DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?\s+(?:(\d{1,2})(?:st|nd|rd|th)?,?\s+)?(\d{4})\b").unwrap()
+});
+static AMOUNT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)[$€£₩¥]\s?\d[\d,]*(?:\.\d+)?(?:\s?(?:k|m|bn|b|thousand|million|billion)\b)?|\b\d[\d,]*(?:\.\d+)?\s?(?:usd|eur|krw|jpy|만\s?원|억\s?원|원|달러|억|만)").unwrap()
+});
+static PHRASE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[A-Z][A-Za-z0-9&]+(?:\s+(?:of\s+|and\s+)?[A-Z][A-Za-z0-9&]+)+\b").unwrap());
+
+/// Leading words dropped from capitalized phrases ("The Federal Reserve").
+const PHRASE_LEADING_STOPWORDS: &[&str] = &["The", "A", "An", "This", "That", "In", "On", "At", "For"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedEntity {
+    /// "date", "amount", "email", "url", "phrase" or a gazetteer type.
+    pub entity_type: String,
+    /// Text as it appears in the chunk.
+    pub text: String,
+    /// Canonical value used for filtering ("2023-09-15", "$1,000", lowercase emails/terms).
+    pub normalized: String,
+}
+
+/// A user-provided term to tag, e.g. `{ term: "Ethereum", entity_type: "chain" }`.
+#[derive(Debug, Clone)]
+pub struct GazetteerEntry {
+    pub term: String,
+    pub entity_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityFacet {
+    pub entity_type: String,
+    pub normalized: String,
+    pub chunk_count: u32,
+}
+
+/// Gazetteer entries with their precompiled case-insensitive patterns.
+static GAZETTEER: Lazy<RwLock<Vec<(Regex, GazetteerEntry)>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Replace the gazetteer used for new chunks.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_entity_gazetteer(entries: Vec<GazetteerEntry>) {
+    let compiled: Vec<(Regex, GazetteerEntry)> = entries
+        .into_iter()
+        .filter(|e| !e.term.trim().is_empty())
+        .filter_map(|e| {
+            // Word boundaries only make sense next to word characters.
+            let term = e.term.trim();
+            let left = if term.starts_with(|c: char| c.is_ascii_alphanumeric()) { r"\b" } else { "" };
+            let right = if term.ends_with(|c: char| c.is_ascii_alphanumeric()) { r"\b" } else { "" };
+            let pattern = Regex::new(&format!("(?i){}{}{}", left, regex::escape(term), right)).ok()?;
+            Some((pattern, e))
+        })
+        .collect();
+    info!("[entity] Gazetteer set with {} entries", compiled.len());
+    *GAZETTEER.write().unwrap() = compiled;
+}
+
+fn month_number(name: &str) -> u32 {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    MONTHS.iter().position(|m| name.to_lowercase().starts_with(m)).map_or(0, |i| i as u32 + 1)
+}
+
+fn normalize_date(year: &str, month: u32, day: Option<u32>) -> Option<String> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    match day {
+        Some(d) if (1..=31).contains(&d) => Some(format!("{}-{:02}-{:02}", year, month, d)),
+        Some(_) => None,
+        None => Some(format!("{}-{:02}", year, month)),
+    }
+}
+
+/// Collects entities, skipping matches that overlap an earlier (higher-priority) one.
+struct Collector {
+    claimed: Vec<(usize, usize)>,
+    entities: Vec<ExtractedEntity>,
+}
+
+impl Collector {
+    fn push(&mut self, start: usize, end: usize, entity_type: &str, text: &str, normalized: String) {
+        if self.claimed.iter().any(|&(s, e)| start < e && s < end) {
+            return;
+        }
+        self.claimed.push((start, end));
+        if !self.entities.iter().any(|e| e.entity_type == entity_type && e.normalized == normalized) {
+            self.entities.push(ExtractedEntity { entity_type: entity_type.to_string(), text: text.to_string(), normalized });
+        }
+    }
+}
+
+/// Extract entities from text (the same rules used at ingestion).
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_entities(text: String) -> Vec<ExtractedEntity> {
+    let mut out = Collector { claimed: Vec::new(), entities: Vec::new() };
+
+    for m in URL_RE.find_iter(&text) {
+        let url = m.as_str().trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        out.push(m.start(), m.start() + url.len(), ENTITY_URL, url, url.to_string());
+    }
+    for m in EMAIL_RE.find_iter(&text) {
+        out.push(m.start(), m.end(), ENTITY_EMAIL, m.as_str(), m.as_str().to_lowercase());
+    }
+    for caps in ISO_DATE_RE.captures_iter(&text).chain(KO_DATE_RE.captures_iter(&text)) {
+        let m = caps.get(0).unwrap();
+        let month = caps[2].parse().unwrap_or(0);
+        let day = caps.get(3).and_then(|d| d.as_str().parse().ok());
+        if let Some(normalized) = normalize_date(&caps[1], month, day) {
+            out.push(m.start(), m.end(), ENTITY_DATE, m.as_str(), normalized);
+        }
+    }
+    for caps in EN_DATE_RE.captures_iter(&text) {
+        let m = caps.get(0).unwrap();
+        let day = caps.get(2).and_then(|d| d.as_str().parse().ok());
+        if let Some(normalized) = normalize_date(&caps[3], month_number(&caps[1]), day) {
+            out.push(m.start(), m.end(), ENTITY_DATE, m.as_str(), normalized);
+        }
+    }
+    for m in AMOUNT_RE.find_iter(&text) {
+        let normalized: String = m.as_str().split_whitespace().collect::<String>().to_lowercase();
+        out.push(m.start(), m.end(), ENTITY_AMOUNT, m.as_str(), normalized);
+    }
+
+    for (pattern, entry) in GAZETTEER.read().unwrap().iter() {
+        if let Some(m) = pattern.find(&text) {
+            out.push(m.start(), m.end(), &entry.entity_type, m.as_str(), entry.term.to_lowercase());
+        }
+    }
+
+    for m in PHRASE_RE.find_iter(&text) {
+        let mut phrase = m.as_str();
+        let mut start = m.start();
+        for stop in PHRASE_LEADING_STOPWORDS {
+            if let Some(rest) = phrase.strip_prefix(stop).and_then(|r| r.strip_prefix(' ')) {
+                start += phrase.len() - rest.len();
+                phrase = rest;
+                break;
+            }
+        }
+        if phrase.contains(' ') {
+            out.push(start, start + phrase.len(), ENTITY_PHRASE, phrase, phrase.to_lowercase());
+        }
+    }
+
+    out.entities
+}
+
+/// Create the `chunk_entities` table (called from `init_source_db`).
+pub(crate) fn create_entity_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS chunk_entities (
+            id INTEGER PRIMARY KEY,
+            chunk_id INTEGER NOT NULL,
+            entity_type TEXT NOT NULL,
+            text TEXT NOT NULL,
+            normalized TEXT NOT NULL,
+            FOREIGN KEY (chunk_id) REFERENCES chunks(id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunk_entities_normalized ON chunk_entities(normalized)", [])?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunk_entities_chunk ON chunk_entities(chunk_id)", [])?;
+    Ok(())
+}
+
+/// Extract and store the entities of one chunk (runs inside `add_chunks`' transaction).
+pub(crate) fn store_chunk_entities(conn: &Connection, chunk_id: i64, content: &str) -> rusqlite::Result<usize> {
+    let entities = extract_entities(content.to_string());
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO chunk_entities (chunk_id, entity_type, text, normalized) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for e in &entities {
+        stmt.execute(params![chunk_id, e.entity_type, e.text, e.normalized])?;
+    }
+    Ok(entities.len())
+}
+
+/// SQL condition on `c.id` keeping chunks that mention `value`. A value
+/// also matches more specific ones below it: "2023-09" matches "2023-09-15".
+pub(crate) fn entity_filter_condition(value: &str) -> String {
+    let value = value.trim().to_lowercase().replace('\'', "''");
+    let like = value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!(
+        "c.id IN (SELECT chunk_id FROM chunk_entities WHERE normalized = '{}' OR normalized LIKE '{}-%' ESCAPE '\\')",
+        value, like
+    )
+}
+
+/// Entities stored for a chunk.
+pub fn get_chunk_entities(chunk_id: i64) -> Result<Vec<ExtractedEntity>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT e.entity_type, e.text, e.normalized FROM chunk_entities e
+             JOIN chunks c ON c.id = e.chunk_id
+             WHERE e.chunk_id = ?1 AND c.tenant_id = ?2 ORDER BY e.id",
+        )
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map(params![chunk_id, active_tenant()], |row| {
+            Ok(ExtractedEntity { entity_type: row.get(0)?, text: row.get(1)?, normalized: row.get(2)? })
+        })
+        .map_err(db_error)?;
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(db_error)
+}
+
+/// Most frequent entity values (optionally of one type) for facet UIs.
+pub fn list_entity_facets(entity_type: Option<String>, limit: u32) -> Result<Vec<EntityFacet>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT e.entity_type, e.normalized, COUNT(DISTINCT e.chunk_id) AS n FROM chunk_entities e
+             JOIN chunks c ON c.id = e.chunk_id
+             WHERE (?1 IS NULL OR e.entity_type = ?1) AND c.tenant_id = ?3
+             GROUP BY e.entity_type, e.normalized
+             ORDER BY n DESC, e.normalized
+             LIMIT ?2",
+        )
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map(params![entity_type, limit, active_tenant()], |row| {
+            Ok(EntityFacet { entity_type: row.get(0)?, normalized: row.get(1)?, chunk_count: row.get(2)? })
+        })
+        .map_err(db_error)?;
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(db_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized_of(entities: &[ExtractedEntity], entity_type: &str) -> Vec<String> {
+        entities.iter().filter(|e| e.entity_type == entity_type).map(|e| e.normalized.clone()).collect()
+    }
+
+    #[test]
+    fn test_extract_entities_by_type() {
+        let text = "On Sept 15, 2023 the Federal Reserve paid $1,200 to ops@Example.com (see https://example.com/report.) \
+                    2024년 3월 공시 금액은 5억 원입니다.";
+        let entities = extract_entities(text.to_string());
+        assert_eq!(normalized_of(&entities, ENTITY_DATE), vec!["2024-03", "2023-09-15"]);
+        assert_eq!(normalized_of(&entities, ENTITY_AMOUNT), vec!["$1,200", "5억원"]);
+        assert_eq!(normalized_of(&entities, ENTITY_EMAIL), vec!["ops@example.com"]);
+        assert_eq!(normalized_of(&entities, ENTITY_URL), vec!["https://example.com/report"]);
+        assert_eq!(normalized_of(&entities, ENTITY_PHRASE), vec!["federal reserve"]);
+    }
+
+    /// Restores the gazetteer it was created with when dropped, even if
+    /// the test fails.
+    struct RestoreGazetteer(Vec<(Regex, GazetteerEntry)>);
+
+    impl Drop for RestoreGazetteer {
+        fn drop(&mut self) {
+            *GAZETTEER.write().unwrap() = std::mem::take(&mut self.0);
+        }
+    }
+
+    #[test]
+    fn test_gazetteer_terms() {
+        let _restore = RestoreGazetteer(GAZETTEER.read().unwrap().clone());
+        set_entity_gazetteer(vec![GazetteerEntry { term: "Ethereum".to_string(), entity_type: "chain".to_string() }]);
+        let entities = extract_entities("이더리움 aka ETHEREUM mainnet, not Ethereumish".to_string());
+        assert_eq!(normalized_of(&entities, "chain"), vec!["ethereum"]);
+        assert_eq!(entities.iter().find(|e| e.entity_type == "chain").unwrap().text, "ETHEREUM");
+    }
+
+    #[test]
+    fn test_entity_filter_matches_date_prefix() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT);").unwrap();
+        create_entity_table(&conn).unwrap();
+        for (id, content) in [(1, "Filed 2023-09-15."), (2, "Filed 2023-10-01."), (3, "Filed 2023-09.")] {
+            conn.execute("INSERT INTO chunks (id, content) VALUES (?1, ?2)", params![id, content]).unwrap();
+            store_chunk_entities(&conn, id, content).unwrap();
+        }
+        let sql = format!("SELECT c.id FROM chunks c WHERE {} ORDER BY c.id", entity_filter_condition("2023-09"));
+        let ids: Vec<i64> = conn.prepare(&sql).unwrap().query_map([], |r| r.get(0)).unwrap().flatten().collect();
+        assert_eq!(ids, vec![1, 3]);
+    }
+}
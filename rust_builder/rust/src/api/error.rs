@@ -1,5 +1,6 @@
 use thiserror::Error;
 use flutter_rust_bridge::frb;
+use crate::api::metrics::record_db_error;
 
 /// Structured error type passed to Dart via FFI.
 #[frb(dart_metadata=("freezed"))] // Generated as a sealed class in Dart.
@@ -28,4 +29,124 @@ pub enum RagError {
     /// Unknown error.
     #[error("Unknown error: {0}")]
     Unknown(String),
+
+    /// Database stayed busy/locked after all automatic retries.
+    #[error("Database busy: {0}")]
+    DatabaseBusy(String),
+
+    /// A required component (DB pool, tokenizer, index) was not set up yet.
+    #[error("Not initialized: {0}")]
+    NotInitialized(String),
+
+    /// Input could not be parsed (corrupt PDF/DOCX, unreadable archive).
+    #[error("Parse error: {0}")]
+    ParseError(String),
+}
+
+/// SQLite's messages for SQLITE_BUSY and SQLITE_LOCKED.
+const BUSY_MARKERS: &[&str] = &["database is locked", "database table is locked", "database is busy"];
+
+/// `std::io::Error` messages of kinds that can pass on their own.
+const TRANSIENT_IO_MARKERS: &[&str] = &[
+    "interrupted",
+    "timed out",
+    "temporarily unavailable",
+    "would block",
+    "resource busy",
+];
+
+fn mentions_any(message: &str, markers: &[&str]) -> bool {
+    let message = message.to_lowercase();
+    markers.iter().any(|m| message.contains(m))
+}
+
+/// Build a `RagError::DatabaseError`, counting it in the engine metrics.
+pub(crate) fn db_error(e: impl std::fmt::Display) -> RagError {
+    record_db_error();
+    RagError::DatabaseError(e.to_string())
+}
+
+impl RagError {
+    /// Stable machine-readable code for this error kind.
+    ///
+    /// Codes never change between releases, so the Flutter layer can branch
+    /// on them instead of parsing messages.
+    #[frb(sync)]
+    pub fn code(&self) -> String {
+        match self {
+            RagError::DatabaseError(_) => "database",
+            RagError::IoError(_) => "io",
+            RagError::ModelLoadError(_) => "model_load",
+            RagError::InvalidInput(_) => "invalid_input",
+            RagError::InternalError(_) => "internal",
+            RagError::Unknown(_) => "unknown",
+            RagError::DatabaseBusy(_) => "database_busy",
+            RagError::NotInitialized(_) => "not_initialized",
+            RagError::ParseError(_) => "parse",
+        }
+        .to_string()
+    }
+
+    /// Whether retrying the same call may succeed: a busy or locked
+    /// database, or a transient file system error (interrupted, timed out,
+    /// temporarily unavailable). Other errors need a user-facing message or
+    /// a code fix instead.
+    #[frb(sync)]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            RagError::DatabaseBusy(_) => true,
+            RagError::DatabaseError(m) => mentions_any(m, BUSY_MARKERS),
+            RagError::IoError(m) => mentions_any(m, BUSY_MARKERS) || mentions_any(m, TRANSIENT_IO_MARKERS),
+            _ => false,
+        }
+    }
+
+    /// Message without the kind prefix.
+    #[frb(sync)]
+    pub fn detail(&self) -> String {
+        match self {
+            RagError::DatabaseError(m)
+            | RagError::IoError(m)
+            | RagError::ModelLoadError(m)
+            | RagError::InvalidInput(m)
+            | RagError::InternalError(m)
+            | RagError::Unknown(m)
+            | RagError::DatabaseBusy(m)
+            | RagError::NotInitialized(m)
+            | RagError::ParseError(m) => m.clone(),
+        }
+    }
+}
+
+/// Build a `RagError::IoError` with the path or operation that failed.
+pub(crate) fn io_error(context: impl std::fmt::Display, e: impl std::fmt::Display) -> RagError {
+    RagError::IoError(format!("{}: {}", context, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_and_retryable() {
+        let db = RagError::DatabaseError("database is locked".to_string());
+        assert_eq!(db.code(), "database");
+        assert!(db.is_retryable());
+        assert_eq!(db.detail(), "database is locked");
+        assert!(!RagError::DatabaseError("no such table: chunks".to_string()).is_retryable());
+        assert!(!RagError::DatabaseError("UNIQUE constraint failed".to_string()).is_retryable());
+        assert!(RagError::DatabaseBusy("write retries exhausted".to_string()).is_retryable());
+        assert!(io_error("/tmp/x", "Resource temporarily unavailable (os error 11)").is_retryable());
+        assert!(!io_error("/tmp/x", "Permission denied (os error 13)").is_retryable());
+
+        let input = RagError::InvalidInput("empty query".to_string());
+        assert_eq!(input.code(), "invalid_input");
+        assert!(!input.is_retryable());
+        assert_eq!(io_error("/tmp/x", "denied").detail(), "/tmp/x: denied");
+
+        let uninit = RagError::NotInitialized("tokenizer".to_string());
+        assert_eq!(uninit.code(), "not_initialized");
+        assert!(!uninit.is_retryable());
+        assert_eq!(RagError::ParseError("bad xref".to_string()).code(), "parse");
+    }
 }
@@ -0,0 +1,382 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! SQLite FTS5 keyword backend.
+//!
+//! An alternative to the in-memory BM25 index for large corpora: keyword
+//! search runs against contentless FTS5 tables (`chunks_fts`, and
+//! `docs_fts` when the simple RAG `docs` table exists) whose rowids are
+//! the chunk/doc ids. Triggers keep them in sync with every insert,
+//! update and delete, so the index persists across restarts and costs
+//! no RAM. Queries support the boolean syntax of `query_parser` plus
+//! prefix terms (`pars*`). Ranking uses FTS5's built-in bm25(); stemming,
+//! analyzers and CJK n-grams of the in-memory index do not apply.
+
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+use log::{info, warn};
+use rusqlite::{params, Connection};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::bm25_search::{drop_all_bm25_indexes, DocConstraint};
+use crate::api::content_crypto::is_content_encryption_enabled;
+use crate::api::db_retry::with_write_retry;
+use crate::api::error::{db_error, RagError};
+use crate::api::query_parser::parse_boolean_query;
+use crate::api::db_pool::{get_connection, is_pool_initialized};
+use crate::api::tenant::DEFAULT_TENANT;
+
+/// Where keyword (BM25) search runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordBackend {
+    /// The per-tenant in-memory inverted index (best for small corpora).
+    #[default]
+    InMemory,
+    /// SQLite FTS5 tables maintained by triggers.
+    Fts5,
+}
+
+static KEYWORD_BACKEND: RwLock<KeywordBackend> = RwLock::new(KeywordBackend::InMemory);
+
+const CHUNKS_FTS_SCHEMA: &str = "
+    CREATE VIRTUAL TABLE IF NOT EXISTS chunks_fts USING fts5(
+        content, content='', contentless_delete=1,
+        tokenize='unicode61 remove_diacritics 2'
+    );
+    CREATE TRIGGER IF NOT EXISTS chunks_fts_insert AFTER INSERT ON chunks BEGIN
+        INSERT INTO chunks_fts(rowid, content) VALUES (new.id, new.content);
+    END;
+    CREATE TRIGGER IF NOT EXISTS chunks_fts_delete AFTER DELETE ON chunks BEGIN
+        DELETE FROM chunks_fts WHERE rowid = old.id;
+    END;
+    CREATE TRIGGER IF NOT EXISTS chunks_fts_update AFTER UPDATE OF content ON chunks BEGIN
+        DELETE FROM chunks_fts WHERE rowid = old.id;
+        INSERT INTO chunks_fts(rowid, content) VALUES (new.id, new.content);
+    END;
+";
+
+const DOCS_FTS_SCHEMA: &str = "
+    CREATE VIRTUAL TABLE IF NOT EXISTS docs_fts USING fts5(
+        content, content='', contentless_delete=1,
+        tokenize='unicode61 remove_diacritics 2'
+    );
+    CREATE TRIGGER IF NOT EXISTS docs_fts_insert AFTER INSERT ON docs BEGIN
+        INSERT INTO docs_fts(rowid, content) VALUES (new.id, new.content);
+    END;
+    CREATE TRIGGER IF NOT EXISTS docs_fts_delete AFTER DELETE ON docs BEGIN
+        DELETE FROM docs_fts WHERE rowid = old.id;
+    END;
+    CREATE TRIGGER IF NOT EXISTS docs_fts_update AFTER UPDATE OF content ON docs BEGIN
+        DELETE FROM docs_fts WHERE rowid = old.id;
+        INSERT INTO docs_fts(rowid, content) VALUES (new.id, new.content);
+    END;
+";
+
+const DROP_FTS: &str = "
+    DROP TRIGGER IF EXISTS chunks_fts_insert;
+    DROP TRIGGER IF EXISTS chunks_fts_delete;
+    DROP TRIGGER IF EXISTS chunks_fts_update;
+    DROP TABLE IF EXISTS chunks_fts;
+    DROP TRIGGER IF EXISTS docs_fts_insert;
+    DROP TRIGGER IF EXISTS docs_fts_delete;
+    DROP TRIGGER IF EXISTS docs_fts_update;
+    DROP TABLE IF EXISTS docs_fts;
+";
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_keyword_backend() -> KeywordBackend {
+    *KEYWORD_BACKEND.read().unwrap()
+}
+
+pub(crate) fn fts_keyword_enabled() -> bool {
+    get_keyword_backend() == KeywordBackend::Fts5
+}
+
+/// Select the keyword backend.
+///
+/// Switching to `Fts5` creates the FTS tables and triggers (filling them
+/// from existing rows the first time) and frees the in-memory indexes.
+/// The tables persist, so call this again with `Fts5` after each start.
+/// Not available with content encryption, since the index would hold
+/// ciphertext. Switching back to `InMemory` drops the tables; rebuild
+/// the in-memory index afterwards (`rebuild_chunk_bm25_index`).
+pub fn set_keyword_backend(backend: KeywordBackend) -> Result<(), RagError> {
+    match backend {
+        KeywordBackend::Fts5 => {
+            if is_content_encryption_enabled() {
+                return Err(RagError::InvalidInput(
+                    "The FTS5 keyword backend cannot index encrypted content".to_string(),
+                ));
+            }
+            let filled = with_write_retry("set_keyword_backend", |conn| {
+                let tx = conn.transaction()?;
+                let filled = install_fts(&tx)?;
+                tx.commit()?;
+                Ok(filled)
+            })?;
+            *KEYWORD_BACKEND.write().unwrap() = backend;
+            drop_all_bm25_indexes();
+            info!("[fts] FTS5 keyword backend enabled ({} rows indexed now)", filled);
+        }
+        KeywordBackend::InMemory => {
+            with_write_retry("set_keyword_backend", |conn| {
+                conn.execute_batch(DROP_FTS)?;
+                Ok(())
+            })?;
+            *KEYWORD_BACKEND.write().unwrap() = backend;
+            info!("[fts] In-memory keyword backend enabled; rebuild the BM25 index");
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn table_exists(conn: &Connection, name: &str) -> rusqlite::Result<bool> {
+    conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE name = ?1)",
+        params![name],
+        |row| row.get(0),
+    )
+}
+
+/// Whether the database holds FTS tables, whose triggers would copy
+/// every content write into them. `false` before the pool is opened.
+pub(crate) fn fts_tables_installed() -> Result<bool, RagError> {
+    if !is_pool_initialized() {
+        return Ok(false);
+    }
+    let conn = get_connection()?;
+    Ok(table_exists(&conn, "chunks_fts").map_err(db_error)?
+        || table_exists(&conn, "docs_fts").map_err(db_error)?)
+}
+
+/// Drop the FTS tables and fall back to the in-memory backend, for
+/// databases opened with content encryption enabled.
+pub(crate) fn drop_fts_for_encryption(conn: &Connection) -> rusqlite::Result<()> {
+    if table_exists(conn, "chunks_fts")? || table_exists(conn, "docs_fts")? {
+        warn!("[fts] Content encryption is enabled; dropping the FTS5 tables");
+        conn.execute_batch(DROP_FTS)?;
+    }
+    *KEYWORD_BACKEND.write().unwrap() = KeywordBackend::InMemory;
+    Ok(())
+}
+
+/// Create the FTS tables and triggers; tables created now are filled
+/// from their source table. Returns the number of rows filled.
+fn install_fts(conn: &Connection) -> rusqlite::Result<usize> {
+    let mut filled = 0;
+    for (source, schema) in [("chunks", CHUNKS_FTS_SCHEMA), ("docs", DOCS_FTS_SCHEMA)] {
+        if !table_exists(conn, source)? {
+            continue;
+        }
+        let fresh = !table_exists(conn, &format!("{}_fts", source))?;
+        conn.execute_batch(schema)?;
+        if fresh {
+            filled += conn.execute(
+                &format!("INSERT INTO {0}_fts(rowid, content) SELECT id, content FROM {0}", source),
+                [],
+            )?;
+        }
+    }
+    Ok(filled)
+}
+
+/// One FTS5 string: the words of `clause` as a phrase, with a trailing
+/// `*` kept as a prefix marker. `None` if it has no words.
+fn fts_phrase(clause: &str) -> Option<String> {
+    let words: Vec<String> = clause
+        .unicode_words()
+        .map(|w| w.to_lowercase().replace('"', "\"\""))
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    let prefix = if clause.trim_end().ends_with('*') { "*" } else { "" };
+    Some(format!("\"{}\"{}", words.join(" "), prefix))
+}
+
+fn join_phrases(clauses: &[String], operator: &str) -> Option<String> {
+    let phrases: Vec<String> = clauses.iter().filter_map(|c| fts_phrase(c)).collect();
+    (!phrases.is_empty()).then(|| format!("({})", phrases.join(operator)))
+}
+
+/// The FTS5 MATCH expression for `query`: required clauses if any,
+/// otherwise any optional one, minus the excluded clauses. `None` when
+/// nothing can match.
+fn match_expression(query: &str) -> Option<String> {
+    let parsed = parse_boolean_query(query);
+    let positive = if parsed.must.is_empty() {
+        join_phrases(&parsed.should, " OR ")
+    } else {
+        join_phrases(&parsed.must, " AND ")
+    }?;
+    Some(match join_phrases(&parsed.must_not, " OR ") {
+        Some(excluded) => format!("{} NOT {}", positive, excluded),
+        None => positive,
+    })
+}
+
+/// Ids matching `expression` (best first, bm25 score flipped so higher
+/// is better). Docs belong to the default tenant.
+fn fts_matches(
+    conn: &Connection,
+    tenant_id: &str,
+    expression: &str,
+    limit: i64,
+) -> rusqlite::Result<Vec<(i64, f64)>> {
+    let mut stmt = conn.prepare(
+        "SELECT chunks_fts.rowid, -bm25(chunks_fts) FROM chunks_fts
+         JOIN chunks c ON c.id = chunks_fts.rowid
+         WHERE chunks_fts MATCH ?1 AND c.tenant_id = ?2
+         ORDER BY bm25(chunks_fts) LIMIT ?3",
+    )?;
+    let mut matches = stmt
+        .query_map(params![expression, tenant_id, limit], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(i64, f64)>>>()?;
+
+    if tenant_id == DEFAULT_TENANT && table_exists(conn, "docs_fts")? {
+        let mut stmt = conn.prepare(
+            "SELECT rowid, -bm25(docs_fts) FROM docs_fts WHERE docs_fts MATCH ?1
+             ORDER BY bm25(docs_fts) LIMIT ?2",
+        )?;
+        let docs = stmt
+            .query_map(params![expression, limit], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<Vec<(i64, f64)>>>()?;
+        matches.extend(docs);
+        matches.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        if limit >= 0 {
+            matches.truncate(limit as usize);
+        }
+    }
+    Ok(matches)
+}
+
+fn search_fts(
+    conn: &Connection,
+    tenant_id: &str,
+    query: &str,
+    top_k: u32,
+) -> rusqlite::Result<Vec<(i64, f64)>> {
+    match match_expression(query) {
+        Some(expression) => fts_matches(conn, tenant_id, &expression, top_k as i64),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Keyword search through FTS5; errors are logged and give no results,
+/// like a missing in-memory index.
+pub(crate) fn fts_search(tenant_id: &str, query: &str, top_k: u32) -> Vec<(i64, f64)> {
+    let result = get_connection()
+        .and_then(|conn| search_fts(&conn, tenant_id, query, top_k).map_err(db_error));
+    result.unwrap_or_else(|e| {
+        warn!("[fts] Search for '{}' failed: {}", query, e);
+        Vec::new()
+    })
+}
+
+fn fts_constraint_in(
+    conn: &Connection,
+    tenant_id: &str,
+    query: &str,
+) -> rusqlite::Result<Option<DocConstraint>> {
+    let parsed = parse_boolean_query(query);
+    if !parsed.has_operators() {
+        return Ok(None);
+    }
+    let ids = |expression: Option<String>| -> rusqlite::Result<Option<HashSet<i64>>> {
+        expression
+            .map(|e| {
+                fts_matches(conn, tenant_id, &e, -1)
+                    .map(|matches| matches.into_iter().map(|(id, _)| id).collect())
+            })
+            .transpose()
+    };
+    let required = if parsed.must.is_empty() {
+        None
+    } else {
+        // Required clauses without any words admit nothing.
+        Some(ids(join_phrases(&parsed.must, " AND "))?.unwrap_or_default())
+    };
+    let excluded = ids(join_phrases(&parsed.must_not, " OR "))?.unwrap_or_default();
+    Ok(Some(DocConstraint { required, excluded }))
+}
+
+/// `bm25_boolean_constraint` evaluated against the FTS5 tables.
+pub(crate) fn fts_constraint(tenant_id: &str, query: &str) -> Option<DocConstraint> {
+    let result = get_connection()
+        .and_then(|conn| fts_constraint_in(&conn, tenant_id, query).map_err(db_error));
+    result.unwrap_or_else(|e| {
+        warn!("[fts] Constraint for '{}' failed: {}", query, e);
+        // Fail closed on required clauses, as the in-memory path does
+        // without an index.
+        let parsed = parse_boolean_query(query);
+        parsed.has_operators().then(|| DocConstraint {
+            required: (!parsed.must.is_empty()).then(HashSet::new),
+            excluded: HashSet::new(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fts_tables_follow_chunks() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, tenant_id TEXT NOT NULL, content TEXT NOT NULL);
+             INSERT INTO chunks VALUES (1, 'default', 'Parsing JSON with serde');",
+        )
+        .unwrap();
+        assert_eq!(install_fts(&conn).unwrap(), 1);
+        assert!(table_exists(&conn, "chunks_fts").unwrap());
+        conn.execute_batch(
+            "INSERT INTO chunks VALUES (2, 'default', 'A parser for TOML files');
+             INSERT INTO chunks VALUES (3, 'other', 'parser internals');
+             INSERT INTO chunks VALUES (4, 'default', 'Unrelated text');",
+        )
+        .unwrap();
+
+        let ids = |query: &str| -> Vec<i64> {
+            let mut ids: Vec<i64> = search_fts(&conn, "default", query, 10)
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            ids.sort_unstable();
+            ids
+        };
+        assert_eq!(ids("pars*"), vec![1, 2]);
+        assert_eq!(ids("pars* -toml"), vec![1]);
+        assert_eq!(ids("+\"json with\""), vec![1]);
+        assert!(ids("-json").is_empty());
+
+        conn.execute("UPDATE chunks SET content = 'TOML only' WHERE id = 1", []).unwrap();
+        conn.execute("DELETE FROM chunks WHERE id = 2", []).unwrap();
+        assert!(ids("pars*").is_empty());
+        assert_eq!(ids("toml"), vec![1]);
+
+        let constraint = fts_constraint_in(&conn, "default", "+toml -unrelated").unwrap().unwrap();
+        assert_eq!(constraint.required, Some(HashSet::from([1])));
+        assert!(constraint.excluded.contains(&4));
+
+        drop_fts_for_encryption(&conn).unwrap();
+        assert!(!table_exists(&conn, "chunks_fts").unwrap());
+        conn.execute("INSERT INTO chunks VALUES (5, 'default', 'enc1:k1:...')", []).unwrap();
+        assert!(!fts_keyword_enabled());
+    }
+}
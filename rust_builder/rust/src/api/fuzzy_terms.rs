@@ -0,0 +1,211 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Typo-tolerant BM25 terms.
+//!
+//! Each BM25 index keeps its vocabulary in a BK-tree (a metric tree over
+//! Levenshtein distance), so the terms within one or two edits of a query
+//! term are found without scanning the whole vocabulary. When fuzzy
+//! matching is on, a query term with no postings is replaced by its
+//! nearest indexed terms, scored at a discount per edit: "kuberntes"
+//! still finds chunks about "kubernetes".
+
+use std::sync::RwLock;
+
+use log::info;
+
+use crate::api::error::RagError;
+
+/// Terms shorter than this many characters allow at most one edit.
+const TWO_EDIT_MIN_CHARS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25FuzzyConfig {
+    /// Edit distance allowed for long terms, 1 or 2.
+    pub max_edits: u32,
+    /// Shorter query terms are never corrected.
+    pub min_term_chars: u32,
+    /// Indexed terms a misspelled term expands to, closest first.
+    pub max_expansions: u32,
+}
+
+impl Default for Bm25FuzzyConfig {
+    fn default() -> Self {
+        Self {
+            max_edits: 2,
+            min_term_chars: 4,
+            max_expansions: 3,
+        }
+    }
+}
+
+static FUZZY_MATCHING: RwLock<Option<Bm25FuzzyConfig>> = RwLock::new(None);
+
+/// Enable fuzzy matching for BM25 query terms without postings, or turn
+/// it off with `None` (the default).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_bm25_fuzzy_matching(config: Option<Bm25FuzzyConfig>) -> Result<(), RagError> {
+    if let Some(c) = &config {
+        if !(1..=2).contains(&c.max_edits) {
+            return Err(RagError::InvalidInput(format!(
+                "max_edits must be 1 or 2, got {}",
+                c.max_edits
+            )));
+        }
+    }
+    *FUZZY_MATCHING.write().unwrap() = config;
+    info!("[bm25] Fuzzy matching set to {:?}", config);
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_bm25_fuzzy_matching() -> Option<Bm25FuzzyConfig> {
+    *FUZZY_MATCHING.read().unwrap()
+}
+
+/// Edits allowed when correcting `term` (0 = use it as is).
+pub(crate) fn allowed_edits(term: &str, config: &Bm25FuzzyConfig) -> u32 {
+    let chars = term.chars().count();
+    if chars < config.min_term_chars as usize {
+        0
+    } else if chars < TWO_EDIT_MIN_CHARS {
+        config.max_edits.min(1)
+    } else {
+        config.max_edits
+    }
+}
+
+/// Score weight of a term reached with `edits` corrections.
+pub(crate) fn fuzzy_weight(edits: u32) -> f64 {
+    1.0 / (1.0 + edits as f64)
+}
+
+fn levenshtein(a: &str, b: &str) -> u32 {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<u32> = (0..=b.len() as u32).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i as u32 + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + u32::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[derive(Debug, Clone)]
+struct BkNode {
+    term: String,
+    /// `(distance to this node's term, child node)`.
+    children: Vec<(u32, usize)>,
+}
+
+/// A vocabulary indexed by edit distance. Terms are only ever added;
+/// callers filter out terms they no longer index.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BkTree {
+    nodes: Vec<BkNode>,
+}
+
+impl BkTree {
+    pub fn insert(&mut self, term: &str) {
+        if self.nodes.is_empty() {
+            self.push(term);
+            return;
+        }
+        let mut current = 0;
+        loop {
+            let distance = levenshtein(term, &self.nodes[current].term);
+            if distance == 0 {
+                return;
+            }
+            match self.nodes[current].children.iter().find(|(d, _)| *d == distance) {
+                Some(&(_, child)) => current = child,
+                None => {
+                    let child = self.push(term);
+                    self.nodes[current].children.push((distance, child));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, term: &str) -> usize {
+        self.nodes.push(BkNode {
+            term: term.to_string(),
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Terms within `max_edits` of `term`, with their distance.
+    pub fn find(&self, term: &str, max_edits: u32) -> Vec<(&str, u32)> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() {
+            return found;
+        }
+        let mut pending = vec![0];
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            let distance = levenshtein(term, &node.term);
+            if distance <= max_edits {
+                found.push((node.term.as_str(), distance));
+            }
+            // Triangle inequality: only these subtrees can hold matches.
+            let range = distance.saturating_sub(max_edits)..=distance + max_edits;
+            pending.extend(
+                node.children
+                    .iter()
+                    .filter(|(d, _)| range.contains(d))
+                    .map(|(_, child)| *child),
+            );
+        }
+        found
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bk_tree_finds_close_terms() {
+        assert_eq!(levenshtein("kuberntes", "kubernetes"), 1);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+
+        let mut tree = BkTree::default();
+        for term in ["kubernetes", "kubectl", "docker", "compose", "kubernetes"] {
+            tree.insert(term);
+        }
+        let mut found = tree.find("kuberntes", 2);
+        found.sort();
+        assert_eq!(found, vec![("kubernetes", 1)]);
+        assert!(tree.find("podman", 1).is_empty());
+
+        let config = Bm25FuzzyConfig::default();
+        assert_eq!(allowed_edits("api", &config), 0);
+        assert_eq!(allowed_edits("dockr", &config), 1);
+        assert_eq!(allowed_edits("kuberntes", &config), 2);
+    }
+}
@@ -16,13 +16,28 @@
 //
 //! HNSW (Hierarchical Navigable Small Worlds) vector indexing module.
 
+use hnsw_rs::filter::FilterT;
 use hnsw_rs::prelude::*;
-use hnsw_rs::hnswio::*;
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 use once_cell::sync::Lazy;
 use log::{info, debug, warn};
 use std::path::Path;
-use serde::{Serialize, Deserialize};
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
+
+use crate::api::binary_index::{clear_all_binary_indexes, drop_binary_index};
+use crate::api::embedding_storage::{quantize_values, set_quantized_embedding_storage};
+use crate::api::error::{io_error, RagError};
+use crate::api::memory_budget::memory_profile;
+use crate::api::named_index::clear_all_named_indexes;
+use crate::api::panic_report::catch_panic;
+use crate::api::progress::{Progress, PHASE_BUILD, PHASE_INDEX};
+use crate::api::quantized_index::{
+    build_quantized_index, clear_all_quantized_indexes, drop_quantized_index, has_quantized_index,
+    insert_quantized, quantized_ids, quantized_point_count, search_quantized,
+};
+use crate::api::source_index::clear_all_source_indexes;
+use crate::api::tenant::{active_tenant, tenant_index_path};
 
 /// Embedding point wrapper for FRB compatibility (legacy support).
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -39,9 +54,347 @@ impl EmbeddingPoint {
     }
 }
 
-/// Global HNSW index (thread-safe in-memory cache).
-static HNSW_INDEX: Lazy<RwLock<Option<Hnsw<'static, f32, DistCosine>>>> = 
-    Lazy::new(|| RwLock::new(None));
+/// Cosine distance between int8 vectors. Each vector has its own scale,
+/// which cancels out of the cosine, so the raw values are compared.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DistCosineI8;
+
+// Written out so the binding generator, which cannot parse unit structs,
+// can be told to skip it.
+impl Default for DistCosineI8 {
+    #[flutter_rust_bridge::frb(ignore)]
+    fn default() -> Self {
+        DistCosineI8
+    }
+}
+
+impl Distance<i8> for DistCosineI8 {
+    fn eval(&self, va: &[i8], vb: &[i8]) -> f32 {
+        let (mut dot, mut norm_a, mut norm_b) = (0i64, 0i64, 0i64);
+        for (&a, &b) in va.iter().zip(vb) {
+            let (a, b) = (a as i64, b as i64);
+            dot += a * b;
+            norm_a += a * a;
+            norm_b += b * b;
+        }
+        if norm_a == 0 || norm_b == 0 {
+            return 1.0;
+        }
+        (1.0 - dot as f64 / ((norm_a as f64).sqrt() * (norm_b as f64).sqrt())).max(0.0) as f32
+    }
+}
+
+/// Inner-product "distance" for graph construction. hnsw_rs needs
+/// non-negative distances, so the dot product is mapped through a
+/// positive, strictly decreasing function: `1 / (1 + dot)` for `dot >= 0`
+/// and `1 - dot` below.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DistInnerProduct;
+
+// Written out so the binding generator, which cannot parse unit structs,
+// can be told to skip it.
+impl Default for DistInnerProduct {
+    #[flutter_rust_bridge::frb(ignore)]
+    fn default() -> Self {
+        DistInnerProduct
+    }
+}
+
+impl Distance<f32> for DistInnerProduct {
+    fn eval(&self, va: &[f32], vb: &[f32]) -> f32 {
+        let dot: f32 = va.iter().zip(vb).map(|(a, b)| a * b).sum();
+        if dot >= 0.0 { 1.0 / (1.0 + dot) } else { 1.0 - dot }
+    }
+}
+
+/// The dot product behind a `DistInnerProduct` value.
+fn inner_product_from_graph(distance: f32) -> f32 {
+    if distance <= 1.0 { 1.0 / distance - 1.0 } else { 1.0 - distance }
+}
+
+fn quantize_for_graph(embedding: &[f32]) -> Vec<i8> {
+    quantize_values(embedding).1
+}
+
+/// Vector representation of the tenant HNSW graphs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quantization {
+    /// Full `f32` vectors.
+    #[default]
+    None,
+    /// int8 vectors with a per-vector scale, about a quarter of the memory
+    /// for the vectors at a small recall cost.
+    Int8,
+}
+
+/// How vector search compares embeddings.
+///
+/// Search results report a distance where lower is better; `1 - distance`
+/// is the cosine similarity or the dot product, and L2 results report the
+/// Euclidean distance itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Angle between the vectors; magnitudes are ignored.
+    #[default]
+    Cosine,
+    /// Inner product, for models trained with unnormalized dot-product scores.
+    DotProduct,
+    /// Euclidean distance.
+    L2,
+}
+
+impl DistanceMetric {
+    /// Result distance from the dot product and both vector norms.
+    pub(crate) fn distance(self, dot: f32, query_norm: f32, norm: f32) -> f32 {
+        match self {
+            DistanceMetric::Cosine if query_norm == 0.0 || norm == 0.0 => 1.0,
+            DistanceMetric::Cosine => 1.0 - dot / (query_norm * norm),
+            DistanceMetric::DotProduct => 1.0 - dot,
+            DistanceMetric::L2 => (query_norm * query_norm + norm * norm - 2.0 * dot).max(0.0).sqrt(),
+        }
+    }
+}
+
+/// Storage and index options; see `set_index_config`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IndexConfig {
+    pub quantization: Quantization,
+    /// Metric of indexes built from now on (and of exact scans).
+    pub metric: DistanceMetric,
+}
+
+static INDEX_CONFIG: RwLock<IndexConfig> =
+    RwLock::new(IndexConfig { quantization: Quantization::None, metric: DistanceMetric::Cosine });
+
+/// Set how embeddings are stored and indexed.
+///
+/// With `Quantization::Int8`, new embeddings are stored as int8 with a
+/// per-vector scale (see `set_quantized_embedding_storage`) and HNSW
+/// graphs are built over int8 vectors. Existing rows keep their format
+/// until `quantize_stored_embeddings`, and loaded graphs until their next
+/// rebuild. Low-memory mode's flat quantized index still takes precedence.
+///
+/// The metric applies to exact scans immediately and to HNSW graphs from
+/// their next build; int8 graphs support cosine only.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_index_config(config: IndexConfig) -> Result<(), RagError> {
+    if config.quantization == Quantization::Int8 && config.metric != DistanceMetric::Cosine {
+        return Err(RagError::InvalidInput(format!(
+            "Int8 quantization supports the cosine metric only, got {:?}",
+            config.metric
+        )));
+    }
+    info!("[hnsw] Index config: {:?}", config);
+    set_quantized_embedding_storage(config.quantization == Quantization::Int8);
+    let previous = std::mem::replace(&mut *INDEX_CONFIG.write().unwrap(), config);
+    if previous != config {
+        // Cached sub-indexes are rebuilt on demand with the new settings.
+        clear_all_source_indexes();
+    }
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_index_config() -> IndexConfig {
+    *INDEX_CONFIG.read().unwrap()
+}
+
+/// A tenant's HNSW graph over full or quantized vectors.
+pub(crate) enum TenantGraph {
+    F32(Hnsw<'static, f32, DistCosine>),
+    F32Dot(Hnsw<'static, f32, DistInnerProduct>),
+    F32L2(Hnsw<'static, f32, DistL2>),
+    Int8(Hnsw<'static, i8, DistCosineI8>),
+}
+
+/// Apply `$body` to the `Hnsw` inside any `TenantGraph` variant.
+macro_rules! with_graph {
+    ($graph:expr, $h:ident => $body:expr) => {
+        match $graph {
+            TenantGraph::F32($h) => $body,
+            TenantGraph::F32Dot($h) => $body,
+            TenantGraph::F32L2($h) => $body,
+            TenantGraph::Int8($h) => $body,
+        }
+    };
+}
+
+impl TenantGraph {
+    pub(crate) fn quantization(&self) -> Quantization {
+        match self {
+            TenantGraph::Int8(_) => Quantization::Int8,
+            _ => Quantization::None,
+        }
+    }
+
+    pub(crate) fn metric(&self) -> DistanceMetric {
+        match self {
+            TenantGraph::F32Dot(_) => DistanceMetric::DotProduct,
+            TenantGraph::F32L2(_) => DistanceMetric::L2,
+            TenantGraph::F32(_) | TenantGraph::Int8(_) => DistanceMetric::Cosine,
+        }
+    }
+
+    pub(crate) fn get_nb_point(&self) -> usize {
+        with_graph!(self, h => h.get_nb_point())
+    }
+
+    fn dimension(&self) -> usize {
+        with_graph!(self, h => h.get_point_indexation().get_data_dimension())
+    }
+
+    pub(crate) fn insert(&self, embedding: &[f32], id: i64) {
+        match self {
+            TenantGraph::F32(h) => h.insert((embedding, id as usize)),
+            TenantGraph::F32Dot(h) => h.insert((embedding, id as usize)),
+            TenantGraph::F32L2(h) => h.insert((embedding, id as usize)),
+            TenantGraph::Int8(h) => h.insert((&quantize_for_graph(embedding), id as usize)),
+        }
+    }
+
+    /// Neighbours with distances as described on `DistanceMetric`, only
+    /// among points `filter` accepts when given.
+    pub(crate) fn search(&self, query: &[f32], k: usize, ef_search: usize, filter: Option<&dyn FilterT>) -> Vec<Neighbour> {
+        match self {
+            TenantGraph::F32(h) => h.search_filter(query, k, ef_search, filter),
+            TenantGraph::F32Dot(h) => {
+                let mut neighbours = h.search_filter(query, k, ef_search, filter);
+                for n in &mut neighbours {
+                    n.distance = 1.0 - inner_product_from_graph(n.distance);
+                }
+                neighbours
+            }
+            TenantGraph::F32L2(h) => h.search_filter(query, k, ef_search, filter),
+            TenantGraph::Int8(h) => h.search_filter(&quantize_for_graph(query), k, ef_search, filter),
+        }
+    }
+
+    fn point_ids(&self) -> Vec<i64> {
+        with_graph!(self, h => h.get_point_indexation().into_iter().map(|p| p.get_origin_id() as i64).collect())
+    }
+
+    fn file_dump(&self, dir: &Path, basename: &str) -> anyhow::Result<String> {
+        with_graph!(self, h => h.file_dump(dir, basename))
+    }
+}
+
+/// Global HNSW indexes, one per tenant (thread-safe in-memory cache).
+static HNSW_INDEX: Lazy<RwLock<HashMap<String, TenantGraph>>> = 
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Caller-chosen build parameters per tenant; absent means adaptive.
+static CUSTOM_BUILD_CONFIGS: Lazy<RwLock<HashMap<String, HnswBuildConfig>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Caller-chosen `ef_search` per tenant; absent means adaptive.
+static CUSTOM_EF_SEARCH: Lazy<RwLock<HashMap<String, u32>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Parameters the in-memory index of each tenant was built with.
+static BUILT_WITH: Lazy<RwLock<HashMap<String, HnswBuildConfig>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Ids deleted since each tenant's index was built. hnsw_rs cannot remove
+/// points, so searches skip these until the next build.
+static TOMBSTONES: Lazy<RwLock<HashMap<String, HashSet<i64>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Seed of the reproducible build mode; `None` builds normally.
+static SEEDED_BUILD: Lazy<RwLock<Option<u64>>> = Lazy::new(|| RwLock::new(None));
+
+/// Layout of saved indexes (hnsw_rs dump plus manifest); bump on change.
+pub const HNSW_FORMAT_VERSION: u32 = 1;
+
+/// hnsw_rs rejects more connections per node than this.
+const MAX_CONNECTIONS: u32 = 256;
+/// hnsw_rs caps the number of layers at this.
+const MAX_LAYERS: u32 = 16;
+
+/// HNSW graph construction parameters.
+///
+/// hnsw_rs gives layer 0 `2 * m` connections itself, so M0 is derived
+/// from `m` rather than set separately.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HnswBuildConfig {
+    /// Max connections per node on the upper layers (2..=256).
+    pub m: u32,
+    /// Number of graph layers (1..=16).
+    pub max_layer: u32,
+    /// Candidate list size during insertion; higher gives better recall
+    /// and a slower build.
+    pub ef_construction: u32,
+}
+
+impl HnswBuildConfig {
+    /// The adaptive parameters used when no config is given.
+    pub(crate) fn adaptive(count: usize) -> Self {
+        // - Small datasets (<1000): faster build, adequate recall
+        // - Large datasets (>10000): higher quality, better recall
+        let (m, ef_construction) = if count > 10_000 {
+            (24, 200)
+        } else if count > 1_000 {
+            (20, 150)
+        } else {
+            (16, 100)
+        };
+        Self { m, max_layer: MAX_LAYERS, ef_construction }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), RagError> {
+        if !(2..=MAX_CONNECTIONS).contains(&self.m)
+            || !(1..=MAX_LAYERS).contains(&self.max_layer)
+            || self.ef_construction < self.m
+        {
+            return Err(RagError::InvalidInput(format!(
+                "Invalid HNSW config {:?}: m must be 2..={}, max_layer 1..={}, ef_construction >= m",
+                self, MAX_CONNECTIONS, MAX_LAYERS
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Build and search parameters of the active tenant's index.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HnswConfig {
+    /// Graph parameters for subsequent builds; `None` uses the adaptive ones.
+    pub build: Option<HnswBuildConfig>,
+    /// Candidate list size during search (raised to the number of results
+    /// fetched when lower); `None` uses `max(100, 5 * top_k)`.
+    pub ef_search: Option<u32>,
+}
+
+/// Effective parameters of the active tenant's loaded graph.
+#[derive(Debug, Clone)]
+pub struct HnswIndexStats {
+    pub point_count: u64,
+    pub dimension: u32,
+    pub quantization: Quantization,
+    pub metric: DistanceMetric,
+    /// Parameters the graph was built with.
+    pub build: HnswBuildConfig,
+    /// Layer 0 connections (`2 * build.m`).
+    pub m0: u32,
+    /// `build` was chosen by the caller rather than adaptive.
+    pub custom_build: bool,
+    /// Configured `ef_search`; `None` means adaptive per search.
+    pub ef_search: Option<u32>,
+    /// Deleted points still in the graph until the next rebuild.
+    pub deleted_points: u64,
+}
+
+/// Stored next to the index files as `<base>.hnsw.meta.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexManifest {
+    config: HnswBuildConfig,
+    /// The config was chosen by the caller rather than adaptive.
+    custom: bool,
+    point_count: usize,
+    #[serde(default)]
+    quantization: Quantization,
+    #[serde(default)]
+    metric: DistanceMetric,
+}
 
 /// Build HNSW index from embedding points.
 /// 
@@ -49,62 +402,282 @@ static HNSW_INDEX: Lazy<RwLock<Option<Hnsw<'static, f32, DistCosine>>>> =
 /// - M (max connections per node): 16-24 based on dataset size
 /// - M0 (layer 0 connections): 2*M for better recall
 /// - efConstruction: 100-200 based on dataset size
-pub fn build_hnsw_index(points: Vec<(i64, Vec<f32>)>) -> anyhow::Result<()> {
-    info!("[hnsw] Building index with {} points", points.len());
+///
+/// A config set with `build_hnsw_index_with_config` or
+/// `set_hnsw_build_config` is used instead when present.
+pub fn build_hnsw_index(points: Vec<(i64, Vec<f32>)>) -> Result<(), RagError> {
+    build_hnsw_index_for_tenant(&active_tenant(), points)
+}
+
+/// Build with explicit parameters (`None` returns to the adaptive ones).
+/// The choice sticks for later rebuilds of the active tenant.
+pub fn build_hnsw_index_with_config(
+    points: Vec<(i64, Vec<f32>)>,
+    config: Option<HnswBuildConfig>,
+) -> Result<(), RagError> {
+    set_hnsw_build_config(config)?;
+    build_hnsw_index(points)
+}
+
+/// Set (or with `None`, clear) the active tenant's build parameters for
+/// subsequent builds and rebuilds without building now.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_hnsw_build_config(config: Option<HnswBuildConfig>) -> Result<(), RagError> {
+    set_tenant_build_config(&active_tenant(), config)
+}
+
+fn set_tenant_build_config(tenant_id: &str, config: Option<HnswBuildConfig>) -> Result<(), RagError> {
+    let mut configs = CUSTOM_BUILD_CONFIGS.write().unwrap();
+    match config {
+        Some(config) => {
+            config.validate()?;
+            configs.insert(tenant_id.to_string(), config);
+        }
+        None => {
+            configs.remove(tenant_id);
+        }
+    }
+    Ok(())
+}
+
+/// Set the active tenant's build and search parameters. Build parameters
+/// apply from the next build; `ef_search` applies immediately.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_hnsw_config(config: HnswConfig) -> Result<(), RagError> {
+    if config.ef_search == Some(0) {
+        return Err(RagError::InvalidInput("ef_search must be at least 1".to_string()));
+    }
+    let tenant_id = active_tenant();
+    set_tenant_build_config(&tenant_id, config.build)?;
+    let mut ef = CUSTOM_EF_SEARCH.write().unwrap();
+    match config.ef_search {
+        Some(ef_search) => ef.insert(tenant_id, ef_search),
+        None => ef.remove(&tenant_id),
+    };
+    Ok(())
+}
+
+/// The active tenant's configured parameters (`None` fields are adaptive).
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_hnsw_config() -> HnswConfig {
+    let tenant_id = active_tenant();
+    HnswConfig {
+        build: CUSTOM_BUILD_CONFIGS.read().unwrap().get(&tenant_id).copied(),
+        ef_search: CUSTOM_EF_SEARCH.read().unwrap().get(&tenant_id).copied(),
+    }
+}
+
+/// Effective parameters of the active tenant's graph, or `None` when no
+/// graph is loaded (including low-memory mode's flat index).
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_hnsw_index_stats() -> Option<HnswIndexStats> {
+    let tenant_id = active_tenant();
+    let index_guard = HNSW_INDEX.read().unwrap();
+    let index = index_guard.get(&tenant_id)?;
+    let build = BUILT_WITH.read().unwrap().get(&tenant_id).copied()?;
+    Some(HnswIndexStats {
+        point_count: index.get_nb_point() as u64,
+        dimension: index.dimension() as u32,
+        quantization: index.quantization(),
+        metric: index.metric(),
+        build,
+        m0: 2 * build.m,
+        custom_build: CUSTOM_BUILD_CONFIGS.read().unwrap().get(&tenant_id) == Some(&build),
+        ef_search: CUSTOM_EF_SEARCH.read().unwrap().get(&tenant_id).copied(),
+        deleted_points: TOMBSTONES.read().unwrap().get(&tenant_id).map_or(0, |t| t.len()) as u64,
+    })
+}
+
+/// Parameters the active tenant's in-memory index was built with, or
+/// `None` when no graph index is loaded.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_hnsw_build_config() -> Option<HnswBuildConfig> {
+    BUILT_WITH.read().unwrap().get(&active_tenant()).copied()
+}
+
+/// Enable (or with `None`, disable) reproducible builds for tests and
+/// evaluation runs.
+///
+/// hnsw_rs draws node levels from an unseeded RNG, so seeded builds use a
+/// single layer (no draw matters) and insert one point at a time in an
+/// order shuffled by `seed`. The same points and seed always give the same
+/// graph and search results. Recall is similar for small corpora, but
+/// searches of large ones are slower than with the layered graph.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_hnsw_seeded_build(seed: Option<u64>) {
+    info!("[hnsw] Seeded build mode: {:?}", seed);
+    *SEEDED_BUILD.write().unwrap() = seed;
+}
+
+/// Seed of the reproducible build mode, if enabled.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_hnsw_seeded_build() -> Option<u64> {
+    *SEEDED_BUILD.read().unwrap()
+}
+
+/// Insertion order for a seeded build: by id, then a Fisher-Yates shuffle
+/// driven by splitmix64 so it doesn't depend on the query's row order.
+fn seeded_order<T>(points: &mut [(i64, Vec<T>)], seed: u64) {
+    points.sort_by_key(|(id, _)| *id);
+    let mut state = seed;
+    for i in (1..points.len()).rev() {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        points.swap(i, (z % (i as u64 + 1)) as usize);
+    }
+}
+
+/// Build a graph from `points`, reproducibly when `seed` is set (see
+/// `set_hnsw_seeded_build`). Returns the parameters actually used.
+pub(crate) fn build_graph(
+    points: Vec<(i64, Vec<f32>)>,
+    config: HnswBuildConfig,
+    seed: Option<u64>,
+    context: &str,
+    progress: Progress,
+) -> Result<(Hnsw<'static, f32, DistCosine>, HnswBuildConfig), RagError> {
+    build_typed_graph(points, config, seed, DistCosine, context, progress)
+}
+
+/// `build_graph` over vectors of any element type hnsw_rs accepts.
+fn build_typed_graph<T, D>(
+    mut points: Vec<(i64, Vec<T>)>,
+    mut config: HnswBuildConfig,
+    seed: Option<u64>,
+    distance: D,
+    context: &str,
+    progress: Progress,
+) -> Result<(Hnsw<'static, T, D>, HnswBuildConfig), RagError>
+where
+    T: Clone + Send + Sync + Serialize + DeserializeOwned,
+    D: Distance<T> + Send + Sync,
+{
+    if let Some(seed) = seed {
+        config.max_layer = 1;
+        seeded_order(&mut points, seed);
+    }
+    let count = points.len();
+    // hnsw_rs panics on malformed points (e.g. mixed dimensions); the
+    // graph is built before any index lock is taken, so nothing is poisoned.
+    let hnsw = catch_panic(context, || {
+        let hnsw = Hnsw::new(config.m as usize, count, config.max_layer as usize, config.ef_construction as usize, distance);
+        for (i, (id, embedding)) in points.into_iter().enumerate() {
+            progress.report(PHASE_BUILD, i, count);
+            hnsw.insert((&embedding, id as usize));
+        }
+        progress.report(PHASE_BUILD, count, count);
+        hnsw
+    })?;
+    Ok((hnsw, config))
+}
+
+/// Build a tenant graph with the given vector representation and metric
+/// (int8 graphs are always cosine).
+pub(crate) fn build_tenant_graph(
+    points: Vec<(i64, Vec<f32>)>,
+    config: HnswBuildConfig,
+    seed: Option<u64>,
+    quantization: Quantization,
+    metric: DistanceMetric,
+    progress: Progress,
+) -> Result<(TenantGraph, HnswBuildConfig), RagError> {
+    match (quantization, metric) {
+        (Quantization::None, DistanceMetric::Cosine) => {
+            let (hnsw, config) = build_graph(points, config, seed, "HNSW index build", progress)?;
+            Ok((TenantGraph::F32(hnsw), config))
+        }
+        (Quantization::None, DistanceMetric::DotProduct) => {
+            let (hnsw, config) = build_typed_graph(points, config, seed, DistInnerProduct, "HNSW index build", progress)?;
+            Ok((TenantGraph::F32Dot(hnsw), config))
+        }
+        (Quantization::None, DistanceMetric::L2) => {
+            let (hnsw, config) = build_typed_graph(points, config, seed, DistL2, "HNSW index build", progress)?;
+            Ok((TenantGraph::F32L2(hnsw), config))
+        }
+        (Quantization::Int8, _) => {
+            let points = points.into_iter().map(|(id, e)| (id, quantize_for_graph(&e))).collect();
+            let (hnsw, config) = build_typed_graph(points, config, seed, DistCosineI8, "int8 HNSW index build", progress)?;
+            Ok((TenantGraph::Int8(hnsw), config))
+        }
+    }
+}
+
+pub(crate) fn build_hnsw_index_for_tenant(tenant_id: &str, points: Vec<(i64, Vec<f32>)>) -> Result<(), RagError> {
+    build_hnsw_index_with_progress(tenant_id, points, Progress::NONE)
+}
+
+/// `build_hnsw_index_for_tenant`, reporting each inserted point.
+pub(crate) fn build_hnsw_index_with_progress(
+    tenant_id: &str,
+    points: Vec<(i64, Vec<f32>)>,
+    progress: Progress,
+) -> Result<(), RagError> {
+    info!("[hnsw] Building index for tenant {} with {} points", tenant_id, points.len());
     
     if points.is_empty() {
         warn!("[hnsw] No points provided");
         return Ok(());
     }
     
+    if memory_profile().quantize_index {
+        HNSW_INDEX.write().unwrap().remove(tenant_id);
+        BUILT_WITH.write().unwrap().remove(tenant_id);
+        build_quantized_index(tenant_id, points, get_index_config().metric, progress);
+        clear_tombstones(tenant_id);
+        return Ok(());
+    }
+    drop_quantized_index(tenant_id);
+    
     let count = points.len();
     
-    // Adaptive parameters based on dataset size
-    // - Small datasets (<1000): faster build, adequate recall
-    // - Large datasets (>10000): higher quality, better recall
-    let (m, m0, ef_construction, size_category) = if count > 10_000 {
-        (24, 48, 200, "large (>10K)")
-    } else if count > 1_000 {
-        (20, 40, 150, "medium (1K-10K)")
-    } else {
-        (16, 32, 100, "small (<1K)")
-    };
+    let custom = CUSTOM_BUILD_CONFIGS.read().unwrap().get(tenant_id).copied();
+    let config = custom.unwrap_or_else(|| HnswBuildConfig::adaptive(count));
+    let HnswBuildConfig { m, max_layer, ef_construction } = config;
     
     // Debug output for Flutter console (only in debug builds)
     #[cfg(debug_assertions)]
     {
+        let size_category = if count > 10_000 { "large (>10K)" } else if count > 1_000 { "medium (1K-10K)" } else { "small (<1K)" };
         println!("[HNSW] Dataset size: {} points ({})", count, size_category);
-        println!("[HNSW] Parameters: M={}, M0={}, efConstruction={}", m, m0, ef_construction);
-        println!("[HNSW] Expected recall: ~{}%", if count > 10_000 { "97" } else if count > 1_000 { "95" } else { "92" });
+        println!("[HNSW] Parameters: M={}, M0={}, efConstruction={}{}", m, 2 * m, ef_construction, if custom.is_some() { " (custom)" } else { "" });
+        if custom.is_none() {
+            println!("[HNSW] Expected recall: ~{}%", if count > 10_000 { "97" } else if count > 1_000 { "95" } else { "92" });
+        }
     }
     
-    debug!("[hnsw] Using M={}, M0={}, efConstruction={}", m, m0, ef_construction);
+    let IndexConfig { quantization, metric } = get_index_config();
+    debug!("[hnsw] Using M={}, M0={}, layers={}, efConstruction={}, {:?}, {:?}", m, 2 * m, max_layer, ef_construction, quantization, metric);
     
-    let hnsw = Hnsw::new(m, count, m0, ef_construction, DistCosine);
-    
-    for (id, embedding) in points {
-        hnsw.insert((&embedding, id as usize));
-    }
+    let (hnsw, config) = build_tenant_graph(points, config, get_hnsw_seeded_build(), quantization, metric, progress)?;
     
     let mut index_guard = HNSW_INDEX.write().unwrap();
-    *index_guard = Some(hnsw);
+    index_guard.insert(tenant_id.to_string(), hnsw);
+    BUILT_WITH.write().unwrap().insert(tenant_id.to_string(), config);
+    drop(index_guard);
+    clear_tombstones(tenant_id);
     
     #[cfg(debug_assertions)]
     println!("[HNSW] ✅ Index build complete");
     
-    info!("[hnsw] Index build complete (M={}, M0={}, efC={})", m, m0, ef_construction);
+    info!("[hnsw] Index build complete (M={}, M0={}, efC={})", m, 2 * m, ef_construction);
     Ok(())
 }
 
 /// Save HNSW index to disk using hnsw_rs persistence.
 ///
 /// This saves the full graph and data to a directory specified by [base_path].
-pub fn save_hnsw_index(base_path: &str) -> anyhow::Result<()> {
+/// Non-default tenants save next to it with a `_<tenant>` file suffix.
+pub fn save_hnsw_index(base_path: &str) -> Result<(), RagError> {
+    let tenant_id = active_tenant();
+    let base_path = &tenant_index_path(base_path, &tenant_id);
     info!("[hnsw] Saving index to {}", base_path);
     
     let index_guard = HNSW_INDEX.read().unwrap();
     
-    let index = match index_guard.as_ref() {
+    let index = match index_guard.get(&tenant_id) {
         Some(idx) => idx,
         None => {
             warn!("[hnsw] Index not initialized (empty), skipping save");
@@ -118,16 +691,29 @@ pub fn save_hnsw_index(base_path: &str) -> anyhow::Result<()> {
     }
     
     let path = Path::new(base_path);
-    let parent = path.parent().ok_or_else(|| anyhow::anyhow!("Invalid base path"))?;
-    let file_stem = path.file_stem().ok_or_else(|| anyhow::anyhow!("Invalid filename"))?;
+    let parent = path.parent().ok_or_else(|| RagError::InvalidInput(format!("Invalid base path: {}", base_path)))?;
+    let file_stem = path.file_stem().ok_or_else(|| RagError::InvalidInput(format!("Invalid filename: {}", base_path)))?;
     // Convert OsStr to String, which file_dump expects for filename base
-    let filename = file_stem.to_str().ok_or_else(|| anyhow::anyhow!("Invalid UTF-8 filename"))?;
+    let filename = file_stem.to_str().ok_or_else(|| RagError::InvalidInput(format!("Invalid UTF-8 filename: {}", base_path)))?;
 
     // Create directory if it doesn't exist
-    std::fs::create_dir_all(parent)?;
+    std::fs::create_dir_all(parent).map_err(|e| io_error(parent.display(), e))?;
     
     // hnsw_rs 0.3 file_dump takes (directory, filename_base)
-    index.file_dump(parent, filename)?;
+    let dumped = index.file_dump(parent, filename).map_err(|e| io_error(base_path, e))?;
+    
+    let built_with = BUILT_WITH.read().unwrap().get(&tenant_id).copied();
+    if let Some(config) = built_with {
+        let custom = CUSTOM_BUILD_CONFIGS.read().unwrap().get(&tenant_id) == Some(&config);
+        let manifest = IndexManifest {
+            config,
+            custom,
+            point_count: index.get_nb_point(),
+            quantization: index.quantization(),
+            metric: index.metric(),
+        };
+        write_manifest(parent, &dumped, &manifest)?;
+    }
     
     info!("[hnsw] Index saved successfully");
     Ok(())
@@ -136,13 +722,15 @@ pub fn save_hnsw_index(base_path: &str) -> anyhow::Result<()> {
 /// Load HNSW index from disk. 
 /// 
 /// Returns true if the index was successfully loaded into memory.
-pub fn load_hnsw_index(base_path: &str) -> anyhow::Result<bool> {
+pub fn load_hnsw_index(base_path: &str) -> Result<bool, RagError> {
+    let tenant_id = active_tenant();
+    let base_path = &tenant_index_path(base_path, &tenant_id);
     // Check if the primary data file exists to avoid unnecessary log noise
     // hnsw_rs adds .hnsw.data and .hnsw.graph to the base name (which is the file stem)
     let path = Path::new(base_path);
-    let parent = path.parent().ok_or_else(|| anyhow::anyhow!("Invalid base path"))?;
-    let file_stem = path.file_stem().ok_or_else(|| anyhow::anyhow!("Invalid filename"))?;
-    let filename = file_stem.to_str().ok_or_else(|| anyhow::anyhow!("Invalid UTF-8 filename"))?;
+    let parent = path.parent().ok_or_else(|| RagError::InvalidInput(format!("Invalid base path: {}", base_path)))?;
+    let file_stem = path.file_stem().ok_or_else(|| RagError::InvalidInput(format!("Invalid filename: {}", base_path)))?;
+    let filename = file_stem.to_str().ok_or_else(|| RagError::InvalidInput(format!("Invalid UTF-8 filename: {}", base_path)))?;
 
     let data_path = parent.join(format!("{}.hnsw.data", filename));
     
@@ -153,33 +741,132 @@ pub fn load_hnsw_index(base_path: &str) -> anyhow::Result<bool> {
 
     info!("[hnsw] Loading index from {}", base_path);
 
-    // hnsw_rs 0.3 load_hnsw is a method of HnswIo
-    let hnswio = HnswIo::new(parent, filename);
-    
-    // We need to extend the lifetime of hnswio to 'static because HNSW_INDEX is static.
-    // Hnsw<'b> borrows from HnswIo if mmap is used (or is tied to it by signature).
-    // By leaking the Box, we get a &'static mut HnswIo, allowing load_hnsw to return Hnsw<'static>.
-    let hnswio = Box::leak(Box::new(hnswio));
-
-    // hnsw_rs load_hnsw reconstructs the index from files
-    // DistCosine must match the one used during build
-    match hnswio.load_hnsw::<f32, DistCosine>() {
-        Ok(hnsw) => {
-            let mut index_guard = HNSW_INDEX.write().unwrap();
-            *index_guard = Some(hnsw);
+    match load_tenant_hnsw_index(&tenant_id, parent, filename) {
+        Ok(()) => {
+            // Older saves have no manifest; their parameters stay unknown.
+            if let Some(manifest) = read_manifest(parent, filename) {
+                debug!("[hnsw] Manifest: {:?}", manifest);
+                if manifest.custom {
+                    let _ = set_tenant_build_config(&tenant_id, Some(manifest.config));
+                }
+                BUILT_WITH.write().unwrap().insert(tenant_id.clone(), manifest.config);
+            }
             info!("[hnsw] Index loaded successfully");
             Ok(true)
         }
         Err(e) => {
-            // If loading fails, we technically leaked hnswio memory, but this happens rarely (only on error)
-            // and it's a small struct (path + options), so it's acceptable for this use case.
-            // Ideally we would reconstruct the box and drop it, but error handling complexity outweighs benefit here.
             warn!("[hnsw] Failed to load index: {}. Rebuild required.", e);
             Ok(false)
         }
     }
 }
 
+fn manifest_path(dir: &Path, basename: &str) -> std::path::PathBuf {
+    dir.join(format!("{}.hnsw.meta.json", basename))
+}
+
+fn write_manifest(dir: &Path, basename: &str, manifest: &IndexManifest) -> Result<(), RagError> {
+    let path = manifest_path(dir, basename);
+    let json = serde_json::to_vec_pretty(manifest).map_err(|e| io_error(path.display(), e))?;
+    std::fs::write(&path, json).map_err(|e| io_error(path.display(), e))
+}
+
+fn read_manifest(dir: &Path, basename: &str) -> Option<IndexManifest> {
+    let path = manifest_path(dir, basename);
+    let bytes = std::fs::read(&path).ok()?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| warn!("[hnsw] Ignoring unreadable manifest {}: {}", path.display(), e))
+        .ok()
+}
+
+/// Load `<dir>/<basename>.hnsw.{graph,data}` as `tenant_id`'s index.
+/// Data is read into memory, so the files may be removed afterwards.
+pub(crate) fn load_tenant_hnsw_index(tenant_id: &str, dir: &Path, basename: &str) -> Result<(), RagError> {
+    let (quantization, metric) = dumped_graph_kind(dir, basename)?;
+    // hnsw_rs 0.3 load_hnsw is a method of HnswIo, and the loaded graph
+    // borrows from it; HNSW_INDEX is static, so the HnswIo is leaked to get
+    // a 'static graph. The dump's description picks the distance and
+    // element types up front, so exactly one HnswIo is leaked per load.
+    let hnswio = Box::leak(Box::new(HnswIo::new(dir, basename)));
+    let load_error = |e: anyhow::Error| io_error(dir.join(basename).display(), e);
+    let hnsw = match (quantization, metric) {
+        (Quantization::Int8, _) => TenantGraph::Int8(hnswio.load_hnsw::<i8, DistCosineI8>().map_err(load_error)?),
+        (Quantization::None, DistanceMetric::Cosine) => {
+            TenantGraph::F32(hnswio.load_hnsw::<f32, DistCosine>().map_err(load_error)?)
+        }
+        (Quantization::None, DistanceMetric::DotProduct) => {
+            TenantGraph::F32Dot(hnswio.load_hnsw::<f32, DistInnerProduct>().map_err(load_error)?)
+        }
+        (Quantization::None, DistanceMetric::L2) => {
+            TenantGraph::F32L2(hnswio.load_hnsw::<f32, DistL2>().map_err(load_error)?)
+        }
+    };
+    let mut index_guard = HNSW_INDEX.write().unwrap();
+    index_guard.insert(tenant_id.to_string(), hnsw);
+    drop(index_guard);
+    clear_tombstones(tenant_id);
+    Ok(())
+}
+
+/// Vector representation and metric of the graph dumped as
+/// `<dir>/<basename>.hnsw.graph`, from the distance and element type
+/// names hnsw_rs records in its header.
+fn dumped_graph_kind(dir: &Path, basename: &str) -> Result<(Quantization, DistanceMetric), RagError> {
+    let path = dir.join(format!("{}.hnsw.graph", basename));
+    let file = std::fs::File::open(&path).map_err(|e| io_error(path.display(), e))?;
+    let description = hnsw_rs::hnswio::load_description(&mut std::io::BufReader::new(file))
+        .map_err(|e| io_error(path.display(), e))?;
+    let distance = description.distname.rsplit("::").next().unwrap_or_default();
+    match distance {
+        "DistCosineI8" => Ok((Quantization::Int8, DistanceMetric::Cosine)),
+        "DistCosine" => Ok((Quantization::None, DistanceMetric::Cosine)),
+        "DistInnerProduct" => Ok((Quantization::None, DistanceMetric::DotProduct)),
+        "DistL2" => Ok((Quantization::None, DistanceMetric::L2)),
+        other => Err(io_error(path.display(), format!("unsupported distance {}", other))),
+    }
+}
+
+/// Representation and metric of `tenant_id`'s loaded graph.
+pub(crate) fn hnsw_graph_kind(tenant_id: &str) -> Option<(Quantization, DistanceMetric)> {
+    HNSW_INDEX
+        .read()
+        .unwrap()
+        .get(tenant_id)
+        .map(|graph| (graph.quantization(), graph.metric()))
+}
+
+/// Dump `tenant_id`'s index as `<dir>/<basename>.hnsw.{graph,data}`.
+///
+/// Returns the basename hnsw_rs actually used, or `None` when the tenant
+/// has no (or an empty) index.
+pub(crate) fn dump_tenant_hnsw_index(tenant_id: &str, dir: &Path, basename: &str) -> Result<Option<String>, RagError> {
+    let index_guard = HNSW_INDEX.read().unwrap();
+    match index_guard.get(tenant_id) {
+        Some(index) if index.get_nb_point() > 0 => {
+            std::fs::create_dir_all(dir).map_err(|e| io_error(dir.display(), e))?;
+            let dumped = index.file_dump(dir, basename).map_err(|e| io_error(dir.join(basename).display(), e))?;
+            Ok(Some(dumped))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Tenants with an index in memory.
+pub(crate) fn hnsw_tenants() -> Vec<String> {
+    HNSW_INDEX.read().unwrap().keys().cloned().collect()
+}
+
+/// Drop every tenant's index from memory.
+pub(crate) fn clear_all_hnsw_indexes() {
+    HNSW_INDEX.write().unwrap().clear();
+    BUILT_WITH.write().unwrap().clear();
+    TOMBSTONES.write().unwrap().clear();
+    clear_all_quantized_indexes();
+    clear_all_binary_indexes();
+    clear_all_named_indexes();
+    info!("[hnsw] All indexes cleared");
+}
+
 /// HNSW search result containing doc ID and distance.
 #[derive(Debug)]
 pub struct HnswSearchResult {
@@ -193,31 +880,90 @@ pub struct HnswSearchResult {
 /// - Higher ef_search = better recall but slower
 /// - Lower ef_search = faster but may miss relevant results
 /// 
-/// Current tuning targets ~95% recall for most use cases.
-pub fn search_hnsw(query_embedding: Vec<f32>, top_k: usize) -> anyhow::Result<Vec<HnswSearchResult>> {
-    debug!("[hnsw] Starting search, top_k: {}", top_k);
+/// Current tuning targets ~95% recall for most use cases; set a fixed
+/// value with `set_hnsw_config` or per call with `search_hnsw_with_ef`.
+pub fn search_hnsw(query_embedding: Vec<f32>, top_k: usize) -> Result<Vec<HnswSearchResult>, RagError> {
+    search_hnsw_with_ef(query_embedding, top_k, None)
+}
+
+/// `search_hnsw` with an `ef_search` override for this call only (`None`
+/// uses the configured or adaptive value).
+pub fn search_hnsw_with_ef(
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    ef_search: Option<u32>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    search_hnsw_in_tenant(&active_tenant(), query_embedding, top_k, ef_search)
+}
+
+/// `search_hnsw` among `allowed_ids` only, e.g. the chunks of a few
+/// sources. The graph is still traversed through other points, so this
+/// stays sublinear where an exact scan of the allowed chunks would not;
+/// recall drops when the allowed set is a small share of the index.
+pub fn search_hnsw_filtered(
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    allowed_ids: Vec<i64>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    let allowed: HashSet<i64> = allowed_ids.into_iter().collect();
+    search_hnsw_in_tenant_filtered(&active_tenant(), query_embedding, top_k, None, Some(&allowed))
+}
+
+pub(crate) fn search_hnsw_in_tenant(
+    tenant_id: &str,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    ef_search: Option<u32>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    search_hnsw_in_tenant_filtered(tenant_id, query_embedding, top_k, ef_search, None)
+}
+
+pub(crate) fn search_hnsw_in_tenant_filtered(
+    tenant_id: &str,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    ef_search: Option<u32>,
+    allowed: Option<&HashSet<i64>>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    debug!("[hnsw] Starting search in tenant {}, top_k: {}", tenant_id, top_k);
+    
+    // Deleted points still in the graph are over-fetched, then dropped.
+    let deleted = TOMBSTONES.read().unwrap().get(tenant_id).cloned().unwrap_or_default();
+    let without_deleted = |results: Vec<HnswSearchResult>| -> Vec<HnswSearchResult> {
+        results.into_iter().filter(|r| !deleted.contains(&r.id)).take(top_k).collect()
+    };
+    let fetch_k = top_k + deleted.len();
     
     let index_guard = HNSW_INDEX.read().unwrap();
-    let index = index_guard.as_ref()
-        .ok_or_else(|| anyhow::anyhow!("HNSW index not initialized"))?;
+    let Some(index) = index_guard.get(tenant_id) else {
+        // Low-memory mode keeps a quantized index instead of the graph.
+        return search_quantized(tenant_id, &query_embedding, fetch_k, allowed)
+            .map(without_deleted)
+            .ok_or_else(|| RagError::NotInitialized(format!("HNSW index not initialized for tenant {}", tenant_id)));
+    };
     
     // ef_search should be >= top_k, higher values improve recall
     // Rule of thumb: ef_search = max(100, top_k * 5) for ~95% recall
-    let ef_search = core::cmp::max(100, top_k * 5);
+    let ef_search = match ef_search.or_else(|| CUSTOM_EF_SEARCH.read().unwrap().get(tenant_id).copied()) {
+        Some(ef) => (ef as usize).max(fetch_k),
+        None => core::cmp::max(100, fetch_k * 5),
+    };
     
     #[cfg(debug_assertions)]
     println!("[HNSW] Search: top_k={}, ef_search={} (recall target: ~95%)", top_k, ef_search);
     
     debug!("[hnsw] Using ef_search={}", ef_search);
     
-    let neighbors = index.search(&query_embedding, top_k, ef_search);
+    let in_allowed = |id: &DataId| allowed.is_none_or(|ids| ids.contains(&(*id as i64)));
+    let filter: Option<&dyn FilterT> = allowed.map(|_| &in_allowed as &dyn FilterT);
+    let neighbors = index.search(&query_embedding, fetch_k, ef_search, filter);
     
-    let results: Vec<HnswSearchResult> = neighbors.iter()
+    let results = without_deleted(neighbors.iter()
         .map(|neighbor| HnswSearchResult {
             id: neighbor.d_id as i64,
             distance: neighbor.distance,
         })
-        .collect();
+        .collect());
     
     #[cfg(debug_assertions)]
     println!("[HNSW] Found {} results", results.len());
@@ -226,17 +972,127 @@ pub fn search_hnsw(query_embedding: Vec<f32>, top_k: usize) -> anyhow::Result<Ve
     Ok(results)
 }
 
-/// Check if HNSW index is loaded.
+/// Insert one point into the active tenant's live index, where it is
+/// searchable immediately without a rebuild.
+///
+/// Returns false when no index is loaded yet (build one first). `id` must
+/// not already be in the index; hnsw_rs would keep both points.
+pub fn hnsw_insert(id: i64, embedding: Vec<f32>) -> Result<bool, RagError> {
+    Ok(insert_into_tenant_index(&active_tenant(), vec![(id, embedding)], Progress::NONE)?.is_some())
+}
+
+/// Insert `points` into `tenant_id`'s graph (or quantized index in
+/// low-memory mode). Returns how many were inserted, or `None` when the
+/// tenant has no index to insert into.
+pub(crate) fn insert_into_tenant_index(
+    tenant_id: &str,
+    points: Vec<(i64, Vec<f32>)>,
+    progress: Progress,
+) -> Result<Option<usize>, RagError> {
+    // A reused id would bring its deleted point back; only a rebuild can
+    // replace it.
+    let reused = TOMBSTONES
+        .read()
+        .unwrap()
+        .get(tenant_id)
+        .is_some_and(|deleted| points.iter().any(|(id, _)| deleted.contains(id)));
+    if reused {
+        info!("[hnsw] Inserted ids were deleted before, tenant {} needs a rebuild", tenant_id);
+        return Ok(None);
+    }
+    let index_guard = HNSW_INDEX.read().unwrap();
+    let Some(index) = index_guard.get(tenant_id) else {
+        return Ok(insert_quantized(tenant_id, &points));
+    };
+    let dimension = index.dimension();
+    if let Some((id, embedding)) = points.iter().find(|(_, e)| dimension > 0 && e.len() != dimension) {
+        return Err(RagError::InvalidInput(format!(
+            "Point {} has dimension {}, index has {}", id, embedding.len(), dimension
+        )));
+    }
+    let count = points.len();
+    // Insertion takes the graph's own locks, so a shared guard is enough.
+    catch_panic("HNSW insert", || {
+        for (i, (id, embedding)) in points.iter().enumerate() {
+            progress.report(PHASE_INDEX, i, count);
+            index.insert(embedding, *id);
+        }
+        progress.report(PHASE_INDEX, count, count);
+    })?;
+    debug!("[hnsw] Inserted {} points into index of tenant {}", count, tenant_id);
+    Ok(Some(count))
+}
+
+/// Hide deleted `ids` from `tenant_id`'s searches until its next build.
+pub(crate) fn add_tombstones(tenant_id: &str, ids: &[i64]) {
+    let loaded = HNSW_INDEX.read().unwrap().contains_key(tenant_id) || has_quantized_index(tenant_id);
+    if !loaded || ids.is_empty() {
+        return;
+    }
+    let mut tombstones = TOMBSTONES.write().unwrap();
+    let deleted = tombstones.entry(tenant_id.to_string()).or_default();
+    deleted.extend(ids);
+    debug!("[hnsw] Tenant {} has {} deleted points", tenant_id, deleted.len());
+}
+
+/// Deleted points still held by the active tenant's index.
+pub(crate) fn tombstone_count() -> usize {
+    TOMBSTONES.read().unwrap().get(&active_tenant()).map_or(0, HashSet::len)
+}
+
+fn clear_tombstones(tenant_id: &str) {
+    TOMBSTONES.write().unwrap().remove(tenant_id);
+}
+
+/// Check if HNSW index is loaded (for the active tenant).
 pub fn is_hnsw_index_loaded() -> bool {
+    tenant_hnsw_index_loaded(&active_tenant())
+}
+
+pub(crate) fn tenant_hnsw_index_loaded(tenant_id: &str) -> bool {
     let index_guard = HNSW_INDEX.read().unwrap();
-    index_guard.is_some()
+    index_guard.contains_key(tenant_id) || has_quantized_index(tenant_id)
 }
 
-/// Clear HNSW index from memory.
+/// Clear HNSW index from memory (for the active tenant).
 pub fn clear_hnsw_index() {
+    drop_tenant_hnsw_index(&active_tenant());
+}
+
+/// Points in the active tenant's index, or `None` when none is loaded.
+pub(crate) fn hnsw_point_count() -> Option<usize> {
+    tenant_hnsw_point_count(&active_tenant())
+}
+
+/// Points in `tenant_id`'s index, or `None` when none is loaded.
+pub(crate) fn tenant_hnsw_point_count(tenant_id: &str) -> Option<usize> {
+    let index_guard = HNSW_INDEX.read().unwrap();
+    index_guard
+        .get(tenant_id)
+        .map(|index| index.get_nb_point())
+        .or_else(|| quantized_point_count(tenant_id))
+}
+
+/// Ids of the points in `tenant_id`'s index that are not deleted, or
+/// `None` when none is loaded.
+pub(crate) fn hnsw_point_ids(tenant_id: &str) -> Option<Vec<i64>> {
+    let deleted = TOMBSTONES.read().unwrap().get(tenant_id).cloned().unwrap_or_default();
+    let ids = match HNSW_INDEX.read().unwrap().get(tenant_id) {
+        Some(index) => index.point_ids(),
+        None => quantized_ids(tenant_id)?,
+    };
+    Some(ids.into_iter().filter(|id| !deleted.contains(id)).collect())
+}
+
+pub(crate) fn drop_tenant_hnsw_index(tenant_id: &str) {
     let mut index_guard = HNSW_INDEX.write().unwrap();
-    *index_guard = None;
-    info!("[hnsw] Index cleared");
+    index_guard.remove(tenant_id);
+    BUILT_WITH.write().unwrap().remove(tenant_id);
+    drop(index_guard);
+    clear_tombstones(tenant_id);
+    drop_quantized_index(tenant_id);
+    drop_binary_index(tenant_id);
+    info!("[hnsw] Index cleared for tenant {}", tenant_id);
 }
 
 #[cfg(test)]
@@ -253,6 +1109,159 @@ mod tests {
         }).collect()
     }
 
+    #[test]
+    fn test_custom_build_config_and_manifest() {
+        let tenant = "hnsw_config_test";
+        let bad = HnswBuildConfig { m: 1, max_layer: 16, ef_construction: 100 };
+        assert!(set_tenant_build_config(tenant, Some(bad)).is_err());
+
+        let config = HnswBuildConfig { m: 8, max_layer: 4, ef_construction: 40 };
+        set_tenant_build_config(tenant, Some(config)).unwrap();
+        let points: Vec<(i64, Vec<f32>)> = (0..20).map(|i| (i, make_random_embedding(i as u64, 8))).collect();
+        let query = points[3].1.clone();
+        build_hnsw_index_for_tenant(tenant, points).unwrap();
+        assert_eq!(BUILT_WITH.read().unwrap().get(tenant), Some(&config));
+
+        // A configured ef_search below the fetch size is raised to it.
+        CUSTOM_EF_SEARCH.write().unwrap().insert(tenant.to_string(), 1);
+        assert_eq!(search_hnsw_in_tenant(tenant, query.clone(), 5, None).unwrap().len(), 5);
+        assert_eq!(search_hnsw_in_tenant(tenant, query, 1, Some(64)).unwrap()[0].id, 3);
+        CUSTOM_EF_SEARCH.write().unwrap().remove(tenant);
+
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = IndexManifest { config, custom: true, point_count: 20, quantization: Quantization::None, metric: DistanceMetric::Cosine };
+        write_manifest(dir.path(), "idx", &manifest).unwrap();
+        let loaded = read_manifest(dir.path(), "idx").unwrap();
+        assert_eq!((loaded.config, loaded.custom, loaded.point_count), (config, true, 20));
+        assert!(read_manifest(dir.path(), "missing").is_none());
+
+        drop_tenant_hnsw_index(tenant);
+        assert!(!BUILT_WITH.read().unwrap().contains_key(tenant));
+        set_tenant_build_config(tenant, None).unwrap();
+    }
+
+    #[test]
+    fn test_insert_into_live_index() {
+        let tenant = "hnsw_insert_test";
+        assert_eq!(insert_into_tenant_index(tenant, vec![(0, make_random_embedding(0, 16))], Progress::NONE).unwrap(), None);
+
+        let points: Vec<(i64, Vec<f32>)> = (0..50).map(|i| (i, make_random_embedding(i as u64, 16))).collect();
+        build_hnsw_index_for_tenant(tenant, points).unwrap();
+        let added = make_random_embedding(999, 16);
+        assert_eq!(insert_into_tenant_index(tenant, vec![(999, added.clone())], Progress::NONE).unwrap(), Some(1));
+        let results = search_hnsw_in_tenant(tenant, added, 1, None).unwrap();
+        assert_eq!(results[0].id, 999);
+
+        let wrong_dimension = vec![(1000, make_random_embedding(1000, 8))];
+        assert!(insert_into_tenant_index(tenant, wrong_dimension, Progress::NONE).is_err());
+        drop_tenant_hnsw_index(tenant);
+    }
+
+    #[test]
+    fn test_tombstoned_ids_are_not_returned() {
+        let tenant = "hnsw_tombstone_test";
+        let points: Vec<(i64, Vec<f32>)> = (0..50).map(|i| (i, make_random_embedding(i as u64, 16))).collect();
+        build_hnsw_index_for_tenant(tenant, points.clone()).unwrap();
+        let query = make_random_embedding(0, 16);
+        assert_eq!(search_hnsw_in_tenant(tenant, query.clone(), 3, None).unwrap()[0].id, 0);
+
+        add_tombstones(tenant, &[0, 1]);
+        let results = search_hnsw_in_tenant(tenant, query.clone(), 3, None).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.id > 1));
+        let mut live = hnsw_point_ids(tenant).unwrap();
+        live.sort_unstable();
+        assert_eq!(live, (2..50).collect::<Vec<i64>>());
+        // Reusing a deleted id requires a rebuild
+        assert_eq!(insert_into_tenant_index(tenant, vec![(1, query.clone())], Progress::NONE).unwrap(), None);
+
+        build_hnsw_index_for_tenant(tenant, points).unwrap();
+        assert_eq!(search_hnsw_in_tenant(tenant, query, 3, None).unwrap()[0].id, 0);
+        drop_tenant_hnsw_index(tenant);
+    }
+
+    #[test]
+    fn test_int8_graph_search_and_reload() {
+        assert_eq!(DistCosineI8.eval(&[3, 4], &[6, 8]), 0.0);
+        assert!((DistCosineI8.eval(&[1, 0], &[0, 1]) - 1.0).abs() < 1e-6);
+
+        let tenant = "hnsw_int8_test";
+        let points: Vec<(i64, Vec<f32>)> = (0..100).map(|i| (i, make_random_embedding(i as u64, 32))).collect();
+        let config = HnswBuildConfig::adaptive(points.len());
+        let (graph, _) = build_tenant_graph(points, config, None, Quantization::Int8, DistanceMetric::Cosine, Progress::NONE).unwrap();
+        assert_eq!(graph.quantization(), Quantization::Int8);
+        HNSW_INDEX.write().unwrap().insert(tenant.to_string(), graph);
+        let query = make_random_embedding(7, 32);
+        assert_eq!(search_hnsw_in_tenant(tenant, query.clone(), 1, None).unwrap()[0].id, 7);
+
+        let dir = tempfile::tempdir().unwrap();
+        let basename = dump_tenant_hnsw_index(tenant, dir.path(), tenant).unwrap().unwrap();
+        drop_tenant_hnsw_index(tenant);
+        load_tenant_hnsw_index(tenant, dir.path(), &basename).unwrap();
+        assert_eq!(HNSW_INDEX.read().unwrap().get(tenant).map(|g| g.quantization()), Some(Quantization::Int8));
+        assert_eq!(search_hnsw_in_tenant(tenant, query, 1, None).unwrap()[0].id, 7);
+        drop_tenant_hnsw_index(tenant);
+    }
+
+    #[test]
+    fn test_dot_product_graph_ranks_by_inner_product_and_reloads() {
+        for dot in [-2.0f32, 0.0, 0.5, 3.0] {
+            assert!((inner_product_from_graph(DistInnerProduct.eval(&[dot], &[1.0])) - dot).abs() < 1e-5);
+        }
+        assert!(set_index_config(IndexConfig { quantization: Quantization::Int8, metric: DistanceMetric::L2 }).is_err());
+
+        let tenant = "hnsw_dot_test";
+        // Same direction, growing magnitude: cosine ties them, dot product doesn't.
+        let points: Vec<(i64, Vec<f32>)> = (1..=20).map(|i| (i, vec![i as f32, 0.5])).collect();
+        let config = HnswBuildConfig::adaptive(points.len());
+        let (graph, _) = build_tenant_graph(points, config, None, Quantization::None, DistanceMetric::DotProduct, Progress::NONE).unwrap();
+        assert_eq!(graph.metric(), DistanceMetric::DotProduct);
+        HNSW_INDEX.write().unwrap().insert(tenant.to_string(), graph);
+        let results = search_hnsw_in_tenant(tenant, vec![1.0, 0.0], 2, None).unwrap();
+        assert_eq!(results.iter().map(|r| r.id).collect::<Vec<_>>(), vec![20, 19]);
+        assert!((results[0].distance - (1.0 - 20.0)).abs() < 1e-3);
+
+        let dir = tempfile::tempdir().unwrap();
+        let basename = dump_tenant_hnsw_index(tenant, dir.path(), tenant).unwrap().unwrap();
+        drop_tenant_hnsw_index(tenant);
+        load_tenant_hnsw_index(tenant, dir.path(), &basename).unwrap();
+        assert_eq!(HNSW_INDEX.read().unwrap().get(tenant).map(|g| g.metric()), Some(DistanceMetric::DotProduct));
+        assert_eq!(search_hnsw_in_tenant(tenant, vec![1.0, 0.0], 1, None).unwrap()[0].id, 20);
+        drop_tenant_hnsw_index(tenant);
+    }
+
+    #[test]
+    fn test_filtered_search_returns_allowed_ids_only() {
+        let tenant = "hnsw_filter_test";
+        let points: Vec<(i64, Vec<f32>)> = (0..200).map(|i| (i, make_random_embedding(i as u64, 16))).collect();
+        build_hnsw_index_for_tenant(tenant, points).unwrap();
+        let allowed: HashSet<i64> = (0..200).filter(|id| id % 2 == 1).collect();
+        let query = make_random_embedding(10, 16);
+        let results = search_hnsw_in_tenant_filtered(tenant, query.clone(), 5, None, Some(&allowed)).unwrap();
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|r| allowed.contains(&r.id)));
+        assert_eq!(search_hnsw_in_tenant(tenant, query, 1, None).unwrap()[0].id, 10);
+        drop_tenant_hnsw_index(tenant);
+    }
+
+    #[test]
+    fn test_seeded_build_is_reproducible() {
+        let points: Vec<(i64, Vec<f32>)> = (0..300).map(|i| (i, make_random_embedding(i as u64 * 7, 32))).collect();
+        let mut reversed = points.clone();
+        reversed.reverse();
+        let config = HnswBuildConfig::adaptive(points.len());
+        let (a, used) = build_graph(points, config, Some(42), "test", Progress::NONE).unwrap();
+        let (b, _) = build_graph(reversed, config, Some(42), "test", Progress::NONE).unwrap();
+        assert_eq!(used.max_layer, 1);
+        for q in 0..20 {
+            let query = make_random_embedding(q * 13 + 5, 32);
+            let ids = |h: &Hnsw<'static, f32, DistCosine>| {
+                h.search(&query, 10, 16).iter().map(|n| (n.d_id, n.distance)).collect::<Vec<_>>()
+            };
+            assert_eq!(ids(&a), ids(&b));
+        }
+    }
+
     #[test]
     fn test_build_empty_index() {
         let result = build_hnsw_index(vec![]);
@@ -17,18 +17,67 @@
 //! Hybrid Search: Vector + Keyword with Reciprocal Rank Fusion.
 
 use log::{debug, info};
-use std::collections::{HashMap, HashSet};
-
-use crate::api::bm25_search::{bm25_search, tokenize_for_bm25, Bm25SearchResult};
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use rusqlite::{params, Connection};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::api::bm25_search::{
+    bm25_boolean_constraint, bm25_search, get_bm25_config, tokenize_for_bm25, Bm25SearchResult,
+};
+use crate::api::content_crypto::read_content;
 use crate::api::db_pool::get_connection;
-use crate::api::error::RagError;
-use crate::api::hnsw_index::{is_hnsw_index_loaded, search_hnsw, HnswSearchResult};
-use ndarray::Array1;
-
-#[derive(Debug, Clone)]
+use crate::api::embedding_storage::{blob_distance, decode_embedding};
+use crate::api::entity_extraction::entity_filter_condition;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::{
+    get_index_config, is_hnsw_index_loaded, search_hnsw_in_tenant_filtered, search_hnsw_with_ef,
+    HnswSearchResult,
+};
+use crate::api::intent_presets::{get_retrieval_preset, RetrievalPreset};
+use crate::api::maintenance::query_guard;
+use crate::api::memory_budget::memory_profile;
+use crate::api::metrics::record_search;
+use crate::api::mmr::{load_embeddings, mmr_select, validate_lambda, MMR_FETCH_MULTIPLIER};
+use crate::api::named_bm25::search_bm25_named_constrained;
+use crate::api::named_index::search_hnsw_named_with_ef;
+use crate::api::pagination::{
+    decode_cursor, encode_cursor, merge_deeper, RankingCache, PAGES_PER_RANKING,
+};
+use crate::api::panic_report::payload_message;
+use crate::api::query_parser::scoring_query_text;
+use crate::api::related_chunks::{expand_with_related, ExpandedSearchResult, ExpansionConfig};
+use crate::api::retrieval_state::record_hybrid_retrieval;
+use crate::api::search_analytics::record_search_event;
+use crate::api::search_trace::{SearchDiagnostics, SearchTrace, SearchTracer};
+use crate::api::snippets::{build_snippet, SearchSnippet};
+use crate::api::source_grouping::{group_by_source, SourceGroup, GROUP_FETCH_MULTIPLIER};
+use crate::api::source_index::search_source_indexes;
+use crate::api::source_rag::split_chunk_type;
+use crate::api::synonyms::expand_query;
+use crate::api::tenant::{active_tenant, tenant_condition};
+use crate::frb_generated::StreamSink;
+
+#[derive(Debug, Clone, Default)]
 pub struct SearchFilter {
     pub source_ids: Option<Vec<i64>>,
     pub metadata_like: Option<String>, // SQL LIKE pattern
+    /// Only chunks mentioning this entity value (e.g. "2023-09"; see `entity_extraction`).
+    pub entity: Option<String>,
+    /// Only chunks in this language (e.g. "ko"; see `detect_language`).
+    pub language: Option<String>,
+    /// Only chunks of these types ("definition", "code", ...).
+    pub chunk_types: Option<Vec<String>>,
+    /// Only chunks whose source metadata JSON has every `(key, value)`
+    /// pair as a top-level field; values compare as text.
+    pub metadata_equals: Option<Vec<(String, String)>>,
+    /// Only chunks of sources created at or after this time (unix seconds).
+    pub created_after: Option<i64>,
+    /// Only chunks of sources created before this time (unix seconds).
+    pub created_before: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +90,71 @@ pub struct HybridSearchResult {
     pub source_id: i64,
     pub metadata: Option<String>,
     pub chunk_index: u32,
+    /// "general", "definition", "code", ... ("general" for Simple RAG docs).
+    pub chunk_type: String,
+    /// Section breadcrumb such as "Installation > Windows", when the
+    /// chunker recorded one.
+    pub header_path: Option<String>,
+    /// Query terms (as BM25 tokens) found in `content`.
+    pub matched_terms: Vec<String>,
+    /// Best-matching sentence window with highlighted query terms.
+    pub snippet: Option<SearchSnippet>,
+}
+
+/// Hybrid search results with an optional per-stage trace.
+#[derive(Debug, Clone)]
+pub struct TracedHybridSearch {
+    pub results: Vec<HybridSearchResult>,
+    pub trace: Option<SearchTrace>,
+}
+
+/// A fused ranking entry before its content has been fetched.
+#[derive(Debug, Clone)]
+pub struct RankedHit {
+    pub doc_id: i64,
+    pub score: f64,
+    pub vector_rank: u32,
+    pub bm25_rank: u32,
+}
+
+/// Events emitted by `search_hybrid_stream`, in order: one `Ranking`,
+/// zero or more `Results`, then `Done`.
+#[derive(Debug, Clone)]
+pub enum HybridSearchEvent {
+    /// The fused ranking, best first.
+    Ranking(Vec<RankedHit>),
+    /// The next hydrated results, in ranking order.
+    Results(Vec<HybridSearchResult>),
+    /// All results have been delivered.
+    Done,
+}
+
+/// One result of a paired `/compare` retrieval.
+#[derive(Debug, Clone)]
+pub struct CompareSearchResult {
+    /// 0 for the left subject, 1 for the right subject.
+    pub subject_index: u32,
+    pub result: HybridSearchResult,
+}
+
+/// How per-modality scores are put on one scale before blending.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreNormalization {
+    /// Rescale each list to 0-1 by its own best and worst score.
+    MinMax,
+    /// Standardize each list, then map through a logistic to 0-1.
+    ZScore,
+}
+
+/// How vector and BM25 candidates are combined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FusionStrategy {
+    /// Reciprocal rank fusion: only ranks count (the default).
+    Rrf,
+    /// Blend normalized vector similarities and BM25 scores with the
+    /// configured weights, so a very confident match in one list stands
+    /// out. A candidate missing from a list gets that list's lowest value.
+    WeightedScore { normalization: ScoreNormalization },
 }
 
 #[derive(Debug, Clone)]
@@ -48,6 +162,18 @@ pub struct RrfConfig {
     pub k: u32,
     pub vector_weight: f64,
     pub bm25_weight: f64,
+    /// Score multipliers by chunk type, applied after fusion (e.g.
+    /// `{"definition": 1.5, "code": 0.5}`); unlisted types keep 1.0.
+    pub type_boosts: HashMap<String, f64>,
+    /// Half-life of the recency boost in days, by when the source was
+    /// last updated (its `created_at` if never); `None` disables it.
+    /// Chunks without a date get the full decay.
+    pub recency_half_life_days: Option<f64>,
+    /// Share of the score that decays with age (0.0-1.0): a chunk one
+    /// half-life old keeps `1 - recency_weight / 2` of its score.
+    pub recency_weight: f64,
+    /// `k` only applies to `FusionStrategy::Rrf`.
+    pub fusion: FusionStrategy,
 }
 
 impl Default for RrfConfig {
@@ -56,56 +182,845 @@ impl Default for RrfConfig {
             k: 60,
             vector_weight: 0.5,
             bm25_weight: 0.5,
+            type_boosts: HashMap::new(),
+            recency_half_life_days: None,
+            recency_weight: 0.3,
+            fusion: FusionStrategy::Rrf,
+        }
+    }
+}
+
+fn language_condition(language: &str) -> String {
+    format!("c.language = '{}'", language.replace('\'', "''"))
+}
+
+fn sql_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// `s.metadata.key = value`; sources without valid JSON metadata never
+/// match (json_extract would fail the whole query on them). JSON booleans
+/// compare as `true`/`false` rather than the 1/0 json_extract returns.
+fn metadata_equals_condition(key: &str, value: &str) -> String {
+    let path = sql_quote(&format!("$.\"{}\"", key.replace('"', "")));
+    format!(
+        "(CASE WHEN json_valid(s.metadata) THEN
+            CASE json_type(s.metadata, {path})
+                WHEN 'true' THEN 'true'
+                WHEN 'false' THEN 'false'
+                ELSE CAST(json_extract(s.metadata, {path}) AS TEXT)
+            END
+         END) = {}",
+        sql_quote(value)
+    )
+}
+
+/// SQL conditions for a filter, over `chunks c LEFT JOIN sources s`.
+pub(crate) fn filter_sql_conditions(filter: &SearchFilter) -> Vec<String> {
+    let mut conditions = Vec::new();
+
+    if let Some(sids) = &filter.source_ids {
+        if !sids.is_empty() {
+            conditions.push(format!("c.source_id IN ({})", id_list(sids)));
         }
     }
+    conditions.extend(source_sql_conditions(filter));
+    conditions.extend(chunk_sql_conditions(filter));
+    conditions
+}
+
+fn id_list(ids: &[i64]) -> String {
+    ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// The filter's conditions on the source row (`s`), which every chunk of
+/// a source passes or fails together.
+fn source_sql_conditions(filter: &SearchFilter) -> Vec<String> {
+    let mut conditions = Vec::new();
+
+    if let Some(pattern) = &filter.metadata_like {
+        conditions.push(format!("s.metadata LIKE '{}'", pattern.replace("'", "''")));
+    }
+
+    for (key, value) in filter.metadata_equals.iter().flatten() {
+        conditions.push(metadata_equals_condition(key, value));
+    }
+
+    if let Some(after) = filter.created_after {
+        conditions.push(format!("s.created_at >= {}", after));
+    }
+    if let Some(before) = filter.created_before {
+        conditions.push(format!("s.created_at < {}", before));
+    }
+    conditions
+}
+
+/// The filter's conditions on the chunk row (`c`).
+fn chunk_sql_conditions(filter: &SearchFilter) -> Vec<String> {
+    let mut conditions = Vec::new();
+
+    if let Some(entity) = &filter.entity {
+        conditions.push(entity_filter_condition(entity));
+    }
+
+    if let Some(language) = &filter.language {
+        conditions.push(language_condition(language));
+    }
+
+    if let Some(types) = &filter.chunk_types {
+        let list = types.iter().map(|t| sql_quote(t)).collect::<Vec<_>>().join(",");
+        conditions.push(format!("COALESCE(c.chunk_type, 'general') IN ({})", list));
+    }
+    conditions
+}
+
+/// Whether `filter` narrows results to less than whole sources.
+fn narrows_within_sources(filter: &SearchFilter) -> bool {
+    !chunk_sql_conditions(filter).is_empty()
+}
+
+/// The active tenant's sources among `source_ids` that pass the filter's
+/// source-level conditions.
+fn sources_passing_filter(
+    conn: &Connection,
+    source_ids: &[i64],
+    filter: &SearchFilter,
+) -> Result<Vec<i64>, RagError> {
+    let mut conditions = vec![
+        tenant_condition("s"),
+        format!("s.id IN ({})", id_list(source_ids)),
+    ];
+    conditions.extend(source_sql_conditions(filter));
+    let query = format!(
+        "SELECT s.id FROM sources s WHERE {} ORDER BY s.id",
+        conditions.join(" AND ")
+    );
+    let mut stmt = conn.prepare(&query).map_err(db_error)?;
+    let ids = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<Vec<i64>>>()
+        .map_err(db_error)?;
+    Ok(ids)
+}
+
+/// Source-filtered sets of at least this many chunks get their vector
+/// candidates from the HNSW graph restricted to the filtered ids instead
+/// of an exact scan, which would read every one of them.
+const GRAPH_FILTER_MIN_CHUNKS: usize = 2_000;
+
+/// Ids of the active tenant's chunks matching `filter`.
+fn filtered_chunk_ids(conn: &Connection, filter: &SearchFilter) -> Result<HashSet<i64>, RagError> {
+    let mut conditions = vec![tenant_condition("c")];
+    conditions.extend(filter_sql_conditions(filter));
+    let query = format!(
+        "SELECT c.id FROM chunks c LEFT JOIN sources s ON c.source_id = s.id WHERE {}",
+        conditions.join(" AND ")
+    );
+    let mut stmt = conn.prepare(&query).map_err(db_error)?;
+    let ids = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<HashSet<i64>>>()
+        .map_err(db_error)?;
+    Ok(ids)
+}
+
+/// A chunk row fetched for the per-source exact scan.
+struct ScanRow {
+    id: i64,
+    embedding_blob: Vec<u8>,
+    content: String,
+}
+
+/// Output of `exact_scan`: the best `candidate_k` vector matches (closest
+/// first) and, per chunk with tokens, `(id, doc length, query term freqs)`.
+struct ExactScan {
+    vector_results: Vec<HnswSearchResult>,
+    term_stats: Vec<(i64, usize, HashMap<String, u32>)>,
+}
+
+/// Max-heap entry ordered by distance (ties broken by id), so the heap top
+/// is the worst of the kept candidates.
+#[derive(PartialEq)]
+struct ScoredChunk {
+    distance: f32,
+    id: i64,
+}
+
+impl Eq for ScoredChunk {}
+
+impl PartialOrd for ScoredChunk {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredChunk {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+fn push_bounded(heap: &mut BinaryHeap<ScoredChunk>, entry: ScoredChunk, k: usize) {
+    if heap.len() < k {
+        heap.push(entry);
+    } else if heap.peek().is_some_and(|worst| entry < *worst) {
+        heap.pop();
+        heap.push(entry);
+    }
+}
+
+/// Score every row against the query in parallel. Each rayon split keeps
+/// its best `candidate_k` vector matches in a bounded heap and the heaps
+/// are merged pairwise, so ranking is O(n log k) rather than a full sort.
+/// The rows themselves and `term_stats` are still O(n); callers bound n
+/// by paging rows in low-memory mode.
+fn exact_scan(
+    rows: &[ScanRow],
+    query_embedding: &[f32],
+    query_token_set: &HashSet<String>,
+    candidate_k: usize,
+) -> ExactScan {
+    let query_norm = query_embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let metric = get_index_config().metric;
+
+    let vector_heap = rows
+        .par_iter()
+        .fold(BinaryHeap::new, |mut heap, row| {
+            if let Some(distance) = blob_distance(query_embedding, query_norm, &row.embedding_blob, metric) {
+                let entry = ScoredChunk { distance, id: row.id };
+                push_bounded(&mut heap, entry, candidate_k);
+            }
+            heap
+        })
+        .reduce(BinaryHeap::new, |mut a, b| {
+            for entry in b {
+                push_bounded(&mut a, entry, candidate_k);
+            }
+            a
+        });
+    let vector_results = vector_heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|e| HnswSearchResult {
+            id: e.id,
+            distance: e.distance,
+        })
+        .collect();
+
+    let term_stats = if query_token_set.is_empty() {
+        Vec::new()
+    } else {
+        rows.par_iter()
+            .filter_map(|row| {
+                let doc_tokens = tokenize_for_bm25(&row.content);
+                if doc_tokens.is_empty() {
+                    return None;
+                }
+                let doc_length = doc_tokens.len();
+                let mut term_freqs: HashMap<String, u32> = HashMap::new();
+                for token in doc_tokens {
+                    if query_token_set.contains(&token) {
+                        *term_freqs.entry(token).or_insert(0) += 1;
+                    }
+                }
+                Some((row.id, doc_length, term_freqs))
+            })
+            .collect()
+    };
+
+    ExactScan {
+        vector_results,
+        term_stats,
+    }
 }
 
 fn rrf_score(rank: usize, k: u32) -> f64 {
     1.0 / (k as f64 + rank as f64)
 }
 
+/// `scores` on a 0-1 scale, plus the value for ids missing from the list.
+pub(crate) fn normalize_scores(
+    scores: &[(i64, f64)],
+    method: ScoreNormalization,
+) -> (HashMap<i64, f64>, f64) {
+    if scores.is_empty() {
+        return (HashMap::new(), 0.0);
+    }
+    let n = scores.len() as f64;
+    let normalized: HashMap<i64, f64> = match method {
+        ScoreNormalization::MinMax => {
+            let min = scores.iter().map(|s| s.1).fold(f64::INFINITY, f64::min);
+            let max = scores.iter().map(|s| s.1).fold(f64::NEG_INFINITY, f64::max);
+            let range = max - min;
+            scores
+                .iter()
+                .map(|&(id, s)| (id, if range > 0.0 { (s - min) / range } else { 1.0 }))
+                .collect()
+        }
+        ScoreNormalization::ZScore => {
+            let mean = scores.iter().map(|s| s.1).sum::<f64>() / n;
+            let std = (scores.iter().map(|s| (s.1 - mean).powi(2)).sum::<f64>() / n).sqrt();
+            scores
+                .iter()
+                .map(|&(id, s)| {
+                    let z = if std > 0.0 { (s - mean) / std } else { 0.0 };
+                    (id, 1.0 / (1.0 + (-z).exp()))
+                })
+                .collect()
+        }
+    };
+    let floor = match method {
+        ScoreNormalization::MinMax => 0.0,
+        ScoreNormalization::ZScore => normalized.values().copied().fold(f64::INFINITY, f64::min),
+    };
+    (normalized, floor)
+}
+
+/// Per-call options of `search_hybrid`. Paging (`page_offset` or
+/// `cursor`) does not combine with `diversity_lambda`, `group_by_source`
+/// or `collection`.
+#[derive(Debug, Clone, Default)]
+pub struct HybridSearchOptions {
+    /// HNSW `ef_search` for this call only (`None` uses the configured or
+    /// adaptive value; see `set_hnsw_config`).
+    pub ef_search: Option<u32>,
+    /// MMR diversification: 1.0 keeps the fused ranking, lower values
+    /// trade relevance for covering more distinct passages (0.5-0.7
+    /// usually works well).
+    pub diversity_lambda: Option<f64>,
+    /// Search the collection with this name instead: its named HNSW index
+    /// (`build_hnsw_index_named`) fused with its named BM25 index
+    /// (`build_bm25_index_named`), so keyword statistics come from that
+    /// collection only. Both indexes must exist; `filter` must be `None`.
+    pub collection: Option<String>,
+    /// Collapse results to the best `top_k` sources, each with at most
+    /// this many chunks (at least 1), in `HybridSearchResponse::groups`.
+    pub group_by_source: Option<u32>,
+    /// Return per-stage timings and candidate counts, for tuning candidate
+    /// multipliers and HNSW parameters on the device.
+    pub with_diagnostics: bool,
+    /// Page the results: `top_k` results starting at this offset, plus a
+    /// cursor for the next page. Ranks a few pages ahead so following
+    /// pages are only a content fetch.
+    pub page_offset: Option<u32>,
+    /// Continue a paged search from the `next_cursor` of its previous
+    /// page, with that search's query, config and filter; results already
+    /// returned are never repeated.
+    pub cursor: Option<String>,
+}
+
+/// What `search_hybrid` found, shaped by its `HybridSearchOptions`.
+#[derive(Debug, Clone, Default)]
+pub struct HybridSearchResponse {
+    /// The best results, best first; empty when grouped.
+    pub results: Vec<HybridSearchResult>,
+    /// With `group_by_source`: the best sources, best first.
+    pub groups: Option<Vec<SourceGroup>>,
+    /// With `with_diagnostics`: stage timings and candidate counts.
+    pub diagnostics: Option<SearchDiagnostics>,
+    /// When paging: pass as `cursor` for the following page; `None` once
+    /// there are no more results.
+    pub next_cursor: Option<String>,
+}
+
+impl HybridSearchOptions {
+    fn paged(&self) -> bool {
+        self.page_offset.is_some() || self.cursor.is_some()
+    }
+
+    fn validate(&self, filter: &Option<SearchFilter>) -> Result<(), RagError> {
+        if self.paged()
+            && (self.diversity_lambda.is_some()
+                || self.group_by_source.is_some()
+                || self.collection.is_some())
+        {
+            return Err(RagError::InvalidInput(
+                "Paging does not combine with diversity, grouping or collections".to_string(),
+            ));
+        }
+        if self.collection.is_some() && filter.is_some() {
+            return Err(RagError::InvalidInput(
+                "Filters do not apply to collection searches".to_string(),
+            ));
+        }
+        if let Some(lambda) = self.diversity_lambda {
+            validate_lambda(lambda)?;
+        }
+        Ok(())
+    }
+}
+
 /// Perform hybrid search combining vector and keyword search.
+///
+/// Without `options` this returns the best `top_k` chunks in `results`;
+/// see `HybridSearchOptions` for the rest.
 pub fn search_hybrid(
     query_text: String,
     query_embedding: Vec<f32>,
     top_k: u32,
     config: Option<RrfConfig>,
     filter: Option<SearchFilter>,
-) -> Result<Vec<HybridSearchResult>, RagError> {
+    options: Option<HybridSearchOptions>,
+) -> Result<HybridSearchResponse, RagError> {
+    let options = options.unwrap_or_default();
+    options.validate(&filter)?;
+    let mut tracer = SearchTracer::with_diagnostics(options.with_diagnostics);
+    let mut response = search_hybrid_impl(
+        query_text,
+        query_embedding,
+        top_k,
+        config,
+        filter,
+        &options,
+        &mut tracer,
+    )?;
+    response.diagnostics = tracer.diagnostics();
+    Ok(response)
+}
+
+/// Hybrid search that also returns per-stage timings when search tracing
+/// is enabled (`set_search_tracing(true)`); `trace` is `None` otherwise.
+pub fn search_hybrid_traced(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+) -> Result<TracedHybridSearch, RagError> {
+    let mut tracer = SearchTracer::new();
+    let response = search_hybrid_impl(
+        query_text,
+        query_embedding,
+        top_k,
+        config,
+        filter,
+        &HybridSearchOptions::default(),
+        &mut tracer,
+    )?;
+    Ok(TracedHybridSearch {
+        results: response.results,
+        trace: tracer.finish(),
+    })
+}
+
+/// A paged search's ranking and what is needed to rank it deeper.
+#[derive(Clone)]
+struct PagedHybridRanking {
+    query_text: String,
+    query_embedding: Vec<f32>,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    ef_search: Option<u32>,
+    hits: Vec<RankedHit>,
+    exhausted: bool,
+}
+
+static HYBRID_RANKINGS: Lazy<RwLock<RankingCache<PagedHybridRanking>>> =
+    Lazy::new(|| RwLock::new(RankingCache::new()));
+
+fn validate_page_size(page_size: u32) -> Result<usize, RagError> {
+    if page_size == 0 {
+        return Err(RagError::InvalidInput(
+            "page_size must be at least 1".to_string(),
+        ));
+    }
+    Ok(page_size as usize)
+}
+
+/// The page of `page_size` results `options` asks for: a new ranking
+/// from `page_offset`, or the page after the one `cursor` came with.
+fn search_hybrid_page(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    page_size: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    options: &HybridSearchOptions,
+    tracer: &mut SearchTracer,
+) -> Result<HybridSearchResponse, RagError> {
+    let page_size = validate_page_size(page_size)?;
+    if let Some(cursor) = &options.cursor {
+        let (id, offset) = decode_cursor(cursor)?;
+        let mut ranking = HYBRID_RANKINGS.read().unwrap().get(id, &active_tenant())?;
+        if offset.saturating_add(page_size) > ranking.hits.len() && !ranking.exhausted {
+            let depth = offset.saturating_add(page_size.saturating_mul(PAGES_PER_RANKING));
+            deepen_hybrid(&mut ranking, offset, depth, tracer)?;
+            HYBRID_RANKINGS.write().unwrap().update(id, ranking.clone());
+        }
+        return hybrid_page(id, &ranking, offset, page_size);
+    }
+    let offset = options.page_offset.unwrap_or(0) as usize;
+    let mut ranking = PagedHybridRanking {
+        query_text,
+        query_embedding,
+        config,
+        filter,
+        ef_search: options.ef_search,
+        hits: Vec::new(),
+        exhausted: false,
+    };
+    let depth = offset.saturating_add(page_size.saturating_mul(PAGES_PER_RANKING));
+    deepen_hybrid(&mut ranking, 0, depth, tracer)?;
+    let id = HYBRID_RANKINGS
+        .write()
+        .unwrap()
+        .insert(active_tenant(), ranking.clone());
+    hybrid_page(id, &ranking, offset, page_size)
+}
+
+/// Re-rank `depth` deep, keeping the first `delivered` hits in place.
+fn deepen_hybrid(
+    ranking: &mut PagedHybridRanking,
+    delivered: usize,
+    depth: usize,
+    tracer: &mut SearchTracer,
+) -> Result<(), RagError> {
+    let fresh = rank_candidates(
+        &ranking.query_text,
+        ranking.query_embedding.clone(),
+        depth,
+        ranking.config.clone(),
+        ranking.filter.clone(),
+        ranking.ef_search,
+        tracer,
+    )?;
+    ranking.exhausted = fresh.len() < depth;
+    ranking.hits = merge_deeper(&ranking.hits, delivered, fresh, |hit| hit.doc_id);
+    debug!(
+        "[hybrid] Paged ranking now {} deep (exhausted: {})",
+        ranking.hits.len(),
+        ranking.exhausted
+    );
+    Ok(())
+}
+
+fn hybrid_page(
+    id: u64,
+    ranking: &PagedHybridRanking,
+    offset: usize,
+    page_size: usize,
+) -> Result<HybridSearchResponse, RagError> {
+    let start = offset.min(ranking.hits.len());
+    let end = offset.saturating_add(page_size).min(ranking.hits.len());
+    let query_text = scoring_query_text(&ranking.query_text);
+    let results = if start < end {
+        let conn = get_connection()?;
+        let mut results = hydrate(&conn, &ranking.hits[start..end], ranking.filter.is_none());
+        attach_snippets(&mut results, &query_text);
+        results
+    } else {
+        vec![]
+    };
+    record_hybrid_retrieval(&query_text, &results);
+    let more = end < ranking.hits.len() || !ranking.exhausted;
+    Ok(HybridSearchResponse {
+        results,
+        next_cursor: more.then(|| encode_cursor(id, end)),
+        ..Default::default()
+    })
+}
+
+/// Hits hydrated per `HybridSearchEvent::Results` batch.
+const STREAM_BATCH_SIZE: usize = 4;
+
+/// Hybrid search that streams over `sink`: the fused ranking first, then
+/// contents in rank order as they are fetched, so the UI can render the
+/// ranking immediately. Stops early if the listener goes away.
+pub fn search_hybrid_stream(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    sink: StreamSink<HybridSearchEvent>,
+) -> Result<(), RagError> {
+    let _query = query_guard();
+    let started = Instant::now();
+    let outcome = stream_hybrid_stages(&query_text, query_embedding, top_k, config, filter, &sink);
+    record_search(started.elapsed());
+    if let Ok(ids) = &outcome {
+        record_search_event("hybrid_stream", Some(&query_text), started.elapsed(), ids);
+    }
+    outcome.map(|_| ())
+}
+
+/// Returns the ranked ids, for search analytics.
+fn stream_hybrid_stages(
+    query_text: &str,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    sink: &StreamSink<HybridSearchEvent>,
+) -> Result<Vec<i64>, RagError> {
+    let mut tracer = SearchTracer::new();
+    let include_docs = filter.is_none();
+    let ranking = rank_candidates(
+        query_text,
+        query_embedding,
+        top_k as usize,
+        config,
+        filter,
+        None,
+        &mut tracer,
+    )?;
+    let query_text = scoring_query_text(query_text);
+    let ids: Vec<i64> = ranking.iter().map(|hit| hit.doc_id).collect();
+    if sink
+        .add(HybridSearchEvent::Ranking(ranking.clone()))
+        .is_err()
+    {
+        debug!("[hybrid] Stream closed before ranking was delivered");
+        return Ok(ids);
+    }
+
+    if !ranking.is_empty() {
+        let conn = get_connection()?;
+        let mut delivered = Vec::with_capacity(ranking.len());
+        for batch in ranking.chunks(STREAM_BATCH_SIZE) {
+            let mut results = hydrate(&conn, batch, include_docs);
+            attach_snippets(&mut results, &query_text);
+            delivered.extend(results.iter().cloned());
+            if sink.add(HybridSearchEvent::Results(results)).is_err() {
+                debug!("[hybrid] Stream closed after {} results", delivered.len());
+                return Ok(ids);
+            }
+        }
+        info!("[hybrid] Streamed {} results", delivered.len());
+        record_hybrid_retrieval(&query_text, &delivered);
+    }
+
+    let _ = sink.add(HybridSearchEvent::Done);
+    Ok(ids)
+}
+
+fn search_hybrid_impl(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    options: &HybridSearchOptions,
+    tracer: &mut SearchTracer,
+) -> Result<HybridSearchResponse, RagError> {
+    let _query = query_guard();
+    let started = Instant::now();
+    let response = if options.paged() {
+        search_hybrid_page(
+            query_text.clone(),
+            query_embedding,
+            top_k,
+            config,
+            filter,
+            options,
+            tracer,
+        )
+    } else {
+        run_hybrid_stages(
+            &query_text,
+            query_embedding,
+            top_k as usize,
+            config,
+            filter,
+            options,
+            tracer,
+        )
+    };
+    record_search(started.elapsed());
+    if let Ok(response) = &response {
+        let ids: Vec<i64> = match &response.groups {
+            Some(groups) => groups.iter().flat_map(|g| g.chunks.iter().map(|c| c.doc_id)).collect(),
+            None => response.results.iter().map(|r| r.doc_id).collect(),
+        };
+        record_search_event("hybrid", Some(&query_text), started.elapsed(), &ids);
+    }
+    response
+}
+
+fn run_hybrid_stages(
+    query_text: &str,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    options: &HybridSearchOptions,
+    tracer: &mut SearchTracer,
+) -> Result<HybridSearchResponse, RagError> {
+    // Grouping and diversification pick from a deeper ranking.
+    let wanted = match options.group_by_source {
+        Some(max_chunks) => top_k
+            .saturating_mul(max_chunks.max(1) as usize)
+            .saturating_mul(GROUP_FETCH_MULTIPLIER),
+        None => top_k,
+    };
+    let fetch_k = match options.diversity_lambda {
+        Some(_) => wanted.saturating_mul(MMR_FETCH_MULTIPLIER as usize),
+        None => wanted,
+    };
+    let include_docs = filter.is_none() && options.collection.is_none();
+    let ranking = match &options.collection {
+        Some(name) => rank_collection(
+            name,
+            query_text,
+            &query_embedding,
+            fetch_k,
+            config,
+            options.ef_search,
+            tracer,
+        )?,
+        None => rank_candidates(
+            query_text,
+            query_embedding,
+            fetch_k,
+            config,
+            filter,
+            options.ef_search,
+            tracer,
+        )?,
+    };
+    let query_text = scoring_query_text(query_text);
+
+    // 4. Batch Content Fetch
+    let fetch_started = Instant::now();
+    if ranking.is_empty() {
+        return Ok(HybridSearchResponse {
+            groups: options.group_by_source.map(|_| Vec::new()),
+            ..Default::default()
+        });
+    }
+    let conn = get_connection()?;
+    let mut results = hydrate(&conn, &ranking, include_docs);
+    tracer.record("content_fetch", fetch_started.elapsed());
+
+    if let Some(lambda) = options.diversity_lambda {
+        let ids: Vec<i64> = results.iter().map(|r| r.doc_id).collect();
+        let embeddings = load_embeddings(&conn, &ids)?;
+        let embedding_of = |id: i64| embeddings.get(&id).map(|e| e.as_slice());
+        results = diversify(results, embedding_of, lambda, wanted);
+    }
+
+    let Some(max_chunks) = options.group_by_source else {
+        attach_snippets(&mut results, &query_text);
+        info!("[hybrid] Returning {} results", results.len());
+        record_hybrid_retrieval(&query_text, &results);
+        return Ok(HybridSearchResponse {
+            results,
+            ..Default::default()
+        });
+    };
+    let mut groups = group_by_source(&conn, results, max_chunks, top_k, include_docs)?;
+    for group in &mut groups {
+        attach_snippets(&mut group.chunks, &query_text);
+    }
+    let kept: Vec<HybridSearchResult> = groups.iter().flat_map(|g| g.chunks.iter().cloned()).collect();
+    info!("[hybrid] Returning {} sources", groups.len());
+    record_hybrid_retrieval(&query_text, &kept);
+    Ok(HybridSearchResponse {
+        groups: Some(groups),
+        ..Default::default()
+    })
+}
+
+/// Stages 1-3: candidate search, filtering and RRF fusion, truncated to `top_k`.
+fn rank_candidates(
+    query_text: &str,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    ef_search: Option<u32>,
+    tracer: &mut SearchTracer,
+) -> Result<Vec<RankedHit>, RagError> {
     let config = config.unwrap_or_default();
     info!("[hybrid] Starting hybrid search, top_k: {}", top_k);
 
     // Fetch more candidates if filtering is active to maintain recall
-    let multiplier = if filter.is_some() { 4 } else { 2 };
-    let candidate_k = (top_k * multiplier) as usize;
+    // (fewer in low-memory mode).
+    let profile = memory_profile();
+    let multiplier = if filter.is_some() {
+        profile.filtered_candidate_multiplier
+    } else {
+        profile.candidate_multiplier
+    };
+    // Capped so it converts losslessly for the BM25 search and diagnostics.
+    let candidate_k = top_k.saturating_mul(multiplier as usize).min(u32::MAX as usize);
+
+    // Large source-filtered sets search the graph among their own ids;
+    // small ones keep the exact scan below, which is cheap and exact.
+    let hnsw_loaded = is_hnsw_index_loaded();
+    let allowlist = match &filter {
+        Some(f) if hnsw_loaded && f.source_ids.as_ref().is_some_and(|sids| !sids.is_empty()) => {
+            let conn = get_connection()?;
+            let ids = filtered_chunk_ids(&conn, f)?;
+            (ids.len() >= GRAPH_FILTER_MIN_CHUNKS).then_some(ids)
+        }
+        _ => None,
+    };
 
     // 1. Parallel Execution: Run Vector and BM25 search simultaneously
-    let (mut vector_results, mut bm25_results) = std::thread::scope(|s| {
-        let handle_vec = s.spawn(|| {
-            if is_hnsw_index_loaded() {
-                search_hnsw(query_embedding.clone(), candidate_k).unwrap_or_else(|e| {
-                    log::error!("[hybrid] Vector search failed: {}", e);
+    let ((mut vector_results, vector_elapsed), (mut bm25_results, bm25_elapsed)) =
+        std::thread::scope(|s| {
+            let handle_vec = s.spawn(|| {
+                let started = Instant::now();
+                let results = if hnsw_loaded {
+                    match &allowlist {
+                        Some(ids) => search_hnsw_in_tenant_filtered(
+                            &active_tenant(),
+                            query_embedding.clone(),
+                            candidate_k,
+                            ef_search,
+                            Some(ids),
+                        ),
+                        None => search_hnsw_with_ef(query_embedding.clone(), candidate_k, ef_search),
+                    }
+                    .unwrap_or_else(|e| {
+                        log::error!("[hybrid] Vector search failed: {}", e);
+                        vec![]
+                    })
+                } else {
+                    debug!("[hybrid] HNSW index not loaded, skipping vector search");
                     vec![]
-                })
-            } else {
-                debug!("[hybrid] HNSW index not loaded, skipping vector search");
-                vec![]
-            }
-        });
+                };
+                (results, started.elapsed())
+            });
 
-        let handle_bm25 = s.spawn(|| bm25_search(query_text.clone(), candidate_k as u32));
+            let handle_bm25 = s.spawn(|| {
+                let started = Instant::now();
+                (
+                    bm25_search(query_text.to_string(), candidate_k as u32),
+                    started.elapsed(),
+                )
+            });
 
-        let vec_res = handle_vec.join().unwrap_or_else(|e| {
-            log::error!("[hybrid] Vector search thread panicked: {:?}", e);
-            vec![]
-        });
+            let vec_res = handle_vec.join().unwrap_or_else(|e| {
+                log::error!(
+                    "[hybrid] Vector search thread panicked: {}",
+                    payload_message(&*e)
+                );
+                (vec![], Duration::ZERO)
+            });
 
-        let bm25_res = handle_bm25.join().unwrap_or_else(|e| {
-            log::error!("[hybrid] BM25 search thread panicked: {:?}", e);
-            vec![]
-        });
+            let bm25_res = handle_bm25.join().unwrap_or_else(|e| {
+                log::error!(
+                    "[hybrid] BM25 search thread panicked: {}",
+                    payload_message(&*e)
+                );
+                (vec![], Duration::ZERO)
+            });
 
-        (vec_res, bm25_res)
+            (vec_res, bm25_res)
+        });
+    tracer.record("vector_search", vector_elapsed);
+    tracer.record("bm25_search", bm25_elapsed);
+    tracer.diagnose(|d| {
+        d.candidate_k = candidate_k as u32;
+        d.vector_candidates = vector_results.len() as u32;
+        d.bm25_candidates = bm25_results.len() as u32;
+        d.hnsw_loaded = hnsw_loaded;
     });
 
     info!(
@@ -115,12 +1030,21 @@ pub fn search_hybrid(
     );
 
     // 2. Filter-Aware Search Strategy
+    let filter_started = Instant::now();
     // If filtering by source_id, performing a global HNSW search and then filtering is inefficient
     // and prone to low recall (if source is small/obscure).
     // Instead, perform an exact scan over the target source's chunks and compute
     // both vector and BM25 ranks in that scoped set.
     let mut used_exact_source_scan = false;
-    if let Some(f) = &filter {
+    if let Some(ids) = &allowlist {
+        bm25_results.retain(|r| ids.contains(&r.doc_id));
+        info!(
+            "[hybrid] {} filtered chunks, using the graph - Vector: {}, BM25: {}",
+            ids.len(),
+            vector_results.len(),
+            bm25_results.len()
+        );
+    } else if let Some(f) = &filter {
         if let Some(sids) = &f.source_ids {
             if !sids.is_empty() {
                 used_exact_source_scan = true;
@@ -129,35 +1053,49 @@ pub fn search_hybrid(
                     sids
                 );
 
-                let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-                let sids_str = sids
-                    .iter()
-                    .map(|id| id.to_string())
-                    .collect::<Vec<_>>()
-                    .join(",");
-
-                // Fetch ALL chunks for these sources for scoped vector + BM25 scoring.
-                let query = format!(
-                    "SELECT c.id, c.embedding, c.content FROM chunks c WHERE c.source_id IN ({})",
-                    sids_str
+                let conn = get_connection()?;
+
+                // Per-source sub-indexes (when enabled) supply the vector
+                // candidates; they cover whole sources, so only for the
+                // sources passing the filter and not with chunk-level
+                // conditions.
+                let ann_results = if !narrows_within_sources(f) {
+                    let passing = sources_passing_filter(&conn, sids, f)?;
+                    search_source_indexes(&passing, &query_embedding, candidate_k)?
+                } else {
+                    None
+                };
+                // Without embeddings the scan below only computes BM25 stats.
+                let embedding_column = if ann_results.is_some() {
+                    "x''"
+                } else {
+                    "c.embedding"
+                };
+
+                // Fetch ALL chunks matching the filter for scoped vector +
+                // BM25 scoring; the same conditions as the post-filter path.
+                let mut conditions = vec![tenant_condition("c")];
+                conditions.extend(filter_sql_conditions(f));
+                let mut query = format!(
+                    "SELECT c.id, {}, c.content FROM chunks c
+                     LEFT JOIN sources s ON c.source_id = s.id WHERE {}",
+                    embedding_column,
+                    conditions.join(" AND ")
                 );
 
+                // Rows are paged by id; low-memory mode streams content in
+                // bounded pages instead of holding every chunk at once.
+                let page_rows = profile.scan_page_rows as usize;
+                query.push_str(" AND c.id > ?1 ORDER BY c.id");
+                if page_rows > 0 {
+                    query.push_str(&format!(" LIMIT {}", page_rows));
+                }
                 let mut stmt = conn
                     .prepare(&query)
-                    .map_err(|e| RagError::DatabaseError(e.to_string()))?;
-                let chunk_iter = stmt
-                    .query_map([], |row| {
-                        Ok((
-                            row.get::<_, i64>(0)?,
-                            row.get::<_, Vec<u8>>(1)?,
-                            row.get::<_, String>(2)?,
-                        ))
-                    })
-                    .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+                    .map_err(db_error)?;
 
-                let query_vec = Array1::from(query_embedding.clone());
-                let query_norm = query_vec.mapv(|x| x * x).sum().sqrt();
-                let query_tokens = tokenize_for_bm25(&query_text);
+                let query_tokens =
+                    tokenize_for_bm25(&scoring_query_text(&expand_query(&active_tenant(), query_text)));
                 let query_token_set: HashSet<String> = query_tokens.iter().cloned().collect();
 
                 let mut scoped_doc_count = 0usize;
@@ -170,64 +1108,52 @@ pub fn search_hybrid(
                 vector_results.clear();
                 bm25_results.clear();
 
-                for row in chunk_iter {
-                    if let Ok((id, embedding_blob, content)) = row {
-                        let embedding: Vec<f32> = embedding_blob
-                            .chunks(4)
-                            .map(|c| f32::from_ne_bytes(c.try_into().unwrap()))
-                            .collect();
-
-                        if embedding.len() == query_embedding.len() {
-                            let target_vec = Array1::from(embedding);
-                            let target_norm = target_vec.mapv(|x| x * x).sum().sqrt();
-                            let dot = query_vec.dot(&target_vec);
-                            let sim = if query_norm == 0.0 || target_norm == 0.0 {
-                                0.0
-                            } else {
-                                dot / (query_norm * target_norm)
-                            };
-
-                            vector_results.push(HnswSearchResult {
-                                id,
-                                distance: (1.0 - sim) as f32, // lower is better
-                            });
-                        }
-
-                        if !query_token_set.is_empty() {
-                            let doc_tokens = tokenize_for_bm25(&content);
-                            let doc_length = doc_tokens.len();
-                            if doc_length > 0 {
-                                scoped_doc_count += 1;
-                                scoped_total_doc_length += doc_length;
-                                scoped_doc_lengths.insert(id, doc_length);
-
-                                let mut term_freqs: HashMap<String, u32> = HashMap::new();
-                                for token in doc_tokens {
-                                    if query_token_set.contains(&token) {
-                                        *term_freqs.entry(token).or_insert(0) += 1;
-                                    }
-                                }
-                                for term in term_freqs.keys() {
-                                    *scoped_doc_freqs.entry(term.clone()).or_insert(0) += 1;
-                                }
-                                scoped_term_freqs.insert(id, term_freqs);
-                            }
+                let mut last_id = i64::MIN;
+                loop {
+                    let rows: Vec<ScanRow> = stmt
+                        .query_map(params![last_id], |row| {
+                            Ok(ScanRow {
+                                id: row.get(0)?,
+                                embedding_blob: row.get(1)?,
+                                content: read_content(row, 2)?,
+                            })
+                        })
+                        .map_err(db_error)?
+                        .collect::<rusqlite::Result<_>>()
+                        .map_err(db_error)?;
+                    let Some(last) = rows.last() else {
+                        break;
+                    };
+                    last_id = last.id;
+
+                    let scan = exact_scan(&rows, &query_embedding, &query_token_set, candidate_k);
+                    vector_results.extend(scan.vector_results);
+                    vector_results
+                        .sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.id.cmp(&b.id)));
+                    vector_results.truncate(candidate_k);
+
+                    for (id, doc_length, term_freqs) in scan.term_stats {
+                        scoped_doc_count += 1;
+                        scoped_total_doc_length += doc_length;
+                        scoped_doc_lengths.insert(id, doc_length);
+                        for term in term_freqs.keys() {
+                            *scoped_doc_freqs.entry(term.clone()).or_insert(0) += 1;
                         }
+                        scoped_term_freqs.insert(id, term_freqs);
+                    }
+                    if page_rows == 0 || rows.len() < page_rows {
+                        break;
                     }
                 }
 
-                // Sort by distance ASCENDING (best match first)
-                vector_results.sort_by(|a, b| {
-                    a.distance
-                        .partial_cmp(&b.distance)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                vector_results.truncate(candidate_k);
+                if let Some(ann) = ann_results {
+                    info!("[hybrid] Vector candidates from per-source sub-indexes");
+                    vector_results = ann;
+                }
 
                 if !query_tokens.is_empty() && scoped_doc_count > 0 {
                     let avg_doc_length = scoped_total_doc_length as f64 / scoped_doc_count as f64;
-                    let k1 = 1.2;
-                    let b = 0.75;
+                    let bm25_config = get_bm25_config();
                     let mut scoped_bm25_scores: Vec<Bm25SearchResult> = Vec::new();
 
                     for (doc_id, term_freqs) in scoped_term_freqs {
@@ -245,12 +1171,12 @@ pub fn search_hybrid(
 
                             let n = *df as f64;
                             let idf = ((scoped_doc_count as f64 - n + 0.5) / (n + 0.5) + 1.0).ln();
-                            let tf_f = *tf as f64;
-                            let doc_len_f = *doc_len as f64;
-                            let tf_component = (tf_f * (k1 + 1.0))
-                                / (tf_f
-                                    + k1 * (1.0 - b + b * (doc_len_f / avg_doc_length.max(1.0))));
-                            score += idf * tf_component;
+                            score += bm25_config.term_score(
+                                idf,
+                                *tf as f64,
+                                *doc_len as f64,
+                                avg_doc_length.max(1.0),
+                            );
                         }
                         if score > 0.0 {
                             scoped_bm25_scores.push(Bm25SearchResult { doc_id, score });
@@ -276,8 +1202,9 @@ pub fn search_hybrid(
         }
     }
 
-    // Standard global search post-filtering (skip when exact source scan already scoped).
-    if !used_exact_source_scan {
+    // Standard global search post-filtering (skip when exact source scan
+    // or the allowlist already scoped).
+    if !used_exact_source_scan && allowlist.is_none() {
         if let Some(f) = &filter {
             let mut all_doc_ids: Vec<i64> = vector_results
                 .iter()
@@ -288,31 +1215,16 @@ pub fn search_hybrid(
             all_doc_ids.dedup();
 
             if !all_doc_ids.is_empty() {
-                let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+                let conn = get_connection()?;
                 let id_list = all_doc_ids
                     .iter()
                     .map(|id| id.to_string())
                     .collect::<Vec<_>>()
                     .join(",");
 
-                let mut sql_conditions = Vec::new();
-                sql_conditions.push(format!("c.id IN ({})", id_list));
-
-                if let Some(sids) = &f.source_ids {
-                    if !sids.is_empty() {
-                        let sids_str = sids
-                            .iter()
-                            .map(|id| id.to_string())
-                            .collect::<Vec<_>>()
-                            .join(",");
-                        sql_conditions.push(format!("c.source_id IN ({})", sids_str));
-                    }
-                }
-
-                if let Some(pattern) = &f.metadata_like {
-                    sql_conditions
-                        .push(format!("s.metadata LIKE '{}'", pattern.replace("'", "''")));
-                }
+                let mut sql_conditions =
+                    vec![format!("c.id IN ({})", id_list), tenant_condition("c")];
+                sql_conditions.extend(filter_sql_conditions(f));
 
                 let query = format!(
                     "SELECT c.id FROM chunks c
@@ -325,10 +1237,10 @@ pub fn search_hybrid(
 
                 let mut stmt = conn
                     .prepare(&query)
-                    .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+                    .map_err(db_error)?;
                 let valid_ids: HashSet<i64> = stmt
                     .query_map([], |row| row.get(0))
-                    .map_err(|e| RagError::DatabaseError(e.to_string()))?
+                    .map_err(db_error)?
                     .filter_map(|r| r.ok())
                     .collect();
 
@@ -344,7 +1256,37 @@ pub fn search_hybrid(
         }
     }
 
+    // `+`/`-`/AND/NOT clauses also apply to vector candidates.
+    if let Some(constraint) = bm25_boolean_constraint(query_text) {
+        vector_results.retain(|r| constraint.allows(r.id));
+        bm25_results.retain(|r| constraint.allows(r.doc_id));
+    }
+
+    if filter.is_some() {
+        tracer.record("filter_sql", filter_started.elapsed());
+    }
+    tracer.diagnose(|d| {
+        d.filtered_vector_candidates = vector_results.len() as u32;
+        d.filtered_bm25_candidates = bm25_results.len() as u32;
+        d.exact_scan = used_exact_source_scan;
+        d.graph_filter = allowlist.is_some();
+    });
+
     // 3. RRF Ranking
+    let fusion_started = Instant::now();
+    let hits = fuse_candidates(&vector_results, &bm25_results, &config, top_k)?;
+    tracer.record("fusion", fusion_started.elapsed());
+    Ok(hits)
+}
+
+/// Fuse vector and BM25 candidates (each best first) with `config`'s
+/// strategy, apply its type boosts and recency, and keep the best `top_k`.
+fn fuse_candidates(
+    vector_results: &[HnswSearchResult],
+    bm25_results: &[Bm25SearchResult],
+    config: &RrfConfig,
+    top_k: usize,
+) -> Result<Vec<RankedHit>, RagError> {
     let mut vector_ranks: HashMap<i64, usize> = HashMap::new();
     for (rank, result) in vector_results.iter().enumerate() {
         vector_ranks.insert(result.id, rank + 1);
@@ -367,17 +1309,37 @@ pub fn search_hybrid(
         return Ok(vec![]);
     }
 
+    let normalized = match config.fusion {
+        FusionStrategy::Rrf => None,
+        FusionStrategy::WeightedScore { normalization } => {
+            let similarities: Vec<(i64, f64)> = vector_results
+                .iter()
+                .map(|r| (r.id, 1.0 - r.distance as f64))
+                .collect();
+            let keyword: Vec<(i64, f64)> = bm25_results.iter().map(|r| (r.doc_id, r.score)).collect();
+            Some((
+                normalize_scores(&similarities, normalization),
+                normalize_scores(&keyword, normalization),
+            ))
+        }
+    };
+
     let mut rrf_scores: Vec<(i64, f64, u32, u32)> = Vec::with_capacity(all_doc_ids.len());
     for doc_id in &all_doc_ids {
         let vec_rank = vector_ranks.get(doc_id).copied();
         let bm25_rank = bm25_ranks.get(doc_id).copied();
 
         let mut combined_score = 0.0;
-        if let Some(rank) = vec_rank {
-            combined_score += config.vector_weight * rrf_score(rank, config.k);
-        }
-        if let Some(rank) = bm25_rank {
-            combined_score += config.bm25_weight * rrf_score(rank, config.k);
+        if let Some(((vector, vector_floor), (bm25, bm25_floor))) = &normalized {
+            combined_score += config.vector_weight * vector.get(doc_id).copied().unwrap_or(*vector_floor);
+            combined_score += config.bm25_weight * bm25.get(doc_id).copied().unwrap_or(*bm25_floor);
+        } else {
+            if let Some(rank) = vec_rank {
+                combined_score += config.vector_weight * rrf_score(rank, config.k);
+            }
+            if let Some(rank) = bm25_rank {
+                combined_score += config.bm25_weight * rrf_score(rank, config.k);
+            }
         }
 
         rrf_scores.push((
@@ -388,99 +1350,270 @@ pub fn search_hybrid(
         ));
     }
 
-    rrf_scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-    rrf_scores.truncate(top_k as usize);
+    if !config.type_boosts.is_empty() {
+        let conn = get_connection()?;
+        let types = load_chunk_types(&conn, &all_doc_ids)?;
+        apply_type_boosts(&mut rrf_scores, &types, &config.type_boosts);
+    }
+    if let Some(half_life_days) = config.recency_half_life_days.filter(|d| *d > 0.0) {
+        let conn = get_connection()?;
+        let dated = load_source_dates(&conn, &all_doc_ids)?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64);
+        apply_recency(
+            &mut rrf_scores,
+            &dated,
+            now,
+            half_life_days,
+            config.recency_weight.clamp(0.0, 1.0),
+        );
+    }
 
-    // 4. Batch Content Fetch
-    if rrf_scores.is_empty() {
-        return Ok(vec![]);
+    // Ties break by id so repeated and deeper rankings agree on order.
+    rrf_scores.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.0.cmp(&b.0))
+    });
+    rrf_scores.truncate(top_k);
+
+    Ok(rrf_scores
+        .into_iter()
+        .map(|(doc_id, score, vector_rank, bm25_rank)| RankedHit {
+            doc_id,
+            score,
+            vector_rank,
+            bm25_rank,
+        })
+        .collect())
+}
+
+/// Map: chunk id -> chunk_type ("general" when unset). Types still in
+/// the legacy "type|header path" form are cut at the '|'.
+fn load_chunk_types(conn: &Connection, ids: &[i64]) -> Result<HashMap<i64, String>, RagError> {
+    let id_list = ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let query = format!(
+        "SELECT id, COALESCE(chunk_type, 'general') FROM chunks WHERE id IN ({})",
+        id_list
+    );
+    let mut stmt = conn.prepare(&query).map_err(db_error)?;
+    let types = stmt
+        .query_map([], |row| {
+            let chunk_type: String = row.get(1)?;
+            Ok((row.get::<_, i64>(0)?, split_chunk_type(&chunk_type).0.to_string()))
+        })
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    Ok(types)
+}
+
+/// Multiply fused scores by their chunk type's boost. Negative boosts
+/// count as 0 so they cannot flip a score's sign.
+fn apply_type_boosts(
+    rrf_scores: &mut [(i64, f64, u32, u32)],
+    types: &HashMap<i64, String>,
+    boosts: &HashMap<String, f64>,
+) {
+    for (doc_id, score, _, _) in rrf_scores.iter_mut() {
+        if let Some(boost) = types.get(doc_id).and_then(|t| boosts.get(t)) {
+            *score *= boost.max(0.0);
+        }
     }
+}
 
-    let target_ids: Vec<String> = rrf_scores
+/// Map: chunk id -> when its source was last updated, or created if it
+/// never was (unix seconds). Chunks of undated sources are left out.
+fn load_source_dates(conn: &Connection, ids: &[i64]) -> Result<HashMap<i64, i64>, RagError> {
+    let id_list = ids
         .iter()
-        .map(|(id, _, _, _)| id.to_string())
-        .collect();
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let query = format!(
+        "SELECT c.id, COALESCE(s.updated_at, s.created_at) FROM chunks c JOIN sources s ON c.source_id = s.id
+         WHERE c.id IN ({}) AND COALESCE(s.updated_at, s.created_at) IS NOT NULL",
+        id_list
+    );
+    let mut stmt = conn.prepare(&query).map_err(db_error)?;
+    let dated = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    Ok(dated)
+}
+
+/// Scale fused scores by `(1 - weight) + weight * 0.5^(age / half_life)`.
+/// Chunks dated in the future are not decayed; chunks without a date are
+/// treated as infinitely old, so they never outrank an equal dated one.
+fn apply_recency(
+    rrf_scores: &mut [(i64, f64, u32, u32)],
+    dated: &HashMap<i64, i64>,
+    now: i64,
+    half_life_days: f64,
+    weight: f64,
+) {
+    for (doc_id, score, _, _) in rrf_scores.iter_mut() {
+        let decay = dated.get(doc_id).map_or(0.0, |date| {
+            let age_days = (now - date).max(0) as f64 / 86_400.0;
+            0.5f64.powf(age_days / half_life_days)
+        });
+        *score *= (1.0 - weight) + weight * decay;
+    }
+}
+
+/// Fetch content for ranked hits, preserving rank order. Hits whose rows
+/// are gone (or belong to another tenant) are dropped. Snippets are left
+/// to `attach_snippets`, once the results to return are known.
+fn hydrate(
+    conn: &Connection,
+    hits: &[RankedHit],
+    include_docs: bool,
+) -> Vec<HybridSearchResult> {
+    if hits.is_empty() {
+        return vec![];
+    }
+
+    let target_ids: Vec<String> = hits.iter().map(|h| h.doc_id.to_string()).collect();
     let id_list = target_ids.join(",");
 
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    // Map: id -> (content, source_id, metadata, chunk_index)
-    let mut content_map: HashMap<i64, (String, i64, Option<String>, u32)> = HashMap::new();
+    // Map: id -> result with the hit's scores still unset
+    let mut content_map: HashMap<i64, HybridSearchResult> = HashMap::new();
+    let stored = |doc_id: i64, content: String, source_id: i64, metadata: Option<String>, chunk_index: u32| {
+        HybridSearchResult {
+            doc_id,
+            content,
+            score: 0.0,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id,
+            metadata,
+            chunk_index,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        }
+    };
 
     // First try docs table (Simple RAG) - assume source_id=id, metadata=None, chunk_index=0
     // BUT if filter was active, we likely filtered these out.
-    if filter.is_none() {
+    if include_docs {
         let query_docs = format!("SELECT id, content FROM docs WHERE id IN ({})", id_list);
         if let Ok(mut stmt) = conn.prepare(&query_docs) {
             let found_docs = stmt.query_map([], |row| {
                 Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
             });
             if let Ok(rows) = found_docs {
-                for row in rows {
-                    if let Ok((id, content)) = row {
-                        content_map.insert(id, (content, id, None, 0));
-                    }
+                for (id, content) in rows.flatten() {
+                    content_map.insert(id, stored(id, content, id, None, 0));
                 }
             }
         }
     }
 
     // If missing, try chunks table
-    let missing_ids: Vec<String> = rrf_scores
+    let missing_ids: Vec<String> = hits
         .iter()
-        .filter(|(id, _, _, _)| !content_map.contains_key(id))
-        .map(|(id, _, _, _)| id.to_string())
+        .filter(|h| !content_map.contains_key(&h.doc_id))
+        .map(|h| h.doc_id.to_string())
         .collect();
 
     if !missing_ids.is_empty() {
         let missing_list = missing_ids.join(",");
         let query_chunks = format!(
-            "SELECT c.id, c.content, c.source_id, s.metadata, c.chunk_index 
+            "SELECT c.id, c.content, c.source_id, s.metadata, c.chunk_index,
+                    COALESCE(c.chunk_type, 'general'), c.header_path
              FROM chunks c 
              LEFT JOIN sources s ON c.source_id = s.id 
-             WHERE c.id IN ({})",
-            missing_list
+             WHERE c.id IN ({}) AND {}",
+            missing_list,
+            tenant_condition("c")
         );
 
         if let Ok(mut stmt) = conn.prepare(&query_chunks) {
             let found_chunks = stmt.query_map([], |row| {
-                Ok((
-                    row.get::<_, i64>(0)?,
-                    row.get::<_, String>(1)?,
-                    row.get::<_, i64>(2)?,
-                    row.get::<_, Option<String>>(3)?,
-                    row.get::<_, u32>(4)?,
-                ))
+                Ok(HybridSearchResult {
+                    chunk_type: row.get(5)?,
+                    header_path: row.get(6)?,
+                    ..stored(row.get(0)?, read_content(row, 1)?, row.get(2)?, row.get(3)?, row.get(4)?)
+                })
             });
 
             if let Ok(results_iter) = found_chunks {
-                for row in results_iter {
-                    if let Ok((id, content, source_id, metadata, chunk_index)) = row {
-                        content_map.insert(id, (content, source_id, metadata, chunk_index));
-                    }
+                for result in results_iter.flatten() {
+                    content_map.insert(result.doc_id, result);
                 }
             }
         }
     }
 
-    let mut results: Vec<HybridSearchResult> = Vec::with_capacity(rrf_scores.len());
-
-    for (doc_id, score, vec_rank, bm25_rank) in rrf_scores {
-        if let Some((content, source_id, metadata, chunk_index)) = content_map.remove(&doc_id) {
-            results.push(HybridSearchResult {
-                doc_id,
-                content,
-                score,
-                vector_rank: vec_rank,
-                bm25_rank,
-                source_id,
-                metadata,
-                chunk_index,
-            });
+    let mut results: Vec<HybridSearchResult> = Vec::with_capacity(hits.len());
+    for hit in hits {
+        if let Some(mut result) = content_map.remove(&hit.doc_id) {
+            result.score = hit.score;
+            result.vector_rank = hit.vector_rank;
+            result.bm25_rank = hit.bm25_rank;
+            results.push(result);
         }
     }
+    results
+}
+
+/// Fill in `matched_terms` and `snippet` of the results to return.
+fn attach_snippets(results: &mut [HybridSearchResult], query_text: &str) {
+    for result in results {
+        (result.matched_terms, result.snippet) = build_snippet(&result.content, query_text);
+    }
+}
 
-    info!("[hybrid] Returning {} results", results.len());
-    Ok(results)
+/// Stages 1-3 within the collection `name` (see
+/// `HybridSearchOptions::collection`).
+fn rank_collection(
+    name: &str,
+    query_text: &str,
+    query_embedding: &[f32],
+    top_k: usize,
+    config: Option<RrfConfig>,
+    ef_search: Option<u32>,
+    tracer: &mut SearchTracer,
+) -> Result<Vec<RankedHit>, RagError> {
+    let config = config.unwrap_or_default();
+    let candidate_k = top_k
+        .saturating_mul(memory_profile().candidate_multiplier as usize)
+        .min(u32::MAX as usize);
+    let vector_started = Instant::now();
+    let mut vector_results = search_hnsw_named_with_ef(name, query_embedding, candidate_k, ef_search)?;
+    tracer.record("vector_search", vector_started.elapsed());
+    let bm25_started = Instant::now();
+    let (mut bm25_results, constraint) = search_bm25_named_constrained(name, query_text, candidate_k)?;
+    tracer.record("bm25_search", bm25_started.elapsed());
+    tracer.diagnose(|d| {
+        d.candidate_k = candidate_k as u32;
+        d.vector_candidates = vector_results.len() as u32;
+        d.bm25_candidates = bm25_results.len() as u32;
+        d.hnsw_loaded = true;
+    });
+    if let Some(constraint) = constraint {
+        vector_results.retain(|r| constraint.allows(r.id));
+        bm25_results.retain(|r| constraint.allows(r.doc_id));
+    }
+    tracer.diagnose(|d| {
+        d.filtered_vector_candidates = vector_results.len() as u32;
+        d.filtered_bm25_candidates = bm25_results.len() as u32;
+    });
+
+    let fusion_started = Instant::now();
+    let hits = fuse_candidates(&vector_results, &bm25_results, &config, top_k)?;
+    tracer.record("fusion", fusion_started.elapsed());
+    info!("[hybrid] Collection '{}' ranked {} hits", name, hits.len());
+    Ok(hits)
 }
 
 /// Simplified hybrid search returning content strings only.
@@ -490,7 +1623,8 @@ pub fn search_hybrid_simple(
     top_k: u32,
 ) -> Result<Vec<String>, RagError> {
     Ok(
-        search_hybrid(query_text, query_embedding, top_k, None, None)?
+        search_hybrid(query_text, query_embedding, top_k, None, None, None)?
+            .results
             .into_iter()
             .map(|r| r.content)
             .collect(),
@@ -509,8 +1643,220 @@ pub fn search_hybrid_weighted(
         k: 60,
         vector_weight: vector_weight.clamp(0.0, 1.0),
         bm25_weight: bm25_weight.clamp(0.0, 1.0),
+        ..RrfConfig::default()
+    };
+    Ok(search_hybrid(query_text, query_embedding, top_k, Some(config), None, None)?.results)
+}
+
+/// Hybrid search using the retrieval preset of an intent type
+/// ("define", "summary", ...; see `intent_presets`).
+///
+/// Applies the preset's top_k and weights, boosts its favored chunk type
+/// and optionally diversifies the results with MMR.
+pub fn search_hybrid_for_intent(
+    intent_type: String,
+    query_text: String,
+    query_embedding: Vec<f32>,
+    filter: Option<SearchFilter>,
+) -> Result<Vec<HybridSearchResult>, RagError> {
+    let preset = get_retrieval_preset(intent_type.clone());
+    info!("[hybrid] Intent '{}' using preset {:?}", intent_type, preset);
+
+    let config = RrfConfig {
+        k: 60,
+        vector_weight: preset.vector_weight,
+        bm25_weight: preset.bm25_weight,
+        ..RrfConfig::default()
     };
-    search_hybrid(query_text, query_embedding, top_k, Some(config), None)
+    // Over-fetch when re-ranking so boosting/MMR have something to choose from.
+    let reranks = preset.boost_chunk_type.is_some() || preset.use_mmr;
+    let fetch_k = if reranks { preset.top_k * 3 } else { preset.top_k };
+
+    let mut results = search_hybrid(
+        query_text.clone(),
+        query_embedding,
+        fetch_k,
+        Some(config),
+        filter,
+        None,
+    )?
+    .results;
+    if reranks && !results.is_empty() {
+        let chunk_info = load_chunk_type_and_embedding(&results)?;
+        results = rerank_with_preset(results, &chunk_info, &preset);
+        record_hybrid_retrieval(&query_text, &results);
+    }
+    Ok(results)
+}
+
+/// Map: chunk id -> (chunk_type, embedding)
+fn load_chunk_type_and_embedding(
+    results: &[HybridSearchResult],
+) -> Result<HashMap<i64, (String, Vec<f32>)>, RagError> {
+    let id_list = results
+        .iter()
+        .map(|r| r.doc_id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let conn = get_connection()?;
+    let query = format!(
+        "SELECT id, COALESCE(chunk_type, 'general'), embedding FROM chunks WHERE id IN ({})",
+        id_list
+    );
+    let mut stmt = conn
+        .prepare(&query)
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Vec<u8>>(2)?,
+            ))
+        })
+        .map_err(db_error)?;
+
+    let mut out = HashMap::new();
+    for (id, chunk_type, blob) in rows.flatten() {
+        out.insert(id, (chunk_type, decode_embedding(&blob)));
+    }
+    Ok(out)
+}
+
+/// Reorder `results` by MMR and keep `top_k`, with scores normalized by
+/// the best one as relevance.
+fn diversify<'a>(
+    results: Vec<HybridSearchResult>,
+    embedding_of: impl Fn(i64) -> Option<&'a [f32]>,
+    lambda: f64,
+    top_k: usize,
+) -> Vec<HybridSearchResult> {
+    let max_score = results.iter().map(|r| r.score).fold(f64::MIN, f64::max).max(f64::EPSILON);
+    let candidates: Vec<(i64, f64)> = results
+        .iter()
+        .map(|r| (r.doc_id, r.score / max_score))
+        .collect();
+    let order = mmr_select(&candidates, embedding_of, lambda, top_k);
+    let mut slots: Vec<Option<HybridSearchResult>> = results.into_iter().map(Some).collect();
+    order.into_iter().filter_map(|i| slots[i].take()).collect()
+}
+
+fn rerank_with_preset(
+    mut results: Vec<HybridSearchResult>,
+    chunk_info: &HashMap<i64, (String, Vec<f32>)>,
+    preset: &RetrievalPreset,
+) -> Vec<HybridSearchResult> {
+    if let Some(boost_type) = &preset.boost_chunk_type {
+        for r in results.iter_mut() {
+            if chunk_info.get(&r.doc_id).is_some_and(|(t, _)| t == boost_type) {
+                r.score *= preset.chunk_type_boost;
+            }
+        }
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    let top_k = preset.top_k as usize;
+    if !preset.use_mmr {
+        results.truncate(top_k);
+        return results;
+    }
+
+    let embedding_of = |id: i64| chunk_info.get(&id).map(|(_, e)| e.as_slice());
+    diversify(results, embedding_of, preset.mmr_lambda, top_k)
+}
+
+/// Paired retrieval for `/compare A vs B`.
+///
+/// Runs one hybrid search per subject and interleaves the rankings
+/// (left #1, right #1, left #2, ...). A chunk found by both subjects is
+/// kept only at its first position.
+pub fn search_hybrid_compare(
+    left_text: String,
+    left_embedding: Vec<f32>,
+    right_text: String,
+    right_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+) -> Result<Vec<CompareSearchResult>, RagError> {
+    let query = format!("{} vs {}", left_text, right_text);
+    let left = search_hybrid(
+        left_text,
+        left_embedding,
+        top_k,
+        config.clone(),
+        filter.clone(),
+        None,
+    )?
+    .results;
+    let right = search_hybrid(right_text, right_embedding, top_k, config, filter, None)?.results;
+    let results = interleave_compare_results(left, right);
+    info!("[hybrid] Compare returning {} results", results.len());
+    let combined: Vec<HybridSearchResult> = results.iter().map(|r| r.result.clone()).collect();
+    record_hybrid_retrieval(&query, &combined);
+    Ok(results)
+}
+
+/// Hybrid search followed by related-chunk expansion.
+///
+/// Each hit pulls in up to `max_related_per_hit` chunks linked to it (same
+/// source neighbours, shared entities, same split code block), scored at
+/// a decayed fraction of the hit. Expanded chunks respect `filter`.
+pub fn search_hybrid_expanded(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+    expansion: Option<ExpansionConfig>,
+) -> Result<Vec<ExpandedSearchResult>, RagError> {
+    let expansion = expansion.unwrap_or_default();
+    let mut conditions = vec![tenant_condition("c")];
+    if let Some(f) = &filter {
+        conditions.extend(filter_sql_conditions(f));
+    }
+    let hits = search_hybrid(query_text.clone(), query_embedding, top_k, config, filter, None)?.results;
+    let conn = get_connection()?;
+    let mut results = expand_with_related(&conn, hits, &expansion, &conditions).map_err(db_error)?;
+    let normalized = scoring_query_text(&query_text);
+    for expanded in results.iter_mut().filter(|r| r.expanded_from.is_some()) {
+        let (matched_terms, snippet) = build_snippet(&expanded.result.content, &normalized);
+        expanded.result.matched_terms = matched_terms;
+        expanded.result.snippet = snippet;
+    }
+    info!(
+        "[hybrid] Expansion returning {} results ({} related)",
+        results.len(),
+        results.iter().filter(|r| r.expanded_from.is_some()).count()
+    );
+    let combined: Vec<HybridSearchResult> = results.iter().map(|r| r.result.clone()).collect();
+    record_hybrid_retrieval(&query_text, &combined);
+    Ok(results)
+}
+
+fn interleave_compare_results(
+    left: Vec<HybridSearchResult>,
+    right: Vec<HybridSearchResult>,
+) -> Vec<CompareSearchResult> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+    loop {
+        let l = left.next();
+        let r = right.next();
+        if l.is_none() && r.is_none() {
+            break;
+        }
+        for (subject_index, result) in [(0, l), (1, r)] {
+            if let Some(result) = result {
+                if seen.insert(result.doc_id) {
+                    out.push(CompareSearchResult { subject_index, result });
+                }
+            }
+        }
+    }
+    out
 }
 
 #[cfg(test)]
@@ -531,12 +1877,320 @@ mod tests {
         out
     }
 
+    #[test]
+    fn test_exact_scan_keeps_closest_k() {
+        let rows: Vec<ScanRow> = (0..200)
+            .map(|i| {
+                let angle = i as f32 * 0.01;
+                ScanRow {
+                    id: i,
+                    embedding_blob: embedding_to_blob(&[angle.cos(), angle.sin()]),
+                    content: if i % 50 == 0 {
+                        "rayon heap".to_string()
+                    } else {
+                        "other text".to_string()
+                    },
+                }
+            })
+            .chain(std::iter::once(ScanRow {
+                id: 999,
+                embedding_blob: embedding_to_blob(&[1.0, 0.0, 0.0]),
+                content: String::new(),
+            }))
+            .collect();
+        let query_tokens: HashSet<String> = tokenize_for_bm25("rayon").into_iter().collect();
+
+        let scan = exact_scan(&rows, &[1.0, 0.0], &query_tokens, 5);
+        let ids: Vec<i64> = scan.vector_results.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+
+        let matching: Vec<i64> = scan
+            .term_stats
+            .iter()
+            .filter(|(_, _, tf)| !tf.is_empty())
+            .map(|(id, _, _)| *id)
+            .collect();
+        assert_eq!(matching.len(), 4);
+        assert!(matching.iter().all(|id| id % 50 == 0));
+    }
+
     #[test]
     fn test_rrf_score() {
         let score = rrf_score(1, 60);
         assert!((score - 0.0164).abs() < 0.001);
     }
 
+    #[test]
+    fn test_normalize_scores() {
+        let scores = [(1, 12.0), (2, 3.0), (3, 2.0)];
+        let (min_max, floor) = normalize_scores(&scores, ScoreNormalization::MinMax);
+        assert_eq!((min_max[&1], min_max[&3], floor), (1.0, 0.0, 0.0));
+        assert!((min_max[&2] - 0.1).abs() < 1e-9);
+
+        // The standout keeps its margin; all values stay within 0-1.
+        let (z, floor) = normalize_scores(&scores, ScoreNormalization::ZScore);
+        assert!(z[&1] > 0.75 && z[&2] < 0.5 && floor == z[&3]);
+        assert_eq!(normalize_scores(&[(7, 0.4)], ScoreNormalization::MinMax).0[&7], 1.0);
+    }
+
+    #[test]
+    fn test_search_hybrid_options_group_and_page() {
+        use crate::test_support::{fake_embedding, TestEngine, TEST_EMBEDDING_DIM};
+
+        let engine = TestEngine::new().unwrap();
+        let guide = engine
+            .source("Apple guide")
+            .chunk("apple orchards in autumn")
+            .chunk("apple varieties for baking")
+            .chunk("apple storage over winter")
+            .add()
+            .unwrap();
+        let recipe = engine.source("Pie").chunk("apple pie recipe").add().unwrap();
+        engine.rebuild_indexes().unwrap();
+        let search = |top_k: u32, options: HybridSearchOptions| {
+            let embedding = fake_embedding("apple", TEST_EMBEDDING_DIM);
+            search_hybrid("apple".to_string(), embedding, top_k, None, None, Some(options))
+        };
+
+        let grouped = search(2, HybridSearchOptions { group_by_source: Some(1), ..Default::default() }).unwrap();
+        let groups = grouped.groups.unwrap();
+        let mut sources: Vec<i64> = groups.iter().map(|g| g.source_id).collect();
+        sources.sort();
+        assert_eq!(sources, vec![guide, recipe]);
+        assert!(groups.iter().all(|g| g.chunks.len() == 1 && !g.is_document));
+        assert!(grouped.results.is_empty());
+
+        let first = search(3, HybridSearchOptions { page_offset: Some(0), ..Default::default() }).unwrap();
+        assert_eq!(first.results.len(), 3);
+        let cursor = first.next_cursor.unwrap();
+        let second = search(3, HybridSearchOptions { cursor: Some(cursor), ..Default::default() }).unwrap();
+        let mut ids: Vec<i64> = first.results.iter().chain(&second.results).map(|r| r.doc_id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 4);
+        assert!(second.next_cursor.is_none());
+
+        let diagnosed = search(1, HybridSearchOptions { with_diagnostics: true, ..Default::default() }).unwrap();
+        assert!(diagnosed.diagnostics.unwrap().candidate_k >= 1);
+        let paged_groups = HybridSearchOptions {
+            page_offset: Some(0),
+            group_by_source: Some(1),
+            ..Default::default()
+        };
+        assert!(search(1, paged_groups).is_err());
+        let embedding = fake_embedding("apple", TEST_EMBEDDING_DIM);
+        let collection = HybridSearchOptions { collection: Some("notes".to_string()), ..Default::default() };
+        let filter = SearchFilter { source_ids: Some(vec![guide]), ..Default::default() };
+        assert!(search_hybrid("apple".to_string(), embedding, 1, None, Some(filter), Some(collection)).is_err());
+    }
+
+    #[test]
+    fn test_fuse_candidates_follows_fusion_strategy() {
+        let vector = [
+            HnswSearchResult { id: 1, distance: 0.0 },
+            HnswSearchResult { id: 2, distance: 0.1 },
+            HnswSearchResult { id: 3, distance: 1.0 },
+        ];
+        let bm25 = [
+            Bm25SearchResult { doc_id: 2, score: 10.0 },
+            Bm25SearchResult { doc_id: 4, score: 9.95 },
+            Bm25SearchResult { doc_id: 1, score: 9.9 },
+            Bm25SearchResult { doc_id: 3, score: 0.0 },
+        ];
+        // Ranks favor doc 2; scores show doc 1 all but tied on BM25.
+        let rrf = RrfConfig::default();
+        assert_eq!(fuse_candidates(&vector, &bm25, &rrf, 10).unwrap()[0].doc_id, 2);
+        let weighted = RrfConfig {
+            fusion: FusionStrategy::WeightedScore { normalization: ScoreNormalization::MinMax },
+            ..RrfConfig::default()
+        };
+        let hits = fuse_candidates(&vector, &bm25, &weighted, 1).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].doc_id, 1);
+        assert!((hits[0].score - 0.995).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hydrate_keeps_rank_order_and_drops_missing() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE docs (id INTEGER PRIMARY KEY, content TEXT);
+             INSERT INTO docs VALUES (1, 'one'), (2, 'two'), (3, 'three');",
+        )
+        .unwrap();
+        let hit = |doc_id: i64, score: f64| RankedHit {
+            doc_id,
+            score,
+            vector_rank: 1,
+            bm25_rank: 0,
+        };
+        let hits = vec![hit(3, 0.9), hit(99, 0.5), hit(1, 0.2)];
+
+        let mut results = hydrate(&conn, &hits, true);
+        assert!(results.iter().all(|r| r.snippet.is_none()));
+        attach_snippets(&mut results, "three");
+        let ids: Vec<i64> = results.iter().map(|r| r.doc_id).collect();
+        assert_eq!(ids, vec![3, 1]);
+        assert_eq!(results[0].content, "three");
+        assert_eq!(results[1].score, 0.2);
+        assert_eq!(results[0].matched_terms, vec!["three".to_string()]);
+        assert!(results[1].matched_terms.is_empty());
+
+        assert!(hydrate(&conn, &hits, false).is_empty());
+    }
+
+    #[test]
+    fn test_interleave_compare_results() {
+        let make = |doc_id: i64| HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score: 0.0,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id: 0,
+            metadata: None,
+            chunk_index: 0,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        };
+        let out = interleave_compare_results(vec![make(1), make(2), make(3)], vec![make(4), make(1)]);
+        let order: Vec<(u32, i64)> = out.iter().map(|r| (r.subject_index, r.result.doc_id)).collect();
+        assert_eq!(order, vec![(0, 1), (1, 4), (0, 2), (0, 3)]);
+    }
+
+    #[test]
+    fn test_rerank_with_preset_boost_and_mmr() {
+        let make = |doc_id: i64, score: f64| HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id: 0,
+            metadata: None,
+            chunk_index: 0,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        };
+        let mut info = HashMap::new();
+        info.insert(1, ("general".to_string(), vec![1.0, 0.0]));
+        info.insert(2, ("general".to_string(), vec![1.0, 0.0]));
+        info.insert(3, ("definition".to_string(), vec![0.0, 1.0]));
+
+        let boost = RetrievalPreset {
+            top_k: 1,
+            boost_chunk_type: Some("definition".to_string()),
+            chunk_type_boost: 2.0,
+            ..RetrievalPreset::default()
+        };
+        let out = rerank_with_preset(vec![make(1, 1.0), make(2, 0.9), make(3, 0.6)], &info, &boost);
+        assert_eq!(out[0].doc_id, 3);
+
+        // Doc 2 duplicates doc 1, so MMR picks the dissimilar doc 3 second.
+        let mmr = RetrievalPreset { top_k: 2, use_mmr: true, mmr_lambda: 0.5, ..RetrievalPreset::default() };
+        let out = rerank_with_preset(vec![make(1, 1.0), make(2, 0.9), make(3, 0.6)], &info, &mmr);
+        let ids: Vec<i64> = out.iter().map(|r| r.doc_id).collect();
+        assert_eq!(ids, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_apply_type_boosts() {
+        let mut scores = vec![(1, 1.0, 1, 0), (2, 0.8, 2, 0), (3, 0.6, 0, 1)];
+        let types: HashMap<i64, String> = [(1, "code"), (2, "definition"), (3, "general")]
+            .into_iter()
+            .map(|(id, t)| (id, t.to_string()))
+            .collect();
+        let boosts: HashMap<String, f64> = [("definition".to_string(), 2.0), ("code".to_string(), -1.0)]
+            .into_iter()
+            .collect();
+        apply_type_boosts(&mut scores, &types, &boosts);
+        let boosted: Vec<f64> = scores.iter().map(|s| s.1).collect();
+        assert_eq!(boosted, vec![0.0, 1.6, 0.6]);
+    }
+
+    #[test]
+    fn test_apply_recency_decays_old_sources() {
+        let day = 86_400;
+        let now = 100 * day;
+        let mut scores = vec![(1, 1.0, 1, 0), (2, 1.0, 2, 0), (3, 1.0, 3, 0)];
+        let dated: HashMap<i64, i64> = [(1, now), (2, now - 7 * day)].into_iter().collect();
+        apply_recency(&mut scores, &dated, now, 7.0, 0.4);
+        assert!((scores[0].1 - 1.0).abs() < 1e-9);
+        assert!((scores[1].1 - 0.8).abs() < 1e-9);
+        // Undated chunks get the full decay.
+        assert!((scores[2].1 - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_structured_filter_conditions() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, metadata TEXT, created_at INTEGER);
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER, chunk_type TEXT);
+             INSERT INTO sources VALUES (1, '{\"team\": \"infra\", \"year\": 2024}', 100),
+                                        (2, 'not json', 200),
+                                        (3, '{\"team\": \"web\"}', 300);
+             INSERT INTO chunks VALUES (10, 1, 'definition'), (11, 1, NULL),
+                                       (20, 2, 'definition'), (30, 3, 'code');",
+        )
+        .unwrap();
+        let matching = |filter: SearchFilter| -> Vec<i64> {
+            let conditions = filter_sql_conditions(&filter);
+            let query = format!(
+                "SELECT c.id FROM chunks c LEFT JOIN sources s ON c.source_id = s.id
+                 WHERE {} ORDER BY c.id",
+                conditions.join(" AND ")
+            );
+            let mut stmt = conn.prepare(&query).unwrap();
+            let ids = stmt.query_map([], |row| row.get(0)).unwrap();
+            ids.map(|id| id.unwrap()).collect()
+        };
+
+        let by_type = SearchFilter {
+            chunk_types: Some(vec!["definition".to_string(), "general".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(matching(by_type), vec![10, 11, 20]);
+        let by_metadata = SearchFilter {
+            metadata_equals: Some(vec![
+                ("team".to_string(), "infra".to_string()),
+                ("year".to_string(), "2024".to_string()),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(matching(by_metadata), vec![10, 11]);
+        let by_date = SearchFilter {
+            created_after: Some(200),
+            created_before: Some(300),
+            ..Default::default()
+        };
+        assert_eq!(matching(by_date.clone()), vec![20]);
+        assert!(!narrows_within_sources(&by_date));
+        assert!(narrows_within_sources(&SearchFilter {
+            source_ids: Some(vec![1]),
+            chunk_types: Some(vec!["code".to_string()]),
+            ..Default::default()
+        }));
+
+        conn.execute(
+            "INSERT INTO sources VALUES (4, '{\"draft\": true, \"pinned\": false}', 400)",
+            [],
+        )
+        .unwrap();
+        conn.execute("INSERT INTO chunks VALUES (40, 4, 'general')", []).unwrap();
+        let by_flag = |key: &str, value: &str| SearchFilter {
+            metadata_equals: Some(vec![(key.to_string(), value.to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(matching(by_flag("draft", "true")), vec![40]);
+        assert_eq!(matching(by_flag("pinned", "false")), vec![40]);
+        assert!(matching(by_flag("draft", "1")).is_empty());
+    }
+
     #[test]
     fn test_rrf_config_default() {
         let config = RrfConfig::default();
@@ -596,8 +2250,10 @@ mod tests {
             2,
             None,
             None, // No filter
+            None,
         )
-        .unwrap();
+        .unwrap()
+        .results;
 
         // Verify results
         assert_eq!(results.len(), 2);
@@ -671,10 +2327,12 @@ mod tests {
             None,
             Some(SearchFilter {
                 source_ids: Some(vec![1]),
-                metadata_like: None,
+                ..Default::default()
             }),
+            None,
         )
-        .unwrap();
+        .unwrap()
+        .results;
 
         assert!(!results.is_empty());
         assert!(results.iter().all(|r| r.source_id == 1));
@@ -15,15 +15,64 @@
 // Please submit a PR with detailed explanation of changes before modifying.
 //
 //! Incremental Vector Index with Dual-Index Strategy (buffer + HNSW).
+//!
+//! `merge_buffer_into_hnsw` folds the buffer into the graph on the
+//! background worker; with `auto_merge` set that happens by itself once
+//! the buffer reaches its threshold.
 
-use std::sync::RwLock;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, RwLock};
 use once_cell::sync::Lazy;
 use log::{info, debug, warn};
-use crate::api::hnsw_index::{search_hnsw, is_hnsw_index_loaded};
+use rusqlite::params;
+use crate::api::background_tasks::submit_buffer_merge;
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::decode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::{
+    build_hnsw_index_for_tenant, hnsw_point_ids, insert_into_tenant_index, is_hnsw_index_loaded, search_hnsw,
+};
+use crate::api::progress::Progress;
+use crate::api::tenant::active_tenant;
 
 const BUFFER_THRESHOLD: usize = 100;
 
-static RECENT_BUFFER: Lazy<RwLock<Vec<BufferEntry>>> = Lazy::new(|| RwLock::new(Vec::new()));
+/// The table whose row ids a buffer holds. `docs` and `chunks` ids
+/// overlap, so each gets its own buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BufferTable {
+    /// Source chunks (`source_rag`).
+    Chunks,
+    /// Simple RAG documents (`simple_rag`).
+    Docs,
+}
+
+type BufferKey = (String, BufferTable);
+
+/// Recent vectors, one buffer per tenant and table.
+static RECENT_BUFFER: Lazy<RwLock<HashMap<BufferKey, Vec<BufferEntry>>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Buffers with an automatic merge queued, so a growing buffer queues one.
+static AUTO_MERGE_PENDING: Lazy<Mutex<HashSet<BufferKey>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+static CONFIG: RwLock<IncrementalConfig> = RwLock::new(IncrementalConfig { auto_merge: false });
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IncrementalConfig {
+    /// Queue `merge_buffer_into_hnsw` when the buffer reaches the threshold.
+    pub auto_merge: bool,
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_incremental_config(config: IncrementalConfig) {
+    info!("[incremental] Config: {:?}", config);
+    *CONFIG.write().unwrap() = config;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_incremental_config() -> IncrementalConfig {
+    *CONFIG.read().unwrap()
+}
 
 #[derive(Clone, Debug)]
 struct BufferEntry {
@@ -45,30 +94,133 @@ impl BufferEntry {
     }
 }
 
-/// Add a single vector to buffer (immediately searchable).
-pub fn incremental_add(doc_id: i64, embedding: Vec<f32>) {
+/// Add a single vector to `table`'s buffer (immediately searchable).
+pub fn incremental_add(table: BufferTable, doc_id: i64, embedding: Vec<f32>) {
     let entry = BufferEntry::new(doc_id, embedding);
-    let mut buffer = RECENT_BUFFER.write().unwrap();
+    let mut buffers = RECENT_BUFFER.write().unwrap();
+    let buffer = buffers.entry((active_tenant(), table)).or_default();
     buffer.push(entry);
     let buffer_size = buffer.len();
+    drop(buffers);
     debug!("[incremental] Added doc {} to buffer, size: {}", doc_id, buffer_size);
     if buffer_size >= BUFFER_THRESHOLD {
-        warn!("[incremental] Buffer threshold reached ({}), consider calling merge_buffer()", buffer_size);
+        on_threshold_reached(table, buffer_size);
     }
 }
 
-/// Add multiple vectors to buffer.
-pub fn incremental_add_batch(docs: Vec<(i64, Vec<f32>)>) {
-    let mut buffer = RECENT_BUFFER.write().unwrap();
+/// Add multiple vectors to `table`'s buffer.
+pub fn incremental_add_batch(table: BufferTable, docs: Vec<(i64, Vec<f32>)>) {
+    let mut buffers = RECENT_BUFFER.write().unwrap();
+    let buffer = buffers.entry((active_tenant(), table)).or_default();
     for (doc_id, embedding) in docs {
         buffer.push(BufferEntry::new(doc_id, embedding));
     }
-    info!("[incremental] Added batch to buffer, total size: {}", buffer.len());
+    let buffer_size = buffer.len();
+    drop(buffers);
+    info!("[incremental] Added batch to buffer, total size: {}", buffer_size);
+    if buffer_size >= BUFFER_THRESHOLD {
+        on_threshold_reached(table, buffer_size);
+    }
+}
+
+/// Queue a merge if `auto_merge` is on and none is pending, else warn.
+fn on_threshold_reached(table: BufferTable, buffer_size: usize) {
+    if !get_incremental_config().auto_merge {
+        warn!("[incremental] Buffer threshold reached ({}), consider calling merge_buffer_into_hnsw()", buffer_size);
+        return;
+    }
+    let key = (active_tenant(), table);
+    if !AUTO_MERGE_PENDING.lock().unwrap().insert(key.clone()) {
+        return;
+    }
+    match submit_buffer_merge(table) {
+        Ok(task_id) => info!("[incremental] Buffer at {}, queued merge task {}", buffer_size, task_id),
+        Err(e) => {
+            warn!("[incremental] Could not queue buffer merge: {}", e);
+            AUTO_MERGE_PENDING.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+/// Fold the active tenant's buffer of `table` into its HNSW index on the
+/// background worker. Returns the task id for `get_background_task`.
+///
+/// Buffered vectors are inserted into the live graph when one is loaded;
+/// otherwise (or when an id was deleted from it before) the graph is
+/// rebuilt from `table` plus the buffer and swapped in once built, so
+/// searches keep using the old one meanwhile. Vectors added during the
+/// merge stay buffered.
+pub fn merge_buffer_into_hnsw(table: BufferTable) -> Result<i64, RagError> {
+    submit_buffer_merge(table)
+}
+
+/// Embeddings of `tenant_id`'s rows in `table` from the database (the
+/// docs table has no tenants).
+fn stored_points(tenant_id: &str, table: BufferTable) -> Result<Vec<(i64, Vec<f32>)>, RagError> {
+    let conn = get_connection()?;
+    let point = |row: &rusqlite::Row| Ok((row.get(0)?, decode_embedding(&row.get::<_, Vec<u8>>(1)?)));
+    let points = match table {
+        BufferTable::Chunks => conn
+            .prepare("SELECT id, embedding FROM chunks WHERE tenant_id = ?1")
+            .map_err(db_error)?
+            .query_map(params![tenant_id], point)
+            .map_err(db_error)?
+            .collect::<rusqlite::Result<_>>(),
+        BufferTable::Docs => conn
+            .prepare("SELECT id, embedding FROM docs")
+            .map_err(db_error)?
+            .query_map([], point)
+            .map_err(db_error)?
+            .collect::<rusqlite::Result<_>>(),
+    };
+    points.map_err(db_error)
 }
 
-/// Remove a document from buffer.
-pub fn incremental_remove(doc_id: i64) {
-    let mut buffer = RECENT_BUFFER.write().unwrap();
+/// Merge `tenant_id`'s buffer of `table` into its index (on the calling
+/// thread). Returns how many buffered vectors were merged.
+pub(crate) fn merge_tenant_buffer(tenant_id: &str, table: BufferTable) -> Result<usize, RagError> {
+    let key = (tenant_id.to_string(), table);
+    AUTO_MERGE_PENDING.lock().unwrap().remove(&key);
+    let entries: Vec<(i64, Vec<f32>)> = RECENT_BUFFER
+        .read()
+        .unwrap()
+        .get(&key)
+        .into_iter()
+        .flatten()
+        .map(|entry| (entry.id, entry.embedding.clone()))
+        .collect();
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    let merged: HashSet<i64> = entries.iter().map(|(id, _)| *id).collect();
+
+    let inserted = match hnsw_point_ids(tenant_id) {
+        Some(indexed) => {
+            let indexed: HashSet<i64> = indexed.into_iter().collect();
+            let new: Vec<(i64, Vec<f32>)> = entries.iter().filter(|(id, _)| !indexed.contains(id)).cloned().collect();
+            insert_into_tenant_index(tenant_id, new, Progress::NONE)?.is_some()
+        }
+        None => false,
+    };
+    if !inserted {
+        let mut points: Vec<(i64, Vec<f32>)> =
+            stored_points(tenant_id, table)?.into_iter().filter(|(id, _)| !merged.contains(id)).collect();
+        points.extend(entries);
+        info!("[incremental] Rebuilding index of tenant {} with {} points", tenant_id, points.len());
+        build_hnsw_index_for_tenant(tenant_id, points)?;
+    }
+
+    if let Some(buffer) = RECENT_BUFFER.write().unwrap().get_mut(&key) {
+        buffer.retain(|entry| !merged.contains(&entry.id));
+    }
+    info!("[incremental] Merged {} buffered vectors of tenant {}", merged.len(), tenant_id);
+    Ok(merged.len())
+}
+
+/// Remove a row from `table`'s buffer.
+pub fn incremental_remove(table: BufferTable, doc_id: i64) {
+    let mut buffers = RECENT_BUFFER.write().unwrap();
+    let Some(buffer) = buffers.get_mut(&(active_tenant(), table)) else { return };
     let initial_len = buffer.len();
     buffer.retain(|entry| entry.id != doc_id);
     if buffer.len() < initial_len { debug!("[incremental] Removed doc {} from buffer", doc_id); }
@@ -81,14 +233,18 @@ pub struct IncrementalSearchResult {
     pub source: String,
 }
 
-/// Search both buffer and HNSW.
-pub fn incremental_search(query_embedding: Vec<f32>, top_k: usize) -> anyhow::Result<Vec<IncrementalSearchResult>> {
+/// Search both `table`'s buffer and HNSW.
+pub fn incremental_search(
+    table: BufferTable,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+) -> anyhow::Result<Vec<IncrementalSearchResult>> {
     let query_norm = query_embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
     let mut all_results: Vec<(i64, f32, &str)> = Vec::new();
     
     {
-        let buffer = RECENT_BUFFER.read().unwrap();
-        for entry in buffer.iter() {
+        let buffers = RECENT_BUFFER.read().unwrap();
+        for entry in buffers.get(&(active_tenant(), table)).into_iter().flatten() {
             let distance = entry.cosine_distance(&query_embedding, query_norm);
             all_results.push((entry.id, distance, "buffer"));
         }
@@ -119,26 +275,54 @@ pub struct BufferStats {
     pub hnsw_loaded: bool,
 }
 
-pub fn get_buffer_stats() -> BufferStats {
-    let buffer = RECENT_BUFFER.read().unwrap();
-    BufferStats { buffer_size: buffer.len(), threshold: BUFFER_THRESHOLD, hnsw_loaded: is_hnsw_index_loaded() }
+pub fn get_buffer_stats(table: BufferTable) -> BufferStats {
+    BufferStats { buffer_size: buffer_len(table), threshold: BUFFER_THRESHOLD, hnsw_loaded: is_hnsw_index_loaded() }
 }
 
-/// Clear buffer.
-pub fn clear_buffer() {
-    let mut buffer = RECENT_BUFFER.write().unwrap();
-    buffer.clear();
-    info!("[incremental] Buffer cleared");
+/// Clear `table`'s buffer.
+pub fn clear_buffer(table: BufferTable) {
+    drop_tenant_buffer(&active_tenant(), table);
 }
 
-/// Check if buffer needs merging.
-pub fn needs_merge() -> bool {
-    RECENT_BUFFER.read().unwrap().len() >= BUFFER_THRESHOLD
+pub(crate) fn drop_tenant_buffer(tenant_id: &str, table: BufferTable) {
+    RECENT_BUFFER.write().unwrap().remove(&(tenant_id.to_string(), table));
+    info!("[incremental] {:?} buffer cleared for tenant {}", table, tenant_id);
 }
 
-/// Get buffer entries for HNSW rebuild.
-pub fn get_buffer_for_merge() -> Vec<(i64, Vec<f32>)> {
-    RECENT_BUFFER.read().unwrap().iter().map(|entry| (entry.id, entry.embedding.clone())).collect()
+/// Drop both of `tenant_id`'s buffers.
+pub(crate) fn drop_tenant_buffers(tenant_id: &str) {
+    RECENT_BUFFER.write().unwrap().retain(|(tenant, _), _| tenant != tenant_id);
+    info!("[incremental] Buffers cleared for tenant {}", tenant_id);
+}
+
+/// Drop every tenant's buffer.
+pub(crate) fn clear_all_buffers() {
+    RECENT_BUFFER.write().unwrap().clear();
+    info!("[incremental] All buffers cleared");
+}
+
+fn buffer_len(table: BufferTable) -> usize {
+    tenant_buffer_len(&active_tenant(), table)
+}
+
+pub(crate) fn tenant_buffer_len(tenant_id: &str, table: BufferTable) -> usize {
+    RECENT_BUFFER.read().unwrap().get(&(tenant_id.to_string(), table)).map_or(0, |b| b.len())
+}
+
+/// Check if `table`'s buffer needs merging.
+pub fn needs_merge(table: BufferTable) -> bool {
+    buffer_len(table) >= BUFFER_THRESHOLD
+}
+
+/// Get `table`'s buffer entries for HNSW rebuild.
+pub fn get_buffer_for_merge(table: BufferTable) -> Vec<(i64, Vec<f32>)> {
+    let buffers = RECENT_BUFFER.read().unwrap();
+    buffers
+        .get(&(active_tenant(), table))
+        .into_iter()
+        .flatten()
+        .map(|entry| (entry.id, entry.embedding.clone()))
+        .collect()
 }
 
 #[cfg(test)]
@@ -151,11 +335,32 @@ mod tests {
 
     #[test]
     fn test_incremental_add_and_search() {
-        clear_buffer();
-        incremental_add(1, make_embedding(1.0));
-        incremental_add(2, make_embedding(2.0));
-        let results = incremental_search(make_embedding(1.0), 3).unwrap();
+        clear_buffer(BufferTable::Docs);
+        incremental_add(BufferTable::Docs, 1, make_embedding(1.0));
+        incremental_add(BufferTable::Docs, 2, make_embedding(2.0));
+        let results = incremental_search(BufferTable::Docs, make_embedding(1.0), 3).unwrap();
         assert_eq!(results[0].doc_id, 1);
-        clear_buffer();
+        // Chunk ids live in their own buffer.
+        assert!(get_buffer_for_merge(BufferTable::Chunks).iter().all(|(id, _)| *id != 1));
+        clear_buffer(BufferTable::Docs);
+    }
+
+    #[test]
+    fn test_merge_inserts_buffer_into_live_index() {
+        let tenant = "incremental_merge_test";
+        let points: Vec<(i64, Vec<f32>)> = (0..20).map(|i| (i, make_embedding(i as f32))).collect();
+        build_hnsw_index_for_tenant(tenant, points).unwrap();
+        // Id 5 is already indexed and must not be inserted twice.
+        let buffered = vec![BufferEntry::new(5, make_embedding(5.0)), BufferEntry::new(100, make_embedding(100.0))];
+        RECENT_BUFFER.write().unwrap().insert((tenant.to_string(), BufferTable::Chunks), buffered);
+
+        assert_eq!(merge_tenant_buffer(tenant, BufferTable::Chunks).unwrap(), 2);
+        assert_eq!(tenant_buffer_len(tenant, BufferTable::Chunks), 0);
+        let mut ids = hnsw_point_ids(tenant).unwrap();
+        ids.sort_unstable();
+        assert_eq!(ids.len(), 21);
+        assert_eq!(ids.last(), Some(&100));
+        assert_eq!(merge_tenant_buffer(tenant, BufferTable::Chunks).unwrap(), 0);
+        crate::api::hnsw_index::drop_tenant_hnsw_index(tenant);
     }
 }
@@ -0,0 +1,398 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Change tracking between the database and the in-memory indexes.
+//!
+//! Triggers on `chunks` bump per-tenant counters in `index_changes`:
+//! inserts and deletes bump both, embedding updates the vector counter
+//! and content updates the text counter. The chunk index rebuilds record
+//! the counter they were built from, so `ensure_indexes_fresh` can
+//! rebuild only the HNSW or BM25 index whose data changed since.
+//! `add_chunks` inserts into a loaded HNSW index directly and advances
+//! its counter, so new chunks alone do not make it stale.
+//!
+//! An index loaded from disk has no recorded counter; the first check
+//! adopts the current one, so changes made before that are not seen.
+//! `verify_index_consistency` covers that gap (and crashes between a
+//! database write and the index update) by comparing ids directly.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::bm25_search::{
+    bm25_add_documents_to_tenant, bm25_document_ids, bm25_remove_documents_from_tenant, tenant_bm25_index_loaded,
+};
+use crate::api::content_crypto::read_content;
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::decode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::fts_keyword::fts_keyword_enabled;
+use crate::api::hnsw_index::{add_tombstones, hnsw_point_ids, insert_into_tenant_index, tenant_hnsw_index_loaded};
+use crate::api::named_bm25::delete_named_bm25_chunks;
+use crate::api::named_index::delete_named_chunks;
+use crate::api::progress::{Progress, OP_BM25_REBUILD, OP_HNSW_REBUILD};
+use crate::api::source_rag::{rebuild_chunk_hnsw_index, rebuild_tenant_chunk_bm25, rebuild_tenant_chunk_hnsw};
+use crate::api::tenant::active_tenant;
+
+/// `(vector, text)` counters an in-memory index pair reflects.
+type IndexedVersions = (Option<i64>, Option<i64>);
+
+static INDEXED_VERSIONS: Lazy<RwLock<HashMap<String, IndexedVersions>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IndexKind {
+    Vector,
+    Text,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IndexStaleness {
+    pub hnsw_stale: bool,
+    pub bm25_stale: bool,
+}
+
+pub(crate) fn create_change_tracking(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS index_changes (
+            tenant_id TEXT PRIMARY KEY,
+            vector_version INTEGER NOT NULL DEFAULT 0,
+            text_version INTEGER NOT NULL DEFAULT 0
+        );
+        CREATE TRIGGER IF NOT EXISTS chunks_track_insert AFTER INSERT ON chunks BEGIN
+            INSERT INTO index_changes (tenant_id, vector_version, text_version) VALUES (NEW.tenant_id, 1, 1)
+            ON CONFLICT(tenant_id) DO UPDATE SET vector_version = vector_version + 1, text_version = text_version + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS chunks_track_delete AFTER DELETE ON chunks BEGIN
+            INSERT INTO index_changes (tenant_id, vector_version, text_version) VALUES (OLD.tenant_id, 1, 1)
+            ON CONFLICT(tenant_id) DO UPDATE SET vector_version = vector_version + 1, text_version = text_version + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS chunks_track_embedding AFTER UPDATE OF embedding ON chunks BEGIN
+            INSERT INTO index_changes (tenant_id, vector_version) VALUES (NEW.tenant_id, 1)
+            ON CONFLICT(tenant_id) DO UPDATE SET vector_version = vector_version + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS chunks_track_content AFTER UPDATE OF content ON chunks BEGIN
+            INSERT INTO index_changes (tenant_id, text_version) VALUES (NEW.tenant_id, 1)
+            ON CONFLICT(tenant_id) DO UPDATE SET text_version = text_version + 1;
+        END;",
+    )
+}
+
+/// Current `(vector, text)` counters of `tenant_id`.
+pub(crate) fn change_versions(conn: &Connection, tenant_id: &str) -> rusqlite::Result<(i64, i64)> {
+    Ok(conn
+        .query_row(
+            "SELECT vector_version, text_version FROM index_changes WHERE tenant_id = ?1",
+            params![tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?
+        .unwrap_or((0, 0)))
+}
+
+/// Record that `tenant_id`'s `kind` index now reflects `version`.
+pub(crate) fn record_indexed(tenant_id: &str, kind: IndexKind, version: i64) {
+    let mut versions = INDEXED_VERSIONS.write().unwrap();
+    let entry = versions.entry(tenant_id.to_string()).or_default();
+    match kind {
+        IndexKind::Vector => entry.0 = Some(version),
+        IndexKind::Text => entry.1 = Some(version),
+    }
+}
+
+/// Record that changes taking the counter from `from` to `to` were applied
+/// to `tenant_id`'s `kind` index in place. Ignored unless the index was at
+/// `from`, since other changes may have happened in between.
+pub(crate) fn advance_indexed(tenant_id: &str, kind: IndexKind, from: i64, to: i64) {
+    let mut versions = INDEXED_VERSIONS.write().unwrap();
+    let Some(entry) = versions.get_mut(tenant_id) else { return };
+    let indexed = match kind {
+        IndexKind::Vector => &mut entry.0,
+        IndexKind::Text => &mut entry.1,
+    };
+    if *indexed == Some(from) {
+        *indexed = Some(to);
+    }
+}
+
+/// Forget what `tenant_id`'s indexes were built from, e.g. after its
+/// database was replaced.
+pub(crate) fn forget_indexed_versions(tenant_id: &str) {
+    INDEXED_VERSIONS.write().unwrap().remove(tenant_id);
+}
+
+/// Whether an index is stale given if it is loaded, the counter it was
+/// built from and the current counter. Adopts `current` when unknown.
+fn is_stale(loaded: bool, has_chunks: bool, indexed: &mut Option<i64>, current: i64) -> bool {
+    if !loaded {
+        return has_chunks;
+    }
+    match *indexed {
+        Some(version) => version != current,
+        None => {
+            *indexed = Some(current);
+            false
+        }
+    }
+}
+
+/// Compare the active tenant's indexes against the database without
+/// rebuilding anything.
+pub fn check_index_freshness() -> Result<IndexStaleness, RagError> {
+    check_tenant_index_freshness(active_tenant())
+}
+
+fn check_tenant_index_freshness(tenant_id: String) -> Result<IndexStaleness, RagError> {
+    let conn = get_connection()?;
+    let (vector_version, text_version) = change_versions(&conn, &tenant_id).map_err(db_error)?;
+    let has_chunks: bool = conn
+        .query_row(
+            "SELECT EXISTS (SELECT 1 FROM chunks WHERE tenant_id = ?1)",
+            params![tenant_id],
+            |row| row.get(0),
+        )
+        .map_err(db_error)?;
+    let (hnsw_loaded, bm25_loaded) = (tenant_hnsw_index_loaded(&tenant_id), tenant_bm25_index_loaded(&tenant_id));
+    let mut versions = INDEXED_VERSIONS.write().unwrap();
+    let indexed = versions.entry(tenant_id).or_default();
+    let freshness = IndexStaleness {
+        hnsw_stale: is_stale(hnsw_loaded, has_chunks, &mut indexed.0, vector_version),
+        // FTS5 tables are kept in sync by triggers and never go stale.
+        bm25_stale: !fts_keyword_enabled() && is_stale(bm25_loaded, has_chunks, &mut indexed.1, text_version),
+    };
+    debug!("[index_freshness] {:?}", freshness);
+    Ok(freshness)
+}
+
+/// Rebuild whichever of the active tenant's HNSW and BM25 indexes is
+/// stale. Returns what was found stale (and therefore rebuilt).
+pub fn ensure_indexes_fresh() -> Result<IndexStaleness, RagError> {
+    ensure_tenant_indexes_fresh(&active_tenant())
+}
+
+/// `ensure_indexes_fresh` for `tenant_id`, whichever tenant is active.
+pub(crate) fn ensure_tenant_indexes_fresh(tenant_id: &str) -> Result<IndexStaleness, RagError> {
+    let freshness = check_tenant_index_freshness(tenant_id.to_string())?;
+    if freshness.hnsw_stale {
+        info!("[index_freshness] HNSW index of tenant {} is stale, rebuilding", tenant_id);
+        rebuild_tenant_chunk_hnsw(tenant_id, Progress::new(OP_HNSW_REBUILD))?;
+    }
+    if freshness.bm25_stale {
+        info!("[index_freshness] BM25 index of tenant {} is stale, rebuilding", tenant_id);
+        rebuild_tenant_chunk_bm25(tenant_id, Progress::new(OP_BM25_REBUILD))?;
+    }
+    Ok(freshness)
+}
+
+/// Ids an index disagrees with the database on.
+#[derive(Debug, Clone, Default)]
+pub struct IndexDivergence {
+    /// Whether the index is loaded; nothing is compared when it is not.
+    pub loaded: bool,
+    pub indexed_count: u64,
+    /// Chunks the index does not have.
+    pub missing_ids: Vec<i64>,
+    /// Indexed ids with no chunk row behind them.
+    pub extra_ids: Vec<i64>,
+}
+
+impl IndexDivergence {
+    fn between(indexed: Option<Vec<i64>>, chunks: &HashSet<i64>) -> Self {
+        let Some(indexed) = indexed else { return Self::default() };
+        let indexed_set: HashSet<i64> = indexed.iter().copied().collect();
+        let mut missing_ids: Vec<i64> = chunks.difference(&indexed_set).copied().collect();
+        let mut extra_ids: Vec<i64> = indexed_set.difference(chunks).copied().collect();
+        missing_ids.sort_unstable();
+        extra_ids.sort_unstable();
+        Self { loaded: true, indexed_count: indexed.len() as u64, missing_ids, extra_ids }
+    }
+
+    fn is_consistent(&self) -> bool {
+        self.missing_ids.is_empty() && self.extra_ids.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IndexConsistency {
+    pub chunk_count: u64,
+    pub hnsw: IndexDivergence,
+    pub bm25: IndexDivergence,
+    /// Whether both loaded indexes match the chunks table.
+    pub consistent: bool,
+    /// Whether `repair` applied the differences.
+    pub repaired: bool,
+}
+
+/// `ids` as a comma-separated list for an `IN (...)` clause.
+fn id_list(ids: &[i64]) -> String {
+    ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Apply the HNSW differences in place: insert missing chunks and hide
+/// extra points. Falls back to a rebuild when the index refuses the insert.
+fn repair_hnsw(tenant_id: &str, divergence: &IndexDivergence) -> Result<(), RagError> {
+    add_tombstones(tenant_id, &divergence.extra_ids);
+    delete_named_chunks(tenant_id, &divergence.extra_ids);
+    delete_named_bm25_chunks(tenant_id, &divergence.extra_ids);
+    if divergence.missing_ids.is_empty() {
+        return Ok(());
+    }
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(&format!("SELECT id, embedding FROM chunks WHERE id IN ({})", id_list(&divergence.missing_ids)))
+        .map_err(db_error)?;
+    let points: Vec<(i64, Vec<f32>)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, decode_embedding(&row.get::<_, Vec<u8>>(1)?))))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    drop(stmt);
+    drop(conn);
+    if insert_into_tenant_index(tenant_id, points, Progress::NONE)?.is_none() {
+        info!("[index_freshness] HNSW index cannot take the missing chunks, rebuilding");
+        rebuild_chunk_hnsw_index()?;
+    }
+    Ok(())
+}
+
+fn repair_bm25(tenant_id: &str, divergence: &IndexDivergence) -> Result<(), RagError> {
+    if !divergence.extra_ids.is_empty() {
+        bm25_remove_documents_from_tenant(tenant_id, &divergence.extra_ids);
+    }
+    if divergence.missing_ids.is_empty() {
+        return Ok(());
+    }
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(&format!("SELECT id, content FROM chunks WHERE id IN ({})", id_list(&divergence.missing_ids)))
+        .map_err(db_error)?;
+    let docs: Vec<(i64, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, read_content(row, 1)?)))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    bm25_add_documents_to_tenant(tenant_id, docs);
+    Ok(())
+}
+
+/// Compare the ids in the active tenant's HNSW and BM25 indexes with the
+/// chunks table. With `repair`, the differences are applied in place (a
+/// delta, not a rebuild) and the indexes are marked fresh.
+///
+/// Unlike `check_index_freshness`, this also catches indexes that were
+/// loaded from disk already out of date or missed a write in a crash.
+pub fn verify_index_consistency(repair: bool) -> Result<IndexConsistency, RagError> {
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    let (vector_version, text_version) = change_versions(&conn, &tenant_id).map_err(db_error)?;
+    let mut stmt = conn.prepare("SELECT id FROM chunks WHERE tenant_id = ?1").map_err(db_error)?;
+    let chunks: HashSet<i64> = stmt
+        .query_map(params![tenant_id], |row| row.get(0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    drop(stmt);
+    drop(conn);
+
+    let bm25_ids =
+        (!fts_keyword_enabled() && tenant_bm25_index_loaded(&tenant_id)).then(|| bm25_document_ids(&tenant_id));
+    let hnsw = IndexDivergence::between(hnsw_point_ids(&tenant_id), &chunks);
+    let bm25 = IndexDivergence::between(bm25_ids, &chunks);
+    let mut report = IndexConsistency {
+        chunk_count: chunks.len() as u64,
+        consistent: hnsw.is_consistent() && bm25.is_consistent(),
+        hnsw,
+        bm25,
+        repaired: false,
+    };
+    if report.consistent {
+        debug!("[index_freshness] Indexes match {} chunks", report.chunk_count);
+        return Ok(report);
+    }
+    warn!(
+        "[index_freshness] Index divergence: HNSW {}/{} missing/extra, BM25 {}/{}",
+        report.hnsw.missing_ids.len(),
+        report.hnsw.extra_ids.len(),
+        report.bm25.missing_ids.len(),
+        report.bm25.extra_ids.len(),
+    );
+    if repair {
+        if !report.hnsw.is_consistent() {
+            repair_hnsw(&tenant_id, &report.hnsw)?;
+            record_indexed(&tenant_id, IndexKind::Vector, vector_version);
+        }
+        if !report.bm25.is_consistent() {
+            repair_bm25(&tenant_id, &report.bm25)?;
+            record_indexed(&tenant_id, IndexKind::Text, text_version);
+        }
+        report.repaired = true;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triggers_bump_versions() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT, embedding BLOB, tenant_id TEXT);",
+        )
+        .unwrap();
+        create_change_tracking(&conn).unwrap();
+        assert_eq!(change_versions(&conn, "a").unwrap(), (0, 0));
+
+        conn.execute_batch(
+            "INSERT INTO chunks VALUES (1, 'x', x'00', 'a'), (2, 'y', x'00', 'a');
+             UPDATE chunks SET embedding = x'01' WHERE id = 1;
+             UPDATE chunks SET content = 'z' WHERE id = 2;",
+        )
+        .unwrap();
+        assert_eq!(change_versions(&conn, "a").unwrap(), (3, 3));
+        conn.execute("DELETE FROM chunks WHERE id = 1", []).unwrap();
+        assert_eq!(change_versions(&conn, "a").unwrap(), (4, 4));
+        assert_eq!(change_versions(&conn, "b").unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_is_stale() {
+        assert!(is_stale(false, true, &mut None, 0));
+        assert!(!is_stale(false, false, &mut None, 0));
+        let mut indexed = None;
+        assert!(!is_stale(true, true, &mut indexed, 5));
+        assert_eq!(indexed, Some(5));
+        assert!(is_stale(true, true, &mut indexed, 6));
+    }
+
+    #[test]
+    fn test_index_divergence() {
+        let chunks: HashSet<i64> = [1, 2, 3, 4].into_iter().collect();
+        let divergence = IndexDivergence::between(Some(vec![5, 3, 1, 2]), &chunks);
+        assert!(divergence.loaded);
+        assert_eq!(divergence.indexed_count, 4);
+        assert_eq!((divergence.missing_ids.clone(), divergence.extra_ids.clone()), (vec![4], vec![5]));
+        assert!(!divergence.is_consistent());
+
+        let unloaded = IndexDivergence::between(None, &chunks);
+        assert!(!unloaded.loaded && unloaded.is_consistent());
+        assert_eq!(id_list(&[3, 7]), "3,7");
+    }
+}
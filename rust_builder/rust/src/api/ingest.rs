@@ -0,0 +1,438 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! One-call ingestion: parse → chunk → embed → store → index.
+//!
+//! `ingest_document` replaces the add_source / chunk / embed / add_chunks /
+//! update_source_status / rebuild sequence every app used to repeat. The
+//! embedding model stays on the Dart side and is called back per chunk;
+//! progress is reported through a second callback. Nothing is stored until
+//! every chunk is embedded; the source and its chunks are then stored in
+//! one transaction, so a failed ingest leaves no orphaned source for the
+//! next attempt to be deduplicated against.
+
+use std::future::Future;
+use std::time::Instant;
+
+use flutter_rust_bridge::DartFnFuture;
+use log::{info, warn};
+
+use crate::api::chunk_dedup::{find_duplicates, DedupeConfig};
+use crate::api::document_parser::extract_text_from_document;
+use crate::api::error::RagError;
+use crate::api::index_freshness::ensure_indexes_fresh;
+use crate::api::semantic_chunker::{markdown_chunk, semantic_chunk_with_overlap, ChunkingStrategy};
+use crate::api::source_rag::{add_source_with_chunks, find_source_by_content, AddSourceResult, ChunkRow};
+
+/// Progress stages, in order.
+pub const STAGE_PARSE: &str = "parse";
+pub const STAGE_CHUNK: &str = "chunk";
+pub const STAGE_EMBED: &str = "embed";
+pub const STAGE_STORE: &str = "store";
+pub const STAGE_INDEX: &str = "index";
+pub const STAGE_DONE: &str = "done";
+
+#[derive(Debug, Clone)]
+pub enum IngestInput {
+    Text(String),
+    /// PDF or DOCX bytes (detected by magic bytes); anything else must be UTF-8.
+    Bytes(Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+pub struct IngestOptions {
+    pub name: Option<String>,
+    pub metadata: Option<String>,
+    /// `None` picks markdown chunking for `.md`/`.markdown` names.
+    pub strategy: Option<ChunkingStrategy>,
+    pub max_chars: i32,
+    pub overlap_chars: i32,
+    /// Bring the HNSW and BM25 indexes up to date after storing the chunks.
+    /// The chunks are already in a loaded HNSW index, so usually only BM25
+    /// is rebuilt.
+    pub rebuild_indexes: bool,
+    /// Skip exact and near-duplicate chunks (repeated headers, footers).
+    pub dedupe_chunks: bool,
+}
+
+impl Default for IngestOptions {
+    fn default() -> Self {
+        Self {
+            name: None,
+            metadata: None,
+            strategy: None,
+            max_chars: 500,
+            overlap_chars: 50,
+            rebuild_indexes: true,
+            dedupe_chunks: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IngestProgress {
+    /// One of the `STAGE_*` values.
+    pub stage: String,
+    pub done: u32,
+    pub total: u32,
+    pub source_id: Option<i64>,
+}
+
+/// Outcome of `ingest_document`.
+#[derive(Debug, Clone)]
+pub struct IngestReport {
+    pub source_id: i64,
+    /// The content was already stored; nothing was embedded.
+    pub is_duplicate: bool,
+    /// Status of the stored source: `completed`, or for a duplicate
+    /// whatever the earlier ingest left it at.
+    pub status: String,
+    /// Chunks stored (0 for a duplicate).
+    pub chunk_count: u32,
+    /// Exact and near-duplicate chunks dropped before embedding.
+    pub skipped_chunks: u32,
+    /// Characters of text after parsing.
+    pub text_chars: u64,
+    pub elapsed_ms: u64,
+}
+
+/// What `embed_and_store` stored.
+struct Stored {
+    chunks: usize,
+    skipped: usize,
+}
+
+/// A chunk before embedding.
+#[derive(Debug, Clone)]
+pub(crate) struct PreparedChunk {
+    pub content: String,
+    pub chunk_index: i32,
+    pub start_pos: i32,
+    pub end_pos: i32,
+    pub chunk_type: String,
+    pub header_path: Option<String>,
+    pub batch_id: Option<String>,
+}
+
+/// Chunks of `text` to embed, and how many duplicates were dropped
+/// (when `options.dedupe_chunks` is set).
+pub(crate) fn chunks_to_embed(text: &str, options: &IngestOptions) -> (Vec<PreparedChunk>, usize) {
+    let prepared = prepare_chunks(text, options);
+    if !options.dedupe_chunks {
+        return (prepared, 0);
+    }
+    let report = find_duplicates(
+        &prepared.iter().map(|c| c.content.as_str()).collect::<Vec<_>>(),
+        &DedupeConfig::default(),
+    );
+    if report.duplicates.is_empty() {
+        return (prepared, 0);
+    }
+    info!("[ingest] Skipping {} duplicate chunks", report.duplicates.len());
+    let kept = report.kept.iter().map(|&i| prepared[i as usize].clone()).collect();
+    (kept, report.duplicates.len())
+}
+
+fn parse_input(input: IngestInput) -> Result<String, RagError> {
+    match input {
+        IngestInput::Text(text) => Ok(text),
+        IngestInput::Bytes(bytes) if bytes.starts_with(b"%PDF") || bytes.starts_with(b"PK") => {
+            extract_text_from_document(bytes)
+        }
+        IngestInput::Bytes(bytes) => String::from_utf8(bytes)
+            .map_err(|_| RagError::InvalidInput("Unsupported document: not PDF, DOCX or UTF-8 text".to_string())),
+    }
+}
+
+fn resolve_strategy(options: &IngestOptions) -> ChunkingStrategy {
+    options.strategy.unwrap_or_else(|| {
+        let is_markdown = options
+            .name
+            .as_deref()
+            .map(|n| n.to_lowercase())
+            .is_some_and(|n| n.ends_with(".md") || n.ends_with(".markdown"));
+        if is_markdown {
+            ChunkingStrategy::Markdown
+        } else {
+            ChunkingStrategy::Recursive
+        }
+    })
+}
+
+fn prepare_chunks(text: &str, options: &IngestOptions) -> Vec<PreparedChunk> {
+    match resolve_strategy(options) {
+        ChunkingStrategy::Markdown => markdown_chunk(text.to_string(), options.max_chars)
+            .into_iter()
+            .map(|c| PreparedChunk {
+                chunk_type: c.chunk_type,
+                header_path: Some(c.header_path).filter(|p| !p.is_empty()),
+                content: c.content,
+                chunk_index: c.index,
+                start_pos: c.start_pos,
+                end_pos: c.end_pos,
+                batch_id: c.batch_id,
+            })
+            .collect(),
+        ChunkingStrategy::Recursive => {
+            semantic_chunk_with_overlap(text.to_string(), options.max_chars, options.overlap_chars)
+                .into_iter()
+                .map(|c| PreparedChunk {
+                    content: c.content,
+                    chunk_index: c.index,
+                    start_pos: c.start_pos,
+                    end_pos: c.end_pos,
+                    chunk_type: c.chunk_type,
+                    header_path: None,
+                    batch_id: None,
+                })
+                .collect()
+        }
+    }
+}
+
+/// Ingest a document in one call.
+///
+/// `embed` is called once per chunk with its text; `on_progress` receives
+/// one event per stage (and per embedded chunk). Duplicate content is
+/// detected before embedding, so re-ingesting a document is cheap. The
+/// source is stored with all of its chunks in one transaction once every
+/// chunk is embedded, so a failed ingest stores nothing.
+pub async fn ingest_document(
+    input: IngestInput,
+    options: IngestOptions,
+    embed: impl Fn(String) -> DartFnFuture<Vec<f32>>,
+    on_progress: impl Fn(IngestProgress) -> DartFnFuture<()>,
+) -> Result<IngestReport, RagError> {
+    ingest_with(input, options, embed, on_progress, || Ok(())).await
+}
+
+/// Pipeline behind `ingest_document`. `check_interrupt` runs before each
+/// chunk is embedded; returning an error aborts the ingest (the job queue
+/// uses this for pause/cancel).
+pub(crate) async fn ingest_with<E, EF, P, PF, C>(
+    input: IngestInput,
+    options: IngestOptions,
+    embed: E,
+    on_progress: P,
+    check_interrupt: C,
+) -> Result<IngestReport, RagError>
+where
+    E: Fn(String) -> EF,
+    EF: Future<Output = Vec<f32>>,
+    P: Fn(IngestProgress) -> PF,
+    PF: Future<Output = ()>,
+    C: Fn() -> Result<(), RagError>,
+{
+    let progress = |stage: &str, done: usize, total: usize, source_id: Option<i64>| {
+        on_progress(IngestProgress {
+            stage: stage.to_string(),
+            done: done as u32,
+            total: total as u32,
+            source_id,
+        })
+    };
+
+    let started = Instant::now();
+    progress(STAGE_PARSE, 0, 1, None).await;
+    let text = parse_input(input)?;
+    if text.trim().is_empty() {
+        return Err(RagError::InvalidInput("Document has no text".to_string()));
+    }
+    let report = |source_id: i64, status: String, stored: Option<Stored>| IngestReport {
+        source_id,
+        is_duplicate: stored.is_none(),
+        status,
+        chunk_count: stored.as_ref().map_or(0, |s| s.chunks as u32),
+        skipped_chunks: stored.map_or(0, |s| s.skipped as u32),
+        text_chars: text.chars().count() as u64,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    };
+    let duplicate = |source_id: i64| -> Result<IngestReport, RagError> {
+        let status = find_source_by_content(&text)?.map_or_else(|| "completed".to_string(), |(_, status)| status);
+        info!("[ingest] Duplicate of source {} ({})", source_id, status);
+        Ok(report(source_id, status, None))
+    };
+
+    if let Some((source_id, _)) = find_source_by_content(&text)? {
+        progress(STAGE_DONE, 1, 1, Some(source_id)).await;
+        return duplicate(source_id);
+    }
+
+    let (source, stored) = embed_and_store(&text, &options, &embed, &progress, &check_interrupt).await?;
+    let source_id = source.source_id;
+    if source.is_duplicate {
+        // Stored by a concurrent ingest while this one was embedding.
+        progress(STAGE_DONE, 1, 1, Some(source_id)).await;
+        return duplicate(source_id);
+    }
+    if options.rebuild_indexes {
+        progress(STAGE_INDEX, 0, 1, Some(source_id)).await;
+        // The source is committed either way; a stale index is rebuilt later.
+        if let Err(e) = ensure_indexes_fresh() {
+            warn!("[ingest] Index refresh after source {} failed: {}", source_id, e);
+        }
+    }
+    progress(STAGE_DONE, 1, 1, Some(source_id)).await;
+    info!("[ingest] Source {} ingested with {} chunks", source_id, stored.chunks);
+    Ok(report(source_id, "completed".to_string(), Some(stored)))
+}
+
+/// Embed the chunks of `text`, then store the source with all of them.
+async fn embed_and_store<E, EF, P, PF>(
+    text: &str,
+    options: &IngestOptions,
+    embed: &E,
+    progress: &P,
+    check_interrupt: &impl Fn() -> Result<(), RagError>,
+) -> Result<(AddSourceResult, Stored), RagError>
+where
+    E: Fn(String) -> EF,
+    EF: Future<Output = Vec<f32>>,
+    P: Fn(&str, usize, usize, Option<i64>) -> PF,
+    PF: Future<Output = ()>,
+{
+    let (prepared, skipped) = chunks_to_embed(text, options);
+    let total = prepared.len();
+    progress(STAGE_CHUNK, total, total, None).await;
+
+    let mut embeddings = Vec::with_capacity(total);
+    let mut dimension = None;
+    for (i, chunk) in prepared.iter().enumerate() {
+        check_interrupt()?;
+        let embedding = embed(chunk.content.clone()).await;
+        if embedding.is_empty() || dimension.is_some_and(|d| d != embedding.len()) {
+            return Err(RagError::InvalidInput(format!(
+                "Embedding callback returned {} dims for chunk {}",
+                embedding.len(),
+                i
+            )));
+        }
+        dimension = Some(embedding.len());
+        embeddings.push(embedding);
+        progress(STAGE_EMBED, i + 1, total, None).await;
+    }
+
+    progress(STAGE_STORE, 0, 1, None).await;
+    check_interrupt()?;
+    let rows: Vec<ChunkRow> = prepared
+        .iter()
+        .zip(&embeddings)
+        .map(|(chunk, embedding)| ChunkRow {
+            content: &chunk.content,
+            chunk_index: chunk.chunk_index,
+            start_pos: chunk.start_pos,
+            end_pos: chunk.end_pos,
+            chunk_type: &chunk.chunk_type,
+            header_path: chunk.header_path.as_deref(),
+            batch_id: chunk.batch_id.as_deref(),
+            embedding,
+        })
+        .collect();
+    let source = add_source_with_chunks(text, options.metadata.clone(), options.name.clone(), &rows, &|_| Ok(()))?;
+    Ok((source, Stored { chunks: total, skipped }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_text_and_bytes() {
+        assert_eq!(parse_input(IngestInput::Text("hi".to_string())).unwrap(), "hi");
+        assert_eq!(parse_input(IngestInput::Bytes("héllo".as_bytes().to_vec())).unwrap(), "héllo");
+        assert!(parse_input(IngestInput::Bytes(vec![0xff, 0xfe, 0x00, 0x01])).is_err());
+    }
+
+    #[test]
+    fn test_prepare_chunks_picks_markdown_by_name() {
+        let text = "# Title\n\nIntro paragraph.\n\n## Setup\n\nInstall the package.";
+        let options = IngestOptions { name: Some("guide.MD".to_string()), ..Default::default() };
+        let chunks = prepare_chunks(text, &options);
+        assert!(!chunks.is_empty());
+        assert!(chunks.iter().any(|c| c.header_path.as_deref().is_some_and(|p| p.contains("Title"))));
+        assert!(chunks.iter().all(|c| !c.chunk_type.contains('|')));
+
+        let plain = prepare_chunks(text, &IngestOptions::default());
+        assert!(plain.iter().all(|c| c.header_path.is_none()));
+    }
+
+    #[test]
+    fn test_duplicate_ingest_keeps_existing_status() {
+        use crate::api::ingest_jobs::block_on;
+        use crate::api::source_rag::{add_source, list_sources};
+        use crate::test_support::TestEngine;
+
+        let _engine = TestEngine::new().unwrap();
+        let text = "Ownership moves values between bindings.";
+        let pending = add_source(text.to_string(), None, None).unwrap().source_id;
+        let options = IngestOptions { rebuild_indexes: false, ..Default::default() };
+        let ingest = |text: &str| {
+            block_on(ingest_with(
+                IngestInput::Text(text.to_string()),
+                options.clone(),
+                |_| std::future::ready(vec![1.0, 0.0]),
+                |_| std::future::ready(()),
+                || Ok(()),
+            ))
+            .unwrap()
+        };
+
+        let report = ingest(text);
+        assert!(report.is_duplicate);
+        assert_eq!((report.source_id, report.status.as_str()), (pending, "pending"));
+        let sources = list_sources().unwrap();
+        assert_eq!(sources[0].status.as_deref(), Some("pending"));
+
+        let report = ingest("Borrowing lends values without moving them.");
+        assert!(!report.is_duplicate);
+        assert_eq!((report.status.as_str(), report.chunk_count), ("completed", 1));
+    }
+
+    #[test]
+    fn test_failed_ingest_rolls_back_source() {
+        use crate::api::db_pool::init_db_pool;
+        use crate::api::ingest_jobs::block_on;
+        use crate::api::source_rag::{get_source_stats, init_source_db};
+        use std::cell::Cell;
+
+        let db_path = std::env::temp_dir().join("test_ingest_rollback.db");
+        let _ = std::fs::remove_file(&db_path);
+        init_db_pool(db_path.to_str().unwrap().to_string(), 1).unwrap();
+        init_source_db().unwrap();
+
+        let text = "Rust has ownership. Borrowing checks references at compile time. \
+                    Lifetimes name how long references live. Traits describe shared behaviour.";
+        let options = IngestOptions { max_chars: 40, overlap_chars: 0, rebuild_indexes: false, ..Default::default() };
+        // The second chunk's embedding has the wrong dimension.
+        let calls = Cell::new(0);
+        let embed = |_: String| {
+            calls.set(calls.get() + 1);
+            std::future::ready(vec![0.5; if calls.get() == 1 { 2 } else { 3 }])
+        };
+        let result = block_on(ingest_with(
+            IngestInput::Text(text.to_string()),
+            options,
+            embed,
+            |_| std::future::ready(()),
+            || Ok(()),
+        ));
+        assert!(result.is_err());
+        assert!(calls.get() >= 2);
+        assert_eq!(get_source_stats().unwrap().source_count, 0);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+}
@@ -0,0 +1,615 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Persistent ingestion job queue for multi-document imports.
+//!
+//! `enqueue_ingest_job` records a file in the `ingest_jobs` table;
+//! `start_ingest_worker` processes queued jobs one at a time on a
+//! background thread using the `ingest` pipeline. Jobs move through
+//! queued → running → completed/failed, and can be paused, resumed or
+//! cancelled. A job left `running` by an app restart is re-queued the
+//! next time the worker starts.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+
+use flutter_rust_bridge::DartFnFuture;
+use log::{info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::panic_report::catch_panic;
+use crate::api::index_freshness::ensure_tenant_indexes_fresh;
+use crate::api::ingest::{ingest_with, IngestInput, IngestOptions, IngestProgress};
+use crate::api::semantic_chunker::ChunkingStrategy;
+use crate::api::tenant::active_tenant;
+
+/// Job states.
+pub const JOB_QUEUED: &str = "queued";
+pub const JOB_RUNNING: &str = "running";
+pub const JOB_PAUSED: &str = "paused";
+pub const JOB_COMPLETED: &str = "completed";
+pub const JOB_FAILED: &str = "failed";
+pub const JOB_CANCELLED: &str = "cancelled";
+
+static WORKER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone)]
+pub struct IngestJob {
+    pub id: i64,
+    pub file_path: String,
+    pub name: Option<String>,
+    /// One of the `JOB_*` values.
+    pub state: String,
+    /// Last `ingest` stage reported for this job.
+    pub stage: Option<String>,
+    pub progress_done: u32,
+    pub progress_total: u32,
+    pub source_id: Option<i64>,
+    pub error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// A job claimed by the worker, with its stored ingest options.
+struct ClaimedJob {
+    id: i64,
+    tenant_id: String,
+    file_path: String,
+    options: IngestOptions,
+}
+
+pub(crate) fn create_ingest_jobs_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS ingest_jobs (
+            id INTEGER PRIMARY KEY,
+            tenant_id TEXT NOT NULL DEFAULT 'default',
+            file_path TEXT NOT NULL,
+            name TEXT,
+            metadata TEXT,
+            strategy TEXT,
+            max_chars INTEGER NOT NULL,
+            overlap_chars INTEGER NOT NULL,
+            dedupe_chunks INTEGER NOT NULL DEFAULT 1,
+            state TEXT NOT NULL DEFAULT 'queued',
+            stage TEXT,
+            progress_done INTEGER NOT NULL DEFAULT 0,
+            progress_total INTEGER NOT NULL DEFAULT 0,
+            source_id INTEGER,
+            error TEXT,
+            created_at INTEGER DEFAULT (strftime('%s', 'now')),
+            updated_at INTEGER DEFAULT (strftime('%s', 'now'))
+        )",
+        [],
+    )?;
+    if conn.prepare("SELECT dedupe_chunks FROM ingest_jobs LIMIT 1").is_err() {
+        conn.execute(
+            "ALTER TABLE ingest_jobs ADD COLUMN dedupe_chunks INTEGER NOT NULL DEFAULT 1",
+            [],
+        )?;
+    }
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_ingest_jobs_state ON ingest_jobs(tenant_id, state)",
+        [],
+    )?;
+    Ok(())
+}
+
+/// States a job may be in before moving to `to`.
+fn allowed_from(to: &str) -> &'static [&'static str] {
+    match to {
+        JOB_RUNNING => &[JOB_QUEUED],
+        JOB_PAUSED => &[JOB_QUEUED, JOB_RUNNING],
+        JOB_QUEUED => &[JOB_PAUSED, JOB_FAILED],
+        JOB_CANCELLED => &[JOB_QUEUED, JOB_RUNNING, JOB_PAUSED, JOB_FAILED],
+        JOB_COMPLETED | JOB_FAILED => &[JOB_RUNNING],
+        _ => &[],
+    }
+}
+
+/// Move a job to `to` if the state machine allows it. Returns false when
+/// the job does not exist or is in a state that cannot make the move.
+fn transition(conn: &Connection, tenant_id: &str, job_id: i64, to: &str) -> rusqlite::Result<bool> {
+    let from = allowed_from(to)
+        .iter()
+        .map(|s| format!("'{}'", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!(
+        "UPDATE ingest_jobs SET state = ?1, updated_at = strftime('%s', 'now')
+         WHERE id = ?2 AND tenant_id = ?3 AND state IN ({})",
+        from
+    );
+    Ok(conn.execute(&sql, params![to, job_id, tenant_id])? > 0)
+}
+
+fn insert_job(conn: &Connection, tenant_id: &str, file_path: &str, options: &IngestOptions) -> rusqlite::Result<i64> {
+    let strategy = options.strategy.map(|s| match s {
+        ChunkingStrategy::Recursive => "recursive",
+        ChunkingStrategy::Markdown => "markdown",
+    });
+    conn.execute(
+        "INSERT INTO ingest_jobs (tenant_id, file_path, name, metadata, strategy, max_chars, overlap_chars,
+                                  dedupe_chunks)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            tenant_id,
+            file_path,
+            options.name,
+            options.metadata,
+            strategy,
+            options.max_chars,
+            options.overlap_chars,
+            options.dedupe_chunks
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn read_job(row: &rusqlite::Row) -> rusqlite::Result<IngestJob> {
+    Ok(IngestJob {
+        id: row.get(0)?,
+        file_path: row.get(1)?,
+        name: row.get(2)?,
+        state: row.get(3)?,
+        stage: row.get(4)?,
+        progress_done: row.get(5)?,
+        progress_total: row.get(6)?,
+        source_id: row.get(7)?,
+        error: row.get(8)?,
+        created_at: row.get(9)?,
+        updated_at: row.get(10)?,
+    })
+}
+
+const JOB_COLUMNS: &str = "id, file_path, name, state, stage, progress_done, progress_total, source_id, error, \
+                           created_at, updated_at";
+
+fn load_job(conn: &Connection, job_id: i64) -> rusqlite::Result<Option<IngestJob>> {
+    conn.query_row(
+        &format!("SELECT {} FROM ingest_jobs WHERE id = ?1", JOB_COLUMNS),
+        params![job_id],
+        read_job,
+    )
+    .optional()
+}
+
+/// Claim the oldest queued job of `tenant_id`, marking it running.
+fn claim_next_job(conn: &Connection, tenant_id: &str) -> rusqlite::Result<Option<ClaimedJob>> {
+    let next = conn
+        .query_row(
+            "SELECT id, file_path, name, metadata, strategy, max_chars, overlap_chars, dedupe_chunks
+             FROM ingest_jobs WHERE tenant_id = ?1 AND state = ?2 ORDER BY id LIMIT 1",
+            params![tenant_id, JOB_QUEUED],
+            |row| {
+                let strategy: Option<String> = row.get(4)?;
+                Ok(ClaimedJob {
+                    id: row.get(0)?,
+                    tenant_id: tenant_id.to_string(),
+                    file_path: row.get(1)?,
+                    options: IngestOptions {
+                        name: row.get(2)?,
+                        metadata: row.get(3)?,
+                        strategy: strategy.map(|s| match s.as_str() {
+                            "markdown" => ChunkingStrategy::Markdown,
+                            _ => ChunkingStrategy::Recursive,
+                        }),
+                        max_chars: row.get(5)?,
+                        overlap_chars: row.get(6)?,
+                        // Indexes are rebuilt once when the queue drains.
+                        rebuild_indexes: false,
+                        dedupe_chunks: row.get(7)?,
+                    },
+                })
+            },
+        )
+        .optional()?;
+    match next {
+        Some(job) if transition(conn, tenant_id, job.id, JOB_RUNNING)? => {
+            conn.execute(
+                "UPDATE ingest_jobs SET error = NULL, stage = NULL, progress_done = 0, progress_total = 0
+                 WHERE id = ?1",
+                params![job.id],
+            )?;
+            Ok(Some(job))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn record_progress(conn: &Connection, job_id: i64, progress: &IngestProgress) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE ingest_jobs SET stage = ?1, progress_done = ?2, progress_total = ?3,
+             source_id = COALESCE(?4, source_id), updated_at = strftime('%s', 'now')
+         WHERE id = ?5",
+        params![progress.stage, progress.done, progress.total, progress.source_id, job_id],
+    )?;
+    Ok(())
+}
+
+/// Remove a chunk-less source row recorded for a job. Ingests store the
+/// source together with its chunks, but jobs interrupted by older
+/// versions left such rows behind, and a retry would be deduplicated
+/// against them.
+fn discard_partial_source(conn: &Connection, job_id: i64, tenant_id: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "DELETE FROM sources WHERE tenant_id = ?2
+             AND id = (SELECT source_id FROM ingest_jobs WHERE id = ?1)
+             AND NOT EXISTS (SELECT 1 FROM chunks WHERE chunks.source_id = sources.id)",
+        params![job_id, tenant_id],
+    )?;
+    conn.execute("UPDATE ingest_jobs SET source_id = NULL WHERE id = ?1", params![job_id])?;
+    Ok(())
+}
+
+/// Re-queue jobs of `tenant_id` left running by a previous process.
+fn requeue_interrupted_jobs(conn: &Connection, tenant_id: &str) -> rusqlite::Result<usize> {
+    let ids: Vec<i64> = conn
+        .prepare("SELECT id FROM ingest_jobs WHERE tenant_id = ?1 AND state = ?2")?
+        .query_map(params![tenant_id, JOB_RUNNING], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    for id in &ids {
+        discard_partial_source(conn, *id, tenant_id)?;
+        conn.execute(
+            "UPDATE ingest_jobs SET state = ?1, updated_at = strftime('%s', 'now') WHERE id = ?2",
+            params![JOB_QUEUED, id],
+        )?;
+    }
+    Ok(ids.len())
+}
+
+/// Queue a file for ingestion into the active tenant. Returns the job id.
+pub fn enqueue_ingest_job(file_path: String, options: IngestOptions) -> Result<i64, RagError> {
+    if !std::path::Path::new(&file_path).is_file() {
+        return Err(RagError::InvalidInput(format!("Not a file: {}", file_path)));
+    }
+    let conn = get_connection()?;
+    let mut options = options;
+    if options.name.is_none() {
+        options.name = std::path::Path::new(&file_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned());
+    }
+    let job_id = insert_job(&conn, &active_tenant(), &file_path, &options).map_err(db_error)?;
+    info!("[ingest_jobs] Queued job {} for {}", job_id, file_path);
+    Ok(job_id)
+}
+
+fn set_job_state(job_id: i64, to: &str) -> Result<(), RagError> {
+    let conn = get_connection()?;
+    if !transition(&conn, &active_tenant(), job_id, to).map_err(db_error)? {
+        return Err(RagError::InvalidInput(format!("Job {} cannot move to {}", job_id, to)));
+    }
+    info!("[ingest_jobs] Job {} -> {}", job_id, to);
+    Ok(())
+}
+
+/// Pause a queued or running job. A running job stops before its next
+/// chunk is embedded and restarts from the beginning when resumed.
+pub fn pause_ingest_job(job_id: i64) -> Result<(), RagError> {
+    set_job_state(job_id, JOB_PAUSED)
+}
+
+/// Re-queue a paused or failed job.
+pub fn resume_ingest_job(job_id: i64) -> Result<(), RagError> {
+    set_job_state(job_id, JOB_QUEUED)
+}
+
+/// Cancel a job that has not completed.
+pub fn cancel_ingest_job(job_id: i64) -> Result<(), RagError> {
+    set_job_state(job_id, JOB_CANCELLED)
+}
+
+/// Jobs of the active tenant, oldest first.
+pub fn list_ingest_jobs() -> Result<Vec<IngestJob>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM ingest_jobs WHERE tenant_id = ?1 ORDER BY id",
+            JOB_COLUMNS
+        ))
+        .map_err(db_error)?;
+    let jobs = stmt
+        .query_map(params![active_tenant()], read_job)
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .map_err(db_error)?;
+    Ok(jobs)
+}
+
+/// Delete completed and cancelled jobs of the active tenant.
+pub fn clear_finished_ingest_jobs() -> Result<u32, RagError> {
+    let conn = get_connection()?;
+    let removed = conn
+        .execute(
+            "DELETE FROM ingest_jobs WHERE tenant_id = ?1 AND state IN (?2, ?3)",
+            params![active_tenant(), JOB_COMPLETED, JOB_CANCELLED],
+        )
+        .map_err(db_error)?;
+    Ok(removed as u32)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_ingest_worker_running() -> bool {
+    WORKER_RUNNING.load(Ordering::Acquire)
+}
+
+/// Start processing queued jobs of the active tenant on a background
+/// thread. `embed` is called per chunk as in `ingest_document`;
+/// `on_job_update` receives a job snapshot after each progress event and
+/// state change. The worker stops when the queue is empty, then rebuilds
+/// the indexes of each tenant it ingested into once. Returns false if a worker is already running (it
+/// will pick up newly queued jobs).
+pub fn start_ingest_worker(
+    embed: impl Fn(String) -> DartFnFuture<Vec<f32>> + Send + Sync + 'static,
+    on_job_update: impl Fn(IngestJob) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<bool, RagError> {
+    if WORKER_RUNNING.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_err() {
+        return Ok(false);
+    }
+    let recovered = get_connection()
+        .and_then(|conn| requeue_interrupted_jobs(&conn, &active_tenant()).map_err(db_error));
+    match recovered {
+        Ok(0) => {}
+        Ok(n) => info!("[ingest_jobs] Re-queued {} interrupted jobs", n),
+        Err(e) => {
+            WORKER_RUNNING.store(false, Ordering::Release);
+            return Err(e);
+        }
+    }
+    std::thread::Builder::new()
+        .name("rag-ingest-jobs".to_string())
+        .spawn(move || {
+            // Jobs left `running` by a panic are re-queued on the next start.
+            if let Err(e) = catch_panic("ingest worker", || run_worker(embed, on_job_update)) {
+                warn!("[ingest_jobs] {}", e);
+                WORKER_RUNNING.store(false, Ordering::Release);
+            }
+        })
+        .map_err(|e| {
+            WORKER_RUNNING.store(false, Ordering::Release);
+            RagError::InternalError(format!("Failed to start ingest worker: {}", e))
+        })?;
+    Ok(true)
+}
+
+fn run_worker(
+    embed: impl Fn(String) -> DartFnFuture<Vec<f32>>,
+    on_job_update: impl Fn(IngestJob) -> DartFnFuture<()>,
+) {
+    info!("[ingest_jobs] Worker started");
+    // Jobs are claimed from whichever tenant is active, so one run can
+    // ingest into several tenants.
+    let mut ingested: Vec<String> = Vec::new();
+    loop {
+        let claimed = get_connection()
+            .and_then(|conn| claim_next_job(&conn, &active_tenant()).map_err(db_error));
+        let job = match claimed {
+            Ok(Some(job)) => job,
+            Ok(None) => {
+                WORKER_RUNNING.store(false, Ordering::Release);
+                // A job queued between the claim and the store above would
+                // otherwise wait for the next start_ingest_worker call.
+                let has_queued = get_connection().ok().is_some_and(|conn| {
+                    conn.query_row(
+                        "SELECT EXISTS (SELECT 1 FROM ingest_jobs WHERE tenant_id = ?1 AND state = ?2)",
+                        params![active_tenant(), JOB_QUEUED],
+                        |row| row.get::<_, bool>(0),
+                    )
+                    .unwrap_or(false)
+                });
+                if has_queued
+                    && WORKER_RUNNING.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_ok()
+                {
+                    continue;
+                }
+                break;
+            }
+            Err(e) => {
+                warn!("[ingest_jobs] Worker stopped: {}", e);
+                WORKER_RUNNING.store(false, Ordering::Release);
+                break;
+            }
+        };
+        let tenant_id = job.tenant_id.clone();
+        if process_job(job, &embed, &on_job_update) && !ingested.contains(&tenant_id) {
+            ingested.push(tenant_id);
+        }
+    }
+
+    for tenant_id in ingested {
+        info!("[ingest_jobs] Refreshing indexes of tenant {}", tenant_id);
+        if let Err(e) = ensure_tenant_indexes_fresh(&tenant_id) {
+            warn!("[ingest_jobs] Index rebuild for tenant {} failed: {}", tenant_id, e);
+        }
+    }
+    info!("[ingest_jobs] Worker idle");
+}
+
+/// Run one claimed job to completion. Returns true if it completed.
+fn process_job(
+    job: ClaimedJob,
+    embed: &impl Fn(String) -> DartFnFuture<Vec<f32>>,
+    on_job_update: &impl Fn(IngestJob) -> DartFnFuture<()>,
+) -> bool {
+    let ClaimedJob { id, tenant_id, file_path, options } = job;
+    info!("[ingest_jobs] Running job {} ({})", id, file_path);
+    let notify = || -> DartFnFuture<()> {
+        match get_connection().ok().and_then(|conn| load_job(&conn, id).ok().flatten()) {
+            Some(job) => on_job_update(job),
+            None => Box::pin(async {}),
+        }
+    };
+    block_on(notify());
+
+    let check_interrupt = || -> Result<(), RagError> {
+        let conn = get_connection()?;
+        let state: String = conn
+            .query_row("SELECT state FROM ingest_jobs WHERE id = ?1", params![id], |row| row.get(0))
+            .map_err(db_error)?;
+        if state != JOB_RUNNING {
+            return Err(RagError::InternalError(format!("Job {} was {}", id, state)));
+        }
+        if active_tenant() != tenant_id {
+            return Err(RagError::InternalError(format!("Job {} interrupted by tenant switch", id)));
+        }
+        Ok(())
+    };
+    let on_progress = |progress: IngestProgress| -> DartFnFuture<()> {
+        if let Ok(conn) = get_connection() {
+            if let Err(e) = record_progress(&conn, id, &progress) {
+                warn!("[ingest_jobs] Failed to record progress for job {}: {}", id, e);
+            }
+        }
+        notify()
+    };
+
+    let result = std::fs::read(&file_path)
+        .map_err(|e| RagError::InvalidInput(format!("Failed to read {}: {}", file_path, e)))
+        .and_then(|bytes| {
+            check_interrupt()?;
+            block_on(ingest_with(IngestInput::Bytes(bytes), options, embed, on_progress, &check_interrupt))
+        });
+
+    let finished = get_connection().and_then(|conn| {
+        match &result {
+            Ok(source) => {
+                conn.execute(
+                    "UPDATE ingest_jobs SET state = ?1, source_id = ?2, updated_at = strftime('%s', 'now')
+                     WHERE id = ?3",
+                    params![JOB_COMPLETED, source.source_id, id],
+                )
+                .map_err(db_error)?;
+            }
+            Err(e) => {
+                discard_partial_source(&conn, id, &tenant_id).map_err(db_error)?;
+                // Paused/cancelled jobs keep their state; a tenant switch
+                // puts the job back in the queue for that tenant.
+                let state: String = conn
+                    .query_row("SELECT state FROM ingest_jobs WHERE id = ?1", params![id], |row| row.get(0))
+                    .map_err(db_error)?;
+                if state == JOB_RUNNING {
+                    let next = if active_tenant() != tenant_id { JOB_QUEUED } else { JOB_FAILED };
+                    conn.execute(
+                        "UPDATE ingest_jobs SET state = ?1, error = ?2, updated_at = strftime('%s', 'now')
+                         WHERE id = ?3",
+                        params![next, e.to_string(), id],
+                    )
+                    .map_err(db_error)?;
+                }
+            }
+        }
+        Ok(())
+    });
+    if let Err(e) = finished {
+        warn!("[ingest_jobs] Failed to finish job {}: {}", id, e);
+    }
+    match &result {
+        Ok(_) => info!("[ingest_jobs] Job {} completed", id),
+        Err(e) => warn!("[ingest_jobs] Job {} stopped: {}", id, e),
+    }
+    block_on(notify());
+    result.is_ok_and(|source| !source.is_duplicate)
+}
+
+/// Drive a future to completion on the current thread. The worker thread
+/// has no async runtime; Dart callback futures wake it when they resolve.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        create_ingest_jobs_table(&conn).unwrap();
+        conn
+    }
+
+    fn state(conn: &Connection, job_id: i64) -> String {
+        load_job(conn, job_id).unwrap().unwrap().state
+    }
+
+    #[test]
+    fn test_job_state_machine() {
+        let conn = setup();
+        let a = insert_job(&conn, "default", "/tmp/a.md", &IngestOptions::default()).unwrap();
+        let b = insert_job(&conn, "default", "/tmp/b.txt", &IngestOptions::default()).unwrap();
+        let other = insert_job(&conn, "other", "/tmp/c.txt", &IngestOptions::default()).unwrap();
+
+        // Claims are FIFO and per tenant.
+        let claimed = claim_next_job(&conn, "default").unwrap().unwrap();
+        assert_eq!(claimed.id, a);
+        assert!(!claimed.options.rebuild_indexes);
+        assert_eq!(state(&conn, a), JOB_RUNNING);
+
+        assert!(transition(&conn, "default", b, JOB_PAUSED).unwrap());
+        assert!(claim_next_job(&conn, "default").unwrap().is_none());
+        assert!(!transition(&conn, "default", b, JOB_COMPLETED).unwrap());
+        assert!(transition(&conn, "default", b, JOB_QUEUED).unwrap());
+        assert!(transition(&conn, "default", b, JOB_CANCELLED).unwrap());
+        assert!(!transition(&conn, "default", b, JOB_QUEUED).unwrap());
+
+        // Other tenants cannot touch the job.
+        assert!(!transition(&conn, "default", other, JOB_CANCELLED).unwrap());
+        assert_eq!(state(&conn, other), JOB_QUEUED);
+    }
+
+    #[test]
+    fn test_requeue_interrupted_job_discards_partial_source() {
+        let conn = setup();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, tenant_id TEXT NOT NULL DEFAULT 'default');
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER NOT NULL);
+             INSERT INTO sources (id) VALUES (7);",
+        )
+        .unwrap();
+        let options = IngestOptions { strategy: Some(ChunkingStrategy::Markdown), ..Default::default() };
+        let job = insert_job(&conn, "default", "/tmp/a.md", &options).unwrap();
+        let claimed = claim_next_job(&conn, "default").unwrap().unwrap();
+        assert_eq!(claimed.options.strategy, Some(ChunkingStrategy::Markdown));
+        let progress = IngestProgress { stage: "embed".to_string(), done: 1, total: 4, source_id: Some(7) };
+        record_progress(&conn, job, &progress).unwrap();
+
+        assert_eq!(requeue_interrupted_jobs(&conn, "default").unwrap(), 1);
+        let job = load_job(&conn, job).unwrap().unwrap();
+        assert_eq!(job.state, JOB_QUEUED);
+        assert_eq!(job.source_id, None);
+        let sources: i64 = conn.query_row("SELECT COUNT(*) FROM sources", [], |row| row.get(0)).unwrap();
+        assert_eq!(sources, 0);
+        assert_eq!(block_on(async { 42 }), 42);
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Retrieval presets per user intent.
+//!
+//! Maps an intent type (`UserIntent::intent_type`) to retrieval parameters,
+//! so `/define` asks for a few definition chunks while `/summary` pulls a
+//! wide, diversified set. The table is global and can be overridden from
+//! the app at runtime.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use log::info;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetrievalPreset {
+    pub top_k: u32,
+    pub vector_weight: f64,
+    pub bm25_weight: f64,
+    /// Chunk type to favor (see `ChunkType::as_str`), e.g. "definition".
+    pub boost_chunk_type: Option<String>,
+    /// Score multiplier applied to chunks of `boost_chunk_type`.
+    pub chunk_type_boost: f64,
+    /// Re-rank with Maximal Marginal Relevance to diversify results.
+    pub use_mmr: bool,
+    /// MMR trade-off: 1.0 = pure relevance, 0.0 = pure diversity.
+    pub mmr_lambda: f64,
+}
+
+impl Default for RetrievalPreset {
+    fn default() -> Self {
+        Self {
+            top_k: 5,
+            vector_weight: 0.5,
+            bm25_weight: 0.5,
+            boost_chunk_type: None,
+            chunk_type_boost: 1.0,
+            use_mmr: false,
+            mmr_lambda: 0.7,
+        }
+    }
+}
+
+fn default_presets() -> HashMap<String, RetrievalPreset> {
+    let general = RetrievalPreset::default();
+    let mut presets = HashMap::new();
+    presets.insert(
+        "define".to_string(),
+        RetrievalPreset {
+            top_k: 3,
+            vector_weight: 0.4,
+            bm25_weight: 0.6,
+            boost_chunk_type: Some("definition".to_string()),
+            chunk_type_boost: 1.5,
+            ..general.clone()
+        },
+    );
+    presets.insert(
+        "summary".to_string(),
+        RetrievalPreset { top_k: 12, use_mmr: true, mmr_lambda: 0.5, ..general.clone() },
+    );
+    presets.insert(
+        "more".to_string(),
+        RetrievalPreset { top_k: 8, use_mmr: true, mmr_lambda: 0.6, ..general.clone() },
+    );
+    // top_k applies per subject; results are interleaved.
+    presets.insert(
+        "compare".to_string(),
+        RetrievalPreset {
+            top_k: 4,
+            boost_chunk_type: Some("comparison".to_string()),
+            chunk_type_boost: 1.3,
+            ..general.clone()
+        },
+    );
+    presets.insert("general".to_string(), general);
+    presets
+}
+
+static PRESETS: Lazy<RwLock<HashMap<String, RetrievalPreset>>> =
+    Lazy::new(|| RwLock::new(default_presets()));
+
+/// Get the preset for an intent type, falling back to "general".
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_retrieval_preset(intent_type: String) -> RetrievalPreset {
+    let presets = PRESETS.read().unwrap();
+    presets
+        .get(&intent_type)
+        .or_else(|| presets.get("general"))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Override (or add) the preset for an intent type.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_retrieval_preset(intent_type: String, preset: RetrievalPreset) {
+    info!("[intent_presets] Setting preset for '{}': {:?}", intent_type, preset);
+    PRESETS.write().unwrap().insert(intent_type, preset);
+}
+
+/// Restore the built-in preset table.
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_retrieval_presets() {
+    *PRESETS.write().unwrap() = default_presets();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_presets_differ_by_intent() {
+        let presets = default_presets();
+        assert_eq!(presets["define"].top_k, 3);
+        assert_eq!(presets["define"].boost_chunk_type.as_deref(), Some("definition"));
+        assert!(presets["summary"].use_mmr);
+        assert!(!presets["general"].use_mmr);
+    }
+
+    #[test]
+    fn test_unknown_intent_falls_back_to_general() {
+        assert_eq!(get_retrieval_preset("no-such-intent".to_string()), get_retrieval_preset("general".to_string()));
+    }
+}
@@ -0,0 +1,333 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Optional knowledge-graph layer for multi-hop questions.
+//!
+//! When enabled, `add_chunks` extracts (subject, relation, object) triples
+//! with simple sentence patterns and stores them with the chunk they came
+//! from. `get_entity_neighborhood` walks the graph from an entity and
+//! `get_entity_supporting_chunks` returns the chunks backing those edges,
+//! so a question about "X" can pull evidence about what X is linked to.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rusqlite::{params, Connection};
+use log::info;
+
+use crate::api::content_crypto::read_content;
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::source_rag::ChunkSearchResult;
+use crate::api::tenant::active_tenant;
+
+/// Maximum hops `get_entity_neighborhood` will walk.
+const MAX_HOPS: u32 = 3;
+
+static GRAPH_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Noun phrase: up to four words, optionally led by an article.
+const NP: &str = r"(?:(?:the|a|an)\s+)?([\p{L}\p{N}][\p{L}\p{N}&.-]*(?:\s+[\p{L}\p{N}][\p{L}\p{N}&.-]*){0,3}?)";
+
+/// (pattern, relation) pairs, tried in order on each sentence.
+static PATTERNS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
+    let rules: [(&str, &str); 8] = [
+        (r"{NP}\s+(?:is|are)\s+(?:a\s+)?part\s+of\s+{NP}", "part_of"),
+        (r"{NP}\s+(?:is|was)\s+(?:founded|created|developed)\s+by\s+{NP}", "created_by"),
+        (r"{NP}\s+(?:acquired|bought)\s+{NP}", "acquired"),
+        (r"{NP}\s+(?:owns|operates|runs)\s+{NP}", "owns"),
+        (r"{NP}\s+(?:issued|issues|launched|launches)\s+{NP}", "issued"),
+        (r"{NP}\s+(?:uses|relies\s+on|is\s+built\s+on)\s+{NP}", "uses"),
+        (r"{NP}\s+(?:is|are)\s+(?:a|an|the)\s+{NP}", "is_a"),
+        (r"{NP}(?:은|는)\s+{NP}(?:이다|입니다|이에요|예요)", "is_a"),
+    ];
+    rules
+        .iter()
+        .map(|(rule, relation)| {
+            let pattern = format!("(?i)^{}$", rule.replace("{NP}", NP));
+            (Regex::new(&pattern).unwrap(), *relation)
+        })
+        .collect()
+});
+
+static SENTENCE_SPLIT: Lazy<Regex> = Lazy::new(|| Regex::new(r"[.!?。\n;]+").unwrap());
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowledgeTriple {
+    pub subject: String,
+    pub relation: String,
+    pub object: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphEdge {
+    pub subject: String,
+    pub relation: String,
+    pub object: String,
+    pub chunk_id: i64,
+    /// Hops from the queried entity (1 = direct edge).
+    pub hop: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphNeighborhood {
+    pub entity: String,
+    pub edges: Vec<GraphEdge>,
+    /// Distinct supporting chunks, closest hop first.
+    pub chunk_ids: Vec<i64>,
+}
+
+/// Enable or disable triple extraction at ingestion (off by default).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_knowledge_graph_enabled(enabled: bool) {
+    GRAPH_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_knowledge_graph_enabled() -> bool {
+    GRAPH_ENABLED.load(Ordering::Relaxed)
+}
+
+fn entity_key(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Extract triples from text using the sentence pattern rules.
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_triples(text: String) -> Vec<KnowledgeTriple> {
+    let mut triples = Vec::new();
+    for sentence in SENTENCE_SPLIT.split(&text) {
+        let sentence = sentence.trim().trim_end_matches(',');
+        if sentence.is_empty() {
+            continue;
+        }
+        for (pattern, relation) in PATTERNS.iter() {
+            if let Some(caps) = pattern.captures(sentence) {
+                let triple = KnowledgeTriple {
+                    subject: caps[1].trim().to_string(),
+                    relation: relation.to_string(),
+                    object: caps[2].trim().to_string(),
+                };
+                if !triples.contains(&triple) {
+                    triples.push(triple);
+                }
+                break;
+            }
+        }
+    }
+    triples
+}
+
+/// Create the `kg_triples` table (called from `init_source_db`).
+pub(crate) fn create_knowledge_graph_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS kg_triples (
+            id INTEGER PRIMARY KEY,
+            subject TEXT NOT NULL,
+            subject_key TEXT NOT NULL,
+            relation TEXT NOT NULL,
+            object TEXT NOT NULL,
+            object_key TEXT NOT NULL,
+            chunk_id INTEGER NOT NULL,
+            FOREIGN KEY (chunk_id) REFERENCES chunks(id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_kg_subject ON kg_triples(subject_key)", [])?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_kg_object ON kg_triples(object_key)", [])?;
+    Ok(())
+}
+
+/// Extract and store a chunk's triples when the graph is enabled
+/// (runs inside `add_chunks`' transaction).
+pub(crate) fn store_chunk_triples(conn: &Connection, chunk_id: i64, content: &str) -> rusqlite::Result<usize> {
+    if !is_knowledge_graph_enabled() {
+        return Ok(0);
+    }
+    let triples = extract_triples(content.to_string());
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO kg_triples (subject, subject_key, relation, object, object_key, chunk_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    for t in &triples {
+        stmt.execute(params![t.subject, entity_key(&t.subject), t.relation, t.object, entity_key(&t.object), chunk_id])?;
+    }
+    Ok(triples.len())
+}
+
+/// Breadth-first walk over a tenant's edges touching `entity` (either direction).
+fn walk_neighborhood(
+    conn: &Connection,
+    tenant_id: &str,
+    entity: &str,
+    max_hops: u32,
+    limit: usize,
+) -> rusqlite::Result<GraphNeighborhood> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT t.id, t.subject, t.relation, t.object, t.chunk_id, t.subject_key, t.object_key FROM kg_triples t
+         JOIN chunks c ON c.id = t.chunk_id
+         WHERE (t.subject_key = ?1 OR t.object_key = ?1) AND c.tenant_id = ?2",
+    )?;
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut seen_edges: HashSet<i64> = HashSet::new();
+    let mut queue: VecDeque<(String, u32)> = VecDeque::new();
+    let mut edges = Vec::new();
+    let start = entity_key(entity);
+    visited.insert(start.clone());
+    queue.push_back((start, 0));
+
+    while let Some((key, depth)) = queue.pop_front() {
+        if depth >= max_hops || edges.len() >= limit {
+            break;
+        }
+        let rows = stmt.query_map(params![key, tenant_id], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                GraphEdge {
+                    subject: row.get(1)?,
+                    relation: row.get(2)?,
+                    object: row.get(3)?,
+                    chunk_id: row.get(4)?,
+                    hop: depth + 1,
+                },
+                row.get::<_, String>(5)?,
+                row.get::<_, String>(6)?,
+            ))
+        })?;
+        for row in rows {
+            let (id, edge, subject_key, object_key) = row?;
+            if edges.len() >= limit || !seen_edges.insert(id) {
+                continue;
+            }
+            for next in [subject_key, object_key] {
+                if visited.insert(next.clone()) {
+                    queue.push_back((next, depth + 1));
+                }
+            }
+            edges.push(edge);
+        }
+    }
+
+    let mut chunk_ids: Vec<i64> = Vec::new();
+    for edge in &edges {
+        if !chunk_ids.contains(&edge.chunk_id) {
+            chunk_ids.push(edge.chunk_id);
+        }
+    }
+    Ok(GraphNeighborhood { entity: entity.to_string(), edges, chunk_ids })
+}
+
+/// Edges within `max_hops` (1..=3) of an entity, nearest first.
+pub fn get_entity_neighborhood(entity: String, max_hops: u32, limit: u32) -> Result<GraphNeighborhood, RagError> {
+    let conn = get_connection()?;
+    walk_neighborhood(&conn, &active_tenant(), &entity, max_hops.clamp(1, MAX_HOPS), limit as usize).map_err(db_error)
+}
+
+/// Chunks backing an entity's neighborhood. `similarity` is 1/hop, so
+/// direct evidence ranks above multi-hop evidence.
+pub fn get_entity_supporting_chunks(entity: String, max_hops: u32, limit: u32) -> Result<Vec<ChunkSearchResult>, RagError> {
+    let conn = get_connection()?;
+    let tenant_id = active_tenant();
+    let neighborhood =
+        walk_neighborhood(&conn, &tenant_id, &entity, max_hops.clamp(1, MAX_HOPS), usize::MAX).map_err(db_error)?;
+
+    let mut results = Vec::new();
+    for chunk_id in neighborhood.chunk_ids.iter().take(limit as usize) {
+        let hop = neighborhood.edges.iter().filter(|e| e.chunk_id == *chunk_id).map(|e| e.hop).min().unwrap_or(1);
+        let row = conn.query_row(
+            "SELECT c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), s.metadata, c.header_path
+             FROM chunks c LEFT JOIN sources s ON c.source_id = s.id WHERE c.id = ?1 AND c.tenant_id = ?2",
+            params![chunk_id, tenant_id],
+            |row| {
+                Ok(ChunkSearchResult {
+                    chunk_id: *chunk_id,
+                    source_id: row.get(0)?,
+                    chunk_index: row.get(1)?,
+                    content: read_content(row, 2)?,
+                    chunk_type: row.get(3)?,
+                    header_path: row.get(5)?,
+                    similarity: 1.0 / hop as f64,
+                    metadata: row.get(4)?,
+                })
+            },
+        );
+        match row {
+            Ok(result) => results.push(result),
+            Err(rusqlite::Error::QueryReturnedNoRows) => continue,
+            Err(e) => return Err(db_error(e)),
+        }
+    }
+    info!("[knowledge_graph] {} supporting chunks for '{}'", results.len(), entity);
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_triples_patterns() {
+        let triples = extract_triples(
+            "Ondo Finance issued USDY. USDY is a tokenized note. 이더리움은 블록체인이다.".to_string(),
+        );
+        let simple: Vec<(&str, &str, &str)> =
+            triples.iter().map(|t| (t.subject.as_str(), t.relation.as_str(), t.object.as_str())).collect();
+        assert_eq!(
+            simple,
+            vec![
+                ("Ondo Finance", "issued", "USDY"),
+                ("USDY", "is_a", "tokenized note"),
+                ("이더리움", "is_a", "블록체인"),
+            ]
+        );
+    }
+
+    /// Sets `GRAPH_ENABLED` and restores the previous value when dropped,
+    /// even if the test fails.
+    struct GraphEnabled(bool);
+
+    impl GraphEnabled {
+        fn set(enabled: bool) -> Self {
+            GraphEnabled(GRAPH_ENABLED.swap(enabled, Ordering::Relaxed))
+        }
+    }
+
+    impl Drop for GraphEnabled {
+        fn drop(&mut self) {
+            GRAPH_ENABLED.store(self.0, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_walk_neighborhood_two_hops() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT, tenant_id TEXT);
+             INSERT INTO chunks (id, tenant_id) VALUES (1, 'a'), (2, 'a'), (3, 'a'), (4, 'b');").unwrap();
+        create_knowledge_graph_table(&conn).unwrap();
+        let _enabled = GraphEnabled::set(true);
+        store_chunk_triples(&conn, 1, "Ondo Finance issued USDY.").unwrap();
+        store_chunk_triples(&conn, 2, "USDY is a tokenized note.").unwrap();
+        store_chunk_triples(&conn, 3, "Apple acquired Beats.").unwrap();
+        store_chunk_triples(&conn, 4, "Ondo Finance owns a vault.").unwrap();
+
+        let one_hop = walk_neighborhood(&conn, "a", "ondo finance", 1, 10).unwrap();
+        assert_eq!(one_hop.chunk_ids, vec![1]);
+        let two_hops = walk_neighborhood(&conn, "a", "Ondo  Finance", 2, 10).unwrap();
+        assert_eq!(two_hops.chunk_ids, vec![1, 2]);
+        assert_eq!(two_hops.edges[1].hop, 2);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Script-based language detection for queries and chunks.
+//!
+//! Counts characters per script (Hangul, Kana, Han, Latin) and picks the
+//! dominant one. Cheap enough to run on every query and every chunk.
+
+/// Detected language codes (ISO 639-1, plus "unknown").
+pub const LANG_KOREAN: &str = "ko";
+pub const LANG_JAPANESE: &str = "ja";
+pub const LANG_CHINESE: &str = "zh";
+pub const LANG_ENGLISH: &str = "en";
+pub const LANG_UNKNOWN: &str = "unknown";
+
+/// A CJK glyph carries roughly as much content as a short Latin word,
+/// so CJK counts are weighted up before comparing against Latin letters.
+const CJK_CHAR_WEIGHT: usize = 3;
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default, Clone, Copy)]
+struct ScriptCounts {
+    hangul: usize,
+    kana: usize,
+    han: usize,
+    latin: usize,
+}
+
+fn count_scripts(text: &str) -> ScriptCounts {
+    let mut counts = ScriptCounts::default();
+    for ch in text.chars() {
+        match ch as u32 {
+            0xAC00..=0xD7A3 | 0x1100..=0x11FF | 0x3130..=0x318F => counts.hangul += 1,
+            0x3040..=0x30FF | 0x31F0..=0x31FF => counts.kana += 1,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => counts.han += 1,
+            _ if ch.is_ascii_alphabetic() => counts.latin += 1,
+            0x00C0..=0x024F if ch.is_alphabetic() => counts.latin += 1,
+            _ => {}
+        }
+    }
+    counts
+}
+
+/// Detect the dominant language of `text` (internal, allocation-free).
+pub(crate) fn detect_language_code(text: &str) -> &'static str {
+    let counts = count_scripts(text);
+
+    // Kana only appears in Japanese, even when most glyphs are Han.
+    if counts.kana > 0 && counts.kana + counts.han >= counts.hangul {
+        let cjk = (counts.kana + counts.han) * CJK_CHAR_WEIGHT;
+        if cjk >= counts.latin {
+            return LANG_JAPANESE;
+        }
+    }
+
+    let hangul = counts.hangul * CJK_CHAR_WEIGHT;
+    let han = counts.han * CJK_CHAR_WEIGHT;
+    let latin = counts.latin;
+
+    if hangul == 0 && han == 0 && latin == 0 {
+        return LANG_UNKNOWN;
+    }
+    if hangul >= han && hangul >= latin {
+        LANG_KOREAN
+    } else if han >= latin {
+        LANG_CHINESE
+    } else {
+        LANG_ENGLISH
+    }
+}
+
+/// Detect the dominant language of a text.
+///
+/// Returns an ISO 639-1 code ("ko", "ja", "zh", "en") or "unknown" when the
+/// text has no letters. Latin-script text is reported as "en".
+#[flutter_rust_bridge::frb(sync)]
+pub fn detect_language(text: String) -> String {
+    detect_language_code(&text).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_single_script() {
+        assert_eq!(detect_language_code("What is a smart contract?"), "en");
+        assert_eq!(detect_language_code("스마트 계약이란 무엇인가요?"), "ko");
+        assert_eq!(detect_language_code("スマートコントラクトとは何ですか"), "ja");
+        assert_eq!(detect_language_code("什么是智能合约"), "zh");
+        assert_eq!(detect_language_code("1234 !?"), "unknown");
+    }
+
+    #[test]
+    fn test_detect_mixed_korean_english() {
+        // Short Hangul runs still dominate a few embedded English terms.
+        assert_eq!(detect_language_code("RWA 토큰화 구조 설명해줘"), "ko");
+        assert_eq!(detect_language_code("explain the tokenization flow of 삼성"), "en");
+    }
+}
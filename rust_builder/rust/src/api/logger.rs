@@ -1,29 +1,90 @@
 use flutter_rust_bridge::frb;
 use crate::frb_generated::StreamSink;
+use std::collections::HashMap;
 use std::sync::RwLock;
 use std::sync::atomic::{AtomicBool, Ordering};
 use lazy_static::lazy_static;
 
 lazy_static! {
     static ref DART_LOG_SINK: RwLock<Option<StreamSink<String>>> = RwLock::new(None);
+    static ref LOG_LEVELS: RwLock<LogLevels> = RwLock::new(LogLevels::default());
 }
 
 /// Track whether the logger has been initialized to avoid double initialization errors.
 static LOGGER_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
-use log::{Level, Metadata, Record};
+use log::{LevelFilter, Metadata, Record};
+
+/// Level for the build profile: DEBUG in debug builds, INFO in release.
+fn default_level() -> LevelFilter {
+    #[cfg(debug_assertions)]
+    {
+        LevelFilter::Debug
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        LevelFilter::Info
+    }
+}
+
+/// Global level plus per-module overrides, changeable at runtime.
+#[flutter_rust_bridge::frb(ignore)]
+struct LogLevels {
+    global: LevelFilter,
+    /// Module name (e.g. `source_rag` or `hnsw_rs`) -> level.
+    modules: HashMap<String, LevelFilter>,
+}
+
+impl Default for LogLevels {
+    fn default() -> Self {
+        Self { global: default_level(), modules: HashMap::new() }
+    }
+}
+
+impl LogLevels {
+    /// Level for a log target such as `rag_engine_flutter::api::source_rag`.
+    /// The most specific matching override wins.
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target == module.as_str()
+                    || target.ends_with(&format!("::{}", module))
+                    || target.starts_with(&format!("{}::", module))
+                    || target.contains(&format!("::{}::", module))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.global)
+    }
+
+    /// Most verbose level any target can log at (for `log::set_max_level`).
+    fn max_level(&self) -> LevelFilter {
+        self.modules.values().copied().fold(self.global, Ord::max)
+    }
+}
+
+fn parse_level(level: &str) -> anyhow::Result<LevelFilter> {
+    level
+        .trim()
+        .parse::<LevelFilter>()
+        .map_err(|_| anyhow::anyhow!("Unknown log level '{}'. Use off, error, warn, info, debug or trace.", level))
+}
+
+fn apply_log_levels(update: impl FnOnce(&mut LogLevels)) -> anyhow::Result<()> {
+    let mut levels = LOG_LEVELS.write().map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
+    update(&mut levels);
+    log::set_max_level(levels.max_level());
+    Ok(())
+}
 
 struct CombinedLogger;
 
 impl log::Log for CombinedLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        #[cfg(debug_assertions)]
-        {
-            metadata.level() <= Level::Debug
-        }
-        #[cfg(not(debug_assertions))]
-        {
-            metadata.level() <= Level::Info
+        match LOG_LEVELS.read() {
+            Ok(levels) => metadata.level() <= levels.level_for(metadata.target()),
+            Err(_) => metadata.level() <= default_level(),
         }
     }
 
@@ -53,9 +114,8 @@ static LOGGER: CombinedLogger = CombinedLogger;
 /// This function is idempotent - calling it multiple times is safe and will
 /// simply return Ok(()) if the logger is already initialized.
 /// 
-/// Log levels:
-/// - Debug builds: DEBUG and above
-/// - Release builds: INFO and above
+/// Log levels default to the build profile (DEBUG in debug builds, INFO in
+/// release); use `set_log_level` / `set_module_log_level` to change them.
 pub fn init_logger() -> anyhow::Result<()> {
     // Check if already initialized using atomic compare-exchange
     if LOGGER_INITIALIZED.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
@@ -63,11 +123,7 @@ pub fn init_logger() -> anyhow::Result<()> {
         return Ok(());
     }
     
-    // Set appropriate log level based on build type
-    #[cfg(debug_assertions)]
-    let level = log::LevelFilter::Debug;
-    #[cfg(not(debug_assertions))]
-    let level = log::LevelFilter::Info;
+    let level = LOG_LEVELS.read().map(|levels| levels.max_level()).unwrap_or_else(|_| default_level());
     
     log::set_logger(&LOGGER)
         .map(|()| log::set_max_level(level))
@@ -77,6 +133,47 @@ pub fn init_logger() -> anyhow::Result<()> {
             anyhow::anyhow!("Logger init failed: {}", e)
         })
 }
+
+/// Set the global log level at runtime (`off`, `error`, `warn`, `info`,
+/// `debug` or `trace`). Module overrides still take precedence.
+#[frb(sync)]
+pub fn set_log_level(level: String) -> anyhow::Result<()> {
+    let level = parse_level(&level)?;
+    apply_log_levels(|levels| levels.global = level)
+}
+
+/// Current global log level.
+#[frb(sync)]
+pub fn get_log_level() -> String {
+    LOG_LEVELS
+        .read()
+        .map(|levels| levels.global)
+        .unwrap_or_else(|_| default_level())
+        .to_string()
+        .to_lowercase()
+}
+
+/// Override the level for one module (e.g. `hybrid_search`, or a
+/// dependency crate such as `hnsw_rs`). `None` removes the override.
+#[frb(sync)]
+pub fn set_module_log_level(module: String, level: Option<String>) -> anyhow::Result<()> {
+    let level = level.as_deref().map(parse_level).transpose()?;
+    apply_log_levels(|levels| match level {
+        Some(level) => {
+            levels.modules.insert(module, level);
+        }
+        None => {
+            levels.modules.remove(&module);
+        }
+    })
+}
+
+/// Drop all overrides and go back to the build profile's level.
+#[frb(sync)]
+pub fn reset_log_levels() -> anyhow::Result<()> {
+    apply_log_levels(|levels| *levels = LogLevels::default())
+}
+
 /// Initialize the Dart log stream.
 /// Call this from Dart to start receiving Rust logs.
 #[frb(sync)]
@@ -114,3 +211,27 @@ fn try_send_log_to_dart(msg: &str) -> bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_override_wins_over_global() {
+        let mut levels = LogLevels { global: LevelFilter::Warn, modules: HashMap::new() };
+        levels.modules.insert("source_rag".to_string(), LevelFilter::Trace);
+        levels.modules.insert("hnsw_rs".to_string(), LevelFilter::Off);
+
+        assert_eq!(levels.level_for("rag_engine_flutter::api::source_rag"), LevelFilter::Trace);
+        assert_eq!(levels.level_for("hnsw_rs::hnsw"), LevelFilter::Off);
+        assert_eq!(levels.level_for("rag_engine_flutter::api::source_rag_ext"), LevelFilter::Warn);
+        assert_eq!(levels.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level("DEBUG").unwrap(), LevelFilter::Debug);
+        assert_eq!(parse_level(" off ").unwrap(), LevelFilter::Off);
+        assert!(parse_level("verbose").is_err());
+    }
+}
@@ -0,0 +1,313 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Background maintenance scheduler.
+//!
+//! The host app reports idle/charging state with `notify_host_state`.
+//! When the device is idle (and charging, if required) a pass runs on a
+//! low-priority thread: merge the incremental buffer into HNSW, compact
+//! HNSW points left behind by deleted chunks, prune stale BM25 documents
+//! and checkpoint the WAL. The pass works on the tenant that was active
+//! when it started, even if the app switches tenants meanwhile. Searches
+//! register through `query_guard`; the pass waits while any query is in
+//! flight, both between steps and between the points of an HNSW rebuild,
+//! and stops after its current step once the host is no longer idle.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use rusqlite::params;
+
+use crate::api::bm25_search::{bm25_document_ids, bm25_remove_documents_from_tenant};
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::panic_report::catch_panic;
+use crate::api::hnsw_index::tenant_hnsw_point_count;
+use crate::api::incremental_index::{drop_tenant_buffer, tenant_buffer_len, BufferTable};
+use crate::api::progress::{Progress, OP_HNSW_REBUILD};
+use crate::api::source_rag::rebuild_tenant_chunk_hnsw;
+use crate::api::tenant::active_tenant;
+
+/// How often a paused pass re-checks for in-flight queries.
+const QUERY_POLL: Duration = Duration::from_millis(25);
+
+static HOST_IDLE: AtomicBool = AtomicBool::new(false);
+static HOST_CHARGING: AtomicBool = AtomicBool::new(false);
+static PASS_RUNNING: AtomicBool = AtomicBool::new(false);
+static ACTIVE_QUERIES: AtomicUsize = AtomicUsize::new(0);
+/// Start of the last pass, in seconds since the Unix epoch.
+static LAST_PASS_AT: AtomicI64 = AtomicI64::new(0);
+
+static CONFIG: Lazy<RwLock<MaintenanceConfig>> = Lazy::new(|| RwLock::new(MaintenanceConfig::default()));
+static LAST_REPORT: Lazy<RwLock<Option<MaintenanceReport>>> = Lazy::new(|| RwLock::new(None));
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceConfig {
+    /// Only run while the device is charging.
+    pub require_charging: bool,
+    /// Minimum time between the start of two passes.
+    pub min_interval_secs: u32,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self { require_charging: true, min_interval_secs: 900 }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceReport {
+    pub started_at: i64,
+    pub duration_ms: u64,
+    /// Buffered vectors were folded into the HNSW index.
+    pub buffer_merged: bool,
+    /// HNSW was rebuilt to drop points of deleted chunks.
+    pub hnsw_compacted: bool,
+    pub bm25_pruned: u32,
+    pub wal_pages_checkpointed: i64,
+    /// The pass stopped early because the host left the idle state.
+    pub interrupted: bool,
+}
+
+/// Marks a search in flight; maintenance waits until all guards drop.
+pub(crate) struct QueryGuard;
+
+impl Drop for QueryGuard {
+    fn drop(&mut self) {
+        ACTIVE_QUERIES.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+pub(crate) fn query_guard() -> QueryGuard {
+    ACTIVE_QUERIES.fetch_add(1, Ordering::AcqRel);
+    QueryGuard
+}
+
+/// Block until no search is in flight.
+pub(crate) fn wait_for_idle_queries() {
+    while ACTIVE_QUERIES.load(Ordering::Acquire) > 0 {
+        std::thread::sleep(QUERY_POLL);
+    }
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_maintenance_config(config: MaintenanceConfig) {
+    info!("[maintenance] Config: {:?}", config);
+    *CONFIG.write().unwrap() = config;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_maintenance_config() -> MaintenanceConfig {
+    CONFIG.read().unwrap().clone()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_maintenance_running() -> bool {
+    PASS_RUNNING.load(Ordering::Acquire)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_last_maintenance_report() -> Option<MaintenanceReport> {
+    LAST_REPORT.read().unwrap().clone()
+}
+
+fn host_allows(config: &MaintenanceConfig, idle: bool, charging: bool) -> bool {
+    idle && (charging || !config.require_charging)
+}
+
+fn pass_due(config: &MaintenanceConfig, last_pass_at: i64, now: i64) -> bool {
+    last_pass_at == 0 || now - last_pass_at >= config.min_interval_secs as i64
+}
+
+/// Report the host's idle/charging state. Starts a maintenance pass for
+/// the active tenant when the state allows it and the last pass is older
+/// than `min_interval_secs`; returns true if a pass was started. Leaving
+/// the idle state stops a running pass after its current step.
+#[flutter_rust_bridge::frb(sync)]
+pub fn notify_host_state(idle: bool, charging: bool) -> bool {
+    HOST_IDLE.store(idle, Ordering::Release);
+    HOST_CHARGING.store(charging, Ordering::Release);
+    let config = get_maintenance_config();
+    if !host_allows(&config, idle, charging) || !pass_due(&config, LAST_PASS_AT.load(Ordering::Acquire), now_secs()) {
+        return false;
+    }
+    if PASS_RUNNING.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_err() {
+        return false;
+    }
+    LAST_PASS_AT.store(now_secs(), Ordering::Release);
+    let tenant_id = active_tenant();
+    let spawned = std::thread::Builder::new()
+        .name("rag-maintenance".to_string())
+        .spawn(move || {
+            lower_thread_priority();
+            match catch_panic("maintenance pass", || run_pass(&tenant_id)) {
+                Ok(report) => {
+                    info!("[maintenance] Pass finished: {:?}", report);
+                    *LAST_REPORT.write().unwrap() = Some(report);
+                }
+                Err(e) => warn!("[maintenance] {}", e),
+            }
+            PASS_RUNNING.store(false, Ordering::Release);
+        });
+    if let Err(e) = spawned {
+        warn!("[maintenance] Failed to start pass: {}", e);
+        PASS_RUNNING.store(false, Ordering::Release);
+        return false;
+    }
+    true
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lower_thread_priority() {
+    // On Linux/Android `who = 0` with PRIO_PROCESS targets the calling thread.
+    let rc = unsafe { libc::setpriority(libc::PRIO_PROCESS as _, 0, 10) };
+    if rc != 0 {
+        warn!("[maintenance] Could not lower thread priority");
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+pub(crate) fn lower_thread_priority() {}
+
+/// Wait while queries are in flight. Returns false when the pass should
+/// stop because the host left the idle state.
+fn wait_for_turn() -> bool {
+    loop {
+        let config = get_maintenance_config();
+        if !host_allows(&config, HOST_IDLE.load(Ordering::Acquire), HOST_CHARGING.load(Ordering::Acquire)) {
+            return false;
+        }
+        if ACTIVE_QUERIES.load(Ordering::Acquire) == 0 {
+            return true;
+        }
+        std::thread::sleep(QUERY_POLL);
+    }
+}
+
+fn run_pass(tenant_id: &str) -> MaintenanceReport {
+    let started = Instant::now();
+    let mut report = MaintenanceReport { started_at: now_secs(), ..Default::default() };
+    info!("[maintenance] Pass started for tenant {}", tenant_id);
+
+    type Step = fn(&str, &mut MaintenanceReport) -> Result<(), RagError>;
+    let steps: [(&str, Step); 3] = [("hnsw", compact_hnsw), ("bm25", prune_bm25), ("wal", checkpoint_wal)];
+    for (name, step) in steps {
+        if !wait_for_turn() {
+            report.interrupted = true;
+            break;
+        }
+        if let Err(e) = step(tenant_id, &mut report) {
+            warn!("[maintenance] Step {} failed: {}", name, e);
+        }
+    }
+    report.duration_ms = started.elapsed().as_millis() as u64;
+    report
+}
+
+fn chunk_ids(tenant_id: &str) -> Result<HashSet<i64>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare("SELECT id FROM chunks WHERE tenant_id = ?1").map_err(db_error)?;
+    let ids = stmt
+        .query_map(params![tenant_id], |row| row.get(0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<HashSet<i64>>>()
+        .map_err(db_error)?;
+    Ok(ids)
+}
+
+/// Rebuild HNSW when the buffer holds vectors or the index has points
+/// for deleted chunks (hnsw_rs cannot remove points in place).
+fn compact_hnsw(tenant_id: &str, report: &mut MaintenanceReport) -> Result<(), RagError> {
+    let Some(points) = tenant_hnsw_point_count(tenant_id) else {
+        // Not loaded; the next search builds it from the database.
+        return Ok(());
+    };
+    let buffered = tenant_buffer_len(tenant_id, BufferTable::Chunks);
+    let chunks = chunk_ids(tenant_id)?.len();
+    if buffered == 0 && points == chunks {
+        return Ok(());
+    }
+    rebuild_tenant_chunk_hnsw(tenant_id, Progress::new(OP_HNSW_REBUILD).with_pause(wait_for_idle_queries))?;
+    drop_tenant_buffer(tenant_id, BufferTable::Chunks);
+    report.buffer_merged = buffered > 0;
+    report.hnsw_compacted = points > chunks;
+    Ok(())
+}
+
+/// BM25 document ids with no chunk row behind them.
+fn stale_ids(indexed: Vec<i64>, live: &HashSet<i64>) -> Vec<i64> {
+    indexed.into_iter().filter(|id| !live.contains(id)).collect()
+}
+
+fn prune_bm25(tenant_id: &str, report: &mut MaintenanceReport) -> Result<(), RagError> {
+    let stale = stale_ids(bm25_document_ids(tenant_id), &chunk_ids(tenant_id)?);
+    if !stale.is_empty() {
+        bm25_remove_documents_from_tenant(tenant_id, &stale);
+    }
+    report.bm25_pruned = stale.len() as u32;
+    Ok(())
+}
+
+fn checkpoint_wal(_tenant_id: &str, report: &mut MaintenanceReport) -> Result<(), RagError> {
+    let conn = get_connection()?;
+    // (busy, log frames, checkpointed frames); -1 when not in WAL mode.
+    let (busy, checkpointed): (i64, i64) = conn
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| Ok((row.get(0)?, row.get(2)?)))
+        .map_err(db_error)?;
+    if busy != 0 {
+        info!("[maintenance] WAL checkpoint blocked by readers");
+    }
+    report.wal_pages_checkpointed = checkpointed.max(0);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_gates() {
+        let config = MaintenanceConfig::default();
+        assert!(host_allows(&config, true, true));
+        assert!(!host_allows(&config, true, false));
+        assert!(!host_allows(&config, false, true));
+        let relaxed = MaintenanceConfig { require_charging: false, ..config.clone() };
+        assert!(host_allows(&relaxed, true, false));
+
+        assert!(pass_due(&config, 0, 1_000));
+        assert!(!pass_due(&config, 1_000, 1_000 + 899));
+        assert!(pass_due(&config, 1_000, 1_000 + 900));
+    }
+
+    #[test]
+    fn test_stale_ids_and_query_guard() {
+        let live: HashSet<i64> = [1, 2, 4].into_iter().collect();
+        assert_eq!(stale_ids(vec![1, 2, 3, 4, 5], &live), vec![3, 5]);
+
+        let before = ACTIVE_QUERIES.load(Ordering::Acquire);
+        let guard = query_guard();
+        assert!(ACTIVE_QUERIES.load(Ordering::Acquire) > before);
+        drop(guard);
+    }
+}
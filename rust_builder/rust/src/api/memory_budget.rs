@@ -0,0 +1,189 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Memory budget and low-memory mode.
+//!
+//! `set_memory_budget` picks a `MemoryProfile` for the device. Below
+//! `LOW_MEMORY_THRESHOLD_MB` the engine switches to a constrained mode:
+//! - smaller SQLite page cache and no mmap
+//! - int8 quantized vector index instead of the HNSW graph
+//! - smaller search candidate multipliers
+//! - exact scans stream chunk content in pages instead of loading it all
+//!
+//! Call it before `init_db_pool` so the pool starts with the right PRAGMAs;
+//! calling it later re-opens the pool and rebuilds the vector index.
+
+use std::sync::RwLock;
+
+use flutter_rust_bridge::frb;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+
+use crate::api::db_pool::{db_pool_config, init_db_pool, is_pool_initialized};
+use crate::api::error::RagError;
+use crate::api::hnsw_index::hnsw_point_count;
+use crate::api::incremental_index::{tenant_buffer_len, BufferTable};
+use crate::api::quantized_index::{quantized_index_bytes, quantized_point_count};
+use crate::api::source_rag::rebuild_chunk_hnsw_index;
+use crate::api::tenant::active_tenant;
+
+/// Budgets below this (in MB) select low-memory mode.
+pub const LOW_MEMORY_THRESHOLD_MB: u32 = 512;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryProfile {
+    /// `None` = no budget set (standard mode).
+    pub budget_mb: Option<u32>,
+    pub low_memory: bool,
+    pub sqlite_cache_kb: u32,
+    pub sqlite_mmap_mb: u32,
+    /// Use the int8 quantized flat index instead of HNSW.
+    pub quantize_index: bool,
+    /// Vector/BM25 candidates fetched per requested result.
+    pub candidate_multiplier: u32,
+    /// Same, when a search filter is active.
+    pub filtered_candidate_multiplier: u32,
+    /// Rows fetched per page by exact scans; 0 loads them all at once.
+    pub scan_page_rows: u32,
+}
+
+impl MemoryProfile {
+    fn for_budget(budget_mb: Option<u32>) -> Self {
+        if budget_mb.is_some_and(|mb| mb < LOW_MEMORY_THRESHOLD_MB) {
+            Self {
+                budget_mb,
+                low_memory: true,
+                sqlite_cache_kb: 8_000,
+                sqlite_mmap_mb: 0,
+                quantize_index: true,
+                candidate_multiplier: 1,
+                filtered_candidate_multiplier: 2,
+                scan_page_rows: 500,
+            }
+        } else {
+            Self {
+                budget_mb,
+                low_memory: false,
+                sqlite_cache_kb: 64_000,
+                sqlite_mmap_mb: 256,
+                quantize_index: false,
+                candidate_multiplier: 2,
+                filtered_candidate_multiplier: 4,
+                scan_page_rows: 0,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryUsage {
+    pub low_memory: bool,
+    /// Points in the active tenant's HNSW graph (0 when not loaded).
+    pub hnsw_points: u64,
+    /// Points in the active tenant's quantized index (0 when not loaded).
+    pub quantized_points: u64,
+    /// Approximate bytes held by all quantized indexes.
+    pub quantized_index_bytes: u64,
+    /// Embeddings waiting in the active tenant's incremental buffer.
+    pub buffered_embeddings: u64,
+    /// Heap currently allocated by SQLite (page caches included).
+    pub sqlite_memory_bytes: u64,
+    /// Resident set size of the process, where the platform exposes it.
+    pub process_rss_bytes: Option<u64>,
+}
+
+static PROFILE: Lazy<RwLock<MemoryProfile>> = Lazy::new(|| RwLock::new(MemoryProfile::for_budget(None)));
+
+/// Set the memory budget in MB (`None` for standard mode) and return the
+/// resulting profile.
+pub fn set_memory_budget(budget_mb: Option<u32>) -> Result<MemoryProfile, RagError> {
+    let profile = MemoryProfile::for_budget(budget_mb);
+    let previous = std::mem::replace(&mut *PROFILE.write().unwrap(), profile.clone());
+    if previous == profile {
+        return Ok(profile);
+    }
+    info!("[memory] Budget {:?} MB -> low_memory={}", budget_mb, profile.low_memory);
+
+    if is_pool_initialized() {
+        if let Some((db_path, max_size)) = db_pool_config() {
+            init_db_pool(db_path, max_size)?;
+        }
+        if previous.quantize_index != profile.quantize_index {
+            if let Err(e) = rebuild_chunk_hnsw_index() {
+                warn!("[memory] Vector index rebuild after mode switch failed: {}", e);
+            }
+        }
+    }
+    Ok(profile)
+}
+
+#[frb(sync)]
+pub fn get_memory_profile() -> MemoryProfile {
+    memory_profile()
+}
+
+/// Current memory usage of the engine's main consumers.
+#[frb(sync)]
+pub fn get_memory_usage() -> MemoryUsage {
+    let tenant_id = active_tenant();
+    MemoryUsage {
+        low_memory: memory_profile().low_memory,
+        hnsw_points: hnsw_point_count().unwrap_or(0) as u64,
+        quantized_points: quantized_point_count(&tenant_id).unwrap_or(0) as u64,
+        quantized_index_bytes: quantized_index_bytes() as u64,
+        buffered_embeddings: (tenant_buffer_len(&tenant_id, BufferTable::Chunks)
+            + tenant_buffer_len(&tenant_id, BufferTable::Docs)) as u64,
+        sqlite_memory_bytes: unsafe { rusqlite::ffi::sqlite3_memory_used() }.max(0) as u64,
+        process_rss_bytes: process_rss_bytes(),
+    }
+}
+
+pub(crate) fn memory_profile() -> MemoryProfile {
+    PROFILE.read().unwrap().clone()
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn process_rss_bytes() -> Option<u64> {
+    // statm: size resident shared text lib data dt (in pages)
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let resident: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    (page_size > 0).then(|| resident * page_size as u64)
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn process_rss_bytes() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_for_budget() {
+        let standard = MemoryProfile::for_budget(None);
+        assert!(!standard.low_memory && !standard.quantize_index);
+        assert_eq!(standard.scan_page_rows, 0);
+
+        let low = MemoryProfile::for_budget(Some(256));
+        assert!(low.low_memory && low.quantize_index);
+        assert!(low.sqlite_cache_kb < standard.sqlite_cache_kb);
+        assert!(low.candidate_multiplier < standard.candidate_multiplier);
+
+        assert!(!MemoryProfile::for_budget(Some(LOW_MEMORY_THRESHOLD_MB)).low_memory);
+    }
+}
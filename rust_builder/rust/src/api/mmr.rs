@@ -0,0 +1,137 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Maximal Marginal Relevance (MMR) diversification.
+//!
+//! Picks results one at a time, each maximizing
+//! `lambda * relevance - (1 - lambda) * max cosine to the picks so far`,
+//! so several near-identical chunks of the same page do not fill the
+//! whole top-k. Searches over-fetch `MMR_FETCH_MULTIPLIER` times the
+//! requested count to give it something to choose from.
+
+use std::collections::HashMap;
+
+use rusqlite::Connection;
+
+use crate::api::embedding_storage::decode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::vector_ops::{dot, norm};
+
+/// Candidates fetched per requested result before diversifying.
+pub(crate) const MMR_FETCH_MULTIPLIER: u32 = 3;
+
+/// `lambda` must be in [0, 1]: 1.0 is pure relevance, 0.0 pure diversity.
+pub(crate) fn validate_lambda(lambda: f64) -> Result<(), RagError> {
+    if !(0.0..=1.0).contains(&lambda) {
+        return Err(RagError::InvalidInput(format!(
+            "diversity_lambda must be between 0 and 1, got {}",
+            lambda
+        )));
+    }
+    Ok(())
+}
+
+/// Stored embeddings of `ids`; missing chunks are left out.
+pub(crate) fn load_embeddings(
+    conn: &Connection,
+    ids: &[i64],
+) -> Result<HashMap<i64, Vec<f32>>, RagError> {
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let id_list = ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let query = format!("SELECT id, embedding FROM chunks WHERE id IN ({})", id_list);
+    let mut stmt = conn.prepare(&query).map_err(db_error)?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?)))
+        .map_err(db_error)?;
+    Ok(rows
+        .flatten()
+        .map(|(id, blob)| (id, decode_embedding(&blob)))
+        .collect())
+}
+
+/// Indices into `candidates` (`(id, relevance)`, relevance roughly in
+/// [0, 1]) in MMR selection order, at most `top_k` of them. Candidates
+/// `embedding_of` has no vector for count as similar to nothing.
+pub(crate) fn mmr_select<'a>(
+    candidates: &[(i64, f64)],
+    embedding_of: impl Fn(i64) -> Option<&'a [f32]>,
+    lambda: f64,
+    top_k: usize,
+) -> Vec<usize> {
+    let vectors: Vec<Option<(&[f32], f32)>> = candidates
+        .iter()
+        .map(|(id, _)| embedding_of(*id).map(|e| (e, norm(e))))
+        .collect();
+    let cosine = |a: usize, b: usize| match (vectors[a], vectors[b]) {
+        (Some((va, na)), Some((vb, nb))) if va.len() == vb.len() && na > 0.0 && nb > 0.0 => {
+            (dot(va, vb) / (na * nb)) as f64
+        }
+        _ => 0.0,
+    };
+
+    // Highest similarity of each candidate to anything picked so far
+    // (never below 0), updated as picks are made instead of recomputed
+    // every round.
+    let mut redundancy = vec![0.0f64; candidates.len()];
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+    let mut selected = Vec::with_capacity(top_k.min(candidates.len()));
+    while selected.len() < top_k && !remaining.is_empty() {
+        let mut best = (0, f64::MIN);
+        for (pos, &i) in remaining.iter().enumerate() {
+            let score = lambda * candidates[i].1 - (1.0 - lambda) * redundancy[i];
+            if score > best.1 {
+                best = (pos, score);
+            }
+        }
+        let picked = remaining.remove(best.0);
+        for &i in &remaining {
+            redundancy[i] = redundancy[i].max(cosine(i, picked));
+        }
+        selected.push(picked);
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mmr_skips_near_duplicates() {
+        let candidates = vec![(1, 1.0), (2, 0.99), (3, 0.8)];
+        let embeddings: HashMap<i64, Vec<f32>> = [
+            (1, vec![1.0, 0.0]),
+            (2, vec![0.999, 0.01]),
+            (3, vec![0.0, 1.0]),
+        ]
+        .into_iter()
+        .collect();
+
+        let embedding_of = |id: i64| embeddings.get(&id).map(|e| e.as_slice());
+
+        assert_eq!(mmr_select(&candidates, embedding_of, 1.0, 2), vec![0, 1]);
+        assert_eq!(mmr_select(&candidates, embedding_of, 0.5, 2), vec![0, 2]);
+        assert_eq!(mmr_select(&candidates, embedding_of, 0.5, 10).len(), 3);
+        assert!(validate_lambda(1.5).is_err());
+        assert!(validate_lambda(f64::NAN).is_err());
+    }
+}
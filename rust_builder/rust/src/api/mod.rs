@@ -11,12 +11,69 @@ pub mod tokenizer;
 pub mod hnsw_index;
 pub mod source_rag;
 pub mod semantic_chunker;
+pub mod code_chunker;
+pub mod chunk_dedup;
+pub mod classification_rules;
 pub mod bm25_search;
 pub mod hybrid_search;
 pub mod incremental_index;
 pub mod compression_utils;
 pub mod user_intent;
+pub mod language_detect;
+pub mod sentence_segmenter;
+pub mod retrieval_state;
+pub mod query_keywords;
+pub mod query_decompose;
+pub mod intent_presets;
+pub mod query_rewrite;
+pub mod query_normalize;
+pub mod query_parser;
+pub mod fts_keyword;
+pub mod query_history;
+pub mod search_analytics;
+pub mod benchmark;
+pub mod metrics;
+pub mod search_trace;
+pub mod chat_memory;
+pub mod entity_extraction;
+pub mod knowledge_graph;
+pub mod related_chunks;
+pub mod mmr;
+pub mod rerank;
+pub mod pagination;
+pub mod snippets;
+pub mod tenant;
+pub mod content_crypto;
+pub mod corpus_report;
+pub mod ingest;
+pub mod ingest_jobs;
+pub mod staged_ingest;
+pub mod snapshot;
+pub mod maintenance;
+pub mod reembedding;
+pub mod analyzers;
+pub mod audit;
+pub mod quantized_index;
+pub mod binary_index;
+pub mod named_index;
+pub mod named_bm25;
+pub mod fuzzy_terms;
+pub mod synonyms;
+pub mod source_grouping;
+pub mod async_ops;
+pub mod progress;
+pub mod memory_budget;
+pub mod background_tasks;
+pub mod chunk_store;
+pub mod embedding_storage;
+pub mod vector_ops;
+pub mod source_index;
+pub mod index_freshness;
+pub mod chunk_index_store;
+pub mod engine_info;
 pub mod document_parser;
 pub mod db_pool;
+pub mod db_retry;
 pub mod error;
 pub mod logger;
+pub mod panic_report;
@@ -0,0 +1,266 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Named BM25 indexes (collections), the keyword side of `named_index`.
+//!
+//! Each collection has its own inverted index, so document frequencies
+//! and average lengths come from that collection alone and a search in
+//! "work" is not skewed by terms common in "notes". Collections belong
+//! to the active tenant, use its index options (see
+//! `set_bm25_index_options`) and stay in memory whatever the keyword
+//! backend. Document ids are chunk ids: deleting a chunk removes it from
+//! the tenant's collections, so a later chunk reusing its id starts clean.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use log::info;
+use once_cell::sync::Lazy;
+
+use crate::api::bm25_search::{
+    configured_options, BooleanTokens, Bm25SearchResult, DocConstraint, InvertedIndex,
+};
+use crate::api::error::RagError;
+use crate::api::named_index::index_key;
+use crate::api::query_normalize::normalize_query_text;
+use crate::api::query_parser::parse_boolean_query;
+use crate::api::tenant::active_tenant;
+
+/// Named indexes keyed by `(tenant, name)`.
+static NAMED_BM25_INDEXES: Lazy<RwLock<HashMap<(String, String), InvertedIndex>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone)]
+pub struct NamedBm25IndexInfo {
+    pub name: String,
+    pub document_count: u64,
+    pub vocabulary_size: u64,
+}
+
+fn missing(name: &str) -> RagError {
+    RagError::InvalidInput(format!("No BM25 index named '{}'", name))
+}
+
+/// Build (or replace) the active tenant's BM25 index `name` from `docs`.
+pub fn build_bm25_index_named(name: String, docs: Vec<(i64, String)>) -> Result<(), RagError> {
+    let key = index_key(&name)?;
+    let mut index = InvertedIndex::with_options(configured_options(&key.0));
+    for (doc_id, content) in &docs {
+        index.add_document(*doc_id, content);
+    }
+    info!(
+        "[named_bm25] Built '{}' for tenant {} with {} documents",
+        key.1,
+        key.0,
+        index.len()
+    );
+    NAMED_BM25_INDEXES.write().unwrap().insert(key, index);
+    Ok(())
+}
+
+/// Add documents to the existing index `name`; ids already in it are
+/// re-indexed with the new content.
+pub fn bm25_add_documents_named(name: String, docs: Vec<(i64, String)>) -> Result<(), RagError> {
+    let key = index_key(&name)?;
+    let mut indexes = NAMED_BM25_INDEXES.write().unwrap();
+    let index = indexes.get_mut(&key).ok_or_else(|| missing(&name))?;
+    for (doc_id, content) in &docs {
+        index.update_document(*doc_id, content);
+    }
+    Ok(())
+}
+
+/// Remove documents from the index `name`; unknown ids are ignored.
+pub fn bm25_remove_documents_named(name: String, doc_ids: Vec<i64>) -> Result<(), RagError> {
+    let key = index_key(&name)?;
+    let mut indexes = NAMED_BM25_INDEXES.write().unwrap();
+    let index = indexes.get_mut(&key).ok_or_else(|| missing(&name))?;
+    for doc_id in doc_ids {
+        index.remove_document(doc_id);
+    }
+    Ok(())
+}
+
+/// The best `top_k` matches in `index` and, for a query with
+/// `+`/`-`/AND/NOT clauses, the documents it admits.
+fn search_index(
+    index: &InvertedIndex,
+    query: &str,
+    top_k: usize,
+) -> (Vec<(i64, f64)>, Option<DocConstraint>) {
+    let parsed = parse_boolean_query(query);
+    if !parsed.has_operators() {
+        return (index.search(&normalize_query_text(query), top_k), None);
+    }
+    let tokens = BooleanTokens::from_query(&parsed, |t| index.tokenize(t));
+    (index.search_boolean(&tokens, top_k), Some(index.constraint(&tokens)))
+}
+
+/// `search_bm25_named` plus the boolean constraint of the query, for
+/// fusing with other candidate lists.
+pub(crate) fn search_bm25_named_constrained(
+    name: &str,
+    query: &str,
+    top_k: usize,
+) -> Result<(Vec<Bm25SearchResult>, Option<DocConstraint>), RagError> {
+    let key = index_key(name)?;
+    let indexes = NAMED_BM25_INDEXES.read().unwrap();
+    let index = indexes.get(&key).ok_or_else(|| missing(name))?;
+    let (hits, constraint) = search_index(index, query, top_k);
+    let results = hits
+        .into_iter()
+        .map(|(doc_id, score)| Bm25SearchResult { doc_id, score })
+        .collect();
+    Ok((results, constraint))
+}
+
+/// Search the active tenant's BM25 index `name`, best first.
+pub fn search_bm25_named(
+    name: String,
+    query: String,
+    top_k: u32,
+) -> Result<Vec<Bm25SearchResult>, RagError> {
+    Ok(search_bm25_named_constrained(&name, &query, top_k as usize)?.0)
+}
+
+/// Drop the active tenant's BM25 index `name`; false if it did not exist.
+#[flutter_rust_bridge::frb(sync)]
+pub fn drop_bm25_index_named(name: String) -> bool {
+    NAMED_BM25_INDEXES
+        .write()
+        .unwrap()
+        .remove(&(active_tenant(), name))
+        .is_some()
+}
+
+/// The active tenant's named BM25 indexes, sorted by name.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_bm25_indexes_named() -> Vec<NamedBm25IndexInfo> {
+    let tenant_id = active_tenant();
+    let mut infos: Vec<NamedBm25IndexInfo> = NAMED_BM25_INDEXES
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|((tenant, _), _)| *tenant == tenant_id)
+        .map(|((_, name), index)| NamedBm25IndexInfo {
+            name: name.clone(),
+            document_count: index.len() as u64,
+            vocabulary_size: index.vocabulary_size() as u64,
+        })
+        .collect();
+    infos.sort_by(|a, b| a.name.cmp(&b.name));
+    infos
+}
+
+/// Remove deleted chunks from all of `tenant_id`'s named BM25 indexes.
+pub(crate) fn delete_named_bm25_chunks(tenant_id: &str, chunk_ids: &[i64]) {
+    if chunk_ids.is_empty() {
+        return;
+    }
+    for ((tenant, _), index) in NAMED_BM25_INDEXES.write().unwrap().iter_mut() {
+        if tenant == tenant_id {
+            for chunk_id in chunk_ids {
+                index.remove_document(*chunk_id);
+            }
+        }
+    }
+}
+
+/// Re-index `doc_id` with `content` in those of `tenant_id`'s named BM25
+/// indexes that hold it.
+pub(crate) fn update_named_bm25_document(tenant_id: &str, doc_id: i64, content: &str) {
+    for ((tenant, _), index) in NAMED_BM25_INDEXES.write().unwrap().iter_mut() {
+        if tenant == tenant_id && index.contains(doc_id) {
+            index.update_document(doc_id, content);
+        }
+    }
+}
+
+pub(crate) fn drop_tenant_named_bm25_indexes(tenant_id: &str) {
+    NAMED_BM25_INDEXES
+        .write()
+        .unwrap()
+        .retain(|(tenant, _), _| tenant != tenant_id);
+}
+
+pub(crate) fn clear_all_named_bm25_indexes() {
+    NAMED_BM25_INDEXES.write().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_bm25_indexes_keep_their_own_statistics() {
+        let mut work = InvertedIndex::new();
+        work.add_document(1, "quarterly budget review");
+        work.add_document(2, "budget planning meeting");
+        let mut notes = InvertedIndex::new();
+        notes.add_document(10, "budget for groceries");
+        notes.add_document(11, "hiking trip ideas");
+        notes.add_document(12, "book list");
+
+        // "budget" is in every work document but rare among notes.
+        assert!(work.idf(work.document_frequency("budget")) < notes.idf(notes.document_frequency("budget")));
+
+        let (hits, constraint) = search_index(&work, "budget -meeting", 10);
+        assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![1]);
+        assert!(!constraint.unwrap().allows(2));
+        let (hits, constraint) = search_index(&notes, "hiking", 10);
+        assert_eq!(hits[0].0, 11);
+        assert!(constraint.is_none());
+    }
+
+    #[test]
+    fn test_deleted_chunks_leave_named_bm25_indexes() {
+        let tenant = "named_bm25_delete_test";
+        let key = |name: &str| (tenant.to_string(), name.to_string());
+        for name in ["work", "notes"] {
+            let mut index = InvertedIndex::new();
+            index.add_document(1, "budget review");
+            index.add_document(2, "budget planning");
+            NAMED_BM25_INDEXES.write().unwrap().insert(key(name), index);
+        }
+
+        delete_named_bm25_chunks(tenant, &[1]);
+        delete_named_bm25_chunks("other_tenant", &[2]);
+        for name in ["work", "notes"] {
+            let indexes = NAMED_BM25_INDEXES.read().unwrap();
+            let (hits, _) = search_index(&indexes[&key(name)], "budget", 10);
+            assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![2]);
+        }
+        drop_tenant_named_bm25_indexes(tenant);
+    }
+
+    #[test]
+    fn test_updated_documents_are_reindexed_where_held() {
+        let tenant = "named_bm25_update_test";
+        let key = |name: &str| (tenant.to_string(), name.to_string());
+        let mut work = InvertedIndex::new();
+        work.add_document(1, "budget review");
+        NAMED_BM25_INDEXES.write().unwrap().insert(key("work"), work);
+        NAMED_BM25_INDEXES.write().unwrap().insert(key("notes"), InvertedIndex::new());
+
+        update_named_bm25_document(tenant, 1, "hiring plan");
+        let indexes = NAMED_BM25_INDEXES.read().unwrap();
+        assert!(search_index(&indexes[&key("work")], "budget", 10).0.is_empty());
+        assert_eq!(search_index(&indexes[&key("work")], "hiring", 10).0[0].0, 1);
+        assert!(indexes[&key("notes")].is_empty());
+        drop(indexes);
+        drop_tenant_named_bm25_indexes(tenant);
+    }
+}
@@ -0,0 +1,413 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Named vector indexes (collections) alongside the tenant's main index.
+//!
+//! An app can keep, say, "work" and "notes" embeddings in separate HNSW
+//! graphs, so a search never mixes the two vector spaces and adding to
+//! one collection never rebuilds the other. Collections belong to the
+//! active tenant and are in memory only. Each is built with the
+//! representation of the current index config and, unless its
+//! `NamedIndexConfig` says otherwise, its metric.
+//!
+//! Point ids are chunk ids. hnsw_rs cannot remove points, so deleting a
+//! chunk (or its source) only hides it from the tenant's collections,
+//! like `delete_hnsw_named`; SQLite may hand the id to a later chunk,
+//! which the collection accepts only after a rebuild.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use log::{debug, info};
+use once_cell::sync::Lazy;
+
+use crate::api::error::RagError;
+use crate::api::hnsw_index::{
+    build_tenant_graph, get_hnsw_seeded_build, get_index_config, DistanceMetric, HnswBuildConfig,
+    HnswSearchResult, Quantization, TenantGraph,
+};
+use crate::api::panic_report::catch_panic;
+use crate::api::progress::Progress;
+use crate::api::tenant::active_tenant;
+
+struct NamedIndex {
+    graph: Arc<TenantGraph>,
+    /// Embedding dimension; 0 until the first point of an empty build.
+    dimension: usize,
+    /// Every id ever inserted, deleted ones included.
+    ids: HashSet<i64>,
+    /// Ids hidden from searches until the next build.
+    deleted: HashSet<i64>,
+    ef_search: Option<u32>,
+}
+
+/// Named indexes keyed by `(tenant, name)`.
+static NAMED_INDEXES: Lazy<RwLock<HashMap<(String, String), NamedIndex>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Parameters of one named index; `None` fields fall back to the index
+/// config's metric, the adaptive build parameters and `max(100, 5 * top_k)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NamedIndexConfig {
+    /// Distance metric; int8 indexes support cosine only.
+    pub metric: Option<DistanceMetric>,
+    pub build: Option<HnswBuildConfig>,
+    /// Candidate list size during search (raised to the number of results
+    /// fetched when lower).
+    pub ef_search: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NamedIndexInfo {
+    pub name: String,
+    /// Points in the graph, deleted ones included.
+    pub point_count: u64,
+    pub deleted_points: u64,
+    pub dimension: u32,
+    pub metric: DistanceMetric,
+}
+
+pub(crate) fn index_key(name: &str) -> Result<(String, String), RagError> {
+    if name.trim().is_empty() {
+        return Err(RagError::InvalidInput(
+            "Index name must not be empty".to_string(),
+        ));
+    }
+    Ok((active_tenant(), name.to_string()))
+}
+
+fn missing(name: &str) -> RagError {
+    RagError::InvalidInput(format!("No index named '{}'", name))
+}
+
+/// Check that `points` have one non-zero dimension (`dimension` when
+/// known) and new, distinct ids; returns the dimension.
+fn check_points(
+    points: &[(i64, Vec<f32>)],
+    dimension: usize,
+    ids: &HashSet<i64>,
+) -> Result<usize, RagError> {
+    let dimension = match points.first() {
+        Some((_, e)) if dimension == 0 => e.len(),
+        _ => dimension,
+    };
+    if let Some((id, e)) = points.iter().find(|(_, e)| e.is_empty() || e.len() != dimension) {
+        return Err(RagError::InvalidInput(format!(
+            "Point {} has dimension {}, expected {}",
+            id,
+            e.len(),
+            dimension
+        )));
+    }
+    let mut seen = HashSet::new();
+    if let Some((id, _)) = points.iter().find(|(id, _)| ids.contains(id) || !seen.insert(*id)) {
+        return Err(RagError::InvalidInput(format!(
+            "Point {} is repeated or was already inserted; rebuild the index to replace it",
+            id
+        )));
+    }
+    Ok(dimension)
+}
+
+fn build_named(
+    key: (String, String),
+    points: Vec<(i64, Vec<f32>)>,
+    config: NamedIndexConfig,
+) -> Result<(), RagError> {
+    let dimension = check_points(&points, 0, &HashSet::new())?;
+    let index_config = get_index_config();
+    let metric = config.metric.unwrap_or(index_config.metric);
+    if index_config.quantization == Quantization::Int8 && metric != DistanceMetric::Cosine {
+        return Err(RagError::InvalidInput(format!(
+            "Int8 quantization supports the cosine metric only, got {:?}",
+            metric
+        )));
+    }
+    if config.ef_search == Some(0) {
+        return Err(RagError::InvalidInput("ef_search must be at least 1".to_string()));
+    }
+    let build = match config.build {
+        Some(build) => {
+            build.validate()?;
+            build
+        }
+        None => HnswBuildConfig::adaptive(points.len()),
+    };
+    let ids: HashSet<i64> = points.iter().map(|(id, _)| *id).collect();
+    let (graph, _) = build_tenant_graph(
+        points,
+        build,
+        get_hnsw_seeded_build(),
+        index_config.quantization,
+        metric,
+        Progress::NONE,
+    )?;
+    info!(
+        "[named_index] Built '{}' for tenant {} with {} points",
+        key.1,
+        key.0,
+        ids.len()
+    );
+    NAMED_INDEXES.write().unwrap().insert(
+        key,
+        NamedIndex {
+            graph: Arc::new(graph),
+            dimension,
+            ids,
+            deleted: HashSet::new(),
+            ef_search: config.ef_search,
+        },
+    );
+    Ok(())
+}
+
+/// Build (or replace) the active tenant's index `name` from `points`,
+/// which may be empty.
+pub fn build_hnsw_index_named(
+    name: String,
+    points: Vec<(i64, Vec<f32>)>,
+    config: NamedIndexConfig,
+) -> Result<(), RagError> {
+    build_named(index_key(&name)?, points, config)
+}
+
+fn search_named(
+    key: &(String, String),
+    query_embedding: &[f32],
+    top_k: usize,
+    ef_search: Option<u32>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    let (graph, deleted, ef_search) = {
+        let guard = NAMED_INDEXES.read().unwrap();
+        let index = guard.get(key).ok_or_else(|| missing(&key.1))?;
+        if index.dimension == 0 {
+            return Ok(Vec::new());
+        }
+        if query_embedding.len() != index.dimension {
+            return Err(RagError::InvalidInput(format!(
+                "Query dimension {} does not match index '{}' ({})",
+                query_embedding.len(),
+                key.1,
+                index.dimension
+            )));
+        }
+        (index.graph.clone(), index.deleted.clone(), ef_search.or(index.ef_search))
+    };
+    // Deleted points still in the graph are over-fetched, then dropped.
+    let fetch_k = top_k + deleted.len();
+    let ef_search = match ef_search {
+        Some(ef) => (ef as usize).max(fetch_k),
+        None => core::cmp::max(100, fetch_k * 5),
+    };
+    Ok(graph
+        .search(query_embedding, fetch_k, ef_search, None)
+        .into_iter()
+        .map(|n| HnswSearchResult {
+            id: n.d_id as i64,
+            distance: n.distance,
+        })
+        .filter(|r| !deleted.contains(&r.id))
+        .take(top_k)
+        .collect())
+}
+
+/// Search the active tenant's index `name`, nearest first.
+pub fn search_hnsw_named(
+    name: String,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    search_named(&index_key(&name)?, &query_embedding, top_k, None)
+}
+
+/// `search_hnsw_named` with an `ef_search` override for this call.
+pub(crate) fn search_hnsw_named_with_ef(
+    name: &str,
+    query_embedding: &[f32],
+    top_k: usize,
+    ef_search: Option<u32>,
+) -> Result<Vec<HnswSearchResult>, RagError> {
+    search_named(&index_key(name)?, query_embedding, top_k, ef_search)
+}
+
+fn insert_named(key: &(String, String), points: Vec<(i64, Vec<f32>)>) -> Result<(), RagError> {
+    let graph = {
+        let mut guard = NAMED_INDEXES.write().unwrap();
+        let index = guard.get_mut(key).ok_or_else(|| missing(&key.1))?;
+        index.dimension = check_points(&points, index.dimension, &index.ids)?;
+        index.ids.extend(points.iter().map(|(id, _)| *id));
+        index.graph.clone()
+    };
+    // Insertion takes the graph's own locks, so the map's isn't held.
+    catch_panic("named index insert", || {
+        for (id, embedding) in &points {
+            graph.insert(embedding, *id);
+        }
+    })
+}
+
+/// Add points to the existing index `name` without rebuilding it. Ids
+/// must be new to the index, deleted ones included.
+pub fn insert_hnsw_named(name: String, points: Vec<(i64, Vec<f32>)>) -> Result<(), RagError> {
+    insert_named(&index_key(&name)?, points)
+}
+
+fn delete_from(index: &mut NamedIndex, ids: &[i64]) -> usize {
+    let NamedIndex { ids: inserted, deleted, .. } = index;
+    ids.iter()
+        .filter(|id| inserted.contains(id) && deleted.insert(**id))
+        .count()
+}
+
+/// Hide `ids` from searches of the index `name` until it is rebuilt.
+/// Returns how many were newly deleted; unknown ids are ignored.
+pub fn delete_hnsw_named(name: String, ids: Vec<i64>) -> Result<u32, RagError> {
+    let key = index_key(&name)?;
+    let mut guard = NAMED_INDEXES.write().unwrap();
+    let index = guard.get_mut(&key).ok_or_else(|| missing(&name))?;
+    Ok(delete_from(index, &ids) as u32)
+}
+
+/// Hide deleted chunks from all of `tenant_id`'s named indexes.
+pub(crate) fn delete_named_chunks(tenant_id: &str, chunk_ids: &[i64]) {
+    if chunk_ids.is_empty() {
+        return;
+    }
+    for ((tenant, name), index) in NAMED_INDEXES.write().unwrap().iter_mut() {
+        if tenant == tenant_id {
+            let count = delete_from(index, chunk_ids);
+            if count > 0 {
+                debug!("[named_index] Deleted {} chunks from '{}' of tenant {}", count, name, tenant);
+            }
+        }
+    }
+}
+
+/// Drop the active tenant's index `name`; false if it did not exist.
+#[flutter_rust_bridge::frb(sync)]
+pub fn drop_hnsw_index_named(name: String) -> bool {
+    NAMED_INDEXES
+        .write()
+        .unwrap()
+        .remove(&(active_tenant(), name))
+        .is_some()
+}
+
+/// The active tenant's named indexes, sorted by name.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_hnsw_indexes_named() -> Vec<NamedIndexInfo> {
+    let tenant_id = active_tenant();
+    let mut infos: Vec<NamedIndexInfo> = NAMED_INDEXES
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|((tenant, _), _)| *tenant == tenant_id)
+        .map(|((_, name), index)| NamedIndexInfo {
+            name: name.clone(),
+            point_count: index.graph.get_nb_point() as u64,
+            deleted_points: index.deleted.len() as u64,
+            dimension: index.dimension as u32,
+            metric: index.graph.metric(),
+        })
+        .collect();
+    infos.sort_by(|a, b| a.name.cmp(&b.name));
+    infos
+}
+
+pub(crate) fn drop_tenant_named_indexes(tenant_id: &str) {
+    NAMED_INDEXES
+        .write()
+        .unwrap()
+        .retain(|(tenant, _), _| tenant != tenant_id);
+}
+
+pub(crate) fn clear_all_named_indexes() {
+    NAMED_INDEXES.write().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(hits: &[HnswSearchResult]) -> Vec<i64> {
+        hits.iter().map(|h| h.id).collect()
+    }
+
+    /// One layer: hnsw_rs can miss points of tiny layered graphs.
+    fn single_layer() -> NamedIndexConfig {
+        NamedIndexConfig {
+            build: Some(HnswBuildConfig { m: 16, max_layer: 1, ef_construction: 100 }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_named_indexes_are_separate() {
+        let tenant = "named_index_test";
+        let key = |name: &str| (tenant.to_string(), name.to_string());
+        let work = vec![(1, vec![1.0, 0.0]), (2, vec![0.9, 0.1])];
+        let notes = vec![(10, vec![0.0, 1.0, 0.0])];
+        build_named(key("work"), work, single_layer()).unwrap();
+        build_named(key("notes"), notes, single_layer()).unwrap();
+
+        let hits = search_named(&key("work"), &[1.0, 0.0], 5, None).unwrap();
+        assert_eq!(ids(&hits), vec![1, 2]);
+        assert_eq!(
+            search_named(&key("notes"), &[0.0, 1.0, 0.0], 5, None).unwrap()[0].id,
+            10
+        );
+        assert!(search_named(&key("notes"), &[1.0, 0.0], 5, None).is_err());
+        assert!(search_named(&key("missing"), &[1.0, 0.0], 5, None).is_err());
+        let bad = vec![(1, vec![1.0]), (2, vec![1.0, 0.0])];
+        assert!(build_named(key("bad"), bad, NamedIndexConfig::default()).is_err());
+
+        drop_tenant_named_indexes(tenant);
+        assert!(search_named(&key("work"), &[1.0, 0.0], 5, None).is_err());
+    }
+
+    #[test]
+    fn test_named_index_starts_empty_and_hides_deleted_chunks() {
+        let tenant = "named_index_delete_test";
+        let key = (tenant.to_string(), "docs".to_string());
+        let config = NamedIndexConfig {
+            metric: Some(DistanceMetric::L2),
+            ef_search: Some(10),
+            ..single_layer()
+        };
+        build_named(key.clone(), Vec::new(), config).unwrap();
+        assert!(search_named(&key, &[1.0, 0.0], 5, None).unwrap().is_empty());
+
+        let points = vec![(1, vec![1.0, 0.0]), (2, vec![0.9, 0.1]), (3, vec![0.0, 1.0])];
+        insert_named(&key, points).unwrap();
+        assert_eq!(ids(&search_named(&key, &[1.0, 0.0], 2, None).unwrap()), vec![1, 2]);
+        assert!(insert_named(&key, vec![(4, vec![1.0, 0.0, 0.0])]).is_err());
+
+        delete_named_chunks(tenant, &[1, 99]);
+        delete_named_chunks("other_tenant", &[2]);
+        assert_eq!(ids(&search_named(&key, &[1.0, 0.0], 2, None).unwrap()), vec![2, 3]);
+        // A reused id would bring back the deleted point.
+        assert!(insert_named(&key, vec![(1, vec![0.5, 0.5])]).is_err());
+        assert!(insert_named(&key, vec![(5, vec![0.5, 0.5]), (5, vec![0.5, 0.5])]).is_err());
+
+        {
+            let indexes = NAMED_INDEXES.read().unwrap();
+            let index = &indexes[&key];
+            assert_eq!((index.dimension, index.deleted.len()), (2, 1));
+            assert_eq!(index.graph.metric(), DistanceMetric::L2);
+        }
+        drop_tenant_named_indexes(tenant);
+    }
+}
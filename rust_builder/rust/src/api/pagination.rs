@@ -0,0 +1,133 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Cursors for paged search results.
+//!
+//! A paged search keeps its ranking in a small in-memory cache and hands
+//! out opaque cursors (`"<ranking id>:<offset>"`), so "load more" slices
+//! the cached ranking instead of re-running the pipeline. When a page
+//! runs past the cached depth the search is re-run deeper; results
+//! already delivered keep their positions and are not repeated.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::api::error::RagError;
+
+/// Rankings kept at once; the oldest is dropped beyond this.
+const MAX_CACHED_RANKINGS: usize = 16;
+
+/// Pages ranked ahead whenever a paged search runs.
+pub(crate) const PAGES_PER_RANKING: usize = 5;
+
+pub(crate) fn encode_cursor(ranking_id: u64, offset: usize) -> String {
+    format!("{}:{}", ranking_id, offset)
+}
+
+pub(crate) fn decode_cursor(cursor: &str) -> Result<(u64, usize), RagError> {
+    let invalid = || RagError::InvalidInput(format!("Invalid search cursor '{}'", cursor));
+    let (id, offset) = cursor.split_once(':').ok_or_else(invalid)?;
+    Ok((
+        id.parse().map_err(|_| invalid())?,
+        offset.parse().map_err(|_| invalid())?,
+    ))
+}
+
+/// Cached rankings by id, each tagged with the tenant that made it.
+pub(crate) struct RankingCache<T> {
+    entries: HashMap<u64, (String, T)>,
+    next_id: u64,
+}
+
+impl<T: Clone> RankingCache<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Store a ranking and return its id, evicting the oldest if full.
+    pub(crate) fn insert(&mut self, tenant_id: String, ranking: T) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.insert(id, (tenant_id, ranking));
+        while self.entries.len() > MAX_CACHED_RANKINGS {
+            if let Some(oldest) = self.entries.keys().min().copied() {
+                self.entries.remove(&oldest);
+            }
+        }
+        id
+    }
+
+    /// The ranking `id` of `tenant_id`; expired and foreign ids are errors.
+    pub(crate) fn get(&self, id: u64, tenant_id: &str) -> Result<T, RagError> {
+        match self.entries.get(&id) {
+            Some((owner, ranking)) if owner == tenant_id => Ok(ranking.clone()),
+            _ => Err(RagError::InvalidInput(
+                "Search cursor has expired; run the search again".to_string(),
+            )),
+        }
+    }
+
+    /// Replace ranking `id` after it was deepened; a no-op if evicted.
+    pub(crate) fn update(&mut self, id: u64, ranking: T) {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.1 = ranking;
+        }
+    }
+}
+
+/// The first `delivered` items of `current` followed by the items of the
+/// `deeper` ranking that are not among them.
+pub(crate) fn merge_deeper<T: Clone>(
+    current: &[T],
+    delivered: usize,
+    deeper: Vec<T>,
+    id_of: impl Fn(&T) -> i64,
+) -> Vec<T> {
+    let prefix = &current[..delivered.min(current.len())];
+    let seen: HashSet<i64> = prefix.iter().map(&id_of).collect();
+    prefix
+        .iter()
+        .cloned()
+        .chain(deeper.into_iter().filter(|item| !seen.contains(&id_of(item))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip_and_cache() {
+        assert_eq!(decode_cursor(&encode_cursor(7, 20)).unwrap(), (7, 20));
+        assert!(decode_cursor("7").is_err());
+        assert!(decode_cursor("a:1").is_err());
+
+        let mut cache = RankingCache::new();
+        let first = cache.insert("t".to_string(), vec![1i64]);
+        assert_eq!(cache.get(first, "t").unwrap(), vec![1]);
+        assert!(cache.get(first, "other").is_err());
+        for _ in 0..MAX_CACHED_RANKINGS {
+            cache.insert("t".to_string(), vec![]);
+        }
+        assert!(cache.get(first, "t").is_err());
+
+        // Delivered 1, 2 stay first; the deeper ranking fills in the rest.
+        let merged = merge_deeper(&[1i64, 2, 3], 2, vec![2, 4, 1, 3, 5], |id| *id);
+        assert_eq!(merged, vec![1, 2, 4, 3, 5]);
+    }
+}
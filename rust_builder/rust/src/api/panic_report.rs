@@ -0,0 +1,150 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Panic capture and reporting.
+//!
+//! `install_panic_hook` turns every panic into a structured `PanicEvent`:
+//! it is logged at ERROR level (so it reaches the Dart log stream), counted
+//! in the engine metrics and kept in a small ring buffer for
+//! `get_recent_panics`. `init_app` installs it on startup. Worker threads
+//! additionally run their bodies through `catch_panic`, so a panic
+//! surfaces as a `RagError` and the thread's running flags are reset
+//! instead of staying stuck.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flutter_rust_bridge::frb;
+use log::error;
+use once_cell::sync::Lazy;
+
+use crate::api::error::RagError;
+use crate::api::metrics::record_panic;
+
+/// Panics kept for `get_recent_panics`.
+const MAX_RECENT_PANICS: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct PanicEvent {
+    /// Name of the panicking thread (`<unnamed>` for anonymous threads).
+    pub thread: String,
+    pub message: String,
+    /// `file:line:column`, when known.
+    pub location: Option<String>,
+    /// Captured in debug builds only.
+    pub backtrace: Option<String>,
+    /// Unix timestamp (seconds).
+    pub timestamp: i64,
+}
+
+static HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
+static RECENT_PANICS: Lazy<RwLock<VecDeque<PanicEvent>>> = Lazy::new(|| RwLock::new(VecDeque::new()));
+
+/// Install the panic hook. Safe to call more than once; the previously
+/// installed hook still runs after the event is recorded.
+#[frb(sync)]
+pub fn install_panic_hook() {
+    if HOOK_INSTALLED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let event = PanicEvent {
+            thread: std::thread::current().name().unwrap_or("<unnamed>").to_string(),
+            message: payload_message(info.payload()),
+            location: info.location().map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            backtrace: capture_backtrace(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64),
+        };
+        report(event);
+        previous(info);
+    }));
+}
+
+/// Most recent panics, oldest first.
+#[frb(sync)]
+pub fn get_recent_panics() -> Vec<PanicEvent> {
+    RECENT_PANICS.read().map(|p| p.iter().cloned().collect()).unwrap_or_default()
+}
+
+#[frb(sync)]
+pub fn clear_recent_panics() {
+    if let Ok(mut recent) = RECENT_PANICS.write() {
+        recent.clear();
+    }
+}
+
+fn report(event: PanicEvent) {
+    record_panic();
+    error!(
+        "[panic] thread={} location={} message={}",
+        event.thread,
+        event.location.as_deref().unwrap_or("unknown"),
+        event.message
+    );
+    if let Some(backtrace) = &event.backtrace {
+        error!("[panic] backtrace:\n{}", backtrace);
+    }
+    // The hook may run while this lock is poisoned by an earlier panic.
+    let mut recent = RECENT_PANICS.write().unwrap_or_else(|e| e.into_inner());
+    if recent.len() == MAX_RECENT_PANICS {
+        recent.pop_front();
+    }
+    recent.push_back(event);
+}
+
+#[cfg(debug_assertions)]
+fn capture_backtrace() -> Option<String> {
+    Some(std::backtrace::Backtrace::force_capture().to_string())
+}
+
+#[cfg(not(debug_assertions))]
+fn capture_backtrace() -> Option<String> {
+    None
+}
+
+/// Text of a panic payload (`panic!` with a literal or a formatted message).
+pub(crate) fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+/// Run `f`, turning a panic into `RagError::InternalError`.
+pub(crate) fn catch_panic<T>(context: &str, f: impl FnOnce() -> T) -> Result<T, RagError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| RagError::InternalError(format!("{} panicked: {}", context, payload_message(&*payload))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_panic_converts_to_error() {
+        assert_eq!(catch_panic("ok", || 7).unwrap(), 7);
+        let err = catch_panic("index build", || -> i32 { panic!("bad dimension {}", 3) }).unwrap_err();
+        assert!(matches!(err, RagError::InternalError(ref m) if m == "index build panicked: bad dimension 3"));
+    }
+}
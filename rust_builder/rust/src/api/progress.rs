@@ -0,0 +1,149 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Progress events for index rebuilds and bulk chunk insertion.
+//!
+//! Dart subscribes with `init_progress_stream`, as it does for logs with
+//! `init_log_stream`. The HNSW and BM25 rebuilds and `add_chunks` create
+//! a `Progress` for their operation and hand it to the code doing the
+//! work (graph inserts, BM25 indexing, live index inserts), which reports
+//! per item. The handle is `Copy` and `Send`, so work moved to rayon or a
+//! pool thread reports through the handle it was given. Events are only
+//! sent with a subscriber, and at most about one per percent of each
+//! phase. Nothing is reported from inside a write transaction: a retried
+//! write would report its rows twice.
+//!
+//! A handle may also carry a pause hook that runs before every reported
+//! item; background maintenance uses it to hold a rebuild while searches
+//! are in flight.
+
+use std::sync::RwLock;
+
+use crate::frb_generated::StreamSink;
+
+/// Operations that report progress.
+pub const OP_HNSW_REBUILD: &str = "hnsw_rebuild";
+pub const OP_BM25_REBUILD: &str = "bm25_rebuild";
+pub const OP_ADD_CHUNKS: &str = "add_chunks";
+
+/// Phases, in the order an operation goes through them.
+/// `insert` is reported once, after the rows are committed.
+pub const PHASE_INSERT: &str = "insert";
+pub const PHASE_BUILD: &str = "build";
+pub const PHASE_INDEX: &str = "index";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressEvent {
+    /// `hnsw_rebuild`, `bm25_rebuild` or `add_chunks` (the `OP_*` values).
+    pub operation: String,
+    /// `insert`, `build` or `index` (the `PHASE_*` values).
+    pub phase: String,
+    /// Items of this phase processed so far.
+    pub done: u32,
+    pub total: u32,
+}
+
+static PROGRESS_SINK: RwLock<Option<StreamSink<ProgressEvent>>> = RwLock::new(None);
+
+/// Start receiving progress events.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_progress_stream(sink: StreamSink<ProgressEvent>) {
+    *PROGRESS_SINK.write().unwrap() = Some(sink);
+}
+
+/// Stop sending progress events.
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_progress_stream() {
+    *PROGRESS_SINK.write().unwrap() = None;
+}
+
+/// Reports the progress of one operation, from any thread.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Progress {
+    operation: Option<&'static str>,
+    pause: Option<fn()>,
+}
+
+impl Progress {
+    /// Reports nothing, for callers outside a tracked operation.
+    pub(crate) const NONE: Progress = Progress { operation: None, pause: None };
+
+    pub(crate) fn new(operation: &'static str) -> Self {
+        Progress { operation: Some(operation), pause: None }
+    }
+
+    /// Run `pause` before each item; it may block to hold the work.
+    pub(crate) fn with_pause(self, pause: fn()) -> Self {
+        Progress { pause: Some(pause), ..self }
+    }
+
+    /// Report `done` of `total` items of `phase`.
+    pub(crate) fn report(self, phase: &str, done: usize, total: usize) {
+        if let Some(pause) = self.pause {
+            pause();
+        }
+        let Some(operation) = self.operation else {
+            return;
+        };
+        let (done, total) = (done.min(u32::MAX as usize) as u32, total.min(u32::MAX as usize) as u32);
+        if !is_milestone(done, total) {
+            return;
+        }
+        if let Some(sink) = &*PROGRESS_SINK.read().unwrap() {
+            let _ = sink.add(ProgressEvent {
+                operation: operation.to_string(),
+                phase: phase.to_string(),
+                done,
+                total,
+            });
+        }
+    }
+}
+
+/// Whether `done` of `total` is worth an event: the first and last item
+/// and every percent in between.
+fn is_milestone(done: u32, total: u32) -> bool {
+    done == 0 || done >= total || done.is_multiple_of((total / 100).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_milestones_thin_events() {
+        let sent = (0..=1000).filter(|&done| is_milestone(done, 1000)).count();
+        assert_eq!(sent, 101);
+        assert!((0..=7).all(|done| is_milestone(done, 7)));
+
+        fn assert_send<T: Send + Sync>(_: T) {}
+        assert_send(Progress::new(OP_HNSW_REBUILD));
+    }
+
+    #[test]
+    fn test_pause_hook_runs_per_item() {
+        static PAUSES: AtomicUsize = AtomicUsize::new(0);
+        fn count_pause() {
+            PAUSES.fetch_add(1, Ordering::Relaxed);
+        }
+        let progress = Progress::NONE.with_pause(count_pause);
+        for i in 0..3 {
+            progress.report(PHASE_BUILD, i, 3);
+        }
+        assert_eq!(PAUSES.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Int8 scalar-quantized flat index used in low-memory mode.
+//!
+//! Each embedding is stored as `i8` values plus one `f32` scale and its
+//! norm, about a quarter of the memory of the f32 HNSW graph and with no
+//! neighbour lists. Search is an exact (parallel) scan, so recall does not
+//! depend on graph parameters; it trades some latency on large corpora for
+//! a much smaller footprint. `hnsw_index` routes to this index when
+//! `memory_budget` asks for it. Distances follow the index's
+//! `DistanceMetric`, fixed when it is built.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+use log::info;
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+
+use crate::api::embedding_storage::quantize_values;
+use crate::api::hnsw_index::{DistanceMetric, HnswSearchResult};
+use crate::api::progress::{Progress, PHASE_BUILD};
+use crate::api::vector_ops::norm;
+
+struct QuantizedVector {
+    id: i64,
+    /// Multiply an `i8` value by this to get the normalized component back.
+    scale: f32,
+    /// Norm of the original embedding.
+    norm: f32,
+    values: Vec<i8>,
+}
+
+struct QuantizedIndex {
+    dimension: usize,
+    metric: DistanceMetric,
+    vectors: Vec<QuantizedVector>,
+}
+
+impl QuantizedIndex {
+    fn size_bytes(&self) -> usize {
+        self.vectors.len() * (self.dimension + std::mem::size_of::<QuantizedVector>())
+    }
+}
+
+static QUANTIZED_INDEX: Lazy<RwLock<HashMap<String, QuantizedIndex>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn quantize(id: i64, embedding: &[f32]) -> QuantizedVector {
+    let (scale, values) = quantize_values(embedding);
+    QuantizedVector { id, scale, norm: norm(embedding), values }
+}
+
+/// Replace `tenant_id`'s quantized index. Points whose dimension differs
+/// from the first point are skipped.
+pub(crate) fn build_quantized_index(
+    tenant_id: &str,
+    points: Vec<(i64, Vec<f32>)>,
+    metric: DistanceMetric,
+    progress: Progress,
+) {
+    let dimension = points.first().map_or(0, |(_, e)| e.len());
+    let count = points.len();
+    let done = AtomicUsize::new(0);
+    progress.report(PHASE_BUILD, 0, count);
+    let vectors: Vec<QuantizedVector> = points
+        .par_iter()
+        .inspect(|_| progress.report(PHASE_BUILD, done.fetch_add(1, Ordering::Relaxed) + 1, count))
+        .filter(|(_, e)| e.len() == dimension)
+        .map(|(id, e)| quantize(*id, e))
+        .collect();
+    info!("[quantized] Built {:?} index for tenant {} with {} points", metric, tenant_id, vectors.len());
+    QUANTIZED_INDEX
+        .write()
+        .unwrap()
+        .insert(tenant_id.to_string(), QuantizedIndex { dimension, metric, vectors });
+}
+
+/// Search by the index's metric (among `allowed` ids when given), or
+/// `None` when the tenant has no quantized index.
+pub(crate) fn search_quantized(
+    tenant_id: &str,
+    query: &[f32],
+    top_k: usize,
+    allowed: Option<&HashSet<i64>>,
+) -> Option<Vec<HnswSearchResult>> {
+    let guard = QUANTIZED_INDEX.read().unwrap();
+    let index = guard.get(tenant_id)?;
+    if query.len() != index.dimension || top_k == 0 {
+        return Some(Vec::new());
+    }
+    let query_norm = norm(query);
+    let mut scored: Vec<(f32, i64)> = index
+        .vectors
+        .par_iter()
+        .filter(|v| allowed.is_none_or(|ids| ids.contains(&v.id)))
+        .map(|v| {
+            let dot: f32 = v.values.iter().zip(query).map(|(&q, &x)| q as f32 * x).sum();
+            (index.metric.distance(dot * v.scale, query_norm, v.norm), v.id)
+        })
+        .collect();
+    let by_distance = |a: &(f32, i64), b: &(f32, i64)| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1));
+    if scored.len() > top_k {
+        scored.select_nth_unstable_by(top_k - 1, by_distance);
+        scored.truncate(top_k);
+    }
+    scored.sort_by(by_distance);
+    Some(scored.into_iter().map(|(distance, id)| HnswSearchResult { id, distance }).collect())
+}
+
+/// Append points to `tenant_id`'s quantized index. Returns how many were
+/// added, or `None` when the tenant has no quantized index or a point's
+/// dimension differs from the index's; nothing is inserted then, so the
+/// caller marks the index for a rebuild instead of losing points.
+pub(crate) fn insert_quantized(tenant_id: &str, points: &[(i64, Vec<f32>)]) -> Option<usize> {
+    let mut guard = QUANTIZED_INDEX.write().unwrap();
+    let index = guard.get_mut(tenant_id)?;
+    if index.vectors.is_empty() {
+        if let Some((_, first)) = points.first() {
+            index.dimension = first.len();
+        }
+    }
+    let dimension = index.dimension;
+    if let Some((id, embedding)) = points.iter().find(|(_, e)| e.len() != dimension) {
+        info!(
+            "[quantized] Point {} has dimension {}, index of tenant {} has {}",
+            id,
+            embedding.len(),
+            tenant_id,
+            dimension
+        );
+        return None;
+    }
+    index.vectors.extend(points.iter().map(|(id, e)| quantize(*id, e)));
+    Some(points.len())
+}
+
+pub(crate) fn has_quantized_index(tenant_id: &str) -> bool {
+    QUANTIZED_INDEX.read().unwrap().contains_key(tenant_id)
+}
+
+pub(crate) fn quantized_point_count(tenant_id: &str) -> Option<usize> {
+    QUANTIZED_INDEX.read().unwrap().get(tenant_id).map(|index| index.vectors.len())
+}
+
+pub(crate) fn quantized_ids(tenant_id: &str) -> Option<Vec<i64>> {
+    QUANTIZED_INDEX.read().unwrap().get(tenant_id).map(|index| index.vectors.iter().map(|v| v.id).collect())
+}
+
+/// Approximate memory held by all tenants' quantized indexes.
+pub(crate) fn quantized_index_bytes() -> usize {
+    QUANTIZED_INDEX.read().unwrap().values().map(QuantizedIndex::size_bytes).sum()
+}
+
+pub(crate) fn drop_quantized_index(tenant_id: &str) {
+    QUANTIZED_INDEX.write().unwrap().remove(tenant_id);
+}
+
+pub(crate) fn clear_all_quantized_indexes() {
+    QUANTIZED_INDEX.write().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantized_search_ranks_like_cosine() {
+        let tenant = "quantized_test_tenant";
+        let points: Vec<(i64, Vec<f32>)> = (0..50)
+            .map(|i| {
+                let angle = i as f32 * 0.05;
+                (i, vec![angle.cos() * 3.0, angle.sin() * 3.0, 0.0])
+            })
+            .collect();
+        build_quantized_index(tenant, points, DistanceMetric::Cosine, Progress::NONE);
+        assert_eq!(quantized_point_count(tenant), Some(50));
+
+        let results = search_quantized(tenant, &[1.0, 0.0, 0.0], 3, None).unwrap();
+        let ids: Vec<i64> = results.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert!(results[0].distance.abs() < 0.01);
+
+        assert!(search_quantized(tenant, &[1.0, 0.0], 3, None).unwrap().is_empty());
+        drop_quantized_index(tenant);
+        assert!(search_quantized(tenant, &[1.0, 0.0, 0.0], 3, None).is_none());
+    }
+
+    #[test]
+    fn test_quantized_search_by_dot_product_and_l2() {
+        let tenant = "quantized_metric_test_tenant";
+        let points = vec![(1, vec![1.0, 0.0]), (2, vec![4.0, 1.0]), (3, vec![-2.0, 0.0])];
+        build_quantized_index(tenant, points.clone(), DistanceMetric::DotProduct, Progress::NONE);
+        let ids: Vec<i64> = search_quantized(tenant, &[1.0, 0.0], 3, None).unwrap().iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![2, 1, 3]);
+
+        build_quantized_index(tenant, points, DistanceMetric::L2, Progress::NONE);
+        let results = search_quantized(tenant, &[1.0, 0.0], 3, None).unwrap();
+        assert_eq!(results.iter().map(|r| r.id).collect::<Vec<_>>(), vec![1, 3, 2]);
+        assert!(results[0].distance.abs() < 0.01);
+        assert!((results[1].distance - 3.0).abs() < 0.05);
+        drop_quantized_index(tenant);
+    }
+
+    #[test]
+    fn test_insert_with_other_dimension_inserts_nothing() {
+        let tenant = "quantized_insert_test_tenant";
+        build_quantized_index(tenant, vec![(1, vec![1.0, 0.0])], DistanceMetric::Cosine, Progress::NONE);
+        assert_eq!(insert_quantized(tenant, &[(2, vec![0.0, 1.0]), (3, vec![1.0, 0.0, 0.0])]), None);
+        assert_eq!(quantized_point_count(tenant), Some(1));
+        assert_eq!(insert_quantized(tenant, &[(2, vec![0.0, 1.0])]), Some(1));
+        assert_eq!(quantized_point_count(tenant), Some(2));
+        drop_quantized_index(tenant);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Rule-based decomposition of multi-part questions.
+//!
+//! "What is X and how does it differ from Y?" becomes
+//! ["What is X", "How does X differ from Y"], so each part can be
+//! retrieved separately (multi-query retrieval) and the results merged.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Upper bound on sub-queries; anything beyond is kept in the last part.
+const MAX_SUB_QUERIES: usize = 4;
+
+/// Question boundaries: sentence-ending question marks, semicolons, and
+/// conjunctions that are immediately followed by a new question word.
+static SPLIT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)[?？]\s*|;\s*|,?\s+(?:and|also|then|그리고|또한)\s+(?P<next>what|how|why|when|where|which|who|whom|whose|is|are|does|do|did|can|could|should|would|will)\b",
+    )
+    .unwrap()
+});
+
+/// Subject of a definitional question ("what is X", "what are X", "define X").
+static SUBJECT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:what\s+(?:is|are)|define|explain)\s+(?:an?\s+|the\s+)?(?P<subject>.+)$").unwrap()
+});
+
+static PRONOUN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:it|they|them)\b").unwrap());
+
+#[derive(Debug, Clone)]
+pub struct QueryDecomposition {
+    pub original: String,
+    /// Sub-queries in question order; just `[original]` when nothing was split.
+    pub sub_queries: Vec<String>,
+}
+
+impl QueryDecomposition {
+    pub fn is_decomposed(&self) -> bool {
+        self.sub_queries.len() > 1
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn split_parts(query: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for caps in SPLIT_PATTERN.captures_iter(query) {
+        let whole = caps.get(0).unwrap();
+        parts.push(query[start..whole.start()].to_string());
+        // Keep the question word that started the next part.
+        start = caps.name("next").map_or(whole.end(), |m| m.start());
+    }
+    parts.push(query[start..].to_string());
+
+    parts
+        .into_iter()
+        .map(|p| p.trim().trim_end_matches(['.', ',', '?', '？']).trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Split a multi-part question into ordered sub-queries.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decompose_query(query: String) -> QueryDecomposition {
+    let original = query.trim().to_string();
+    let mut parts = split_parts(&original);
+
+    if parts.len() <= 1 {
+        return QueryDecomposition { sub_queries: vec![original.clone()], original };
+    }
+
+    if parts.len() > MAX_SUB_QUERIES {
+        let tail = parts.split_off(MAX_SUB_QUERIES - 1).join(" ");
+        parts.push(tail);
+    }
+
+    // Resolve "it"/"they" in follow-up parts to the first part's subject,
+    // otherwise the follow-up retrieves nothing useful on its own.
+    let subject = SUBJECT_PATTERN
+        .captures(&parts[0])
+        .and_then(|c| c.name("subject"))
+        .map(|m| m.as_str().to_string());
+    let sub_queries = parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| match (&subject, i) {
+            (Some(subject), i) if i > 0 => PRONOUN_PATTERN.replace_all(part, subject.as_str()).into_owned(),
+            _ => part.clone(),
+        })
+        .map(|part| capitalize_first(&part))
+        .collect();
+
+    QueryDecomposition { original, sub_queries }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_conjunction_with_pronoun() {
+        let result = decompose_query("what is RWA and how does it differ from DeFi?".to_string());
+        assert_eq!(result.sub_queries, vec!["What is RWA", "How does RWA differ from DeFi"]);
+        assert_eq!(result.original, "what is RWA and how does it differ from DeFi?");
+    }
+
+    #[test]
+    fn test_decompose_keeps_single_question() {
+        let result = decompose_query("Compare apples and oranges".to_string());
+        assert!(!result.is_decomposed());
+        assert_eq!(result.sub_queries, vec!["Compare apples and oranges"]);
+
+        let multi = decompose_query("Who founded it? When was it listed?".to_string());
+        assert_eq!(multi.sub_queries.len(), 2);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Saved query history and on-device search suggestions.
+//!
+//! Queries are stored per tenant with their result counts so the search
+//! box can autocomplete from frequent/recent queries that actually found
+//! something.
+
+use rusqlite::{params, Connection};
+use log::{debug, info};
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::query_normalize::normalize_query_text;
+use crate::api::tenant::active_tenant;
+
+/// Number of suggestions returned by `get_query_suggestions`.
+const MAX_SUGGESTIONS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct QuerySuggestion {
+    /// Most recent spelling of the query as the user typed it.
+    pub query: String,
+    pub use_count: u32,
+    /// Unix timestamp (seconds) of the last use.
+    pub last_used_at: i64,
+}
+
+/// Create the `queries` table (called from `init_source_db`).
+pub(crate) fn create_query_history_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS queries (
+            id INTEGER PRIMARY KEY,
+            query_text TEXT NOT NULL,
+            normalized TEXT NOT NULL,
+            result_count INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER DEFAULT (strftime('%s', 'now')),
+            tenant_id TEXT NOT NULL DEFAULT 'default'
+        )",
+        [],
+    )?;
+    // Migration: queries stored before tenants belong to the default tenant
+    if conn.prepare("SELECT tenant_id FROM queries LIMIT 1").is_err() {
+        info!("[query_history] Migrating: adding tenant_id column to queries");
+        conn.execute("ALTER TABLE queries ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default'", [])?;
+    }
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_queries_normalized ON queries(normalized)", [])?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_queries_tenant_normalized ON queries(tenant_id, normalized)", [])?;
+    Ok(())
+}
+
+fn insert_query(conn: &Connection, tenant_id: &str, query_text: &str, result_count: u32) -> rusqlite::Result<()> {
+    let normalized = normalize_query_text(query_text);
+    if normalized.is_empty() {
+        return Ok(());
+    }
+    conn.execute(
+        "INSERT INTO queries (query_text, normalized, result_count, tenant_id) VALUES (?1, ?2, ?3, ?4)",
+        params![query_text.trim(), normalized, result_count, tenant_id],
+    )?;
+    Ok(())
+}
+
+fn select_suggestions(
+    conn: &Connection,
+    tenant_id: &str,
+    prefix: &str,
+    limit: usize,
+) -> rusqlite::Result<Vec<QuerySuggestion>> {
+    let normalized = normalize_query_text(prefix);
+    let pattern = format!(
+        "{}%",
+        normalized.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    );
+    // Queries that never returned anything are not worth suggesting.
+    let mut stmt = conn.prepare(
+        "SELECT
+            (SELECT q2.query_text FROM queries q2
+             WHERE q2.normalized = q.normalized AND q2.tenant_id = q.tenant_id
+             ORDER BY q2.id DESC LIMIT 1),
+            COUNT(*),
+            MAX(created_at)
+         FROM queries q
+         WHERE tenant_id = ?3 AND normalized LIKE ?1 ESCAPE '\\'
+         GROUP BY normalized
+         HAVING MAX(result_count) > 0
+         ORDER BY COUNT(*) DESC, MAX(id) DESC
+         LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(params![pattern, limit as i64, tenant_id], |row| {
+        Ok(QuerySuggestion { query: row.get(0)?, use_count: row.get(1)?, last_used_at: row.get(2)? })
+    })?;
+    rows.collect()
+}
+
+/// Record an executed query and how many results it returned.
+pub fn record_query(query_text: String, result_count: u32) -> Result<(), RagError> {
+    let conn = get_connection()?;
+    insert_query(&conn, &active_tenant(), &query_text, result_count).map_err(db_error)?;
+    debug!("[query_history] Recorded query ({} results)", result_count);
+    Ok(())
+}
+
+/// Suggest previous queries starting with `prefix`, most frequent first
+/// (ties broken by recency).
+pub fn get_query_suggestions(prefix: String) -> Result<Vec<QuerySuggestion>, RagError> {
+    let conn = get_connection()?;
+    select_suggestions(&conn, &active_tenant(), &prefix, MAX_SUGGESTIONS).map_err(db_error)
+}
+
+/// Delete the active tenant's saved queries.
+pub fn clear_query_history() -> Result<(), RagError> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM queries WHERE tenant_id = ?1", params![active_tenant()]).map_err(db_error)?;
+    info!("[query_history] History cleared");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestions_rank_by_frequency() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_query_history_table(&conn).unwrap();
+        insert_query(&conn, "default", "RWA fees", 3).unwrap();
+        insert_query(&conn, "default", "rwa tokenization", 5).unwrap();
+        insert_query(&conn, "default", "RWA Tokenization?", 4).unwrap();
+        insert_query(&conn, "default", "rwa nothing", 0).unwrap();
+        insert_query(&conn, "default", "defi lending", 2).unwrap();
+
+        insert_query(&conn, "work", "rwa private deal", 1).unwrap();
+
+        let suggestions = select_suggestions(&conn, "default", "Rw", 5).unwrap();
+        let queries: Vec<&str> = suggestions.iter().map(|s| s.query.as_str()).collect();
+        assert_eq!(queries, vec!["RWA Tokenization?", "RWA fees"]);
+        assert_eq!(suggestions[0].use_count, 2);
+        let work = select_suggestions(&conn, "work", "rw", 5).unwrap();
+        assert_eq!(work.len(), 1);
+    }
+}
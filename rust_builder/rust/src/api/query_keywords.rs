@@ -0,0 +1,147 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Keyword extraction from user queries.
+//!
+//! RAKE-style candidate phrases (split at stopwords and punctuation, word
+//! score = degree / frequency) weighted by the BM25 dictionary, so terms
+//! that actually discriminate between indexed chunks rank first. Results
+//! can be used for highlighting and as boost terms for hybrid search.
+
+use std::collections::HashMap;
+use log::debug;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::analyzers::is_stopword;
+use crate::api::bm25_search::{bm25_term_stats, tokenize_for_bm25};
+
+/// Longest phrase kept as a single keyword; longer runs are split.
+const MAX_PHRASE_WORDS: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct QueryKeyword {
+    /// Lowercased keyword or phrase (words joined by a single space).
+    pub text: String,
+    pub score: f64,
+    /// Document frequency of the keyword's rarest indexed word; 0 when none
+    /// of its words are in the BM25 dictionary.
+    pub doc_freq: u32,
+    /// Whether any word of the keyword exists in the BM25 dictionary.
+    pub in_index: bool,
+}
+
+/// Split a query into RAKE candidate phrases (lists of BM25 tokens).
+fn candidate_phrases(query: &str) -> Vec<Vec<String>> {
+    let mut phrases = Vec::new();
+    for segment in query.split(|c: char| (c.is_ascii_punctuation() && c != '-') || "。、！？".contains(c)) {
+        let mut current: Vec<String> = Vec::new();
+        for word in segment.unicode_words() {
+            let lowered = word.to_lowercase();
+            let tokens = tokenize_for_bm25(&lowered);
+            if tokens.is_empty() || is_stopword(&lowered) {
+                if !current.is_empty() {
+                    phrases.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            for token in tokens {
+                if current.len() == MAX_PHRASE_WORDS {
+                    phrases.push(std::mem::take(&mut current));
+                }
+                current.push(token);
+            }
+        }
+        if !current.is_empty() {
+            phrases.push(current);
+        }
+    }
+    phrases
+}
+
+/// Extract ranked keywords (single terms and short phrases) from a query.
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_query_keywords(query: String) -> Vec<QueryKeyword> {
+    let phrases = candidate_phrases(&query);
+
+    // RAKE word scores: degree (co-occurrence within phrases) / frequency.
+    let mut freq: HashMap<&str, f64> = HashMap::new();
+    let mut degree: HashMap<&str, f64> = HashMap::new();
+    for phrase in &phrases {
+        for word in phrase {
+            *freq.entry(word.as_str()).or_default() += 1.0;
+            *degree.entry(word.as_str()).or_default() += phrase.len() as f64;
+        }
+    }
+
+    let mut keywords: Vec<QueryKeyword> = Vec::new();
+    for phrase in &phrases {
+        let text = phrase.join(" ");
+        if keywords.iter().any(|k| k.text == text) {
+            continue;
+        }
+
+        let mut score = 0.0;
+        let mut min_doc_freq: Option<usize> = None;
+        let mut in_index = false;
+        for word in phrase {
+            let rake = degree[word.as_str()] / freq[word.as_str()];
+            // Without an index every word counts equally.
+            let weight = match bm25_term_stats(word) {
+                Some((doc_freq, idf)) => {
+                    if doc_freq > 0 {
+                        in_index = true;
+                        min_doc_freq = Some(min_doc_freq.map_or(doc_freq, |m| m.min(doc_freq)));
+                    }
+                    idf
+                }
+                None => 1.0,
+            };
+            score += rake * weight;
+        }
+
+        keywords.push(QueryKeyword {
+            text,
+            score,
+            doc_freq: min_doc_freq.unwrap_or(0) as u32,
+            in_index,
+        });
+    }
+
+    keywords.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    debug!("[query_keywords] Extracted {} keywords", keywords.len());
+    keywords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidate_phrases_split_on_stopwords() {
+        let phrases = candidate_phrases("What is the settlement layer of RWA tokenization?");
+        assert_eq!(
+            phrases,
+            vec![vec!["settlement".to_string(), "layer".to_string()], vec!["rwa".to_string(), "tokenization".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_candidate_phrases_korean() {
+        let phrases = candidate_phrases("삼성전자 주가, 전망");
+        assert_eq!(phrases.len(), 2);
+        assert_eq!(phrases[0].join(" "), "삼성전자 주가");
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Query normalization applied before BM25 and embedding.
+//!
+//! The engine normalizes query text itself in `search_hybrid` and
+//! `bm25_search`; the app should embed `normalize_query(query)` so both
+//! retrieval paths see exactly the same text. Normalization is idempotent.
+
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::api::analyzers::is_stopword;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryNormalizationConfig {
+    /// NFKC: folds full-width forms, ligatures and compatibility characters.
+    pub unicode_nfkc: bool,
+    /// Replace punctuation and symbols with spaces, except `+`/`#` ending a
+    /// word so names like "C++" and "C#" survive.
+    pub strip_punctuation: bool,
+    pub lowercase: bool,
+    /// Drop stopwords of each word's language (off by default; hurts embeddings).
+    pub remove_stopwords: bool,
+}
+
+impl Default for QueryNormalizationConfig {
+    fn default() -> Self {
+        Self { unicode_nfkc: true, strip_punctuation: true, lowercase: true, remove_stopwords: false }
+    }
+}
+
+static NORMALIZATION_CONFIG: Lazy<RwLock<QueryNormalizationConfig>> =
+    Lazy::new(|| RwLock::new(QueryNormalizationConfig::default()));
+
+/// `+` and `#` are kept when they follow a letter or digit (or another kept
+/// symbol), so "C++" stays "C++" while "a + b" loses the operator.
+fn strip_punctuation(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_word = false;
+    for c in text.chars() {
+        let keep = c.is_alphanumeric() || c.is_whitespace() || (in_word && matches!(c, '+' | '#'));
+        in_word = keep && !c.is_whitespace();
+        out.push(if keep { c } else { ' ' });
+    }
+    out
+}
+
+/// Normalize with an explicit config. Whitespace is always collapsed.
+pub(crate) fn normalize_query_with(query: &str, config: &QueryNormalizationConfig) -> String {
+    let mut text: String = if config.unicode_nfkc { query.nfkc().collect() } else { query.to_string() };
+    if config.lowercase {
+        text = text.to_lowercase();
+    }
+    if config.strip_punctuation {
+        text = strip_punctuation(&text);
+    }
+    text.split_whitespace()
+        .filter(|w| !config.remove_stopwords || !is_stopword(&w.to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Normalize with the engine's current config.
+pub(crate) fn normalize_query_text(query: &str) -> String {
+    let config = NORMALIZATION_CONFIG.read().unwrap().clone();
+    normalize_query_with(query, &config)
+}
+
+/// Normalize a query exactly as the engine does before searching.
+#[flutter_rust_bridge::frb(sync)]
+pub fn normalize_query(query: String) -> String {
+    normalize_query_text(&query)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_query_normalization_config(config: QueryNormalizationConfig) {
+    *NORMALIZATION_CONFIG.write().unwrap() = config;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_query_normalization_config() -> QueryNormalizationConfig {
+    NORMALIZATION_CONFIG.read().unwrap().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_default() {
+        let config = QueryNormalizationConfig::default();
+        assert_eq!(normalize_query_with("  Ｗhat's   the ＲＷＡ  fee?! ", &config), "what s the rwa fee");
+        assert_eq!(normalize_query_with("스마트 계약이란?", &config), "스마트 계약이란");
+        assert_eq!(normalize_query_with("C++ vs C#, a + b", &config), "c++ vs c# a b");
+        assert_eq!(normalize_query_with("c++ vs c# a b", &config), "c++ vs c# a b");
+    }
+
+    #[test]
+    fn test_normalize_is_idempotent_and_configurable() {
+        let config = QueryNormalizationConfig { remove_stopwords: true, ..Default::default() };
+        let once = normalize_query_with("What is the RWA settlement layer?", &config);
+        assert_eq!(once, "rwa settlement layer");
+        assert_eq!(normalize_query_with(&once, &config), once);
+
+        let raw = QueryNormalizationConfig { unicode_nfkc: false, strip_punctuation: false, lowercase: false, remove_stopwords: false };
+        assert_eq!(normalize_query_with("C++  Guide", &raw), "C++ Guide");
+    }
+}
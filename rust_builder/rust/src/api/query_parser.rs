@@ -0,0 +1,216 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Boolean keyword query syntax.
+//!
+//! Supported forms, which can be mixed:
+//! - `+term` / `-term`: the term is required / excluded. `-` is only an
+//!   operator before a letter or a quote, so `-5`, `--verbose` and `-`
+//!   stay search terms.
+//! - `a AND b`: both required; `a OR b`: both optional (the default).
+//! - `NOT term`: excluded.
+//! - `"two words"`: a phrase clause; a prefix or operator applies to
+//!   every word in it.
+//!
+//! Operators must be upper case, so the words "and", "or" and "not" in a
+//! normal query stay search terms. A query without any of this syntax
+//! parses to optional terms only and searches exactly as before.
+
+use crate::api::query_normalize::normalize_query_text;
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct BooleanQuery {
+    /// Scored but not required.
+    pub should: Vec<String>,
+    /// Every word must appear.
+    pub must: Vec<String>,
+    /// No word may appear.
+    pub must_not: Vec<String>,
+}
+
+impl BooleanQuery {
+    /// Whether any clause is required or excluded.
+    pub fn has_operators(&self) -> bool {
+        !self.must.is_empty() || !self.must_not.is_empty()
+    }
+
+    /// The clauses that contribute to scoring, as plain text.
+    pub fn scoring_text(&self) -> String {
+        self.should
+            .iter()
+            .chain(&self.must)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Lexeme {
+    And,
+    Or,
+    Not,
+    Term(Option<char>, String),
+}
+
+fn lex(query: &str) -> Vec<Lexeme> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut lexemes = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let prefix = match chars[i] {
+            '+' if chars.get(i + 1).is_some_and(|c| !c.is_whitespace()) => {
+                i += 1;
+                Some('+')
+            }
+            '-' if chars.get(i + 1).is_some_and(|&c| c.is_alphabetic() || c == '"') => {
+                i += 1;
+                Some('-')
+            }
+            _ => None,
+        };
+        let text: String = if chars[i] == '"' {
+            let start = i + 1;
+            let end = chars[start..]
+                .iter()
+                .position(|&c| c == '"')
+                .map_or(chars.len(), |p| start + p);
+            i = end + 1;
+            chars[start..end].iter().collect()
+        } else {
+            let start = i;
+            while i < chars.len() && !chars[i].is_whitespace() {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match (prefix, word.as_str()) {
+                (None, "AND") => {
+                    lexemes.push(Lexeme::And);
+                    continue;
+                }
+                (None, "OR") => {
+                    lexemes.push(Lexeme::Or);
+                    continue;
+                }
+                (None, "NOT") => {
+                    lexemes.push(Lexeme::Not);
+                    continue;
+                }
+                _ => word,
+            }
+        };
+        lexemes.push(Lexeme::Term(prefix, text));
+    }
+    lexemes
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Occur {
+    Should,
+    Must,
+    MustNot,
+}
+
+pub(crate) fn parse_boolean_query(query: &str) -> BooleanQuery {
+    let mut clauses: Vec<(Occur, String)> = Vec::new();
+    let mut pending_and = false;
+    let mut pending_not = false;
+    for lexeme in lex(query) {
+        match lexeme {
+            Lexeme::And => pending_and = !clauses.is_empty(),
+            Lexeme::Or => pending_and = false,
+            Lexeme::Not => pending_not = true,
+            Lexeme::Term(prefix, text) => {
+                let mut occur = match prefix {
+                    Some('+') => Occur::Must,
+                    Some('-') => Occur::MustNot,
+                    _ => Occur::Should,
+                };
+                if pending_and {
+                    if let Some(last) = clauses.last_mut().filter(|c| c.0 == Occur::Should) {
+                        last.0 = Occur::Must;
+                    }
+                    if occur == Occur::Should {
+                        occur = Occur::Must;
+                    }
+                }
+                if pending_not {
+                    occur = Occur::MustNot;
+                }
+                pending_and = false;
+                pending_not = false;
+                clauses.push((occur, text));
+            }
+        }
+    }
+
+    let mut parsed = BooleanQuery::default();
+    for (occur, text) in clauses {
+        match occur {
+            Occur::Should => parsed.should.push(text),
+            Occur::Must => parsed.must.push(text),
+            Occur::MustNot => parsed.must_not.push(text),
+        }
+    }
+    parsed
+}
+
+/// The normalized text to score and highlight with: the query itself
+/// when it has no operators, otherwise its non-excluded clauses.
+pub(crate) fn scoring_query_text(query: &str) -> String {
+    let parsed = parse_boolean_query(query);
+    if parsed.has_operators() {
+        normalize_query_text(&parsed.scoring_text())
+    } else {
+        normalize_query_text(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_boolean_query() {
+        let plain = parse_boolean_query("error handling and retries");
+        assert!(!plain.has_operators());
+        assert_eq!(plain.should, words(&["error", "handling", "and", "retries"]));
+
+        let q = parse_boolean_query("error -deprecated +\"stack trace\" well-known");
+        assert_eq!(q.should, words(&["error", "well-known"]));
+        assert_eq!(q.must, words(&["stack trace"]));
+        assert_eq!(q.must_not, words(&["deprecated"]));
+
+        let q = parse_boolean_query("temperature -5 --verbose -x");
+        assert_eq!(q.should, words(&["temperature", "-5", "--verbose"]));
+        assert_eq!(q.must_not, words(&["x"]));
+
+        let q = parse_boolean_query("rust AND async OR tokio NOT blocking -");
+        assert_eq!(q.must, words(&["rust", "async"]));
+        assert_eq!(q.should, words(&["tokio", "-"]));
+        assert_eq!(q.must_not, words(&["blocking"]));
+        assert_eq!(q.scoring_text(), "tokio - rust async");
+    }
+}
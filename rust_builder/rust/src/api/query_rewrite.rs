@@ -0,0 +1,184 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Conversation-context query rewriting.
+//!
+//! Follow-up questions ("what about its fees?", "and for DeFi?") are not
+//! retrievable on their own. The rewriter resolves pronouns and ellipsis
+//! against the previous user turns and produces a standalone query.
+
+use std::collections::VecDeque;
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use log::debug;
+
+use crate::api::query_keywords::extract_query_keywords;
+
+/// Number of user turns kept in the engine's conversation memory.
+const MAX_HISTORY_TURNS: usize = 5;
+
+static CONVERSATION: Lazy<RwLock<VecDeque<String>>> =
+    Lazy::new(|| RwLock::new(VecDeque::with_capacity(MAX_HISTORY_TURNS)));
+
+/// "what about X", "how about X", "and X", "X는요?"
+static ELLIPSIS_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:(?:what|how)\s+about|and(?:\s+for)?)\s+(?P<body>.+?)[?？.]*$|^(?P<ko>.+?)(?:은요|는요|도요)[?？]*$").unwrap()
+});
+
+static POSSESSIVE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:its|their)\b").unwrap());
+
+// Demonstratives (this/that/these/those) are left out: they are usually
+// determiners ("how does this function work?") rather than references.
+static PRONOUN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:it|they|them)\b|그것|그거|이것|이거").unwrap());
+
+#[derive(Debug, Clone)]
+pub struct RewrittenQuery {
+    pub original: String,
+    /// Standalone query for retrieval (equal to `original` when unchanged).
+    pub rewritten: String,
+    pub was_rewritten: bool,
+    /// Topic from history that pronouns/ellipsis were resolved to.
+    pub antecedent: Option<String>,
+}
+
+impl RewrittenQuery {
+    fn unchanged(query: String) -> Self {
+        Self { rewritten: query.clone(), original: query, was_rewritten: false, antecedent: None }
+    }
+}
+
+/// Most salient topic of the latest turn that has one. Among equally
+/// scored keywords the later one wins ("the risks of staking" -> "staking").
+fn find_antecedent(history: &[String]) -> Option<(String, String)> {
+    history.iter().rev().find_map(|turn| {
+        extract_query_keywords(turn.clone())
+            .into_iter()
+            .filter(|k| !PRONOUN_PATTERN.is_match(&k.text))
+            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|k| (turn.clone(), k.text))
+    })
+}
+
+fn replace_case_insensitive(haystack: &str, needle: &str, replacement: &str) -> Option<String> {
+    let pattern = Regex::new(&format!("(?i){}", regex::escape(needle))).ok()?;
+    pattern
+        .is_match(haystack)
+        .then(|| pattern.replace(haystack, regex::NoExpand(replacement)).into_owned())
+}
+
+/// Rewrite `query` into a standalone query using earlier user turns
+/// (oldest first).
+#[flutter_rust_bridge::frb(sync)]
+pub fn rewrite_query(history: Vec<String>, query: String) -> RewrittenQuery {
+    let query = query.trim().to_string();
+    let has_possessive = POSSESSIVE_PATTERN.is_match(&query);
+    let has_pronoun = PRONOUN_PATTERN.is_match(&query);
+    let ellipsis = ELLIPSIS_PATTERN.captures(&query);
+
+    if !has_possessive && !has_pronoun && ellipsis.is_none() {
+        return RewrittenQuery::unchanged(query);
+    }
+    let Some((previous_turn, topic)) = find_antecedent(&history) else {
+        return RewrittenQuery::unchanged(query);
+    };
+
+    let rewritten = if has_possessive || has_pronoun {
+        // "what about its fees?" -> "what about rwa's fees?"
+        let resolved = POSSESSIVE_PATTERN.replace_all(&query, format!("{}'s", topic).as_str());
+        PRONOUN_PATTERN.replace_all(&resolved, topic.as_str()).into_owned()
+    } else {
+        // "what about DeFi?" after "what are the risks of RWA?" -> "what are the risks of DeFi?"
+        let caps = ellipsis.unwrap();
+        let body = caps.name("body").or_else(|| caps.name("ko")).map_or("", |m| m.as_str().trim());
+        match replace_case_insensitive(&previous_turn, &topic, body) {
+            Some(swapped) => swapped,
+            None => format!("{} {}", body, topic),
+        }
+    };
+
+    debug!("[query_rewrite] '{}' -> '{}'", query, rewritten);
+    RewrittenQuery { original: query, rewritten, was_rewritten: true, antecedent: Some(topic) }
+}
+
+/// Remember a user turn for `rewrite_query_with_context`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_conversation_turn(text: String) {
+    let mut turns = CONVERSATION.write().unwrap();
+    if turns.len() == MAX_HISTORY_TURNS {
+        turns.pop_front();
+    }
+    turns.push_back(text);
+}
+
+/// User turns currently kept by the engine (oldest first).
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_conversation_turns() -> Vec<String> {
+    CONVERSATION.read().unwrap().iter().cloned().collect()
+}
+
+/// Forget the conversation (e.g. when the user starts a new chat).
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_conversation_turns() {
+    CONVERSATION.write().unwrap().clear();
+}
+
+/// Rewrite against the engine's stored turns, then store the rewritten query
+/// as the newest turn so follow-ups chain.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rewrite_query_with_context(query: String) -> RewrittenQuery {
+    let result = rewrite_query(get_conversation_turns(), query);
+    add_conversation_turn(result.rewritten.clone());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_resolves_possessive_pronoun() {
+        let history = vec!["Explain RWA tokenization".to_string()];
+        let result = rewrite_query(history, "what about its fees?".to_string());
+        assert!(result.was_rewritten);
+        assert_eq!(result.antecedent.as_deref(), Some("rwa tokenization"));
+        assert_eq!(result.rewritten, "what about rwa tokenization's fees?");
+    }
+
+    #[test]
+    fn test_rewrite_ellipsis_swaps_subject() {
+        let history = vec!["What are the risks of staking?".to_string()];
+        let result = rewrite_query(history, "what about lending?".to_string());
+        assert_eq!(result.rewritten, "What are the risks of lending?");
+    }
+
+    #[test]
+    fn test_standalone_query_unchanged() {
+        let result = rewrite_query(vec!["What is RWA?".to_string()], "How do bonds work?".to_string());
+        assert!(!result.was_rewritten);
+        assert_eq!(result.rewritten, "How do bonds work?");
+    }
+
+    #[test]
+    fn test_demonstrative_determiner_unchanged() {
+        let history = vec!["Explain RWA tokenization".to_string()];
+        let result = rewrite_query(history, "how does this function work?".to_string());
+        assert!(!result.was_rewritten);
+        assert_eq!(result.rewritten, "how does this function work?");
+    }
+}
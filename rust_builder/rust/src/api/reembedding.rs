@@ -0,0 +1,409 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Resumable re-embedding after an embedding model change.
+//!
+//! `start_reembedding_job` walks the active tenant's chunks in id order,
+//! embeds each batch through a Dart callback and writes the vectors
+//! together with a checkpoint (last chunk id) in one transaction. If the
+//! app is killed, paused or a batch fails, calling it again with the same
+//! model resumes after the checkpoint. The job's tenant HNSW index is
+//! rebuilt once the last batch is stored, even if the app switched tenants
+//! meanwhile.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use flutter_rust_bridge::DartFnFuture;
+use log::{info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::content_crypto::read_content;
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::encode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::incremental_index::{drop_tenant_buffer, BufferTable};
+use crate::api::progress::{Progress, OP_HNSW_REBUILD};
+use crate::api::source_rag::rebuild_tenant_chunk_hnsw;
+use crate::api::tenant::active_tenant;
+use crate::api::vector_ops::norm;
+
+pub const REEMBED_RUNNING: &str = "running";
+pub const REEMBED_PAUSED: &str = "paused";
+pub const REEMBED_COMPLETED: &str = "completed";
+pub const REEMBED_FAILED: &str = "failed";
+pub const REEMBED_CANCELLED: &str = "cancelled";
+
+/// Only one job may drive the embedding loop at a time.
+static DRIVER_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Holds `DRIVER_ACTIVE` until dropped, so an early return, a panic or a
+/// cancelled future releases the driver.
+struct DriverGuard;
+
+impl DriverGuard {
+    fn acquire() -> Option<Self> {
+        DRIVER_ACTIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+            .then_some(DriverGuard)
+    }
+}
+
+impl Drop for DriverGuard {
+    fn drop(&mut self) {
+        DRIVER_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReembeddingJob {
+    pub id: i64,
+    pub model_name: String,
+    pub batch_size: u32,
+    /// One of the `REEMBED_*` values.
+    pub state: String,
+    pub done: u32,
+    pub total: u32,
+    /// Checkpoint: every chunk with a smaller or equal id is re-embedded.
+    pub last_chunk_id: i64,
+    pub dimension: Option<u32>,
+    pub error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub(crate) fn create_reembedding_jobs_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS reembedding_jobs (
+            id INTEGER PRIMARY KEY,
+            tenant_id TEXT NOT NULL DEFAULT 'default',
+            model_name TEXT NOT NULL,
+            batch_size INTEGER NOT NULL,
+            state TEXT NOT NULL,
+            done INTEGER NOT NULL DEFAULT 0,
+            total INTEGER NOT NULL DEFAULT 0,
+            last_chunk_id INTEGER NOT NULL DEFAULT 0,
+            dimension INTEGER,
+            error TEXT,
+            created_at INTEGER DEFAULT (strftime('%s', 'now')),
+            updated_at INTEGER DEFAULT (strftime('%s', 'now'))
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+const JOB_COLUMNS: &str =
+    "id, model_name, batch_size, state, done, total, last_chunk_id, dimension, error, created_at, updated_at";
+
+fn read_job(row: &rusqlite::Row) -> rusqlite::Result<ReembeddingJob> {
+    Ok(ReembeddingJob {
+        id: row.get(0)?,
+        model_name: row.get(1)?,
+        batch_size: row.get(2)?,
+        state: row.get(3)?,
+        done: row.get(4)?,
+        total: row.get(5)?,
+        last_chunk_id: row.get(6)?,
+        dimension: row.get(7)?,
+        error: row.get(8)?,
+        created_at: row.get(9)?,
+        updated_at: row.get(10)?,
+    })
+}
+
+fn load_job(conn: &Connection, job_id: i64) -> rusqlite::Result<ReembeddingJob> {
+    conn.query_row(&format!("SELECT {} FROM reembedding_jobs WHERE id = ?1", JOB_COLUMNS), params![job_id], read_job)
+}
+
+/// Most recent job of `tenant_id` that can still be resumed.
+fn load_unfinished_job(conn: &Connection, tenant_id: &str) -> rusqlite::Result<Option<ReembeddingJob>> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM reembedding_jobs WHERE tenant_id = ?1 AND state IN (?2, ?3, ?4) ORDER BY id DESC LIMIT 1",
+            JOB_COLUMNS
+        ),
+        params![tenant_id, REEMBED_RUNNING, REEMBED_PAUSED, REEMBED_FAILED],
+        read_job,
+    )
+    .optional()
+}
+
+fn count_chunks_after(conn: &Connection, tenant_id: &str, after_id: i64) -> rusqlite::Result<u32> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM chunks WHERE tenant_id = ?1 AND id > ?2",
+        params![tenant_id, after_id],
+        |row| row.get(0),
+    )
+}
+
+/// Resume `tenant_id`'s unfinished job for `model_name`, or start a new one
+/// (superseding an unfinished job for a different model).
+fn open_job(conn: &Connection, tenant_id: &str, model_name: &str, batch_size: u32) -> rusqlite::Result<ReembeddingJob> {
+    if let Some(job) = load_unfinished_job(conn, tenant_id)? {
+        if job.model_name == model_name {
+            // Chunks added since the last run are picked up too.
+            let remaining = count_chunks_after(conn, tenant_id, job.last_chunk_id)?;
+            conn.execute(
+                "UPDATE reembedding_jobs SET state = ?1, batch_size = ?2, total = done + ?3, error = NULL,
+                     updated_at = strftime('%s', 'now')
+                 WHERE id = ?4",
+                params![REEMBED_RUNNING, batch_size, remaining, job.id],
+            )?;
+            return load_job(conn, job.id);
+        }
+        info!("[reembedding] Job {} for {} superseded by {}", job.id, job.model_name, model_name);
+        conn.execute(
+            "UPDATE reembedding_jobs SET state = ?1, updated_at = strftime('%s', 'now') WHERE id = ?2",
+            params![REEMBED_CANCELLED, job.id],
+        )?;
+    }
+    let total = count_chunks_after(conn, tenant_id, 0)?;
+    conn.execute(
+        "INSERT INTO reembedding_jobs (tenant_id, model_name, batch_size, state, total) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![tenant_id, model_name, batch_size, REEMBED_RUNNING, total],
+    )?;
+    load_job(conn, conn.last_insert_rowid())
+}
+
+fn next_batch(conn: &Connection, tenant_id: &str, after_id: i64, limit: u32) -> rusqlite::Result<Vec<(i64, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, content FROM chunks WHERE tenant_id = ?1 AND id > ?2 ORDER BY id LIMIT ?3",
+    )?;
+    let rows = stmt
+        .query_map(params![tenant_id, after_id, limit], |row| Ok((row.get(0)?, read_content(row, 1)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
+/// Write one batch of embeddings and advance the checkpoint atomically.
+fn store_batch(conn: &mut Connection, job_id: i64, tenant_id: &str, batch: &[(i64, Vec<f32>)]) -> rusqlite::Result<()> {
+    let Some((last_id, first)) = batch.last().map(|(id, _)| *id).zip(batch.first()) else {
+        return Ok(());
+    };
+    let tx = conn.transaction()?;
+    {
+        let mut update =
+            tx.prepare("UPDATE chunks SET embedding = ?1, embedding_norm = ?4 WHERE id = ?2 AND tenant_id = ?3")?;
+        for (chunk_id, embedding) in batch {
+            update.execute(params![encode_embedding(embedding), chunk_id, tenant_id, norm(embedding)])?;
+        }
+    }
+    tx.execute(
+        "UPDATE reembedding_jobs SET done = done + ?1, last_chunk_id = ?2, dimension = ?3,
+             updated_at = strftime('%s', 'now')
+         WHERE id = ?4",
+        params![batch.len() as u32, last_id, first.1.len() as u32, job_id],
+    )?;
+    tx.commit()
+}
+
+fn set_state(conn: &Connection, job_id: i64, state: &str, error: Option<&str>) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE reembedding_jobs SET state = ?1, error = ?2, updated_at = strftime('%s', 'now') WHERE id = ?3",
+        params![state, error, job_id],
+    )?;
+    Ok(())
+}
+
+/// The active tenant's latest re-embedding job, if any.
+pub fn get_reembedding_job() -> Result<Option<ReembeddingJob>, RagError> {
+    let conn = get_connection()?;
+    conn.query_row(
+        &format!("SELECT {} FROM reembedding_jobs WHERE tenant_id = ?1 ORDER BY id DESC LIMIT 1", JOB_COLUMNS),
+        params![active_tenant()],
+        read_job,
+    )
+    .optional()
+    .map_err(db_error)
+}
+
+fn stop_job(state: &str) -> Result<bool, RagError> {
+    let conn = get_connection()?;
+    let changed = conn
+        .execute(
+            "UPDATE reembedding_jobs SET state = ?1, updated_at = strftime('%s', 'now')
+             WHERE tenant_id = ?2 AND state IN (?3, ?4)",
+            params![state, active_tenant(), REEMBED_RUNNING, REEMBED_PAUSED],
+        )
+        .map_err(db_error)?;
+    Ok(changed > 0)
+}
+
+/// Stop the running job after its current batch; `start_reembedding_job`
+/// with the same model resumes it. Returns false if nothing was running.
+pub fn pause_reembedding_job() -> Result<bool, RagError> {
+    stop_job(REEMBED_PAUSED)
+}
+
+/// Abandon the running or paused job. Chunks already re-embedded keep
+/// their new vectors; the next start begins from the first chunk.
+pub fn cancel_reembedding_job() -> Result<bool, RagError> {
+    stop_job(REEMBED_CANCELLED)
+}
+
+/// Re-embed every chunk of the active tenant with `model_name`.
+///
+/// `embed_batch` receives up to `batch_size` chunk texts and must return
+/// one vector per text, all of the same dimension. `on_progress` gets a
+/// job snapshot after each stored batch. Resumes an unfinished job for the
+/// same model; returns the job in its final (or paused/cancelled) state.
+pub async fn start_reembedding_job(
+    model_name: String,
+    batch_size: u32,
+    embed_batch: impl Fn(Vec<String>) -> DartFnFuture<Vec<Vec<f32>>>,
+    on_progress: impl Fn(ReembeddingJob) -> DartFnFuture<()>,
+) -> Result<ReembeddingJob, RagError> {
+    if model_name.trim().is_empty() || batch_size == 0 {
+        return Err(RagError::InvalidInput("model_name and a non-zero batch_size are required".to_string()));
+    }
+    let Some(_driver) = DriverGuard::acquire() else {
+        return Err(RagError::InvalidInput("A re-embedding job is already running".to_string()));
+    };
+    drive_job(&model_name, batch_size, &embed_batch, &on_progress).await
+}
+
+async fn drive_job(
+    model_name: &str,
+    batch_size: u32,
+    embed_batch: &impl Fn(Vec<String>) -> DartFnFuture<Vec<Vec<f32>>>,
+    on_progress: &impl Fn(ReembeddingJob) -> DartFnFuture<()>,
+) -> Result<ReembeddingJob, RagError> {
+    let tenant_id = active_tenant();
+    let job = {
+        let conn = get_connection()?;
+        open_job(&conn, &tenant_id, model_name, batch_size).map_err(db_error)?
+    };
+    info!(
+        "[reembedding] Job {} ({}): {} of {} chunks done",
+        job.id, model_name, job.done, job.total
+    );
+    let job_id = job.id;
+    let mut last_chunk_id = job.last_chunk_id;
+    let mut dimension = job.dimension.map(|d| d as usize);
+
+    loop {
+        let batch = {
+            let conn = get_connection()?;
+            let state: String = conn
+                .query_row("SELECT state FROM reembedding_jobs WHERE id = ?1", params![job_id], |row| row.get(0))
+                .map_err(db_error)?;
+            if state != REEMBED_RUNNING {
+                info!("[reembedding] Job {} stopped: {}", job_id, state);
+                return load_job(&conn, job_id).map_err(db_error);
+            }
+            next_batch(&conn, &tenant_id, last_chunk_id, batch_size).map_err(db_error)?
+        };
+        if batch.is_empty() {
+            break;
+        }
+
+        let (ids, texts): (Vec<i64>, Vec<String>) = batch.into_iter().unzip();
+        let embeddings = embed_batch(texts).await;
+        let bad_shape = embeddings.len() != ids.len()
+            || embeddings.iter().any(|e| e.is_empty() || dimension.is_some_and(|d| d != e.len()))
+            || embeddings.windows(2).any(|w| w[0].len() != w[1].len());
+        if bad_shape {
+            let message = format!(
+                "Embedding callback returned {} vectors for {} chunks (or mismatched dimensions)",
+                embeddings.len(),
+                ids.len()
+            );
+            warn!("[reembedding] Job {} failed: {}", job_id, message);
+            let conn = get_connection()?;
+            set_state(&conn, job_id, REEMBED_FAILED, Some(&message)).map_err(db_error)?;
+            return Err(RagError::InvalidInput(message));
+        }
+        dimension = embeddings.first().map(|e| e.len());
+
+        let batch: Vec<(i64, Vec<f32>)> = ids.into_iter().zip(embeddings).collect();
+        let mut conn = get_connection()?;
+        store_batch(&mut conn, job_id, &tenant_id, &batch).map_err(db_error)?;
+        last_chunk_id = batch.last().map_or(last_chunk_id, |(id, _)| *id);
+        let snapshot = load_job(&conn, job_id).map_err(db_error)?;
+        drop(conn);
+        on_progress(snapshot).await;
+    }
+
+    // Old vectors in the incremental buffer would shadow the new ones.
+    drop_tenant_buffer(&tenant_id, BufferTable::Chunks);
+    if let Err(e) = rebuild_tenant_chunk_hnsw(&tenant_id, Progress::new(OP_HNSW_REBUILD)) {
+        let conn = get_connection()?;
+        set_state(&conn, job_id, REEMBED_FAILED, Some(&e.to_string())).map_err(db_error)?;
+        return Err(e);
+    }
+    let conn = get_connection()?;
+    set_state(&conn, job_id, REEMBED_COMPLETED, None).map_err(db_error)?;
+    let job = load_job(&conn, job_id).map_err(db_error)?;
+    drop(conn);
+    info!("[reembedding] Job {} completed: {} chunks", job_id, job.done);
+    on_progress(job.clone()).await;
+    Ok(job)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT NOT NULL, embedding BLOB NOT NULL,
+                 tenant_id TEXT NOT NULL DEFAULT 'default', embedding_norm REAL);
+             INSERT INTO chunks (id, content, embedding) VALUES (1, 'a', x''), (2, 'b', x''), (3, 'c', x'');
+             INSERT INTO chunks (id, content, embedding, tenant_id) VALUES (4, 'd', x'', 'other');",
+        )
+        .unwrap();
+        create_reembedding_jobs_table(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_batches_checkpoint_and_resume() {
+        let mut conn = setup();
+        let job = open_job(&conn, "default", "model-b", 2).unwrap();
+        assert_eq!(job.total, 3);
+
+        let batch = next_batch(&conn, "default", job.last_chunk_id, 2).unwrap();
+        assert_eq!(batch.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1, 2]);
+        let embedded: Vec<(i64, Vec<f32>)> = batch.iter().map(|(id, _)| (*id, vec![1.0, 0.0])).collect();
+        store_batch(&mut conn, job.id, "default", &embedded).unwrap();
+        let blob: Vec<u8> = conn.query_row("SELECT embedding FROM chunks WHERE id = 2", [], |r| r.get(0)).unwrap();
+        assert_eq!(blob.len(), 8);
+
+        // Interrupted: reopening with the same model resumes at the checkpoint.
+        set_state(&conn, job.id, REEMBED_PAUSED, None).unwrap();
+        let resumed = open_job(&conn, "default", "model-b", 2).unwrap();
+        assert_eq!((resumed.id, resumed.done, resumed.last_chunk_id), (job.id, 2, 2));
+        assert_eq!(resumed.dimension, Some(2));
+        let rest = next_batch(&conn, "default", resumed.last_chunk_id, 2).unwrap();
+        assert_eq!(rest.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![3]);
+
+        // A different model supersedes the unfinished job.
+        let fresh = open_job(&conn, "default", "model-c", 2).unwrap();
+        assert_ne!(fresh.id, job.id);
+        assert_eq!((fresh.done, fresh.last_chunk_id), (0, 0));
+        assert_eq!(load_job(&conn, job.id).unwrap().state, REEMBED_CANCELLED);
+    }
+
+    #[test]
+    fn test_driver_guard_released_on_drop() {
+        let guard = DriverGuard::acquire().unwrap();
+        assert!(DriverGuard::acquire().is_none());
+        drop(guard);
+        assert!(DriverGuard::acquire().is_some());
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Related-chunk expansion for evidence that spans sections.
+//!
+//! From each hybrid hit, follows three kinds of links one hop out:
+//! neighbouring chunks of the same source, chunks sharing an extracted
+//! entity (see `entity_extraction`), and chunks of the same split code
+//! block (`batch_id`). Related chunks inherit a decayed copy of the score
+//! of the hit that led to them.
+
+use rusqlite::{params, Connection};
+use std::collections::{HashMap, HashSet};
+
+use crate::api::content_crypto::read_content;
+use crate::api::hybrid_search::HybridSearchResult;
+
+pub const LINK_HIT: &str = "hit";
+pub const LINK_ADJACENT: &str = "adjacent";
+pub const LINK_SHARED_ENTITY: &str = "shared_entity";
+pub const LINK_SAME_BATCH: &str = "same_batch";
+
+#[derive(Debug, Clone)]
+pub struct ExpansionConfig {
+    /// Related chunks added per hit at most.
+    pub max_related_per_hit: u32,
+    /// Related score = hit score * decay.
+    pub score_decay: f64,
+    pub follow_adjacent: bool,
+    pub follow_shared_entities: bool,
+    pub follow_same_batch: bool,
+}
+
+impl Default for ExpansionConfig {
+    fn default() -> Self {
+        Self {
+            max_related_per_hit: 3,
+            score_decay: 0.5,
+            follow_adjacent: true,
+            follow_shared_entities: true,
+            follow_same_batch: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpandedSearchResult {
+    /// Related chunks have `vector_rank` and `bm25_rank` set to 0.
+    pub result: HybridSearchResult,
+    /// One of the `LINK_*` values ("hit" for the original results).
+    pub link: String,
+    /// Chunk id of the hit this chunk was reached from.
+    pub expanded_from: Option<i64>,
+}
+
+/// Chunks one hop from `hit`, batch siblings first, then neighbours,
+/// then chunks sharing the most entities.
+fn find_related(
+    conn: &Connection,
+    hit: &HybridSearchResult,
+    config: &ExpansionConfig,
+) -> rusqlite::Result<Vec<(i64, &'static str)>> {
+    let limit = config.max_related_per_hit as usize;
+    let mut related: Vec<(i64, &'static str)> = Vec::new();
+    let mut push = |id: i64, link: &'static str| {
+        if id != hit.doc_id && related.len() < limit && !related.iter().any(|(r, _)| *r == id) {
+            related.push((id, link));
+        }
+    };
+
+    if config.follow_same_batch {
+        let mut stmt = conn.prepare_cached(
+            "SELECT c2.id FROM chunks c1
+             JOIN chunks c2 ON c2.batch_id = c1.batch_id AND c2.id != c1.id
+             WHERE c1.id = ?1 AND c1.batch_id IS NOT NULL
+             ORDER BY c2.chunk_index",
+        )?;
+        for id in stmt.query_map(params![hit.doc_id], |row| row.get::<_, i64>(0))? {
+            push(id?, LINK_SAME_BATCH);
+        }
+    }
+
+    if config.follow_adjacent {
+        let mut stmt = conn.prepare_cached(
+            "SELECT id FROM chunks WHERE source_id = ?1 AND chunk_index IN (?2 - 1, ?2 + 1)
+             ORDER BY chunk_index",
+        )?;
+        for id in stmt.query_map(params![hit.source_id, hit.chunk_index], |row| {
+            row.get::<_, i64>(0)
+        })? {
+            push(id?, LINK_ADJACENT);
+        }
+    }
+
+    if config.follow_shared_entities {
+        let mut stmt = conn.prepare_cached(
+            "SELECT e2.chunk_id, COUNT(DISTINCT e2.normalized) AS shared FROM chunk_entities e1
+             JOIN chunk_entities e2 ON e2.entity_type = e1.entity_type
+                AND e2.normalized = e1.normalized AND e2.chunk_id != e1.chunk_id
+             WHERE e1.chunk_id = ?1
+             GROUP BY e2.chunk_id ORDER BY shared DESC, e2.chunk_id LIMIT ?2",
+        )?;
+        for id in stmt.query_map(params![hit.doc_id, limit as i64], |row| {
+            row.get::<_, i64>(0)
+        })? {
+            push(id?, LINK_SHARED_ENTITY);
+        }
+    }
+
+    Ok(related)
+}
+
+/// Add 1-hop related chunks to `hits` and re-sort by score.
+///
+/// `conditions` are extra SQL conditions over `chunks c LEFT JOIN sources s`
+/// (the search filter), so expansion never leaves the filtered scope.
+pub(crate) fn expand_with_related(
+    conn: &Connection,
+    hits: Vec<HybridSearchResult>,
+    config: &ExpansionConfig,
+    conditions: &[String],
+) -> rusqlite::Result<Vec<ExpandedSearchResult>> {
+    let hit_ids: HashSet<i64> = hits.iter().map(|h| h.doc_id).collect();
+    // chunk id -> (score, link, expanded_from)
+    let mut candidates: HashMap<i64, (f64, &'static str, i64)> = HashMap::new();
+    for hit in &hits {
+        let score = hit.score * config.score_decay;
+        for (id, link) in find_related(conn, hit, config)? {
+            if hit_ids.contains(&id) {
+                continue;
+            }
+            let entry = candidates.entry(id).or_insert((score, link, hit.doc_id));
+            if score > entry.0 {
+                *entry = (score, link, hit.doc_id);
+            }
+        }
+    }
+
+    let mut out: Vec<ExpandedSearchResult> = hits
+        .into_iter()
+        .map(|result| ExpandedSearchResult {
+            result,
+            link: LINK_HIT.to_string(),
+            expanded_from: None,
+        })
+        .collect();
+
+    if !candidates.is_empty() {
+        let id_list = candidates
+            .keys()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut sql_conditions = vec![format!("c.id IN ({})", id_list)];
+        sql_conditions.extend(conditions.iter().cloned());
+        let query = format!(
+            "SELECT c.id, c.content, c.source_id, s.metadata, c.chunk_index,
+                    COALESCE(c.chunk_type, 'general'), c.header_path FROM chunks c
+             LEFT JOIN sources s ON c.source_id = s.id
+             WHERE {}
+             ORDER BY c.id",
+            sql_conditions.join(" AND ")
+        );
+        let mut stmt = conn.prepare(&query)?;
+        let rows = stmt.query_map([], |row| {
+            Ok(HybridSearchResult {
+                doc_id: row.get(0)?,
+                content: read_content(row, 1)?,
+                score: 0.0,
+                vector_rank: 0,
+                bm25_rank: 0,
+                source_id: row.get(2)?,
+                metadata: row.get(3)?,
+                chunk_index: row.get::<_, i64>(4)? as u32,
+                chunk_type: row.get(5)?,
+                header_path: row.get(6)?,
+                matched_terms: Vec::new(),
+                snippet: None,
+            })
+        })?;
+        for row in rows {
+            let mut result = row?;
+            let (score, link, from) = candidates[&result.doc_id];
+            result.score = score;
+            out.push(ExpandedSearchResult {
+                result,
+                link: link.to_string(),
+                expanded_from: Some(from),
+            });
+        }
+    }
+
+    // Stable sort: on ties, hits stay first and related chunks keep id order.
+    out.sort_by(|a, b| {
+        b.result
+            .score
+            .partial_cmp(&a.result.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::entity_extraction::{create_entity_table, store_chunk_entities};
+
+    fn hit(doc_id: i64, source_id: i64, chunk_index: u32, score: f64) -> HybridSearchResult {
+        HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score,
+            vector_rank: 1,
+            bm25_rank: 1,
+            source_id,
+            metadata: None,
+            chunk_index,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn test_expand_follows_all_link_types() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sources (id INTEGER PRIMARY KEY, metadata TEXT);
+             CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER, chunk_index INTEGER,
+                content TEXT, batch_id TEXT, chunk_type TEXT, header_path TEXT);
+             INSERT INTO sources (id) VALUES (1), (2);
+             INSERT INTO chunks VALUES
+                (1, 1, 0, 'Intro, filed 2023-09-15.', NULL, NULL, NULL),
+                (2, 1, 1, 'fn main() {', 'b1', 'code', NULL),
+                (3, 1, 2, 'Details.', NULL, 'text', 'Setup > Details'),
+                (4, 1, 5, '}', 'b1', 'code', NULL),
+                (5, 2, 0, 'Appendix dated 2023-09-15.', NULL, NULL, NULL),
+                (6, 2, 1, 'Unrelated.', NULL, NULL, NULL);",
+        )
+        .unwrap();
+        create_entity_table(&conn).unwrap();
+        for (id, content) in [
+            (1, "Intro, filed 2023-09-15."),
+            (5, "Appendix dated 2023-09-15."),
+        ] {
+            store_chunk_entities(&conn, id, content).unwrap();
+        }
+
+        let config = ExpansionConfig::default();
+        let out = expand_with_related(
+            &conn,
+            vec![hit(1, 1, 0, 1.0), hit(2, 1, 1, 0.8)],
+            &config,
+            &[],
+        )
+        .unwrap();
+        let summary: Vec<(i64, &str, Option<i64>)> = out
+            .iter()
+            .map(|r| (r.result.doc_id, r.link.as_str(), r.expanded_from))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, LINK_HIT, None),
+                (2, LINK_HIT, None),
+                (5, LINK_SHARED_ENTITY, Some(1)),
+                (3, LINK_ADJACENT, Some(2)),
+                (4, LINK_SAME_BATCH, Some(2)),
+            ]
+        );
+        assert!((out[2].result.score - 0.5).abs() < 1e-9);
+        assert_eq!(out[2].result.chunk_type, "general");
+        assert_eq!(out[3].result.chunk_type, "text");
+        assert_eq!(out[3].result.header_path.as_deref(), Some("Setup > Details"));
+
+        // Filter conditions keep expansion inside the scope.
+        let scoped = expand_with_related(
+            &conn,
+            vec![hit(1, 1, 0, 1.0)],
+            &config,
+            &["c.source_id IN (1)".to_string()],
+        )
+        .unwrap();
+        assert!(scoped.iter().all(|r| r.result.source_id == 1));
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Two-phase hybrid search for reranking with a model that runs in Dart.
+//!
+//! `search_hybrid_candidates` returns the top-N fused candidates with
+//! their contents and remembers them; the app scores each (query,
+//! content) pair with its cross-encoder and passes the scores to
+//! `finalize_with_rerank_scores`, which adds the rerank scores as one
+//! more list of the search's fusion strategy and returns the final order. Candidates are kept per
+//! tenant until finalized or replaced by the next candidate search.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+
+use crate::api::error::RagError;
+use crate::api::hybrid_search::{
+    normalize_scores, search_hybrid, FusionStrategy, HybridSearchResult, RrfConfig, SearchFilter,
+};
+use crate::api::retrieval_state::record_hybrid_retrieval;
+use crate::api::tenant::active_tenant;
+
+/// Weight of the rerank list when `finalize_with_rerank_scores` gets none.
+const DEFAULT_RERANK_WEIGHT: f64 = 1.0;
+
+struct PendingCandidates {
+    query_text: String,
+    rrf_k: u32,
+    fusion: FusionStrategy,
+    results: Vec<HybridSearchResult>,
+}
+
+static PENDING: Lazy<RwLock<HashMap<String, PendingCandidates>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Phase one: the top `candidate_count` hybrid results, with contents, to
+/// score externally. Replaces any candidates still pending.
+pub fn search_hybrid_candidates(
+    query_text: String,
+    query_embedding: Vec<f32>,
+    candidate_count: u32,
+    config: Option<RrfConfig>,
+    filter: Option<SearchFilter>,
+) -> Result<Vec<HybridSearchResult>, RagError> {
+    let RrfConfig { k: rrf_k, fusion, .. } = config.clone().unwrap_or_default();
+    let results =
+        search_hybrid(query_text.clone(), query_embedding, candidate_count, config, filter, None)?.results;
+    PENDING.write().unwrap().insert(
+        active_tenant(),
+        PendingCandidates {
+            query_text,
+            rrf_k,
+            fusion,
+            results: results.clone(),
+        },
+    );
+    Ok(results)
+}
+
+/// Phase two: fuse cross-encoder `scores` (higher is better, one per id
+/// in `doc_ids`) into the pending candidates and return the best `top_k`.
+///
+/// Each candidate gets `rerank_weight / (k + rerank rank)` added to its
+/// RRF score or, with `FusionStrategy::WeightedScore`, `rerank_weight`
+/// times its rerank score normalized like the other lists
+/// (`rerank_weight` defaults to 1.0, letting the reranker dominate the
+/// 0.5/0.5 vector/BM25 weights). Candidates left unscored keep their
+/// score; ids that were not candidates are ignored.
+pub fn finalize_with_rerank_scores(
+    doc_ids: Vec<i64>,
+    scores: Vec<f64>,
+    top_k: u32,
+    rerank_weight: Option<f64>,
+) -> Result<Vec<HybridSearchResult>, RagError> {
+    if doc_ids.len() != scores.len() {
+        return Err(RagError::InvalidInput(format!(
+            "Got {} doc ids but {} scores",
+            doc_ids.len(),
+            scores.len()
+        )));
+    }
+    let pending = PENDING
+        .write()
+        .unwrap()
+        .remove(&active_tenant())
+        .ok_or_else(|| {
+            RagError::InvalidInput(
+                "No pending candidates; call search_hybrid_candidates first".to_string(),
+            )
+        })?;
+    let weight = rerank_weight.unwrap_or(DEFAULT_RERANK_WEIGHT);
+    let mut results = fuse_rerank_scores(
+        pending.results,
+        pending.fusion,
+        pending.rrf_k,
+        &doc_ids,
+        &scores,
+        weight,
+    );
+    results.truncate(top_k as usize);
+    info!("[rerank] Finalized {} results from {} scores", results.len(), scores.len());
+    record_hybrid_retrieval(&pending.query_text, &results);
+    Ok(results)
+}
+
+/// Drop the pending candidates of `tenant_id`.
+pub(crate) fn drop_tenant_candidates(tenant_id: &str) {
+    PENDING.write().unwrap().remove(tenant_id);
+}
+
+/// `candidates` with the rerank list added to their scores, best first.
+fn fuse_rerank_scores(
+    mut candidates: Vec<HybridSearchResult>,
+    fusion: FusionStrategy,
+    rrf_k: u32,
+    doc_ids: &[i64],
+    scores: &[f64],
+    weight: f64,
+) -> Vec<HybridSearchResult> {
+    let mut ranked: Vec<(i64, f64)> = doc_ids
+        .iter()
+        .copied()
+        .zip(scores.iter().copied())
+        .filter(|(_, score)| !score.is_nan())
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    // An id scored twice keeps its best score.
+    let mut seen = std::collections::HashSet::new();
+    ranked.retain(|(id, _)| seen.insert(*id));
+    let rerank_terms: HashMap<i64, f64> = match fusion {
+        FusionStrategy::Rrf => ranked
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (*id, weight / (rrf_k as f64 + (i + 1) as f64)))
+            .collect(),
+        FusionStrategy::WeightedScore { normalization } => normalize_scores(&ranked, normalization)
+            .0
+            .into_iter()
+            .map(|(id, score)| (id, weight * score))
+            .collect(),
+    };
+
+    let mut unknown = rerank_terms.len();
+    for candidate in candidates.iter_mut() {
+        if let Some(term) = rerank_terms.get(&candidate.doc_id) {
+            candidate.score += term;
+            unknown -= 1;
+        }
+    }
+    if unknown > 0 {
+        warn!("[rerank] Ignored {} scores for ids that were not candidates", unknown);
+    }
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::hybrid_search::ScoreNormalization;
+
+    #[test]
+    fn test_rerank_scores_reorder_candidates() {
+        let make = |doc_id: i64, score: f64| HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id: 0,
+            metadata: None,
+            chunk_index: 0,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        };
+        let candidates = vec![make(1, 0.0164), make(2, 0.0161), make(3, 0.0159)];
+
+        let rrf = FusionStrategy::Rrf;
+        let fused = fuse_rerank_scores(candidates.clone(), rrf, 60, &[3, 2, 99], &[0.9, 0.8, 0.1], 1.0);
+        let ids: Vec<i64> = fused.iter().map(|r| r.doc_id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+
+        let unchanged = fuse_rerank_scores(candidates, rrf, 60, &[3], &[0.9], 0.0);
+        assert_eq!(unchanged[0].doc_id, 1);
+    }
+
+    #[test]
+    fn test_rerank_scores_match_weighted_fusion_scale() {
+        let make = |doc_id: i64, score: f64| HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id: 0,
+            metadata: None,
+            chunk_index: 0,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        };
+        // Blended 0-1 scores: an RRF-sized rerank term could not reorder them.
+        let candidates = vec![make(1, 0.9), make(2, 0.6), make(3, 0.3)];
+        let weighted = FusionStrategy::WeightedScore { normalization: ScoreNormalization::MinMax };
+        let fused = fuse_rerank_scores(candidates, weighted, 60, &[3, 2, 1], &[5.0, 2.0, 1.0], 1.0);
+        assert_eq!(fused.iter().map(|r| r.doc_id).collect::<Vec<_>>(), vec![3, 1, 2]);
+        assert!((fused[0].score - 1.3).abs() < 1e-9);
+    }
+}
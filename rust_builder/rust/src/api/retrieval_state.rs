@@ -0,0 +1,177 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Last-retrieval state for source attribution (`/cite`, `/sources`).
+//!
+//! Every hybrid or chunk search records which chunks it returned, so the
+//! app can answer "where did that come from?" for the previous answer
+//! without re-running retrieval. The state is kept per tenant, and searches
+//! with a blank query don't replace it.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use log::debug;
+
+use crate::api::hybrid_search::HybridSearchResult;
+use crate::api::source_rag::ChunkSearchResult;
+use crate::api::tenant::active_tenant;
+
+/// One chunk that contributed to the last retrieval.
+#[derive(Debug, Clone)]
+pub struct RetrievalCitation {
+    pub chunk_id: i64,
+    pub source_id: i64,
+    pub chunk_index: i32,
+    pub score: f64,
+    /// Source metadata (JSON), including page information when the app stored it.
+    pub metadata: Option<String>,
+}
+
+/// Snapshot of the most recent retrieval.
+#[derive(Debug, Clone)]
+pub struct LastRetrieval {
+    /// Query text; `None` for vector-only (chunk) searches.
+    pub query: Option<String>,
+    pub citations: Vec<RetrievalCitation>,
+}
+
+impl LastRetrieval {
+    /// Distinct source ids in citation order.
+    pub fn source_ids(&self) -> Vec<i64> {
+        let mut ids: Vec<i64> = Vec::new();
+        for c in &self.citations {
+            if !ids.contains(&c.source_id) {
+                ids.push(c.source_id);
+            }
+        }
+        ids
+    }
+}
+
+static LAST_RETRIEVAL: Lazy<RwLock<HashMap<String, LastRetrieval>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn citations_from_hybrid(results: &[HybridSearchResult]) -> Vec<RetrievalCitation> {
+    results
+        .iter()
+        .map(|r| RetrievalCitation {
+            chunk_id: r.doc_id,
+            source_id: r.source_id,
+            chunk_index: r.chunk_index as i32,
+            score: r.score,
+            metadata: r.metadata.clone(),
+        })
+        .collect()
+}
+
+fn citations_from_chunks(results: &[ChunkSearchResult]) -> Vec<RetrievalCitation> {
+    results
+        .iter()
+        .map(|r| RetrievalCitation {
+            chunk_id: r.chunk_id,
+            source_id: r.source_id,
+            chunk_index: r.chunk_index,
+            score: r.similarity,
+            metadata: r.metadata.clone(),
+        })
+        .collect()
+}
+
+fn store(tenant_id: String, retrieval: LastRetrieval) {
+    debug!("[retrieval_state] Recording {} citations for tenant {}", retrieval.citations.len(), tenant_id);
+    if let Ok(mut guard) = LAST_RETRIEVAL.write() {
+        guard.insert(tenant_id, retrieval);
+    }
+}
+
+/// Record the results of a hybrid search as the active tenant's last
+/// retrieval. Blank queries (e.g. a cleared search box) are ignored.
+pub(crate) fn record_hybrid_retrieval(query: &str, results: &[HybridSearchResult]) {
+    if query.trim().is_empty() {
+        return;
+    }
+    let citations = citations_from_hybrid(results);
+    store(active_tenant(), LastRetrieval { query: Some(query.to_string()), citations });
+}
+
+/// Record the results of a chunk (vector-only) search as the active
+/// tenant's last retrieval.
+pub(crate) fn record_chunk_retrieval(results: &[ChunkSearchResult]) {
+    store(active_tenant(), LastRetrieval { query: None, citations: citations_from_chunks(results) });
+}
+
+/// Get the chunks and sources used by the active tenant's most recent
+/// retrieval.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_last_retrieval() -> Option<LastRetrieval> {
+    LAST_RETRIEVAL.read().ok().and_then(|guard| guard.get(&active_tenant()).cloned())
+}
+
+/// Forget the active tenant's last retrieval (e.g. when starting a new
+/// conversation).
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_last_retrieval() {
+    drop_tenant_retrieval(&active_tenant());
+}
+
+/// Forget the last retrieval of a deleted tenant.
+pub(crate) fn drop_tenant_retrieval(tenant_id: &str) {
+    if let Ok(mut guard) = LAST_RETRIEVAL.write() {
+        guard.remove(tenant_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_citations_from_hybrid() {
+        let make = |doc_id: i64, source_id: i64| HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score: 0.5,
+            vector_rank: 1,
+            bm25_rank: 0,
+            source_id,
+            metadata: Some("{\"page\":3}".to_string()),
+            chunk_index: 2,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        };
+        let snapshot = LastRetrieval {
+            query: Some("q".to_string()),
+            citations: citations_from_hybrid(&[make(10, 1), make(11, 2), make(12, 1)]),
+        };
+        assert_eq!(snapshot.citations[0].chunk_id, 10);
+        assert_eq!(snapshot.citations[0].metadata.as_deref(), Some("{\"page\":3}"));
+        assert_eq!(snapshot.source_ids(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_last_retrieval_is_kept_per_tenant() {
+        let retrieval = |query: &str| LastRetrieval { query: Some(query.to_string()), citations: Vec::new() };
+        store("retrieval_a".to_string(), retrieval("first"));
+        store("retrieval_b".to_string(), retrieval("second"));
+        drop_tenant_retrieval("retrieval_a");
+
+        let guard = LAST_RETRIEVAL.read().unwrap();
+        assert!(!guard.contains_key("retrieval_a"));
+        assert_eq!(guard["retrieval_b"].query.as_deref(), Some("second"));
+    }
+}
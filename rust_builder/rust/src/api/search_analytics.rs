@@ -0,0 +1,368 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Opt-in search analytics for tuning chunking and fusion weights.
+//!
+//! When enabled (`set_search_analytics_enabled(true)`), every hybrid or
+//! chunk search records its latency, result ids and whether it came back
+//! empty. Hybrid searches also record a salted hash of the normalized query
+//! (never the text); the salt is random per database, so hashes can't be
+//! matched against a dictionary of common queries. The app reports which
+//! results the user opened or accepted with `report_search_feedback`, and
+//! `export_search_analytics` writes the active tenant's searches as JSON Lines.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use log::{info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::api::db_pool::get_connection;
+use crate::api::db_retry::with_write_retry;
+use crate::api::error::{db_error, io_error, RagError};
+use crate::api::query_normalize::{normalize_query_with, QueryNormalizationConfig};
+use crate::api::tenant::active_tenant;
+
+static ANALYTICS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// What the user did with a search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchFeedbackAction {
+    /// Opened or expanded the result.
+    Clicked,
+    /// Used the result, e.g. kept it in the answer context.
+    Accepted,
+}
+
+impl SearchFeedbackAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            SearchFeedbackAction::Clicked => "clicked",
+            SearchFeedbackAction::Accepted => "accepted",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedFeedback {
+    chunk_id: i64,
+    action: String,
+    /// 1-based position in the event's results; `None` if it wasn't among them.
+    rank: Option<u32>,
+    created_at: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedEvent {
+    id: i64,
+    tenant_id: String,
+    kind: String,
+    /// `None` for vector-only searches, which have no query text.
+    query_hash: Option<String>,
+    latency_ms: f64,
+    result_count: u32,
+    zero_result: bool,
+    result_ids: Vec<i64>,
+    created_at: i64,
+    feedback: Vec<ExportedFeedback>,
+}
+
+/// Create the analytics tables (called from `init_source_db`).
+pub(crate) fn create_search_analytics_tables(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS search_events (
+            id INTEGER PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            query_hash TEXT NOT NULL,
+            latency_ms REAL NOT NULL,
+            result_count INTEGER NOT NULL,
+            zero_result INTEGER NOT NULL,
+            result_ids TEXT NOT NULL,
+            created_at INTEGER DEFAULT (strftime('%s', 'now'))
+        );
+        CREATE TABLE IF NOT EXISTS search_analytics_salt (salt TEXT NOT NULL);
+        CREATE INDEX IF NOT EXISTS idx_search_events_hash ON search_events(tenant_id, query_hash);
+        CREATE TABLE IF NOT EXISTS search_feedback (
+            id INTEGER PRIMARY KEY,
+            event_id INTEGER NOT NULL REFERENCES search_events(id) ON DELETE CASCADE,
+            chunk_id INTEGER NOT NULL,
+            action TEXT NOT NULL,
+            rank INTEGER,
+            created_at INTEGER DEFAULT (strftime('%s', 'now'))
+        );",
+    )?;
+    let has_salt: bool = conn.query_row("SELECT EXISTS(SELECT 1 FROM search_analytics_salt)", [], |row| row.get(0))?;
+    if !has_salt {
+        conn.execute(
+            "INSERT INTO search_analytics_salt (salt) VALUES (?1)",
+            params![uuid::Uuid::new_v4().to_string()],
+        )?;
+    }
+    Ok(())
+}
+
+/// Turn analytics collection on or off (off by default).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_search_analytics_enabled(enabled: bool) {
+    info!("[search_analytics] Collection {}", if enabled { "enabled" } else { "disabled" });
+    ANALYTICS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_search_analytics_enabled() -> bool {
+    ANALYTICS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Hex SHA-256 of the database salt and the normalized query, so repeated
+/// queries group together without storing what the user typed. Uses the
+/// default normalization so hashes stay comparable if the app changes its
+/// config.
+fn query_hash(conn: &Connection, query_text: &str) -> rusqlite::Result<String> {
+    let salt: String = conn.query_row("SELECT salt FROM search_analytics_salt LIMIT 1", [], |row| row.get(0))?;
+    let normalized = normalize_query_with(query_text, &QueryNormalizationConfig::default());
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(normalized.as_bytes());
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn insert_event(
+    conn: &Connection,
+    tenant_id: &str,
+    kind: &str,
+    query_text: Option<&str>,
+    latency: Duration,
+    result_ids: &[i64],
+) -> rusqlite::Result<()> {
+    let hash = query_text.map(|q| query_hash(conn, q)).transpose()?.unwrap_or_default();
+    let ids = result_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+    conn.execute(
+        "INSERT INTO search_events (tenant_id, kind, query_hash, latency_ms, result_count, zero_result, result_ids)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            tenant_id,
+            kind,
+            hash,
+            latency.as_secs_f64() * 1000.0,
+            result_ids.len() as i64,
+            result_ids.is_empty(),
+            ids
+        ],
+    )?;
+    Ok(())
+}
+
+/// Record a finished search when collection is enabled. `query_text` is
+/// `None` for vector-only searches; those are stored without a hash and
+/// never receive feedback. Failures are logged and never fail the search.
+pub(crate) fn record_search_event(kind: &str, query_text: Option<&str>, latency: Duration, result_ids: &[i64]) {
+    if !is_search_analytics_enabled() {
+        return;
+    }
+    let outcome = get_connection()
+        .map_err(|e| e.to_string())
+        .and_then(|conn| {
+            insert_event(&conn, &active_tenant(), kind, query_text, latency, result_ids).map_err(|e| e.to_string())
+        });
+    if let Err(e) = outcome {
+        warn!("[search_analytics] Failed to record search: {}", e);
+    }
+}
+
+fn insert_feedback(
+    conn: &Connection,
+    tenant_id: &str,
+    query_text: &str,
+    chunk_id: i64,
+    action: SearchFeedbackAction,
+) -> rusqlite::Result<bool> {
+    if query_text.trim().is_empty() {
+        return Ok(false);
+    }
+    let event: Option<(i64, String)> = conn
+        .query_row(
+            "SELECT id, result_ids FROM search_events WHERE tenant_id = ?1 AND query_hash = ?2
+             ORDER BY id DESC LIMIT 1",
+            params![tenant_id, query_hash(conn, query_text)?],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some((event_id, result_ids)) = event else {
+        return Ok(false);
+    };
+    let rank = result_ids
+        .split(',')
+        .position(|id| id == chunk_id.to_string())
+        .map(|i| i as u32 + 1);
+    conn.execute(
+        "INSERT INTO search_feedback (event_id, chunk_id, action, rank) VALUES (?1, ?2, ?3, ?4)",
+        params![event_id, chunk_id, action.as_str(), rank],
+    )?;
+    Ok(true)
+}
+
+/// Report that the user clicked or accepted `chunk_id` from the results of
+/// `query_text`. Attaches to the latest recorded search for that query in
+/// the active tenant; returns `false` when there is none (e.g. collection
+/// was disabled at search time).
+pub fn report_search_feedback(
+    query_text: String,
+    chunk_id: i64,
+    action: SearchFeedbackAction,
+) -> Result<bool, RagError> {
+    if !is_search_analytics_enabled() {
+        return Ok(false);
+    }
+    let conn = get_connection()?;
+    insert_feedback(&conn, &active_tenant(), &query_text, chunk_id, action).map_err(db_error)
+}
+
+fn load_events(conn: &Connection, tenant_id: &str) -> rusqlite::Result<Vec<ExportedEvent>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, tenant_id, kind, query_hash, latency_ms, result_count, zero_result, result_ids, created_at
+         FROM search_events WHERE tenant_id = ?1 ORDER BY id",
+    )?;
+    let mut events: Vec<ExportedEvent> = stmt
+        .query_map(params![tenant_id], |row| {
+            let ids: String = row.get(7)?;
+            let hash: String = row.get(3)?;
+            Ok(ExportedEvent {
+                id: row.get(0)?,
+                tenant_id: row.get(1)?,
+                kind: row.get(2)?,
+                query_hash: Some(hash).filter(|h| !h.is_empty()),
+                latency_ms: row.get(4)?,
+                result_count: row.get(5)?,
+                zero_result: row.get(6)?,
+                result_ids: ids.split(',').filter_map(|id| id.parse().ok()).collect(),
+                created_at: row.get(8)?,
+                feedback: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT f.event_id, f.chunk_id, f.action, f.rank, f.created_at
+         FROM search_feedback f JOIN search_events e ON e.id = f.event_id
+         WHERE e.tenant_id = ?1 ORDER BY f.id",
+    )?;
+    let feedback = stmt.query_map(params![tenant_id], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            ExportedFeedback { chunk_id: row.get(1)?, action: row.get(2)?, rank: row.get(3)?, created_at: row.get(4)? },
+        ))
+    })?;
+    for item in feedback {
+        let (event_id, feedback) = item?;
+        if let Ok(i) = events.binary_search_by_key(&event_id, |e| e.id) {
+            events[i].feedback.push(feedback);
+        }
+    }
+    Ok(events)
+}
+
+/// Write the active tenant's recorded searches with their feedback to
+/// `path` as JSON Lines, one search per line. Returns the number written.
+pub fn export_search_analytics(path: String) -> Result<u32, RagError> {
+    let conn = get_connection()?;
+    let events = load_events(&conn, &active_tenant()).map_err(db_error)?;
+    let mut out = Vec::new();
+    for event in &events {
+        serde_json::to_writer(&mut out, event).map_err(|e| io_error(&path, e))?;
+        out.push(b'\n');
+    }
+    std::fs::File::create(&path)
+        .and_then(|mut file| file.write_all(&out))
+        .map_err(|e| io_error(&path, e))?;
+    info!("[search_analytics] Exported {} searches to {}", events.len(), path);
+    Ok(events.len() as u32)
+}
+
+/// Delete the recorded searches and feedback of `tenant_id`.
+pub(crate) fn delete_search_analytics(conn: &Connection, tenant_id: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "DELETE FROM search_feedback WHERE event_id IN (SELECT id FROM search_events WHERE tenant_id = ?1)",
+        params![tenant_id],
+    )?;
+    conn.execute("DELETE FROM search_events WHERE tenant_id = ?1", params![tenant_id])?;
+    Ok(())
+}
+
+/// Delete the active tenant's recorded searches and feedback.
+pub fn clear_search_analytics() -> Result<(), RagError> {
+    let tenant = active_tenant();
+    with_write_retry("clear_search_analytics", |conn| {
+        let tx = conn.transaction()?;
+        delete_search_analytics(&tx, &tenant)?;
+        tx.commit()?;
+        Ok(())
+    })?;
+    info!("[search_analytics] Cleared tenant {}", tenant);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feedback_attaches_to_latest_search_with_rank() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_search_analytics_tables(&conn).unwrap();
+        let ms = Duration::from_millis;
+        insert_event(&conn, "default", "hybrid", Some("Rust lifetimes"), ms(12), &[7, 3]).unwrap();
+        insert_event(&conn, "default", "hybrid", Some("rust  LIFETIMES"), ms(8), &[3, 9, 7]).unwrap();
+        insert_event(&conn, "default", "hybrid", Some("unknown topic"), ms(4), &[]).unwrap();
+        insert_event(&conn, "default", "chunks", None, ms(2), &[7]).unwrap();
+        insert_event(&conn, "other", "hybrid", Some("rust lifetimes"), ms(5), &[7]).unwrap();
+
+        assert!(insert_feedback(&conn, "default", "Rust lifetimes", 7, SearchFeedbackAction::Accepted).unwrap());
+        assert!(insert_feedback(&conn, "default", "rust lifetimes", 42, SearchFeedbackAction::Clicked).unwrap());
+        assert!(!insert_feedback(&conn, "default", "", 7, SearchFeedbackAction::Clicked).unwrap());
+        assert!(!insert_feedback(&conn, "third", "rust lifetimes", 7, SearchFeedbackAction::Clicked).unwrap());
+
+        let events = load_events(&conn, "default").unwrap();
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].query_hash, events[1].query_hash);
+        assert_eq!(events[0].query_hash.as_ref().map(String::len), Some(64));
+        assert!(events[0].feedback.is_empty());
+        let ranks: Vec<_> = events[1].feedback.iter().map(|f| (f.chunk_id, f.rank)).collect();
+        assert_eq!(ranks, vec![(7, Some(3)), (42, None)]);
+        assert!(events[2].zero_result && events[2].result_ids.is_empty());
+        assert_eq!(events[3].query_hash, None);
+
+        delete_search_analytics(&conn, "default").unwrap();
+        assert!(load_events(&conn, "default").unwrap().is_empty());
+        assert_eq!(load_events(&conn, "other").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_query_hash_is_salted_per_database() {
+        let a = Connection::open_in_memory().unwrap();
+        let b = Connection::open_in_memory().unwrap();
+        create_search_analytics_tables(&a).unwrap();
+        create_search_analytics_tables(&b).unwrap();
+        let first = query_hash(&a, "rust").unwrap();
+        create_search_analytics_tables(&a).unwrap();
+        assert_eq!(query_hash(&a, "rust").unwrap(), first);
+        assert_ne!(query_hash(&b, "rust").unwrap(), first);
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Lightweight per-search tracing.
+//!
+//! When enabled, each stage of a hybrid search (vector search, BM25,
+//! filter SQL, fusion, content fetch) records a span that is logged and
+//! returned alongside the results by `search_hybrid_traced`. Disabled by
+//! default; the cost when off is a single atomic load per search.
+//!
+//! `SearchDiagnostics` is the fixed-shape variant for tuning: stage times
+//! plus candidate counts, requested per call by
+//! `HybridSearchOptions::with_diagnostics` regardless of the tracing switch.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use log::debug;
+
+static TRACING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone)]
+pub struct SearchSpan {
+    /// Stage name, e.g. "vector_search" or "content_fetch".
+    pub name: String,
+    pub duration_ms: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchTrace {
+    pub spans: Vec<SearchSpan>,
+    pub total_ms: f64,
+}
+
+/// Stage timings and candidate counts of one hybrid search.
+#[derive(Debug, Clone, Default)]
+pub struct SearchDiagnostics {
+    pub vector_ms: f64,
+    pub bm25_ms: f64,
+    /// Filter SQL, or the exact scan over filtered sources.
+    pub filter_ms: f64,
+    pub fusion_ms: f64,
+    pub fetch_ms: f64,
+    pub total_ms: f64,
+    /// Candidates requested from each retriever (`top_k` times the multiplier).
+    pub candidate_k: u32,
+    /// Raw vector and BM25 candidates, before filtering.
+    pub vector_candidates: u32,
+    pub bm25_candidates: u32,
+    /// Candidates left after filtering (or found by the exact scan).
+    pub filtered_vector_candidates: u32,
+    pub filtered_bm25_candidates: u32,
+    /// Whether vector candidates came from an exact scan of the filtered
+    /// sources instead of the HNSW index.
+    pub exact_scan: bool,
+    /// Whether vector candidates came from the HNSW graph restricted to
+    /// the filtered chunk ids.
+    pub graph_filter: bool,
+    pub hnsw_loaded: bool,
+}
+
+/// Enable or disable search tracing (debug builds / field diagnostics).
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_search_tracing(enabled: bool) {
+    TRACING_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_search_tracing_enabled() -> bool {
+    TRACING_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Collects spans for one search; a no-op when tracing is disabled and
+/// no diagnostics were asked for.
+pub(crate) struct SearchTracer {
+    enabled: bool,
+    started: Instant,
+    spans: Vec<SearchSpan>,
+    diagnostics: Option<SearchDiagnostics>,
+}
+
+impl SearchTracer {
+    pub(crate) fn new() -> Self {
+        Self { enabled: is_search_tracing_enabled(), started: Instant::now(), spans: Vec::new(), diagnostics: None }
+    }
+
+    /// A tracer that also fills `SearchDiagnostics` when `enabled`.
+    pub(crate) fn with_diagnostics(enabled: bool) -> Self {
+        Self { diagnostics: enabled.then(SearchDiagnostics::default), ..Self::new() }
+    }
+
+    pub(crate) fn record(&mut self, name: &str, duration: Duration) {
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        if let Some(diagnostics) = &mut self.diagnostics {
+            match name {
+                "vector_search" => diagnostics.vector_ms = duration_ms,
+                "bm25_search" => diagnostics.bm25_ms = duration_ms,
+                "filter_sql" => diagnostics.filter_ms = duration_ms,
+                "fusion" => diagnostics.fusion_ms = duration_ms,
+                "content_fetch" => diagnostics.fetch_ms = duration_ms,
+                _ => {}
+            }
+        }
+        if !self.enabled {
+            return;
+        }
+        debug!("[trace] {} took {:.2}ms", name, duration_ms);
+        self.spans.push(SearchSpan { name: name.to_string(), duration_ms });
+    }
+
+    /// Update the diagnostics, if they were asked for.
+    pub(crate) fn diagnose(&mut self, update: impl FnOnce(&mut SearchDiagnostics)) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            update(diagnostics);
+        }
+    }
+
+    /// The diagnostics so far, with `total_ms` up to now.
+    pub(crate) fn diagnostics(&self) -> Option<SearchDiagnostics> {
+        self.diagnostics.clone().map(|mut diagnostics| {
+            diagnostics.total_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+            diagnostics
+        })
+    }
+
+    /// The collected trace, or `None` when tracing was disabled.
+    pub(crate) fn finish(self) -> Option<SearchTrace> {
+        self.enabled.then(|| SearchTrace {
+            spans: self.spans,
+            total_ms: self.started.elapsed().as_secs_f64() * 1000.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracer_records_only_when_enabled() {
+        let mut off = SearchTracer { enabled: false, started: Instant::now(), spans: Vec::new(), diagnostics: None };
+        off.record("fusion", Duration::from_millis(2));
+        off.diagnose(|d| d.exact_scan = true);
+        assert!(off.diagnostics().is_none());
+        assert!(off.finish().is_none());
+
+        let mut on = SearchTracer { enabled: true, started: Instant::now(), spans: Vec::new(), diagnostics: None };
+        on.record("fusion", Duration::from_millis(2));
+        let trace = on.finish().unwrap();
+        assert_eq!(trace.spans.len(), 1);
+        assert!((trace.spans[0].duration_ms - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_diagnostics_collect_stage_times_without_tracing() {
+        let mut tracer = SearchTracer { enabled: false, ..SearchTracer::with_diagnostics(true) };
+        tracer.record("bm25_search", Duration::from_millis(3));
+        tracer.record("content_fetch", Duration::from_millis(1));
+        tracer.diagnose(|d| d.vector_candidates = 40);
+        let diagnostics = tracer.diagnostics().unwrap();
+        assert!((diagnostics.bm25_ms - 3.0).abs() < f64::EPSILON);
+        assert!((diagnostics.fetch_ms - 1.0).abs() < f64::EPSILON);
+        assert_eq!(diagnostics.vector_candidates, 40);
+        assert!(tracer.finish().is_none());
+    }
+}
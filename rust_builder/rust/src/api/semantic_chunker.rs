@@ -16,7 +16,19 @@
 //
 //! Semantic text chunking with paragraph-first strategy for multilingual support.
 
-use text_splitter::TextSplitter;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use text_splitter::{ChunkConfig, ChunkSizer, TextSplitter};
+
+use crate::api::bm25_search::is_cjk_or_hangul;
+use crate::api::classification_rules::classify_with_rules;
+use crate::api::corpus_report::CorpusCount;
+use crate::api::error::RagError;
+use crate::api::language_detect::detect_language_code;
+use crate::api::sentence_segmenter::{sentence_spans, split_sentences};
+use crate::api::tokenizer::count_tokens;
 
 /// Chunk type classification.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -53,22 +65,27 @@ impl ChunkType {
     }
 }
 
+/// Whether `line` starts a bullet or numbered list item.
+fn is_list_item(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with("•") || trimmed.starts_with("●")
+        || trimmed.starts_with("-") || trimmed.starts_with("*")
+        || trimmed.starts_with("①") || trimmed.starts_with("②")
+        || trimmed.starts_with("③") || trimmed.starts_with("④")
+        || (trimmed.len() > 2 && trimmed.chars().next().is_some_and(|c| c.is_numeric())
+            && (trimmed.chars().nth(1) == Some('.') || trimmed.chars().nth(1) == Some(')')))
+}
+
 /// Classify chunk by rule-based pattern matching.
 #[flutter_rust_bridge::frb(sync)]
 pub fn classify_chunk(text: &str) -> ChunkType {
+    if let Some(chunk_type) = classify_with_rules(text) {
+        return chunk_type;
+    }
     let text_lower = text.to_lowercase();
     
     // List detection
-    let bullet_count = text.lines()
-        .filter(|l| {
-            let trimmed = l.trim();
-            trimmed.starts_with("•") || trimmed.starts_with("●")
-                || trimmed.starts_with("-") || trimmed.starts_with("*")
-                || trimmed.starts_with("①") || trimmed.starts_with("②")
-                || trimmed.starts_with("③") || trimmed.starts_with("④")
-                || (trimmed.len() > 2 && trimmed.chars().next().map_or(false, |c| c.is_numeric()) 
-                    && (trimmed.chars().nth(1) == Some('.') || trimmed.chars().nth(1) == Some(')')))
-        }).count();
+    let bullet_count = text.lines().filter(|l| is_list_item(l)).count();
     if bullet_count >= 3 { return ChunkType::List; }
     
     // Definition patterns
@@ -102,36 +119,55 @@ pub fn classify_chunk(text: &str) -> ChunkType {
 pub struct SemanticChunk {
     pub index: i32,
     pub content: String,
+    /// Char offset of `content` in the source text.
     pub start_pos: i32,
+    /// Char offset one past the end of `content`.
     pub end_pos: i32,
     pub chunk_type: String,
+    /// Dominant language code ("ko", "en", ...; see `detect_language`).
+    pub language: String,
 }
 
 /// Split text into semantic chunks using paragraph-first strategy.
 #[flutter_rust_bridge::frb(sync)]
 pub fn semantic_chunk(text: String, max_chars: i32) -> Vec<SemanticChunk> {
+    let config = ChunkingConfig { max_chars, ..ChunkingConfig::default() };
+    paragraph_chunk(&text, &config)
+}
+
+fn paragraph_chunk(text: &str, config: &ChunkingConfig) -> Vec<SemanticChunk> {
     if text.is_empty() { return vec![]; }
     
-    let max_chars_usize = max_chars.max(100) as usize;
+    let max_chars_usize = config.effective_max_chars();
     let mut chunks = Vec::new();
-    let mut current_pos = 0i32;
     let mut chunk_index = 0i32;
     
-    let paragraphs: Vec<&str> = text.split("\n\n").collect();
+    let paragraphs = group_list_intros(text.split("\n\n").collect(), config.keep_list_intro);
     
-    for para in paragraphs {
+    for para in &paragraphs {
         let para_trimmed = para.trim();
         if para_trimmed.is_empty() { continue; }
         
-        if para_trimmed.len() <= max_chars_usize {
+        let has_inner_title = para_trimmed.lines().skip(1).any(|l| is_article_title(l.trim()));
+        if para_trimmed.len() <= max_chars_usize && !has_inner_title {
             let chunk_type = classify_chunk(para_trimmed);
             chunks.push(SemanticChunk {
                 index: chunk_index, content: para_trimmed.to_string(),
-                start_pos: current_pos, end_pos: current_pos + para_trimmed.len() as i32,
+                start_pos: 0, end_pos: 0,
                 chunk_type: chunk_type.as_str().to_string(),
+                language: detect_language_code(para_trimmed).to_string(),
             });
             chunk_index += 1;
-            current_pos += para_trimmed.len() as i32 + 1;
+        } else if let Some(pieces) = split_list(para_trimmed, max_chars_usize, config) {
+            for piece in pieces {
+                chunks.push(SemanticChunk {
+                    index: chunk_index, content: piece.clone(),
+                    start_pos: 0, end_pos: 0,
+                    chunk_type: ChunkType::List.as_str().to_string(),
+                    language: detect_language_code(&piece).to_string(),
+                });
+                chunk_index += 1;
+            }
         } else {
             let lines: Vec<&str> = para_trimmed.split('\n').collect();
             let mut line_buffer = String::new();
@@ -152,29 +188,28 @@ pub fn semantic_chunk(text: String, max_chars: i32) -> Vec<SemanticChunk> {
                         let chunk_type = classify_chunk(&line_buffer);
                         chunks.push(SemanticChunk {
                             index: chunk_index, content: line_buffer.clone(),
-                            start_pos: current_pos, end_pos: current_pos + line_buffer.len() as i32,
+                            start_pos: 0, end_pos: 0,
                             chunk_type: chunk_type.as_str().to_string(),
+                            language: detect_language_code(&line_buffer).to_string(),
                         });
                         chunk_index += 1;
-                        current_pos += line_buffer.len() as i32 + 1;
                         line_buffer.clear();
                     }
                     
                     if line_trimmed.len() <= max_chars_usize {
                         line_buffer.push_str(line_trimmed);
                     } else {
-                        let splitter = TextSplitter::new(max_chars_usize);
-                        for sub_chunk in splitter.chunks(line_trimmed) {
+                        for sub_chunk in split_by_sentences(line_trimmed, max_chars_usize, &config.sentence_terminators) {
                             let sub_chunk_trimmed = sub_chunk.trim();
                             if !sub_chunk_trimmed.is_empty() {
                                 let chunk_type = classify_chunk(sub_chunk_trimmed);
                                 chunks.push(SemanticChunk {
                                     index: chunk_index, content: sub_chunk_trimmed.to_string(),
-                                    start_pos: current_pos, end_pos: current_pos + sub_chunk_trimmed.len() as i32,
+                                    start_pos: 0, end_pos: 0,
                                     chunk_type: chunk_type.as_str().to_string(),
+                                    language: detect_language_code(sub_chunk_trimmed).to_string(),
                                 });
                                 chunk_index += 1;
-                                current_pos += sub_chunk_trimmed.len() as i32;
                             }
                         }
                     }
@@ -185,53 +220,357 @@ pub fn semantic_chunk(text: String, max_chars: i32) -> Vec<SemanticChunk> {
                 let chunk_type = classify_chunk(&line_buffer);
                 chunks.push(SemanticChunk {
                     index: chunk_index, content: line_buffer.clone(),
-                    start_pos: current_pos, end_pos: current_pos + line_buffer.len() as i32,
+                    start_pos: 0, end_pos: 0,
                     chunk_type: chunk_type.as_str().to_string(),
+                    language: detect_language_code(&line_buffer).to_string(),
                 });
                 chunk_index += 1;
-                current_pos += line_buffer.len() as i32 + 2;
             }
         }
     }
     
+    anchor_semantic_chunks(text, &mut chunks);
     chunks
 }
 
-#[allow(dead_code)]
-fn is_article_title(_line: &str) -> bool { false }
+/// Smallest accepted `max_tokens` for `semantic_chunk_by_tokens`.
+const MIN_CHUNK_TOKENS: usize = 16;
+
+/// Token count estimate for when no tokenizer is loaded: one token per
+/// CJK/Hangul glyph or symbol, and one per 4 characters of other words.
+/// Errs high so chunks still fit the model's context.
+pub(crate) fn estimate_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut word_len = 0usize;
+    for c in text.chars() {
+        if c.is_alphanumeric() && !is_cjk_or_hangul(c) {
+            word_len += 1;
+            continue;
+        }
+        tokens += word_len.div_ceil(4);
+        word_len = 0;
+        if !c.is_whitespace() {
+            tokens += 1;
+        }
+    }
+    tokens + word_len.div_ceil(4)
+}
+
+/// Sizes chunks with the loaded tokenizer, or `estimate_tokens` without one.
+struct TokenSizer {
+    exact: bool,
+}
+
+impl ChunkSizer for TokenSizer {
+    fn size(&self, chunk: &str) -> usize {
+        if self.exact {
+            if let Some(count) = count_tokens(chunk) {
+                return count;
+            }
+        }
+        estimate_tokens(chunk)
+    }
+}
+
+/// Split text into chunks of at most `max_tokens` tokens (special tokens
+/// excluded), preferring paragraph, then sentence, then word boundaries.
+///
+/// Counts come from the tokenizer passed to `init_tokenizer`, so CJK and
+/// Latin text fill the embedding context equally well; without one a
+/// conservative estimate is used.
+#[flutter_rust_bridge::frb(sync)]
+pub fn semantic_chunk_by_tokens(text: String, max_tokens: i32) -> Vec<SemanticChunk> {
+    let max_tokens = (max_tokens.max(0) as usize).max(MIN_CHUNK_TOKENS);
+    let sizer = TokenSizer { exact: count_tokens("").is_some() };
+    let splitter = TextSplitter::new(ChunkConfig::new(max_tokens).with_sizer(sizer));
+    let chars = CharIndex::new(&text);
+    splitter
+        .chunk_indices(&text)
+        .enumerate()
+        .map(|(index, (offset, content))| SemanticChunk {
+            index: index as i32,
+            content: content.to_string(),
+            start_pos: chars.char_at(offset),
+            end_pos: chars.char_at(offset + content.len()),
+            chunk_type: classify_chunk(content).as_str().to_string(),
+            language: detect_language_code(content).to_string(),
+        })
+        .collect()
+}
+
+/// Korean statute articles (`제3조`, `제3조의2`), matched by default.
+const DEFAULT_SECTION_PATTERNS: &[&str] = &[r"제\s*\d+\s*조(?:의\s*\d+)?"];
+
+/// Header level given to section titles in `markdown_chunk`, below `######`.
+const SECTION_TITLE_LEVEL: i32 = 7;
+
+/// Section-title patterns as registered, with their line-anchored regexes.
+static SECTION_PATTERNS: Lazy<RwLock<Vec<(String, Regex)>>> = Lazy::new(|| {
+    RwLock::new(compile_section_patterns(DEFAULT_SECTION_PATTERNS).expect("default section patterns compile"))
+});
+
+fn compile_section_patterns<S: AsRef<str>>(patterns: &[S]) -> Result<Vec<(String, Regex)>, RagError> {
+    patterns
+        .iter()
+        .map(|p| p.as_ref().trim())
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            Regex::new(&format!("^(?:{})", p))
+                .map(|re| (p.to_string(), re))
+                .map_err(|e| RagError::InvalidInput(format!("Invalid section pattern '{}': {}", p, e)))
+        })
+        .collect()
+}
+
+/// The section title `line` starts with, if any.
+fn match_section_title<'a>(patterns: &[(String, Regex)], line: &'a str) -> Option<&'a str> {
+    patterns.iter().find_map(|(_, re)| re.find(line)).map(|m| m.as_str().trim())
+}
+
+/// Register regex patterns for section titles (e.g. `제\d+조`, `Article \d+`,
+/// `Section \d+`). A line starting with a match begins a new chunk in both
+/// `semantic_chunk` and `markdown_chunk`, and becomes part of the header
+/// path (the matched text) in the latter. Replaces the current patterns; an empty list turns
+/// detection off. Defaults to Korean statute articles.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_section_patterns(patterns: Vec<String>) -> Result<(), RagError> {
+    let compiled = compile_section_patterns(&patterns)?;
+    *SECTION_PATTERNS.write().unwrap() = compiled;
+    Ok(())
+}
+
+/// Currently registered section-title patterns.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_section_patterns() -> Vec<String> {
+    SECTION_PATTERNS.read().unwrap().iter().map(|(p, _)| p.clone()).collect()
+}
+
+fn section_title(line: &str) -> Option<String> {
+    match_section_title(&SECTION_PATTERNS.read().unwrap(), line).map(str::to_string)
+}
 
-fn tail_chars(text: &str, count: usize) -> String {
-    if count == 0 || text.is_empty() {
-        return String::new();
+fn is_article_title(line: &str) -> bool {
+    section_title(line).is_some()
+}
+
+/// Maps between byte offsets and char offsets of one text.
+pub(crate) struct CharIndex {
+    /// Byte offset of every char, in order.
+    boundaries: Vec<usize>,
+    len: usize,
+}
+
+impl CharIndex {
+    pub(crate) fn new(text: &str) -> Self {
+        CharIndex { boundaries: text.char_indices().map(|(i, _)| i).collect(), len: text.len() }
     }
-    let total_chars = text.chars().count();
-    if total_chars <= count {
-        return text.to_string();
+
+    /// Char offset of the char starting at (or containing) `byte`.
+    pub(crate) fn char_at(&self, byte: usize) -> i32 {
+        self.boundaries.partition_point(|&b| b < byte) as i32
+    }
+
+    /// Byte offset of char `pos`; past-the-end positions map to the text length.
+    pub(crate) fn byte_at(&self, pos: i32) -> usize {
+        self.boundaries.get(pos.max(0) as usize).copied().unwrap_or(self.len)
     }
-    text.chars().skip(total_chars - count).collect()
 }
 
-/// Split text with overlap (API compatibility wrapper).
+/// Finds chunk contents in the source text, in order.
+///
+/// Chunkers trim lines and rejoin them, so contents are matched ignoring
+/// whitespace. Each match moves the cursor forward, keeping repeated
+/// passages tied to the right occurrence.
+struct SpanLocator<'a> {
+    text: &'a str,
+    cursor: usize,
+}
+
+impl<'a> SpanLocator<'a> {
+    fn new(text: &'a str) -> Self {
+        SpanLocator { text, cursor: 0 }
+    }
+
+    /// Byte span of `content` at or after the cursor.
+    fn find(&mut self, content: &str) -> Option<(usize, usize)> {
+        let mut needle = content.chars().filter(|c| !c.is_whitespace());
+        let first = needle.next()?;
+        let rest: Vec<char> = needle.collect();
+        for (start, c) in self.text[self.cursor..].char_indices() {
+            if c != first {
+                continue;
+            }
+            let start = self.cursor + start;
+            let mut end = start + c.len_utf8();
+            let mut matched = 0;
+            for (offset, c) in self.text[end..].char_indices() {
+                if matched == rest.len() {
+                    break;
+                }
+                if c.is_whitespace() {
+                    continue;
+                }
+                if c != rest[matched] {
+                    break;
+                }
+                matched += 1;
+                end = start + first.len_utf8() + offset + c.len_utf8();
+            }
+            if matched == rest.len() {
+                self.cursor = end;
+                return Some((start, end));
+            }
+        }
+        None
+    }
+
+    /// Byte span of `content`, and whether it covers all of it. Chunks that
+    /// repeat context (table headers, list intros) match from their first
+    /// line that is not a repeat.
+    fn place(&mut self, content: &str) -> Option<((usize, usize), bool)> {
+        if let Some(span) = self.find(content) {
+            return Some((span, true));
+        }
+        let mut rest = content;
+        while let Some((_, tail)) = rest.split_once('\n') {
+            rest = tail;
+            if let Some(span) = self.find(rest) {
+                return Some((span, false));
+            }
+        }
+        None
+    }
+}
+
+/// Set `start_pos`/`end_pos` of `content` to its char span in `text`.
+/// Content that only lost indentation or line-end spaces is replaced with
+/// the exact slice, so `text[start_pos..end_pos] == content` (in chars)
+/// holds for every chunk that was not deliberately reshaped (a list intro
+/// joined to its items, a repeated table header).
+fn anchor_chunk(
+    text: &str,
+    index: &CharIndex,
+    locator: &mut SpanLocator,
+    content: &mut String,
+    start_pos: &mut i32,
+    end_pos: &mut i32,
+) {
+    if let Some(((start, end), exact)) = locator.place(content) {
+        let slice = &text[start..end];
+        if exact && slice.lines().count() == content.lines().count() {
+            *content = slice.to_string();
+        }
+        *start_pos = index.char_at(start);
+        *end_pos = index.char_at(end);
+    } else {
+        // Not found (rewritten content): point at the end of the last match
+        let at = index.char_at(locator.cursor);
+        *start_pos = at;
+        *end_pos = at;
+    }
+}
+
+fn anchor_semantic_chunks(text: &str, chunks: &mut [SemanticChunk]) {
+    let index = CharIndex::new(text);
+    let mut locator = SpanLocator::new(text);
+    for chunk in chunks {
+        anchor_chunk(text, &index, &mut locator, &mut chunk.content, &mut chunk.start_pos, &mut chunk.end_pos);
+    }
+}
+
+/// Anchor chunks taken from `text`; linearized table rows get the span of
+/// their table.
+fn anchor_structured_chunks(text: &str, chunks: &mut [StructuredChunk]) {
+    let index = CharIndex::new(text);
+    let mut locator = SpanLocator::new(text);
+    for chunk in chunks.iter_mut().filter(|c| c.chunk_type != "table_text") {
+        anchor_chunk(text, &index, &mut locator, &mut chunk.content, &mut chunk.start_pos, &mut chunk.end_pos);
+    }
+    let mut table_spans: HashMap<String, (i32, i32)> = HashMap::new();
+    for chunk in chunks.iter().filter(|c| c.chunk_type == "table") {
+        if let Some(batch_id) = &chunk.batch_id {
+            let span = table_spans.entry(batch_id.clone()).or_insert((chunk.start_pos, chunk.end_pos));
+            *span = (span.0.min(chunk.start_pos), span.1.max(chunk.end_pos));
+        }
+    }
+    for chunk in chunks.iter_mut().filter(|c| c.chunk_type == "table_text") {
+        if let Some(&(start, end)) = chunk.batch_id.as_ref().and_then(|id| table_spans.get(id)) {
+            chunk.start_pos = start;
+            chunk.end_pos = end;
+        }
+    }
+}
+
+/// Split text with overlap (API compatibility wrapper). `overlap_chars`
+/// counts characters, `max_chars` bytes.
 #[flutter_rust_bridge::frb(sync)]
 pub fn semantic_chunk_with_overlap(text: String, max_chars: i32, overlap_chars: i32) -> Vec<SemanticChunk> {
-    let base_chunks = semantic_chunk(text, max_chars);
-    let overlap = overlap_chars.max(0) as usize;
+    let chunks = semantic_chunk(text.clone(), max_chars);
+    with_overlap(&text, chunks, overlap_chars.max(0) as usize)
+}
+
+/// Size distribution of the chunks a config would produce.
+#[derive(Debug, Clone)]
+pub struct ChunkStats {
+    pub chunk_count: u32,
+    /// Sizes are in bytes, the unit `max_chars` limits.
+    pub min_size: u32,
+    pub max_size: u32,
+    pub avg_size: f64,
+    pub median_size: f64,
+    /// Chunk counts by `chunk_type`, most common first.
+    pub type_histogram: Vec<CorpusCount>,
+    /// Chunks longer than `max_chars` (unsplittable lines, overlap, tables).
+    pub oversize_count: u32,
+}
+
+/// Chunk `text` with `config` and report the size distribution without
+/// storing anything, for previewing chunker settings before embedding.
+#[flutter_rust_bridge::frb(sync)]
+pub fn analyze_chunks(text: String, config: ChunkingConfig) -> ChunkStats {
+    let max_chars = config.effective_max_chars();
+    let chunks = chunk_text(text, config);
+    let mut sizes: Vec<usize> = chunks.iter().map(|c| c.content.len()).collect();
+    sizes.sort_unstable();
+
+    let mut counts: HashMap<&str, u64> = HashMap::new();
+    for chunk in &chunks {
+        *counts.entry(chunk.chunk_type.as_str()).or_default() += 1;
+    }
+    let mut type_histogram: Vec<CorpusCount> =
+        counts.into_iter().map(|(key, count)| CorpusCount { key: key.to_string(), count }).collect();
+    type_histogram.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+
+    let n = sizes.len();
+    let median_size = match n {
+        0 => 0.0,
+        _ if n % 2 == 1 => sizes[n / 2] as f64,
+        _ => (sizes[n / 2 - 1] + sizes[n / 2]) as f64 / 2.0,
+    };
+    ChunkStats {
+        chunk_count: n as u32,
+        min_size: sizes.first().copied().unwrap_or(0) as u32,
+        max_size: sizes.last().copied().unwrap_or(0) as u32,
+        avg_size: if n == 0 { 0.0 } else { sizes.iter().sum::<usize>() as f64 / n as f64 },
+        median_size,
+        type_histogram,
+        oversize_count: sizes.iter().filter(|&&s| s > max_chars).count() as u32,
+    }
+}
 
+fn with_overlap(text: &str, base_chunks: Vec<SemanticChunk>, overlap: usize) -> Vec<SemanticChunk> {
     if overlap == 0 || base_chunks.len() <= 1 {
         return base_chunks;
     }
 
+    let index = CharIndex::new(text);
     let mut overlapped = Vec::with_capacity(base_chunks.len());
 
     for (i, base_chunk) in base_chunks.iter().enumerate() {
         let mut chunk = base_chunk.clone();
         if i > 0 {
-            let prefix = tail_chars(&base_chunks[i - 1].content, overlap);
-            if !prefix.is_empty() && !chunk.content.starts_with(&prefix) {
-                chunk.start_pos = (chunk.start_pos - prefix.len() as i32).max(0);
-                chunk.content = format!("{}\n{}", prefix, chunk.content);
-                chunk.end_pos = chunk.start_pos + chunk.content.len() as i32;
-            }
+            let prev = &base_chunks[i - 1];
+            extend_with_overlap(text, &index, (prev.start_pos, prev.end_pos), overlap, &mut chunk.content, &mut chunk.start_pos, chunk.end_pos);
         }
         overlapped.push(chunk);
     }
@@ -239,6 +578,31 @@ pub fn semantic_chunk_with_overlap(text: String, max_chars: i32, overlap_chars:
     overlapped
 }
 
+/// Start `content` up to `overlap` chars before the end of the previous
+/// chunk (`prev` span). Exact slices stay exact slices of `text`; other
+/// chunks get the previous chunk's tail prepended on its own line.
+fn extend_with_overlap(
+    text: &str,
+    index: &CharIndex,
+    prev: (i32, i32),
+    overlap: usize,
+    content: &mut String,
+    start_pos: &mut i32,
+    end_pos: i32,
+) {
+    let from = (prev.1 - overlap as i32).max(prev.0);
+    if from >= *start_pos {
+        return;
+    }
+    let slice = |a: i32, b: i32| &text[index.byte_at(a)..index.byte_at(b)];
+    *content = if slice(*start_pos, end_pos) == content.as_str() {
+        slice(from, end_pos).to_string()
+    } else {
+        format!("{}\n{}", slice(from, prev.1), content)
+    };
+    *start_pos = from;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,6 +648,155 @@ mod tests {
         assert!(chunks[1].content.contains("Second chunk starts here."));
     }
 
+    #[test]
+    fn test_chunks_carry_language() {
+        let text = "Smart contracts run on chain.\n\n스마트 계약은 블록체인에서 실행됩니다.";
+        let languages: Vec<String> = semantic_chunk(text.to_string(), 500).into_iter().map(|c| c.language).collect();
+        assert_eq!(languages, vec!["en", "ko"]);
+        let structured = markdown_chunk("# 소개\n\n스마트 계약은 블록체인에서 실행됩니다.".to_string(), 500);
+        assert!(structured.iter().all(|c| c.language == "ko"));
+    }
+
+    #[test]
+    fn test_estimate_tokens_counts_cjk_per_glyph() {
+        assert_eq!(estimate_tokens("hello world"), 4);
+        assert_eq!(estimate_tokens("한국어 문장."), 6);
+        assert_eq!(estimate_tokens(""), 0);
+    }
+
+    #[test]
+    fn test_semantic_chunk_by_tokens_respects_budget() {
+        // No tokenizer is loaded in unit tests, so the estimate applies.
+        let text = format!("{}\n\n{}", "검색 증강 생성은 문서를 나눈다. ".repeat(20), "Short English paragraph.");
+        let chunks = semantic_chunk_by_tokens(text.clone(), 32);
+        assert!(chunks.len() > 2);
+        for chunk in &chunks {
+            assert!(estimate_tokens(&chunk.content) <= 32);
+            assert_eq!(char_slice(&text, chunk.start_pos, chunk.end_pos), chunk.content);
+        }
+    }
+
+    fn char_slice(text: &str, start: i32, end: i32) -> String {
+        text.chars().skip(start as usize).take((end - start) as usize).collect()
+    }
+
+    #[test]
+    fn test_chunk_offsets_slice_source_text() {
+        let text = "# 개요\n\n  검색 증강 생성은 문서를 나눈다.\n  각 줄은 들여쓰기가 있다.\n\n## 표\n\n| 이름 | 값 |\n|---|---|\n| 가 | 1 |\n\n마지막 문단. Ünïcode ✓";
+        for strategy in [ChunkingStrategy::Recursive, ChunkingStrategy::Markdown] {
+            let config = ChunkingConfig { max_chars: 40, min_chars: 10, overlap: 8, strategy, ..ChunkingConfig::default() };
+            let chunks = chunk_text(text.to_string(), config);
+            assert!(chunks.len() > 2);
+            let mut last_start = 0;
+            for chunk in &chunks {
+                assert_eq!(char_slice(text, chunk.start_pos, chunk.end_pos), chunk.content, "{:?}", chunk);
+                assert!(chunk.start_pos >= last_start);
+                last_start = chunk.start_pos;
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunk_text_honors_config() {
+        let text = "첫 번째 문장이다。두 번째 문장이다。세 번째 문장이다。";
+        let config = ChunkingConfig {
+            max_chars: 30,
+            min_chars: 10,
+            sentence_terminators: vec!["。".to_string()],
+            ..ChunkingConfig::default()
+        };
+        let chunks = chunk_text(text.to_string(), config);
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.content.ends_with('。')));
+
+        // Defaults match the dedicated entry points.
+        let defaults = chunk_text(text.to_string(), ChunkingConfig::default());
+        assert_eq!(defaults.len(), semantic_chunk(text.to_string(), 500).len());
+    }
+
+    #[test]
+    fn test_chunk_text_markdown_without_table_preservation() {
+        let text = "# Data\n\n| Name | Value |\n|------|-------|\n| A | 1 |";
+        let config = ChunkingConfig { strategy: ChunkingStrategy::Markdown, ..ChunkingConfig::default() };
+        assert!(chunk_text(text.to_string(), config.clone()).iter().any(|c| c.chunk_type == "table"));
+        let config = ChunkingConfig { preserve_tables: false, ..config };
+        assert!(chunk_text(text.to_string(), config).iter().all(|c| c.chunk_type != "table"));
+    }
+
+    #[test]
+    fn test_section_titles_force_boundaries() {
+        let text = "제1조 (목적) 이 법은 목적을 정한다.\n제2조 (정의) 용어의 뜻은 다음과 같다.";
+        let chunks = semantic_chunk(text.to_string(), 500);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[1].content.starts_with("제2조"));
+
+        let structured = markdown_chunk(format!("# 민법\n\n{}", text), 500);
+        let second = structured.iter().find(|c| c.content.starts_with("제2조")).unwrap();
+        assert_eq!(second.header_path, "민법 > 제2조");
+    }
+
+    #[test]
+    fn test_custom_section_patterns() {
+        let patterns = compile_section_patterns(&["Article \\d+", " "]).unwrap();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(match_section_title(&patterns, "Article 12. Scope"), Some("Article 12"));
+        assert_eq!(match_section_title(&patterns, "See Article 12."), None);
+        assert!(compile_section_patterns(&["Section (\\d+"]).is_err());
+    }
+
+    #[test]
+    fn test_markdown_section_summaries() {
+        let text = "Preamble.\n\n# Install\n\nDownload the app. Open it. Sign in.\n\n```sh\nrun it\n```\n\n## Windows\n\nUse the MSI.\n\n# Usage\n\nSearch your notes.";
+        let config = ChunkingConfig {
+            strategy: ChunkingStrategy::Markdown,
+            section_summary_sentences: 2,
+            ..ChunkingConfig::default()
+        };
+        let chunks = chunk_text(text.to_string(), config);
+        let summaries: Vec<&StructuredChunk> = chunks.iter().filter(|c| c.chunk_type == "summary").collect();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].content, "Install\nDownload the app. Open it.");
+        assert_eq!(summaries[0].header_path, "Install");
+        assert_eq!(summaries[1].content, "Usage\nSearch your notes.");
+        // Each summary precedes its section's chunks
+        let first = chunks.iter().position(|c| c.chunk_type == "summary").unwrap();
+        assert!(chunks[first + 1].content.starts_with("# Install"));
+        assert!(chunks.iter().enumerate().all(|(i, c)| c.index == i as i32));
+        assert!(markdown_chunk(text.to_string(), 500).iter().all(|c| c.chunk_type != "summary"));
+    }
+
+    #[test]
+    fn test_analyze_chunks_reports_distribution() {
+        let text = "# A\n\nShort.\n\n| x | y |\n|---|---|\n| 1 | 2 |\n\n# B\n\nAnother paragraph here.";
+        let config = ChunkingConfig { strategy: ChunkingStrategy::Markdown, ..ChunkingConfig::default() };
+        let chunks = chunk_text(text.to_string(), config.clone());
+        let stats = analyze_chunks(text.to_string(), config);
+        assert_eq!(stats.chunk_count as usize, chunks.len());
+        assert_eq!(stats.min_size as usize, chunks.iter().map(|c| c.content.len()).min().unwrap());
+        assert_eq!((stats.type_histogram[0].key.as_str(), stats.type_histogram[0].count), ("text", 2));
+        assert_eq!(stats.type_histogram.iter().map(|c| c.count).sum::<u64>(), stats.chunk_count as u64);
+        assert_eq!(stats.oversize_count, 0);
+
+        let empty = analyze_chunks(String::new(), ChunkingConfig::default());
+        assert_eq!((empty.chunk_count, empty.median_size), (0, 0.0));
+    }
+
+    #[test]
+    fn test_keep_list_intro_splits_between_items() {
+        let items: Vec<String> = (1..=6).map(|i| format!("- Feature number {} does one thing well", i)).collect();
+        let text = format!("Features:\n\n{}", items.join("\n"));
+        let config = ChunkingConfig { max_chars: 100, keep_list_intro: true, ..ChunkingConfig::default() };
+        let chunks = chunk_text(text.clone(), config.clone());
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.content.starts_with("Features:\n- Feature"), "{}", chunk.content);
+            assert!(chunk.content.lines().skip(1).all(|l| items.contains(&l.to_string())));
+        }
+
+        let markdown = chunk_text(format!("# Spec\n\n{}", text), ChunkingConfig { strategy: ChunkingStrategy::Markdown, ..config });
+        assert!(markdown.iter().skip(1).all(|c| c.content.starts_with("Features:")));
+    }
+
     #[test]
     fn test_semantic_chunk_with_overlap_zero_overlap_is_noop() {
         let text = "First paragraph.\n\nSecond paragraph.";
@@ -306,11 +819,15 @@ pub struct StructuredChunk {
     pub content: String,
     pub header_path: String,   // e.g., "# Installation > ## Windows"
     pub chunk_type: String,    // "text", "code", "table", "header"
+    /// Char offset of `content` in the source text. Summary and
+    /// `table_text` chunks span the section or table they were built from.
     pub start_pos: i32,
+    /// Char offset one past the end of the chunk's span.
     pub end_pos: i32,
     pub batch_id: Option<String>,
     pub batch_index: Option<i32>,
     pub batch_total: Option<i32>,
+    pub language: String,          // see `SemanticChunk::language`
 }
 
 /// Chunking strategy for structure-aware chunking.
@@ -320,6 +837,103 @@ pub enum ChunkingStrategy {
     Markdown,    // Header-based with structure preservation
 }
 
+/// Tunable chunking behavior for `chunk_text`.
+///
+/// The defaults reproduce `semantic_chunk` / `markdown_chunk`.
+#[derive(Debug, Clone)]
+pub struct ChunkingConfig {
+    /// Upper bound on chunk length in bytes.
+    pub max_chars: i32,
+    /// Floor applied to `max_chars`, so tiny limits can't shred the text.
+    pub min_chars: i32,
+    /// Characters (not bytes, unlike `max_chars`) of the previous chunk's
+    /// tail prepended to each text chunk.
+    pub overlap: i32,
+    pub strategy: ChunkingStrategy,
+    /// Keep Markdown tables as units, repeating the header row when split.
+    pub preserve_tables: bool,
+    /// Keep fenced code blocks as units, linked by `batch_id` when split.
+    pub preserve_code: bool,
+    /// Strings that end a sentence when a paragraph must be split further.
+    /// Empty uses the Unicode-aware segmenter (see `sentence_segmenter`).
+    pub sentence_terminators: Vec<String>,
+    /// Markdown only: when > 0, emit a synthetic `"summary"` chunk per
+    /// top-level header holding the first N sentences of its section.
+    pub section_summary_sentences: u32,
+    /// Markdown only: also emit each table as `"Column: value"` sentences per
+    /// row (`"table_text"` chunks), linked to the raw table by `batch_id`.
+    pub linearize_tables: bool,
+    /// Split lists between items and repeat their introduction ("Features:")
+    /// in every piece, instead of splitting by line or sentence.
+    pub keep_list_intro: bool,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            max_chars: 500,
+            min_chars: 100,
+            overlap: 0,
+            strategy: ChunkingStrategy::Recursive,
+            preserve_tables: true,
+            preserve_code: true,
+            sentence_terminators: Vec::new(),
+            section_summary_sentences: 0,
+            linearize_tables: false,
+            keep_list_intro: false,
+        }
+    }
+}
+
+impl ChunkingConfig {
+    fn effective_max_chars(&self) -> usize {
+        self.max_chars.max(self.min_chars).max(1) as usize
+    }
+}
+
+/// Chunk text with the strategy and limits in `config`.
+///
+/// `Recursive` splits by paragraph, then line, then sentence; chunk types
+/// are the `classify_chunk` categories. `Markdown` follows `markdown_chunk`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn chunk_text(text: String, config: ChunkingConfig) -> Vec<StructuredChunk> {
+    let overlap = config.overlap.max(0) as usize;
+    match config.strategy {
+        ChunkingStrategy::Recursive => with_overlap(&text, paragraph_chunk(&text, &config), overlap)
+            .into_iter()
+            .map(|c| StructuredChunk {
+                index: c.index,
+                content: c.content,
+                header_path: String::new(),
+                chunk_type: c.chunk_type,
+                start_pos: c.start_pos,
+                end_pos: c.end_pos,
+                batch_id: None,
+                batch_index: None,
+                batch_total: None,
+                language: c.language,
+            })
+            .collect(),
+        ChunkingStrategy::Markdown => {
+            let mut chunks = structured_chunk(&text, &config);
+            if overlap > 0 {
+                let index = CharIndex::new(&text);
+                let spans: Vec<(i32, i32)> = chunks.iter().map(|c| (c.start_pos, c.end_pos)).collect();
+                for i in 1..chunks.len() {
+                    let (prev, rest) = chunks.split_at_mut(i);
+                    let (prev, chunk) = (&prev[i - 1], &mut rest[0]);
+                    // Only prose continues across chunks; code and tables stay verbatim.
+                    if chunk.chunk_type != "text" || prev.chunk_type != "text" || prev.header_path != chunk.header_path {
+                        continue;
+                    }
+                    extend_with_overlap(&text, &index, spans[i - 1], overlap, &mut chunk.content, &mut chunk.start_pos, chunk.end_pos);
+                }
+            }
+            chunks
+        }
+    }
+}
+
 /// Markdown chunk with structure preservation and metadata inheritance.
 /// 
 /// - Splits by Markdown headers (#, ##, ###)
@@ -328,23 +942,31 @@ pub enum ChunkingStrategy {
 /// - Inherits header path as metadata
 #[flutter_rust_bridge::frb(sync)]
 pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
+    let config = ChunkingConfig { max_chars, strategy: ChunkingStrategy::Markdown, ..ChunkingConfig::default() };
+    structured_chunk(&text, &config)
+}
+
+fn structured_chunk(text: &str, config: &ChunkingConfig) -> Vec<StructuredChunk> {
     if text.is_empty() {
         return vec![];
     }
 
-    let max_chars_usize = max_chars.max(100) as usize;
+    let max_chars_usize = config.effective_max_chars();
     let mut chunks = Vec::new();
-    let mut current_pos = 0i32;
     let mut chunk_index = 0i32;
 
     // Track header hierarchy for breadcrumbs
     let mut header_stack: Vec<(i32, String)> = vec![]; // (level, header_text)
 
     // First, identify and protect structural blocks
-    let protected = protect_structural_blocks(&text);
+    let protected = protect_structural_blocks(text);
 
     // Split by headers
-    let sections = split_by_headers(&protected.text);
+    let sections = split_by_headers(&protected.text, config.preserve_code, config.preserve_tables);
+
+    // Sections under the shallowest header level get a summary chunk
+    let top_level = sections.iter().filter_map(|s| s.header.as_ref().map(|h| h.0)).min();
+    let mut summaries: Vec<SectionSummary> = vec![];
 
     for section in sections {
         // Update header stack based on section header
@@ -363,6 +985,28 @@ pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
             .collect::<Vec<_>>()
             .join(" > ");
 
+        if config.section_summary_sentences > 0 {
+            match &section.header {
+                Some((level, header_text)) if Some(*level) == top_level => {
+                    summaries.push(SectionSummary {
+                        insert_at: chunks.len(),
+                        header: header_text.clone(),
+                        header_path: header_path.clone(),
+                        prose: String::new(),
+                    });
+                }
+                _ => {}
+            }
+            if let Some(summary) = summaries.last_mut() {
+                if !section.is_code_block && !section.is_table {
+                    for line in section.content.lines().filter(|l| !l.starts_with('#')) {
+                        summary.prose.push_str(line);
+                        summary.prose.push('\n');
+                    }
+                }
+            }
+        }
+
         let content = section.content.trim();
         if content.is_empty() {
             continue;
@@ -382,6 +1026,8 @@ pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
             "text".to_string()
         };
 
+        let first_chunk_of_section = chunks.len();
+
         // Check if content needs recursive splitting
         if content.len() <= max_chars_usize {
             chunks.push(StructuredChunk {
@@ -389,14 +1035,14 @@ pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
                 content: content.to_string(),
                 header_path: header_path.clone(),
                 chunk_type: chunk_type.to_string(),
-                start_pos: current_pos,
-                end_pos: current_pos + content.len() as i32,
+                start_pos: 0,
+                end_pos: 0,
                 batch_id: None,
                 batch_index: None,
                 batch_total: None,
+                language: detect_language_code(content).to_string(),
             });
             chunk_index += 1;
-            current_pos += content.len() as i32 + 1;
         } else {
             // Structure-aware splitting for large sections
             let sub_chunks = if section.is_table {
@@ -404,7 +1050,7 @@ pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
             } else if section.is_code_block {
                  split_by_lines(content, max_chars_usize)
             } else {
-                 recursive_split(content, max_chars_usize)
+                 recursive_split(content, max_chars_usize, config)
             };
 
             // Generate batch linking metadata for code blocks
@@ -428,21 +1074,106 @@ pub fn markdown_chunk(text: String, max_chars: i32) -> Vec<StructuredChunk> {
                     content: sub.clone(),
                     header_path: header_path.clone(),
                     chunk_type: sub_type,
-                    start_pos: current_pos,
-                    end_pos: current_pos + sub.len() as i32,
+                    start_pos: 0,
+                    end_pos: 0,
                     batch_id: batch_id.clone(),
                     batch_index: batch_id.as_ref().map(|_| i as i32),
                     batch_total: batch_id.as_ref().map(|_| total_chunks as i32),
+                    language: detect_language_code(sub).to_string(),
                 });
                 chunk_index += 1;
-                current_pos += sub.len() as i32 + 1;
+            }
+        }
+
+        if section.is_table && config.linearize_tables && chunks.len() > first_chunk_of_section {
+            let rows = linearize_table(content);
+            if !rows.is_empty() {
+                for text in split_by_lines(&rows.join("\n"), max_chars_usize) {
+                    chunks.push(StructuredChunk {
+                        index: chunk_index,
+                        language: detect_language_code(&text).to_string(),
+                        content: text,
+                        header_path: header_path.clone(),
+                        chunk_type: "table_text".to_string(),
+                        // Spans its table; set by `anchor_structured_chunks`
+                        start_pos: 0,
+                        end_pos: 0,
+                        batch_id: None,
+                        batch_index: None,
+                        batch_total: None,
+                    });
+                    chunk_index += 1;
+                }
+                // Raw and linearized chunks of one table form a batch
+                let batch_id = uuid::Uuid::new_v4().to_string();
+                let total = (chunks.len() - first_chunk_of_section) as i32;
+                for (i, chunk) in chunks[first_chunk_of_section..].iter_mut().enumerate() {
+                    chunk.batch_id = Some(batch_id.clone());
+                    chunk.batch_index = Some(i as i32);
+                    chunk.batch_total = Some(total);
+                }
             }
         }
     }
 
+    anchor_structured_chunks(text, &mut chunks);
+
+    if !summaries.is_empty() {
+        // A summary spans its section's chunks
+        let mut section_end = chunks.len();
+        let mut spans = vec![];
+        for summary in summaries.iter().rev() {
+            let section = &chunks[summary.insert_at..section_end];
+            spans.push(match (section.first(), section.iter().map(|c| c.end_pos).max()) {
+                (Some(first), Some(end)) => (first.start_pos, end),
+                _ => (0, 0),
+            });
+            section_end = summary.insert_at;
+        }
+        for (summary, (start_pos, end_pos)) in summaries.into_iter().rev().zip(spans) {
+            let sentences = split_sentences(&summary.prose);
+            if sentences.is_empty() {
+                continue;
+            }
+            let lead = sentences.iter().take(config.section_summary_sentences as usize).cloned().collect::<Vec<_>>();
+            let mut content = format!("{}\n{}", summary.header, lead.join(" "));
+            if content.len() > max_chars_usize {
+                let mut cut = max_chars_usize;
+                while !content.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                content.truncate(cut);
+            }
+            chunks.insert(summary.insert_at, StructuredChunk {
+                index: 0,
+                language: detect_language_code(&content).to_string(),
+                content,
+                header_path: summary.header_path,
+                chunk_type: "summary".to_string(),
+                start_pos,
+                end_pos,
+                batch_id: None,
+                batch_index: None,
+                batch_total: None,
+            });
+        }
+        for (i, chunk) in chunks.iter_mut().enumerate() {
+            chunk.index = i as i32;
+        }
+    }
+
     chunks
 }
 
+/// Lead sentences of a top-level section, collected for its summary chunk.
+struct SectionSummary {
+    /// Position in the chunk list where the section's chunks start.
+    insert_at: usize,
+    header: String,
+    header_path: String,
+    prose: String,
+}
+
 // =============================================================================
 // Helper structures and functions
 // =============================================================================
@@ -469,7 +1200,7 @@ fn protect_structural_blocks(text: &str) -> ProtectedText {
 }
 
 /// Split text by markdown headers while preserving code blocks and tables.
-fn split_by_headers(text: &str) -> Vec<Section> {
+fn split_by_headers(text: &str, preserve_code: bool, preserve_tables: bool) -> Vec<Section> {
     let mut sections: Vec<Section> = vec![];
     let mut current_content = String::new();
     let mut current_header: Option<(i32, String)> = None;
@@ -480,7 +1211,7 @@ fn split_by_headers(text: &str) -> Vec<Section> {
 
     for line in text.lines() {
         // Check for code block start/end
-        if line.trim().starts_with("```") {
+        if preserve_code && line.trim().starts_with("```") {
             if in_code_block {
                 // End of code block
                 code_block_content.push_str(line);
@@ -526,7 +1257,7 @@ fn split_by_headers(text: &str) -> Vec<Section> {
         }
 
         // Check for table (lines starting with |)
-        let is_table_line = line.trim().starts_with('|') && line.trim().ends_with('|');
+        let is_table_line = preserve_tables && line.trim().starts_with('|') && line.trim().ends_with('|');
         if is_table_line {
             if !in_table {
                 // Save current content first
@@ -558,8 +1289,9 @@ fn split_by_headers(text: &str) -> Vec<Section> {
             in_table = false;
         }
 
-        // Check for header
-        if line.starts_with('#') {
+        // Check for header (Markdown or a registered section title)
+        let title = if line.starts_with('#') { None } else { section_title(line.trim()) };
+        if line.starts_with('#') || title.is_some() {
             // Save current content first
             if !current_content.trim().is_empty() {
                 sections.push(Section {
@@ -573,9 +1305,13 @@ fn split_by_headers(text: &str) -> Vec<Section> {
             }
 
             // Parse header level
-            let level = line.chars().take_while(|c| *c == '#').count() as i32;
-            let header_text = line.trim_start_matches('#').trim().to_string();
-            current_header = Some((level, header_text));
+            current_header = Some(match title {
+                Some(title) => (SECTION_TITLE_LEVEL, title),
+                None => {
+                    let level = line.chars().take_while(|c| *c == '#').count() as i32;
+                    (level, line.trim_start_matches('#').trim().to_string())
+                }
+            });
             current_content.push_str(line);
             current_content.push('\n');
         } else {
@@ -654,6 +1390,35 @@ fn split_by_lines(text: &str, max_chars: usize) -> Vec<String> {
     chunks
 }
 
+fn table_cells(line: &str) -> Vec<String> {
+    let trimmed = line.trim().trim_start_matches('|').trim_end_matches('|');
+    trimmed.split('|').map(|c| c.trim().to_string()).collect()
+}
+
+/// Rewrite a Markdown table as one `"Column: value; ..."` sentence per row.
+fn linearize_table(table: &str) -> Vec<String> {
+    let mut lines = table.lines().filter(|l| !l.trim().is_empty());
+    let header = match lines.next() {
+        Some(line) => table_cells(line),
+        None => return vec![],
+    };
+    let is_separator = |cells: &[String]| cells.iter().all(|c| !c.is_empty() && c.chars().all(|ch| matches!(ch, '-' | ':' | ' ')));
+
+    lines
+        .map(table_cells)
+        .filter(|cells| !is_separator(cells))
+        .filter_map(|cells| {
+            let pairs: Vec<String> = header
+                .iter()
+                .zip(&cells)
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(column, value)| if column.is_empty() { value.clone() } else { format!("{}: {}", column, value) })
+                .collect();
+            (!pairs.is_empty()).then(|| format!("{}.", pairs.join("; ")))
+        })
+        .collect()
+}
+
 /// Split table while preserving headers for each chunk.
 fn split_table_preserving_headers(table_content: &str, max_chars: usize) -> Vec<String> {
     let lines: Vec<&str> = table_content.lines().collect(); 
@@ -697,18 +1462,22 @@ fn split_table_preserving_headers(table_content: &str, max_chars: usize) -> Vec<
 }
 
 /// Recursively split large text into smaller chunks.
-fn recursive_split(text: &str, max_chars: usize) -> Vec<String> {
+fn recursive_split(text: &str, max_chars: usize, config: &ChunkingConfig) -> Vec<String> {
     if text.len() <= max_chars {
         return vec![text.to_string()];
     }
 
     let mut chunks = Vec::new();
-    
+    let split_paragraph = |para: &str| {
+        split_list(para, max_chars, config)
+            .unwrap_or_else(|| split_by_sentences(para, max_chars, &config.sentence_terminators))
+    };
+
     // Try splitting by paragraphs first
-    let paragraphs: Vec<&str> = text.split("\n\n").collect();
+    let paragraphs = group_list_intros(text.split("\n\n").collect(), config.keep_list_intro);
     if paragraphs.len() > 1 {
         let mut buffer = String::new();
-        for para in paragraphs {
+        for para in &paragraphs {
             if buffer.len() + para.len() + 2 <= max_chars {
                 if !buffer.is_empty() {
                     buffer.push_str("\n\n");
@@ -722,8 +1491,8 @@ fn recursive_split(text: &str, max_chars: usize) -> Vec<String> {
                 if para.len() <= max_chars {
                     buffer.push_str(para);
                 } else {
-                    // Paragraph too large, split by sentences
-                    chunks.extend(split_by_sentences(para, max_chars));
+                    // Paragraph too large, split by list items or sentences
+                    chunks.extend(split_paragraph(para));
                 }
             }
         }
@@ -731,20 +1500,109 @@ fn recursive_split(text: &str, max_chars: usize) -> Vec<String> {
             chunks.push(buffer);
         }
     } else {
-        // Single paragraph, split by sentences
-        chunks.extend(split_by_sentences(text, max_chars));
+        // Single paragraph, split by list items or sentences
+        chunks.extend(split_paragraph(text));
     }
 
     chunks
 }
 
+/// Split `text` after every occurrence of one of `terminators`, or into
+/// Unicode sentences when none are given.
+fn split_inclusive_terminators<'a>(text: &'a str, terminators: &[String]) -> Vec<&'a str> {
+    if terminators.is_empty() {
+        return sentence_spans(text);
+    }
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i < text.len() {
+        match terminators.iter().find(|t| !t.is_empty() && text[i..].starts_with(t.as_str())) {
+            Some(t) => {
+                i += t.len();
+                parts.push(&text[start..i]);
+                start = i;
+            }
+            None => i += text[i..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if start < text.len() {
+        parts.push(&text[start..]);
+    }
+    parts
+}
+
+/// Joins a short paragraph ending in a colon onto the list paragraph that
+/// follows it, so the introduction travels with its items.
+fn group_list_intros(paragraphs: Vec<&str>, enabled: bool) -> Vec<String> {
+    let mut grouped: Vec<String> = Vec::with_capacity(paragraphs.len());
+    for para in paragraphs {
+        let joins_intro = enabled
+            && classify_chunk(para) == ChunkType::List
+            && grouped.last().is_some_and(|prev| {
+                let prev = prev.trim();
+                !prev.contains('\n') && (prev.ends_with(':') || prev.ends_with('：'))
+            });
+        match grouped.last_mut() {
+            Some(prev) if joins_intro => {
+                prev.push('\n');
+                prev.push_str(para.trim_start_matches('\n'));
+            }
+            _ => grouped.push(para.to_string()),
+        }
+    }
+    grouped
+}
+
+/// Split a list between items, repeating its introduction in each piece.
+/// Returns `None` when `keep_list_intro` is off, `text` is not a list, or
+/// the introduction is too long to repeat.
+fn split_list(text: &str, max_chars: usize, config: &ChunkingConfig) -> Option<Vec<String>> {
+    if !config.keep_list_intro || classify_chunk(text) != ChunkType::List {
+        return None;
+    }
+    let lines: Vec<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    let first_item = lines.iter().position(|l| is_list_item(l))?;
+    let intro = lines[..first_item].join("\n");
+    if intro.len() > max_chars / 2 {
+        return None;
+    }
+
+    // Continuation lines stay with their item
+    let mut items: Vec<String> = vec![];
+    for line in &lines[first_item..] {
+        match items.last_mut() {
+            Some(item) if !is_list_item(line) => {
+                item.push('\n');
+                item.push_str(line);
+            }
+            _ => items.push(line.to_string()),
+        }
+    }
+
+    let mut pieces = Vec::new();
+    let mut current = intro.clone();
+    let mut has_items = false;
+    for item in items {
+        if has_items && current.len() + 1 + item.len() > max_chars {
+            pieces.push(std::mem::replace(&mut current, intro.clone()));
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&item);
+        has_items = true;
+    }
+    pieces.push(current);
+    Some(pieces)
+}
+
 /// Split text by sentences.
-fn split_by_sentences(text: &str, max_chars: usize) -> Vec<String> {
+fn split_by_sentences(text: &str, max_chars: usize, terminators: &[String]) -> Vec<String> {
     let mut chunks = Vec::new();
     let mut buffer = String::new();
 
-    // Simple sentence splitting by . ! ?
-    for part in text.split_inclusive(|c| c == '.' || c == '!' || c == '?') {
+    for part in split_inclusive_terminators(text, terminators) {
         if buffer.len() + part.len() <= max_chars {
             buffer.push_str(part);
         } else {
@@ -859,6 +1717,21 @@ mod markdown_tests {
         }
     }
 
+    #[test]
+    fn test_linearized_tables_share_batch() {
+        let text = "# Fees\n\n| Plan | Price |\n|:-----|------:|\n| Basic | $5 |\n| Pro | |\n\nEnd.";
+        let config = ChunkingConfig { strategy: ChunkingStrategy::Markdown, linearize_tables: true, ..ChunkingConfig::default() };
+        let chunks = chunk_text(text.to_string(), config);
+        let table = chunks.iter().find(|c| c.chunk_type == "table").unwrap();
+        let linear = chunks.iter().find(|c| c.chunk_type == "table_text").unwrap();
+        assert_eq!(linear.content, "Plan: Basic; Price: $5.\nPlan: Pro.");
+        assert_eq!(linear.header_path, "Fees");
+        assert!(table.batch_id.is_some());
+        assert_eq!(table.batch_id, linear.batch_id);
+        assert_eq!((table.batch_index, linear.batch_index, linear.batch_total), (Some(0), Some(1), Some(2)));
+        assert!(markdown_chunk(text.to_string(), 500).iter().all(|c| c.chunk_type != "table_text"));
+    }
+
     #[test]
     fn test_code_block_linking() {
         // Need > 100 chars to force split (min_chars clamped to 100)
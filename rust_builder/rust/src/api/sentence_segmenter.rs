@@ -0,0 +1,151 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Unicode-aware sentence segmentation shared by the chunker and prompt
+//! compression.
+//!
+//! Starts from UAX #29 sentence boundaries, which already know full-width
+//! terminators (`。！？．`), closing quotes after a terminator and decimal
+//! points, then repairs the cases that trip up Korean/Japanese and prose:
+//!
+//! - Quotative particles after a closing quote (`“좋다.”라고`, `「はい。」と`)
+//!   continue the sentence.
+//! - Ellipses (`...`, `…`) mark a pause, not an end, unless a capitalized
+//!   word or a line break follows.
+//! - Common abbreviations (`Dr.`, `e.g.`) don't end a sentence.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Abbreviations (lowercased) whose period doesn't end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "mr.", "mrs.", "ms.", "dr.", "prof.", "sr.", "jr.", "st.", "vs.", "etc.", "e.g.", "i.e.",
+    "no.", "fig.", "inc.", "ltd.", "co.", "cf.", "approx.",
+];
+
+fn is_closing(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\''
+            | ')'
+            | ']'
+            | '}'
+            | '”'
+            | '’'
+            | '」'
+            | '』'
+            | '》'
+            | '〉'
+            | '）'
+            | '］'
+            | '】'
+            | '〕'
+            | '»'
+    )
+}
+
+fn is_terminator(c: char) -> bool {
+    matches!(
+        c,
+        '.' | '!' | '?' | '。' | '！' | '？' | '．' | '…' | '‼' | '⁇' | '⁈' | '⁉'
+    )
+}
+
+/// Whether the sentence `prev` should continue into `next`.
+fn continues(prev: &str, next: &str) -> bool {
+    let Some(first) = next.chars().next() else {
+        return false;
+    };
+    let body = prev.trim_end();
+    let trailing = &prev[body.len()..];
+    let unquoted = body.trim_end_matches(is_closing);
+
+    // `“좋다.”라고`: the quote closes, the sentence goes on.
+    if trailing.is_empty()
+        && unquoted.len() < body.len()
+        && unquoted.ends_with(is_terminator)
+        && first.is_alphabetic()
+    {
+        return true;
+    }
+    if trailing.contains('\n') {
+        return false;
+    }
+    if (unquoted.ends_with("...") || unquoted.ends_with('…')) && !first.is_uppercase() {
+        return true;
+    }
+    let last_word = body
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    ABBREVIATIONS.contains(&last_word.as_str())
+}
+
+/// Splits `text` into sentences. The spans are contiguous and cover the
+/// whole input; trailing whitespace stays with the sentence before it.
+pub(crate) fn sentence_spans(text: &str) -> Vec<&str> {
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    for (start, segment) in text.split_sentence_bound_indices() {
+        let end = start + segment.len();
+        match spans.last_mut() {
+            Some(last) if continues(&text[last.0..last.1], segment) => last.1 = end,
+            _ => spans.push((start, end)),
+        }
+    }
+    spans.into_iter().map(|(s, e)| &text[s..e]).collect()
+}
+
+/// Splits `text` into trimmed, non-empty sentences.
+pub(crate) fn split_sentences(text: &str) -> Vec<String> {
+    sentence_spans(text)
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && !s.chars().all(|c| is_terminator(c) || is_closing(c)))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sentences_korean_and_japanese() {
+        assert_eq!(
+            split_sentences("오늘은 비가 온다. 우산을 챙겨요! 그는 “좋다.”라고 말했다."),
+            vec![
+                "오늘은 비가 온다.",
+                "우산을 챙겨요!",
+                "그는 “좋다.”라고 말했다."
+            ]
+        );
+        assert_eq!(
+            split_sentences("「はい。」と彼は言った。明日は晴れ？うん！"),
+            vec!["「はい。」と彼は言った。", "明日は晴れ？", "うん！"]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_ellipses_and_abbreviations() {
+        assert_eq!(
+            split_sentences("그게... 잘 모르겠어요. Dr. Kim agreed… Then we left."),
+            vec!["그게... 잘 모르겠어요.", "Dr. Kim agreed… Then we left."]
+        );
+        let text = "Pi is 3.14. Really.\nYes";
+        assert_eq!(sentence_spans(text).concat(), text);
+        assert_eq!(split_sentences(text), vec!["Pi is 3.14.", "Really.", "Yes"]);
+    }
+}
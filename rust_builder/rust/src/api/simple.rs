@@ -18,4 +18,6 @@ pub fn init_app() {
     let _ = crate::api::logger::init_logger();
 
     flutter_rust_bridge::setup_default_user_utils();
+    // After the FRB hook, which ours chains to.
+    crate::api::panic_report::install_panic_hook();
 }
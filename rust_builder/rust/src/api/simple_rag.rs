@@ -21,10 +21,15 @@ use rusqlite::{params, Connection};
 use ndarray::Array1;
 use log::{info, warn, error, debug};
 use sha2::{Sha256, Digest};
-use crate::api::hnsw_index::{build_hnsw_index, search_hnsw, is_hnsw_index_loaded, clear_hnsw_index};
-use crate::api::bm25_search::{bm25_add_document, bm25_add_documents, bm25_clear_index};
-use crate::api::incremental_index::{incremental_add, clear_buffer};
+use crate::api::hnsw_index::{build_hnsw_index, search_hnsw, is_hnsw_index_loaded, clear_hnsw_index, hnsw_insert};
+use crate::api::bm25_search::{bm25_add_document, bm25_add_documents, bm25_clear_index, bm25_update_document};
+use crate::api::incremental_index::{clear_buffer, incremental_add, BufferTable};
 use crate::api::db_pool::{get_connection};
+use crate::api::db_retry::with_write_retry;
+use crate::api::named_bm25::update_named_bm25_document;
+use crate::api::tenant::active_tenant;
+use crate::api::metrics::record_index_rebuild;
+use crate::api::error::{db_error, RagError};
 
 fn truncate_str(s: &str, max_chars: usize) -> &str {
     match s.char_indices().nth(max_chars) {
@@ -59,7 +64,7 @@ pub fn calculate_cosine_similarity(vec_a: Vec<f32>, vec_b: Vec<f32>) -> f64 {
 }
 
 /// Initialize database with docs table.
-pub fn init_db() -> anyhow::Result<()> {
+pub fn init_db() -> Result<(), RagError> {
     info!("[init_db] Initializing database tables");
     let conn = get_connection()?;
     
@@ -71,23 +76,24 @@ pub fn init_db() -> anyhow::Result<()> {
             embedding BLOB NOT NULL
         )",
         [],
-    )?;
+    ).map_err(db_error)?;
     
     let has_hash_column: bool = conn.prepare("SELECT content_hash FROM docs LIMIT 1").is_ok();
     
     if !has_hash_column {
         info!("[init_db] Migrating: adding content_hash column");
-        conn.execute("ALTER TABLE docs ADD COLUMN content_hash TEXT", [])?;
+        conn.execute("ALTER TABLE docs ADD COLUMN content_hash TEXT", []).map_err(db_error)?;
         
-        let mut stmt = conn.prepare("SELECT id, content FROM docs WHERE content_hash IS NULL")?;
-        let rows: Vec<(i64, String)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?.filter_map(|r| r.ok()).collect();
+        let mut stmt = conn.prepare("SELECT id, content FROM docs WHERE content_hash IS NULL").map_err(db_error)?;
+        let rows: Vec<(i64, String)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).map_err(db_error)?
+            .collect::<rusqlite::Result<_>>().map_err(db_error)?;
         
         for (id, content) in rows {
             let hash = calculate_content_hash(&content);
-            conn.execute("UPDATE docs SET content_hash = ?1 WHERE id = ?2", params![hash, id])?;
+            conn.execute("UPDATE docs SET content_hash = ?1 WHERE id = ?2", params![hash, id]).map_err(db_error)?;
         }
         
-        conn.execute("CREATE UNIQUE INDEX IF NOT EXISTS idx_content_hash ON docs(content_hash)", [])?;
+        conn.execute("CREATE UNIQUE INDEX IF NOT EXISTS idx_content_hash ON docs(content_hash)", []).map_err(db_error)?;
     }
     
     rebuild_hnsw_index_internal(&conn)?;
@@ -97,31 +103,33 @@ pub fn init_db() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn rebuild_hnsw_index_internal(conn: &Connection) -> anyhow::Result<()> {
-    let mut stmt = conn.prepare("SELECT id, embedding FROM docs")?;
+fn rebuild_hnsw_index_internal(conn: &Connection) -> Result<(), RagError> {
+    let mut stmt = conn.prepare("SELECT id, embedding FROM docs").map_err(db_error)?;
     let points: Vec<(i64, Vec<f32>)> = stmt.query_map([], |row| {
         let id: i64 = row.get(0)?;
         let embedding_blob: Vec<u8> = row.get(1)?;
         let embedding: Vec<f32> = embedding_blob.chunks(4).map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap())).collect();
         Ok((id, embedding))
-    })?.filter_map(|r| r.ok()).collect();
+    }).map_err(db_error)?.collect::<rusqlite::Result<_>>().map_err(db_error)?;
     
     if !points.is_empty() { build_hnsw_index(points)?; }
     Ok(())
 }
 
 /// Rebuild HNSW index.
-pub fn rebuild_hnsw_index() -> anyhow::Result<()> {
+pub fn rebuild_hnsw_index() -> Result<(), RagError> {
     info!("[rebuild_hnsw] Starting index rebuild");
+    record_index_rebuild();
     let conn = get_connection()?;
     rebuild_hnsw_index_internal(&conn)?;
     info!("[rebuild_hnsw] Index rebuild complete");
     Ok(())
 }
 
-fn rebuild_bm25_index_internal(conn: &Connection) -> anyhow::Result<()> {
-    let mut stmt = conn.prepare("SELECT id, content FROM docs")?;
-    let docs: Vec<(i64, String)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?.filter_map(|r| r.ok()).collect();
+fn rebuild_bm25_index_internal(conn: &Connection) -> Result<(), RagError> {
+    let mut stmt = conn.prepare("SELECT id, content FROM docs").map_err(db_error)?;
+    let docs: Vec<(i64, String)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).map_err(db_error)?
+        .collect::<rusqlite::Result<_>>().map_err(db_error)?;
     if !docs.is_empty() {
         info!("[bm25] Building index from {} documents", docs.len());
         bm25_add_documents(docs);
@@ -130,8 +138,9 @@ fn rebuild_bm25_index_internal(conn: &Connection) -> anyhow::Result<()> {
 }
 
 /// Rebuild BM25 index.
-pub fn rebuild_bm25_index() -> anyhow::Result<()> {
+pub fn rebuild_bm25_index() -> Result<(), RagError> {
     info!("[rebuild_bm25] Starting index rebuild");
+    record_index_rebuild();
     let conn = get_connection()?;
     bm25_clear_index();
     rebuild_bm25_index_internal(&conn)?;
@@ -148,7 +157,7 @@ pub struct AddDocumentResult {
 
 
 /// Add document with embedding vector (with deduplication).
-pub fn add_document(content: String, embedding: Vec<f32>) -> anyhow::Result<AddDocumentResult> {
+pub fn add_document(content: String, embedding: Vec<f32>) -> Result<AddDocumentResult, RagError> {
     info!("[add_document] Saving document");
     debug!("[add_document] content length: {} chars, embedding dims: {}", content.chars().count(), embedding.len());
     
@@ -170,27 +179,54 @@ pub fn add_document(content: String, embedding: Vec<f32>) -> anyhow::Result<AddD
     let mut embedding_bytes: Vec<u8> = Vec::with_capacity(embedding.len() * 4);
     for f in &embedding { embedding_bytes.extend_from_slice(&f.to_ne_bytes()); }
 
-    conn.execute("INSERT INTO docs (content, content_hash, embedding) VALUES (?1, ?2, ?3)", params![content, content_hash, embedding_bytes])?;
+    conn.execute("INSERT INTO docs (content, content_hash, embedding) VALUES (?1, ?2, ?3)", params![content, content_hash, embedding_bytes]).map_err(db_error)?;
     
     let doc_id = conn.last_insert_rowid();
     bm25_add_document(doc_id, content.clone());
-    incremental_add(doc_id, embedding);
+    // The linear buffer only holds documents until an index is built.
+    let inserted = hnsw_insert(doc_id, embedding.clone()).unwrap_or_else(|e| {
+        warn!("[add_document] Index insert failed: {}", e);
+        false
+    });
+    if !inserted {
+        incremental_add(BufferTable::Docs, doc_id, embedding);
+    }
     
     info!("[add_document] Document saved (id={})", doc_id);
     Ok(AddDocumentResult { success: true, is_duplicate: false, message: "Document saved successfully".to_string() })
 }
 
+/// Replace a document's content and re-index it for BM25, including the
+/// named BM25 indexes that hold it. The embedding is kept. Returns false
+/// if no document has this id.
+pub fn update_document(doc_id: i64, content: String) -> Result<bool, RagError> {
+    let content_hash = calculate_content_hash(&content);
+    let updated = with_write_retry("update_document", |conn| {
+        Ok(conn.execute(
+            "UPDATE docs SET content = ?1, content_hash = ?2 WHERE id = ?3",
+            params![content, content_hash, doc_id],
+        )?)
+    })?;
+    if updated == 0 {
+        return Ok(false);
+    }
+    update_named_bm25_document(&active_tenant(), doc_id, &content);
+    bm25_update_document(doc_id, content);
+    info!("[update_document] Document updated (id={})", doc_id);
+    Ok(true)
+}
+
 /// Legacy add_document for backward compatibility.
-pub fn add_document_simple(content: String, embedding: Vec<f32>) -> anyhow::Result<()> {
+pub fn add_document_simple(content: String, embedding: Vec<f32>) -> Result<(), RagError> {
     let result = add_document(content, embedding)?;
-    if result.success { Ok(()) } else { Err(anyhow::anyhow!(result.message)) }
+    if result.success { Ok(()) } else { Err(RagError::InvalidInput(result.message)) }
 }
 
 /// Similarity-based search (uses HNSW).
-pub fn search_similar(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Result<Vec<String>> {
+pub fn search_similar(query_embedding: Vec<f32>, top_k: u32) -> Result<Vec<String>, RagError> {
     info!("[search] Starting search, query dims: {}, top_k: {}", query_embedding.len(), top_k);
     
-    if query_embedding.is_empty() { return Err(anyhow::anyhow!("Query embedding is empty")); }
+    if query_embedding.is_empty() { return Err(RagError::InvalidInput("Query embedding is empty".to_string())); }
     
     if is_hnsw_index_loaded() {
         info!("[search] Using HNSW index");
@@ -208,7 +244,7 @@ pub fn search_similar(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Result<V
     search_with_linear_scan(query_embedding, top_k)
 }
 
-fn search_with_hnsw(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Result<Vec<String>> {
+fn search_with_hnsw(query_embedding: Vec<f32>, top_k: u32) -> Result<Vec<String>, RagError> {
     let hnsw_results = search_hnsw(query_embedding, top_k as usize)?;
     if hnsw_results.is_empty() { return Ok(Vec::new()); }
     
@@ -227,9 +263,9 @@ fn search_with_hnsw(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Result<Vec
     Ok(results)
 }
 
-fn search_with_linear_scan(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Result<Vec<String>> {
+fn search_with_linear_scan(query_embedding: Vec<f32>, top_k: u32) -> Result<Vec<String>, RagError> {
     let conn = get_connection()?;
-    let mut stmt = conn.prepare("SELECT content, embedding FROM docs")?;
+    let mut stmt = conn.prepare("SELECT content, embedding FROM docs").map_err(db_error)?;
     
     let query_vec = Array1::from(query_embedding.clone());
     let query_norm = query_vec.mapv(|x| x * x).sum().sqrt();
@@ -239,10 +275,10 @@ fn search_with_linear_scan(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Res
         let content: String = row.get(0)?;
         let embedding_blob: Vec<u8> = row.get(1)?;
         Ok((content, embedding_blob))
-    })?;
+    }).map_err(db_error)?;
 
     for row in rows {
-        let (content, embedding_blob) = row?;
+        let (content, embedding_blob) = row.map_err(db_error)?;
         if embedding_blob.len() % 4 != 0 { continue; }
         
         let embedding_vec: Vec<f32> = embedding_blob.chunks(4).map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap())).collect();
@@ -264,18 +300,18 @@ fn search_with_linear_scan(query_embedding: Vec<f32>, top_k: u32) -> anyhow::Res
 }
 
 /// Get document count.
-pub fn get_document_count() -> anyhow::Result<i64> {
+pub fn get_document_count() -> Result<i64, RagError> {
     let conn = get_connection()?;
-    Ok(conn.query_row("SELECT COUNT(*) FROM docs", [], |row| row.get(0))?)
+    conn.query_row("SELECT COUNT(*) FROM docs", [], |row| row.get(0)).map_err(db_error)
 }
 
 /// Clear all documents.
-pub fn clear_all_documents() -> anyhow::Result<()> {
+pub fn clear_all_documents() -> Result<(), RagError> {
     let conn = get_connection()?;
-    conn.execute("DELETE FROM docs", [])?;
+    conn.execute("DELETE FROM docs", []).map_err(db_error)?;
     clear_hnsw_index();
     bm25_clear_index();
-    clear_buffer();
+    clear_buffer(BufferTable::Docs);
     info!("[clear] All documents and indexes deleted");
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,253 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Highlighted snippets for search results.
+//!
+//! Every word of the content goes through the same tokenizer and
+//! analyzers as BM25, so a word is highlighted exactly when it would have
+//! matched a query term (including stemmed forms). Han and Kana segment
+//! into single glyphs while CJK n-gram tokens span several, so a run of
+//! adjacent CJK words is tokenized as one and each matched term is
+//! highlighted where it occurs in the run. The snippet is the window of
+//! `SNIPPET_SENTENCES` sentences with the most distinct matched terms.
+//! Offsets are UTF-16 code units, as Dart strings are indexed.
+
+use std::collections::HashSet;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::bm25_search::{active_index_options, index_tokens, is_cjk_or_hangul, Bm25IndexOptions};
+use crate::api::sentence_segmenter::sentence_spans;
+
+/// Consecutive sentences in a snippet.
+const SNIPPET_SENTENCES: usize = 2;
+
+/// Longer windows are cut down to this many bytes around their first hit.
+const SNIPPET_MAX_BYTES: usize = 320;
+
+/// UTF-16 range of a query-term hit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchSnippet {
+    pub text: String,
+    /// UTF-16 range of `text` within the result's content.
+    pub start: u32,
+    pub end: u32,
+    /// Query-term hits within `text` (UTF-16 offsets relative to `text`),
+    /// in order and not overlapping.
+    pub highlights: Vec<HighlightSpan>,
+}
+
+fn is_cjk_word(word: &str) -> bool {
+    word.chars().all(is_cjk_or_hangul)
+}
+
+/// Hits in `content`, by start: byte range and the query term matched.
+fn find_hits<'q>(
+    content: &str,
+    query_terms: &'q [String],
+    options: &Bm25IndexOptions,
+) -> Vec<(usize, usize, &'q str)> {
+    let mut hits = Vec::new();
+    let mut words = content.unicode_word_indices().peekable();
+    while let Some((start, word)) = words.next() {
+        if !is_cjk_word(word) {
+            let tokens = index_tokens(word, options);
+            if let Some(term) = query_terms.iter().find(|term| tokens.contains(term)) {
+                hits.push((start, start + word.len(), term.as_str()));
+            }
+            continue;
+        }
+        let mut end = start + word.len();
+        while let Some(&(next, next_word)) = words.peek() {
+            if next != end || !is_cjk_word(next_word) {
+                break;
+            }
+            end += next_word.len();
+            words.next();
+        }
+        let run = &content[start..end];
+        let tokens = index_tokens(run, options);
+        for term in query_terms.iter().filter(|term| tokens.contains(term)) {
+            let before = hits.len();
+            hits.extend(
+                run.match_indices(term.as_str())
+                    .map(|(at, _)| (start + at, start + at + term.len(), term.as_str())),
+            );
+            // An analyzed term that isn't in the text verbatim marks the run.
+            if hits.len() == before {
+                hits.push((start, end, term.as_str()));
+            }
+        }
+    }
+    hits.sort_by_key(|&(start, end, _)| (start, end));
+    hits
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// The query terms found in `content` (in query order) and its best
+/// snippet; `None` for empty content. Tokenizes like the active tenant's
+/// BM25 index.
+pub(crate) fn build_snippet(content: &str, query_text: &str) -> (Vec<String>, Option<SearchSnippet>) {
+    build_snippet_with(content, query_text, &active_index_options())
+}
+
+fn build_snippet_with(
+    content: &str,
+    query_text: &str,
+    options: &Bm25IndexOptions,
+) -> (Vec<String>, Option<SearchSnippet>) {
+    let mut query_terms = index_tokens(query_text, options);
+    let mut seen = HashSet::new();
+    query_terms.retain(|t| seen.insert(t.clone()));
+
+    let hits = find_hits(content, &query_terms, options);
+    let matched_terms: Vec<String> = query_terms
+        .iter()
+        .filter(|term| hits.iter().any(|(_, _, t)| t == term))
+        .cloned()
+        .collect();
+
+    // Sentence byte ranges; the spans are contiguous from offset 0.
+    let mut sentences: Vec<(usize, usize)> = Vec::new();
+    let mut offset = 0;
+    for span in sentence_spans(content) {
+        sentences.push((offset, offset + span.len()));
+        offset += span.len();
+    }
+    if sentences.is_empty() {
+        return (matched_terms, None);
+    }
+
+    let mut best = (0, 0, 0);
+    for first in 0..sentences.len() {
+        let last = (first + SNIPPET_SENTENCES).min(sentences.len()) - 1;
+        let (start, end) = (sentences[first].0, sentences[last].1);
+        let inside: Vec<_> = hits.iter().filter(|(s, e, _)| *s >= start && *e <= end).collect();
+        let distinct = inside.iter().map(|(_, _, t)| *t).collect::<HashSet<_>>().len();
+        if (distinct, inside.len()) > (best.1, best.2) {
+            best = (first, distinct, inside.len());
+        }
+    }
+    let last = (best.0 + SNIPPET_SENTENCES).min(sentences.len()) - 1;
+    let (mut start, mut end) = (sentences[best.0].0, sentences[last].1);
+
+    if end - start > SNIPPET_MAX_BYTES {
+        let anchor = hits
+            .iter()
+            .find(|(s, _, _)| *s >= start)
+            .map_or(start, |(s, _, _)| *s);
+        start = floor_char_boundary(content, anchor.saturating_sub(SNIPPET_MAX_BYTES / 4).max(start));
+        end = floor_char_boundary(content, (start + SNIPPET_MAX_BYTES).min(end));
+    }
+
+    // Drop surrounding whitespace so offsets point at visible text.
+    let raw = &content[start..end];
+    start += raw.len() - raw.trim_start().len();
+    end -= raw.len() - raw.trim_end().len();
+
+    // Bigrams of one run overlap; merge them into one span.
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    for &(s, e, _) in hits.iter().filter(|(s, e, _)| *s >= start && *e <= end) {
+        match spans.last_mut() {
+            Some(last) if s <= last.1 => last.1 = last.1.max(e),
+            _ => spans.push((s, e)),
+        }
+    }
+    let text = &content[start..end];
+    let highlights = spans
+        .into_iter()
+        .map(|(s, e)| HighlightSpan {
+            start: utf16_len(&text[..s - start]),
+            end: utf16_len(&text[..e - start]),
+        })
+        .collect();
+    let utf16_start = utf16_len(&content[..start]);
+    let snippet = SearchSnippet {
+        text: text.to_string(),
+        start: utf16_start,
+        end: utf16_start + utf16_len(text),
+        highlights,
+    };
+    (matched_terms, Some(snippet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bm25_search::Bm25Tokenization;
+
+    /// `text[start..end]` in UTF-16 code units, as Dart would slice it.
+    fn utf16_slice(text: &str, start: u32, end: u32) -> String {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        String::from_utf16(&units[start as usize..end as usize]).unwrap()
+    }
+
+    fn marked(snippet: &SearchSnippet) -> Vec<String> {
+        snippet.highlights.iter().map(|h| utf16_slice(&snippet.text, h.start, h.end)).collect()
+    }
+
+    #[test]
+    fn test_snippet_picks_matching_sentences() {
+        let content = "Intro text here. Nothing relevant. The engine stores vectors. \
+                       Vectors are searched with HNSW. Closing words.";
+        let (terms, snippet) = build_snippet_with(content, "HNSW vectors", &Bm25IndexOptions::default());
+        let snippet = snippet.unwrap();
+
+        assert_eq!(terms, vec!["hnsw".to_string(), "vectors".to_string()]);
+        assert_eq!(snippet.text, "The engine stores vectors. Vectors are searched with HNSW.");
+        assert_eq!(utf16_slice(content, snippet.start, snippet.end), snippet.text);
+        assert_eq!(marked(&snippet), vec!["vectors", "Vectors", "HNSW"]);
+    }
+
+    #[test]
+    fn test_snippet_offsets_are_utf16_for_cjk() {
+        let content = "🙂 검색 엔진 설명. 벡터 검색은 빠르다.";
+        let (terms, snippet) = build_snippet_with(content, "벡터", &Bm25IndexOptions::default());
+        let snippet = snippet.unwrap();
+        assert_eq!(terms, vec!["벡터".to_string()]);
+        assert_eq!(utf16_slice(content, snippet.start, snippet.end), snippet.text);
+        assert_eq!(marked(&snippet), vec!["벡터"]);
+    }
+
+    #[test]
+    fn test_cjk_bigrams_are_highlighted() {
+        let options = Bm25IndexOptions {
+            tokenization: Bm25Tokenization::CjkBigrams,
+            ..Default::default()
+        };
+        let content = "本文介绍搜索引擎的索引结构。";
+        let (terms, snippet) = build_snippet_with(content, "搜索引擎", &options);
+        assert_eq!(terms, vec!["搜索".to_string(), "索引".to_string(), "引擎".to_string()]);
+        assert_eq!(marked(&snippet.unwrap()), vec!["搜索引擎", "索引"]);
+    }
+}
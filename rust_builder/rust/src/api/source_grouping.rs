@@ -0,0 +1,198 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Hybrid search results grouped by source
+//! (`HybridSearchOptions::group_by_source`).
+//!
+//! A long PDF yields many similar chunks that can fill a whole top-10 on
+//! their own. Grouping keeps at most `max_chunks_per_source` chunks per
+//! source and ranks the sources by the sum of their kept chunks' scores,
+//! so a source with several good matches beats one with a single match
+//! but cannot win on volume alone. Simple RAG documents have no source,
+//! so each forms a group of its own.
+
+use std::collections::{HashMap, HashSet};
+
+use rusqlite::Connection;
+
+use crate::api::error::{db_error, RagError};
+use crate::api::fts_keyword::table_exists;
+use crate::api::hybrid_search::HybridSearchResult;
+use crate::api::tenant::tenant_condition;
+
+/// Chunks fetched per requested chunk slot before grouping.
+pub(crate) const GROUP_FETCH_MULTIPLIER: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct SourceGroup {
+    /// The source's id, or the document's when `is_document`.
+    pub source_id: i64,
+    /// Whether the group is a single Simple RAG document, not a source.
+    pub is_document: bool,
+    pub source_name: Option<String>,
+    /// Sum of the scores of `chunks`.
+    pub score: f64,
+    /// The source's best chunks, best first.
+    pub chunks: Vec<HybridSearchResult>,
+}
+
+/// Group `results` (best first) by source, keeping the best
+/// `max_chunks_per_source` of each; ids in `documents` are Simple RAG
+/// documents and get a group each. Groups come best first.
+fn group_results(
+    results: Vec<HybridSearchResult>,
+    max_chunks_per_source: usize,
+    documents: &HashSet<i64>,
+) -> Vec<SourceGroup> {
+    let mut groups: Vec<SourceGroup> = Vec::new();
+    let mut index_of: HashMap<(bool, i64), usize> = HashMap::new();
+    for result in results {
+        let is_document = documents.contains(&result.doc_id);
+        let i = *index_of.entry((is_document, result.source_id)).or_insert_with(|| {
+            groups.push(SourceGroup {
+                source_id: result.source_id,
+                is_document,
+                source_name: None,
+                score: 0.0,
+                chunks: Vec::new(),
+            });
+            groups.len() - 1
+        });
+        let group = &mut groups[i];
+        if group.chunks.len() < max_chunks_per_source {
+            group.score += result.score;
+            group.chunks.push(result);
+        }
+    }
+    // Stable sort: equal scores keep the order of their best chunk.
+    groups.sort_by(|a, b| b.score.total_cmp(&a.score));
+    groups
+}
+
+fn id_list(ids: &[i64]) -> String {
+    ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// Which of `ids` are rows of the Simple RAG `docs` table.
+fn load_document_ids(conn: &Connection, ids: &[i64]) -> Result<HashSet<i64>, RagError> {
+    if !table_exists(conn, "docs").map_err(db_error)? {
+        return Ok(HashSet::new());
+    }
+    let mut stmt = conn
+        .prepare(&format!("SELECT id FROM docs WHERE id IN ({})", id_list(ids)))
+        .map_err(db_error)?;
+    let documents = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    Ok(documents)
+}
+
+fn load_source_names(conn: &Connection, ids: &[i64]) -> Result<HashMap<i64, Option<String>>, RagError> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT s.id, s.name FROM sources s WHERE s.id IN ({}) AND {}",
+            id_list(ids),
+            tenant_condition("s")
+        ))
+        .map_err(db_error)?;
+    let names = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    Ok(names)
+}
+
+/// Collapse hydrated `results` (best first) to the best `top_k` sources,
+/// each with at most `max_chunks_per_source` chunks (at least 1).
+/// `include_docs` says whether `results` may hold Simple RAG documents.
+pub(crate) fn group_by_source(
+    conn: &Connection,
+    results: Vec<HybridSearchResult>,
+    max_chunks_per_source: u32,
+    top_k: usize,
+    include_docs: bool,
+) -> Result<Vec<SourceGroup>, RagError> {
+    let documents = if include_docs && !results.is_empty() {
+        let ids: Vec<i64> = results.iter().map(|r| r.doc_id).collect();
+        load_document_ids(conn, &ids)?
+    } else {
+        HashSet::new()
+    };
+    let mut groups = group_results(results, max_chunks_per_source.max(1) as usize, &documents);
+    groups.truncate(top_k);
+
+    let ids: Vec<i64> = groups.iter().filter(|g| !g.is_document).map(|g| g.source_id).collect();
+    if !ids.is_empty() {
+        let mut names = load_source_names(conn, &ids)?;
+        for group in groups.iter_mut().filter(|g| !g.is_document) {
+            group.source_name = names.remove(&group.source_id).flatten();
+        }
+    }
+    Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make(doc_id: i64, source_id: i64, score: f64) -> HybridSearchResult {
+        HybridSearchResult {
+            doc_id,
+            content: String::new(),
+            score,
+            vector_rank: 0,
+            bm25_rank: 0,
+            source_id,
+            metadata: None,
+            chunk_index: 0,
+            chunk_type: "general".to_string(),
+            header_path: None,
+            matched_terms: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn test_group_by_source_caps_chunks_per_source() {
+        // Source 1 has many mediocre hits, source 2 two strong ones.
+        let results = vec![
+            make(20, 2, 0.9),
+            make(10, 1, 0.5),
+            make(11, 1, 0.45),
+            make(21, 2, 0.4),
+            make(12, 1, 0.4),
+            make(13, 1, 0.35),
+        ];
+        let groups = group_results(results, 2, &HashSet::new());
+        assert_eq!(groups.iter().map(|g| g.source_id).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(groups[1].chunks.iter().map(|c| c.doc_id).collect::<Vec<_>>(), vec![10, 11]);
+        assert!((groups[0].score - 1.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_documents_form_their_own_groups() {
+        // Documents 1 and 2 report their own ids as sources; chunk 10
+        // belongs to source 1.
+        let results = vec![make(1, 1, 0.9), make(10, 1, 0.8), make(2, 2, 0.7)];
+        let groups = group_results(results, 5, &HashSet::from([1, 2]));
+        let keys: Vec<(bool, i64, usize)> =
+            groups.iter().map(|g| (g.is_document, g.source_id, g.chunks.len())).collect();
+        assert_eq!(keys, vec![(true, 1, 1), (false, 1, 1), (true, 2, 1)]);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Per-source HNSW sub-indexes for source-scoped search.
+//!
+//! With `set_source_index_config(enabled: true, ..)`, hybrid searches
+//! filtered by `source_ids` query a small HNSW graph per source instead
+//! of scoring every chunk of the source exactly. Sub-indexes are built
+//! lazily on first use and kept in an LRU cache of `max_cached` entries.
+//! A cached index is rebuilt once the tenant's vector version (see
+//! `index_freshness`) has moved, i.e. after any chunk insert, delete or
+//! embedding change; a reused chunk id can't make it look current.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use log::{debug, info};
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection};
+
+use crate::api::db_pool::get_connection;
+use crate::api::embedding_storage::decode_embedding;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::{
+    build_tenant_graph, get_hnsw_seeded_build, get_index_config, HnswBuildConfig, HnswSearchResult,
+    TenantGraph,
+};
+use crate::api::index_freshness::change_versions;
+use crate::api::progress::Progress;
+use crate::api::tenant::active_tenant;
+
+#[derive(Debug, Clone)]
+pub struct SourceIndexConfig {
+    pub enabled: bool,
+    /// Sub-indexes kept in memory; the least recently used is evicted.
+    pub max_cached: u32,
+}
+
+impl Default for SourceIndexConfig {
+    fn default() -> Self {
+        Self { enabled: false, max_cached: 16 }
+    }
+}
+
+/// `(chunk count of the source, vector version of the tenant)` when the
+/// index was built.
+type Fingerprint = (i64, i64);
+
+#[flutter_rust_bridge::frb(ignore)]
+struct CachedIndex {
+    index: Arc<TenantGraph>,
+    fingerprint: Fingerprint,
+    last_used: u64,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct SourceIndexCache {
+    entries: HashMap<(String, i64), CachedIndex>,
+    clock: u64,
+}
+
+impl SourceIndexCache {
+    fn get(&mut self, key: &(String, i64), fingerprint: Fingerprint) -> Option<Arc<TenantGraph>> {
+        self.clock += 1;
+        let clock = self.clock;
+        let entry = self.entries.get_mut(key).filter(|e| e.fingerprint == fingerprint)?;
+        entry.last_used = clock;
+        Some(entry.index.clone())
+    }
+
+    /// Insert as the most recently used entry, evicting beyond `max_cached`.
+    fn insert(&mut self, key: (String, i64), mut cached: CachedIndex, max_cached: usize) {
+        self.clock += 1;
+        cached.last_used = self.clock;
+        self.entries.insert(key, cached);
+        while self.entries.len() > max_cached.max(1) {
+            let Some(oldest) = self.entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, _)| k.clone()) else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+static CONFIG: Lazy<RwLock<SourceIndexConfig>> = Lazy::new(|| RwLock::new(SourceIndexConfig::default()));
+static CACHE: Lazy<Mutex<SourceIndexCache>> = Lazy::new(|| Mutex::new(SourceIndexCache::default()));
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_source_index_config(config: SourceIndexConfig) {
+    info!("[source_index] Config: {:?}", config);
+    let enabled = config.enabled;
+    *CONFIG.write().unwrap() = config;
+    if !enabled {
+        CACHE.lock().unwrap().entries.clear();
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_source_index_config() -> SourceIndexConfig {
+    CONFIG.read().unwrap().clone()
+}
+
+/// Number of per-source sub-indexes currently cached.
+#[flutter_rust_bridge::frb(sync)]
+pub fn cached_source_index_count() -> u32 {
+    CACHE.lock().unwrap().entries.len() as u32
+}
+
+/// Drop the sub-indexes of `tenant_id` (after its embeddings changed).
+pub(crate) fn drop_tenant_source_indexes(tenant_id: &str) {
+    CACHE.lock().unwrap().entries.retain(|(tenant, _), _| tenant != tenant_id);
+}
+
+/// Drop the sub-index of one source (after its chunks were replaced).
+pub(crate) fn drop_source_index(tenant_id: &str, source_id: i64) {
+    CACHE.lock().unwrap().entries.remove(&(tenant_id.to_string(), source_id));
+}
+
+/// Drop every sub-index (after the index config changed).
+pub(crate) fn clear_all_source_indexes() {
+    CACHE.lock().unwrap().entries.clear();
+}
+
+fn source_fingerprint(conn: &Connection, tenant_id: &str, source_id: i64) -> rusqlite::Result<Fingerprint> {
+    let count = conn.query_row(
+        "SELECT COUNT(*) FROM chunks WHERE source_id = ?1 AND tenant_id = ?2",
+        params![source_id, tenant_id],
+        |row| row.get(0),
+    )?;
+    let (vector_version, _) = change_versions(conn, tenant_id)?;
+    Ok((count, vector_version))
+}
+
+fn build_source_index(
+    conn: &Connection,
+    tenant_id: &str,
+    source_id: i64,
+) -> Result<Option<TenantGraph>, RagError> {
+    let mut stmt = conn
+        .prepare("SELECT id, embedding FROM chunks WHERE source_id = ?1 AND tenant_id = ?2")
+        .map_err(db_error)?;
+    let points: Vec<(i64, Vec<f32>)> = stmt
+        .query_map(params![source_id, tenant_id], |row| {
+            Ok((row.get(0)?, decode_embedding(&row.get::<_, Vec<u8>>(1)?)))
+        })
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    if points.is_empty() {
+        return Ok(None);
+    }
+    let config = HnswBuildConfig::adaptive(points.len());
+    let index_config = get_index_config();
+    debug!("[source_index] Building sub-index for source {} ({} points)", source_id, points.len());
+    let (graph, _) = build_tenant_graph(
+        points,
+        config,
+        get_hnsw_seeded_build(),
+        index_config.quantization,
+        index_config.metric,
+        Progress::NONE,
+    )?;
+    Ok(Some(graph))
+}
+
+/// Vector candidates from the sub-indexes of `source_ids`, best first,
+/// or `None` when sub-indexes are disabled.
+pub(crate) fn search_source_indexes(
+    source_ids: &[i64],
+    query_embedding: &[f32],
+    top_k: usize,
+) -> Result<Option<Vec<HnswSearchResult>>, RagError> {
+    let config = get_source_index_config();
+    if !config.enabled {
+        return Ok(None);
+    }
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    let ef_search = core::cmp::max(100, top_k * 5);
+    let mut results = Vec::new();
+    for &source_id in source_ids {
+        let fingerprint = source_fingerprint(&conn, &tenant_id, source_id).map_err(db_error)?;
+        if fingerprint.0 == 0 {
+            continue;
+        }
+        let key = (tenant_id.clone(), source_id);
+        let cached = CACHE.lock().unwrap().get(&key, fingerprint);
+        let index = match cached {
+            Some(index) => index,
+            None => {
+                let Some(built) = build_source_index(&conn, &tenant_id, source_id)? else {
+                    continue;
+                };
+                let index = Arc::new(built);
+                CACHE.lock().unwrap().insert(
+                    key,
+                    CachedIndex { index: index.clone(), fingerprint, last_used: 0 },
+                    config.max_cached as usize,
+                );
+                index
+            }
+        };
+        results.extend(index.search(query_embedding, top_k, ef_search, None).into_iter().map(|n| HnswSearchResult {
+            id: n.d_id as i64,
+            distance: n.distance,
+        }));
+    }
+    results.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.id.cmp(&b.id)));
+    results.truncate(top_k);
+    Ok(Some(results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::hnsw_index::{DistanceMetric, Quantization};
+
+    fn tiny_index(id: i64) -> CachedIndex {
+        let points = vec![(id, vec![1.0f32, 0.0])];
+        let config = HnswBuildConfig::adaptive(1);
+        let (graph, _) = build_tenant_graph(points, config, None, Quantization::None, DistanceMetric::Cosine, Progress::NONE).unwrap();
+        CachedIndex { index: Arc::new(graph), fingerprint: (1, id), last_used: 0 }
+    }
+
+    #[test]
+    fn test_cache_evicts_lru_and_detects_stale_fingerprint() {
+        let mut cache = SourceIndexCache::default();
+        let key = |source_id: i64| ("t".to_string(), source_id);
+        cache.insert(key(1), tiny_index(1), 2);
+        cache.insert(key(2), tiny_index(2), 2);
+        assert!(cache.get(&key(1), (1, 1)).is_some());
+        cache.insert(key(3), tiny_index(3), 2);
+        assert!(cache.entries.contains_key(&key(1)));
+        assert!(!cache.entries.contains_key(&key(2)));
+
+        // The source changed since the index was built.
+        assert!(cache.get(&key(1), (2, 5)).is_none());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_when_a_chunk_id_is_reused() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, source_id INTEGER, tenant_id TEXT,
+                 content TEXT, embedding BLOB);",
+        )
+        .unwrap();
+        crate::api::index_freshness::create_change_tracking(&conn).unwrap();
+        conn.execute("INSERT INTO chunks VALUES (1, 7, 't', 'a', x''), (2, 7, 't', 'b', x'')", []).unwrap();
+        let before = source_fingerprint(&conn, "t", 7).unwrap();
+
+        // Same count and highest id, different chunk.
+        conn.execute("DELETE FROM chunks WHERE id = 2", []).unwrap();
+        conn.execute("INSERT INTO chunks VALUES (2, 7, 't', 'c', x'')", []).unwrap();
+        let after = source_fingerprint(&conn, "t", 7).unwrap();
+        assert_eq!(before.0, after.0);
+        assert_ne!(before, after);
+    }
+}
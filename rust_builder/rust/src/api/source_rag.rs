@@ -16,19 +16,119 @@
 //
 //! Extended RAG API with sources and chunks for LLM-optimized context.
 
-use rusqlite::params;
-use ndarray::Array1;
-use log::{info, debug};
+use rusqlite::{params, Connection, OptionalExtension};
+use log::{info, debug, warn};
+use flutter_rust_bridge::DartFnFuture;
 use sha2::{Sha256, Digest};
 use crate::api::hnsw_index::{
-    build_hnsw_index, search_hnsw, is_hnsw_index_loaded
+    add_tombstones, build_hnsw_index_with_progress, clear_hnsw_index, drop_tenant_hnsw_index, hnsw_point_count,
+    get_index_config, insert_into_tenant_index, search_hnsw, is_hnsw_index_loaded, set_hnsw_build_config,
+    tombstone_count, HnswBuildConfig,
+};
+use crate::api::bm25_search::{
+    bm25_add_documents_with_progress, bm25_clear_tenant_index, bm25_update_document, is_bm25_index_loaded,
 };
-use crate::api::bm25_search::{bm25_add_documents, bm25_clear_index, is_bm25_index_loaded};
 use crate::api::db_pool::get_connection;
-use crate::api::error::RagError;
+use crate::api::db_retry::{with_write_retry, WriteError};
+use crate::api::embedding_storage::{decode_embedding, decode_embedding_into, encode_embedding, stored_dimension};
+use crate::api::error::{db_error, RagError};
+use crate::api::retrieval_state::record_chunk_retrieval;
+use crate::api::query_history::create_query_history_table;
+use crate::api::search_analytics::{create_search_analytics_tables, record_search_event};
+use crate::api::chat_memory::create_chat_memory_table;
+use crate::api::entity_extraction::{create_entity_table, store_chunk_entities};
+use crate::api::knowledge_graph::{create_knowledge_graph_table, store_chunk_triples};
+use crate::api::language_detect::detect_language_code;
+use crate::api::incremental_index::{drop_tenant_buffer, BufferTable};
+use crate::api::ingest_jobs::{block_on, create_ingest_jobs_table};
+use crate::api::maintenance::query_guard;
+use crate::api::mmr::{load_embeddings, mmr_select, validate_lambda, MMR_FETCH_MULTIPLIER};
+use crate::api::pagination::{decode_cursor, encode_cursor, merge_deeper, RankingCache, PAGES_PER_RANKING};
+use crate::api::reembedding::create_reembedding_jobs_table;
+use crate::api::index_freshness::{advance_indexed, change_versions, create_change_tracking, record_indexed, IndexKind};
+use crate::api::chunk_index_store::{chunk_table_state, persist_chunk_index};
+use crate::api::source_index::{drop_source_index, drop_tenant_source_indexes};
+use crate::api::named_bm25::{delete_named_bm25_chunks, update_named_bm25_document};
+use crate::api::named_index::delete_named_chunks;
+use crate::api::progress::{Progress, OP_ADD_CHUNKS, OP_BM25_REBUILD, OP_HNSW_REBUILD, PHASE_INSERT};
+use crate::api::metrics::{record_cache_hit, record_cache_miss, record_index_rebuild, record_search};
+use crate::api::tenant::{active_tenant, DEFAULT_TENANT};
+use crate::api::content_crypto::{
+    decrypt_content, encrypt_content, is_content_encryption_enabled, purge_plaintext_side_tables, read_content,
+};
+use crate::api::vector_ops::{dot, norm};
+use crate::api::classification_rules::{create_classification_rules_table, load_classification_rules};
+use crate::api::synonyms::{create_synonyms_table, load_synonyms};
+use crate::api::staged_ingest::{create_staging_tables, purge_expired};
+use crate::api::fts_keyword::drop_fts_for_encryption;
+use std::collections::HashSet;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
+
+/// Detect the language of chunks stored before the `language` column
+/// existed. Rows whose content can't be decrypted yet stay NULL and are
+/// retried on the next init or when a content key is installed. Returns
+/// how many were left undecrypted.
+pub(crate) fn backfill_chunk_languages(conn: &rusqlite::Connection) -> rusqlite::Result<usize> {
+    let rows: Vec<(i64, String)> = conn
+        .prepare("SELECT id, content FROM chunks WHERE language IS NULL")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    if rows.is_empty() {
+        return Ok(0);
+    }
+    let mut update = conn.prepare("UPDATE chunks SET language = ?1 WHERE id = ?2")?;
+    let mut detected = 0usize;
+    let mut undecrypted = 0usize;
+    for (id, stored) in rows {
+        match decrypt_content(stored) {
+            Ok(content) => {
+                update.execute(params![detect_language_code(&content), id])?;
+                detected += 1;
+            }
+            Err(e) => {
+                debug!("[init_source_db] Chunk {} not decrypted for language detection: {}", id, e);
+                undecrypted += 1;
+            }
+        }
+    }
+    info!("[init_source_db] Detected language of {} existing chunks", detected);
+    if undecrypted > 0 {
+        warn!(
+            "[init_source_db] {} chunks could not be decrypted, their language is detected once their key is installed",
+            undecrypted
+        );
+    }
+    Ok(undecrypted)
+}
 
-fn hash_content(content: &str) -> String {
+fn backfill_embedding_norms(conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+    let rows: Vec<(i64, Vec<u8>)> = conn
+        .prepare("SELECT id, embedding FROM chunks WHERE embedding_norm IS NULL")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    let mut update = conn.prepare("UPDATE chunks SET embedding_norm = ?1 WHERE id = ?2")?;
+    for (id, blob) in &rows {
+        update.execute(params![norm(&decode_embedding(blob)), id])?;
+    }
+    info!("[init_source_db] Stored embedding norms of {} existing chunks", rows.len());
+    Ok(())
+}
+
+/// Version of the table layout created by `init_source_db`, stored in the
+/// database as `PRAGMA user_version`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// `content_hash` is UNIQUE, so other tenants hash (tenant, content) to keep
+/// duplicate detection per tenant. Default-tenant hashes are unchanged.
+pub(crate) fn hash_content(content: &str, tenant_id: &str) -> String {
     let mut hasher = Sha256::new();
+    if tenant_id != DEFAULT_TENANT {
+        hasher.update(tenant_id.as_bytes());
+        hasher.update([0u8]);
+    }
     hasher.update(content.as_bytes());
     format!("{:x}", hasher.finalize())
 }
@@ -36,7 +136,7 @@ fn hash_content(content: &str) -> String {
 /// Initialize database with sources and chunks tables.
 pub fn init_source_db() -> Result<(), RagError> {
     info!("[init_source_db] Initializing database tables");
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
     
     conn.execute(
         "CREATE TABLE IF NOT EXISTS sources (
@@ -45,10 +145,12 @@ pub fn init_source_db() -> Result<(), RagError> {
             content_hash TEXT UNIQUE,
             metadata TEXT,
             created_at INTEGER DEFAULT (strftime('%s', 'now')),
-            name TEXT
+            name TEXT,
+            tenant_id TEXT NOT NULL DEFAULT 'default',
+            updated_at INTEGER
         )",
         [],
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    ).map_err(db_error)?;
     
     conn.execute(
         "CREATE TABLE IF NOT EXISTS chunks (
@@ -59,24 +161,29 @@ pub fn init_source_db() -> Result<(), RagError> {
             start_pos INTEGER NOT NULL,
             end_pos INTEGER NOT NULL,
             chunk_type TEXT DEFAULT 'general',
+            header_path TEXT,
             embedding BLOB NOT NULL,
+            batch_id TEXT,
+            tenant_id TEXT NOT NULL DEFAULT 'default',
+            language TEXT,
+            embedding_norm REAL,
             FOREIGN KEY (source_id) REFERENCES sources(id) ON DELETE CASCADE
         )",
         [],
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    ).map_err(db_error)?;
     
     // Migration: Add chunk_type if missing
     let has_chunk_type: bool = conn.prepare("SELECT chunk_type FROM chunks LIMIT 1").is_ok();
     if !has_chunk_type {
         info!("[init_source_db] Migrating: adding chunk_type column");
-        conn.execute("ALTER TABLE chunks ADD COLUMN chunk_type TEXT DEFAULT 'general'", []).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+        conn.execute("ALTER TABLE chunks ADD COLUMN chunk_type TEXT DEFAULT 'general'", []).map_err(db_error)?;
     }
 
     // Migration: Add name if missing
     let has_name: bool = conn.prepare("SELECT name FROM sources LIMIT 1").is_ok();
     if !has_name {
         info!("[init_source_db] Migrating: adding name column to sources");
-        conn.execute("ALTER TABLE sources ADD COLUMN name TEXT", []).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+        conn.execute("ALTER TABLE sources ADD COLUMN name TEXT", []).map_err(db_error)?;
     }
     
     // Migration: Add status if missing
@@ -84,10 +191,96 @@ pub fn init_source_db() -> Result<(), RagError> {
     if (!has_status) {
         info!("[init_source_db] Migrating: adding status column to sources");
          // Default to 'completed' for existing sources (backward compatibility)
-        conn.execute("ALTER TABLE sources ADD COLUMN status TEXT DEFAULT 'completed'", []).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+        conn.execute("ALTER TABLE sources ADD COLUMN status TEXT DEFAULT 'completed'", []).map_err(db_error)?;
     }
     
-    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunks_source_id ON chunks(source_id)", []).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    // Migration: Add updated_at if missing (set when `update_source` commits)
+    let has_updated_at: bool = conn.prepare("SELECT updated_at FROM sources LIMIT 1").is_ok();
+    if !has_updated_at {
+        info!("[init_source_db] Migrating: adding updated_at column to sources");
+        conn.execute("ALTER TABLE sources ADD COLUMN updated_at INTEGER", []).map_err(db_error)?;
+    }
+    
+    // Migration: Add batch_id if missing
+    let has_batch_id: bool = conn.prepare("SELECT batch_id FROM chunks LIMIT 1").is_ok();
+    if !has_batch_id {
+        info!("[init_source_db] Migrating: adding batch_id column to chunks");
+        conn.execute("ALTER TABLE chunks ADD COLUMN batch_id TEXT", []).map_err(db_error)?;
+    }
+    
+    // Migration: Add tenant_id if missing (existing rows belong to the default tenant)
+    for table in ["sources", "chunks"] {
+        let has_tenant_id: bool = conn.prepare(&format!("SELECT tenant_id FROM {} LIMIT 1", table)).is_ok();
+        if !has_tenant_id {
+            info!("[init_source_db] Migrating: adding tenant_id column to {}", table);
+            conn.execute(&format!("ALTER TABLE {} ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default'", table), [])
+                .map_err(db_error)?;
+        }
+    }
+    
+    // Migration: Add language if missing, detected from existing content
+    let has_language: bool = conn.prepare("SELECT language FROM chunks LIMIT 1").is_ok();
+    if !has_language {
+        info!("[init_source_db] Migrating: adding language column to chunks");
+        conn.execute("ALTER TABLE chunks ADD COLUMN language TEXT", []).map_err(db_error)?;
+    }
+    // Also retries rows an earlier backfill couldn't decrypt.
+    backfill_chunk_languages(&conn).map_err(db_error)?;
+    
+    // Migration: Add header_path if missing, split out of the older
+    // "type|header path" chunk_type encoding
+    let has_header_path: bool = conn.prepare("SELECT header_path FROM chunks LIMIT 1").is_ok();
+    if !has_header_path {
+        info!("[init_source_db] Migrating: adding header_path column to chunks");
+        conn.execute("ALTER TABLE chunks ADD COLUMN header_path TEXT", []).map_err(db_error)?;
+        conn.execute(
+            "UPDATE chunks SET
+                 header_path = NULLIF(TRIM(substr(chunk_type, instr(chunk_type, '|') + 1)), ''),
+                 chunk_type = substr(chunk_type, 1, instr(chunk_type, '|') - 1)
+             WHERE instr(chunk_type, '|') > 0",
+            [],
+        ).map_err(db_error)?;
+    }
+    
+    // Migration: Add embedding_norm if missing, computed from existing embeddings
+    let has_embedding_norm: bool = conn.prepare("SELECT embedding_norm FROM chunks LIMIT 1").is_ok();
+    if !has_embedding_norm {
+        info!("[init_source_db] Migrating: adding embedding_norm column to chunks");
+        conn.execute("ALTER TABLE chunks ADD COLUMN embedding_norm REAL", []).map_err(db_error)?;
+        backfill_embedding_norms(&conn).map_err(db_error)?;
+    }
+    // Writers that change an embedding without its norm leave it to be
+    // recomputed by the linear scan.
+    conn.execute(
+        "CREATE TRIGGER IF NOT EXISTS chunks_reset_norm AFTER UPDATE OF embedding ON chunks
+         WHEN NEW.embedding_norm IS OLD.embedding_norm
+         BEGIN UPDATE chunks SET embedding_norm = NULL WHERE id = NEW.id; END",
+        [],
+    ).map_err(db_error)?;
+    
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunks_source_id ON chunks(source_id)", []).map_err(db_error)?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_sources_tenant_id ON sources(tenant_id)", []).map_err(db_error)?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunks_tenant_id ON chunks(tenant_id)", []).map_err(db_error)?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_chunks_batch_id ON chunks(batch_id)", []).map_err(db_error)?;
+    create_query_history_table(&conn).map_err(db_error)?;
+    create_search_analytics_tables(&conn).map_err(db_error)?;
+    create_chat_memory_table(&conn).map_err(db_error)?;
+    create_entity_table(&conn).map_err(db_error)?;
+    create_knowledge_graph_table(&conn).map_err(db_error)?;
+    create_ingest_jobs_table(&conn).map_err(db_error)?;
+    create_reembedding_jobs_table(&conn).map_err(db_error)?;
+    create_change_tracking(&conn).map_err(db_error)?;
+    create_classification_rules_table(&conn).map_err(db_error)?;
+    load_classification_rules(&conn).map_err(db_error)?;
+    create_synonyms_table(&conn).map_err(db_error)?;
+    load_synonyms(&conn).map_err(db_error)?;
+    create_staging_tables(&conn).map_err(db_error)?;
+    purge_expired(&conn).map_err(db_error)?;
+    if is_content_encryption_enabled() {
+        drop_fts_for_encryption(&conn).map_err(db_error)?;
+        purge_plaintext_side_tables(&conn).map_err(db_error)?;
+    }
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION).map_err(db_error)?;
     
     info!("[init_source_db] Tables created");
     Ok(())
@@ -109,30 +302,41 @@ pub fn add_source(
 ) -> Result<AddSourceResult, RagError> {
     info!("[add_source] Adding source, {} chars, name={:?}", content.len(), name);
     
-    let content_hash = hash_content(&content);
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let tenant_id = active_tenant();
+    let content_hash = hash_content(&content, &tenant_id);
+    let stored_content = encrypt_content(&content)?;
     
-    let existing: Option<i64> = conn
-        .query_row("SELECT id FROM sources WHERE content_hash = ?1", params![content_hash], |row| row.get(0))
-        .ok();
+    // The duplicate check runs inside the retried write so a concurrent
+    // insert of the same content is seen on retry.
+    let (source_id, is_duplicate) = with_write_retry("add_source", |conn| {
+        let existing: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM sources WHERE content_hash = ?1 AND tenant_id = ?2",
+                params![content_hash, tenant_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(id) = existing {
+            return Ok((id, true));
+        }
+        // New sources start as 'pending'
+        conn.execute(
+            "INSERT INTO sources (content, content_hash, metadata, name, status, tenant_id) VALUES (?1, ?2, ?3, ?4, 'pending', ?5)",
+            params![stored_content, content_hash, metadata, name, tenant_id],
+        )?;
+        Ok((conn.last_insert_rowid(), false))
+    })?;
     
-    if let Some(id) = existing {
-        info!("[add_source] Duplicate found: {}", id);
+    if is_duplicate {
+        info!("[add_source] Duplicate found: {}", source_id);
         return Ok(AddSourceResult {
-            source_id: id,
+            source_id,
             is_duplicate: true,
             chunk_count: 0,
-            message: format!("Source already exists (id={})", id),
+            message: format!("Source already exists (id={})", source_id),
         });
     }
     
-    // New sources start as 'pending'
-    conn.execute(
-        "INSERT INTO sources (content, content_hash, metadata, name, status) VALUES (?1, ?2, ?3, ?4, 'pending')",
-        params![content, content_hash, metadata, name],
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    
-    let source_id = conn.last_insert_rowid();
     info!("[add_source] Created source: {}", source_id);
     
     Ok(AddSourceResult {
@@ -143,13 +347,29 @@ pub fn add_source(
     })
 }
 
+/// Id and status of the active tenant's source with exactly `content`.
+pub(crate) fn find_source_by_content(content: &str) -> Result<Option<(i64, String)>, RagError> {
+    let tenant_id = active_tenant();
+    get_connection()?
+        .query_row(
+            "SELECT id, COALESCE(status, 'completed') FROM sources WHERE content_hash = ?1 AND tenant_id = ?2",
+            params![hash_content(content, &tenant_id), tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+        .map_err(db_error)
+}
+
 /// Update processing status of a source (e.g., 'pending', 'processing', 'completed', 'failed').
 pub fn update_source_status(source_id: i64, status: String) -> Result<(), RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    conn.execute(
-        "UPDATE sources SET status = ?1 WHERE id = ?2",
-        params![status, source_id],
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let tenant_id = active_tenant();
+    with_write_retry("update_source_status", |conn| {
+        conn.execute(
+            "UPDATE sources SET status = ?1 WHERE id = ?2 AND tenant_id = ?3",
+            params![status, source_id, tenant_id],
+        )?;
+        Ok(())
+    })?;
     info!("[update_source_status] Updated source {} to status '{}'", source_id, status);
     Ok(())
 }
@@ -164,12 +384,12 @@ pub struct SourceEntry {
 }
 
 pub fn list_sources() -> Result<Vec<SourceEntry>, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
     // Coalesce null status to 'completed' for legacy rows if any remains (though strict migration sets default)
-    let mut stmt = conn.prepare("SELECT id, name, created_at, metadata, status FROM sources ORDER BY id DESC")
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let mut stmt = conn.prepare("SELECT id, name, created_at, metadata, status FROM sources WHERE tenant_id = ?1 ORDER BY id DESC")
+        .map_err(db_error)?;
     
-    let sources = stmt.query_map([], |row| {
+    let sources = stmt.query_map(params![active_tenant()], |row| {
         Ok(SourceEntry {
             id: row.get(0)?,
             name: row.get(1)?,
@@ -178,7 +398,7 @@ pub fn list_sources() -> Result<Vec<SourceEntry>, RagError> {
             status: row.get(4)?,
         })
     })
-    .map_err(|e| RagError::DatabaseError(e.to_string()))?
+    .map_err(db_error)?
     .filter_map(|r| r.ok())
     .collect();
     
@@ -191,8 +411,54 @@ pub struct ChunkData {
     pub chunk_index: i32,
     pub start_pos: i32,
     pub end_pos: i32,
+    /// May carry a markdown header path as "type|header path"; it is
+    /// stored in its own column.
     pub chunk_type: String,
     pub embedding: Vec<f32>,
+    /// Shared by the sibling chunks of a split code block.
+    pub batch_id: Option<String>,
+}
+
+/// Chunk fields without the embedding, for `add_chunks_packed`.
+#[derive(Debug, Clone)]
+pub struct ChunkMeta {
+    pub content: String,
+    pub chunk_index: i32,
+    pub start_pos: i32,
+    pub end_pos: i32,
+    pub chunk_type: String,
+    pub batch_id: Option<String>,
+}
+
+/// A chunk row borrowed from `ChunkData`, a packed buffer or a staged ingest.
+pub(crate) struct ChunkRow<'a> {
+    pub content: &'a str,
+    pub chunk_index: i32,
+    pub start_pos: i32,
+    pub end_pos: i32,
+    pub chunk_type: &'a str,
+    pub header_path: Option<&'a str>,
+    pub batch_id: Option<&'a str>,
+    pub embedding: &'a [f32],
+}
+
+/// Split a "type|header path" chunk type (as the Dart service passes
+/// markdown chunks) into the type and a non-empty header path.
+pub(crate) fn split_chunk_type(chunk_type: &str) -> (&str, Option<&str>) {
+    match chunk_type.split_once('|') {
+        Some((base, path)) => (base, Some(path.trim()).filter(|p| !p.is_empty())),
+        None => (chunk_type, None),
+    }
+}
+
+/// An existing chunk kept by a source update, at its new position.
+pub(crate) struct KeptChunk<'a> {
+    pub chunk_id: i64,
+    pub chunk_index: i32,
+    pub start_pos: i32,
+    pub end_pos: i32,
+    pub chunk_type: &'a str,
+    pub header_path: Option<&'a str>,
 }
 
 /// Add chunks for a source (uses transaction for atomicity).
@@ -200,81 +466,419 @@ pub fn add_chunks(
     source_id: i64,
     chunks: Vec<ChunkData>,
 ) -> Result<i32, RagError> {
-    info!("[add_chunks] Adding {} chunks for source {}", chunks.len(), source_id);
-    
-    let mut conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let tx = conn.transaction().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    
-    for chunk in &chunks {
-        let mut embedding_bytes: Vec<u8> = Vec::with_capacity(chunk.embedding.len() * 4);
-        for f in &chunk.embedding {
-            embedding_bytes.extend_from_slice(&f.to_ne_bytes());
-        }
+    let rows: Vec<ChunkRow> = chunks
+        .iter()
+        .map(|c| {
+            let (chunk_type, header_path) = split_chunk_type(&c.chunk_type);
+            ChunkRow {
+                content: &c.content,
+                chunk_index: c.chunk_index,
+                start_pos: c.start_pos,
+                end_pos: c.end_pos,
+                chunk_type,
+                header_path,
+                batch_id: c.batch_id.as_deref(),
+                embedding: &c.embedding,
+            }
+        })
+        .collect();
+    store_chunks(source_id, &rows)
+}
+
+/// Add chunks whose embeddings arrive as one packed buffer.
+///
+/// Chunk `i`'s embedding is `embeddings[offsets[i]..offsets[i + 1]]`, so
+/// `offsets` has `chunks.len() + 1` entries and ends at `embeddings.len()`.
+/// One `Float32List` crosses the FFI instead of a list per chunk, which
+/// cuts copies and allocations for bulk ingestion.
+pub fn add_chunks_packed(
+    source_id: i64,
+    chunks: Vec<ChunkMeta>,
+    embeddings: Vec<f32>,
+    offsets: Vec<u32>,
+) -> Result<i32, RagError> {
+    validate_offsets(chunks.len(), embeddings.len(), &offsets)?;
+    let rows: Vec<ChunkRow> = chunks
+        .iter()
+        .zip(offsets.windows(2))
+        .map(|(c, w)| {
+            let (chunk_type, header_path) = split_chunk_type(&c.chunk_type);
+            ChunkRow {
+                content: &c.content,
+                chunk_index: c.chunk_index,
+                start_pos: c.start_pos,
+                end_pos: c.end_pos,
+                chunk_type,
+                header_path,
+                batch_id: c.batch_id.as_deref(),
+                embedding: &embeddings[w[0] as usize..w[1] as usize],
+            }
+        })
+        .collect();
+    store_chunks(source_id, &rows)
+}
+
+fn validate_offsets(chunk_count: usize, embedding_len: usize, offsets: &[u32]) -> Result<(), RagError> {
+    if offsets.len() != chunk_count + 1 {
+        return Err(RagError::InvalidInput(format!(
+            "Expected {} offsets for {} chunks, got {}", chunk_count + 1, chunk_count, offsets.len()
+        )));
+    }
+    if offsets.first() != Some(&0) || offsets.last().map(|&o| o as usize) != Some(embedding_len) {
+        return Err(RagError::InvalidInput(format!(
+            "Offsets must start at 0 and end at the buffer length ({})", embedding_len
+        )));
+    }
+    if let Some(i) = offsets.windows(2).position(|w| w[1] <= w[0]) {
+        return Err(RagError::InvalidInput(format!("Chunk {} has an empty or negative embedding range", i)));
+    }
+    Ok(())
+}
+
+/// Insert `chunks` of `source_id` within `tx`; returns their ids.
+fn insert_chunk_rows(
+    tx: &rusqlite::Connection,
+    tenant_id: &str,
+    source_id: i64,
+    chunks: &[ChunkRow],
+) -> Result<Vec<i64>, WriteError> {
+    // Entities and triples are plaintext side tables, so they are not
+    // extracted while content encryption is on.
+    let encrypted = is_content_encryption_enabled();
+    let mut ids = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let embedding_bytes = encode_embedding(chunk.embedding);
         
         tx.execute(
-            "INSERT INTO chunks (source_id, chunk_index, content, start_pos, end_pos, chunk_type, embedding)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![source_id, chunk.chunk_index, chunk.content, chunk.start_pos, chunk.end_pos, chunk.chunk_type, embedding_bytes],
-        ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+            "INSERT INTO chunks (source_id, chunk_index, content, start_pos, end_pos, chunk_type, header_path, embedding, batch_id, tenant_id, language, embedding_norm)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![source_id, chunk.chunk_index, encrypt_content(chunk.content)?, chunk.start_pos, chunk.end_pos, chunk.chunk_type, chunk.header_path, embedding_bytes, chunk.batch_id, tenant_id, detect_language_code(chunk.content), norm(chunk.embedding)],
+        )?;
+        let chunk_id = tx.last_insert_rowid();
+        ids.push(chunk_id);
+        if !encrypted {
+            store_chunk_entities(tx, chunk_id, chunk.content)?;
+            store_chunk_triples(tx, chunk_id, chunk.content)?;
+        }
+    }
+    Ok(ids)
+}
+
+/// Put chunks just committed (moving the vector version from
+/// `versions.0` to `versions.1`) straight into the live index instead of
+/// waiting for a rebuild.
+fn index_new_chunks(tenant_id: &str, ids: Vec<i64>, chunks: &[ChunkRow], versions: (i64, i64), progress: Progress) {
+    let points = ids.into_iter().zip(chunks).map(|(id, chunk)| (id, chunk.embedding.to_vec())).collect();
+    match insert_into_tenant_index(tenant_id, points, progress) {
+        Ok(Some(inserted)) => {
+            advance_indexed(tenant_id, IndexKind::Vector, versions.0, versions.1);
+            debug!("[add_chunks] Inserted {} chunks into the live index", inserted);
+        }
+        Ok(None) => {}
+        Err(e) => warn!("[add_chunks] Live index insert failed, index needs a rebuild: {}", e),
     }
+}
+
+fn store_chunks(source_id: i64, chunks: &[ChunkRow]) -> Result<i32, RagError> {
+    info!("[add_chunks] Adding {} chunks for source {}", chunks.len(), source_id);
+    let progress = Progress::new(OP_ADD_CHUNKS);
+    
+    let tenant_id = active_tenant();
     
-    tx.commit().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let (ids, versions) = with_write_retry("add_chunks", |conn| {
+        let owned: bool = conn
+            .query_row(
+                "SELECT 1 FROM sources WHERE id = ?1 AND tenant_id = ?2",
+                params![source_id, tenant_id],
+                |_| Ok(true),
+            )
+            .optional()?
+            .unwrap_or(false);
+        if !owned {
+            return Err(RagError::InvalidInput(format!(
+                "Source {} does not belong to tenant '{}'", source_id, tenant_id
+            )).into());
+        }
+        let tx = conn.transaction()?;
+        let (before, _) = change_versions(&tx, &tenant_id)?;
+        let ids = insert_chunk_rows(&tx, &tenant_id, source_id, chunks)?;
+        let (after, _) = change_versions(&tx, &tenant_id)?;
+        tx.commit()?;
+        Ok((ids, (before, after)))
+    })?;
     info!("[add_chunks] Added {} chunks", chunks.len());
+    progress.report(PHASE_INSERT, chunks.len(), chunks.len());
+
+    index_new_chunks(&tenant_id, ids, chunks, versions, progress);
     Ok(chunks.len() as i32)
 }
 
+/// Add a source together with its embedded chunks in one transaction, so
+/// the source is never visible without them. The source is stored as
+/// `completed`; duplicate content stores nothing. `finish` runs in the
+/// same transaction just before it commits, duplicate or not.
+pub(crate) fn add_source_with_chunks(
+    content: &str,
+    metadata: Option<String>,
+    name: Option<String>,
+    chunks: &[ChunkRow],
+    finish: &dyn Fn(&Connection) -> rusqlite::Result<()>,
+) -> Result<AddSourceResult, RagError> {
+    info!("[add_source] Adding source with {} chunks, name={:?}", chunks.len(), name);
+    let progress = Progress::new(OP_ADD_CHUNKS);
+    let tenant_id = active_tenant();
+    let content_hash = hash_content(content, &tenant_id);
+    let stored_content = encrypt_content(content)?;
+
+    let (source_id, stored) = with_write_retry("add_source_with_chunks", |conn| {
+        let tx = conn.transaction()?;
+        let existing: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM sources WHERE content_hash = ?1 AND tenant_id = ?2",
+                params![content_hash, tenant_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(id) = existing {
+            finish(&tx)?;
+            tx.commit()?;
+            return Ok((id, None));
+        }
+        let (before, _) = change_versions(&tx, &tenant_id)?;
+        tx.execute(
+            "INSERT INTO sources (content, content_hash, metadata, name, status, tenant_id) VALUES (?1, ?2, ?3, ?4, 'completed', ?5)",
+            params![stored_content, content_hash, metadata, name, tenant_id],
+        )?;
+        let source_id = tx.last_insert_rowid();
+        let ids = insert_chunk_rows(&tx, &tenant_id, source_id, chunks)?;
+        let (after, _) = change_versions(&tx, &tenant_id)?;
+        finish(&tx)?;
+        tx.commit()?;
+        Ok((source_id, Some((ids, (before, after)))))
+    })?;
+
+    match stored {
+        None => {
+            info!("[add_source] Duplicate found: {}", source_id);
+            Ok(AddSourceResult {
+                source_id,
+                is_duplicate: true,
+                chunk_count: 0,
+                message: format!("Source already exists (id={})", source_id),
+            })
+        }
+        Some((ids, versions)) => {
+            info!("[add_source] Created source {} with {} chunks", source_id, chunks.len());
+            progress.report(PHASE_INSERT, chunks.len(), chunks.len());
+            index_new_chunks(&tenant_id, ids, chunks, versions, progress);
+            Ok(AddSourceResult {
+                source_id,
+                is_duplicate: false,
+                chunk_count: chunks.len() as i32,
+                message: format!("Source created with {} chunks", chunks.len()),
+            })
+        }
+    }
+}
+
+/// Replace a source's content in one transaction: `kept` chunks move to
+/// their new positions with their embeddings, `added` chunks are inserted
+/// and every other chunk of the source is deleted. `metadata` and `name`
+/// replace the stored ones when set, and `updated_at` is set to now.
+/// `added` must match the dimension of the kept embeddings. `finish` runs
+/// in the same transaction just before it commits. Returns how many
+/// chunks were deleted.
+pub(crate) fn update_source_with_chunks(
+    source_id: i64,
+    content: &str,
+    metadata: Option<String>,
+    name: Option<String>,
+    kept: &[KeptChunk],
+    added: &[ChunkRow],
+    finish: &dyn Fn(&Connection) -> rusqlite::Result<()>,
+) -> Result<usize, RagError> {
+    info!("[update_source] Updating source {}: {} kept, {} new chunks", source_id, kept.len(), added.len());
+    let progress = Progress::new(OP_ADD_CHUNKS);
+    let tenant_id = active_tenant();
+    let content_hash = hash_content(content, &tenant_id);
+    let stored_content = encrypt_content(content)?;
+    let kept_ids: HashSet<i64> = kept.iter().map(|k| k.chunk_id).collect();
+
+    let (removed, ids, versions) = with_write_retry("update_source", |conn| {
+        let tx = conn.transaction()?;
+        let (before, _) = change_versions(&tx, &tenant_id)?;
+        let updated = tx.execute(
+            "UPDATE sources SET content = ?1, content_hash = ?2, metadata = COALESCE(?3, metadata),
+                 name = COALESCE(?4, name), status = 'completed', updated_at = strftime('%s', 'now')
+             WHERE id = ?5 AND tenant_id = ?6",
+            params![stored_content, content_hash, metadata, name, source_id, tenant_id],
+        )?;
+        if updated == 0 {
+            return Err(RagError::InvalidInput(format!(
+                "Source {} does not belong to tenant '{}'", source_id, tenant_id
+            )).into());
+        }
+        let existing: HashSet<i64> = tx
+            .prepare("SELECT id FROM chunks WHERE source_id = ?1 AND tenant_id = ?2")?
+            .query_map(params![source_id, tenant_id], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        if !kept_ids.is_subset(&existing) {
+            return Err(RagError::InvalidInput(format!(
+                "Chunks of source {} changed since the update was prepared", source_id
+            )).into());
+        }
+        if let (Some(first_kept), Some(first_added)) = (kept.first(), added.first()) {
+            let blob: Vec<u8> = tx.query_row(
+                "SELECT embedding FROM chunks WHERE id = ?1",
+                params![first_kept.chunk_id],
+                |row| row.get(0),
+            )?;
+            let kept_dimension = stored_dimension(blob.len(), &blob);
+            if kept_dimension != Some(first_added.embedding.len()) {
+                return Err(RagError::InvalidInput(format!(
+                    "New embeddings have dimension {}, the kept chunks of source {} have {:?}",
+                    first_added.embedding.len(), source_id, kept_dimension
+                )).into());
+            }
+        }
+        let removed: Vec<i64> = existing.difference(&kept_ids).copied().collect();
+        for chunk_id in &removed {
+            tx.execute("DELETE FROM chunk_entities WHERE chunk_id = ?1", params![chunk_id])?;
+            tx.execute("DELETE FROM kg_triples WHERE chunk_id = ?1", params![chunk_id])?;
+            tx.execute("DELETE FROM chunks WHERE id = ?1", params![chunk_id])?;
+        }
+        for chunk in kept {
+            tx.execute(
+                "UPDATE chunks SET chunk_index = ?1, start_pos = ?2, end_pos = ?3, chunk_type = ?4, header_path = ?5
+                 WHERE id = ?6",
+                params![chunk.chunk_index, chunk.start_pos, chunk.end_pos, chunk.chunk_type, chunk.header_path, chunk.chunk_id],
+            )?;
+        }
+        let ids = insert_chunk_rows(&tx, &tenant_id, source_id, added)?;
+        let (after, _) = change_versions(&tx, &tenant_id)?;
+        finish(&tx)?;
+        tx.commit()?;
+        Ok((removed, ids, (before, after)))
+    })?;
+    progress.report(PHASE_INSERT, added.len(), added.len());
+
+    // As in `delete_source`, searches skip the removed points until `compact_index`.
+    add_tombstones(&tenant_id, &removed);
+    delete_named_chunks(&tenant_id, &removed);
+    delete_named_bm25_chunks(&tenant_id, &removed);
+    drop_source_index(&tenant_id, source_id);
+    if ids.is_empty() {
+        advance_indexed(&tenant_id, IndexKind::Vector, versions.0, versions.1);
+    } else {
+        index_new_chunks(&tenant_id, ids, added, versions, progress);
+    }
+    info!("[update_source] Updated source {} ({} chunks deleted)", source_id, removed.len());
+    Ok(removed.len())
+}
+
 /// Rebuild HNSW index from chunks table.
+///
+/// The result is also saved next to the database for `ensure_chunk_index`.
 pub fn rebuild_chunk_hnsw_index() -> Result<(), RagError> {
-    info!("[rebuild_chunk_hnsw] Starting");
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    rebuild_tenant_chunk_hnsw(&active_tenant(), Progress::new(OP_HNSW_REBUILD))
+}
+
+/// `rebuild_chunk_hnsw_index` for `tenant_id`, whichever tenant is active.
+pub(crate) fn rebuild_tenant_chunk_hnsw(tenant_id: &str, progress: Progress) -> Result<(), RagError> {
+    info!("[rebuild_chunk_hnsw] Starting for tenant {}", tenant_id);
+    record_index_rebuild();
+    let conn = get_connection()?;
+    let tenant_id = tenant_id.to_string();
+    // Read before the rows so changes made during the build mark it stale.
+    let state = chunk_table_state(&conn, &tenant_id).ok();
     
-    let mut stmt = conn.prepare("SELECT id, embedding FROM chunks")
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let mut stmt = conn.prepare("SELECT id, embedding FROM chunks WHERE tenant_id = ?1")
+        .map_err(db_error)?;
     
-    let points: Vec<(i64, Vec<f32>)> = stmt.query_map([], |row| {
+    let points: Vec<(i64, Vec<f32>)> = stmt.query_map(params![tenant_id], |row| {
         let id: i64 = row.get(0)?;
         let embedding_blob: Vec<u8> = row.get(1)?;
-        let mut embedding = Vec::with_capacity(embedding_blob.len() / 4);
-        for chunk in embedding_blob.chunks_exact(4) {
-            embedding.push(f32::from_ne_bytes(chunk.try_into().unwrap()));
-        }
-        Ok((id, embedding))
+        Ok((id, decode_embedding(&embedding_blob)))
     })
-    .map_err(|e| RagError::DatabaseError(e.to_string()))?
+    .map_err(db_error)?
     .filter_map(|r| r.ok())
     .collect();
     
-    if !points.is_empty() {
-        build_hnsw_index(points).map_err(|e| RagError::InternalError(e.to_string()))?;
-        // Note: save_hnsw_index needs db_path for marker file
-        // This is acceptable as it's a one-time operation
+    drop_tenant_source_indexes(&tenant_id);
+    if points.is_empty() {
+        drop_tenant_hnsw_index(&tenant_id);
+    } else {
+        build_hnsw_index_with_progress(&tenant_id, points, progress)?;
         info!("[rebuild_chunk_hnsw] Built index");
     }
+    if let Some(state) = state {
+        record_indexed(&tenant_id, IndexKind::Vector, state.vector_version);
+        // The in-memory index is usable either way; a failed save only costs a rebuild.
+        if let Err(e) = persist_chunk_index(&tenant_id, state) {
+            warn!("[rebuild_chunk_hnsw] Could not save index: {}", e);
+        }
+    }
     
     Ok(())
 }
 
+/// Share of deleted points in the chunk index above which `compact_index`
+/// rebuilds it.
+const COMPACT_TOMBSTONE_RATIO: f64 = 0.2;
+
+/// Rebuild the chunk HNSW index if more than 20% of its points belong to
+/// deleted chunks, dropping them (see `delete_source`). Returns whether it
+/// rebuilt.
+pub fn compact_index() -> Result<bool, RagError> {
+    let deleted = tombstone_count();
+    let points = hnsw_point_count().unwrap_or(0);
+    if deleted == 0 || (deleted as f64) <= points as f64 * COMPACT_TOMBSTONE_RATIO {
+        debug!("[compact_index] {} of {} points deleted, nothing to do", deleted, points);
+        return Ok(false);
+    }
+    info!("[compact_index] Rebuilding to drop {} of {} points", deleted, points);
+    rebuild_chunk_hnsw_index()?;
+    Ok(true)
+}
+
+/// Rebuild the chunk HNSW index with explicit build parameters (`None`
+/// returns to the adaptive ones); later rebuilds keep using them.
+pub fn rebuild_chunk_hnsw_index_with_config(config: Option<HnswBuildConfig>) -> Result<(), RagError> {
+    set_hnsw_build_config(config)?;
+    rebuild_chunk_hnsw_index()
+}
+
 /// Rebuild BM25 index from chunks table.
 pub fn rebuild_chunk_bm25_index() -> Result<(), RagError> {
-    info!("[rebuild_chunk_bm25] Starting");
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    rebuild_tenant_chunk_bm25(&active_tenant(), Progress::new(OP_BM25_REBUILD))
+}
+
+/// `rebuild_chunk_bm25_index` for `tenant_id`, whichever tenant is active.
+pub(crate) fn rebuild_tenant_chunk_bm25(tenant_id: &str, progress: Progress) -> Result<(), RagError> {
+    info!("[rebuild_chunk_bm25] Starting for tenant {}", tenant_id);
+    record_index_rebuild();
+    let conn = get_connection()?;
+    let version = change_versions(&conn, tenant_id).ok().map(|(_, text)| text);
     
     // Clear existing BM25 index
-    bm25_clear_index();
+    bm25_clear_tenant_index(tenant_id);
     
-    let mut stmt = conn.prepare("SELECT id, content FROM chunks")
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let mut stmt = conn.prepare("SELECT id, content FROM chunks WHERE tenant_id = ?1")
+        .map_err(db_error)?;
     
-    let docs: Vec<(i64, String)> = stmt.query_map([], |row| {
-        Ok((row.get(0)?, row.get(1)?))
+    let docs: Vec<(i64, String)> = stmt.query_map(params![tenant_id], |row| {
+        Ok((row.get(0)?, read_content(row, 1)?))
     })
-    .map_err(|e| RagError::DatabaseError(e.to_string()))?
-    .filter_map(|r| r.ok())
-    .collect();
+    .map_err(db_error)?
+    .collect::<rusqlite::Result<_>>()
+    .map_err(db_error)?;
     
     if !docs.is_empty() {
         info!("[rebuild_chunk_bm25] Building index from {} chunks", docs.len());
-        bm25_add_documents(docs);
+        bm25_add_documents_with_progress(tenant_id, docs, progress);
+    }
+    if let Some(version) = version {
+        record_indexed(tenant_id, IndexKind::Text, version);
     }
     
     info!("[rebuild_chunk_bm25] Complete");
@@ -293,130 +897,292 @@ pub struct ChunkSearchResult {
     pub chunk_index: i32,
     pub content: String,
     pub chunk_type: String,
+    /// Markdown header path ("Setup > Install") of the chunk, if any.
+    pub header_path: Option<String>,
     pub similarity: f64,
     pub metadata: Option<String>,
 }
 
+/// Analytics for a vector-only search; it has no query text, so all of
+/// them share the empty-query hash.
+pub(crate) fn record_chunk_search_event(latency: Duration, results: &[ChunkSearchResult]) {
+    let ids: Vec<i64> = results.iter().map(|r| r.chunk_id).collect();
+    record_search_event("chunks", None, latency, &ids);
+}
+
 /// Search chunks by embedding similarity.
+///
+/// `similarity` is `1 - distance` under the configured `DistanceMetric`.
 pub fn search_chunks(
     query_embedding: Vec<f32>,
     top_k: u32,
 ) -> Result<Vec<ChunkSearchResult>, RagError> {
     info!("[search_chunks] Searching, top_k={}", top_k);
+    let _query = query_guard();
     
     // HNSW index enabled - use O(log n) search when index is available
     // Falls back to linear scan if index not loaded
     
+    let started = Instant::now();
     let hnsw_loaded = is_hnsw_index_loaded();
     
-    if !hnsw_loaded {
+    if hnsw_loaded {
+        record_cache_hit();
+    } else {
         // HNSW index is in-memory only - rebuild from DB on each app launch
         debug!("[search_chunks] HNSW not in memory, rebuilding from DB");
+        record_cache_miss();
         rebuild_chunk_hnsw_index()?;
     }
     
     if !is_hnsw_index_loaded() {
         debug!("[search_chunks] Falling back to linear scan");
-        return search_chunks_linear(query_embedding, top_k);
+        let results = search_chunks_linear(query_embedding, top_k)?;
+        record_chunk_retrieval(&results);
+        record_search(started.elapsed());
+        record_chunk_search_event(started.elapsed(), &results);
+        return Ok(results);
     }
     
     debug!("[search_chunks] Using HNSW index");
     
-    let hnsw_results = search_hnsw(query_embedding, top_k as usize)
-        .map_err(|e| RagError::InternalError(e.to_string()))?;
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let hnsw_results = search_hnsw(query_embedding, top_k as usize)?;
+    let conn = get_connection()?;
+    let tenant_id = active_tenant();
     
-    let mut results = Vec::new();
-    for result in hnsw_results {
-        let row: Option<(i64, i32, String, String, Option<String>)> = conn
-            .query_row(
-                "SELECT c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), s.metadata 
-                 FROM chunks c
-                 LEFT JOIN sources s ON c.source_id = s.id
-                 WHERE c.id = ?1",
-                params![result.id],
-                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
-            )
-            .ok();
-        
-        if let Some((source_id, chunk_index, content, chunk_type, metadata)) = row {
-            results.push(ChunkSearchResult {
-                chunk_id: result.id,
-                source_id,
-                chunk_index,
-                content,
-                chunk_type,
-                similarity: 1.0 - result.distance as f64,
-                metadata,
-            });
-        }
-    }
+    let results: Vec<ChunkSearchResult> = hnsw_results
+        .into_iter()
+        .filter_map(|result| load_chunk_result(&conn, &tenant_id, result.id, 1.0 - result.distance as f64).transpose())
+        .collect::<Result<_, _>>()?;
     
     info!("[search_chunks] Found {} results", results.len());
+    record_chunk_retrieval(&results);
+    record_search(started.elapsed());
+    record_chunk_search_event(started.elapsed(), &results);
+    Ok(results)
+}
+
+/// `search_chunks` with MMR diversification (as with
+/// `HybridSearchOptions::diversity_lambda`): `diversity_lambda` 1.0 keeps
+/// the similarity order, lower values skip chunks that repeat ones
+/// already picked.
+pub fn search_chunks_diverse(
+    query_embedding: Vec<f32>,
+    top_k: u32,
+    diversity_lambda: f64,
+) -> Result<Vec<ChunkSearchResult>, RagError> {
+    validate_lambda(diversity_lambda)?;
+    let results = search_chunks(query_embedding, top_k.saturating_mul(MMR_FETCH_MULTIPLIER))?;
+    if results.is_empty() {
+        return Ok(results);
+    }
+    let ids: Vec<i64> = results.iter().map(|r| r.chunk_id).collect();
+    let embeddings = {
+        let conn = get_connection()?;
+        load_embeddings(&conn, &ids)?
+    };
+    // Similarities are rescaled to [0, 1] so they weigh the same as the
+    // cosine redundancy under every metric.
+    let (min, max) = results.iter().fold((f64::MAX, f64::MIN), |(lo, hi), r| {
+        (lo.min(r.similarity), hi.max(r.similarity))
+    });
+    let span = (max - min).max(f64::EPSILON);
+    let candidates: Vec<(i64, f64)> = results
+        .iter()
+        .map(|r| (r.chunk_id, (r.similarity - min) / span))
+        .collect();
+    let order = mmr_select(
+        &candidates,
+        |id| embeddings.get(&id).map(|e| e.as_slice()),
+        diversity_lambda,
+        top_k as usize,
+    );
+    let mut slots: Vec<Option<ChunkSearchResult>> = results.into_iter().map(Some).collect();
+    let results: Vec<ChunkSearchResult> = order.into_iter().filter_map(|i| slots[i].take()).collect();
+    record_chunk_retrieval(&results);
     Ok(results)
 }
 
+/// One page of `search_chunks_paged` results.
+#[derive(Debug, Clone)]
+pub struct ChunkSearchPage {
+    pub results: Vec<ChunkSearchResult>,
+    /// Pass to `search_chunks_next_page` for the following page; `None`
+    /// once there are no more results.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone)]
+struct PagedChunkRanking {
+    query_embedding: Vec<f32>,
+    results: Vec<ChunkSearchResult>,
+    exhausted: bool,
+}
+
+static CHUNK_RANKINGS: Lazy<RwLock<RankingCache<PagedChunkRanking>>> =
+    Lazy::new(|| RwLock::new(RankingCache::new()));
+
+/// `search_chunks` returning `page_size` results starting at `offset`,
+/// plus a cursor for the next page (see `HybridSearchOptions::page_offset`).
+pub fn search_chunks_paged(
+    query_embedding: Vec<f32>,
+    page_size: u32,
+    offset: u32,
+) -> Result<ChunkSearchPage, RagError> {
+    if page_size == 0 {
+        return Err(RagError::InvalidInput("page_size must be at least 1".to_string()));
+    }
+    let (page_size, offset) = (page_size as usize, offset as usize);
+    let mut ranking = PagedChunkRanking {
+        query_embedding,
+        results: Vec::new(),
+        exhausted: false,
+    };
+    deepen_chunks(&mut ranking, 0, offset.saturating_add(page_size.saturating_mul(PAGES_PER_RANKING)))?;
+    let id = CHUNK_RANKINGS.write().unwrap().insert(active_tenant(), ranking.clone());
+    Ok(chunk_page(id, &ranking, offset, page_size))
+}
+
+/// The page after the one `cursor` came with, never repeating results.
+pub fn search_chunks_next_page(
+    cursor: String,
+    page_size: u32,
+) -> Result<ChunkSearchPage, RagError> {
+    if page_size == 0 {
+        return Err(RagError::InvalidInput("page_size must be at least 1".to_string()));
+    }
+    let page_size = page_size as usize;
+    let (id, offset) = decode_cursor(&cursor)?;
+    let mut ranking = CHUNK_RANKINGS.read().unwrap().get(id, &active_tenant())?;
+    if offset.saturating_add(page_size) > ranking.results.len() && !ranking.exhausted {
+        let depth = offset.saturating_add(page_size.saturating_mul(PAGES_PER_RANKING));
+        deepen_chunks(&mut ranking, offset, depth)?;
+        CHUNK_RANKINGS.write().unwrap().update(id, ranking.clone());
+    }
+    Ok(chunk_page(id, &ranking, offset, page_size))
+}
+
+/// Search `depth` deep, keeping the first `delivered` results in place.
+fn deepen_chunks(ranking: &mut PagedChunkRanking, delivered: usize, depth: usize) -> Result<(), RagError> {
+    let depth = depth.min(u32::MAX as usize);
+    let mut fresh = search_chunks(ranking.query_embedding.clone(), depth as u32)?;
+    // The graph returns equal distances in no fixed order; order them by
+    // id so a deeper search agrees with the pages already served.
+    fresh.sort_by(|a, b| b.similarity.total_cmp(&a.similarity).then(a.chunk_id.cmp(&b.chunk_id)));
+    ranking.exhausted = fresh.len() < depth;
+    ranking.results = merge_deeper(&ranking.results, delivered, fresh, |r| r.chunk_id);
+    Ok(())
+}
+
+fn chunk_page(id: u64, ranking: &PagedChunkRanking, offset: usize, page_size: usize) -> ChunkSearchPage {
+    let start = offset.min(ranking.results.len());
+    let end = offset.saturating_add(page_size).min(ranking.results.len());
+    let results = ranking.results[start..end].to_vec();
+    record_chunk_retrieval(&results);
+    let more = end < ranking.results.len() || !ranking.exhausted;
+    ChunkSearchPage {
+        results,
+        next_cursor: more.then(|| encode_cursor(id, end)),
+    }
+}
+
+/// Result row for `chunk_id`, or `None` if it no longer exists.
+pub(crate) fn load_chunk_result(
+    conn: &rusqlite::Connection,
+    tenant_id: &str,
+    chunk_id: i64,
+    similarity: f64,
+) -> Result<Option<ChunkSearchResult>, RagError> {
+    conn.query_row(
+        "SELECT c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), s.metadata, c.header_path
+         FROM chunks c
+         LEFT JOIN sources s ON c.source_id = s.id
+         WHERE c.id = ?1 AND c.tenant_id = ?2",
+        params![chunk_id, tenant_id],
+        |row| Ok(ChunkSearchResult {
+            chunk_id,
+            source_id: row.get(0)?,
+            chunk_index: row.get(1)?,
+            content: read_content(row, 2)?,
+            chunk_type: row.get(3)?,
+            header_path: row.get(5)?,
+            similarity,
+            metadata: row.get(4)?,
+        }),
+    )
+    .optional()
+    .map_err(db_error)
+}
+
+/// Exact scan over the active tenant's stored embeddings, used while no
+/// index is loaded. Only the best `top_k` rows are fetched in full.
 fn search_chunks_linear(
     query_embedding: Vec<f32>,
     top_k: u32,
 ) -> Result<Vec<ChunkSearchResult>, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let top_k = top_k as usize;
+    let conn = get_connection()?;
+    let tenant_id = active_tenant();
     let mut stmt = conn.prepare(
-        "SELECT c.id, c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), c.embedding, s.metadata 
-         FROM chunks c
-         LEFT JOIN sources s ON c.source_id = s.id"
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    
-    let query_vec = Array1::from(query_embedding.clone());
-    let query_norm = query_vec.mapv(|x| x * x).sum().sqrt();
+        "SELECT id, embedding, embedding_norm FROM chunks WHERE tenant_id = ?1"
+    ).map_err(db_error)?;
     
-    let mut candidates: Vec<(f64, i64, i64, i32, String, String, Option<String>)> = Vec::new();
+    let metric = get_index_config().metric;
+    let query_norm = norm(&query_embedding);
+    // Decoded into for every row instead of allocating per row.
+    let mut embedding: Vec<f32> = Vec::with_capacity(query_embedding.len());
+    let mut scored: Vec<(f32, i64)> = Vec::new();
     
-    let rows = stmt.query_map([], |row| {
-        Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get::<_, Vec<u8>>(5)?, row.get(6)?))
-    }).map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    
-    for row in rows {
-        let (id, source_id, chunk_index, content, chunk_type, embedding_blob, metadata): (i64, i64, i32, String, String, Vec<u8>, Option<String>) = row.map_err(|e| RagError::DatabaseError(e.to_string()))?;
-        
-        let embedding: Vec<f32> = embedding_blob.chunks(4)
-            .map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap()))
-            .collect();
-        
+    let mut rows = stmt.query(params![tenant_id]).map_err(db_error)?;
+    while let Some(row) = rows.next().map_err(db_error)? {
+        decode_embedding_into(row.get_ref(1).map_err(db_error)?.as_blob().unwrap_or_default(), &mut embedding);
         if embedding.len() != query_embedding.len() { continue; }
-        
-        let target_vec = Array1::from(embedding);
-        let target_norm = target_vec.mapv(|x| x * x).sum().sqrt();
-        let dot_product = query_vec.dot(&target_vec);
-        
-        let similarity = if query_norm == 0.0 || target_norm == 0.0 { 0.0 }
-        else { (dot_product / (query_norm * target_norm)) as f64 };
-        
-        candidates.push((similarity, id, source_id, chunk_index, content, chunk_type, metadata));
+        // Rows written before the column existed, or re-embedded without
+        // it, have no stored norm.
+        let stored_norm: Option<f64> = row.get(2).map_err(db_error)?;
+        let target_norm = stored_norm.map_or_else(|| norm(&embedding), |n| n as f32);
+        let distance = metric.distance(dot(&query_embedding, &embedding), query_norm, target_norm);
+        scored.push((distance, row.get(0).map_err(db_error)?));
     }
+    drop(rows);
     
-    candidates.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+    let by_distance = |a: &(f32, i64), b: &(f32, i64)| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1));
+    if top_k == 0 {
+        return Ok(Vec::new());
+    }
+    if scored.len() > top_k {
+        scored.select_nth_unstable_by(top_k - 1, by_distance);
+        scored.truncate(top_k);
+    }
+    scored.sort_by(by_distance);
     
-    Ok(candidates.into_iter().take(top_k as usize)
-        .map(|(sim, id, source_id, chunk_index, content, chunk_type, metadata)| ChunkSearchResult {
-            chunk_id: id, source_id, chunk_index, content, chunk_type, similarity: sim, metadata,
-        }).collect())
+    scored
+        .into_iter()
+        .filter_map(|(distance, id)| load_chunk_result(&conn, &tenant_id, id, 1.0 - distance as f64).transpose())
+        .collect()
 }
 
 /// Get source document by ID.
 pub fn get_source(source_id: i64) -> Result<Option<String>, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    Ok(conn.query_row("SELECT content FROM sources WHERE id = ?1", params![source_id], |row| row.get(0)).ok())
+    let conn = get_connection()?;
+    conn.query_row(
+        "SELECT content FROM sources WHERE id = ?1 AND tenant_id = ?2",
+        params![source_id, active_tenant()],
+        |row| read_content(row, 0),
+    )
+    .optional()
+    .map_err(db_error)
 }
 
 /// Get all chunks for a source.
 pub fn get_source_chunks(source_id: i64) -> Result<Vec<String>, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let mut stmt = conn.prepare("SELECT content FROM chunks WHERE source_id = ?1 ORDER BY chunk_index")
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let chunks: Vec<String> = stmt.query_map(params![source_id], |row| row.get(0))
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?
-        .filter_map(|r| r.ok()).collect();
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare("SELECT content FROM chunks WHERE source_id = ?1 AND tenant_id = ?2 ORDER BY chunk_index")
+        .map_err(db_error)?;
+    let chunks: Vec<String> = stmt.query_map(params![source_id, active_tenant()], |row| read_content(row, 0))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
     Ok(chunks)
 }
 
@@ -427,25 +1193,27 @@ pub fn get_adjacent_chunks(
     max_index: i32,
 ) -> Result<Vec<ChunkSearchResult>, RagError> {
     info!("[get_adjacent_chunks] source={}, range={}..{}", source_id, min_index, max_index);
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
     
     let mut stmt = conn.prepare(
-        "SELECT c.id, c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), s.metadata 
+        "SELECT c.id, c.source_id, c.chunk_index, c.content, COALESCE(c.chunk_type, 'general'), s.metadata, c.header_path
          FROM chunks c 
          LEFT JOIN sources s ON c.source_id = s.id
-         WHERE c.source_id = ?1 AND c.chunk_index >= ?2 AND c.chunk_index <= ?3 ORDER BY c.chunk_index"
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+         WHERE c.source_id = ?1 AND c.chunk_index >= ?2 AND c.chunk_index <= ?3 AND c.tenant_id = ?4
+         ORDER BY c.chunk_index"
+    ).map_err(db_error)?;
     
     let chunks: Vec<ChunkSearchResult> = stmt
-        .query_map(params![source_id, min_index, max_index], |row| {
+        .query_map(params![source_id, min_index, max_index, active_tenant()], |row| {
             Ok(ChunkSearchResult {
                 chunk_id: row.get(0)?, source_id: row.get(1)?, chunk_index: row.get(2)?,
-                content: row.get(3)?, chunk_type: row.get(4)?, similarity: 0.0,
-                metadata: row.get(5)?,
+                content: read_content(row, 3)?, chunk_type: row.get(4)?, header_path: row.get(6)?,
+                similarity: 0.0, metadata: row.get(5)?,
             })
         })
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?
-        .filter_map(|r| r.ok()).collect();
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
     
     info!("[get_adjacent_chunks] Found {} chunks", chunks.len());
     Ok(chunks)
@@ -453,12 +1221,34 @@ pub fn get_adjacent_chunks(
 
 /// Delete a source and all its chunks.
 pub fn delete_source(source_id: i64) -> Result<(), RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    conn.execute("DELETE FROM chunks WHERE source_id = ?1", params![source_id])
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    conn.execute("DELETE FROM sources WHERE id = ?1", params![source_id])
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    info!("[delete_source] Deleted source {}", source_id);
+    let tenant_id = active_tenant();
+    let (chunk_ids, versions) = with_write_retry("delete_source", |conn| {
+        let tx = conn.transaction()?;
+        let (before, _) = change_versions(&tx, &tenant_id)?;
+        let chunk_ids: Vec<i64> = tx
+            .prepare("SELECT id FROM chunks WHERE source_id = ?1 AND tenant_id = ?2")?
+            .query_map(params![source_id, tenant_id], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        tx.execute(
+            "DELETE FROM chunk_entities WHERE chunk_id IN (SELECT id FROM chunks WHERE source_id = ?1 AND tenant_id = ?2)",
+            params![source_id, tenant_id],
+        )?;
+        tx.execute(
+            "DELETE FROM kg_triples WHERE chunk_id IN (SELECT id FROM chunks WHERE source_id = ?1 AND tenant_id = ?2)",
+            params![source_id, tenant_id],
+        )?;
+        tx.execute("DELETE FROM chunks WHERE source_id = ?1 AND tenant_id = ?2", params![source_id, tenant_id])?;
+        tx.execute("DELETE FROM sources WHERE id = ?1 AND tenant_id = ?2", params![source_id, tenant_id])?;
+        let (after, _) = change_versions(&tx, &tenant_id)?;
+        tx.commit()?;
+        Ok((chunk_ids, (before, after)))
+    })?;
+    // The index keeps the deleted points; searches skip them until `compact_index`.
+    add_tombstones(&tenant_id, &chunk_ids);
+    delete_named_chunks(&tenant_id, &chunk_ids);
+    delete_named_bm25_chunks(&tenant_id, &chunk_ids);
+    advance_indexed(&tenant_id, IndexKind::Vector, versions.0, versions.1);
+    info!("[delete_source] Deleted source {} ({} chunks)", source_id, chunk_ids.len());
     Ok(())
 }
 
@@ -470,21 +1260,22 @@ pub struct SourceStats {
 
 /// Get the number of chunks for a specific source.
 pub fn get_source_chunk_count(source_id: i64) -> Result<i32, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
     let count: i32 = conn.query_row(
-        "SELECT COUNT(*) FROM chunks WHERE source_id = ?1",
-        params![source_id],
+        "SELECT COUNT(*) FROM chunks WHERE source_id = ?1 AND tenant_id = ?2",
+        params![source_id, active_tenant()],
         |row| row.get(0),
-    ).map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    ).map_err(db_error)?;
     Ok(count)
 }
 
 pub fn get_source_stats() -> Result<SourceStats, RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let source_count: i64 = conn.query_row("SELECT COUNT(*) FROM sources", [], |row| row.get(0))
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let chunk_count: i64 = conn.query_row("SELECT COUNT(*) FROM chunks", [], |row| row.get(0))
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
+    let tenant_id = active_tenant();
+    let source_count: i64 = conn.query_row("SELECT COUNT(*) FROM sources WHERE tenant_id = ?1", params![tenant_id], |row| row.get(0))
+        .map_err(db_error)?;
+    let chunk_count: i64 = conn.query_row("SELECT COUNT(*) FROM chunks WHERE tenant_id = ?1", params![tenant_id], |row| row.get(0))
+        .map_err(db_error)?;
     Ok(SourceStats { source_count, chunk_count })
 }
 
@@ -497,29 +1288,132 @@ pub struct ChunkForReembedding {
 /// Get all chunk IDs and contents for re-embedding.
 pub fn get_all_chunk_ids_and_contents() -> Result<Vec<ChunkForReembedding>, RagError> {
     info!("[get_all_chunk_ids_and_contents] Starting");
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let mut stmt = conn.prepare("SELECT id, content FROM chunks ORDER BY id")
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare("SELECT id, content FROM chunks WHERE tenant_id = ?1 ORDER BY id")
+        .map_err(db_error)?;
     let chunks: Vec<ChunkForReembedding> = stmt
-        .query_map([], |row| Ok(ChunkForReembedding { chunk_id: row.get(0)?, content: row.get(1)? }))
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?
-        .filter_map(|r| r.ok()).collect();
+        .query_map(params![active_tenant()], |row| Ok(ChunkForReembedding { chunk_id: row.get(0)?, content: read_content(row, 1)? }))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
     info!("[get_all_chunk_ids_and_contents] Found {} chunks", chunks.len());
     Ok(chunks)
 }
 
+/// Replace the content of a chunk and re-index it for keyword search,
+/// entities and triples. The embedding is left as is; pass the new one
+/// to `update_chunk_embedding`. Returns false if the chunk does not exist
+/// in the active tenant.
+pub fn update_chunk_content(chunk_id: i64, content: String) -> Result<bool, RagError> {
+    let tenant_id = active_tenant();
+    let encrypted = is_content_encryption_enabled();
+    let versions = with_write_retry("update_chunk_content", |conn| {
+        let tx = conn.transaction()?;
+        let (_, before) = change_versions(&tx, &tenant_id)?;
+        let updated = tx.execute(
+            "UPDATE chunks SET content = ?1, language = ?2 WHERE id = ?3 AND tenant_id = ?4",
+            params![encrypt_content(&content)?, detect_language_code(&content), chunk_id, tenant_id],
+        )?;
+        if updated == 0 {
+            return Ok(None);
+        }
+        tx.execute("DELETE FROM chunk_entities WHERE chunk_id = ?1", params![chunk_id])?;
+        tx.execute("DELETE FROM kg_triples WHERE chunk_id = ?1", params![chunk_id])?;
+        if !encrypted {
+            store_chunk_entities(&tx, chunk_id, &content)?;
+            store_chunk_triples(&tx, chunk_id, &content)?;
+        }
+        let (_, after) = change_versions(&tx, &tenant_id)?;
+        tx.commit()?;
+        Ok(Some((before, after)))
+    })?;
+    let Some((before, after)) = versions else {
+        return Ok(false);
+    };
+    update_named_bm25_document(&tenant_id, chunk_id, &content);
+    // Only a loaded index is patched; otherwise the next rebuild reads the row.
+    if is_bm25_index_loaded() {
+        bm25_update_document(chunk_id, content);
+        advance_indexed(&tenant_id, IndexKind::Text, before, after);
+    }
+    info!("[update_chunk_content] Updated chunk {}", chunk_id);
+    Ok(true)
+}
+
 /// Update embedding for a single chunk.
 pub fn update_chunk_embedding(chunk_id: i64, embedding: Vec<f32>) -> Result<(), RagError> {
-    let conn = get_connection().map_err(|e| RagError::DatabaseError(e.to_string()))?;
-    let mut embedding_bytes: Vec<u8> = Vec::with_capacity(embedding.len() * 4);
-    for f in &embedding {
-        embedding_bytes.extend_from_slice(&f.to_ne_bytes());
-    }
-    conn.execute("UPDATE chunks SET embedding = ?1 WHERE id = ?2", params![embedding_bytes, chunk_id])
-        .map_err(|e| RagError::DatabaseError(e.to_string()))?;
+    let embedding_bytes = encode_embedding(&embedding);
+    let tenant_id = active_tenant();
+    with_write_retry("update_chunk_embedding", |conn| {
+        conn.execute(
+            "UPDATE chunks SET embedding = ?1, embedding_norm = ?4 WHERE id = ?2 AND tenant_id = ?3",
+            params![embedding_bytes, chunk_id, tenant_id, norm(&embedding)],
+        )?;
+        Ok(())
+    })?;
+    drop_tenant_source_indexes(&tenant_id);
     Ok(())
 }
 
+#[derive(Debug, Clone)]
+pub struct EmbeddingBatchProgress {
+    pub done: u32,
+    pub total: u32,
+}
+
+/// Write `updates` as `(chunk_id, embedding)` pairs inside `tx`.
+/// Returns how many rows of `tenant_id` were updated.
+fn write_embedding_updates(
+    tx: &rusqlite::Transaction,
+    tenant_id: &str,
+    updates: &[(i64, Vec<f32>)],
+) -> rusqlite::Result<u32> {
+    let mut stmt =
+        tx.prepare("UPDATE chunks SET embedding = ?1, embedding_norm = ?4 WHERE id = ?2 AND tenant_id = ?3")?;
+    let mut updated = 0u32;
+    for (chunk_id, embedding) in updates {
+        updated += stmt.execute(params![encode_embedding(embedding), chunk_id, tenant_id, norm(embedding)])? as u32;
+    }
+    Ok(updated)
+}
+
+/// Update many chunk embeddings in one transaction: either all rows are
+/// written or none are. After commit the active tenant's in-memory HNSW
+/// index and incremental buffer are dropped so the next rebuild picks up
+/// the new vectors, then `on_progress` reports the finished batch. It is
+/// never called inside the transaction, so a slow callback can't hold the
+/// write lock and a retried write can't report twice.
+/// Returns the number of chunks updated (ids of other tenants or deleted
+/// chunks are skipped).
+pub fn update_chunk_embeddings_batch(
+    updates: Vec<(i64, Vec<f32>)>,
+    on_progress: impl Fn(EmbeddingBatchProgress) -> DartFnFuture<()>,
+) -> Result<u32, RagError> {
+    if updates.is_empty() {
+        return Ok(0);
+    }
+    let dimension = updates[0].1.len();
+    if dimension == 0 || updates.iter().any(|(_, e)| e.len() != dimension) {
+        return Err(RagError::InvalidInput(
+            "All embeddings must be non-empty and share one dimension".to_string(),
+        ));
+    }
+    let tenant_id = active_tenant();
+    let total = updates.len() as u32;
+    let updated = with_write_retry("update_chunk_embeddings_batch", |conn| {
+        let tx = conn.transaction()?;
+        let updated = write_embedding_updates(&tx, &tenant_id, &updates)?;
+        tx.commit()?;
+        Ok(updated)
+    })?;
+    clear_hnsw_index();
+    drop_tenant_buffer(&tenant_id, BufferTable::Chunks);
+    drop_tenant_source_indexes(&tenant_id);
+    block_on(on_progress(EmbeddingBatchProgress { done: total, total }));
+    info!("[update_chunk_embeddings_batch] Updated {} of {} chunks", updated, total);
+    Ok(updated)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,8 +1439,9 @@ mod tests {
             chunk_index: 0,
             start_pos: 0,
             end_pos: 10,
-            chunk_type: "text".to_string(),
+            chunk_type: "text|Guide > Setup".to_string(),
             embedding: vec![1.0, 0.0, 0.0, 0.0], // 4 dims
+            batch_id: None,
         };
         add_chunks(source_res.source_id, vec![chunk]).unwrap();
 
@@ -557,9 +1452,72 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].metadata, Some(metadata.to_string()));
         assert_eq!(results[0].source_id, source_res.source_id);
+        assert_eq!(results[0].chunk_type, "text");
+        assert_eq!(results[0].header_path.as_deref(), Some("Guide > Setup"));
 
         // 5. Cleanup
         close_db_pool();
         let _ = std::fs::remove_file(db_path);
     }
+
+    #[test]
+    fn test_split_chunk_type() {
+        assert_eq!(split_chunk_type("general"), ("general", None));
+        assert_eq!(split_chunk_type("text|Installation > Windows"), ("text", Some("Installation > Windows")));
+        assert_eq!(split_chunk_type("code|"), ("code", None));
+    }
+
+    #[test]
+    fn test_write_embedding_updates() {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, embedding BLOB, tenant_id TEXT, embedding_norm REAL);
+             INSERT INTO chunks (id, embedding, tenant_id) VALUES (1, x'', 'default'), (2, x'', 'default'), (3, x'', 'other');",
+        )
+        .unwrap();
+        let updates: Vec<(i64, Vec<f32>)> = (1..=3).map(|id| (id, vec![id as f32, 0.0])).collect();
+        let tx = conn.transaction().unwrap();
+        let updated = write_embedding_updates(&tx, "default", &updates).unwrap();
+        tx.commit().unwrap();
+
+        assert_eq!(updated, 2);
+        let blob: Vec<u8> = conn.query_row("SELECT embedding FROM chunks WHERE id = 2", [], |r| r.get(0)).unwrap();
+        assert_eq!(decode_embedding(&blob), vec![2.0, 0.0]);
+        let stored_norm: f64 = conn.query_row("SELECT embedding_norm FROM chunks WHERE id = 2", [], |r| r.get(0)).unwrap();
+        assert_eq!(stored_norm, 2.0);
+        let other: Vec<u8> = conn.query_row("SELECT embedding FROM chunks WHERE id = 3", [], |r| r.get(0)).unwrap();
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn test_backfill_chunk_languages() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE chunks (id INTEGER PRIMARY KEY, content TEXT, language TEXT);
+             INSERT INTO chunks VALUES (1, '스마트 계약은 블록체인에서 실행됩니다', NULL),
+                (2, 'Smart contracts run on chain.', NULL), (3, 'already set', 'ja'),
+                (4, 'enc1:missing_key:AAAA', NULL);",
+        )
+        .unwrap();
+        assert_eq!(backfill_chunk_languages(&conn).unwrap(), 1);
+        let languages: Vec<Option<String>> = conn
+            .prepare("SELECT language FROM chunks ORDER BY id").unwrap()
+            .query_map([], |r| r.get(0)).unwrap()
+            .collect::<rusqlite::Result<_>>().unwrap();
+        assert_eq!(
+            languages,
+            vec![Some("ko".to_string()), Some("en".to_string()), Some("ja".to_string()), None]
+        );
+        // The undecryptable row is retried, not skipped for good.
+        assert_eq!(backfill_chunk_languages(&conn).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_validate_packed_offsets() {
+        assert!(validate_offsets(2, 6, &[0, 3, 6]).is_ok());
+        assert!(validate_offsets(2, 6, &[0, 3]).is_err());
+        assert!(validate_offsets(2, 6, &[0, 3, 5]).is_err());
+        assert!(validate_offsets(2, 6, &[0, 0, 6]).is_err());
+        assert!(validate_offsets(0, 0, &[0]).is_ok());
+    }
 }
@@ -0,0 +1,650 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Two-phase ingestion for apps that embed in Dart.
+//!
+//! `prepare_ingest` chunks a document and stages the chunk rows in the
+//! `ingest_staging` tables, returning the texts to embed. `commit_ingest`
+//! takes one embedding per chunk and stores the source with all of its
+//! chunks in a single transaction, so a source never shows up half
+//! embedded. A staged document nobody commits expires after
+//! `STAGING_TTL_SECS`; expired rows are purged by the next prepare or
+//! commit.
+//!
+//! `update_source` stages an edited version of a stored source the same
+//! way. New chunks whose text matches an existing chunk reuse it, so only
+//! new or changed chunks are returned for embedding; the commit then
+//! swaps the source's content and chunks at once.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::content_crypto::{encrypt_content, read_content};
+use crate::api::db_pool::get_connection;
+use crate::api::db_retry::with_write_retry;
+use crate::api::error::{db_error, RagError};
+use crate::api::index_freshness::ensure_indexes_fresh;
+use crate::api::ingest::{chunks_to_embed, IngestOptions, IngestReport, PreparedChunk};
+use crate::api::source_rag::{
+    add_source_with_chunks, find_source_by_content, hash_content, update_source_with_chunks, ChunkRow, KeptChunk,
+};
+use crate::api::tenant::{active_tenant, DEFAULT_TENANT};
+
+/// Seconds a staged document waits for `commit_ingest`.
+pub const STAGING_TTL_SECS: i64 = 30 * 60;
+
+#[derive(Debug, Clone)]
+pub struct StagedIngest {
+    /// Pass to `commit_ingest` or `cancel_ingest`.
+    pub staging_id: String,
+    /// Chunk texts to embed, in order; `commit_ingest` expects one
+    /// embedding per entry.
+    pub chunks: Vec<String>,
+    /// Exact and near-duplicate chunks dropped before staging.
+    pub skipped_chunks: u32,
+    /// Seconds since the Unix epoch after which the staging is dropped.
+    pub expires_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceUpdate {
+    /// Pass to `commit_ingest` or `cancel_ingest`.
+    pub staging_id: String,
+    /// Texts of the new or changed chunks, in order; `commit_ingest`
+    /// expects one embedding per entry (none when this is empty).
+    pub chunks: Vec<String>,
+    /// Unchanged chunks that keep their embeddings.
+    pub kept_chunks: u32,
+    /// Existing chunks the commit deletes.
+    pub removed_chunks: u32,
+    /// Exact and near-duplicate chunks dropped before staging.
+    pub skipped_chunks: u32,
+    /// Seconds since the Unix epoch after which the staging is dropped.
+    pub expires_at: i64,
+}
+
+/// Staged chunk read back for commit.
+struct StagedChunk {
+    /// Existing chunk reused by a source update.
+    chunk_id: Option<i64>,
+    content: String,
+    chunk_index: i32,
+    start_pos: i32,
+    end_pos: i32,
+    chunk_type: String,
+    header_path: Option<String>,
+    batch_id: Option<String>,
+}
+
+/// Create the staging tables (called from `init_source_db`).
+pub(crate) fn create_staging_tables(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS ingest_staging (
+            id TEXT PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            source_id INTEGER,
+            content TEXT NOT NULL,
+            metadata TEXT,
+            name TEXT,
+            rebuild_indexes INTEGER NOT NULL,
+            skipped_chunks INTEGER NOT NULL,
+            created_at INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS ingest_staging_chunks (
+            staging_id TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            content TEXT NOT NULL,
+            chunk_index INTEGER NOT NULL,
+            start_pos INTEGER NOT NULL,
+            end_pos INTEGER NOT NULL,
+            chunk_type TEXT NOT NULL,
+            header_path TEXT,
+            batch_id TEXT,
+            chunk_id INTEGER,
+            PRIMARY KEY (staging_id, position)
+        );",
+    )
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Delete the staging rows matching `condition` (on `ingest_staging`).
+fn delete_staging(conn: &Connection, condition: &str, param: &dyn rusqlite::ToSql) -> rusqlite::Result<usize> {
+    conn.execute(
+        &format!(
+            "DELETE FROM ingest_staging_chunks WHERE staging_id IN (SELECT id FROM ingest_staging WHERE {})",
+            condition
+        ),
+        params![param],
+    )?;
+    conn.execute(&format!("DELETE FROM ingest_staging WHERE {}", condition), params![param])
+}
+
+/// Drop staged documents older than `STAGING_TTL_SECS` (also called from
+/// `init_source_db`).
+pub(crate) fn purge_expired(conn: &Connection) -> rusqlite::Result<usize> {
+    let purged = delete_staging(conn, "created_at < ?1", &(now_secs() - STAGING_TTL_SECS))?;
+    if purged > 0 {
+        info!("[staged_ingest] Purged {} expired staged documents", purged);
+    }
+    Ok(purged)
+}
+
+/// Drop `tenant_id`'s staged documents (called from `delete_tenant`).
+pub(crate) fn delete_tenant_staging(conn: &Connection, tenant_id: &str) -> rusqlite::Result<()> {
+    delete_staging(conn, "tenant_id = ?1", &tenant_id)?;
+    Ok(())
+}
+
+/// Stage `chunks` of `text` under a new staging id, each with the
+/// existing chunk it reuses (`reused`, updates of `source_id` only).
+/// Returns the id and the staging time.
+fn stage(
+    text: &str,
+    options: &IngestOptions,
+    source_id: Option<i64>,
+    chunks: &[PreparedChunk],
+    reused: &[Option<i64>],
+    skipped: usize,
+) -> Result<(String, i64), RagError> {
+    let staging_id = uuid::Uuid::new_v4().to_string();
+    let tenant_id = active_tenant();
+    let stored_content = encrypt_content(text)?;
+    let created_at = now_secs();
+
+    with_write_retry("stage_ingest", |conn| {
+        let tx = conn.transaction()?;
+        purge_expired(&tx)?;
+        tx.execute(
+            "INSERT INTO ingest_staging
+                 (id, tenant_id, source_id, content, metadata, name, rebuild_indexes, skipped_chunks, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                staging_id,
+                tenant_id,
+                source_id,
+                stored_content,
+                options.metadata,
+                options.name,
+                options.rebuild_indexes,
+                skipped as i64,
+                created_at
+            ],
+        )?;
+        for (position, (chunk, chunk_id)) in chunks.iter().zip(reused).enumerate() {
+            tx.execute(
+                "INSERT INTO ingest_staging_chunks
+                     (staging_id, position, content, chunk_index, start_pos, end_pos, chunk_type, header_path, batch_id, chunk_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    staging_id,
+                    position as i64,
+                    encrypt_content(&chunk.content)?,
+                    chunk.chunk_index,
+                    chunk.start_pos,
+                    chunk.end_pos,
+                    chunk.chunk_type,
+                    chunk.header_path,
+                    chunk.batch_id,
+                    chunk_id
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    })?;
+    Ok((staging_id, created_at))
+}
+
+/// Chunk `text` and stage the chunks; embed the returned texts and pass
+/// the vectors to `commit_ingest`. Nothing is searchable until then.
+pub fn prepare_ingest(text: String, options: IngestOptions) -> Result<StagedIngest, RagError> {
+    if text.trim().is_empty() {
+        return Err(RagError::InvalidInput("Document has no text".to_string()));
+    }
+    let (chunks, skipped) = chunks_to_embed(&text, &options);
+    if chunks.is_empty() {
+        return Err(RagError::InvalidInput("Document produced no chunks".to_string()));
+    }
+    let (staging_id, created_at) = stage(&text, &options, None, &chunks, &vec![None; chunks.len()], skipped)?;
+    info!("[staged_ingest] Staged {} with {} chunks", staging_id, chunks.len());
+
+    Ok(StagedIngest {
+        staging_id,
+        chunks: chunks.into_iter().map(|c| c.content).collect(),
+        skipped_chunks: skipped as u32,
+        expires_at: created_at + STAGING_TTL_SECS,
+    })
+}
+
+/// For each new chunk, an existing chunk (id, text) with the same text
+/// to reuse. Texts are compared by hash and each existing chunk is reused
+/// at most once.
+fn match_chunks(existing: &[(i64, String)], chunks: &[&str]) -> Vec<Option<i64>> {
+    let mut by_hash: HashMap<String, VecDeque<i64>> = HashMap::new();
+    for (id, content) in existing {
+        by_hash.entry(hash_content(content, DEFAULT_TENANT)).or_default().push_back(*id);
+    }
+    chunks
+        .iter()
+        .map(|content| by_hash.get_mut(&hash_content(content, DEFAULT_TENANT)).and_then(|ids| ids.pop_front()))
+        .collect()
+}
+
+/// Re-chunk source `source_id` with `new_content` and stage the update.
+/// Unchanged chunks keep their embeddings, so only the returned `chunks`
+/// need embedding before `commit_ingest`. The source stays as it is until
+/// the commit. `options.metadata` and `options.name` replace the stored
+/// ones when set; use the chunking options the source was ingested with,
+/// or few chunks will match.
+pub fn update_source(source_id: i64, new_content: String, options: IngestOptions) -> Result<SourceUpdate, RagError> {
+    if new_content.trim().is_empty() {
+        return Err(RagError::InvalidInput("Document has no text".to_string()));
+    }
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    let stored_name: Option<String> = conn
+        .query_row(
+            "SELECT name FROM sources WHERE id = ?1 AND tenant_id = ?2",
+            params![source_id, tenant_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(db_error)?
+        .ok_or_else(|| RagError::InvalidInput(format!("Source {} not found", source_id)))?;
+    let clash: Option<i64> = conn
+        .query_row(
+            "SELECT id FROM sources WHERE content_hash = ?1 AND tenant_id = ?2 AND id != ?3",
+            params![hash_content(&new_content, &tenant_id), tenant_id, source_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(db_error)?;
+    if let Some(other) = clash {
+        return Err(RagError::InvalidInput(format!("Content is already stored as source {}", other)));
+    }
+    let existing: Vec<(i64, String)> = conn
+        .prepare("SELECT id, content FROM chunks WHERE source_id = ?1 AND tenant_id = ?2 ORDER BY chunk_index")
+        .map_err(db_error)?
+        .query_map(params![source_id, tenant_id], |row| Ok((row.get(0)?, read_content(row, 1)?)))
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    drop(conn);
+
+    // Markdown detection falls back to the stored name.
+    let chunk_options = IngestOptions {
+        name: options.name.clone().or(stored_name),
+        ..options.clone()
+    };
+    let (chunks, skipped) = chunks_to_embed(&new_content, &chunk_options);
+    if chunks.is_empty() {
+        return Err(RagError::InvalidInput("Document produced no chunks".to_string()));
+    }
+    let reused = match_chunks(&existing, &chunks.iter().map(|c| c.content.as_str()).collect::<Vec<_>>());
+    let kept = reused.iter().filter(|id| id.is_some()).count();
+    let (staging_id, created_at) = stage(&new_content, &options, Some(source_id), &chunks, &reused, skipped)?;
+    info!(
+        "[staged_ingest] Staged {} for source {}: {} kept, {} to embed",
+        staging_id,
+        source_id,
+        kept,
+        chunks.len() - kept
+    );
+
+    Ok(SourceUpdate {
+        staging_id,
+        chunks: chunks
+            .into_iter()
+            .zip(&reused)
+            .filter(|(_, id)| id.is_none())
+            .map(|(c, _)| c.content)
+            .collect(),
+        kept_chunks: kept as u32,
+        removed_chunks: (existing.len() - kept) as u32,
+        skipped_chunks: skipped as u32,
+        expires_at: created_at + STAGING_TTL_SECS,
+    })
+}
+
+/// Store the staged document with `embeddings` (one per returned chunk
+/// text, in order, all of one dimension) in a single transaction. The
+/// staging is kept if the commit fails, so it can be retried until it
+/// expires.
+pub fn commit_ingest(staging_id: String, embeddings: Vec<Vec<f32>>) -> Result<IngestReport, RagError> {
+    let started = Instant::now();
+    let tenant_id = active_tenant();
+    let conn = get_connection()?;
+    purge_expired(&conn).map_err(db_error)?;
+    let staged = conn
+        .query_row(
+            "SELECT content, metadata, name, rebuild_indexes, skipped_chunks, source_id
+             FROM ingest_staging WHERE id = ?1 AND tenant_id = ?2",
+            params![staging_id, tenant_id],
+            |row| {
+                Ok((
+                    read_content(row, 0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, bool>(3)?,
+                    row.get::<_, i64>(4)?,
+                    row.get::<_, Option<i64>>(5)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(db_error)?;
+    let Some((content, metadata, name, rebuild_indexes, skipped, update_of)) = staged else {
+        return Err(RagError::InvalidInput(format!("Unknown or expired staging id {}", staging_id)));
+    };
+    let chunks: Vec<StagedChunk> = conn
+        .prepare(
+            "SELECT content, chunk_index, start_pos, end_pos, chunk_type, header_path, batch_id, chunk_id
+             FROM ingest_staging_chunks WHERE staging_id = ?1 ORDER BY position",
+        )
+        .map_err(db_error)?
+        .query_map(params![staging_id], |row| {
+            Ok(StagedChunk {
+                chunk_id: row.get(7)?,
+                content: read_content(row, 0)?,
+                chunk_index: row.get(1)?,
+                start_pos: row.get(2)?,
+                end_pos: row.get(3)?,
+                chunk_type: row.get(4)?,
+                header_path: row.get(5)?,
+                batch_id: row.get(6)?,
+            })
+        })
+        .map_err(db_error)?
+        .collect::<rusqlite::Result<_>>()
+        .map_err(db_error)?;
+    drop(conn);
+
+    let to_embed = chunks.iter().filter(|c| c.chunk_id.is_none()).count();
+    if embeddings.len() != to_embed {
+        return Err(RagError::InvalidInput(format!(
+            "Expected {} embeddings, got {}",
+            to_embed,
+            embeddings.len()
+        )));
+    }
+    let dimension = embeddings.first().map_or(0, |e| e.len());
+    if (to_embed > 0 && dimension == 0) || embeddings.iter().any(|e| e.len() != dimension) {
+        return Err(RagError::InvalidInput("Embeddings must be non-empty and of one dimension".to_string()));
+    }
+
+    let rows: Vec<ChunkRow> = chunks
+        .iter()
+        .filter(|c| c.chunk_id.is_none())
+        .zip(&embeddings)
+        .map(|(c, embedding)| ChunkRow {
+            content: &c.content,
+            chunk_index: c.chunk_index,
+            start_pos: c.start_pos,
+            end_pos: c.end_pos,
+            chunk_type: &c.chunk_type,
+            header_path: c.header_path.as_deref(),
+            batch_id: c.batch_id.as_deref(),
+            embedding,
+        })
+        .collect();
+    // The staging goes in the transaction that stores the source, so a
+    // committed document can't be committed twice.
+    let drop_staging = |conn: &Connection| delete_staging(conn, "id = ?1", &staging_id).map(|_| ());
+    let (source_id, is_duplicate) = match update_of {
+        Some(source_id) => {
+            let kept: Vec<KeptChunk> = chunks
+                .iter()
+                .filter_map(|c| {
+                    Some(KeptChunk {
+                        chunk_id: c.chunk_id?,
+                        chunk_index: c.chunk_index,
+                        start_pos: c.start_pos,
+                        end_pos: c.end_pos,
+                        chunk_type: &c.chunk_type,
+                        header_path: c.header_path.as_deref(),
+                    })
+                })
+                .collect();
+            update_source_with_chunks(source_id, &content, metadata, name, &kept, &rows, &drop_staging)?;
+            (source_id, false)
+        }
+        None => {
+            let source = add_source_with_chunks(&content, metadata, name, &rows, &drop_staging)?;
+            (source.source_id, source.is_duplicate)
+        }
+    };
+
+    if rebuild_indexes && !is_duplicate {
+        // The source is committed either way; a stale index is rebuilt later.
+        if let Err(e) = ensure_indexes_fresh() {
+            warn!("[staged_ingest] Index refresh after commit failed: {}", e);
+        }
+    }
+    info!("[staged_ingest] Committed {} as source {}", staging_id, source_id);
+    let status = if is_duplicate {
+        find_source_by_content(&content)?.map_or_else(|| "completed".to_string(), |(_, status)| status)
+    } else {
+        "completed".to_string()
+    };
+
+    Ok(IngestReport {
+        source_id,
+        is_duplicate,
+        status,
+        chunk_count: if is_duplicate { 0 } else { chunks.len() as u32 },
+        skipped_chunks: skipped as u32,
+        text_chars: content.chars().count() as u64,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Drop a staged document without storing it; false if unknown.
+pub fn cancel_ingest(staging_id: String) -> Result<bool, RagError> {
+    let tenant_id = active_tenant();
+    let owned = get_connection()?
+        .query_row(
+            "SELECT 1 FROM ingest_staging WHERE id = ?1 AND tenant_id = ?2",
+            params![staging_id, tenant_id],
+            |_| Ok(()),
+        )
+        .optional()
+        .map_err(db_error)?
+        .is_some();
+    if owned {
+        with_write_retry("cancel_ingest", |conn| Ok(delete_staging(conn, "id = ?1", &staging_id)?))?;
+    }
+    Ok(owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fake_embedding, TestEngine, TEST_EMBEDDING_DIM};
+
+    fn embed(texts: &[String]) -> Vec<Vec<f32>> {
+        texts.iter().map(|t| fake_embedding(t, TEST_EMBEDDING_DIM)).collect()
+    }
+
+    fn source_chunks(source_id: i64) -> Vec<(i64, String)> {
+        get_connection()
+            .unwrap()
+            .prepare("SELECT id, content FROM chunks WHERE source_id = ?1 ORDER BY chunk_index")
+            .unwrap()
+            .query_map(params![source_id], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_update_source_commit_keeps_unchanged_chunks() {
+        let _engine = TestEngine::new().unwrap();
+        let options = IngestOptions {
+            name: Some("notes.md".to_string()),
+            rebuild_indexes: false,
+            ..IngestOptions::default()
+        };
+        let original = "# Intro\n\nOwnership moves values.\n\n# Body\n\nBorrowing lends them.\n\n# End\n\nLifetimes bound borrows.\n";
+        let staged = prepare_ingest(original.to_string(), options.clone()).unwrap();
+        let source_id = commit_ingest(staged.staging_id, embed(&staged.chunks)).unwrap().source_id;
+        let before = source_chunks(source_id);
+
+        let edited = original.replace("Borrowing lends them.", "Borrowing lends them for a while.");
+        let update = update_source(source_id, edited.clone(), options.clone()).unwrap();
+        assert_eq!(update.chunks.len(), 1);
+        assert_eq!(update.removed_chunks, 1);
+        assert_eq!(update.kept_chunks as usize, before.len() - 1);
+
+        // A wrong dimension is rejected and the staging survives for a retry.
+        let wrong = vec![vec![1.0f32; TEST_EMBEDDING_DIM + 1]];
+        assert!(commit_ingest(update.staging_id.clone(), wrong).is_err());
+        assert_eq!(source_chunks(source_id), before);
+
+        let report = commit_ingest(update.staging_id.clone(), embed(&update.chunks)).unwrap();
+        assert_eq!((report.source_id, report.is_duplicate), (source_id, false));
+        let after = source_chunks(source_id);
+        assert_eq!(after.len(), before.len());
+        let kept: Vec<&(i64, String)> = after.iter().filter(|c| before.contains(c)).collect();
+        assert_eq!(kept.len(), before.len() - 1);
+        assert!(after.iter().any(|(_, text)| text.contains("for a while")));
+
+        let conn = get_connection().unwrap();
+        let (content, updated_at): (String, Option<i64>) = conn
+            .query_row("SELECT content, updated_at FROM sources WHERE id = ?1", params![source_id], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(content, edited);
+        assert!(updated_at.is_some());
+        let staged_left: i64 = conn.query_row("SELECT COUNT(*) FROM ingest_staging", [], |row| row.get(0)).unwrap();
+        assert_eq!(staged_left, 0);
+    }
+
+    #[test]
+    fn test_purge_expired_keeps_fresh_staging() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_staging_tables(&conn).unwrap();
+        for (id, created_at) in [("old", now_secs() - STAGING_TTL_SECS - 1), ("new", now_secs())] {
+            conn.execute(
+                "INSERT INTO ingest_staging (id, tenant_id, content, rebuild_indexes, skipped_chunks, created_at)
+                 VALUES (?1, 'default', 'text', 0, 0, ?2)",
+                params![id, created_at],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO ingest_staging_chunks
+                     (staging_id, position, content, chunk_index, start_pos, end_pos, chunk_type)
+                 VALUES (?1, 0, 'text', 0, 0, 4, 'general')",
+                params![id],
+            )
+            .unwrap();
+        }
+
+        assert_eq!(purge_expired(&conn).unwrap(), 1);
+        let left: Vec<String> = conn
+            .prepare("SELECT staging_id FROM ingest_staging_chunks")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(left, vec!["new".to_string()]);
+
+        delete_tenant_staging(&conn, "default").unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM ingest_staging", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 0);
+    }
+
+    fn table_count(table: &str) -> i64 {
+        get_connection()
+            .unwrap()
+            .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_commit_ingest_is_atomic_and_single_use() {
+        let _engine = TestEngine::new().unwrap();
+        let options = IngestOptions { rebuild_indexes: false, ..IngestOptions::default() };
+        let staged = prepare_ingest("Ownership moves values.".to_string(), options.clone()).unwrap();
+
+        // Wrong embedding count: nothing stored, the staging is kept.
+        assert!(commit_ingest(staged.staging_id.clone(), Vec::new()).is_err());
+        assert_eq!((table_count("sources"), table_count("ingest_staging")), (0, 1));
+
+        // A failure inside the storing transaction rolls the source back.
+        let embedding = fake_embedding("Ownership moves values.", TEST_EMBEDDING_DIM);
+        let row = ChunkRow {
+            content: "Ownership moves values.",
+            chunk_index: 0,
+            start_pos: 0,
+            end_pos: 23,
+            chunk_type: "general",
+            header_path: None,
+            batch_id: None,
+            embedding: &embedding,
+        };
+        let failing = |_: &Connection| Err(rusqlite::Error::InvalidQuery);
+        assert!(add_source_with_chunks("Ownership moves values.", None, None, &[row], &failing).is_err());
+        assert_eq!(table_count("sources"), 0);
+        assert_eq!(table_count("chunks"), 0);
+
+        let report = commit_ingest(staged.staging_id.clone(), embed(&staged.chunks)).unwrap();
+        assert!(!report.is_duplicate);
+        assert_eq!((table_count("sources"), table_count("ingest_staging")), (1, 0));
+        assert_eq!(table_count("ingest_staging_chunks"), 0);
+        assert!(commit_ingest(staged.staging_id, embed(&staged.chunks)).is_err());
+        assert_eq!(table_count("sources"), 1);
+    }
+
+    #[test]
+    fn test_commit_ingest_rejects_expired_staging() {
+        let _engine = TestEngine::new().unwrap();
+        let options = IngestOptions { rebuild_indexes: false, ..IngestOptions::default() };
+        let staged = prepare_ingest("Borrowing lends values.".to_string(), options).unwrap();
+        get_connection()
+            .unwrap()
+            .execute(
+                "UPDATE ingest_staging SET created_at = ?1 WHERE id = ?2",
+                params![now_secs() - STAGING_TTL_SECS - 1, staged.staging_id],
+            )
+            .unwrap();
+
+        let err = commit_ingest(staged.staging_id, embed(&staged.chunks)).unwrap_err();
+        assert!(err.to_string().contains("expired"), "{}", err);
+        assert_eq!(table_count("sources"), 0);
+        assert_eq!((table_count("ingest_staging"), table_count("ingest_staging_chunks")), (0, 0));
+    }
+
+    #[test]
+    fn test_match_chunks_reuses_each_unchanged_chunk_once() {
+        let existing = vec![
+            (1, "intro".to_string()),
+            (2, "body".to_string()),
+            (3, "footer".to_string()),
+            (4, "footer".to_string()),
+        ];
+        // "body" was edited, a paragraph was added and the footer now appears three times.
+        let reused = match_chunks(&existing, &["intro", "new paragraph", "body v2", "footer", "footer", "footer"]);
+        assert_eq!(reused, vec![Some(1), None, None, Some(3), Some(4), None]);
+    }
+}
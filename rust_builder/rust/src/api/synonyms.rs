@@ -0,0 +1,286 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Synonym dictionary for keyword query expansion.
+//!
+//! BM25 only matches the words it is given, so a query for "LLM" misses
+//! chunks that say "large language model". Apps register per-tenant
+//! entries (term -> expansions, either side may be a phrase); keyword
+//! search appends the expansions of every term found in the query's
+//! scored clauses as optional terms; excluded (`-term`, `NOT term`)
+//! clauses are neither expanded nor brought back as expansions. Entries live in the `synonyms` table and are loaded by
+//! `init_source_db`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use log::info;
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection};
+
+use crate::api::db_retry::with_write_retry;
+use crate::api::error::RagError;
+use crate::api::query_normalize::{normalize_query_with, QueryNormalizationConfig};
+use crate::api::query_parser::parse_boolean_query;
+use crate::api::tenant::active_tenant;
+
+/// Longest term, in words, looked up in a query.
+const MAX_TERM_WORDS: usize = 4;
+
+/// tenant -> normalized term -> expansions, in insertion order.
+type Dictionary = HashMap<String, HashMap<String, Vec<String>>>;
+
+static SYNONYMS: Lazy<RwLock<Dictionary>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynonymEntry {
+    pub term: String,
+    pub expansions: Vec<String>,
+}
+
+/// Terms and expansions are stored as lowercase words without
+/// punctuation, whatever the query normalization config.
+fn canonical(text: &str) -> String {
+    normalize_query_with(text, &QueryNormalizationConfig::default())
+}
+
+/// Create the `synonyms` table (called from `init_source_db`).
+pub(crate) fn create_synonyms_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS synonyms (
+            id INTEGER PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            term TEXT NOT NULL,
+            expansion TEXT NOT NULL,
+            UNIQUE (tenant_id, term, expansion)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Load every tenant's entries into memory (called from `init_source_db`).
+pub(crate) fn load_synonyms(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT tenant_id, term, expansion FROM synonyms ORDER BY id")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+    })?;
+    let mut dictionary: Dictionary = HashMap::new();
+    let mut count = 0;
+    for row in rows {
+        let (tenant_id, term, expansion) = row?;
+        dictionary.entry(tenant_id).or_default().entry(term).or_default().push(expansion);
+        count += 1;
+    }
+    if count > 0 {
+        info!("[synonyms] Loaded {} synonym entries", count);
+    }
+    *SYNONYMS.write().unwrap() = dictionary;
+    Ok(())
+}
+
+/// Add `expansions` for `term` in the active tenant; existing pairs are
+/// kept. Returns how many pairs were new.
+pub fn add_synonyms(term: String, expansions: Vec<String>) -> Result<u32, RagError> {
+    let term = canonical(&term);
+    if term.is_empty() {
+        return Err(RagError::InvalidInput("Synonym term must not be empty".to_string()));
+    }
+    let expansions: Vec<String> = expansions
+        .iter()
+        .map(|e| canonical(e))
+        .filter(|e| !e.is_empty() && *e != term)
+        .collect();
+    let tenant_id = active_tenant();
+    let added = with_write_retry("add_synonyms", |conn| {
+        let tx = conn.transaction()?;
+        let mut added = Vec::new();
+        for expansion in &expansions {
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO synonyms (tenant_id, term, expansion) VALUES (?1, ?2, ?3)",
+                params![tenant_id, term, expansion],
+            )?;
+            if inserted > 0 {
+                added.push(expansion.clone());
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    })?;
+
+    let count = added.len() as u32;
+    if !added.is_empty() {
+        SYNONYMS
+            .write()
+            .unwrap()
+            .entry(tenant_id)
+            .or_default()
+            .entry(term.clone())
+            .or_default()
+            .extend(added);
+    }
+    info!("[synonyms] Added {} expansions for '{}'", count, term);
+    Ok(count)
+}
+
+/// Remove `expansions` of `term` in the active tenant, or all of them
+/// when `None`. Returns how many pairs were removed.
+pub fn remove_synonyms(term: String, expansions: Option<Vec<String>>) -> Result<u32, RagError> {
+    let term = canonical(&term);
+    let tenant_id = active_tenant();
+    let removed = with_write_retry("remove_synonyms", |conn| {
+        let tx = conn.transaction()?;
+        let removed = match &expansions {
+            None => tx.execute(
+                "DELETE FROM synonyms WHERE tenant_id = ?1 AND term = ?2",
+                params![tenant_id, term],
+            )?,
+            Some(expansions) => {
+                let mut removed = 0;
+                for expansion in expansions {
+                    removed += tx.execute(
+                        "DELETE FROM synonyms WHERE tenant_id = ?1 AND term = ?2 AND expansion = ?3",
+                        params![tenant_id, term, canonical(expansion)],
+                    )?;
+                }
+                removed
+            }
+        };
+        tx.commit()?;
+        Ok(removed)
+    })?;
+
+    let mut dictionary = SYNONYMS.write().unwrap();
+    if let Some(terms) = dictionary.get_mut(&tenant_id) {
+        match &expansions {
+            None => {
+                terms.remove(&term);
+            }
+            Some(expansions) => {
+                let gone: HashSet<String> = expansions.iter().map(|e| canonical(e)).collect();
+                if let Some(list) = terms.get_mut(&term) {
+                    list.retain(|e| !gone.contains(e));
+                    if list.is_empty() {
+                        terms.remove(&term);
+                    }
+                }
+            }
+        }
+    }
+    info!("[synonyms] Removed {} expansions for '{}'", removed, term);
+    Ok(removed as u32)
+}
+
+/// The active tenant's entries, sorted by term.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_synonyms() -> Vec<SynonymEntry> {
+    let dictionary = SYNONYMS.read().unwrap();
+    let mut entries: Vec<SynonymEntry> = dictionary
+        .get(&active_tenant())
+        .map(|terms| {
+            terms
+                .iter()
+                .map(|(term, expansions)| SynonymEntry {
+                    term: term.clone(),
+                    expansions: expansions.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by(|a, b| a.term.cmp(&b.term));
+    entries
+}
+
+/// Forget `tenant_id`'s entries in memory; its rows go with `delete_tenant`.
+pub(crate) fn drop_tenant_synonyms(tenant_id: &str) {
+    SYNONYMS.write().unwrap().remove(tenant_id);
+}
+
+/// Expansions of the terms (up to `MAX_TERM_WORDS` words) found in
+/// `query`, skipping words the query already has.
+fn expansions_for(terms: &HashMap<String, Vec<String>>, query: &str) -> Vec<String> {
+    let words: Vec<&str> = query.split_whitespace().collect();
+    let present: HashSet<&str> = words.iter().copied().collect();
+    let mut extra: Vec<String> = Vec::new();
+    for n in 1..=MAX_TERM_WORDS.min(words.len()) {
+        for window in words.windows(n) {
+            let Some(expansions) = terms.get(&window.join(" ")) else {
+                continue;
+            };
+            for expansion in expansions {
+                if !present.contains(expansion.as_str()) && !extra.contains(expansion) {
+                    extra.push(expansion.clone());
+                }
+            }
+        }
+    }
+    extra
+}
+
+/// `query` with the active tenant's synonym expansions appended as
+/// optional terms; unchanged when none apply. Only required and optional
+/// clauses are expanded, and an expansion the query excludes is dropped.
+pub(crate) fn expand_query(tenant_id: &str, query: &str) -> String {
+    let dictionary = SYNONYMS.read().unwrap();
+    let Some(terms) = dictionary.get(tenant_id).filter(|t| !t.is_empty()) else {
+        return query.to_string();
+    };
+    let parsed = parse_boolean_query(query);
+    let excluded: HashSet<String> = parsed.must_not.iter().map(|clause| canonical(clause)).collect();
+    let mut extra = expansions_for(terms, &canonical(&parsed.scoring_text()));
+    extra.retain(|expansion| !excluded.contains(expansion));
+    if extra.is_empty() {
+        return query.to_string();
+    }
+    format!("{} {}", query, extra.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expansions_for_words_and_phrases() {
+        let mut terms: HashMap<String, Vec<String>> = HashMap::new();
+        terms.insert("llm".to_string(), vec!["large language model".to_string()]);
+        terms.insert("vector db".to_string(), vec!["vector database".to_string(), "llm".to_string()]);
+
+        assert_eq!(
+            expansions_for(&terms, &canonical("LLM benchmarks")),
+            vec!["large language model".to_string()]
+        );
+        // Phrases match too; expansions already in the query are skipped.
+        assert_eq!(
+            expansions_for(&terms, &canonical("Vector-DB for LLM")),
+            vec!["large language model".to_string(), "vector database".to_string()]
+        );
+        assert!(expansions_for(&terms, "vector search").is_empty());
+    }
+
+    #[test]
+    fn test_expand_query_skips_excluded_terms() {
+        let tenant = "synonyms_test_excluded";
+        let mut terms: HashMap<String, Vec<String>> = HashMap::new();
+        terms.insert("llm".to_string(), vec!["large language model".to_string()]);
+        terms.insert("gpu".to_string(), vec!["graphics card".to_string()]);
+        SYNONYMS.write().unwrap().insert(tenant.to_string(), terms);
+
+        assert_eq!(expand_query(tenant, "llm -gpu"), "llm -gpu large language model");
+        assert_eq!(expand_query(tenant, "benchmarks NOT llm"), "benchmarks NOT llm");
+        assert_eq!(expand_query(tenant, "llm -\"large language model\""), "llm -\"large language model\"");
+        drop_tenant_synonyms(tenant);
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Tenant scoping for sources, chunks and the in-memory indexes.
+//!
+//! Every source and chunk row carries a `tenant_id`, and the HNSW, BM25
+//! and incremental indexes keep one namespace per tenant. All reads and
+//! writes go through the active tenant, so one app instance can host
+//! several isolated profiles (e.g. work/personal). Existing data belongs
+//! to `DEFAULT_TENANT`.
+
+use std::path::Path;
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use rusqlite::params;
+use log::info;
+
+use crate::api::bm25_search::drop_tenant_bm25_index;
+use crate::api::db_pool::get_connection;
+use crate::api::error::{db_error, RagError};
+use crate::api::hnsw_index::drop_tenant_hnsw_index;
+use crate::api::incremental_index::drop_tenant_buffers;
+use crate::api::index_freshness::forget_indexed_versions;
+use crate::api::named_bm25::drop_tenant_named_bm25_indexes;
+use crate::api::named_index::drop_tenant_named_indexes;
+use crate::api::rerank::drop_tenant_candidates;
+use crate::api::retrieval_state::drop_tenant_retrieval;
+use crate::api::search_analytics::delete_search_analytics;
+use crate::api::staged_ingest::delete_tenant_staging;
+use crate::api::synonyms::drop_tenant_synonyms;
+
+pub const DEFAULT_TENANT: &str = "default";
+
+const MAX_TENANT_ID_LEN: usize = 64;
+
+static ACTIVE_TENANT: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new(DEFAULT_TENANT.to_string()));
+
+/// Tenant ids end up in SQL literals and index file names, so only
+/// ASCII letters, digits, '_' and '-' are allowed.
+fn validate_tenant_id(tenant_id: &str) -> Result<(), RagError> {
+    let valid = !tenant_id.is_empty()
+        && tenant_id.len() <= MAX_TENANT_ID_LEN
+        && tenant_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(RagError::InvalidInput(format!(
+            "Invalid tenant id '{}' (use 1-{} letters, digits, '_' or '-')",
+            tenant_id, MAX_TENANT_ID_LEN
+        )))
+    }
+}
+
+/// Switch the active tenant. Indexes of other tenants stay in memory.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_active_tenant(tenant_id: String) -> Result<(), RagError> {
+    validate_tenant_id(&tenant_id)?;
+    info!("[tenant] Active tenant: {}", tenant_id);
+    *ACTIVE_TENANT.write().unwrap() = tenant_id;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_active_tenant() -> String {
+    active_tenant()
+}
+
+pub(crate) fn active_tenant() -> String {
+    ACTIVE_TENANT.read().unwrap().clone()
+}
+
+/// SQL condition scoping `alias` (a sources or chunks alias) to the
+/// active tenant. Safe to inline: tenant ids are validated on set.
+pub(crate) fn tenant_condition(alias: &str) -> String {
+    format!("{}.tenant_id = '{}'", alias, active_tenant())
+}
+
+/// Per-tenant index file path: the default tenant keeps `base_path`,
+/// others get `_<tenant>` appended to the file stem.
+pub(crate) fn tenant_index_path(base_path: &str, tenant_id: &str) -> String {
+    if tenant_id == DEFAULT_TENANT {
+        return base_path.to_string();
+    }
+    let path = Path::new(base_path);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("index");
+    let file_name = match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}_{}.{}", stem, tenant_id, ext),
+        None => format!("{}_{}", stem, tenant_id),
+    };
+    path.with_file_name(file_name).to_string_lossy().into_owned()
+}
+
+/// Tenants that own at least one source, plus the active tenant.
+pub fn list_tenants() -> Result<Vec<String>, RagError> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare("SELECT DISTINCT tenant_id FROM sources ORDER BY tenant_id")
+        .map_err(db_error)?;
+    let mut tenants: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(db_error)?
+        .filter_map(|r| r.ok())
+        .collect();
+    let active = active_tenant();
+    if !tenants.contains(&active) {
+        tenants.push(active);
+        tenants.sort();
+    }
+    Ok(tenants)
+}
+
+/// Delete every source, chunk, chat message, saved query and search
+/// analytics record of a tenant and drop its indexes.
+pub fn delete_tenant(tenant_id: String) -> Result<(), RagError> {
+    validate_tenant_id(&tenant_id)?;
+    let mut conn = get_connection()?;
+    let tx = conn.transaction().map_err(db_error)?;
+    for table in ["chunk_entities", "kg_triples"] {
+        tx.execute(
+            &format!("DELETE FROM {} WHERE chunk_id IN (SELECT id FROM chunks WHERE tenant_id = ?1)", table),
+            params![tenant_id],
+        )
+        .map_err(db_error)?;
+    }
+    tx.execute("DELETE FROM chunks WHERE tenant_id = ?1", params![tenant_id]).map_err(db_error)?;
+    tx.execute("DELETE FROM sources WHERE tenant_id = ?1", params![tenant_id]).map_err(db_error)?;
+    for table in ["synonyms", "messages", "queries"] {
+        tx.execute(&format!("DELETE FROM {} WHERE tenant_id = ?1", table), params![tenant_id])
+            .map_err(db_error)?;
+    }
+    delete_tenant_staging(&tx, &tenant_id).map_err(db_error)?;
+    delete_search_analytics(&tx, &tenant_id).map_err(db_error)?;
+    tx.commit().map_err(db_error)?;
+
+    drop_tenant_hnsw_index(&tenant_id);
+    drop_tenant_bm25_index(&tenant_id);
+    drop_tenant_buffers(&tenant_id);
+    drop_tenant_named_indexes(&tenant_id);
+    drop_tenant_named_bm25_indexes(&tenant_id);
+    drop_tenant_candidates(&tenant_id);
+    drop_tenant_retrieval(&tenant_id);
+    drop_tenant_synonyms(&tenant_id);
+    forget_indexed_versions(&tenant_id);
+    info!("[tenant] Deleted tenant {}", tenant_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bm25_search::{bm25_add_documents_to_tenant, bm25_search_in_tenant};
+    use crate::api::hnsw_index::{build_hnsw_index_for_tenant, search_hnsw_in_tenant};
+
+    #[test]
+    fn test_tenant_id_validation_and_index_path() {
+        assert!(validate_tenant_id("work_profile-2").is_ok());
+        assert!(validate_tenant_id("").is_err());
+        assert!(validate_tenant_id("x'; DROP TABLE sources; --").is_err());
+
+        assert_eq!(tenant_index_path("/data/chunks.hnsw", DEFAULT_TENANT), "/data/chunks.hnsw");
+        assert_eq!(tenant_index_path("/data/chunks.hnsw", "work"), "/data/chunks_work.hnsw");
+        assert_eq!(tenant_index_path("/data/chunks", "work"), "/data/chunks_work");
+    }
+
+    #[test]
+    fn test_indexes_are_namespaced_per_tenant() {
+        build_hnsw_index_for_tenant("tenant_test_a", vec![(1, vec![1.0, 0.0])]).unwrap();
+        build_hnsw_index_for_tenant("tenant_test_b", vec![(2, vec![1.0, 0.0])]).unwrap();
+        let a = search_hnsw_in_tenant("tenant_test_a", vec![1.0, 0.0], 5, None).unwrap();
+        assert_eq!(a.iter().map(|r| r.id).collect::<Vec<_>>(), vec![1]);
+
+        bm25_add_documents_to_tenant("tenant_test_a", vec![(1, "shared secret".to_string())]);
+        assert_eq!(bm25_search_in_tenant("tenant_test_a", "secret", 5).len(), 1);
+        assert!(bm25_search_in_tenant("tenant_test_b", "secret", 5).is_empty());
+
+        for tenant in ["tenant_test_a", "tenant_test_b"] {
+            drop_tenant_hnsw_index(tenant);
+            drop_tenant_bm25_index(tenant);
+        }
+        assert!(search_hnsw_in_tenant("tenant_test_a", vec![1.0, 0.0], 5, None).is_err());
+    }
+}
@@ -16,13 +16,16 @@
 //
 //! HuggingFace tokenizers integration module.
 
-use anyhow::Result;
 use flutter_rust_bridge::frb;
 use once_cell::sync::Lazy;
 use std::sync::RwLock;
 use tokenizers::Tokenizer;
 
+use crate::api::error::RagError;
+
 static TOKENIZER: Lazy<RwLock<Option<Tokenizer>>> = Lazy::new(|| RwLock::new(None));
+/// `<dir>/<file>` of the loaded tokenizer, without the rest of the path.
+static TOKENIZER_NAME: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
 const TOKENIZER_BASE_TRUNCATION_MAX_LENGTH: usize = 256;
 const TOKENIZER_MID_TRUNCATION_MAX_LENGTH: usize = 384;
 const TOKENIZER_MAX_TRUNCATION_MAX_LENGTH: usize = 512;
@@ -40,26 +43,60 @@ fn resolve_truncation_max_length(text: &str) -> usize {
     }
 }
 
+fn not_initialized() -> RagError {
+    RagError::NotInitialized("Tokenizer not initialized. Call init_tokenizer first.".to_string())
+}
+
 /// Initialize tokenizer with tokenizer.json file path.
-pub fn init_tokenizer(tokenizer_path: String) -> Result<()> {
-    let mut tokenizer = Tokenizer::from_file(&tokenizer_path)
-        .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
+pub fn init_tokenizer(tokenizer_path: String) -> Result<(), RagError> {
+    let mut tokenizer = Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+        RagError::ModelLoadError(format!(
+            "Failed to load tokenizer from {}: {}",
+            tokenizer_path, e
+        ))
+    })?;
 
     tokenizer.with_padding(None);
     tokenizer.with_truncation(None).ok();
 
     let mut global_tokenizer = TOKENIZER.write().unwrap();
     *global_tokenizer = Some(tokenizer);
+    *TOKENIZER_NAME.write().unwrap() = Some(tokenizer_name(&tokenizer_path));
     Ok(())
 }
 
+fn tokenizer_name(path: &str) -> String {
+    let path = std::path::Path::new(path);
+    let file = path.file_name().map(|f| f.to_string_lossy());
+    let dir = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .map(|d| d.to_string_lossy());
+    match (dir, file) {
+        (Some(dir), Some(file)) => format!("{}/{}", dir, file),
+        (None, Some(file)) => file.to_string(),
+        _ => path.display().to_string(),
+    }
+}
+
+/// Name of the loaded tokenizer (see `TOKENIZER_NAME`), if any.
+pub(crate) fn loaded_tokenizer_name() -> Option<String> {
+    TOKENIZER_NAME.read().unwrap().clone()
+}
+
+/// Number of tokens in `text` without special tokens, or `None` when no
+/// tokenizer is loaded.
+pub(crate) fn count_tokens(text: &str) -> Option<usize> {
+    let tokenizer_guard = TOKENIZER.read().unwrap();
+    let tokenizer = tokenizer_guard.as_ref()?;
+    tokenizer.encode(text, false).ok().map(|e| e.len())
+}
+
 /// Tokenize text (returns token IDs with CLS/SEP tokens).
 #[frb(sync)]
-pub fn tokenize(text: String) -> Result<Vec<u32>> {
+pub fn tokenize(text: String) -> Result<Vec<u32>, RagError> {
     let tokenizer_guard = TOKENIZER.read().unwrap();
-    let tokenizer = tokenizer_guard
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Tokenizer not initialized. Call init_tokenizer first."))?;
+    let tokenizer = tokenizer_guard.as_ref().ok_or_else(not_initialized)?;
 
     // Dynamically widen truncation for longer chunks while keeping
     // an upper bound for mobile runtime stability.
@@ -74,31 +111,27 @@ pub fn tokenize(text: String) -> Result<Vec<u32>> {
 
     let encoding = tokenizer
         .encode(text, true)
-        .map_err(|e| anyhow::anyhow!("Tokenization failed: {}", e))?;
+        .map_err(|e| RagError::InvalidInput(format!("Tokenization failed: {}", e)))?;
     Ok(encoding.get_ids().to_vec())
 }
 
 /// Decode token IDs to text.
 #[frb(sync)]
-pub fn decode_tokens(token_ids: Vec<u32>) -> Result<String> {
+pub fn decode_tokens(token_ids: Vec<u32>) -> Result<String, RagError> {
     let tokenizer_guard = TOKENIZER.read().unwrap();
-    let tokenizer = tokenizer_guard
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Tokenizer not initialized."))?;
+    let tokenizer = tokenizer_guard.as_ref().ok_or_else(not_initialized)?;
 
     let decoded = tokenizer
         .decode(&token_ids, true)
-        .map_err(|e| anyhow::anyhow!("Decoding failed: {}", e))?;
+        .map_err(|e| RagError::InvalidInput(format!("Decoding failed: {}", e)))?;
     Ok(decoded)
 }
 
 /// Get vocab size.
 #[frb(sync)]
-pub fn get_vocab_size() -> Result<u32> {
+pub fn get_vocab_size() -> Result<u32, RagError> {
     let tokenizer_guard = TOKENIZER.read().unwrap();
-    let tokenizer = tokenizer_guard
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Tokenizer not initialized."))?;
+    let tokenizer = tokenizer_guard.as_ref().ok_or_else(not_initialized)?;
     Ok(tokenizer.get_vocab_size(true) as u32)
 }
 
@@ -122,4 +155,13 @@ mod tests {
         let text = "x".repeat(3000);
         assert_eq!(resolve_truncation_max_length(&text), 512);
     }
+
+    #[test]
+    fn test_tokenizer_name_keeps_last_two_components() {
+        assert_eq!(
+            tokenizer_name("/data/user/0/app/files/bge-m3/tokenizer.json"),
+            "bge-m3/tokenizer.json"
+        );
+        assert_eq!(tokenizer_name("tokenizer.json"), "tokenizer.json");
+    }
 }
@@ -16,6 +16,14 @@
 //
 //! User intent parsing for slash commands.
 
+use crate::api::language_detect::detect_language_code;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Separator between the two subjects of `/compare` ("vs", "vs.", "versus", "대").
+static COMPARE_SEPARATOR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\s+(?:vs\.?|versus|대)\s+").unwrap());
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum UserIntent {
     Summary { query: String },
@@ -23,6 +31,12 @@ pub enum UserIntent {
     ExpandKnowledge { query: String },
     General { query: String },
     InvalidCommand { command: String, reason: String },
+    /// Compare two subjects; retrieval runs once per subject and interleaves.
+    Compare { left: String, right: String },
+    /// Cite the chunks behind the previous answer (see `get_last_retrieval`).
+    Cite { query: String },
+    /// List the sources behind the previous answer (see `get_last_retrieval`).
+    Sources { query: String },
 }
 
 impl UserIntent {
@@ -33,6 +47,9 @@ impl UserIntent {
             UserIntent::ExpandKnowledge { query } => query,
             UserIntent::General { query } => query,
             UserIntent::InvalidCommand { command, .. } => command,
+            UserIntent::Compare { left, .. } => left,
+            UserIntent::Cite { query } => query,
+            UserIntent::Sources { query } => query,
         }
     }
     
@@ -43,6 +60,9 @@ impl UserIntent {
             UserIntent::ExpandKnowledge { .. } => "more",
             UserIntent::General { .. } => "general",
             UserIntent::InvalidCommand { .. } => "invalid",
+            UserIntent::Compare { .. } => "compare",
+            UserIntent::Cite { .. } => "cite",
+            UserIntent::Sources { .. } => "sources",
         }
     }
 }
@@ -74,7 +94,19 @@ pub fn parse_user_intent(input: &str) -> UserIntent {
             }
         }
         "/more" => UserIntent::ExpandKnowledge { query: argument.to_string() },
-        _ => UserIntent::InvalidCommand { command: command.to_string(), reason: format!("Unknown command '{}'. Available: /summary, /define, /more", command) }
+        "/compare" => parse_compare(&command, argument),
+        "/cite" => UserIntent::Cite { query: argument.to_string() },
+        "/sources" => UserIntent::Sources { query: argument.to_string() },
+        _ => UserIntent::InvalidCommand { command: command.to_string(), reason: format!("Unknown command '{}'. Available: /summary, /define, /more, /compare, /cite, /sources", command) }
+    }
+}
+
+/// Split a `/compare` argument into its two subjects.
+fn parse_compare(command: &str, argument: &str) -> UserIntent {
+    let mut subjects = COMPARE_SEPARATOR.splitn(argument, 2).map(str::trim);
+    match (subjects.next(), subjects.next()) {
+        (Some(left), Some(right)) if !left.is_empty() && !right.is_empty() => UserIntent::Compare { left: left.to_string(), right: right.to_string() },
+        _ => UserIntent::InvalidCommand { command: command.to_string(), reason: "Two subjects required for /compare. Usage: /compare <A> vs <B>".to_string() },
     }
 }
 
@@ -84,18 +116,24 @@ pub struct ParsedIntent {
     pub query: String,
     pub is_valid: bool,
     pub error_message: Option<String>,
+    /// Detected query language ("ko", "ja", "zh", "en", "unknown").
+    pub language: String,
 }
 
 /// Parse intent (FRB-friendly wrapper).
 #[flutter_rust_bridge::frb(sync)]
 pub fn parse_intent(input: String) -> ParsedIntent {
     let intent = parse_user_intent(&input);
+    let language = detect_language_code(intent.get_query()).to_string();
     match intent {
-        UserIntent::Summary { query } => ParsedIntent { intent_type: "summary".to_string(), query, is_valid: true, error_message: None },
-        UserIntent::Define { term } => ParsedIntent { intent_type: "define".to_string(), query: term, is_valid: true, error_message: None },
-        UserIntent::ExpandKnowledge { query } => ParsedIntent { intent_type: "more".to_string(), query, is_valid: true, error_message: None },
-        UserIntent::General { query } => ParsedIntent { intent_type: "general".to_string(), query, is_valid: true, error_message: None },
-        UserIntent::InvalidCommand { command, reason } => ParsedIntent { intent_type: "invalid".to_string(), query: command, is_valid: false, error_message: Some(reason) },
+        UserIntent::Summary { query } => ParsedIntent { intent_type: "summary".to_string(), query, is_valid: true, error_message: None, language },
+        UserIntent::Define { term } => ParsedIntent { intent_type: "define".to_string(), query: term, is_valid: true, error_message: None, language },
+        UserIntent::ExpandKnowledge { query } => ParsedIntent { intent_type: "more".to_string(), query, is_valid: true, error_message: None, language },
+        UserIntent::General { query } => ParsedIntent { intent_type: "general".to_string(), query, is_valid: true, error_message: None, language },
+        UserIntent::InvalidCommand { command, reason } => ParsedIntent { intent_type: "invalid".to_string(), query: command, is_valid: false, error_message: Some(reason), language },
+        UserIntent::Compare { left, right } => ParsedIntent { intent_type: "compare".to_string(), query: format!("{} vs {}", left, right), is_valid: true, error_message: None, language },
+        UserIntent::Cite { query } => ParsedIntent { intent_type: "cite".to_string(), query, is_valid: true, error_message: None, language },
+        UserIntent::Sources { query } => ParsedIntent { intent_type: "sources".to_string(), query, is_valid: true, error_message: None, language },
     }
 }
 
@@ -116,6 +154,30 @@ mod tests {
         assert!(matches!(intent, UserIntent::Define { .. }));
     }
     
+    #[test]
+    fn test_parse_compare_command() {
+        let intent = parse_user_intent("/compare RWA VS DeFi lending");
+        assert_eq!(intent, UserIntent::Compare { left: "RWA".to_string(), right: "DeFi lending".to_string() });
+        assert_eq!(intent.intent_type(), "compare");
+
+        let missing = parse_user_intent("/compare RWA");
+        assert!(matches!(missing, UserIntent::InvalidCommand { .. }));
+    }
+
+    #[test]
+    fn test_parse_attribution_commands() {
+        assert_eq!(parse_user_intent("/cite"), UserIntent::Cite { query: String::new() });
+        assert_eq!(parse_user_intent("/SOURCES").intent_type(), "sources");
+    }
+
+    #[test]
+    fn test_parse_intent_detects_language() {
+        assert_eq!(parse_intent("/define 스마트 계약".to_string()).language, "ko");
+        assert_eq!(parse_intent("what is a smart contract".to_string()).language, "en");
+        // Language is detected on the argument, not the slash command itself.
+        assert_eq!(parse_intent("/summary".to_string()).language, "unknown");
+    }
+
     #[test]
     fn test_parse_empty_input() {
         let intent = parse_user_intent("");
@@ -0,0 +1,84 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Dot products for exact scans.
+//!
+//! On aarch64 (every supported phone) `dot` uses NEON with four
+//! independent accumulators; elsewhere it sums eight lanes at a time,
+//! which LLVM turns into SSE/AVX. Both reorder the additions, so results
+//! can differ from a sequential sum in the last bits.
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    use std::arch::aarch64::{vaddq_f32, vaddvq_f32, vdupq_n_f32, vfmaq_f32, vld1q_f32};
+
+    let n = a.len().min(b.len());
+    let blocks = n / 16;
+    // SAFETY: NEON is part of the aarch64 baseline, and every load reads
+    // four floats starting below `blocks * 16 <= n` in steps of four.
+    let mut total = unsafe {
+        let mut acc = [vdupq_n_f32(0.0); 4];
+        for block in 0..blocks {
+            for (lane, sum) in acc.iter_mut().enumerate() {
+                let offset = block * 16 + lane * 4;
+                let va = vld1q_f32(a.as_ptr().add(offset));
+                let vb = vld1q_f32(b.as_ptr().add(offset));
+                *sum = vfmaq_f32(*sum, va, vb);
+            }
+        }
+        vaddvq_f32(vaddq_f32(vaddq_f32(acc[0], acc[1]), vaddq_f32(acc[2], acc[3])))
+    };
+    for (x, y) in a[blocks * 16..n].iter().zip(&b[blocks * 16..n]) {
+        total += x * y;
+    }
+    total
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+pub(crate) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    const LANES: usize = 8;
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+    let (chunks_a, chunks_b) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail: f32 = chunks_a.remainder().iter().zip(chunks_b.remainder()).map(|(x, y)| x * y).sum();
+    let mut acc = [0.0f32; LANES];
+    for (xs, ys) in chunks_a.zip(chunks_b) {
+        for ((sum, x), y) in acc.iter_mut().zip(xs).zip(ys) {
+            *sum += x * y;
+        }
+    }
+    acc.iter().sum::<f32>() + tail
+}
+
+pub(crate) fn norm(v: &[f32]) -> f32 {
+    dot(v, v).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_matches_sequential_sum() {
+        for len in [0, 3, 8, 16, 37, 384] {
+            let a: Vec<f32> = (0..len).map(|i| (i as f32 * 0.37).sin()).collect();
+            let b: Vec<f32> = (0..len).map(|i| (i as f32 * 0.11).cos()).collect();
+            let expected: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+            assert!((dot(&a, &b) - expected).abs() < 1e-4, "len {}", len);
+        }
+        assert_eq!(norm(&[3.0, 4.0]), 5.0);
+    }
+}
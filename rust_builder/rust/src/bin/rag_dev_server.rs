@@ -0,0 +1,46 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Run the engine's localhost JSON API against a database file.
+//!
+//! Usage: `cargo run --features dev-server --bin rag_dev_server -- <db_path> [addr]`
+
+use rag_engine_flutter::api::db_pool::init_db_pool;
+use rag_engine_flutter::api::source_rag::init_source_db;
+use rag_engine_flutter::dev_server::serve;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let Some(db_path) = args.next() else {
+        eprintln!("usage: rag_dev_server <db_path> [addr (default 127.0.0.1:8765)]");
+        std::process::exit(2);
+    };
+    let addr = args.next().unwrap_or_else(|| "127.0.0.1:8765".to_string());
+
+    if let Err(e) = init_db_pool(db_path, 4) {
+        eprintln!("failed to open database: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(e) = init_source_db() {
+        eprintln!("failed to initialize tables: {}", e);
+        std::process::exit(1);
+    }
+    eprintln!("serving on http://{}", addr);
+    if let Err(e) = serve(&addr) {
+        eprintln!("server error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,358 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! C ABI over the core engine for hosts without Flutter.
+//!
+//! Native iOS/Android apps and React Native modules link the same static
+//! or dynamic library and call these `rag_*` functions (declared in
+//! `include/rag_engine.h`); FRB stays one binding layer over `crate::api`.
+//!
+//! Every function returns `RAG_OK` (0) or a negative status matching the
+//! `RagError` variant; `rag_last_error` then describes the failure on the
+//! calling thread. Strings returned through out-pointers are owned by the
+//! caller and must be released with `rag_string_free`. Panics are caught
+//! and reported as `RAG_ERR_INTERNAL` instead of unwinding into the host.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+
+use crate::api::db_pool::init_db_pool;
+use crate::api::error::RagError;
+use crate::api::hybrid_search::{search_hybrid, HybridSearchResult};
+use crate::api::panic_report::catch_panic;
+use crate::api::semantic_chunker::classify_chunk;
+use crate::api::source_rag::{
+    add_chunks, add_source, delete_source, get_source_stats, init_source_db,
+    rebuild_chunk_bm25_index, rebuild_chunk_hnsw_index, ChunkData,
+};
+
+pub const RAG_OK: i32 = 0;
+pub const RAG_ERR_DATABASE: i32 = -1;
+pub const RAG_ERR_IO: i32 = -2;
+pub const RAG_ERR_MODEL_LOAD: i32 = -3;
+pub const RAG_ERR_INVALID_INPUT: i32 = -4;
+pub const RAG_ERR_INTERNAL: i32 = -5;
+pub const RAG_ERR_UNKNOWN: i32 = -6;
+pub const RAG_ERR_DATABASE_BUSY: i32 = -7;
+pub const RAG_ERR_NOT_INITIALIZED: i32 = -8;
+pub const RAG_ERR_PARSE: i32 = -9;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn status_code(e: &RagError) -> i32 {
+    match e {
+        RagError::DatabaseError(_) => RAG_ERR_DATABASE,
+        RagError::IoError(_) => RAG_ERR_IO,
+        RagError::ModelLoadError(_) => RAG_ERR_MODEL_LOAD,
+        RagError::InvalidInput(_) => RAG_ERR_INVALID_INPUT,
+        RagError::InternalError(_) => RAG_ERR_INTERNAL,
+        RagError::Unknown(_) => RAG_ERR_UNKNOWN,
+        RagError::DatabaseBusy(_) => RAG_ERR_DATABASE_BUSY,
+        RagError::NotInitialized(_) => RAG_ERR_NOT_INITIALIZED,
+        RagError::ParseError(_) => RAG_ERR_PARSE,
+    }
+}
+
+fn set_last_error(message: String) {
+    // Interior NULs would truncate the message; replace them.
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Run `f`, translating its error (or panic) into a status code.
+fn call(context: &str, f: impl FnOnce() -> Result<(), RagError>) -> i32 {
+    match catch_panic(context, f).and_then(|result| result) {
+        Ok(()) => {
+            LAST_ERROR.with(|last| *last.borrow_mut() = None);
+            RAG_OK
+        }
+        Err(e) => {
+            let code = status_code(&e);
+            set_last_error(e.to_string());
+            code
+        }
+    }
+}
+
+/// Borrow a required UTF-8 argument.
+unsafe fn required_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, RagError> {
+    if ptr.is_null() {
+        return Err(RagError::InvalidInput(format!("{} must not be null", name)));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| RagError::InvalidInput(format!("{} is not valid UTF-8", name)))
+}
+
+/// Borrow an optional UTF-8 argument; null means `None`.
+unsafe fn optional_str<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, RagError> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    required_str(ptr, name).map(Some)
+}
+
+unsafe fn required_slice<'a, T>(
+    ptr: *const T,
+    len: usize,
+    name: &str,
+) -> Result<&'a [T], RagError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(RagError::InvalidInput(format!("{} must not be null", name)));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+/// JSON form of a search result, shared with the dev server.
+pub(crate) fn hybrid_result_json(r: HybridSearchResult) -> serde_json::Value {
+    serde_json::json!({
+        "doc_id": r.doc_id,
+        "source_id": r.source_id,
+        "chunk_index": r.chunk_index,
+        "score": r.score,
+        "vector_rank": r.vector_rank,
+        "bm25_rank": r.bm25_rank,
+        "content": r.content,
+        "metadata": r.metadata,
+    })
+}
+
+fn into_c_string(value: String) -> Result<*mut c_char, RagError> {
+    CString::new(value)
+        .map(CString::into_raw)
+        .map_err(|_| RagError::InternalError("Result contains a NUL byte".to_string()))
+}
+
+/// Open (or create) the database at `db_path` and its tables.
+///
+/// # Safety
+/// `db_path` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rag_init(db_path: *const c_char, max_pool_size: u32) -> i32 {
+    call("rag_init", || {
+        let db_path = required_str(db_path, "db_path")?;
+        init_db_pool(db_path.to_string(), max_pool_size.max(1))?;
+        init_source_db()
+    })
+}
+
+/// Add a source document. `metadata` and `name` may be null.
+///
+/// # Safety
+/// String arguments must be null or valid NUL-terminated strings;
+/// `out_source_id` and `out_is_duplicate` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn rag_add_source(
+    content: *const c_char,
+    metadata: *const c_char,
+    name: *const c_char,
+    out_source_id: *mut i64,
+    out_is_duplicate: *mut bool,
+) -> i32 {
+    call("rag_add_source", || {
+        let result = add_source(
+            required_str(content, "content")?.to_string(),
+            optional_str(metadata, "metadata")?.map(str::to_string),
+            optional_str(name, "name")?.map(str::to_string),
+        )?;
+        if !out_source_id.is_null() {
+            *out_source_id = result.source_id;
+        }
+        if !out_is_duplicate.is_null() {
+            *out_is_duplicate = result.is_duplicate;
+        }
+        Ok(())
+    })
+}
+
+/// Add `count` chunks to a source. `contents[i]` is chunk `i`'s text and
+/// its embedding is `embeddings[i * dimension..(i + 1) * dimension]`.
+/// Chunk types are classified from the text.
+///
+/// # Safety
+/// `contents` must point to `count` valid NUL-terminated strings and
+/// `embeddings` to `count * dimension` floats; `out_added` must be null
+/// or writable.
+#[no_mangle]
+pub unsafe extern "C" fn rag_add_chunks(
+    source_id: i64,
+    contents: *const *const c_char,
+    count: usize,
+    embeddings: *const f32,
+    dimension: usize,
+    out_added: *mut i32,
+) -> i32 {
+    call("rag_add_chunks", || {
+        if count > 0 && dimension == 0 {
+            return Err(RagError::InvalidInput(
+                "dimension must be non-zero".to_string(),
+            ));
+        }
+        let texts = required_slice(contents, count, "contents")?;
+        let total = count
+            .checked_mul(dimension)
+            .ok_or_else(|| RagError::InvalidInput("count * dimension overflows".to_string()))?;
+        let vectors = required_slice(embeddings, total, "embeddings")?;
+        let mut chunks = Vec::with_capacity(count);
+        let mut position = 0i32;
+        for (i, (text, embedding)) in texts
+            .iter()
+            .zip(vectors.chunks(dimension.max(1)))
+            .enumerate()
+        {
+            let content = required_str(*text, "contents[i]")?.to_string();
+            let end_pos = position + content.chars().count() as i32;
+            chunks.push(ChunkData {
+                chunk_index: i as i32,
+                start_pos: position,
+                end_pos,
+                chunk_type: classify_chunk(&content).as_str().to_string(),
+                content,
+                embedding: embedding.to_vec(),
+                batch_id: None,
+            });
+            position = end_pos;
+        }
+        let added = add_chunks(source_id, chunks)?;
+        if !out_added.is_null() {
+            *out_added = added;
+        }
+        Ok(())
+    })
+}
+
+/// Rebuild the chunk HNSW and BM25 indexes after adding chunks.
+#[no_mangle]
+pub extern "C" fn rag_rebuild_indexes() -> i32 {
+    call("rag_rebuild_indexes", || {
+        rebuild_chunk_hnsw_index()?;
+        rebuild_chunk_bm25_index()
+    })
+}
+
+/// Hybrid search. On success `*out_json` receives a JSON array of
+/// `{doc_id, source_id, chunk_index, score, vector_rank, bm25_rank,
+/// content, metadata}` objects, best first.
+///
+/// # Safety
+/// `query` must be a valid NUL-terminated string, `embedding` must point
+/// to `dimension` floats and `out_json` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn rag_search(
+    query: *const c_char,
+    embedding: *const f32,
+    dimension: usize,
+    top_k: u32,
+    out_json: *mut *mut c_char,
+) -> i32 {
+    call("rag_search", || {
+        if out_json.is_null() {
+            return Err(RagError::InvalidInput(
+                "out_json must not be null".to_string(),
+            ));
+        }
+        let query = required_str(query, "query")?.to_string();
+        let embedding = required_slice(embedding, dimension, "embedding")?.to_vec();
+        let results = search_hybrid(query, embedding, top_k, None, None, None)?.results;
+        let json: Vec<serde_json::Value> = results.into_iter().map(hybrid_result_json).collect();
+        *out_json = into_c_string(serde_json::Value::Array(json).to_string())?;
+        Ok(())
+    })
+}
+
+/// Delete a source and its chunks.
+#[no_mangle]
+pub extern "C" fn rag_delete_source(source_id: i64) -> i32 {
+    call("rag_delete_source", || delete_source(source_id))
+}
+
+/// Source and chunk counts of the active tenant.
+///
+/// # Safety
+/// The out-pointers must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn rag_source_stats(out_sources: *mut i64, out_chunks: *mut i64) -> i32 {
+    call("rag_source_stats", || {
+        let stats = get_source_stats()?;
+        if !out_sources.is_null() {
+            *out_sources = stats.source_count;
+        }
+        if !out_chunks.is_null() {
+            *out_chunks = stats.chunk_count;
+        }
+        Ok(())
+    })
+}
+
+/// Message of the last failed call on this thread, or null. The pointer
+/// stays valid until the next `rag_*` call on the same thread.
+#[no_mangle]
+pub extern "C" fn rag_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |s| s.as_ptr())
+    })
+}
+
+/// Release a string returned by this library.
+///
+/// # Safety
+/// `s` must be null or a pointer returned by a `rag_*` function that has
+/// not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn rag_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_arguments_set_last_error() {
+        let code = unsafe {
+            rag_add_source(
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, RAG_ERR_INVALID_INPUT);
+        let message = unsafe { CStr::from_ptr(rag_last_error()) }
+            .to_str()
+            .unwrap();
+        assert!(message.contains("content must not be null"));
+
+        let mut out: *mut c_char = std::ptr::null_mut();
+        let query = CString::new("q").unwrap();
+        let code = unsafe { rag_search(query.as_ptr(), std::ptr::null(), 3, 5, &mut out) };
+        assert_eq!(code, RAG_ERR_INVALID_INPUT);
+        assert!(out.is_null());
+
+        assert_eq!(call("ok", || Ok(())), RAG_OK);
+        assert!(rag_last_error().is_null());
+    }
+}
@@ -0,0 +1,334 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Localhost JSON API for desktop development (feature `dev-server`).
+//!
+//! Exposes the on-device pipeline to scripts and integration tests
+//! without a running Flutter app:
+//!
+//! - `GET /stats` → `{source_count, chunk_count}`
+//! - `POST /ingest` with `{content, metadata?, name?, chunks: [{content,
+//!   embedding}]}` → `{source_id, is_duplicate, chunks_added}`; indexes are
+//!   rebuilt after each ingest.
+//! - `POST /search` with `{query, embedding, top_k?}` → result array
+//!
+//! Embeddings are computed by the caller, as in the app. Requests are
+//! handled one at a time and the server only binds loopback addresses.
+//! Requests whose `Host` header names anything other than a loopback
+//! host are rejected with 403, so a web page can't reach the server
+//! through DNS rebinding.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use log::{info, warn};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::api::error::RagError;
+use crate::api::hybrid_search::search_hybrid;
+use crate::api::semantic_chunker::classify_chunk;
+use crate::api::source_rag::{
+    add_chunks, add_source, get_source_stats, rebuild_chunk_bm25_index, rebuild_chunk_hnsw_index,
+    ChunkData,
+};
+use crate::c_api::hybrid_result_json;
+
+/// Largest accepted request body.
+const MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Deserialize)]
+struct IngestChunk {
+    content: String,
+    embedding: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct IngestRequest {
+    content: String,
+    metadata: Option<String>,
+    name: Option<String>,
+    #[serde(default)]
+    chunks: Vec<IngestChunk>,
+}
+
+#[derive(Deserialize)]
+struct SearchRequest {
+    query: String,
+    embedding: Vec<f32>,
+    top_k: Option<u32>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    host: Option<String>,
+    body: Vec<u8>,
+}
+
+/// Serve requests on `addr` (e.g. `127.0.0.1:8765`) until the process
+/// exits. The database must already be initialized.
+pub fn serve(addr: &str) -> std::io::Result<()> {
+    let addr: SocketAddr = addr
+        .parse()
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    if !addr.ip().is_loopback() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "dev server only binds loopback addresses",
+        ));
+    }
+    let listener = TcpListener::bind(addr)?;
+    info!("[dev_server] Listening on http://{}", addr);
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(e) = handle_connection(stream) {
+                    warn!("[dev_server] Connection error: {}", e);
+                }
+            }
+            Err(e) => warn!("[dev_server] Accept failed: {}", e),
+        }
+    }
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream) -> std::io::Result<()> {
+    let (status, body) = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => {
+            info!("[dev_server] {} {}", request.method, request.path);
+            route(&request)
+        }
+        Err(e) => (
+            400,
+            json!({ "error": e.to_string(), "code": "invalid_input" }),
+        ),
+    };
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> std::io::Result<Request> {
+    let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string());
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(invalid("malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0usize;
+    let mut host = None;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid("bad Content-Length"))?;
+            } else if name.trim().eq_ignore_ascii_case("host") {
+                host = Some(value.trim().to_string());
+            }
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(invalid("request body too large"));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        host,
+        body,
+    })
+}
+
+/// Whether a `Host` header names a loopback host (`localhost`,
+/// `127.0.0.1`, `[::1]`), with or without a port.
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => match rest.split_once(']') {
+            Some((name, port)) if port.is_empty() || port.starts_with(':') => name,
+            _ => return false,
+        },
+        None => host.split(':').next().unwrap_or(""),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+fn route(request: &Request) -> (u16, Value) {
+    if !request.host.as_deref().is_some_and(is_loopback_host) {
+        return (
+            403,
+            json!({ "error": "Host must be a loopback address", "code": "forbidden_host" }),
+        );
+    }
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/stats") => stats(),
+        ("POST", "/ingest") => parse(&request.body).and_then(ingest),
+        ("POST", "/search") => parse(&request.body).and_then(search),
+        _ => return (404, json!({ "error": "not found", "code": "not_found" })),
+    };
+    match result {
+        Ok(body) => (200, body),
+        Err(e) => {
+            let status = match e {
+                RagError::InvalidInput(_) | RagError::ParseError(_) => 400,
+                RagError::DatabaseBusy(_) | RagError::NotInitialized(_) => 503,
+                _ => 500,
+            };
+            (status, json!({ "error": e.to_string(), "code": e.code() }))
+        }
+    }
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(body: &[u8]) -> Result<T, RagError> {
+    serde_json::from_slice(body)
+        .map_err(|e| RagError::InvalidInput(format!("Invalid JSON body: {}", e)))
+}
+
+fn stats() -> Result<Value, RagError> {
+    let stats = get_source_stats()?;
+    Ok(json!({ "source_count": stats.source_count, "chunk_count": stats.chunk_count }))
+}
+
+fn ingest(request: IngestRequest) -> Result<Value, RagError> {
+    let source = add_source(request.content, request.metadata, request.name)?;
+    let mut chunks_added = 0;
+    if !source.is_duplicate && !request.chunks.is_empty() {
+        let mut position = 0i32;
+        let chunks = request
+            .chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                let end_pos = position + chunk.content.chars().count() as i32;
+                let data = ChunkData {
+                    chunk_index: i as i32,
+                    start_pos: position,
+                    end_pos,
+                    chunk_type: classify_chunk(&chunk.content).as_str().to_string(),
+                    content: chunk.content,
+                    embedding: chunk.embedding,
+                    batch_id: None,
+                };
+                position = end_pos;
+                data
+            })
+            .collect();
+        chunks_added = add_chunks(source.source_id, chunks)?;
+        rebuild_chunk_hnsw_index()?;
+        rebuild_chunk_bm25_index()?;
+    }
+    Ok(json!({
+        "source_id": source.source_id,
+        "is_duplicate": source.is_duplicate,
+        "chunks_added": chunks_added,
+    }))
+}
+
+fn search(request: SearchRequest) -> Result<Value, RagError> {
+    let results = search_hybrid(
+        request.query,
+        request.embedding,
+        request.top_k.unwrap_or(5),
+        None,
+        None,
+        None,
+    )?
+    .results;
+    Ok(Value::Array(
+        results.into_iter().map(hybrid_result_json).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request_and_error_routes() {
+        let raw = b"POST /search HTTP/1.1\r\nHost: localhost\r\ncontent-length: 7\r\n\r\n{\"q\":1}";
+        let request = read_request(&mut &raw[..]).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/search");
+        assert_eq!(request.body, b"{\"q\":1}");
+
+        let (status, body) = route(&request);
+        assert_eq!(status, 400);
+        assert_eq!(body["code"], "invalid_input");
+
+        let missing = Request {
+            method: "GET".to_string(),
+            path: "/nope".to_string(),
+            host: Some("127.0.0.1:8765".to_string()),
+            body: vec![],
+        };
+        assert_eq!(route(&missing).0, 404);
+        assert!(serve("0.0.0.0:0").is_err());
+    }
+
+    #[test]
+    fn test_non_loopback_host_is_forbidden() {
+        for host in ["localhost", "LOCALHOST:8765", "127.0.0.1:8765", "[::1]:8765", "[::1]"] {
+            assert!(is_loopback_host(host), "{host}");
+        }
+        for host in ["evil.example", "localhost.evil.example", "10.0.0.1:8765", "[::1]x", ""] {
+            assert!(!is_loopback_host(host), "{host}");
+        }
+
+        let raw = b"GET /stats HTTP/1.1\r\nHost: attacker.example:8765\r\n\r\n";
+        let request = read_request(&mut &raw[..]).unwrap();
+        assert_eq!(request.host.as_deref(), Some("attacker.example:8765"));
+        assert_eq!(route(&request).0, 403);
+
+        let no_host = Request {
+            method: "GET".to_string(),
+            path: "/stats".to_string(),
+            host: None,
+            body: vec![],
+        };
+        assert_eq!(route(&no_host).0, 403);
+    }
+}
@@ -25,6 +25,8 @@
 
 // Section: imports
 
+use crate::api::chunk_store::ChunkStore;
+use crate::api::chunk_store::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -37,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1964511040;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 2072812933;
 
 // Section: executor
 
@@ -45,7 +47,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__source_rag__add_chunks_impl(
+fn wire__crate__api__chunk_store__MemoryStore_chunk_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +55,7 @@ fn wire__crate__api__source_rag__add_chunks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_chunks",
+            debug_name: "MemoryStore_chunk_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,20 +69,36 @@ fn wire__crate__api__source_rag__add_chunks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
-            let api_chunks =
-                <Vec<crate::api::source_rag::ChunkData>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::add_chunks(api_source_id, api_chunks)?;
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chunk_store::MemoryStore::chunk_count(&*api_that_guard),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__add_document_impl(
+fn wire__crate__api__chunk_store__MemoryStore_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -88,7 +106,7 @@ fn wire__crate__api__simple_rag__add_document_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_document",
+            debug_name: "MemoryStore_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -102,22 +120,18 @@ fn wire__crate__api__simple_rag__add_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::simple_rag::add_document(api_content, api_embedding)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chunk_store::MemoryStore::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__add_document_simple_impl(
+fn wire__crate__api__chunk_store__MemoryStore_delete_source_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -125,7 +139,7 @@ fn wire__crate__api__simple_rag__add_document_simple_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_document_simple",
+            debug_name: "MemoryStore_delete_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -139,24 +153,39 @@ fn wire__crate__api__simple_rag__add_document_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::add_document_simple(
-                            api_content,
-                            api_embedding,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, true,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let mut api_that_guard = api_that_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chunk_store::MemoryStore::delete_source(
+                            &mut *api_that_guard,
+                            api_source_id,
+                        ))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__add_source_impl(
+fn wire__crate__api__chunk_store__MemoryStore_get_chunk_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -164,7 +193,7 @@ fn wire__crate__api__source_rag__add_source_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_source",
+            debug_name: "MemoryStore_get_chunk",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -178,21 +207,37 @@ fn wire__crate__api__source_rag__add_source_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_metadata = <Option<String>>::sse_decode(&mut deserializer);
-            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok =
-                        crate::api::source_rag::add_source(api_content, api_metadata, api_name)?;
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chunk_store::MemoryStore::get_chunk(&*api_that_guard, api_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_add_document_impl(
+fn wire__crate__api__chunk_store__MemoryStore_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -200,7 +245,7 @@ fn wire__crate__api__bm25_search__bm25_add_document_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_add_document",
+            debug_name: "MemoryStore_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -214,21 +259,18 @@ fn wire__crate__api__bm25_search__bm25_add_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_doc_id = <i64>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::bm25_search::bm25_add_document(api_doc_id, api_content);
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chunk_store::MemoryStore::new())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_add_documents_impl(
+fn wire__crate__api__chunk_store__MemoryStore_put_chunk_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -236,7 +278,7 @@ fn wire__crate__api__bm25_search__bm25_add_documents_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_add_documents",
+            debug_name: "MemoryStore_put_chunk",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -250,12 +292,32 @@ fn wire__crate__api__bm25_search__bm25_add_documents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_docs = <Vec<(i64, String)>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_chunk = <crate::api::chunk_store::StoredChunk>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, true,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let mut api_that_guard = api_that_guard.unwrap();
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::bm25_search::bm25_add_documents(api_docs);
+                        crate::api::chunk_store::MemoryStore::put_chunk(
+                            &mut *api_that_guard,
+                            api_chunk,
+                        );
                     })?;
                     Ok(output_ok)
                 })())
@@ -263,7 +325,7 @@ fn wire__crate__api__bm25_search__bm25_add_documents_impl(
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_clear_index_impl(
+fn wire__crate__api__chunk_store__MemoryStore_search_vectors_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -271,7 +333,7 @@ fn wire__crate__api__bm25_search__bm25_clear_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_clear_index",
+            debug_name: "MemoryStore_search_vectors",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -285,19 +347,41 @@ fn wire__crate__api__bm25_search__bm25_clear_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::bm25_search::bm25_clear_index();
-                    })?;
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chunk_store::MemoryStore::search_vectors(
+                            &*api_that_guard,
+                            &api_query,
+                            api_top_k,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_get_document_count_impl(
+fn wire__crate__api__source_rag__add_chunks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -305,7 +389,7 @@ fn wire__crate__api__bm25_search__bm25_get_document_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_get_document_count",
+            debug_name: "add_chunks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -319,26 +403,28 @@ fn wire__crate__api__bm25_search__bm25_get_document_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_chunks =
+                <Vec<crate::api::source_rag::ChunkData>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::bm25_search::bm25_get_document_count())?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::add_chunks(api_source_id, api_chunks)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_remove_document_impl(
+fn wire__crate__api__async_ops__add_chunks_async_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_remove_document",
+            debug_name: "add_chunks_async",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -352,20 +438,25 @@ fn wire__crate__api__bm25_search__bm25_remove_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_chunks =
+                <Vec<crate::api::source_rag::ChunkData>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::bm25_search::bm25_remove_document(api_doc_id);
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::async_ops::add_chunks_async(api_source_id, api_chunks)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__bm25_search__bm25_search_impl(
+fn wire__crate__api__source_rag__add_chunks_packed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -373,7 +464,7 @@ fn wire__crate__api__bm25_search__bm25_search_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "bm25_search",
+            debug_name: "add_chunks_packed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -387,29 +478,35 @@ fn wire__crate__api__bm25_search__bm25_search_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_chunks =
+                <Vec<crate::api::source_rag::ChunkMeta>>::sse_decode(&mut deserializer);
+            let api_embeddings = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_offsets = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::bm25_search::bm25_search(
-                        api_query, api_top_k,
-                    ))?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::add_chunks_packed(
+                        api_source_id,
+                        api_chunks,
+                        api_embeddings,
+                        api_offsets,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__build_hnsw_index_impl(
+fn wire__crate__api__async_ops__add_chunks_packed_async_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "build_hnsw_index",
+            debug_name: "add_chunks_packed_async",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -423,27 +520,38 @@ fn wire__crate__api__hnsw_index__build_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_points = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_chunks =
+                <Vec<crate::api::source_rag::ChunkMeta>>::sse_decode(&mut deserializer);
+            let api_embeddings = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_offsets = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::hnsw_index::build_hnsw_index(api_points)?;
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok = crate::api::async_ops::add_chunks_packed_async(
+                            api_source_id,
+                            api_chunks,
+                            api_embeddings,
+                            api_offsets,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__calculate_cosine_similarity_impl(
+fn wire__crate__api__query_rewrite__add_conversation_turn_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calculate_cosine_similarity",
+            debug_name: "add_conversation_turn",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -457,19 +565,18 @@ fn wire__crate__api__simple_rag__calculate_cosine_similarity_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_vec_a = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_vec_b = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple_rag::calculate_cosine_similarity(api_vec_a, api_vec_b),
-                )?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::query_rewrite::add_conversation_turn(api_text);
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
+fn wire__crate__api__simple_rag__add_document_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -477,7 +584,7 @@ fn wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "chunk_type_as_str",
+            debug_name: "add_document",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -491,20 +598,20 @@ fn wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::semantic_chunker::ChunkType>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::semantic_chunker::ChunkType::as_str(&api_that);
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::simple_rag::add_document(api_content, api_embedding)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
+fn wire__crate__api__simple_rag__add_document_simple_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -512,7 +619,7 @@ fn wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "chunk_type_from_str",
+            debug_name: "add_document_simple",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -526,29 +633,30 @@ fn wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_s = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::semantic_chunker::ChunkType::from_str(&api_s),
-                    )?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::simple_rag::add_document_simple(api_content, api_embedding)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__semantic_chunker__classify_chunk_impl(
+fn wire__crate__api__chat_memory__add_memory_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "classify_chunk",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "add_memory_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -560,17 +668,26 @@ fn wire__crate__api__semantic_chunker__classify_chunk_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_role = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::semantic_chunker::classify_chunk(&api_text))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::chat_memory::add_memory_message(
+                        api_conversation_id,
+                        api_role,
+                        api_content,
+                        api_embedding,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple_rag__clear_all_documents_impl(
+fn wire__crate__api__source_rag__add_source_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -578,7 +695,7 @@ fn wire__crate__api__simple_rag__clear_all_documents_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_all_documents",
+            debug_name: "add_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -592,19 +709,21 @@ fn wire__crate__api__simple_rag__clear_all_documents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_metadata = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::clear_all_documents()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::add_source(api_content, api_metadata, api_name)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__clear_buffer_impl(
+fn wire__crate__api__synonyms__add_synonyms_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -612,7 +731,7 @@ fn wire__crate__api__incremental_index__clear_buffer_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_buffer",
+            debug_name: "add_synonyms",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -626,29 +745,28 @@ fn wire__crate__api__incremental_index__clear_buffer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_term = <String>::sse_decode(&mut deserializer);
+            let api_expansions = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::incremental_index::clear_buffer();
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::synonyms::add_synonyms(api_term, api_expansions)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__clear_hnsw_index_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__semantic_chunker__analyze_chunks_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_hnsw_index",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "analyze_chunks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -660,19 +778,20 @@ fn wire__crate__api__hnsw_index__clear_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::semantic_chunker::ChunkingConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::hnsw_index::clear_hnsw_index();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::analyze_chunks(
+                    api_text, api_config,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__db_pool__close_db_pool_impl(
+fn wire__crate__api__audit__audit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -680,7 +799,7 @@ fn wire__crate__api__db_pool__close_db_pool_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "close_db_pool",
+            debug_name: "audit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -696,26 +815,25 @@ fn wire__crate__api__db_pool__close_db_pool_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::db_pool::close_db_pool();
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::audit::audit()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__close_log_stream_impl(
+fn wire__crate__api__benchmark__benchmark_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "close_log_stream",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "benchmark_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -728,16 +846,17 @@ fn wire__crate__api__logger__close_log_stream_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::logger::close_log_stream()?;
-                    Ok(output_ok)
-                })(),
-            )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::benchmark::BenchmarkConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__compression_utils__compress_text_impl(
+fn wire__crate__api__bm25_search__bm25_add_document_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -745,7 +864,7 @@ fn wire__crate__api__compression_utils__compress_text_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "compress_text",
+            debug_name: "bm25_add_document",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -759,26 +878,21 @@ fn wire__crate__api__compression_utils__compress_text_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_max_chars = <i32>::sse_decode(&mut deserializer);
-            let api_options =
-                <crate::api::compression_utils::CompressionOptions>::sse_decode(&mut deserializer);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::compression_utils::compress_text(
-                            api_text,
-                            api_max_chars,
-                            api_options,
-                        ))?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::bm25_search::bm25_add_document(api_doc_id, api_content);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__compression_utils__compress_text_simple_impl(
+fn wire__crate__api__bm25_search__bm25_add_documents_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -786,7 +900,7 @@ fn wire__crate__api__compression_utils__compress_text_simple_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "compress_text_simple",
+            debug_name: "bm25_add_documents",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -800,21 +914,20 @@ fn wire__crate__api__compression_utils__compress_text_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_level = <i32>::sse_decode(&mut deserializer);
+            let api_docs = <Vec<(i64, String)>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::compression_utils::compress_text_simple(api_text, api_level),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::bm25_search::bm25_add_documents(api_docs);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__compression_utils__compression_options_default_impl(
+fn wire__crate__api__named_bm25__bm25_add_documents_named_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -822,7 +935,7 @@ fn wire__crate__api__compression_utils__compression_options_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "compression_options_default",
+            debug_name: "bm25_add_documents_named",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -836,28 +949,30 @@ fn wire__crate__api__compression_utils__compression_options_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_docs = <Vec<(i64, String)>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::compression_utils::CompressionOptions::default(),
-                    )?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::named_bm25::bm25_add_documents_named(api_name, api_docs)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tokenizer__decode_tokens_impl(
+fn wire__crate__api__bm25_search__bm25_clear_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decode_tokens",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "bm25_clear_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -869,18 +984,19 @@ fn wire__crate__api__tokenizer__decode_tokens_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_token_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::tokenizer::decode_tokens(api_token_ids)?;
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::bm25_search::bm25_clear_index();
+                    })?;
                     Ok(output_ok)
-                })(),
-            )
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__source_rag__delete_source_impl(
+fn wire__crate__api__bm25_search__bm25_get_document_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -888,7 +1004,7 @@ fn wire__crate__api__source_rag__delete_source_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_source",
+            debug_name: "bm25_get_document_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -902,18 +1018,18 @@ fn wire__crate__api__source_rag__delete_source_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::delete_source(api_source_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::bm25_search::bm25_get_document_count())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__embedding_point_new_impl(
+fn wire__crate__api__bm25_search__bm25_remove_document_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -921,7 +1037,7 @@ fn wire__crate__api__hnsw_index__embedding_point_new_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "embedding_point_new",
+            debug_name: "bm25_remove_document",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -935,21 +1051,20 @@ fn wire__crate__api__hnsw_index__embedding_point_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <i64>::sse_decode(&mut deserializer);
-            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::hnsw_index::EmbeddingPoint::new(api_id, api_embedding),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::bm25_search::bm25_remove_document(api_doc_id);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__document_parser__extract_text_from_document_impl(
+fn wire__crate__api__named_bm25__bm25_remove_documents_named_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -957,7 +1072,7 @@ fn wire__crate__api__document_parser__extract_text_from_document_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_text_from_document",
+            debug_name: "bm25_remove_documents_named",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -971,22 +1086,20 @@ fn wire__crate__api__document_parser__extract_text_from_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_doc_ids = <Vec<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::document_parser::extract_text_from_document(
-                            api_file_bytes,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::named_bm25::bm25_remove_documents_named(api_name, api_doc_ids)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__document_parser__extract_text_from_docx_impl(
+fn wire__crate__api__bm25_search__bm25_search_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -994,7 +1107,7 @@ fn wire__crate__api__document_parser__extract_text_from_docx_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_text_from_docx",
+            debug_name: "bm25_search",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1008,21 +1121,21 @@ fn wire__crate__api__document_parser__extract_text_from_docx_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::document_parser::extract_text_from_docx(api_file_bytes)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::bm25_search::bm25_search(
+                        api_query, api_top_k,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__document_parser__extract_text_from_pdf_impl(
+fn wire__crate__api__bm25_search__bm25_update_document_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1030,7 +1143,7 @@ fn wire__crate__api__document_parser__extract_text_from_pdf_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_text_from_pdf",
+            debug_name: "bm25_update_document",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1044,21 +1157,21 @@ fn wire__crate__api__document_parser__extract_text_from_pdf_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::document_parser::extract_text_from_pdf(api_file_bytes)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::bm25_search::bm25_update_document(api_doc_id, api_content);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_adjacent_chunks_impl(
+fn wire__crate__api__bm25_search__bm_25_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1066,7 +1179,7 @@ fn wire__crate__api__source_rag__get_adjacent_chunks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_adjacent_chunks",
+            debug_name: "bm_25_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1080,24 +1193,18 @@ fn wire__crate__api__source_rag__get_adjacent_chunks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
-            let api_min_index = <i32>::sse_decode(&mut deserializer);
-            let api_max_index = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_adjacent_chunks(
-                        api_source_id,
-                        api_min_index,
-                        api_max_index,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::bm25_search::Bm25Config::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
+fn wire__crate__api__fuzzy_terms__bm_25_fuzzy_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1105,7 +1212,7 @@ fn wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_chunk_ids_and_contents",
+            debug_name: "bm_25_fuzzy_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1121,15 +1228,16 @@ fn wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_all_chunk_ids_and_contents()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::fuzzy_terms::Bm25FuzzyConfig::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__get_buffer_for_merge_impl(
+fn wire__crate__api__bm25_search__bm_25_index_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1137,7 +1245,7 @@ fn wire__crate__api__incremental_index__get_buffer_for_merge_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_buffer_for_merge",
+            debug_name: "bm_25_index_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1155,14 +1263,14 @@ fn wire__crate__api__incremental_index__get_buffer_for_merge_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::incremental_index::get_buffer_for_merge())?;
+                        Result::<_, ()>::Ok(crate::api::bm25_search::Bm25IndexOptions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__get_buffer_stats_impl(
+fn wire__crate__api__bm25_search__bm_25_tokenization_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1170,7 +1278,7 @@ fn wire__crate__api__incremental_index__get_buffer_stats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_buffer_stats",
+            debug_name: "bm_25_tokenization_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1188,14 +1296,14 @@ fn wire__crate__api__incremental_index__get_buffer_stats_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::incremental_index::get_buffer_stats())?;
+                        Result::<_, ()>::Ok(crate::api::bm25_search::Bm25Tokenization::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__get_document_count_impl(
+fn wire__crate__api__binary_index__build_binary_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1203,7 +1311,7 @@ fn wire__crate__api__simple_rag__get_document_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_document_count",
+            debug_name: "build_binary_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1219,17 +1327,15 @@ fn wire__crate__api__simple_rag__get_document_count_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::get_document_count()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::binary_index::build_binary_index()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__db_pool__get_pool_stats_impl(
+fn wire__crate__api__named_bm25__build_bm25_index_named_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1237,7 +1343,7 @@ fn wire__crate__api__db_pool__get_pool_stats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_pool_stats",
+            debug_name: "build_bm25_index_named",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1251,17 +1357,20 @@ fn wire__crate__api__db_pool__get_pool_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_docs = <Vec<(i64, String)>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::db_pool::get_pool_stats())?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::named_bm25::build_bm25_index_named(api_name, api_docs)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_source_impl(
+fn wire__crate__api__hnsw_index__build_hnsw_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1269,7 +1378,7 @@ fn wire__crate__api__source_rag__get_source_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_source",
+            debug_name: "build_hnsw_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1283,18 +1392,18 @@ fn wire__crate__api__source_rag__get_source_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_points = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_source(api_source_id)?;
+                    let output_ok = crate::api::hnsw_index::build_hnsw_index(api_points)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_source_chunk_count_impl(
+fn wire__crate__api__named_index__build_hnsw_index_named_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1302,7 +1411,7 @@ fn wire__crate__api__source_rag__get_source_chunk_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_source_chunk_count",
+            debug_name: "build_hnsw_index_named",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1316,18 +1425,23 @@ fn wire__crate__api__source_rag__get_source_chunk_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_points = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::named_index::NamedIndexConfig>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_source_chunk_count(api_source_id)?;
+                    let output_ok = crate::api::named_index::build_hnsw_index_named(
+                        api_name, api_points, api_config,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_source_chunks_impl(
+fn wire__crate__api__hnsw_index__build_hnsw_index_with_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1335,7 +1449,7 @@ fn wire__crate__api__source_rag__get_source_chunks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_source_chunks",
+            debug_name: "build_hnsw_index_with_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1349,28 +1463,31 @@ fn wire__crate__api__source_rag__get_source_chunks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_points = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_source_chunks(api_source_id)?;
+                    let output_ok = crate::api::hnsw_index::build_hnsw_index_with_config(
+                        api_points, api_config,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__get_source_stats_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__source_index__cached_source_index_count_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_source_stats",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "cached_source_index_count",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1383,23 +1500,22 @@ fn wire__crate__api__source_rag__get_source_stats_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::get_source_stats()?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::source_index::cached_source_index_count())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__tokenizer__get_vocab_size_impl(
+fn wire__crate__api__simple_rag__calculate_cosine_similarity_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_vocab_size",
+            debug_name: "calculate_cosine_similarity",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1413,24 +1529,26 @@ fn wire__crate__api__tokenizer__get_vocab_size_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_vec_a = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_vec_b = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::tokenizer::get_vocab_size()?;
-                    Ok(output_ok)
-                })(),
-            )
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::simple_rag::calculate_cosine_similarity(api_vec_a, api_vec_b),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__background_tasks__cancel_background_task_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "cancel_background_task",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1444,16 +1562,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::background_tasks::cancel_background_task(api_task_id),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__incremental_index__incremental_add_impl(
+fn wire__crate__api__staged_ingest__cancel_ingest_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1461,7 +1581,7 @@ fn wire__crate__api__incremental_index__incremental_add_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "incremental_add",
+            debug_name: "cancel_ingest",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1475,21 +1595,18 @@ fn wire__crate__api__incremental_index__incremental_add_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_doc_id = <i64>::sse_decode(&mut deserializer);
-            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_staging_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::incremental_index::incremental_add(api_doc_id, api_embedding);
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::staged_ingest::cancel_ingest(api_staging_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__incremental_add_batch_impl(
+fn wire__crate__api__ingest_jobs__cancel_ingest_job_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1497,7 +1614,7 @@ fn wire__crate__api__incremental_index__incremental_add_batch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "incremental_add_batch",
+            debug_name: "cancel_ingest_job",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1511,20 +1628,18 @@ fn wire__crate__api__incremental_index__incremental_add_batch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_docs = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            let api_job_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::incremental_index::incremental_add_batch(api_docs);
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::ingest_jobs::cancel_ingest_job(api_job_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__incremental_remove_impl(
+fn wire__crate__api__reembedding__cancel_reembedding_job_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1532,7 +1647,7 @@ fn wire__crate__api__incremental_index__incremental_remove_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "incremental_remove",
+            debug_name: "cancel_reembedding_job",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1546,20 +1661,17 @@ fn wire__crate__api__incremental_index__incremental_remove_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_doc_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::incremental_index::incremental_remove(api_doc_id);
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::reembedding::cancel_reembedding_job()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__incremental_index__incremental_search_impl(
+fn wire__crate__api__index_freshness__check_index_freshness_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1567,7 +1679,7 @@ fn wire__crate__api__incremental_index__incremental_search_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "incremental_search",
+            debug_name: "check_index_freshness",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1581,24 +1693,51 @@ fn wire__crate__api__incremental_index__incremental_search_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::incremental_index::incremental_search(
-                            api_query_embedding,
-                            api_top_k,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::index_freshness::check_index_freshness()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__semantic_chunker__chunk_text_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chunk_text",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::semantic_chunker::ChunkingConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::chunk_text(
+                    api_text, api_config,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1606,7 +1745,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "chunk_type_as_str",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1620,11 +1759,12 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::semantic_chunker::ChunkType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
+                        crate::api::semantic_chunker::ChunkType::as_str(&api_that);
                     })?;
                     Ok(output_ok)
                 })())
@@ -1632,7 +1772,7 @@ fn wire__crate__api__simple__init_app_impl(
         },
     )
 }
-fn wire__crate__api__simple_rag__init_db_impl(
+fn wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1640,7 +1780,7 @@ fn wire__crate__api__simple_rag__init_db_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_db",
+            debug_name: "chunk_type_from_str",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1654,19 +1794,20 @@ fn wire__crate__api__simple_rag__init_db_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_s = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::init_db()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::semantic_chunker::ChunkType::from_str(&api_s),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__db_pool__init_db_pool_impl(
+fn wire__crate__api__semantic_chunker__chunking_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1674,7 +1815,7 @@ fn wire__crate__api__db_pool__init_db_pool_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_db_pool",
+            debug_name: "chunking_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1688,29 +1829,26 @@ fn wire__crate__api__db_pool__init_db_pool_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_db_path = <String>::sse_decode(&mut deserializer);
-            let api_max_size = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::db_pool::init_db_pool(api_db_path, api_max_size)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::semantic_chunker::ChunkingConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__logger__init_log_stream_impl(
+fn wire__crate__api__semantic_chunker__classify_chunk_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_log_stream",
+            debug_name: "classify_chunk",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -1724,21 +1862,17 @@ fn wire__crate__api__logger__init_log_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::logger::init_log_stream(api_sink)?;
-                    Ok(output_ok)
-                })(),
-            )
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::semantic_chunker::classify_chunk(&api_text))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__logger__init_logger_impl(
+fn wire__crate__api__simple_rag__clear_all_documents_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1746,7 +1880,7 @@ fn wire__crate__api__logger__init_logger_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logger",
+            debug_name: "clear_all_documents",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1762,17 +1896,15 @@ fn wire__crate__api__logger__init_logger_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::logger::init_logger()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::simple_rag::clear_all_documents()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__init_source_db_impl(
+fn wire__crate__api__incremental_index__clear_buffer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1780,7 +1912,7 @@ fn wire__crate__api__source_rag__init_source_db_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_source_db",
+            debug_name: "clear_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1794,27 +1926,30 @@ fn wire__crate__api__source_rag__init_source_db_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::init_source_db()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::incremental_index::clear_buffer(api_table);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tokenizer__init_tokenizer_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__content_crypto__clear_content_encryption_keys_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_tokenizer",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "clear_content_encryption_keys",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1826,30 +1961,26 @@ fn wire__crate__api__tokenizer__init_tokenizer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tokenizer_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::tokenizer::init_tokenizer(api_tokenizer_path)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::content_crypto::clear_content_encryption_keys();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__bm25_search__is_bm25_index_loaded_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__query_rewrite__clear_conversation_turns_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_bm25_index_loaded",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "clear_conversation_turns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1862,17 +1993,16 @@ fn wire__crate__api__bm25_search__is_bm25_index_loaded_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::bm25_search::is_bm25_index_loaded())?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::query_rewrite::clear_conversation_turns();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
+fn wire__crate__api__ingest_jobs__clear_finished_ingest_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1880,7 +2010,7 @@ fn wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_chunk_bm25_index_loaded",
+            debug_name: "clear_finished_ingest_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1896,16 +2026,15 @@ fn wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::source_rag::is_chunk_bm25_index_loaded())?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::ingest_jobs::clear_finished_ingest_jobs()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
+fn wire__crate__api__hnsw_index__clear_hnsw_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1913,7 +2042,7 @@ fn wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_hnsw_index_loaded",
+            debug_name: "clear_hnsw_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1930,25 +2059,25 @@ fn wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::hnsw_index::is_hnsw_index_loaded())?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::hnsw_index::clear_hnsw_index();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__db_pool__is_pool_initialized_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__retrieval_state__clear_last_retrieval_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_pool_initialized",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "clear_last_retrieval",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1961,17 +2090,16 @@ fn wire__crate__api__db_pool__is_pool_initialized_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::db_pool::is_pool_initialized())?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::retrieval_state::clear_last_retrieval();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__source_rag__list_sources_impl(
+fn wire__crate__api__query_history__clear_query_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1979,7 +2107,7 @@ fn wire__crate__api__source_rag__list_sources_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_sources",
+            debug_name: "clear_query_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1996,24 +2124,23 @@ fn wire__crate__api__source_rag__list_sources_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::list_sources()?;
+                    let output_ok = crate::api::query_history::clear_query_history()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__load_hnsw_index_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__panic_report__clear_recent_panics_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_hnsw_index",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "clear_recent_panics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2025,29 +2152,27 @@ fn wire__crate__api__hnsw_index__load_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::hnsw_index::load_hnsw_index(&api_base_path)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::panic_report::clear_recent_panics();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__semantic_chunker__markdown_chunk_impl(
+fn wire__crate__api__search_analytics__clear_search_analytics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "markdown_chunk",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "clear_search_analytics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2059,20 +2184,17 @@ fn wire__crate__api__semantic_chunker__markdown_chunk_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_max_chars = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::markdown_chunk(
-                    api_text,
-                    api_max_chars,
-                ))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::search_analytics::clear_search_analytics()?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__incremental_index__needs_merge_impl(
+fn wire__crate__api__db_pool__close_db_pool_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2080,7 +2202,7 @@ fn wire__crate__api__incremental_index__needs_merge_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "needs_merge",
+            debug_name: "close_db_pool",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2097,22 +2219,54 @@ fn wire__crate__api__incremental_index__needs_merge_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::incremental_index::needs_merge())?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::db_pool::close_db_pool();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__user_intent__parse_intent_impl(
+fn wire__crate__api__logger__close_log_stream_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_intent",
+            debug_name: "close_log_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::logger::close_log_stream()?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__progress__close_progress_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_progress_stream",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2126,24 +2280,24 @@ fn wire__crate__api__user_intent__parse_intent_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::user_intent::parse_intent(api_input))?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::progress::close_progress_stream();
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__user_intent__parse_user_intent_impl(
+fn wire__crate__api__code_chunker__code_chunk_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_user_intent",
+            debug_name: "code_chunk",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2157,17 +2311,22 @@ fn wire__crate__api__user_intent__parse_user_intent_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_language = <String>::sse_decode(&mut deserializer);
+            let api_max_chars = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::user_intent::parse_user_intent(&api_input))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::code_chunker::code_chunk(
+                    api_text,
+                    api_language,
+                    api_max_chars,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple_rag__rebuild_bm25_index_impl(
+fn wire__crate__api__staged_ingest__commit_ingest_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2175,7 +2334,7 @@ fn wire__crate__api__simple_rag__rebuild_bm25_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rebuild_bm25_index",
+            debug_name: "commit_ingest",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2189,19 +2348,20 @@ fn wire__crate__api__simple_rag__rebuild_bm25_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_staging_id = <String>::sse_decode(&mut deserializer);
+            let api_embeddings = <Vec<Vec<f32>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::rebuild_bm25_index()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::staged_ingest::commit_ingest(api_staging_id, api_embeddings)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
+fn wire__crate__api__source_rag__compact_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2209,7 +2369,7 @@ fn wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rebuild_chunk_bm25_index",
+            debug_name: "compact_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2226,14 +2386,14 @@ fn wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::rebuild_chunk_bm25_index()?;
+                    let output_ok = crate::api::source_rag::compact_index()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
+fn wire__crate__api__compression_utils__compress_text_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2241,7 +2401,7 @@ fn wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rebuild_chunk_hnsw_index",
+            debug_name: "compress_text",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2255,17 +2415,26 @@ fn wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_chars = <i32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::compression_utils::CompressionOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::rebuild_chunk_hnsw_index()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::compression_utils::compress_text(
+                            api_text,
+                            api_max_chars,
+                            api_options,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__rebuild_hnsw_index_impl(
+fn wire__crate__api__compression_utils__compress_text_simple_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2273,7 +2442,7 @@ fn wire__crate__api__simple_rag__rebuild_hnsw_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rebuild_hnsw_index",
+            debug_name: "compress_text_simple",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2287,19 +2456,21 @@ fn wire__crate__api__simple_rag__rebuild_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_level = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::simple_rag::rebuild_hnsw_index()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::compression_utils::compress_text_simple(api_text, api_level),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__hybrid_search__rrf_config_default_impl(
+fn wire__crate__api__compression_utils__compression_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2307,7 +2478,7 @@ fn wire__crate__api__hybrid_search__rrf_config_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rrf_config_default",
+            debug_name: "compression_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2324,15 +2495,16 @@ fn wire__crate__api__hybrid_search__rrf_config_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::hybrid_search::RrfConfig::default())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::compression_utils::CompressionOptions::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__save_hnsw_index_impl(
+fn wire__crate__api__snapshot__create_snapshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2340,7 +2512,7 @@ fn wire__crate__api__hnsw_index__save_hnsw_index_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_hnsw_index",
+            debug_name: "create_snapshot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2354,20 +2526,18 @@ fn wire__crate__api__hnsw_index__save_hnsw_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_path = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::hnsw_index::save_hnsw_index(&api_base_path)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::snapshot::create_snapshot(api_path)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__search_chunks_impl(
+fn wire__crate__api__db_retry__db_retry_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2375,7 +2545,7 @@ fn wire__crate__api__source_rag__search_chunks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_chunks",
+            debug_name: "db_retry_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2389,30 +2559,27 @@ fn wire__crate__api__source_rag__search_chunks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        crate::api::source_rag::search_chunks(api_query_embedding, api_top_k)?;
+                        Result::<_, ()>::Ok(crate::api::db_retry::DbRetryConfig::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__hnsw_index__search_hnsw_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__tokenizer__decode_tokens_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_hnsw",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "decode_tokens",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2424,32 +2591,25 @@ fn wire__crate__api__hnsw_index__search_hnsw_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            let api_token_ids = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::hnsw_index::search_hnsw(api_query_embedding, api_top_k)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::tokenizer::decode_tokens(api_token_ids)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__hybrid_search__search_hybrid_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__query_decompose__decompose_query_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_hybrid",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "decompose_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2461,40 +2621,26 @@ fn wire__crate__api__hybrid_search__search_hybrid_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_text = <String>::sse_decode(&mut deserializer);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_config =
-                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
-            let api_filter =
-                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::hybrid_search::search_hybrid(
-                        api_query_text,
-                        api_query_embedding,
-                        api_top_k,
-                        api_config,
-                        api_filter,
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::query_decompose::decompose_query(api_query))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__hybrid_search__search_hybrid_simple_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__chunk_dedup__dedupe_chunks_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_hybrid_simple",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "dedupe_chunks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2506,24 +2652,19 @@ fn wire__crate__api__hybrid_search__search_hybrid_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_text = <String>::sse_decode(&mut deserializer);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_chunks = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::chunk_dedup::DedupeConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::hybrid_search::search_hybrid_simple(
-                        api_query_text,
-                        api_query_embedding,
-                        api_top_k,
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::chunk_dedup::dedupe_chunks(
+                    api_chunks, api_config,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
+fn wire__crate__api__chunk_dedup__dedupe_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2531,7 +2672,7 @@ fn wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_hybrid_weighted",
+            debug_name: "dedupe_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2545,28 +2686,18 @@ fn wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_text = <String>::sse_decode(&mut deserializer);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_vector_weight = <f64>::sse_decode(&mut deserializer);
-            let api_bm25_weight = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::hybrid_search::search_hybrid_weighted(
-                        api_query_text,
-                        api_query_embedding,
-                        api_top_k,
-                        api_vector_weight,
-                        api_bm25_weight,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chunk_dedup::DedupeConfig::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple_rag__search_similar_impl(
+fn wire__crate__api__chat_memory__delete_conversation_memory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2574,7 +2705,7 @@ fn wire__crate__api__simple_rag__search_similar_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_similar",
+            debug_name: "delete_conversation_memory",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2588,29 +2719,126 @@ fn wire__crate__api__simple_rag__search_similar_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::simple_rag::search_similar(api_query_embedding, api_top_k)?;
-                        Ok(output_ok)
-                    })(),
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::chat_memory::delete_conversation_memory(api_conversation_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_index__delete_hnsw_named_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_hnsw_named",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::named_index::delete_hnsw_named(api_name, api_ids)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__semantic_chunker__semantic_chunk_impl(
+fn wire__crate__api__source_rag__delete_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::delete_source(api_source_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tenant__delete_tenant_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_tenant",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tenant_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::tenant::delete_tenant(api_tenant_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__language_detect__detect_language_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "semantic_chunk",
+            debug_name: "detect_language",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2625,26 +2853,56 @@ fn wire__crate__api__semantic_chunker__semantic_chunk_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_text = <String>::sse_decode(&mut deserializer);
-            let api_max_chars = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::semantic_chunk(
-                    api_text,
-                    api_max_chars,
-                ))?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::language_detect::detect_language(api_text))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__semantic_chunker__semantic_chunk_with_overlap_impl(
+fn wire__crate__api__hnsw_index__distance_metric_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "distance_metric_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hnsw_index::DistanceMetric::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_bm25__drop_bm25_index_named_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "semantic_chunk_with_overlap",
+            debug_name: "drop_bm25_index_named",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2658,24 +2916,48 @@ fn wire__crate__api__semantic_chunker__semantic_chunk_with_overlap_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_max_chars = <i32>::sse_decode(&mut deserializer);
-            let api__overlap_chars = <i32>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::semantic_chunker::semantic_chunk_with_overlap(
-                        api_text,
-                        api_max_chars,
-                        api__overlap_chars,
-                    ),
-                )?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::named_bm25::drop_bm25_index_named(api_name))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__compression_utils__sentence_hash_impl(
+fn wire__crate__api__named_index__drop_hnsw_index_named_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "drop_hnsw_index_named",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::named_index::drop_hnsw_index_named(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__embedding_point_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2683,7 +2965,7 @@ fn wire__crate__api__compression_utils__sentence_hash_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sentence_hash",
+            debug_name: "embedding_point_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2697,12 +2979,13 @@ fn wire__crate__api__compression_utils__sentence_hash_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sentence = <String>::sse_decode(&mut deserializer);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::compression_utils::sentence_hash(api_sentence),
+                        crate::api::hnsw_index::EmbeddingPoint::new(api_id, api_embedding),
                     )?;
                     Ok(output_ok)
                 })())
@@ -2710,7 +2993,7 @@ fn wire__crate__api__compression_utils__sentence_hash_impl(
         },
     )
 }
-fn wire__crate__api__compression_utils__should_compress_impl(
+fn wire__crate__api__embedding_storage__embedding_storage_stats_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2718,7 +3001,7 @@ fn wire__crate__api__compression_utils__should_compress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "should_compress",
+            debug_name: "embedding_storage_stats_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2732,23 +3015,19 @@ fn wire__crate__api__compression_utils__should_compress_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_token_threshold = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::compression_utils::should_compress(
-                            api_text,
-                            api_token_threshold,
-                        ))?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::embedding_storage::EmbeddingStorageStats::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__compression_utils__split_sentences_impl(
+fn wire__crate__api__ingest_jobs__enqueue_ingest_job_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2756,7 +3035,7 @@ fn wire__crate__api__compression_utils__split_sentences_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "split_sentences",
+            debug_name: "enqueue_ingest_job",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2770,29 +3049,30 @@ fn wire__crate__api__compression_utils__split_sentences_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ingest::IngestOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::compression_utils::split_sentences(api_text),
-                    )?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::ingest_jobs::enqueue_ingest_job(api_file_path, api_options)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tokenizer__tokenize_impl(
+fn wire__crate__api__chunk_index_store__ensure_chunk_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tokenize",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "ensure_chunk_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2804,18 +3084,17 @@ fn wire__crate__api__tokenizer__tokenize_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::tokenizer::tokenize(api_text)?;
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::chunk_index_store::ensure_chunk_index()?;
                     Ok(output_ok)
-                })(),
-            )
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__source_rag__update_chunk_embedding_impl(
+fn wire__crate__api__index_freshness__ensure_indexes_fresh_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2823,7 +3102,7 @@ fn wire__crate__api__source_rag__update_chunk_embedding_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_chunk_embedding",
+            debug_name: "ensure_indexes_fresh",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2837,22 +3116,17 @@ fn wire__crate__api__source_rag__update_chunk_embedding_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_chunk_id = <i64>::sse_decode(&mut deserializer);
-            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
-                    let output_ok = crate::api::source_rag::update_chunk_embedding(
-                        api_chunk_id,
-                        api_embedding,
-                    )?;
+                    let output_ok = crate::api::index_freshness::ensure_indexes_fresh()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__source_rag__update_source_status_impl(
+fn wire__crate__api__benchmark__evaluate_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2860,7 +3134,7 @@ fn wire__crate__api__source_rag__update_source_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_source_status",
+            debug_name: "evaluate_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2874,20 +3148,20 @@ fn wire__crate__api__source_rag__update_source_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source_id = <i64>::sse_decode(&mut deserializer);
-            let api_status = <String>::sse_decode(&mut deserializer);
+            let api_sample_size = <u32>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::RagError>((move || {
                     let output_ok =
-                        crate::api::source_rag::update_source_status(api_source_id, api_status)?;
+                        crate::api::benchmark::evaluate_index(api_sample_size, api_top_k)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__user_intent__user_intent_get_query_impl(
+fn wire__crate__api__related_chunks__expansion_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2895,7 +3169,7 @@ fn wire__crate__api__user_intent__user_intent_get_query_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "user_intent_get_query",
+            debug_name: "expansion_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2909,20 +3183,19 @@ fn wire__crate__api__user_intent__user_intent_get_query_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::user_intent::UserIntent>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::user_intent::UserIntent::get_query(&api_that);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::related_chunks::ExpansionConfig::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__user_intent__user_intent_intent_type_impl(
+fn wire__crate__api__search_analytics__export_search_analytics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2930,7 +3203,7 @@ fn wire__crate__api__user_intent__user_intent_intent_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "user_intent_intent_type",
+            debug_name: "export_search_analytics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2944,2190 +3217,17730 @@ fn wire__crate__api__user_intent__user_intent_intent_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::user_intent::UserIntent>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::user_intent::UserIntent::intent_type(&api_that);
-                    })?;
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::search_analytics::export_search_analytics(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__entity_extraction__extract_entities_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_entities",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::entity_extraction::extract_entities(api_text))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__query_keywords__extract_query_keywords_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_query_keywords",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::query_keywords::extract_query_keywords(api_query),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__document_parser__extract_text_from_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_text_from_document",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::document_parser::extract_text_from_document(api_file_bytes)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__document_parser__extract_text_from_docx_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_text_from_docx",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::document_parser::extract_text_from_docx(api_file_bytes)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__document_parser__extract_text_from_pdf_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_text_from_pdf",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::document_parser::extract_text_from_pdf(api_file_bytes)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__knowledge_graph__extract_triples_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_triples",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::knowledge_graph::extract_triples(api_text))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__rerank__finalize_with_rerank_scores_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "finalize_with_rerank_scores",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_doc_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            let api_scores = <Vec<f64>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_rerank_weight = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::rerank::finalize_with_rerank_scores(
+                        api_doc_ids,
+                        api_scores,
+                        api_top_k,
+                        api_rerank_weight,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
+fn wire__crate__api__tenant__get_active_tenant_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_active_tenant",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::tenant::get_active_tenant())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_adjacent_chunks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_adjacent_chunks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_min_index = <i32>::sse_decode(&mut deserializer);
+            let api_max_index = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_adjacent_chunks(
+                        api_source_id,
+                        api_min_index,
+                        api_max_index,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_chunk_ids_and_contents",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_all_chunk_ids_and_contents()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__get_background_task_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_background_task",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::background_tasks::get_background_task(api_task_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__bm25_search__get_bm25_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_bm25_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::bm25_search::get_bm25_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__fuzzy_terms__get_bm25_fuzzy_matching_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_bm25_fuzzy_matching",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::fuzzy_terms::get_bm25_fuzzy_matching())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__bm25_search__get_bm25_index_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_bm25_index_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::bm25_search::get_bm25_index_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__incremental_index__get_buffer_for_merge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_buffer_for_merge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::incremental_index::get_buffer_for_merge(api_table),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__get_buffer_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_buffer_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::incremental_index::get_buffer_stats(api_table),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__entity_extraction__get_chunk_entities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_chunk_entities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chunk_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::entity_extraction::get_chunk_entities(api_chunk_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__classification_rules__get_classification_rules_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_classification_rules",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::classification_rules::get_classification_rules(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__chat_memory__get_conversation_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_conversation_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::chat_memory::get_conversation_messages(api_conversation_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_rewrite__get_conversation_turns_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_conversation_turns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::query_rewrite::get_conversation_turns())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__corpus_report__get_corpus_report_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_corpus_report",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::corpus_report::get_corpus_report()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__db_retry__get_db_retry_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_db_retry_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::db_retry::get_db_retry_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple_rag__get_document_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_document_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::simple_rag::get_document_count()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__embedding_storage__get_embedding_storage_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_embedding_storage_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::embedding_storage::get_embedding_storage_stats()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__engine_info__get_engine_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_engine_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::engine_info::get_engine_info())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__knowledge_graph__get_entity_neighborhood_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_entity_neighborhood",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entity = <String>::sse_decode(&mut deserializer);
+            let api_max_hops = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::knowledge_graph::get_entity_neighborhood(
+                        api_entity,
+                        api_max_hops,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__knowledge_graph__get_entity_supporting_chunks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_entity_supporting_chunks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entity = <String>::sse_decode(&mut deserializer);
+            let api_max_hops = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::knowledge_graph::get_entity_supporting_chunks(
+                        api_entity,
+                        api_max_hops,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__get_hnsw_build_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_hnsw_build_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::hnsw_index::get_hnsw_build_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__get_hnsw_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_hnsw_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::hnsw_index::get_hnsw_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__get_hnsw_index_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_hnsw_index_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::hnsw_index::get_hnsw_index_stats())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__get_hnsw_seeded_build_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_hnsw_seeded_build",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::hnsw_index::get_hnsw_seeded_build())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__incremental_index__get_incremental_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_incremental_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::incremental_index::get_incremental_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__get_index_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_index_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::hnsw_index::get_index_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__fts_keyword__get_keyword_backend_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_keyword_backend",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::fts_keyword::get_keyword_backend())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__analyzers__get_language_analyzer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_language_analyzer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_language = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::analyzers::get_language_analyzer(
+                    api_language,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__audit__get_last_audit_report_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_last_audit_report",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::audit::get_last_audit_report())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__maintenance__get_last_maintenance_report_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_last_maintenance_report",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::maintenance::get_last_maintenance_report())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__retrieval_state__get_last_retrieval_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_last_retrieval",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::retrieval_state::get_last_retrieval())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__logger__get_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::logger::get_log_level())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__maintenance__get_maintenance_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_maintenance_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::maintenance::get_maintenance_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory_budget__get_memory_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::memory_budget::get_memory_profile())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory_budget__get_memory_usage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_usage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::memory_budget::get_memory_usage())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__metrics__get_metrics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::metrics::get_metrics())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__db_pool__get_pool_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pool_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::db_pool::get_pool_stats())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_normalize__get_query_normalization_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_query_normalization_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::query_normalize::get_query_normalization_config(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__query_history__get_query_suggestions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_query_suggestions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_prefix = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::query_history::get_query_suggestions(api_prefix)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__panic_report__get_recent_panics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recent_panics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::panic_report::get_recent_panics())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__reembedding__get_reembedding_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_reembedding_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::reembedding::get_reembedding_job()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__intent_presets__get_retrieval_preset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_retrieval_preset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_intent_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::intent_presets::get_retrieval_preset(api_intent_type),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__get_section_patterns_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_section_patterns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::semantic_chunker::get_section_patterns())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_source(api_source_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_source_chunk_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_source_chunk_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_source_chunk_count(api_source_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_source_chunks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_source_chunks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_source_chunks(api_source_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_index__get_source_index_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_source_index_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::source_index::get_source_index_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__get_source_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_source_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::get_source_stats()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tokenizer__get_vocab_size_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_vocab_size",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::tokenizer::get_vocab_size()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__hnsw_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hnsw_index::HnswConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__hnsw_insert_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_insert",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hnsw_index::hnsw_insert(api_id, api_embedding)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__hybrid_search_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hybrid_search_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::hybrid_search::HybridSearchOptions::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__hybrid_search_response_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hybrid_search_response_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::hybrid_search::HybridSearchResponse::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__incremental_add_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "incremental_add",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::incremental_index::incremental_add(
+                            api_table,
+                            api_doc_id,
+                            api_embedding,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__incremental_add_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "incremental_add_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            let api_docs = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::incremental_index::incremental_add_batch(api_table, api_docs);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__incremental_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "incremental_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::incremental_index::IncrementalConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__incremental_remove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "incremental_remove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::incremental_index::incremental_remove(api_table, api_doc_id);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__incremental_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "incremental_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::incremental_index::incremental_search(
+                            api_table,
+                            api_query_embedding,
+                            api_top_k,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__index_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hnsw_index::IndexConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__index_freshness__index_consistency_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_consistency_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::index_freshness::IndexConsistency::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__index_freshness__index_divergence_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_divergence_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::index_freshness::IndexDivergence::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__index_freshness__index_staleness_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_staleness_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::index_freshness::IndexStaleness::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ingest__ingest_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ingest_document",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <crate::api::ingest::IngestInput>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ingest::IngestOptions>::sse_decode(&mut deserializer);
+            let api_embed =
+                decode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_on_progress = decode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok = crate::api::ingest::ingest_document(
+                            api_input,
+                            api_options,
+                            api_embed,
+                            api_on_progress,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__ingest__ingest_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ingest_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::ingest::IngestOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple_rag__init_db_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_db",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::simple_rag::init_db()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__db_pool__init_db_pool_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_db_pool",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_db_path = <String>::sse_decode(&mut deserializer);
+            let api_max_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::db_pool::init_db_pool(api_db_path, api_max_size)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__init_log_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_log_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::logger::init_log_stream(api_sink)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__logger__init_logger_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logger",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::logger::init_logger()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__progress__init_progress_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_progress_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::progress::ProgressEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::progress::init_progress_stream(api_sink);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__init_source_db_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_source_db",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::init_source_db()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tokenizer__init_tokenizer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_tokenizer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tokenizer_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::tokenizer::init_tokenizer(api_tokenizer_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_index__insert_hnsw_named_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "insert_hnsw_named",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_points = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::named_index::insert_hnsw_named(api_name, api_points)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__panic_report__install_panic_hook_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "install_panic_hook",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::panic_report::install_panic_hook();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__background_tasks__is_background_worker_running_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_background_worker_running",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::background_tasks::is_background_worker_running(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__bm25_search__is_bm25_index_loaded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_bm25_index_loaded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::bm25_search::is_bm25_index_loaded())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_chunk_bm25_index_loaded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::source_rag::is_chunk_bm25_index_loaded())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__content_crypto__is_content_encryption_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_content_encryption_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::content_crypto::is_content_encryption_enabled(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_hnsw_index_loaded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hnsw_index::is_hnsw_index_loaded())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ingest_jobs__is_ingest_worker_running_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_ingest_worker_running",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::ingest_jobs::is_ingest_worker_running())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__knowledge_graph__is_knowledge_graph_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_knowledge_graph_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::knowledge_graph::is_knowledge_graph_enabled())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__maintenance__is_maintenance_running_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_maintenance_running",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::maintenance::is_maintenance_running())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__db_pool__is_pool_initialized_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_pool_initialized",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::db_pool::is_pool_initialized())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__embedding_storage__is_quantized_embedding_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_quantized_embedding_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::embedding_storage::is_quantized_embedding_storage(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_analytics__is_search_analytics_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_search_analytics_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::search_analytics::is_search_analytics_enabled(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_trace__is_search_tracing_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_search_tracing_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::search_trace::is_search_tracing_enabled())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__fts_keyword__keyword_backend_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "keyword_backend_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::fts_keyword::KeywordBackend::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retrieval_state__last_retrieval_source_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "last_retrieval_source_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::retrieval_state::LastRetrieval>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::retrieval_state::LastRetrieval::source_ids(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__list_background_tasks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_background_tasks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::background_tasks::list_background_tasks())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__named_bm25__list_bm25_indexes_named_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_bm25_indexes_named",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::named_bm25::list_bm25_indexes_named())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__entity_extraction__list_entity_facets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_entity_facets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entity_type = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::entity_extraction::list_entity_facets(
+                        api_entity_type,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_index__list_hnsw_indexes_named_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_hnsw_indexes_named",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::named_index::list_hnsw_indexes_named())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__ingest_jobs__list_ingest_jobs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_ingest_jobs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::ingest_jobs::list_ingest_jobs()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzers__list_language_analyzers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_language_analyzers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::analyzers::list_language_analyzers())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__list_sources_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_sources",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::list_sources()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__synonyms__list_synonyms_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_synonyms",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::synonyms::list_synonyms())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__tenant__list_tenants_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_tenants",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::tenant::list_tenants()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__load_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hnsw_index::load_hnsw_index(&api_base_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__maintenance__maintenance_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "maintenance_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::maintenance::MaintenanceConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__maintenance__maintenance_report_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "maintenance_report_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::maintenance::MaintenanceReport::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__markdown_chunk_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "markdown_chunk",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_chars = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::markdown_chunk(
+                    api_text,
+                    api_max_chars,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__incremental_index__merge_buffer_into_hnsw_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "merge_buffer_into_hnsw",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::incremental_index::merge_buffer_into_hnsw(api_table)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_index__named_index_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "named_index_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::named_index::NamedIndexConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__incremental_index__needs_merge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "needs_merge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::incremental_index::needs_merge(api_table))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_normalize__normalize_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "normalize_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::query_normalize::normalize_query(api_query))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__maintenance__notify_host_state_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notify_host_state",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_idle = <bool>::sse_decode(&mut deserializer);
+            let api_charging = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::maintenance::notify_host_state(
+                    api_idle,
+                    api_charging,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__user_intent__parse_intent_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_intent",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::user_intent::parse_intent(api_input))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__user_intent__parse_user_intent_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_user_intent",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::user_intent::parse_user_intent(&api_input))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__ingest_jobs__pause_ingest_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_ingest_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::ingest_jobs::pause_ingest_job(api_job_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__reembedding__pause_reembedding_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_reembedding_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::reembedding::pause_reembedding_job()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__staged_ingest__prepare_ingest_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prepare_ingest",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ingest::IngestOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::staged_ingest::prepare_ingest(api_text, api_options)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__quantization_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quantization_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hnsw_index::Quantization::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__embedding_storage__quantize_stored_embeddings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quantize_stored_embeddings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::embedding_storage::quantize_stored_embeddings()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_decompose__query_decomposition_is_decomposed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_decomposition_is_decomposed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::query_decompose::QueryDecomposition>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::query_decompose::QueryDecomposition::is_decomposed(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_normalize__query_normalization_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_normalization_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::query_normalize::QueryNormalizationConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__error__rag_error_code_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rag_error_code",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::error::RagError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::error::RagError::code(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__error__rag_error_detail_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rag_error_detail",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::error::RagError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::error::RagError::detail(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__error__rag_error_is_retryable_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rag_error_is_retryable",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::error::RagError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::error::RagError::is_retryable(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple_rag__rebuild_bm25_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_bm25_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::simple_rag::rebuild_bm25_index()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_chunk_bm25_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::rebuild_chunk_bm25_index()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__async_ops__rebuild_chunk_bm25_index_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_chunk_bm25_index_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::async_ops::rebuild_chunk_bm25_index_async().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_chunk_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::rebuild_chunk_hnsw_index()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__async_ops__rebuild_chunk_hnsw_index_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_chunk_hnsw_index_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::async_ops::rebuild_chunk_hnsw_index_async().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__rebuild_chunk_hnsw_index_with_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_chunk_hnsw_index_with_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::rebuild_chunk_hnsw_index_with_config(api_config)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple_rag__rebuild_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rebuild_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::simple_rag::rebuild_hnsw_index()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_history__record_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "record_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_result_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::query_history::record_query(api_query_text, api_result_count)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzers__register_language_analyzer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_language_analyzer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_analyzer =
+                <crate::api::analyzers::LanguageAnalyzer>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::analyzers::register_language_analyzer(api_analyzer)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__synonyms__remove_synonyms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_synonyms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_term = <String>::sse_decode(&mut deserializer);
+            let api_expansions = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::synonyms::remove_synonyms(api_term, api_expansions)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search_analytics__report_search_feedback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_search_feedback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_chunk_id = <i64>::sse_decode(&mut deserializer);
+            let api_action =
+                <crate::api::search_analytics::SearchFeedbackAction>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::search_analytics::report_search_feedback(
+                        api_query_text,
+                        api_chunk_id,
+                        api_action,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzers__reset_language_analyzers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_language_analyzers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::analyzers::reset_language_analyzers();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__logger__reset_log_levels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_log_levels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::logger::reset_log_levels()?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__metrics__reset_metrics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::metrics::reset_metrics();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__intent_presets__reset_retrieval_presets_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_retrieval_presets",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::intent_presets::reset_retrieval_presets();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__snapshot__restore_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::snapshot::restore_snapshot(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ingest_jobs__resume_ingest_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_ingest_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_job_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::ingest_jobs::resume_ingest_job(api_job_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__intent_presets__retrieval_preset_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retrieval_preset_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::intent_presets::RetrievalPreset::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__query_rewrite__rewrite_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rewrite_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_history = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::query_rewrite::rewrite_query(
+                    api_history,
+                    api_query,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__query_rewrite__rewrite_query_with_context_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rewrite_query_with_context",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::query_rewrite::rewrite_query_with_context(api_query),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__content_crypto__rotate_content_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rotate_content_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_new_key_id = <String>::sse_decode(&mut deserializer);
+            let api_new_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::content_crypto::rotate_content_key(
+                        api_new_key_id,
+                        api_new_key,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__rrf_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rrf_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hybrid_search::RrfConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__benchmark__run_benchmark_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_benchmark",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::benchmark::BenchmarkConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::benchmark::run_benchmark(api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__save_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hnsw_index::save_hnsw_index(&api_base_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_bm25__search_bm25_named_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_bm25_named",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::named_bm25::search_bm25_named(api_name, api_query, api_top_k)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__search_chunks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_chunks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::search_chunks(api_query_embedding, api_top_k)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__binary_index__search_chunks_binary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_chunks_binary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::binary_index::search_chunks_binary(
+                        api_query_embedding,
+                        api_top_k,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__search_chunks_diverse_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_chunks_diverse",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_diversity_lambda = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::search_chunks_diverse(
+                        api_query_embedding,
+                        api_top_k,
+                        api_diversity_lambda,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__search_chunks_next_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_chunks_next_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cursor = <String>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::search_chunks_next_page(api_cursor, api_page_size)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__search_chunks_paged_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_chunks_paged",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::search_chunks_paged(
+                        api_query_embedding,
+                        api_page_size,
+                        api_offset,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search_trace__search_diagnostics_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_diagnostics_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::search_trace::SearchDiagnostics::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::hybrid_search::SearchFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__search_hnsw_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hnsw",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::hnsw_index::search_hnsw(api_query_embedding, api_top_k)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__search_hnsw_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hnsw_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            let api_allowed_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hnsw_index::search_hnsw_filtered(
+                        api_query_embedding,
+                        api_top_k,
+                        api_allowed_ids,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__named_index__search_hnsw_named_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hnsw_named",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::named_index::search_hnsw_named(
+                        api_name,
+                        api_query_embedding,
+                        api_top_k,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__search_hnsw_with_ef_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hnsw_with_ef",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            let api_ef_search = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hnsw_index::search_hnsw_with_ef(
+                        api_query_embedding,
+                        api_top_k,
+                        api_ef_search,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            let api_options = <Option<crate::api::hybrid_search::HybridSearchOptions>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                        api_config,
+                        api_filter,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__async_ops__search_hybrid_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            let api_options = <Option<crate::api::hybrid_search::HybridSearchOptions>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok = crate::api::async_ops::search_hybrid_async(
+                            api_query_text,
+                            api_query_embedding,
+                            api_top_k,
+                            api_config,
+                            api_filter,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__rerank__search_hybrid_candidates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_candidates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_candidate_count = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::rerank::search_hybrid_candidates(
+                        api_query_text,
+                        api_query_embedding,
+                        api_candidate_count,
+                        api_config,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_compare_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_compare",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_left_text = <String>::sse_decode(&mut deserializer);
+            let api_left_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_right_text = <String>::sse_decode(&mut deserializer);
+            let api_right_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_compare(
+                        api_left_text,
+                        api_left_embedding,
+                        api_right_text,
+                        api_right_embedding,
+                        api_top_k,
+                        api_config,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_expanded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_expanded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            let api_expansion = <Option<crate::api::related_chunks::ExpansionConfig>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_expanded(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                        api_config,
+                        api_filter,
+                        api_expansion,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_for_intent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_for_intent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_intent_type = <String>::sse_decode(&mut deserializer);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_for_intent(
+                        api_intent_type,
+                        api_query_text,
+                        api_query_embedding,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_simple_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_simple",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_simple(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::hybrid_search::HybridSearchEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_stream(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                        api_config,
+                        api_filter,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_traced_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_traced",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::hybrid_search::RrfConfig>>::sse_decode(&mut deserializer);
+            let api_filter =
+                <Option<crate::api::hybrid_search::SearchFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_traced(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                        api_config,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_hybrid_weighted",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_vector_weight = <f64>::sse_decode(&mut deserializer);
+            let api_bm25_weight = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::hybrid_search::search_hybrid_weighted(
+                        api_query_text,
+                        api_query_embedding,
+                        api_top_k,
+                        api_vector_weight,
+                        api_bm25_weight,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_memory__search_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::chat_memory::search_memory(
+                        api_query_embedding,
+                        api_top_k,
+                        api_conversation_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple_rag__search_similar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_similar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::simple_rag::search_similar(api_query_embedding, api_top_k)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__semantic_chunk_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_chunk",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_chars = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::semantic_chunker::semantic_chunk(
+                    api_text,
+                    api_max_chars,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__semantic_chunk_by_tokens_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_chunk_by_tokens",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_tokens = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::semantic_chunker::semantic_chunk_by_tokens(
+                        api_text,
+                        api_max_tokens,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__semantic_chunk_with_overlap_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_chunk_with_overlap",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_chars = <i32>::sse_decode(&mut deserializer);
+            let api_overlap_chars = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::semantic_chunker::semantic_chunk_with_overlap(
+                        api_text,
+                        api_max_chars,
+                        api_overlap_chars,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__compression_utils__sentence_hash_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sentence_hash",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sentence = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::compression_utils::sentence_hash(api_sentence),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tenant__set_active_tenant_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_active_tenant",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tenant_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::tenant::set_active_tenant(api_tenant_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__bm25_search__set_bm25_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bm25_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::bm25_search::Bm25Config>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::bm25_search::set_bm25_config(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__fuzzy_terms__set_bm25_fuzzy_matching_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bm25_fuzzy_matching",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <Option<crate::api::fuzzy_terms::Bm25FuzzyConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::fuzzy_terms::set_bm25_fuzzy_matching(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__bm25_search__set_bm25_index_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bm25_index_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options =
+                <crate::api::bm25_search::Bm25IndexOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::bm25_search::set_bm25_index_options(api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__classification_rules__set_classification_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_classification_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rules = <Vec<crate::api::classification_rules::ClassificationRule>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::classification_rules::set_classification_rules(api_rules)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__content_crypto__set_content_encryption_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_content_encryption_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key_id = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok =
+                    crate::api::content_crypto::set_content_encryption_key(api_key_id, api_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__db_retry__set_db_retry_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_db_retry_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::db_retry::DbRetryConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::db_retry::set_db_retry_config(api_config);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__engine_info__set_embedding_model_name_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_embedding_model_name",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::engine_info::set_embedding_model_name(api_name);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__entity_extraction__set_entity_gazetteer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_entity_gazetteer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entries =
+                <Vec<crate::api::entity_extraction::GazetteerEntry>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::entity_extraction::set_entity_gazetteer(api_entries);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__set_hnsw_build_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_hnsw_build_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::hnsw_index::set_hnsw_build_config(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__set_hnsw_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_hnsw_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::hnsw_index::HnswConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::hnsw_index::set_hnsw_config(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__set_hnsw_seeded_build_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_hnsw_seeded_build",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_seed = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::hnsw_index::set_hnsw_seeded_build(api_seed);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__incremental_index__set_incremental_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_incremental_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::incremental_index::IncrementalConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::incremental_index::set_incremental_config(api_config);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index__set_index_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_index_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::hnsw_index::IndexConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::hnsw_index::set_index_config(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__fts_keyword__set_keyword_backend_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_keyword_backend",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_backend =
+                <crate::api::fts_keyword::KeywordBackend>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::fts_keyword::set_keyword_backend(api_backend)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__knowledge_graph__set_knowledge_graph_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_knowledge_graph_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::knowledge_graph::set_knowledge_graph_enabled(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__logger__set_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::logger::set_log_level(api_level)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__maintenance__set_maintenance_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_maintenance_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::maintenance::MaintenanceConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::maintenance::set_maintenance_config(api_config);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory_budget__set_memory_budget_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_budget",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_budget_mb = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::memory_budget::set_memory_budget(api_budget_mb)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__set_module_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_module_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_module = <String>::sse_decode(&mut deserializer);
+            let api_level = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::logger::set_module_log_level(api_module, api_level)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__embedding_storage__set_quantized_embedding_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_quantized_embedding_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::embedding_storage::set_quantized_embedding_storage(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__query_normalize__set_query_normalization_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_query_normalization_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::query_normalize::QueryNormalizationConfig>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::query_normalize::set_query_normalization_config(api_config);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__intent_presets__set_retrieval_preset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_retrieval_preset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_intent_type = <String>::sse_decode(&mut deserializer);
+            let api_preset =
+                <crate::api::intent_presets::RetrievalPreset>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::intent_presets::set_retrieval_preset(api_intent_type, api_preset);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_analytics__set_search_analytics_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_search_analytics_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::search_analytics::set_search_analytics_enabled(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_trace__set_search_tracing_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_search_tracing",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::search_trace::set_search_tracing(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__semantic_chunker__set_section_patterns_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_section_patterns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_patterns = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::semantic_chunker::set_section_patterns(api_patterns)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_index__set_source_index_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_source_index_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::source_index::SourceIndexConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::source_index::set_source_index_config(api_config);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__compression_utils__should_compress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "should_compress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_token_threshold = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::compression_utils::should_compress(
+                            api_text,
+                            api_token_threshold,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_index__source_index_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_index_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::source_index::SourceIndexConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__compression_utils__split_sentences_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "split_sentences",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::compression_utils::split_sentences(api_text),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ingest_jobs__start_ingest_worker_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_ingest_worker",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_embed =
+                decode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_on_job_update = decode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::ingest_jobs::start_ingest_worker(api_embed, api_on_job_update)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__reembedding__start_reembedding_job_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_reembedding_job",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_batch_size = <u32>::sse_decode(&mut deserializer);
+            let api_embed_batch =
+                decode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_on_progress = decode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::RagError>(
+                    (move || async move {
+                        let output_ok = crate::api::reembedding::start_reembedding_job(
+                            api_model_name,
+                            api_batch_size,
+                            api_embed_batch,
+                            api_on_progress,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__submit_buffer_merge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_buffer_merge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_table =
+                <crate::api::incremental_index::BufferTable>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::background_tasks::submit_buffer_merge(api_table)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__submit_index_rebuild_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_index_rebuild",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::background_tasks::submit_index_rebuild()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__submit_reembedding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_reembedding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_batch_size = <u32>::sse_decode(&mut deserializer);
+            let api_embed_batch =
+                decode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_on_progress = decode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::background_tasks::submit_reembedding(
+                        api_model_name,
+                        api_batch_size,
+                        api_embed_batch,
+                        api_on_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__background_tasks__submit_snapshot_export_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "submit_snapshot_export",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::background_tasks::submit_snapshot_export(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tokenizer__tokenize_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tokenize",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::RagError>((move || {
+                let output_ok = crate::api::tokenizer::tokenize(api_text)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__source_rag__update_chunk_content_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_chunk_content",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chunk_id = <i64>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::update_chunk_content(api_chunk_id, api_content)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__update_chunk_embedding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_chunk_embedding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_chunk_id = <i64>::sse_decode(&mut deserializer);
+            let api_embedding = <Vec<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::update_chunk_embedding(
+                        api_chunk_id,
+                        api_embedding,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__update_chunk_embeddings_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_chunk_embeddings_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_updates = <Vec<(i64, Vec<f32>)>>::sse_decode(&mut deserializer);
+            let api_on_progress =
+                decode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::source_rag::update_chunk_embeddings_batch(
+                        api_updates,
+                        api_on_progress,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple_rag__update_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_document",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_doc_id = <i64>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::simple_rag::update_document(api_doc_id, api_content)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__staged_ingest__update_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_new_content = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ingest::IngestOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok = crate::api::staged_ingest::update_source(
+                        api_source_id,
+                        api_new_content,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__source_rag__update_source_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_source_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_id = <i64>::sse_decode(&mut deserializer);
+            let api_status = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::source_rag::update_source_status(api_source_id, api_status)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__user_intent__user_intent_get_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "user_intent_get_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::user_intent::UserIntent>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::user_intent::UserIntent::get_query(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__user_intent__user_intent_intent_type_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "user_intent_intent_type",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::user_intent::UserIntent>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::user_intent::UserIntent::intent_type(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__index_freshness__verify_index_consistency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_index_consistency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_repair = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::RagError>((move || {
+                    let output_ok =
+                        crate::api::index_freshness::verify_index_consistency(api_repair)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_String_Output_list_prim_f_32_strict_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<Vec<f32>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> Vec<f32> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Vec<f32>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_embedding_batch_progress_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::source_rag::EmbeddingBatchProgress) -> flutter_rust_bridge::DartFnFuture<()>
+{
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::source_rag::EmbeddingBatchProgress,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::source_rag::EmbeddingBatchProgress| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_ingest_job_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::ingest_jobs::IngestJob) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::ingest_jobs::IngestJob,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::ingest_jobs::IngestJob| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_ingest_progress_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::ingest::IngestProgress) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::ingest::IngestProgress,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::ingest::IngestProgress| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_list_String_Output_list_list_prim_f_32_strict_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(Vec<String>) -> flutter_rust_bridge::DartFnFuture<Vec<Vec<f32>>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: Vec<String>,
+    ) -> Vec<Vec<f32>> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Vec<Vec<f32>>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: Vec<String>| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_reembedding_job_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::reembedding::ReembeddingJob) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::reembedding::ReembeddingJob,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::reembedding::ReembeddingJob| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for MemoryStore {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, f64)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::hybrid_search::HybridSearchEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::progress::ProgressEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::simple_rag::AddDocumentResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_isDuplicate = <bool>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::simple_rag::AddDocumentResult {
+            success: var_success,
+            is_duplicate: var_isDuplicate,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::AddSourceResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_isDuplicate = <bool>::sse_decode(deserializer);
+        let mut var_chunkCount = <i32>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::source_rag::AddSourceResult {
+            source_id: var_sourceId,
+            is_duplicate: var_isDuplicate,
+            chunk_count: var_chunkCount,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audit::AuditIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_severity = <String>::sse_decode(deserializer);
+        let mut var_code = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_sourceId = <Option<i64>>::sse_decode(deserializer);
+        let mut var_chunkId = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::audit::AuditIssue {
+            severity: var_severity,
+            code: var_code,
+            message: var_message,
+            source_id: var_sourceId,
+            chunk_id: var_chunkId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audit::AuditReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_sourcesChecked = <u64>::sse_decode(deserializer);
+        let mut var_chunksChecked = <u64>::sse_decode(deserializer);
+        let mut var_issues = <Vec<crate::api::audit::AuditIssue>>::sse_decode(deserializer);
+        let mut var_issueCount = <u32>::sse_decode(deserializer);
+        let mut var_maxSeverity = <Option<String>>::sse_decode(deserializer);
+        return crate::api::audit::AuditReport {
+            started_at: var_startedAt,
+            duration_ms: var_durationMs,
+            sources_checked: var_sourcesChecked,
+            chunks_checked: var_chunksChecked,
+            issues: var_issues,
+            issue_count: var_issueCount,
+            max_severity: var_maxSeverity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::background_tasks::BackgroundTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_kind =
+            <crate::api::background_tasks::BackgroundTaskKind>::sse_decode(deserializer);
+        let mut var_tenantId = <String>::sse_decode(deserializer);
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_detail = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_submittedAt = <i64>::sse_decode(deserializer);
+        let mut var_startedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_finishedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::background_tasks::BackgroundTask {
+            id: var_id,
+            kind: var_kind,
+            tenant_id: var_tenantId,
+            state: var_state,
+            detail: var_detail,
+            error: var_error,
+            submitted_at: var_submittedAt,
+            started_at: var_startedAt,
+            finished_at: var_finishedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::background_tasks::BackgroundTaskKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::background_tasks::BackgroundTaskKind::IndexRebuild,
+            1 => crate::api::background_tasks::BackgroundTaskKind::BufferMerge,
+            2 => crate::api::background_tasks::BackgroundTaskKind::SnapshotExport,
+            3 => crate::api::background_tasks::BackgroundTaskKind::Reembedding,
+            _ => unreachable!("Invalid variant for BackgroundTaskKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::benchmark::BenchmarkConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_documentBytes = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_syntheticParagraphs = <u32>::sse_decode(deserializer);
+        let mut var_chunkMaxChars = <i32>::sse_decode(deserializer);
+        let mut var_embeddingDim = <u32>::sse_decode(deserializer);
+        let mut var_searchIterations = <u32>::sse_decode(deserializer);
+        let mut var_topK = <u32>::sse_decode(deserializer);
+        return crate::api::benchmark::BenchmarkConfig {
+            document_bytes: var_documentBytes,
+            synthetic_paragraphs: var_syntheticParagraphs,
+            chunk_max_chars: var_chunkMaxChars,
+            embedding_dim: var_embeddingDim,
+            search_iterations: var_searchIterations,
+            top_k: var_topK,
+        };
+    }
+}
+
+impl SseDecode for crate::api::benchmark::BenchmarkReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stages = <Vec<crate::api::benchmark::BenchmarkStage>>::sse_decode(deserializer);
+        let mut var_documentChars = <u32>::sse_decode(deserializer);
+        let mut var_chunkCount = <u32>::sse_decode(deserializer);
+        let mut var_tokenCount = <u32>::sse_decode(deserializer);
+        let mut var_searchMeanMs = <f64>::sse_decode(deserializer);
+        let mut var_searchP95Ms = <f64>::sse_decode(deserializer);
+        let mut var_peakRssKb = <Option<u64>>::sse_decode(deserializer);
+        let mut var_totalMs = <f64>::sse_decode(deserializer);
+        return crate::api::benchmark::BenchmarkReport {
+            stages: var_stages,
+            document_chars: var_documentChars,
+            chunk_count: var_chunkCount,
+            token_count: var_tokenCount,
+            search_mean_ms: var_searchMeanMs,
+            search_p95_ms: var_searchP95Ms,
+            peak_rss_kb: var_peakRssKb,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::benchmark::BenchmarkStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_durationMs = <f64>::sse_decode(deserializer);
+        let mut var_skipped = <bool>::sse_decode(deserializer);
+        let mut var_rssKb = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::benchmark::BenchmarkStage {
+            name: var_name,
+            duration_ms: var_durationMs,
+            skipped: var_skipped,
+            rss_kb: var_rssKb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::bm25_search::Bm25Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_k1 = <f64>::sse_decode(deserializer);
+        let mut var_b = <f64>::sse_decode(deserializer);
+        let mut var_variant = <crate::api::bm25_search::Bm25Variant>::sse_decode(deserializer);
+        return crate::api::bm25_search::Bm25Config {
+            k1: var_k1,
+            b: var_b,
+            variant: var_variant,
+        };
+    }
+}
+
+impl SseDecode for crate::api::fuzzy_terms::Bm25FuzzyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxEdits = <u32>::sse_decode(deserializer);
+        let mut var_minTermChars = <u32>::sse_decode(deserializer);
+        let mut var_maxExpansions = <u32>::sse_decode(deserializer);
+        return crate::api::fuzzy_terms::Bm25FuzzyConfig {
+            max_edits: var_maxEdits,
+            min_term_chars: var_minTermChars,
+            max_expansions: var_maxExpansions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::bm25_search::Bm25IndexOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tokenization =
+            <crate::api::bm25_search::Bm25Tokenization>::sse_decode(deserializer);
+        let mut var_stemmer = <Option<String>>::sse_decode(deserializer);
+        let mut var_stripKoreanParticles = <bool>::sse_decode(deserializer);
+        let mut var_removeStopwords = <bool>::sse_decode(deserializer);
+        return crate::api::bm25_search::Bm25IndexOptions {
+            tokenization: var_tokenization,
+            stemmer: var_stemmer,
+            strip_korean_particles: var_stripKoreanParticles,
+            remove_stopwords: var_removeStopwords,
+        };
+    }
+}
+
+impl SseDecode for crate::api::bm25_search::Bm25SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_docId = <i64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::bm25_search::Bm25SearchResult {
+            doc_id: var_docId,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::bm25_search::Bm25Tokenization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::bm25_search::Bm25Tokenization::Words,
+            1 => crate::api::bm25_search::Bm25Tokenization::Whitespace,
+            2 => crate::api::bm25_search::Bm25Tokenization::CjkBigrams,
+            3 => crate::api::bm25_search::Bm25Tokenization::CjkTrigrams,
+            4 => crate::api::bm25_search::Bm25Tokenization::Mixed,
+            _ => unreachable!("Invalid variant for Bm25Tokenization: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::bm25_search::Bm25Variant {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::bm25_search::Bm25Variant::Bm25,
+            1 => crate::api::bm25_search::Bm25Variant::Bm25Plus,
+            2 => crate::api::bm25_search::Bm25Variant::Bm25L,
+            _ => unreachable!("Invalid variant for Bm25Variant: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::incremental_index::BufferStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bufferSize = <usize>::sse_decode(deserializer);
+        let mut var_threshold = <usize>::sse_decode(deserializer);
+        let mut var_hnswLoaded = <bool>::sse_decode(deserializer);
+        return crate::api::incremental_index::BufferStats {
+            buffer_size: var_bufferSize,
+            threshold: var_threshold,
+            hnsw_loaded: var_hnswLoaded,
+        };
+    }
+}
+
+impl SseDecode for crate::api::incremental_index::BufferTable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::incremental_index::BufferTable::Chunks,
+            1 => crate::api::incremental_index::BufferTable::Docs,
+            _ => unreachable!("Invalid variant for BufferTable: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::ChunkData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
+        let mut var_startPos = <i32>::sse_decode(deserializer);
+        let mut var_endPos = <i32>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_embedding = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_batchId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::source_rag::ChunkData {
+            content: var_content,
+            chunk_index: var_chunkIndex,
+            start_pos: var_startPos,
+            end_pos: var_endPos,
+            chunk_type: var_chunkType,
+            embedding: var_embedding,
+            batch_id: var_batchId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::ChunkForReembedding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkId = <i64>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        return crate::api::source_rag::ChunkForReembedding {
+            chunk_id: var_chunkId,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_index_store::ChunkIndexSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::chunk_index_store::ChunkIndexSource::Memory,
+            1 => crate::api::chunk_index_store::ChunkIndexSource::Disk,
+            2 => crate::api::chunk_index_store::ChunkIndexSource::Rebuilt,
+            _ => unreachable!("Invalid variant for ChunkIndexSource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::ChunkMeta {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
+        let mut var_startPos = <i32>::sse_decode(deserializer);
+        let mut var_endPos = <i32>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_batchId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::source_rag::ChunkMeta {
+            content: var_content,
+            chunk_index: var_chunkIndex,
+            start_pos: var_startPos,
+            end_pos: var_endPos,
+            chunk_type: var_chunkType,
+            batch_id: var_batchId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::ChunkSearchPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_results =
+            <Vec<crate::api::source_rag::ChunkSearchResult>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        return crate::api::source_rag::ChunkSearchPage {
+            results: var_results,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::ChunkSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkId = <i64>::sse_decode(deserializer);
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_headerPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        return crate::api::source_rag::ChunkSearchResult {
+            chunk_id: var_chunkId,
+            source_id: var_sourceId,
+            chunk_index: var_chunkIndex,
+            content: var_content,
+            chunk_type: var_chunkType,
+            header_path: var_headerPath,
+            similarity: var_similarity,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::ChunkStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkCount = <u32>::sse_decode(deserializer);
+        let mut var_minSize = <u32>::sse_decode(deserializer);
+        let mut var_maxSize = <u32>::sse_decode(deserializer);
+        let mut var_avgSize = <f64>::sse_decode(deserializer);
+        let mut var_medianSize = <f64>::sse_decode(deserializer);
+        let mut var_typeHistogram =
+            <Vec<crate::api::corpus_report::CorpusCount>>::sse_decode(deserializer);
+        let mut var_oversizeCount = <u32>::sse_decode(deserializer);
+        return crate::api::semantic_chunker::ChunkStats {
+            chunk_count: var_chunkCount,
+            min_size: var_minSize,
+            max_size: var_maxSize,
+            avg_size: var_avgSize,
+            median_size: var_medianSize,
+            type_histogram: var_typeHistogram,
+            oversize_count: var_oversizeCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::ChunkType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::semantic_chunker::ChunkType::Definition,
+            1 => crate::api::semantic_chunker::ChunkType::Example,
+            2 => crate::api::semantic_chunker::ChunkType::List,
+            3 => crate::api::semantic_chunker::ChunkType::Procedure,
+            4 => crate::api::semantic_chunker::ChunkType::Comparison,
+            5 => crate::api::semantic_chunker::ChunkType::General,
+            _ => unreachable!("Invalid variant for ChunkType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::ChunkingConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxChars = <i32>::sse_decode(deserializer);
+        let mut var_minChars = <i32>::sse_decode(deserializer);
+        let mut var_overlap = <i32>::sse_decode(deserializer);
+        let mut var_strategy =
+            <crate::api::semantic_chunker::ChunkingStrategy>::sse_decode(deserializer);
+        let mut var_preserveTables = <bool>::sse_decode(deserializer);
+        let mut var_preserveCode = <bool>::sse_decode(deserializer);
+        let mut var_sentenceTerminators = <Vec<String>>::sse_decode(deserializer);
+        let mut var_sectionSummarySentences = <u32>::sse_decode(deserializer);
+        let mut var_linearizeTables = <bool>::sse_decode(deserializer);
+        let mut var_keepListIntro = <bool>::sse_decode(deserializer);
+        return crate::api::semantic_chunker::ChunkingConfig {
+            max_chars: var_maxChars,
+            min_chars: var_minChars,
+            overlap: var_overlap,
+            strategy: var_strategy,
+            preserve_tables: var_preserveTables,
+            preserve_code: var_preserveCode,
+            sentence_terminators: var_sentenceTerminators,
+            section_summary_sentences: var_sectionSummarySentences,
+            linearize_tables: var_linearizeTables,
+            keep_list_intro: var_keepListIntro,
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::ChunkingStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::semantic_chunker::ChunkingStrategy::Recursive,
+            1 => crate::api::semantic_chunker::ChunkingStrategy::Markdown,
+            _ => unreachable!("Invalid variant for ChunkingStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::classification_rules::ClassificationRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_patterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_minMatches = <u32>::sse_decode(deserializer);
+        return crate::api::classification_rules::ClassificationRule {
+            label: var_label,
+            language: var_language,
+            patterns: var_patterns,
+            min_matches: var_minMatches,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::CompareSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subjectIndex = <u32>::sse_decode(deserializer);
+        let mut var_result =
+            <crate::api::hybrid_search::HybridSearchResult>::sse_decode(deserializer);
+        return crate::api::hybrid_search::CompareSearchResult {
+            subject_index: var_subjectIndex,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::api::compression_utils::CompressedText {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_originalChars = <i32>::sse_decode(deserializer);
+        let mut var_compressedChars = <i32>::sse_decode(deserializer);
+        let mut var_ratio = <f64>::sse_decode(deserializer);
+        let mut var_sentencesRemoved = <i32>::sse_decode(deserializer);
+        let mut var_charsSavedStopwords = <i32>::sse_decode(deserializer);
+        let mut var_charsSavedTruncation = <i32>::sse_decode(deserializer);
+        return crate::api::compression_utils::CompressedText {
+            text: var_text,
+            original_chars: var_originalChars,
+            compressed_chars: var_compressedChars,
+            ratio: var_ratio,
+            sentences_removed: var_sentencesRemoved,
+            chars_saved_stopwords: var_charsSavedStopwords,
+            chars_saved_truncation: var_charsSavedTruncation,
+        };
+    }
+}
+
+impl SseDecode for crate::api::compression_utils::CompressionOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_removeStopwords = <bool>::sse_decode(deserializer);
+        let mut var_removeDuplicates = <bool>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_level = <i32>::sse_decode(deserializer);
+        return crate::api::compression_utils::CompressionOptions {
+            remove_stopwords: var_removeStopwords,
+            remove_duplicates: var_removeDuplicates,
+            language: var_language,
+            level: var_level,
+        };
+    }
+}
+
+impl SseDecode for crate::api::corpus_report::CorpusCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::corpus_report::CorpusCount {
+            key: var_key,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::corpus_report::CorpusReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceCount = <u64>::sse_decode(deserializer);
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_totalChars = <u64>::sse_decode(deserializer);
+        let mut var_vocabularySize = <u64>::sse_decode(deserializer);
+        let mut var_sources =
+            <Vec<crate::api::corpus_report::SourceCorpusStats>>::sse_decode(deserializer);
+        let mut var_chunkLengthHistogram =
+            <Vec<crate::api::corpus_report::LengthBucket>>::sse_decode(deserializer);
+        let mut var_chunkTypes =
+            <Vec<crate::api::corpus_report::CorpusCount>>::sse_decode(deserializer);
+        let mut var_languages =
+            <Vec<crate::api::corpus_report::CorpusCount>>::sse_decode(deserializer);
+        let mut var_index = <crate::api::corpus_report::IndexFreshness>::sse_decode(deserializer);
+        return crate::api::corpus_report::CorpusReport {
+            source_count: var_sourceCount,
+            chunk_count: var_chunkCount,
+            total_chars: var_totalChars,
+            vocabulary_size: var_vocabularySize,
+            sources: var_sources,
+            chunk_length_histogram: var_chunkLengthHistogram,
+            chunk_types: var_chunkTypes,
+            languages: var_languages,
+            index: var_index,
+        };
+    }
+}
+
+impl SseDecode for crate::api::db_retry::DbRetryConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxAttempts = <u32>::sse_decode(deserializer);
+        let mut var_baseDelayMs = <u64>::sse_decode(deserializer);
+        let mut var_maxDelayMs = <u64>::sse_decode(deserializer);
+        return crate::api::db_retry::DbRetryConfig {
+            max_attempts: var_maxAttempts,
+            base_delay_ms: var_baseDelayMs,
+            max_delay_ms: var_maxDelayMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_dedup::DedupeConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nearDuplicateThreshold = <f64>::sse_decode(deserializer);
+        let mut var_shingleSize = <u32>::sse_decode(deserializer);
+        let mut var_exactOnly = <bool>::sse_decode(deserializer);
+        return crate::api::chunk_dedup::DedupeConfig {
+            near_duplicate_threshold: var_nearDuplicateThreshold,
+            shingle_size: var_shingleSize,
+            exact_only: var_exactOnly,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_dedup::DedupeReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kept = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_duplicates =
+            <Vec<crate::api::chunk_dedup::DuplicateChunk>>::sse_decode(deserializer);
+        return crate::api::chunk_dedup::DedupeReport {
+            kept: var_kept,
+            duplicates: var_duplicates,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::DistanceMetric {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::hnsw_index::DistanceMetric::Cosine,
+            1 => crate::api::hnsw_index::DistanceMetric::DotProduct,
+            2 => crate::api::hnsw_index::DistanceMetric::L2,
+            _ => unreachable!("Invalid variant for DistanceMetric: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_dedup::DuplicateChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_duplicateOf = <u32>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        return crate::api::chunk_dedup::DuplicateChunk {
+            index: var_index,
+            duplicate_of: var_duplicateOf,
+            similarity: var_similarity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::EmbeddingBatchProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::source_rag::EmbeddingBatchProgress {
+            done: var_done,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::EmbeddingPoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_embedding = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_norm = <f32>::sse_decode(deserializer);
+        return crate::api::hnsw_index::EmbeddingPoint {
+            id: var_id,
+            embedding: var_embedding,
+            norm: var_norm,
+        };
+    }
+}
+
+impl SseDecode for crate::api::embedding_storage::EmbeddingStorageStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_f32Chunks = <i64>::sse_decode(deserializer);
+        let mut var_quantizedChunks = <i64>::sse_decode(deserializer);
+        let mut var_embeddingBytes = <i64>::sse_decode(deserializer);
+        return crate::api::embedding_storage::EmbeddingStorageStats {
+            f32_chunks: var_f32Chunks,
+            quantized_chunks: var_quantizedChunks,
+            embedding_bytes: var_embeddingBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::engine_info::EngineInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_crateVersion = <String>::sse_decode(deserializer);
+        let mut var_schemaVersion = <u32>::sse_decode(deserializer);
+        let mut var_databaseSchemaVersion = <Option<u32>>::sse_decode(deserializer);
+        let mut var_hnswFormatVersion = <u32>::sse_decode(deserializer);
+        let mut var_bm25FormatVersion = <u32>::sse_decode(deserializer);
+        let mut var_snapshotFormatVersion = <u32>::sse_decode(deserializer);
+        let mut var_tokenizerName = <Option<String>>::sse_decode(deserializer);
+        let mut var_embeddingModelName = <Option<String>>::sse_decode(deserializer);
+        let mut var_embeddingDimension = <Option<u32>>::sse_decode(deserializer);
+        let mut var_activeTenant = <String>::sse_decode(deserializer);
+        let mut var_activeFeatures = <Vec<String>>::sse_decode(deserializer);
+        let mut var_buildFeatures = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::engine_info::EngineInfo {
+            crate_version: var_crateVersion,
+            schema_version: var_schemaVersion,
+            database_schema_version: var_databaseSchemaVersion,
+            hnsw_format_version: var_hnswFormatVersion,
+            bm25_format_version: var_bm25FormatVersion,
+            snapshot_format_version: var_snapshotFormatVersion,
+            tokenizer_name: var_tokenizerName,
+            embedding_model_name: var_embeddingModelName,
+            embedding_dimension: var_embeddingDimension,
+            active_tenant: var_activeTenant,
+            active_features: var_activeFeatures,
+            build_features: var_buildFeatures,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metrics::EngineMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_searchesExecuted = <u64>::sse_decode(deserializer);
+        let mut var_cacheHits = <u64>::sse_decode(deserializer);
+        let mut var_cacheMisses = <u64>::sse_decode(deserializer);
+        let mut var_indexRebuilds = <u64>::sse_decode(deserializer);
+        let mut var_dbErrors = <u64>::sse_decode(deserializer);
+        let mut var_panics = <u64>::sse_decode(deserializer);
+        let mut var_avgSearchLatencyMs = <f64>::sse_decode(deserializer);
+        let mut var_searchLatencyHistogram =
+            <Vec<crate::api::metrics::LatencyBucket>>::sse_decode(deserializer);
+        return crate::api::metrics::EngineMetrics {
+            searches_executed: var_searchesExecuted,
+            cache_hits: var_cacheHits,
+            cache_misses: var_cacheMisses,
+            index_rebuilds: var_indexRebuilds,
+            db_errors: var_dbErrors,
+            panics: var_panics,
+            avg_search_latency_ms: var_avgSearchLatencyMs,
+            search_latency_histogram: var_searchLatencyHistogram,
+        };
+    }
+}
+
+impl SseDecode for crate::api::entity_extraction::EntityFacet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entityType = <String>::sse_decode(deserializer);
+        let mut var_normalized = <String>::sse_decode(deserializer);
+        let mut var_chunkCount = <u32>::sse_decode(deserializer);
+        return crate::api::entity_extraction::EntityFacet {
+            entity_type: var_entityType,
+            normalized: var_normalized,
+            chunk_count: var_chunkCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::related_chunks::ExpandedSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_result =
+            <crate::api::hybrid_search::HybridSearchResult>::sse_decode(deserializer);
+        let mut var_link = <String>::sse_decode(deserializer);
+        let mut var_expandedFrom = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::related_chunks::ExpandedSearchResult {
+            result: var_result,
+            link: var_link,
+            expanded_from: var_expandedFrom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::related_chunks::ExpansionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxRelatedPerHit = <u32>::sse_decode(deserializer);
+        let mut var_scoreDecay = <f64>::sse_decode(deserializer);
+        let mut var_followAdjacent = <bool>::sse_decode(deserializer);
+        let mut var_followSharedEntities = <bool>::sse_decode(deserializer);
+        let mut var_followSameBatch = <bool>::sse_decode(deserializer);
+        return crate::api::related_chunks::ExpansionConfig {
+            max_related_per_hit: var_maxRelatedPerHit,
+            score_decay: var_scoreDecay,
+            follow_adjacent: var_followAdjacent,
+            follow_shared_entities: var_followSharedEntities,
+            follow_same_batch: var_followSameBatch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::entity_extraction::ExtractedEntity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entityType = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_normalized = <String>::sse_decode(deserializer);
+        return crate::api::entity_extraction::ExtractedEntity {
+            entity_type: var_entityType,
+            text: var_text,
+            normalized: var_normalized,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::FusionStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::hybrid_search::FusionStrategy::Rrf;
+            }
+            1 => {
+                let mut var_normalization =
+                    <crate::api::hybrid_search::ScoreNormalization>::sse_decode(deserializer);
+                return crate::api::hybrid_search::FusionStrategy::WeightedScore {
+                    normalization: var_normalization,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::entity_extraction::GazetteerEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_term = <String>::sse_decode(deserializer);
+        let mut var_entityType = <String>::sse_decode(deserializer);
+        return crate::api::entity_extraction::GazetteerEntry {
+            term: var_term,
+            entity_type: var_entityType,
+        };
+    }
+}
+
+impl SseDecode for crate::api::knowledge_graph::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_relation = <String>::sse_decode(deserializer);
+        let mut var_object = <String>::sse_decode(deserializer);
+        let mut var_chunkId = <i64>::sse_decode(deserializer);
+        let mut var_hop = <u32>::sse_decode(deserializer);
+        return crate::api::knowledge_graph::GraphEdge {
+            subject: var_subject,
+            relation: var_relation,
+            object: var_object,
+            chunk_id: var_chunkId,
+            hop: var_hop,
+        };
+    }
+}
+
+impl SseDecode for crate::api::knowledge_graph::GraphNeighborhood {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entity = <String>::sse_decode(deserializer);
+        let mut var_edges = <Vec<crate::api::knowledge_graph::GraphEdge>>::sse_decode(deserializer);
+        let mut var_chunkIds = <Vec<i64>>::sse_decode(deserializer);
+        return crate::api::knowledge_graph::GraphNeighborhood {
+            entity: var_entity,
+            edges: var_edges,
+            chunk_ids: var_chunkIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::snippets::HighlightSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::api::snippets::HighlightSpan {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::HnswBuildConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_m = <u32>::sse_decode(deserializer);
+        let mut var_maxLayer = <u32>::sse_decode(deserializer);
+        let mut var_efConstruction = <u32>::sse_decode(deserializer);
+        return crate::api::hnsw_index::HnswBuildConfig {
+            m: var_m,
+            max_layer: var_maxLayer,
+            ef_construction: var_efConstruction,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::HnswConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_build =
+            <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_decode(deserializer);
+        let mut var_efSearch = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::hnsw_index::HnswConfig {
+            build: var_build,
+            ef_search: var_efSearch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::HnswIndexStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pointCount = <u64>::sse_decode(deserializer);
+        let mut var_dimension = <u32>::sse_decode(deserializer);
+        let mut var_quantization = <crate::api::hnsw_index::Quantization>::sse_decode(deserializer);
+        let mut var_metric = <crate::api::hnsw_index::DistanceMetric>::sse_decode(deserializer);
+        let mut var_build = <crate::api::hnsw_index::HnswBuildConfig>::sse_decode(deserializer);
+        let mut var_m0 = <u32>::sse_decode(deserializer);
+        let mut var_customBuild = <bool>::sse_decode(deserializer);
+        let mut var_efSearch = <Option<u32>>::sse_decode(deserializer);
+        let mut var_deletedPoints = <u64>::sse_decode(deserializer);
+        return crate::api::hnsw_index::HnswIndexStats {
+            point_count: var_pointCount,
+            dimension: var_dimension,
+            quantization: var_quantization,
+            metric: var_metric,
+            build: var_build,
+            m0: var_m0,
+            custom_build: var_customBuild,
+            ef_search: var_efSearch,
+            deleted_points: var_deletedPoints,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::HnswSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_distance = <f32>::sse_decode(deserializer);
+        return crate::api::hnsw_index::HnswSearchResult {
+            id: var_id,
+            distance: var_distance,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::HybridSearchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <Vec<crate::api::hybrid_search::RankedHit>>::sse_decode(deserializer);
+                return crate::api::hybrid_search::HybridSearchEvent::Ranking(var_field0);
+            }
+            1 => {
+                let mut var_field0 =
+                    <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_decode(deserializer);
+                return crate::api::hybrid_search::HybridSearchEvent::Results(var_field0);
+            }
+            2 => {
+                return crate::api::hybrid_search::HybridSearchEvent::Done;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::HybridSearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_efSearch = <Option<u32>>::sse_decode(deserializer);
+        let mut var_diversityLambda = <Option<f64>>::sse_decode(deserializer);
+        let mut var_collection = <Option<String>>::sse_decode(deserializer);
+        let mut var_groupBySource = <Option<u32>>::sse_decode(deserializer);
+        let mut var_withDiagnostics = <bool>::sse_decode(deserializer);
+        let mut var_pageOffset = <Option<u32>>::sse_decode(deserializer);
+        let mut var_cursor = <Option<String>>::sse_decode(deserializer);
+        return crate::api::hybrid_search::HybridSearchOptions {
+            ef_search: var_efSearch,
+            diversity_lambda: var_diversityLambda,
+            collection: var_collection,
+            group_by_source: var_groupBySource,
+            with_diagnostics: var_withDiagnostics,
+            page_offset: var_pageOffset,
+            cursor: var_cursor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::HybridSearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_results =
+            <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_decode(deserializer);
+        let mut var_groups =
+            <Option<Vec<crate::api::source_grouping::SourceGroup>>>::sse_decode(deserializer);
+        let mut var_diagnostics =
+            <Option<crate::api::search_trace::SearchDiagnostics>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        return crate::api::hybrid_search::HybridSearchResponse {
+            results: var_results,
+            groups: var_groups,
+            diagnostics: var_diagnostics,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::HybridSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_docId = <i64>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_vectorRank = <u32>::sse_decode(deserializer);
+        let mut var_bm25Rank = <u32>::sse_decode(deserializer);
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkIndex = <u32>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_headerPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_matchedTerms = <Vec<String>>::sse_decode(deserializer);
+        let mut var_snippet =
+            <Option<crate::api::snippets::SearchSnippet>>::sse_decode(deserializer);
+        return crate::api::hybrid_search::HybridSearchResult {
+            doc_id: var_docId,
+            content: var_content,
+            score: var_score,
+            vector_rank: var_vectorRank,
+            bm25_rank: var_bm25Rank,
+            source_id: var_sourceId,
+            metadata: var_metadata,
+            chunk_index: var_chunkIndex,
+            chunk_type: var_chunkType,
+            header_path: var_headerPath,
+            matched_terms: var_matchedTerms,
+            snippet: var_snippet,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::incremental_index::IncrementalConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_autoMerge = <bool>::sse_decode(deserializer);
+        return crate::api::incremental_index::IncrementalConfig {
+            auto_merge: var_autoMerge,
+        };
+    }
+}
+
+impl SseDecode for crate::api::incremental_index::IncrementalSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_docId = <i64>::sse_decode(deserializer);
+        let mut var_distance = <f32>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        return crate::api::incremental_index::IncrementalSearchResult {
+            doc_id: var_docId,
+            distance: var_distance,
+            source: var_source,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::IndexConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quantization = <crate::api::hnsw_index::Quantization>::sse_decode(deserializer);
+        let mut var_metric = <crate::api::hnsw_index::DistanceMetric>::sse_decode(deserializer);
+        return crate::api::hnsw_index::IndexConfig {
+            quantization: var_quantization,
+            metric: var_metric,
+        };
+    }
+}
+
+impl SseDecode for crate::api::index_freshness::IndexConsistency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_hnsw = <crate::api::index_freshness::IndexDivergence>::sse_decode(deserializer);
+        let mut var_bm25 = <crate::api::index_freshness::IndexDivergence>::sse_decode(deserializer);
+        let mut var_consistent = <bool>::sse_decode(deserializer);
+        let mut var_repaired = <bool>::sse_decode(deserializer);
+        return crate::api::index_freshness::IndexConsistency {
+            chunk_count: var_chunkCount,
+            hnsw: var_hnsw,
+            bm25: var_bm25,
+            consistent: var_consistent,
+            repaired: var_repaired,
+        };
+    }
+}
+
+impl SseDecode for crate::api::index_freshness::IndexDivergence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_loaded = <bool>::sse_decode(deserializer);
+        let mut var_indexedCount = <u64>::sse_decode(deserializer);
+        let mut var_missingIds = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_extraIds = <Vec<i64>>::sse_decode(deserializer);
+        return crate::api::index_freshness::IndexDivergence {
+            loaded: var_loaded,
+            indexed_count: var_indexedCount,
+            missing_ids: var_missingIds,
+            extra_ids: var_extraIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::benchmark::IndexEvaluation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sampleSize = <u32>::sse_decode(deserializer);
+        let mut var_topK = <u32>::sse_decode(deserializer);
+        let mut var_corpusSize = <u64>::sse_decode(deserializer);
+        let mut var_recallAtK = <f64>::sse_decode(deserializer);
+        let mut var_minRecall = <f64>::sse_decode(deserializer);
+        let mut var_latencyP50Ms = <f64>::sse_decode(deserializer);
+        let mut var_latencyP95Ms = <f64>::sse_decode(deserializer);
+        let mut var_latencyP99Ms = <f64>::sse_decode(deserializer);
+        let mut var_exactMeanMs = <f64>::sse_decode(deserializer);
+        return crate::api::benchmark::IndexEvaluation {
+            sample_size: var_sampleSize,
+            top_k: var_topK,
+            corpus_size: var_corpusSize,
+            recall_at_k: var_recallAtK,
+            min_recall: var_minRecall,
+            latency_p50_ms: var_latencyP50Ms,
+            latency_p95_ms: var_latencyP95Ms,
+            latency_p99_ms: var_latencyP99Ms,
+            exact_mean_ms: var_exactMeanMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::corpus_report::IndexFreshness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dbChunkCount = <u64>::sse_decode(deserializer);
+        let mut var_hnswPointCount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bm25DocumentCount = <u64>::sse_decode(deserializer);
+        let mut var_hnswUpToDate = <bool>::sse_decode(deserializer);
+        let mut var_bm25UpToDate = <bool>::sse_decode(deserializer);
+        let mut var_lastSourceAddedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::corpus_report::IndexFreshness {
+            db_chunk_count: var_dbChunkCount,
+            hnsw_point_count: var_hnswPointCount,
+            bm25_document_count: var_bm25DocumentCount,
+            hnsw_up_to_date: var_hnswUpToDate,
+            bm25_up_to_date: var_bm25UpToDate,
+            last_source_added_at: var_lastSourceAddedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::index_freshness::IndexStaleness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hnswStale = <bool>::sse_decode(deserializer);
+        let mut var_bm25Stale = <bool>::sse_decode(deserializer);
+        return crate::api::index_freshness::IndexStaleness {
+            hnsw_stale: var_hnswStale,
+            bm25_stale: var_bm25Stale,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ingest::IngestInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::ingest::IngestInput::Text(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::ingest::IngestInput::Bytes(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::ingest_jobs::IngestJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_stage = <Option<String>>::sse_decode(deserializer);
+        let mut var_progressDone = <u32>::sse_decode(deserializer);
+        let mut var_progressTotal = <u32>::sse_decode(deserializer);
+        let mut var_sourceId = <Option<i64>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::ingest_jobs::IngestJob {
+            id: var_id,
+            file_path: var_filePath,
+            name: var_name,
+            state: var_state,
+            stage: var_stage,
+            progress_done: var_progressDone,
+            progress_total: var_progressTotal,
+            source_id: var_sourceId,
+            error: var_error,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ingest::IngestOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        let mut var_strategy =
+            <Option<crate::api::semantic_chunker::ChunkingStrategy>>::sse_decode(deserializer);
+        let mut var_maxChars = <i32>::sse_decode(deserializer);
+        let mut var_overlapChars = <i32>::sse_decode(deserializer);
+        let mut var_rebuildIndexes = <bool>::sse_decode(deserializer);
+        let mut var_dedupeChunks = <bool>::sse_decode(deserializer);
+        return crate::api::ingest::IngestOptions {
+            name: var_name,
+            metadata: var_metadata,
+            strategy: var_strategy,
+            max_chars: var_maxChars,
+            overlap_chars: var_overlapChars,
+            rebuild_indexes: var_rebuildIndexes,
+            dedupe_chunks: var_dedupeChunks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ingest::IngestProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <String>::sse_decode(deserializer);
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_sourceId = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::ingest::IngestProgress {
+            stage: var_stage,
+            done: var_done,
+            total: var_total,
+            source_id: var_sourceId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ingest::IngestReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_isDuplicate = <bool>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_chunkCount = <u32>::sse_decode(deserializer);
+        let mut var_skippedChunks = <u32>::sse_decode(deserializer);
+        let mut var_textChars = <u64>::sse_decode(deserializer);
+        let mut var_elapsedMs = <u64>::sse_decode(deserializer);
+        return crate::api::ingest::IngestReport {
+            source_id: var_sourceId,
+            is_duplicate: var_isDuplicate,
+            status: var_status,
+            chunk_count: var_chunkCount,
+            skipped_chunks: var_skippedChunks,
+            text_chars: var_textChars,
+            elapsed_ms: var_elapsedMs,
+        };
+    }
+}
+
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::fts_keyword::KeywordBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::fts_keyword::KeywordBackend::InMemory,
+            1 => crate::api::fts_keyword::KeywordBackend::Fts5,
+            _ => unreachable!("Invalid variant for KeywordBackend: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::knowledge_graph::KnowledgeTriple {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_relation = <String>::sse_decode(deserializer);
+        let mut var_object = <String>::sse_decode(deserializer);
+        return crate::api::knowledge_graph::KnowledgeTriple {
+            subject: var_subject,
+            relation: var_relation,
+            object: var_object,
+        };
+    }
+}
+
+impl SseDecode for crate::api::analyzers::LanguageAnalyzer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_stopwords = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::analyzers::LanguageAnalyzer {
+            language: var_language,
+            stopwords: var_stopwords,
+        };
+    }
+}
+
+impl SseDecode for crate::api::retrieval_state::LastRetrieval {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_query = <Option<String>>::sse_decode(deserializer);
+        let mut var_citations =
+            <Vec<crate::api::retrieval_state::RetrievalCitation>>::sse_decode(deserializer);
+        return crate::api::retrieval_state::LastRetrieval {
+            query: var_query,
+            citations: var_citations,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metrics::LatencyBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_leMs = <Option<f64>>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::metrics::LatencyBucket {
+            le_ms: var_leMs,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::corpus_report::LengthBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_leChars = <Option<u32>>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::corpus_report::LengthBucket {
+            le_chars: var_leChars,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::audit::AuditIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audit::AuditIssue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::background_tasks::BackgroundTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::background_tasks::BackgroundTask>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::benchmark::BenchmarkStage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::benchmark::BenchmarkStage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::bm25_search::Bm25SearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::bm25_search::Bm25SearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_rag::ChunkData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_rag::ChunkData>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_rag::ChunkForReembedding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_rag::ChunkForReembedding>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_rag::ChunkMeta> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_rag::ChunkMeta>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_rag::ChunkSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_rag::ChunkSearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::classification_rules::ClassificationRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::classification_rules::ClassificationRule>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::hybrid_search::CompareSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::hybrid_search::CompareSearchResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::corpus_report::CorpusCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::corpus_report::CorpusCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chunk_dedup::DuplicateChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chunk_dedup::DuplicateChunk>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::entity_extraction::EntityFacet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::entity_extraction::EntityFacet>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::related_chunks::ExpandedSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::related_chunks::ExpandedSearchResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::entity_extraction::ExtractedEntity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::entity_extraction::ExtractedEntity>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::entity_extraction::GazetteerEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::entity_extraction::GazetteerEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::knowledge_graph::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::knowledge_graph::GraphEdge>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::snippets::HighlightSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::snippets::HighlightSpan>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::hnsw_index::HnswSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::hnsw_index::HnswSearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::hybrid_search::HybridSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::hybrid_search::HybridSearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::incremental_index::IncrementalSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::incremental_index::IncrementalSearchResult>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ingest_jobs::IngestJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ingest_jobs::IngestJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::knowledge_graph::KnowledgeTriple> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::knowledge_graph::KnowledgeTriple>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::analyzers::LanguageAnalyzer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::analyzers::LanguageAnalyzer>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::metrics::LatencyBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metrics::LatencyBucket>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::corpus_report::LengthBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::corpus_report::LengthBucket>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<f32>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_memory::MemoryMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_memory::MemoryMessage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_memory::MemorySearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_memory::MemorySearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::named_bm25::NamedBm25IndexInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::named_bm25::NamedBm25IndexInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::named_index::NamedIndexInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::named_index::NamedIndexInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::panic_report::PanicEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::panic_report::PanicEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query_keywords::QueryKeyword> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query_keywords::QueryKeyword>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query_history::QuerySuggestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query_history::QuerySuggestion>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::hybrid_search::RankedHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::hybrid_search::RankedHit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(i64, Vec<f32>)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(i64, Vec<f32>)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(i64, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(i64, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, f64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::retrieval_state::RetrievalCitation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::retrieval_state::RetrievalCitation>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_trace::SearchSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_trace::SearchSpan>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::semantic_chunker::SemanticChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::semantic_chunker::SemanticChunk>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::snapshot::SnapshotFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::snapshot::SnapshotFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::corpus_report::SourceCorpusStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::corpus_report::SourceCorpusStats>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_rag::SourceEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_rag::SourceEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::source_grouping::SourceGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::source_grouping::SourceGroup>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chunk_store::StoreSearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chunk_store::StoreSearchHit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::semantic_chunker::StructuredChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::semantic_chunker::StructuredChunk>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::synonyms::SynonymEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::synonyms::SynonymEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::maintenance::MaintenanceConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requireCharging = <bool>::sse_decode(deserializer);
+        let mut var_minIntervalSecs = <u32>::sse_decode(deserializer);
+        return crate::api::maintenance::MaintenanceConfig {
+            require_charging: var_requireCharging,
+            min_interval_secs: var_minIntervalSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::maintenance::MaintenanceReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_bufferMerged = <bool>::sse_decode(deserializer);
+        let mut var_hnswCompacted = <bool>::sse_decode(deserializer);
+        let mut var_bm25Pruned = <u32>::sse_decode(deserializer);
+        let mut var_walPagesCheckpointed = <i64>::sse_decode(deserializer);
+        let mut var_interrupted = <bool>::sse_decode(deserializer);
+        return crate::api::maintenance::MaintenanceReport {
+            started_at: var_startedAt,
+            duration_ms: var_durationMs,
+            buffer_merged: var_bufferMerged,
+            hnsw_compacted: var_hnswCompacted,
+            bm25_pruned: var_bm25Pruned,
+            wal_pages_checkpointed: var_walPagesCheckpointed,
+            interrupted: var_interrupted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_memory::MemoryMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_role = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::chat_memory::MemoryMessage {
+            id: var_id,
+            conversation_id: var_conversationId,
+            role: var_role,
+            content: var_content,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::memory_budget::MemoryProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_budgetMb = <Option<u32>>::sse_decode(deserializer);
+        let mut var_lowMemory = <bool>::sse_decode(deserializer);
+        let mut var_sqliteCacheKb = <u32>::sse_decode(deserializer);
+        let mut var_sqliteMmapMb = <u32>::sse_decode(deserializer);
+        let mut var_quantizeIndex = <bool>::sse_decode(deserializer);
+        let mut var_candidateMultiplier = <u32>::sse_decode(deserializer);
+        let mut var_filteredCandidateMultiplier = <u32>::sse_decode(deserializer);
+        let mut var_scanPageRows = <u32>::sse_decode(deserializer);
+        return crate::api::memory_budget::MemoryProfile {
+            budget_mb: var_budgetMb,
+            low_memory: var_lowMemory,
+            sqlite_cache_kb: var_sqliteCacheKb,
+            sqlite_mmap_mb: var_sqliteMmapMb,
+            quantize_index: var_quantizeIndex,
+            candidate_multiplier: var_candidateMultiplier,
+            filtered_candidate_multiplier: var_filteredCandidateMultiplier,
+            scan_page_rows: var_scanPageRows,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_memory::MemorySearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_message = <crate::api::chat_memory::MemoryMessage>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        return crate::api::chat_memory::MemorySearchResult {
+            message: var_message,
+            similarity: var_similarity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::memory_budget::MemoryUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lowMemory = <bool>::sse_decode(deserializer);
+        let mut var_hnswPoints = <u64>::sse_decode(deserializer);
+        let mut var_quantizedPoints = <u64>::sse_decode(deserializer);
+        let mut var_quantizedIndexBytes = <u64>::sse_decode(deserializer);
+        let mut var_bufferedEmbeddings = <u64>::sse_decode(deserializer);
+        let mut var_sqliteMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_processRssBytes = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::memory_budget::MemoryUsage {
+            low_memory: var_lowMemory,
+            hnsw_points: var_hnswPoints,
+            quantized_points: var_quantizedPoints,
+            quantized_index_bytes: var_quantizedIndexBytes,
+            buffered_embeddings: var_bufferedEmbeddings,
+            sqlite_memory_bytes: var_sqliteMemoryBytes,
+            process_rss_bytes: var_processRssBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::named_bm25::NamedBm25IndexInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_documentCount = <u64>::sse_decode(deserializer);
+        let mut var_vocabularySize = <u64>::sse_decode(deserializer);
+        return crate::api::named_bm25::NamedBm25IndexInfo {
+            name: var_name,
+            document_count: var_documentCount,
+            vocabulary_size: var_vocabularySize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::named_index::NamedIndexConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_metric =
+            <Option<crate::api::hnsw_index::DistanceMetric>>::sse_decode(deserializer);
+        let mut var_build =
+            <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_decode(deserializer);
+        let mut var_efSearch = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::named_index::NamedIndexConfig {
+            metric: var_metric,
+            build: var_build,
+            ef_search: var_efSearch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::named_index::NamedIndexInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_pointCount = <u64>::sse_decode(deserializer);
+        let mut var_deletedPoints = <u64>::sse_decode(deserializer);
+        let mut var_dimension = <u32>::sse_decode(deserializer);
+        let mut var_metric = <crate::api::hnsw_index::DistanceMetric>::sse_decode(deserializer);
+        return crate::api::named_index::NamedIndexInfo {
+            name: var_name,
+            point_count: var_pointCount,
+            deleted_points: var_deletedPoints,
+            dimension: var_dimension,
+            metric: var_metric,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audit::AuditReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audit::AuditReport>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::background_tasks::BackgroundTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::background_tasks::BackgroundTask>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::fuzzy_terms::Bm25FuzzyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::fuzzy_terms::Bm25FuzzyConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::semantic_chunker::ChunkingStrategy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::semantic_chunker::ChunkingStrategy>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hnsw_index::DistanceMetric> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::hnsw_index::DistanceMetric>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::related_chunks::ExpansionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::related_chunks::ExpansionConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hnsw_index::HnswBuildConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::hnsw_index::HnswBuildConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hnsw_index::HnswIndexStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::hnsw_index::HnswIndexStats>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hybrid_search::HybridSearchOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::hybrid_search::HybridSearchOptions>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::retrieval_state::LastRetrieval> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::retrieval_state::LastRetrieval>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::maintenance::MaintenanceReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::maintenance::MaintenanceReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<(u32, u32, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<(u32, u32, u32)>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::reembedding::ReembeddingJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::reembedding::ReembeddingJob>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hybrid_search::RrfConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::hybrid_search::RrfConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_trace::SearchDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_trace::SearchDiagnostics>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::hybrid_search::SearchFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::hybrid_search::SearchFilter>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::snippets::SearchSnippet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::snippets::SearchSnippet>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_trace::SearchTrace> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_trace::SearchTrace>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::chunk_store::StoredChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::chunk_store::StoredChunk>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<i64>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<i64>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<(String, String)>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<(String, String)>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::source_grouping::SourceGroup>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::source_grouping::SourceGroup>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::panic_report::PanicEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_thread = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_location = <Option<String>>::sse_decode(deserializer);
+        let mut var_backtrace = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        return crate::api::panic_report::PanicEvent {
+            thread: var_thread,
+            message: var_message,
+            location: var_location,
+            backtrace: var_backtrace,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::user_intent::ParsedIntent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_intentType = <String>::sse_decode(deserializer);
+        let mut var_query = <String>::sse_decode(deserializer);
+        let mut var_isValid = <bool>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        return crate::api::user_intent::ParsedIntent {
+            intent_type: var_intentType,
+            query: var_query,
+            is_valid: var_isValid,
+            error_message: var_errorMessage,
+            language: var_language,
+        };
+    }
+}
+
+impl SseDecode for crate::api::progress::ProgressEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_operation = <String>::sse_decode(deserializer);
+        let mut var_phase = <String>::sse_decode(deserializer);
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::progress::ProgressEvent {
+            operation: var_operation,
+            phase: var_phase,
+            done: var_done,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hnsw_index::Quantization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::hnsw_index::Quantization::None,
+            1 => crate::api::hnsw_index::Quantization::Int8,
+            _ => unreachable!("Invalid variant for Quantization: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::query_decompose::QueryDecomposition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_original = <String>::sse_decode(deserializer);
+        let mut var_subQueries = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::query_decompose::QueryDecomposition {
+            original: var_original,
+            sub_queries: var_subQueries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query_keywords::QueryKeyword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_docFreq = <u32>::sse_decode(deserializer);
+        let mut var_inIndex = <bool>::sse_decode(deserializer);
+        return crate::api::query_keywords::QueryKeyword {
+            text: var_text,
+            score: var_score,
+            doc_freq: var_docFreq,
+            in_index: var_inIndex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query_normalize::QueryNormalizationConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_unicodeNfkc = <bool>::sse_decode(deserializer);
+        let mut var_stripPunctuation = <bool>::sse_decode(deserializer);
+        let mut var_lowercase = <bool>::sse_decode(deserializer);
+        let mut var_removeStopwords = <bool>::sse_decode(deserializer);
+        return crate::api::query_normalize::QueryNormalizationConfig {
+            unicode_nfkc: var_unicodeNfkc,
+            strip_punctuation: var_stripPunctuation,
+            lowercase: var_lowercase,
+            remove_stopwords: var_removeStopwords,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query_history::QuerySuggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_query = <String>::sse_decode(deserializer);
+        let mut var_useCount = <u32>::sse_decode(deserializer);
+        let mut var_lastUsedAt = <i64>::sse_decode(deserializer);
+        return crate::api::query_history::QuerySuggestion {
+            query: var_query,
+            use_count: var_useCount,
+            last_used_at: var_lastUsedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::RagError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::DatabaseError(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::IoError(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::ModelLoadError(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::InvalidInput(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::InternalError(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::Unknown(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::DatabaseBusy(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::NotInitialized(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RagError::ParseError(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::RankedHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_docId = <i64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_vectorRank = <u32>::sse_decode(deserializer);
+        let mut var_bm25Rank = <u32>::sse_decode(deserializer);
+        return crate::api::hybrid_search::RankedHit {
+            doc_id: var_docId,
+            score: var_score,
+            vector_rank: var_vectorRank,
+            bm25_rank: var_bm25Rank,
+        };
+    }
+}
+
+impl SseDecode for (i64, Vec<f32>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <i64>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<f32>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (i64, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <i64>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (String, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <f64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (u32, u32, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <u32>::sse_decode(deserializer);
+        let mut var_field1 = <u32>::sse_decode(deserializer);
+        let mut var_field2 = <u32>::sse_decode(deserializer);
+        return (var_field0, var_field1, var_field2);
+    }
+}
+
+impl SseDecode for crate::api::reembedding::ReembeddingJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_modelName = <String>::sse_decode(deserializer);
+        let mut var_batchSize = <u32>::sse_decode(deserializer);
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_lastChunkId = <i64>::sse_decode(deserializer);
+        let mut var_dimension = <Option<u32>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::reembedding::ReembeddingJob {
+            id: var_id,
+            model_name: var_modelName,
+            batch_size: var_batchSize,
+            state: var_state,
+            done: var_done,
+            total: var_total,
+            last_chunk_id: var_lastChunkId,
+            dimension: var_dimension,
+            error: var_error,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::retrieval_state::RetrievalCitation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkId = <i64>::sse_decode(deserializer);
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        return crate::api::retrieval_state::RetrievalCitation {
+            chunk_id: var_chunkId,
+            source_id: var_sourceId,
+            chunk_index: var_chunkIndex,
+            score: var_score,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::intent_presets::RetrievalPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_topK = <u32>::sse_decode(deserializer);
+        let mut var_vectorWeight = <f64>::sse_decode(deserializer);
+        let mut var_bm25Weight = <f64>::sse_decode(deserializer);
+        let mut var_boostChunkType = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkTypeBoost = <f64>::sse_decode(deserializer);
+        let mut var_useMmr = <bool>::sse_decode(deserializer);
+        let mut var_mmrLambda = <f64>::sse_decode(deserializer);
+        return crate::api::intent_presets::RetrievalPreset {
+            top_k: var_topK,
+            vector_weight: var_vectorWeight,
+            bm25_weight: var_bm25Weight,
+            boost_chunk_type: var_boostChunkType,
+            chunk_type_boost: var_chunkTypeBoost,
+            use_mmr: var_useMmr,
+            mmr_lambda: var_mmrLambda,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query_rewrite::RewrittenQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_original = <String>::sse_decode(deserializer);
+        let mut var_rewritten = <String>::sse_decode(deserializer);
+        let mut var_wasRewritten = <bool>::sse_decode(deserializer);
+        let mut var_antecedent = <Option<String>>::sse_decode(deserializer);
+        return crate::api::query_rewrite::RewrittenQuery {
+            original: var_original,
+            rewritten: var_rewritten,
+            was_rewritten: var_wasRewritten,
+            antecedent: var_antecedent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::RrfConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_k = <u32>::sse_decode(deserializer);
+        let mut var_vectorWeight = <f64>::sse_decode(deserializer);
+        let mut var_bm25Weight = <f64>::sse_decode(deserializer);
+        let mut var_typeBoosts = <std::collections::HashMap<String, f64>>::sse_decode(deserializer);
+        let mut var_recencyHalfLifeDays = <Option<f64>>::sse_decode(deserializer);
+        let mut var_recencyWeight = <f64>::sse_decode(deserializer);
+        let mut var_fusion = <crate::api::hybrid_search::FusionStrategy>::sse_decode(deserializer);
+        return crate::api::hybrid_search::RrfConfig {
+            k: var_k,
+            vector_weight: var_vectorWeight,
+            bm25_weight: var_bm25Weight,
+            type_boosts: var_typeBoosts,
+            recency_half_life_days: var_recencyHalfLifeDays,
+            recency_weight: var_recencyWeight,
+            fusion: var_fusion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::ScoreNormalization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::hybrid_search::ScoreNormalization::MinMax,
+            1 => crate::api::hybrid_search::ScoreNormalization::ZScore,
+            _ => unreachable!("Invalid variant for ScoreNormalization: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_trace::SearchDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_vectorMs = <f64>::sse_decode(deserializer);
+        let mut var_bm25Ms = <f64>::sse_decode(deserializer);
+        let mut var_filterMs = <f64>::sse_decode(deserializer);
+        let mut var_fusionMs = <f64>::sse_decode(deserializer);
+        let mut var_fetchMs = <f64>::sse_decode(deserializer);
+        let mut var_totalMs = <f64>::sse_decode(deserializer);
+        let mut var_candidateK = <u32>::sse_decode(deserializer);
+        let mut var_vectorCandidates = <u32>::sse_decode(deserializer);
+        let mut var_bm25Candidates = <u32>::sse_decode(deserializer);
+        let mut var_filteredVectorCandidates = <u32>::sse_decode(deserializer);
+        let mut var_filteredBm25Candidates = <u32>::sse_decode(deserializer);
+        let mut var_exactScan = <bool>::sse_decode(deserializer);
+        let mut var_graphFilter = <bool>::sse_decode(deserializer);
+        let mut var_hnswLoaded = <bool>::sse_decode(deserializer);
+        return crate::api::search_trace::SearchDiagnostics {
+            vector_ms: var_vectorMs,
+            bm25_ms: var_bm25Ms,
+            filter_ms: var_filterMs,
+            fusion_ms: var_fusionMs,
+            fetch_ms: var_fetchMs,
+            total_ms: var_totalMs,
+            candidate_k: var_candidateK,
+            vector_candidates: var_vectorCandidates,
+            bm25_candidates: var_bm25Candidates,
+            filtered_vector_candidates: var_filteredVectorCandidates,
+            filtered_bm25_candidates: var_filteredBm25Candidates,
+            exact_scan: var_exactScan,
+            graph_filter: var_graphFilter,
+            hnsw_loaded: var_hnswLoaded,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_analytics::SearchFeedbackAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_analytics::SearchFeedbackAction::Clicked,
+            1 => crate::api::search_analytics::SearchFeedbackAction::Accepted,
+            _ => unreachable!("Invalid variant for SearchFeedbackAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::SearchFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceIds = <Option<Vec<i64>>>::sse_decode(deserializer);
+        let mut var_metadataLike = <Option<String>>::sse_decode(deserializer);
+        let mut var_entity = <Option<String>>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkTypes = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_metadataEquals = <Option<Vec<(String, String)>>>::sse_decode(deserializer);
+        let mut var_createdAfter = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdBefore = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::hybrid_search::SearchFilter {
+            source_ids: var_sourceIds,
+            metadata_like: var_metadataLike,
+            entity: var_entity,
+            language: var_language,
+            chunk_types: var_chunkTypes,
+            metadata_equals: var_metadataEquals,
+            created_after: var_createdAfter,
+            created_before: var_createdBefore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::snippets::SearchSnippet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        let mut var_highlights =
+            <Vec<crate::api::snippets::HighlightSpan>>::sse_decode(deserializer);
+        return crate::api::snippets::SearchSnippet {
+            text: var_text,
+            start: var_start,
+            end: var_end,
+            highlights: var_highlights,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_trace::SearchSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_durationMs = <f64>::sse_decode(deserializer);
+        return crate::api::search_trace::SearchSpan {
+            name: var_name,
+            duration_ms: var_durationMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_trace::SearchTrace {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spans = <Vec<crate::api::search_trace::SearchSpan>>::sse_decode(deserializer);
+        let mut var_totalMs = <f64>::sse_decode(deserializer);
+        return crate::api::search_trace::SearchTrace {
+            spans: var_spans,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::SemanticChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <i32>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_startPos = <i32>::sse_decode(deserializer);
+        let mut var_endPos = <i32>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        return crate::api::semantic_chunker::SemanticChunk {
+            index: var_index,
+            content: var_content,
+            start_pos: var_startPos,
+            end_pos: var_endPos,
+            chunk_type: var_chunkType,
+            language: var_language,
+        };
+    }
+}
+
+impl SseDecode for crate::api::snapshot::SnapshotFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        return crate::api::snapshot::SnapshotFile {
+            name: var_name,
+            size: var_size,
+            sha256: var_sha256,
+        };
+    }
+}
+
+impl SseDecode for crate::api::snapshot::SnapshotManifest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_formatVersion = <u32>::sse_decode(deserializer);
+        let mut var_engineVersion = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_tenants = <Vec<String>>::sse_decode(deserializer);
+        let mut var_sourceCount = <i64>::sse_decode(deserializer);
+        let mut var_chunkCount = <i64>::sse_decode(deserializer);
+        let mut var_files = <Vec<crate::api::snapshot::SnapshotFile>>::sse_decode(deserializer);
+        return crate::api::snapshot::SnapshotManifest {
+            format_version: var_formatVersion,
+            engine_version: var_engineVersion,
+            created_at: var_createdAt,
+            tenants: var_tenants,
+            source_count: var_sourceCount,
+            chunk_count: var_chunkCount,
+            files: var_files,
+        };
+    }
+}
+
+impl SseDecode for crate::api::corpus_report::SourceCorpusStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkCount = <u64>::sse_decode(deserializer);
+        let mut var_minChunkChars = <u32>::sse_decode(deserializer);
+        let mut var_maxChunkChars = <u32>::sse_decode(deserializer);
+        let mut var_avgChunkChars = <f64>::sse_decode(deserializer);
+        return crate::api::corpus_report::SourceCorpusStats {
+            source_id: var_sourceId,
+            name: var_name,
+            status: var_status,
+            chunk_count: var_chunkCount,
+            min_chunk_chars: var_minChunkChars,
+            max_chunk_chars: var_maxChunkChars,
+            avg_chunk_chars: var_avgChunkChars,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::SourceEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <Option<String>>::sse_decode(deserializer);
+        return crate::api::source_rag::SourceEntry {
+            id: var_id,
+            name: var_name,
+            created_at: var_createdAt,
+            metadata: var_metadata,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_grouping::SourceGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_isDocument = <bool>::sse_decode(deserializer);
+        let mut var_sourceName = <Option<String>>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_chunks =
+            <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_decode(deserializer);
+        return crate::api::source_grouping::SourceGroup {
+            source_id: var_sourceId,
+            is_document: var_isDocument,
+            source_name: var_sourceName,
+            score: var_score,
+            chunks: var_chunks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_index::SourceIndexConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_maxCached = <u32>::sse_decode(deserializer);
+        return crate::api::source_index::SourceIndexConfig {
+            enabled: var_enabled,
+            max_cached: var_maxCached,
+        };
+    }
+}
+
+impl SseDecode for crate::api::source_rag::SourceStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceCount = <i64>::sse_decode(deserializer);
+        let mut var_chunkCount = <i64>::sse_decode(deserializer);
+        return crate::api::source_rag::SourceStats {
+            source_count: var_sourceCount,
+            chunk_count: var_chunkCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::staged_ingest::SourceUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stagingId = <String>::sse_decode(deserializer);
+        let mut var_chunks = <Vec<String>>::sse_decode(deserializer);
+        let mut var_keptChunks = <u32>::sse_decode(deserializer);
+        let mut var_removedChunks = <u32>::sse_decode(deserializer);
+        let mut var_skippedChunks = <u32>::sse_decode(deserializer);
+        let mut var_expiresAt = <i64>::sse_decode(deserializer);
+        return crate::api::staged_ingest::SourceUpdate {
+            staging_id: var_stagingId,
+            chunks: var_chunks,
+            kept_chunks: var_keptChunks,
+            removed_chunks: var_removedChunks,
+            skipped_chunks: var_skippedChunks,
+            expires_at: var_expiresAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::staged_ingest::StagedIngest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stagingId = <String>::sse_decode(deserializer);
+        let mut var_chunks = <Vec<String>>::sse_decode(deserializer);
+        let mut var_skippedChunks = <u32>::sse_decode(deserializer);
+        let mut var_expiresAt = <i64>::sse_decode(deserializer);
+        return crate::api::staged_ingest::StagedIngest {
+            staging_id: var_stagingId,
+            chunks: var_chunks,
+            skipped_chunks: var_skippedChunks,
+            expires_at: var_expiresAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_store::StoreSearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        return crate::api::chunk_store::StoreSearchHit {
+            id: var_id,
+            similarity: var_similarity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chunk_store::StoredChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_sourceId = <i64>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_embedding = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::chunk_store::StoredChunk {
+            id: var_id,
+            source_id: var_sourceId,
+            content: var_content,
+            embedding: var_embedding,
+        };
+    }
+}
+
+impl SseDecode for crate::api::semantic_chunker::StructuredChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <i32>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_headerPath = <String>::sse_decode(deserializer);
+        let mut var_chunkType = <String>::sse_decode(deserializer);
+        let mut var_startPos = <i32>::sse_decode(deserializer);
+        let mut var_endPos = <i32>::sse_decode(deserializer);
+        let mut var_batchId = <Option<String>>::sse_decode(deserializer);
+        let mut var_batchIndex = <Option<i32>>::sse_decode(deserializer);
+        let mut var_batchTotal = <Option<i32>>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        return crate::api::semantic_chunker::StructuredChunk {
+            index: var_index,
+            content: var_content,
+            header_path: var_headerPath,
+            chunk_type: var_chunkType,
+            start_pos: var_startPos,
+            end_pos: var_endPos,
+            batch_id: var_batchId,
+            batch_index: var_batchIndex,
+            batch_total: var_batchTotal,
+            language: var_language,
+        };
+    }
+}
+
+impl SseDecode for crate::api::synonyms::SynonymEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_term = <String>::sse_decode(deserializer);
+        let mut var_expansions = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::synonyms::SynonymEntry {
+            term: var_term,
+            expansions: var_expansions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::hybrid_search::TracedHybridSearch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_results =
+            <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_decode(deserializer);
+        let mut var_trace =
+            <Option<crate::api::search_trace::SearchTrace>>::sse_decode(deserializer);
+        return crate::api::hybrid_search::TracedHybridSearch {
+            results: var_results,
+            trace: var_trace,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::user_intent::UserIntent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_query = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::Summary { query: var_query };
+            }
+            1 => {
+                let mut var_term = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::Define { term: var_term };
+            }
+            2 => {
+                let mut var_query = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::ExpandKnowledge { query: var_query };
+            }
+            3 => {
+                let mut var_query = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::General { query: var_query };
+            }
+            4 => {
+                let mut var_command = <String>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::InvalidCommand {
+                    command: var_command,
+                    reason: var_reason,
+                };
+            }
+            5 => {
+                let mut var_left = <String>::sse_decode(deserializer);
+                let mut var_right = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::Compare {
+                    left: var_left,
+                    right: var_right,
+                };
+            }
+            6 => {
+                let mut var_query = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::Cite { query: var_query };
+            }
+            7 => {
+                let mut var_query = <String>::sse_decode(deserializer);
+                return crate::api::user_intent::UserIntent::Sources { query: var_query };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__chunk_store__MemoryStore_chunk_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__chunk_store__MemoryStore_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__chunk_store__MemoryStore_delete_source_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__chunk_store__MemoryStore_get_chunk_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__chunk_store__MemoryStore_new_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__chunk_store__MemoryStore_put_chunk_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__chunk_store__MemoryStore_search_vectors_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__source_rag__add_chunks_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__async_ops__add_chunks_async_impl(port, ptr, rust_vec_len, data_len),
+        15 => {
+            wire__crate__api__source_rag__add_chunks_packed_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => wire__crate__api__async_ops__add_chunks_packed_async_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__simple_rag__add_document_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple_rag__add_document_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__chat_memory__add_memory_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__source_rag__add_source_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__synonyms__add_synonyms_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__audit__audit_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__benchmark__benchmark_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => {
+            wire__crate__api__bm25_search__bm25_add_document_impl(port, ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__bm25_search__bm25_add_documents_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__named_bm25__bm25_add_documents_named_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => {
+            wire__crate__api__bm25_search__bm25_clear_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        30 => wire__crate__api__bm25_search__bm25_get_document_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__bm25_search__bm25_remove_document_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__named_bm25__bm25_remove_documents_named_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__bm25_search__bm25_search_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__bm25_search__bm25_update_document_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__bm25_search__bm_25_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__fuzzy_terms__bm_25_fuzzy_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__bm25_search__bm_25_index_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__bm25_search__bm_25_tokenization_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__binary_index__build_binary_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__named_bm25__build_bm25_index_named_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => {
+            wire__crate__api__hnsw_index__build_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        42 => wire__crate__api__named_index__build_hnsw_index_named_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__hnsw_index__build_hnsw_index_with_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => {
+            wire__crate__api__staged_ingest__cancel_ingest_impl(port, ptr, rust_vec_len, data_len)
+        }
+        48 => {
+            wire__crate__api__ingest_jobs__cancel_ingest_job_impl(port, ptr, rust_vec_len, data_len)
+        }
+        49 => wire__crate__api__reembedding__cancel_reembedding_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__index_freshness__check_index_freshness_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__semantic_chunker__chunking_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__simple_rag__clear_all_documents_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__incremental_index__clear_buffer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => wire__crate__api__ingest_jobs__clear_finished_ingest_jobs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => {
+            wire__crate__api__hnsw_index__clear_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        63 => wire__crate__api__query_history__clear_query_history_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__search_analytics__clear_search_analytics_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__db_pool__close_db_pool_impl(port, ptr, rust_vec_len, data_len),
+        70 => {
+            wire__crate__api__staged_ingest__commit_ingest_impl(port, ptr, rust_vec_len, data_len)
+        }
+        71 => wire__crate__api__source_rag__compact_index_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__compression_utils__compress_text_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__compression_utils__compress_text_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__compression_utils__compression_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => wire__crate__api__snapshot__create_snapshot_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__db_retry__db_retry_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => wire__crate__api__chunk_dedup__dedupe_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__chat_memory__delete_conversation_memory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => {
+            wire__crate__api__named_index__delete_hnsw_named_impl(port, ptr, rust_vec_len, data_len)
+        }
+        83 => wire__crate__api__source_rag__delete_source_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__tenant__delete_tenant_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__hnsw_index__distance_metric_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__hnsw_index__embedding_point_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__embedding_storage__embedding_storage_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__ingest_jobs__enqueue_ingest_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => wire__crate__api__chunk_index_store__ensure_chunk_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        93 => wire__crate__api__index_freshness__ensure_indexes_fresh_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        94 => wire__crate__api__benchmark__evaluate_index_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__related_chunks__expansion_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        96 => wire__crate__api__search_analytics__export_search_analytics_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__document_parser__extract_text_from_document_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__api__document_parser__extract_text_from_docx_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__api__document_parser__extract_text_from_pdf_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        103 => wire__crate__api__rerank__finalize_with_rerank_scores_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__source_rag__get_adjacent_chunks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__api__incremental_index__get_buffer_for_merge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__api__incremental_index__get_buffer_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__entity_extraction__get_chunk_entities_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__api__chat_memory__get_conversation_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__corpus_report__get_corpus_report_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => {
+            wire__crate__api__simple_rag__get_document_count_impl(port, ptr, rust_vec_len, data_len)
+        }
+        120 => wire__crate__api__embedding_storage__get_embedding_storage_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => {
+            wire__crate__api__engine_info__get_engine_info_impl(port, ptr, rust_vec_len, data_len)
+        }
+        122 => wire__crate__api__knowledge_graph__get_entity_neighborhood_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => wire__crate__api__knowledge_graph__get_entity_supporting_chunks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        140 => wire__crate__api__db_pool__get_pool_stats_impl(port, ptr, rust_vec_len, data_len),
+        142 => wire__crate__api__query_history__get_query_suggestions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        144 => wire__crate__api__reembedding__get_reembedding_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        147 => wire__crate__api__source_rag__get_source_impl(port, ptr, rust_vec_len, data_len),
+        148 => wire__crate__api__source_rag__get_source_chunk_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        149 => {
+            wire__crate__api__source_rag__get_source_chunks_impl(port, ptr, rust_vec_len, data_len)
+        }
+        151 => {
+            wire__crate__api__source_rag__get_source_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        154 => wire__crate__api__hnsw_index__hnsw_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        155 => wire__crate__api__hnsw_index__hnsw_insert_impl(port, ptr, rust_vec_len, data_len),
+        156 => wire__crate__api__hybrid_search__hybrid_search_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        157 => wire__crate__api__hybrid_search__hybrid_search_response_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        158 => wire__crate__api__incremental_index__incremental_add_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        159 => wire__crate__api__incremental_index__incremental_add_batch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        160 => wire__crate__api__incremental_index__incremental_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        161 => wire__crate__api__incremental_index__incremental_remove_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        162 => wire__crate__api__incremental_index__incremental_search_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => wire__crate__api__hnsw_index__index_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        164 => wire__crate__api__index_freshness__index_consistency_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        165 => wire__crate__api__index_freshness__index_divergence_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__index_freshness__index_staleness_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        167 => wire__crate__api__ingest__ingest_document_impl(port, ptr, rust_vec_len, data_len),
+        168 => {
+            wire__crate__api__ingest__ingest_options_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        169 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        170 => wire__crate__api__simple_rag__init_db_impl(port, ptr, rust_vec_len, data_len),
+        171 => wire__crate__api__db_pool__init_db_pool_impl(port, ptr, rust_vec_len, data_len),
+        173 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
+        175 => wire__crate__api__source_rag__init_source_db_impl(port, ptr, rust_vec_len, data_len),
+        176 => wire__crate__api__tokenizer__init_tokenizer_impl(port, ptr, rust_vec_len, data_len),
+        177 => {
+            wire__crate__api__named_index__insert_hnsw_named_impl(port, ptr, rust_vec_len, data_len)
+        }
+        180 => wire__crate__api__bm25_search__is_bm25_index_loaded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        181 => wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        183 => wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        187 => {
+            wire__crate__api__db_pool__is_pool_initialized_impl(port, ptr, rust_vec_len, data_len)
+        }
+        191 => wire__crate__api__fts_keyword__keyword_backend_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        192 => wire__crate__api__retrieval_state__last_retrieval_source_ids_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        195 => wire__crate__api__entity_extraction__list_entity_facets_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        197 => {
+            wire__crate__api__ingest_jobs__list_ingest_jobs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        199 => wire__crate__api__source_rag__list_sources_impl(port, ptr, rust_vec_len, data_len),
+        201 => wire__crate__api__tenant__list_tenants_impl(port, ptr, rust_vec_len, data_len),
+        202 => {
+            wire__crate__api__hnsw_index__load_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        203 => wire__crate__api__maintenance__maintenance_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        204 => wire__crate__api__maintenance__maintenance_report_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        206 => wire__crate__api__incremental_index__merge_buffer_into_hnsw_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        207 => wire__crate__api__named_index__named_index_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        208 => {
+            wire__crate__api__incremental_index__needs_merge_impl(port, ptr, rust_vec_len, data_len)
+        }
+        213 => {
+            wire__crate__api__ingest_jobs__pause_ingest_job_impl(port, ptr, rust_vec_len, data_len)
+        }
+        214 => wire__crate__api__reembedding__pause_reembedding_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        215 => {
+            wire__crate__api__staged_ingest__prepare_ingest_impl(port, ptr, rust_vec_len, data_len)
+        }
+        216 => wire__crate__api__hnsw_index__quantization_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        217 => wire__crate__api__embedding_storage__quantize_stored_embeddings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        218 => wire__crate__api__query_decompose__query_decomposition_is_decomposed_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        219 => wire__crate__api__query_normalize__query_normalization_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        223 => {
+            wire__crate__api__simple_rag__rebuild_bm25_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        224 => wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        225 => wire__crate__api__async_ops__rebuild_chunk_bm25_index_async_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        226 => wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        227 => wire__crate__api__async_ops__rebuild_chunk_hnsw_index_async_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        228 => wire__crate__api__source_rag__rebuild_chunk_hnsw_index_with_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        229 => {
+            wire__crate__api__simple_rag__rebuild_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        230 => {
+            wire__crate__api__query_history__record_query_impl(port, ptr, rust_vec_len, data_len)
+        }
+        232 => wire__crate__api__synonyms__remove_synonyms_impl(port, ptr, rust_vec_len, data_len),
+        233 => wire__crate__api__search_analytics__report_search_feedback_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        238 => wire__crate__api__snapshot__restore_snapshot_impl(port, ptr, rust_vec_len, data_len),
+        239 => {
+            wire__crate__api__ingest_jobs__resume_ingest_job_impl(port, ptr, rust_vec_len, data_len)
+        }
+        240 => wire__crate__api__intent_presets__retrieval_preset_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        243 => wire__crate__api__content_crypto__rotate_content_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        244 => wire__crate__api__hybrid_search__rrf_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        245 => wire__crate__api__benchmark__run_benchmark_impl(port, ptr, rust_vec_len, data_len),
+        246 => {
+            wire__crate__api__hnsw_index__save_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
+        }
+        247 => {
+            wire__crate__api__named_bm25__search_bm25_named_impl(port, ptr, rust_vec_len, data_len)
+        }
+        248 => wire__crate__api__source_rag__search_chunks_impl(port, ptr, rust_vec_len, data_len),
+        249 => wire__crate__api__binary_index__search_chunks_binary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        250 => wire__crate__api__source_rag__search_chunks_diverse_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        251 => wire__crate__api__source_rag__search_chunks_next_page_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        252 => wire__crate__api__source_rag__search_chunks_paged_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        253 => wire__crate__api__search_trace__search_diagnostics_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        254 => wire__crate__api__hybrid_search__search_filter_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        255 => wire__crate__api__hnsw_index__search_hnsw_impl(port, ptr, rust_vec_len, data_len),
+        256 => wire__crate__api__hnsw_index__search_hnsw_filtered_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        257 => {
+            wire__crate__api__named_index__search_hnsw_named_impl(port, ptr, rust_vec_len, data_len)
+        }
+        258 => wire__crate__api__hnsw_index__search_hnsw_with_ef_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        259 => {
+            wire__crate__api__hybrid_search__search_hybrid_impl(port, ptr, rust_vec_len, data_len)
+        }
+        260 => {
+            wire__crate__api__async_ops__search_hybrid_async_impl(port, ptr, rust_vec_len, data_len)
+        }
+        261 => wire__crate__api__rerank__search_hybrid_candidates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        262 => wire__crate__api__hybrid_search__search_hybrid_compare_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        263 => wire__crate__api__hybrid_search__search_hybrid_expanded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        264 => wire__crate__api__hybrid_search__search_hybrid_for_intent_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        265 => wire__crate__api__hybrid_search__search_hybrid_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        266 => wire__crate__api__hybrid_search__search_hybrid_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        267 => wire__crate__api__hybrid_search__search_hybrid_traced_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        268 => wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        269 => wire__crate__api__chat_memory__search_memory_impl(port, ptr, rust_vec_len, data_len),
+        270 => wire__crate__api__simple_rag__search_similar_impl(port, ptr, rust_vec_len, data_len),
+        274 => wire__crate__api__compression_utils__sentence_hash_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        279 => wire__crate__api__classification_rules__set_classification_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        289 => wire__crate__api__fts_keyword__set_keyword_backend_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        293 => wire__crate__api__memory_budget__set_memory_budget_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        302 => wire__crate__api__compression_utils__should_compress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        303 => wire__crate__api__source_index__source_index_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        304 => wire__crate__api__compression_utils__split_sentences_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        305 => wire__crate__api__ingest_jobs__start_ingest_worker_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        306 => wire__crate__api__reembedding__start_reembedding_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        307 => wire__crate__api__background_tasks__submit_buffer_merge_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        308 => wire__crate__api__background_tasks__submit_index_rebuild_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        309 => wire__crate__api__background_tasks__submit_reembedding_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        310 => wire__crate__api__background_tasks__submit_snapshot_export_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        312 => wire__crate__api__source_rag__update_chunk_content_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        313 => wire__crate__api__source_rag__update_chunk_embedding_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        314 => wire__crate__api__source_rag__update_chunk_embeddings_batch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        315 => {
+            wire__crate__api__simple_rag__update_document_impl(port, ptr, rust_vec_len, data_len)
+        }
+        316 => {
+            wire__crate__api__staged_ingest__update_source_impl(port, ptr, rust_vec_len, data_len)
+        }
+        317 => wire__crate__api__source_rag__update_source_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        318 => wire__crate__api__user_intent__user_intent_get_query_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        319 => wire__crate__api__user_intent__user_intent_intent_type_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        320 => wire__crate__api__index_freshness__verify_index_consistency_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        17 => {
+            wire__crate__api__query_rewrite__add_conversation_turn_impl(ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__semantic_chunker__analyze_chunks_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__source_index__cached_source_index_count_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__simple_rag__calculate_cosine_similarity_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__background_tasks__cancel_background_task_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__semantic_chunker__chunk_text_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__semantic_chunker__classify_chunk_impl(ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__content_crypto__clear_content_encryption_keys_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__query_rewrite__clear_conversation_turns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__retrieval_state__clear_last_retrieval_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__panic_report__clear_recent_panics_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__logger__close_log_stream_impl(ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__progress__close_progress_stream_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__code_chunker__code_chunk_impl(ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__tokenizer__decode_tokens_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__query_decompose__decompose_query_impl(ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__chunk_dedup__dedupe_chunks_impl(ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__language_detect__detect_language_impl(ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__named_bm25__drop_bm25_index_named_impl(ptr, rust_vec_len, data_len),
+        88 => {
+            wire__crate__api__named_index__drop_hnsw_index_named_impl(ptr, rust_vec_len, data_len)
+        }
+        97 => {
+            wire__crate__api__entity_extraction__extract_entities_impl(ptr, rust_vec_len, data_len)
+        }
+        98 => wire__crate__api__query_keywords__extract_query_keywords_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        102 => wire__crate__api__knowledge_graph__extract_triples_impl(ptr, rust_vec_len, data_len),
+        104 => wire__crate__api__tenant__get_active_tenant_impl(ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__background_tasks__get_background_task_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        108 => wire__crate__api__bm25_search__get_bm25_config_impl(ptr, rust_vec_len, data_len),
+        109 => {
+            wire__crate__api__fuzzy_terms__get_bm25_fuzzy_matching_impl(ptr, rust_vec_len, data_len)
+        }
+        110 => {
+            wire__crate__api__bm25_search__get_bm25_index_options_impl(ptr, rust_vec_len, data_len)
+        }
+        114 => wire__crate__api__classification_rules__get_classification_rules_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__query_rewrite__get_conversation_turns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => wire__crate__api__db_retry__get_db_retry_config_impl(ptr, rust_vec_len, data_len),
+        124 => {
+            wire__crate__api__hnsw_index__get_hnsw_build_config_impl(ptr, rust_vec_len, data_len)
+        }
+        125 => wire__crate__api__hnsw_index__get_hnsw_config_impl(ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__hnsw_index__get_hnsw_index_stats_impl(ptr, rust_vec_len, data_len),
+        127 => {
+            wire__crate__api__hnsw_index__get_hnsw_seeded_build_impl(ptr, rust_vec_len, data_len)
+        }
+        128 => wire__crate__api__incremental_index__get_incremental_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        129 => wire__crate__api__hnsw_index__get_index_config_impl(ptr, rust_vec_len, data_len),
+        130 => wire__crate__api__fts_keyword__get_keyword_backend_impl(ptr, rust_vec_len, data_len),
+        131 => wire__crate__api__analyzers__get_language_analyzer_impl(ptr, rust_vec_len, data_len),
+        132 => wire__crate__api__audit__get_last_audit_report_impl(ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__maintenance__get_last_maintenance_report_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        134 => {
+            wire__crate__api__retrieval_state__get_last_retrieval_impl(ptr, rust_vec_len, data_len)
+        }
+        135 => wire__crate__api__logger__get_log_level_impl(ptr, rust_vec_len, data_len),
+        136 => {
+            wire__crate__api__maintenance__get_maintenance_config_impl(ptr, rust_vec_len, data_len)
+        }
+        137 => {
+            wire__crate__api__memory_budget__get_memory_profile_impl(ptr, rust_vec_len, data_len)
+        }
+        138 => wire__crate__api__memory_budget__get_memory_usage_impl(ptr, rust_vec_len, data_len),
+        139 => wire__crate__api__metrics__get_metrics_impl(ptr, rust_vec_len, data_len),
+        141 => wire__crate__api__query_normalize__get_query_normalization_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        143 => wire__crate__api__panic_report__get_recent_panics_impl(ptr, rust_vec_len, data_len),
+        145 => {
+            wire__crate__api__intent_presets__get_retrieval_preset_impl(ptr, rust_vec_len, data_len)
+        }
+        146 => wire__crate__api__semantic_chunker__get_section_patterns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        150 => wire__crate__api__source_index__get_source_index_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        152 => wire__crate__api__tokenizer__get_vocab_size_impl(ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        172 => wire__crate__api__logger__init_log_stream_impl(ptr, rust_vec_len, data_len),
+        174 => wire__crate__api__progress__init_progress_stream_impl(ptr, rust_vec_len, data_len),
+        178 => wire__crate__api__panic_report__install_panic_hook_impl(ptr, rust_vec_len, data_len),
+        179 => wire__crate__api__background_tasks__is_background_worker_running_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        182 => wire__crate__api__content_crypto__is_content_encryption_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        184 => wire__crate__api__ingest_jobs__is_ingest_worker_running_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        185 => wire__crate__api__knowledge_graph__is_knowledge_graph_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        186 => {
+            wire__crate__api__maintenance__is_maintenance_running_impl(ptr, rust_vec_len, data_len)
+        }
+        188 => wire__crate__api__embedding_storage__is_quantized_embedding_storage_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        189 => wire__crate__api__search_analytics__is_search_analytics_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        190 => wire__crate__api__search_trace__is_search_tracing_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        193 => wire__crate__api__background_tasks__list_background_tasks_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        194 => {
+            wire__crate__api__named_bm25__list_bm25_indexes_named_impl(ptr, rust_vec_len, data_len)
+        }
+        196 => {
+            wire__crate__api__named_index__list_hnsw_indexes_named_impl(ptr, rust_vec_len, data_len)
+        }
+        198 => {
+            wire__crate__api__analyzers__list_language_analyzers_impl(ptr, rust_vec_len, data_len)
+        }
+        200 => wire__crate__api__synonyms__list_synonyms_impl(ptr, rust_vec_len, data_len),
+        205 => wire__crate__api__semantic_chunker__markdown_chunk_impl(ptr, rust_vec_len, data_len),
+        209 => wire__crate__api__query_normalize__normalize_query_impl(ptr, rust_vec_len, data_len),
+        210 => wire__crate__api__maintenance__notify_host_state_impl(ptr, rust_vec_len, data_len),
+        211 => wire__crate__api__user_intent__parse_intent_impl(ptr, rust_vec_len, data_len),
+        212 => wire__crate__api__user_intent__parse_user_intent_impl(ptr, rust_vec_len, data_len),
+        220 => wire__crate__api__error__rag_error_code_impl(ptr, rust_vec_len, data_len),
+        221 => wire__crate__api__error__rag_error_detail_impl(ptr, rust_vec_len, data_len),
+        222 => wire__crate__api__error__rag_error_is_retryable_impl(ptr, rust_vec_len, data_len),
+        231 => wire__crate__api__analyzers__register_language_analyzer_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        234 => {
+            wire__crate__api__analyzers__reset_language_analyzers_impl(ptr, rust_vec_len, data_len)
+        }
+        235 => wire__crate__api__logger__reset_log_levels_impl(ptr, rust_vec_len, data_len),
+        236 => wire__crate__api__metrics__reset_metrics_impl(ptr, rust_vec_len, data_len),
+        237 => wire__crate__api__intent_presets__reset_retrieval_presets_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        241 => wire__crate__api__query_rewrite__rewrite_query_impl(ptr, rust_vec_len, data_len),
+        242 => wire__crate__api__query_rewrite__rewrite_query_with_context_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        271 => wire__crate__api__semantic_chunker__semantic_chunk_impl(ptr, rust_vec_len, data_len),
+        272 => wire__crate__api__semantic_chunker__semantic_chunk_by_tokens_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        273 => wire__crate__api__semantic_chunker__semantic_chunk_with_overlap_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        275 => wire__crate__api__tenant__set_active_tenant_impl(ptr, rust_vec_len, data_len),
+        276 => wire__crate__api__bm25_search__set_bm25_config_impl(ptr, rust_vec_len, data_len),
+        277 => {
+            wire__crate__api__fuzzy_terms__set_bm25_fuzzy_matching_impl(ptr, rust_vec_len, data_len)
+        }
+        278 => {
+            wire__crate__api__bm25_search__set_bm25_index_options_impl(ptr, rust_vec_len, data_len)
+        }
+        280 => wire__crate__api__content_crypto__set_content_encryption_key_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        281 => wire__crate__api__db_retry__set_db_retry_config_impl(ptr, rust_vec_len, data_len),
+        282 => wire__crate__api__engine_info__set_embedding_model_name_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        283 => wire__crate__api__entity_extraction__set_entity_gazetteer_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        284 => {
+            wire__crate__api__hnsw_index__set_hnsw_build_config_impl(ptr, rust_vec_len, data_len)
+        }
+        285 => wire__crate__api__hnsw_index__set_hnsw_config_impl(ptr, rust_vec_len, data_len),
+        286 => {
+            wire__crate__api__hnsw_index__set_hnsw_seeded_build_impl(ptr, rust_vec_len, data_len)
+        }
+        287 => wire__crate__api__incremental_index__set_incremental_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        288 => wire__crate__api__hnsw_index__set_index_config_impl(ptr, rust_vec_len, data_len),
+        290 => wire__crate__api__knowledge_graph__set_knowledge_graph_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        291 => wire__crate__api__logger__set_log_level_impl(ptr, rust_vec_len, data_len),
+        292 => {
+            wire__crate__api__maintenance__set_maintenance_config_impl(ptr, rust_vec_len, data_len)
+        }
+        294 => wire__crate__api__logger__set_module_log_level_impl(ptr, rust_vec_len, data_len),
+        295 => wire__crate__api__embedding_storage__set_quantized_embedding_storage_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        296 => wire__crate__api__query_normalize__set_query_normalization_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        297 => {
+            wire__crate__api__intent_presets__set_retrieval_preset_impl(ptr, rust_vec_len, data_len)
+        }
+        298 => wire__crate__api__search_analytics__set_search_analytics_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        299 => wire__crate__api__search_trace__set_search_tracing_impl(ptr, rust_vec_len, data_len),
+        300 => wire__crate__api__semantic_chunker__set_section_patterns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        301 => wire__crate__api__source_index__set_source_index_config_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        311 => wire__crate__api__tokenizer__tokenize_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MemoryStore> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MemoryStore> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MemoryStore>> for MemoryStore {
+    fn into_into_dart(self) -> FrbWrapper<MemoryStore> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple_rag::AddDocumentResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.success.into_into_dart().into_dart(),
+            self.is_duplicate.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple_rag::AddDocumentResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple_rag::AddDocumentResult>
+    for crate::api::simple_rag::AddDocumentResult
+{
+    fn into_into_dart(self) -> crate::api::simple_rag::AddDocumentResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::AddSourceResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_id.into_into_dart().into_dart(),
+            self.is_duplicate.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::AddSourceResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::AddSourceResult>
+    for crate::api::source_rag::AddSourceResult
+{
+    fn into_into_dart(self) -> crate::api::source_rag::AddSourceResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.severity.into_into_dart().into_dart(),
+            self.code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.chunk_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::audit::AuditIssue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditIssue>
+    for crate::api::audit::AuditIssue
+{
+    fn into_into_dart(self) -> crate::api::audit::AuditIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.started_at.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.sources_checked.into_into_dart().into_dart(),
+            self.chunks_checked.into_into_dart().into_dart(),
+            self.issues.into_into_dart().into_dart(),
+            self.issue_count.into_into_dart().into_dart(),
+            self.max_severity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audit::AuditReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditReport>
+    for crate::api::audit::AuditReport
+{
+    fn into_into_dart(self) -> crate::api::audit::AuditReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::background_tasks::BackgroundTask {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.tenant_id.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.submitted_at.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.finished_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::background_tasks::BackgroundTask
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::background_tasks::BackgroundTask>
+    for crate::api::background_tasks::BackgroundTask
+{
+    fn into_into_dart(self) -> crate::api::background_tasks::BackgroundTask {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::background_tasks::BackgroundTaskKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::IndexRebuild => 0.into_dart(),
+            Self::BufferMerge => 1.into_dart(),
+            Self::SnapshotExport => 2.into_dart(),
+            Self::Reembedding => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::background_tasks::BackgroundTaskKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::background_tasks::BackgroundTaskKind>
+    for crate::api::background_tasks::BackgroundTaskKind
+{
+    fn into_into_dart(self) -> crate::api::background_tasks::BackgroundTaskKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::benchmark::BenchmarkConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.document_bytes.into_into_dart().into_dart(),
+            self.synthetic_paragraphs.into_into_dart().into_dart(),
+            self.chunk_max_chars.into_into_dart().into_dart(),
+            self.embedding_dim.into_into_dart().into_dart(),
+            self.search_iterations.into_into_dart().into_dart(),
+            self.top_k.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::benchmark::BenchmarkConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::benchmark::BenchmarkConfig>
+    for crate::api::benchmark::BenchmarkConfig
+{
+    fn into_into_dart(self) -> crate::api::benchmark::BenchmarkConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::benchmark::BenchmarkReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stages.into_into_dart().into_dart(),
+            self.document_chars.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.token_count.into_into_dart().into_dart(),
+            self.search_mean_ms.into_into_dart().into_dart(),
+            self.search_p95_ms.into_into_dart().into_dart(),
+            self.peak_rss_kb.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::benchmark::BenchmarkReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::benchmark::BenchmarkReport>
+    for crate::api::benchmark::BenchmarkReport
+{
+    fn into_into_dart(self) -> crate::api::benchmark::BenchmarkReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::benchmark::BenchmarkStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.rss_kb.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::benchmark::BenchmarkStage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::benchmark::BenchmarkStage>
+    for crate::api::benchmark::BenchmarkStage
+{
+    fn into_into_dart(self) -> crate::api::benchmark::BenchmarkStage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25Config {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.k1.into_into_dart().into_dart(),
+            self.b.into_into_dart().into_dart(),
+            self.variant.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::bm25_search::Bm25Config
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25Config>
+    for crate::api::bm25_search::Bm25Config
+{
+    fn into_into_dart(self) -> crate::api::bm25_search::Bm25Config {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::fuzzy_terms::Bm25FuzzyConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_edits.into_into_dart().into_dart(),
+            self.min_term_chars.into_into_dart().into_dart(),
+            self.max_expansions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::fuzzy_terms::Bm25FuzzyConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::fuzzy_terms::Bm25FuzzyConfig>
+    for crate::api::fuzzy_terms::Bm25FuzzyConfig
+{
+    fn into_into_dart(self) -> crate::api::fuzzy_terms::Bm25FuzzyConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25IndexOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tokenization.into_into_dart().into_dart(),
+            self.stemmer.into_into_dart().into_dart(),
+            self.strip_korean_particles.into_into_dart().into_dart(),
+            self.remove_stopwords.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::bm25_search::Bm25IndexOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25IndexOptions>
+    for crate::api::bm25_search::Bm25IndexOptions
+{
+    fn into_into_dart(self) -> crate::api::bm25_search::Bm25IndexOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25SearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.doc_id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::bm25_search::Bm25SearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25SearchResult>
+    for crate::api::bm25_search::Bm25SearchResult
+{
+    fn into_into_dart(self) -> crate::api::bm25_search::Bm25SearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25Tokenization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Words => 0.into_dart(),
+            Self::Whitespace => 1.into_dart(),
+            Self::CjkBigrams => 2.into_dart(),
+            Self::CjkTrigrams => 3.into_dart(),
+            Self::Mixed => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::bm25_search::Bm25Tokenization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25Tokenization>
+    for crate::api::bm25_search::Bm25Tokenization
+{
+    fn into_into_dart(self) -> crate::api::bm25_search::Bm25Tokenization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25Variant {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Bm25 => 0.into_dart(),
+            Self::Bm25Plus => 1.into_dart(),
+            Self::Bm25L => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::bm25_search::Bm25Variant
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25Variant>
+    for crate::api::bm25_search::Bm25Variant
+{
+    fn into_into_dart(self) -> crate::api::bm25_search::Bm25Variant {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::BufferStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.buffer_size.into_into_dart().into_dart(),
+            self.threshold.into_into_dart().into_dart(),
+            self.hnsw_loaded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::incremental_index::BufferStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::BufferStats>
+    for crate::api::incremental_index::BufferStats
+{
+    fn into_into_dart(self) -> crate::api::incremental_index::BufferStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::BufferTable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Chunks => 0.into_dart(),
+            Self::Docs => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::incremental_index::BufferTable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::BufferTable>
+    for crate::api::incremental_index::BufferTable
+{
+    fn into_into_dart(self) -> crate::api::incremental_index::BufferTable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.start_pos.into_into_dart().into_dart(),
+            self.end_pos.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.embedding.into_into_dart().into_dart(),
+            self.batch_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::ChunkData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkData>
+    for crate::api::source_rag::ChunkData
+{
+    fn into_into_dart(self) -> crate::api::source_rag::ChunkData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkForReembedding {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::ChunkForReembedding
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkForReembedding>
+    for crate::api::source_rag::ChunkForReembedding
+{
+    fn into_into_dart(self) -> crate::api::source_rag::ChunkForReembedding {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_index_store::ChunkIndexSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Memory => 0.into_dart(),
+            Self::Disk => 1.into_dart(),
+            Self::Rebuilt => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_index_store::ChunkIndexSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_index_store::ChunkIndexSource>
+    for crate::api::chunk_index_store::ChunkIndexSource
+{
+    fn into_into_dart(self) -> crate::api::chunk_index_store::ChunkIndexSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkMeta {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.start_pos.into_into_dart().into_dart(),
+            self.end_pos.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.batch_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::ChunkMeta
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkMeta>
+    for crate::api::source_rag::ChunkMeta
+{
+    fn into_into_dart(self) -> crate::api::source_rag::ChunkMeta {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkSearchPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.results.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::ChunkSearchPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkSearchPage>
+    for crate::api::source_rag::ChunkSearchPage
+{
+    fn into_into_dart(self) -> crate::api::source_rag::ChunkSearchPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_id.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.header_path.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::ChunkSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkSearchResult>
+    for crate::api::source_rag::ChunkSearchResult
+{
+    fn into_into_dart(self) -> crate::api::source_rag::ChunkSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::ChunkStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_count.into_into_dart().into_dart(),
+            self.min_size.into_into_dart().into_dart(),
+            self.max_size.into_into_dart().into_dart(),
+            self.avg_size.into_into_dart().into_dart(),
+            self.median_size.into_into_dart().into_dart(),
+            self.type_histogram.into_into_dart().into_dart(),
+            self.oversize_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::ChunkStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::ChunkStats>
+    for crate::api::semantic_chunker::ChunkStats
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::ChunkStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::ChunkType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Definition => 0.into_dart(),
+            Self::Example => 1.into_dart(),
+            Self::List => 2.into_dart(),
+            Self::Procedure => 3.into_dart(),
+            Self::Comparison => 4.into_dart(),
+            Self::General => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::ChunkType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::ChunkType>
+    for crate::api::semantic_chunker::ChunkType
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::ChunkType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::ChunkingConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_chars.into_into_dart().into_dart(),
+            self.min_chars.into_into_dart().into_dart(),
+            self.overlap.into_into_dart().into_dart(),
+            self.strategy.into_into_dart().into_dart(),
+            self.preserve_tables.into_into_dart().into_dart(),
+            self.preserve_code.into_into_dart().into_dart(),
+            self.sentence_terminators.into_into_dart().into_dart(),
+            self.section_summary_sentences.into_into_dart().into_dart(),
+            self.linearize_tables.into_into_dart().into_dart(),
+            self.keep_list_intro.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::ChunkingConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::ChunkingConfig>
+    for crate::api::semantic_chunker::ChunkingConfig
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::ChunkingConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::ChunkingStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Recursive => 0.into_dart(),
+            Self::Markdown => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::ChunkingStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::ChunkingStrategy>
+    for crate::api::semantic_chunker::ChunkingStrategy
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::ChunkingStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::classification_rules::ClassificationRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.patterns.into_into_dart().into_dart(),
+            self.min_matches.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::classification_rules::ClassificationRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::classification_rules::ClassificationRule>
+    for crate::api::classification_rules::ClassificationRule
+{
+    fn into_into_dart(self) -> crate::api::classification_rules::ClassificationRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::CompareSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject_index.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::CompareSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::CompareSearchResult>
+    for crate::api::hybrid_search::CompareSearchResult
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::CompareSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::compression_utils::CompressedText {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.original_chars.into_into_dart().into_dart(),
+            self.compressed_chars.into_into_dart().into_dart(),
+            self.ratio.into_into_dart().into_dart(),
+            self.sentences_removed.into_into_dart().into_dart(),
+            self.chars_saved_stopwords.into_into_dart().into_dart(),
+            self.chars_saved_truncation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::compression_utils::CompressedText
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::compression_utils::CompressedText>
+    for crate::api::compression_utils::CompressedText
+{
+    fn into_into_dart(self) -> crate::api::compression_utils::CompressedText {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::compression_utils::CompressionOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.remove_stopwords.into_into_dart().into_dart(),
+            self.remove_duplicates.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::compression_utils::CompressionOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::compression_utils::CompressionOptions>
+    for crate::api::compression_utils::CompressionOptions
+{
+    fn into_into_dart(self) -> crate::api::compression_utils::CompressionOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::corpus_report::CorpusCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::corpus_report::CorpusCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::corpus_report::CorpusCount>
+    for crate::api::corpus_report::CorpusCount
+{
+    fn into_into_dart(self) -> crate::api::corpus_report::CorpusCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::corpus_report::CorpusReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_count.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.total_chars.into_into_dart().into_dart(),
+            self.vocabulary_size.into_into_dart().into_dart(),
+            self.sources.into_into_dart().into_dart(),
+            self.chunk_length_histogram.into_into_dart().into_dart(),
+            self.chunk_types.into_into_dart().into_dart(),
+            self.languages.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::corpus_report::CorpusReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::corpus_report::CorpusReport>
+    for crate::api::corpus_report::CorpusReport
+{
+    fn into_into_dart(self) -> crate::api::corpus_report::CorpusReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::db_retry::DbRetryConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_attempts.into_into_dart().into_dart(),
+            self.base_delay_ms.into_into_dart().into_dart(),
+            self.max_delay_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::db_retry::DbRetryConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::db_retry::DbRetryConfig>
+    for crate::api::db_retry::DbRetryConfig
+{
+    fn into_into_dart(self) -> crate::api::db_retry::DbRetryConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_dedup::DedupeConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.near_duplicate_threshold.into_into_dart().into_dart(),
+            self.shingle_size.into_into_dart().into_dart(),
+            self.exact_only.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_dedup::DedupeConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_dedup::DedupeConfig>
+    for crate::api::chunk_dedup::DedupeConfig
+{
+    fn into_into_dart(self) -> crate::api::chunk_dedup::DedupeConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_dedup::DedupeReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kept.into_into_dart().into_dart(),
+            self.duplicates.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_dedup::DedupeReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_dedup::DedupeReport>
+    for crate::api::chunk_dedup::DedupeReport
+{
+    fn into_into_dart(self) -> crate::api::chunk_dedup::DedupeReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::DistanceMetric {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Cosine => 0.into_dart(),
+            Self::DotProduct => 1.into_dart(),
+            Self::L2 => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::DistanceMetric
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::DistanceMetric>
+    for crate::api::hnsw_index::DistanceMetric
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::DistanceMetric {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_dedup::DuplicateChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.duplicate_of.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_dedup::DuplicateChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_dedup::DuplicateChunk>
+    for crate::api::chunk_dedup::DuplicateChunk
+{
+    fn into_into_dart(self) -> crate::api::chunk_dedup::DuplicateChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::EmbeddingBatchProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::EmbeddingBatchProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::EmbeddingBatchProgress>
+    for crate::api::source_rag::EmbeddingBatchProgress
+{
+    fn into_into_dart(self) -> crate::api::source_rag::EmbeddingBatchProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::EmbeddingPoint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.embedding.into_into_dart().into_dart(),
+            self.norm.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::EmbeddingPoint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::EmbeddingPoint>
+    for crate::api::hnsw_index::EmbeddingPoint
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::EmbeddingPoint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::embedding_storage::EmbeddingStorageStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.f32_chunks.into_into_dart().into_dart(),
+            self.quantized_chunks.into_into_dart().into_dart(),
+            self.embedding_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::embedding_storage::EmbeddingStorageStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::embedding_storage::EmbeddingStorageStats>
+    for crate::api::embedding_storage::EmbeddingStorageStats
+{
+    fn into_into_dart(self) -> crate::api::embedding_storage::EmbeddingStorageStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::engine_info::EngineInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.crate_version.into_into_dart().into_dart(),
+            self.schema_version.into_into_dart().into_dart(),
+            self.database_schema_version.into_into_dart().into_dart(),
+            self.hnsw_format_version.into_into_dart().into_dart(),
+            self.bm25_format_version.into_into_dart().into_dart(),
+            self.snapshot_format_version.into_into_dart().into_dart(),
+            self.tokenizer_name.into_into_dart().into_dart(),
+            self.embedding_model_name.into_into_dart().into_dart(),
+            self.embedding_dimension.into_into_dart().into_dart(),
+            self.active_tenant.into_into_dart().into_dart(),
+            self.active_features.into_into_dart().into_dart(),
+            self.build_features.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::engine_info::EngineInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::engine_info::EngineInfo>
+    for crate::api::engine_info::EngineInfo
+{
+    fn into_into_dart(self) -> crate::api::engine_info::EngineInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::EngineMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.searches_executed.into_into_dart().into_dart(),
+            self.cache_hits.into_into_dart().into_dart(),
+            self.cache_misses.into_into_dart().into_dart(),
+            self.index_rebuilds.into_into_dart().into_dart(),
+            self.db_errors.into_into_dart().into_dart(),
+            self.panics.into_into_dart().into_dart(),
+            self.avg_search_latency_ms.into_into_dart().into_dart(),
+            self.search_latency_histogram.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::EngineMetrics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::EngineMetrics>
+    for crate::api::metrics::EngineMetrics
+{
+    fn into_into_dart(self) -> crate::api::metrics::EngineMetrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::entity_extraction::EntityFacet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entity_type.into_into_dart().into_dart(),
+            self.normalized.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::entity_extraction::EntityFacet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::entity_extraction::EntityFacet>
+    for crate::api::entity_extraction::EntityFacet
+{
+    fn into_into_dart(self) -> crate::api::entity_extraction::EntityFacet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::related_chunks::ExpandedSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.result.into_into_dart().into_dart(),
+            self.link.into_into_dart().into_dart(),
+            self.expanded_from.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::related_chunks::ExpandedSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::related_chunks::ExpandedSearchResult>
+    for crate::api::related_chunks::ExpandedSearchResult
+{
+    fn into_into_dart(self) -> crate::api::related_chunks::ExpandedSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::related_chunks::ExpansionConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_related_per_hit.into_into_dart().into_dart(),
+            self.score_decay.into_into_dart().into_dart(),
+            self.follow_adjacent.into_into_dart().into_dart(),
+            self.follow_shared_entities.into_into_dart().into_dart(),
+            self.follow_same_batch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::related_chunks::ExpansionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::related_chunks::ExpansionConfig>
+    for crate::api::related_chunks::ExpansionConfig
+{
+    fn into_into_dart(self) -> crate::api::related_chunks::ExpansionConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::entity_extraction::ExtractedEntity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entity_type.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.normalized.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::entity_extraction::ExtractedEntity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::entity_extraction::ExtractedEntity>
+    for crate::api::entity_extraction::ExtractedEntity
+{
+    fn into_into_dart(self) -> crate::api::entity_extraction::ExtractedEntity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::FusionStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::hybrid_search::FusionStrategy::Rrf => [0.into_dart()].into_dart(),
+            crate::api::hybrid_search::FusionStrategy::WeightedScore { normalization } => {
+                [1.into_dart(), normalization.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::FusionStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::FusionStrategy>
+    for crate::api::hybrid_search::FusionStrategy
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::FusionStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::entity_extraction::GazetteerEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.term.into_into_dart().into_dart(),
+            self.entity_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::entity_extraction::GazetteerEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::entity_extraction::GazetteerEntry>
+    for crate::api::entity_extraction::GazetteerEntry
+{
+    fn into_into_dart(self) -> crate::api::entity_extraction::GazetteerEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::knowledge_graph::GraphEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject.into_into_dart().into_dart(),
+            self.relation.into_into_dart().into_dart(),
+            self.object.into_into_dart().into_dart(),
+            self.chunk_id.into_into_dart().into_dart(),
+            self.hop.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::knowledge_graph::GraphEdge
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knowledge_graph::GraphEdge>
+    for crate::api::knowledge_graph::GraphEdge
+{
+    fn into_into_dart(self) -> crate::api::knowledge_graph::GraphEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::knowledge_graph::GraphNeighborhood {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entity.into_into_dart().into_dart(),
+            self.edges.into_into_dart().into_dart(),
+            self.chunk_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::knowledge_graph::GraphNeighborhood
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knowledge_graph::GraphNeighborhood>
+    for crate::api::knowledge_graph::GraphNeighborhood
+{
+    fn into_into_dart(self) -> crate::api::knowledge_graph::GraphNeighborhood {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::snippets::HighlightSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::snippets::HighlightSpan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::snippets::HighlightSpan>
+    for crate::api::snippets::HighlightSpan
+{
+    fn into_into_dart(self) -> crate::api::snippets::HighlightSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::HnswBuildConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.m.into_into_dart().into_dart(),
+            self.max_layer.into_into_dart().into_dart(),
+            self.ef_construction.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::HnswBuildConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::HnswBuildConfig>
+    for crate::api::hnsw_index::HnswBuildConfig
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::HnswBuildConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::HnswConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.build.into_into_dart().into_dart(),
+            self.ef_search.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::HnswConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::HnswConfig>
+    for crate::api::hnsw_index::HnswConfig
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::HnswConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::HnswIndexStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.point_count.into_into_dart().into_dart(),
+            self.dimension.into_into_dart().into_dart(),
+            self.quantization.into_into_dart().into_dart(),
+            self.metric.into_into_dart().into_dart(),
+            self.build.into_into_dart().into_dart(),
+            self.m0.into_into_dart().into_dart(),
+            self.custom_build.into_into_dart().into_dart(),
+            self.ef_search.into_into_dart().into_dart(),
+            self.deleted_points.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::HnswIndexStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::HnswIndexStats>
+    for crate::api::hnsw_index::HnswIndexStats
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::HnswIndexStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::HnswSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.distance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::HnswSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::HnswSearchResult>
+    for crate::api::hnsw_index::HnswSearchResult
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::HnswSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::HybridSearchEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::hybrid_search::HybridSearchEvent::Ranking(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::hybrid_search::HybridSearchEvent::Results(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::hybrid_search::HybridSearchEvent::Done => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::HybridSearchEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::HybridSearchEvent>
+    for crate::api::hybrid_search::HybridSearchEvent
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::HybridSearchEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::HybridSearchOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ef_search.into_into_dart().into_dart(),
+            self.diversity_lambda.into_into_dart().into_dart(),
+            self.collection.into_into_dart().into_dart(),
+            self.group_by_source.into_into_dart().into_dart(),
+            self.with_diagnostics.into_into_dart().into_dart(),
+            self.page_offset.into_into_dart().into_dart(),
+            self.cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::HybridSearchOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::HybridSearchOptions>
+    for crate::api::hybrid_search::HybridSearchOptions
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::HybridSearchOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::HybridSearchResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.results.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::HybridSearchResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::HybridSearchResponse>
+    for crate::api::hybrid_search::HybridSearchResponse
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::HybridSearchResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::HybridSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.doc_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.vector_rank.into_into_dart().into_dart(),
+            self.bm25_rank.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.header_path.into_into_dart().into_dart(),
+            self.matched_terms.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::HybridSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::HybridSearchResult>
+    for crate::api::hybrid_search::HybridSearchResult
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::HybridSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::IncrementalConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.auto_merge.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::incremental_index::IncrementalConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::IncrementalConfig>
+    for crate::api::incremental_index::IncrementalConfig
+{
+    fn into_into_dart(self) -> crate::api::incremental_index::IncrementalConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::IncrementalSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.doc_id.into_into_dart().into_dart(),
+            self.distance.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::incremental_index::IncrementalSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::IncrementalSearchResult>
+    for crate::api::incremental_index::IncrementalSearchResult
+{
+    fn into_into_dart(self) -> crate::api::incremental_index::IncrementalSearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::IndexConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.quantization.into_into_dart().into_dart(),
+            self.metric.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::IndexConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::IndexConfig>
+    for crate::api::hnsw_index::IndexConfig
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::IndexConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::index_freshness::IndexConsistency {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_count.into_into_dart().into_dart(),
+            self.hnsw.into_into_dart().into_dart(),
+            self.bm25.into_into_dart().into_dart(),
+            self.consistent.into_into_dart().into_dart(),
+            self.repaired.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::index_freshness::IndexConsistency
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::index_freshness::IndexConsistency>
+    for crate::api::index_freshness::IndexConsistency
+{
+    fn into_into_dart(self) -> crate::api::index_freshness::IndexConsistency {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::index_freshness::IndexDivergence {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.loaded.into_into_dart().into_dart(),
+            self.indexed_count.into_into_dart().into_dart(),
+            self.missing_ids.into_into_dart().into_dart(),
+            self.extra_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::index_freshness::IndexDivergence
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::index_freshness::IndexDivergence>
+    for crate::api::index_freshness::IndexDivergence
+{
+    fn into_into_dart(self) -> crate::api::index_freshness::IndexDivergence {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::benchmark::IndexEvaluation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sample_size.into_into_dart().into_dart(),
+            self.top_k.into_into_dart().into_dart(),
+            self.corpus_size.into_into_dart().into_dart(),
+            self.recall_at_k.into_into_dart().into_dart(),
+            self.min_recall.into_into_dart().into_dart(),
+            self.latency_p50_ms.into_into_dart().into_dart(),
+            self.latency_p95_ms.into_into_dart().into_dart(),
+            self.latency_p99_ms.into_into_dart().into_dart(),
+            self.exact_mean_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::benchmark::IndexEvaluation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::benchmark::IndexEvaluation>
+    for crate::api::benchmark::IndexEvaluation
+{
+    fn into_into_dart(self) -> crate::api::benchmark::IndexEvaluation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::corpus_report::IndexFreshness {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.db_chunk_count.into_into_dart().into_dart(),
+            self.hnsw_point_count.into_into_dart().into_dart(),
+            self.bm25_document_count.into_into_dart().into_dart(),
+            self.hnsw_up_to_date.into_into_dart().into_dart(),
+            self.bm25_up_to_date.into_into_dart().into_dart(),
+            self.last_source_added_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::corpus_report::IndexFreshness
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::corpus_report::IndexFreshness>
+    for crate::api::corpus_report::IndexFreshness
+{
+    fn into_into_dart(self) -> crate::api::corpus_report::IndexFreshness {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::index_freshness::IndexStaleness {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hnsw_stale.into_into_dart().into_dart(),
+            self.bm25_stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::index_freshness::IndexStaleness
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::index_freshness::IndexStaleness>
+    for crate::api::index_freshness::IndexStaleness
+{
+    fn into_into_dart(self) -> crate::api::index_freshness::IndexStaleness {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ingest::IngestInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::ingest::IngestInput::Text(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::ingest::IngestInput::Bytes(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ingest::IngestInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ingest::IngestInput>
+    for crate::api::ingest::IngestInput
+{
+    fn into_into_dart(self) -> crate::api::ingest::IngestInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ingest_jobs::IngestJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.stage.into_into_dart().into_dart(),
+            self.progress_done.into_into_dart().into_dart(),
+            self.progress_total.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ingest_jobs::IngestJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ingest_jobs::IngestJob>
+    for crate::api::ingest_jobs::IngestJob
+{
+    fn into_into_dart(self) -> crate::api::ingest_jobs::IngestJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ingest::IngestOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.strategy.into_into_dart().into_dart(),
+            self.max_chars.into_into_dart().into_dart(),
+            self.overlap_chars.into_into_dart().into_dart(),
+            self.rebuild_indexes.into_into_dart().into_dart(),
+            self.dedupe_chunks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ingest::IngestOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ingest::IngestOptions>
+    for crate::api::ingest::IngestOptions
+{
+    fn into_into_dart(self) -> crate::api::ingest::IngestOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ingest::IngestProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ingest::IngestProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ingest::IngestProgress>
+    for crate::api::ingest::IngestProgress
+{
+    fn into_into_dart(self) -> crate::api::ingest::IngestProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ingest::IngestReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_id.into_into_dart().into_dart(),
+            self.is_duplicate.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.skipped_chunks.into_into_dart().into_dart(),
+            self.text_chars.into_into_dart().into_dart(),
+            self.elapsed_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ingest::IngestReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ingest::IngestReport>
+    for crate::api::ingest::IngestReport
+{
+    fn into_into_dart(self) -> crate::api::ingest::IngestReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::fts_keyword::KeywordBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::InMemory => 0.into_dart(),
+            Self::Fts5 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::fts_keyword::KeywordBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::fts_keyword::KeywordBackend>
+    for crate::api::fts_keyword::KeywordBackend
+{
+    fn into_into_dart(self) -> crate::api::fts_keyword::KeywordBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::knowledge_graph::KnowledgeTriple {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subject.into_into_dart().into_dart(),
+            self.relation.into_into_dart().into_dart(),
+            self.object.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::knowledge_graph::KnowledgeTriple
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knowledge_graph::KnowledgeTriple>
+    for crate::api::knowledge_graph::KnowledgeTriple
+{
+    fn into_into_dart(self) -> crate::api::knowledge_graph::KnowledgeTriple {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::analyzers::LanguageAnalyzer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language.into_into_dart().into_dart(),
+            self.stopwords.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::analyzers::LanguageAnalyzer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::analyzers::LanguageAnalyzer>
+    for crate::api::analyzers::LanguageAnalyzer
+{
+    fn into_into_dart(self) -> crate::api::analyzers::LanguageAnalyzer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::retrieval_state::LastRetrieval {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.query.into_into_dart().into_dart(),
+            self.citations.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::retrieval_state::LastRetrieval
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::retrieval_state::LastRetrieval>
+    for crate::api::retrieval_state::LastRetrieval
+{
+    fn into_into_dart(self) -> crate::api::retrieval_state::LastRetrieval {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::LatencyBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.le_ms.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::LatencyBucket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::LatencyBucket>
+    for crate::api::metrics::LatencyBucket
+{
+    fn into_into_dart(self) -> crate::api::metrics::LatencyBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::corpus_report::LengthBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.le_chars.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::corpus_report::LengthBucket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::corpus_report::LengthBucket>
+    for crate::api::corpus_report::LengthBucket
+{
+    fn into_into_dart(self) -> crate::api::corpus_report::LengthBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::maintenance::MaintenanceConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.require_charging.into_into_dart().into_dart(),
+            self.min_interval_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::maintenance::MaintenanceConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::maintenance::MaintenanceConfig>
+    for crate::api::maintenance::MaintenanceConfig
+{
+    fn into_into_dart(self) -> crate::api::maintenance::MaintenanceConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::maintenance::MaintenanceReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.started_at.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.buffer_merged.into_into_dart().into_dart(),
+            self.hnsw_compacted.into_into_dart().into_dart(),
+            self.bm25_pruned.into_into_dart().into_dart(),
+            self.wal_pages_checkpointed.into_into_dart().into_dart(),
+            self.interrupted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::maintenance::MaintenanceReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::maintenance::MaintenanceReport>
+    for crate::api::maintenance::MaintenanceReport
+{
+    fn into_into_dart(self) -> crate::api::maintenance::MaintenanceReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_memory::MemoryMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.conversation_id.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_memory::MemoryMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_memory::MemoryMessage>
+    for crate::api::chat_memory::MemoryMessage
+{
+    fn into_into_dart(self) -> crate::api::chat_memory::MemoryMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::memory_budget::MemoryProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.budget_mb.into_into_dart().into_dart(),
+            self.low_memory.into_into_dart().into_dart(),
+            self.sqlite_cache_kb.into_into_dart().into_dart(),
+            self.sqlite_mmap_mb.into_into_dart().into_dart(),
+            self.quantize_index.into_into_dart().into_dart(),
+            self.candidate_multiplier.into_into_dart().into_dart(),
+            self.filtered_candidate_multiplier
+                .into_into_dart()
+                .into_dart(),
+            self.scan_page_rows.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::memory_budget::MemoryProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::memory_budget::MemoryProfile>
+    for crate::api::memory_budget::MemoryProfile
+{
+    fn into_into_dart(self) -> crate::api::memory_budget::MemoryProfile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_memory::MemorySearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_memory::MemorySearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_memory::MemorySearchResult>
+    for crate::api::chat_memory::MemorySearchResult
+{
+    fn into_into_dart(self) -> crate::api::chat_memory::MemorySearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::memory_budget::MemoryUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.low_memory.into_into_dart().into_dart(),
+            self.hnsw_points.into_into_dart().into_dart(),
+            self.quantized_points.into_into_dart().into_dart(),
+            self.quantized_index_bytes.into_into_dart().into_dart(),
+            self.buffered_embeddings.into_into_dart().into_dart(),
+            self.sqlite_memory_bytes.into_into_dart().into_dart(),
+            self.process_rss_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::memory_budget::MemoryUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::memory_budget::MemoryUsage>
+    for crate::api::memory_budget::MemoryUsage
+{
+    fn into_into_dart(self) -> crate::api::memory_budget::MemoryUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::named_bm25::NamedBm25IndexInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.document_count.into_into_dart().into_dart(),
+            self.vocabulary_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::named_bm25::NamedBm25IndexInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::named_bm25::NamedBm25IndexInfo>
+    for crate::api::named_bm25::NamedBm25IndexInfo
+{
+    fn into_into_dart(self) -> crate::api::named_bm25::NamedBm25IndexInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::named_index::NamedIndexConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.metric.into_into_dart().into_dart(),
+            self.build.into_into_dart().into_dart(),
+            self.ef_search.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::named_index::NamedIndexConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::named_index::NamedIndexConfig>
+    for crate::api::named_index::NamedIndexConfig
+{
+    fn into_into_dart(self) -> crate::api::named_index::NamedIndexConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::named_index::NamedIndexInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.point_count.into_into_dart().into_dart(),
+            self.deleted_points.into_into_dart().into_dart(),
+            self.dimension.into_into_dart().into_dart(),
+            self.metric.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::named_index::NamedIndexInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::named_index::NamedIndexInfo>
+    for crate::api::named_index::NamedIndexInfo
+{
+    fn into_into_dart(self) -> crate::api::named_index::NamedIndexInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::panic_report::PanicEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.thread.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.location.into_into_dart().into_dart(),
+            self.backtrace.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::panic_report::PanicEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::panic_report::PanicEvent>
+    for crate::api::panic_report::PanicEvent
+{
+    fn into_into_dart(self) -> crate::api::panic_report::PanicEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::user_intent::ParsedIntent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.intent_type.into_into_dart().into_dart(),
+            self.query.into_into_dart().into_dart(),
+            self.is_valid.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::user_intent::ParsedIntent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::user_intent::ParsedIntent>
+    for crate::api::user_intent::ParsedIntent
+{
+    fn into_into_dart(self) -> crate::api::user_intent::ParsedIntent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::progress::ProgressEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.operation.into_into_dart().into_dart(),
+            self.phase.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::progress::ProgressEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::progress::ProgressEvent>
+    for crate::api::progress::ProgressEvent
+{
+    fn into_into_dart(self) -> crate::api::progress::ProgressEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::Quantization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Int8 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hnsw_index::Quantization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::Quantization>
+    for crate::api::hnsw_index::Quantization
+{
+    fn into_into_dart(self) -> crate::api::hnsw_index::Quantization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_decompose::QueryDecomposition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.original.into_into_dart().into_dart(),
+            self.sub_queries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_decompose::QueryDecomposition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_decompose::QueryDecomposition>
+    for crate::api::query_decompose::QueryDecomposition
+{
+    fn into_into_dart(self) -> crate::api::query_decompose::QueryDecomposition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_keywords::QueryKeyword {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.doc_freq.into_into_dart().into_dart(),
+            self.in_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_keywords::QueryKeyword
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_keywords::QueryKeyword>
+    for crate::api::query_keywords::QueryKeyword
+{
+    fn into_into_dart(self) -> crate::api::query_keywords::QueryKeyword {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_normalize::QueryNormalizationConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.unicode_nfkc.into_into_dart().into_dart(),
+            self.strip_punctuation.into_into_dart().into_dart(),
+            self.lowercase.into_into_dart().into_dart(),
+            self.remove_stopwords.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_normalize::QueryNormalizationConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_normalize::QueryNormalizationConfig>
+    for crate::api::query_normalize::QueryNormalizationConfig
+{
+    fn into_into_dart(self) -> crate::api::query_normalize::QueryNormalizationConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_history::QuerySuggestion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.query.into_into_dart().into_dart(),
+            self.use_count.into_into_dart().into_dart(),
+            self.last_used_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_history::QuerySuggestion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_history::QuerySuggestion>
+    for crate::api::query_history::QuerySuggestion
+{
+    fn into_into_dart(self) -> crate::api::query_history::QuerySuggestion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::RagError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::RagError::DatabaseError(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::IoError(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::ModelLoadError(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::InvalidInput(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::InternalError(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::Unknown(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::DatabaseBusy(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::NotInitialized(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RagError::ParseError(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::RagError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::RagError>
+    for crate::api::error::RagError
+{
+    fn into_into_dart(self) -> crate::api::error::RagError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::RankedHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.doc_id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.vector_rank.into_into_dart().into_dart(),
+            self.bm25_rank.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::RankedHit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::RankedHit>
+    for crate::api::hybrid_search::RankedHit
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::RankedHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::reembedding::ReembeddingJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.model_name.into_into_dart().into_dart(),
+            self.batch_size.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.last_chunk_id.into_into_dart().into_dart(),
+            self.dimension.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::reembedding::ReembeddingJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::reembedding::ReembeddingJob>
+    for crate::api::reembedding::ReembeddingJob
+{
+    fn into_into_dart(self) -> crate::api::reembedding::ReembeddingJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::retrieval_state::RetrievalCitation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_id.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::retrieval_state::RetrievalCitation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::retrieval_state::RetrievalCitation>
+    for crate::api::retrieval_state::RetrievalCitation
+{
+    fn into_into_dart(self) -> crate::api::retrieval_state::RetrievalCitation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::intent_presets::RetrievalPreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.top_k.into_into_dart().into_dart(),
+            self.vector_weight.into_into_dart().into_dart(),
+            self.bm25_weight.into_into_dart().into_dart(),
+            self.boost_chunk_type.into_into_dart().into_dart(),
+            self.chunk_type_boost.into_into_dart().into_dart(),
+            self.use_mmr.into_into_dart().into_dart(),
+            self.mmr_lambda.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::intent_presets::RetrievalPreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::intent_presets::RetrievalPreset>
+    for crate::api::intent_presets::RetrievalPreset
+{
+    fn into_into_dart(self) -> crate::api::intent_presets::RetrievalPreset {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_rewrite::RewrittenQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.original.into_into_dart().into_dart(),
+            self.rewritten.into_into_dart().into_dart(),
+            self.was_rewritten.into_into_dart().into_dart(),
+            self.antecedent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_rewrite::RewrittenQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_rewrite::RewrittenQuery>
+    for crate::api::query_rewrite::RewrittenQuery
+{
+    fn into_into_dart(self) -> crate::api::query_rewrite::RewrittenQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::RrfConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.k.into_into_dart().into_dart(),
+            self.vector_weight.into_into_dart().into_dart(),
+            self.bm25_weight.into_into_dart().into_dart(),
+            self.type_boosts.into_into_dart().into_dart(),
+            self.recency_half_life_days.into_into_dart().into_dart(),
+            self.recency_weight.into_into_dart().into_dart(),
+            self.fusion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::RrfConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::RrfConfig>
+    for crate::api::hybrid_search::RrfConfig
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::RrfConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::ScoreNormalization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::MinMax => 0.into_dart(),
+            Self::ZScore => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::ScoreNormalization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::ScoreNormalization>
+    for crate::api::hybrid_search::ScoreNormalization
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::ScoreNormalization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_trace::SearchDiagnostics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.vector_ms.into_into_dart().into_dart(),
+            self.bm25_ms.into_into_dart().into_dart(),
+            self.filter_ms.into_into_dart().into_dart(),
+            self.fusion_ms.into_into_dart().into_dart(),
+            self.fetch_ms.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+            self.candidate_k.into_into_dart().into_dart(),
+            self.vector_candidates.into_into_dart().into_dart(),
+            self.bm25_candidates.into_into_dart().into_dart(),
+            self.filtered_vector_candidates.into_into_dart().into_dart(),
+            self.filtered_bm25_candidates.into_into_dart().into_dart(),
+            self.exact_scan.into_into_dart().into_dart(),
+            self.graph_filter.into_into_dart().into_dart(),
+            self.hnsw_loaded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_trace::SearchDiagnostics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_trace::SearchDiagnostics>
+    for crate::api::search_trace::SearchDiagnostics
+{
+    fn into_into_dart(self) -> crate::api::search_trace::SearchDiagnostics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_analytics::SearchFeedbackAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Clicked => 0.into_dart(),
+            Self::Accepted => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_analytics::SearchFeedbackAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_analytics::SearchFeedbackAction>
+    for crate::api::search_analytics::SearchFeedbackAction
+{
+    fn into_into_dart(self) -> crate::api::search_analytics::SearchFeedbackAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::SearchFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_ids.into_into_dart().into_dart(),
+            self.metadata_like.into_into_dart().into_dart(),
+            self.entity.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.chunk_types.into_into_dart().into_dart(),
+            self.metadata_equals.into_into_dart().into_dart(),
+            self.created_after.into_into_dart().into_dart(),
+            self.created_before.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::SearchFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::SearchFilter>
+    for crate::api::hybrid_search::SearchFilter
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::SearchFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::snippets::SearchSnippet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.highlights.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::snippets::SearchSnippet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::snippets::SearchSnippet>
+    for crate::api::snippets::SearchSnippet
+{
+    fn into_into_dart(self) -> crate::api::snippets::SearchSnippet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_trace::SearchSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_trace::SearchSpan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_trace::SearchSpan>
+    for crate::api::search_trace::SearchSpan
+{
+    fn into_into_dart(self) -> crate::api::search_trace::SearchSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_trace::SearchTrace {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spans.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_trace::SearchTrace
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_trace::SearchTrace>
+    for crate::api::search_trace::SearchTrace
+{
+    fn into_into_dart(self) -> crate::api::search_trace::SearchTrace {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::SemanticChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.start_pos.into_into_dart().into_dart(),
+            self.end_pos.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::SemanticChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::SemanticChunk>
+    for crate::api::semantic_chunker::SemanticChunk
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::SemanticChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::snapshot::SnapshotFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::snapshot::SnapshotFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::snapshot::SnapshotFile>
+    for crate::api::snapshot::SnapshotFile
+{
+    fn into_into_dart(self) -> crate::api::snapshot::SnapshotFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::snapshot::SnapshotManifest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format_version.into_into_dart().into_dart(),
+            self.engine_version.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.tenants.into_into_dart().into_dart(),
+            self.source_count.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::snapshot::SnapshotManifest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::snapshot::SnapshotManifest>
+    for crate::api::snapshot::SnapshotManifest
+{
+    fn into_into_dart(self) -> crate::api::snapshot::SnapshotManifest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::corpus_report::SourceCorpusStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+            self.min_chunk_chars.into_into_dart().into_dart(),
+            self.max_chunk_chars.into_into_dart().into_dart(),
+            self.avg_chunk_chars.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::corpus_report::SourceCorpusStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::corpus_report::SourceCorpusStats>
+    for crate::api::corpus_report::SourceCorpusStats
+{
+    fn into_into_dart(self) -> crate::api::corpus_report::SourceCorpusStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::SourceEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::SourceEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::SourceEntry>
+    for crate::api::source_rag::SourceEntry
+{
+    fn into_into_dart(self) -> crate::api::source_rag::SourceEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_grouping::SourceGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_id.into_into_dart().into_dart(),
+            self.is_document.into_into_dart().into_dart(),
+            self.source_name.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.chunks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_grouping::SourceGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_grouping::SourceGroup>
+    for crate::api::source_grouping::SourceGroup
+{
+    fn into_into_dart(self) -> crate::api::source_grouping::SourceGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_index::SourceIndexConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.max_cached.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_index::SourceIndexConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_index::SourceIndexConfig>
+    for crate::api::source_index::SourceIndexConfig
+{
+    fn into_into_dart(self) -> crate::api::source_index::SourceIndexConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::source_rag::SourceStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_count.into_into_dart().into_dart(),
+            self.chunk_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::source_rag::SourceStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::SourceStats>
+    for crate::api::source_rag::SourceStats
+{
+    fn into_into_dart(self) -> crate::api::source_rag::SourceStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::staged_ingest::SourceUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.staging_id.into_into_dart().into_dart(),
+            self.chunks.into_into_dart().into_dart(),
+            self.kept_chunks.into_into_dart().into_dart(),
+            self.removed_chunks.into_into_dart().into_dart(),
+            self.skipped_chunks.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::staged_ingest::SourceUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::staged_ingest::SourceUpdate>
+    for crate::api::staged_ingest::SourceUpdate
+{
+    fn into_into_dart(self) -> crate::api::staged_ingest::SourceUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::staged_ingest::StagedIngest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.staging_id.into_into_dart().into_dart(),
+            self.chunks.into_into_dart().into_dart(),
+            self.skipped_chunks.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::staged_ingest::StagedIngest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::staged_ingest::StagedIngest>
+    for crate::api::staged_ingest::StagedIngest
+{
+    fn into_into_dart(self) -> crate::api::staged_ingest::StagedIngest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_store::StoreSearchHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_store::StoreSearchHit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_store::StoreSearchHit>
+    for crate::api::chunk_store::StoreSearchHit
+{
+    fn into_into_dart(self) -> crate::api::chunk_store::StoreSearchHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chunk_store::StoredChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.embedding.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chunk_store::StoredChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chunk_store::StoredChunk>
+    for crate::api::chunk_store::StoredChunk
+{
+    fn into_into_dart(self) -> crate::api::chunk_store::StoredChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::StructuredChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.header_path.into_into_dart().into_dart(),
+            self.chunk_type.into_into_dart().into_dart(),
+            self.start_pos.into_into_dart().into_dart(),
+            self.end_pos.into_into_dart().into_dart(),
+            self.batch_id.into_into_dart().into_dart(),
+            self.batch_index.into_into_dart().into_dart(),
+            self.batch_total.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::semantic_chunker::StructuredChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::StructuredChunk>
+    for crate::api::semantic_chunker::StructuredChunk
+{
+    fn into_into_dart(self) -> crate::api::semantic_chunker::StructuredChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::synonyms::SynonymEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.term.into_into_dart().into_dart(),
+            self.expansions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::synonyms::SynonymEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::synonyms::SynonymEntry>
+    for crate::api::synonyms::SynonymEntry
+{
+    fn into_into_dart(self) -> crate::api::synonyms::SynonymEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::TracedHybridSearch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.results.into_into_dart().into_dart(),
+            self.trace.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::hybrid_search::TracedHybridSearch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::TracedHybridSearch>
+    for crate::api::hybrid_search::TracedHybridSearch
+{
+    fn into_into_dart(self) -> crate::api::hybrid_search::TracedHybridSearch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::user_intent::UserIntent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::user_intent::UserIntent::Summary { query } => {
+                [0.into_dart(), query.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::user_intent::UserIntent::Define { term } => {
+                [1.into_dart(), term.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::user_intent::UserIntent::ExpandKnowledge { query } => {
+                [2.into_dart(), query.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::user_intent::UserIntent::General { query } => {
+                [3.into_dart(), query.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::user_intent::UserIntent::InvalidCommand { command, reason } => [
+                4.into_dart(),
+                command.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::user_intent::UserIntent::Compare { left, right } => [
+                5.into_dart(),
+                left.into_into_dart().into_dart(),
+                right.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::user_intent::UserIntent::Cite { query } => {
+                [6.into_dart(), query.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::user_intent::UserIntent::Sources { query } => {
+                [7.into_dart(), query.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::user_intent::UserIntent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::user_intent::UserIntent>
+    for crate::api::user_intent::UserIntent
+{
+    fn into_into_dart(self) -> crate::api::user_intent::UserIntent {
+        self
+    }
+}
 
-// Section: dart2rust
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
 
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for MemoryStore {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+impl SseEncode for flutter_rust_bridge::DartOpaque {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for std::collections::HashMap<String, f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, f64)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for crate::api::simple_rag::AddDocumentResult {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_success = <bool>::sse_decode(deserializer);
-        let mut var_isDuplicate = <bool>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::simple_rag::AddDocumentResult {
-            success: var_success,
-            is_duplicate: var_isDuplicate,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::source_rag::AddSourceResult {
+impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourceId = <i64>::sse_decode(deserializer);
-        let mut var_isDuplicate = <bool>::sse_decode(deserializer);
-        let mut var_chunkCount = <i32>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::source_rag::AddSourceResult {
-            source_id: var_sourceId,
-            is_duplicate: var_isDuplicate,
-            chunk_count: var_chunkCount,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::bm25_search::Bm25SearchResult {
+impl SseEncode
+    for StreamSink<
+        crate::api::hybrid_search::HybridSearchEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_docId = <i64>::sse_decode(deserializer);
-        let mut var_score = <f64>::sse_decode(deserializer);
-        return crate::api::bm25_search::Bm25SearchResult {
-            doc_id: var_docId,
-            score: var_score,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode
+    for StreamSink<
+        crate::api::progress::ProgressEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::incremental_index::BufferStats {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_bufferSize = <usize>::sse_decode(deserializer);
-        let mut var_threshold = <usize>::sse_decode(deserializer);
-        let mut var_hnswLoaded = <bool>::sse_decode(deserializer);
-        return crate::api::incremental_index::BufferStats {
-            buffer_size: var_bufferSize,
-            threshold: var_threshold,
-            hnsw_loaded: var_hnswLoaded,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for crate::api::source_rag::ChunkData {
+impl SseEncode for crate::api::simple_rag::AddDocumentResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
-        let mut var_startPos = <i32>::sse_decode(deserializer);
-        let mut var_endPos = <i32>::sse_decode(deserializer);
-        let mut var_chunkType = <String>::sse_decode(deserializer);
-        let mut var_embedding = <Vec<f32>>::sse_decode(deserializer);
-        return crate::api::source_rag::ChunkData {
-            content: var_content,
-            chunk_index: var_chunkIndex,
-            start_pos: var_startPos,
-            end_pos: var_endPos,
-            chunk_type: var_chunkType,
-            embedding: var_embedding,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.success, serializer);
+        <bool>::sse_encode(self.is_duplicate, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::api::source_rag::ChunkForReembedding {
+impl SseEncode for crate::api::source_rag::AddSourceResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_chunkId = <i64>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        return crate::api::source_rag::ChunkForReembedding {
-            chunk_id: var_chunkId,
-            content: var_content,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.source_id, serializer);
+        <bool>::sse_encode(self.is_duplicate, serializer);
+        <i32>::sse_encode(self.chunk_count, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::api::source_rag::ChunkSearchResult {
+impl SseEncode for crate::api::audit::AuditIssue {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_chunkId = <i64>::sse_decode(deserializer);
-        let mut var_sourceId = <i64>::sse_decode(deserializer);
-        let mut var_chunkIndex = <i32>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_chunkType = <String>::sse_decode(deserializer);
-        let mut var_similarity = <f64>::sse_decode(deserializer);
-        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
-        return crate::api::source_rag::ChunkSearchResult {
-            chunk_id: var_chunkId,
-            source_id: var_sourceId,
-            chunk_index: var_chunkIndex,
-            content: var_content,
-            chunk_type: var_chunkType,
-            similarity: var_similarity,
-            metadata: var_metadata,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.severity, serializer);
+        <String>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<i64>>::sse_encode(self.source_id, serializer);
+        <Option<i64>>::sse_encode(self.chunk_id, serializer);
     }
 }
 
-impl SseDecode for crate::api::semantic_chunker::ChunkType {
+impl SseEncode for crate::api::audit::AuditReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::semantic_chunker::ChunkType::Definition,
-            1 => crate::api::semantic_chunker::ChunkType::Example,
-            2 => crate::api::semantic_chunker::ChunkType::List,
-            3 => crate::api::semantic_chunker::ChunkType::Procedure,
-            4 => crate::api::semantic_chunker::ChunkType::Comparison,
-            5 => crate::api::semantic_chunker::ChunkType::General,
-            _ => unreachable!("Invalid variant for ChunkType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <u64>::sse_encode(self.sources_checked, serializer);
+        <u64>::sse_encode(self.chunks_checked, serializer);
+        <Vec<crate::api::audit::AuditIssue>>::sse_encode(self.issues, serializer);
+        <u32>::sse_encode(self.issue_count, serializer);
+        <Option<String>>::sse_encode(self.max_severity, serializer);
     }
 }
 
-impl SseDecode for crate::api::compression_utils::CompressedText {
+impl SseEncode for crate::api::background_tasks::BackgroundTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_text = <String>::sse_decode(deserializer);
-        let mut var_originalChars = <i32>::sse_decode(deserializer);
-        let mut var_compressedChars = <i32>::sse_decode(deserializer);
-        let mut var_ratio = <f64>::sse_decode(deserializer);
-        let mut var_sentencesRemoved = <i32>::sse_decode(deserializer);
-        let mut var_charsSavedStopwords = <i32>::sse_decode(deserializer);
-        let mut var_charsSavedTruncation = <i32>::sse_decode(deserializer);
-        return crate::api::compression_utils::CompressedText {
-            text: var_text,
-            original_chars: var_originalChars,
-            compressed_chars: var_compressedChars,
-            ratio: var_ratio,
-            sentences_removed: var_sentencesRemoved,
-            chars_saved_stopwords: var_charsSavedStopwords,
-            chars_saved_truncation: var_charsSavedTruncation,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <crate::api::background_tasks::BackgroundTaskKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.tenant_id, serializer);
+        <String>::sse_encode(self.state, serializer);
+        <Option<String>>::sse_encode(self.detail, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <i64>::sse_encode(self.submitted_at, serializer);
+        <Option<i64>>::sse_encode(self.started_at, serializer);
+        <Option<i64>>::sse_encode(self.finished_at, serializer);
     }
 }
 
-impl SseDecode for crate::api::compression_utils::CompressionOptions {
+impl SseEncode for crate::api::background_tasks::BackgroundTaskKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_removeStopwords = <bool>::sse_decode(deserializer);
-        let mut var_removeDuplicates = <bool>::sse_decode(deserializer);
-        let mut var_language = <String>::sse_decode(deserializer);
-        let mut var_level = <i32>::sse_decode(deserializer);
-        return crate::api::compression_utils::CompressionOptions {
-            remove_stopwords: var_removeStopwords,
-            remove_duplicates: var_removeDuplicates,
-            language: var_language,
-            level: var_level,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::background_tasks::BackgroundTaskKind::IndexRebuild => 0,
+                crate::api::background_tasks::BackgroundTaskKind::BufferMerge => 1,
+                crate::api::background_tasks::BackgroundTaskKind::SnapshotExport => 2,
+                crate::api::background_tasks::BackgroundTaskKind::Reembedding => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::hnsw_index::EmbeddingPoint {
+impl SseEncode for crate::api::benchmark::BenchmarkConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_embedding = <Vec<f32>>::sse_decode(deserializer);
-        let mut var_norm = <f32>::sse_decode(deserializer);
-        return crate::api::hnsw_index::EmbeddingPoint {
-            id: var_id,
-            embedding: var_embedding,
-            norm: var_norm,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<Vec<u8>>>::sse_encode(self.document_bytes, serializer);
+        <u32>::sse_encode(self.synthetic_paragraphs, serializer);
+        <i32>::sse_encode(self.chunk_max_chars, serializer);
+        <u32>::sse_encode(self.embedding_dim, serializer);
+        <u32>::sse_encode(self.search_iterations, serializer);
+        <u32>::sse_encode(self.top_k, serializer);
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for crate::api::benchmark::BenchmarkReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::benchmark::BenchmarkStage>>::sse_encode(self.stages, serializer);
+        <u32>::sse_encode(self.document_chars, serializer);
+        <u32>::sse_encode(self.chunk_count, serializer);
+        <u32>::sse_encode(self.token_count, serializer);
+        <f64>::sse_encode(self.search_mean_ms, serializer);
+        <f64>::sse_encode(self.search_p95_ms, serializer);
+        <Option<u64>>::sse_encode(self.peak_rss_kb, serializer);
+        <f64>::sse_encode(self.total_ms, serializer);
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for crate::api::benchmark::BenchmarkStage {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <f64>::sse_encode(self.duration_ms, serializer);
+        <bool>::sse_encode(self.skipped, serializer);
+        <Option<u64>>::sse_encode(self.rss_kb, serializer);
     }
 }
 
-impl SseDecode for crate::api::hnsw_index::HnswSearchResult {
+impl SseEncode for crate::api::bm25_search::Bm25Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_distance = <f32>::sse_decode(deserializer);
-        return crate::api::hnsw_index::HnswSearchResult {
-            id: var_id,
-            distance: var_distance,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.k1, serializer);
+        <f64>::sse_encode(self.b, serializer);
+        <crate::api::bm25_search::Bm25Variant>::sse_encode(self.variant, serializer);
     }
 }
 
-impl SseDecode for crate::api::hybrid_search::HybridSearchResult {
+impl SseEncode for crate::api::fuzzy_terms::Bm25FuzzyConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_docId = <i64>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_score = <f64>::sse_decode(deserializer);
-        let mut var_vectorRank = <u32>::sse_decode(deserializer);
-        let mut var_bm25Rank = <u32>::sse_decode(deserializer);
-        let mut var_sourceId = <i64>::sse_decode(deserializer);
-        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
-        let mut var_chunkIndex = <u32>::sse_decode(deserializer);
-        return crate::api::hybrid_search::HybridSearchResult {
-            doc_id: var_docId,
-            content: var_content,
-            score: var_score,
-            vector_rank: var_vectorRank,
-            bm25_rank: var_bm25Rank,
-            source_id: var_sourceId,
-            metadata: var_metadata,
-            chunk_index: var_chunkIndex,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_edits, serializer);
+        <u32>::sse_encode(self.min_term_chars, serializer);
+        <u32>::sse_encode(self.max_expansions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::bm25_search::Bm25IndexOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::bm25_search::Bm25Tokenization>::sse_encode(self.tokenization, serializer);
+        <Option<String>>::sse_encode(self.stemmer, serializer);
+        <bool>::sse_encode(self.strip_korean_particles, serializer);
+        <bool>::sse_encode(self.remove_stopwords, serializer);
+    }
+}
+
+impl SseEncode for crate::api::bm25_search::Bm25SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.doc_id, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::api::bm25_search::Bm25Tokenization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::bm25_search::Bm25Tokenization::Words => 0,
+                crate::api::bm25_search::Bm25Tokenization::Whitespace => 1,
+                crate::api::bm25_search::Bm25Tokenization::CjkBigrams => 2,
+                crate::api::bm25_search::Bm25Tokenization::CjkTrigrams => 3,
+                crate::api::bm25_search::Bm25Tokenization::Mixed => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::bm25_search::Bm25Variant {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::bm25_search::Bm25Variant::Bm25 => 0,
+                crate::api::bm25_search::Bm25Variant::Bm25Plus => 1,
+                crate::api::bm25_search::Bm25Variant::Bm25L => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for crate::api::incremental_index::IncrementalSearchResult {
+impl SseEncode for crate::api::incremental_index::BufferStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_docId = <i64>::sse_decode(deserializer);
-        let mut var_distance = <f32>::sse_decode(deserializer);
-        let mut var_source = <String>::sse_decode(deserializer);
-        return crate::api::incremental_index::IncrementalSearchResult {
-            doc_id: var_docId,
-            distance: var_distance,
-            source: var_source,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.buffer_size, serializer);
+        <usize>::sse_encode(self.threshold, serializer);
+        <bool>::sse_encode(self.hnsw_loaded, serializer);
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for crate::api::incremental_index::BufferTable {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::incremental_index::BufferTable::Chunks => 0,
+                crate::api::incremental_index::BufferTable::Docs => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<crate::api::bm25_search::Bm25SearchResult> {
+impl SseEncode for crate::api::source_rag::ChunkData {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::bm25_search::Bm25SearchResult>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <i32>::sse_encode(self.chunk_index, serializer);
+        <i32>::sse_encode(self.start_pos, serializer);
+        <i32>::sse_encode(self.end_pos, serializer);
+        <String>::sse_encode(self.chunk_type, serializer);
+        <Vec<f32>>::sse_encode(self.embedding, serializer);
+        <Option<String>>::sse_encode(self.batch_id, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::source_rag::ChunkData> {
+impl SseEncode for crate::api::source_rag::ChunkForReembedding {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::source_rag::ChunkData>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.chunk_id, serializer);
+        <String>::sse_encode(self.content, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::source_rag::ChunkForReembedding> {
+impl SseEncode for crate::api::chunk_index_store::ChunkIndexSource {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::source_rag::ChunkForReembedding>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::chunk_index_store::ChunkIndexSource::Memory => 0,
+                crate::api::chunk_index_store::ChunkIndexSource::Disk => 1,
+                crate::api::chunk_index_store::ChunkIndexSource::Rebuilt => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<crate::api::source_rag::ChunkSearchResult> {
+impl SseEncode for crate::api::source_rag::ChunkMeta {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::source_rag::ChunkSearchResult>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <i32>::sse_encode(self.chunk_index, serializer);
+        <i32>::sse_encode(self.start_pos, serializer);
+        <i32>::sse_encode(self.end_pos, serializer);
+        <String>::sse_encode(self.chunk_type, serializer);
+        <Option<String>>::sse_encode(self.batch_id, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::hnsw_index::HnswSearchResult> {
+impl SseEncode for crate::api::source_rag::ChunkSearchPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::hnsw_index::HnswSearchResult>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::source_rag::ChunkSearchResult>>::sse_encode(self.results, serializer);
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::hybrid_search::HybridSearchResult> {
+impl SseEncode for crate::api::source_rag::ChunkSearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::hybrid_search::HybridSearchResult>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.chunk_id, serializer);
+        <i64>::sse_encode(self.source_id, serializer);
+        <i32>::sse_encode(self.chunk_index, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <String>::sse_encode(self.chunk_type, serializer);
+        <Option<String>>::sse_encode(self.header_path, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
+        <Option<String>>::sse_encode(self.metadata, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::incremental_index::IncrementalSearchResult> {
+impl SseEncode for crate::api::semantic_chunker::ChunkStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::incremental_index::IncrementalSearchResult>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.chunk_count, serializer);
+        <u32>::sse_encode(self.min_size, serializer);
+        <u32>::sse_encode(self.max_size, serializer);
+        <f64>::sse_encode(self.avg_size, serializer);
+        <f64>::sse_encode(self.median_size, serializer);
+        <Vec<crate::api::corpus_report::CorpusCount>>::sse_encode(self.type_histogram, serializer);
+        <u32>::sse_encode(self.oversize_count, serializer);
     }
 }
 
-impl SseDecode for Vec<f32> {
+impl SseEncode for crate::api::semantic_chunker::ChunkType {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::semantic_chunker::ChunkType::Definition => 0,
+                crate::api::semantic_chunker::ChunkType::Example => 1,
+                crate::api::semantic_chunker::ChunkType::List => 2,
+                crate::api::semantic_chunker::ChunkType::Procedure => 3,
+                crate::api::semantic_chunker::ChunkType::Comparison => 4,
+                crate::api::semantic_chunker::ChunkType::General => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<i64> {
+impl SseEncode for crate::api::semantic_chunker::ChunkingConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i64>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.max_chars, serializer);
+        <i32>::sse_encode(self.min_chars, serializer);
+        <i32>::sse_encode(self.overlap, serializer);
+        <crate::api::semantic_chunker::ChunkingStrategy>::sse_encode(self.strategy, serializer);
+        <bool>::sse_encode(self.preserve_tables, serializer);
+        <bool>::sse_encode(self.preserve_code, serializer);
+        <Vec<String>>::sse_encode(self.sentence_terminators, serializer);
+        <u32>::sse_encode(self.section_summary_sentences, serializer);
+        <bool>::sse_encode(self.linearize_tables, serializer);
+        <bool>::sse_encode(self.keep_list_intro, serializer);
     }
 }
 
-impl SseDecode for Vec<u32> {
+impl SseEncode for crate::api::semantic_chunker::ChunkingStrategy {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u32>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::semantic_chunker::ChunkingStrategy::Recursive => 0,
+                crate::api::semantic_chunker::ChunkingStrategy::Markdown => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::classification_rules::ClassificationRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.language, serializer);
+        <Vec<String>>::sse_encode(self.patterns, serializer);
+        <u32>::sse_encode(self.min_matches, serializer);
     }
 }
 
-impl SseDecode for Vec<(i64, Vec<f32>)> {
+impl SseEncode for crate::api::hybrid_search::CompareSearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i64, Vec<f32>)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.subject_index, serializer);
+        <crate::api::hybrid_search::HybridSearchResult>::sse_encode(self.result, serializer);
     }
 }
 
-impl SseDecode for Vec<(i64, String)> {
+impl SseEncode for crate::api::compression_utils::CompressedText {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i64, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <i32>::sse_encode(self.original_chars, serializer);
+        <i32>::sse_encode(self.compressed_chars, serializer);
+        <f64>::sse_encode(self.ratio, serializer);
+        <i32>::sse_encode(self.sentences_removed, serializer);
+        <i32>::sse_encode(self.chars_saved_stopwords, serializer);
+        <i32>::sse_encode(self.chars_saved_truncation, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::semantic_chunker::SemanticChunk> {
+impl SseEncode for crate::api::compression_utils::CompressionOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::semantic_chunker::SemanticChunk>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.remove_stopwords, serializer);
+        <bool>::sse_encode(self.remove_duplicates, serializer);
+        <String>::sse_encode(self.language, serializer);
+        <i32>::sse_encode(self.level, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::source_rag::SourceEntry> {
+impl SseEncode for crate::api::corpus_report::CorpusCount {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::source_rag::SourceEntry>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <u64>::sse_encode(self.count, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::semantic_chunker::StructuredChunk> {
+impl SseEncode for crate::api::corpus_report::CorpusReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::semantic_chunker::StructuredChunk>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.source_count, serializer);
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <u64>::sse_encode(self.total_chars, serializer);
+        <u64>::sse_encode(self.vocabulary_size, serializer);
+        <Vec<crate::api::corpus_report::SourceCorpusStats>>::sse_encode(self.sources, serializer);
+        <Vec<crate::api::corpus_report::LengthBucket>>::sse_encode(
+            self.chunk_length_histogram,
+            serializer,
+        );
+        <Vec<crate::api::corpus_report::CorpusCount>>::sse_encode(self.chunk_types, serializer);
+        <Vec<crate::api::corpus_report::CorpusCount>>::sse_encode(self.languages, serializer);
+        <crate::api::corpus_report::IndexFreshness>::sse_encode(self.index, serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::api::db_retry::DbRetryConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_attempts, serializer);
+        <u64>::sse_encode(self.base_delay_ms, serializer);
+        <u64>::sse_encode(self.max_delay_ms, serializer);
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseEncode for crate::api::chunk_dedup::DedupeConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.near_duplicate_threshold, serializer);
+        <u32>::sse_encode(self.shingle_size, serializer);
+        <bool>::sse_encode(self.exact_only, serializer);
     }
 }
 
-impl SseDecode for Option<(u32, u32, u32)> {
+impl SseEncode for crate::api::chunk_dedup::DedupeReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<(u32, u32, u32)>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.kept, serializer);
+        <Vec<crate::api::chunk_dedup::DuplicateChunk>>::sse_encode(self.duplicates, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::hybrid_search::RrfConfig> {
+impl SseEncode for crate::api::hnsw_index::DistanceMetric {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::hybrid_search::RrfConfig>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::hnsw_index::DistanceMetric::Cosine => 0,
+                crate::api::hnsw_index::DistanceMetric::DotProduct => 1,
+                crate::api::hnsw_index::DistanceMetric::L2 => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Option<crate::api::hybrid_search::SearchFilter> {
+impl SseEncode for crate::api::chunk_dedup::DuplicateChunk {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::hybrid_search::SearchFilter>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <u32>::sse_encode(self.duplicate_of, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
     }
 }
 
-impl SseDecode for Option<Vec<i64>> {
+impl SseEncode for crate::api::source_rag::EmbeddingBatchProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<i64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseDecode for crate::api::user_intent::ParsedIntent {
+impl SseEncode for crate::api::hnsw_index::EmbeddingPoint {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_intentType = <String>::sse_decode(deserializer);
-        let mut var_query = <String>::sse_decode(deserializer);
-        let mut var_isValid = <bool>::sse_decode(deserializer);
-        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
-        return crate::api::user_intent::ParsedIntent {
-            intent_type: var_intentType,
-            query: var_query,
-            is_valid: var_isValid,
-            error_message: var_errorMessage,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <Vec<f32>>::sse_encode(self.embedding, serializer);
+        <f32>::sse_encode(self.norm, serializer);
     }
 }
 
-impl SseDecode for crate::api::error::RagError {
+impl SseEncode for crate::api::embedding_storage::EmbeddingStorageStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::DatabaseError(var_field0);
-            }
-            1 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::IoError(var_field0);
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::ModelLoadError(var_field0);
-            }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::InvalidInput(var_field0);
-            }
-            4 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::InternalError(var_field0);
-            }
-            5 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::error::RagError::Unknown(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.f32_chunks, serializer);
+        <i64>::sse_encode(self.quantized_chunks, serializer);
+        <i64>::sse_encode(self.embedding_bytes, serializer);
     }
 }
 
-impl SseDecode for (i64, Vec<f32>) {
+impl SseEncode for crate::api::engine_info::EngineInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <i64>::sse_decode(deserializer);
-        let mut var_field1 = <Vec<f32>>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.crate_version, serializer);
+        <u32>::sse_encode(self.schema_version, serializer);
+        <Option<u32>>::sse_encode(self.database_schema_version, serializer);
+        <u32>::sse_encode(self.hnsw_format_version, serializer);
+        <u32>::sse_encode(self.bm25_format_version, serializer);
+        <u32>::sse_encode(self.snapshot_format_version, serializer);
+        <Option<String>>::sse_encode(self.tokenizer_name, serializer);
+        <Option<String>>::sse_encode(self.embedding_model_name, serializer);
+        <Option<u32>>::sse_encode(self.embedding_dimension, serializer);
+        <String>::sse_encode(self.active_tenant, serializer);
+        <Vec<String>>::sse_encode(self.active_features, serializer);
+        <Vec<String>>::sse_encode(self.build_features, serializer);
     }
 }
 
-impl SseDecode for (i64, String) {
+impl SseEncode for crate::api::metrics::EngineMetrics {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <i64>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.searches_executed, serializer);
+        <u64>::sse_encode(self.cache_hits, serializer);
+        <u64>::sse_encode(self.cache_misses, serializer);
+        <u64>::sse_encode(self.index_rebuilds, serializer);
+        <u64>::sse_encode(self.db_errors, serializer);
+        <u64>::sse_encode(self.panics, serializer);
+        <f64>::sse_encode(self.avg_search_latency_ms, serializer);
+        <Vec<crate::api::metrics::LatencyBucket>>::sse_encode(
+            self.search_latency_histogram,
+            serializer,
+        );
     }
 }
 
-impl SseDecode for (u32, u32, u32) {
+impl SseEncode for crate::api::entity_extraction::EntityFacet {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <u32>::sse_decode(deserializer);
-        let mut var_field1 = <u32>::sse_decode(deserializer);
-        let mut var_field2 = <u32>::sse_decode(deserializer);
-        return (var_field0, var_field1, var_field2);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.entity_type, serializer);
+        <String>::sse_encode(self.normalized, serializer);
+        <u32>::sse_encode(self.chunk_count, serializer);
     }
 }
 
-impl SseDecode for crate::api::hybrid_search::RrfConfig {
+impl SseEncode for crate::api::related_chunks::ExpandedSearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_k = <u32>::sse_decode(deserializer);
-        let mut var_vectorWeight = <f64>::sse_decode(deserializer);
-        let mut var_bm25Weight = <f64>::sse_decode(deserializer);
-        return crate::api::hybrid_search::RrfConfig {
-            k: var_k,
-            vector_weight: var_vectorWeight,
-            bm25_weight: var_bm25Weight,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::hybrid_search::HybridSearchResult>::sse_encode(self.result, serializer);
+        <String>::sse_encode(self.link, serializer);
+        <Option<i64>>::sse_encode(self.expanded_from, serializer);
     }
 }
 
-impl SseDecode for crate::api::hybrid_search::SearchFilter {
+impl SseEncode for crate::api::related_chunks::ExpansionConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourceIds = <Option<Vec<i64>>>::sse_decode(deserializer);
-        let mut var_metadataLike = <Option<String>>::sse_decode(deserializer);
-        return crate::api::hybrid_search::SearchFilter {
-            source_ids: var_sourceIds,
-            metadata_like: var_metadataLike,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_related_per_hit, serializer);
+        <f64>::sse_encode(self.score_decay, serializer);
+        <bool>::sse_encode(self.follow_adjacent, serializer);
+        <bool>::sse_encode(self.follow_shared_entities, serializer);
+        <bool>::sse_encode(self.follow_same_batch, serializer);
     }
 }
 
-impl SseDecode for crate::api::semantic_chunker::SemanticChunk {
+impl SseEncode for crate::api::entity_extraction::ExtractedEntity {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_index = <i32>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_startPos = <i32>::sse_decode(deserializer);
-        let mut var_endPos = <i32>::sse_decode(deserializer);
-        let mut var_chunkType = <String>::sse_decode(deserializer);
-        return crate::api::semantic_chunker::SemanticChunk {
-            index: var_index,
-            content: var_content,
-            start_pos: var_startPos,
-            end_pos: var_endPos,
-            chunk_type: var_chunkType,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.entity_type, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <String>::sse_encode(self.normalized, serializer);
     }
 }
 
-impl SseDecode for crate::api::source_rag::SourceEntry {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_createdAt = <i64>::sse_decode(deserializer);
-        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
-        let mut var_status = <Option<String>>::sse_decode(deserializer);
-        return crate::api::source_rag::SourceEntry {
-            id: var_id,
-            name: var_name,
-            created_at: var_createdAt,
-            metadata: var_metadata,
-            status: var_status,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for crate::api::source_rag::SourceStats {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourceCount = <i64>::sse_decode(deserializer);
-        let mut var_chunkCount = <i64>::sse_decode(deserializer);
-        return crate::api::source_rag::SourceStats {
-            source_count: var_sourceCount,
-            chunk_count: var_chunkCount,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for crate::api::semantic_chunker::StructuredChunk {
+impl SseEncode for crate::api::hybrid_search::FusionStrategy {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_index = <i32>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_headerPath = <String>::sse_decode(deserializer);
-        let mut var_chunkType = <String>::sse_decode(deserializer);
-        let mut var_startPos = <i32>::sse_decode(deserializer);
-        let mut var_endPos = <i32>::sse_decode(deserializer);
-        let mut var_batchId = <Option<String>>::sse_decode(deserializer);
-        let mut var_batchIndex = <Option<i32>>::sse_decode(deserializer);
-        let mut var_batchTotal = <Option<i32>>::sse_decode(deserializer);
-        return crate::api::semantic_chunker::StructuredChunk {
-            index: var_index,
-            content: var_content,
-            header_path: var_headerPath,
-            chunk_type: var_chunkType,
-            start_pos: var_startPos,
-            end_pos: var_endPos,
-            batch_id: var_batchId,
-            batch_index: var_batchIndex,
-            batch_total: var_batchTotal,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::hybrid_search::FusionStrategy::Rrf => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::hybrid_search::FusionStrategy::WeightedScore { normalization } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::hybrid_search::ScoreNormalization>::sse_encode(
+                    normalization,
+                    serializer,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::entity_extraction::GazetteerEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.term, serializer);
+        <String>::sse_encode(self.entity_type, serializer);
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::knowledge_graph::GraphEdge {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.subject, serializer);
+        <String>::sse_encode(self.relation, serializer);
+        <String>::sse_encode(self.object, serializer);
+        <i64>::sse_encode(self.chunk_id, serializer);
+        <u32>::sse_encode(self.hop, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::knowledge_graph::GraphNeighborhood {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.entity, serializer);
+        <Vec<crate::api::knowledge_graph::GraphEdge>>::sse_encode(self.edges, serializer);
+        <Vec<i64>>::sse_encode(self.chunk_ids, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::snippets::HighlightSpan {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
 }
 
-impl SseDecode for crate::api::user_intent::UserIntent {
+impl SseEncode for crate::api::hnsw_index::HnswBuildConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_query = <String>::sse_decode(deserializer);
-                return crate::api::user_intent::UserIntent::Summary { query: var_query };
-            }
-            1 => {
-                let mut var_term = <String>::sse_decode(deserializer);
-                return crate::api::user_intent::UserIntent::Define { term: var_term };
-            }
-            2 => {
-                let mut var_query = <String>::sse_decode(deserializer);
-                return crate::api::user_intent::UserIntent::ExpandKnowledge { query: var_query };
-            }
-            3 => {
-                let mut var_query = <String>::sse_decode(deserializer);
-                return crate::api::user_intent::UserIntent::General { query: var_query };
-            }
-            4 => {
-                let mut var_command = <String>::sse_decode(deserializer);
-                let mut var_reason = <String>::sse_decode(deserializer);
-                return crate::api::user_intent::UserIntent::InvalidCommand {
-                    command: var_command,
-                    reason: var_reason,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.m, serializer);
+        <u32>::sse_encode(self.max_layer, serializer);
+        <u32>::sse_encode(self.ef_construction, serializer);
     }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::hnsw_index::HnswConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_encode(self.build, serializer);
+        <Option<u32>>::sse_encode(self.ef_search, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__source_rag__add_chunks_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple_rag__add_document_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple_rag__add_document_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__source_rag__add_source_impl(port, ptr, rust_vec_len, data_len),
-        5 => {
-            wire__crate__api__bm25_search__bm25_add_document_impl(port, ptr, rust_vec_len, data_len)
-        }
-        6 => wire__crate__api__bm25_search__bm25_add_documents_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => {
-            wire__crate__api__bm25_search__bm25_clear_index_impl(port, ptr, rust_vec_len, data_len)
-        }
-        8 => wire__crate__api__bm25_search__bm25_get_document_count_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__bm25_search__bm25_remove_document_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__bm25_search__bm25_search_impl(port, ptr, rust_vec_len, data_len),
-        11 => {
-            wire__crate__api__hnsw_index__build_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
-        }
-        13 => wire__crate__api__semantic_chunker__chunk_type_as_str_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__semantic_chunker__chunk_type_from_str_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        16 => wire__crate__api__simple_rag__clear_all_documents_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__api__incremental_index__clear_buffer_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => {
-            wire__crate__api__hnsw_index__clear_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
-        }
-        19 => wire__crate__api__db_pool__close_db_pool_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__compression_utils__compress_text_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__compression_utils__compress_text_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        23 => wire__crate__api__compression_utils__compression_options_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        25 => wire__crate__api__source_rag__delete_source_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__hnsw_index__embedding_point_new_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        27 => wire__crate__api__document_parser__extract_text_from_document_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        28 => wire__crate__api__document_parser__extract_text_from_docx_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        29 => wire__crate__api__document_parser__extract_text_from_pdf_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        30 => wire__crate__api__source_rag__get_adjacent_chunks_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        31 => wire__crate__api__source_rag__get_all_chunk_ids_and_contents_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        32 => wire__crate__api__incremental_index__get_buffer_for_merge_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        33 => wire__crate__api__incremental_index__get_buffer_stats_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        34 => {
-            wire__crate__api__simple_rag__get_document_count_impl(port, ptr, rust_vec_len, data_len)
-        }
-        35 => wire__crate__api__db_pool__get_pool_stats_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__source_rag__get_source_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__source_rag__get_source_chunk_count_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        38 => {
-            wire__crate__api__source_rag__get_source_chunks_impl(port, ptr, rust_vec_len, data_len)
-        }
-        39 => {
-            wire__crate__api__source_rag__get_source_stats_impl(port, ptr, rust_vec_len, data_len)
-        }
-        42 => wire__crate__api__incremental_index__incremental_add_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        43 => wire__crate__api__incremental_index__incremental_add_batch_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        44 => wire__crate__api__incremental_index__incremental_remove_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        45 => wire__crate__api__incremental_index__incremental_search_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        46 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__simple_rag__init_db_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__db_pool__init_db_pool_impl(port, ptr, rust_vec_len, data_len),
-        50 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__source_rag__init_source_db_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__tokenizer__init_tokenizer_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__bm25_search__is_bm25_index_loaded_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        54 => wire__crate__api__source_rag__is_chunk_bm25_index_loaded_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        55 => wire__crate__api__hnsw_index__is_hnsw_index_loaded_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        56 => {
-            wire__crate__api__db_pool__is_pool_initialized_impl(port, ptr, rust_vec_len, data_len)
-        }
-        57 => wire__crate__api__source_rag__list_sources_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__hnsw_index__load_hnsw_index_impl(port, ptr, rust_vec_len, data_len),
-        60 => {
-            wire__crate__api__incremental_index__needs_merge_impl(port, ptr, rust_vec_len, data_len)
-        }
-        63 => {
-            wire__crate__api__simple_rag__rebuild_bm25_index_impl(port, ptr, rust_vec_len, data_len)
-        }
-        64 => wire__crate__api__source_rag__rebuild_chunk_bm25_index_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        65 => wire__crate__api__source_rag__rebuild_chunk_hnsw_index_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        66 => {
-            wire__crate__api__simple_rag__rebuild_hnsw_index_impl(port, ptr, rust_vec_len, data_len)
-        }
-        67 => wire__crate__api__hybrid_search__rrf_config_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        68 => wire__crate__api__hnsw_index__save_hnsw_index_impl(port, ptr, rust_vec_len, data_len),
-        69 => wire__crate__api__source_rag__search_chunks_impl(port, ptr, rust_vec_len, data_len),
-        70 => wire__crate__api__hnsw_index__search_hnsw_impl(port, ptr, rust_vec_len, data_len),
-        71 => {
-            wire__crate__api__hybrid_search__search_hybrid_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for crate::api::hnsw_index::HnswIndexStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.point_count, serializer);
+        <u32>::sse_encode(self.dimension, serializer);
+        <crate::api::hnsw_index::Quantization>::sse_encode(self.quantization, serializer);
+        <crate::api::hnsw_index::DistanceMetric>::sse_encode(self.metric, serializer);
+        <crate::api::hnsw_index::HnswBuildConfig>::sse_encode(self.build, serializer);
+        <u32>::sse_encode(self.m0, serializer);
+        <bool>::sse_encode(self.custom_build, serializer);
+        <Option<u32>>::sse_encode(self.ef_search, serializer);
+        <u64>::sse_encode(self.deleted_points, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hnsw_index::HnswSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <f32>::sse_encode(self.distance, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hybrid_search::HybridSearchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::hybrid_search::HybridSearchEvent::Ranking(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<crate::api::hybrid_search::RankedHit>>::sse_encode(field0, serializer);
+            }
+            crate::api::hybrid_search::HybridSearchEvent::Results(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            crate::api::hybrid_search::HybridSearchEvent::Done => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        72 => wire__crate__api__hybrid_search__search_hybrid_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        73 => wire__crate__api__hybrid_search__search_hybrid_weighted_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        74 => wire__crate__api__simple_rag__search_similar_impl(port, ptr, rust_vec_len, data_len),
-        77 => wire__crate__api__compression_utils__sentence_hash_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        78 => wire__crate__api__compression_utils__should_compress_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        79 => wire__crate__api__compression_utils__split_sentences_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        81 => wire__crate__api__source_rag__update_chunk_embedding_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        82 => wire__crate__api__source_rag__update_source_status_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        83 => wire__crate__api__user_intent__user_intent_get_query_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        84 => wire__crate__api__user_intent__user_intent_intent_type_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        12 => wire__crate__api__simple_rag__calculate_cosine_similarity_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        15 => wire__crate__api__semantic_chunker__classify_chunk_impl(ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__logger__close_log_stream_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__tokenizer__decode_tokens_impl(ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__tokenizer__get_vocab_size_impl(ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__logger__init_log_stream_impl(ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__semantic_chunker__markdown_chunk_impl(ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__user_intent__parse_intent_impl(ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__user_intent__parse_user_intent_impl(ptr, rust_vec_len, data_len),
-        75 => wire__crate__api__semantic_chunker__semantic_chunk_impl(ptr, rust_vec_len, data_len),
-        76 => wire__crate__api__semantic_chunker__semantic_chunk_with_overlap_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        80 => wire__crate__api__tokenizer__tokenize_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::hybrid_search::HybridSearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.ef_search, serializer);
+        <Option<f64>>::sse_encode(self.diversity_lambda, serializer);
+        <Option<String>>::sse_encode(self.collection, serializer);
+        <Option<u32>>::sse_encode(self.group_by_source, serializer);
+        <bool>::sse_encode(self.with_diagnostics, serializer);
+        <Option<u32>>::sse_encode(self.page_offset, serializer);
+        <Option<String>>::sse_encode(self.cursor, serializer);
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::hybrid_search::HybridSearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_encode(self.results, serializer);
+        <Option<Vec<crate::api::source_grouping::SourceGroup>>>::sse_encode(
+            self.groups,
+            serializer,
+        );
+        <Option<crate::api::search_trace::SearchDiagnostics>>::sse_encode(
+            self.diagnostics,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple_rag::AddDocumentResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.success.into_into_dart().into_dart(),
-            self.is_duplicate.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::hybrid_search::HybridSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.doc_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <u32>::sse_encode(self.vector_rank, serializer);
+        <u32>::sse_encode(self.bm25_rank, serializer);
+        <i64>::sse_encode(self.source_id, serializer);
+        <Option<String>>::sse_encode(self.metadata, serializer);
+        <u32>::sse_encode(self.chunk_index, serializer);
+        <String>::sse_encode(self.chunk_type, serializer);
+        <Option<String>>::sse_encode(self.header_path, serializer);
+        <Vec<String>>::sse_encode(self.matched_terms, serializer);
+        <Option<crate::api::snippets::SearchSnippet>>::sse_encode(self.snippet, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple_rag::AddDocumentResult
-{
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple_rag::AddDocumentResult>
-    for crate::api::simple_rag::AddDocumentResult
-{
-    fn into_into_dart(self) -> crate::api::simple_rag::AddDocumentResult {
-        self
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::AddSourceResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source_id.into_into_dart().into_dart(),
-            self.is_duplicate.into_into_dart().into_dart(),
-            self.chunk_count.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::incremental_index::IncrementalConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.auto_merge, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::AddSourceResult
-{
+
+impl SseEncode for crate::api::incremental_index::IncrementalSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.doc_id, serializer);
+        <f32>::sse_encode(self.distance, serializer);
+        <String>::sse_encode(self.source, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::AddSourceResult>
-    for crate::api::source_rag::AddSourceResult
-{
-    fn into_into_dart(self) -> crate::api::source_rag::AddSourceResult {
-        self
+
+impl SseEncode for crate::api::hnsw_index::IndexConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::hnsw_index::Quantization>::sse_encode(self.quantization, serializer);
+        <crate::api::hnsw_index::DistanceMetric>::sse_encode(self.metric, serializer);
+    }
+}
+
+impl SseEncode for crate::api::index_freshness::IndexConsistency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <crate::api::index_freshness::IndexDivergence>::sse_encode(self.hnsw, serializer);
+        <crate::api::index_freshness::IndexDivergence>::sse_encode(self.bm25, serializer);
+        <bool>::sse_encode(self.consistent, serializer);
+        <bool>::sse_encode(self.repaired, serializer);
+    }
+}
+
+impl SseEncode for crate::api::index_freshness::IndexDivergence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.loaded, serializer);
+        <u64>::sse_encode(self.indexed_count, serializer);
+        <Vec<i64>>::sse_encode(self.missing_ids, serializer);
+        <Vec<i64>>::sse_encode(self.extra_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::benchmark::IndexEvaluation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.sample_size, serializer);
+        <u32>::sse_encode(self.top_k, serializer);
+        <u64>::sse_encode(self.corpus_size, serializer);
+        <f64>::sse_encode(self.recall_at_k, serializer);
+        <f64>::sse_encode(self.min_recall, serializer);
+        <f64>::sse_encode(self.latency_p50_ms, serializer);
+        <f64>::sse_encode(self.latency_p95_ms, serializer);
+        <f64>::sse_encode(self.latency_p99_ms, serializer);
+        <f64>::sse_encode(self.exact_mean_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::corpus_report::IndexFreshness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.db_chunk_count, serializer);
+        <Option<u64>>::sse_encode(self.hnsw_point_count, serializer);
+        <u64>::sse_encode(self.bm25_document_count, serializer);
+        <bool>::sse_encode(self.hnsw_up_to_date, serializer);
+        <bool>::sse_encode(self.bm25_up_to_date, serializer);
+        <Option<i64>>::sse_encode(self.last_source_added_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::index_freshness::IndexStaleness {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.hnsw_stale, serializer);
+        <bool>::sse_encode(self.bm25_stale, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ingest::IngestInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::ingest::IngestInput::Text(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::ingest::IngestInput::Bytes(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::ingest_jobs::IngestJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.file_path, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.state, serializer);
+        <Option<String>>::sse_encode(self.stage, serializer);
+        <u32>::sse_encode(self.progress_done, serializer);
+        <u32>::sse_encode(self.progress_total, serializer);
+        <Option<i64>>::sse_encode(self.source_id, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ingest::IngestOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.metadata, serializer);
+        <Option<crate::api::semantic_chunker::ChunkingStrategy>>::sse_encode(
+            self.strategy,
+            serializer,
+        );
+        <i32>::sse_encode(self.max_chars, serializer);
+        <i32>::sse_encode(self.overlap_chars, serializer);
+        <bool>::sse_encode(self.rebuild_indexes, serializer);
+        <bool>::sse_encode(self.dedupe_chunks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ingest::IngestProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stage, serializer);
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <Option<i64>>::sse_encode(self.source_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ingest::IngestReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.source_id, serializer);
+        <bool>::sse_encode(self.is_duplicate, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <u32>::sse_encode(self.chunk_count, serializer);
+        <u32>::sse_encode(self.skipped_chunks, serializer);
+        <u64>::sse_encode(self.text_chars, serializer);
+        <u64>::sse_encode(self.elapsed_ms, serializer);
+    }
+}
+
+impl SseEncode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_i64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::fts_keyword::KeywordBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::fts_keyword::KeywordBackend::InMemory => 0,
+                crate::api::fts_keyword::KeywordBackend::Fts5 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::knowledge_graph::KnowledgeTriple {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.subject, serializer);
+        <String>::sse_encode(self.relation, serializer);
+        <String>::sse_encode(self.object, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::bm25_search::Bm25SearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.doc_id.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::analyzers::LanguageAnalyzer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.language, serializer);
+        <Vec<String>>::sse_encode(self.stopwords, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::bm25_search::Bm25SearchResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::bm25_search::Bm25SearchResult>
-    for crate::api::bm25_search::Bm25SearchResult
-{
-    fn into_into_dart(self) -> crate::api::bm25_search::Bm25SearchResult {
-        self
+
+impl SseEncode for crate::api::retrieval_state::LastRetrieval {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.query, serializer);
+        <Vec<crate::api::retrieval_state::RetrievalCitation>>::sse_encode(
+            self.citations,
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::BufferStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.buffer_size.into_into_dart().into_dart(),
-            self.threshold.into_into_dart().into_dart(),
-            self.hnsw_loaded.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::metrics::LatencyBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.le_ms, serializer);
+        <u64>::sse_encode(self.count, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::incremental_index::BufferStats
-{
+
+impl SseEncode for crate::api::corpus_report::LengthBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.le_chars, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::BufferStats>
-    for crate::api::incremental_index::BufferStats
-{
-    fn into_into_dart(self) -> crate::api::incremental_index::BufferStats {
-        self
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.content.into_into_dart().into_dart(),
-            self.chunk_index.into_into_dart().into_dart(),
-            self.start_pos.into_into_dart().into_dart(),
-            self.end_pos.into_into_dart().into_dart(),
-            self.chunk_type.into_into_dart().into_dart(),
-            self.embedding.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::audit::AuditIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audit::AuditIssue>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::ChunkData
-{
+
+impl SseEncode for Vec<crate::api::background_tasks::BackgroundTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::background_tasks::BackgroundTask>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkData>
-    for crate::api::source_rag::ChunkData
-{
-    fn into_into_dart(self) -> crate::api::source_rag::ChunkData {
-        self
+
+impl SseEncode for Vec<crate::api::benchmark::BenchmarkStage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::benchmark::BenchmarkStage>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkForReembedding {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.chunk_id.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::bm25_search::Bm25SearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::bm25_search::Bm25SearchResult>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::ChunkForReembedding
-{
+
+impl SseEncode for Vec<crate::api::source_rag::ChunkData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_rag::ChunkData>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkForReembedding>
-    for crate::api::source_rag::ChunkForReembedding
-{
-    fn into_into_dart(self) -> crate::api::source_rag::ChunkForReembedding {
-        self
+
+impl SseEncode for Vec<crate::api::source_rag::ChunkForReembedding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_rag::ChunkForReembedding>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::ChunkSearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.chunk_id.into_into_dart().into_dart(),
-            self.source_id.into_into_dart().into_dart(),
-            self.chunk_index.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.chunk_type.into_into_dart().into_dart(),
-            self.similarity.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::source_rag::ChunkMeta> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_rag::ChunkMeta>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::ChunkSearchResult
-{
+
+impl SseEncode for Vec<crate::api::source_rag::ChunkSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_rag::ChunkSearchResult>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::ChunkSearchResult>
-    for crate::api::source_rag::ChunkSearchResult
-{
-    fn into_into_dart(self) -> crate::api::source_rag::ChunkSearchResult {
-        self
+
+impl SseEncode for Vec<crate::api::classification_rules::ClassificationRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::classification_rules::ClassificationRule>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::ChunkType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Definition => 0.into_dart(),
-            Self::Example => 1.into_dart(),
-            Self::List => 2.into_dart(),
-            Self::Procedure => 3.into_dart(),
-            Self::Comparison => 4.into_dart(),
-            Self::General => 5.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::api::hybrid_search::CompareSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::hybrid_search::CompareSearchResult>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::semantic_chunker::ChunkType
-{
+
+impl SseEncode for Vec<crate::api::corpus_report::CorpusCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::corpus_report::CorpusCount>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::ChunkType>
-    for crate::api::semantic_chunker::ChunkType
-{
-    fn into_into_dart(self) -> crate::api::semantic_chunker::ChunkType {
-        self
+
+impl SseEncode for Vec<crate::api::chunk_dedup::DuplicateChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chunk_dedup::DuplicateChunk>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::compression_utils::CompressedText {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.text.into_into_dart().into_dart(),
-            self.original_chars.into_into_dart().into_dart(),
-            self.compressed_chars.into_into_dart().into_dart(),
-            self.ratio.into_into_dart().into_dart(),
-            self.sentences_removed.into_into_dart().into_dart(),
-            self.chars_saved_stopwords.into_into_dart().into_dart(),
-            self.chars_saved_truncation.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::entity_extraction::EntityFacet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::entity_extraction::EntityFacet>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::compression_utils::CompressedText
-{
+
+impl SseEncode for Vec<crate::api::related_chunks::ExpandedSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::related_chunks::ExpandedSearchResult>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::compression_utils::CompressedText>
-    for crate::api::compression_utils::CompressedText
-{
-    fn into_into_dart(self) -> crate::api::compression_utils::CompressedText {
-        self
+
+impl SseEncode for Vec<crate::api::entity_extraction::ExtractedEntity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::entity_extraction::ExtractedEntity>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::compression_utils::CompressionOptions {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.remove_stopwords.into_into_dart().into_dart(),
-            self.remove_duplicates.into_into_dart().into_dart(),
-            self.language.into_into_dart().into_dart(),
-            self.level.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::entity_extraction::GazetteerEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::entity_extraction::GazetteerEntry>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::compression_utils::CompressionOptions
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::compression_utils::CompressionOptions>
-    for crate::api::compression_utils::CompressionOptions
-{
-    fn into_into_dart(self) -> crate::api::compression_utils::CompressionOptions {
-        self
+
+impl SseEncode for Vec<crate::api::knowledge_graph::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::knowledge_graph::GraphEdge>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::EmbeddingPoint {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.embedding.into_into_dart().into_dart(),
-            self.norm.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::snippets::HighlightSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::snippets::HighlightSpan>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::hnsw_index::EmbeddingPoint
-{
+
+impl SseEncode for Vec<crate::api::hnsw_index::HnswSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::hnsw_index::HnswSearchResult>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::EmbeddingPoint>
-    for crate::api::hnsw_index::EmbeddingPoint
-{
-    fn into_into_dart(self) -> crate::api::hnsw_index::EmbeddingPoint {
-        self
+
+impl SseEncode for Vec<crate::api::hybrid_search::HybridSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::hybrid_search::HybridSearchResult>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::hnsw_index::HnswSearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.distance.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::incremental_index::IncrementalSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::incremental_index::IncrementalSearchResult>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::hnsw_index::HnswSearchResult
-{
+
+impl SseEncode for Vec<crate::api::ingest_jobs::IngestJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ingest_jobs::IngestJob>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::hnsw_index::HnswSearchResult>
-    for crate::api::hnsw_index::HnswSearchResult
-{
-    fn into_into_dart(self) -> crate::api::hnsw_index::HnswSearchResult {
-        self
+
+impl SseEncode for Vec<crate::api::knowledge_graph::KnowledgeTriple> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::knowledge_graph::KnowledgeTriple>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::HybridSearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.doc_id.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-            self.vector_rank.into_into_dart().into_dart(),
-            self.bm25_rank.into_into_dart().into_dart(),
-            self.source_id.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-            self.chunk_index.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::analyzers::LanguageAnalyzer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::analyzers::LanguageAnalyzer>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::hybrid_search::HybridSearchResult
-{
+
+impl SseEncode for Vec<crate::api::metrics::LatencyBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metrics::LatencyBucket>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::HybridSearchResult>
-    for crate::api::hybrid_search::HybridSearchResult
-{
-    fn into_into_dart(self) -> crate::api::hybrid_search::HybridSearchResult {
-        self
+
+impl SseEncode for Vec<crate::api::corpus_report::LengthBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::corpus_report::LengthBucket>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::incremental_index::IncrementalSearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.doc_id.into_into_dart().into_dart(),
-            self.distance.into_into_dart().into_dart(),
-            self.source.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<f32>>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::incremental_index::IncrementalSearchResult
-{
+
+impl SseEncode for Vec<crate::api::chat_memory::MemoryMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_memory::MemoryMessage>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::incremental_index::IncrementalSearchResult>
-    for crate::api::incremental_index::IncrementalSearchResult
-{
-    fn into_into_dart(self) -> crate::api::incremental_index::IncrementalSearchResult {
-        self
+
+impl SseEncode for Vec<crate::api::chat_memory::MemorySearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_memory::MemorySearchResult>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::user_intent::ParsedIntent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.intent_type.into_into_dart().into_dart(),
-            self.query.into_into_dart().into_dart(),
-            self.is_valid.into_into_dart().into_dart(),
-            self.error_message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::named_bm25::NamedBm25IndexInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::named_bm25::NamedBm25IndexInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::user_intent::ParsedIntent
-{
+
+impl SseEncode for Vec<crate::api::named_index::NamedIndexInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::named_index::NamedIndexInfo>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::user_intent::ParsedIntent>
-    for crate::api::user_intent::ParsedIntent
-{
-    fn into_into_dart(self) -> crate::api::user_intent::ParsedIntent {
-        self
+
+impl SseEncode for Vec<crate::api::panic_report::PanicEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::panic_report::PanicEvent>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::error::RagError {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::error::RagError::DatabaseError(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::RagError::IoError(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::RagError::ModelLoadError(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::RagError::InvalidInput(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::RagError::InternalError(field0) => {
-                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::error::RagError::Unknown(field0) => {
-                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::RagError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::error::RagError>
-    for crate::api::error::RagError
-{
-    fn into_into_dart(self) -> crate::api::error::RagError {
-        self
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::RrfConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.k.into_into_dart().into_dart(),
-            self.vector_weight.into_into_dart().into_dart(),
-            self.bm25_weight.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i64>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::hybrid_search::RrfConfig
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::RrfConfig>
-    for crate::api::hybrid_search::RrfConfig
-{
-    fn into_into_dart(self) -> crate::api::hybrid_search::RrfConfig {
-        self
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::hybrid_search::SearchFilter {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source_ids.into_into_dart().into_dart(),
-            self.metadata_like.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::hybrid_search::SearchFilter
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::hybrid_search::SearchFilter>
-    for crate::api::hybrid_search::SearchFilter
-{
-    fn into_into_dart(self) -> crate::api::hybrid_search::SearchFilter {
-        self
+
+impl SseEncode for Vec<crate::api::query_keywords::QueryKeyword> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::query_keywords::QueryKeyword>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::SemanticChunk {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.index.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.start_pos.into_into_dart().into_dart(),
-            self.end_pos.into_into_dart().into_dart(),
-            self.chunk_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::query_history::QuerySuggestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::query_history::QuerySuggestion>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::semantic_chunker::SemanticChunk
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::SemanticChunk>
-    for crate::api::semantic_chunker::SemanticChunk
-{
-    fn into_into_dart(self) -> crate::api::semantic_chunker::SemanticChunk {
-        self
+
+impl SseEncode for Vec<crate::api::hybrid_search::RankedHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::hybrid_search::RankedHit>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::SourceEntry {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<(i64, Vec<f32>)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(i64, Vec<f32>)>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::SourceEntry
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::SourceEntry>
-    for crate::api::source_rag::SourceEntry
-{
-    fn into_into_dart(self) -> crate::api::source_rag::SourceEntry {
-        self
+
+impl SseEncode for Vec<(i64, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(i64, String)>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::source_rag::SourceStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source_count.into_into_dart().into_dart(),
-            self.chunk_count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<(String, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, f64)>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::source_rag::SourceStats
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::source_rag::SourceStats>
-    for crate::api::source_rag::SourceStats
-{
-    fn into_into_dart(self) -> crate::api::source_rag::SourceStats {
-        self
+
+impl SseEncode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::semantic_chunker::StructuredChunk {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.index.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.header_path.into_into_dart().into_dart(),
-            self.chunk_type.into_into_dart().into_dart(),
-            self.start_pos.into_into_dart().into_dart(),
-            self.end_pos.into_into_dart().into_dart(),
-            self.batch_id.into_into_dart().into_dart(),
-            self.batch_index.into_into_dart().into_dart(),
-            self.batch_total.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::retrieval_state::RetrievalCitation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::retrieval_state::RetrievalCitation>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::semantic_chunker::StructuredChunk
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::semantic_chunker::StructuredChunk>
-    for crate::api::semantic_chunker::StructuredChunk
-{
-    fn into_into_dart(self) -> crate::api::semantic_chunker::StructuredChunk {
-        self
+
+impl SseEncode for Vec<crate::api::search_trace::SearchSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_trace::SearchSpan>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::user_intent::UserIntent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::user_intent::UserIntent::Summary { query } => {
-                [0.into_dart(), query.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::user_intent::UserIntent::Define { term } => {
-                [1.into_dart(), term.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::user_intent::UserIntent::ExpandKnowledge { query } => {
-                [2.into_dart(), query.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::user_intent::UserIntent::General { query } => {
-                [3.into_dart(), query.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::user_intent::UserIntent::InvalidCommand { command, reason } => [
-                4.into_dart(),
-                command.into_into_dart().into_dart(),
-                reason.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Vec<crate::api::semantic_chunker::SemanticChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::semantic_chunker::SemanticChunk>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::user_intent::UserIntent
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::user_intent::UserIntent>
-    for crate::api::user_intent::UserIntent
-{
-    fn into_into_dart(self) -> crate::api::user_intent::UserIntent {
-        self
+
+impl SseEncode for Vec<crate::api::snapshot::SnapshotFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::snapshot::SnapshotFile>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Vec<crate::api::corpus_report::SourceCorpusStats> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::corpus_report::SourceCorpusStats>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+impl SseEncode for Vec<crate::api::source_rag::SourceEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_rag::SourceEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::source_grouping::SourceGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::source_grouping::SourceGroup>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple_rag::AddDocumentResult {
+impl SseEncode for Vec<crate::api::chunk_store::StoreSearchHit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.success, serializer);
-        <bool>::sse_encode(self.is_duplicate, serializer);
-        <String>::sse_encode(self.message, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chunk_store::StoreSearchHit>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::source_rag::AddSourceResult {
+impl SseEncode for Vec<crate::api::semantic_chunker::StructuredChunk> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.source_id, serializer);
-        <bool>::sse_encode(self.is_duplicate, serializer);
-        <i32>::sse_encode(self.chunk_count, serializer);
-        <String>::sse_encode(self.message, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::semantic_chunker::StructuredChunk>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::bm25_search::Bm25SearchResult {
+impl SseEncode for Vec<crate::api::synonyms::SynonymEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.doc_id, serializer);
-        <f64>::sse_encode(self.score, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::synonyms::SynonymEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::maintenance::MaintenanceConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.require_charging, serializer);
+        <u32>::sse_encode(self.min_interval_secs, serializer);
     }
 }
 
-impl SseEncode for crate::api::incremental_index::BufferStats {
+impl SseEncode for crate::api::maintenance::MaintenanceReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.buffer_size, serializer);
-        <usize>::sse_encode(self.threshold, serializer);
-        <bool>::sse_encode(self.hnsw_loaded, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <bool>::sse_encode(self.buffer_merged, serializer);
+        <bool>::sse_encode(self.hnsw_compacted, serializer);
+        <u32>::sse_encode(self.bm25_pruned, serializer);
+        <i64>::sse_encode(self.wal_pages_checkpointed, serializer);
+        <bool>::sse_encode(self.interrupted, serializer);
     }
 }
 
-impl SseEncode for crate::api::source_rag::ChunkData {
+impl SseEncode for crate::api::chat_memory::MemoryMessage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.conversation_id, serializer);
+        <String>::sse_encode(self.role, serializer);
         <String>::sse_encode(self.content, serializer);
-        <i32>::sse_encode(self.chunk_index, serializer);
-        <i32>::sse_encode(self.start_pos, serializer);
-        <i32>::sse_encode(self.end_pos, serializer);
-        <String>::sse_encode(self.chunk_type, serializer);
-        <Vec<f32>>::sse_encode(self.embedding, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
     }
 }
 
-impl SseEncode for crate::api::source_rag::ChunkForReembedding {
+impl SseEncode for crate::api::memory_budget::MemoryProfile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.chunk_id, serializer);
-        <String>::sse_encode(self.content, serializer);
+        <Option<u32>>::sse_encode(self.budget_mb, serializer);
+        <bool>::sse_encode(self.low_memory, serializer);
+        <u32>::sse_encode(self.sqlite_cache_kb, serializer);
+        <u32>::sse_encode(self.sqlite_mmap_mb, serializer);
+        <bool>::sse_encode(self.quantize_index, serializer);
+        <u32>::sse_encode(self.candidate_multiplier, serializer);
+        <u32>::sse_encode(self.filtered_candidate_multiplier, serializer);
+        <u32>::sse_encode(self.scan_page_rows, serializer);
     }
 }
 
-impl SseEncode for crate::api::source_rag::ChunkSearchResult {
+impl SseEncode for crate::api::chat_memory::MemorySearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.chunk_id, serializer);
-        <i64>::sse_encode(self.source_id, serializer);
-        <i32>::sse_encode(self.chunk_index, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <String>::sse_encode(self.chunk_type, serializer);
+        <crate::api::chat_memory::MemoryMessage>::sse_encode(self.message, serializer);
         <f64>::sse_encode(self.similarity, serializer);
-        <Option<String>>::sse_encode(self.metadata, serializer);
     }
 }
 
-impl SseEncode for crate::api::semantic_chunker::ChunkType {
+impl SseEncode for crate::api::memory_budget::MemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::semantic_chunker::ChunkType::Definition => 0,
-                crate::api::semantic_chunker::ChunkType::Example => 1,
-                crate::api::semantic_chunker::ChunkType::List => 2,
-                crate::api::semantic_chunker::ChunkType::Procedure => 3,
-                crate::api::semantic_chunker::ChunkType::Comparison => 4,
-                crate::api::semantic_chunker::ChunkType::General => 5,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <bool>::sse_encode(self.low_memory, serializer);
+        <u64>::sse_encode(self.hnsw_points, serializer);
+        <u64>::sse_encode(self.quantized_points, serializer);
+        <u64>::sse_encode(self.quantized_index_bytes, serializer);
+        <u64>::sse_encode(self.buffered_embeddings, serializer);
+        <u64>::sse_encode(self.sqlite_memory_bytes, serializer);
+        <Option<u64>>::sse_encode(self.process_rss_bytes, serializer);
     }
 }
 
-impl SseEncode for crate::api::compression_utils::CompressedText {
+impl SseEncode for crate::api::named_bm25::NamedBm25IndexInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.text, serializer);
-        <i32>::sse_encode(self.original_chars, serializer);
-        <i32>::sse_encode(self.compressed_chars, serializer);
-        <f64>::sse_encode(self.ratio, serializer);
-        <i32>::sse_encode(self.sentences_removed, serializer);
-        <i32>::sse_encode(self.chars_saved_stopwords, serializer);
-        <i32>::sse_encode(self.chars_saved_truncation, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.document_count, serializer);
+        <u64>::sse_encode(self.vocabulary_size, serializer);
     }
 }
 
-impl SseEncode for crate::api::compression_utils::CompressionOptions {
+impl SseEncode for crate::api::named_index::NamedIndexConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.remove_stopwords, serializer);
-        <bool>::sse_encode(self.remove_duplicates, serializer);
-        <String>::sse_encode(self.language, serializer);
-        <i32>::sse_encode(self.level, serializer);
+        <Option<crate::api::hnsw_index::DistanceMetric>>::sse_encode(self.metric, serializer);
+        <Option<crate::api::hnsw_index::HnswBuildConfig>>::sse_encode(self.build, serializer);
+        <Option<u32>>::sse_encode(self.ef_search, serializer);
     }
 }
 
-impl SseEncode for crate::api::hnsw_index::EmbeddingPoint {
+impl SseEncode for crate::api::named_index::NamedIndexInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.id, serializer);
-        <Vec<f32>>::sse_encode(self.embedding, serializer);
-        <f32>::sse_encode(self.norm, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.point_count, serializer);
+        <u64>::sse_encode(self.deleted_points, serializer);
+        <u32>::sse_encode(self.dimension, serializer);
+        <crate::api::hnsw_index::DistanceMetric>::sse_encode(self.metric, serializer);
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Option<crate::api::audit::AuditReport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::audit::AuditReport>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::hnsw_index::HnswSearchResult {
+impl SseEncode for Option<crate::api::background_tasks::BackgroundTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.id, serializer);
-        <f32>::sse_encode(self.distance, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::background_tasks::BackgroundTask>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::hybrid_search::HybridSearchResult {
+impl SseEncode for Option<crate::api::fuzzy_terms::Bm25FuzzyConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.doc_id, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <f64>::sse_encode(self.score, serializer);
-        <u32>::sse_encode(self.vector_rank, serializer);
-        <u32>::sse_encode(self.bm25_rank, serializer);
-        <i64>::sse_encode(self.source_id, serializer);
-        <Option<String>>::sse_encode(self.metadata, serializer);
-        <u32>::sse_encode(self.chunk_index, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::fuzzy_terms::Bm25FuzzyConfig>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::semantic_chunker::ChunkingStrategy> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::semantic_chunker::ChunkingStrategy>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Option<crate::api::hnsw_index::DistanceMetric> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hnsw_index::DistanceMetric>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::incremental_index::IncrementalSearchResult {
+impl SseEncode for Option<crate::api::related_chunks::ExpansionConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.doc_id, serializer);
-        <f32>::sse_encode(self.distance, serializer);
-        <String>::sse_encode(self.source, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::related_chunks::ExpansionConfig>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::bm25_search::Bm25SearchResult> {
+impl SseEncode for Option<crate::api::hnsw_index::HnswBuildConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::bm25_search::Bm25SearchResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hnsw_index::HnswBuildConfig>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::source_rag::ChunkData> {
+impl SseEncode for Option<crate::api::hnsw_index::HnswIndexStats> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::source_rag::ChunkData>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hnsw_index::HnswIndexStats>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::source_rag::ChunkForReembedding> {
+impl SseEncode for Option<crate::api::hybrid_search::HybridSearchOptions> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::source_rag::ChunkForReembedding>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hybrid_search::HybridSearchOptions>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::source_rag::ChunkSearchResult> {
+impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::source_rag::ChunkSearchResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::hnsw_index::HnswSearchResult> {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::hnsw_index::HnswSearchResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::hybrid_search::HybridSearchResult> {
+impl SseEncode for Option<crate::api::retrieval_state::LastRetrieval> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::hybrid_search::HybridSearchResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::retrieval_state::LastRetrieval>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::incremental_index::IncrementalSearchResult> {
+impl SseEncode for Option<crate::api::maintenance::MaintenanceReport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::incremental_index::IncrementalSearchResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::maintenance::MaintenanceReport>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<f32> {
+impl SseEncode for Option<(u32, u32, u32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <(u32, u32, u32)>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<i64> {
+impl SseEncode for Option<crate::api::reembedding::ReembeddingJob> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i64>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::reembedding::ReembeddingJob>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u32> {
+impl SseEncode for Option<crate::api::hybrid_search::RrfConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u32>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hybrid_search::RrfConfig>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::api::search_trace::SearchDiagnostics> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_trace::SearchDiagnostics>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(i64, Vec<f32>)> {
+impl SseEncode for Option<crate::api::hybrid_search::SearchFilter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i64, Vec<f32>)>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::hybrid_search::SearchFilter>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(i64, String)> {
+impl SseEncode for Option<crate::api::snippets::SearchSnippet> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i64, String)>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::snippets::SearchSnippet>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::semantic_chunker::SemanticChunk> {
+impl SseEncode for Option<crate::api::search_trace::SearchTrace> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::semantic_chunker::SemanticChunk>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_trace::SearchTrace>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::source_rag::SourceEntry> {
+impl SseEncode for Option<crate::api::chunk_store::StoredChunk> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::source_rag::SourceEntry>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::chunk_store::StoredChunk>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::semantic_chunker::StructuredChunk> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::semantic_chunker::StructuredChunk>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <Vec<String>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<i32> {
+impl SseEncode for Option<Vec<i64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
+            <Vec<i64>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<(u32, u32, u32)> {
+impl SseEncode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <(u32, u32, u32)>::sse_encode(value, serializer);
+            <Vec<u8>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::hybrid_search::RrfConfig> {
+impl SseEncode for Option<Vec<(String, String)>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::hybrid_search::RrfConfig>::sse_encode(value, serializer);
+            <Vec<(String, String)>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::hybrid_search::SearchFilter> {
+impl SseEncode for Option<Vec<crate::api::source_grouping::SourceGroup>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::hybrid_search::SearchFilter>::sse_encode(value, serializer);
+            <Vec<crate::api::source_grouping::SourceGroup>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<Vec<i64>> {
+impl SseEncode for crate::api::panic_report::PanicEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<i64>>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.thread, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.location, serializer);
+        <Option<String>>::sse_encode(self.backtrace, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
     }
 }
 
@@ -5138,6 +20951,70 @@ impl SseEncode for crate::api::user_intent::ParsedIntent {
         <String>::sse_encode(self.query, serializer);
         <bool>::sse_encode(self.is_valid, serializer);
         <Option<String>>::sse_encode(self.error_message, serializer);
+        <String>::sse_encode(self.language, serializer);
+    }
+}
+
+impl SseEncode for crate::api::progress::ProgressEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.operation, serializer);
+        <String>::sse_encode(self.phase, serializer);
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hnsw_index::Quantization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::hnsw_index::Quantization::None => 0,
+                crate::api::hnsw_index::Quantization::Int8 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::query_decompose::QueryDecomposition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.original, serializer);
+        <Vec<String>>::sse_encode(self.sub_queries, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query_keywords::QueryKeyword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <u32>::sse_encode(self.doc_freq, serializer);
+        <bool>::sse_encode(self.in_index, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query_normalize::QueryNormalizationConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.unicode_nfkc, serializer);
+        <bool>::sse_encode(self.strip_punctuation, serializer);
+        <bool>::sse_encode(self.lowercase, serializer);
+        <bool>::sse_encode(self.remove_stopwords, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query_history::QuerySuggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.query, serializer);
+        <u32>::sse_encode(self.use_count, serializer);
+        <i64>::sse_encode(self.last_used_at, serializer);
     }
 }
 
@@ -5169,6 +21046,18 @@ impl SseEncode for crate::api::error::RagError {
                 <i32>::sse_encode(5, serializer);
                 <String>::sse_encode(field0, serializer);
             }
+            crate::api::error::RagError::DatabaseBusy(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RagError::NotInitialized(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RagError::ParseError(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -5176,6 +21065,16 @@ impl SseEncode for crate::api::error::RagError {
     }
 }
 
+impl SseEncode for crate::api::hybrid_search::RankedHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.doc_id, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <u32>::sse_encode(self.vector_rank, serializer);
+        <u32>::sse_encode(self.bm25_rank, serializer);
+    }
+}
+
 impl SseEncode for (i64, Vec<f32>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5192,6 +21091,22 @@ impl SseEncode for (i64, String) {
     }
 }
 
+impl SseEncode for (String, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <f64>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <String>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (u32, u32, u32) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5201,12 +21116,119 @@ impl SseEncode for (u32, u32, u32) {
     }
 }
 
+impl SseEncode for crate::api::reembedding::ReembeddingJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.model_name, serializer);
+        <u32>::sse_encode(self.batch_size, serializer);
+        <String>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <i64>::sse_encode(self.last_chunk_id, serializer);
+        <Option<u32>>::sse_encode(self.dimension, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::retrieval_state::RetrievalCitation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.chunk_id, serializer);
+        <i64>::sse_encode(self.source_id, serializer);
+        <i32>::sse_encode(self.chunk_index, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <Option<String>>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::intent_presets::RetrievalPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.top_k, serializer);
+        <f64>::sse_encode(self.vector_weight, serializer);
+        <f64>::sse_encode(self.bm25_weight, serializer);
+        <Option<String>>::sse_encode(self.boost_chunk_type, serializer);
+        <f64>::sse_encode(self.chunk_type_boost, serializer);
+        <bool>::sse_encode(self.use_mmr, serializer);
+        <f64>::sse_encode(self.mmr_lambda, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query_rewrite::RewrittenQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.original, serializer);
+        <String>::sse_encode(self.rewritten, serializer);
+        <bool>::sse_encode(self.was_rewritten, serializer);
+        <Option<String>>::sse_encode(self.antecedent, serializer);
+    }
+}
+
 impl SseEncode for crate::api::hybrid_search::RrfConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u32>::sse_encode(self.k, serializer);
         <f64>::sse_encode(self.vector_weight, serializer);
         <f64>::sse_encode(self.bm25_weight, serializer);
+        <std::collections::HashMap<String, f64>>::sse_encode(self.type_boosts, serializer);
+        <Option<f64>>::sse_encode(self.recency_half_life_days, serializer);
+        <f64>::sse_encode(self.recency_weight, serializer);
+        <crate::api::hybrid_search::FusionStrategy>::sse_encode(self.fusion, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hybrid_search::ScoreNormalization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::hybrid_search::ScoreNormalization::MinMax => 0,
+                crate::api::hybrid_search::ScoreNormalization::ZScore => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search_trace::SearchDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.vector_ms, serializer);
+        <f64>::sse_encode(self.bm25_ms, serializer);
+        <f64>::sse_encode(self.filter_ms, serializer);
+        <f64>::sse_encode(self.fusion_ms, serializer);
+        <f64>::sse_encode(self.fetch_ms, serializer);
+        <f64>::sse_encode(self.total_ms, serializer);
+        <u32>::sse_encode(self.candidate_k, serializer);
+        <u32>::sse_encode(self.vector_candidates, serializer);
+        <u32>::sse_encode(self.bm25_candidates, serializer);
+        <u32>::sse_encode(self.filtered_vector_candidates, serializer);
+        <u32>::sse_encode(self.filtered_bm25_candidates, serializer);
+        <bool>::sse_encode(self.exact_scan, serializer);
+        <bool>::sse_encode(self.graph_filter, serializer);
+        <bool>::sse_encode(self.hnsw_loaded, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_analytics::SearchFeedbackAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_analytics::SearchFeedbackAction::Clicked => 0,
+                crate::api::search_analytics::SearchFeedbackAction::Accepted => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -5215,6 +21237,38 @@ impl SseEncode for crate::api::hybrid_search::SearchFilter {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<Vec<i64>>>::sse_encode(self.source_ids, serializer);
         <Option<String>>::sse_encode(self.metadata_like, serializer);
+        <Option<String>>::sse_encode(self.entity, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Option<Vec<String>>>::sse_encode(self.chunk_types, serializer);
+        <Option<Vec<(String, String)>>>::sse_encode(self.metadata_equals, serializer);
+        <Option<i64>>::sse_encode(self.created_after, serializer);
+        <Option<i64>>::sse_encode(self.created_before, serializer);
+    }
+}
+
+impl SseEncode for crate::api::snippets::SearchSnippet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+        <Vec<crate::api::snippets::HighlightSpan>>::sse_encode(self.highlights, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_trace::SearchSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <f64>::sse_encode(self.duration_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_trace::SearchTrace {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::search_trace::SearchSpan>>::sse_encode(self.spans, serializer);
+        <f64>::sse_encode(self.total_ms, serializer);
     }
 }
 
@@ -5226,6 +21280,42 @@ impl SseEncode for crate::api::semantic_chunker::SemanticChunk {
         <i32>::sse_encode(self.start_pos, serializer);
         <i32>::sse_encode(self.end_pos, serializer);
         <String>::sse_encode(self.chunk_type, serializer);
+        <String>::sse_encode(self.language, serializer);
+    }
+}
+
+impl SseEncode for crate::api::snapshot::SnapshotFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+    }
+}
+
+impl SseEncode for crate::api::snapshot::SnapshotManifest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.format_version, serializer);
+        <String>::sse_encode(self.engine_version, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Vec<String>>::sse_encode(self.tenants, serializer);
+        <i64>::sse_encode(self.source_count, serializer);
+        <i64>::sse_encode(self.chunk_count, serializer);
+        <Vec<crate::api::snapshot::SnapshotFile>>::sse_encode(self.files, serializer);
+    }
+}
+
+impl SseEncode for crate::api::corpus_report::SourceCorpusStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.source_id, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.status, serializer);
+        <u64>::sse_encode(self.chunk_count, serializer);
+        <u32>::sse_encode(self.min_chunk_chars, serializer);
+        <u32>::sse_encode(self.max_chunk_chars, serializer);
+        <f64>::sse_encode(self.avg_chunk_chars, serializer);
     }
 }
 
@@ -5240,6 +21330,25 @@ impl SseEncode for crate::api::source_rag::SourceEntry {
     }
 }
 
+impl SseEncode for crate::api::source_grouping::SourceGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.source_id, serializer);
+        <bool>::sse_encode(self.is_document, serializer);
+        <Option<String>>::sse_encode(self.source_name, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_encode(self.chunks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::source_index::SourceIndexConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.max_cached, serializer);
+    }
+}
+
 impl SseEncode for crate::api::source_rag::SourceStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5248,6 +21357,46 @@ impl SseEncode for crate::api::source_rag::SourceStats {
     }
 }
 
+impl SseEncode for crate::api::staged_ingest::SourceUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.staging_id, serializer);
+        <Vec<String>>::sse_encode(self.chunks, serializer);
+        <u32>::sse_encode(self.kept_chunks, serializer);
+        <u32>::sse_encode(self.removed_chunks, serializer);
+        <u32>::sse_encode(self.skipped_chunks, serializer);
+        <i64>::sse_encode(self.expires_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::staged_ingest::StagedIngest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.staging_id, serializer);
+        <Vec<String>>::sse_encode(self.chunks, serializer);
+        <u32>::sse_encode(self.skipped_chunks, serializer);
+        <i64>::sse_encode(self.expires_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chunk_store::StoreSearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chunk_store::StoredChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <i64>::sse_encode(self.source_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<f32>>::sse_encode(self.embedding, serializer);
+    }
+}
+
 impl SseEncode for crate::api::semantic_chunker::StructuredChunk {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5260,6 +21409,23 @@ impl SseEncode for crate::api::semantic_chunker::StructuredChunk {
         <Option<String>>::sse_encode(self.batch_id, serializer);
         <Option<i32>>::sse_encode(self.batch_index, serializer);
         <Option<i32>>::sse_encode(self.batch_total, serializer);
+        <String>::sse_encode(self.language, serializer);
+    }
+}
+
+impl SseEncode for crate::api::synonyms::SynonymEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.term, serializer);
+        <Vec<String>>::sse_encode(self.expansions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::hybrid_search::TracedHybridSearch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::hybrid_search::HybridSearchResult>>::sse_encode(self.results, serializer);
+        <Option<crate::api::search_trace::SearchTrace>>::sse_encode(self.trace, serializer);
     }
 }
 
@@ -5314,6 +21480,19 @@ impl SseEncode for crate::api::user_intent::UserIntent {
                 <String>::sse_encode(command, serializer);
                 <String>::sse_encode(reason, serializer);
             }
+            crate::api::user_intent::UserIntent::Compare { left, right } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(left, serializer);
+                <String>::sse_encode(right, serializer);
+            }
+            crate::api::user_intent::UserIntent::Cite { query } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(query, serializer);
+            }
+            crate::api::user_intent::UserIntent::Sources { query } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(query, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -5339,6 +21518,8 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::chunk_store::ChunkStore;
+    use crate::api::chunk_store::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -5348,6 +21529,20 @@ mod io {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mobile_rag_engine_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mobile_rag_engine_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -5361,6 +21556,8 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::chunk_store::ChunkStore;
+    use crate::api::chunk_store::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -5372,6 +21569,20 @@ mod web {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryStore>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;
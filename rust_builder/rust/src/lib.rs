@@ -6,4 +6,9 @@
 // Please submit a PR with detailed explanation of changes before modifying.
 
 pub mod api;
+pub mod c_api;
+#[cfg(feature = "dev-server")]
+pub mod dev_server;
 mod frb_generated;
+#[cfg(any(test, feature = "test_support"))]
+pub mod test_support;
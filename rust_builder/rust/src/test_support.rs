@@ -0,0 +1,273 @@
+// Copyright 2025 mobile_rag_engine contributors
+// SPDX-License-Identifier: MIT
+//
+// Licensed under the MIT License. You may obtain a copy of the License at
+// https://opensource.org/licenses/MIT
+//
+// This software is provided "AS IS", without warranty of any kind, express or
+// implied, including but not limited to the warranties of merchantability,
+// fitness for a particular purpose, and noninfringement. In no event shall the
+// authors or copyright holders be liable for any claim, damages, or other
+// liability arising from the use of this software.
+//
+// CONTRIBUTOR GUIDELINES:
+// This file is part of the core engine. Any modifications require owner approval.
+// Please submit a PR with detailed explanation of changes before modifying.
+//
+//! Ephemeral engine for integration tests (feature `test_support`).
+//!
+//! [`TestEngine`] points the engine at a private in-memory database, so
+//! tests never touch real files and row ids start at 1 for every engine.
+//! The connection pool, active tenant and in-memory indexes are process
+//! globals, so engines are serialized: a second `TestEngine::new()` blocks
+//! until the first is dropped, which also clears the indexes it built.
+//!
+//! ```ignore
+//! let engine = TestEngine::new()?;
+//! let id = engine.source("Rust ownership guide").chunk("Borrowing rules").add()?;
+//! engine.rebuild_indexes()?;
+//! let hits = engine.search("borrowing", 3)?;
+//! assert_eq!(hits[0].source_id, id);
+//! ```
+//!
+//! Embeddings come from [`fake_embedding`], a hashed bag of words: texts
+//! sharing words score higher, and the same text always maps to the same
+//! vector. HNSW indexes are built in seeded mode (`set_hnsw_seeded_build`)
+//! so rankings are reproducible across runs.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::api::bm25_search::{drop_tenant_bm25_index, tokenize_for_bm25};
+use crate::api::db_pool::{close_db_pool, init_db_pool};
+use crate::api::error::RagError;
+use crate::api::hnsw_index::{clear_all_hnsw_indexes, set_hnsw_seeded_build};
+use crate::api::hybrid_search::{search_hybrid, HybridSearchResult};
+use crate::api::incremental_index::clear_all_buffers;
+use crate::api::index_freshness::forget_indexed_versions;
+use crate::api::quantized_index::clear_all_quantized_indexes;
+use crate::api::semantic_chunker::classify_chunk;
+use crate::api::source_index::drop_tenant_source_indexes;
+use crate::api::source_rag::{
+    add_chunks, add_source, init_source_db, rebuild_chunk_bm25_index, rebuild_chunk_hnsw_index,
+    ChunkData,
+};
+use crate::api::tenant::{set_active_tenant, DEFAULT_TENANT};
+
+/// Dimension of [`fake_embedding`] vectors.
+pub const TEST_EMBEDDING_DIM: usize = 64;
+
+/// Seed of the engine's HNSW builds.
+pub const TEST_HNSW_SEED: u64 = 0x5eed;
+
+/// Pool size of the in-memory database.
+const TEST_POOL_SIZE: u32 = 4;
+
+static ENGINE_LOCK: Mutex<()> = Mutex::new(());
+static NEXT_DB: AtomicU64 = AtomicU64::new(0);
+
+/// Deterministic `dim`-dimensional unit vector for `text`: each BM25 token
+/// is hashed (FNV-1a) into a signed bucket.
+pub fn fake_embedding(text: &str, dim: usize) -> Vec<f32> {
+    let mut vector = vec![0.0f32; dim.max(1)];
+    for token in tokenize_for_bm25(text) {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in token.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        let bucket = (hash % vector.len() as u64) as usize;
+        let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[bucket] += sign;
+    }
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        vector[0] = 1.0;
+    } else {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+/// The engine bound to a private in-memory database for its lifetime.
+pub struct TestEngine {
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl TestEngine {
+    /// Wait for any other engine to be dropped, then start on an empty
+    /// database with the default tenant active.
+    pub fn new() -> Result<Self, RagError> {
+        // A test that panicked while holding the lock still dropped its
+        // engine, so the globals are clean.
+        let guard = ENGINE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        reset_globals();
+        set_hnsw_seeded_build(Some(TEST_HNSW_SEED));
+        let uri = format!(
+            "file:rag_test_{}_{}?mode=memory&cache=shared",
+            std::process::id(),
+            NEXT_DB.fetch_add(1, Ordering::Relaxed)
+        );
+        init_db_pool(uri, TEST_POOL_SIZE)?;
+        init_source_db()?;
+        Ok(Self { _guard: guard })
+    }
+
+    /// Start building a source with `content`.
+    pub fn source(&self, content: &str) -> SourceBuilder<'_> {
+        SourceBuilder {
+            _engine: self,
+            content: content.to_string(),
+            name: None,
+            metadata: None,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Rebuild the HNSW and BM25 indexes from the database.
+    pub fn rebuild_indexes(&self) -> Result<(), RagError> {
+        rebuild_chunk_hnsw_index()?;
+        rebuild_chunk_bm25_index()
+    }
+
+    /// Hybrid search for `query`, embedded with [`fake_embedding`].
+    pub fn search(&self, query: &str, top_k: u32) -> Result<Vec<HybridSearchResult>, RagError> {
+        search_hybrid(
+            query.to_string(),
+            fake_embedding(query, TEST_EMBEDDING_DIM),
+            top_k,
+            None,
+            None,
+            None,
+        )
+        .map(|response| response.results)
+    }
+}
+
+impl Drop for TestEngine {
+    fn drop(&mut self) {
+        reset_globals();
+        // Closing the last connection frees the in-memory database.
+        close_db_pool();
+    }
+}
+
+fn reset_globals() {
+    set_hnsw_seeded_build(None);
+    let _ = set_active_tenant(DEFAULT_TENANT.to_string());
+    clear_all_hnsw_indexes();
+    clear_all_quantized_indexes();
+    clear_all_buffers();
+    drop_tenant_bm25_index(DEFAULT_TENANT);
+    drop_tenant_source_indexes(DEFAULT_TENANT);
+    forget_indexed_versions(DEFAULT_TENANT);
+}
+
+/// A source and its chunks, added by [`SourceBuilder::add`].
+pub struct SourceBuilder<'a> {
+    _engine: &'a TestEngine,
+    content: String,
+    name: Option<String>,
+    metadata: Option<String>,
+    chunks: Vec<(String, Option<Vec<f32>>)>,
+}
+
+impl SourceBuilder<'_> {
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &str) -> Self {
+        self.metadata = Some(metadata.to_string());
+        self
+    }
+
+    /// Add a chunk embedded with [`fake_embedding`].
+    pub fn chunk(mut self, content: &str) -> Self {
+        self.chunks.push((content.to_string(), None));
+        self
+    }
+
+    /// Add a chunk with an explicit embedding.
+    pub fn chunk_with_embedding(mut self, content: &str, embedding: Vec<f32>) -> Self {
+        self.chunks.push((content.to_string(), Some(embedding)));
+        self
+    }
+
+    /// Store the source and its chunks (the whole content as one chunk if
+    /// none were given) and return the source id. Indexes are not rebuilt.
+    pub fn add(self) -> Result<i64, RagError> {
+        let result = add_source(self.content.clone(), self.metadata, self.name)?;
+        if result.is_duplicate {
+            return Ok(result.source_id);
+        }
+        let mut chunks = self.chunks;
+        if chunks.is_empty() {
+            chunks.push((self.content, None));
+        }
+        let mut position = 0;
+        let data = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, (content, embedding))| {
+                let end_pos = position + content.chars().count() as i32;
+                let chunk = ChunkData {
+                    chunk_index: index as i32,
+                    start_pos: position,
+                    end_pos,
+                    chunk_type: classify_chunk(&content).as_str().to_string(),
+                    embedding: embedding
+                        .unwrap_or_else(|| fake_embedding(&content, TEST_EMBEDDING_DIM)),
+                    content,
+                    batch_id: None,
+                };
+                position = end_pos;
+                chunk
+            })
+            .collect();
+        add_chunks(result.source_id, data)?;
+        Ok(result.source_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_embedding_is_deterministic_and_normalized() {
+        let a = fake_embedding("borrow checker rules", TEST_EMBEDDING_DIM);
+        assert_eq!(
+            a,
+            fake_embedding("borrow checker rules", TEST_EMBEDDING_DIM)
+        );
+        let norm = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+        assert_eq!(fake_embedding("", 8)[0], 1.0);
+    }
+
+    #[test]
+    fn test_engine_search_and_fresh_ids() {
+        for _ in 0..2 {
+            let engine = TestEngine::new().unwrap();
+            let rust = engine
+                .source("Rust guide")
+                .chunk("ownership and borrowing rules")
+                .chunk("lifetimes annotate references")
+                .add()
+                .unwrap();
+            let cooking = engine
+                .source("Pasta recipe")
+                .chunk("boil the pasta in salted water")
+                .add()
+                .unwrap();
+            assert_eq!((rust, cooking), (1, 2));
+            engine.rebuild_indexes().unwrap();
+
+            let hits = engine.search("borrowing rules", 2).unwrap();
+            assert_eq!(hits[0].source_id, rust);
+            assert_eq!(hits[0].content, "ownership and borrowing rules");
+        }
+    }
+}
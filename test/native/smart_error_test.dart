@@ -24,17 +24,20 @@ void main() {
         log('\n[Caught RagError Successfully]');
 
         e.when(
-          databaseError: (msg) {
-            log('✅ Type: DatabaseError');
-            log('✅ Message: $msg');
-            // Expect the message from get_connection() in db_pool.rs
-            expect(msg, contains('DB pool not initialized'));
-          },
+          databaseError: (msg) => fail('Unexpected DatabaseError: $msg'),
           ioError: (msg) => fail('Unexpected IoError: $msg'),
           modelLoadError: (msg) => fail('Unexpected ModelLoadError: $msg'),
           invalidInput: (msg) => fail('Unexpected InvalidInput: $msg'),
           internalError: (msg) => fail('Unexpected InternalError: $msg'),
           unknown: (msg) => fail('Unexpected Unknown error: $msg'),
+          databaseBusy: (msg) => fail('Unexpected DatabaseBusy: $msg'),
+          notInitialized: (msg) {
+            log('✅ Type: NotInitialized');
+            log('✅ Message: $msg');
+            // Expect the message from get_connection() in db_pool.rs
+            expect(msg, contains('DB pool not initialized'));
+          },
+          parseError: (msg) => fail('Unexpected ParseError: $msg'),
         );
       }
     },